@@ -0,0 +1,56 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::process::Command;
+
+use pnet_datalink as pnet;
+
+use Result;
+use namespace;
+use user;
+use util;
+
+/// Takes down every interface of a created network namespace other than loopback, so that
+/// processes running in it can no longer reach the network.
+pub fn run<P: AsRef<Path>>(ns_dir: P) -> Result<()> {
+    user::check_running_user_is_root()?;
+    util::check_required_packages(&[util::IP_PKG])?;
+
+    namespace::setns_network(namespace::netns_file(&ns_dir))?;
+    for interface in pnet::interfaces() {
+        if interface.is_loopback() {
+            continue;
+        }
+        util::run_cmd(interface_down_cmd(&interface.name)?)?;
+    }
+
+    println!(
+        "Network namespace {} locked down.",
+        namespace::netns_file(&ns_dir).display()
+    );
+
+    Ok(())
+}
+
+fn interface_down_cmd(interface: &str) -> Result<Command> {
+    let mut command = util::ip_cmd()?;
+    command.arg("link");
+    command.arg("set");
+    command.arg("dev");
+    command.arg(interface);
+    command.arg("down");
+
+    Ok(command)
+}
@@ -16,3 +16,4 @@ pub mod create;
 pub mod createasuser;
 pub mod createinns;
 pub mod destroy;
+pub mod lockdown;
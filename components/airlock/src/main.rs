@@ -54,6 +54,7 @@ fn _main() -> Result<()> {
                 ("createasuser", Some(m)) => sub_netns_createasuser(m),
                 ("createinns", Some(m)) => sub_netns_createinns(m),
                 ("destroy", Some(m)) => sub_netns_destroy(m),
+                ("lockdown", Some(m)) => sub_netns_lockdown(m),
                 _ => unreachable!(),
             }
         }
@@ -96,6 +97,12 @@ fn sub_netns_destroy(m: &ArgMatches) -> Result<()> {
     command::netns::destroy::run(ns_dir)
 }
 
+fn sub_netns_lockdown(m: &ArgMatches) -> Result<()> {
+    let ns_dir = Path::new(m.value_of("NS_DIR").unwrap());
+
+    command::netns::lockdown::run(ns_dir)
+}
+
 fn sub_nsrun(m: &ArgMatches) -> Result<()> {
     let fs_root = Path::new(m.value_of("FS_ROOT").unwrap());
     let mount_artifacts = m.is_present("MOUNT_ARTIFACT_CACHE");
@@ -227,6 +234,11 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 (@arg NS_DIR: --("ns-dir") -d +takes_value +required {validate_dir_exists}
                     "Path where the namespace files will be mounted")
             )
+            (@subcommand lockdown =>
+                (about: "Drop all network access of a created network namespace")
+                (@arg NS_DIR: --("ns-dir") -d +takes_value +required {validate_dir_exists}
+                    "Path where the namespace files will be mounted")
+            )
         )
     )
 }
@@ -28,6 +28,8 @@ pub struct Config {
     pub http: HttpCfg,
     pub routers: Vec<RouterAddr>,
     pub github: GitHubCfg,
+    /// OAuth provider users sign in with
+    pub oauth: OAuthCfg,
     pub ui: UiCfg,
 }
 
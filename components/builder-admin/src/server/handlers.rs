@@ -18,6 +18,7 @@ use bodyparser;
 use hab_net::privilege;
 use http_gateway::http::controller::*;
 use iron::status;
+use protocol::jobsrv::{JobHistoryPrune, JobHistoryPruneResponse};
 use protocol::sessionsrv::*;
 use router::Router;
 
@@ -59,6 +60,11 @@ struct SearchTerm {
     value: String,
 }
 
+#[derive(Clone, Serialize, Deserialize)]
+struct PruneReq {
+    retention_days: Option<u32>,
+}
+
 pub fn account_show(req: &mut Request) -> IronResult<Response> {
     let mut account_get_id = AccountGetId::new();
     {
@@ -75,6 +81,23 @@ pub fn account_show(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+pub fn job_history_prune(req: &mut Request) -> IronResult<Response> {
+    let mut prune = JobHistoryPrune::new();
+    match req.get::<bodyparser::Struct<PruneReq>>() {
+        Ok(Some(body)) => {
+            if let Some(retention_days) = body.retention_days {
+                prune.set_retention_days(retention_days);
+            }
+        }
+        Ok(None) => (),
+        Err(_) => return Ok(Response::with(status::UnprocessableEntity)),
+    }
+    match route_message::<JobHistoryPrune, JobHistoryPruneResponse>(req, &prune) {
+        Ok(pruned) => Ok(render_json(status::Ok, &pruned)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 /// Endpoint for determining availability of builder-api components.
 ///
 /// Returns a status 200 on success. Any non-200 responses are an outage or a partial outage.
@@ -20,6 +20,7 @@ use github_api_client::GitHubClient;
 use hab_net::privilege;
 use http_gateway;
 use http_gateway::app::prelude::*;
+use http_gateway::oauth;
 use iron;
 use persistent;
 use staticfile::Static;
@@ -37,6 +38,9 @@ impl HttpGateway for AdminSrv {
         chain.link(persistent::Read::<GitHubCli>::both(
             GitHubClient::new(config.github.clone()),
         ));
+        chain.link(persistent::Read::<OAuthCli>::both(
+            oauth::client(&config.oauth, &config.github),
+        ));
     }
 
     fn mount(config: Arc<Self::Config>, chain: iron::Chain) -> Mount {
@@ -55,6 +59,9 @@ impl HttpGateway for AdminSrv {
             status: get "/status" => status,
             search: post "/search" => XHandler::new(search).before(admin.clone()),
             account: get "/accounts/:id" => XHandler::new(account_show).before(admin.clone()),
+            job_history_prune: post "/jobs/prune" => {
+                XHandler::new(job_history_prune).before(admin.clone())
+            },
         )
     }
 }
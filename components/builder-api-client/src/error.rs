@@ -32,6 +32,7 @@ pub enum Error {
     HyperError(hyper::error::Error),
     IO(io::Error),
     Json(serde_json::Error),
+    JobLogStream(String),
     URL(url::ParseError),
 }
 
@@ -44,6 +45,7 @@ impl fmt::Display for Error {
             Error::HyperError(ref err) => format!("{}", err),
             Error::IO(ref e) => format!("{}", e),
             Error::Json(ref e) => format!("{}", e),
+            Error::JobLogStream(ref e) => format!("Unable to stream the job log: {}", e),
             Error::URL(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
@@ -58,6 +60,7 @@ impl error::Error for Error {
             Error::HyperError(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
             Error::Json(ref err) => err.description(),
+            Error::JobLogStream(_) => "Unable to stream the job log",
             Error::URL(ref err) => err.description(),
         }
     }
@@ -32,13 +32,15 @@ extern crate url;
 pub mod error;
 pub use error::{Error, Result};
 
-use std::io::Read;
+use std::io::{BufRead, BufReader, Read};
 use std::path::Path;
 
 use hab_core::package::PackageIdent;
+use hab_core::url::bldr_urls;
 use hab_http::ApiClient;
 use hyper::client::{IntoUrl, Response, RequestBuilder};
-use hyper::header::{Accept, Authorization, Bearer, ContentType};
+use hyper::header::{qitem, Accept, Authorization, Bearer, ContentType};
+use hyper::mime::{Mime, SubLevel, TopLevel};
 use hyper::status::StatusCode;
 
 const DEFAULT_API_PATH: &'static str = "/v1";
@@ -52,6 +54,28 @@ pub struct ReverseDependencies {
     pub rdeps: Vec<String>,
 }
 
+#[derive(Deserialize, Serialize)]
+pub struct Job {
+    pub id: String,
+    pub origin: String,
+    pub name: String,
+    pub version: Option<String>,
+    pub release: Option<String>,
+    pub state: String,
+    pub created_at: Option<String>,
+    pub build_started_at: Option<String>,
+    pub build_finished_at: Option<String>,
+    pub channel: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct JobLog {
+    pub start: u64,
+    pub stop: u64,
+    pub content: Vec<String>,
+    pub is_complete: bool,
+}
+
 #[derive(Default, Deserialize)]
 pub struct JobGroupPromoteResponse {
     pub group_id: String,
@@ -59,6 +83,8 @@ pub struct JobGroupPromoteResponse {
 }
 
 impl Client {
+    /// Creates a client for the Builder at `endpoint`. Only the first mirror is used when it lists
+    /// several of them.
     pub fn new<U>(
         endpoint: U,
         product: &str,
@@ -66,9 +92,11 @@ impl Client {
         fs_root_path: Option<&Path>,
     ) -> Result<Self>
     where
-        U: IntoUrl,
+        U: AsRef<str>,
     {
-        let mut endpoint = endpoint.into_url().map_err(Error::URL)?;
+        let mut endpoint = bldr_urls(endpoint.as_ref())[0].into_url().map_err(
+            Error::URL,
+        )?;
         if !endpoint.cannot_be_a_base() && endpoint.path() == "/" {
             endpoint.set_path(DEFAULT_API_PATH);
         }
@@ -211,13 +239,185 @@ impl Client {
         Ok(())
     }
 
+    /// Show a job
+    ///
+    /// # Failures
+    ///
+    /// * Remote API Server is not available
+    /// * The job doesn't exist
+    pub fn job_show(&self, job_id: u64, token: &str) -> Result<Job> {
+        let url = format!("jobs/{}", job_id);
+        let mut res = self.add_authz(self.0.get(&url), token).send().map_err(
+            Error::HyperError,
+        )?;
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+
+        let mut encoded = String::new();
+        res.read_to_string(&mut encoded).map_err(Error::IO)?;
+        debug!("Body: {:?}", encoded);
+        serde_json::from_str(&encoded).map_err(Error::Json)
+    }
+
+    /// Fetch the log of a job from the `start` line
+    ///
+    /// # Failures
+    ///
+    /// * Remote API Server is not available
+    /// * The job doesn't exist or has no log yet
+    pub fn job_log(&self, job_id: u64, start: u64, token: &str) -> Result<JobLog> {
+        let url = format!("jobs/{}/log", job_id);
+        let query = format!("start={}", start);
+        let mut res = self.add_authz(
+            self.0.get_with_custom_url(&url, |u| u.set_query(Some(&query))),
+            token,
+        ).send()
+            .map_err(Error::HyperError)?;
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        }
+
+        let mut encoded = String::new();
+        res.read_to_string(&mut encoded).map_err(Error::IO)?;
+        serde_json::from_str(&encoded).map_err(Error::Json)
+    }
+
+    /// Follow the log of a job from the `start` line until the job is done, calling `on_log` with
+    /// every part of it as soon as the worker sends it. The stream is reopened where it left off
+    /// whenever the connection drops after making some progress.
+    ///
+    /// # Failures
+    ///
+    /// * Remote API Server is not available
+    /// * The job doesn't exist
+    /// * The log can't be read
+    pub fn job_log_follow<F>(
+        &self,
+        job_id: u64,
+        start: u64,
+        token: &str,
+        mut on_log: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&JobLog),
+    {
+        let url = format!("jobs/{}/log/stream", job_id);
+        let mut start = start;
+        loop {
+            let query = format!("start={}", start);
+            let res = self.add_authz(
+                self.0.get_with_custom_url(&url, |u| u.set_query(Some(&query))),
+                token,
+            ).header(Accept(vec![qitem(Mime(TopLevel::Text, SubLevel::EventStream, vec![]))]))
+                .send()
+                .map_err(Error::HyperError)?;
+            if res.status != StatusCode::Ok {
+                return Err(err_from_response(res));
+            }
+
+            let resumed_at = start;
+            let mut reader = BufReader::new(res);
+            while let Some(event) = next_event(&mut reader)? {
+                match event.name.as_str() {
+                    "log" => {
+                        let log: JobLog = serde_json::from_str(&event.data).map_err(Error::Json)?;
+                        start = log.stop;
+                        on_log(&log);
+                    }
+                    "complete" => return Ok(()),
+                    "error" => return Err(Error::JobLogStream(event.data)),
+                    name => debug!("Ignoring unknown job log event, name={}", name),
+                }
+            }
+            if start == resumed_at {
+                return Err(Error::JobLogStream(
+                    "the connection closed before the job was done".to_string(),
+                ));
+            }
+            debug!("Job log stream closed, resuming at line {}", start);
+        }
+    }
+
     fn add_authz<'a>(&'a self, rb: RequestBuilder<'a>, token: &str) -> RequestBuilder {
         rb.header(Authorization(Bearer { token: token.to_string() }))
     }
 }
 
+/// A server-sent event
+#[derive(Debug, PartialEq)]
+struct Event {
+    name: String,
+    data: String,
+}
+
+/// Reads the next server-sent event, skipping comments, or returns `None` at the end of the stream
+fn next_event<R: BufRead>(reader: &mut R) -> Result<Option<Event>> {
+    let mut name = None;
+    let mut data: Vec<String> = Vec::new();
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line).map_err(Error::IO)? == 0 {
+            return Ok(None);
+        }
+        let line = line.trim_right_matches(|c| c == '\n' || c == '\r');
+        if line.is_empty() {
+            if name.is_some() || !data.is_empty() {
+                return Ok(Some(Event {
+                    name: name.unwrap_or("message".to_string()),
+                    data: data.join("\n"),
+                }));
+            }
+            continue;
+        }
+        if line.starts_with(':') {
+            continue;
+        }
+        let (field, value) = match line.find(':') {
+            Some(i) => (&line[..i], line[i + 1..].trim_left_matches(' ')),
+            None => (line, ""),
+        };
+        match field {
+            "event" => name = Some(value.to_string()),
+            "data" => data.push(value.to_string()),
+            _ => (),
+        }
+    }
+}
+
 fn err_from_response(mut response: Response) -> Error {
     let mut s = String::new();
     response.read_to_string(&mut s).map_err(Error::IO).unwrap();
     Error::APIError(response.status, s)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn server_sent_events() {
+        let mut stream = Cursor::new(
+            ": keepalive\n\nevent: log\nid: 2\ndata: {\"start\":0,\n\
+             data: \"stop\":2}\n\nevent: complete\r\ndata: {\"stop\":2}\r\n\r\n"
+                .as_bytes(),
+        );
+        assert_eq!(
+            next_event(&mut stream).unwrap(),
+            Some(Event {
+                name: "log".to_string(),
+                data: "{\"start\":0,\n\"stop\":2}".to_string(),
+            })
+        );
+        assert_eq!(
+            next_event(&mut stream).unwrap(),
+            Some(Event {
+                name: "complete".to_string(),
+                data: "{\"stop\":2}".to_string(),
+            })
+        );
+        assert_eq!(next_event(&mut stream).unwrap(), None);
+    }
+}
@@ -9,6 +9,10 @@ habitatConfig({
     github_web_url: "{{cfg.github.web_url}}",
     github_app_url: "{{cfg.github.app_url}}",
     github_app_id: "{{cfg.github.app_id}}",
+    oauth_provider: "{{cfg.oauth.provider}}",
+    oauth_authorize_url: "{{cfg.oauth.authorize_url}}",
+    oauth_client_id: "{{cfg.oauth.client_id}}",
+    oauth_provider_name: "{{cfg.oauth.provider_name}}",
     source_code_url: "{{cfg.source_code_url}}",
     tutorials_url: "{{cfg.tutorials_url}}",
     version: "{{pkg.ident}}",
@@ -15,4 +15,7 @@ pkg_exposes=(port)
 pkg_binds=(
   [router]="port"
 )
+pkg_binds_optional=(
+  [jobsrv]="log_stream_port"
+)
 bin="bldr-api"
\ No newline at end of file
@@ -32,7 +32,11 @@ pub struct Config {
     pub http: HttpCfg,
     /// List of net addresses for routing servers to connect to
     pub routers: Vec<RouterAddr>,
+    /// List of net addresses for job servers to follow job logs from
+    pub jobsrvs: Vec<JobSrvAddr>,
     pub github: GitHubCfg,
+    /// OAuth provider users sign in with
+    pub oauth: OAuthCfg,
     pub segment: SegmentCfg,
     pub ui: UiCfg,
     /// Depot's configuration
@@ -43,6 +47,7 @@ pub struct Config {
     pub non_core_builds_enabled: bool,
     /// Where to record log events for funnel metrics
     pub log_dir: String,
+    pub rate_limit: RateLimitCfg,
 }
 
 impl Default for Config {
@@ -50,13 +55,16 @@ impl Default for Config {
         Config {
             http: HttpCfg::default(),
             routers: vec![RouterAddr::default()],
+            jobsrvs: vec![JobSrvAddr::default()],
             github: GitHubCfg::default(),
+            oauth: OAuthCfg::default(),
             segment: SegmentCfg::default(),
             ui: UiCfg::default(),
             depot: depot::config::Config::default(),
             events_enabled: false,
             non_core_builds_enabled: true,
             log_dir: env::temp_dir().to_string_lossy().into_owned(),
+            rate_limit: RateLimitCfg::default(),
         }
     }
 }
@@ -117,6 +125,29 @@ impl ToSocketAddrs for HttpCfg {
     }
 }
 
+/// Net address of a job server's log stream, on which new output of job logs is published
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct JobSrvAddr {
+    pub host: IpAddr,
+    pub log_stream_port: u16,
+}
+
+impl JobSrvAddr {
+    pub fn log_stream_addr(&self) -> String {
+        format!("tcp://{}:{}", self.host, self.log_stream_port)
+    }
+}
+
+impl Default for JobSrvAddr {
+    fn default() -> Self {
+        JobSrvAddr {
+            host: IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+            log_stream_port: 5569,
+        }
+    }
+}
+
 #[derive(Debug, Default, Deserialize)]
 #[serde(default)]
 pub struct UiCfg {
@@ -124,8 +155,34 @@ pub struct UiCfg {
     pub root: Option<String>,
 }
 
+/// Limits how many requests a client can make. Each client address, or authenticated access token,
+/// gets a bucket of `burst` requests which refills at `per_minute` requests a minute.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct RateLimitCfg {
+    pub enabled: bool,
+    pub burst: u32,
+    pub per_minute: u32,
+    /// Whether to take the client address from the `X-Forwarded-For` header. Only enable this
+    /// behind a proxy which sets the header, as clients could pick their address otherwise.
+    pub trust_forwarded_for: bool,
+}
+
+impl Default for RateLimitCfg {
+    fn default() -> Self {
+        RateLimitCfg {
+            enabled: false,
+            burst: 120,
+            per_minute: 600,
+            trust_forwarded_for: false,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use http_gateway::oauth::ProviderKind;
+
     use super::*;
 
     #[test]
@@ -142,6 +199,12 @@ mod tests {
         [ui]
         root = "/some/path"
 
+        [rate_limit]
+        enabled = true
+        burst = 20
+        per_minute = 60
+        trust_forwarded_for = true
+
         [depot]
         path = "/hab/svc/hab-depot/data"
         events_enabled = true
@@ -159,10 +222,20 @@ mod tests {
         host = "172.18.0.2"
         port = 9632
 
+        [[jobsrvs]]
+        host = "172.18.0.3"
+        log_stream_port = 5569
+
         [github]
         url = "https://api.github.com"
         client_id = "0c2f738a7d0bd300de10"
         client_secret = "438223113eeb6e7edf2d2f91a232b72de72b9bdf"
+
+        [oauth]
+        provider = "gitlab"
+        url = "https://gitlab.example.com"
+        client_id = "a9f6c3e1d2"
+        client_secret = "e6d2c0b9a8"
         "#;
 
         let config = Config::from_raw(&content).unwrap();
@@ -172,13 +245,22 @@ mod tests {
         assert_eq!(config.http.port, 9636);
         assert_eq!(config.http.handler_count, 128);
         assert_eq!(&format!("{}", config.routers[0]), "172.18.0.2:9632");
+        assert_eq!(config.jobsrvs[0].log_stream_addr(), "tcp://172.18.0.3:5569");
         assert_eq!(config.github.url, "https://api.github.com");
         assert_eq!(config.github.client_id, "0c2f738a7d0bd300de10");
         assert_eq!(
             config.github.client_secret,
             "438223113eeb6e7edf2d2f91a232b72de72b9bdf"
         );
+        assert_eq!(config.oauth.provider, ProviderKind::GitLab);
+        assert_eq!(config.oauth.url, "https://gitlab.example.com");
+        assert_eq!(config.oauth.client_id, "a9f6c3e1d2");
+        assert_eq!(config.oauth.client_secret, "e6d2c0b9a8");
         assert_eq!(config.ui.root, Some("/some/path".to_string()));
+        assert_eq!(config.rate_limit.enabled, true);
+        assert_eq!(config.rate_limit.burst, 20);
+        assert_eq!(config.rate_limit.per_minute, 60);
+        assert_eq!(config.rate_limit.trust_forwarded_for, true);
         assert_eq!(config.segment.url, "https://api.segment.io");
     }
 
@@ -193,5 +275,7 @@ mod tests {
         assert_eq!(config.events_enabled, false);
         assert_eq!(config.non_core_builds_enabled, true);
         assert_eq!(config.http.port, 9000);
+        assert_eq!(config.oauth.provider, ProviderKind::GitHub);
+        assert_eq!(config.rate_limit.enabled, false);
     }
 }
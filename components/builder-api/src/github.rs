@@ -27,7 +27,7 @@ use openssl::pkey::PKey;
 use openssl::sign::Signer;
 use persistent;
 use protocol::originsrv::{OriginProject, OriginProjectGet};
-use protocol::jobsrv::{JobGroup, JobGroupSpec};
+use protocol::jobsrv::{JobGroup, JobGroupCreateError, JobGroupSpec};
 use router::Router;
 use serde_json;
 
@@ -237,8 +237,12 @@ fn build_plans(req: &mut Request, repo_url: &str, plans: Vec<Plan>) -> IronResul
         // the directory structure the plan is found in or metadata inside the plan. We will need
         // to have this done before we support building additional targets with Builder.
         request.set_target("x86_64-linux".to_string());
-        match route_message::<JobGroupSpec, JobGroup>(req, &request) {
-            Ok(group) => debug!("JobGroup created, {:?}", group),
+        match route_message_either::<JobGroupSpec, JobGroup, JobGroupCreateError>(
+            req,
+            &request,
+        ) {
+            Ok(Ok(group)) => debug!("JobGroup created, {:?}", group),
+            Ok(Err(err)) => warn!("Failed to create group, {}", err),
             Err(err) => debug!("Failed to create group, {:?}", err),
         }
     }
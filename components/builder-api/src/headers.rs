@@ -15,3 +15,5 @@
 header! { (XGitHubDelivery, "X-GitHub-Delivery") => [String] }
 header! { (XGitHubEvent, "X-GitHub-Event") => [String] }
 header! { (XHubSignature, "X-Hub-Signature") => [String] }
+
+header! { (RetryAfter, "Retry-After") => [u64] }
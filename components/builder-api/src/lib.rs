@@ -32,6 +32,8 @@ extern crate hex;
 extern crate hyper;
 extern crate iron;
 #[macro_use]
+extern crate juniper;
+#[macro_use]
 extern crate log;
 extern crate mount;
 extern crate openssl;
@@ -41,6 +43,7 @@ extern crate protobuf;
 #[macro_use]
 extern crate router;
 extern crate segment_api_client;
+extern crate serde;
 #[macro_use]
 extern crate serde_derive;
 #[macro_use]
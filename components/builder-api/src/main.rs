@@ -38,6 +38,10 @@ fn main() {
     env_logger::init().unwrap();
     let matches = app().get_matches();
     debug!("CLI matches: {:?}", matches);
+    if matches.subcommand_name() == Some("openapi") {
+        println!("{}", api::server::openapi());
+        return;
+    }
     let config = match config_from_args(&matches) {
         Ok(result) => result,
         Err(e) => return exit_with(e, 1),
@@ -62,6 +66,9 @@ fn app<'a, 'b>() -> clap::App<'a, 'b> {
                 "Filepath to store packages, keys, and other artifacts.")
             (@arg port: --port +takes_value "Listen port. [default: 9636]")
         )
+        (@subcommand openapi =>
+            (about: "Print the OpenAPI document describing the API")
+        )
     )
 }
 
@@ -0,0 +1,735 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GraphQL schema over origins, channels, packages, projects, jobs and job groups.
+//!
+//! Every field is resolved by routing the same messages as the REST endpoints, with the same
+//! access rules: private packages are only visible to members of their origin, and projects, jobs
+//! and job groups only to members of their project's origin. A query may resolve any number of
+//! fields, so the route connection of the request is moved into the query's context while it's
+//! executed, and whether the session has access to an origin is only checked once per query.
+
+use std::cell::RefCell;
+use std::cmp;
+use std::collections::HashMap;
+
+use bodyparser;
+use hab_net::conn::RouteClient;
+use hab_net::privilege::{self, FeatureFlags};
+use http_gateway::http::controller::*;
+use http_gateway::http::helpers;
+use iron::method::Method;
+use iron::status;
+use juniper::{self, EmptyMutation, FieldError, FieldResult, RootNode};
+use juniper::http::GraphQLRequest;
+use protobuf;
+use protocol::Routable;
+use protocol::jobsrv;
+use protocol::originsrv::{self, OriginPackageVisibility};
+use protocol::sessionsrv::Session;
+
+/// Number of entries in a page of a list
+const PAGE_SIZE: u64 = 50;
+
+pub type Schema = RootNode<'static, Query, EmptyMutation<Context>>;
+
+/// Executes a GraphQL query, given by the body of a POST request or by the `query` parameter of a
+/// GET request.
+pub fn graphql(req: &mut Request) -> IronResult<Response> {
+    let request = if req.method == Method::Get {
+        match helpers::extract_query_value("query", req) {
+            Some(query) => {
+                let operation_name = helpers::extract_query_value("operationName", req);
+                GraphQLRequest::new(query, operation_name, None)
+            }
+            None => return Ok(Response::with(status::BadRequest)),
+        }
+    } else {
+        match req.get::<bodyparser::Struct<GraphQLRequest>>() {
+            Ok(Some(request)) => request,
+            _ => return Ok(Response::with(status::UnprocessableEntity)),
+        }
+    };
+
+    let conn = req.extensions.remove::<XRouteClient>().expect(
+        "no XRouteClient extension in request",
+    );
+    let session = req.extensions.get::<Authenticated>().cloned();
+    let context = Context::new(conn, session);
+    let schema = Schema::new(Query, EmptyMutation::new());
+    let response = {
+        let response = request.execute(&schema, &context);
+        let status = if response.is_ok() {
+            status::Ok
+        } else {
+            status::BadRequest
+        };
+        render_json(status, &response)
+    };
+    req.extensions.insert::<XRouteClient>(context.into_conn());
+    Ok(response)
+}
+
+pub struct Context {
+    conn: RefCell<RouteClient>,
+    session: Option<Session>,
+    access: RefCell<HashMap<String, bool>>,
+}
+
+impl juniper::Context for Context {}
+
+impl Context {
+    fn new(conn: RouteClient, session: Option<Session>) -> Self {
+        Context {
+            conn: RefCell::new(conn),
+            session: session,
+            access: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn into_conn(self) -> RouteClient {
+        self.conn.into_inner()
+    }
+
+    fn route<M, R>(&self, msg: &M) -> NetResult<R>
+    where
+        M: Routable,
+        R: protobuf::MessageStatic,
+    {
+        self.conn.borrow_mut().route::<M, R>(msg)
+    }
+
+    /// Whether the session is a member of the origin, or a worker
+    fn has_origin_access(&self, origin: &str) -> FieldResult<bool> {
+        let session = match self.session {
+            Some(ref session) => session,
+            None => return Ok(false),
+        };
+        let flags = FeatureFlags::from_bits(session.get_flags()).unwrap_or(FeatureFlags::empty());
+        if flags.contains(privilege::BUILD_WORKER) {
+            return Ok(true);
+        }
+        if let Some(has_access) = self.access.borrow().get(origin) {
+            return Ok(*has_access);
+        }
+        let mut request = originsrv::CheckOriginAccessRequest::new();
+        request.set_account_id(session.get_id());
+        request.set_origin_name(origin.to_string());
+        let has_access = self.route::<_, originsrv::CheckOriginAccessResponse>(&request)?
+            .get_has_access();
+        self.access.borrow_mut().insert(origin.to_string(), has_access);
+        Ok(has_access)
+    }
+
+    fn check_origin_access(&self, origin: &str) -> FieldResult<()> {
+        if self.has_origin_access(origin)? {
+            Ok(())
+        } else {
+            Err(FieldError::from(format!("Access to origin {} denied", origin)))
+        }
+    }
+
+    /// Visibilities of the origin's packages the session may see
+    fn visibilities(&self, origin: &str) -> FieldResult<Vec<OriginPackageVisibility>> {
+        let mut visibilities = vec![OriginPackageVisibility::Public];
+        if self.has_origin_access(origin)? {
+            visibilities.push(OriginPackageVisibility::Private);
+        }
+        Ok(visibilities)
+    }
+
+    /// Gets a package, or the latest release of the package if `ident` isn't fully qualified. Only
+    /// the releases in `channel` are considered if there's one.
+    fn package(
+        &self,
+        ident: originsrv::OriginPackageIdent,
+        channel: Option<String>,
+        target: Option<String>,
+    ) -> FieldResult<Option<originsrv::OriginPackage>> {
+        let visibilities = self.visibilities(ident.get_origin())?;
+        let ident = if ident.fully_qualified() {
+            ident
+        } else {
+            let target = target.unwrap_or(jobsrv::DEFAULT_TARGET.to_string());
+            let latest = match channel {
+                Some(ref channel) => {
+                    let mut request = originsrv::OriginChannelPackageLatestGet::new();
+                    request.set_name(channel.clone());
+                    request.set_target(target);
+                    request.set_visibilities(visibilities.clone());
+                    request.set_ident(ident);
+                    found(self.route::<_, originsrv::OriginPackageIdent>(&request))?
+                }
+                None => {
+                    let mut request = originsrv::OriginPackageLatestGet::new();
+                    request.set_target(target);
+                    request.set_visibilities(visibilities.clone());
+                    request.set_ident(ident);
+                    found(self.route::<_, originsrv::OriginPackageIdent>(&request))?
+                }
+            };
+            match latest {
+                Some(ident) => ident,
+                None => return Ok(None),
+            }
+        };
+        match channel {
+            Some(channel) => {
+                let mut request = originsrv::OriginChannelPackageGet::new();
+                request.set_name(channel);
+                request.set_visibilities(visibilities);
+                request.set_ident(ident);
+                found(self.route(&request))
+            }
+            None => {
+                let mut request = originsrv::OriginPackageGet::new();
+                request.set_visibilities(visibilities);
+                request.set_ident(ident);
+                found(self.route(&request))
+            }
+        }
+    }
+
+    fn job(&self, id: u64) -> FieldResult<Option<jobsrv::Job>> {
+        let mut request = jobsrv::JobGet::new();
+        request.set_id(id);
+        let job = match found(self.route::<_, jobsrv::Job>(&request))? {
+            Some(job) => job,
+            None => return Ok(None),
+        };
+        self.check_origin_access(job.get_project().get_origin_name())?;
+        Ok(Some(job))
+    }
+}
+
+pub struct Query;
+
+graphql_object!(Query: Context |&self| {
+    description: "Builder's origins, packages and builds"
+
+    field origin(&executor, name: String) -> FieldResult<Option<Origin>>
+        as "An origin by its name"
+    {
+        let mut request = originsrv::OriginGet::new();
+        request.set_name(name);
+        Ok(found(executor.context().route(&request))?.map(Origin))
+    }
+
+    field package(
+        &executor,
+        ident: String,
+        channel: Option<String>,
+        target: Option<String>
+    ) -> FieldResult<Option<Package>>
+        as "A package by its identifier, or its latest release if the identifier isn't fully \
+            qualified, optionally in a channel"
+    {
+        let ident = parse_ident(&ident)?;
+        Ok(executor.context().package(ident, channel, target)?.map(Package))
+    }
+
+    field project(&executor, origin: String, name: String) -> FieldResult<Option<Project>>
+        as "A project by its origin and package name"
+    {
+        executor.context().check_origin_access(&origin)?;
+        let mut request = originsrv::OriginProjectGet::new();
+        request.set_name(format!("{}/{}", origin, name));
+        Ok(found(executor.context().route(&request))?.map(Project))
+    }
+
+    field job(&executor, id: String) -> FieldResult<Option<Job>> as "A job by its id" {
+        Ok(executor.context().job(parse_id(&id)?)?.map(Job))
+    }
+
+    field job_group(&executor, id: String) -> FieldResult<Option<JobGroup>>
+        as "A job group by its id"
+    {
+        let mut request = jobsrv::JobGroupGet::new();
+        request.set_group_id(parse_id(&id)?);
+        let group = match found(executor.context().route::<_, jobsrv::JobGroup>(&request))? {
+            Some(group) => group,
+            None => return Ok(None),
+        };
+        let origin = group.get_project_name().split('/').next().unwrap_or("").to_string();
+        executor.context().check_origin_access(&origin)?;
+        Ok(Some(JobGroup(group)))
+    }
+});
+
+pub struct Origin(originsrv::Origin);
+
+graphql_object!(Origin: Context |&self| {
+    description: "An origin packages are published in"
+
+    field name() -> &str {
+        self.0.get_name()
+    }
+
+    field owner_id() -> String {
+        self.0.get_owner_id().to_string()
+    }
+
+    field default_package_visibility() -> String {
+        self.0.get_default_package_visibility().to_string()
+    }
+
+    field channels(&executor, include_sandbox: Option<bool>) -> FieldResult<Vec<Channel>>
+        as "The origin's channels, including the sandbox channels of builds if asked for"
+    {
+        let mut request = originsrv::OriginChannelListRequest::new();
+        request.set_origin_id(self.0.get_id());
+        request.set_include_sandbox_channels(include_sandbox.unwrap_or(false));
+        let response = executor
+            .context()
+            .route::<_, originsrv::OriginChannelListResponse>(&request)?;
+        Ok(response
+            .get_channels()
+            .iter()
+            .map(|channel| {
+                Channel {
+                    origin: self.0.get_name().to_string(),
+                    name: channel.get_name().to_string(),
+                }
+            })
+            .collect())
+    }
+
+    field packages(&executor, start: Option<i32>) -> FieldResult<Vec<PackageIdent>>
+        as "A page of the names of the origin's packages"
+    {
+        let (start, stop) = page(start);
+        let mut request = originsrv::OriginPackageUniqueListRequest::new();
+        request.set_origin(self.0.get_name().to_string());
+        request.set_start(start);
+        request.set_stop(stop);
+        request.set_visibilities(executor.context().visibilities(self.0.get_name())?);
+        let response = executor
+            .context()
+            .route::<_, originsrv::OriginPackageUniqueListResponse>(&request)?;
+        Ok(response.get_idents().iter().cloned().map(PackageIdent).collect())
+    }
+
+    field projects(&executor) -> FieldResult<Vec<String>> as "Names of the origin's projects" {
+        executor.context().check_origin_access(self.0.get_name())?;
+        let mut request = originsrv::OriginProjectListGet::new();
+        request.set_origin(self.0.get_name().to_string());
+        let response = executor
+            .context()
+            .route::<_, originsrv::OriginProjectList>(&request)?;
+        Ok(response.get_names().to_vec())
+    }
+});
+
+pub struct Channel {
+    origin: String,
+    name: String,
+}
+
+graphql_object!(Channel: Context |&self| {
+    description: "A channel packages are promoted to"
+
+    field name() -> &str {
+        &self.name
+    }
+
+    field origin() -> &str {
+        &self.origin
+    }
+
+    field packages(&executor, name: Option<String>, start: Option<i32>)
+        -> FieldResult<Vec<PackageIdent>>
+        as "A page of the releases in the channel, optionally of one package"
+    {
+        let ident = match name {
+            Some(name) => format!("{}/{}", self.origin, name),
+            None => self.origin.clone(),
+        };
+        let (start, stop) = page(start);
+        let mut request = originsrv::OriginChannelPackageListRequest::new();
+        request.set_name(self.name.clone());
+        request.set_ident(parse_ident(&ident)?);
+        request.set_start(start);
+        request.set_stop(stop);
+        request.set_visibilities(executor.context().visibilities(&self.origin)?);
+        let response = executor
+            .context()
+            .route::<_, originsrv::OriginPackageListResponse>(&request)?;
+        Ok(response.get_idents().iter().cloned().map(PackageIdent).collect())
+    }
+});
+
+pub struct PackageIdent(originsrv::OriginPackageIdent);
+
+graphql_object!(PackageIdent: Context |&self| {
+    description: "The identifier of a package, which may not be fully qualified"
+
+    field origin() -> &str {
+        self.0.get_origin()
+    }
+
+    field name() -> &str {
+        self.0.get_name()
+    }
+
+    field version() -> Option<String> {
+        optional(self.0.get_version())
+    }
+
+    field release() -> Option<String> {
+        optional(self.0.get_release())
+    }
+
+    field ident() -> String as "The identifier as a string, such as core/redis/3.2.4/20170514001355"
+    {
+        self.0.to_string()
+    }
+
+    field package(&executor, channel: Option<String>, target: Option<String>)
+        -> FieldResult<Option<Package>>
+        as "The package, or its latest release if the identifier isn't fully qualified, \
+            optionally in a channel"
+    {
+        Ok(executor.context().package(self.0.clone(), channel, target)?.map(Package))
+    }
+});
+
+pub struct Package(originsrv::OriginPackage);
+
+graphql_object!(Package: Context |&self| {
+    description: "A release of a package"
+
+    field ident() -> PackageIdent {
+        PackageIdent(self.0.get_ident().clone())
+    }
+
+    field checksum() -> &str {
+        self.0.get_checksum()
+    }
+
+    field manifest() -> &str {
+        self.0.get_manifest()
+    }
+
+    field config() -> &str {
+        self.0.get_config()
+    }
+
+    field target() -> &str {
+        self.0.get_target()
+    }
+
+    field visibility() -> String {
+        self.0.get_visibility().to_string()
+    }
+
+    field exposes() -> Vec<i32> as "Ports the package's service exposes" {
+        self.0.get_exposes().iter().map(|port| *port as i32).collect()
+    }
+
+    field deps() -> Vec<PackageIdent> as "Runtime dependencies" {
+        self.0.get_deps().iter().cloned().map(PackageIdent).collect()
+    }
+
+    field tdeps() -> Vec<PackageIdent> as "Transitive runtime dependencies" {
+        self.0.get_tdeps().iter().cloned().map(PackageIdent).collect()
+    }
+
+    field channels(&executor) -> FieldResult<Vec<String>> as "Channels the release is in" {
+        let ident = self.0.get_ident();
+        let mut request = originsrv::OriginPackageChannelListRequest::new();
+        request.set_visibilities(executor.context().visibilities(ident.get_origin())?);
+        request.set_ident(ident.clone());
+        let response = executor
+            .context()
+            .route::<_, originsrv::OriginPackageChannelListResponse>(&request)?;
+        Ok(response
+            .get_channels()
+            .iter()
+            .map(|channel| channel.get_name().to_string())
+            .collect())
+    }
+});
+
+pub struct Project(originsrv::OriginProject);
+
+graphql_object!(Project: Context |&self| {
+    description: "A plan Builder builds packages from"
+
+    field name() -> &str {
+        self.0.get_name()
+    }
+
+    field plan_path() -> &str {
+        self.0.get_plan_path()
+    }
+
+    field vcs_data() -> &str as "URL of the repository the plan is cloned from" {
+        self.0.get_vcs_data()
+    }
+
+    field build_timeout() -> Option<i32> as "Minutes a build may run for, if not the default" {
+        if self.0.has_build_timeout() {
+            Some(self.0.get_build_timeout() as i32)
+        } else {
+            None
+        }
+    }
+
+    field worker_labels() -> Vec<String> {
+        self.0.get_worker_labels().to_vec()
+    }
+
+    field verify_reproducible() -> bool {
+        self.0.get_verify_reproducible()
+    }
+
+    field jobs(&executor, start: Option<i32>) -> FieldResult<Vec<Job>>
+        as "A page of the project's jobs, most recent first"
+    {
+        let (start, stop) = page(start);
+        let mut request = jobsrv::ProjectJobsGet::new();
+        request.set_name(self.0.get_name().to_string());
+        request.set_start(start);
+        request.set_stop(stop);
+        let response = executor
+            .context()
+            .route::<_, jobsrv::ProjectJobsGetResponse>(&request)?;
+        Ok(response.get_jobs().iter().cloned().map(Job).collect())
+    }
+});
+
+pub struct Job(jobsrv::Job);
+
+graphql_object!(Job: Context |&self| {
+    description: "A build of a project"
+
+    field id() -> String {
+        self.0.get_id().to_string()
+    }
+
+    field state() -> String {
+        self.0.get_state().to_string()
+    }
+
+    field project_name() -> &str {
+        self.0.get_project().get_name()
+    }
+
+    field ident() -> Option<PackageIdent> as "The identifier of what was built" {
+        if self.0.has_package_ident() {
+            Some(PackageIdent(self.0.get_package_ident().clone()))
+        } else {
+            None
+        }
+    }
+
+    field package(&executor) -> FieldResult<Option<Package>> as "The package that was built" {
+        if !self.0.get_package_ident().fully_qualified() {
+            return Ok(None);
+        }
+        let ident = self.0.get_package_ident().clone();
+        Ok(executor.context().package(ident, None, None)?.map(Package))
+    }
+
+    field channel() -> Option<String> {
+        optional(self.0.get_channel())
+    }
+
+    field target() -> Option<String> {
+        optional(self.0.get_target())
+    }
+
+    field created_at() -> &str {
+        self.0.get_created_at()
+    }
+
+    field build_started_at() -> Option<String> {
+        optional(self.0.get_build_started_at())
+    }
+
+    field build_finished_at() -> Option<String> {
+        optional(self.0.get_build_finished_at())
+    }
+
+    field error_message() -> Option<String> {
+        if self.0.has_error() {
+            Some(self.0.get_error().get_message().to_string())
+        } else {
+            None
+        }
+    }
+
+    field error_category() -> Option<String> {
+        if self.0.has_error() && self.0.get_error().has_category() {
+            Some(self.0.get_error().get_category().to_string())
+        } else {
+            None
+        }
+    }
+
+    field retries() -> i32 {
+        self.0.get_retries() as i32
+    }
+
+    field tags() -> Vec<String> {
+        self.0.get_tags().to_vec()
+    }
+
+    field artifact_checksum() -> Option<String> as "blake2b digest of the built artifact" {
+        if self.0.has_artifact() {
+            Some(self.0.get_artifact().get_checksum().to_string())
+        } else {
+            None
+        }
+    }
+
+    field reproducible() -> Option<bool>
+        as "Whether a second build had the same contents, for projects verifying it"
+    {
+        if self.0.has_reproducible() {
+            Some(self.0.get_reproducible())
+        } else {
+            None
+        }
+    }
+});
+
+pub struct JobGroup(jobsrv::JobGroup);
+
+graphql_object!(JobGroup: Context |&self| {
+    description: "The builds of a project and of the projects depending on it"
+
+    field id() -> String {
+        self.0.get_id().to_string()
+    }
+
+    field state() -> String {
+        self.0.get_state().to_string()
+    }
+
+    field project_name() -> &str {
+        self.0.get_project_name()
+    }
+
+    field created_at() -> &str {
+        self.0.get_created_at()
+    }
+
+    field target() -> Option<String> {
+        optional(self.0.get_target())
+    }
+
+    field tags() -> Vec<String> {
+        self.0.get_tags().to_vec()
+    }
+
+    field queue_position() -> Option<i32> as "Position of a queued group in the queue" {
+        match self.0.get_queue_position() {
+            0 => None,
+            position => Some(position as i32),
+        }
+    }
+
+    field projects() -> Vec<JobGroupProject> {
+        self.0.get_projects().iter().cloned().map(JobGroupProject).collect()
+    }
+});
+
+pub struct JobGroupProject(jobsrv::JobGroupProject);
+
+graphql_object!(JobGroupProject: Context |&self| {
+    description: "The build of a project in a job group"
+
+    field name() -> &str {
+        self.0.get_name()
+    }
+
+    field ident() -> Option<String> {
+        optional(self.0.get_ident())
+    }
+
+    field state() -> String {
+        self.0.get_state().to_string()
+    }
+
+    field target() -> Option<String> {
+        optional(self.0.get_target())
+    }
+
+    field skip_reason() -> Option<String> {
+        optional(self.0.get_skip_reason())
+    }
+
+    field job(&executor) -> FieldResult<Option<Job>> as "The project's job, once it's started" {
+        match self.0.get_job_id() {
+            0 => Ok(None),
+            id => Ok(executor.context().job(id)?.map(Job)),
+        }
+    }
+});
+
+/// Turns entities which weren't found into `None`
+fn found<T>(result: NetResult<T>) -> FieldResult<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(ref err) if err.code() == ErrCode::ENTITY_NOT_FOUND => Ok(None),
+        Err(err) => Err(FieldError::from(err)),
+    }
+}
+
+/// The first and last index of the page of a list starting at `start`
+fn page(start: Option<i32>) -> (u64, u64) {
+    let start = cmp::max(start.unwrap_or(0), 0) as u64;
+    (start, start + PAGE_SIZE - 1)
+}
+
+fn optional(value: &str) -> Option<String> {
+    if value.is_empty() {
+        None
+    } else {
+        Some(value.to_string())
+    }
+}
+
+fn parse_id(id: &str) -> FieldResult<u64> {
+    id.parse::<u64>().map_err(|_| {
+        FieldError::from(format!("Invalid id {}", id))
+    })
+}
+
+fn parse_ident(ident: &str) -> FieldResult<originsrv::OriginPackageIdent> {
+    ident.parse::<originsrv::OriginPackageIdent>().map_err(|_| {
+        FieldError::from(format!("Invalid package identifier {}", ident))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pages() {
+        assert_eq!(page(None), (0, 49));
+        assert_eq!(page(Some(50)), (50, 99));
+        assert_eq!(page(Some(-1)), (0, 49));
+    }
+
+    #[test]
+    fn schema_fields_are_camel_cased() {
+        let schema = Schema::new(Query, EmptyMutation::new());
+        let query = schema.concrete_query_type();
+        assert!(query.field_by_name("jobGroup").is_some());
+        assert!(query.field_by_name("job_group").is_none());
+    }
+}
@@ -14,26 +14,33 @@
 
 //! A collection of handlers for the HTTP server's router
 
+use std::collections::HashMap;
 use std::env;
 
 use bodyparser;
 use bldr_core::helpers::transition_visibility;
-use github_api_client::HubError;
 use hab_core::package::{Identifiable, Plan};
 use hab_core::event::*;
 use http_client::ApiClient;
 use http_gateway::http::controller::*;
 use http_gateway::http::helpers::{self, check_origin_access, get_param, validate_params};
+use http_gateway::oauth::OAuthError;
 use hyper::header::{Accept, ContentType};
 use hyper::status::StatusCode;
 use iron::status;
 use params::{FromValue, Params};
 use persistent;
-use protocol::jobsrv::{Job, JobGet, JobLogGet, JobLog, JobState, ProjectJobsGet,
-                       ProjectJobsGetResponse, JobGroupCancel, JobGroupGet, JobGroup};
+use protobuf::RepeatedField;
+use protocol::ansi::AnsiFilter;
+use protocol::jobsrv::{Job, JobAuditGet, JobAuditResponse, JobCancel, JobGet, JobLogGet,
+                       JobLog, JobState, ProjectJobsGet, ProjectJobsGetResponse,
+                       JobGroupCancel, JobGroupGet, JobGroupRetry, JobGroup, JobsByTagGet,
+                       JobsByTagGetResponse, WorkerListGet, WorkerListResponse};
 use protocol::jobsrv::{JobGraphPackageReverseDependenciesGet, JobGraphPackageReverseDependencies};
 use protocol::originsrv::*;
-use protocol::sessionsrv::{Account, AccountGetId, AccountInvitationListRequest,
+use protocol::sessionsrv::{AccessToken, AccessTokenCreate, AccessTokenListRequest,
+                           AccessTokenListResponse, AccessTokenRevoke, AccessTokenScope, Account,
+                           AccountGetId, AccountInvitationListRequest,
                            AccountInvitationListResponse, AccountOriginListRequest,
                            AccountOriginListResponse, AccountUpdate};
 use serde_json;
@@ -44,6 +51,7 @@ use github;
 use headers::*;
 use types::*;
 use super::SegmentCli;
+use super::log_stream;
 
 // A default name for per-project integrations. Currently, there
 // can only be one.
@@ -54,7 +62,7 @@ const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"))
 
 define_event_log!();
 
-pub fn github_authenticate(req: &mut Request) -> IronResult<Response> {
+pub fn oauth_authenticate(req: &mut Request) -> IronResult<Response> {
     let code = match get_param(req, "code") {
         Some(c) => c,
         None => return Ok(Response::with(status::BadRequest)),
@@ -67,13 +75,13 @@ pub fn github_authenticate(req: &mut Request) -> IronResult<Response> {
         return Ok(render_json(status::Ok, &session));
     }
 
-    let github = req.get::<persistent::Read<GitHubCli>>().unwrap();
+    let oauth = req.get::<persistent::Read<OAuthCli>>().unwrap();
     let segment = req.get::<persistent::Read<SegmentCli>>().unwrap();
 
-    match github.authenticate(&code) {
+    match oauth.authenticate(&code) {
         Ok(token) => {
             let session = {
-                session_create_github(req, token)?
+                session_create_oauth(req, token)?
             };
             log_event!(
                 req,
@@ -94,17 +102,17 @@ pub fn github_authenticate(req: &mut Request) -> IronResult<Response> {
 
             Ok(render_json(status::Ok, &session))
         }
-        Err(HubError::Auth(e)) => {
-            let err = NetError::new(ErrCode::ACCESS_DENIED, e.error);
+        Err(OAuthError::Auth(e)) => {
+            let err = NetError::new(ErrCode::ACCESS_DENIED, e);
             Ok(render_net_error(&err))
         }
-        Err(HubError::Serialization(e)) => {
-            warn!("bad reply from GitHub, {}", e);
+        Err(OAuthError::Serialization(e)) => {
+            warn!("bad reply from OAuth provider, {}", e);
             let err = NetError::new(ErrCode::BAD_REMOTE_REPLY, "rg:auth:1");
             Ok(render_net_error(&err))
         }
         Err(e) => {
-            warn!("unhandled github authentication error, {:?}", e);
+            warn!("unhandled OAuth authentication error, {:?}", e);
             let err = NetError::new(ErrCode::BUG, "rg:auth:2");
             Ok(render_net_error(&err))
         }
@@ -156,6 +164,85 @@ pub fn get_profile(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+pub fn access_token_create(req: &mut Request) -> IronResult<Response> {
+    let session_id = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        session.get_id()
+    };
+
+    let body = match req.get::<bodyparser::Struct<AccessTokenCreateReq>>() {
+        Ok(Some(body)) => body,
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+    if body.name.len() <= 0 {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            "Missing value for field: `name`",
+        )));
+    }
+    if body.scopes.is_empty() {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            "Missing value for field: `scopes`",
+        )));
+    }
+    let mut scopes = Vec::new();
+    for scope in body.scopes.iter() {
+        match scope.parse::<AccessTokenScope>() {
+            Ok(scope) => {
+                if !scopes.contains(&scope) {
+                    scopes.push(scope)
+                }
+            }
+            Err(_) => {
+                return Ok(Response::with((
+                    status::UnprocessableEntity,
+                    format!("Unknown scope: `{}`", scope),
+                )))
+            }
+        }
+    }
+
+    let mut request = AccessTokenCreate::new();
+    request.set_account_id(session_id);
+    request.set_name(body.name);
+    request.set_scopes(scopes);
+
+    match route_message::<AccessTokenCreate, AccessToken>(req, &request) {
+        Ok(token) => Ok(render_json(status::Created, &token)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn access_token_list(req: &mut Request) -> IronResult<Response> {
+    let mut request = AccessTokenListRequest::new();
+    {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        request.set_account_id(session.get_id());
+    }
+    match route_message::<AccessTokenListRequest, AccessTokenListResponse>(req, &request) {
+        Ok(tokens) => Ok(render_json(status::Ok, &tokens)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn access_token_revoke(req: &mut Request) -> IronResult<Response> {
+    let id = match get_param(req, "id").and_then(|id| id.parse::<u64>().ok()) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let mut request = AccessTokenRevoke::new();
+    request.set_id(id);
+    {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        request.set_account_id(session.get_id());
+    }
+    match route_message::<AccessTokenRevoke, NetOk>(req, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 pub fn job_group_promote(req: &mut Request) -> IronResult<Response> {
     job_group_promote_or_demote(req, true)
 
@@ -210,6 +297,33 @@ fn job_group_promote_or_demote(req: &mut Request, promote: bool) -> IronResult<R
     }
 }
 
+/// Promotes every package built by a completed job group into a channel, and reports which
+/// projects were promoted and which were skipped.
+pub fn job_group_promote_all(req: &mut Request) -> IronResult<Response> {
+    let group_id = match get_param(req, "id") {
+        Some(id) => {
+            match id.parse::<u64>() {
+                Ok(g) => g,
+                Err(e) => {
+                    debug!("Error finding group. e = {:?}", e);
+                    return Ok(Response::with(status::BadRequest));
+                }
+            }
+        }
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let channel = match get_param(req, "channel") {
+        Some(c) => c,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    match helpers::promote_job_group(req, group_id, &channel) {
+        Ok(promotion) => Ok(render_json(status::Ok, &promotion)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 pub fn job_group_cancel(req: &mut Request) -> IronResult<Response> {
     let group_id = match get_param(req, "id") {
         Some(id) => {
@@ -241,6 +355,11 @@ pub fn job_group_cancel(req: &mut Request) -> IronResult<Response> {
 
     let mut jgc = JobGroupCancel::new();
     jgc.set_group_id(group_id);
+    {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        jgc.set_requester_id(session.get_id());
+        jgc.set_requester_name(session.get_name().to_string());
+    }
 
     match route_message::<JobGroupCancel, NetOk>(req, &jgc) {
         Ok(_) => Ok(Response::with(status::NoContent)),
@@ -248,6 +367,262 @@ pub fn job_group_cancel(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+pub fn job_group_retry(req: &mut Request) -> IronResult<Response> {
+    let group_id = match get_param(req, "id") {
+        Some(id) => {
+            match id.parse::<u64>() {
+                Ok(g) => g,
+                Err(e) => {
+                    debug!("Error finding group. e = {:?}", e);
+                    return Ok(Response::with(status::BadRequest));
+                }
+            }
+        }
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut jgg = JobGroupGet::new();
+    jgg.set_group_id(group_id);
+
+    let group = match route_message::<JobGroupGet, JobGroup>(req, &jgg) {
+        Ok(group) => group,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    let name_split: Vec<&str> = group.get_project_name().split("/").collect();
+    assert!(name_split.len() == 2);
+
+    if !check_origin_access(req, &name_split[0]).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut jgr = JobGroupRetry::new();
+    jgr.set_group_id(group_id);
+    {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        jgr.set_requester_id(session.get_id());
+        jgr.set_requester_name(session.get_name().to_string());
+    }
+
+    match route_message::<JobGroupRetry, JobGroup>(req, &jgr) {
+        Ok(group) => Ok(render_json(status::Ok, &group)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn job_cancel(req: &mut Request) -> IronResult<Response> {
+    let job_id = match get_param(req, "id") {
+        Some(id) => {
+            match id.parse::<u64>() {
+                Ok(i) => i,
+                Err(e) => {
+                    debug!("Error finding id. e = {:?}", e);
+                    return Ok(Response::with(status::BadRequest));
+                }
+            }
+        }
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut request = JobGet::new();
+    request.set_id(job_id);
+
+    let job = match route_message::<JobGet, Job>(req, &request) {
+        Ok(job) => job,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    if !check_origin_access(req, job.get_project().get_origin_name()).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut jc = JobCancel::new();
+    jc.set_job_id(job_id);
+    {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        jc.set_requester_id(session.get_id());
+        jc.set_requester_name(session.get_name().to_string());
+    }
+
+    match route_message::<JobCancel, Job>(req, &jc) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Lists the jobs with the `tag` of the query, leaving out the ones of origins the user can't
+/// access.
+pub fn jobs_by_tag(req: &mut Request) -> IronResult<Response> {
+    let tag = match helpers::extract_query_value("tag", req) {
+        Some(tag) => tag,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let page = match helpers::extract_page(req) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = JobsByTagGet::new();
+    request.set_tag(tag);
+    page.apply(&mut request);
+    match route_message::<JobsByTagGet, JobsByTagGetResponse>(req, &request) {
+        Ok(response) => {
+            let mut access = HashMap::new();
+            let jobs: Vec<&Job> = response
+                .get_jobs()
+                .iter()
+                .filter(|job| has_job_access(req, &mut access, job))
+                .collect();
+            helpers::paginated_response(
+                &jobs,
+                response.get_count() as isize,
+                response.get_start() as isize,
+                response.get_stop() as isize,
+                &page,
+            )
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Cancels every job with the `tag` of the query which hasn't finished yet, except for the ones
+/// of origins the user can't access. Responds with the IDs of the jobs which were canceled.
+pub fn jobs_by_tag_cancel(req: &mut Request) -> IronResult<Response> {
+    let tag = match helpers::extract_query_value("tag", req) {
+        Some(tag) => tag,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let mut page = match helpers::extract_page(req) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
+    let (requester_id, requester_name) = {
+        let session = req.extensions.get::<Authenticated>().unwrap();
+        (session.get_id(), session.get_name().to_string())
+    };
+
+    let mut request = JobsByTagGet::new();
+    request.set_tag(tag);
+    let mut access = HashMap::new();
+    let mut canceled = Vec::new();
+    loop {
+        page.apply(&mut request);
+        let response = match route_message::<JobsByTagGet, JobsByTagGetResponse>(req, &request) {
+            Ok(response) => response,
+            Err(err) => return Ok(render_net_error(&err)),
+        };
+        for job in response.get_jobs() {
+            match job.get_state() {
+                JobState::Pending | JobState::Dispatched | JobState::Processing => (),
+                _ => continue,
+            }
+            if !has_job_access(req, &mut access, job) {
+                continue;
+            }
+            let mut jc = JobCancel::new();
+            jc.set_job_id(job.get_id());
+            jc.set_requester_id(requester_id);
+            jc.set_requester_name(requester_name.clone());
+            // The job may have finished since it was listed
+            match route_message::<JobCancel, Job>(req, &jc) {
+                Ok(_) => canceled.push(job.get_id().to_string()),
+                Err(err) => warn!("Unable to cancel job {}, err={}", job.get_id(), err),
+            }
+        }
+        if response.get_jobs().is_empty() || response.get_stop() + 1 >= response.get_count() {
+            break;
+        }
+        let limit = page.stop - page.start + 1;
+        page.start = response.get_stop() + 1;
+        page.stop = page.start + limit - 1;
+    }
+    Ok(render_json(status::Ok, &json!({ "canceled": canceled })))
+}
+
+/// Whether the user can access the origin of a job, remembering the answer for each origin
+fn has_job_access(req: &mut Request, access: &mut HashMap<String, bool>, job: &Job) -> bool {
+    let origin = job.get_project().get_origin_name();
+    if let Some(allowed) = access.get(origin) {
+        return *allowed;
+    }
+    let allowed = check_origin_access(req, origin).unwrap_or(false);
+    access.insert(origin.to_string(), allowed);
+    allowed
+}
+
+pub fn job_audit(req: &mut Request) -> IronResult<Response> {
+    let job_id = match get_param(req, "id") {
+        Some(id) => {
+            match id.parse::<u64>() {
+                Ok(i) => i,
+                Err(e) => {
+                    debug!("Error finding id. e = {:?}", e);
+                    return Ok(Response::with(status::BadRequest));
+                }
+            }
+        }
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut request = JobGet::new();
+    request.set_id(job_id);
+
+    let job = match route_message::<JobGet, Job>(req, &request) {
+        Ok(job) => job,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    if !check_origin_access(req, job.get_project().get_origin_name()).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut jag = JobAuditGet::new();
+    jag.set_job_id(job_id);
+
+    match route_message::<JobAuditGet, JobAuditResponse>(req, &jag) {
+        Ok(audits) => Ok(render_json(status::Ok, &audits)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn job_group_audit(req: &mut Request) -> IronResult<Response> {
+    let group_id = match get_param(req, "id") {
+        Some(id) => {
+            match id.parse::<u64>() {
+                Ok(g) => g,
+                Err(e) => {
+                    debug!("Error finding group. e = {:?}", e);
+                    return Ok(Response::with(status::BadRequest));
+                }
+            }
+        }
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut jgg = JobGroupGet::new();
+    jgg.set_group_id(group_id);
+
+    let group = match route_message::<JobGroupGet, JobGroup>(req, &jgg) {
+        Ok(group) => group,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    let name_split: Vec<&str> = group.get_project_name().split("/").collect();
+    assert!(name_split.len() == 2);
+
+    if !check_origin_access(req, &name_split[0]).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut jag = JobAuditGet::new();
+    jag.set_group_id(group_id);
+
+    match route_message::<JobAuditGet, JobAuditResponse>(req, &jag) {
+        Ok(audits) => Ok(render_json(status::Ok, &audits)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 pub fn validate_registry_credentials(req: &mut Request) -> IronResult<Response> {
     let json_body = req.get::<bodyparser::Json>();
 
@@ -431,6 +806,13 @@ pub fn job_show(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+pub fn worker_list(req: &mut Request) -> IronResult<Response> {
+    match route_message::<WorkerListGet, WorkerListResponse>(req, &WorkerListGet::new()) {
+        Ok(workers) => Ok(render_json(status::Ok, &workers)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 pub fn job_log(req: &mut Request) -> IronResult<Response> {
     let start = match get_param(req, "start") {
         Some(start) => {
@@ -451,6 +833,12 @@ pub fn job_log(req: &mut Request) -> IronResult<Response> {
         .and_then(FromValue::from_value)
         .unwrap_or(false);
 
+    let follow = req.get_ref::<Params>()
+        .unwrap()
+        .find(&["follow"])
+        .and_then(FromValue::from_value)
+        .unwrap_or(false);
+
     let mut job_get = JobGet::new();
     let mut request = JobLogGet::new();
     request.set_start(start);
@@ -482,10 +870,15 @@ pub fn job_log(req: &mut Request) -> IronResult<Response> {
                     return Ok(Response::with(status::Forbidden));
                 }
             }
-            match route_message::<JobLogGet, JobLog>(req, &request) {
+            let log = if follow {
+                log_stream::follow_job_log(req, &job, start)
+            } else {
+                route_message::<JobLogGet, JobLog>(req, &request)
+            };
+            match log {
                 Ok(mut log) => {
                     if !include_color {
-                        log.strip_ansi();
+                        log.strip_ansi(&mut AnsiFilter::new());
                     }
                     Ok(render_json(status::Ok, &log))
                 }
@@ -525,14 +918,84 @@ pub fn list_account_invitations(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Accepts an invitation of the authenticated user, which makes them a member of its origin.
+pub fn accept_account_invitation(req: &mut Request) -> IronResult<Response> {
+    let invitation = match account_invitation(req) {
+        Ok(invitation) => invitation,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginInvitationAcceptRequest::new();
+    request.set_account_id(invitation.get_account_id());
+    request.set_invite_id(invitation.get_id());
+    request.set_origin_name(invitation.get_origin_name().to_string());
+    request.set_ignore(false);
+    match route_message::<OriginInvitationAcceptRequest, NetOk>(req, &request) {
+        Ok(_) => {
+            log_event!(
+                req,
+                Event::OriginInvitationAccept {
+                    id: request.get_invite_id().to_string(),
+                    account: request.get_account_id().to_string(),
+                }
+            );
+            Ok(Response::with(status::NoContent))
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Declines an invitation of the authenticated user. The origin's owner can't send another one
+/// until it's rescinded.
+pub fn decline_account_invitation(req: &mut Request) -> IronResult<Response> {
+    let invitation = match account_invitation(req) {
+        Ok(invitation) => invitation,
+        Err(response) => return Ok(response),
+    };
+    let mut request = OriginInvitationIgnoreRequest::new();
+    request.set_account_id(invitation.get_account_id());
+    request.set_invitation_id(invitation.get_id());
+    match route_message::<OriginInvitationIgnoreRequest, NetOk>(req, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Looks up the invitation in the request's path. Invitations of other users are reported as not
+/// found.
+fn account_invitation(req: &mut Request) -> Result<OriginInvitation, Response> {
+    let invitation_id = match get_param(req, "invitation_id").map(|id| id.parse::<u64>()) {
+        Some(Ok(id)) => id,
+        _ => return Err(Response::with(status::BadRequest)),
+    };
+    let account_id = req.extensions.get::<Authenticated>().unwrap().get_id();
+    let mut request = OriginInvitationGet::new();
+    request.set_invitation_id(invitation_id);
+    match route_message::<OriginInvitationGet, OriginInvitation>(req, &request) {
+        Ok(ref invitation) if invitation.get_account_id() != account_id => {
+            Err(Response::with(status::NotFound))
+        }
+        Ok(invitation) => Ok(invitation),
+        Err(err) => Err(render_net_error(&err)),
+    }
+}
+
 pub fn list_user_origins(req: &mut Request) -> IronResult<Response> {
     let mut request = AccountOriginListRequest::new();
     {
         let session = req.extensions.get::<Authenticated>().unwrap();
         request.set_account_id(session.get_id());
     }
+    let page = match helpers::extract_optional_page(req) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
     match route_message::<AccountOriginListRequest, AccountOriginListResponse>(req, &request) {
-        Ok(invites) => Ok(render_json(status::Ok, &invites)),
+        Ok(invites) => {
+            match page {
+                Some(page) => helpers::paginated_list_response(invites.get_origins(), &page),
+                None => Ok(render_json(status::Ok, &invites)),
+            }
+        }
         Err(err) => Ok(render_net_error(&err)),
     }
 }
@@ -577,6 +1040,15 @@ pub fn project_create(req: &mut Request) -> IronResult<Response> {
             project.set_plan_path(body.plan_path);
             project.set_vcs_type(String::from("git"));
             project.set_vcs_installation_id(body.installation_id);
+            if let Some(build_timeout) = body.build_timeout {
+                project.set_build_timeout(build_timeout);
+            }
+            if let Some(worker_labels) = body.worker_labels {
+                project.set_worker_labels(RepeatedField::from_vec(worker_labels));
+            }
+            if let Some(verify_reproducible) = body.verify_reproducible {
+                project.set_verify_reproducible(verify_reproducible);
+            }
 
             match github.repo(&token, body.repo_id) {
                 Ok(Some(repo)) => project.set_vcs_data(repo.clone_url),
@@ -726,6 +1198,15 @@ pub fn project_update(req: &mut Request) -> IronResult<Response> {
 
             project.set_plan_path(body.plan_path);
             project.set_vcs_installation_id(body.installation_id);
+            if let Some(build_timeout) = body.build_timeout {
+                project.set_build_timeout(build_timeout);
+            }
+            if let Some(worker_labels) = body.worker_labels {
+                project.set_worker_labels(RepeatedField::from_vec(worker_labels));
+            }
+            if let Some(verify_reproducible) = body.verify_reproducible {
+                project.set_verify_reproducible(verify_reproducible);
+            }
             match github.repo(&token, body.repo_id) {
                 Ok(Some(repo)) => project.set_vcs_data(repo.clone_url),
                 Ok(None) => return Ok(Response::with((status::NotFound, "rg:pu:2"))),
@@ -850,13 +1331,11 @@ pub fn project_jobs(req: &mut Request) -> IronResult<Response> {
         return Ok(Response::with(status::Forbidden));
     }
 
-    match helpers::extract_pagination(req) {
-        Ok((start, stop)) => {
-            jobs_get.set_start(start as u64);
-            jobs_get.set_stop(stop as u64);
-        }
+    let page = match helpers::extract_page(req) {
+        Ok(page) => page,
         Err(response) => return Ok(response),
-    }
+    };
+    page.apply(&mut jobs_get);
     match route_message::<ProjectJobsGet, ProjectJobsGetResponse>(req, &jobs_get) {
         Ok(response) => {
             let list: Vec<serde_json::Value> = response
@@ -888,6 +1367,7 @@ pub fn project_jobs(req: &mut Request) -> IronResult<Response> {
                 response.get_count() as isize,
                 response.get_start() as isize,
                 response.get_stop() as isize,
+                &page,
             )
         }
         Err(err) => Ok(render_net_error(&err)),
@@ -0,0 +1,87 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reports whether builder-api can reach the services it routes requests to, at `/health/ready`
+//! and `/health/deps`.
+//!
+//! Each service is probed through the routers with a lookup of an entity which can't exist, so
+//! any reply, including a not found error, shows that the routers and the service are up. Errors
+//! raised on the way to the service, or by a service which lost its datastore, show that they
+//! aren't.
+
+use hab_net::app::health::{DepHealth, HealthReport};
+use http_gateway::http::controller::*;
+use http_gateway::http::helpers::dont_cache_response;
+use protobuf;
+use protocol::Routable;
+use protocol::jobsrv::{Job, JobGet};
+use protocol::originsrv::{Origin, OriginGet};
+use protocol::sessionsrv::{Account, AccountGet};
+
+/// Answers whether every service builder-api depends on is reachable.
+pub fn ready(req: &mut Request) -> IronResult<Response> {
+    let report = check(req);
+    let status = if report.ready {
+        status::Ok
+    } else {
+        status::ServiceUnavailable
+    };
+    let mut response = render_json(status, &json!({ "ready": report.ready }));
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+/// Reports whether each service builder-api depends on is reachable.
+pub fn deps(req: &mut Request) -> IronResult<Response> {
+    let report = check(req);
+    let mut response = render_json(status::Ok, &report);
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+fn check(req: &mut Request) -> HealthReport {
+    let deps = vec![
+        probe::<OriginGet, Origin>(req, "originsrv", &OriginGet::new()),
+        probe::<AccountGet, Account>(req, "sessionsrv", &AccountGet::new()),
+        probe::<JobGet, Job>(req, "jobsrv", &JobGet::new()),
+    ];
+    HealthReport {
+        ready: deps.iter().all(|dep| dep.healthy),
+        deps: deps,
+    }
+}
+
+fn probe<M, R>(req: &mut Request, name: &str, msg: &M) -> DepHealth
+where
+    M: Routable,
+    R: protobuf::MessageStatic,
+{
+    let error = match route_message::<M, R>(req, msg) {
+        Ok(_) => None,
+        Err(err) => {
+            match err.code() {
+                ErrCode::BUG | ErrCode::TIMEOUT | ErrCode::NO_SHARD | ErrCode::SOCK |
+                ErrCode::DATA_STORE | ErrCode::REMOTE_UNAVAILABLE | ErrCode::SYS => {
+                    Some(err.to_string())
+                }
+                _ => None,
+            }
+        }
+    };
+    DepHealth {
+        name: name.to_string(),
+        healthy: error.is_none(),
+        error: error,
+    }
+}
@@ -0,0 +1,342 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streams the log of a job to the client as server-sent events.
+//!
+//! The stream subscribes to the job's topic on the log streams of the job servers first, and then
+//! reads the log up to where it is, so that no output published in between is missed. New output
+//! is sent as it is published. Whenever published output doesn't pick up where the client is, as
+//! when it was published before the subscription took effect, the log is read again from there
+//! instead. Every reply with new lines is sent as a `log` event, whose id is the line the next
+//! event starts at, so that a client reconnecting with a `Last-Event-ID` header picks up where it
+//! left off. A comment is sent whenever nothing was published for a while, which keeps proxies
+//! from closing the connection and notices clients that went away. The stream ends with a
+//! `complete` event once the job is done and its whole log was sent, or with an `error` event if
+//! the log can't be read.
+
+use std::io::{self, Write};
+
+use hyper::mime::{Mime, SubLevel, TopLevel};
+use hab_net::conn::RouteClient;
+use hab_net::socket::DEFAULT_CONTEXT;
+use http_gateway::http::controller::*;
+use http_gateway::http::helpers::{check_origin_access, dont_cache_response, get_param};
+use iron::response::WriteBody;
+use iron::status;
+use params::{FromValue, Params};
+use persistent;
+use protobuf::parse_from_bytes;
+use protocol::ansi::AnsiFilter;
+use protocol::jobsrv::{log_stream_topic, Job, JobGet, JobLog, JobLogSubscribe, JobState};
+use protocol::originsrv::OriginPackageVisibility;
+use serde_json;
+use zmq;
+
+use config::{Config, JobSrvAddr};
+
+/// Max time to wait for new output before checking on the job and reading its log again
+const FOLLOW_WAIT_MS: i64 = 10_000;
+
+/// Follows the new output of a job's log, as published on the log streams of the job servers.
+/// Output published before the follower is created isn't received, so the log has to be read up
+/// to where it is once the follower exists.
+pub struct LogFollower {
+    sock: zmq::Socket,
+}
+
+impl LogFollower {
+    pub fn new(jobsrvs: &[JobSrvAddr], job_id: u64) -> NetResult<Self> {
+        let sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::SUB).map_err(|e| {
+            NetError::new(ErrCode::SOCK, format!("api:log-follower:0, {}", e))
+        })?;
+        for jobsrv in jobsrvs {
+            sock.connect(&jobsrv.log_stream_addr()).map_err(|e| {
+                NetError::new(ErrCode::SOCK, format!("api:log-follower:1, {}", e))
+            })?;
+        }
+        sock.set_subscribe(log_stream_topic(job_id).as_bytes())
+            .map_err(|e| {
+                NetError::new(ErrCode::SOCK, format!("api:log-follower:2, {}", e))
+            })?;
+        Ok(LogFollower { sock: sock })
+    }
+
+    /// Waits up to `timeout_ms` for output to be published, returning `None` if there was none.
+    pub fn next(&self, timeout_ms: i64) -> NetResult<Option<JobLog>> {
+        let ready = zmq::poll(&mut [self.sock.as_poll_item(zmq::POLLIN)], timeout_ms)
+            .map_err(|e| {
+                NetError::new(ErrCode::SOCK, format!("api:log-follower:3, {}", e))
+            })?;
+        if ready == 0 {
+            return Ok(None);
+        }
+        // The first frame is the job's topic
+        let body = self.sock
+            .recv_bytes(0)
+            .and_then(|_| self.sock.recv_bytes(0))
+            .map_err(|e| {
+                NetError::new(ErrCode::SOCK, format!("api:log-follower:4, {}", e))
+            })?;
+        match parse_from_bytes::<JobLog>(&body) {
+            Ok(log) => Ok(Some(log)),
+            Err(e) => Err(NetError::new(
+                ErrCode::BUG,
+                format!("api:log-follower:5, {}", e),
+            )),
+        }
+    }
+}
+
+/// Reads a job's log from the `start` line on. If there is no output past `start` yet and the job
+/// is still running, waits until the job server publishes some, or a while passes, and then reads
+/// the log again.
+pub fn follow_job_log(req: &mut Request, job: &Job, start: u64) -> NetResult<JobLog> {
+    let follower = {
+        let cfg = req.get::<persistent::Read<Config>>().unwrap();
+        LogFollower::new(&cfg.jobsrvs, job.get_id())?
+    };
+    let mut subscribe = JobLogSubscribe::new();
+    subscribe.set_job_id(job.get_id());
+    subscribe.set_start(start);
+    let log = route_message::<JobLogSubscribe, JobLog>(req, &subscribe)?;
+    if !log.get_content().is_empty() || log.get_is_complete() || !is_running(job) {
+        return Ok(log);
+    }
+    match follower.next(FOLLOW_WAIT_MS)? {
+        Some(_) => route_message::<JobLogSubscribe, JobLog>(req, &subscribe),
+        None => Ok(log),
+    }
+}
+
+/// Whether a job may still produce output
+fn is_running(job: &Job) -> bool {
+    match job.get_state() {
+        JobState::Pending | JobState::Dispatched | JobState::Processing => true,
+        _ => false,
+    }
+}
+
+/// Streams the log of a job from the `start` line, or from the line given by the `Last-Event-ID`
+/// header of a reconnecting client.
+pub fn job_log_stream(req: &mut Request) -> IronResult<Response> {
+    let id = match get_param(req, "id").and_then(|id| id.parse::<u64>().ok()) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let last_event_id = req.headers.get_raw("Last-Event-ID").and_then(|values| {
+        values.first().and_then(|value| {
+            String::from_utf8_lossy(value).trim().parse::<u64>().ok()
+        })
+    });
+    let start = match last_event_id {
+        Some(start) => start,
+        None => {
+            match get_param(req, "start") {
+                Some(start) => {
+                    match start.parse::<u64>() {
+                        Ok(start) => start,
+                        Err(_) => return Ok(Response::with(status::BadRequest)),
+                    }
+                }
+                None => 0,
+            }
+        }
+    };
+    let include_color = req.get_ref::<Params>()
+        .unwrap()
+        .find(&["color"])
+        .and_then(FromValue::from_value)
+        .unwrap_or(false);
+
+    let mut job_get = JobGet::new();
+    job_get.set_id(id);
+    match route_message::<JobGet, Job>(req, &job_get) {
+        Ok(job) => {
+            let project = job.get_project();
+            if project.get_visibility() == OriginPackageVisibility::Private {
+                if !check_origin_access(req, project.get_origin_name()).unwrap_or(false) {
+                    return Ok(Response::with(status::Forbidden));
+                }
+            }
+        }
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+
+    // Subscribe before the log is first read, so that no output is missed in between
+    let follower = {
+        let cfg = req.get::<persistent::Read<Config>>().unwrap();
+        match LogFollower::new(&cfg.jobsrvs, id) {
+            Ok(follower) => follower,
+            Err(err) => return Ok(render_net_error(&err)),
+        }
+    };
+    // The stream outlives the handler, so it takes the request's route connection along
+    let conn = req.extensions.remove::<XRouteClient>().expect(
+        "no XRouteClient extension in request",
+    );
+    let mut response = Response::with(status::Ok);
+    response.headers.set(ContentType(
+        Mime(TopLevel::Text, SubLevel::EventStream, vec![]),
+    ));
+    dont_cache_response(&mut response);
+    response.body = Some(Box::new(LogStream {
+        conn: conn,
+        follower: follower,
+        job_id: id,
+        start: start,
+        include_color: include_color,
+        filter: AnsiFilter::new(),
+    }));
+    Ok(response)
+}
+
+struct LogStream {
+    conn: RouteClient,
+    follower: LogFollower,
+    job_id: u64,
+    start: u64,
+    include_color: bool,
+    /// Kept for the life of the stream, so that escape sequences split between replies are removed
+    filter: AnsiFilter,
+}
+
+impl LogStream {
+    /// Whether the job may still produce output
+    fn is_running(&mut self) -> NetResult<bool> {
+        let mut request = JobGet::new();
+        request.set_id(self.job_id);
+        let job = self.conn.route::<JobGet, Job>(&request)?;
+        Ok(is_running(&job))
+    }
+
+    /// Reads the log from where the client is
+    fn read(&mut self) -> NetResult<JobLog> {
+        let mut request = JobLogSubscribe::new();
+        request.set_job_id(self.job_id);
+        request.set_start(self.start);
+        match self.conn.route::<JobLogSubscribe, JobLog>(&request) {
+            Ok(log) => Ok(log),
+            // The job hasn't produced any output yet
+            Err(ref err) if err.code() == ErrCode::ENTITY_NOT_FOUND => {
+                let mut log = JobLog::new();
+                log.set_start(self.start);
+                log.set_stop(self.start);
+                Ok(log)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Sends the output of `log` to the client, returning whether there was any
+    fn send(&mut self, res: &mut Write, mut log: JobLog) -> io::Result<bool> {
+        if log.get_content().is_empty() {
+            return Ok(false);
+        }
+        if !self.include_color {
+            log.strip_ansi(&mut self.filter);
+        }
+        self.start = log.get_stop();
+        let data = serde_json::to_string(&log).unwrap();
+        write_event(res, "log", Some(self.start), &data)?;
+        Ok(true)
+    }
+
+    fn complete(&self, res: &mut Write) -> io::Result<()> {
+        write_event(res, "complete", None, &format!("{{\"stop\":{}}}", self.start))
+    }
+
+    fn error(&self, res: &mut Write, err: NetError) -> io::Result<()> {
+        warn!("Unable to stream log of job {}, err={}", self.job_id, err);
+        let data = serde_json::to_string(&err).unwrap();
+        write_event(res, "error", None, &data)
+    }
+}
+
+impl WriteBody for LogStream {
+    fn write_body(&mut self, res: &mut Write) -> io::Result<()> {
+        loop {
+            // Catch up with the log from where the client is
+            let log = match self.read() {
+                Ok(log) => log,
+                Err(err) => return self.error(res, err),
+            };
+            let is_complete = log.get_is_complete();
+            let has_output = self.send(res, log)?;
+            if is_complete {
+                return self.complete(res);
+            }
+            // There's nothing more to wait for once the job is done
+            if !has_output {
+                match self.is_running() {
+                    Ok(true) => (),
+                    Ok(false) => return self.complete(res),
+                    Err(err) => return self.error(res, err),
+                }
+            }
+
+            // Send what is published for as long as it picks up where the client is
+            loop {
+                match self.follower.next(FOLLOW_WAIT_MS) {
+                    Ok(Some(log)) => {
+                        if log.get_is_complete() || log.get_start() != self.start {
+                            break;
+                        }
+                        self.send(res, log)?;
+                    }
+                    Ok(None) => {
+                        res.write_all(b": keepalive\n\n")?;
+                        res.flush()?;
+                        break;
+                    }
+                    Err(err) => return self.error(res, err),
+                }
+            }
+        }
+    }
+}
+
+/// Writes a server-sent event and flushes it to the client.
+fn write_event(res: &mut Write, event: &str, id: Option<u64>, data: &str) -> io::Result<()> {
+    res.write_all(format_event(event, id, data).as_bytes())?;
+    res.flush()
+}
+
+fn format_event(event: &str, id: Option<u64>, data: &str) -> String {
+    let mut message = format!("event: {}\n", event);
+    if let Some(id) = id {
+        message.push_str(&format!("id: {}\n", id));
+    }
+    for line in data.lines() {
+        message.push_str(&format!("data: {}\n", line));
+    }
+    message.push('\n');
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn events() {
+        assert_eq!(
+            format_event("log", Some(42), "{\"start\":40}"),
+            "event: log\nid: 42\ndata: {\"start\":40}\n\n"
+        );
+        assert_eq!(
+            format_event("complete", None, "a\nb"),
+            "event: complete\ndata: a\ndata: b\n\n"
+        );
+    }
+}
@@ -12,23 +12,40 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+#[macro_use]
+mod openapi;
+mod graphql;
 mod handlers;
+mod health;
+mod log_stream;
+mod promotions;
+mod rate_limit;
 
 use depot;
 use github_api_client::GitHubClient;
 use hab_core::event::EventLogger;
+use hab_net::privilege;
+use protocol::sessionsrv::AccessTokenScope;
 use http_gateway;
 use http_gateway::app::prelude::*;
+use http_gateway::http::metrics::{self, RequestMetrics};
+use http_gateway::http::webhooks::Webhooks;
+use http_gateway::oauth;
 use iron;
 use mount::Mount;
 use persistent::{self, Read};
 use segment_api_client::SegmentClient;
+use serde_json;
 use staticfile::Static;
 
 use super::github;
 use self::handlers::*;
+use self::openapi::{OpenApi, Route};
+use self::rate_limit::RateLimit;
 use config::Config;
 
+const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
+
 struct ApiSrv;
 impl HttpGateway for ApiSrv {
     const APP_NAME: &'static str = "builder-api";
@@ -40,20 +57,33 @@ impl HttpGateway for ApiSrv {
         chain.link(persistent::Read::<GitHubCli>::both(
             GitHubClient::new(config.github.clone()),
         ));
+        chain.link(persistent::Read::<OAuthCli>::both(
+            oauth::client(&config.oauth, &config.github),
+        ));
         chain.link(persistent::Read::<SegmentCli>::both(
             SegmentClient::new(config.segment.clone()),
         ));
+        chain.link(persistent::Read::<Webhooks>::both(Webhooks {
+            config: config.depot.webhook.clone(),
+            key_dir: config.depot.key_dir.clone(),
+        }));
         chain.link(Read::<EventLog>::both(
             EventLogger::new(&config.log_dir, config.events_enabled),
         ));
         chain.link_after(Cors);
     }
 
-    fn mount(config: Arc<Self::Config>, chain: iron::Chain) -> Mount {
+    fn mount(config: Arc<Self::Config>, mut chain: iron::Chain) -> Mount {
         let mut depot_config = config.depot.clone();
         depot_config.segment = config.segment.clone();
+        depot_config.oauth = config.oauth.clone();
         let depot = depot::DepotUtil::new(depot_config);
-        let depot_chain = depot::server::router(depot).unwrap();
+        let mut depot_chain = depot::server::router(depot).unwrap();
+        depot_chain.link((RequestMetrics, RequestMetrics));
+        // The API and the depot share the limit of each client
+        let rate_limit = RateLimit::new(config.rate_limit.clone());
+        chain.link((rate_limit.clone(), rate_limit.clone()));
+        depot_chain.link((rate_limit.clone(), rate_limit));
         let mut mount = Mount::new();
         if let Some(ref path) = config.ui.root {
             debug!("Mounting UI at filepath {}", path);
@@ -61,83 +91,167 @@ impl HttpGateway for ApiSrv {
         }
         mount.mount("/v1", chain);
         mount.mount("/v1/depot", depot_chain);
+        mount.mount("/metrics", metrics::metrics);
         mount
     }
 
     fn router(config: Arc<Self::Config>) -> Router {
-        let basic = Authenticated::new(config.github.clone());
-
-        router!(
-            status: get "/status" => status,
-            authenticate: get "/authenticate/:code" => github_authenticate,
-            notify: post "/notify" => notify,
-            update_profile: patch "/profile" => XHandler::new(update_profile).before(basic.clone()),
-            get_profile: get "/profile" => XHandler::new(get_profile).before(basic.clone()),
-
-            job: get "/jobs/:id" => XHandler::new(job_show).before(basic.clone()),
-            job_log: get "/jobs/:id/log" => XHandler::new(job_log).before(basic.clone()),
-            job_group_promote: post "/jobs/group/:id/promote/:channel" => {
-                XHandler::new(job_group_promote).before(basic.clone())
-            },
-            job_group_demote: post "/jobs/group/:id/demote/:channel" => {
-                XHandler::new(job_group_demote).before(basic.clone())
-            },
-            job_group_cancel: post "/jobs/group/:id/cancel" => {
-                XHandler::new(job_group_cancel).before(basic.clone())
-            },
-            rdeps: get "/rdeps/:origin/:name" => rdeps_show,
-
-            user_invitations: get "/user/invitations" => {
-                XHandler::new(list_account_invitations).before(basic.clone())
-            },
-            user_origins: get "/user/origins" => {
-                XHandler::new(list_user_origins).before(basic.clone())
-            },
-
-            projects: post "/projects" => XHandler::new(project_create).before(basic.clone()),
-            project: get "/projects/:origin/:name" => {
-                XHandler::new(project_show).before(basic.clone())
-            },
-            project_list: get "/projects/:origin" => {
-                XHandler::new(project_list).before(basic.clone())
-            },
-            project_jobs: get "/projects/:origin/:name/jobs" => {
-                XHandler::new(project_jobs).before(basic.clone())
-            },
-            edit_project: put "/projects/:origin/:name" => {
-                XHandler::new(project_update).before(basic.clone())
-            },
-            delete_project: delete "/projects/:origin/:name" => {
-                XHandler::new(project_delete).before(basic.clone())
-            },
-            project_privacy_toggle: patch "/projects/:origin/:name/:visibility" => {
-                XHandler::new(project_privacy_toggle).before(basic.clone())
-            },
-            project_integration_get: get
-                "/projects/:origin/:name/integrations/:integration/default" => {
-                XHandler::new(get_project_integration).before(basic.clone())
-            },
-            project_integration_put: put
-                "/projects/:origin/:name/integrations/:integration/default" => {
-                XHandler::new(create_project_integration).before(basic.clone())
-            },
-            project_integration_delete: delete
-                "/projects/:origin/:name/integrations/:integration/default" => {
-                XHandler::new(delete_project_integration).before(basic.clone())
-            },
-            ext_search_code: get "/ext/installations/:install_id/search/code" => {
-                XHandler::new(github::search_code).before(basic.clone())
-            },
-            ext_repo_content: get "/ext/installations/:install_id/repos/:repo/contents/:path" => {
-                XHandler::new(github::repo_file_content).before(basic.clone())
-            },
-            ext_credentials_registry: post "/ext/integrations/:registry_type/credentials/validate" => {
-                XHandler::new(validate_registry_credentials).before(basic.clone())
-            },
-        )
+        let (mut router, routes) = routes(config);
+        router.get(
+            "/openapi.json",
+            OpenApi::new(&routes, VERSION),
+            "openapi",
+        );
+        router
     }
 }
 
+/// The routes of the API, along with their description for its OpenAPI document
+fn routes(config: Arc<Config>) -> (Router, Vec<Route>) {
+    let basic = Authenticated::new(config.github.clone());
+    let admin = Authenticated::new(config.github.clone()).require(privilege::ADMIN);
+    let opt = basic.clone().optional();
+    let scheduler = basic.clone().scope(AccessTokenScope::JobSchedule);
+    let promote = basic
+        .clone()
+        .scope(AccessTokenScope::PackageUpload)
+        .scope(AccessTokenScope::OriginAdmin);
+    let origin_admin = basic.clone().scope(AccessTokenScope::OriginAdmin);
+
+    api_router!(
+        status: get "/status" => status,
+        health_ready: get "/health/ready" => XHandler::new(health::ready),
+        health_deps: get "/health/deps" => XHandler::new(health::deps),
+        authenticate: get "/authenticate/:code" => oauth_authenticate,
+        notify: post "/notify" => notify,
+        graphql: post "/graphql" => XHandler::new(graphql::graphql).before(opt.clone()),
+        graphql_query: get "/graphql" => XHandler::new(graphql::graphql).before(opt.clone()),
+        update_profile: patch "/profile" => XHandler::new(update_profile).before(basic.clone()),
+        get_profile: get "/profile" => XHandler::new(get_profile).before(basic.clone()),
+        access_tokens: get "/profile/access-tokens" => {
+            XHandler::new(access_token_list).before(basic.clone())
+        },
+        access_token_create: post "/profile/access-tokens" => {
+            XHandler::new(access_token_create).before(basic.clone())
+        },
+        access_token_revoke: delete "/profile/access-tokens/:id" => {
+            XHandler::new(access_token_revoke).before(basic.clone())
+        },
+
+        workers: get "/jobs/workers" => XHandler::new(worker_list).before(admin.clone()),
+        jobs: get "/jobs" => XHandler::new(jobs_by_tag).before(scheduler.clone()),
+        jobs_cancel: post "/jobs/cancel" => {
+            XHandler::new(jobs_by_tag_cancel).before(scheduler.clone())
+        },
+        job: get "/jobs/:id" => XHandler::new(job_show).before(scheduler.clone()),
+        job_log: get "/jobs/:id/log" => XHandler::new(job_log).before(scheduler.clone()),
+        job_log_stream: get "/jobs/:id/log/stream" => {
+            XHandler::new(log_stream::job_log_stream).before(scheduler.clone())
+        },
+        job_cancel: post "/jobs/:id/cancel" => {
+            XHandler::new(job_cancel).before(scheduler.clone())
+        },
+        job_audit: get "/jobs/:id/audit" => XHandler::new(job_audit).before(scheduler.clone()),
+        job_group_promote: post "/jobs/group/:id/promote/:channel" => {
+            XHandler::new(job_group_promote).before(promote.clone())
+        },
+        job_group_promote_all: put "/jobs/group/:id/promote/:channel" => {
+            XHandler::new(job_group_promote_all).before(promote.clone())
+        },
+        job_group_demote: post "/jobs/group/:id/demote/:channel" => {
+            XHandler::new(job_group_demote).before(promote.clone())
+        },
+        job_group_cancel: post "/jobs/group/:id/cancel" => {
+            XHandler::new(job_group_cancel).before(scheduler.clone())
+        },
+        job_group_retry: post "/jobs/group/:id/retry" => {
+            XHandler::new(job_group_retry).before(scheduler.clone())
+        },
+        job_group_audit: get "/jobs/group/:id/audit" => {
+            XHandler::new(job_group_audit).before(scheduler.clone())
+        },
+        rdeps: get "/rdeps/:origin/:name" => rdeps_show,
+
+        promotions: get "/promotions/:origin" => {
+            XHandler::new(promotions::promotion_list).before(basic.clone())
+        },
+        promotion_create: post "/promotions/:origin" => {
+            XHandler::new(promotions::promotion_create).before(promote.clone())
+        },
+        promotion: get "/promotions/:origin/:id" => {
+            XHandler::new(promotions::promotion_show).before(basic.clone())
+        },
+        promotion_approve: post "/promotions/:origin/:id/approve" => {
+            XHandler::new(promotions::promotion_approve).before(promote.clone())
+        },
+        promotion_reject: post "/promotions/:origin/:id/reject" => {
+            XHandler::new(promotions::promotion_reject).before(promote.clone())
+        },
+
+        user_invitations: get "/user/invitations" => {
+            XHandler::new(list_account_invitations).before(basic.clone())
+        },
+        user_invitation_accept: put "/user/invitations/:invitation_id" => {
+            XHandler::new(accept_account_invitation).before(basic.clone())
+        },
+        user_invitation_decline: delete "/user/invitations/:invitation_id" => {
+            XHandler::new(decline_account_invitation).before(basic.clone())
+        },
+        user_origins: get "/user/origins" => {
+            XHandler::new(list_user_origins).before(basic.clone())
+        },
+
+        projects: post "/projects" => {
+            XHandler::new(project_create).before(origin_admin.clone())
+        },
+        project: get "/projects/:origin/:name" => {
+            XHandler::new(project_show).before(origin_admin.clone())
+        },
+        project_list: get "/projects/:origin" => {
+            XHandler::new(project_list).before(origin_admin.clone())
+        },
+        project_jobs: get "/projects/:origin/:name/jobs" => {
+            XHandler::new(project_jobs).before(scheduler.clone())
+        },
+        edit_project: put "/projects/:origin/:name" => {
+            XHandler::new(project_update).before(origin_admin.clone())
+        },
+        delete_project: delete "/projects/:origin/:name" => {
+            XHandler::new(project_delete).before(origin_admin.clone())
+        },
+        project_privacy_toggle: patch "/projects/:origin/:name/:visibility" => {
+            XHandler::new(project_privacy_toggle).before(origin_admin.clone())
+        },
+        project_integration_get: get
+            "/projects/:origin/:name/integrations/:integration/default" => {
+            XHandler::new(get_project_integration).before(origin_admin.clone())
+        },
+        project_integration_put: put
+            "/projects/:origin/:name/integrations/:integration/default" => {
+            XHandler::new(create_project_integration).before(origin_admin.clone())
+        },
+        project_integration_delete: delete
+            "/projects/:origin/:name/integrations/:integration/default" => {
+            XHandler::new(delete_project_integration).before(origin_admin.clone())
+        },
+        ext_search_code: get "/ext/installations/:install_id/search/code" => {
+            XHandler::new(github::search_code).before(basic.clone())
+        },
+        ext_repo_content: get "/ext/installations/:install_id/repos/:repo/contents/:path" => {
+            XHandler::new(github::repo_file_content).before(basic.clone())
+        },
+        ext_credentials_registry: post "/ext/integrations/:registry_type/credentials/validate" => {
+            XHandler::new(validate_registry_credentials).before(basic.clone())
+        },
+    )
+}
+
+/// Renders the OpenAPI document of the API.
+pub fn openapi() -> String {
+    let (_, routes) = routes(Arc::new(Config::default()));
+    serde_json::to_string_pretty(&openapi::document(&routes, VERSION)).unwrap()
+}
+
 pub fn run(config: Config) -> AppResult<()> {
     http_gateway::start::<ApiSrv>(config)
 }
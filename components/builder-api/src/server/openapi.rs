@@ -0,0 +1,285 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Describes the API with an OpenAPI 3 document generated from its route table.
+//!
+//! Routes are declared with `api_router!`, which builds the router and records the id, method
+//! and path of every route along the way, so the document can't drift from what is served. The
+//! schema of a response is inferred from the `Serialize` impl of the protocol message a route
+//! renders, by serializing a sample of it. Routes rendering something else are described without
+//! a schema.
+
+use iron::headers::ContentType;
+use iron::prelude::*;
+use iron::{status, Handler};
+use protocol::jobsrv::{Job, JobAudit, JobAuditResponse, JobGraphPackageReverseDependencies,
+                       JobGroup, JobLog, ProjectJobsGetResponse, WorkerListResponse,
+                       WorkerStatus};
+use protocol::originsrv::{OriginProject, OriginPromotionRequest,
+                          OriginPromotionRequestListResponse};
+use protocol::sessionsrv::{AccessToken, AccessTokenListResponse, Account,
+                           AccountInvitationListResponse, AccountOriginInvitation,
+                           AccountOriginListResponse};
+use serde::Serialize;
+use serde_json::{self, Map, Value};
+
+/// Builds a `Router` like `router!` does, along with the `Route` of every entry.
+macro_rules! api_router {
+    ($($id:ident: $method:ident $path:expr => $handler:expr),+ $(,)*) => {{
+        let mut router = Router::new();
+        let mut routes = Vec::new();
+        $(
+            router.$method($path, $handler, stringify!($id));
+            routes.push($crate::server::openapi::Route {
+                id: stringify!($id),
+                method: stringify!($method),
+                path: $path,
+            });
+        )+
+        (router, routes)
+    }};
+}
+
+/// A route of the API, as declared in its route table
+#[derive(Clone, Debug, PartialEq)]
+pub struct Route {
+    pub id: &'static str,
+    pub method: &'static str,
+    pub path: &'static str,
+}
+
+/// Serves the pre-rendered OpenAPI document of the API.
+pub struct OpenApi(String);
+
+impl OpenApi {
+    pub fn new(routes: &[Route], version: &str) -> Self {
+        OpenApi(serde_json::to_string(&document(routes, version)).unwrap())
+    }
+}
+
+impl Handler for OpenApi {
+    fn handle(&self, _req: &mut Request) -> IronResult<Response> {
+        let mut response = Response::with((status::Ok, self.0.clone()));
+        response.headers.set(ContentType::json());
+        Ok(response)
+    }
+}
+
+/// Renders the OpenAPI document describing `routes`.
+pub fn document(routes: &[Route], version: &str) -> Value {
+    let mut paths = Map::new();
+    for route in routes {
+        let (path, params) = openapi_path(route.path);
+        let parameters: Vec<Value> = params
+            .iter()
+            .map(|name| {
+                json!({
+                    "name": name,
+                    "in": "path",
+                    "required": true,
+                    "schema": { "type": "string" },
+                })
+            })
+            .collect();
+        let responses = match response(route.id) {
+            Some((code, sample)) => {
+                let mut responses = Map::new();
+                responses.insert(
+                    code.to_string(),
+                    json!({
+                        "description": "Success",
+                        "content": { "application/json": { "schema": schema(&sample) } },
+                    }),
+                );
+                Value::Object(responses)
+            }
+            None => json!({ "default": { "description": "See the API documentation" } }),
+        };
+        let operation = json!({
+            "operationId": route.id,
+            "parameters": parameters,
+            "responses": responses,
+        });
+        let item = paths.entry(path).or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(ref mut item) = *item {
+            item.insert(route.method.to_string(), operation);
+        }
+    }
+    json!({
+        "openapi": "3.0.0",
+        "info": { "title": "Habitat Builder API", "version": version.trim() },
+        "servers": [{ "url": "/v1" }],
+        "paths": paths,
+    })
+}
+
+/// Turns a route path into an OpenAPI one, returning it along with the names of its parameters.
+fn openapi_path(path: &str) -> (String, Vec<String>) {
+    let mut params = Vec::new();
+    let segments: Vec<String> = path.split('/')
+        .map(|segment| if segment.starts_with(':') {
+            params.push(segment[1..].to_string());
+            format!("{{{}}}", &segment[1..])
+        } else {
+            segment.to_string()
+        })
+        .collect();
+    (segments.join("/"), params)
+}
+
+/// The status code of a successful response of a route and a sample of what it renders, for the
+/// routes rendering a protocol message
+fn response(id: &str) -> Option<(&'static str, Value)> {
+    let response = match id {
+        "get_profile" => ("200", sample(&Account::new())),
+        "access_tokens" => {
+            let mut tokens = AccessTokenListResponse::new();
+            tokens.mut_tokens().push(AccessToken::new());
+            ("200", sample(&tokens))
+        }
+        "access_token_create" => ("201", sample(&AccessToken::new())),
+        "workers" => {
+            let mut workers = WorkerListResponse::new();
+            workers.mut_workers().push(WorkerStatus::new());
+            ("200", sample(&workers))
+        }
+        "job" | "job_cancel" => ("200", sample(&Job::new())),
+        "job_log" => ("200", sample(&JobLog::new())),
+        "job_audit" | "job_group_audit" => {
+            let mut audits = JobAuditResponse::new();
+            audits.mut_audits().push(JobAudit::new());
+            ("200", sample(&audits))
+        }
+        "job_group_retry" => ("200", sample(&JobGroup::new())),
+        "rdeps" => {
+            let mut rdeps = JobGraphPackageReverseDependencies::new();
+            rdeps.mut_rdeps().push(String::new());
+            ("200", sample(&rdeps))
+        }
+        "promotions" => {
+            let mut promotions = OriginPromotionRequestListResponse::new();
+            promotions.mut_promotions().push(OriginPromotionRequest::new());
+            ("200", sample(&promotions))
+        }
+        "promotion" | "promotion_approve" | "promotion_reject" => {
+            ("200", sample(&OriginPromotionRequest::new()))
+        }
+        "promotion_create" => ("201", sample(&OriginPromotionRequest::new())),
+        "user_invitations" => {
+            let mut invitations = AccountInvitationListResponse::new();
+            invitations.mut_invitations().push(AccountOriginInvitation::new());
+            ("200", sample(&invitations))
+        }
+        "user_origins" => {
+            let mut origins = AccountOriginListResponse::new();
+            origins.mut_origins().push(String::new());
+            ("200", sample(&origins))
+        }
+        "projects" => ("201", sample(&OriginProject::new())),
+        "project" => ("200", sample(&OriginProject::new())),
+        "project_list" => ("200", json!([""])),
+        "project_jobs" => {
+            let mut jobs = ProjectJobsGetResponse::new();
+            jobs.mut_jobs().push(Job::new());
+            ("200", sample(&jobs))
+        }
+        _ => return None,
+    };
+    Some(response)
+}
+
+fn sample<T: Serialize>(message: &T) -> Value {
+    serde_json::to_value(message).unwrap()
+}
+
+/// Infers the JSON schema of a value. The items of an array are described by its first one.
+fn schema(value: &Value) -> Value {
+    match *value {
+        Value::Null => json!({}),
+        Value::Bool(_) => json!({ "type": "boolean" }),
+        Value::Number(ref number) if number.is_f64() => json!({ "type": "number" }),
+        Value::Number(_) => json!({ "type": "integer" }),
+        Value::String(_) => json!({ "type": "string" }),
+        Value::Array(ref items) => {
+            let items = items.first().map(schema).unwrap_or(json!({}));
+            json!({ "type": "array", "items": items })
+        }
+        Value::Object(ref fields) => {
+            let properties: Map<String, Value> = fields
+                .iter()
+                .map(|(name, field)| (name.clone(), schema(field)))
+                .collect();
+            json!({ "type": "object", "properties": properties })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_parameters() {
+        assert_eq!(
+            openapi_path("/jobs/group/:id/promote/:channel"),
+            (
+                "/jobs/group/{id}/promote/{channel}".to_string(),
+                vec!["id".to_string(), "channel".to_string()],
+            )
+        );
+        assert_eq!(openapi_path("/status"), ("/status".to_string(), vec![]));
+    }
+
+    #[test]
+    fn inferred_schema() {
+        let value = json!({ "id": "42", "count": 3, "tags": ["a"], "done": false });
+        assert_eq!(
+            schema(&value),
+            json!({
+                "type": "object",
+                "properties": {
+                    "id": { "type": "string" },
+                    "count": { "type": "integer" },
+                    "tags": { "type": "array", "items": { "type": "string" } },
+                    "done": { "type": "boolean" },
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn methods_share_a_path() {
+        let routes = vec![
+            Route {
+                id: "project",
+                method: "get",
+                path: "/projects/:origin/:name",
+            },
+            Route {
+                id: "delete_project",
+                method: "delete",
+                path: "/projects/:origin/:name",
+            },
+        ];
+        let doc = document(&routes, "1.0.0\n");
+        let item = &doc["paths"]["/projects/{origin}/{name}"];
+        assert_eq!(item["get"]["operationId"], json!("project"));
+        assert_eq!(item["delete"]["operationId"], json!("delete_project"));
+        assert_eq!(doc["info"]["version"], json!("1.0.0"));
+        assert_eq!(
+            item["get"]["responses"]["200"]["content"]["application/json"]["schema"]["type"],
+            json!("object")
+        );
+    }
+}
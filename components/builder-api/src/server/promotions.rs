@@ -0,0 +1,294 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Promotion requests, which let members of an origin propose promoting a set of its packages to
+//! a channel. Approvers of the origin review them, and the packages are only promoted once one of
+//! them approves. Every change of state of a request is recorded along with who made it.
+
+use std::str::FromStr;
+
+use bodyparser;
+use hab_core::package::Identifiable;
+use hab_net::NetError;
+use http_gateway::http::controller::*;
+use http_gateway::http::helpers::{self, all_visibilities, check_origin_access,
+                                  check_origin_approver, get_param};
+use iron::status;
+use protocol::originsrv::{OriginChannel, OriginChannelGet, OriginPackage, OriginPackageGet,
+                          OriginPackageIdent, OriginPromotionRequest,
+                          OriginPromotionRequestCreate, OriginPromotionRequestGet,
+                          OriginPromotionRequestListRequest, OriginPromotionRequestListResponse,
+                          OriginPromotionRequestState, OriginPromotionRequestTransition};
+use protocol::sessionsrv::Session;
+
+use types::*;
+
+pub fn promotion_create(req: &mut Request) -> IronResult<Response> {
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let body = match req.get::<bodyparser::Struct<PromotionCreateReq>>() {
+        Ok(Some(body)) => body,
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+    if !check_origin_access(req, &origin)? {
+        return Ok(Response::with(status::Forbidden));
+    }
+    if body.idents.is_empty() {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            "Missing value for field: `idents`",
+        )));
+    }
+    let channel = body.channel.unwrap_or(String::from("stable"));
+    // Every package is in the unstable channel already
+    if channel == "unstable" {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            "Packages can't be promoted to `unstable`",
+        )));
+    }
+
+    let mut channel_get = OriginChannelGet::new();
+    channel_get.set_origin_name(origin.clone());
+    channel_get.set_name(channel.clone());
+    if let Err(err) = route_message::<OriginChannelGet, OriginChannel>(req, &channel_get) {
+        return Ok(render_net_error(&err));
+    }
+
+    let mut idents = Vec::new();
+    for value in body.idents {
+        let ident = match OriginPackageIdent::from_str(&value) {
+            Ok(ident) => ident,
+            Err(_) => return Ok(Response::with(status::UnprocessableEntity)),
+        };
+        if ident.get_origin() != origin || !ident.fully_qualified() {
+            return Ok(Response::with((
+                status::UnprocessableEntity,
+                format!("Not a fully qualified package of origin {}: `{}`", origin, value),
+            )));
+        }
+        let mut package_get = OriginPackageGet::new();
+        package_get.set_ident(ident.clone());
+        package_get.set_visibilities(all_visibilities());
+        if let Err(err) = route_message::<OriginPackageGet, OriginPackage>(req, &package_get) {
+            return Ok(render_net_error(&err));
+        }
+        idents.push(ident.to_string());
+    }
+    idents.sort();
+    idents.dedup();
+
+    let mut promotion = OriginPromotionRequest::new();
+    promotion.set_origin(origin);
+    promotion.set_channel(channel);
+    promotion.set_idents(idents.into());
+    promotion.set_requester_id(session.get_id());
+    promotion.set_requester_name(session.get_name().to_string());
+    let mut request = OriginPromotionRequestCreate::new();
+    request.set_promotion(promotion);
+    request.set_comment(body.comment.unwrap_or_default());
+    match route_message::<OriginPromotionRequestCreate, OriginPromotionRequest>(req, &request) {
+        Ok(promotion) => Ok(render_json(status::Created, &promotion)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn promotion_list(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    if !check_origin_access(req, &origin)? {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginPromotionRequestListRequest::new();
+    request.set_origin(origin);
+    if let Some(state) = helpers::extract_query_value("state", req) {
+        match state.parse::<OriginPromotionRequestState>() {
+            Ok(state) => request.set_state(state),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    }
+    match route_message::<OriginPromotionRequestListRequest, OriginPromotionRequestListResponse>(
+        req,
+        &request,
+    ) {
+        Ok(promotions) => {
+            let mut response = render_json(status::Ok, &promotions);
+            helpers::dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn promotion_show(req: &mut Request) -> IronResult<Response> {
+    let (origin, id) = match promotion_params(req) {
+        Some(params) => params,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    if !check_origin_access(req, &origin)? {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginPromotionRequestGet::new();
+    request.set_origin(origin);
+    request.set_id(id);
+    match route_message::<OriginPromotionRequestGet, OriginPromotionRequest>(req, &request) {
+        Ok(promotion) => {
+            let mut response = render_json(status::Ok, &promotion);
+            helpers::dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Approves a pending promotion request and promotes its packages. The request ends up
+/// `promoted`, or `failed` with the packages which couldn't be promoted in its audit trail.
+pub fn promotion_approve(req: &mut Request) -> IronResult<Response> {
+    let (session, promotion, comment) = match review(req)? {
+        Ok(review) => review,
+        Err(response) => return Ok(response),
+    };
+    let promotion = match transition(
+        req,
+        &session,
+        &promotion,
+        OriginPromotionRequestState::Pending,
+        OriginPromotionRequestState::Approved,
+        comment,
+    ) {
+        Ok(promotion) => promotion,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    let mut failures = Vec::new();
+    for ident in promotion.get_idents() {
+        // Unwrap OK, the identifiers were validated when the request was created
+        let ident = OriginPackageIdent::from_str(ident).unwrap();
+        let channel = promotion.get_channel();
+        if let Err(err) = helpers::promote_package_to_channel(req, &ident, channel) {
+            warn!("Unable to promote {} for request {}, {}", ident, promotion.get_id(), err);
+            failures.push(format!("{}: {}", ident, err));
+        }
+    }
+    let (state, comment) = if failures.is_empty() {
+        (OriginPromotionRequestState::Promoted, String::new())
+    } else {
+        (
+            OriginPromotionRequestState::Failed,
+            format!("Unable to promote {}", failures.join(", ")),
+        )
+    };
+    match transition(
+        req,
+        &session,
+        &promotion,
+        OriginPromotionRequestState::Approved,
+        state,
+        comment,
+    ) {
+        Ok(promotion) => Ok(render_json(status::Ok, &promotion)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn promotion_reject(req: &mut Request) -> IronResult<Response> {
+    let (session, promotion, comment) = match review(req)? {
+        Ok(review) => review,
+        Err(response) => return Ok(response),
+    };
+    match transition(
+        req,
+        &session,
+        &promotion,
+        OriginPromotionRequestState::Pending,
+        OriginPromotionRequestState::Rejected,
+        comment,
+    ) {
+        Ok(promotion) => Ok(render_json(status::Ok, &promotion)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Checks that the session may review the promotion request, and returns the request along with
+/// the reviewer's comment. Requesters can't review their own requests, even if they're approvers.
+fn review(
+    req: &mut Request,
+) -> IronResult<Result<(Session, OriginPromotionRequest, String), Response>> {
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let (origin, id) = match promotion_params(req) {
+        Some(params) => params,
+        None => return Ok(Err(Response::with(status::BadRequest))),
+    };
+    let comment = match req.get::<bodyparser::Struct<PromotionReviewReq>>() {
+        Ok(Some(body)) => body.comment.unwrap_or_default(),
+        Ok(None) => String::new(),
+        Err(_) => return Ok(Err(Response::with(status::UnprocessableEntity))),
+    };
+    if !check_origin_approver(req, session.get_id(), &origin)? {
+        return Ok(Err(Response::with(status::Forbidden)));
+    }
+
+    let mut request = OriginPromotionRequestGet::new();
+    request.set_origin(origin);
+    request.set_id(id);
+    let promotion =
+        match route_message::<OriginPromotionRequestGet, OriginPromotionRequest>(req, &request) {
+            Ok(promotion) => promotion,
+            Err(err) => return Ok(Err(render_net_error(&err))),
+        };
+    if promotion.get_requester_id() == session.get_id() {
+        return Ok(Err(Response::with((
+            status::Forbidden,
+            "Promotion requests can't be reviewed by their requester",
+        ))));
+    }
+    Ok(Ok((session, promotion, comment)))
+}
+
+fn transition(
+    req: &mut Request,
+    session: &Session,
+    promotion: &OriginPromotionRequest,
+    from: OriginPromotionRequestState,
+    to: OriginPromotionRequestState,
+    comment: String,
+) -> Result<OriginPromotionRequest, NetError> {
+    let mut request = OriginPromotionRequestTransition::new();
+    request.set_origin(promotion.get_origin().to_string());
+    request.set_id(promotion.get_id());
+    request.set_from_state(from);
+    request.set_to_state(to);
+    request.set_account_id(session.get_id());
+    request.set_account_name(session.get_name().to_string());
+    request.set_comment(comment);
+    route_message::<OriginPromotionRequestTransition, OriginPromotionRequest>(req, &request)
+}
+
+fn promotion_params(req: &mut Request) -> Option<(String, u64)> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return None,
+    };
+    match get_param(req, "id").and_then(|id| id.parse::<u64>().ok()) {
+        Some(id) => Some((origin, id)),
+        None => None,
+    }
+}
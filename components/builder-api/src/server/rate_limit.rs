@@ -0,0 +1,297 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Limits the rate of requests of each client, so that a runaway client can't slow down the
+//! services behind the API for everyone else.
+//!
+//! Every client gets a token bucket which holds up to a burst of requests and refills at a steady
+//! rate. Clients are told apart by their address. Once an access token was authenticated by a
+//! route, the requests made with it get a bucket of their own, so that users behind a shared
+//! address don't run out of requests because of each other. Tokens which were never authenticated
+//! don't, as anyone could make up a new one for every request. A request finding its client's
+//! bucket empty is rejected with a `429 Too Many Requests` and a `Retry-After` header telling the
+//! client how long to wait for its next request.
+
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use hab_net::{ErrCode, NetError};
+use http_gateway::http::net_err_to_http;
+use iron::headers::{Authorization, Bearer};
+use http_gateway::http::controller::Authenticated;
+use iron::middleware::{AfterMiddleware, BeforeMiddleware};
+use iron::modifiers::Header;
+use iron::prelude::*;
+use serde_json;
+
+use config::RateLimitCfg;
+use headers::RetryAfter;
+
+/// How often the buckets of clients which stopped making requests are dropped
+const PRUNE_INTERVAL_SECS: u64 = 60;
+/// How long an authenticated access token keeps its own bucket after its last request
+const TOKEN_IDLE_SECS: u64 = 15 * 60;
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum Client {
+    /// Hash of an authenticated access token, so tokens aren't kept around in memory
+    Token(u64),
+    Addr(IpAddr),
+}
+
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+struct Buckets {
+    clients: HashMap<Client, Bucket>,
+    /// Hashes of the access tokens which were authenticated, and when they were last used
+    tokens: HashMap<u64, Instant>,
+    pruned_at: Instant,
+    burst: f64,
+    per_sec: f64,
+}
+
+impl Buckets {
+    fn new(config: &RateLimitCfg, now: Instant) -> Self {
+        Buckets {
+            clients: HashMap::new(),
+            tokens: HashMap::new(),
+            pruned_at: now,
+            burst: config.burst.max(1) as f64,
+            per_sec: config.per_minute.max(1) as f64 / 60.0,
+        }
+    }
+
+    /// The client making a request with the given access token from the given address
+    fn client(&mut self, token: Option<u64>, addr: IpAddr, now: Instant) -> Client {
+        if let Some(token) = token {
+            if let Some(used_at) = self.tokens.get_mut(&token) {
+                *used_at = now;
+                return Client::Token(token);
+            }
+        }
+        Client::Addr(addr)
+    }
+
+    /// Lets the requests made with an access token use a bucket of their own
+    fn authenticated(&mut self, token: u64, now: Instant) {
+        self.tokens.insert(token, now);
+    }
+
+    /// Takes a request out of the bucket of a client. Returns the number of seconds until the
+    /// client can make its next request if its bucket is empty.
+    fn take(&mut self, client: Client, now: Instant) -> Result<(), f64> {
+        if now.duration_since(self.pruned_at) >= Duration::from_secs(PRUNE_INTERVAL_SECS) {
+            self.prune(now);
+        }
+        let (burst, per_sec) = (self.burst, self.per_sec);
+        let bucket = self.clients.entry(client).or_insert(Bucket {
+            tokens: burst,
+            updated_at: now,
+        });
+        bucket.tokens = (bucket.tokens + seconds(now.duration_since(bucket.updated_at)) * per_sec)
+            .min(burst);
+        bucket.updated_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err((1.0 - bucket.tokens) / per_sec)
+        }
+    }
+
+    /// Drops the buckets which have filled up again, as their clients are as good as new, and
+    /// forgets the access tokens which weren't used for a while
+    fn prune(&mut self, now: Instant) {
+        let (burst, per_sec) = (self.burst, self.per_sec);
+        self.clients.retain(|_, bucket| {
+            bucket.tokens + seconds(now.duration_since(bucket.updated_at)) * per_sec < burst
+        });
+        self.tokens.retain(|_, used_at| {
+            now.duration_since(*used_at) < Duration::from_secs(TOKEN_IDLE_SECS)
+        });
+        self.pruned_at = now;
+    }
+}
+
+/// Rejects the requests of clients which are over their rate limit, and learns which access tokens
+/// were authenticated after the requests were handled. The buckets are shared by every clone, so
+/// that one limit covers all the routes a clone is linked to.
+#[derive(Clone)]
+pub struct RateLimit {
+    config: RateLimitCfg,
+    buckets: Arc<Mutex<Buckets>>,
+}
+
+impl RateLimit {
+    pub fn new(config: RateLimitCfg) -> Self {
+        let buckets = Buckets::new(&config, Instant::now());
+        RateLimit {
+            config: config,
+            buckets: Arc::new(Mutex::new(buckets)),
+        }
+    }
+
+    fn addr(&self, req: &Request) -> IpAddr {
+        if self.config.trust_forwarded_for {
+            let forwarded = req.headers.get_raw("X-Forwarded-For").and_then(|values| {
+                values.first().and_then(|value| {
+                    forwarded_for(&String::from_utf8_lossy(value))
+                })
+            });
+            if let Some(addr) = forwarded {
+                return addr;
+            }
+        }
+        req.remote_addr.ip()
+    }
+}
+
+impl BeforeMiddleware for RateLimit {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+        let token = token_hash(req);
+        let addr = self.addr(req);
+        let (client, taken) = {
+            let mut buckets = self.buckets.lock().expect("rate limit buckets lock is poisoned");
+            let now = Instant::now();
+            let client = buckets.client(token, addr, now);
+            let taken = buckets.take(client.clone(), now);
+            (client, taken)
+        };
+        match taken {
+            Ok(()) => Ok(()),
+            Err(wait) => {
+                debug!("Rate limiting {:?}, retry in {}s", client, wait);
+                let err = NetError::new(ErrCode::RATE_LIMITED, "api:rate-limit:0");
+                let status = net_err_to_http(err.get_code());
+                let body = serde_json::to_string(&err).unwrap();
+                let retry_after = Header(RetryAfter(wait.ceil().max(1.0) as u64));
+                Err(IronError::new(err, (status, body, retry_after)))
+            }
+        }
+    }
+}
+
+impl AfterMiddleware for RateLimit {
+    fn after(&self, req: &mut Request, res: Response) -> IronResult<Response> {
+        if !self.config.enabled || req.extensions.get::<Authenticated>().is_none() {
+            return Ok(res);
+        }
+        if let Some(token) = token_hash(req) {
+            let mut buckets = self.buckets.lock().expect("rate limit buckets lock is poisoned");
+            buckets.authenticated(token, Instant::now());
+        }
+        Ok(res)
+    }
+}
+
+/// Hash of the access token of a request, if it has one
+fn token_hash(req: &Request) -> Option<u64> {
+    match req.headers.get::<Authorization<Bearer>>() {
+        Some(&Authorization(Bearer { ref token })) => {
+            let mut hasher = DefaultHasher::new();
+            token.hash(&mut hasher);
+            Some(hasher.finish())
+        }
+        None => None,
+    }
+}
+
+/// The address of the client a proxy forwarded a request for, which is the first one listed in
+/// the `X-Forwarded-For` header
+fn forwarded_for(value: &str) -> Option<IpAddr> {
+    value.split(',').next().and_then(
+        |addr| addr.trim().parse().ok(),
+    )
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1_000_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_refills() {
+        let mut config = RateLimitCfg::default();
+        config.burst = 2;
+        config.per_minute = 60;
+        let start = Instant::now();
+        let mut buckets = Buckets::new(&config, start);
+        let client = Client::Addr("10.0.0.1".parse().unwrap());
+        let other = Client::Addr("10.0.0.2".parse().unwrap());
+
+        assert!(buckets.take(client.clone(), start).is_ok());
+        assert!(buckets.take(client.clone(), start).is_ok());
+        assert_eq!(buckets.take(client.clone(), start), Err(1.0));
+        assert!(buckets.take(other.clone(), start).is_ok());
+
+        let later = start + Duration::from_millis(500);
+        assert_eq!(buckets.take(client.clone(), later), Err(0.5));
+        let later = start + Duration::from_secs(1);
+        assert!(buckets.take(client.clone(), later).is_ok());
+    }
+
+    #[test]
+    fn prune_full_buckets() {
+        let config = RateLimitCfg::default();
+        let start = Instant::now();
+        let mut buckets = Buckets::new(&config, start);
+        let client = Client::Token(42);
+        assert!(buckets.take(client.clone(), start).is_ok());
+        assert_eq!(buckets.clients.len(), 1);
+
+        let later = start + Duration::from_secs(PRUNE_INTERVAL_SECS);
+        assert!(buckets.take(Client::Token(7), later).is_ok());
+        assert_eq!(buckets.clients.len(), 1);
+        assert!(!buckets.clients.contains_key(&client));
+    }
+
+    #[test]
+    fn unauthenticated_tokens_share_addr_bucket() {
+        let config = RateLimitCfg::default();
+        let start = Instant::now();
+        let mut buckets = Buckets::new(&config, start);
+        let addr = "10.0.0.1".parse().unwrap();
+        assert_eq!(buckets.client(Some(42), addr, start), Client::Addr(addr));
+        assert_eq!(buckets.client(Some(7), addr, start), Client::Addr(addr));
+
+        buckets.authenticated(42, start);
+        assert_eq!(buckets.client(Some(42), addr, start), Client::Token(42));
+        assert_eq!(buckets.client(Some(7), addr, start), Client::Addr(addr));
+
+        buckets.prune(start + Duration::from_secs(TOKEN_IDLE_SECS));
+        assert_eq!(buckets.client(Some(42), addr, start), Client::Addr(addr));
+    }
+
+    #[test]
+    fn forwarded_for_first_addr() {
+        assert_eq!(
+            forwarded_for("203.0.113.7, 10.0.0.1"),
+            Some("203.0.113.7".parse().unwrap())
+        );
+        assert_eq!(forwarded_for("2001:db8::1"), Some("2001:db8::1".parse().unwrap()));
+        assert_eq!(forwarded_for("unknown"), None);
+    }
+}
@@ -14,6 +14,13 @@
 
 pub use github_api_client::types::*;
 
+#[derive(Clone, Serialize, Deserialize)]
+pub struct AccessTokenCreateReq {
+    pub name: String,
+    /// What the token may be used for, such as `package:upload`
+    pub scopes: Vec<String>,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct JobCreateReq {
     pub project_id: String,
@@ -25,6 +32,12 @@ pub struct ProjectCreateReq {
     pub plan_path: String,
     pub installation_id: u32,
     pub repo_id: u32,
+    /// Minutes a build may run for, `0` uses the server's default
+    pub build_timeout: Option<u32>,
+    /// Labels a worker must have to be dispatched the project's builds
+    pub worker_labels: Option<Vec<String>>,
+    /// Whether builds are built twice to verify that they're reproducible
+    pub verify_reproducible: Option<bool>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -32,6 +45,26 @@ pub struct ProjectUpdateReq {
     pub plan_path: String,
     pub installation_id: u32,
     pub repo_id: u32,
+    /// Minutes a build may run for, `0` uses the server's default
+    pub build_timeout: Option<u32>,
+    /// Labels a worker must have to be dispatched the project's builds
+    pub worker_labels: Option<Vec<String>>,
+    /// Whether builds are built twice to verify that they're reproducible
+    pub verify_reproducible: Option<bool>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PromotionCreateReq {
+    /// Channel to promote the packages to, `stable` if not given
+    pub channel: Option<String>,
+    /// Fully qualified identifiers of the packages to promote
+    pub idents: Vec<String>,
+    pub comment: Option<String>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PromotionReviewReq {
+    pub comment: Option<String>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
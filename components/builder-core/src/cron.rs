@@ -0,0 +1,287 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Cron-style schedules for recurring builds.
+//!
+//! Expressions have the five standard fields, `minute hour day-of-month month day-of-week`, each
+//! of which is `*`, a value, a range `a-b` or a comma separated list of those, optionally with a
+//! step such as `*/15`. Day of week runs from 0 (Sunday) to 6, with 7 also accepted for Sunday.
+//! The `@hourly`, `@daily` (`@midnight`), `@weekly`, `@monthly` and `@yearly` (`@annually`)
+//! shorthands are accepted too. All times are in UTC.
+
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{Datelike, DateTime, Duration, TimeZone, Timelike, UTC};
+
+use error::{Error, Result};
+
+/// How far ahead to look for the next matching time before deciding there is none, e.g. for
+/// `0 0 30 2 *`. Leap days come around at least once in this window.
+const MAX_LOOKAHEAD_DAYS: i64 = 366 * 8;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Schedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    // Cron matches a day when either the day of month or the day of week matches, unless one of
+    // them is `*`
+    days_restricted: bool,
+    weekdays_restricted: bool,
+}
+
+impl Schedule {
+    /// Returns the first time matching the schedule which is strictly after the given time.
+    /// Schedules which can never match return `None`.
+    pub fn next_after(&self, after: &DateTime<UTC>) -> Option<DateTime<UTC>> {
+        let limit = *after + Duration::days(MAX_LOOKAHEAD_DAYS);
+        let mut t = UTC.ymd(after.year(), after.month(), after.day()).and_hms(
+            after.hour(),
+            after.minute(),
+            0,
+        ) + Duration::minutes(1);
+
+        while t < limit {
+            if !contains(self.months, t.month()) {
+                let (year, month) = if t.month() == 12 {
+                    (t.year() + 1, 1)
+                } else {
+                    (t.year(), t.month() + 1)
+                };
+                t = UTC.ymd(year, month, 1).and_hms(0, 0, 0);
+                continue;
+            }
+            if !self.matches_day(&t) {
+                t = t.date().succ().and_hms(0, 0, 0);
+                continue;
+            }
+            if !contains(self.hours, t.hour()) {
+                t = t.date().and_hms(t.hour(), 0, 0) + Duration::hours(1);
+                continue;
+            }
+            if !contains(self.minutes, t.minute()) {
+                t = t + Duration::minutes(1);
+                continue;
+            }
+            return Some(t);
+        }
+        None
+    }
+
+    fn matches_day(&self, t: &DateTime<UTC>) -> bool {
+        let day = contains(self.days, t.day());
+        let weekday = contains(self.weekdays, t.weekday().num_days_from_sunday());
+        match (self.days_restricted, self.weekdays_restricted) {
+            (true, true) => day || weekday,
+            (true, false) => day,
+            (false, true) => weekday,
+            (false, false) => true,
+        }
+    }
+}
+
+impl fmt::Display for Schedule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+impl FromStr for Schedule {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        let expression = value.trim();
+        let expanded = match expression {
+            "@hourly" => "0 * * * *",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@weekly" => "0 0 * * 0",
+            "@monthly" => "0 0 1 * *",
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            e => e,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(bad_expression(
+                expression,
+                "expected 5 fields: minute hour day-of-month month day-of-week",
+            ));
+        }
+
+        let mut weekdays = parse_field(expression, fields[4], 0, 7)?;
+        // 7 is an alias for Sunday
+        if contains(weekdays, 7) {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+
+        Ok(Schedule {
+            expression: expression.to_string(),
+            minutes: parse_field(expression, fields[0], 0, 59)?,
+            hours: parse_field(expression, fields[1], 0, 23)?,
+            days: parse_field(expression, fields[2], 1, 31)?,
+            months: parse_field(expression, fields[3], 1, 12)?,
+            weekdays: weekdays,
+            days_restricted: fields[2] != "*",
+            weekdays_restricted: fields[4] != "*",
+        })
+    }
+}
+
+fn contains(set: u64, value: u32) -> bool {
+    set & (1 << value) != 0
+}
+
+fn bad_expression(expression: &str, reason: &str) -> Error {
+    Error::BadCronExpression(format!("{}, {}", expression, reason))
+}
+
+/// Parse one field of an expression into a bit set of the values it matches.
+fn parse_field(expression: &str, field: &str, min: u32, max: u32) -> Result<u64> {
+    let mut set = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.find('/') {
+            Some(i) => {
+                let step = parse_value(expression, &item[i + 1..])?;
+                if step == 0 {
+                    return Err(bad_expression(expression, "step must be greater than 0"));
+                }
+                (&item[..i], step)
+            }
+            None => (item, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else {
+            match range.find('-') {
+                Some(i) => {
+                    (
+                        parse_value(expression, &range[..i])?,
+                        parse_value(expression, &range[i + 1..])?,
+                    )
+                }
+                None => {
+                    let value = parse_value(expression, range)?;
+                    // `a/n` means every n-th value starting at a
+                    if item.contains('/') {
+                        (value, max)
+                    } else {
+                        (value, value)
+                    }
+                }
+            }
+        };
+        if start < min || end > max || start > end {
+            return Err(bad_expression(
+                expression,
+                &format!("'{}' is outside of {}-{}", item, min, max),
+            ));
+        }
+        let mut value = start;
+        while value <= end {
+            set |= 1 << value;
+            value += step;
+        }
+    }
+    Ok(set)
+}
+
+fn parse_value(expression: &str, value: &str) -> Result<u32> {
+    value.parse::<u32>().map_err(|_| {
+        bad_expression(expression, &format!("'{}' is not a number", value))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn next(expression: &str, after: &str) -> Option<String> {
+        let schedule = expression.parse::<Schedule>().unwrap();
+        let after = DateTime::parse_from_rfc3339(after).unwrap().with_timezone(&UTC);
+        schedule.next_after(&after).map(|t| t.to_rfc3339())
+    }
+
+    #[test]
+    fn nightly() {
+        assert_eq!(
+            next("0 2 * * *", "2017-06-01T01:59:30+00:00").unwrap(),
+            "2017-06-01T02:00:00+00:00"
+        );
+        assert_eq!(
+            next("0 2 * * *", "2017-06-01T02:00:00+00:00").unwrap(),
+            "2017-06-02T02:00:00+00:00"
+        );
+        assert_eq!(
+            next("@daily", "2017-12-31T12:00:00+00:00").unwrap(),
+            "2018-01-01T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn steps_ranges_and_lists() {
+        assert_eq!(
+            next("*/15 * * * *", "2017-06-01T00:16:00+00:00").unwrap(),
+            "2017-06-01T00:30:00+00:00"
+        );
+        assert_eq!(
+            next("30 9-17/4 * * 1-5", "2017-06-02T17:30:00+00:00").unwrap(),
+            "2017-06-05T09:30:00+00:00"
+        );
+        assert_eq!(
+            next("0 0 1,15 * *", "2017-06-02T00:00:00+00:00").unwrap(),
+            "2017-06-15T00:00:00+00:00"
+        );
+        assert_eq!(
+            next("5/20 * * * *", "2017-06-01T00:26:00+00:00").unwrap(),
+            "2017-06-01T00:45:00+00:00"
+        );
+    }
+
+    #[test]
+    fn day_of_month_or_day_of_week() {
+        // 2017-06-01 is a Thursday, so the first Sunday comes before the 13th
+        assert_eq!(
+            next("0 0 13 * 0", "2017-06-01T00:00:00+00:00").unwrap(),
+            "2017-06-04T00:00:00+00:00"
+        );
+        assert_eq!(
+            next("0 0 * * 7", "2017-06-01T00:00:00+00:00").unwrap(),
+            "2017-06-04T00:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn rare_and_impossible_dates() {
+        assert_eq!(
+            next("0 0 29 2 *", "2017-03-01T00:00:00+00:00").unwrap(),
+            "2020-02-29T00:00:00+00:00"
+        );
+        assert_eq!(next("0 0 30 2 *", "2017-03-01T00:00:00+00:00"), None);
+    }
+
+    #[test]
+    fn reject_malformed() {
+        assert!("".parse::<Schedule>().is_err());
+        assert!("* * * *".parse::<Schedule>().is_err());
+        assert!("60 * * * *".parse::<Schedule>().is_err());
+        assert!("* * 0 * *".parse::<Schedule>().is_err());
+        assert!("*/0 * * * *".parse::<Schedule>().is_err());
+        assert!("5-1 * * * *".parse::<Schedule>().is_err());
+        assert!("@fortnightly".parse::<Schedule>().is_err());
+        assert!("a * * * *".parse::<Schedule>().is_err());
+    }
+}
@@ -14,19 +14,33 @@
 
 use std::error;
 use std::fmt;
+use std::io;
 use std::result;
 use std::string;
 
 use base64;
 use hab_core;
+use hyper;
+use openssl;
+use serde_json;
 
 #[derive(Debug)]
 pub enum Error {
     Base64Error(base64::DecodeError),
+    BadCronExpression(String),
     DecryptError(String),
+    /// Packages which depend on each other in a cycle, each one depending on the next and the
+    /// last one depending on the first
+    DependencyCycle(Vec<String>),
     EncryptError(String),
     FromUtf8Error(string::FromUtf8Error),
+    GraphSnapshotVersion(u32),
     HabitatCore(hab_core::Error),
+    IO(io::Error),
+    Json(serde_json::Error),
+    WebhookDelivery(hyper::Error),
+    WebhookSignature(openssl::error::ErrorStack),
+    WebhookStatus(hyper::status::StatusCode),
 }
 
 pub type Result<T> = result::Result<T, Error>;
@@ -35,10 +49,26 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
             Error::Base64Error(ref e) => format!("{}", e),
+            Error::BadCronExpression(ref e) => format!("Invalid cron expression {}", e),
             Error::DecryptError(ref e) => format!("{}", e),
+            Error::DependencyCycle(ref cycle) => {
+                format!(
+                    "Dependency cycle, {} -> {}",
+                    cycle.join(" -> "),
+                    cycle.first().map(|s| s.as_str()).unwrap_or("")
+                )
+            }
             Error::EncryptError(ref e) => format!("{}", e),
             Error::FromUtf8Error(ref e) => format!("{}", e),
+            Error::GraphSnapshotVersion(v) => {
+                format!("Unsupported package graph snapshot version {}", v)
+            }
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::IO(ref e) => format!("{}", e),
+            Error::Json(ref e) => format!("{}", e),
+            Error::WebhookDelivery(ref e) => format!("Unable to reach webhook, {}", e),
+            Error::WebhookSignature(ref e) => format!("Unable to sign webhook payload, {}", e),
+            Error::WebhookStatus(ref status) => format!("Webhook responded with {}", status),
         };
         write!(f, "{}", msg)
     }
@@ -48,10 +78,18 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::Base64Error(ref e) => e.description(),
+            Error::BadCronExpression(_) => "Invalid cron expression",
             Error::DecryptError(_) => "Error decrypting integration",
+            Error::DependencyCycle(_) => "Packages depend on each other in a cycle",
             Error::EncryptError(_) => "Error encrypting integration",
             Error::FromUtf8Error(ref e) => e.description(),
+            Error::GraphSnapshotVersion(_) => "Unsupported package graph snapshot version",
             Error::HabitatCore(ref err) => err.description(),
+            Error::IO(ref err) => err.description(),
+            Error::Json(ref err) => err.description(),
+            Error::WebhookDelivery(ref err) => err.description(),
+            Error::WebhookSignature(ref err) => err.description(),
+            Error::WebhookStatus(_) => "Webhook responded with an error",
         }
     }
 }
@@ -61,3 +99,15 @@ impl From<hab_core::Error> for Error {
         Error::HabitatCore(err)
     }
 }
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Error {
+        Error::IO(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Error {
+        Error::Json(err)
+    }
+}
@@ -19,7 +19,7 @@ use std::path::Path;
 
 use base64;
 
-use hab_core::crypto::BoxKeyPair;
+use hab_core::crypto::{BoxKeyPair, ANONYMOUS_BOX_FORMAT_VERSION};
 use error::{Error, Result};
 use keys;
 
@@ -66,3 +66,72 @@ where
 
     Ok(plaintext)
 }
+
+/// Encrypts `content` to the box key pair of an origin, given the contents of its public key
+/// file.
+pub fn encrypt_with_key(public_key: &str, content: &str) -> Result<String> {
+    let ciphertext = BoxKeyPair::from_key_strings(public_key, None)
+        .and_then(|kp| kp.encrypt(content.as_bytes(), None))
+        .map_err(|err| {
+            let e = format!("Unable to encrypt with origin key pair, err={:?}", &err);
+            error!("Unable to encrypt with origin key pair, err={:?}", err);
+            Error::EncryptError(e)
+        })?;
+
+    Ok(base64::encode(&ciphertext))
+}
+
+/// Decrypts content encrypted to the box key pair of an origin, given the contents of its key
+/// files.
+pub fn decrypt_with_key(public_key: &str, secret_key: &str, b64text: &str) -> Result<String> {
+    let ciphertext = base64::decode(b64text).map_err(Error::Base64Error)?;
+    let plaintext = match BoxKeyPair::from_key_strings(public_key, Some(secret_key))
+        .and_then(|kp| kp.decrypt_anonymous(&ciphertext)) {
+        Ok(bytes) => String::from_utf8(bytes).map_err(Error::FromUtf8Error)?,
+        Err(err) => {
+            let e = format!("Unable to decrypt with origin key pair, err={:?}", err);
+            return Err(Error::DecryptError(e));
+        }
+    };
+
+    Ok(plaintext)
+}
+
+/// Checks that content encrypted by a client was encrypted to the box key pair of an origin,
+/// given the contents of its public key file, without being able to decrypt it.
+pub fn is_encrypted_with_key(public_key: &str, b64text: &str) -> bool {
+    let kp = match BoxKeyPair::from_key_strings(public_key, None) {
+        Ok(kp) => kp,
+        Err(_) => return false,
+    };
+    let ciphertext = match base64::decode(b64text) {
+        Ok(ciphertext) => ciphertext,
+        Err(_) => return false,
+    };
+    let payload = String::from_utf8_lossy(&ciphertext);
+    let mut lines = payload.lines();
+    lines.next() == Some(ANONYMOUS_BOX_FORMAT_VERSION) &&
+        lines.next() == Some(kp.name_with_rev().as_str()) &&
+        lines.next().map_or(false, |val| base64::decode(val).is_ok())
+}
+
+/// The revision of the box key pair of an origin which content was encrypted to, or `None` if it
+/// wasn't encrypted to one of the origin's key pairs.
+pub fn encrypted_key_revision(origin: &str, b64text: &str) -> Option<String> {
+    let ciphertext = match base64::decode(b64text) {
+        Ok(ciphertext) => ciphertext,
+        Err(_) => return None,
+    };
+    let payload = String::from_utf8_lossy(&ciphertext);
+    let mut lines = payload.lines();
+    if lines.next() != Some(ANONYMOUS_BOX_FORMAT_VERSION) {
+        return None;
+    }
+    let prefix = format!("{}-", origin);
+    match lines.next() {
+        Some(name_with_rev) if name_with_rev.starts_with(&prefix) => {
+            Some(name_with_rev[prefix.len()..].to_string())
+        }
+        _ => None,
+    }
+}
@@ -71,6 +71,17 @@ impl fmt::Debug for Job {
                 r
             })
             .collect();
+        let secrets: Vec<originsrv::OriginSecret> = self.0
+            .get_secrets()
+            .into_iter()
+            .map(|s| {
+                let mut r = originsrv::OriginSecret::new();
+                r.set_origin(s.get_origin().to_string());
+                r.set_name(s.get_name().to_string());
+                r.set_value("[secure]".to_string());
+                r
+            })
+            .collect();
 
         f.debug_struct("Job")
             .field("id", &self.0.get_id())
@@ -84,6 +95,7 @@ impl fmt::Debug for Job {
             .field("package_ident", &self.0.get_package_ident())
             .field("integrations", &integrations)
             .field("project_integrations", &self.0.get_project_integrations())
+            .field("secrets", &secrets)
             .field("worker", &self.0.get_worker())
             .finish()
     }
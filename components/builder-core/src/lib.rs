@@ -19,9 +19,14 @@ extern crate glob;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
 extern crate habitat_net as hab_net;
+extern crate hex;
+#[macro_use]
+extern crate hyper;
+extern crate hyper_openssl;
 extern crate iron;
 #[macro_use]
 extern crate log;
+extern crate openssl;
 extern crate statsd;
 extern crate time;
 extern crate petgraph;
@@ -36,6 +41,7 @@ extern crate serde_json;
 extern crate toml;
 
 pub mod build_config;
+pub mod cron;
 pub mod data_structures;
 pub mod error;
 pub mod file_walker;
@@ -47,6 +53,7 @@ pub mod metrics;
 pub mod package_graph;
 pub mod rdeps;
 pub mod target_graph;
+pub mod webhook;
 pub mod job;
 
 pub use error::Error;
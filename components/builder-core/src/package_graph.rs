@@ -21,7 +21,8 @@ use petgraph::graph::NodeIndex;
 use petgraph::algo::{is_cyclic_directed, connected_components};
 use hab_core::package::PackageIdent;
 
-use rdeps::rdeps;
+use error::{Error, Result};
+use rdeps::{rdeps, GraphErr};
 
 #[derive(Debug)]
 pub struct Stats {
@@ -55,6 +56,24 @@ impl PartialEq for HeapEntry {
     }
 }
 
+/// A serializable copy of a `PackageGraph`.
+///
+/// Restoring a graph from a snapshot doesn't need to check each edge for cycles, so it's much
+/// faster than building the graph again from every package.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct PackageGraphSnapshot {
+    /// Names of all the nodes, in node index order
+    names: Vec<String>,
+    /// Latest release of each package, with the names of its dependencies
+    packages: Vec<SnapshotPackage>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotPackage {
+    ident: String,
+    deps: Vec<String>,
+}
+
 fn short_name(name: &str) -> String {
     let parts: Vec<&str> = name.split("/").collect();
     assert!(parts.len() >= 2);
@@ -103,6 +122,52 @@ impl PackageGraph {
         id
     }
 
+    /// Rebuild a graph from a snapshot taken with `snapshot`.
+    pub fn restore(snapshot: PackageGraphSnapshot) -> Result<Self> {
+        let mut graph = PackageGraph::new();
+
+        for name in snapshot.names.iter() {
+            graph.generate_id(name);
+        }
+
+        for package in snapshot.packages {
+            let pkg_ident = PackageIdent::from_str(&package.ident)?;
+            let (_, pkg_node) = graph.generate_id(&package.ident);
+            for dep in package.deps.iter() {
+                let (_, dep_node) = graph.generate_id(dep);
+                graph.graph.add_edge(dep_node, pkg_node, 0);
+            }
+            graph.latest_map.insert(
+                short_name(&package.ident),
+                pkg_ident,
+            );
+        }
+
+        Ok(graph)
+    }
+
+    pub fn snapshot(&self) -> PackageGraphSnapshot {
+        let packages = self.latest_map
+            .iter()
+            .map(|(name, ident)| {
+                let (_, node) = self.package_map[name];
+                let deps = self.graph
+                    .neighbors_directed(node, Direction::Incoming)
+                    .map(|n| self.package_names[self.graph[n]].clone())
+                    .collect();
+                SnapshotPackage {
+                    ident: format!("{}", ident),
+                    deps: deps,
+                }
+            })
+            .collect();
+
+        PackageGraphSnapshot {
+            names: self.package_names.clone(),
+            packages: packages,
+        }
+    }
+
     pub fn build<T>(&mut self, packages: T) -> (usize, usize)
     where
         T: Iterator<Item = jobsrv::JobGraphPackage>,
@@ -236,7 +301,13 @@ impl PackageGraph {
         (self.graph.node_count(), self.graph.edge_count())
     }
 
-    pub fn rdeps(&self, name: &str) -> Option<Vec<(String, String)>> {
+    /// Returns the (name, ident) tuples of the packages which depend on the named package, in
+    /// build order, or `None` if the package isn't in the graph.
+    ///
+    /// # Errors
+    ///
+    /// * If the graph has a dependency cycle, which is returned as an `Error::DependencyCycle`
+    pub fn rdeps(&self, name: &str) -> Result<Option<Vec<(String, String)>>> {
         let mut v: Vec<(String, String)> = Vec::new();
 
         match self.package_map.get(name) {
@@ -249,13 +320,22 @@ impl PackageGraph {
                             v.push((name, ident));
                         }
                     }
-                    Err(e) => panic!("Error: {:?}", e),
+                    Err(GraphErr::GraphCyclic(cycle)) => {
+                        // Edges point from a dependency to the package depending on it, so the
+                        // cycle is reversed to list each package before its dependency
+                        let names = cycle
+                            .iter()
+                            .rev()
+                            .map(|n| self.package_names[*n].clone())
+                            .collect();
+                        return Err(Error::DependencyCycle(names));
+                    }
                 }
             }
-            None => return None,
+            None => return Ok(None),
         }
 
-        Some(v)
+        Ok(Some(v))
     }
 
     // Mostly for debugging
@@ -385,6 +465,69 @@ mod test {
         assert_eq!(pre_check, false);
     }
 
+    #[test]
+    fn snapshot_round_trip() {
+        let mut graph = PackageGraph::new();
+        let mut packages = Vec::new();
+
+        for &(ident, deps) in &[
+            ("core/glibc/2.22/1", vec![]),
+            ("core/zlib/1.2.8/1", vec!["core/glibc/2.22/1"]),
+            ("core/openssl/1.0.2/1", vec!["core/glibc/2.22/1", "core/zlib/1.2.8/1"]),
+            ("core/openssl/1.0.2/2", vec!["core/zlib/1.2.8/1"]),
+            ("core/nginx/1.13.0/1", vec!["core/openssl/1.0.2/1", "core/pcre/8.38/1"]),
+        ]
+        {
+            let mut package = jobsrv::JobGraphPackage::new();
+            package.set_ident(ident.to_string());
+            package.set_deps(RepeatedField::from_vec(
+                deps.iter().map(|d| d.to_string()).collect(),
+            ));
+            packages.push(package);
+        }
+        graph.build(packages.into_iter());
+
+        let restored = PackageGraph::restore(graph.snapshot()).unwrap();
+
+        assert_eq!(restored.stats().node_count, graph.stats().node_count);
+        assert_eq!(restored.stats().edge_count, graph.stats().edge_count);
+        assert_eq!(restored.search("core/"), graph.search("core/"));
+        assert_eq!(
+            restored.resolve("core/openssl"),
+            Some("core/openssl/1.0.2/2".to_string())
+        );
+        for name in &["core/glibc", "core/zlib", "core/openssl", "core/pcre"] {
+            let mut expected = graph.rdeps(name).unwrap().unwrap();
+            let mut actual = restored.rdeps(name).unwrap().unwrap();
+            expected.sort();
+            actual.sort();
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn rdeps_reports_dependency_cycle() {
+        // Snapshots are restored without checking for cycles
+        let snapshot: PackageGraphSnapshot = ::serde_json::from_str(
+            r#"{
+                "names": ["core/a", "core/b", "core/c"],
+                "packages": [
+                    {"ident": "core/a/1/1", "deps": ["core/c"]},
+                    {"ident": "core/b/1/1", "deps": ["core/a"]},
+                    {"ident": "core/c/1/1", "deps": ["core/b"]}
+                ]
+            }"#,
+        ).unwrap();
+        let graph = PackageGraph::restore(snapshot).unwrap();
+
+        match graph.rdeps("core/a") {
+            Err(Error::DependencyCycle(cycle)) => {
+                assert_eq!(cycle, vec!["core/c", "core/b", "core/a"]);
+            }
+            other => panic!("expected a dependency cycle, got {:?}", other),
+        }
+    }
+
     #[test]
     fn pre_check_with_dep_not_present() {
         let mut graph = PackageGraph::new();
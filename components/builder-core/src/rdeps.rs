@@ -16,20 +16,73 @@ use std::collections::{HashMap, HashSet};
 
 use petgraph::Graph;
 use petgraph::graph::NodeIndex;
-use petgraph::algo::{toposort, is_cyclic_directed};
+use petgraph::algo::toposort;
 use petgraph::visit::{Bfs, Walker};
 
 #[derive(Debug, PartialEq)]
 pub enum GraphErr {
-    GraphCyclic,
+    /// The graph has a cycle through these nodes. Each node has an edge to the next one, and the
+    /// last node has an edge to the first.
+    GraphCyclic(Vec<GType>),
 }
 
 pub type GType = usize;
 
+#[derive(Clone, Copy, PartialEq)]
+enum Mark {
+    Unvisited,
+    OnPath,
+    Visited,
+}
+
+/// Find a cycle in the graph, returning its nodes in edge order. Each node has an edge to the
+/// next one, and the last node has an edge to the first.
+pub fn find_cycle(g: &Graph<GType, GType>) -> Option<Vec<GType>> {
+    let mut marks = vec![Mark::Unvisited; g.node_count()];
+
+    for start in g.node_indices() {
+        if marks[start.index()] != Mark::Unvisited {
+            continue;
+        }
+
+        // Depth first search, keeping the current path along with the neighbors of each of its
+        // nodes which are still to be visited
+        let mut path: Vec<(NodeIndex, Vec<NodeIndex>)> =
+            vec![(start, g.neighbors(start).collect())];
+        marks[start.index()] = Mark::OnPath;
+
+        while !path.is_empty() {
+            let next = path.last_mut().unwrap().1.pop();
+            match next {
+                Some(n) => {
+                    match marks[n.index()] {
+                        Mark::Unvisited => {
+                            marks[n.index()] = Mark::OnPath;
+                            path.push((n, g.neighbors(n).collect()));
+                        }
+                        Mark::OnPath => {
+                            // Found an edge back to a node on the path, which closes a cycle
+                            let pos = path.iter().position(|&(p, _)| p == n).unwrap();
+                            return Some(path[pos..].iter().map(|&(p, _)| p.index()).collect());
+                        }
+                        Mark::Visited => (),
+                    }
+                }
+                None => {
+                    let (node, _) = path.pop().unwrap();
+                    marks[node.index()] = Mark::Visited;
+                }
+            }
+        }
+    }
+
+    None
+}
+
 pub fn rdeps(g: &Graph<GType, GType>, n: NodeIndex) -> Result<Vec<GType>, GraphErr> {
-    if is_cyclic_directed(&g) {
-        error!("Input graph should not be cyclic!");
-        return Err(GraphErr::GraphCyclic);
+    if let Some(cycle) = find_cycle(&g) {
+        error!("Input graph should not be cyclic! Cycle: {:?}", cycle);
+        return Err(GraphErr::GraphCyclic(cycle));
     }
 
     // unwrap should never panic as we pre-check for cycle
@@ -82,10 +135,32 @@ mod tests {
 
         match rdeps(&deps, a) {
             Ok(_) => panic!("Cyclic graph should fail!"),
-            Err(e) => assert_eq!(e, GraphErr::GraphCyclic),
+            Err(e) => assert_eq!(e, GraphErr::GraphCyclic(vec![0, 1, 2])),
         }
     }
 
+    #[test]
+    fn finds_only_the_nodes_on_the_cycle() {
+        let mut deps = Graph::<usize, usize>::new();
+        let a = deps.add_node(10);
+        let b = deps.add_node(11);
+        let c = deps.add_node(12);
+        let d = deps.add_node(13);
+
+        deps.extend_with_edges(&[(a, b), (c, d), (d, b), (b, c)]);
+
+        assert_eq!(find_cycle(&deps), Some(vec![1, 2, 3]));
+
+        let mut acyclic = Graph::<usize, usize>::new();
+        let a = acyclic.add_node(10);
+        let b = acyclic.add_node(11);
+        let c = acyclic.add_node(12);
+
+        acyclic.extend_with_edges(&[(a, b), (a, c), (b, c)]);
+
+        assert_eq!(find_cycle(&acyclic), None);
+    }
+
     #[test]
     fn basic_graph_works() {
         let mut deps = Graph::<usize, usize>::new();
@@ -15,9 +15,21 @@
 use std::str::FromStr;
 use std::iter::Iterator;
 use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
 use hab_core::package::PackageTarget;
 use protocol::jobsrv;
-use package_graph::PackageGraph;
+use serde_json;
+use error::{Error, Result};
+use package_graph::{PackageGraph, PackageGraphSnapshot};
+
+/// Version of the snapshot file format, bumped whenever the format changes so that an old
+/// snapshot is discarded rather than misread.
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// The targets which packages can be built for.
+pub const SUPPORTED_TARGETS: &'static [&'static str] = &["x86_64-linux", "x86_64-windows"];
 
 pub struct TargetGraphStats {
     pub target: PackageTarget,
@@ -27,21 +39,101 @@ pub struct TargetGraphStats {
 
 pub struct TargetGraph {
     graphs: HashMap<PackageTarget, PackageGraph>,
+    revision: u64,
+}
+
+/// A serializable copy of a `TargetGraph`, see `TargetGraph::snapshot`.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TargetGraphSnapshot {
+    version: u32,
+    revision: u64,
+    graphs: HashMap<String, PackageGraphSnapshot>,
+}
+
+impl TargetGraphSnapshot {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let file = File::open(path)?;
+        let snapshot: TargetGraphSnapshot = serde_json::from_reader(BufReader::new(file))?;
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(Error::GraphSnapshotVersion(snapshot.version));
+        }
+        Ok(snapshot)
+    }
+
+    /// Write the snapshot to the given path. The snapshot is written to a temporary file which
+    /// is then renamed into place, so a crash part way through never leaves a truncated snapshot.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let tmp_path = path.with_extension("tmp");
+        {
+            let file = File::create(&tmp_path)?;
+            serde_json::to_writer(BufWriter::new(file), self)?;
+        }
+        fs::rename(&tmp_path, path)?;
+        Ok(())
+    }
+
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
 }
 
 impl TargetGraph {
     pub fn new() -> Self {
         let mut graphs = HashMap::new();
 
-        // We only support the following targets currently
-        for target_str in &["x86_64-linux", "x86_64-windows"] {
+        for target_str in SUPPORTED_TARGETS {
             graphs.insert(
                 PackageTarget::from_str(target_str).unwrap(),
                 PackageGraph::new(),
             );
         }
 
-        TargetGraph { graphs: graphs }
+        TargetGraph {
+            graphs: graphs,
+            revision: 0,
+        }
+    }
+
+    /// Rebuild a graph from a snapshot taken with `snapshot`.
+    pub fn restore(snapshot: TargetGraphSnapshot) -> Result<Self> {
+        let mut target_graph = TargetGraph::new();
+        target_graph.revision = snapshot.revision;
+
+        for (target_str, graph) in snapshot.graphs {
+            let target = PackageTarget::from_str(&target_str)?;
+            if target_graph.graphs.contains_key(&target) {
+                target_graph.graphs.insert(
+                    target,
+                    PackageGraph::restore(graph)?,
+                );
+            }
+        }
+
+        Ok(target_graph)
+    }
+
+    pub fn snapshot(&self) -> TargetGraphSnapshot {
+        TargetGraphSnapshot {
+            version: SNAPSHOT_VERSION,
+            revision: self.revision,
+            graphs: self.graphs
+                .iter()
+                .map(|(target, graph)| (target.to_string(), graph.snapshot()))
+                .collect(),
+        }
+    }
+
+    /// The revision of the most recent package the graph has been extended with. Packages
+    /// persisted with a higher revision still need to be added to the graph.
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    pub fn set_revision(&mut self, revision: u64) {
+        if revision > self.revision {
+            self.revision = revision;
+        }
     }
 
     pub fn graph(&self, target_str: &str) -> Option<&PackageGraph> {
@@ -77,6 +169,7 @@ impl TargetGraph {
         T: Iterator<Item = jobsrv::JobGraphPackage>,
     {
         for p in packages {
+            self.set_revision(p.get_revision());
             match self.graph_mut(p.get_target()) {
                 Some(ref mut graph) => {
                     graph.extend(&p);
@@ -85,6 +178,10 @@ impl TargetGraph {
             }
         }
 
+        self.stats()
+    }
+
+    pub fn stats(&self) -> Vec<TargetGraphStats> {
         let mut target_stats = Vec::new();
         for (target, graph) in self.graphs.iter() {
             let stats = graph.stats();
@@ -0,0 +1,196 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Delivers the webhooks origins subscribe to with their notifications.
+//!
+//! A payload is posted as JSON along with the name of its event in the `X-Builder-Event` header.
+//! Webhooks registered with a secret get the HMAC-SHA256 of the payload keyed with the secret, in
+//! the `X-Builder-Signature` header as `sha256=<hex digest>`, so that receivers can tell that the
+//! payload came from Builder. Deliveries which can't reach the endpoint, or which it answers with
+//! a server error, are retried a few times, waiting twice as long before every further retry.
+
+use std::thread;
+use std::time::Duration;
+
+use hex::ToHex;
+use hyper;
+use hyper::header::{ContentType, UserAgent};
+use hyper::net::HttpsConnector;
+use hyper::status::StatusCode;
+use hyper_openssl::OpensslClient;
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::sign::Signer;
+
+use error::{Error, Result};
+
+header! { (XBuilderEvent, "X-Builder-Event") => [String] }
+header! { (XBuilderSignature, "X-Builder-Signature") => [String] }
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct WebhookCfg {
+    /// Max time (in seconds) to wait on a webhook endpoint
+    pub timeout: u64,
+    /// Number of times a failed delivery is retried
+    pub retries: u32,
+    /// Time (in seconds) to wait before the first retry, doubled before every further one
+    pub backoff: u64,
+}
+
+impl Default for WebhookCfg {
+    fn default() -> Self {
+        WebhookCfg {
+            timeout: 10,
+            retries: 5,
+            backoff: 2,
+        }
+    }
+}
+
+/// Posts a payload to a webhook, retrying as configured. The payload is signed if the webhook
+/// has a secret.
+pub fn deliver(
+    config: &WebhookCfg,
+    url: &str,
+    secret: Option<&str>,
+    event: &str,
+    payload: &str,
+) -> Result<()> {
+    let signature = match secret {
+        Some(secret) => Some(signature(secret, payload)?),
+        None => None,
+    };
+    let mut delay = Duration::from_secs(config.backoff);
+    let mut retries = 0;
+    loop {
+        match post(config, url, signature.as_ref(), event, payload) {
+            Ok(()) => return Ok(()),
+            Err(ref err) if retries < config.retries && is_transient(err) => {
+                warn!(
+                    "Webhook delivery to {} failed, retrying in {}s, {}",
+                    url,
+                    delay.as_secs(),
+                    err
+                );
+                thread::sleep(delay);
+                delay = delay * 2;
+                retries += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+/// Delivers a payload to a webhook on a thread of its own, so that the caller isn't held up by
+/// retries. Failed deliveries are logged.
+pub fn spawn_delivery(
+    config: WebhookCfg,
+    url: String,
+    secret: Option<String>,
+    event: String,
+    payload: String,
+) {
+    let spawned = thread::Builder::new()
+        .name("webhook".to_string())
+        .spawn(move || {
+            let secret = secret.as_ref().map(|s| s.as_str());
+            if let Err(err) = deliver(&config, &url, secret, &event, &payload) {
+                warn!("Unable to deliver {} webhook to {}, {}", event, url, err);
+            }
+        });
+    if let Err(err) = spawned {
+        warn!("Unable to start webhook delivery, {}", err);
+    }
+}
+
+/// Value of the signature header of a payload
+pub fn signature(secret: &str, payload: &str) -> Result<String> {
+    let key = PKey::hmac(secret.as_bytes()).map_err(Error::WebhookSignature)?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key).map_err(
+        Error::WebhookSignature,
+    )?;
+    signer.update(payload.as_bytes()).map_err(
+        Error::WebhookSignature,
+    )?;
+    let hmac = signer.finish().map_err(Error::WebhookSignature)?;
+    Ok(format!("sha256={}", hmac.to_hex()))
+}
+
+fn post(
+    config: &WebhookCfg,
+    url: &str,
+    signature: Option<&String>,
+    event: &str,
+    payload: &str,
+) -> Result<()> {
+    let client = hyper_client(config.timeout);
+    let mut request = client
+        .post(url)
+        .header(ContentType::json())
+        .header(UserAgent("Habitat-Builder".to_string()))
+        .header(XBuilderEvent(event.to_string()));
+    if let Some(signature) = signature {
+        request = request.header(XBuilderSignature(signature.clone()));
+    }
+    let response = request.body(payload).send().map_err(Error::WebhookDelivery)?;
+    if !response.status.is_success() {
+        return Err(Error::WebhookStatus(response.status));
+    }
+    Ok(())
+}
+
+/// Whether a failed delivery might succeed when retried
+fn is_transient(err: &Error) -> bool {
+    match *err {
+        Error::WebhookDelivery(_) => true,
+        Error::WebhookStatus(status) => {
+            status.is_server_error() || status == StatusCode::TooManyRequests
+        }
+        _ => false,
+    }
+}
+
+fn hyper_client(timeout: u64) -> hyper::Client {
+    let ssl = OpensslClient::new().unwrap();
+    let connector = HttpsConnector::new(ssl);
+    let mut client = hyper::Client::with_connector(connector);
+    client.set_read_timeout(Some(Duration::from_secs(timeout)));
+    client.set_write_timeout(Some(Duration::from_secs(timeout)));
+    client
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_signature() {
+        assert_eq!(
+            signature("key", "The quick brown fox jumps over the lazy dog").unwrap(),
+            "sha256=f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+    }
+
+    #[test]
+    fn transient_failures() {
+        assert!(is_transient(
+            &Error::WebhookStatus(StatusCode::ServiceUnavailable),
+        ));
+        assert!(is_transient(
+            &Error::WebhookStatus(StatusCode::TooManyRequests),
+        ));
+        assert!(!is_transient(&Error::WebhookStatus(StatusCode::NotFound)));
+    }
+}
@@ -16,8 +16,10 @@ use std::ops::{Deref, DerefMut};
 use std::thread;
 use std::time::Duration;
 use std::fmt;
+use std::result;
 
 use fnv::FnvHasher;
+use hab_net::app::health::HealthCheck;
 use rand::{self, Rng};
 use r2d2;
 use r2d2_postgres::{self, PostgresConnectionManager, TlsMode};
@@ -114,6 +116,14 @@ impl Pool {
     }
 }
 
+impl HealthCheck for Pool {
+    fn check(&self) -> result::Result<(), String> {
+        let conn = self.get_raw().map_err(|e| e.to_string())?;
+        conn.execute("SELECT 1", &[]).map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
 impl Deref for Pool {
     type Target = r2d2::Pool<PostgresConnectionManager>;
 
@@ -20,6 +20,7 @@ extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
 extern crate habitat_http_client as hab_http;
 extern crate broadcast;
+extern crate bsdiff;
 #[macro_use]
 extern crate hyper;
 extern crate hyper_openssl;
@@ -38,22 +39,36 @@ extern crate tee;
 extern crate url;
 
 pub mod error;
+pub mod progress;
+pub mod retry;
+pub mod throttle;
 pub use error::{Error, Result};
+pub use progress::{DisplayProgress, NoProgress, Progress, ProgressTracker, Transfer};
+pub use retry::{RetryOn, RetryPolicy};
+pub use throttle::{Rate, Throttle, Throttled};
 
+use std::cmp;
 use std::fmt;
 use std::fs::{self, File};
-use std::io::{self, Read, Write};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
 use std::string::ToString;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::thread;
 
 use broadcast::BroadcastWriter;
 use chrono::DateTime;
-use hab_core::package::{Identifiable, PackageArchive};
+use hab_core::crypto::{artifact, hash};
+use hab_core::crypto::keys::parse_name_with_rev;
+use hab_core::package::{Identifiable, PackageArchive, PackageTarget};
+use hab_core::url::bldr_urls;
 use hab_http::ApiClient;
 use hab_http::util::decoded_response;
 use hyper::client::{Body, IntoUrl, Response, RequestBuilder};
 use hyper::status::StatusCode;
-use hyper::header::{Authorization, Bearer};
+use hyper::header::{Authorization, Bearer, ContentLength, ContentRange, ContentRangeSpec, Range};
 use hyper::Url;
 use protobuf::core::ProtobufEnum;
 use protocol::{originsrv, net};
@@ -63,8 +78,13 @@ use url::percent_encoding::{percent_encode, PATH_SEGMENT_ENCODE_SET};
 
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
+header! { (IfNoneMatch, "If-None-Match") => [String] }
 
 const DEFAULT_API_PATH: &'static str = "/v1";
+/// Size of the segments a package is downloaded in, in bytes
+const DOWNLOAD_SEGMENT_SIZE: u64 = 4 * 1024 * 1024;
+/// How many segments of a package are downloaded at once
+const DOWNLOAD_STREAMS: u64 = 4;
 
 #[derive(Clone, Deserialize)]
 #[serde(rename = "error")]
@@ -82,14 +102,23 @@ impl ToString for NetError {
     }
 }
 
+/// The parts of a chunked upload Builder received so far
+#[derive(Clone, Deserialize)]
+struct UploadStatus {
+    part_size: u64,
+    parts: Vec<u64>,
+}
+
 #[derive(Clone, Deserialize)]
 pub struct OriginKeyIdent {
     pub origin: String,
     pub revision: String,
     pub location: String,
+    #[serde(default)]
+    pub deprecated: bool,
 }
 
-#[derive(Clone, Default, Deserialize)]
+#[derive(Clone, Default, Deserialize, Serialize)]
 pub struct Project {
     pub name: String,
     pub ident: String,
@@ -112,7 +141,7 @@ impl fmt::Display for Project {
     }
 }
 
-#[derive(Default, Deserialize)]
+#[derive(Default, Deserialize, Serialize)]
 pub struct SchedulerResponse {
     pub id: String,
     pub state: String,
@@ -156,6 +185,7 @@ impl Into<originsrv::OriginKeyIdent> for OriginKeyIdent {
         out.set_origin(self.origin);
         out.set_revision(self.revision);
         out.set_location(self.location);
+        out.set_deprecated(self.deprecated);
         out
     }
 }
@@ -185,6 +215,53 @@ mod json_u64 {
     }
 }
 
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct OriginSecret {
+    pub origin: String,
+    pub name: String,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+pub struct OriginSecretList {
+    pub secrets: Vec<OriginSecret>,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+struct OriginSecretCreate<'a> {
+    name: &'a str,
+    value: &'a str,
+}
+
+#[derive(Clone, Serialize, Deserialize, Debug)]
+struct BundleExport<'a> {
+    packages: &'a [String],
+    channel: Option<&'a str>,
+}
+
+/// A bundle exported from an origin, to be downloaded before it expires
+#[derive(Clone, Deserialize, Debug)]
+pub struct BundleExported {
+    pub checksum: String,
+    pub size: u64,
+    pub packages: Vec<String>,
+}
+
+/// The new revision of an origin's encryption key, and the secrets re-encrypted with it
+#[derive(Clone, Deserialize, Debug)]
+pub struct EncryptionKeyRotation {
+    pub revision: String,
+    pub reencrypted: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+/// What importing a bundle added to Builder
+#[derive(Clone, Deserialize, Debug)]
+pub struct BundleImported {
+    pub packages_imported: Vec<String>,
+    pub packages_skipped: Vec<String>,
+    pub keys_imported: Vec<String>,
+}
+
 #[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct OriginSecretKey {
     #[serde(with = "json_u64")]
@@ -255,6 +332,13 @@ pub struct OriginChannelIdent {
     pub name: String,
 }
 
+/// A channel of an origin along with how many packages are in it
+#[derive(Clone, Debug, Deserialize)]
+pub struct ChannelPackageCount {
+    pub name: String,
+    pub package_count: u64,
+}
+
 impl Into<originsrv::OriginChannelIdent> for OriginChannelIdent {
     fn into(self) -> originsrv::OriginChannelIdent {
         let mut out = originsrv::OriginChannelIdent::new();
@@ -263,30 +347,59 @@ impl Into<originsrv::OriginChannelIdent> for OriginChannelIdent {
     }
 }
 
-pub trait DisplayProgress: Write {
-    fn size(&mut self, size: u64);
-    fn finish(&mut self);
+pub struct Client {
+    /// Every mirror of Builder with its client, in the order they are tried
+    endpoints: Vec<(Url, Arc<ApiClient>)>,
+    /// Index of the last endpoint which answered a request
+    active: AtomicUsize,
+    /// How requests which failed for reasons that may go away are retried
+    retry: RetryPolicy,
+    /// Limits the rate archives are transferred at, if any
+    throttle: Option<Throttle>,
 }
 
-pub struct Client(ApiClient);
-
 impl Client {
+    /// Creates a client for the Builder at `endpoints`, which may be a comma-separated list of
+    /// mirrors. Requests fail over to the next mirror when one can't be reached or answers with a
+    /// server error, and the one that answered is tried first from then on. Requests which still
+    /// fail are retried with the policy given in the environment, and archives are transferred
+    /// no faster than the rate given in it. Requests which aren't idempotent, like scheduling a
+    /// build, only fail over or are retried when they couldn't be sent at all, or Builder asked
+    /// to slow down.
     pub fn new<U>(
-        endpoint: U,
+        endpoints: U,
         product: &str,
         version: &str,
         fs_root_path: Option<&Path>,
     ) -> Result<Self>
     where
-        U: IntoUrl,
+        U: AsRef<str>,
     {
-        let mut endpoint = endpoint.into_url()?;
-        if !endpoint.cannot_be_a_base() && endpoint.path() == "/" {
-            endpoint.set_path(DEFAULT_API_PATH);
+        let mut clients = Vec::new();
+        for endpoint in bldr_urls(endpoints.as_ref()) {
+            let mut endpoint = endpoint.into_url()?;
+            if !endpoint.cannot_be_a_base() && endpoint.path() == "/" {
+                endpoint.set_path(DEFAULT_API_PATH);
+            }
+            let client = ApiClient::new(endpoint.clone(), product, version, fs_root_path)?;
+            clients.push((endpoint, Arc::new(client)));
         }
-        Ok(Client(
-            ApiClient::new(endpoint, product, version, fs_root_path)?,
-        ))
+        Ok(Client {
+            endpoints: clients,
+            active: AtomicUsize::new(0),
+            retry: RetryPolicy::from_env(),
+            throttle: Throttle::from_env(),
+        })
+    }
+
+    /// Replaces the policy requests are retried with
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry = policy;
+    }
+
+    /// Limits the rate archives are transferred at, or lifts the limit
+    pub fn set_throttle(&mut self, rate: Option<Rate>) {
+        self.throttle = rate.map(Throttle::new);
     }
 
     /// Retrieves the status of every group job in an origin
@@ -295,22 +408,9 @@ impl Client {
     ///
     /// * Remote Builder is not available
     pub fn get_origin_schedule(&self, origin: &str) -> Result<String> {
-        debug!(
-            "Retrieving schedule for all job groups in the {} origin",
-            origin
-        );
-
-        let path = format!("depot/pkgs/schedule/{}/status", origin);
-        let res = self.0.get(&path).send()?;
-
-        if res.status != StatusCode::Ok {
-            return Err(err_from_response(res));
-        }
-
-        let sr: Vec<SchedulerResponse> = decoded_response(res)?;
         let mut resp = Vec::new();
 
-        for s in sr.iter() {
+        for s in self.get_origin_schedules(origin)?.iter() {
             resp.push(s.to_string());
             resp.push("".to_string());
             resp.push("-------------------------------------".to_string());
@@ -320,6 +420,30 @@ impl Client {
         Ok(resp.join("\n"))
     }
 
+    /// Retrieves the status of every job group in an origin
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    pub fn get_origin_schedules(&self, origin: &str) -> Result<Vec<SchedulerResponse>> {
+        debug!(
+            "Retrieving schedule for all job groups in the {} origin",
+            origin
+        );
+
+        let path = format!("depot/pkgs/schedule/{}/status", origin);
+        let res = self.send(|api| api.get(&path))?;
+
+        match res.status {
+            StatusCode::Ok |
+            StatusCode::PartialContent => (),
+            _ => return Err(err_from_response(res)),
+        }
+
+        let results: PackageResults<SchedulerResponse> = decoded_response(res)?;
+        Ok(results.data)
+    }
+
     /// Retrieves the status of a group job
     ///
     /// # Failures
@@ -329,7 +453,7 @@ impl Client {
         debug!("Retrieving schedule for job group {}", group_id);
 
         let path = format!("depot/pkgs/schedule/{}", group_id);
-        let res = self.0.get(&path).send()?;
+        let res = self.send(|api| api.get(&path))?;
 
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
@@ -351,14 +475,14 @@ impl Client {
     {
         // TODO (SA): This API needs to be extended to support a target param.
         let path = format!("depot/pkgs/schedule/{}/{}", ident.origin(), ident.name());
-        let result = if package_only {
+        let result = self.send_non_idempotent(|api| if package_only {
             let custom =
                 |url: &mut Url| { url.query_pairs_mut().append_pair("package_only", "true"); };
-            self.add_authz(self.0.post_with_custom_url(&path, custom), token)
+            self.add_authz(api.post_with_custom_url(&path, custom), token)
                 .send()
         } else {
-            self.add_authz(self.0.post(&path), token).send()
-        };
+            self.add_authz(api.post(&path), token).send()
+        });
         match result {
             Ok(response) => {
                 if response.status == StatusCode::Ok {
@@ -425,7 +549,7 @@ impl Client {
     }
 
     pub fn show_origin_keys(&self, origin: &str) -> Result<Vec<originsrv::OriginKeyIdent>> {
-        let mut res = self.0.get(&origin_keys_path(origin)).send()?;
+        let mut res = self.send(|api| api.get(&origin_keys_path(origin)))?;
         debug!("Response: {:?}", res);
 
         if res.status != StatusCode::Ok {
@@ -480,7 +604,7 @@ impl Client {
         let path = package_channels_path(ident);
         debug!("Retrieving channels for {}", ident);
 
-        let mut res = self.maybe_add_authz(self.0.get(&path), token).send()?;
+        let mut res = self.send(|api| self.maybe_add_authz(api.get(&path), token))?;
 
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
@@ -518,20 +642,23 @@ impl Client {
         D: DisplayProgress + Sized,
     {
         let path = format!("depot/origins/{}/keys/{}", &origin, &revision);
-        let mut file = File::open(src_path)?;
-        let file_size = file.metadata()?.len();
-
-        let result = if let Some(mut progress) = progress {
-            progress.size(file_size);
-            let mut reader = TeeReader::new(file, progress);
-            self.add_authz(self.0.post(&path), token)
-                .body(Body::SizedBody(&mut reader, file_size))
-                .send()
-        } else {
-            self.add_authz(self.0.post(&path), token)
-                .body(Body::SizedBody(&mut file, file_size))
-                .send()
-        };
+        let file_size = File::open(src_path)?.metadata()?.len();
+
+        let mut progress = progress;
+        let result = self.send_with(|api| {
+            let mut file = self.throttled(File::open(src_path)?);
+            if let Some(ref mut progress) = progress {
+                progress.size(file_size);
+                let mut reader = TeeReader::new(file, progress);
+                self.add_authz(api.post(&path), token)
+                    .body(Body::SizedBody(&mut reader, file_size))
+                    .send()
+            } else {
+                self.add_authz(api.post(&path), token)
+                    .body(Body::SizedBody(&mut file, file_size))
+                    .send()
+            }
+        });
         match result {
             Ok(Response { status: StatusCode::Created, .. }) => Ok(()),
             Ok(response) => Err(err_from_response(response)),
@@ -583,20 +710,23 @@ impl Client {
         D: DisplayProgress + Sized,
     {
         let path = format!("depot/origins/{}/secret_keys/{}", &origin, &revision);
-        let mut file = File::open(src_path)?;
-        let file_size = file.metadata()?.len();
-
-        let result = if let Some(mut progress) = progress {
-            progress.size(file_size);
-            let mut reader = TeeReader::new(file, progress);
-            self.add_authz(self.0.post(&path), token)
-                .body(Body::SizedBody(&mut reader, file_size))
-                .send()
-        } else {
-            self.add_authz(self.0.post(&path), token)
-                .body(Body::SizedBody(&mut file, file_size))
-                .send()
-        };
+        let file_size = File::open(src_path)?.metadata()?.len();
+
+        let mut progress = progress;
+        let result = self.send_with(|api| {
+            let mut file = self.throttled(File::open(src_path)?);
+            if let Some(ref mut progress) = progress {
+                progress.size(file_size);
+                let mut reader = TeeReader::new(file, progress);
+                self.add_authz(api.post(&path), token)
+                    .body(Body::SizedBody(&mut reader, file_size))
+                    .send()
+            } else {
+                self.add_authz(api.post(&path), token)
+                    .body(Body::SizedBody(&mut file, file_size))
+                    .send()
+            }
+        });
         match result {
             Ok(Response { status: StatusCode::Created, .. }) => Ok(()),
             Ok(response) => Err(err_from_response(response)),
@@ -638,6 +768,82 @@ impl Client {
         }
     }
 
+    /// Download a package from a remote Builder to the given directory, unless the archive of it
+    /// at `cached` is already the same as Builder's. Returns `None` when the cached archive is
+    /// the same, which is told from its checksum without downloading anything.
+    ///
+    /// The ident must be fully qualified. Builder isn't asked again when it can't be reached, as
+    /// the cached archive can be used instead.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found
+    /// * Remote Builder is not available
+    /// * The cached archive cannot be read
+    /// * File cannot be created and written to
+    pub fn fetch_package_unless_cached<D, I, P>(
+        &self,
+        ident: &I,
+        token: Option<&str>,
+        cached: &Path,
+        dst_path: &P,
+        progress: Option<D>,
+    ) -> Result<Option<PackageArchive>>
+    where
+        P: AsRef<Path> + ?Sized,
+        I: Identifiable,
+        D: DisplayProgress + Sized,
+    {
+        let file = self.download_unless_cached(
+            &package_download(ident),
+            dst_path.as_ref(),
+            token,
+            Some(cached),
+            progress,
+        )?;
+        Ok(file.map(PackageArchive::new))
+    }
+
+    /// Download a package from a remote Builder as a delta from the archive of an earlier
+    /// release of it, and apply the delta to that archive at `from_path` to put the archive of
+    /// the package together in the given directory.
+    ///
+    /// Both idents must be fully qualified. Callers should fall back to `fetch_package` when this
+    /// fails, as Builder doesn't serve deltas that wouldn't save anything.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder doesn't have a delta between both releases
+    /// * Remote Builder is not available
+    /// * The delta cannot be applied to the archive at `from_path`
+    pub fn fetch_package_delta<D, I, P>(
+        &self,
+        ident: &I,
+        from: &I,
+        from_path: &Path,
+        token: Option<&str>,
+        dst_path: &P,
+        progress: Option<D>,
+    ) -> Result<PackageArchive>
+    where
+        P: AsRef<Path> + ?Sized,
+        I: Identifiable,
+        D: DisplayProgress + Sized,
+    {
+        if !ident.fully_qualified() || !from.fully_qualified() {
+            return Err(Error::IdentNotFullyQualified);
+        }
+        let delta_path = self.download(
+            &package_delta(ident, from),
+            dst_path.as_ref(),
+            token,
+            progress,
+        )?;
+        let result = apply_delta(from_path, &delta_path);
+        fs::remove_file(&delta_path)?;
+        result.map(PackageArchive::new)
+    }
+
     /// Returns a package struct for the latest package.
     ///
     /// An optional version can be specified which will scope the release returned to the latest
@@ -653,6 +859,26 @@ impl Client {
         channel: Option<&str>,
         token: Option<&str>,
     ) -> Result<originsrv::OriginPackage>
+    where
+        I: Identifiable,
+    {
+        self.show_package_for_target(package, channel, None, token)
+    }
+
+    /// Returns a package struct for the latest package built for the given target, rather than
+    /// for the target of this client.
+    ///
+    /// # Failures
+    ///
+    /// * Package cannot be found for the target
+    /// * Remote Builder is not available
+    pub fn show_package_for_target<I>(
+        &self,
+        package: &I,
+        channel: Option<&str>,
+        target: Option<&PackageTarget>,
+        token: Option<&str>,
+    ) -> Result<originsrv::OriginPackage>
     where
         I: Identifiable,
     {
@@ -668,7 +894,15 @@ impl Client {
             url.push_str("/latest");
         }
 
-        let mut res = self.maybe_add_authz(self.0.get(&url), token).send()?;
+        let mut res = self.send(|api| {
+            let custom = |url: &mut Url| if let Some(target) = target {
+                url.query_pairs_mut().append_pair(
+                    "target",
+                    &target.to_string(),
+                );
+            };
+            self.maybe_add_authz(api.get_with_custom_url(&url, custom), token)
+        })?;
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
         }
@@ -701,23 +935,27 @@ impl Client {
     {
         let checksum = pa.checksum()?;
         let ident = pa.ident()?;
-        let mut file = File::open(&pa.path)?;
-        let file_size = file.metadata()?.len();
+        let file_size = File::open(&pa.path)?.metadata()?.len();
         let path = package_path(&ident);
-        let custom = |url: &mut Url| { url.query_pairs_mut().append_pair("checksum", &checksum); };
         debug!("Reading from {}", &pa.path.display());
 
-        let result = if let Some(mut progress) = progress {
-            progress.size(file_size);
-            let mut reader = TeeReader::new(file, progress);
-            self.add_authz(self.0.post_with_custom_url(&path, custom), token)
-                .body(Body::SizedBody(&mut reader, file_size))
-                .send()
-        } else {
-            self.add_authz(self.0.post_with_custom_url(&path, custom), token)
-                .body(Body::SizedBody(&mut file, file_size))
-                .send()
-        };
+        let mut progress = progress;
+        let result = self.send_with(|api| {
+            let mut file = self.throttled(File::open(&pa.path)?);
+            let custom =
+                |url: &mut Url| { url.query_pairs_mut().append_pair("checksum", &checksum); };
+            if let Some(ref mut progress) = progress {
+                progress.size(file_size);
+                let mut reader = TeeReader::new(file, progress);
+                self.add_authz(api.post_with_custom_url(&path, custom), token)
+                    .body(Body::SizedBody(&mut reader, file_size))
+                    .send()
+            } else {
+                self.add_authz(api.post_with_custom_url(&path, custom), token)
+                    .body(Body::SizedBody(&mut file, file_size))
+                    .send()
+            }
+        });
         match result {
             Ok(Response { status: StatusCode::Created, .. }) => Ok(()),
             Ok(response) => Err(err_from_response(response)),
@@ -725,22 +963,41 @@ impl Client {
         }
     }
 
+    /// Upload a package to a remote Builder in parts, on behalf of a build. An upload of the same
+    /// archive that was interrupted before is resumed, sending only the parts Builder is missing.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * File cannot be read
+    /// * A part could not be uploaded after retrying it
     pub fn x_put_package(&self, pa: &mut PackageArchive, token: &str) -> Result<()> {
         let checksum = pa.checksum()?;
         let ident = pa.ident()?;
         let mut file = File::open(&pa.path)?;
         let file_size = file.metadata()?.len();
-        let path = package_path(&ident);
-        let custom = |url: &mut Url| {
-            url.query_pairs_mut()
-                .append_pair("checksum", &checksum)
-                .append_pair("builder", "");
-        };
         debug!("Reading from {}", &pa.path.display());
 
-        let result = self.add_authz(self.0.post_with_custom_url(&path, custom), token)
-            .body(Body::SizedBody(&mut file, file_size))
-            .send();
+        let upload = self.start_upload(&ident, &checksum, token)?;
+        let part_size = cmp::max(upload.part_size, 1);
+        let part_count = cmp::max((file_size + part_size - 1) / part_size, 1);
+        for part in 0..part_count {
+            if upload.parts.contains(&part) {
+                debug!("Part {} of {} was uploaded before", part, ident);
+                continue;
+            }
+            let mut content = Vec::new();
+            file.seek(SeekFrom::Start(part * part_size))?;
+            (&mut file).take(part_size).read_to_end(&mut content)?;
+            self.put_upload_part(&ident, &checksum, part, &content, token)?;
+        }
+
+        let path = package_upload_complete(&ident, &checksum);
+        let result = self.send_with(|api| {
+            let custom = |url: &mut Url| { url.query_pairs_mut().append_pair("builder", ""); };
+            self.add_authz(api.post_with_custom_url(&path, custom), token)
+                .send()
+        });
         match result {
             Ok(Response { status: StatusCode::Created, .. }) => Ok(()),
             Ok(response) => Err(err_from_response(response)),
@@ -748,6 +1005,54 @@ impl Client {
         }
     }
 
+    /// Starts a chunked upload of an archive, or looks up the one started before
+    fn start_upload<I>(&self, ident: &I, checksum: &str, token: &str) -> Result<UploadStatus>
+    where
+        I: Identifiable,
+    {
+        let path = package_uploads_path(ident);
+        let res = self.send_with(|api| {
+            let custom =
+                |url: &mut Url| { url.query_pairs_mut().append_pair("checksum", checksum); };
+            self.add_authz(api.post_with_custom_url(&path, custom), token)
+                .send()
+        })?;
+        match res.status {
+            StatusCode::Ok | StatusCode::Created => Ok(decoded_response(res)?),
+            _ => Err(err_from_response(res)),
+        }
+    }
+
+    /// Uploads a part of an archive
+    fn put_upload_part<I>(
+        &self,
+        ident: &I,
+        checksum: &str,
+        part: u64,
+        content: &[u8],
+        token: &str,
+    ) -> Result<()>
+    where
+        I: Identifiable,
+    {
+        let path = package_upload_part_path(ident, checksum, part);
+        let part_checksum = hash::hash_bytes(content);
+        debug!("Uploading part {} of {}", part, ident);
+        let result = self.send_with(|api| {
+            let mut part = self.throttled(content);
+            let custom =
+                |url: &mut Url| { url.query_pairs_mut().append_pair("checksum", &part_checksum); };
+            self.add_authz(api.put_with_custom_url(&path, custom), token)
+                .body(Body::SizedBody(&mut part, content.len() as u64))
+                .send()
+        });
+        match result {
+            Ok(Response { status: StatusCode::NoContent, .. }) => Ok(()),
+            Ok(response) => Err(err_from_response(response)),
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
     /// Promote a package to a given channel
     ///
     /// # Failures
@@ -768,7 +1073,7 @@ impl Client {
         let path = channel_package_promote(channel, ident);
         debug!("Promoting package {}", ident);
 
-        let res = self.add_authz(self.0.put(&path), token).send()?;
+        let res = self.send(|api| self.add_authz(api.put(&path), token))?;
 
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
@@ -797,15 +1102,263 @@ impl Client {
         let path = channel_package_demote(channel, ident);
         debug!("Demoting package {}", ident);
 
-        let res = self.add_authz(self.0.put(&path), token).send()?;
+        let res = self.send(|api| self.add_authz(api.put(&path), token))?;
+
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        };
+
+        Ok(())
+    }
+
+    /// Returns the contents of the public key file secrets of an origin are encrypted with.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Authorization token is not a member of the origin
+    pub fn fetch_origin_encryption_key(&self, origin: &str, token: &str) -> Result<String> {
+        let path = format!("depot/origins/{}/encryption_key", origin);
+        let mut res = self.send(|api| self.add_authz(api.get(&path), token))?;
+        debug!("Response: {:?}", res);
 
         if res.status != StatusCode::Ok {
             return Err(err_from_response(res));
         };
 
+        let mut key = String::new();
+        res.read_to_string(&mut key)?;
+        Ok(key)
+    }
+
+    /// Uploads a secret of an origin, whose value is already encrypted with the origin's
+    /// encryption key.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Authorization token is not the owner of the origin
+    /// * Value isn't encrypted with the latest encryption key of the origin
+    pub fn create_origin_secret(
+        &self,
+        origin: &str,
+        token: &str,
+        name: &str,
+        encrypted_value: &str,
+    ) -> Result<()> {
+        let path = format!("depot/origins/{}/secrets", origin);
+        let body = serde_json::to_string(&OriginSecretCreate {
+            name: name,
+            value: encrypted_value,
+        })?;
+        let res = self.send_non_idempotent(|api| {
+            self.add_authz(api.post(&path), token)
+                .body(&body)
+                .send()
+        })?;
+
+        if res.status != StatusCode::Created {
+            return Err(err_from_response(res));
+        };
+
         Ok(())
     }
 
+    /// Returns the names of the secrets of an origin
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Authorization token is not the owner of the origin
+    pub fn list_origin_secrets(&self, origin: &str, token: &str) -> Result<Vec<String>> {
+        let path = format!("depot/origins/{}/secrets", origin);
+        let mut res = self.send(|api| self.add_authz(api.get(&path), token))?;
+
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        };
+
+        let mut encoded = String::new();
+        res.read_to_string(&mut encoded)?;
+        let list: OriginSecretList = serde_json::from_str(&encoded)?;
+        Ok(list.secrets.into_iter().map(|s| s.name).collect())
+    }
+
+    /// Deletes a secret of an origin
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Authorization token is not the owner of the origin
+    pub fn delete_origin_secret(&self, origin: &str, token: &str, name: &str) -> Result<()> {
+        let path = format!("depot/origins/{}/secrets/{}", origin, name);
+        let res = self.send(|api| self.add_authz(api.delete(&path), token))?;
+
+        if res.status != StatusCode::NoContent {
+            return Err(err_from_response(res));
+        };
+
+        Ok(())
+    }
+
+    /// Replaces the encryption key of an origin with a new revision and re-encrypts its secrets
+    /// with it. Secrets encrypted to the Builder key by older releases are skipped.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Authorization token is not an admin of the origin
+    pub fn rotate_origin_encryption_key(
+        &self,
+        origin: &str,
+        token: &str,
+    ) -> Result<EncryptionKeyRotation> {
+        let path = format!("depot/origins/{}/encryption_key/rotate", origin);
+        let res = self.send_non_idempotent(
+            |api| self.add_authz(api.post(&path), token).send(),
+        )?;
+
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        };
+        decoded_response(res).map_err(Error::from)
+    }
+
+    /// Deprecates every public key revision of an origin older than its latest one, returning the
+    /// revisions which were deprecated.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Authorization token is not an admin of the origin
+    /// * Origin has no public keys
+    pub fn deprecate_origin_keys(
+        &self,
+        origin: &str,
+        token: &str,
+    ) -> Result<Vec<originsrv::OriginKeyIdent>> {
+        let path = format!("depot/origins/{}/keys/deprecate", origin);
+        let res = self.send_non_idempotent(
+            |api| self.add_authz(api.post(&path), token).send(),
+        )?;
+
+        if res.status != StatusCode::Ok {
+            return Err(err_from_response(res));
+        };
+        let keys: Vec<OriginKeyIdent> = decoded_response(res)?;
+        Ok(keys.into_iter().map(|k| k.into()).collect())
+    }
+
+    /// Export packages of an origin to a signed bundle, along with their transitive dependencies,
+    /// the channels they're in and the public keys of their origins. Packages which aren't fully
+    /// qualified are resolved to their latest release in `channel`, or at all.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * Authorization token is not an admin of the origin
+    /// * A package cannot be found
+    pub fn export_bundle(
+        &self,
+        origin: &str,
+        packages: &[String],
+        channel: Option<&str>,
+        target: Option<&str>,
+        token: &str,
+    ) -> Result<BundleExported> {
+        let path = format!("depot/origins/{}/exports", origin);
+        let body = serde_json::to_string(&BundleExport {
+            packages: packages,
+            channel: channel,
+        })?;
+        let res = self.send_non_idempotent(|api| {
+            let custom = |url: &mut Url| {
+                if let Some(target) = target {
+                    url.query_pairs_mut().append_pair("target", target);
+                }
+            };
+            self.add_authz(api.post_with_custom_url(&path, custom), token)
+                .body(&body)
+                .send()
+        })?;
+
+        if res.status != StatusCode::Created {
+            return Err(err_from_response(res));
+        };
+        decoded_response(res).map_err(Error::from)
+    }
+
+    /// Download a bundle exported from an origin to the given directory.
+    ///
+    /// # Failures
+    ///
+    /// * Bundle cannot be found, or expired
+    /// * Remote Builder is not available
+    /// * File cannot be created and written to
+    pub fn fetch_bundle<D, P: ?Sized>(
+        &self,
+        origin: &str,
+        checksum: &str,
+        token: &str,
+        dst_path: &P,
+        progress: Option<D>,
+    ) -> Result<PathBuf>
+    where
+        P: AsRef<Path>,
+        D: DisplayProgress + Sized,
+    {
+        let path = format!("depot/origins/{}/exports/{}", origin, checksum);
+        self.download(&path, dst_path.as_ref(), Some(token), progress)
+    }
+
+    /// Import a bundle into the origin whose key it's signed with.
+    ///
+    /// # Failures
+    ///
+    /// * Remote Builder is not available
+    /// * File cannot be read, or isn't a signed bundle
+    /// * Authorization token is not an admin of the origins in the bundle
+    /// * Public key the bundle is signed with was not uploaded
+    pub fn import_bundle<D>(
+        &self,
+        src_path: &Path,
+        token: &str,
+        progress: Option<D>,
+    ) -> Result<BundleImported>
+    where
+        D: DisplayProgress + Sized,
+    {
+        let (origin, _) = parse_name_with_rev(artifact::artifact_signer(&src_path)?)?;
+        let path = format!("depot/origins/{}/imports", origin);
+        let file_size = File::open(src_path)?.metadata()?.len();
+
+        let mut progress = progress;
+        let result = self.send_non_idempotent(|api| {
+            let mut file = self.throttled(File::open(src_path)?);
+            if let Some(ref mut progress) = progress {
+                progress.size(file_size);
+                let mut reader = TeeReader::new(file, progress);
+                self.add_authz(api.post(&path), token)
+                    .body(Body::SizedBody(&mut reader, file_size))
+                    .send()
+            } else {
+                self.add_authz(api.post(&path), token)
+                    .body(Body::SizedBody(&mut file, file_size))
+                    .send()
+            }
+        });
+        match result {
+            Ok(response) => {
+                if response.status == StatusCode::Ok {
+                    decoded_response(response).map_err(Error::from)
+                } else {
+                    Err(err_from_response(response))
+                }
+            }
+            Err(e) => Err(Error::from(e)),
+        }
+    }
+
     /// Create a custom channel
     ///
     /// # Failures
@@ -815,7 +1368,9 @@ impl Client {
         let path = format!("depot/channels/{}/{}", origin, channel);
         debug!("Creating channel, path: {:?}", path);
 
-        let res = self.add_authz(self.0.post(&path), token).send()?;
+        let res = self.send_non_idempotent(
+            |api| self.add_authz(api.post(&path), token).send(),
+        )?;
 
         if res.status != StatusCode::Created {
             return Err(err_from_response(res));
@@ -838,11 +1393,11 @@ impl Client {
         let mut res;
 
         if include_sandbox_channels {
-            res = self.0
-                .get_with_custom_url(&path, |url| url.set_query(Some("sandbox=true")))
-                .send()?;
+            res = self.send(|api| {
+                api.get_with_custom_url(&path, |url| url.set_query(Some("sandbox=true")))
+            })?;
         } else {
-            res = self.0.get(&path).send()?;
+            res = self.send(|api| api.get(&path))?;
         }
 
         match res.status {
@@ -858,6 +1413,42 @@ impl Client {
         }
     }
 
+    /// Return the channels of an origin along with how many packages are in each of them. Private
+    /// packages are only counted when the token is of a member of the origin.
+    ///
+    /// # Failures
+    /// * Remote Builder is not available
+    /// * Origin does not exist
+    pub fn list_channels_with_counts(
+        &self,
+        origin: &str,
+        include_sandbox_channels: bool,
+        token: Option<&str>,
+    ) -> Result<Vec<ChannelPackageCount>> {
+        let path = format!("depot/channels/{}", origin);
+        let query = if include_sandbox_channels {
+            "counts=true&sandbox=true"
+        } else {
+            "counts=true"
+        };
+        let mut res = self.send(|api| {
+            self.maybe_add_authz(
+                api.get_with_custom_url(&path, |url| url.set_query(Some(query))),
+                token,
+            )
+        })?;
+
+        match res.status {
+            StatusCode::Ok |
+            StatusCode::PartialContent => {
+                let mut encoded = String::new();
+                res.read_to_string(&mut encoded)?;
+                Ok(serde_json::from_str(&encoded)?)
+            }
+            _ => Err(err_from_response(res)),
+        }
+    }
+
     /// Returns a vector of PackageIdent structs
     ///
     /// # Failures
@@ -868,8 +1459,9 @@ impl Client {
         search_term: &str,
         token: Option<&str>,
     ) -> Result<(Vec<hab_core::package::PackageIdent>, bool)> {
-        let mut res = self.maybe_add_authz(self.0.get(&package_search(search_term)), token)
-            .send()?;
+        let mut res = self.send(|api| {
+            self.maybe_add_authz(api.get(&package_search(search_term)), token)
+        })?;
         match res.status {
             StatusCode::Ok |
             StatusCode::PartialContent => {
@@ -884,6 +1476,81 @@ impl Client {
         }
     }
 
+    /// The endpoint requests are sent to
+    fn active(&self) -> &Arc<ApiClient> {
+        &self.endpoints[self.active.load(Ordering::Relaxed)].1
+    }
+
+    /// Sends the request built by `request`, starting with the active endpoint and failing over
+    /// to the others when an endpoint can't be reached or answers with a server error. The
+    /// request is retried once every endpoint failed.
+    fn send<'a, F>(&'a self, request: F) -> hyper::Result<Response>
+    where
+        F: Fn(&'a ApiClient) -> RequestBuilder<'a>,
+    {
+        self.send_with(|api| request(api).send())
+    }
+
+    /// Sends a request with `attempt`, which is given the endpoint to send it to, failing over
+    /// and retrying like `send`. A request with a body builds it again for every attempt.
+    fn send_with<'a, F>(&'a self, mut attempt: F) -> hyper::Result<Response>
+    where
+        F: FnMut(&'a ApiClient) -> hyper::Result<Response>,
+    {
+        self.retry.run(|| self.fail_over(true, &mut attempt))
+    }
+
+    /// Sends a request which isn't idempotent with `attempt`. It only fails over to the next
+    /// endpoint, or is retried, when it couldn't be sent at all or Builder asked to slow down, as
+    /// Builder may have acted on it otherwise.
+    fn send_non_idempotent<'a, F>(&'a self, mut attempt: F) -> hyper::Result<Response>
+    where
+        F: FnMut(&'a ApiClient) -> hyper::Result<Response>,
+    {
+        self.retry.run_non_idempotent(|| self.fail_over(false, &mut attempt))
+    }
+
+    fn fail_over<'a, F>(&'a self, idempotent: bool, attempt: &mut F) -> hyper::Result<Response>
+    where
+        F: FnMut(&'a ApiClient) -> hyper::Result<Response>,
+    {
+        let active = self.active.load(Ordering::Relaxed);
+        let count = self.endpoints.len();
+        for i in 0..count {
+            let index = (active + i) % count;
+            let (ref url, ref api) = self.endpoints[index];
+            let is_last = i == count - 1;
+            match attempt(&**api) {
+                Ok(ref res) if res.status.is_server_error() && idempotent && !is_last => {
+                    warn!(
+                        "Builder at {} answered with {}, trying the next one",
+                        url,
+                        res.status
+                    );
+                }
+                Err(ref err) if (idempotent || retry::is_unsent(err)) && !is_last => {
+                    warn!(
+                        "Unable to reach Builder at {}, trying the next one, err={}",
+                        url,
+                        err
+                    );
+                }
+                result => {
+                    if result.is_ok() {
+                        self.active.store(index, Ordering::Relaxed);
+                    }
+                    return result;
+                }
+            }
+        }
+        unreachable!("a client always has at least one endpoint")
+    }
+
+    /// Throttles the transfers of `inner` with the throttle of the client, if it has one
+    fn throttled<T>(&self, inner: T) -> Throttled<T> {
+        Throttled::new(inner, self.throttle.clone())
+    }
+
     fn maybe_add_authz<'a>(
         &'a self,
         rb: RequestBuilder<'a>,
@@ -910,11 +1577,55 @@ impl Client {
     where
         D: DisplayProgress + Sized,
     {
-        let mut res = self.maybe_add_authz(self.0.get(path), token).send()?;
+        match self.download_unless_cached(path, dst_path, token, None, progress)? {
+            Some(dst_file_path) => Ok(dst_file_path),
+            None => unreachable!("only a cached archive is left undownloaded"),
+        }
+    }
+
+    /// Downloads a file to `dst_path`, unless it's the same as the archive at `cached`, which is
+    /// told by sending Builder its checksum. Returns the path of the downloaded file, or `None`
+    /// when the cached archive is the same. Builder is only asked once when there's a cached
+    /// archive, as it can be used in case Builder can't be reached.
+    fn download_unless_cached<'a, D>(
+        &'a self,
+        path: &str,
+        dst_path: &Path,
+        token: Option<&str>,
+        cached: Option<&Path>,
+        progress: Option<D>,
+    ) -> Result<Option<PathBuf>>
+    where
+        D: DisplayProgress + Sized,
+    {
+        let cached_checksum = match cached {
+            Some(cached) => Some(hash::hash_file(cached)?),
+            None => None,
+        };
+        // The first segment is asked for on its own, which tells the size of the archive and
+        // whether Builder serves ranges of it at all
+        let request = |api: &'a ApiClient| {
+            let rb = self.maybe_add_authz(api.get(path), token).header(
+                Range::bytes(0, DOWNLOAD_SEGMENT_SIZE - 1),
+            );
+            match cached_checksum {
+                Some(ref checksum) => rb.header(IfNoneMatch(checksum.clone())),
+                None => rb,
+            }
+        };
+        let res = if cached.is_some() {
+            self.fail_over(true, &mut |api: &'a ApiClient| request(api).send())?
+        } else {
+            self.send(request)?
+        };
 
         debug!("Response: {:?}", res);
+        if res.status == StatusCode::NotModified && cached.is_some() {
+            return Ok(None);
+        }
 
-        if res.status != hyper::status::StatusCode::Ok {
+        let partial = res.status == StatusCode::PartialContent;
+        if res.status != StatusCode::Ok && !partial {
             return Err(err_from_response(res));
         }
         fs::create_dir_all(&dst_path)?;
@@ -929,20 +1640,42 @@ impl Client {
             thread_rng().gen_ascii_chars().take(8).collect::<String>()
         ));
         let dst_file_path = dst_path.join(file_name);
+        let checksum = res.headers.get::<ETag>().map(|etag| etag.to_string());
+        let size = match res.headers.get::<ContentRange>() {
+            Some(&ContentRange(ContentRangeSpec::Bytes { instance_length: Some(size), .. }))
+                if partial => size,
+            _ => res.headers.get::<ContentLength>().map_or(0, |v| **v),
+        };
         debug!("Writing to {}", &tmp_file_path.display());
         let mut f = File::create(&tmp_file_path)?;
-        match progress {
-            Some(mut progress) => {
-                let size: u64 = res.headers.get::<hyper::header::ContentLength>().map_or(
-                    0,
-                    |v| **v,
-                );
+        let mut body = self.throttled(res);
+        let mut progress = progress;
+        let mut written = match progress {
+            Some(ref mut progress) => {
                 progress.size(size);
                 let mut writer = BroadcastWriter::new(&mut f, progress);
-                io::copy(&mut res, &mut writer)?
+                io::copy(&mut body, &mut writer)?
             }
-            None => io::copy(&mut res, &mut f)?,
+            None => io::copy(&mut body, &mut f)?,
         };
+        if partial && written < size {
+            written += self.download_segments(path, token, written, size, &mut f, &mut progress)?;
+        }
+        if partial && written != size {
+            fs::remove_file(&tmp_file_path)?;
+            return Err(Error::DownloadFailed(
+                format!("received {} of {} bytes", written, size),
+            ));
+        }
+        if let Some(checksum) = checksum {
+            if hash::hash_file(&tmp_file_path)? != checksum {
+                fs::remove_file(&tmp_file_path)?;
+                return Err(Error::DownloadFailed(format!(
+                    "checksum of {} does not match",
+                    dst_file_path.display()
+                )));
+            }
+        }
         debug!(
             "Moving {} to {}",
             &tmp_file_path.display(),
@@ -952,12 +1685,67 @@ impl Client {
         Ok(dst_file_path)
     }
 
+    /// Downloads the bytes of an archive from `start` up to `size` over several connections at
+    /// once, writing them to `f` as they arrive. Returns how many bytes were written.
+    fn download_segments<D>(
+        &self,
+        path: &str,
+        token: Option<&str>,
+        start: u64,
+        size: u64,
+        f: &mut File,
+        progress: &mut Option<D>,
+    ) -> Result<u64>
+    where
+        D: DisplayProgress + Sized,
+    {
+        let segment_size = cmp::max((size - start + DOWNLOAD_STREAMS - 1) / DOWNLOAD_STREAMS, 1);
+        let (tx, rx) = mpsc::channel();
+        let mut streams = Vec::new();
+        let mut first = start;
+        while first < size {
+            let end = cmp::min(first + segment_size, size);
+            // Segments are fetched from the mirror which answered the first request, since the
+            // others may hold a different artifact under the same name
+            let client = self.active().clone();
+            let retry = self.retry.clone();
+            let throttle = self.throttle.clone();
+            let path = path.to_string();
+            let token = token.map(|token| token.to_string());
+            let tx = tx.clone();
+            streams.push(thread::spawn(move || {
+                fetch_range(&client, &retry, throttle, &path, token, first, end, tx)
+                    .map_err(|e| e.to_string())
+            }));
+            first = end;
+        }
+        drop(tx);
+
+        let mut written = 0;
+        for (offset, chunk) in rx {
+            f.seek(SeekFrom::Start(offset))?;
+            f.write_all(&chunk)?;
+            if let Some(ref mut progress) = *progress {
+                progress.write_all(&chunk)?;
+            }
+            written += chunk.len() as u64;
+        }
+        for stream in streams {
+            match stream.join() {
+                Ok(Ok(())) => (),
+                Ok(Err(e)) => return Err(Error::DownloadFailed(e)),
+                Err(_) => return Err(Error::DownloadFailed("download stream panicked".to_string())),
+            }
+        }
+        Ok(written)
+    }
+
     // TODO: Ideally we would have a single download function that can support
     // both progress and non-progress versions, however the Rust compiler cannot
     // infer the type for a None for a Display + Sized trait, and makes this task
     // much more difficult than it should be. Fix later.
     fn x_download(&self, path: &str, dst_path: &Path, token: &str) -> Result<PathBuf> {
-        let mut res = self.add_authz(self.0.get(path), token).send()?;
+        let res = self.send(|api| self.add_authz(api.get(path), token))?;
         debug!("Response: {:?}", res);
 
         if res.status != hyper::status::StatusCode::Ok {
@@ -977,7 +1765,7 @@ impl Client {
         let dst_file_path = dst_path.join(file_name);
         debug!("Writing to {}", &tmp_file_path.display());
         let mut f = File::create(&tmp_file_path)?;
-        io::copy(&mut res, &mut f)?;
+        io::copy(&mut self.throttled(res), &mut f)?;
 
         debug!(
             "Moving {} to {}",
@@ -989,6 +1777,69 @@ impl Client {
     }
 }
 
+/// Applies the delta at `delta_path` to the archive at `from_path`, writing the archive it makes
+/// next to the delta under the name of the delta without its extension
+fn apply_delta(from_path: &Path, delta_path: &Path) -> Result<PathBuf> {
+    let dst_file_path = delta_path.with_extension("");
+    let tmp_file_path = dst_file_path.with_extension(format!(
+        "tmp-{}",
+        thread_rng().gen_ascii_chars().take(8).collect::<String>()
+    ));
+    let mut old = Vec::new();
+    File::open(from_path)?.read_to_end(&mut old)?;
+    let mut new = File::create(&tmp_file_path)?;
+    let mut delta = File::open(delta_path)?;
+    if let Err(err) = bsdiff::patch(&old, &mut delta, &mut new) {
+        fs::remove_file(&tmp_file_path)?;
+        return Err(Error::DownloadFailed(format!(
+            "unable to apply delta to {}, {}",
+            from_path.display(),
+            err
+        )));
+    }
+    fs::rename(&tmp_file_path, &dst_file_path)?;
+    Ok(dst_file_path)
+}
+
+/// Fetches the bytes of a download from `start` up to `end`, sending them on along with the
+/// offset they start at.
+fn fetch_range(
+    client: &ApiClient,
+    retry: &RetryPolicy,
+    throttle: Option<Throttle>,
+    path: &str,
+    token: Option<String>,
+    start: u64,
+    end: u64,
+    tx: Sender<(u64, Vec<u8>)>,
+) -> Result<()> {
+    let mut res = retry.run(|| {
+        let mut rb = client.get(path).header(Range::bytes(start, end - 1));
+        if let Some(ref token) = token {
+            rb = rb.header(Authorization(Bearer { token: token.clone() }));
+        }
+        rb.send()
+    })?;
+    if res.status != StatusCode::PartialContent {
+        return Err(err_from_response(res));
+    }
+    let mut res = Throttled::new(res, throttle);
+    let mut offset = start;
+    let mut buf = vec![0; 64 * 1024];
+    loop {
+        let len = res.read(&mut buf)?;
+        if len == 0 {
+            break;
+        }
+        // Nobody is waiting for the bytes anymore once the download failed
+        if tx.send((offset, buf[..len].to_vec())).is_err() {
+            return Ok(());
+        }
+        offset += len as u64;
+    }
+    Ok(())
+}
+
 fn err_from_response(mut response: hyper::client::Response) -> Error {
     if response.status == StatusCode::Unauthorized {
         return Error::APIError(
@@ -1027,6 +1878,18 @@ where
     format!("{}/download", package_path(package))
 }
 
+fn package_delta<I>(package: &I, from: &I) -> String
+where
+    I: Identifiable,
+{
+    format!(
+        "{}/delta/{}/{}",
+        package_path(package),
+        from.version().unwrap(),
+        from.release().unwrap()
+    )
+}
+
 fn package_path<I>(package: &I) -> String
 where
     I: Identifiable,
@@ -1034,6 +1897,27 @@ where
     format!("depot/pkgs/{}", package)
 }
 
+fn package_uploads_path<I>(package: &I) -> String
+where
+    I: Identifiable,
+{
+    format!("{}/uploads", package_path(package))
+}
+
+fn package_upload_part_path<I>(package: &I, checksum: &str, part: u64) -> String
+where
+    I: Identifiable,
+{
+    format!("{}/{}/parts/{}", package_uploads_path(package), checksum, part)
+}
+
+fn package_upload_complete<I>(package: &I, checksum: &str) -> String
+where
+    I: Identifiable,
+{
+    format!("{}/{}/complete", package_uploads_path(package), checksum)
+}
+
 fn package_search(term: &str) -> String {
     let encoded_term = percent_encode(term.as_bytes(), PATH_SEGMENT_ENCODE_SET);
     format!("depot/pkgs/search/{}", encoded_term)
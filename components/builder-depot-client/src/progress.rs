@@ -0,0 +1,190 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reports the progress of downloads and uploads.
+//!
+//! Transfers write the bytes they move to a `DisplayProgress`, after telling it how many there
+//! are in total. Instead of implementing it themselves, callers which only want to know how far
+//! along a transfer is can hand a `Progress` callback to a `ProgressTracker`, which counts the
+//! bytes and works out the rate they're moving at.
+
+use std::io::{self, Write};
+use std::time::Instant;
+
+/// Receives the bytes of a transfer as they're moved
+pub trait DisplayProgress: Write {
+    fn size(&mut self, size: u64);
+    fn finish(&mut self);
+}
+
+/// How far along a transfer is
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Transfer {
+    /// Bytes moved so far
+    pub transferred: u64,
+    /// Bytes to move in total, or 0 when unknown
+    pub total: u64,
+    /// Bytes moved per second since the transfer started
+    pub rate: f64,
+}
+
+/// A callback told about the progress of a transfer
+pub trait Progress {
+    /// Called whenever more bytes were moved
+    fn update(&mut self, transfer: &Transfer);
+
+    /// Called once every byte was moved
+    fn finish(&mut self, _transfer: &Transfer) {}
+}
+
+impl<F> Progress for F
+where
+    F: FnMut(&Transfer),
+{
+    fn update(&mut self, transfer: &Transfer) {
+        self(transfer)
+    }
+}
+
+/// Tells a `Progress` callback about a transfer it's given as its `DisplayProgress`
+pub struct ProgressTracker<P: Progress> {
+    callback: P,
+    transferred: u64,
+    total: u64,
+    started: Instant,
+}
+
+impl<P: Progress> ProgressTracker<P> {
+    pub fn new(callback: P) -> Self {
+        ProgressTracker {
+            callback: callback,
+            transferred: 0,
+            total: 0,
+            started: Instant::now(),
+        }
+    }
+
+    /// Returns the callback, once the transfer is done
+    pub fn into_inner(self) -> P {
+        self.callback
+    }
+
+    fn transfer(&self) -> Transfer {
+        let elapsed = self.started.elapsed();
+        let secs = elapsed.as_secs() as f64 + elapsed.subsec_nanos() as f64 / 1_000_000_000.0;
+        Transfer {
+            transferred: self.transferred,
+            total: self.total,
+            rate: if secs > 0.0 {
+                self.transferred as f64 / secs
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+impl<P: Progress> DisplayProgress for ProgressTracker<P> {
+    /// Starts over, as a transfer which is retried is sized again
+    fn size(&mut self, size: u64) {
+        self.total = size;
+        self.transferred = 0;
+        self.started = Instant::now();
+    }
+
+    fn finish(&mut self) {
+        let transfer = self.transfer();
+        self.callback.finish(&transfer);
+    }
+}
+
+impl<P: Progress> Write for ProgressTracker<P> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.transferred += buf.len() as u64;
+        let transfer = self.transfer();
+        self.callback.update(&transfer);
+        if self.total > 0 && self.transferred == self.total {
+            self.finish();
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Discards the progress of a transfer nobody is watching
+pub struct NoProgress;
+
+impl DisplayProgress for NoProgress {
+    fn size(&mut self, _size: u64) {}
+    fn finish(&mut self) {}
+}
+
+impl Write for NoProgress {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        updates: Vec<(u64, u64)>,
+        finished: Option<u64>,
+    }
+
+    impl Progress for Recorder {
+        fn update(&mut self, transfer: &Transfer) {
+            self.updates.push((transfer.transferred, transfer.total));
+        }
+
+        fn finish(&mut self, transfer: &Transfer) {
+            self.finished = Some(transfer.transferred);
+        }
+    }
+
+    #[test]
+    fn tracks_transfer() {
+        let mut tracker = ProgressTracker::new(Recorder::default());
+        tracker.size(6);
+        tracker.write_all(b"abc").unwrap();
+        assert_eq!(tracker.callback.finished, None);
+        tracker.write_all(b"def").unwrap();
+        let recorder = tracker.into_inner();
+        assert_eq!(recorder.updates, vec![(3, 6), (6, 6)]);
+        assert_eq!(recorder.finished, Some(6));
+    }
+
+    #[test]
+    fn closure_callback() {
+        let mut seen = 0;
+        {
+            let mut tracker = ProgressTracker::new(|transfer: &Transfer| {
+                seen = transfer.transferred
+            });
+            tracker.size(10);
+            tracker.write_all(b"four").unwrap();
+        }
+        assert_eq!(seen, 4);
+    }
+}
@@ -0,0 +1,357 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Retries requests to Builder which failed for reasons that may go away by themselves.
+//!
+//! A request is retried when Builder can't be reached, answers with a server error or asks to
+//! slow down, waiting longer before every retry. The delay doubles with every retry up to a
+//! limit, and a random part of it is dropped, so that clients which failed together don't all
+//! come back at the same time. The policy of a client is read from the environment, falling back
+//! to the defaults for the settings which aren't given.
+//!
+//! Requests which aren't idempotent, like the ones scheduling a build or rotating a key, are only
+//! sent again when Builder asked to slow down or couldn't be connected to at all. Builder may have
+//! acted on them even though it answered with a server error, or the connection broke while
+//! waiting for its answer.
+
+use std::cmp;
+use std::io;
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use hab_core::env;
+use hyper;
+use hyper::client::Response;
+use hyper::status::StatusCode;
+use rand::{Rng, thread_rng};
+
+/// How many times a failed request is retried
+pub const RETRIES_ENVVAR: &'static str = "HAB_BLDR_RETRIES";
+/// Delay in milliseconds before the first retry
+pub const BACKOFF_ENVVAR: &'static str = "HAB_BLDR_RETRY_BACKOFF_MS";
+/// Longest delay in milliseconds between two attempts
+pub const MAX_BACKOFF_ENVVAR: &'static str = "HAB_BLDR_RETRY_MAX_BACKOFF_MS";
+/// Fraction of a delay which is random, between 0 and 1
+pub const JITTER_ENVVAR: &'static str = "HAB_BLDR_RETRY_JITTER";
+/// Comma-separated classes of failures which are retried
+pub const RETRY_ON_ENVVAR: &'static str = "HAB_BLDR_RETRY_ON";
+
+/// Classes of failures a request is retried on
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetryOn {
+    /// Builder couldn't be reached, or the connection to it broke
+    Connect,
+    /// Builder answered with a server error
+    ServerError,
+    /// Builder answered with `429 Too Many Requests`
+    Throttled,
+}
+
+impl FromStr for RetryOn {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim() {
+            "connect" => Ok(RetryOn::Connect),
+            "server_error" => Ok(RetryOn::ServerError),
+            "throttled" => Ok(RetryOn::Throttled),
+            _ => Err(format!("unknown failure class {}", value)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    /// How many times a failed request is sent again before giving up
+    pub retries: u32,
+    /// Delay in milliseconds before the first retry, doubled for every retry after it
+    pub backoff_ms: u64,
+    /// Longest delay in milliseconds between two attempts
+    pub max_backoff_ms: u64,
+    /// Fraction of every delay which is dropped at random, between 0 and 1
+    pub jitter: f64,
+    pub retry_on: Vec<RetryOn>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            retries: 4,
+            backoff_ms: 500,
+            max_backoff_ms: 30_000,
+            jitter: 0.5,
+            retry_on: vec![RetryOn::Connect, RetryOn::ServerError, RetryOn::Throttled],
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy which never retries
+    pub fn none() -> Self {
+        RetryPolicy {
+            retries: 0,
+            ..RetryPolicy::default()
+        }
+    }
+
+    /// The default policy with the settings given in the environment. Settings which can't be
+    /// parsed are ignored.
+    pub fn from_env() -> Self {
+        let mut policy = RetryPolicy::default();
+        if let Some(retries) = parse_var(RETRIES_ENVVAR) {
+            policy.retries = retries;
+        }
+        if let Some(backoff) = parse_var(BACKOFF_ENVVAR) {
+            policy.backoff_ms = backoff;
+        }
+        if let Some(max_backoff) = parse_var(MAX_BACKOFF_ENVVAR) {
+            policy.max_backoff_ms = max_backoff;
+        }
+        if let Some(jitter) = parse_var::<f64>(JITTER_ENVVAR) {
+            if jitter >= 0.0 && jitter <= 1.0 {
+                policy.jitter = jitter;
+            } else {
+                warn!("Ignoring {}={}, it must be between 0 and 1", JITTER_ENVVAR, jitter);
+            }
+        }
+        if let Ok(value) = env::var(RETRY_ON_ENVVAR) {
+            match value.split(',').map(RetryOn::from_str).collect() {
+                Ok(retry_on) => policy.retry_on = retry_on,
+                Err(e) => warn!("Ignoring {}={}, {}", RETRY_ON_ENVVAR, value, e),
+            }
+        }
+        policy
+    }
+
+    /// Sends a request with `attempt` until it succeeds, fails in a way which isn't retried or
+    /// runs out of retries. Returns the result of the last attempt.
+    pub fn run<F>(&self, attempt: F) -> hyper::Result<Response>
+    where
+        F: FnMut() -> hyper::Result<Response>,
+    {
+        self.run_with(true, attempt)
+    }
+
+    /// Like `run`, for requests which aren't idempotent. Those are only sent again when Builder
+    /// asked to slow down, or when they couldn't be sent at all.
+    pub fn run_non_idempotent<F>(&self, attempt: F) -> hyper::Result<Response>
+    where
+        F: FnMut() -> hyper::Result<Response>,
+    {
+        self.run_with(false, attempt)
+    }
+
+    fn run_with<T, F>(&self, idempotent: bool, mut attempt: F) -> hyper::Result<T>
+    where
+        T: Answer,
+        F: FnMut() -> hyper::Result<T>,
+    {
+        let mut retry = 0;
+        loop {
+            let result = attempt();
+            let retried = match result {
+                Ok(ref res) if idempotent => self.retries_status(res.status()),
+                Ok(ref res) => self.retries_non_idempotent_status(res.status()),
+                Err(ref err) if idempotent => self.retries_error(err),
+                Err(ref err) => self.retries_non_idempotent_error(err),
+            };
+            if !retried || retry >= self.retries {
+                return result;
+            }
+            retry += 1;
+            let delay = self.delay(retry, thread_rng().gen::<f64>());
+            match result {
+                Ok(ref res) => {
+                    warn!(
+                        "Builder answered with {}, retry {} of {} in {}ms",
+                        res.status(),
+                        retry,
+                        self.retries,
+                        delay
+                    )
+                }
+                Err(ref err) => {
+                    warn!(
+                        "Unable to reach Builder, retry {} of {} in {}ms, err={}",
+                        retry,
+                        self.retries,
+                        delay,
+                        err
+                    )
+                }
+            }
+            thread::sleep(Duration::from_millis(delay));
+        }
+    }
+
+    /// Whether a request Builder answered with `status` is retried
+    pub fn retries_status(&self, status: StatusCode) -> bool {
+        if status == StatusCode::TooManyRequests {
+            self.retry_on.contains(&RetryOn::Throttled)
+        } else {
+            status.is_server_error() && self.retry_on.contains(&RetryOn::ServerError)
+        }
+    }
+
+    /// Whether a request which couldn't be sent because of `err` is retried
+    pub fn retries_error(&self, err: &hyper::Error) -> bool {
+        match *err {
+            hyper::Error::Io(_) => self.retry_on.contains(&RetryOn::Connect),
+            _ => false,
+        }
+    }
+
+    /// Whether a request which isn't idempotent and which Builder answered with `status` is
+    /// retried
+    pub fn retries_non_idempotent_status(&self, status: StatusCode) -> bool {
+        status == StatusCode::TooManyRequests && self.retry_on.contains(&RetryOn::Throttled)
+    }
+
+    /// Whether a request which isn't idempotent and which couldn't be sent because of `err` is
+    /// retried
+    pub fn retries_non_idempotent_error(&self, err: &hyper::Error) -> bool {
+        is_unsent(err) && self.retry_on.contains(&RetryOn::Connect)
+    }
+
+    /// Delay in milliseconds before the given retry, counting from 1, where `random` is between 0
+    /// and 1
+    fn delay(&self, retry: u32, random: f64) -> u64 {
+        let exponent = cmp::min(retry.saturating_sub(1), 32);
+        let backoff = cmp::min(self.backoff_ms.saturating_mul(1 << exponent), self.max_backoff_ms);
+        backoff - (backoff as f64 * self.jitter * random) as u64
+    }
+}
+
+/// Whether a request failed before any of it was written, as Builder couldn't be connected to
+pub fn is_unsent(err: &hyper::Error) -> bool {
+    match *err {
+        hyper::Error::Io(ref err) => {
+            match err.kind() {
+                io::ErrorKind::ConnectionRefused |
+                io::ErrorKind::AddrNotAvailable => true,
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// The answer to an attempt to send a request
+trait Answer {
+    fn status(&self) -> StatusCode;
+}
+
+impl Answer for Response {
+    fn status(&self) -> StatusCode {
+        self.status
+    }
+}
+
+fn parse_var<T: FromStr>(name: &str) -> Option<T> {
+    match env::var(name) {
+        Ok(value) => {
+            match value.parse() {
+                Ok(parsed) => Some(parsed),
+                Err(_) => {
+                    warn!("Ignoring {}={}, it isn't a valid number", name, value);
+                    None
+                }
+            }
+        }
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays() {
+        let policy = RetryPolicy {
+            backoff_ms: 500,
+            max_backoff_ms: 3_000,
+            jitter: 0.5,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.delay(1, 0.0), 500);
+        assert_eq!(policy.delay(2, 0.0), 1_000);
+        assert_eq!(policy.delay(3, 0.0), 2_000);
+        assert_eq!(policy.delay(4, 0.0), 3_000);
+        assert_eq!(policy.delay(40, 0.0), 3_000);
+        assert_eq!(policy.delay(2, 1.0), 500);
+        assert_eq!(policy.delay(2, 0.5), 750);
+    }
+
+    #[test]
+    fn retried_failures() {
+        let policy = RetryPolicy {
+            retry_on: vec![RetryOn::ServerError],
+            ..RetryPolicy::default()
+        };
+        assert!(policy.retries_status(StatusCode::ServiceUnavailable));
+        assert!(!policy.retries_status(StatusCode::TooManyRequests));
+        assert!(!policy.retries_status(StatusCode::NotFound));
+        assert!(!policy.retries_error(&hyper::Error::Io(
+            ::std::io::Error::new(::std::io::ErrorKind::ConnectionRefused, "refused"),
+        )));
+        assert!(RetryPolicy::default().retries_status(StatusCode::TooManyRequests));
+        assert_eq!("throttled".parse(), Ok(RetryOn::Throttled));
+        assert!("sometimes".parse::<RetryOn>().is_err());
+    }
+
+    impl Answer for StatusCode {
+        fn status(&self) -> StatusCode {
+            *self
+        }
+    }
+
+    fn attempts(idempotent: bool, result: fn() -> hyper::Result<StatusCode>) -> u32 {
+        let policy = RetryPolicy {
+            retries: 2,
+            backoff_ms: 0,
+            ..RetryPolicy::default()
+        };
+        let mut attempts = 0;
+        let _ = policy.run_with(idempotent, || {
+            attempts += 1;
+            result()
+        });
+        attempts
+    }
+
+    fn refused() -> hyper::Result<StatusCode> {
+        Err(hyper::Error::Io(
+            io::Error::new(io::ErrorKind::ConnectionRefused, "refused"),
+        ))
+    }
+
+    fn reset() -> hyper::Result<StatusCode> {
+        Err(hyper::Error::Io(
+            io::Error::new(io::ErrorKind::ConnectionReset, "reset"),
+        ))
+    }
+
+    #[test]
+    fn non_idempotent_requests_are_not_resent_after_server_errors() {
+        assert_eq!(attempts(true, || Ok(StatusCode::ServiceUnavailable)), 3);
+        assert_eq!(attempts(false, || Ok(StatusCode::ServiceUnavailable)), 1);
+        assert_eq!(attempts(false, || Ok(StatusCode::TooManyRequests)), 3);
+        assert_eq!(attempts(false, || Ok(StatusCode::Created)), 1);
+        assert_eq!(attempts(true, reset), 3);
+        assert_eq!(attempts(false, reset), 1);
+        assert_eq!(attempts(false, refused), 3);
+    }
+}
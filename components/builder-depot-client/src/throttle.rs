@@ -0,0 +1,234 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Limits the rate at which a client transfers archives to and from Builder.
+//!
+//! Every transfer of a client takes from one shared allowance, so that downloads split over
+//! several connections don't go faster together than the rate given. A transfer waits once it
+//! got ahead of the rate, which is kept up over the whole time the client transfers. Time spent
+//! without transferring only counts for up to a second, so that a transfer can't burst after
+//! being idle for long. The rate of a client is read from the environment, and transfers aren't
+//! limited when it isn't given.
+
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use hab_core::env;
+
+/// Most bytes transferred per second, such as `5MB/s`
+pub const THROTTLE_ENVVAR: &'static str = "HAB_BLDR_THROTTLE";
+
+/// How long idle time counts for at most
+const MAX_IDLE_SECS: u64 = 1;
+
+/// A transfer rate in bytes per second
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rate(pub u64);
+
+impl FromStr for Rate {
+    type Err = String;
+
+    /// Parses a number of bytes per second, which may be followed by a unit of `B`, `KB`, `MB`
+    /// or `GB`, or `KiB`, `MiB` or `GiB` for powers of 1024, and `/s`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let rate = value.trim();
+        let rate = rate.trim_right_matches("/s").trim_right_matches("/S");
+        let split = rate.find(|c: char| !c.is_digit(10) && c != '.').unwrap_or(
+            rate.len(),
+        );
+        let (number, unit) = rate.split_at(split);
+        let multiplier = match unit.trim().to_lowercase().as_str() {
+            "" | "b" => 1,
+            "k" | "kb" => 1_000,
+            "m" | "mb" => 1_000_000,
+            "g" | "gb" => 1_000_000_000,
+            "kib" => 1 << 10,
+            "mib" => 1 << 20,
+            "gib" => 1 << 30,
+            _ => return Err(format!("unknown unit in transfer rate {}", value)),
+        };
+        let number = number.parse::<f64>().map_err(|_| {
+            format!("transfer rate {} isn't a number of bytes per second", value)
+        })?;
+        let rate = (number * multiplier as f64) as u64;
+        if rate == 0 {
+            return Err(format!("transfer rate {} must be more than 0", value));
+        }
+        Ok(Rate(rate))
+    }
+}
+
+impl fmt::Display for Rate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}B/s", self.0)
+    }
+}
+
+/// The allowance shared by every transfer of a client
+#[derive(Clone, Debug)]
+pub struct Throttle {
+    rate: Rate,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    /// When the rate started to be kept up
+    since: Instant,
+    /// Bytes transferred since then
+    transferred: u64,
+}
+
+impl Throttle {
+    pub fn new(rate: Rate) -> Self {
+        Throttle {
+            rate: rate,
+            bucket: Arc::new(Mutex::new(Bucket {
+                since: Instant::now(),
+                transferred: 0,
+            })),
+        }
+    }
+
+    /// A throttle for the rate given in the environment, if any. A rate which can't be parsed is
+    /// ignored.
+    pub fn from_env() -> Option<Self> {
+        match env::var(THROTTLE_ENVVAR) {
+            Ok(value) => {
+                match value.parse() {
+                    Ok(rate) => Some(Throttle::new(rate)),
+                    Err(e) => {
+                        warn!("Ignoring {}={}, {}", THROTTLE_ENVVAR, value, e);
+                        None
+                    }
+                }
+            }
+            Err(_) => None,
+        }
+    }
+
+    pub fn rate(&self) -> Rate {
+        self.rate
+    }
+
+    /// Counts `bytes` as transferred, waiting for as long as the transfers got ahead of the rate
+    pub fn consume(&self, bytes: u64) {
+        let delay = {
+            let mut bucket = self.bucket.lock().expect("Throttle lock poisoned");
+            let now = Instant::now();
+            let elapsed = now.duration_since(bucket.since);
+            let idle = elapsed.checked_sub(duration_of(bucket.transferred, self.rate));
+            if idle.map_or(false, |idle| idle > Duration::from_secs(MAX_IDLE_SECS)) {
+                bucket.since = now - Duration::from_secs(MAX_IDLE_SECS);
+                bucket.transferred = 0;
+            }
+            bucket.transferred += bytes;
+            delay(
+                bucket.transferred,
+                self.rate,
+                now.duration_since(bucket.since),
+            )
+        };
+        if let Some(delay) = delay {
+            thread::sleep(delay);
+        }
+    }
+}
+
+/// A reader or writer whose transfers are throttled, if there's a throttle
+pub struct Throttled<T> {
+    inner: T,
+    throttle: Option<Throttle>,
+}
+
+impl<T> Throttled<T> {
+    pub fn new(inner: T, throttle: Option<Throttle>) -> Self {
+        Throttled {
+            inner: inner,
+            throttle: throttle,
+        }
+    }
+
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Read> Read for Throttled<T> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        if let Some(ref throttle) = self.throttle {
+            throttle.consume(len as u64);
+        }
+        Ok(len)
+    }
+}
+
+impl<T: Write> Write for Throttled<T> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = self.inner.write(buf)?;
+        if let Some(ref throttle) = self.throttle {
+            throttle.consume(len as u64);
+        }
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// How long transferring `bytes` takes at `rate`
+fn duration_of(bytes: u64, rate: Rate) -> Duration {
+    let nanos = (bytes % rate.0) * 1_000_000_000 / rate.0;
+    Duration::new(bytes / rate.0, nanos as u32)
+}
+
+/// How long to wait once `transferred` bytes went by in `elapsed`, if they got ahead of `rate`
+fn delay(transferred: u64, rate: Rate, elapsed: Duration) -> Option<Duration> {
+    let due = duration_of(transferred, rate);
+    if due > elapsed { Some(due - elapsed) } else { None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rates() {
+        assert_eq!("5MB/s".parse(), Ok(Rate(5_000_000)));
+        assert_eq!("512 KiB/s".parse(), Ok(Rate(512 * 1024)));
+        assert_eq!("1.5mb".parse(), Ok(Rate(1_500_000)));
+        assert_eq!("2048".parse(), Ok(Rate(2048)));
+        assert!("0MB/s".parse::<Rate>().is_err());
+        assert!("fast".parse::<Rate>().is_err());
+        assert!("5 parsecs".parse::<Rate>().is_err());
+    }
+
+    #[test]
+    fn delays() {
+        let rate = Rate(1_000);
+        assert_eq!(duration_of(2_500, rate), Duration::from_millis(2_500));
+        assert_eq!(
+            delay(2_000, rate, Duration::from_millis(500)),
+            Some(Duration::from_millis(1_500))
+        );
+        assert_eq!(delay(2_000, rate, Duration::from_secs(2)), None);
+        assert_eq!(delay(2_000, rate, Duration::from_secs(3)), None);
+    }
+}
@@ -0,0 +1,205 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bundles of packages for mirroring Builder content to sites without network access to it.
+//!
+//! A bundle is a tarball holding the archives of the packages exported along with their
+//! transitive dependencies, the public keys of the origins of those packages and a manifest
+//! recording the channels each package is in. The tarball is signed with the secret signing key
+//! of the origin it's exported from, in the same format as package archives, so that the Builder
+//! importing it can check it with the origin's public key before trusting anything in it.
+
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use hab_core::crypto::{artifact, hash, SigKeyPair};
+use hab_core::crypto::keys::parse_name_with_rev;
+use serde_json;
+use tar;
+
+use error::{Error, Result};
+
+/// Name of the manifest within a bundle
+const MANIFEST: &'static str = "MANIFEST.json";
+
+/// What a bundle holds
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Manifest {
+    /// The origin the bundle was exported from and signed by
+    pub origin: String,
+    /// Packages in the bundle, dependencies before the packages depending on them
+    pub packages: Vec<BundledPackage>,
+    pub keys: Vec<BundledKey>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundledPackage {
+    pub ident: String,
+    pub target: String,
+    pub checksum: String,
+    /// Channels the package is in, other than `unstable`
+    pub channels: Vec<String>,
+}
+
+impl BundledPackage {
+    fn path(&self) -> Result<PathBuf> {
+        if self.checksum.is_empty() || !self.checksum.chars().all(|c| c.is_digit(16)) {
+            return Err(Error::InvalidBundle(
+                format!("invalid checksum for {}", self.ident),
+            ));
+        }
+        Ok(Path::new("pkgs").join(format!("{}.hart", self.checksum)))
+    }
+}
+
+/// A public origin key
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BundledKey {
+    pub origin: String,
+    pub revision: String,
+}
+
+impl BundledKey {
+    fn path(&self) -> Result<PathBuf> {
+        let valid = |part: &str| !part.is_empty() && !part.contains('/') && !part.contains("..");
+        if !valid(&self.origin) || !valid(&self.revision) {
+            return Err(Error::InvalidBundle(
+                format!("invalid key {}-{}", self.origin, self.revision),
+            ));
+        }
+        Ok(Path::new("keys").join(
+            format!("{}-{}.pub", self.origin, self.revision),
+        ))
+    }
+}
+
+/// Writes the tarball of a bundle, which is signed once everything was added to it.
+pub struct BundleWriter {
+    tar: tar::Builder<BufWriter<File>>,
+    tar_path: PathBuf,
+    manifest: Manifest,
+}
+
+impl BundleWriter {
+    /// Starts a bundle of the given origin, whose tarball is written to `tar_path`
+    pub fn new(origin: &str, tar_path: &Path) -> Result<BundleWriter> {
+        let file = File::create(tar_path)?;
+        Ok(BundleWriter {
+            tar: tar::Builder::new(BufWriter::new(file)),
+            tar_path: tar_path.to_path_buf(),
+            manifest: Manifest {
+                origin: origin.to_string(),
+                ..Manifest::default()
+            },
+        })
+    }
+
+    pub fn add_package(&mut self, package: BundledPackage, archive: &Path) -> Result<()> {
+        self.tar.append_path_with_name(archive, package.path()?)?;
+        self.manifest.packages.push(package);
+        Ok(())
+    }
+
+    pub fn add_key(&mut self, key: BundledKey, body: &[u8]) -> Result<()> {
+        append_data(&mut self.tar, &key.path()?, body)?;
+        self.manifest.keys.push(key);
+        Ok(())
+    }
+
+    /// Adds the manifest and signs the bundle with `pair`, writing it to `dst`. Returns the
+    /// checksum of the signed bundle.
+    pub fn finish(mut self, pair: &SigKeyPair, dst: &Path) -> Result<String> {
+        let manifest = serde_json::to_vec_pretty(&self.manifest).unwrap();
+        append_data(&mut self.tar, Path::new(MANIFEST), &manifest)?;
+        self.tar.into_inner()?.flush()?;
+        let signed = artifact::sign(&self.tar_path, dst, pair);
+        fs::remove_file(&self.tar_path)?;
+        signed?;
+        Ok(hash::hash_file(dst)?)
+    }
+}
+
+/// The origin and revision of the key a bundle was signed with
+pub fn signer(path: &Path) -> Result<(String, String)> {
+    let name_with_rev = artifact::artifact_signer(&path)?;
+    Ok(parse_name_with_rev(name_with_rev)?)
+}
+
+/// Checks the signature of the bundle at `path` with the keys in `cache_key_path` and unpacks
+/// it into `dst`. Returns the bundle's manifest.
+pub fn unpack(path: &Path, cache_key_path: &Path, dst: &Path) -> Result<Manifest> {
+    artifact::verify(path, cache_key_path)?;
+    let reader = artifact::get_archive_reader(&path)?;
+    tar::Archive::new(reader).unpack(dst)?;
+    let file = File::open(dst.join(MANIFEST)).map_err(|_| {
+        Error::InvalidBundle(format!("missing {}", MANIFEST))
+    })?;
+    serde_json::from_reader(file).map_err(|e| Error::InvalidBundle(e.to_string()))
+}
+
+/// Path of the archive of a package unpacked into `dir`
+pub fn package_path(dir: &Path, package: &BundledPackage) -> Result<PathBuf> {
+    Ok(dir.join(package.path()?))
+}
+
+/// Path of a public key unpacked into `dir`
+pub fn key_path(dir: &Path, key: &BundledKey) -> Result<PathBuf> {
+    Ok(dir.join(key.path()?))
+}
+
+fn append_data<W: Write>(
+    tar: &mut tar::Builder<W>,
+    path: &Path,
+    data: &[u8],
+) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(data.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    tar.append_data(&mut header, path, data)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_paths() {
+        let package = BundledPackage {
+            ident: "core/redis/3.2.4/20170514001355".to_string(),
+            target: "x86_64-linux".to_string(),
+            checksum: "0a1b2c".to_string(),
+            channels: vec![],
+        };
+        assert_eq!(package.path().unwrap(), Path::new("pkgs/0a1b2c.hart"));
+        let package = BundledPackage {
+            checksum: "../../etc/passwd".to_string(),
+            ..package
+        };
+        assert!(package.path().is_err());
+
+        let key = BundledKey {
+            origin: "core".to_string(),
+            revision: "20160810182414".to_string(),
+        };
+        assert_eq!(key.path().unwrap(), Path::new("keys/core-20160810182414.pub"));
+        let key = BundledKey {
+            revision: "../20160810182414".to_string(),
+            ..key
+        };
+        assert!(key.path().is_err());
+    }
+}
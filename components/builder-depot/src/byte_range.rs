@@ -0,0 +1,118 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serves a range of the bytes of an archive, which lets clients download a large archive over
+//! several connections at once. Only requests for a single range of bytes are honored, anything
+//! else is answered with the whole archive.
+
+use std::fs::File;
+use std::io::{self, Read, Seek, SeekFrom};
+use std::path::Path;
+
+use hyper::header::{ByteRangeSpec, Range};
+
+/// What to serve of a file for the `Range` header of a request
+#[derive(Debug, PartialEq)]
+pub enum ByteRange {
+    Full,
+    /// The first and last byte to serve
+    Partial(u64, u64),
+    Unsatisfiable,
+}
+
+/// The part of a file of `len` bytes to serve for the `Range` header of a request.
+pub fn byte_range(range: Option<&Range>, len: u64) -> ByteRange {
+    let spec = match range {
+        Some(&Range::Bytes(ref specs)) if specs.len() == 1 => &specs[0],
+        _ => return ByteRange::Full,
+    };
+    match *spec {
+        ByteRangeSpec::FromTo(first, last) if first <= last => {
+            if first >= len {
+                ByteRange::Unsatisfiable
+            } else {
+                ByteRange::Partial(first, last.min(len - 1))
+            }
+        }
+        ByteRangeSpec::FromTo(..) => ByteRange::Full,
+        ByteRangeSpec::AllFrom(first) => {
+            if first >= len {
+                ByteRange::Unsatisfiable
+            } else {
+                ByteRange::Partial(first, len - 1)
+            }
+        }
+        ByteRangeSpec::Last(count) => {
+            if count == 0 || len == 0 {
+                ByteRange::Unsatisfiable
+            } else {
+                ByteRange::Partial(len.saturating_sub(count), len - 1)
+            }
+        }
+    }
+}
+
+/// Reads the bytes of a file from `first` up to and including `last`
+pub fn read_range(path: &Path, first: u64, last: u64) -> io::Result<Box<Read + Send>> {
+    let mut file = File::open(path)?;
+    file.seek(SeekFrom::Start(first))?;
+    Ok(Box::new(file.take(last - first + 1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bytes(spec: ByteRangeSpec) -> Range {
+        Range::Bytes(vec![spec])
+    }
+
+    #[test]
+    fn single_ranges() {
+        let range = bytes(ByteRangeSpec::FromTo(0, 99));
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Partial(0, 99));
+        let range = bytes(ByteRangeSpec::FromTo(900, 1999));
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Partial(900, 999));
+        let range = bytes(ByteRangeSpec::AllFrom(400));
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Partial(400, 999));
+        let range = bytes(ByteRangeSpec::Last(100));
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Partial(900, 999));
+        let range = bytes(ByteRangeSpec::Last(2000));
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Partial(0, 999));
+    }
+
+    #[test]
+    fn unsatisfiable_ranges() {
+        let range = bytes(ByteRangeSpec::FromTo(1000, 1099));
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Unsatisfiable);
+        let range = bytes(ByteRangeSpec::AllFrom(1000));
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Unsatisfiable);
+        let range = bytes(ByteRangeSpec::Last(0));
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Unsatisfiable);
+    }
+
+    #[test]
+    fn whole_file() {
+        assert_eq!(byte_range(None, 1000), ByteRange::Full);
+        let range = Range::Bytes(vec![
+            ByteRangeSpec::FromTo(0, 99),
+            ByteRangeSpec::FromTo(200, 299),
+        ]);
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Full);
+        let range = bytes(ByteRangeSpec::FromTo(99, 0));
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Full);
+        let range = Range::Unregistered("pages".to_string(), "1-2".to_string());
+        assert_eq!(byte_range(Some(&range), 1000), ByteRange::Full);
+    }
+}
@@ -0,0 +1,58 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generates binary deltas between the archives of two releases of a package. A client which has
+//! the archive of the earlier release applies the delta to it to get the archive of the later
+//! one, which saves downloading the whole archive again when only part of the package changed.
+//!
+//! Deltas are bsdiff patches of the archives exactly as they're stored, so that the archive a
+//! client puts together keeps the signature it was uploaded with.
+
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::Path;
+
+use bsdiff;
+use uuid::Uuid;
+
+use error::Result;
+
+/// Largest archive deltas are generated for, as both archives are held in memory along with the
+/// index of the earlier one while the delta is generated
+pub const MAX_ARCHIVE_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Writes the delta turning the archive at `from` into the archive at `to` to `dst`.
+pub fn generate(from: &Path, to: &Path, dst: &Path) -> Result<()> {
+    let old = read(from)?;
+    let new = read(to)?;
+    let mut patch = Vec::new();
+    bsdiff::diff(&old, &new, &mut patch)?;
+
+    // Deltas are written under a temporary name first, so that a delta which is still being
+    // generated is never served
+    let dir = dst.parent().expect("delta path has a parent");
+    fs::create_dir_all(dir)?;
+    let tmp_path = dir.join(format!("{}.tmp", Uuid::new_v4()));
+    File::create(&tmp_path).and_then(
+        |mut f| f.write_all(&patch),
+    )?;
+    fs::rename(&tmp_path, dst)?;
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<Vec<u8>> {
+    let mut content = Vec::new();
+    File::open(path)?.read_to_end(&mut content)?;
+    Ok(content)
+}
@@ -0,0 +1,79 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! Signed download URLs, which let machines without a Builder token download a package of a
+//! private origin for a limited time.
+//!
+//! A URL carries the time it expires at, in seconds since the epoch, and the HMAC-SHA256 of the
+//! package's ident and that time, keyed with the depot's `download_url_secret`. Anyone holding
+//! the URL can download that one package until it expires.
+
+use crypto::hmac::Hmac;
+use crypto::mac::Mac;
+use crypto::sha2::Sha256;
+use crypto::util::fixed_time_eq;
+
+/// How long a URL is valid for unless asked otherwise, in seconds
+pub const DEFAULT_TTL: i64 = 3600;
+/// The longest a URL can be valid for, in seconds
+pub const MAX_TTL: i64 = 7 * 24 * 3600;
+
+/// Signature of the download URL of a package which expires at the given time
+pub fn sign(secret: &str, ident: &str, expires: i64) -> String {
+    let mut hmac = Hmac::new(Sha256::new(), secret.as_bytes());
+    hmac.input(format!("{}\n{}", ident, expires).as_bytes());
+    hmac.result()
+        .code()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Whether a download URL of a package was signed with the secret and is still valid at `now`
+pub fn verify(secret: &str, ident: &str, expires: i64, signature: &str, now: i64) -> bool {
+    if secret.is_empty() || expires < now {
+        return false;
+    }
+    fixed_time_eq(sign(secret, ident, expires).as_bytes(), signature.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDENT: &'static str = "core/redis/3.2.4/20170514001355";
+
+    #[test]
+    fn verify_signed_url() {
+        let signature = sign("sekrit", IDENT, 1507000000);
+        assert_eq!(signature.len(), 64);
+        assert!(verify("sekrit", IDENT, 1507000000, &signature, 1506990000));
+        assert!(verify("sekrit", IDENT, 1507000000, &signature, 1507000000));
+    }
+
+    #[test]
+    fn reject_tampered_or_expired_url() {
+        let signature = sign("sekrit", IDENT, 1507000000);
+        assert!(!verify("sekrit", IDENT, 1507000000, &signature, 1507000001));
+        assert!(!verify("sekrit", IDENT, 1507999999, &signature, 1506990000));
+        assert!(!verify("other", IDENT, 1507000000, &signature, 1506990000));
+        assert!(!verify(
+            "sekrit",
+            "core/redis/3.2.4/20170514001356",
+            1507000000,
+            &signature,
+            1506990000,
+        ));
+        assert!(!verify("", IDENT, 1507000000, &sign("", IDENT, 1507000000), 1506990000));
+    }
+}
@@ -18,6 +18,8 @@ use std::io;
 use std::fmt;
 use std::result;
 
+use aws_sdk_rust::aws::errors::s3::S3Error;
+use depot_client;
 use hab_core;
 use hab_core::package::{self, Identifiable};
 use hab_net;
@@ -25,13 +27,17 @@ use hyper;
 
 #[derive(Debug)]
 pub enum Error {
+    ArtifactStore(String, S3Error),
     BadPort(String),
+    CaughtPanic(String, String),
     ChannelAlreadyExists(String),
     ChannelDoesNotExist(String),
+    DepotClient(depot_client::Error),
     HabitatCore(hab_core::Error),
     HabitatNet(hab_net::error::LibError),
     NetError(hab_net::NetError),
     HTTP(hyper::status::StatusCode),
+    InvalidBundle(String),
     InvalidPackageIdent(String),
     IO(io::Error),
     MessageTypeNotFound,
@@ -39,6 +45,7 @@ pub enum Error {
     NoFilePart,
     NulError(ffi::NulError),
     PackageIsAlreadyInChannel(String, String),
+    RemoteOriginKeyNotFound(String),
     RemotePackageNotFound(package::PackageIdent),
     WriteSyncFailed,
 }
@@ -48,12 +55,18 @@ pub type Result<T> = result::Result<T, Error>;
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            Error::ArtifactStore(ref what, ref e) => format!("Unable to {}, {}", what, e),
             Error::BadPort(ref e) => format!("{} is an invalid port. Valid range 1-65535.", e),
+            Error::CaughtPanic(ref msg, ref source) => {
+                format!("Caught a panic: {}. {}", msg, source)
+            }
             Error::ChannelAlreadyExists(ref e) => format!("{} already exists.", e),
             Error::ChannelDoesNotExist(ref e) => format!("{} does not exist.", e),
+            Error::DepotClient(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HabitatNet(ref e) => format!("{}", e),
             Error::HTTP(ref e) => format!("{}", e),
+            Error::InvalidBundle(ref e) => format!("Invalid bundle, {}", e),
             Error::InvalidPackageIdent(ref e) => {
                 format!(
                     "Invalid package identifier: {:?}. A valid identifier is in the form \
@@ -77,6 +90,9 @@ impl fmt::Display for Error {
             Error::PackageIsAlreadyInChannel(ref p, ref c) => {
                 format!("{} is already in the {} channel.", p, c)
             }
+            Error::RemoteOriginKeyNotFound(ref origin) => {
+                format!("Cannot find a public key for origin {} in any sources", origin)
+            }
             Error::RemotePackageNotFound(ref pkg) => {
                 if pkg.fully_qualified() {
                     format!("Cannot find package in any sources: {}", pkg)
@@ -95,12 +111,16 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::ArtifactStore(_, ref err) => err.description(),
             Error::BadPort(_) => "Received an invalid port or a number outside of the valid range.",
+            Error::CaughtPanic(_, _) => "Caught a panic",
             Error::ChannelAlreadyExists(_) => "Channel already exists.",
             Error::ChannelDoesNotExist(_) => "Channel does not exist.",
+            Error::DepotClient(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HabitatNet(ref err) => err.description(),
             Error::HTTP(_) => "Received an HTTP error",
+            Error::InvalidBundle(_) => "Bundle is missing or has invalid contents",
             Error::InvalidPackageIdent(_) => {
                 "Package identifiers must be in origin/name format (example: acme/redis)"
             }
@@ -110,6 +130,7 @@ impl error::Error for Error {
                 "An attempt was made to build a CString with a null byte inside it"
             }
             Error::PackageIsAlreadyInChannel(_, _) => "Package is already in channel",
+            Error::RemoteOriginKeyNotFound(_) => "Cannot find an origin key in any sources",
             Error::RemotePackageNotFound(_) => "Cannot find a package in any sources",
             Error::NoXFilename => "Invalid download from Builder - missing X-Filename header",
             Error::NoFilePart => {
@@ -123,6 +144,12 @@ impl error::Error for Error {
     }
 }
 
+impl From<depot_client::Error> for Error {
+    fn from(err: depot_client::Error) -> Error {
+        Error::DepotClient(err)
+    }
+}
+
 impl From<hab_core::Error> for Error {
     fn from(err: hab_core::Error) -> Error {
         Error::HabitatCore(err)
@@ -1 +1,3 @@
 pub mod integrations;
+pub mod notifications;
+pub mod secrets;
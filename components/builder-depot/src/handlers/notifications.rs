@@ -0,0 +1,152 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bodyparser;
+use http_gateway::http::controller::*;
+use http_gateway::http::helpers;
+use iron::status;
+use protocol::net::NetOk;
+use protocol::originsrv::*;
+use url::Url;
+
+use super::integrations::{encrypt, validate_params};
+
+#[derive(Clone, Serialize, Deserialize)]
+struct NotificationCreateReq {
+    kind: String,
+    target: String,
+    events: Vec<String>,
+    /// Key webhook deliveries are signed with
+    #[serde(default)]
+    secret: Option<String>,
+}
+
+pub fn fetch_origin_notifications(req: &mut Request) -> IronResult<Response> {
+    let params = match validate_params(req, &["origin"]) {
+        Ok(p) => p,
+        Err(st) => return Ok(Response::with(st)),
+    };
+
+    let mut request = OriginNotificationListRequest::new();
+    request.set_origin(params["origin"].clone());
+    match route_message::<OriginNotificationListRequest, OriginNotificationListResponse>(
+        req,
+        &request,
+    ) {
+        Ok(notifications) => {
+            let mut response = render_json(status::Ok, &notifications);
+            helpers::dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn create_origin_notification(req: &mut Request) -> IronResult<Response> {
+    let params = match validate_params(req, &["origin"]) {
+        Ok(p) => p,
+        Err(st) => return Ok(Response::with(st)),
+    };
+
+    let body = match req.get::<bodyparser::Struct<NotificationCreateReq>>() {
+        Ok(Some(body)) => body,
+        Ok(None) => {
+            debug!("create_origin_notification: Empty body in request");
+            return Ok(Response::with(status::BadRequest));
+        }
+        Err(e) => {
+            debug!("create_origin_notification, Error parsing body: {:?}", e);
+            return Ok(Response::with(status::BadRequest));
+        }
+    };
+
+    let secret = body.secret.clone();
+    let mut notification = match notification_from_req(body) {
+        Ok(notification) => notification,
+        Err(msg) => return Ok(Response::with((status::UnprocessableEntity, msg))),
+    };
+    if let Some(secret) = secret {
+        match encrypt(req, &secret) {
+            Ok(encrypted) => notification.set_secret(encrypted),
+            Err(st) => return Ok(Response::with(st)),
+        }
+    }
+    notification.set_origin(params["origin"].clone());
+    notification.set_owner_id(req.extensions.get::<Authenticated>().unwrap().get_id());
+
+    let mut request = OriginNotificationCreate::new();
+    request.set_notification(notification);
+    match route_message::<OriginNotificationCreate, OriginNotification>(req, &request) {
+        Ok(notification) => Ok(render_json(status::Created, &notification)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn delete_origin_notification(req: &mut Request) -> IronResult<Response> {
+    let params = match validate_params(req, &["origin", "id"]) {
+        Ok(p) => p,
+        Err(st) => return Ok(Response::with(st)),
+    };
+    let id = match params["id"].parse::<u64>() {
+        Ok(id) => id,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut request = OriginNotificationDelete::new();
+    request.set_origin(params["origin"].clone());
+    request.set_id(id);
+    match route_message::<OriginNotificationDelete, NetOk>(req, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn notification_from_req(body: NotificationCreateReq) -> Result<OriginNotification, String> {
+    let kind = body.kind.parse::<OriginNotificationKind>().map_err(
+        |e| e.to_string(),
+    )?;
+    let valid_target = match kind {
+        OriginNotificationKind::Webhook => {
+            Url::parse(&body.target)
+                .map(|url| url.scheme() == "http" || url.scheme() == "https")
+                .unwrap_or(false)
+        }
+        OriginNotificationKind::Email => {
+            // The address ends up in an email header, so it mustn't be able to start another
+            let parts: Vec<&str> = body.target.split('@').collect();
+            parts.len() == 2 && !parts[0].is_empty() && !parts[1].is_empty() &&
+                !body.target.chars().any(|c| c.is_whitespace() || c.is_control())
+        }
+    };
+    if !valid_target {
+        return Err(format!("Invalid {} target: `{}`", kind, body.target));
+    }
+    if body.secret.is_some() && kind != OriginNotificationKind::Webhook {
+        return Err(format!("Unexpected secret for {} notification", kind));
+    }
+    if body.events.is_empty() {
+        return Err(String::from("Missing value for field: `events`"));
+    }
+
+    let mut notification = OriginNotification::new();
+    notification.set_kind(kind);
+    notification.set_target(body.target);
+    for event in body.events {
+        let event = event.parse::<OriginNotificationEvent>().map_err(
+            |e| e.to_string(),
+        )?;
+        notification.mut_events().push(event);
+    }
+    Ok(notification)
+}
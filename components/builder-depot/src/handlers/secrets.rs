@@ -0,0 +1,352 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Secrets of an origin are encrypted to a box key pair of the origin, which Builder generates the
+//! first time it's needed and keeps to itself, apart from its public key. Clients can encrypt a
+//! secret with that public key before uploading it, so that Builder never sees its value until the
+//! job server decrypts it for a build.
+//!
+//! Rotating the encryption key generates a new revision and re-encrypts every secret with it. Each
+//! secret is decrypted with the revision it was encrypted to, as every revision is kept, so the
+//! secrets left behind by an interrupted rotation are re-encrypted by the next one. Secrets
+//! encrypted to the Builder key by older releases are left as they are, as builds can still
+//! decrypt them.
+
+use std::collections::HashMap;
+
+use bldr_core;
+use bodyparser;
+use hab_core::crypto::BoxKeyPair;
+use http_gateway::http::controller::*;
+use http_gateway::http::helpers;
+use iron::status;
+use protocol::net::NetOk;
+use protocol::originsrv::*;
+
+use super::integrations::validate_params;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct SecretSetReq {
+    value: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct SecretCreateReq {
+    name: String,
+    value: String,
+}
+
+#[derive(Serialize)]
+struct EncryptionKeyRotation {
+    revision: String,
+    reencrypted: Vec<String>,
+    skipped: Vec<String>,
+}
+
+/// Downloads the public key secrets of an origin are encrypted with.
+pub fn download_origin_encryption_key(req: &mut Request) -> IronResult<Response> {
+    let params = match validate_params(req, &["origin"]) {
+        Ok(p) => p,
+        Err(st) => return Ok(Response::with(st)),
+    };
+
+    match origin_encryption_key(req, &params["origin"]) {
+        Ok(key) => {
+            let xfilename = format!("{}-{}.pub", key.get_origin(), key.get_revision());
+            let mut response = Response::with((status::Ok, key.get_public_key()));
+            response.headers.set(ContentDisposition(
+                format!("attachment; filename=\"{}\"", xfilename),
+            ));
+            response.headers.set(XFileName(xfilename));
+            helpers::dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Replaces the encryption key of an origin with a new revision and re-encrypts its secrets with
+/// it, replying with the new revision and the names of the secrets which were re-encrypted.
+pub fn rotate_origin_encryption_key(req: &mut Request) -> IronResult<Response> {
+    let params = match validate_params(req, &["origin"]) {
+        Ok(p) => p,
+        Err(st) => return Ok(Response::with(st)),
+    };
+
+    let mut request = OriginSecretListRequest::new();
+    request.set_origin(params["origin"].clone());
+    let secrets = match route_message::<OriginSecretListRequest, OriginSecretListResponse>(
+        req,
+        &request,
+    ) {
+        Ok(secrets) => secrets,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+    let new_key = match create_origin_encryption_key(req, &params["origin"]) {
+        Ok(key) => key,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    let mut rotation = EncryptionKeyRotation {
+        revision: new_key.get_revision().to_string(),
+        reencrypted: Vec::new(),
+        skipped: Vec::new(),
+    };
+    let mut keys = HashMap::new();
+    for secret in secrets.get_secrets() {
+        let revision = match bldr_core::integrations::encrypted_key_revision(
+            &params["origin"],
+            secret.get_value(),
+        ) {
+            Some(revision) => revision,
+            None => {
+                rotation.skipped.push(secret.get_name().to_string());
+                continue;
+            }
+        };
+        if !keys.contains_key(&revision) {
+            let mut request = OriginEncryptionKeyGet::new();
+            request.set_origin(params["origin"].clone());
+            request.set_revision(revision.clone());
+            let key = match route_message::<OriginEncryptionKeyGet, OriginEncryptionKey>(
+                req,
+                &request,
+            ) {
+                Ok(key) => Some(key),
+                Err(ref err) if err.code() == ErrCode::ENTITY_NOT_FOUND => None,
+                Err(err) => return Ok(render_net_error(&err)),
+            };
+            keys.insert(revision.clone(), key);
+        }
+        let decrypted = keys[&revision].as_ref().and_then(|key| {
+            bldr_core::integrations::decrypt_with_key(
+                key.get_public_key(),
+                key.get_secret_key(),
+                secret.get_value(),
+            ).ok()
+        });
+        let value = match decrypted {
+            Some(value) => value,
+            None => {
+                rotation.skipped.push(secret.get_name().to_string());
+                continue;
+            }
+        };
+        let mut secret = secret.clone();
+        match bldr_core::integrations::encrypt_with_key(new_key.get_public_key(), &value) {
+            Ok(encrypted) => secret.set_value(encrypted),
+            Err(_) => return Ok(Response::with(status::InternalServerError)),
+        }
+        let mut request = OriginSecretCreate::new();
+        request.set_secret(secret);
+        match route_message::<OriginSecretCreate, OriginSecret>(req, &request) {
+            Ok(secret) => rotation.reencrypted.push(secret.get_name().to_string()),
+            Err(err) => return Ok(render_net_error(&err)),
+        }
+    }
+
+    let mut response = render_json(status::Ok, &rotation);
+    helpers::dont_cache_response(&mut response);
+    Ok(response)
+}
+
+pub fn fetch_origin_secrets(req: &mut Request) -> IronResult<Response> {
+    let params = match validate_params(req, &["origin"]) {
+        Ok(p) => p,
+        Err(st) => return Ok(Response::with(st)),
+    };
+
+    let mut request = OriginSecretListRequest::new();
+    request.set_origin(params["origin"].clone());
+    match route_message::<OriginSecretListRequest, OriginSecretListResponse>(req, &request) {
+        Ok(secrets) => {
+            let mut response = render_json(status::Ok, &secrets);
+            helpers::dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Stores a secret which the client already encrypted with the origin's public encryption key.
+pub fn create_origin_secret(req: &mut Request) -> IronResult<Response> {
+    let params = match validate_params(req, &["origin"]) {
+        Ok(p) => p,
+        Err(st) => return Ok(Response::with(st)),
+    };
+
+    let body = match req.get::<bodyparser::Struct<SecretCreateReq>>() {
+        Ok(Some(body)) => body,
+        Ok(None) => {
+            debug!("create_origin_secret: Empty body in request");
+            return Ok(Response::with(status::BadRequest));
+        }
+        Err(e) => {
+            debug!("create_origin_secret, Error parsing body: {:?}", e);
+            return Ok(Response::with(status::BadRequest));
+        }
+    };
+    if !OriginSecret::is_valid_name(&body.name) {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            format!("Invalid secret name: `{}`", body.name),
+        )));
+    }
+
+    let key = match origin_encryption_key(req, &params["origin"]) {
+        Ok(key) => key,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+    if !bldr_core::integrations::is_encrypted_with_key(key.get_public_key(), &body.value) {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            format!(
+                "Secret values must be encrypted with the latest encryption key of the origin, \
+                 {}-{}",
+                key.get_origin(),
+                key.get_revision()
+            ),
+        )));
+    }
+
+    let mut secret = OriginSecret::new();
+    secret.set_origin(params["origin"].clone());
+    secret.set_name(body.name);
+    secret.set_value(body.value);
+
+    let mut request = OriginSecretCreate::new();
+    request.set_secret(secret);
+    match route_message::<OriginSecretCreate, OriginSecret>(req, &request) {
+        Ok(_) => Ok(Response::with(status::Created)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Stores a secret given in plain text, encrypting it with the origin's public encryption key.
+pub fn set_origin_secret(req: &mut Request) -> IronResult<Response> {
+    let params = match validate_params(req, &["origin", "name"]) {
+        Ok(p) => p,
+        Err(st) => return Ok(Response::with(st)),
+    };
+    if !OriginSecret::is_valid_name(&params["name"]) {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            format!("Invalid secret name: `{}`", params["name"]),
+        )));
+    }
+
+    let body = match req.get::<bodyparser::Struct<SecretSetReq>>() {
+        Ok(Some(body)) => body,
+        Ok(None) => {
+            debug!("set_origin_secret: Empty body in request");
+            return Ok(Response::with(status::BadRequest));
+        }
+        Err(e) => {
+            debug!("set_origin_secret, Error parsing body: {:?}", e);
+            return Ok(Response::with(status::BadRequest));
+        }
+    };
+    // Secrets are handed to the studio as a whitespace separated list of variables
+    if body.value.is_empty() || body.value.chars().any(|c| c.is_whitespace()) {
+        return Ok(Response::with((
+            status::UnprocessableEntity,
+            "Secret values must be non-empty and can't contain whitespace",
+        )));
+    }
+
+    let mut secret = OriginSecret::new();
+    secret.set_origin(params["origin"].clone());
+    secret.set_name(params["name"].clone());
+    let key = match origin_encryption_key(req, &params["origin"]) {
+        Ok(key) => key,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+    match bldr_core::integrations::encrypt_with_key(key.get_public_key(), &body.value) {
+        Ok(encrypted) => secret.set_value(encrypted),
+        Err(_) => return Ok(Response::with(status::InternalServerError)),
+    }
+
+    let mut request = OriginSecretCreate::new();
+    request.set_secret(secret);
+    match route_message::<OriginSecretCreate, OriginSecret>(req, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+pub fn delete_origin_secret(req: &mut Request) -> IronResult<Response> {
+    let params = match validate_params(req, &["origin", "name"]) {
+        Ok(p) => p,
+        Err(st) => return Ok(Response::with(st)),
+    };
+
+    let mut request = OriginSecretDelete::new();
+    request.set_origin(params["origin"].clone());
+    request.set_name(params["name"].clone());
+    match route_message::<OriginSecretDelete, NetOk>(req, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Gets the latest encryption key of an origin, generating its first one if it has none yet.
+fn origin_encryption_key(req: &mut Request, origin: &str) -> NetResult<OriginEncryptionKey> {
+    let mut request = OriginEncryptionKeyGet::new();
+    request.set_origin(origin.to_string());
+    match route_message::<OriginEncryptionKeyGet, OriginEncryptionKey>(req, &request) {
+        Ok(key) => return Ok(key),
+        Err(ref err) if err.code() == ErrCode::ENTITY_NOT_FOUND => (),
+        Err(err) => return Err(err),
+    }
+
+    match create_origin_encryption_key(req, origin) {
+        Ok(key) => Ok(key),
+        Err(ref err) if err.code() == ErrCode::SYS => Err(err.clone()),
+        // Another request generated a key at the same time
+        Err(_) => route_message::<OriginEncryptionKeyGet, OriginEncryptionKey>(req, &request),
+    }
+}
+
+/// Generates a new revision of the encryption key of an origin, which becomes its latest.
+fn create_origin_encryption_key(req: &mut Request, origin: &str) -> NetResult<OriginEncryptionKey> {
+    let origin = helpers::get_origin(req, origin)?;
+    let pair = match BoxKeyPair::generate_pair_for_origin(origin.get_name()) {
+        Ok(pair) => pair,
+        Err(err) => {
+            error!("Unable to generate an encryption key for {}, {}", origin.get_name(), err);
+            return Err(NetError::new(ErrCode::SYS, "dp:create-encryption-key:0"));
+        }
+    };
+    let mut key = OriginEncryptionKey::new();
+    key.set_origin_id(origin.get_id());
+    key.set_origin(origin.get_name().to_string());
+    key.set_revision(pair.rev.clone());
+    match pair.to_public_string() {
+        Ok(public_key) => key.set_public_key(public_key),
+        Err(err) => {
+            error!("No public key in the encryption key pair {}, {}", pair.name_with_rev(), err);
+            return Err(NetError::new(ErrCode::SYS, "dp:create-encryption-key:1"));
+        }
+    }
+    match pair.to_secret_string() {
+        Ok(secret_key) => key.set_secret_key(secret_key),
+        Err(err) => {
+            error!("No secret key in the encryption key pair {}, {}", pair.name_with_rev(), err);
+            return Err(NetError::new(ErrCode::SYS, "dp:create-encryption-key:2"));
+        }
+    }
+    let mut create = OriginEncryptionKeyCreate::new();
+    create.set_key(key);
+    route_message::<OriginEncryptionKeyCreate, OriginEncryptionKey>(req, &create)
+}
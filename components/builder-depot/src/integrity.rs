@@ -0,0 +1,170 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Verifies the archives in the depot's artifact store against the origin server's metadata.
+//!
+//! Every release of an origin is checked in turn: its archive has to be in the store, its
+//! checksum has to be the one recorded when it was uploaded, and it has to be signed with a
+//! public key of its origin which the depot has. A corrupt archive is moved out of the store into
+//! the depot's quarantine, so that it stops being served and can be looked into. The release's
+//! metadata is kept, which lets the archive be uploaded again.
+
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use hab_core::crypto::{artifact, hash, SigKeyPair};
+use hab_core::crypto::keys::parse_name_with_rev;
+use hab_core::package::PackageTarget;
+use protocol::originsrv::OriginPackageIdent;
+
+use super::DepotUtil;
+use error::Result;
+
+/// What's wrong with the archive of a release
+#[derive(Debug, PartialEq)]
+pub enum Problem {
+    /// The store has no archive for the release
+    Missing,
+    /// The archive's checksum isn't the one recorded for the release
+    Checksum { expected: String, actual: String },
+    /// The archive is signed by a key the depot doesn't have
+    UnknownKey(String),
+    /// The archive's signature can't be read or doesn't match its contents
+    Signature(String),
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Problem::Missing => write!(f, "archive is missing"),
+            Problem::Checksum {
+                ref expected,
+                ref actual,
+            } => write!(f, "checksum is {}, expected {}", actual, expected),
+            Problem::UnknownKey(ref key) => write!(f, "signed with unknown key {}", key),
+            Problem::Signature(ref reason) => write!(f, "invalid signature, {}", reason),
+        }
+    }
+}
+
+/// A release whose archive is corrupt
+#[derive(Serialize)]
+pub struct Corrupt {
+    pub ident: String,
+    pub target: String,
+    pub problem: String,
+    /// Whether the archive was moved into the quarantine
+    pub quarantined: bool,
+}
+
+#[derive(Serialize)]
+pub struct IntegrityReport {
+    pub dry_run: bool,
+    /// How many releases were checked
+    pub checked: u64,
+    pub packages: Vec<Corrupt>,
+}
+
+impl IntegrityReport {
+    pub fn new(dry_run: bool) -> Self {
+        IntegrityReport {
+            dry_run: dry_run,
+            checked: 0,
+            packages: Vec::new(),
+        }
+    }
+
+    pub fn add(
+        &mut self,
+        ident: &OriginPackageIdent,
+        target: &PackageTarget,
+        problem: &Problem,
+        quarantined: bool,
+    ) {
+        self.packages.push(Corrupt {
+            ident: ident.to_string(),
+            target: target.to_string(),
+            problem: problem.to_string(),
+            quarantined: quarantined,
+        });
+    }
+}
+
+/// Checks the stored archive of a release against the checksum recorded for it, and its
+/// signature against the public keys in `cache_key_path`. A key missing from there is looked up
+/// with `fetch_key`, which returns the body of the key with the given name and revision if the
+/// depot has it. Returns what's wrong with the archive, if anything.
+pub fn check_archive<F>(
+    depot: &DepotUtil,
+    ident: &OriginPackageIdent,
+    target: &PackageTarget,
+    checksum: &str,
+    cache_key_path: &Path,
+    mut fetch_key: F,
+) -> Result<Option<Problem>>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let path = match depot.store.local_path(&depot.archive_key(ident, target))? {
+        Some(path) => path,
+        None => return Ok(Some(Problem::Missing)),
+    };
+    let actual = hash::hash_file(&path)?;
+    if actual != checksum {
+        return Ok(Some(Problem::Checksum {
+            expected: checksum.to_string(),
+            actual: actual,
+        }));
+    }
+    let signer = match artifact::artifact_signer(&path) {
+        Ok(signer) => signer,
+        Err(err) => return Ok(Some(Problem::Signature(err.to_string()))),
+    };
+    match parse_name_with_rev(&signer) {
+        Ok((ref origin, _)) if origin == ident.get_origin() => (),
+        _ => return Ok(Some(Problem::UnknownKey(signer))),
+    }
+    if !cache_key_path.join(format!("{}.pub", signer)).is_file() {
+        match fetch_key(&signer) {
+            Some(body) => {
+                SigKeyPair::write_file_from_str(&body, cache_key_path)?;
+            }
+            None => return Ok(Some(Problem::UnknownKey(signer))),
+        }
+    }
+    match artifact::verify(&path, cache_key_path) {
+        Ok(_) => Ok(None),
+        Err(err) => Ok(Some(Problem::Signature(err.to_string()))),
+    }
+}
+
+/// Moves the archive of a release out of the store into the depot's quarantine.
+pub fn quarantine_archive(
+    depot: &DepotUtil,
+    ident: &OriginPackageIdent,
+    target: &PackageTarget,
+) -> Result<()> {
+    let key = depot.archive_key(ident, target);
+    let path = match depot.store.local_path(&key)? {
+        Some(path) => path,
+        None => return Ok(()),
+    };
+    fs::create_dir_all(depot.quarantine_path())?;
+    fs::copy(
+        &path,
+        depot.quarantine_path().join(depot.archive_name(ident, target)),
+    )?;
+    depot.store.delete(&key)
+}
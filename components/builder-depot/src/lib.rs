@@ -15,13 +15,16 @@
 #![cfg_attr(feature="clippy", feature(plugin))]
 #![cfg_attr(feature="clippy", plugin(clippy))]
 
+extern crate aws_sdk_rust;
 extern crate habitat_builder_protocol as protocol;
+extern crate habitat_depot_client as depot_client;
 #[macro_use]
 extern crate habitat_core as hab_core;
 extern crate habitat_net as hab_net;
 extern crate builder_core as bldr_core;
 extern crate builder_http_gateway as http_gateway;
 extern crate bodyparser;
+extern crate bsdiff;
 extern crate crypto;
 extern crate hyper;
 extern crate iron;
@@ -42,6 +45,7 @@ extern crate serde;
 extern crate serde_derive;
 #[macro_use]
 extern crate serde_json;
+extern crate tar;
 extern crate tempfile;
 extern crate time;
 extern crate toml;
@@ -53,16 +57,24 @@ extern crate uuid;
 extern crate base64;
 extern crate github_api_client;
 
+pub mod bundle;
+pub mod byte_range;
 pub mod config;
+pub mod delta;
 pub mod error;
 pub mod doctor;
+pub mod download_url;
+pub mod integrity;
+pub mod retention;
 pub mod server;
+pub mod storage;
 pub mod handlers;
+pub mod upload;
+pub mod upstream;
 
 pub use self::config::Config;
 pub use self::error::{Error, Result};
 
-use std::fs;
 use std::path::{Path, PathBuf};
 
 use crypto::sha2::Sha256;
@@ -72,45 +84,79 @@ use iron::typemap;
 
 pub struct DepotUtil {
     pub config: Config,
+    /// The Builder packages and keys the depot doesn't have are fetched from, if it caches one
+    pub upstream: Option<upstream::Upstream>,
+    /// Where the archives of packages are stored
+    pub store: Box<storage::ArtifactStore>,
 }
 
 impl DepotUtil {
     pub fn new(config: Config) -> DepotUtil {
-        DepotUtil { config: config }
+        let packages_path = Path::new(&config.path).join("pkgs");
+        DepotUtil {
+            config: config,
+            upstream: None,
+            store: Box::new(storage::local::LocalStore::new(packages_path)),
+        }
     }
 
-    // Return a PackageArchive representing the given package. None is returned if Builder
-    // doesn't have an archive for the given package.
+    // Return a PackageArchive representing the given package, copied to the local filesystem if
+    // it's stored elsewhere. None is returned if Builder doesn't have an archive for the given
+    // package.
     fn archive<T>(&self, ident: &T, target: &PackageTarget) -> Option<PackageArchive>
     where
         T: Identifiable,
     {
-        let file = self.archive_path(ident, target);
-        match fs::metadata(&file) {
-            Ok(_) => Some(PackageArchive::new(file)),
-            Err(_) => None,
+        match self.store.local_path(&self.archive_key(ident, target)) {
+            Ok(path) => path.map(PackageArchive::new),
+            Err(err) => {
+                warn!("Unable to read the archive of {}, err={}", ident, err);
+                None
+            }
         }
     }
 
-    // Return a formatted string representing the filename of an archive for the given package
-    // identifier pieces.
-    fn archive_path<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> PathBuf {
+    // Return the size of the stored archive of the given package, if Builder has one.
+    fn archive_size<T>(&self, ident: &T, target: &PackageTarget) -> Result<Option<u64>>
+    where
+        T: Identifiable,
+    {
+        self.store.size(&self.archive_key(ident, target))
+    }
+
+    // Return a formatted string representing the key an archive for the given package
+    // identifier pieces is stored under.
+    fn archive_key<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> String {
         let mut digest = Sha256::new();
         let mut output = [0; 64];
         digest.input_str(&ident.to_string());
         digest.result(&mut output);
-        self.packages_path()
-            .join(format!("{:x}", output[0]))
-            .join(format!("{:x}", output[1]))
-            .join(format!(
-                "{}-{}-{}-{}-{}-{}.hart",
-                ident.origin(),
-                ident.name(),
-                ident.version().unwrap(),
-                ident.release().unwrap(),
-                target.architecture,
-                target.platform
-            ))
+        format!(
+            "{:x}/{:x}/{}",
+            output[0],
+            output[1],
+            self.archive_name(ident, target)
+        )
+    }
+
+    // Return a formatted string representing the filename of an archive for the given package
+    // identifier pieces.
+    fn archive_name<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> String {
+        format!(
+            "{}-{}-{}-{}-{}-{}.hart",
+            ident.origin(),
+            ident.name(),
+            ident.version().unwrap(),
+            ident.release().unwrap(),
+            target.architecture,
+            target.platform
+        )
+    }
+
+    // Return a formatted string representing the filename of an archive for the given package
+    // identifier pieces on the local filesystem.
+    fn archive_path<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> PathBuf {
+        self.packages_path().join(self.archive_key(ident, target))
     }
 
     // Return a formatted string representing the folder location for an archive.
@@ -124,9 +170,59 @@ impl DepotUtil {
         )
     }
 
+    // Return the path of the delta turning the archive of the `from` release of a package into
+    // the archive of the given one.
+    fn delta_path<T: Identifiable>(&self, from: &T, ident: &T, target: &PackageTarget) -> PathBuf {
+        self.deltas_path(ident, target).join(format!(
+            "{}-{}.delta",
+            from.version().unwrap(),
+            from.release().unwrap()
+        ))
+    }
+
+    // Return the folder location of the deltas into the archive of the given package.
+    fn deltas_path<T: Identifiable>(&self, ident: &T, target: &PackageTarget) -> PathBuf {
+        let archive = self.archive_path(ident, target);
+        let archive_name = archive.file_stem().unwrap().to_string_lossy();
+        Path::new(&self.config.path).join("deltas").join(
+            archive_name.as_ref(),
+        )
+    }
+
     fn packages_path(&self) -> PathBuf {
         Path::new(&self.config.path).join("pkgs")
     }
+
+    // Return the directory exported and imported bundles are kept in.
+    fn bundles_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("bundles")
+    }
+
+    // Return the directory corrupt archives are moved into.
+    fn quarantine_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("quarantine")
+    }
+
+    // Return the cache of the upstream Builder configured for the depot, if any.
+    fn upstream_cache(&self) -> Result<Option<upstream::Upstream>> {
+        if self.config.upstream_url.is_empty() {
+            return Ok(None);
+        }
+        let cache_path = Path::new(&self.config.path).join("upstream");
+        Ok(Some(
+            upstream::Upstream::new(&self.config.upstream_url, &cache_path)?,
+        ))
+    }
+
+    // Return the chunked upload of an archive of the given package with the given checksum.
+    fn upload<T: Identifiable>(&self, ident: &T, checksum: &str) -> upload::Upload {
+        upload::Upload::new(&self.uploads_path(), &ident.to_string(), checksum)
+    }
+
+    // Return the path of the directory holding the parts of chunked uploads.
+    fn uploads_path(&self) -> PathBuf {
+        Path::new(&self.config.path).join("uploads")
+    }
 }
 
 impl typemap::Key for DepotUtil {
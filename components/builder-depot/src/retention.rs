@@ -0,0 +1,80 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reclaims the space taken by releases which have passed the depot's retention.
+//!
+//! The origin server picks the releases of an origin created more than `max_age_days` ago which
+//! aren't worth keeping: releases in `stable` are never deleted, nor are the latest
+//! `keep_releases` releases of a package in each of the channels it's in, releases awaiting
+//! promotion and releases which any other package depends on. Each of them is deleted from the
+//! origin server first, so that it stops being served, and its archive is removed after.
+
+use std::fs;
+use std::io;
+
+use hab_core::package::PackageTarget;
+use protocol::originsrv::OriginPackageIdent;
+
+use super::DepotUtil;
+use error::Result;
+
+/// A release deleted by the retention, or which would be by a dry run
+#[derive(Serialize)]
+pub struct Reclaimed {
+    pub ident: String,
+    pub target: String,
+    /// Size of the release's archive, 0 if the depot doesn't have one
+    pub size: u64,
+}
+
+#[derive(Serialize)]
+pub struct RetentionReport {
+    pub dry_run: bool,
+    pub packages: Vec<Reclaimed>,
+    /// Bytes taken by the archives of the releases
+    pub bytes: u64,
+}
+
+impl RetentionReport {
+    pub fn new(dry_run: bool) -> Self {
+        RetentionReport {
+            dry_run: dry_run,
+            packages: Vec::new(),
+            bytes: 0,
+        }
+    }
+
+    pub fn add(&mut self, ident: &OriginPackageIdent, target: &PackageTarget, size: u64) {
+        self.packages.push(Reclaimed {
+            ident: ident.to_string(),
+            target: target.to_string(),
+            size: size,
+        });
+        self.bytes += size;
+    }
+}
+
+/// Removes the archive of a deleted release, along with the deltas generated into it.
+pub fn delete_archive(
+    depot: &DepotUtil,
+    ident: &OriginPackageIdent,
+    target: &PackageTarget,
+) -> Result<()> {
+    depot.store.delete(&depot.archive_key(ident, target))?;
+    match fs::remove_dir_all(depot.deltas_path(ident, target)) {
+        Ok(()) => Ok(()),
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(err.into()),
+    }
+}
@@ -12,49 +12,75 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::{self, File};
-use std::path::PathBuf;
-use std::io::{BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::io::{self, BufWriter, Read, Write};
 use std::result;
 use std::str::FromStr;
+use std::time::Duration;
 
 use base64;
 use bldr_core;
+use bldr_core::cron::Schedule;
 use bldr_core::helpers::transition_visibility;
+use bldr_core::target_graph::SUPPORTED_TARGETS;
 use bodyparser;
 use github_api_client::GitHubClient;
 use hab_core::package::{ident, FromArchive, Identifiable, PackageArchive, PackageIdent,
                         PackageTarget};
-use hab_core::crypto::keys::PairType;
+use hab_core::crypto::hash;
+use hab_core::crypto::keys::{parse_name_with_rev, PairType};
 use hab_core::crypto::{BoxKeyPair, SigKeyPair};
 use hab_core::crypto::PUBLIC_BOX_KEY_VERSION;
 use hab_core::event::*;
 use http_gateway::http::controller::*;
 use http_gateway::http::helpers::{self, all_visibilities, check_origin_access, check_origin_owner,
                                   dont_cache_response, get_param, visibility_for_optional_session};
-use http_gateway::http::middleware::{SegmentCli, XRouteClient};
+use http_gateway::http::middleware::{OAuthCli, SegmentCli, XRouteClient};
+use http_gateway::http::webhooks::{self, Webhooks};
+use http_gateway::oauth;
 use hab_net::{privilege, ErrCode, NetOk, NetResult};
-use hyper::header::{Charset, ContentDisposition, DispositionParam, DispositionType};
+use hyper::header::{AcceptRanges, Charset, ContentDisposition, ContentLength, ContentRange,
+                    ContentRangeSpec, DispositionParam, DispositionType, Range, RangeUnit};
 use hyper::mime::{Attr, Mime, SubLevel, TopLevel, Value};
 use iron::headers::{ContentType, UserAgent};
+use iron::method::Method;
 use iron::middleware::BeforeMiddleware;
 use iron::request::Body;
+use mount::OriginalUrl;
 use persistent;
 use protobuf;
 use protocol::originsrv::*;
-use protocol::jobsrv::{JobGroup, JobGroupOriginGet, JobGroupOriginResponse, JobGroupSpec,
-                       JobGroupGet, JobGraphPackageStatsGet, JobGraphPackageStats,
-                       JobGraphPackagePreCreate, JobGroupAbort};
-use protocol::sessionsrv::{Account, AccountGet, AccountOriginRemove};
+use protocol::jobsrv::{JobGroup, JobGroupCreateError, JobGroupOriginGet, JobGroupOriginResponse,
+                       JobGroupSpec, JobGroupGet, JobGraphPackageStatsGet, JobGraphPackageStats,
+                       JobGraphPackagePreCreate, JobGroupAbort, JobGroupSchedule,
+                       JobGroupScheduleCreate, JobGroupScheduleDelete, JobGroupScheduleListGet,
+                       JobGroupScheduleListResponse, JobGroupState, JobGroupPreview,
+                       JobGroupPreviewResponse, JobPriority, OriginBuildUsage,
+                       OriginBuildUsageGet};
+use protocol::sessionsrv::{AccessTokenScope, Account, AccountGet, AccountOriginRemove,
+                           Session};
+use protocol::timestamp;
 use regex::Regex;
 use router::{Params, Router};
 use segment_api_client::SegmentClient;
 use serde_json;
+use time;
 use typemap;
 use url;
 use uuid::Uuid;
 
 use super::DepotUtil;
+use bundle;
+use byte_range::{byte_range, read_range, ByteRange};
+use delta;
+use storage;
+use upload;
+use upstream::Upstream;
+use download_url;
+use integrity;
+use retention;
 use error::{Error, Result};
 use handlers;
 
@@ -71,7 +97,44 @@ struct OriginUpdateReq {
     default_package_visibility: String,
 }
 
+#[derive(Clone, Serialize)]
+struct UploadStatus {
+    checksum: String,
+    part_size: u64,
+    parts: Vec<u64>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct PackageYankReq {
+    #[serde(default)]
+    reason: String,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct BundleExportReq {
+    packages: Vec<String>,
+    channel: Option<String>,
+}
+
+#[derive(Clone, Serialize)]
+struct BundleExported {
+    checksum: String,
+    size: u64,
+    packages: Vec<String>,
+}
+
+#[derive(Clone, Default, Serialize)]
+struct BundleImported {
+    packages_imported: Vec<String>,
+    packages_skipped: Vec<String>,
+    keys_imported: Vec<String>,
+}
+
 const ONE_YEAR_IN_SECS: usize = 31536000;
+/// How long an exported bundle is kept to be downloaded
+const BUNDLE_TTL_SECS: u64 = 24 * 60 * 60;
+/// Releases listed at a time by an integrity check
+const INTEGRITY_PAGE_SIZE: u64 = 50;
 
 pub fn origin_update(req: &mut Request) -> IronResult<Response> {
     let mut request = OriginUpdate::new();
@@ -430,6 +493,38 @@ pub fn origin_member_delete(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Grants a member of an origin the approver role on PUT, and revokes it on DELETE. Approvers may
+/// review the origin's promotion requests.
+pub fn origin_member_approver_update(req: &mut Request) -> IronResult<Response> {
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_owner(req, session.get_id(), &origin).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let account_name = match get_param(req, "username") {
+        Some(user) => user,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut request = OriginMemberApproverUpdate::new();
+    match helpers::get_origin(req, origin) {
+        Ok(origin) => request.set_origin_id(origin.get_id()),
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+    request.set_account_name(account_name);
+    request.set_approver(req.method == Method::Put);
+
+    match route_message::<OriginMemberApproverUpdate, NetOk>(req, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
 fn write_archive(filename: &PathBuf, body: &mut Body) -> Result<PackageArchive> {
     let file = File::create(&filename)?;
     let mut writer = BufWriter::new(file);
@@ -671,56 +766,95 @@ fn upload_origin_secret_key(req: &mut Request) -> IronResult<Response> {
 }
 
 fn upload_package(req: &mut Request) -> IronResult<Response> {
+    let ident = match upload_ident(req) {
+        Ok(ident) => ident,
+        Err(response) => return Ok(response),
+    };
+    let checksum_from_param = match helpers::extract_query_value("checksum", req) {
+        Some(checksum) => checksum,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    debug!(
+        "UPLOADING checksum={}, ident={}",
+        checksum_from_param,
+        ident
+    );
+
+    let temp_path = match archive_temp_path(req, &ident) {
+        Ok(temp_path) => temp_path,
+        Err(response) => return Ok(response),
+    };
+    write_archive(&temp_path, &mut req.body)?;
+    // Don't schedule builds if the upload is being done by the builder
+    let schedule = helpers::extract_query_value("builder", req).is_none();
+    store_package(req, ident, checksum_from_param, temp_path, schedule)
+}
+
+/// The ident of the package being uploaded, if it's fully qualified and the session has access
+/// to its origin
+fn upload_ident(req: &mut Request) -> result::Result<OriginPackageIdent, Response> {
     let ident = ident_from_req(req);
-    let session_id = helpers::get_optional_session_id(req);
 
     if !ident.valid() || !ident.fully_qualified() {
         info!(
             "Invalid or not fully qualified package identifier: {}",
             ident
         );
-        return Ok(Response::with(status::BadRequest));
+        return Err(Response::with(status::BadRequest));
     }
 
     if !check_origin_access(req, &ident.get_origin()).unwrap_or(false) {
         debug!("Failed origin access check, ident: {}", ident);
 
-        return Ok(Response::with(status::Forbidden));
+        return Err(Response::with(status::Forbidden));
     }
+    Ok(ident)
+}
 
+/// A path to write an uploaded archive of a package to, before it's checked and stored
+fn archive_temp_path(
+    req: &mut Request,
+    ident: &OriginPackageIdent,
+) -> result::Result<PathBuf, Response> {
     let lock = req.get::<persistent::State<DepotUtil>>().expect(
         "depot not found",
     );
-
     let depot = lock.read().expect("depot read lock is poisoned");
-    let checksum_from_param = match helpers::extract_query_value("checksum", req) {
-        Some(checksum) => checksum,
-        None => return Ok(Response::with(status::BadRequest)),
-    };
-
-    debug!(
-        "UPLOADING checksum={}, ident={}",
-        checksum_from_param,
-        ident
-    );
 
     // Find the path to folder where archive should be created, and
     // create the folder if necessary
-    let parent_path = depot.archive_parent(&ident);
+    let parent_path = depot.archive_parent(ident);
 
     match fs::create_dir_all(parent_path.clone()) {
         Ok(_) => {}
         Err(e) => {
             error!("Unable to create archive directory, err={:?}", e);
-            return Ok(Response::with(status::InternalServerError));
+            return Err(Response::with(status::InternalServerError));
         }
     };
 
     // Create a temp file at the archive location
     let temp_name = format!("{}.tmp", Uuid::new_v4());
-    let temp_path = parent_path.join(temp_name);
+    Ok(parent_path.join(temp_name))
+}
+
+/// Checks the archive of a package uploaded to `temp_path` and adds the package to the depot,
+/// scheduling builds of its dependents if `schedule` is set.
+fn store_package(
+    req: &mut Request,
+    ident: OriginPackageIdent,
+    checksum_from_param: String,
+    temp_path: PathBuf,
+    schedule: bool,
+) -> IronResult<Response> {
+    let session_id = helpers::get_optional_session_id(req);
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let depot = lock.read().expect("depot read lock is poisoned");
 
-    let mut archive = write_archive(&temp_path, &mut req.body)?;
+    let mut archive = PackageArchive::new(temp_path.clone());
     debug!("Package Archive: {:#?}", archive);
 
     let target_from_artifact = match archive.target() {
@@ -764,7 +898,14 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
             }
         };
 
-    if origin_package_found && depot.archive(&ident, &target_from_artifact).is_some() {
+    let archive_found = match depot.archive_size(&ident, &target_from_artifact) {
+        Ok(size) => size.is_some(),
+        Err(err) => {
+            warn!("Unable to look up the archive of {}, err={}", ident, err);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    if origin_package_found && archive_found {
         return Ok(Response::with((status::Conflict)));
     };
 
@@ -819,23 +960,8 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         }
     }
 
-    let filename = depot.archive_path(&ident, &target_from_artifact);
-
-    match fs::rename(&temp_path, &filename) {
-        Ok(_) => {}
-        Err(e) => {
-            error!(
-                "Unable to rename temp archive {:?} to {:?}, err={:?}",
-                temp_path,
-                filename,
-                e
-            );
-            return Ok(Response::with(status::InternalServerError));
-        }
-    }
-
-    info!("File added to Depot at {}", filename.to_string_lossy());
-    let mut archive = PackageArchive::new(filename);
+    // The package is read from the archive before it's stored, as it may not be kept on the
+    // local filesystem
     let mut package = match OriginPackageCreate::from_archive(&mut archive) {
         Ok(package) => package,
         Err(e) => {
@@ -844,6 +970,18 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
         }
     };
 
+    let key = depot.archive_key(&ident, &target_from_artifact);
+    if let Err(e) = depot.store.put(&key, &temp_path) {
+        error!(
+            "Unable to store temp archive {:?} as {}, err={}",
+            temp_path,
+            key,
+            e
+        );
+        return Ok(Response::with(status::InternalServerError));
+    }
+    info!("Archive added to Depot as {}", key);
+
     if ident.satisfies(package.get_ident()) {
         {
             let session = req.extensions.get::<Authenticated>().unwrap();
@@ -897,15 +1035,12 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
             if let Err(err) = route_message::<OriginPackageCreate, OriginPackage>(req, &package) {
                 return Ok(render_net_error(&err));
             }
+            webhooks::notify_package_upload(req, &ident);
 
             // Schedule re-build of dependent packages (if requested)
-            // Don't schedule builds if the upload is being done by the builder
             if depot.config.builds_enabled &&
                 (ident.get_origin() == "core" || depot.config.non_core_builds_enabled) &&
-                !match helpers::extract_query_value("builder", req) {
-                    Some(_) => true,
-                    None => false,
-                }
+                schedule
             {
                 let mut request = JobGroupSpec::new();
                 request.set_origin(ident.get_origin().to_string());
@@ -915,8 +1050,11 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
                 request.set_origin_only(!depot.config.non_core_builds_enabled);
                 request.set_package_only(false);
 
-                match route_message::<JobGroupSpec, JobGroup>(req, &request) {
-                    Ok(group) => {
+                match route_message_either::<JobGroupSpec, JobGroup, JobGroupCreateError>(
+                    req,
+                    &request,
+                ) {
+                    Ok(Ok(group)) => {
                         debug!(
                             "Scheduled reverse dependecy build for {}, group id: {}, origin_only: {}",
                             ident,
@@ -924,6 +1062,7 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
                             !depot.config.non_core_builds_enabled
                         )
                     }
+                    Ok(Err(err)) => warn!("Unable to schedule build, {}", err),
                     Err(err) => warn!("Unable to schedule build, err: {:?}", err),
                 }
             }
@@ -949,6 +1088,158 @@ fn upload_package(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Starts a chunked upload of a package, or returns the parts already received if the upload was
+/// started before.
+fn upload_start(req: &mut Request) -> IronResult<Response> {
+    let ident = match upload_ident(req) {
+        Ok(ident) => ident,
+        Err(response) => return Ok(response),
+    };
+    let checksum = match helpers::extract_query_value("checksum", req) {
+        Some(checksum) => checksum,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let (upload, uploads_path) = {
+        let lock = req.get::<persistent::State<DepotUtil>>().expect(
+            "depot not found",
+        );
+        let depot = lock.read().expect("depot read lock is poisoned");
+        (depot.upload(&ident, &checksum), depot.uploads_path())
+    };
+    match upload::remove_idle(&uploads_path, Duration::from_secs(upload::MAX_IDLE_SECS)) {
+        Ok(0) => (),
+        Ok(removed) => debug!("Removed {} idle uploads", removed),
+        Err(e) => warn!("Unable to remove idle uploads, err={:?}", e),
+    }
+    let started = match upload.start().and_then(|started| {
+        upload.parts().map(|parts| (started, parts))
+    }) {
+        Ok(started) => started,
+        Err(e) => {
+            error!("Unable to start upload of {}, err={:?}", ident, e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    };
+    let (created, parts) = started;
+    debug!("UPLOAD STARTED checksum={}, ident={}, parts={:?}", checksum, ident, parts);
+    let upload_status = UploadStatus {
+        checksum: checksum,
+        part_size: upload::PART_SIZE,
+        parts: parts,
+    };
+    let mut response = render_json(
+        if created { status::Created } else { status::Ok },
+        &upload_status,
+    );
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+/// Receives a part of a chunked upload, which is only kept if it matches its checksum.
+fn upload_part(req: &mut Request) -> IronResult<Response> {
+    let ident = match upload_ident(req) {
+        Ok(ident) => ident,
+        Err(response) => return Ok(response),
+    };
+    let (checksum, part) = {
+        let params = req.extensions.get::<Router>().unwrap();
+        let part = params.find("part").and_then(|part| part.parse::<u64>().ok());
+        match (params.find("checksum"), part) {
+            (Some(checksum), Some(part)) => (checksum.to_string(), part),
+            _ => return Ok(Response::with(status::BadRequest)),
+        }
+    };
+    if part >= upload::MAX_PARTS {
+        return Ok(Response::with((status::UnprocessableEntity, "ds:up:9")));
+    }
+    let part_checksum = match helpers::extract_query_value("checksum", req) {
+        Some(checksum) => checksum,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let upload = {
+        let lock = req.get::<persistent::State<DepotUtil>>().expect(
+            "depot not found",
+        );
+        let depot = lock.read().expect("depot read lock is poisoned");
+        depot.upload(&ident, &checksum)
+    };
+    if !upload.is_started() {
+        return Ok(Response::with(status::NotFound));
+    }
+
+    let mut content = Vec::new();
+    if let Err(e) = (&mut req.body)
+        .take(upload::MAX_PART_SIZE + 1)
+        .read_to_end(&mut content)
+    {
+        debug!("Can't read part {} of upload of {}, err={}", part, ident, e);
+        return Ok(Response::with(status::BadRequest));
+    }
+    if content.len() as u64 > upload::MAX_PART_SIZE {
+        return Ok(Response::with(status::PayloadTooLarge));
+    }
+    match upload.write_part(part, &content, &part_checksum) {
+        Ok(true) => Ok(Response::with(status::NoContent)),
+        Ok(false) => {
+            info!(
+                "Checksum of part {} of upload of {} did not match, from_param={:?}",
+                part,
+                ident,
+                part_checksum
+            );
+            Ok(Response::with((status::UnprocessableEntity, "ds:up:7")))
+        }
+        Err(e) => {
+            error!("Unable to write part {} of upload of {}, err={:?}", part, ident, e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+/// Puts the parts of a chunked upload back together and stores the package like a regular upload.
+fn upload_complete(req: &mut Request) -> IronResult<Response> {
+    let ident = match upload_ident(req) {
+        Ok(ident) => ident,
+        Err(response) => return Ok(response),
+    };
+    let checksum = match get_param(req, "checksum") {
+        Some(checksum) => checksum,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let upload = {
+        let lock = req.get::<persistent::State<DepotUtil>>().expect(
+            "depot not found",
+        );
+        let depot = lock.read().expect("depot read lock is poisoned");
+        depot.upload(&ident, &checksum)
+    };
+    if !upload.is_started() {
+        return Ok(Response::with(status::NotFound));
+    }
+    let temp_path = match archive_temp_path(req, &ident) {
+        Ok(temp_path) => temp_path,
+        Err(response) => return Ok(response),
+    };
+    match upload.assemble(&temp_path) {
+        Ok(true) => (),
+        Ok(false) => return Ok(Response::with((status::UnprocessableEntity, "ds:up:8"))),
+        Err(e) => {
+            error!("Unable to assemble upload of {}, err={:?}", ident, e);
+            return Ok(Response::with(status::InternalServerError));
+        }
+    }
+
+    let schedule = helpers::extract_query_value("builder", req).is_none();
+    let response = store_package(req, ident, checksum, temp_path, schedule)?;
+    // The parts are kept around while the upload can still succeed by completing it again
+    if !response.status.map_or(false, |status| status.is_server_error()) {
+        if let Err(e) = upload.remove() {
+            warn!("Unable to remove parts of upload, err={:?}", e);
+        }
+    }
+    Ok(response)
+}
+
 fn package_stats(req: &mut Request) -> IronResult<Response> {
     let mut request = JobGraphPackageStatsGet::new();
     match get_param(req, "origin") {
@@ -973,76 +1264,14 @@ fn schedule(req: &mut Request) -> IronResult<Response> {
         Some(origin) => origin,
         None => return Ok(Response::with(status::BadRequest)),
     };
-
-    if !check_origin_access(req, &origin_name).unwrap_or(false) {
-        debug!("Failed origin access check, origin: {}", &origin_name);
-        return Ok(Response::with(status::Forbidden));
-    }
-
-    {
-        let lock = req.get::<persistent::State<DepotUtil>>().unwrap();
-        let depot = lock.read().unwrap();
-        if !depot.config.builds_enabled ||
-            (origin_name != "core" && !depot.config.non_core_builds_enabled)
-        {
-            return Ok(Response::with(status::Forbidden));
-        }
-    }
-    let package = match get_param(req, "pkg") {
-        Some(pkg) => pkg,
-        None => return Ok(Response::with(status::BadRequest)),
-    };
-    let target = match helpers::extract_query_value("target", req) {
-        Some(target) => target,
-        None => String::from("x86_64-linux"),
-    };
-    let deps_only = helpers::extract_query_value("deps_only", req).is_some();
-    let origin_only = helpers::extract_query_value("origin_only", req).is_some();
-    let package_only = helpers::extract_query_value("package_only", req).is_some();
-
-    // We only support building for Linux x64 only currently
-    if target != "x86_64-linux" {
-        info!("Rejecting build with target: {}", target);
-        return Ok(Response::with(status::BadRequest));
-    }
-
-    let mut secret_key_request = OriginSecretKeyGet::new();
-    let origin = match helpers::get_origin(req, &origin_name) {
-        Ok(origin) => {
-            secret_key_request.set_owner_id(origin.get_owner_id());
-            secret_key_request.set_origin(origin_name.clone());
-            origin
-        }
-        Err(err) => return Ok(render_net_error(&err)),
-    };
     let account_name = session.get_name().to_string();
-    let need_keys =
-        match route_message::<OriginSecretKeyGet, OriginSecretKey>(req, &secret_key_request) {
-            Ok(key) => {
-                let mut pub_key_request = OriginPublicKeyGet::new();
-                pub_key_request.set_origin(origin_name.clone());
-                pub_key_request.set_revision(key.get_revision().to_string());
-                route_message::<OriginPublicKeyGet, OriginPublicKey>(req, &pub_key_request).is_err()
-            }
-            Err(_) => true,
-        };
-
-    if need_keys {
-        if let Err(err) = helpers::generate_origin_keys(req, session, origin) {
-            return Ok(render_net_error(&err));
-        }
-    }
-
-    let mut request = JobGroupSpec::new();
-    request.set_origin(origin_name);
-    request.set_package(package);
-    request.set_target(target);
-    request.set_deps_only(deps_only);
-    request.set_origin_only(origin_only);
-    request.set_package_only(package_only);
+    let request = match schedulable_spec(req, session, &origin_name) {
+        Ok(request) => request,
+        Err(response) => return Ok(response),
+    };
 
-    match route_message::<JobGroupSpec, JobGroup>(req, &request) {
-        Ok(group) => {
+    match route_message_either::<JobGroupSpec, JobGroup, JobGroupCreateError>(req, &request) {
+        Ok(Ok(group)) => {
             let msg = format!("Scheduled job group for {}", group.get_project_name());
 
             // We don't really want to abort anything just because a call to segment failed. Let's
@@ -1055,36 +1284,332 @@ fn schedule(req: &mut Request) -> IronResult<Response> {
             dont_cache_response(&mut response);
             Ok(response)
         }
+        // The packages can't be built until a plan breaks the dependency cycle
+        Ok(Err(err)) => Ok(render_json(status::UnprocessableEntity, &err)),
         Err(err) => Ok(render_net_error(&err)),
     }
 }
 
-fn get_origin_schedule_status(req: &mut Request) -> IronResult<Response> {
-    let mut request = JobGroupOriginGet::new();
-
-    match get_param(req, "origin") {
-        Some(origin) => request.set_origin(origin),
+fn schedule_preview(req: &mut Request) -> IronResult<Response> {
+    let origin_name = match get_param(req, "origin") {
+        Some(origin) => origin,
         None => return Ok(Response::with(status::BadRequest)),
-    }
+    };
 
-    match route_message::<JobGroupOriginGet, JobGroupOriginResponse>(req, &request) {
-        Ok(jgor) => Ok(render_json(status::Ok, &jgor.get_job_groups())),
-        Err(e) => Ok(render_net_error(&e)),
+    if !check_origin_access(req, &origin_name).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
     }
-}
 
-fn get_schedule(req: &mut Request) -> IronResult<Response> {
-    let group_id = {
-        let group_id_str = match get_param(req, "groupid") {
-            Some(s) => s,
-            None => return Ok(Response::with(status::BadRequest)),
-        };
+    let mut request = JobGroupPreview::new();
+    match group_spec(req, &origin_name) {
+        Ok(spec) => request.set_spec(spec),
+        Err(response) => return Ok(response),
+    }
 
-        match group_id_str.parse::<u64>() {
-            Ok(id) => id,
-            Err(_) => return Ok(Response::with(status::BadRequest)),
+    match route_message_either::<JobGroupPreview, JobGroupPreviewResponse, JobGroupCreateError>(
+        req,
+        &request,
+    ) {
+        Ok(Ok(preview)) => {
+            let mut response = render_json(status::Ok, &preview);
+            dont_cache_response(&mut response);
+            Ok(response)
         }
-    };
+        // The packages can't be built until a plan breaks the dependency cycle
+        Ok(Err(err)) => Ok(render_json(status::UnprocessableEntity, &err)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn schedule_cron_create(req: &mut Request) -> IronResult<Response> {
+    let session = req.extensions.get::<Authenticated>().unwrap().clone();
+    let origin_name = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let cron = match helpers::extract_query_value("cron", req) {
+        Some(cron) => {
+            match cron.parse::<Schedule>() {
+                Ok(cron) => cron,
+                Err(err) => {
+                    debug!("Rejecting group schedule, {}", err);
+                    return Ok(Response::with(status::UnprocessableEntity));
+                }
+            }
+        }
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let spec = match schedulable_spec(req, session, &origin_name) {
+        Ok(spec) => spec,
+        Err(response) => return Ok(response),
+    };
+
+    let mut request = JobGroupScheduleCreate::new();
+    request.set_spec(spec);
+    request.set_cron(cron.to_string());
+
+    match route_message::<JobGroupScheduleCreate, JobGroupSchedule>(req, &request) {
+        Ok(schedule) => {
+            let mut response = render_json(status::Created, &schedule);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn schedule_cron_list(req: &mut Request) -> IronResult<Response> {
+    let origin_name = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_access(req, &origin_name).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = JobGroupScheduleListGet::new();
+    request.set_origin(origin_name);
+
+    match route_message::<JobGroupScheduleListGet, JobGroupScheduleListResponse>(req, &request) {
+        Ok(schedules) => {
+            let mut response = render_json(status::Ok, &schedules);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn schedule_usage(req: &mut Request) -> IronResult<Response> {
+    let origin_name = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_access(req, &origin_name).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginBuildUsageGet::new();
+    request.set_origin(origin_name);
+
+    match route_message::<OriginBuildUsageGet, OriginBuildUsage>(req, &request) {
+        Ok(usage) => {
+            let mut response = render_json(status::Ok, &usage);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn schedule_cron_delete(req: &mut Request) -> IronResult<Response> {
+    let origin_name = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let id = match get_param(req, "id").and_then(|id| id.parse::<u64>().ok()) {
+        Some(id) => id,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_access(req, &origin_name).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = JobGroupScheduleDelete::new();
+    request.set_id(id);
+    request.set_origin(origin_name);
+
+    match route_message::<JobGroupScheduleDelete, NetOk>(req, &request) {
+        Ok(_) => Ok(Response::with(status::NoContent)),
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+// Builds the job group spec for a package from the request's query, after checking that the
+// session may build in the origin and that the origin has keys to sign the builds with
+fn schedulable_spec(
+    req: &mut Request,
+    session: Session,
+    origin_name: &str,
+) -> result::Result<JobGroupSpec, Response> {
+    if !check_origin_access(req, origin_name).unwrap_or(false) {
+        debug!("Failed origin access check, origin: {}", origin_name);
+        return Err(Response::with(status::Forbidden));
+    }
+
+    {
+        let lock = req.get::<persistent::State<DepotUtil>>().unwrap();
+        let depot = lock.read().unwrap();
+        if !depot.config.builds_enabled ||
+            (origin_name != "core" && !depot.config.non_core_builds_enabled)
+        {
+            return Err(Response::with(status::Forbidden));
+        }
+    }
+    let mut request = group_spec(req, origin_name)?;
+
+    let requester_id = session.get_id();
+    let requester_name = session.get_name().to_string();
+
+    let mut secret_key_request = OriginSecretKeyGet::new();
+    let origin = match helpers::get_origin(req, origin_name) {
+        Ok(origin) => {
+            secret_key_request.set_owner_id(origin.get_owner_id());
+            secret_key_request.set_origin(origin_name.to_string());
+            origin
+        }
+        Err(err) => return Err(render_net_error(&err)),
+    };
+    let need_keys =
+        match route_message::<OriginSecretKeyGet, OriginSecretKey>(req, &secret_key_request) {
+            Ok(key) => {
+                let mut pub_key_request = OriginPublicKeyGet::new();
+                pub_key_request.set_origin(origin_name.to_string());
+                pub_key_request.set_revision(key.get_revision().to_string());
+                route_message::<OriginPublicKeyGet, OriginPublicKey>(req, &pub_key_request).is_err()
+            }
+            Err(_) => true,
+        };
+
+    if need_keys {
+        if let Err(err) = helpers::generate_origin_keys(req, session, origin) {
+            return Err(render_net_error(&err));
+        }
+    }
+
+    request.set_requester_id(requester_id);
+    request.set_requester_name(requester_name);
+    Ok(request)
+}
+
+// The JobGroupSpec described by a schedule request's package and query parameters
+fn group_spec(req: &mut Request, origin_name: &str) -> result::Result<JobGroupSpec, Response> {
+    let package = match get_param(req, "pkg") {
+        Some(pkg) => pkg,
+        None => return Err(Response::with(status::BadRequest)),
+    };
+    let target = match helpers::extract_query_value("target", req) {
+        Some(target) => target,
+        None => String::from("x86_64-linux"),
+    };
+    let deps_only = helpers::extract_query_value("deps_only", req).is_some();
+    let origin_only = helpers::extract_query_value("origin_only", req).is_some();
+    let package_only = helpers::extract_query_value("package_only", req).is_some();
+    let priority = match helpers::extract_query_value("priority", req) {
+        Some(priority) => {
+            match priority.parse::<JobPriority>() {
+                Ok(priority) => priority,
+                Err(_) => return Err(Response::with(status::BadRequest)),
+            }
+        }
+        None => JobPriority::default(),
+    };
+    let tags = match helpers::extract_query_value("tags", req) {
+        Some(tags) => split_query_list(&tags),
+        None => vec![],
+    };
+    let packages = match helpers::extract_query_value("packages", req) {
+        Some(packages) => split_query_list(&packages),
+        None => vec![],
+    };
+
+    if !is_supported_target(&target) {
+        info!("Rejecting build with target: {}", target);
+        return Err(Response::with(status::BadRequest));
+    }
+
+    let mut request = JobGroupSpec::new();
+    request.set_origin(origin_name.to_string());
+    request.set_package(package);
+    request.set_target(target);
+    request.set_deps_only(deps_only);
+    request.set_origin_only(origin_only);
+    request.set_package_only(package_only);
+    request.set_priority(priority);
+    request.set_tags(tags.into());
+    request.set_packages(packages.into());
+    Ok(request)
+}
+
+// Whether packages can be built for the given target
+fn is_supported_target(target: &str) -> bool {
+    match PackageTarget::from_str(target) {
+        Ok(target) => {
+            SUPPORTED_TARGETS.iter().any(|supported| {
+                PackageTarget::from_str(supported).ok().as_ref() == Some(&target)
+            })
+        }
+        Err(_) => false,
+    }
+}
+
+// Splits a comma separated query value into its non-empty items
+fn split_query_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|v| v.trim())
+        .filter(|v| !v.is_empty())
+        .map(|v| v.to_string())
+        .collect()
+}
+
+fn get_origin_schedule_status(req: &mut Request) -> IronResult<Response> {
+    let mut request = JobGroupOriginGet::new();
+
+    match get_param(req, "origin") {
+        Some(origin) => request.set_origin(origin),
+        None => return Ok(Response::with(status::BadRequest)),
+    }
+
+    let page = match helpers::extract_page(req) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
+    page.apply(&mut request);
+
+    if let Some(state) = helpers::extract_query_value("state", req) {
+        match state.parse::<JobGroupState>() {
+            Ok(state) => request.set_state(state),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    }
+
+    if let Some(created_since) = helpers::extract_query_value("created_since", req) {
+        match timestamp::normalize(&created_since) {
+            Ok(created_since) => request.set_created_since(created_since),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    }
+
+    match route_message::<JobGroupOriginGet, JobGroupOriginResponse>(req, &request) {
+        Ok(jgor) => {
+            helpers::paginated_response(
+                &jgor.get_job_groups().to_vec(),
+                jgor.get_count() as isize,
+                jgor.get_start() as isize,
+                jgor.get_stop() as isize,
+                &page,
+            )
+        }
+        Err(e) => Ok(render_net_error(&e)),
+    }
+}
+
+fn get_schedule(req: &mut Request) -> IronResult<Response> {
+    let group_id = {
+        let group_id_str = match get_param(req, "groupid") {
+            Some(s) => s,
+            None => return Ok(Response::with(status::BadRequest)),
+        };
+
+        match group_id_str.parse::<u64>() {
+            Ok(id) => id,
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    };
 
     let mut request = JobGroupGet::new();
     request.set_group_id(group_id);
@@ -1124,7 +1649,28 @@ fn abort_schedule(req: &mut Request) -> IronResult<Response> {
 }
 
 // This function should not require authentication (session/auth token)
+/// Serves a public origin key, from the upstream Builder if the depot caches one and doesn't have
+/// the key itself
 fn download_origin_key(req: &mut Request) -> IronResult<Response> {
+    let response = download_local_origin_key(req)?;
+    if response.status != Some(status::NotFound) {
+        return Ok(response);
+    }
+    let (origin, revision) = match (get_param(req, "origin"), get_param(req, "revision")) {
+        (Some(origin), Some(revision)) => (origin, revision),
+        _ => return Ok(response),
+    };
+    match from_upstream(req, |upstream| upstream.origin_key(&origin, &revision)) {
+        Some(path) => {
+            let mut response = cached_key_response(&path)?;
+            do_cache_response(&mut response);
+            Ok(response)
+        }
+        None => Ok(response),
+    }
+}
+
+fn download_local_origin_key(req: &mut Request) -> IronResult<Response> {
     let mut request = OriginPublicKeyGet::new();
     match get_param(req, "origin") {
         Some(origin) => request.set_origin(origin),
@@ -1150,6 +1696,34 @@ fn download_origin_key(req: &mut Request) -> IronResult<Response> {
 
 // This function should not require authentication (session/auth token)
 fn download_latest_origin_key(req: &mut Request) -> IronResult<Response> {
+    let response = download_latest_local_origin_key(req)?;
+    if response.status != Some(status::NotFound) {
+        return Ok(response);
+    }
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(response),
+    };
+    match from_upstream(req, |upstream| upstream.latest_origin_key(&origin)) {
+        Some(path) => cached_key_response(&path),
+        None => Ok(response),
+    }
+}
+
+/// Serves a public origin key cached from the upstream Builder
+fn cached_key_response(path: &Path) -> IronResult<Response> {
+    let mut content = Vec::new();
+    if let Err(err) = File::open(path).and_then(|mut file| file.read_to_end(&mut content)) {
+        warn!("Unable to read cached key {}, err={}", path.display(), err);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    let filename = path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    download_content_as_file(&content, filename)
+}
+
+fn download_latest_local_origin_key(req: &mut Request) -> IronResult<Response> {
     let mut request = OriginPublicKeyLatestGet::new();
     match get_param(req, "origin") {
         Some(origin) => request.set_origin(origin),
@@ -1199,7 +1773,35 @@ fn package_channels(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Serves the archive of a package, from the upstream Builder if the depot caches one and
+/// doesn't have the package itself
 fn download_package(req: &mut Request) -> IronResult<Response> {
+    let response = download_local_package(req)?;
+    let ident = ident_from_req(req);
+    if response.status != Some(status::NotFound) || !ident.fully_qualified() {
+        return Ok(response);
+    }
+    let mut archive = match from_upstream(req, |upstream| upstream.package(&ident)) {
+        Some(archive) => archive,
+        None => return Ok(response),
+    };
+    let (len, checksum) = match (fs::metadata(&archive.path), archive.checksum()) {
+        (Ok(metadata), Ok(checksum)) => (metadata.len(), checksum),
+        _ => return Ok(Response::with(status::InternalServerError)),
+    };
+    let range = byte_range(req.headers.get::<Range>(), len);
+    if range == ByteRange::Unsatisfiable {
+        return Ok(unsatisfiable_response(len));
+    }
+    let path = archive.path.clone();
+    let mut response = archive_response(range, len, archive.file_name(), &checksum, |first, last| {
+        Ok(read_range(&path, first, last)?)
+    });
+    do_cache_response(&mut response);
+    Ok(response)
+}
+
+fn download_local_package(req: &mut Request) -> IronResult<Response> {
     let lock = req.get::<persistent::State<DepotUtil>>().expect(
         "depot not found",
     );
@@ -1207,98 +1809,481 @@ fn download_package(req: &mut Request) -> IronResult<Response> {
     let session_id = helpers::get_optional_session_id(req);
     let mut ident_req = OriginPackageGet::new();
     let ident = ident_from_req(req);
-    let mut vis = visibility_for_optional_session(req, session_id, &ident.get_origin());
-    vis.push(OriginPackageVisibility::Hidden);
-    ident_req.set_visibilities(vis);
-    ident_req.set_ident(ident);
-
-    let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
-    if !depot.config.targets.contains(&agent_target) {
-        return Ok(Response::with((
-            status::NotImplemented,
-            "Unsupported client platform ({}).",
-        )));
+    let signed = match signed_download(req, &depot.config.download_url_secret, &ident) {
+        Ok(signed) => signed,
+        Err(response) => return Ok(response),
+    };
+    if signed {
+        ident_req.set_visibilities(all_visibilities());
+    } else {
+        let mut vis = visibility_for_optional_session(req, session_id, &ident.get_origin());
+        vis.push(OriginPackageVisibility::Hidden);
+        ident_req.set_visibilities(vis);
     }
+    ident_req.set_ident(ident);
 
     match route_message::<OriginPackageGet, OriginPackage>(req, &ident_req) {
         Ok(package) => {
-            if let Some(archive) = depot.archive(package.get_ident(), &agent_target) {
-                match fs::metadata(&archive.path) {
-                    Ok(_) => {
-                        let mut response = Response::with((status::Ok, archive.path.clone()));
-                        do_cache_response(&mut response);
-                        let disp = ContentDisposition {
-                            disposition: DispositionType::Attachment,
-                            parameters: vec![
-                                DispositionParam::Filename(
-                                    Charset::Iso_8859_1,
-                                    None,
-                                    archive.file_name().as_bytes().to_vec()
-                                ),
-                            ],
-                        };
-                        response.headers.set(disp);
-                        response.headers.set(XFileName(archive.file_name()));
-                        Ok(response)
-                    }
-                    Err(_) => Ok(Response::with(status::NotFound)),
+            // The archive is looked up by the target the release was built for, rather than the
+            // client's, which lets any client download the artifacts of every target
+            let target = match package_target(&package) {
+                Some(target) => target,
+                None => return Ok(Response::with(status::NotFound)),
+            };
+            if !depot.config.targets.contains(&target) {
+                return Ok(Response::with((
+                    status::NotImplemented,
+                    format!("Unsupported package platform ({}).", target),
+                )));
+            }
+            let len = match depot.archive_size(package.get_ident(), &target) {
+                Ok(Some(len)) => len,
+                Ok(None) => {
+                    // This can happen if the package is not found in the store for some reason
+                    error!("Inconsistentcy between metadata and filesystem!");
+                    return Ok(Response::with(status::InternalServerError));
+                }
+                Err(err) => {
+                    warn!(
+                        "Unable to look up the archive of {}, err={}",
+                        package.get_ident(),
+                        err
+                    );
+                    return Ok(Response::with(status::InternalServerError));
                 }
+            };
+            if has_archive(req, package.get_checksum()) {
+                let mut response = Response::with(status::NotModified);
+                response.headers.set(ETag(package.get_checksum().to_string()));
+                return Ok(response);
+            }
+            let range = byte_range(req.headers.get::<Range>(), len);
+            // A download split into ranges only counts once, by its first range
+            let counted = match range {
+                ByteRange::Partial(first, _) => first == 0,
+                ByteRange::Full => true,
+                ByteRange::Unsatisfiable => return Ok(unsatisfiable_response(len)),
+            };
+            let key = depot.archive_key(package.get_ident(), &target);
+            let mut response = archive_response(
+                range,
+                len,
+                depot.archive_name(package.get_ident(), &target),
+                package.get_checksum(),
+                |first, last| depot.store.get(&key, first, last),
+            );
+            if signed {
+                dont_cache_response(&mut response);
             } else {
-                // This can happen if the package is not found in the file system for some reason
-                error!("Inconsistentcy between metadata and filesystem!");
-                Ok(Response::with(status::InternalServerError))
+                do_cache_response(&mut response);
             }
+            if counted {
+                record_download(req, package.get_ident());
+            }
+            Ok(response)
         }
         Err(err) => return Ok(render_net_error(&err)),
     }
 }
 
-fn list_origin_keys(req: &mut Request) -> IronResult<Response> {
-    let origin_name = match get_param(req, "origin") {
-        Some(origin) => origin,
-        None => return Ok(Response::with(status::BadRequest)),
-    };
-
-    let mut request = OriginPublicKeyListRequest::new();
-    match helpers::get_origin(req, &origin_name) {
-        Ok(origin) => request.set_origin_id(origin.get_id()),
-        Err(err) => return Ok(render_net_error(&err)),
-    }
-    match route_message::<OriginPublicKeyListRequest, OriginPublicKeyListResponse>(req, &request) {
-        Ok(list) => {
-            let list: Vec<OriginKeyIdent> = list.get_keys()
-                .iter()
-                .map(|key| {
-                    let mut ident = OriginKeyIdent::new();
-                    ident.set_location(format!(
-                        "/origins/{}/keys/{}",
-                        &key.get_name(),
-                        &key.get_revision()
-                    ));
-                    ident.set_origin(key.get_name().to_string());
-                    ident.set_revision(key.get_revision().to_string());
-                    ident
-                })
-                .collect();
-            let body = serde_json::to_string(&list).unwrap();
-            let mut response = Response::with((status::Ok, body));
-            dont_cache_response(&mut response);
-            Ok(response)
+/// Serves the delta turning the archive of an earlier release of a package into the archive of
+/// this one. Deltas are generated the first time they're asked for, and are only served when
+/// they're smaller than the archive itself.
+fn download_package_delta(req: &mut Request) -> IronResult<Response> {
+    let session_id = helpers::get_optional_session_id(req);
+    let ident = ident_from_req(req);
+    let mut from = ident.clone();
+    match (get_param(req, "from_version"), get_param(req, "from_release")) {
+        (Some(version), Some(release)) => {
+            from.set_version(version);
+            from.set_release(release);
         }
-        Err(err) => Ok(render_net_error(&err)),
+        _ => return Ok(Response::with(status::BadRequest)),
+    }
+    if !ident.valid() || !from.valid() || from == ident {
+        return Ok(Response::with(status::BadRequest));
     }
-}
 
-fn list_unique_packages(req: &mut Request) -> IronResult<Response> {
+    let mut vis = visibility_for_optional_session(req, session_id, &ident.get_origin());
+    vis.push(OriginPackageVisibility::Hidden);
+    let mut packages = Vec::new();
+    for ident in vec![from.clone(), ident.clone()] {
+        let mut request = OriginPackageGet::new();
+        request.set_visibilities(vis.clone());
+        request.set_ident(ident);
+        match route_message::<OriginPackageGet, OriginPackage>(req, &request) {
+            Ok(package) => packages.push(package),
+            Err(err) => return Ok(render_net_error(&err)),
+        }
+    }
+    // Both releases have to be built for the same target for a delta between them to be useful
+    let target = match (package_target(&packages[0]), package_target(&packages[1])) {
+        (Some(from_target), Some(target)) if from_target == target => target,
+        _ => return Ok(Response::with(status::NotFound)),
+    };
+
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let (from_archive, archive) = match (
+        depot.archive(&from, &target),
+        depot.archive(&ident, &target),
+    ) {
+        (Some(from_archive), Some(archive)) => (from_archive, archive),
+        _ => return Ok(Response::with(status::NotFound)),
+    };
+    let len = match (fs::metadata(&from_archive.path), fs::metadata(&archive.path)) {
+        (Ok(from_metadata), Ok(metadata)) => {
+            if from_metadata.len() > delta::MAX_ARCHIVE_SIZE ||
+                metadata.len() > delta::MAX_ARCHIVE_SIZE
+            {
+                return Ok(Response::with(status::NotFound));
+            }
+            metadata.len()
+        }
+        _ => return Ok(Response::with(status::NotFound)),
+    };
+
+    let path = depot.delta_path(&from, &ident, &target);
+    if !path.is_file() {
+        if let Err(err) = delta::generate(&from_archive.path, &archive.path, &path) {
+            warn!(
+                "Unable to generate delta from {} to {}, err={}",
+                from,
+                ident,
+                err
+            );
+            return Ok(Response::with(status::InternalServerError));
+        }
+        debug!("Generated delta from {} to {}", from, ident);
+    }
+    let checksum = match (fs::metadata(&path), hash::hash_file(&path)) {
+        // A delta which isn't smaller than the archive isn't worth downloading
+        (Ok(metadata), Ok(_)) if metadata.len() >= len => {
+            return Ok(Response::with(status::NotFound))
+        }
+        (Ok(_), Ok(checksum)) => checksum,
+        _ => return Ok(Response::with(status::InternalServerError)),
+    };
+
+    let xfilename = format!("{}.delta", archive.file_name());
+    let mut response = Response::with((status::Ok, path));
+    response.headers.set(ETag(checksum));
+    response.headers.set(ContentDisposition(
+        format!("attachment; filename=\"{}\"", xfilename),
+    ));
+    response.headers.set(XFileName(xfilename));
+    do_cache_response(&mut response);
+    Ok(response)
+}
+
+/// Serves an archive of `len` bytes with the given file name and checksum, or the range of it a
+/// request asks for. The bytes of the archive from one offset up to and including another are
+/// read with `read`.
+fn archive_response<F>(
+    range: ByteRange,
+    len: u64,
+    file_name: String,
+    checksum: &str,
+    read: F,
+) -> Response
+where
+    F: FnOnce(u64, u64) -> Result<Box<Read + Send>>,
+{
+    let (mut response, first, count) = match range {
+        ByteRange::Partial(first, last) => {
+            let mut response = Response::with(status::PartialContent);
+            response.headers.set(ContentRange(ContentRangeSpec::Bytes {
+                range: Some((first, last)),
+                instance_length: Some(len),
+            }));
+            (response, first, last - first + 1)
+        }
+        _ => (Response::with(status::Ok), 0, len),
+    };
+    let body: Box<Read + Send> = if count == 0 {
+        Box::new(io::empty())
+    } else {
+        match read(first, first + count - 1) {
+            Ok(body) => body,
+            Err(err) => {
+                warn!("Unable to read archive {}, err={}", file_name, err);
+                return Response::with(status::InternalServerError);
+            }
+        }
+    };
+    response.body = Some(Box::new(body));
+    response.headers.set(ContentLength(count));
+    response.headers.set(ContentType(Mime(
+        TopLevel::Application,
+        SubLevel::Ext("octet-stream".to_string()),
+        vec![],
+    )));
+    response.headers.set(AcceptRanges(vec![RangeUnit::Bytes]));
+    response.headers.set(ETag(checksum.to_string()));
+    let disp = ContentDisposition {
+        disposition: DispositionType::Attachment,
+        parameters: vec![
+            DispositionParam::Filename(
+                Charset::Iso_8859_1,
+                None,
+                file_name.as_bytes().to_vec()
+            ),
+        ],
+    };
+    response.headers.set(disp);
+    response.headers.set(XFileName(file_name));
+    response
+}
+
+/// Whether the client already has the archive with the given checksum, which it tells by sending
+/// the checksum in an `If-None-Match` header
+fn has_archive(req: &Request, checksum: &str) -> bool {
+    req.headers.get::<IfNoneMatch>().map_or(false, |tags| {
+        tags.split(',')
+            .map(|tag| tag.trim().trim_matches('"'))
+            .any(|tag| tag == "*" || tag == checksum)
+    })
+}
+
+/// Answers a request for a range of bytes starting past the end of an archive of `len` bytes
+fn unsatisfiable_response(len: u64) -> Response {
+    let mut response = Response::with(status::RangeNotSatisfiable);
+    response.headers.set(ContentRange(ContentRangeSpec::Bytes {
+        range: None,
+        instance_length: Some(len),
+    }));
+    response
+}
+
+/// Fetches something the depot doesn't have from the upstream Builder it caches. Nothing is
+/// fetched if the depot doesn't cache one.
+fn from_upstream<T, F>(req: &mut Request, fetch: F) -> Option<T>
+where
+    F: FnOnce(&Upstream) -> Result<T>,
+{
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let depot = lock.read().expect("depot read lock is poisoned");
+    match depot.upstream {
+        Some(ref upstream) => {
+            match fetch(upstream) {
+                Ok(fetched) => Some(fetched),
+                Err(err) => {
+                    warn!("Unable to fetch from upstream Builder, err={}", err);
+                    None
+                }
+            }
+        }
+        None => None,
+    }
+}
+
+/// Whether a package is downloaded through a signed URL. Signed URLs which are invalid or have
+/// expired are rejected, rather than falling back to what the session may download.
+fn signed_download(
+    req: &mut Request,
+    secret: &str,
+    ident: &OriginPackageIdent,
+) -> result::Result<bool, Response> {
+    let signature = match helpers::extract_query_value("signature", req) {
+        Some(signature) => signature,
+        None => return Ok(false),
+    };
+    let expires = match helpers::extract_query_value("expires", req).and_then(|expires| {
+        expires.parse::<i64>().ok()
+    }) {
+        Some(expires) => expires,
+        None => return Err(Response::with(status::BadRequest)),
+    };
+    let now = time::now_utc().to_timespec().sec;
+    if download_url::verify(secret, &ident.to_string(), expires, &signature, now) {
+        Ok(true)
+    } else {
+        Err(Response::with(status::Forbidden))
+    }
+}
+
+/// Signs a URL downloading a package, which is valid for `expires_in` seconds. Downloads through
+/// it don't need a session, so that machines can download packages of private origins.
+fn download_url_create(req: &mut Request) -> IronResult<Response> {
+    let secret = {
+        let lock = req.get::<persistent::State<DepotUtil>>().expect(
+            "depot not found",
+        );
+        let depot = lock.read().expect("depot read lock is poisoned");
+        depot.config.download_url_secret.clone()
+    };
+    if secret.is_empty() {
+        return Ok(Response::with((status::NotImplemented, "ds:dl-url:0")));
+    }
+
+    let ident = ident_from_req(req);
+    if !check_origin_access(req, ident.get_origin()).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let ttl = match helpers::extract_query_value("expires_in", req) {
+        Some(ttl) => {
+            match ttl.parse::<i64>() {
+                Ok(ttl) if ttl > 0 && ttl <= download_url::MAX_TTL => ttl,
+                _ => return Ok(Response::with((status::BadRequest, "ds:dl-url:1"))),
+            }
+        }
+        None => download_url::DEFAULT_TTL,
+    };
+
+    let mut request = OriginPackageGet::new();
+    request.set_ident(ident.clone());
+    request.set_visibilities(all_visibilities());
+    if let Err(err) = route_message::<OriginPackageGet, OriginPackage>(req, &request) {
+        return Ok(render_net_error(&err));
+    }
+
+    let expires = time::now_utc().to_timespec().sec + ttl;
+    let signature = download_url::sign(&secret, &ident.to_string(), expires);
+    let mut url: url::Url = match req.extensions.get::<OriginalUrl>() {
+        Some(url) => url.clone().into(),
+        None => req.url.clone().into(),
+    };
+    // The download route sits next to this one, and the URL keeps the prefix the depot is
+    // mounted at
+    let path = url.path().trim_right_matches("_url").to_string();
+    url.set_path(&path);
+    url.set_query(Some(
+        &format!("expires={}&signature={}", expires, signature),
+    ));
+    let body = json!({
+        "url": url.as_str(),
+        "expires_at": time::at_utc(time::Timespec::new(expires, 0)).rfc3339().to_string(),
+    });
+    let mut response = render_json(status::Ok, &body);
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+/// Counts a download of a package. A download which can't be counted is still served.
+fn record_download(req: &mut Request, ident: &OriginPackageIdent) {
+    let mut request = OriginPackageDownloadRecord::new();
+    request.set_ident(ident.clone());
+    if let Some(channel) = get_param(req, "channel") {
+        request.set_channel(channel);
+    }
+    if let Err(err) = route_message::<OriginPackageDownloadRecord, NetOk>(req, &request) {
+        warn!("Unable to record download of {}, err={}", ident, err);
+    }
+}
+
+fn package_download_stats(req: &mut Request) -> IronResult<Response> {
+    let mut request = OriginPackageDownloadStatsGet::new();
+    match get_param(req, "origin") {
+        Some(origin) => request.set_origin(origin),
+        None => return Ok(Response::with(status::BadRequest)),
+    }
+    match get_param(req, "pkg") {
+        Some(pkg) => request.set_name(pkg),
+        None => return Ok(Response::with(status::BadRequest)),
+    }
+
+    if !check_origin_access(req, request.get_origin()).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    if let Some(since) = helpers::extract_query_value("since", req) {
+        match timestamp::normalize(&since) {
+            Ok(since) => request.set_since(since),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    }
+
+    match route_message::<OriginPackageDownloadStatsGet, OriginPackageDownloadStatsResponse>(
+        req,
+        &request,
+    ) {
+        Ok(stats) => {
+            let mut response = render_json(status::Ok, &stats);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn list_origin_keys(req: &mut Request) -> IronResult<Response> {
+    let origin_name = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut request = OriginPublicKeyListRequest::new();
+    match helpers::get_origin(req, &origin_name) {
+        Ok(origin) => request.set_origin_id(origin.get_id()),
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+    match route_message::<OriginPublicKeyListRequest, OriginPublicKeyListResponse>(req, &request) {
+        Ok(list) => {
+            let list: Vec<OriginKeyIdent> = list.get_keys().iter().map(origin_key_ident).collect();
+            let body = serde_json::to_string(&list).unwrap();
+            let mut response = Response::with((status::Ok, body));
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Deprecates every public key of an origin older than its latest one, replying with the keys
+/// which were deprecated.
+fn deprecate_origin_keys(req: &mut Request) -> IronResult<Response> {
+    let origin_name = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut request = OriginPublicKeyDeprecate::new();
+    match helpers::get_origin(req, &origin_name) {
+        Ok(origin) => request.set_origin_id(origin.get_id()),
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+    let mut latest = OriginPublicKeyLatestGet::new();
+    latest.set_origin(origin_name);
+    match route_message::<OriginPublicKeyLatestGet, OriginPublicKey>(req, &latest) {
+        Ok(mut key) => request.set_revision(key.take_revision()),
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+    match route_message::<OriginPublicKeyDeprecate, OriginPublicKeyListResponse>(req, &request) {
+        Ok(list) => {
+            let list: Vec<OriginKeyIdent> = list.get_keys().iter().map(origin_key_ident).collect();
+            let mut response = render_json(status::Ok, &list);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+fn origin_key_ident(key: &OriginPublicKey) -> OriginKeyIdent {
+    let mut ident = OriginKeyIdent::new();
+    ident.set_location(format!(
+        "/origins/{}/keys/{}",
+        &key.get_name(),
+        &key.get_revision()
+    ));
+    ident.set_origin(key.get_name().to_string());
+    ident.set_revision(key.get_revision().to_string());
+    ident.set_deprecated(key.get_deprecated());
+    ident
+}
+
+fn list_unique_packages(req: &mut Request) -> IronResult<Response> {
     let session_id = helpers::get_optional_session_id(req);
     let mut request = OriginPackageUniqueListRequest::new();
-    let (start, stop) = match helpers::extract_pagination(req) {
-        Ok(range) => range,
+    let page = match helpers::extract_page(req) {
+        Ok(page) => page,
         Err(response) => return Ok(response),
     };
-
-    request.set_start(start as u64);
-    request.set_stop(stop as u64);
+    page.apply(&mut request);
 
     match get_param(req, "origin") {
         Some(origin) => {
@@ -1324,6 +2309,7 @@ fn list_unique_packages(req: &mut Request) -> IronResult<Response> {
                 packages.get_count() as isize,
                 packages.get_start() as isize,
                 packages.get_stop() as isize,
+                &page,
             );
 
             let mut response =
@@ -1345,102 +2331,840 @@ fn list_unique_packages(req: &mut Request) -> IronResult<Response> {
     }
 }
 
-fn list_package_versions(req: &mut Request) -> IronResult<Response> {
-    let session_id = helpers::get_optional_session_id(req);
+fn list_package_versions(req: &mut Request) -> IronResult<Response> {
+    let session_id = helpers::get_optional_session_id(req);
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let name = match get_param(req, "pkg") {
+        Some(pkg) => pkg,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    let mut request = OriginPackageVersionListRequest::new();
+    request.set_visibilities(visibility_for_optional_session(req, session_id, &origin));
+    request.set_origin(origin);
+    request.set_name(name);
+
+    match route_message::<OriginPackageVersionListRequest, OriginPackageVersionListResponse>(
+        req,
+        &request,
+    ) {
+        Ok(packages) => {
+            let body = serde_json::to_string(&packages.get_versions().to_vec()).unwrap();
+            let mut response = Response::with((status::Ok, body));
+
+            response.headers.set(ContentType(Mime(
+                TopLevel::Application,
+                SubLevel::Json,
+                vec![(Attr::Charset, Value::Utf8)],
+            )));
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+}
+
+fn package_privacy_toggle(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(o) => o,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let visibility = match get_param(req, "visibility") {
+        Some(v) => v,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+
+    // users aren't allowed to set packages to hidden manually
+    if visibility.to_lowercase() == "hidden" {
+        return Ok(Response::with(status::BadRequest));
+    }
+
+    let ident = ident_from_req(req);
+
+    if !ident.valid() || !ident.fully_qualified() {
+        info!(
+            "Invalid or not fully qualified package identifier: {}",
+            ident
+        );
+        return Ok(Response::with(status::BadRequest));
+    }
+
+    let opv: OriginPackageVisibility = match visibility.parse() {
+        Ok(o) => o,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+
+    if !check_origin_access(req, &origin).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut opg = OriginPackageGet::new();
+    opg.set_ident(ident);
+    opg.set_visibilities(all_visibilities());
+
+    match route_message::<OriginPackageGet, OriginPackage>(req, &opg) {
+        Ok(mut package) => {
+            let real_visibility = transition_visibility(opv, package.get_visibility());
+            let mut opu = OriginPackageUpdate::new();
+            package.set_visibility(real_visibility);
+            opu.set_pkg(package);
+
+            match route_message::<OriginPackageUpdate, NetOk>(req, &opu) {
+                Ok(_) => Ok(Response::with(status::Ok)),
+                Err(e) => Ok(render_net_error(&e)),
+            }
+        }
+        Err(e) => Ok(render_net_error(&e)),
+    }
+}
+
+/// Yanks a release on PUT, which hides it from `latest` and from resolving channels while it can
+/// still be downloaded by its ident, and restores it on DELETE.
+fn package_yank(req: &mut Request) -> IronResult<Response> {
+    let ident = ident_from_req(req);
+    if !ident.valid() || !ident.fully_qualified() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    if !check_origin_access(req, ident.get_origin()).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+
+    let mut request = OriginPackageYank::new();
+    request.set_yanked(req.method == Method::Put);
+    if request.get_yanked() {
+        match req.get::<bodyparser::Struct<PackageYankReq>>() {
+            Ok(Some(body)) => request.set_reason(body.reason),
+            Ok(None) => (),
+            Err(_) => return Ok(Response::with(status::UnprocessableEntity)),
+        }
+    }
+    request.set_ident(ident);
+
+    match route_message::<OriginPackageYank, OriginPackage>(req, &request) {
+        Ok(package) => {
+            let mut response = render_json(status::Ok, &package);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(err) => Ok(render_net_error(&err)),
+    }
+}
+
+/// Deletes the releases of an origin which have passed the depot's retention, or only reports
+/// which ones it would delete for a dry run. A dry run may try out other settings than the
+/// configured ones.
+fn package_retention(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let dry_run = req.method == Method::Get;
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let depot = lock.read().expect("depot read lock is poisoned");
+
+    let mut request = OriginPackageRetentionCandidatesGet::new();
+    request.set_origin(origin);
+    request.set_max_age_days(depot.config.retention.max_age_days);
+    request.set_keep_releases(depot.config.retention.keep_releases);
+    if dry_run {
+        if let Some(days) = helpers::extract_query_value("max_age_days", req) {
+            match days.parse::<u32>() {
+                Ok(days) => request.set_max_age_days(days),
+                Err(_) => return Ok(Response::with(status::BadRequest)),
+            }
+        }
+        if let Some(releases) = helpers::extract_query_value("keep_releases", req) {
+            match releases.parse::<u32>() {
+                Ok(releases) => request.set_keep_releases(releases),
+                Err(_) => return Ok(Response::with(status::BadRequest)),
+            }
+        }
+    }
+    if request.get_max_age_days() == 0 {
+        return Ok(Response::with(status::NotAcceptable));
+    }
+
+    let candidates = match route_message::<
+        OriginPackageRetentionCandidatesGet,
+        OriginPackageRetentionCandidatesResponse,
+    >(req, &request) {
+        Ok(candidates) => candidates,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    let mut report = retention::RetentionReport::new(dry_run);
+    for package in candidates.get_packages() {
+        let ident = package.get_ident();
+        let target = match PackageTarget::from_str(package.get_target()) {
+            Ok(target) => target,
+            Err(err) => {
+                warn!("Skipping {} with unknown target, err={}", ident, err);
+                continue;
+            }
+        };
+        let size = match depot.archive_size(ident, &target) {
+            Ok(size) => size.unwrap_or(0),
+            Err(err) => {
+                warn!("Unable to read the archive size of {}, err={}", ident, err);
+                0
+            }
+        };
+        if !dry_run {
+            let mut delete = OriginPackageDelete::new();
+            delete.set_ident(ident.clone());
+            match route_message::<OriginPackageDelete, OriginPackage>(req, &delete) {
+                Ok(_) => (),
+                // Promoted to stable since it was picked
+                Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => continue,
+                Err(err) => return Ok(render_net_error(&err)),
+            }
+            if let Err(err) = retention::delete_archive(&depot, ident, &target) {
+                warn!("Unable to delete the archive of {}, err={}", ident, err);
+            }
+        }
+        report.add(ident, &target, size);
+    }
+    if !dry_run {
+        info!(
+            "Retention deleted {} releases of {}, reclaiming {} bytes",
+            report.packages.len(),
+            request.get_origin(),
+            report.bytes
+        );
+    }
+
+    let mut response = render_json(status::Ok, &report);
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+/// Checks the archive of every release of an origin against its metadata and moves the corrupt
+/// ones into the depot's quarantine, or only reports which ones are corrupt for a dry run.
+fn package_integrity(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    let dry_run = req.method == Method::Get;
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let depot = lock.read().expect("depot read lock is poisoned");
+
+    // The public keys of the origin are fetched into a directory of their own as they're needed
+    let keys = depot.quarantine_path().join("tmp").join(
+        Uuid::new_v4().to_string(),
+    );
+    if let Err(e) = fs::create_dir_all(&keys) {
+        error!("Unable to create {}, err={}", keys.display(), e);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    let report = check_integrity(req, &depot, &origin, &keys, dry_run);
+    if let Err(e) = fs::remove_dir_all(&keys) {
+        warn!("Unable to remove {}, err={}", keys.display(), e);
+    }
+    let report = match report {
+        Ok(report) => report,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+    if !dry_run {
+        info!(
+            "Integrity check of {} quarantined {} of {} releases",
+            origin,
+            report.packages.iter().filter(|p| p.quarantined).count(),
+            report.checked
+        );
+    }
+
+    let mut response = render_json(status::Ok, &report);
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+/// Checks the archives of the releases of an origin a page at a time, using `keys` as the cache
+/// of the origin's public keys
+fn check_integrity(
+    req: &mut Request,
+    depot: &DepotUtil,
+    origin: &str,
+    keys: &Path,
+    dry_run: bool,
+) -> NetResult<integrity::IntegrityReport> {
+    let mut report = integrity::IntegrityReport::new(dry_run);
+    let mut start = 0;
+    loop {
+        let mut request = OriginPackageListRequest::new();
+        request.set_ident(OriginPackageIdent::from_str(origin).expect(
+            "invalid package identifier",
+        ));
+        request.set_start(start);
+        request.set_stop(start + INTEGRITY_PAGE_SIZE - 1);
+        request.set_visibilities(all_visibilities());
+        let list =
+            route_message::<OriginPackageListRequest, OriginPackageListResponse>(req, &request)?;
+        for ident in list.get_idents() {
+            let mut get = OriginPackageGet::new();
+            get.set_ident(ident.clone());
+            get.set_visibilities(all_visibilities());
+            let package = route_message::<OriginPackageGet, OriginPackage>(req, &get)?;
+            let target = match PackageTarget::from_str(package.get_target()) {
+                Ok(target) => target,
+                Err(err) => {
+                    warn!("Skipping {} with unknown target, err={}", ident, err);
+                    continue;
+                }
+            };
+            report.checked += 1;
+            let problem = integrity::check_archive(
+                depot,
+                ident,
+                &target,
+                package.get_checksum(),
+                keys,
+                |name_with_rev| origin_public_key(req, name_with_rev),
+            );
+            let problem = match problem {
+                Ok(Some(problem)) => problem,
+                Ok(None) => continue,
+                Err(err) => {
+                    warn!("Unable to check the archive of {}, err={}", ident, err);
+                    continue;
+                }
+            };
+            let quarantined = if dry_run || problem == integrity::Problem::Missing {
+                false
+            } else {
+                match integrity::quarantine_archive(depot, ident, &target) {
+                    Ok(()) => true,
+                    Err(err) => {
+                        warn!("Unable to quarantine the archive of {}, err={}", ident, err);
+                        false
+                    }
+                }
+            };
+            report.add(ident, &target, &problem, quarantined);
+        }
+        start = list.get_stop() + 1;
+        if list.get_idents().is_empty() || start >= list.get_count() {
+            return Ok(report);
+        }
+    }
+}
+
+/// The body of the public origin key with the given name and revision, if the depot has it
+fn origin_public_key(req: &mut Request, name_with_rev: &str) -> Option<String> {
+    let (origin, revision) = match parse_name_with_rev(name_with_rev) {
+        Ok(parsed) => parsed,
+        Err(_) => return None,
+    };
+    let mut request = OriginPublicKeyGet::new();
+    request.set_origin(origin);
+    request.set_revision(revision);
+    match route_message::<OriginPublicKeyGet, OriginPublicKey>(req, &request) {
+        Ok(key) => Some(String::from_utf8_lossy(key.get_body()).into_owned()),
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => None,
+        Err(err) => {
+            warn!("Unable to fetch public key {}, err={}", name_with_rev, err);
+            None
+        }
+    }
+}
+
+/// Exports packages of an origin to a signed bundle along with their transitive dependencies,
+/// the channels they're in and the public keys of their origins, so that a depot which can't
+/// reach this one can import them. Bundles are kept for a day to be downloaded.
+fn bundle_export(req: &mut Request) -> IronResult<Response> {
+    let origin = match get_param(req, "origin") {
+        Some(origin) => origin,
+        None => return Ok(Response::with(status::BadRequest)),
+    };
+    if !check_origin_access(req, &origin).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let body = match req.get::<bodyparser::Struct<BundleExportReq>>() {
+        Ok(Some(body)) => body,
+        _ => return Ok(Response::with(status::UnprocessableEntity)),
+    };
+    if body.packages.is_empty() {
+        return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:0")));
+    }
+    let target = match target_from_req(req) {
+        Ok(target) => target.to_string(),
+        Err(response) => return Ok(response),
+    };
+
+    let mut packages = BTreeMap::new();
+    for requested in body.packages.iter() {
+        let ident = match OriginPackageIdent::from_str(requested) {
+            Ok(ident) => ident,
+            Err(_) => return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:0"))),
+        };
+        if ident.get_origin() != origin {
+            return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:1")));
+        }
+        let package = match bundle_package(req, ident, body.channel.as_ref(), &target) {
+            Ok(package) => package,
+            Err(err) => return Ok(render_net_error(&err)),
+        };
+        for dep in package.get_tdeps() {
+            let key = dep.to_string();
+            if !packages.contains_key(&key) {
+                match bundle_package(req, dep.clone(), None, &target) {
+                    Ok(dep) => packages.insert(key, dep),
+                    Err(err) => return Ok(render_net_error(&err)),
+                };
+            }
+        }
+        packages.insert(package.get_ident().to_string(), package);
+    }
+    // Dependencies sort before the packages depending on them, having fewer deps of their own
+    let mut packages: Vec<OriginPackage> = packages.into_iter().map(|(_, pkg)| pkg).collect();
+    packages.sort_by_key(|package| package.get_tdeps().len());
+
+    let mut bundled = Vec::new();
+    let mut origins = BTreeSet::new();
+    for package in packages.iter() {
+        let archive = {
+            let lock = req.get::<persistent::State<DepotUtil>>().expect(
+                "depot not found",
+            );
+            let depot = lock.read().expect("depot read lock is poisoned");
+            package_target(package).and_then(|target| depot.archive(package.get_ident(), &target))
+        };
+        let archive = match archive {
+            Some(archive) => archive,
+            None => return Ok(Response::with((status::NotFound, "ds:bundle:2"))),
+        };
+        let channels = helpers::channels_for_package_ident(req, package.get_ident())
+            .unwrap_or(Vec::new())
+            .into_iter()
+            .filter(|channel| channel != "unstable")
+            .collect();
+        let bundled_package = bundle::BundledPackage {
+            ident: package.get_ident().to_string(),
+            target: package.get_target().to_string(),
+            checksum: package.get_checksum().to_string(),
+            channels: channels,
+        };
+        bundled.push((bundled_package, archive.path));
+        origins.insert(package.get_ident().get_origin().to_string());
+    }
+
+    let mut keys = Vec::new();
+    for name in origins {
+        let mut request = OriginPublicKeyListRequest::new();
+        match helpers::get_origin(req, &name) {
+            Ok(origin) => request.set_origin_id(origin.get_id()),
+            Err(err) => return Ok(render_net_error(&err)),
+        }
+        match route_message::<OriginPublicKeyListRequest, OriginPublicKeyListResponse>(
+            req,
+            &request,
+        ) {
+            Ok(mut list) => keys.extend(list.take_keys().into_iter()),
+            Err(err) => return Ok(render_net_error(&err)),
+        }
+    }
+
+    let mut request = OriginSecretKeyGet::new();
+    match helpers::get_origin(req, &origin) {
+        Ok(mut origin) => {
+            request.set_owner_id(origin.get_owner_id());
+            request.set_origin(origin.take_name());
+        }
+        Err(err) => return Ok(render_net_error(&err)),
+    }
+    let secret_key = match route_message::<OriginSecretKeyGet, OriginSecretKey>(req, &request) {
+        Ok(key) => key,
+        Err(err) => return Ok(render_net_error(&err)),
+    };
+
+    let lock = req.get::<persistent::State<DepotUtil>>().expect(
+        "depot not found",
+    );
+    let depot = lock.read().expect("depot read lock is poisoned");
+    let idents: Vec<String> = bundled.iter().map(|&(ref pkg, _)| pkg.ident.clone()).collect();
+    match write_bundle(&depot, &origin, bundled, &keys, &secret_key) {
+        Ok((checksum, size)) => {
+            info!("Exported bundle {} of {}, {} bytes", checksum, origin, size);
+            let exported = BundleExported {
+                checksum: checksum,
+                size: size,
+                packages: idents,
+            };
+            let mut response = render_json(status::Created, &exported);
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
+        Err(e) => {
+            error!("Unable to export bundle of {}, err={}", origin, e);
+            Ok(Response::with(status::InternalServerError))
+        }
+    }
+}
+
+/// The release of a package requested for a bundle: the latest one in `channel`, or the latest
+/// one at all, unless it's fully qualified
+fn bundle_package(
+    req: &mut Request,
+    mut ident: OriginPackageIdent,
+    channel: Option<&String>,
+    target: &str,
+) -> NetResult<OriginPackage> {
+    let session_id = helpers::get_optional_session_id(req);
+    let visibilities = visibility_for_optional_session(req, session_id, ident.get_origin());
+    if !ident.fully_qualified() {
+        ident = match channel {
+            Some(channel) => {
+                let mut request = OriginChannelPackageLatestGet::new();
+                request.set_name(channel.clone());
+                request.set_target(target.to_string());
+                request.set_visibilities(visibilities.clone());
+                request.set_ident(ident);
+                route_message::<OriginChannelPackageLatestGet, OriginPackageIdent>(req, &request)?
+            }
+            None => {
+                let mut request = OriginPackageLatestGet::new();
+                request.set_target(target.to_string());
+                request.set_visibilities(visibilities.clone());
+                request.set_ident(ident);
+                route_message::<OriginPackageLatestGet, OriginPackageIdent>(req, &request)?
+            }
+        };
+    }
+    let mut request = OriginPackageGet::new();
+    request.set_visibilities(visibilities);
+    request.set_ident(ident);
+    route_message::<OriginPackageGet, OriginPackage>(req, &request)
+}
+
+/// Writes a bundle of `packages` and `keys` signed with `secret_key` to the exports of `origin`,
+/// removing the exports which are past their time to be downloaded. Returns the checksum and the
+/// size of the bundle.
+fn write_bundle(
+    depot: &DepotUtil,
+    origin: &str,
+    packages: Vec<(bundle::BundledPackage, PathBuf)>,
+    keys: &[OriginPublicKey],
+    secret_key: &OriginSecretKey,
+) -> Result<(String, u64)> {
+    let dir = depot.bundles_path().join(origin);
+    fs::create_dir_all(&dir)?;
+    remove_stale_bundles(&dir);
+    let tmp_dir = depot.bundles_path().join("tmp").join(
+        Uuid::new_v4().to_string(),
+    );
+    fs::create_dir_all(&tmp_dir)?;
+
+    let signed = tmp_dir.join("bundle");
+    let written = SigKeyPair::write_file_from_str(
+        &String::from_utf8_lossy(secret_key.get_body()),
+        &tmp_dir,
+    ).map_err(Error::from)
+        .and_then(|(pair, _)| {
+            let mut writer = bundle::BundleWriter::new(origin, &tmp_dir.join("bundle.tar"))?;
+            for (package, archive) in packages {
+                writer.add_package(package, &archive)?;
+            }
+            for key in keys {
+                let bundled_key = bundle::BundledKey {
+                    origin: key.get_name().to_string(),
+                    revision: key.get_revision().to_string(),
+                };
+                writer.add_key(bundled_key, key.get_body())?;
+            }
+            writer.finish(&pair, &signed)
+        })
+        .and_then(|checksum| {
+            let path = dir.join(format!("{}.bundle", checksum));
+            fs::rename(&signed, &path)?;
+            Ok((checksum, fs::metadata(&path)?.len()))
+        });
+    if let Err(e) = fs::remove_dir_all(&tmp_dir) {
+        warn!("Unable to remove {}, err={}", tmp_dir.display(), e);
+    }
+    written
+}
+
+/// Removes the bundles in `dir` exported longer ago than they're kept for
+fn remove_stale_bundles(dir: &Path) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let stale = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .map_or(false, |age| age.as_secs() > BUNDLE_TTL_SECS);
+        if stale {
+            if let Err(e) = fs::remove_file(entry.path()) {
+                warn!("Unable to remove {}, err={}", entry.path().display(), e);
+            }
+        }
+    }
+}
+
+/// Downloads a bundle exported from an origin.
+fn bundle_download(req: &mut Request) -> IronResult<Response> {
+    let (origin, checksum) = match (get_param(req, "origin"), get_param(req, "checksum")) {
+        (Some(origin), Some(checksum)) => (origin, checksum),
+        _ => return Ok(Response::with(status::BadRequest)),
+    };
+    if !checksum.chars().all(|c| c.is_digit(16)) {
+        return Ok(Response::with(status::BadRequest));
+    }
+    if !check_origin_access(req, &origin).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let path = {
+        let lock = req.get::<persistent::State<DepotUtil>>().expect(
+            "depot not found",
+        );
+        let depot = lock.read().expect("depot read lock is poisoned");
+        depot.bundles_path().join(&origin).join(
+            format!("{}.bundle", checksum),
+        )
+    };
+    let len = match fs::metadata(&path) {
+        Ok(metadata) => metadata.len(),
+        Err(_) => return Ok(Response::with(status::NotFound)),
+    };
+    let range = byte_range(req.headers.get::<Range>(), len);
+    if range == ByteRange::Unsatisfiable {
+        return Ok(unsatisfiable_response(len));
+    }
+    let file_name = format!("{}-{}.bundle", origin, checksum);
+    let mut response = archive_response(range, len, file_name, &checksum, |first, last| {
+        Ok(read_range(&path, first, last)?)
+    });
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+/// Imports a bundle exported from another depot into an origin. The bundle must be signed with a
+/// key of the origin whose public key was uploaded before. Packages the depot already has are
+/// skipped, but are still added to the channels they're in within the bundle.
+fn bundle_import(req: &mut Request) -> IronResult<Response> {
     let origin = match get_param(req, "origin") {
         Some(origin) => origin,
         None => return Ok(Response::with(status::BadRequest)),
     };
-
-    let name = match get_param(req, "pkg") {
-        Some(pkg) => pkg,
-        None => return Ok(Response::with(status::BadRequest)),
+    if !check_origin_access(req, &origin).unwrap_or(false) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let dir = {
+        let lock = req.get::<persistent::State<DepotUtil>>().expect(
+            "depot not found",
+        );
+        let depot = lock.read().expect("depot read lock is poisoned");
+        depot.bundles_path().join("tmp").join(
+            Uuid::new_v4().to_string(),
+        )
     };
-
-    let mut request = OriginPackageVersionListRequest::new();
-    request.set_visibilities(visibility_for_optional_session(req, session_id, &origin));
-    request.set_origin(origin);
-    request.set_name(name);
-
-    match route_message::<OriginPackageVersionListRequest, OriginPackageVersionListResponse>(
-        req,
-        &request,
-    ) {
-        Ok(packages) => {
-            let body = serde_json::to_string(&packages.get_versions().to_vec()).unwrap();
-            let mut response = Response::with((status::Ok, body));
-
-            response.headers.set(ContentType(Mime(
-                TopLevel::Application,
-                SubLevel::Json,
-                vec![(Attr::Charset, Value::Utf8)],
-            )));
-            dont_cache_response(&mut response);
-            Ok(response)
-        }
-        Err(err) => return Ok(render_net_error(&err)),
+    if let Err(e) = fs::create_dir_all(&dir) {
+        error!("Unable to create {}, err={}", dir.display(), e);
+        return Ok(Response::with(status::InternalServerError));
     }
+    let response = import_bundle(req, &origin, &dir);
+    if let Err(e) = fs::remove_dir_all(&dir) {
+        warn!("Unable to remove {}, err={}", dir.display(), e);
+    }
+    response
 }
 
-fn package_privacy_toggle(req: &mut Request) -> IronResult<Response> {
-    let origin = match get_param(req, "origin") {
-        Some(o) => o,
-        None => return Ok(Response::with(status::BadRequest)),
+/// Imports the bundle sent with the request, using `dir` to unpack it
+fn import_bundle(req: &mut Request, origin: &str, dir: &Path) -> IronResult<Response> {
+    let path = dir.join("bundle");
+    let file = File::create(&path).and_then(|mut file| io::copy(&mut req.body, &mut file));
+    if let Err(e) = file {
+        error!("Unable to write bundle to {}, err={}", path.display(), e);
+        return Ok(Response::with(status::InternalServerError));
+    }
+    let (signer, revision) = match bundle::signer(&path) {
+        Ok(signer) => signer,
+        Err(e) => {
+            info!("Unable to read the signer of a bundle, err={}", e);
+            return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:3")));
+        }
     };
-    let visibility = match get_param(req, "visibility") {
-        Some(v) => v,
-        None => return Ok(Response::with(status::BadRequest)),
+    if signer != origin {
+        return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:4")));
+    }
+    let mut request = OriginPublicKeyGet::new();
+    request.set_origin(signer);
+    request.set_revision(revision);
+    let key = match route_message::<OriginPublicKeyGet, OriginPublicKey>(req, &request) {
+        Ok(key) => key,
+        // Bundles are only trusted once the key they're signed with was uploaded
+        Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+            return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:5")));
+        }
+        Err(err) => return Ok(render_net_error(&err)),
     };
-
-    // users aren't allowed to set packages to hidden manually
-    if visibility.to_lowercase() == "hidden" {
-        return Ok(Response::with(status::BadRequest));
+    let contents = dir.join("contents");
+    let manifest = match unpack_bundle(&path, &key, dir, &contents) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            info!("Unable to unpack bundle, err={}", e);
+            return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:6")));
+        }
+    };
+    if manifest.origin != origin {
+        return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:4")));
     }
 
-    let ident = ident_from_req(req);
-
-    if !ident.valid() || !ident.fully_qualified() {
-        info!(
-            "Invalid or not fully qualified package identifier: {}",
-            ident
-        );
-        return Ok(Response::with(status::BadRequest));
+    let mut idents = Vec::new();
+    for package in manifest.packages.iter() {
+        match OriginPackageIdent::from_str(&package.ident) {
+            Ok(ident) => idents.push(ident),
+            Err(_) => return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:6"))),
+        }
     }
-
-    let opv: OriginPackageVisibility = match visibility.parse() {
-        Ok(o) => o,
-        Err(_) => return Ok(Response::with(status::BadRequest)),
-    };
-
-    if !check_origin_access(req, &origin).unwrap_or(false) {
-        return Ok(Response::with(status::Forbidden));
+    // Dependencies may come from other origins, which the session has to be able to upload to
+    {
+        let origins: BTreeSet<&str> = idents
+            .iter()
+            .map(|ident| ident.get_origin())
+            .chain(manifest.keys.iter().map(|key| key.origin.as_str()))
+            .collect();
+        for name in origins {
+            if !check_origin_access(req, name).unwrap_or(false) {
+                return Ok(Response::with((status::Forbidden, name.to_string())));
+            }
+        }
     }
 
-    let mut opg = OriginPackageGet::new();
-    opg.set_ident(ident);
-    opg.set_visibilities(all_visibilities());
+    let mut imported = BundleImported::default();
+    for key in manifest.keys.iter() {
+        let mut request = OriginPublicKeyGet::new();
+        request.set_origin(key.origin.clone());
+        request.set_revision(key.revision.clone());
+        match route_message::<OriginPublicKeyGet, OriginPublicKey>(req, &request) {
+            Ok(_) => continue,
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+            Err(err) => return Ok(render_net_error(&err)),
+        }
+        let body = match bundle::key_path(&contents, key).and_then(|path| {
+            let mut body = Vec::new();
+            File::open(path)?.read_to_end(&mut body)?;
+            Ok(body)
+        }) {
+            Ok(body) => body,
+            Err(e) => {
+                info!("Unable to read key {}-{}, err={}", key.origin, key.revision, e);
+                return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:6")));
+            }
+        };
+        match SigKeyPair::parse_key_str(&String::from_utf8_lossy(&body)) {
+            Ok((PairType::Public, _, _)) => (),
+            _ => return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:6"))),
+        }
+        let mut request = OriginPublicKeyCreate::new();
+        match helpers::get_origin(req, &key.origin) {
+            Ok(mut origin) => {
+                request.set_name(origin.take_name());
+                request.set_origin_id(origin.get_id());
+            }
+            Err(err) => return Ok(render_net_error(&err)),
+        }
+        request.set_revision(key.revision.clone());
+        request.set_body(body);
+        request.set_owner_id(0);
+        if let Err(err) = route_message::<OriginPublicKeyCreate, OriginPublicKey>(req, &request) {
+            return Ok(render_net_error(&err));
+        }
+        imported.keys_imported.push(format!("{}-{}", key.origin, key.revision));
+    }
 
-    match route_message::<OriginPackageGet, OriginPackage>(req, &opg) {
-        Ok(mut package) => {
-            let real_visibility = transition_visibility(opv, package.get_visibility());
-            let mut opu = OriginPackageUpdate::new();
-            package.set_visibility(real_visibility);
-            opu.set_pkg(package);
+    for (package, ident) in manifest.packages.iter().zip(idents.into_iter()) {
+        let archive = match bundle::package_path(&contents, package) {
+            Ok(archive) => archive,
+            Err(_) => return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:6"))),
+        };
+        let temp_path = match archive_temp_path(req, &ident) {
+            Ok(temp_path) => temp_path,
+            Err(response) => return Ok(response),
+        };
+        if let Err(e) = fs::rename(&archive, &temp_path) {
+            info!("Unable to move archive of {}, err={}", ident, e);
+            return Ok(Response::with((status::UnprocessableEntity, "ds:bundle:6")));
+        }
+        let response = store_package(
+            req,
+            ident.clone(),
+            package.checksum.clone(),
+            temp_path.clone(),
+            false,
+        )?;
+        if temp_path.exists() {
+            if let Err(e) = fs::remove_file(&temp_path) {
+                warn!("Unable to remove {}, err={}", temp_path.display(), e);
+            }
+        }
+        match response.status {
+            Some(status::Created) => imported.packages_imported.push(package.ident.clone()),
+            Some(status::Conflict) => imported.packages_skipped.push(package.ident.clone()),
+            _ => return Ok(response),
+        }
 
-            match route_message::<OriginPackageUpdate, NetOk>(req, &opu) {
-                Ok(_) => Ok(Response::with(status::Ok)),
-                Err(e) => Ok(render_net_error(&e)),
+        for channel in package.channels.iter() {
+            let mut request = OriginChannelGet::new();
+            request.set_origin_name(ident.get_origin().to_string());
+            request.set_name(channel.clone());
+            match route_message::<OriginChannelGet, OriginChannel>(req, &request) {
+                Ok(_) => (),
+                Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+                    if let Err(err) = helpers::create_channel(req, ident.get_origin(), channel) {
+                        return Ok(render_net_error(&err));
+                    }
+                }
+                Err(err) => return Ok(render_net_error(&err)),
+            }
+            if let Err(err) = helpers::promote_package_to_channel(req, &ident, channel) {
+                return Ok(render_net_error(&err));
             }
         }
-        Err(e) => Ok(render_net_error(&e)),
     }
+    info!(
+        "Imported bundle into {}, {} packages and {} keys",
+        origin,
+        imported.packages_imported.len(),
+        imported.keys_imported.len()
+    );
+
+    let mut response = render_json(status::Ok, &imported);
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
+/// Checks the signature of the bundle at `path` with `key` and unpacks it into `dst`, using `dir`
+/// as the key cache
+fn unpack_bundle(
+    path: &Path,
+    key: &OriginPublicKey,
+    dir: &Path,
+    dst: &Path,
+) -> Result<bundle::Manifest> {
+    let cache = dir.join("keys");
+    fs::create_dir_all(&cache)?;
+    SigKeyPair::write_file_from_str(&String::from_utf8_lossy(key.get_body()), &cache)?;
+    bundle::unpack(path, &cache, dst)
 }
 
 fn list_packages(req: &mut Request) -> IronResult<Response> {
     let session_id = helpers::get_optional_session_id(req);
     let mut distinct = false;
-    let (start, stop) = match helpers::extract_pagination(req) {
-        Ok(range) => range,
+    let page = match helpers::extract_page(req) {
+        Ok(page) => page,
         Err(response) => return Ok(response),
     };
 
@@ -1466,13 +3190,24 @@ fn list_packages(req: &mut Request) -> IronResult<Response> {
         (origin, ident, channel)
     };
 
+    // Only list the packages built for a target if one is given, e.g. ?target=aarch64-linux
+    let target = match helpers::extract_query_value("target", req) {
+        Some(target) => {
+            match PackageTarget::from_str(&target) {
+                Ok(target) => target.to_string(),
+                Err(_) => return Ok(Response::with(status::BadRequest)),
+            }
+        }
+        None => String::new(),
+    };
+
     let packages: NetResult<OriginPackageListResponse>;
     match channel {
         Some(channel) => {
             let mut request = OriginChannelPackageListRequest::new();
             request.set_name(channel);
-            request.set_start(start as u64);
-            request.set_stop(stop as u64);
+            page.apply(&mut request);
+            request.set_target(target);
             request.set_visibilities(visibility_for_optional_session(req, session_id, &origin));
 
             request.set_ident(OriginPackageIdent::from_str(ident.as_str()).expect(
@@ -1486,8 +3221,8 @@ fn list_packages(req: &mut Request) -> IronResult<Response> {
         }
         None => {
             let mut request = OriginPackageListRequest::new();
-            request.set_start(start as u64);
-            request.set_stop(stop as u64);
+            page.apply(&mut request);
+            request.set_target(target);
             request.set_visibilities(visibility_for_optional_session(req, session_id, &origin));
 
             // only set this if "distinct" is present as a URL parameter, e.g. ?distinct=true
@@ -1547,6 +3282,7 @@ fn list_packages(req: &mut Request) -> IronResult<Response> {
                 packages.get_count() as isize,
                 packages.get_start() as isize,
                 packages.get_stop() as isize,
+                &page,
             );
 
             let mut response =
@@ -1573,6 +3309,10 @@ fn list_channels(req: &mut Request) -> IronResult<Response> {
         Some(origin) => origin,
         None => return Ok(Response::with(status::BadRequest)),
     };
+    let page = match helpers::extract_optional_page(req) {
+        Ok(page) => page,
+        Err(response) => return Ok(response),
+    };
 
     let mut request = OriginChannelListRequest::new();
     request.set_include_sandbox_channels(false);
@@ -1590,7 +3330,34 @@ fn list_channels(req: &mut Request) -> IronResult<Response> {
         }
     }
 
+    // Pass ?counts=true to this endpoint to get how many packages every channel has, counting
+    // the private ones only for members of the origin.
+    let counts = helpers::extract_query_value("counts", req).map_or(false, |c| c == "true");
+
     match route_message::<OriginChannelListRequest, OriginChannelListResponse>(req, &request) {
+        Ok(list) if counts => {
+            let session_id = helpers::get_optional_session_id(req);
+            let visibilities = visibility_for_optional_session(req, session_id, &origin_name);
+            let mut results = Vec::new();
+            for channel in list.get_channels() {
+                let name = channel.get_name();
+                let package_count =
+                    match channel_package_count(req, &origin_name, name, &visibilities) {
+                        Ok(count) => count,
+                        Err(err) => return Ok(render_net_error(&err)),
+                    };
+                results.push(json!({
+                    "name": name,
+                    "package_count": package_count,
+                }));
+            }
+            let mut response = match page {
+                Some(page) => helpers::paginated_list_response(&results, &page)?,
+                None => Response::with((status::Ok, serde_json::to_string(&results).unwrap())),
+            };
+            dont_cache_response(&mut response);
+            Ok(response)
+        }
         Ok(list) => {
             let list: Vec<OriginChannelIdent> = list.get_channels()
                 .iter()
@@ -1600,8 +3367,10 @@ fn list_channels(req: &mut Request) -> IronResult<Response> {
                     ident
                 })
                 .collect();
-            let body = serde_json::to_string(&list).unwrap();
-            let mut response = Response::with((status::Ok, body));
+            let mut response = match page {
+                Some(page) => helpers::paginated_list_response(&list, &page)?,
+                None => Response::with((status::Ok, serde_json::to_string(&list).unwrap())),
+            };
             dont_cache_response(&mut response);
             Ok(response)
         }
@@ -1609,6 +3378,26 @@ fn list_channels(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// How many packages of an origin with one of the given visibilities are in a channel
+fn channel_package_count(
+    req: &mut Request,
+    origin: &str,
+    channel: &str,
+    visibilities: &[OriginPackageVisibility],
+) -> NetResult<u64> {
+    let mut request = OriginChannelPackageListRequest::new();
+    request.set_name(channel.to_string());
+    request.set_ident(OriginPackageIdent::from_str(origin).expect(
+        "invalid package identifier",
+    ));
+    request.set_start(0);
+    request.set_stop(0);
+    request.set_visibilities(visibilities.to_vec());
+    let packages =
+        route_message::<OriginChannelPackageListRequest, OriginPackageListResponse>(req, &request)?;
+    Ok(packages.get_count())
+}
+
 fn create_channel(req: &mut Request) -> IronResult<Response> {
     let origin = match get_param(req, "origin") {
         Some(origin) => origin,
@@ -1662,7 +3451,25 @@ fn delete_channel(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// Shows a package, from the upstream Builder if the depot caches one and doesn't have the
+/// package itself
 fn show_package(req: &mut Request) -> IronResult<Response> {
+    let response = show_local_package(req)?;
+    if response.status != Some(status::NotFound) {
+        return Ok(response);
+    }
+    let channel = get_param(req, "channel");
+    let ident = ident_from_req(req);
+    let fetched = from_upstream(req, |upstream| {
+        upstream.show_package(&ident, channel.as_ref().map(String::as_str))
+    });
+    match fetched {
+        Some(pkg) => render_package(req, &pkg, ident.fully_qualified()),
+        None => Ok(response),
+    }
+}
+
+fn show_local_package(req: &mut Request) -> IronResult<Response> {
     let session_id = helpers::get_optional_session_id(req);
     let channel = get_param(req, "channel");
 
@@ -1671,9 +3478,10 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
 
     if let Some(channel) = channel {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
+            let target = match target_from_req(req) {
+                Ok(target) => target.to_string(),
+                Err(response) => return Ok(response),
+            };
             let mut request = OriginChannelPackageLatestGet::new();
             request.set_name(channel.clone());
             request.set_target(target);
@@ -1708,9 +3516,10 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
         }
     } else {
         if !qualified {
-            let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap())
-                .unwrap()
-                .to_string();
+            let target = match target_from_req(req) {
+                Ok(target) => target.to_string(),
+                Err(response) => return Ok(response),
+            };
             let mut request = OriginPackageLatestGet::new();
             request.set_target(target);
             request.set_visibilities(visibility_for_optional_session(
@@ -1742,10 +3551,17 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
 
                 let depot = lock.read().expect("depot read lock is poisoned");
 
-                // If we don't have a valid archive on disk, return NotFound
-                let target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
-
-                if !depot.archive(&ident, &target).is_some() {
+                // If we don't have a valid archive in the store, return NotFound
+                let archive_found = package_target(&pkg).map_or(false, |target| {
+                    match depot.archive_size(&ident, &target) {
+                        Ok(size) => size.is_some(),
+                        Err(err) => {
+                            warn!("Unable to look up the archive of {}, err={}", ident, err);
+                            false
+                        }
+                    }
+                });
+                if !archive_found {
                     return Ok(Response::with((status::NotFound)));
                 };
 
@@ -1762,15 +3578,68 @@ fn show_package(req: &mut Request) -> IronResult<Response> {
     }
 }
 
+/// The latest release of a package, or of a version of it, for each target served by the depot,
+/// optionally within a channel. Targets without any release are left out.
+fn show_package_latest_targets(req: &mut Request) -> IronResult<Response> {
+    let session_id = helpers::get_optional_session_id(req);
+    let channel = get_param(req, "channel");
+    let ident = ident_from_req(req);
+    let targets = {
+        let lock = req.get::<persistent::State<DepotUtil>>().expect(
+            "depot not found",
+        );
+        let depot = lock.read().expect("depot read lock is poisoned");
+        depot.config.targets.clone()
+    };
+    let visibilities = visibility_for_optional_session(req, session_id, &ident.get_origin());
+
+    let mut latest = Vec::new();
+    for target in targets {
+        let result = match channel {
+            Some(ref channel) => {
+                let mut request = OriginChannelPackageLatestGet::new();
+                request.set_name(channel.clone());
+                request.set_target(target.to_string());
+                request.set_visibilities(visibilities.clone());
+                request.set_ident(ident.clone());
+                route_message::<OriginChannelPackageLatestGet, OriginPackageIdent>(req, &request)
+            }
+            None => {
+                let mut request = OriginPackageLatestGet::new();
+                request.set_target(target.to_string());
+                request.set_visibilities(visibilities.clone());
+                request.set_ident(ident.clone());
+                route_message::<OriginPackageLatestGet, OriginPackageIdent>(req, &request)
+            }
+        };
+        match result {
+            Ok(id) => latest.push(json!({ "target": target.to_string(), "ident": id })),
+            Err(ref err) if err.get_code() == ErrCode::ENTITY_NOT_FOUND => (),
+            Err(err) => return Ok(render_net_error(&err)),
+        }
+    }
+    if latest.is_empty() {
+        return Ok(Response::with(status::NotFound));
+    }
+
+    let body = json!({
+        "origin": ident.get_origin(),
+        "name": ident.get_name(),
+        "targets": latest,
+    });
+    let mut response = render_json(status::Ok, &body);
+    dont_cache_response(&mut response);
+    Ok(response)
+}
+
 fn search_packages(req: &mut Request) -> IronResult<Response> {
     let session_id = helpers::get_optional_session_id(req);
     let mut request = OriginPackageSearchRequest::new();
-    let (start, stop) = match helpers::extract_pagination(req) {
-        Ok(range) => range,
+    let page = match helpers::extract_page(req) {
+        Ok(page) => page,
         Err(response) => return Ok(response),
     };
-    request.set_start(start as u64);
-    request.set_stop(stop as u64);
+    page.apply(&mut request);
 
     if session_id.is_some() {
         let mut my_origins = MyOriginsRequest::new();
@@ -1831,6 +3700,35 @@ fn search_packages(req: &mut Request) -> IronResult<Response> {
         request.set_distinct(true);
     }
 
+    if let Some(target) = helpers::extract_query_value("target", req) {
+        match PackageTarget::from_str(&target) {
+            Ok(target) => request.set_target(target.to_string()),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    }
+
+    if let Some(channel) = helpers::extract_query_value("channel", req) {
+        request.set_channel(channel);
+    }
+
+    if let Some(license) = helpers::extract_query_value("license", req) {
+        request.set_license(license);
+    }
+
+    if let Some(built_after) = helpers::extract_query_value("built_after", req) {
+        match timestamp::normalize(&built_after) {
+            Ok(built_after) => request.set_built_after(built_after),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    }
+
+    if let Some(sort) = helpers::extract_query_value("sort", req) {
+        match sort.parse::<OriginPackageSearchOrder>() {
+            Ok(order) => request.set_order(order),
+            Err(_) => return Ok(Response::with(status::BadRequest)),
+        }
+    }
+
     match route_message::<OriginPackageSearchRequest, OriginPackageListResponse>(req, &request) {
         Ok(packages) => {
             debug!(
@@ -1844,6 +3742,7 @@ fn search_packages(req: &mut Request) -> IronResult<Response> {
                 packages.get_count() as isize,
                 packages.get_start() as isize,
                 packages.get_stop() as isize,
+                &page,
             );
 
             let mut response =
@@ -1873,7 +3772,7 @@ fn render_package(
     let mut pkg_json = serde_json::to_value(pkg.clone()).unwrap();
     let channels = helpers::channels_for_package_ident(req, pkg.get_ident());
     pkg_json["channels"] = json!(channels);
-    pkg_json["is_a_service"] = json!(is_a_service(req, pkg.get_ident()));
+    pkg_json["is_a_service"] = json!(is_a_service(req, pkg));
 
     let body = serde_json::to_string(&pkg_json).unwrap();
     let mut response = Response::with((status::Ok, body));
@@ -1967,9 +3866,18 @@ fn demote_package(req: &mut Request) -> IronResult<Response> {
                     let mut demote = OriginPackageDemote::new();
                     demote.set_channel_id(origin_channel.get_id());
                     demote.set_package_id(package.get_id());
-                    demote.set_ident(ident);
+                    demote.set_ident(ident.clone());
                     match route_message::<OriginPackageDemote, NetOk>(req, &demote) {
-                        Ok(_) => Ok(Response::with(status::Ok)),
+                        Ok(_) => {
+                            webhooks::notify_channel_change(
+                                req,
+                                ident.get_origin(),
+                                origin_channel.get_name(),
+                                vec![ident.to_string()],
+                                false,
+                            );
+                            Ok(Response::with(status::Ok))
+                        }
                         Err(err) => return Ok(render_net_error(&err)),
                     }
                 }
@@ -2077,17 +3985,34 @@ fn target_from_headers(user_agent_header: &UserAgent) -> result::Result<PackageT
     }
 }
 
-fn is_a_service<T>(req: &mut Request, ident: &T) -> bool
-where
-    T: Identifiable,
-{
+/// The target requested by the `target` query parameter, or else the one of the client
+fn target_from_req(req: &mut Request) -> result::Result<PackageTarget, Response> {
+    match helpers::extract_query_value("target", req) {
+        Some(target) => {
+            match PackageTarget::from_str(&target) {
+                Ok(target) => Ok(target),
+                Err(_) => Err(Response::with((
+                    status::BadRequest,
+                    format!("Invalid target ({}).", target),
+                ))),
+            }
+        }
+        None => target_from_headers(&req.headers.get::<UserAgent>().unwrap()),
+    }
+}
+
+/// The target a package was built for
+fn package_target(package: &OriginPackage) -> Option<PackageTarget> {
+    PackageTarget::from_str(package.get_target()).ok()
+}
+
+fn is_a_service(req: &mut Request, package: &OriginPackage) -> bool {
     let lock = req.get::<persistent::State<DepotUtil>>().expect(
         "depot not found",
     );
     let depot = lock.read().expect("depot read lock is poisoned");
-    let agent_target = target_from_headers(&req.headers.get::<UserAgent>().unwrap()).unwrap();
 
-    match depot.archive(ident, &agent_target) {
+    match package_target(package).and_then(|target| depot.archive(package.get_ident(), &target)) {
         Some(mut archive) => archive.is_a_service(),
         None => false,
     }
@@ -2104,9 +4029,16 @@ where
     M: BeforeMiddleware + Clone,
 {
     let opt = basic.clone().optional();
+    let upload = basic.clone().scope(AccessTokenScope::PackageUpload);
+    let promote = upload.clone().scope(AccessTokenScope::OriginAdmin);
+    let scheduler = basic.clone().scope(AccessTokenScope::JobSchedule);
+    let origin_admin = basic.clone().scope(AccessTokenScope::OriginAdmin);
+    let admin = basic.clone().require(privilege::ADMIN);
 
     router!(
-        channels: get "/channels/:origin" => list_channels,
+        channels: get "/channels/:origin" => {
+            XHandler::new(list_channels).before(opt.clone())
+        },
         channel_packages: get "/channels/:origin/:channel/pkgs" => {
             XHandler::new(list_packages).before(opt.clone())
         },
@@ -2116,6 +4048,10 @@ where
         channel_package_latest: get "/channels/:origin/:channel/pkgs/:pkg/latest" => {
             XHandler::new(show_package).before(opt.clone())
         },
+        channel_package_latest_targets: get
+        "/channels/:origin/:channel/pkgs/:pkg/latest/targets" => {
+            XHandler::new(show_package_latest_targets).before(opt.clone())
+        },
         channel_packages_version: get
         "/channels/:origin/:channel/pkgs/:pkg/:version" => {
             XHandler::new(list_packages).before(opt.clone())
@@ -2124,23 +4060,31 @@ where
         "/channels/:origin/:channel/pkgs/:pkg/:version/latest" => {
             XHandler::new(show_package).before(opt.clone())
         },
+        channel_packages_version_latest_targets: get
+        "/channels/:origin/:channel/pkgs/:pkg/:version/latest/targets" => {
+            XHandler::new(show_package_latest_targets).before(opt.clone())
+        },
         channel_package_release: get
         "/channels/:origin/:channel/pkgs/:pkg/:version/:release" => {
             XHandler::new(show_package).before(opt.clone())
         },
+        channel_package_download: get
+            "/channels/:origin/:channel/pkgs/:pkg/:version/:release/download" => {
+            XHandler::new(download_package).before(opt.clone())
+        },
         channel_package_promote: put
             "/channels/:origin/:channel/pkgs/:pkg/:version/:release/promote" => {
-            XHandler::new(promote_package).before(basic.clone())
+            XHandler::new(promote_package).before(promote.clone())
         },
         channel_package_demote: put
             "/channels/:origin/:channel/pkgs/:pkg/:version/:release/demote" => {
-            XHandler::new(demote_package).before(basic.clone())
+            XHandler::new(demote_package).before(promote.clone())
         },
         channel_create: post "/channels/:origin/:channel" => {
-            XHandler::new(create_channel).before(basic.clone())
+            XHandler::new(create_channel).before(origin_admin.clone())
         },
         channel_delete: delete "/channels/:origin/:channel" => {
-            XHandler::new(delete_channel).before(basic.clone())
+            XHandler::new(delete_channel).before(origin_admin.clone())
         },
         package_search: get "/pkgs/search/:query" => {
             XHandler::new(search_packages).before(opt.clone())
@@ -2157,15 +4101,24 @@ where
         package_pkg_versions: get "/pkgs/:origin/:pkg/versions" => {
             XHandler::new(list_package_versions).before(opt.clone())
         },
+        package_pkg_stats: get "/pkgs/:origin/:pkg/stats" => {
+            XHandler::new(package_download_stats).before(basic.clone())
+        },
         package_pkg_latest: get "/pkgs/:origin/:pkg/latest" => {
             XHandler::new(show_package).before(opt.clone())
         },
+        package_pkg_latest_targets: get "/pkgs/:origin/:pkg/latest/targets" => {
+            XHandler::new(show_package_latest_targets).before(opt.clone())
+        },
         packages_version: get "/pkgs/:origin/:pkg/:version" => {
             XHandler::new(list_packages).before(opt.clone())
         },
         package_version_latest: get "/pkgs/:origin/:pkg/:version/latest" => {
             XHandler::new(show_package).before(opt.clone())
         },
+        package_version_latest_targets: get "/pkgs/:origin/:pkg/:version/latest/targets" => {
+            XHandler::new(show_package_latest_targets).before(opt.clone())
+        },
         package: get "/pkgs/:origin/:pkg/:version/:release" => {
             XHandler::new(show_package).before(opt.clone())
         },
@@ -2175,15 +4128,66 @@ where
         package_download: get "/pkgs/:origin/:pkg/:version/:release/download" => {
             XHandler::new(download_package).before(opt.clone())
         },
+        package_delta: get
+            "/pkgs/:origin/:pkg/:version/:release/delta/:from_version/:from_release" => {
+            XHandler::new(download_package_delta).before(opt.clone())
+        },
+        package_download_url: post "/pkgs/:origin/:pkg/:version/:release/download_url" => {
+            XHandler::new(download_url_create).before(upload.clone())
+        },
         package_upload: post "/pkgs/:origin/:pkg/:version/:release" => {
-            XHandler::new(upload_package).before(basic.clone())
+            XHandler::new(upload_package).before(upload.clone())
+        },
+        package_upload_start: post "/pkgs/:origin/:pkg/:version/:release/uploads" => {
+            XHandler::new(upload_start).before(upload.clone())
+        },
+        package_upload_part: put
+            "/pkgs/:origin/:pkg/:version/:release/uploads/:checksum/parts/:part" => {
+            XHandler::new(upload_part).before(upload.clone())
+        },
+        package_upload_complete: post
+            "/pkgs/:origin/:pkg/:version/:release/uploads/:checksum/complete" => {
+            XHandler::new(upload_complete).before(upload.clone())
+        },
+        package_yank: put "/pkgs/:origin/:pkg/:version/:release/yank" => {
+            XHandler::new(package_yank).before(origin_admin.clone())
+        },
+        package_unyank: delete "/pkgs/:origin/:pkg/:version/:release/yank" => {
+            XHandler::new(package_yank).before(origin_admin.clone())
         },
         package_privacy_toggle: patch "/pkgs/:origin/:pkg/:version/:release/:visibility" => {
-            XHandler::new(package_privacy_toggle).before(basic.clone())
+            XHandler::new(package_privacy_toggle).before(origin_admin.clone())
         },
         packages_stats: get "/pkgs/origins/:origin/stats" => package_stats,
+        packages_retention_report: get "/pkgs/origins/:origin/retention" => {
+            XHandler::new(package_retention).before(admin.clone())
+        },
+        packages_retention: post "/pkgs/origins/:origin/retention" => {
+            XHandler::new(package_retention).before(admin.clone())
+        },
+        packages_integrity_report: get "/pkgs/origins/:origin/integrity" => {
+            XHandler::new(package_integrity).before(admin.clone())
+        },
+        packages_integrity: post "/pkgs/origins/:origin/integrity" => {
+            XHandler::new(package_integrity).before(admin.clone())
+        },
         schedule: post "/pkgs/schedule/:origin/:pkg" => {
-            XHandler::new(schedule).before(basic.clone())
+            XHandler::new(schedule).before(scheduler.clone())
+        },
+        schedule_preview: get "/pkgs/schedule/:origin/:pkg/preview" => {
+            XHandler::new(schedule_preview).before(scheduler.clone())
+        },
+        schedule_cron_create: post "/pkgs/schedule/:origin/:pkg/cron" => {
+            XHandler::new(schedule_cron_create).before(scheduler.clone())
+        },
+        schedule_cron_list: get "/pkgs/schedule/:origin/cron" => {
+            XHandler::new(schedule_cron_list).before(scheduler.clone())
+        },
+        schedule_cron_delete: delete "/pkgs/schedule/:origin/cron/:id" => {
+            XHandler::new(schedule_cron_delete).before(scheduler.clone())
+        },
+        schedule_usage: get "/pkgs/schedule/:origin/usage" => {
+            XHandler::new(schedule_usage).before(scheduler.clone())
         },
         schedule_get: get "/pkgs/schedule/:groupid" => get_schedule,
         schedule_get_global: get "/pkgs/schedule/:origin/status" => get_origin_schedule_status,
@@ -2194,7 +4198,7 @@ where
             XHandler::new(origin_create).before(basic.clone())
         },
         origin_update: put "/origins/:name" => {
-            XHandler::new(origin_update).before(basic.clone())
+            XHandler::new(origin_update).before(origin_admin.clone())
         },
         origin: get "/origins/:origin" => origin_show,
 
@@ -2202,38 +4206,89 @@ where
         origin_key_latest: get "/origins/:origin/keys/latest" => download_latest_origin_key,
         origin_key: get "/origins/:origin/keys/:revision" => download_origin_key,
         origin_key_generate: post "/origins/:origin/keys" => {
-            XHandler::new(generate_origin_keys).before(basic.clone())
+            XHandler::new(generate_origin_keys).before(origin_admin.clone())
+        },
+        origin_keys_deprecate: post "/origins/:origin/keys/deprecate" => {
+            XHandler::new(deprecate_origin_keys).before(origin_admin.clone())
         },
         origin_key_create: post "/origins/:origin/keys/:revision" => {
-            XHandler::new(upload_origin_key).before(basic.clone())
+            XHandler::new(upload_origin_key).before(origin_admin.clone())
         },
         origin_secret_key_create: post "/origins/:origin/secret_keys/:revision" => {
-            XHandler::new(upload_origin_secret_key).before(basic.clone())
+            XHandler::new(upload_origin_secret_key).before(origin_admin.clone())
         },
         origin_secret_key_latest: get "/origins/:origin/secret_keys/latest" => {
-            XHandler::new(download_latest_origin_secret_key).before(basic.clone())
+            XHandler::new(download_latest_origin_secret_key).before(origin_admin.clone())
+        },
+        origin_bundle_export: post "/origins/:origin/exports" => {
+            XHandler::new(bundle_export).before(origin_admin.clone())
+        },
+        origin_bundle_download: get "/origins/:origin/exports/:checksum" => {
+            XHandler::new(bundle_download).before(basic.clone())
+        },
+        origin_bundle_import: post "/origins/:origin/imports" => {
+            XHandler::new(bundle_import).before(origin_admin.clone())
         },
 
         builder_key_latest: get "/builder/keys/latest" => download_latest_builder_key,
 
         origin_integration_get_names: get "/origins/:origin/integrations/:integration/names" => {
             XHandler::new(
-                handlers::integrations::fetch_origin_integration_names).before(basic.clone()
+                handlers::integrations::fetch_origin_integration_names).before(origin_admin.clone()
             )
         },
         origin_integration_put: put "/origins/:origin/integrations/:integration/:name" => {
-            XHandler::new(handlers::integrations::create_origin_integration).before(basic.clone())
+            XHandler::new(
+                handlers::integrations::create_origin_integration).before(origin_admin.clone()
+            )
         },
         origin_integration_delete: delete "/origins/:origin/integrations/:integration/:name" => {
-            XHandler::new(handlers::integrations::delete_origin_integration).before(basic.clone())
+            XHandler::new(
+                handlers::integrations::delete_origin_integration).before(origin_admin.clone()
+            )
         },
         origin_integrations: get "/origins/:origin/integrations" => {
             XHandler::new(
-                handlers::integrations::fetch_origin_integrations).before(basic.clone()
+                handlers::integrations::fetch_origin_integrations).before(origin_admin.clone()
+            )
+        },
+        origin_notifications: get "/origins/:origin/notifications" => {
+            XHandler::new(
+                handlers::notifications::fetch_origin_notifications).before(origin_admin.clone()
+            )
+        },
+        origin_notification_create: post "/origins/:origin/notifications" => {
+            XHandler::new(
+                handlers::notifications::create_origin_notification).before(origin_admin.clone()
             )
         },
+        origin_notification_delete: delete "/origins/:origin/notifications/:id" => {
+            XHandler::new(
+                handlers::notifications::delete_origin_notification).before(origin_admin.clone()
+            )
+        },
+        origin_secrets: get "/origins/:origin/secrets" => {
+            XHandler::new(handlers::secrets::fetch_origin_secrets).before(origin_admin.clone())
+        },
+        origin_secret_post: post "/origins/:origin/secrets" => {
+            XHandler::new(handlers::secrets::create_origin_secret).before(origin_admin.clone())
+        },
+        origin_encryption_key: get "/origins/:origin/encryption_key" => {
+            XHandler::new(handlers::secrets::download_origin_encryption_key)
+                .before(basic.clone())
+        },
+        origin_encryption_key_rotate: post "/origins/:origin/encryption_key/rotate" => {
+            XHandler::new(handlers::secrets::rotate_origin_encryption_key)
+                .before(origin_admin.clone())
+        },
+        origin_secret_put: put "/origins/:origin/secrets/:name" => {
+            XHandler::new(handlers::secrets::set_origin_secret).before(origin_admin.clone())
+        },
+        origin_secret_delete: delete "/origins/:origin/secrets/:name" => {
+            XHandler::new(handlers::secrets::delete_origin_secret).before(origin_admin.clone())
+        },
         origin_invitation_create: post "/origins/:origin/users/:username/invitations" => {
-            XHandler::new(invite_to_origin).before(basic.clone())
+            XHandler::new(invite_to_origin).before(origin_admin.clone())
         },
         origin_invitation_accept: put "/origins/:origin/invitations/:invitation_id" => {
             XHandler::new(accept_invitation).before(basic.clone())
@@ -2242,21 +4297,29 @@ where
             XHandler::new(ignore_invitation).before(basic.clone())
         },
         origin_invitation_rescind: delete "/origins/:origin/invitations/:invitation_id" => {
-            XHandler::new(rescind_invitation).before(basic.clone())
+            XHandler::new(rescind_invitation).before(origin_admin.clone())
         },
         origin_invitations: get "/origins/:origin/invitations" => {
-            XHandler::new(list_origin_invitations).before(basic.clone())
+            XHandler::new(list_origin_invitations).before(origin_admin.clone())
         },
         origin_users: get "/origins/:origin/users" => {
-            XHandler::new(list_origin_members).before(basic.clone())
+            XHandler::new(list_origin_members).before(origin_admin.clone())
         },
         origin_member_delete: delete "/origins/:origin/users/:username" => {
-            XHandler::new(origin_member_delete).before(basic.clone())
+            XHandler::new(origin_member_delete).before(origin_admin.clone())
+        },
+        origin_member_approver_grant: put "/origins/:origin/users/:username/approver" => {
+            XHandler::new(origin_member_approver_update).before(origin_admin.clone())
+        },
+        origin_member_approver_revoke: delete "/origins/:origin/users/:username/approver" => {
+            XHandler::new(origin_member_approver_update).before(origin_admin.clone())
         },
     )
 }
 
-pub fn router(depot: DepotUtil) -> Result<Chain> {
+pub fn router(mut depot: DepotUtil) -> Result<Chain> {
+    depot.upstream = depot.upstream_cache()?;
+    depot.store = storage::from_config(&depot.config.storage, &depot.config.path)?;
     let basic = Authenticated::new(depot.config.github.clone());
     let worker = Authenticated::new(depot.config.github.clone()).require(privilege::BUILD_WORKER);
     let router = routes(basic, worker);
@@ -2268,9 +4331,16 @@ pub fn router(depot: DepotUtil) -> Result<Chain> {
     chain.link(persistent::Read::<GitHubCli>::both(
         GitHubClient::new(depot.config.github.clone()),
     ));
+    chain.link(persistent::Read::<OAuthCli>::both(
+        oauth::client(&depot.config.oauth, &depot.config.github),
+    ));
     chain.link(persistent::Read::<SegmentCli>::both(
         SegmentClient::new(depot.config.segment.clone()),
     ));
+    chain.link(persistent::Read::<Webhooks>::both(Webhooks {
+        config: depot.config.webhook.clone(),
+        key_dir: depot.config.key_dir.clone(),
+    }));
     chain.link(persistent::State::<DepotUtil>::both(depot));
     chain.link_before(XRouteClient);
     chain.link_after(Cors);
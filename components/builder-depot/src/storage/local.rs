@@ -0,0 +1,69 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Store which keeps archives on the local filesystem of the depot.
+
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use byte_range::read_range;
+use error::Result;
+
+use super::ArtifactStore;
+
+pub struct LocalStore(PathBuf);
+
+impl LocalStore {
+    /// Creates a store keeping its archives under `path`
+    pub fn new(path: PathBuf) -> LocalStore {
+        LocalStore(path)
+    }
+}
+
+impl ArtifactStore for LocalStore {
+    fn size(&self, key: &str) -> Result<Option<u64>> {
+        match fs::metadata(self.0.join(key)) {
+            Ok(metadata) => Ok(Some(metadata.len())),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn put(&self, key: &str, path: &Path) -> Result<()> {
+        let dst = self.0.join(key);
+        if let Some(parent) = dst.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(path, &dst)?;
+        Ok(())
+    }
+
+    fn get(&self, key: &str, first: u64, last: u64) -> Result<Box<Read + Send>> {
+        Ok(read_range(&self.0.join(key), first, last)?)
+    }
+
+    fn local_path(&self, key: &str) -> Result<Option<PathBuf>> {
+        let path = self.0.join(key);
+        if path.is_file() { Ok(Some(path)) } else { Ok(None) }
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        match fs::remove_file(self.0.join(key)) {
+            Ok(()) => Ok(()),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Where the depot stores the archives of packages.
+//!
+//! Archives are stored by a key made of their file name and the directories they'd be found in
+//! under the depot's data path. They're either kept on the local filesystem of the depot, or in
+//! S3 (or an API compatible clone), which lets the depot's nodes share them and scale apart from
+//! their storage.
+
+pub mod local;
+pub mod s3;
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use config::StorageCfg;
+use error::Result;
+
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    Local,
+    S3,
+}
+
+pub trait ArtifactStore: Send + Sync {
+    /// Size in bytes of the archive stored under `key`, or `None` if there's no such archive.
+    fn size(&self, key: &str) -> Result<Option<u64>>;
+
+    /// Stores the archive at `path` under `key`, replacing any archive stored under it before.
+    /// The file at `path` is removed once the archive is stored.
+    fn put(&self, key: &str, path: &Path) -> Result<()>;
+
+    /// Reads the archive stored under `key` from byte `first` up to and including byte `last`.
+    fn get(&self, key: &str, first: u64, last: u64) -> Result<Box<Read + Send>>;
+
+    /// Path of a local file with the archive stored under `key`, for reading what's in it, or
+    /// `None` if there's no such archive.
+    fn local_path(&self, key: &str) -> Result<Option<PathBuf>>;
+
+    /// Removes the archive stored under `key`. Removing an archive which isn't there succeeds.
+    fn delete(&self, key: &str) -> Result<()>;
+}
+
+/// The store configured for the depot, which keeps local files under `path`
+pub fn from_config(config: &StorageCfg, path: &Path) -> Result<Box<ArtifactStore>> {
+    match config.backend {
+        StorageBackend::Local => Ok(Box::new(local::LocalStore::new(path.join("pkgs")))),
+        StorageBackend::S3 => Ok(Box::new(s3::S3Store::new(config, &path.join("s3-cache"))?)),
+    }
+}
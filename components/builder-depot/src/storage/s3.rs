@@ -0,0 +1,337 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Store which keeps archives in S3 (or an API compatible clone, such as
+//! [Minio](https://minio.io)).
+//!
+//! Archives are uploaded in parts when they're large, and downloaded a chunk at a time as the
+//! client reads them, so neither is ever held in memory as a whole. Reading what's in an archive
+//! needs a local file, so archives are copied to a local cache the first time that happens.
+//!
+//! # Configuration
+//!
+//! Currently the store must be configured with both an access key ID and a secret access key.
+
+use std::cmp;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use aws_sdk_rust::aws::common::credentials::{DefaultCredentialsProvider, ParametersProvider};
+use aws_sdk_rust::aws::common::region::Region;
+use aws_sdk_rust::aws::errors::s3::S3Error;
+use aws_sdk_rust::aws::s3::endpoint::{Endpoint, Signature};
+use aws_sdk_rust::aws::s3::object::{multipart_upload_finish_xml, DeleteObjectRequest,
+                                    GetObjectRequest, HeadObjectRequest,
+                                    MultipartUploadAbortRequest, MultipartUploadCompleteRequest,
+                                    MultipartUploadCreateRequest, MultipartUploadPartRequest,
+                                    PutObjectRequest};
+use aws_sdk_rust::aws::s3::s3client::S3Client;
+use hyper::client::Client as HyperClient;
+use url;
+use uuid::Uuid;
+
+use config::StorageCfg;
+use error::{Error, Result};
+
+use super::ArtifactStore;
+
+/// Size of the parts archives are uploaded in. Archives up to this size are uploaded at once.
+const PART_SIZE: u64 = 16 * 1024 * 1024;
+/// Size of the chunks archives are downloaded in
+const CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+type Client = S3Client<DefaultCredentialsProvider, HyperClient>;
+
+pub struct S3Store {
+    client: Arc<Client>,
+    bucket: String,
+    /// Local copies of archives, for reading what's in them
+    cache_path: PathBuf,
+}
+
+impl S3Store {
+    pub fn new(config: &StorageCfg, cache_path: &Path) -> Result<S3Store> {
+        let region = Region::from_str(config.region.as_str()).unwrap();
+        let param_provider = Some(
+            ParametersProvider::with_parameters(
+                config.key.as_ref().cloned().expect("Missing S3 key!"),
+                config
+                    .secret
+                    .as_ref()
+                    .cloned()
+                    .expect("Missing S3 secret!")
+                    .as_str(),
+                None,
+            ).unwrap(),
+        );
+        // If given an endpoint, such as a Minio server, don't use virtual buckets. If not, assume
+        // AWS and use virtual buckets.
+        let use_virtual_buckets = !config.endpoint.is_some();
+        let final_endpoint = match config.endpoint {
+            Some(ref url) => Some(url::Url::parse(url.as_str()).expect(
+                "Invalid endpoint URL given",
+            )),
+            None => None,
+        };
+        let provider = DefaultCredentialsProvider::new(param_provider).unwrap();
+        let endpoint = Endpoint::new(
+            region,
+            Signature::V4,
+            final_endpoint,
+            None,
+            Some(String::from("Habitat-Builder")),
+            Some(use_virtual_buckets),
+        );
+
+        Ok(S3Store {
+            client: Arc::new(S3Client::new(provider, endpoint)),
+            bucket: config.bucket.as_ref().cloned().expect(
+                "Missing Bucket Name!",
+            ),
+            cache_path: cache_path.to_path_buf(),
+        })
+    }
+
+    /// Uploads a large archive in parts, aborting the upload if any of them fails
+    fn put_parts(&self, key: &str, file: &mut File, size: u64) -> Result<()> {
+        let mut request = MultipartUploadCreateRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key.to_string();
+        let upload_id = call(format!("start upload of {}", key), || {
+            self.client.multipart_upload_create(&request)
+        })?
+            .upload_id;
+
+        let mut etags = Vec::new();
+        let mut buffer = Vec::with_capacity(PART_SIZE as usize);
+        let mut sent = 0;
+        while sent < size {
+            buffer.clear();
+            file.by_ref().take(PART_SIZE).read_to_end(&mut buffer)?;
+            let mut part = MultipartUploadPartRequest::default();
+            part.bucket = self.bucket.clone();
+            part.key = key.to_string();
+            part.upload_id = upload_id.clone();
+            part.part_number = etags.len() as i32 + 1;
+            part.body = Some(buffer.as_slice());
+            let result = call(
+                format!("upload part {} of {}", part.part_number, key),
+                || self.client.multipart_upload_part(&part),
+            );
+            match result {
+                Ok(etag) => etags.push(etag),
+                Err(err) => {
+                    self.abort_upload(key, &upload_id);
+                    return Err(err);
+                }
+            }
+            sent += buffer.len() as u64;
+        }
+
+        let parts = multipart_upload_finish_xml(&etags).map_err(|e| {
+            Error::ArtifactStore(format!("complete upload of {}", key), e)
+        })?;
+        let mut request = MultipartUploadCompleteRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key.to_string();
+        request.upload_id = upload_id.clone();
+        request.multipart_upload = Some(parts.as_slice());
+        if let Err(err) = call(format!("complete upload of {}", key), || {
+            self.client.multipart_upload_complete(&request)
+        })
+        {
+            self.abort_upload(key, &upload_id);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Discards the parts uploaded so far, which S3 would otherwise keep
+    fn abort_upload(&self, key: &str, upload_id: &str) {
+        let mut request = MultipartUploadAbortRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key.to_string();
+        request.upload_id = upload_id.to_string();
+        if let Err(err) = call(format!("abort upload of {}", key), || {
+            self.client.multipart_upload_abort(&request)
+        })
+        {
+            warn!("{}", err);
+        }
+    }
+}
+
+impl ArtifactStore for S3Store {
+    fn size(&self, key: &str) -> Result<Option<u64>> {
+        let mut request = HeadObjectRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key.to_string();
+        match call(format!("look up {}", key), || self.client.head_object(&request)) {
+            Ok(output) => Ok(Some(output.content_length as u64)),
+            Err(Error::ArtifactStore(_, ref err)) if is_missing(err) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn put(&self, key: &str, path: &Path) -> Result<()> {
+        let mut file = File::open(path)?;
+        let size = file.metadata()?.len();
+        if size > PART_SIZE {
+            self.put_parts(key, &mut file, size)?;
+        } else {
+            let mut buffer = Vec::with_capacity(size as usize);
+            file.read_to_end(&mut buffer)?;
+            let mut request = PutObjectRequest::default();
+            request.bucket = self.bucket.clone();
+            request.key = key.to_string();
+            request.body = Some(buffer.as_slice());
+            call(format!("upload {}", key), || {
+                self.client.put_object(&request, None)
+            })?;
+        }
+        fs::remove_file(path)?;
+        Ok(())
+    }
+
+    fn get(&self, key: &str, first: u64, last: u64) -> Result<Box<Read + Send>> {
+        Ok(Box::new(ObjectReader {
+            client: self.client.clone(),
+            bucket: self.bucket.clone(),
+            key: key.to_string(),
+            next: first,
+            last: last,
+            chunk: io::Cursor::new(Vec::new()),
+        }))
+    }
+
+    fn local_path(&self, key: &str) -> Result<Option<PathBuf>> {
+        let path = self.cache_path.join(key);
+        if path.is_file() {
+            return Ok(Some(path));
+        }
+        let size = match self.size(key)? {
+            Some(size) => size,
+            None => return Ok(None),
+        };
+        let dir = path.parent().expect("cached archive path has a parent");
+        fs::create_dir_all(dir)?;
+        // Copied under a temporary name first, so that a copy which isn't complete is never read
+        let tmp_path = dir.join(format!("{}.tmp", Uuid::new_v4()));
+        let copied = File::create(&tmp_path).map_err(Error::from).and_then(|mut file| {
+            if size > 0 {
+                io::copy(&mut self.get(key, 0, size - 1)?, &mut file)?;
+            }
+            file.flush()?;
+            Ok(())
+        });
+        if let Err(err) = copied {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(err);
+        }
+        fs::rename(&tmp_path, &path)?;
+        Ok(Some(path))
+    }
+
+    fn delete(&self, key: &str) -> Result<()> {
+        let mut request = DeleteObjectRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key.to_string();
+        // Deleting a key which doesn't exist succeeds
+        call(format!("delete {}", key), || {
+            self.client.delete_object(&request, None)
+        })?;
+        match fs::remove_file(self.cache_path.join(key)) {
+            Ok(()) => Ok(()),
+            Err(ref err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+/// Reads an object a chunk at a time, asking S3 for the next chunk once the last one was read
+struct ObjectReader {
+    client: Arc<Client>,
+    bucket: String,
+    key: String,
+    /// First byte of the next chunk
+    next: u64,
+    /// Last byte to read
+    last: u64,
+    chunk: io::Cursor<Vec<u8>>,
+}
+
+impl ObjectReader {
+    fn next_chunk(&mut self) -> Result<Vec<u8>> {
+        let last = cmp::min(self.next + CHUNK_SIZE - 1, self.last);
+        let mut request = GetObjectRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = self.key.clone();
+        request.range = Some(format!("bytes={}-{}", self.next, last));
+        let client = &self.client;
+        let output = call(format!("download {}", self.key), || {
+            client.get_object(&request, None)
+        })?;
+        self.next = last + 1;
+        Ok(output.body)
+    }
+}
+
+impl Read for ObjectReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.chunk.read(buf)?;
+        if read > 0 || buf.is_empty() || self.next > self.last {
+            return Ok(read);
+        }
+        let chunk = self.next_chunk().map_err(
+            |e| io::Error::new(io::ErrorKind::Other, e.to_string()),
+        )?;
+        if chunk.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                format!("{} ended before byte {}", self.key, self.next),
+            ));
+        }
+        self.chunk = io::Cursor::new(chunk);
+        self.chunk.read(buf)
+    }
+}
+
+/// Makes a request to S3, catching the panic the S3 library raises when S3 can't be reached.
+/// The library isn't unwind safe, so it has to be asserted.
+fn call<T, F>(what: String, request: F) -> Result<T>
+where
+    F: FnOnce() -> ::std::result::Result<T, S3Error>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(request)) {
+        Ok(Ok(output)) => Ok(output),
+        Ok(Err(err)) => Err(Error::ArtifactStore(what, err)),
+        Err(err) => {
+            let source = match err.downcast_ref::<String>() {
+                Some(string) => string.to_string(),
+                None => format!("{:?}", err),
+            };
+            Err(Error::CaughtPanic(format!("Failure to {}", what), source))
+        }
+    }
+}
+
+/// Whether S3 answered that there's no object under the key asked for
+fn is_missing(err: &S3Error) -> bool {
+    let message = err.to_string();
+    message.contains("NoSuchKey") || message.contains("404")
+}
@@ -0,0 +1,183 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Chunked uploads, which let clients upload a large artifact in parts and resume an upload that
+//! was interrupted instead of starting over.
+//!
+//! An upload is identified by the package it's for and the checksum of the whole artifact, so a
+//! client starting the same upload again gets back the parts the depot already received. Every
+//! part is sent along with its own checksum and is only kept if it matches. Once all parts are
+//! in, completing the upload puts them back together and hands the artifact to the regular upload
+//! checks.
+//!
+//! The parts of uploads which aren't completed are removed once no part was received for
+//! `MAX_IDLE_SECS`, whenever an upload is started.
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
+use hab_core::crypto::hash;
+
+/// Size of the parts clients are asked to send, in bytes
+pub const PART_SIZE: u64 = 8 * 1024 * 1024;
+/// The largest part the depot accepts, in bytes
+pub const MAX_PART_SIZE: u64 = 64 * 1024 * 1024;
+/// The number of parts an upload can have at most
+pub const MAX_PARTS: u64 = 1024;
+/// How long the parts of an upload are kept after the last one was received, in seconds
+pub const MAX_IDLE_SECS: u64 = 24 * 60 * 60;
+
+const PART_EXT: &'static str = "part";
+
+/// The parts of an upload received so far, which are kept on disk until it completes
+pub struct Upload {
+    dir: PathBuf,
+}
+
+impl Upload {
+    pub fn new(uploads_path: &Path, ident: &str, checksum: &str) -> Self {
+        let mut digest = Sha256::new();
+        digest.input_str(&format!("{}\n{}", ident, checksum));
+        Upload { dir: uploads_path.join(digest.result_str()) }
+    }
+
+    /// Starts the upload. Returns false if it was already started.
+    pub fn start(&self) -> io::Result<bool> {
+        if self.is_started() {
+            return Ok(false);
+        }
+        fs::create_dir_all(&self.dir)?;
+        Ok(true)
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.dir.is_dir()
+    }
+
+    /// The numbers of the parts received so far, in order
+    pub fn parts(&self) -> io::Result<Vec<u64>> {
+        let mut parts = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(PART_EXT) {
+                continue;
+            }
+            if let Some(part) = path.file_stem().and_then(|stem| stem.to_str()).and_then(
+                |stem| stem.parse::<u64>().ok(),
+            )
+            {
+                parts.push(part);
+            }
+        }
+        parts.sort();
+        Ok(parts)
+    }
+
+    /// Stores a part if its content matches the checksum it was sent with. Returns whether it
+    /// did. A part sent again replaces the one received before.
+    pub fn write_part(&self, part: u64, content: &[u8], checksum: &str) -> io::Result<bool> {
+        if hash::hash_bytes(content) != checksum {
+            return Ok(false);
+        }
+        // Written aside first, so that an interrupted write never leaves a partial part behind
+        let temp_path = self.dir.join(format!("{}.tmp", part));
+        File::create(&temp_path)?.write_all(content)?;
+        fs::rename(&temp_path, self.part_path(part))?;
+        Ok(true)
+    }
+
+    /// Puts the parts back together into `dst`. Returns false if any part is missing.
+    pub fn assemble(&self, dst: &Path) -> io::Result<bool> {
+        let parts = self.parts()?;
+        if !is_complete(&parts) {
+            return Ok(false);
+        }
+        let mut file = File::create(dst)?;
+        for part in parts {
+            io::copy(&mut File::open(self.part_path(part))?, &mut file)?;
+        }
+        file.sync_all()?;
+        Ok(true)
+    }
+
+    /// Removes the parts of the upload.
+    pub fn remove(&self) -> io::Result<()> {
+        fs::remove_dir_all(&self.dir)
+    }
+
+    fn part_path(&self, part: u64) -> PathBuf {
+        self.dir.join(format!("{}.{}", part, PART_EXT))
+    }
+}
+
+/// Removes the uploads found under `uploads_path` which didn't receive a part for longer than
+/// `max_idle`. Returns how many were removed.
+pub fn remove_idle(uploads_path: &Path, max_idle: Duration) -> io::Result<usize> {
+    if !uploads_path.is_dir() {
+        return Ok(0);
+    }
+    let now = SystemTime::now();
+    let mut removed = 0;
+    for entry in fs::read_dir(uploads_path)? {
+        let entry = entry?;
+        let metadata = entry.metadata()?;
+        if !metadata.is_dir() {
+            continue;
+        }
+        // Storing a part renames it into the directory of its upload, which touches it
+        let idle = now.duration_since(metadata.modified()?).unwrap_or(
+            Duration::from_secs(0),
+        );
+        if idle > max_idle {
+            fs::remove_dir_all(entry.path())?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Whether parts numbered from zero leave none out
+fn is_complete(parts: &[u64]) -> bool {
+    !parts.is_empty() && parts.iter().enumerate().all(|(i, part)| i as u64 == *part)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn complete_parts() {
+        assert!(is_complete(&[0]));
+        assert!(is_complete(&[0, 1, 2]));
+        assert!(!is_complete(&[]));
+        assert!(!is_complete(&[1, 2]));
+        assert!(!is_complete(&[0, 2]));
+    }
+
+    #[test]
+    fn uploads_of_other_artifacts_are_apart() {
+        let path = Path::new("/hab/svc/builder-api/data/uploads");
+        let upload = Upload::new(path, "core/redis/3.2.4/20170514001355", "abc");
+        assert_eq!(
+            upload.dir,
+            Upload::new(path, "core/redis/3.2.4/20170514001355", "abc").dir
+        );
+        assert!(upload.dir != Upload::new(path, "core/redis/3.2.4/20170514001355", "def").dir);
+        assert!(upload.dir != Upload::new(path, "core/redis/3.2.5/20170514001355", "abc").dir);
+    }
+}
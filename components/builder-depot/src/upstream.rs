@@ -0,0 +1,150 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fetches the packages and keys the depot doesn't have from an upstream Builder, which lets the
+//! depot act as a read-through cache for it.
+//!
+//! Archives and public keys are stored under the depot's data path the first time they're
+//! fetched and served from there afterwards, since they never change once published. What the
+//! latest release of a package or the latest key of an origin is can change at any time, so it's
+//! always asked of the upstream Builder.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use depot_client::{self, NoProgress};
+use hab_core::package::{Identifiable, PackageArchive};
+use protocol::originsrv::{OriginPackage, OriginPackageIdent};
+use uuid::Uuid;
+
+use error::{Error, Result};
+
+const PRODUCT: &'static str = "builder-depot";
+const VERSION: &'static str = include_str!(concat!(env!("OUT_DIR"), "/VERSION"));
+
+pub struct Upstream {
+    client: depot_client::Client,
+    /// Directory holding the cached archives and keys
+    path: PathBuf,
+}
+
+impl Upstream {
+    /// Creates a cache for the Builder at `url`, stored under `path`
+    pub fn new(url: &str, path: &Path) -> Result<Self> {
+        Ok(Upstream {
+            client: depot_client::Client::new(url, PRODUCT, VERSION, None)?,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Looks up a package, optionally in a channel, on the upstream Builder.
+    pub fn show_package(
+        &self,
+        ident: &OriginPackageIdent,
+        channel: Option<&str>,
+    ) -> Result<OriginPackage> {
+        Ok(self.client.show_package(ident, channel, None)?)
+    }
+
+    /// The archive of a fully qualified package, fetched from the upstream Builder if it isn't
+    /// cached yet
+    pub fn package(&self, ident: &OriginPackageIdent) -> Result<PackageArchive> {
+        let dir = self.path
+            .join("pkgs")
+            .join(ident.get_origin())
+            .join(ident.get_name())
+            .join(ident.get_version())
+            .join(ident.get_release());
+        if let Some(path) = cached_file(&dir, "hart")? {
+            return Ok(PackageArchive::new(path));
+        }
+        let path = self.fetch(&dir, |tmp_dir| {
+            let archive = self.client.fetch_package(ident, None, tmp_dir, None::<NoProgress>)?;
+            Ok(archive.path)
+        })?;
+        info!("Cached {} from upstream Builder", ident);
+        Ok(PackageArchive::new(path))
+    }
+
+    /// The public key of an origin at a revision, fetched from the upstream Builder if it isn't
+    /// cached yet
+    pub fn origin_key(&self, origin: &str, revision: &str) -> Result<PathBuf> {
+        let path = self.path.join("keys").join(
+            format!("{}-{}.pub", origin, revision),
+        );
+        if path.is_file() {
+            return Ok(path);
+        }
+        let dir = self.path.join("keys");
+        self.fetch(&dir, |tmp_dir| {
+            Ok(self.client.fetch_origin_key(
+                origin,
+                revision,
+                tmp_dir,
+                None::<NoProgress>,
+            )?)
+        })
+    }
+
+    /// The latest public key of an origin on the upstream Builder
+    pub fn latest_origin_key(&self, origin: &str) -> Result<PathBuf> {
+        let revision = self.client
+            .show_origin_keys(origin)?
+            .into_iter()
+            .map(|key| key.get_revision().to_string())
+            .max();
+        match revision {
+            Some(revision) => self.origin_key(origin, &revision),
+            None => Err(Error::RemoteOriginKeyNotFound(origin.to_string())),
+        }
+    }
+
+    /// Runs `fetch` to download a file into a temporary directory, and moves the file it returns
+    /// into `dir`. Downloads are never served from `dir` before they're complete this way.
+    fn fetch<F>(&self, dir: &Path, fetch: F) -> Result<PathBuf>
+    where
+        F: FnOnce(&Path) -> Result<PathBuf>,
+    {
+        let tmp_dir = self.path.join("tmp").join(Uuid::new_v4().to_string());
+        fs::create_dir_all(&tmp_dir)?;
+        let result = fetch(&tmp_dir).and_then(|file| {
+            let file_name = file.file_name().ok_or(Error::NoFilePart)?.to_owned();
+            fs::create_dir_all(dir)?;
+            let path = dir.join(file_name);
+            fs::rename(&file, &path)?;
+            Ok(path)
+        });
+        if let Err(err) = fs::remove_dir_all(&tmp_dir) {
+            warn!("Unable to remove {}, err={}", tmp_dir.display(), err);
+        }
+        result
+    }
+}
+
+/// The first file in `dir` with the given extension, if there's any
+fn cached_file(dir: &Path, extension: &str) -> Result<Option<PathBuf>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(ref err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(Error::IO(err)),
+    };
+    for entry in entries {
+        let path = entry?.path();
+        if path.extension().map_or(false, |ext| ext == extension) {
+            return Ok(Some(path));
+        }
+    }
+    Ok(None)
+}
@@ -271,7 +271,7 @@ fn do_rdeps(graph: &PackageGraph, name: &str, filter: &str, max: usize) {
     let start_time = PreciseTime::now();
 
     match graph.rdeps(name) {
-        Some(rdeps) => {
+        Ok(Some(rdeps)) => {
             let end_time = PreciseTime::now();
             let mut filtered: Vec<(String, String)> = rdeps
                 .into_iter()
@@ -296,7 +296,8 @@ fn do_rdeps(graph: &PackageGraph, name: &str, filter: &str, max: usize) {
                 println!("{} ({})", s1, s2);
             }
         }
-        None => println!("No entries found"),
+        Ok(None) => println!("No entries found"),
+        Err(e) => println!("Error: {}", e),
     }
 
     println!("");
@@ -111,6 +111,7 @@ use router::Router;
 use self::error::AppResult;
 use config::GatewayCfg;
 use conn::RouteBroker;
+use http::metrics::RequestMetrics;
 use http::middleware::{Cors, XRouteClient};
 
 /// Apply to a networked application which will act as a Gateway connecting to a RouteSrv.
@@ -147,9 +148,11 @@ where
 {
     let cfg = Arc::new(cfg);
     let mut chain = Chain::new(T::router(cfg.clone()));
+    chain.link_before(RequestMetrics);
     T::add_middleware(cfg.clone(), &mut chain);
     chain.link_before(XRouteClient);
     chain.link_after(Cors);
+    chain.link_after(RequestMetrics);
     let mount = T::mount(cfg.clone(), chain);
     let mut server = Iron::new(mount);
     server.threads = cfg.handler_count();
@@ -18,3 +18,4 @@ pub use super::GatewayCfg;
 pub use core::config::ConfigFile;
 pub use github_api_client::config::GitHubCfg;
 pub use hab_net::app::config::RouterAddr;
+pub use oauth::OAuthCfg;
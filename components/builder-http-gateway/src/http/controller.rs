@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::time::Instant;
+
 pub use hab_net::{ErrCode, NetError, NetOk, NetResult};
 pub use iron::{status, headers};
 pub use iron::headers::{ContentType, UserAgent};
@@ -24,6 +26,7 @@ pub use super::headers::*;
 pub use super::middleware::*;
 pub use super::rendering::{render_json, render_net_error};
 pub use conn::RouteBroker;
+use super::metrics;
 use super::middleware::XRouteClient;
 
 pub fn route_message<M, R>(req: &mut Request, msg: &M) -> NetResult<R>
@@ -31,8 +34,28 @@ where
     M: Routable,
     R: protobuf::MessageStatic,
 {
-    req.extensions
+    let started = Instant::now();
+    let result = req.extensions
+        .get_mut::<XRouteClient>()
+        .expect("no XRouteClient extension in request")
+        .route::<M, R>(msg);
+    metrics::observe_upstream(M::protocol(), started, result.as_ref().err());
+    result
+}
+
+/// Route a message to a service which may answer it with either a reply of type `R` or a message
+/// of type `E` explaining why the request couldn't be served.
+pub fn route_message_either<M, R, E>(req: &mut Request, msg: &M) -> NetResult<Result<R, E>>
+where
+    M: Routable,
+    R: protobuf::MessageStatic,
+    E: protobuf::MessageStatic,
+{
+    let started = Instant::now();
+    let result = req.extensions
         .get_mut::<XRouteClient>()
         .expect("no XRouteClient extension in request")
-        .route::<M, R>(msg)
+        .route_either::<M, R, E>(msg);
+    metrics::observe_upstream(M::protocol(), started, result.as_ref().err());
+    result
 }
@@ -16,3 +16,4 @@ header! { (CacheControl, "Cache-Control") => [String] }
 header! { (ContentDisposition, "Content-Disposition") => [String] }
 header! { (XFileName, "X-Filename") => [String] }
 header! { (ETag, "ETag") => [String] }
+header! { (IfNoneMatch, "If-None-Match") => [String] }
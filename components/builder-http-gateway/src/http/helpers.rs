@@ -12,9 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::str::FromStr;
 
+use base64;
 use core::channel::{STABLE_CHANNEL, UNSTABLE_CHANNEL};
 use core::crypto::SigKeyPair;
 use hab_net::{ErrCode, NetError, NetOk, NetResult};
@@ -22,23 +23,27 @@ use hab_net::privilege::{self, FeatureFlags};
 use http::controller::*;
 
 use iron::status::{self, Status};
-use protocol::originsrv::{CheckOriginOwnerRequest, CheckOriginOwnerResponse,
+use protocol::originsrv::{CheckOriginApproverRequest, CheckOriginApproverResponse,
+                          CheckOriginOwnerRequest, CheckOriginOwnerResponse,
                           CheckOriginAccessRequest, CheckOriginAccessResponse, Origin,
-                          OriginChannel, OriginChannelCreate, OriginChannelGet, OriginGet,
+                          OriginChannel, OriginChannelCreate, OriginChannelGet,
+                          OriginChannelPackageGet, OriginGet,
                           OriginPackage, OriginPackageChannelListRequest,
                           OriginPackageChannelListResponse, OriginPackageGet,
                           OriginPackageGroupPromote, OriginPackageGroupDemote, OriginPackageIdent,
                           OriginPackagePlatformListRequest, OriginPackagePlatformListResponse,
                           OriginPackagePromote, OriginPackageVisibility, OriginPublicKeyCreate,
-                          OriginPublicKey, OriginSecretKey, OriginSecretKeyCreate};
+                          OriginPublicKey, OriginSecretKey, OriginSecretKeyCreate, Pageable};
 use protocol::jobsrv::{JobGroup, JobGroupGet, JobGroupProject, JobGroupProjectState};
 use protocol::sessionsrv::Session;
+use protocol::timestamp;
 use serde::Serialize;
 use serde_json;
 use urlencoded::UrlEncodedQuery;
 
 use router::Router;
 use super::controller::route_message;
+use super::webhooks;
 
 pub fn dont_cache_response(response: &mut Response) {
     response.headers.set(CacheControl(
@@ -73,14 +78,76 @@ pub fn validate_params(
     Ok(res)
 }
 
-const PAGINATION_RANGE_DEFAULT: isize = 0;
-const PAGINATION_RANGE_MAX: isize = 50;
+/// Outcome of promoting every package built by a job group into a channel.
+#[derive(Serialize)]
+pub struct JobGroupPromotion {
+    pub group_id: String,
+    pub channel: String,
+    /// Packages built by the group which are now in the channel
+    pub promoted: Vec<String>,
+    /// Projects of the group whose packages weren't promoted, and why
+    pub skipped: Vec<SkippedProject>,
+}
+
+#[derive(Serialize)]
+pub struct SkippedProject {
+    pub name: String,
+    pub ident: String,
+    pub reason: String,
+}
+
+/// Packages of one origin promoted together by `promote_job_group`
+struct OriginPromotion {
+    origin: String,
+    channel: OriginChannel,
+    idents: Vec<String>,
+    package_ids: Vec<u64>,
+    /// Packages which weren't in the channel yet, and get demoted again if the promotion fails
+    new_package_ids: Vec<u64>,
+}
+
+const PAGINATION_RANGE_MAX: u64 = 50;
+
+/// A page of a listing, requested with the `cursor` and `limit` query parameters, or with the
+/// `range` parameter of older clients.
+///
+/// The first page of a listing takes a snapshot of the current time, which the cursors of the
+/// following pages carry along. The rows created after it are left out of every page, so that the
+/// pages don't shift while packages are uploaded or jobs are scheduled, and no row is skipped or
+/// returned twice.
+#[derive(Debug, PartialEq)]
+pub struct Page {
+    pub start: u64,
+    pub stop: u64,
+    /// RFC3339 timestamp of the snapshot
+    pub created_before: String,
+}
+
+impl Page {
+    /// Requests this page of a listing.
+    pub fn apply<P: Pageable>(&self, request: &mut P) {
+        request.set_range([self.start, self.stop]);
+        request.set_snapshot(self.created_before.clone());
+    }
+
+    /// The cursor of the page following a page which ended at `stop`, unless it was the last one
+    /// of a listing of `count` rows.
+    pub fn next_cursor(&self, stop: u64, count: u64) -> Option<String> {
+        if count > stop + 1 {
+            Some(encode_cursor(stop + 1, &self.created_before))
+        } else {
+            None
+        }
+    }
+}
 
 #[derive(Serialize)]
 struct PaginatedResults<'a, T: 'a> {
     range_start: isize,
     range_end: isize,
     total_count: isize,
+    /// Cursor of the next page, or null on the last one
+    next_cursor: Option<String>,
     data: &'a Vec<T>,
 }
 
@@ -89,11 +156,12 @@ pub fn paginated_response<T>(
     count: isize,
     start: isize,
     end: isize,
+    page: &Page,
 ) -> IronResult<Response>
 where
     T: Serialize,
 {
-    let body = package_results_json(body, count, start, end);
+    let body = package_results_json(body, count, start, end, page);
 
     if count > end + 1 {
         Ok(Response::with((status::PartialContent, body)))
@@ -102,43 +170,129 @@ where
     }
 }
 
+/// Responds with the requested page of a listing which was fetched whole.
+pub fn paginated_list_response<T>(list: &[T], page: &Page) -> IronResult<Response>
+where
+    T: Serialize + Clone,
+{
+    let start = page.start as usize;
+    let rows = if start < list.len() {
+        list[start..list.len().min(page.stop as usize + 1)].to_vec()
+    } else {
+        Vec::new()
+    };
+    let stop = if rows.is_empty() {
+        page.stop
+    } else {
+        page.start + rows.len() as u64 - 1
+    };
+    paginated_response(
+        &rows,
+        list.len() as isize,
+        page.start as isize,
+        stop as isize,
+        page,
+    )
+}
+
 pub fn package_results_json<T: Serialize>(
     packages: &Vec<T>,
     count: isize,
     start: isize,
     end: isize,
+    page: &Page,
 ) -> String {
     let results = PaginatedResults {
         range_start: start,
         range_end: end,
         total_count: count,
+        next_cursor: page.next_cursor(end as u64, count as u64),
         data: packages,
     };
 
     serde_json::to_string(&results).unwrap()
 }
 
-// Returns a tuple representing the from and to values representing a paginated set.
-// The range (start, stop) values are zero-based.
-pub fn extract_pagination(req: &mut Request) -> Result<(isize, isize), Response> {
-    let range_from_param = match extract_query_value("range", req) {
-        Some(range) => range,
-        None => PAGINATION_RANGE_DEFAULT.to_string(),
+/// Returns the requested page of a listing. The first page is returned if none was requested.
+pub fn extract_page(req: &mut Request) -> Result<Page, Response> {
+    let limit = match extract_query_value("limit", req) {
+        Some(limit) => {
+            match limit.parse::<u64>() {
+                Ok(limit) if limit > 0 && limit <= PAGINATION_RANGE_MAX => limit,
+                _ => return Err(Response::with(status::BadRequest)),
+            }
+        }
+        None => PAGINATION_RANGE_MAX,
     };
 
-    let offset = {
-        match range_from_param.parse::<usize>() {
-            Ok(range) => range as isize,
-            Err(_) => return Err(Response::with(status::BadRequest)),
+    let (start, created_before) = match extract_query_value("cursor", req) {
+        Some(cursor) => {
+            match decode_cursor(&cursor) {
+                Some(position) => position,
+                None => return Err(Response::with(status::BadRequest)),
+            }
+        }
+        None => {
+            let start = match extract_query_value("range", req) {
+                Some(range) => {
+                    match range.parse::<u64>() {
+                        Ok(range) => range,
+                        Err(_) => return Err(Response::with(status::BadRequest)),
+                    }
+                }
+                None => 0,
+            };
+            (start, timestamp::now())
         }
     };
 
     debug!(
-        "extract_pagination range: (start, end): ({}, {})",
-        offset,
-        (offset + PAGINATION_RANGE_MAX - 1)
+        "extract_page (start, stop): ({}, {}), created before: {}",
+        start,
+        start + limit - 1,
+        created_before
     );
-    Ok((offset, offset + PAGINATION_RANGE_MAX - 1))
+    Ok(Page {
+        start: start,
+        stop: start + limit - 1,
+        created_before: created_before,
+    })
+}
+
+/// Returns the requested page of a listing, or `None` if the request doesn't ask for one. This
+/// is for the listings which are returned whole unless a client asks for a page of them.
+pub fn extract_optional_page(req: &mut Request) -> Result<Option<Page>, Response> {
+    let paginated = ["cursor", "limit", "range"].iter().any(|key| {
+        extract_query_value(key, req).is_some()
+    });
+    if paginated {
+        extract_page(req).map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+/// Cursors are opaque to clients, but hold the offset of a page and the snapshot of its listing.
+fn encode_cursor(start: u64, created_before: &str) -> String {
+    base64::encode_config(
+        format!("{}:{}", start, created_before).as_bytes(),
+        base64::URL_SAFE_NO_PAD,
+    )
+}
+
+fn decode_cursor(cursor: &str) -> Option<(u64, String)> {
+    let decoded = match base64::decode_config(cursor, base64::URL_SAFE_NO_PAD) {
+        Ok(bytes) => String::from_utf8(bytes).unwrap_or_default(),
+        Err(_) => return None,
+    };
+    let parts: Vec<&str> = decoded.splitn(2, ':').collect();
+    if parts.len() != 2 || parts[1].is_empty() {
+        return None;
+    }
+    match (parts[0].parse::<u64>(), timestamp::normalize(parts[1])) {
+        (Ok(start), Ok(created_before)) => Some((start, created_before)),
+        _ => None,
+    }
 }
 
 pub fn extract_query_value(key: &str, req: &mut Request) -> Option<String> {
@@ -294,6 +448,33 @@ where
     }
 }
 
+/// Whether the account may approve promotion requests of the origin
+pub fn check_origin_approver<T>(req: &mut Request, account_id: u64, origin: T) -> IronResult<bool>
+where
+    T: ToString,
+{
+    let mut request = CheckOriginApproverRequest::new();
+    request.set_account_id(account_id);
+    request.set_origin_name(origin.to_string());
+    match route_message::<CheckOriginApproverRequest, CheckOriginApproverResponse>(req, &request) {
+        Ok(response) => Ok(response.get_is_approver()),
+        Err(err) => {
+            let body = serde_json::to_string(&err).unwrap();
+            let status = net_err_to_http(err.get_code());
+            Err(IronError::new(err, (body, status)))
+        }
+    }
+}
+
+/// Whether the requester may promote packages of the origin directly. Other members of the origin
+/// have to open a promotion request, which an approver reviews.
+fn check_promotion_access(req: &mut Request, origin: &str) -> bool {
+    match get_optional_session_id(req) {
+        Some(account_id) => check_origin_approver(req, account_id, origin).unwrap_or(false),
+        None => false,
+    }
+}
+
 pub fn create_channel(req: &mut Request, origin: &str, channel: &str) -> NetResult<OriginChannel> {
     let mut origin = get_origin(req, origin)?;
     let mut request = OriginChannelCreate::new();
@@ -320,6 +501,12 @@ pub fn promote_package_to_channel(
             "core:promote-package-to-channel:0",
         ));
     }
+    if !check_promotion_access(req, ident.get_origin()) {
+        return Err(NetError::new(
+            ErrCode::ACCESS_DENIED,
+            "core:promote-package-to-channel:1",
+        ));
+    }
 
     let mut channel_req = OriginChannelGet::new();
     channel_req.set_origin_name(ident.get_origin().to_string());
@@ -335,7 +522,15 @@ pub fn promote_package_to_channel(
     promote.set_channel_id(origin_channel.get_id());
     promote.set_package_id(package.get_id());
     promote.set_ident(ident.clone());
-    route_message::<OriginPackagePromote, NetOk>(req, &promote)
+    let ok = route_message::<OriginPackagePromote, NetOk>(req, &promote)?;
+    webhooks::notify_channel_change(
+        req,
+        ident.get_origin(),
+        channel,
+        vec![ident.to_string()],
+        true,
+    );
+    Ok(ok)
 }
 
 pub fn promote_or_demote_job_group(
@@ -413,6 +608,168 @@ pub fn promote_or_demote_job_group(
     Ok(NetOk::new())
 }
 
+/// Promotes every package built by a completed job group into a channel, skipping the projects
+/// which weren't built successfully and those of origins the requester can't access.
+///
+/// Every package is looked up before any is promoted, and the packages of each origin are promoted
+/// at once. Origins can live on different shards, so if promoting the packages of one origin
+/// fails, the packages which were added to the channel for the other origins are demoted again.
+pub fn promote_job_group(
+    req: &mut Request,
+    group_id: u64,
+    channel: &str,
+) -> NetResult<JobGroupPromotion> {
+    let mut group_get = JobGroupGet::new();
+    group_get.set_group_id(group_id);
+    let group = route_message::<JobGroupGet, JobGroup>(req, &group_get)?;
+    if group.get_projects().iter().any(|p| {
+        p.get_state() == JobGroupProjectState::NotStarted ||
+            p.get_state() == JobGroupProjectState::InProgress
+    })
+    {
+        return Err(NetError::new(
+            ErrCode::GROUP_NOT_COMPLETE,
+            "hg:promote-job-group:0",
+        ));
+    }
+
+    let mut promotion = JobGroupPromotion {
+        group_id: group_id.to_string(),
+        channel: channel.to_string(),
+        promoted: Vec::new(),
+        skipped: Vec::new(),
+    };
+    let mut origin_map = BTreeMap::new();
+    for project in group.get_projects() {
+        if project.get_state() != JobGroupProjectState::Success {
+            promotion.skipped.push(SkippedProject {
+                name: project.get_name().to_string(),
+                ident: project.get_ident().to_string(),
+                reason: format!("Project state is {}", project.get_state()),
+            });
+            continue;
+        }
+        let ident = OriginPackageIdent::from_str(project.get_ident()).unwrap();
+        origin_map
+            .entry(ident.get_origin().to_string())
+            .or_insert(Vec::new())
+            .push(project);
+    }
+
+    let mut origin_promotions = Vec::new();
+    for (origin, projects) in origin_map {
+        if !check_origin_access(req, &origin).unwrap_or(false) {
+            for project in projects {
+                promotion.skipped.push(SkippedProject {
+                    name: project.get_name().to_string(),
+                    ident: project.get_ident().to_string(),
+                    reason: format!("No access to origin {}", origin),
+                });
+            }
+            continue;
+        }
+        if !check_promotion_access(req, &origin) {
+            for project in projects {
+                promotion.skipped.push(SkippedProject {
+                    name: project.get_name().to_string(),
+                    ident: project.get_ident().to_string(),
+                    reason: format!("Not an approver of origin {}", origin),
+                });
+            }
+            continue;
+        }
+        let origin_channel = get_or_create_channel(req, &origin, channel)?;
+        let mut origin_promotion = OriginPromotion {
+            origin: origin,
+            channel: origin_channel,
+            idents: Vec::new(),
+            package_ids: Vec::new(),
+            new_package_ids: Vec::new(),
+        };
+        for project in projects {
+            let ident = OriginPackageIdent::from_str(project.get_ident()).unwrap();
+            let mut opg = OriginPackageGet::new();
+            opg.set_ident(ident.clone());
+            opg.set_visibilities(all_visibilities());
+            let package = route_message::<OriginPackageGet, OriginPackage>(req, &opg)?;
+
+            let mut ocpg = OriginChannelPackageGet::new();
+            ocpg.set_name(channel.to_string());
+            ocpg.set_ident(ident);
+            ocpg.set_visibilities(all_visibilities());
+            match route_message::<OriginChannelPackageGet, OriginPackage>(req, &ocpg) {
+                Ok(_) => (),
+                Err(ref e) if e.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+                    origin_promotion.new_package_ids.push(package.get_id())
+                }
+                Err(e) => return Err(e),
+            }
+            origin_promotion.idents.push(project.get_ident().to_string());
+            origin_promotion.package_ids.push(package.get_id());
+        }
+        origin_promotions.push(origin_promotion);
+    }
+
+    for i in 0..origin_promotions.len() {
+        let mut opgp = OriginPackageGroupPromote::new();
+        opgp.set_channel_id(origin_promotions[i].channel.get_id());
+        opgp.set_package_ids(origin_promotions[i].package_ids.clone());
+        opgp.set_origin(origin_promotions[i].origin.clone());
+        if let Err(err) = route_message::<OriginPackageGroupPromote, NetOk>(req, &opgp) {
+            warn!(
+                "Failed to promote packages of origin {} for group {}, rolling back, err: {:?}",
+                origin_promotions[i].origin,
+                group_id,
+                err
+            );
+            for done in origin_promotions[..i].iter() {
+                let mut opgd = OriginPackageGroupDemote::new();
+                opgd.set_channel_id(done.channel.get_id());
+                opgd.set_package_ids(done.new_package_ids.clone());
+                opgd.set_origin(done.origin.clone());
+                if let Err(e) = route_message::<OriginPackageGroupDemote, NetOk>(req, &opgd) {
+                    warn!(
+                        "Failed to roll back promotion of origin {} for group {}, err: {:?}",
+                        done.origin,
+                        group_id,
+                        e
+                    );
+                }
+            }
+            return Err(err);
+        }
+    }
+
+    for origin_promotion in origin_promotions {
+        webhooks::notify_channel_change(
+            req,
+            &origin_promotion.origin,
+            origin_promotion.channel.get_name(),
+            origin_promotion.idents.clone(),
+            true,
+        );
+        promotion.promoted.extend(origin_promotion.idents);
+    }
+    Ok(promotion)
+}
+
+fn get_or_create_channel(
+    req: &mut Request,
+    origin: &str,
+    channel: &str,
+) -> NetResult<OriginChannel> {
+    let mut ocg = OriginChannelGet::new();
+    ocg.set_origin_name(origin.to_string());
+    ocg.set_name(channel.to_string());
+    match route_message::<OriginChannelGet, OriginChannel>(req, &ocg) {
+        Ok(channel) => Ok(channel),
+        Err(ref e) if e.get_code() == ErrCode::ENTITY_NOT_FOUND => {
+            create_channel(req, origin, channel)
+        }
+        Err(e) => Err(e),
+    }
+}
+
 pub fn get_optional_session_id(req: &mut Request) -> Option<u64> {
     match req.extensions.get::<Authenticated>() {
         Some(session) => Some(session.get_id()),
@@ -464,6 +821,12 @@ fn do_group_promotion_or_demotion(
             "hg:promote-demote-job-group:0",
         ));
     }
+    if promote && !check_promotion_access(req, origin) {
+        return Err(NetError::new(
+            ErrCode::ACCESS_DENIED,
+            "hg:promote-demote-job-group:1",
+        ));
+    }
 
     let mut ocg = OriginChannelGet::new();
     ocg.set_origin_name(origin.to_string());
@@ -487,6 +850,7 @@ fn do_group_promotion_or_demotion(
     };
 
     let mut package_ids = Vec::new();
+    let idents: Vec<String> = projects.iter().map(|p| p.get_ident().to_string()).collect();
 
     for project in projects {
         let opi = OriginPackageIdent::from_str(project.get_ident()).unwrap();
@@ -498,21 +862,23 @@ fn do_group_promotion_or_demotion(
         package_ids.push(op.get_id());
     }
 
-    if promote {
+    let ok = if promote {
         let mut opgp = OriginPackageGroupPromote::new();
         opgp.set_channel_id(channel.get_id());
         opgp.set_package_ids(package_ids);
         opgp.set_origin(origin.to_string());
 
-        route_message::<OriginPackageGroupPromote, NetOk>(req, &opgp)
+        route_message::<OriginPackageGroupPromote, NetOk>(req, &opgp)?
     } else {
         let mut opgp = OriginPackageGroupDemote::new();
         opgp.set_channel_id(channel.get_id());
         opgp.set_package_ids(package_ids);
         opgp.set_origin(origin.to_string());
 
-        route_message::<OriginPackageGroupDemote, NetOk>(req, &opgp)
-    }
+        route_message::<OriginPackageGroupDemote, NetOk>(req, &opgp)?
+    };
+    webhooks::notify_channel_change(req, origin, channel.get_name(), idents, promote);
+    Ok(ok)
 }
 
 fn is_worker(req: &mut Request) -> bool {
@@ -529,3 +895,42 @@ fn get_session_id(req: &mut Request) -> u64 {
     let session = req.extensions.get::<Authenticated>().unwrap();
     session.get_id()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_roundtrip() {
+        let cursor = encode_cursor(50, "2017-06-01T00:01:00+00:00");
+        assert!(!cursor.contains('='));
+        assert_eq!(
+            decode_cursor(&cursor),
+            Some((50, "2017-06-01T00:01:00+00:00".to_string()))
+        );
+    }
+
+    #[test]
+    fn reject_malformed_cursor() {
+        assert_eq!(decode_cursor("not a cursor"), None);
+        assert_eq!(decode_cursor(&base64::encode("50")), None);
+        assert_eq!(
+            decode_cursor(&base64::encode_config(b"50:yesterday", base64::URL_SAFE_NO_PAD)),
+            None
+        );
+    }
+
+    #[test]
+    fn next_cursor() {
+        let page = Page {
+            start: 0,
+            stop: 49,
+            created_before: "2017-06-01T00:01:00+00:00".to_string(),
+        };
+        assert_eq!(
+            page.next_cursor(49, 120),
+            Some(encode_cursor(50, "2017-06-01T00:01:00+00:00"))
+        );
+        assert_eq!(page.next_cursor(49, 50), None);
+    }
+}
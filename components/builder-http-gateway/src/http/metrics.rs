@@ -0,0 +1,182 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Gathers the metrics of a gateway and serves them at `/metrics` in the Prometheus text format.
+//!
+//! Requests are counted and timed by the route they matched, with the values of its parameters
+//! left out so that every package or job doesn't become a metric of its own. Messages routed to
+//! the services behind the gateway are timed and their errors counted by service, which shows
+//! whether a slow or failing request is waiting on the jobsrv, the originsrv or the sessionsrv.
+
+use std::time::{Duration, Instant};
+
+use hab_net::NetError;
+use iron::middleware::{AfterMiddleware, BeforeMiddleware};
+use iron::prelude::*;
+use iron::status::{self, Status};
+use iron::typemap::Key;
+use mount::OriginalUrl;
+use prometheus::{self, CounterVec, Encoder, Gauge, HistogramVec, TextEncoder};
+use protocol::Protocol;
+use router::{Params, Router};
+
+lazy_static! {
+    static ref REQUESTS: CounterVec = register_counter_vec!(
+        opts!(
+            "http_gateway_requests_total",
+            "Number of requests served, by route, method and status."),
+        &["route", "method", "status"]).unwrap();
+
+    static ref REQUEST_LATENCY: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "http_gateway_request_duration_seconds",
+            "Time taken to serve a request, by route and method, in seconds.",
+            vec![0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0]),
+        &["route", "method"]).unwrap();
+
+    static ref ACTIVE_CONNECTIONS: Gauge = register_gauge!(
+        opts!(
+            "http_gateway_active_connections",
+            "Number of connections with a request being served.")).unwrap();
+
+    static ref UPSTREAM_LATENCY: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "http_gateway_upstream_request_duration_seconds",
+            "Time taken by a service to reply to a message, in seconds.",
+            vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]),
+        &["service"]).unwrap();
+
+    static ref UPSTREAM_ERRORS: CounterVec = register_counter_vec!(
+        opts!(
+            "http_gateway_upstream_errors_total",
+            "Number of messages a service replied to with an error, or which couldn't reach it."),
+        &["service", "code"]).unwrap();
+}
+
+/// Counts and times the requests served by the chain it's linked to, as both a before and an
+/// after middleware.
+pub struct RequestMetrics;
+
+struct RequestStart;
+
+impl Key for RequestStart {
+    type Value = Instant;
+}
+
+impl BeforeMiddleware for RequestMetrics {
+    fn before(&self, req: &mut Request) -> IronResult<()> {
+        ACTIVE_CONNECTIONS.inc();
+        req.extensions.insert::<RequestStart>(Instant::now());
+        Ok(())
+    }
+}
+
+impl AfterMiddleware for RequestMetrics {
+    fn after(&self, req: &mut Request, res: Response) -> IronResult<Response> {
+        observe_request(req, res.status);
+        Ok(res)
+    }
+
+    fn catch(&self, req: &mut Request, err: IronError) -> IronResult<Response> {
+        observe_request(req, err.response.status);
+        Err(err)
+    }
+}
+
+/// Serves the metrics of the gateway.
+pub fn metrics(_req: &mut Request) -> IronResult<Response> {
+    let mut buffer = vec![];
+    let encoder = TextEncoder::new();
+    let metric_families = prometheus::gather();
+    encoder.encode(&metric_families, &mut buffer).unwrap();
+
+    let mut response = Response::with((status::Ok, buffer));
+    response.headers.set_raw(
+        "Content-Type",
+        vec![encoder.format_type().as_bytes().to_vec()],
+    );
+    Ok(response)
+}
+
+/// Record a message routed to a service, once its reply was received or routing it failed.
+pub fn observe_upstream(protocol: Protocol, started: Instant, err: Option<&NetError>) {
+    let service = format!("{:?}", protocol).to_lowercase();
+    UPSTREAM_LATENCY.with_label_values(&[&service]).observe(
+        seconds(started.elapsed()),
+    );
+    if let Some(err) = err {
+        let code = format!("{:?}", err.get_code());
+        UPSTREAM_ERRORS.with_label_values(&[&service, &code]).inc();
+    }
+}
+
+fn observe_request(req: &mut Request, status: Option<Status>) {
+    // Requests which failed before reaching this middleware were never counted as active
+    let started = match req.extensions.remove::<RequestStart>() {
+        Some(started) => started,
+        None => return,
+    };
+    ACTIVE_CONNECTIONS.dec();
+
+    let route = match req.extensions.get::<Router>() {
+        Some(params) => {
+            let url = req.extensions.get::<OriginalUrl>().unwrap_or(&req.url);
+            route_template(&url.path(), params)
+        }
+        None => "unmatched".to_string(),
+    };
+    let method = req.method.to_string();
+    // Iron answers with a 404 when no status was set
+    let status = status.unwrap_or(Status::NotFound).to_u16().to_string();
+    REQUESTS
+        .with_label_values(&[&route, &method, &status])
+        .inc();
+    REQUEST_LATENCY.with_label_values(&[&route, &method]).observe(
+        seconds(started.elapsed()),
+    );
+}
+
+/// The route a path matched, with the segments holding the values of its parameters replaced by
+/// their names, e.g. `/v1/depot/pkgs/:origin/:pkg/latest`
+fn route_template(segments: &[&str], params: &Params) -> String {
+    let route = segments
+        .iter()
+        .map(|segment| match params.iter().find(|&(_, value)| value == *segment) {
+            Some((name, _)) => format!(":{}", name),
+            None => segment.to_string(),
+        })
+        .collect::<Vec<String>>();
+    format!("/{}", route.join("/"))
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1_000_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_params_are_named() {
+        let mut params = Params::new();
+        params.insert("origin".to_string(), "core".to_string());
+        params.insert("pkg".to_string(), "redis".to_string());
+        assert_eq!(
+            route_template(&["v1", "depot", "pkgs", "core", "redis", "latest"], &params),
+            "/v1/depot/pkgs/:origin/:pkg/latest"
+        );
+        assert_eq!(route_template(&["v1", "status"], &Params::new()), "/v1/status");
+    }
+}
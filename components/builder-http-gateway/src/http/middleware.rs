@@ -13,11 +13,10 @@
 // limitations under the License.
 
 use base64;
-use github_api_client::{GitHubCfg, GitHubClient, HubError};
+use github_api_client::{GitHubCfg, GitHubClient};
 use hab_net::{ErrCode, NetError};
 use hab_net::conn::RouteClient;
 use hab_net::privilege::FeatureFlags;
-use hyper;
 use iron::Handler;
 use iron::headers::{self, Authorization, Bearer};
 use iron::method::Method;
@@ -34,6 +33,7 @@ use unicase::UniCase;
 
 use super::net_err_to_http;
 use conn::RouteBroker;
+use oauth::{OAuthClient, OAuthError};
 
 /// Wrapper around the standard `iron::Chain` to assist in adding middleware on a per-handler basis
 pub struct XHandler(Chain);
@@ -87,6 +87,13 @@ impl Key for GitHubCli {
     type Value = GitHubClient;
 }
 
+/// Client of the OAuth provider users sign in with
+pub struct OAuthCli;
+
+impl Key for OAuthCli {
+    type Value = Box<OAuthClient>;
+}
+
 pub struct SegmentCli;
 
 impl Key for SegmentCli {
@@ -111,6 +118,7 @@ pub struct Authenticated {
     github: GitHubClient,
     features: FeatureFlags,
     optional: bool,
+    scopes: Vec<AccessTokenScope>,
 }
 
 impl Authenticated {
@@ -120,6 +128,7 @@ impl Authenticated {
             github: github,
             features: FeatureFlags::empty(),
             optional: false,
+            scopes: vec![],
         }
     }
 
@@ -133,6 +142,20 @@ impl Authenticated {
         self
     }
 
+    /// Accepts sessions of personal access tokens with the given scope. Access tokens are only
+    /// accepted by routes allowing one of their scopes, and are ignored by optional ones otherwise.
+    pub fn scope(mut self, scope: AccessTokenScope) -> Self {
+        self.scopes.push(scope);
+        self
+    }
+
+    fn allows(&self, session: &Session) -> bool {
+        !session.has_access_token_id() ||
+            session.get_scopes().iter().any(
+                |scope| self.scopes.contains(scope),
+            )
+    }
+
     fn authenticate(&self, req: &mut Request, token: SessionToken) -> IronResult<Session> {
         let mut request = SessionGet::new();
         request.set_token(token);
@@ -185,10 +208,10 @@ impl BeforeMiddleware for Authenticated {
                 } else {
                     // TODO: Replace temporary auth workaround
                     // We got a bearer token that is not a valid session token.
-                    // Check to see if this is a valid github token, and create (or
-                    // update) a session. This is a temporary fix until we can roll out
+                    // Check to see if this is a valid token of the OAuth provider, and create
+                    // (or update) a session. This is a temporary fix until we can roll out
                     // and migrate clients to our own personal access tokens.
-                    session_create_github(req, token)?
+                    session_create_oauth(req, token)?
                 }
             } else {
                 let err = NetError::new(ErrCode::BAD_TOKEN, "net:auth:3");
@@ -196,6 +219,14 @@ impl BeforeMiddleware for Authenticated {
             }
         };
 
+        if !self.allows(&session) {
+            if self.optional {
+                return Ok(());
+            }
+            let err = NetError::new(ErrCode::ACCESS_DENIED, "net:auth:4");
+            return Err(IronError::new(err, Status::Forbidden));
+        }
+
         req.extensions.insert::<Self>(session);
         Ok(())
     }
@@ -220,18 +251,19 @@ impl AfterMiddleware for Cors {
     }
 }
 
-pub fn session_create_github(req: &mut Request, token: String) -> IronResult<Session> {
-    let github = req.get::<persistent::Read<GitHubCli>>().unwrap();
+/// Creates (or updates) the session of the user an access token of the OAuth provider belongs to
+pub fn session_create_oauth(req: &mut Request, token: String) -> IronResult<Session> {
+    let oauth = req.get::<persistent::Read<OAuthCli>>().unwrap();
     let conn = req.extensions.get_mut::<XRouteClient>().expect(
         "no XRouteClient extension in request",
     );
-    match github.user(&token) {
+    match oauth.user(&token) {
         Ok(user) => {
             let mut request = SessionCreate::new();
             request.set_token(token);
             request.set_extern_id(user.id);
-            request.set_name(user.login);
-            request.set_provider(OAuthProvider::GitHub);
+            request.set_name(user.username);
+            request.set_provider(oauth.provider());
             if let Some(email) = user.email {
                 request.set_email(email);
             }
@@ -244,21 +276,21 @@ pub fn session_create_github(req: &mut Request, token: String) -> IronResult<Ses
                 }
             }
         }
-        Err(HubError::ApiError(hyper::status::StatusCode::Unauthorized, _)) => {
+        Err(OAuthError::Unauthorized) => {
             let err = NetError::new(ErrCode::ACCESS_DENIED, "net:session-create:1");
             let status = net_err_to_http(err.get_code());
             let body = itry!(serde_json::to_string(&err));
             Err(IronError::new(err, (body, status)))
         }
-        Err(e @ HubError::ApiError(_, _)) => {
-            warn!("Unexpected response from GitHub, {:?}", e);
+        Err(e @ OAuthError::HttpResponse(_)) => {
+            warn!("Unexpected response from OAuth provider, {:?}", e);
             let err = NetError::new(ErrCode::BAD_REMOTE_REPLY, "net:session-create:2");
             let status = net_err_to_http(err.get_code());
             let body = itry!(serde_json::to_string(&err));
             Err(IronError::new(err, (body, status)))
         }
-        Err(e @ HubError::Serialization(_)) => {
-            warn!("Bad response body from GitHub, {:?}", e);
+        Err(e @ OAuthError::Serialization(_)) => {
+            warn!("Bad response body from OAuth provider, {:?}", e);
             let err = NetError::new(ErrCode::BAD_REMOTE_REPLY, "net:session-create:3");
             let status = net_err_to_http(err.get_code());
             let body = itry!(serde_json::to_string(&err));
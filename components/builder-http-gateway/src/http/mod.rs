@@ -17,8 +17,10 @@
 pub mod controller;
 pub mod headers;
 pub mod helpers;
+pub mod metrics;
 pub mod middleware;
 pub mod rendering;
+pub mod webhooks;
 
 use hab_net::ErrCode;
 use iron::status::Status;
@@ -39,21 +41,28 @@ pub fn net_err_to_http(err: ErrCode) -> Status {
 
         ErrCode::NO_SHARD |
         ErrCode::SOCK |
+        ErrCode::LOW_DISK_SPACE |
         ErrCode::REMOTE_UNAVAILABLE => Status::ServiceUnavailable,
 
         ErrCode::BAD_TOKEN => Status::Forbidden,
+        ErrCode::RATE_LIMITED => Status::TooManyRequests,
         ErrCode::GROUP_NOT_COMPLETE => Status::UnprocessableEntity,
         ErrCode::PARTIAL_JOB_GROUP_PROMOTE => Status::PartialContent,
 
         ErrCode::BUG |
         ErrCode::POST_PROCESSOR |
         ErrCode::BUILD |
+        ErrCode::BUILD_TIMEOUT |
+        ErrCode::BUILD_OFFLINE |
+        ErrCode::BUILD_OOM |
+        ErrCode::DOCKER_EXPORT |
         ErrCode::SYS |
         ErrCode::DATA_STORE |
         ErrCode::WORKSPACE_SETUP |
         ErrCode::SECRET_KEY_IMPORT |
         ErrCode::INVALID_INTEGRATIONS |
         ErrCode::REG_CONFLICT |
-        ErrCode::REG_NOT_FOUND => Status::InternalServerError,
+        ErrCode::REG_NOT_FOUND |
+        ErrCode::REG_INCOMPATIBLE_VERSION => Status::InternalServerError,
     }
 }
@@ -0,0 +1,134 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tells origins about changes to their packages, through the webhooks they subscribed to them
+//! with.
+//!
+//! Gateways changing packages link `Webhooks` into their chain, and leave it out to not notify
+//! anyone.
+
+use std::path::PathBuf;
+
+use bldr_core;
+use bldr_core::webhook::{self, WebhookCfg};
+use iron::prelude::*;
+use iron::typemap::Key;
+use persistent;
+use protocol::originsrv::{OriginNotificationEvent, OriginNotificationKind,
+                          OriginNotificationListRequest, OriginNotificationListResponse,
+                          OriginPackageIdent};
+use serde_json;
+
+use super::controller::route_message;
+
+/// How webhooks are delivered, and where the Builder key pair decrypting their secrets is
+#[derive(Clone, Debug)]
+pub struct Webhooks {
+    pub config: WebhookCfg,
+    pub key_dir: PathBuf,
+}
+
+impl Key for Webhooks {
+    type Value = Webhooks;
+}
+
+/// Tells an origin about a package uploaded to it.
+pub fn notify_package_upload(req: &mut Request, ident: &OriginPackageIdent) {
+    let event = OriginNotificationEvent::PackageUpload;
+    let payload = json!({
+        "event": event.to_string(),
+        "origin": ident.get_origin(),
+        "package": ident.to_string(),
+    });
+    notify(req, ident.get_origin(), event, &payload);
+}
+
+/// Tells an origin about packages of it promoted to or demoted from a channel.
+pub fn notify_channel_change(
+    req: &mut Request,
+    origin: &str,
+    channel: &str,
+    idents: Vec<String>,
+    promote: bool,
+) {
+    let event = if promote {
+        OriginNotificationEvent::ChannelPromote
+    } else {
+        OriginNotificationEvent::ChannelDemote
+    };
+    let payload = json!({
+        "event": event.to_string(),
+        "origin": origin,
+        "channel": channel,
+        "packages": idents,
+    });
+    notify(req, origin, event, &payload);
+}
+
+/// Delivers the payload of an event to the webhooks of the origin subscribed to it. Failures
+/// are logged, as the change the event is about has been made either way.
+fn notify(
+    req: &mut Request,
+    origin: &str,
+    event: OriginNotificationEvent,
+    payload: &serde_json::Value,
+) {
+    let webhooks = match req.get::<persistent::Read<Webhooks>>() {
+        Ok(webhooks) => webhooks,
+        Err(_) => return,
+    };
+    let mut request = OriginNotificationListRequest::new();
+    request.set_origin(origin.to_string());
+    let response = match route_message::<
+        OriginNotificationListRequest,
+        OriginNotificationListResponse,
+    >(req, &request) {
+        Ok(response) => response,
+        Err(err) => {
+            warn!("Unable to list the notifications of origin {}, {}", origin, err);
+            return;
+        }
+    };
+
+    let payload = payload.to_string();
+    for notification in response.get_notifications() {
+        if notification.get_kind() != OriginNotificationKind::Webhook ||
+            !notification.wants(event)
+        {
+            continue;
+        }
+        let secret = if notification.has_secret() {
+            match bldr_core::integrations::decrypt(&webhooks.key_dir, notification.get_secret()) {
+                Ok(secret) => Some(secret),
+                Err(err) => {
+                    warn!(
+                        "Unable to decrypt the secret of notification {}, {}",
+                        notification.get_id(),
+                        err
+                    );
+                    continue;
+                }
+            }
+        } else {
+            None
+        };
+        webhook::spawn_delivery(
+            webhooks.config.clone(),
+            notification.get_target().to_string(),
+            secret,
+            event.to_string(),
+            payload.clone(),
+        );
+    }
+}
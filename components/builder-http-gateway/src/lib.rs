@@ -24,20 +24,26 @@ extern crate habitat_core as core;
 extern crate habitat_net as hab_net;
 #[macro_use]
 extern crate hyper;
+extern crate hyper_openssl;
 #[macro_use]
 extern crate iron;
 #[macro_use]
+extern crate lazy_static;
+#[macro_use]
 extern crate log;
 extern crate mount;
 extern crate num_cpus;
 extern crate params;
 extern crate persistent;
+#[macro_use]
+extern crate prometheus;
 extern crate protobuf;
 extern crate router;
 extern crate segment_api_client;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[macro_use]
 extern crate serde_json;
 extern crate staticfile;
 extern crate toml;
@@ -49,5 +55,6 @@ pub mod app;
 pub mod config;
 pub mod conn;
 pub mod http;
+pub mod oauth;
 
 pub use app::start;
@@ -0,0 +1,61 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// URL of GitLab.com
+pub const DEFAULT_GITLAB_URL: &'static str = "https://gitlab.com";
+
+/// OAuth providers users can sign in with
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub enum ProviderKind {
+    #[serde(rename = "github")]
+    GitHub,
+    #[serde(rename = "gitlab")]
+    GitLab,
+    #[serde(rename = "oidc")]
+    Oidc,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
+pub struct OAuthCfg {
+    /// Provider to sign users in with, one of `github`, `gitlab` or `oidc`. GitHub is configured
+    /// by the `github` section.
+    pub provider: ProviderKind,
+    /// URL of the GitLab instance
+    pub url: String,
+    /// URL of the OpenID Connect provider's token endpoint
+    pub token_url: String,
+    /// URL of the OpenID Connect provider's userinfo endpoint
+    pub userinfo_url: String,
+    /// URL users are sent back to once they authorized Builder, if the provider requires it
+    pub redirect_url: String,
+    /// Client identifier of Builder with the provider
+    pub client_id: String,
+    /// Client secret of Builder with the provider
+    pub client_secret: String,
+}
+
+impl Default for OAuthCfg {
+    fn default() -> Self {
+        OAuthCfg {
+            provider: ProviderKind::GitHub,
+            url: DEFAULT_GITLAB_URL.to_string(),
+            token_url: String::new(),
+            userinfo_url: String::new(),
+            redirect_url: String::new(),
+            client_id: String::new(),
+            client_secret: String::new(),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error;
+use std::fmt;
+use std::io;
+
+use github_api_client::HubError;
+use hyper;
+use serde_json;
+
+pub type OAuthResult<T> = Result<T, OAuthError>;
+
+#[derive(Debug)]
+pub enum OAuthError {
+    /// The provider refused to exchange the code, with the error it replied with
+    Auth(String),
+    GitHub(HubError),
+    HttpClient(hyper::Error),
+    HttpClientParse(hyper::error::ParseError),
+    HttpResponse(hyper::status::StatusCode),
+    IO(io::Error),
+    Serialization(serde_json::Error),
+    /// The access token isn't valid
+    Unauthorized,
+}
+
+impl fmt::Display for OAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match *self {
+            OAuthError::Auth(ref e) => format!("OAuth authentication error, {}", e),
+            OAuthError::GitHub(ref e) => format!("{}", e),
+            OAuthError::HttpClient(ref e) => format!("{}", e),
+            OAuthError::HttpClientParse(ref e) => format!("{}", e),
+            OAuthError::HttpResponse(ref e) => format!("{}", e),
+            OAuthError::IO(ref e) => format!("{}", e),
+            OAuthError::Serialization(ref e) => format!("{}", e),
+            OAuthError::Unauthorized => format!("Access token is not authorized"),
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl error::Error for OAuthError {
+    fn description(&self) -> &str {
+        match *self {
+            OAuthError::Auth(_) => "OAuth authorization error.",
+            OAuthError::GitHub(ref err) => err.description(),
+            OAuthError::HttpClient(ref err) => err.description(),
+            OAuthError::HttpClientParse(ref err) => err.description(),
+            OAuthError::HttpResponse(_) => "Non-200 HTTP response.",
+            OAuthError::IO(ref err) => err.description(),
+            OAuthError::Serialization(ref err) => err.description(),
+            OAuthError::Unauthorized => "Access token is not authorized.",
+        }
+    }
+}
+
+impl From<HubError> for OAuthError {
+    fn from(err: HubError) -> Self {
+        match err {
+            HubError::Auth(e) => OAuthError::Auth(e.error),
+            HubError::ApiError(hyper::status::StatusCode::Unauthorized, _) => {
+                OAuthError::Unauthorized
+            }
+            HubError::ApiError(status, _) => OAuthError::HttpResponse(status),
+            HubError::Serialization(e) => OAuthError::Serialization(e),
+            e => OAuthError::GitHub(e),
+        }
+    }
+}
+
+impl From<io::Error> for OAuthError {
+    fn from(err: io::Error) -> Self {
+        OAuthError::IO(err)
+    }
+}
+
+impl From<serde_json::Error> for OAuthError {
+    fn from(err: serde_json::Error) -> Self {
+        OAuthError::Serialization(err)
+    }
+}
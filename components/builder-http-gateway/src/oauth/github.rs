@@ -0,0 +1,46 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use github_api_client::{GitHubCfg, GitHubClient};
+use protocol::sessionsrv::OAuthProvider;
+
+use super::{OAuthClient, OAuthResult, OAuthUser};
+
+pub struct GitHubProvider(GitHubClient);
+
+impl GitHubProvider {
+    pub fn new(config: GitHubCfg) -> Self {
+        GitHubProvider(GitHubClient::new(config))
+    }
+}
+
+impl OAuthClient for GitHubProvider {
+    fn provider(&self) -> OAuthProvider {
+        OAuthProvider::GitHub
+    }
+
+    fn authenticate(&self, code: &str) -> OAuthResult<String> {
+        let token = self.0.authenticate(code)?;
+        Ok(token)
+    }
+
+    fn user(&self, token: &str) -> OAuthResult<OAuthUser> {
+        let user = self.0.user(token)?;
+        Ok(OAuthUser {
+            id: user.id,
+            username: user.login,
+            email: user.email,
+        })
+    }
+}
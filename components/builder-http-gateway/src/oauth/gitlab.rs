@@ -0,0 +1,56 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protocol::sessionsrv::OAuthProvider;
+
+use super::{exchange_code, user_info, OAuthCfg, OAuthClient, OAuthResult, OAuthUser};
+
+#[derive(Deserialize)]
+struct User {
+    id: u32,
+    username: String,
+    email: Option<String>,
+}
+
+/// Signs users in with GitLab.com or a self-hosted GitLab instance
+pub struct GitLabProvider {
+    config: OAuthCfg,
+}
+
+impl GitLabProvider {
+    pub fn new(config: OAuthCfg) -> Self {
+        GitLabProvider { config: config }
+    }
+}
+
+impl OAuthClient for GitLabProvider {
+    fn provider(&self) -> OAuthProvider {
+        OAuthProvider::GitLab
+    }
+
+    fn authenticate(&self, code: &str) -> OAuthResult<String> {
+        let url = format!("{}/oauth/token", self.config.url.trim_right_matches('/'));
+        exchange_code(&self.config, &url, code)
+    }
+
+    fn user(&self, token: &str) -> OAuthResult<OAuthUser> {
+        let url = format!("{}/api/v4/user", self.config.url.trim_right_matches('/'));
+        let user = user_info::<User>(&url, token)?;
+        Ok(OAuthUser {
+            id: user.id,
+            username: user.username,
+            email: user.email,
+        })
+    }
+}
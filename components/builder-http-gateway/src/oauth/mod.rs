@@ -0,0 +1,171 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Authenticates users with the OAuth provider Builder signs them in with.
+//!
+//! Users sign in by authorizing Builder with the provider, which sends them back with a code. The
+//! code is exchanged for an access token, and the token is used to look up the user whenever a
+//! session is created for them. GitHub is used unless configured otherwise; GitLab and any OpenID
+//! Connect provider can be used instead, so that Builder deployments can sign users in with their
+//! own single sign-on service.
+
+pub mod config;
+pub mod error;
+mod github;
+mod gitlab;
+mod oidc;
+
+pub use self::config::{OAuthCfg, ProviderKind};
+pub use self::error::{OAuthError, OAuthResult};
+
+use std::io::Read;
+use std::time::Duration;
+
+use github_api_client::GitHubCfg;
+use hyper::{self, Url};
+use hyper::header::{Accept, Authorization, Bearer, ContentType, UserAgent, qitem};
+use hyper::mime::{Mime, SubLevel, TopLevel};
+use hyper::net::HttpsConnector;
+use hyper::status::StatusCode;
+use hyper::url::form_urlencoded;
+use hyper_openssl::OpensslClient;
+use protocol::sessionsrv::OAuthProvider;
+use serde::de::DeserializeOwned;
+use serde_json;
+
+use self::github::GitHubProvider;
+use self::gitlab::GitLabProvider;
+use self::oidc::OidcProvider;
+
+const USER_AGENT: &'static str = "Habitat-Builder";
+const HTTP_TIMEOUT: u64 = 3_000;
+
+/// A user as known to an OAuth provider
+#[derive(Clone, Debug)]
+pub struct OAuthUser {
+    /// Identifier of the user with the provider
+    pub id: u32,
+    pub username: String,
+    pub email: Option<String>,
+}
+
+/// An OAuth provider which users sign in with
+pub trait OAuthClient: Send + Sync {
+    /// The provider recorded with the sessions of its users
+    fn provider(&self) -> OAuthProvider;
+
+    /// Exchanges the code a user was sent back with for their access token
+    fn authenticate(&self, code: &str) -> OAuthResult<String>;
+
+    /// Returns the user an access token belongs to
+    fn user(&self, token: &str) -> OAuthResult<OAuthUser>;
+}
+
+/// Returns a client of the provider selected by the configuration. GitHub keeps being configured
+/// by its own section, as Builder uses it for more than signing in.
+pub fn client(config: &OAuthCfg, github: &GitHubCfg) -> Box<OAuthClient> {
+    match config.provider {
+        ProviderKind::GitHub => Box::new(GitHubProvider::new(github.clone())),
+        ProviderKind::GitLab => Box::new(GitLabProvider::new(config.clone())),
+        ProviderKind::Oidc => Box::new(OidcProvider::new(config.clone())),
+    }
+}
+
+#[derive(Deserialize)]
+struct TokenOk {
+    access_token: String,
+}
+
+#[derive(Deserialize)]
+struct TokenErr {
+    error: String,
+}
+
+/// Exchanges a code for an access token at a token endpoint, using the authorization code grant
+fn exchange_code(config: &OAuthCfg, url: &str, code: &str) -> OAuthResult<String> {
+    let url = Url::parse(url).map_err(OAuthError::HttpClientParse)?;
+    let mut form = form_urlencoded::Serializer::new(String::new());
+    form.append_pair("grant_type", "authorization_code")
+        .append_pair("code", code)
+        .append_pair("client_id", &config.client_id)
+        .append_pair("client_secret", &config.client_secret);
+    if !config.redirect_url.is_empty() {
+        form.append_pair("redirect_uri", &config.redirect_url);
+    }
+    let body = form.finish();
+    let mut rep = http_client()
+        .post(url)
+        .header(Accept(vec![
+            qitem(Mime(TopLevel::Application, SubLevel::Json, vec![])),
+        ]))
+        .header(ContentType(
+            Mime(TopLevel::Application, SubLevel::WwwFormUrlEncoded, vec![]),
+        ))
+        .header(UserAgent(USER_AGENT.to_string()))
+        .body(body.as_str())
+        .send()
+        .map_err(OAuthError::HttpClient)?;
+    let mut body = String::new();
+    rep.read_to_string(&mut body)?;
+    debug!("OAuth token response body, {}", body);
+    match serde_json::from_str::<TokenOk>(&body) {
+        Ok(msg) => Ok(msg.access_token),
+        Err(_) => {
+            match serde_json::from_str::<TokenErr>(&body) {
+                Ok(err) => Err(OAuthError::Auth(err.error)),
+                Err(_) if !rep.status.is_success() => Err(OAuthError::HttpResponse(rep.status)),
+                Err(err) => Err(OAuthError::Serialization(err)),
+            }
+        }
+    }
+}
+
+/// Reads the JSON document describing the user at `url` with their access token
+fn user_info<T>(url: &str, token: &str) -> OAuthResult<T>
+where
+    T: DeserializeOwned,
+{
+    let url = Url::parse(url).map_err(OAuthError::HttpClientParse)?;
+    let mut rep = http_client()
+        .get(url)
+        .header(Accept(vec![
+            qitem(Mime(TopLevel::Application, SubLevel::Json, vec![])),
+        ]))
+        .header(Authorization(Bearer { token: token.to_string() }))
+        .header(UserAgent(USER_AGENT.to_string()))
+        .send()
+        .map_err(OAuthError::HttpClient)?;
+    let mut body = String::new();
+    rep.read_to_string(&mut body)?;
+    debug!("OAuth user response body, {}", body);
+    match rep.status {
+        StatusCode::Ok => (),
+        StatusCode::Unauthorized => return Err(OAuthError::Unauthorized),
+        status => {
+            warn!("Unexpected user response, status={}, body={}", status, body);
+            return Err(OAuthError::HttpResponse(status));
+        }
+    }
+    let user = serde_json::from_str(&body)?;
+    Ok(user)
+}
+
+fn http_client() -> hyper::Client {
+    let ssl = OpensslClient::new().unwrap();
+    let connector = HttpsConnector::new(ssl);
+    let mut client = hyper::Client::with_connector(connector);
+    client.set_read_timeout(Some(Duration::from_millis(HTTP_TIMEOUT)));
+    client.set_write_timeout(Some(Duration::from_millis(HTTP_TIMEOUT)));
+    client
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use protocol::sessionsrv::OAuthProvider;
+
+use super::{exchange_code, user_info, OAuthCfg, OAuthClient, OAuthResult, OAuthUser};
+
+/// Claims of the userinfo endpoint Builder reads
+#[derive(Deserialize)]
+struct UserInfo {
+    sub: String,
+    preferred_username: Option<String>,
+    email: Option<String>,
+}
+
+/// Signs users in with any OpenID Connect provider, such as a corporate single sign-on service
+pub struct OidcProvider {
+    config: OAuthCfg,
+}
+
+impl OidcProvider {
+    pub fn new(config: OAuthCfg) -> Self {
+        OidcProvider { config: config }
+    }
+}
+
+impl OAuthClient for OidcProvider {
+    fn provider(&self) -> OAuthProvider {
+        OAuthProvider::Oidc
+    }
+
+    fn authenticate(&self, code: &str) -> OAuthResult<String> {
+        exchange_code(&self.config, &self.config.token_url, code)
+    }
+
+    fn user(&self, token: &str) -> OAuthResult<OAuthUser> {
+        let info = user_info::<UserInfo>(&self.config.userinfo_url, token)?;
+        let username = match info.preferred_username {
+            Some(username) => username,
+            None => info.sub.clone(),
+        };
+        Ok(OAuthUser {
+            id: subject_id(&info.sub),
+            username: username,
+            email: info.email,
+        })
+    }
+}
+
+/// Sessions identify users by a number, while the subject of an OpenID Connect user can be any
+/// string. Numeric subjects are used as they are, and others are hashed with 32-bit FNV-1a.
+fn subject_id(sub: &str) -> u32 {
+    if let Ok(id) = sub.parse::<u32>() {
+        return id;
+    }
+    sub.bytes().fold(0x811c9dc5, |hash: u32, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    })
+}
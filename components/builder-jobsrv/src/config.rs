@@ -14,12 +14,15 @@
 
 //! Configuration for a Habitat JobSrv service
 
+use std::collections::HashMap;
 use std::env;
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::path::PathBuf;
 
+use bldr_core::webhook::WebhookCfg;
 use hab_net::app::config::*;
 use db::config::DataStoreCfg;
+use github_api_client::config::GitHubCfg;
 use server::log_archiver::ArchiveBackend;
 
 use error::Error;
@@ -36,27 +39,69 @@ pub struct Config {
     pub log_dir: PathBuf,
     /// Configuration for the job log archiver
     pub archive: ArchiveCfg,
+    /// Configuration for notifying origins of finished builds
+    pub notifier: NotifierCfg,
+    /// Configuration for the build quotas of origins
+    pub quota: QuotaCfg,
+    /// GitHub application used to check that projects' plans can be reached before they're built
+    pub github: GitHubCfg,
     /// Filepath to where the builder encryption keys can be found
     pub key_dir: PathBuf,
     /// Path to scheduler event logs
     pub log_path: PathBuf,
-    /// Max time (in minutes) allowed for a build job
+    /// Max time (in minutes) allowed for a build job, unless its project sets its own limit
     pub job_timeout: u64,
+    /// Max memory (in megabytes) a build job may use, 0 for no limit
+    pub job_memory_limit_mb: u64,
+    /// Max CPU time (in thousandths of a CPU) a build job may use, 0 for no limit
+    pub job_cpu_limit_millicores: u32,
+    /// Min free disk space (in megabytes) a worker must report to be dispatched a job
+    pub worker_min_disk_free: u64,
+    /// Max number of times a job which failed for a transient reason is automatically requeued
+    pub job_max_retries: u32,
+    /// Max time (in minutes) a job may wait in the Pending or Dispatched state before it's failed
+    pub job_ttl: u64,
+    /// Max number of job groups of one origin which may be building at once, 0 for no limit.
+    /// Groups over the limit stay queued and queued groups are served fairly across origins.
+    pub max_groups_per_origin: u32,
+    /// File the package graph is saved to, so that a restart doesn't have to build it again
+    pub graph_snapshot_path: PathBuf,
+    /// How often (in seconds) the package graph is saved, if it has changed
+    pub graph_snapshot_interval: u64,
+    /// Days of finished jobs and job groups to keep, along with their logs, 0 to keep them all
+    pub job_retention_days: u64,
+    /// How often (in seconds) history older than the retention window is pruned
+    pub job_prune_interval: u64,
 }
 
 impl Default for Config {
     fn default() -> Self {
         let mut datastore = DataStoreCfg::default();
         datastore.database = String::from("builder_jobsrv");
+        let mut app = AppCfg::default();
+        app.health_port = 9640;
         Config {
-            app: AppCfg::default(),
+            app: app,
             net: NetCfg::default(),
             datastore: datastore,
             log_dir: env::temp_dir(),
             archive: ArchiveCfg::default(),
+            notifier: NotifierCfg::default(),
+            quota: QuotaCfg::default(),
+            github: GitHubCfg::default(),
             key_dir: PathBuf::from("/hab/svc/hab-depot/files"),
             log_path: PathBuf::from("/tmp"),
             job_timeout: 60,
+            job_memory_limit_mb: 0,
+            job_cpu_limit_millicores: 0,
+            worker_min_disk_free: 1024,
+            job_max_retries: 3,
+            job_ttl: 1440,
+            max_groups_per_origin: 2,
+            graph_snapshot_path: env::temp_dir().join("builder-jobsrv-graph.json"),
+            graph_snapshot_interval: 300,
+            job_retention_days: 0,
+            job_prune_interval: 3600,
         }
     }
 }
@@ -86,6 +131,14 @@ pub struct NetCfg {
     pub log_ingestion_listen: IpAddr,
     /// Worker Log Ingestion socket's port
     pub log_ingestion_port: u16,
+    /// Log Stream socket's listening address
+    pub log_stream_listen: IpAddr,
+    /// Log Stream socket's port, on which new log output is published for followers
+    pub log_stream_port: u16,
+    /// Metrics HTTP server's listening address
+    pub metrics_listen: IpAddr,
+    /// Metrics HTTP server's port
+    pub metrics_port: u16,
 }
 
 impl NetCfg {
@@ -112,6 +165,14 @@ impl NetCfg {
             self.log_ingestion_port
         )
     }
+
+    pub fn log_stream_addr(&self) -> String {
+        format!("tcp://{}:{}", self.log_stream_listen, self.log_stream_port)
+    }
+
+    pub fn metrics_addr(&self) -> SocketAddr {
+        SocketAddr::new(self.metrics_listen, self.metrics_port)
+    }
 }
 
 impl Default for NetCfg {
@@ -123,6 +184,10 @@ impl Default for NetCfg {
             worker_heartbeat_port: 5567,
             log_ingestion_listen: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
             log_ingestion_port: 5568,
+            log_stream_listen: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            log_stream_port: 5569,
+            metrics_listen: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            metrics_port: 9636,
         }
     }
 }
@@ -144,6 +209,11 @@ pub struct ArchiveCfg {
 
     // These are for local log archiving
     pub local_dir: Option<PathBuf>,
+
+    /// How long (in hours) the log directory keeps a copy of a log once it's archived, so
+    /// recently finished jobs are served without a trip to the archive. 0 removes the copy as
+    /// soon as the log is archived.
+    pub local_retention_hours: u64,
 }
 
 impl Default for ArchiveCfg {
@@ -158,10 +228,87 @@ impl Default for ArchiveCfg {
             region: String::from("us-east-1"),
 
             local_dir: None,
+
+            local_retention_hours: 24,
         }
     }
 }
 
+////////////////////////////////////////////////////////////////////////
+// Notifier Configuration
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct NotifierCfg {
+    /// Program used to send notification emails. It's given the message on stdin along with the
+    /// `-t` flag, which any sendmail compatible program accepts.
+    pub sendmail_path: PathBuf,
+    /// Sender address of notification emails
+    pub email_from: String,
+    /// Max time (in seconds) to wait on a webhook endpoint
+    pub webhook_timeout: u64,
+    /// Number of times a failed webhook delivery is retried
+    pub webhook_retries: u32,
+    /// Time (in seconds) to wait before retrying a webhook delivery, doubled before every further
+    /// retry
+    pub webhook_backoff: u64,
+}
+
+impl NotifierCfg {
+    pub fn webhook(&self) -> WebhookCfg {
+        WebhookCfg {
+            timeout: self.webhook_timeout,
+            retries: self.webhook_retries,
+            backoff: self.webhook_backoff,
+        }
+    }
+}
+
+impl Default for NotifierCfg {
+    fn default() -> Self {
+        NotifierCfg {
+            sendmail_path: PathBuf::from("/usr/sbin/sendmail"),
+            email_from: String::from("builder@localhost"),
+            webhook_timeout: 10,
+            webhook_retries: 5,
+            webhook_backoff: 2,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////
+// Quota Configuration
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct QuotaCfg {
+    /// Max number of builds an origin may start in a rolling day, 0 for no limit
+    pub max_builds_per_day: u32,
+    /// Max number of workers the jobs of one origin may occupy at once, 0 for no limit
+    pub max_workers: u32,
+    /// Quotas of particular origins, which take the place of the ones above
+    pub origins: HashMap<String, OriginQuotaCfg>,
+}
+
+impl Default for QuotaCfg {
+    fn default() -> Self {
+        QuotaCfg {
+            max_builds_per_day: 0,
+            max_workers: 0,
+            origins: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct OriginQuotaCfg {
+    /// Max number of builds the origin may start in a rolling day, 0 for no limit
+    pub max_builds_per_day: Option<u32>,
+    /// Max number of workers the origin's jobs may occupy at once, 0 for no limit
+    pub max_workers: Option<u32>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,6 +316,17 @@ mod tests {
     #[test]
     fn config_from_file() {
         let content = r#"
+        worker_min_disk_free = 2048
+        job_memory_limit_mb = 4096
+        job_cpu_limit_millicores = 2000
+        job_max_retries = 5
+        job_ttl = 120
+        max_groups_per_origin = 4
+        graph_snapshot_path = "/hab/svc/builder-jobsrv/data/graph.json"
+        graph_snapshot_interval = 60
+        job_retention_days = 90
+        job_prune_interval = 600
+
         [net]
         worker_command_listen = "1:1:1:1:1:1:1:1"
         worker_command_port = 9000
@@ -176,6 +334,10 @@ mod tests {
         worker_heartbeat_port = 9000
         log_ingestion_listen = "2.2.2.2"
         log_ingestion_port = 9999
+        log_stream_listen = "2.2.2.2"
+        log_stream_port = 9998
+        metrics_listen = "3.3.3.3"
+        metrics_port = 9090
 
         [archive]
         backend = "s3"
@@ -183,6 +345,19 @@ mod tests {
         secret = "THIS_IS_THE_SECRET"
         bucket = "bukkit"
         endpoint = "http://minio.mycompany.com:9000"
+        local_retention_hours = 6
+
+        [notifier]
+        sendmail_path = "/bin/sendmail"
+        email_from = "builds@example.com"
+        webhook_timeout = 5
+
+        [quota]
+        max_builds_per_day = 100
+        max_workers = 2
+
+        [quota.origins.core]
+        max_workers = 8
 
         [datastore]
         host = "1.1.1.1"
@@ -206,9 +381,24 @@ mod tests {
         );
         assert_eq!(&format!("{}", config.net.log_ingestion_listen), "2.2.2.2");
 
+        assert_eq!(config.worker_min_disk_free, 2048);
+        assert_eq!(config.job_memory_limit_mb, 4096);
+        assert_eq!(config.job_cpu_limit_millicores, 2000);
+        assert_eq!(config.job_max_retries, 5);
+        assert_eq!(config.job_ttl, 120);
+        assert_eq!(config.max_groups_per_origin, 4);
+        assert_eq!(
+            config.graph_snapshot_path,
+            PathBuf::from("/hab/svc/builder-jobsrv/data/graph.json")
+        );
+        assert_eq!(config.graph_snapshot_interval, 60);
+        assert_eq!(config.job_retention_days, 90);
+        assert_eq!(config.job_prune_interval, 600);
         assert_eq!(config.net.worker_command_port, 9000);
         assert_eq!(config.net.worker_heartbeat_port, 9000);
         assert_eq!(config.net.log_ingestion_port, 9999);
+        assert_eq!(config.net.log_stream_addr(), "tcp://2.2.2.2:9998");
+        assert_eq!(&format!("{}", config.net.metrics_addr()), "3.3.3.3:9090");
         assert_eq!(config.datastore.port, 9000);
         assert_eq!(config.datastore.user, "test");
         assert_eq!(config.datastore.database, "test_jobsrv");
@@ -230,5 +420,19 @@ mod tests {
         );
         assert_eq!(config.archive.region, "us-east-1");
         assert_eq!(config.archive.local_dir, None);
+        assert_eq!(config.archive.local_retention_hours, 6);
+
+        assert_eq!(
+            config.notifier.sendmail_path,
+            PathBuf::from("/bin/sendmail")
+        );
+        assert_eq!(config.notifier.email_from, "builds@example.com");
+        assert_eq!(config.notifier.webhook_timeout, 5);
+        assert_eq!(config.notifier.webhook_retries, 5);
+
+        assert_eq!(config.quota.max_builds_per_day, 100);
+        assert_eq!(config.quota.max_workers, 2);
+        assert_eq!(config.quota.origins["core"].max_builds_per_day, None);
+        assert_eq!(config.quota.origins["core"].max_workers, Some(8));
     }
 }
@@ -14,6 +14,7 @@
 
 //! The PostgreSQL backend for the Jobsrv.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use chrono::{DateTime, UTC};
@@ -23,18 +24,56 @@ use db::pool::Pool;
 use postgres;
 use postgres::rows::Rows;
 use protobuf;
-use protocol::net::{NetError, ErrCode};
+use protocol::net::ErrCode;
 use protocol::{originsrv, jobsrv};
 use protocol::originsrv::Pageable;
+use protocol::timestamp;
 use protobuf::{ProtobufEnum, RepeatedField};
 use migrations;
 
 use error::{Result, Error};
 
+/// What caused a job or job group state transition, recorded in its audit log
+#[derive(Clone, Debug)]
+pub struct Audit {
+    trigger: jobsrv::JobAuditTrigger,
+    requester: Option<(u64, String)>,
+}
+
+impl Audit {
+    pub fn new(trigger: jobsrv::JobAuditTrigger) -> Self {
+        Audit {
+            trigger: trigger,
+            requester: None,
+        }
+    }
+
+    /// A transition requested by a user. Requests which don't name an account, i.e. have a
+    /// requester id of 0, are recorded without one.
+    pub fn user(requester_id: u64, requester_name: &str) -> Self {
+        let requester = if requester_id == 0 {
+            None
+        } else {
+            Some((requester_id, requester_name.to_string()))
+        };
+        Audit {
+            trigger: jobsrv::JobAuditTrigger::User,
+            requester: requester,
+        }
+    }
+
+    fn requester(&self) -> (Option<i64>, Option<&str>) {
+        match self.requester {
+            Some((id, ref name)) => (Some(id as i64), Some(name.as_str())),
+            None => (None, None),
+        }
+    }
+}
+
 /// DataStore inherints being Send + Sync by virtue of having only one member, the pool itself.
 #[derive(Debug, Clone)]
 pub struct DataStore {
-    pool: Pool,
+    pub pool: Pool,
 }
 
 impl DataStore {
@@ -65,6 +104,8 @@ impl DataStore {
 
         migrations::jobs::migrate(&mut migrator)?;
         migrations::scheduler::migrate(&mut migrator)?;
+        migrations::audit::migrate(&mut migrator)?;
+        migrations::retention::migrate(&mut migrator)?;
 
         migrator.finish()?;
 
@@ -78,7 +119,7 @@ impl DataStore {
     /// * If the pool has no connections available
     /// * If the job cannot be created
     /// * If the job has an unknown VCS type
-    pub fn create_job(&self, job: &jobsrv::Job) -> Result<jobsrv::Job> {
+    pub fn create_job(&self, job: &jobsrv::Job, audit: &Audit) -> Result<jobsrv::Job> {
         let conn = self.pool.get_shard(0)?;
 
         let channel = if job.has_channel() {
@@ -87,6 +128,24 @@ impl DataStore {
             None
         };
 
+        let target = if job.has_target() {
+            job.get_target()
+        } else {
+            jobsrv::DEFAULT_TARGET
+        };
+
+        let expires_at = if job.has_expires_at() {
+            Some(timestamp::parse(job.get_expires_at())?)
+        } else {
+            None
+        };
+
+        let build_timeout = if job.has_build_timeout() {
+            Some(job.get_build_timeout() as i32)
+        } else {
+            None
+        };
+
         if job.get_project().get_vcs_type() == "git" {
             let project = job.get_project();
             let install_id: Option<String> = {
@@ -98,7 +157,7 @@ impl DataStore {
             };
 
             let rows = conn.query(
-                "SELECT * FROM insert_job_v2($1, $2, $3, $4, $5, $6, $7, $8)",
+                "SELECT * FROM insert_job_v11($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)",
                 &[
                     &(job.get_owner_id() as i64),
                     &(project.get_id() as i64),
@@ -108,9 +167,19 @@ impl DataStore {
                     &project.get_vcs_type(),
                     &vec![Some(project.get_vcs_data().to_string()), install_id],
                     &channel,
+                    &job.get_priority().value(),
+                    &target,
+                    &(job.get_max_retries() as i32),
+                    &job.get_tags().to_vec(),
+                    &expires_at,
+                    &build_timeout,
+                    &job.get_worker_labels().to_vec(),
+                    &job.get_kind().value(),
+                    &job.get_verify_reproducible(),
                 ],
             ).map_err(Error::JobCreate)?;
             let job = row_to_job(&rows.get(0))?;
+            insert_job_audit(&conn, &job, audit)?;
             return Ok(job);
         } else {
             return Err(Error::UnknownVCS);
@@ -150,11 +219,12 @@ impl DataStore {
     ) -> Result<jobsrv::ProjectJobsGetResponse> {
         let conn = self.pool.get_shard(0)?;
         let rows = &conn.query(
-            "SELECT * FROM get_jobs_for_project_v2($1, $2, $3)",
+            "SELECT * FROM get_jobs_for_project_v7($1, $2, $3, $4)",
             &[
                 &(project.get_name()),
                 &project.limit(),
                 &(project.get_start() as i64),
+                &snapshot(project.get_created_before())?,
             ],
         ).map_err(Error::ProjectJobsGet)?;
 
@@ -172,7 +242,45 @@ impl DataStore {
         Ok(response)
     }
 
-    /// Get the next pending job from the list of pending jobs
+    /// Get a page of the jobs with the given tag, newest first
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the jobs cannot be selected from the database
+    /// * If a row returned cannot be translated into a Job
+    pub fn get_jobs_by_tag(
+        &self,
+        msg: &jobsrv::JobsByTagGet,
+    ) -> Result<jobsrv::JobsByTagGetResponse> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query(
+            "SELECT * FROM get_jobs_by_tag_v4($1, $2, $3, $4)",
+            &[
+                &msg.get_tag(),
+                &msg.limit(),
+                &(msg.get_start() as i64),
+                &snapshot(msg.get_created_before())?,
+            ],
+        ).map_err(Error::JobsByTagGet)?;
+
+        let mut jobs = protobuf::RepeatedField::new();
+        let mut response = jobsrv::JobsByTagGetResponse::new();
+        response.set_start(msg.get_start());
+        response.set_stop(self.last_index(msg, &rows));
+
+        for row in rows {
+            let count: i64 = row.get("total_count");
+            response.set_count(count as u64);
+            jobs.push(row_to_job(&row)?)
+        }
+        response.set_jobs(jobs);
+        Ok(response)
+    }
+
+    /// Get the next pending job for the given target from the list of pending jobs, highest
+    /// priority first, passing over the jobs of the excluded origins and the jobs which require
+    /// labels the worker doesn't have
     /// Atomically set the job state to Dispatching, and set the worker id
     ///
     /// # Errors
@@ -180,20 +288,62 @@ impl DataStore {
     /// * If a connection cannot be gotten from the pool
     /// * If the pending jobs cannot be selected from the database
     /// * If the row returned cannot be translated into a Job
-    pub fn next_pending_job(&self, worker: &str) -> Result<Option<jobsrv::Job>> {
+    pub fn next_pending_job(
+        &self,
+        worker: &str,
+        target: &str,
+        excluded_origins: &[String],
+        labels: &[String],
+    ) -> Result<Option<jobsrv::Job>> {
         let conn = self.pool.get_shard(0)?;
-        let rows = &conn.query("SELECT * FROM next_pending_job_v1($1)", &[&worker])
-            .map_err(Error::JobPending)?;
+        let rows = &conn.query(
+            "SELECT * FROM next_pending_job_v5($1, $2, $3, $4)",
+            &[&worker, &target, &excluded_origins, &labels],
+        ).map_err(Error::JobPending)?;
 
         if rows.len() != 0 {
             let row = rows.get(0);
             let job = row_to_job(&row)?;
+            insert_job_audit(
+                &conn,
+                &job,
+                &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+            )?;
             Ok(Some(job))
         } else {
             Ok(None)
         }
     }
 
+    /// Get the workers occupied and the builds started in the last day by the jobs of an origin,
+    /// or of every origin with running or recent jobs if none is given. The quotas of the
+    /// returned usage are left unset.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the usage cannot be selected from the database
+    pub fn get_origin_build_usage(
+        &self,
+        origin: Option<&str>,
+    ) -> Result<Vec<jobsrv::OriginBuildUsage>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM get_origin_build_usage_v1($1)", &[&origin])
+            .map_err(Error::OriginBuildUsage)?;
+
+        let mut usages = Vec::new();
+        for row in rows {
+            let mut usage = jobsrv::OriginBuildUsage::new();
+            let workers: i64 = row.get("workers");
+            let builds: i64 = row.get("builds");
+            usage.set_origin(row.get("origin"));
+            usage.set_workers(workers as u32);
+            usage.set_builds_last_day(builds as u32);
+            usages.push(usage);
+        }
+        Ok(usages)
+    }
+
     /// Get a list of cancel-pending jobs
     ///
     /// # Errors
@@ -213,17 +363,36 @@ impl DataStore {
         Ok(jobs)
     }
 
-    /// Get a list of Dispatched jobs
+    /// Get a list of Dispatched and Processing jobs
     ///
     /// # Errors
     ///
     /// * If a connection cannot be gotten from the pool
-    /// * If the cancel pending jobs cannot be selected from the database
+    /// * If the running jobs cannot be selected from the database
     /// * If the row returned cannot be translated into a Job
-    pub fn get_dispatched_jobs(&self) -> Result<Vec<jobsrv::Job>> {
+    pub fn get_running_jobs(&self) -> Result<Vec<jobsrv::Job>> {
         let mut jobs = Vec::new();
         let conn = self.pool.get_shard(0)?;
-        let rows = &conn.query("SELECT * FROM get_dispatched_jobs_v1()", &[])
+        let rows = &conn.query("SELECT * FROM get_running_jobs_v1()", &[])
+            .map_err(Error::JobGet)?;
+        for row in rows {
+            let job = row_to_job(&row)?;
+            jobs.push(job);
+        }
+        Ok(jobs)
+    }
+
+    /// Get a list of Pending and Dispatched jobs which have passed their expiry time
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the expired jobs cannot be selected from the database
+    /// * If the row returned cannot be translated into a Job
+    pub fn get_expired_jobs(&self) -> Result<Vec<jobsrv::Job>> {
+        let mut jobs = Vec::new();
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM get_expired_jobs_v1()", &[])
             .map_err(Error::JobGet)?;
         for row in rows {
             let job = row_to_job(&row)?;
@@ -236,11 +405,13 @@ impl DataStore {
     /// build start and stop times, and recording the identifier of
     /// the package the job produced, if any.
     ///
+    /// The new state is recorded in the job's audit log along with what caused it.
+    ///
     /// # Errors
     ///
     /// * If a connection cannot be gotten from the pool
     /// * If the job cannot be updated in the database
-    pub fn update_job(&self, job: &jobsrv::Job) -> Result<()> {
+    pub fn update_job(&self, job: &jobsrv::Job, audit: &Audit) -> Result<()> {
         let conn = self.pool.get_shard(0)?;
         let job_id = job.get_id() as i64;
         let job_state = job.get_state().to_string();
@@ -250,17 +421,13 @@ impl DataStore {
         // the database will also be updated to be NULL. This should
         // be OK, though, because they shouldn't be changing anyway.
         let build_started_at = if job.has_build_started_at() {
-            Some(job.get_build_started_at().parse::<DateTime<UTC>>().unwrap())
+            Some(timestamp::parse(job.get_build_started_at())?)
         } else {
             None
         };
 
         let build_finished_at = if job.has_build_finished_at() {
-            Some(
-                job.get_build_finished_at()
-                    .parse::<DateTime<UTC>>()
-                    .unwrap(),
-            )
+            Some(timestamp::parse(job.get_build_finished_at())?)
         } else {
             None
         };
@@ -274,14 +441,41 @@ impl DataStore {
         let (err_code, err_msg) = if job.has_error() {
             (
                 Some(job.get_error().get_code() as i32),
-                Some(job.get_error().get_msg()),
+                Some(job.get_error().get_message()),
+            )
+        } else {
+            (None, None)
+        };
+
+        let last_error_category = if job.has_last_error_category() {
+            Some(job.get_last_error_category().to_string())
+        } else {
+            None
+        };
+
+        let (artifact_checksum, artifact_size) = if job.has_artifact() {
+            (
+                Some(job.get_artifact().get_checksum()),
+                Some(job.get_artifact().get_size() as i64),
             )
         } else {
             (None, None)
         };
 
+        let image_digest = if job.has_image_digest() {
+            Some(job.get_image_digest())
+        } else {
+            None
+        };
+
+        let reproducible = if job.has_reproducible() {
+            Some(job.get_reproducible())
+        } else {
+            None
+        };
+
         conn.execute(
-            "SELECT update_job_v3($1, $2, $3, $4, $5, $6, $7)",
+            "SELECT update_job_v7($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)",
             &[
                 &job_id,
                 &job_state,
@@ -290,8 +484,15 @@ impl DataStore {
                 &ident,
                 &err_code,
                 &err_msg,
+                &(job.get_retries() as i32),
+                &last_error_category,
+                &artifact_checksum,
+                &artifact_size,
+                &image_digest,
+                &reproducible,
             ],
         ).map_err(Error::JobSetState)?;
+        insert_job_audit(&conn, job, audit)?;
 
         Ok(())
     }
@@ -306,6 +507,30 @@ impl DataStore {
         Ok(())
     }
 
+    /// Deletes the finished job groups and jobs created before the given time, along with their
+    /// audit records. Returns the number of groups deleted and the ids of the jobs deleted.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the groups or jobs cannot be deleted from the database
+    pub fn prune_job_history(&self, before: &DateTime<UTC>) -> Result<(u64, Vec<u64>)> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT prune_job_groups_v1($1)", &[before])
+            .map_err(Error::JobHistoryPrune)?;
+        let groups: i64 = rows.get(0).get(0);
+
+        let rows = &conn.query("SELECT * FROM prune_jobs_v1($1)", &[before])
+            .map_err(Error::JobHistoryPrune)?;
+        let mut job_ids = Vec::new();
+        for row in rows {
+            let id: i64 = row.get(0);
+            job_ids.push(id as u64);
+        }
+
+        Ok((groups as u64, job_ids))
+    }
+
     fn last_index<P: Pageable>(&self, list_request: &P, rows: &Rows) -> u64 {
         if rows.len() == 0 {
             list_request.get_range()[1]
@@ -379,7 +604,7 @@ impl DataStore {
         let conn = self.pool.get_shard(0)?;
 
         let rows = conn.query(
-            "SELECT * FROM upsert_graph_package_v1($1, $2, $3)",
+            "SELECT * FROM upsert_graph_package_v2($1, $2, $3)",
             &[&msg.get_ident(), &msg.get_deps(), &msg.get_target()],
         ).map_err(Error::JobGraphPackageInsert)?;
 
@@ -408,6 +633,39 @@ impl DataStore {
         Ok(packages)
     }
 
+    /// Retrieve the packages persisted after the given revision, in revision order.
+    pub fn get_job_graph_packages_since(
+        &self,
+        revision: u64,
+    ) -> Result<RepeatedField<jobsrv::JobGraphPackage>> {
+        let mut packages = RepeatedField::new();
+
+        let conn = self.pool.get_shard(0)?;
+
+        let rows = &conn.query(
+            "SELECT * FROM get_graph_packages_since_v1($1)",
+            &[&(revision as i64)],
+        ).map_err(Error::JobGraphPackagesGet)?;
+
+        for row in rows {
+            let package = self.row_to_job_graph_package(&row)?;
+            packages.push(package);
+        }
+
+        Ok(packages)
+    }
+
+    /// Retrieve the revision of the most recently persisted package.
+    pub fn get_job_graph_packages_revision(&self) -> Result<u64> {
+        let conn = self.pool.get_shard(0)?;
+
+        let rows = &conn.query("SELECT get_graph_packages_revision_v1()", &[])
+            .map_err(Error::JobGraphPackagesGet)?;
+
+        let revision: i64 = rows.get(0).get(0);
+        Ok(revision as u64)
+    }
+
     pub fn get_job_graph_package(&self, ident: &str) -> Result<jobsrv::JobGraphPackage> {
         let conn = self.pool.get_shard(0)?;
 
@@ -454,6 +712,27 @@ impl DataStore {
         package_stats.set_builds(build_count as u64);
         package_stats.set_unique_packages(up_count as u64);
 
+        let rows = &conn.query(
+            "SELECT * FROM get_graph_package_target_stats_v1($1)",
+            &[&origin],
+        ).map_err(Error::JobGraphPackageStats)?;
+
+        let mut targets = RepeatedField::new();
+        for row in rows {
+            let target: String = row.get("target");
+            let plans: i64 = row.get("plans");
+            let builds: i64 = row.get("builds");
+            let unique_packages: i64 = row.get("unique_packages");
+
+            let mut target_stats = jobsrv::JobGraphPackageTargetStats::new();
+            target_stats.set_target(target);
+            target_stats.set_plans(plans as u64);
+            target_stats.set_builds(builds as u64);
+            target_stats.set_unique_packages(unique_packages as u64);
+            targets.push(target_stats);
+        }
+        package_stats.set_targets(targets);
+
         Ok(package_stats)
     }
 
@@ -500,7 +779,7 @@ impl DataStore {
 
         let conn = self.pool.get_shard(0)?;
 
-        let rows = &conn.query("SELECT * FROM get_queued_groups_v1()", &[])
+        let rows = &conn.query("SELECT * FROM get_queued_groups_v2()", &[])
             .map_err(Error::JobGroupGet)?;
 
         for row in rows {
@@ -511,6 +790,76 @@ impl DataStore {
         Ok(groups)
     }
 
+    /// Count the groups which are Pending or Dispatching, keyed by origin.
+    pub fn count_active_job_groups_by_origin(&self) -> Result<HashMap<String, u64>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM count_active_groups_by_origin_v1()", &[])
+            .map_err(Error::JobGroupGet)?;
+
+        let mut active = HashMap::new();
+        for row in rows {
+            let origin: String = row.get("origin");
+            let count: i64 = row.get("active");
+            active.insert(origin, count as u64);
+        }
+        Ok(active)
+    }
+
+    /// Count the jobs in each state.
+    pub fn count_jobs_by_state(&self) -> Result<HashMap<String, u64>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM count_jobs_by_state_v1()", &[])
+            .map_err(Error::JobGet)?;
+
+        let mut counts = HashMap::new();
+        for row in rows {
+            let state: String = row.get("job_state");
+            let count: i64 = row.get("jobs");
+            counts.insert(state, count as u64);
+        }
+        Ok(counts)
+    }
+
+    /// Count the jobs waiting to be dispatched, keyed by target.
+    pub fn count_pending_jobs_by_target(&self) -> Result<HashMap<String, u64>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM count_pending_jobs_by_target_v1()", &[])
+            .map_err(Error::JobPending)?;
+
+        let mut counts = HashMap::new();
+        for row in rows {
+            let target: String = row.get("target");
+            let count: i64 = row.get("jobs");
+            counts.insert(target, count as u64);
+        }
+        Ok(counts)
+    }
+
+    /// Count the groups which are Queued, Pending or Dispatching, keyed by state.
+    pub fn count_unfinished_job_groups_by_state(&self) -> Result<HashMap<String, u64>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM count_unfinished_groups_by_state_v1()", &[])
+            .map_err(Error::JobGroupGet)?;
+
+        let mut counts = HashMap::new();
+        for row in rows {
+            let state: String = row.get("group_state");
+            let count: i64 = row.get("groups");
+            counts.insert(state, count as u64);
+        }
+        Ok(counts)
+    }
+
+    pub fn set_job_group_queue_position(&self, group_id: u64, position: Option<u32>) -> Result<()> {
+        let conn = self.pool.get_shard(0)?;
+        let position = position.map(|p| p as i32);
+        conn.execute(
+            "SELECT set_group_queue_position_v1($1, $2)",
+            &[&(group_id as i64), &position],
+        ).map_err(Error::JobGroupSetState)?;
+        Ok(())
+    }
+
     pub fn create_job_group(
         &self,
         msg: &jobsrv::JobGroupSpec,
@@ -521,16 +870,34 @@ impl DataStore {
         assert!(!project_tuples.is_empty());
 
         let root_project = format!("{}/{}", msg.get_origin(), msg.get_package());
+        let target = if msg.has_target() {
+            msg.get_target()
+        } else {
+            jobsrv::DEFAULT_TARGET
+        };
 
         let (project_names, project_idents): (Vec<String>, Vec<String>) =
             project_tuples.iter().cloned().unzip();
 
         let rows = conn.query(
-            "SELECT * FROM insert_group_v2($1, $2, $3)",
-            &[&root_project, &project_names, &project_idents],
+            "SELECT * FROM insert_group_v5($1, $2, $3, $4, $5, $6)",
+            &[
+                &root_project,
+                &project_names,
+                &project_idents,
+                &msg.get_priority().value(),
+                &target,
+                &msg.get_tags().to_vec(),
+            ],
         ).map_err(Error::JobGroupCreate)?;
 
         let mut group = self.row_to_job_group(&rows.get(0))?;
+        let audit = if msg.has_requester_id() {
+            Audit::user(msg.get_requester_id(), msg.get_requester_name())
+        } else {
+            Audit::new(jobsrv::JobAuditTrigger::Scheduler)
+        };
+        insert_group_audit(&conn, group.get_id(), group.get_state(), &audit)?;
         let mut projects = RepeatedField::new();
 
         for (name, ident) in project_tuples {
@@ -548,10 +915,134 @@ impl DataStore {
         Ok(group)
     }
 
-    pub fn cancel_job_group(&self, group_id: u64) -> Result<()> {
+    pub fn cancel_job_group(&self, group_id: u64, audit: &Audit) -> Result<()> {
         let conn = self.pool.get_shard(0)?;
         conn.query("SELECT cancel_group_v1($1)", &[&(group_id as i64)])
             .map_err(Error::JobGroupCancel)?;
+        insert_group_audit(
+            &conn,
+            group_id,
+            jobsrv::JobGroupState::GroupCanceled,
+            audit,
+        )?;
+
+        Ok(())
+    }
+
+    /// Queue a finished job group to build its failed projects again, along with the projects
+    /// which were skipped because of them. Returns the number of projects to be built again.
+    pub fn retry_job_group(&self, group_id: u64, audit: &Audit) -> Result<u64> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = conn.query("SELECT retry_group_v2($1)", &[&(group_id as i64)])
+            .map_err(Error::JobGroupRetry)?;
+        let retried: i64 = rows.get(0).get(0);
+        insert_group_audit(
+            &conn,
+            group_id,
+            jobsrv::JobGroupState::GroupQueued,
+            audit,
+        )?;
+
+        Ok(retried as u64)
+    }
+
+    /// Create a job group schedule, replacing any existing schedule for the same package and
+    /// target.
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the schedule cannot be written to the database
+    pub fn upsert_job_group_schedule(
+        &self,
+        msg: &jobsrv::JobGroupScheduleCreate,
+        next_run_at: &DateTime<UTC>,
+    ) -> Result<jobsrv::JobGroupSchedule> {
+        let conn = self.pool.get_shard(0)?;
+        let spec = msg.get_spec();
+        let target = if spec.has_target() {
+            spec.get_target()
+        } else {
+            jobsrv::DEFAULT_TARGET
+        };
+
+        let rows = conn.query(
+            "SELECT * FROM upsert_group_schedule_v1($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+            &[
+                &spec.get_origin(),
+                &spec.get_package(),
+                &target,
+                &spec.get_priority().value(),
+                &spec.get_tags().to_vec(),
+                &spec.get_packages().to_vec(),
+                &spec.get_deps_only(),
+                &spec.get_origin_only(),
+                &spec.get_package_only(),
+                &msg.get_cron(),
+                next_run_at,
+            ],
+        ).map_err(Error::JobGroupScheduleUpsert)?;
+
+        self.row_to_job_group_schedule(&rows.get(0))
+    }
+
+    pub fn get_job_group_schedules(
+        &self,
+        msg: &jobsrv::JobGroupScheduleListGet,
+    ) -> Result<jobsrv::JobGroupScheduleListResponse> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query(
+            "SELECT * FROM get_group_schedules_for_origin_v1($1)",
+            &[&msg.get_origin()],
+        ).map_err(Error::JobGroupSchedulesGet)?;
+
+        let mut schedules = RepeatedField::new();
+        for row in rows {
+            schedules.push(self.row_to_job_group_schedule(&row)?);
+        }
+
+        let mut response = jobsrv::JobGroupScheduleListResponse::new();
+        response.set_schedules(schedules);
+        Ok(response)
+    }
+
+    /// Delete a job group schedule. Returns `false` if the origin has no such schedule.
+    pub fn delete_job_group_schedule(&self, msg: &jobsrv::JobGroupScheduleDelete) -> Result<bool> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query(
+            "SELECT * FROM delete_group_schedule_v1($1, $2)",
+            &[&(msg.get_id() as i64), &msg.get_origin()],
+        ).map_err(Error::JobGroupScheduleDelete)?;
+
+        Ok(!rows.is_empty())
+    }
+
+    /// Get the job group schedules which are due to run
+    pub fn get_due_job_group_schedules(&self) -> Result<Vec<jobsrv::JobGroupSchedule>> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = &conn.query("SELECT * FROM get_due_group_schedules_v1()", &[])
+            .map_err(Error::JobGroupSchedulesGet)?;
+
+        let mut schedules = Vec::new();
+        for row in rows {
+            schedules.push(self.row_to_job_group_schedule(&row)?);
+        }
+        Ok(schedules)
+    }
+
+    /// Record that a schedule ran, and when it should next run. A schedule without a next run
+    /// time never runs again.
+    pub fn set_job_group_schedule_run(
+        &self,
+        schedule_id: u64,
+        last_run_at: &DateTime<UTC>,
+        next_run_at: Option<DateTime<UTC>>,
+    ) -> Result<()> {
+        let conn = self.pool.get_shard(0)?;
+        conn.execute(
+            "SELECT set_group_schedule_run_v1($1, $2, $3)",
+            &[&(schedule_id as i64), last_run_at, &next_run_at],
+        ).map_err(Error::JobGroupScheduleSetRun)?;
 
         Ok(())
     }
@@ -561,14 +1052,38 @@ impl DataStore {
         msg: &jobsrv::JobGroupOriginGet,
     ) -> Result<jobsrv::JobGroupOriginResponse> {
         let origin = msg.get_origin();
+        let state = if msg.has_state() {
+            Some(msg.get_state().to_string())
+        } else {
+            None
+        };
+        let created_since = if msg.has_created_since() {
+            Some(timestamp::parse(msg.get_created_since())?)
+        } else {
+            None
+        };
+
         let conn = self.pool.get_shard(0)?;
-        let rows = &conn.query("SELECT * FROM get_job_groups_for_origin_v1($1)", &[&origin])
-            .map_err(Error::JobGroupOriginGet)?;
+        let rows = &conn.query(
+            "SELECT * FROM get_job_groups_for_origin_v5($1, $2, $3, $4, $5, $6)",
+            &[
+                &origin,
+                &state,
+                &created_since,
+                &snapshot(msg.get_created_before())?,
+                &msg.limit(),
+                &(msg.get_start() as i64),
+            ],
+        ).map_err(Error::JobGroupOriginGet)?;
 
         let mut response = jobsrv::JobGroupOriginResponse::new();
         let mut job_groups = RepeatedField::new();
+        response.set_start(msg.get_start());
+        response.set_stop(self.last_index(msg, &rows));
 
         for row in rows {
+            let count: i64 = row.get("total_count");
+            response.set_count(count as u64);
             let mut group = self.row_to_job_group(&row)?;
             let project_rows = &conn.query(
                 "SELECT * FROM get_group_projects_for_group_v1($1)",
@@ -611,11 +1126,17 @@ impl DataStore {
     }
 
     // TODO (SA): This is an experimental dev-only function for now
-    pub fn abort_job_group(&self, msg: &jobsrv::JobGroupAbort) -> Result<()> {
+    pub fn abort_job_group(&self, msg: &jobsrv::JobGroupAbort, audit: &Audit) -> Result<()> {
         let group_id = msg.get_group_id();
         let conn = self.pool.get_shard(0)?;
         conn.query("SELECT abort_group_v1($1)", &[&(group_id as i64)])
             .map_err(Error::JobGroupGet)?;
+        insert_group_audit(
+            &conn,
+            group_id,
+            jobsrv::JobGroupState::GroupComplete,
+            audit,
+        )?;
 
         Ok(())
     }
@@ -633,6 +1154,10 @@ impl DataStore {
             package.set_target(target);
         }
 
+        if let Some(Ok(revision)) = row.get_opt::<&str, i64>("revision") {
+            package.set_revision(revision as u64);
+        }
+
         let deps: Vec<String> = row.get("deps");
 
         let mut pb_deps = RepeatedField::new();
@@ -661,9 +1186,68 @@ impl DataStore {
         let project_name: String = row.get("project_name");
         group.set_project_name(project_name);
 
+        let priority: i32 = row.get("priority");
+        if let Some(priority) = jobsrv::JobPriority::from_i32(priority) {
+            group.set_priority(priority);
+        }
+
+        let target: String = row.get("target");
+        group.set_target(target);
+
+        let tags: Vec<String> = row.get("tags");
+        group.set_tags(RepeatedField::from_vec(tags));
+
+        if group_state == jobsrv::JobGroupState::GroupQueued {
+            if let Some(Ok(position)) = row.get_opt::<&str, i32>("queue_position") {
+                group.set_queue_position(position as u32);
+            }
+        }
+
         Ok(group)
     }
 
+    fn row_to_job_group_schedule(
+        &self,
+        row: &postgres::rows::Row,
+    ) -> Result<jobsrv::JobGroupSchedule> {
+        let mut schedule = jobsrv::JobGroupSchedule::new();
+
+        let id: i64 = row.get("id");
+        schedule.set_id(id as u64);
+
+        {
+            let spec = schedule.mut_spec();
+            spec.set_origin(row.get("origin"));
+            spec.set_package(row.get("package"));
+            spec.set_target(row.get("target"));
+            let priority: i32 = row.get("priority");
+            if let Some(priority) = jobsrv::JobPriority::from_i32(priority) {
+                spec.set_priority(priority);
+            }
+            let tags: Vec<String> = row.get("tags");
+            spec.set_tags(RepeatedField::from_vec(tags));
+            let packages: Vec<String> = row.get("packages");
+            spec.set_packages(RepeatedField::from_vec(packages));
+            spec.set_deps_only(row.get("deps_only"));
+            spec.set_origin_only(row.get("origin_only"));
+            spec.set_package_only(row.get("package_only"));
+        }
+
+        schedule.set_cron(row.get("cron"));
+
+        if let Some(Ok(last_run_at)) = row.get_opt::<&str, DateTime<UTC>>("last_run_at") {
+            schedule.set_last_run_at(last_run_at.to_rfc3339());
+        }
+        if let Some(Ok(next_run_at)) = row.get_opt::<&str, DateTime<UTC>>("next_run_at") {
+            schedule.set_next_run_at(next_run_at.to_rfc3339());
+        }
+
+        let created_at = row.get::<&str, DateTime<UTC>>("created_at");
+        schedule.set_created_at(created_at.to_rfc3339());
+
+        Ok(schedule)
+    }
+
     fn row_to_job_group_project(
         &self,
         row: &postgres::rows::Row,
@@ -681,6 +1265,16 @@ impl DataStore {
         project.set_state(project_state);
         project.set_job_id(job_id as u64);
 
+        if let Some(Ok(start)) = row.get_opt::<&str, DateTime<UTC>>("build_started_at") {
+            project.set_build_started_at(start.to_rfc3339());
+        }
+        if let Some(Ok(stop)) = row.get_opt::<&str, DateTime<UTC>>("build_finished_at") {
+            project.set_build_finished_at(stop.to_rfc3339());
+        }
+        if let Some(Ok(reason)) = row.get_opt::<&str, String>("skip_reason") {
+            project.set_skip_reason(reason);
+        }
+
         Ok(project)
     }
 
@@ -702,6 +1296,7 @@ impl DataStore {
         &self,
         group_id: u64,
         group_state: jobsrv::JobGroupState,
+        audit: &Audit,
     ) -> Result<()> {
         let conn = self.pool.get_shard(0)?;
         let state = group_state.to_string();
@@ -709,6 +1304,7 @@ impl DataStore {
             "SELECT set_group_state_v1($1, $2)",
             &[&(group_id as i64), &state],
         ).map_err(Error::JobGroupSetState)?;
+        insert_group_audit(&conn, group_id, group_state, audit)?;
         Ok(())
     }
 
@@ -727,6 +1323,21 @@ impl DataStore {
         Ok(())
     }
 
+    /// Skip a project of a group which can't be built, recording the reason
+    pub fn skip_job_group_project(
+        &self,
+        group_id: u64,
+        project_name: &str,
+        reason: &str,
+    ) -> Result<()> {
+        let conn = self.pool.get_shard(0)?;
+        conn.execute(
+            "SELECT skip_group_project_v1($1, $2, $3)",
+            &[&(group_id as i64), &project_name, &reason],
+        ).map_err(Error::JobGroupProjectSetState)?;
+        Ok(())
+    }
+
     pub fn set_job_group_job_state(&self, job: &jobsrv::Job) -> Result<()> {
         let conn = self.pool.get_shard(0)?;
         let rows = &conn.query(
@@ -755,17 +1366,41 @@ impl DataStore {
             jobsrv::JobState::CancelComplete => "Canceled",
         };
 
+        let build_started_at = if job.has_build_started_at() {
+            Some(timestamp::parse(job.get_build_started_at())?)
+        } else {
+            None
+        };
+        let build_finished_at = if job.has_build_finished_at() {
+            Some(timestamp::parse(job.get_build_finished_at())?)
+        } else {
+            None
+        };
+
         if job.get_state() == jobsrv::JobState::Complete {
             let ident = job.get_package_ident().to_string();
 
             conn.execute(
-                "SELECT set_group_project_state_ident_v1($1, $2, $3, $4)",
-                &[&pid, &(job.get_id() as i64), &state, &ident],
+                "SELECT set_group_project_state_ident_v2($1, $2, $3, $4, $5, $6)",
+                &[
+                    &pid,
+                    &(job.get_id() as i64),
+                    &state,
+                    &ident,
+                    &build_started_at,
+                    &build_finished_at,
+                ],
             ).map_err(Error::JobGroupProjectSetState)?;
         } else {
             conn.execute(
-                "SELECT set_group_project_state_v1($1, $2, $3)",
-                &[&pid, &(job.get_id() as i64), &state],
+                "SELECT set_group_project_state_v2($1, $2, $3, $4, $5)",
+                &[
+                    &pid,
+                    &(job.get_id() as i64),
+                    &state,
+                    &build_started_at,
+                    &build_finished_at,
+                ],
             ).map_err(Error::JobGroupProjectSetState)?;
         };
 
@@ -776,11 +1411,17 @@ impl DataStore {
         let mut groups = Vec::new();
 
         let conn = self.pool.get_shard(0)?;
-        let group_rows = &conn.query("SELECT * FROM pending_groups_v1($1)", &[&count])
+        let group_rows = &conn.query("SELECT * FROM pending_groups_v2($1)", &[&count])
             .map_err(Error::JobGroupPending)?;
 
         for group_row in group_rows {
             let mut group = self.row_to_job_group(&group_row)?;
+            insert_group_audit(
+                &conn,
+                group.get_id(),
+                group.get_state(),
+                &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+            )?;
 
             let project_rows = &conn.query(
                 "SELECT * FROM get_group_projects_for_group_v1($1)",
@@ -795,6 +1436,36 @@ impl DataStore {
         Ok(groups)
     }
 
+    /// Get the audit log of a job, or of a job group and every job it ran, oldest first
+    ///
+    /// # Errors
+    ///
+    /// * If a connection cannot be gotten from the pool
+    /// * If the audit log cannot be selected from the database
+    /// * If a row returned cannot be translated into a JobAudit
+    pub fn get_job_audit(&self, msg: &jobsrv::JobAuditGet) -> Result<jobsrv::JobAuditResponse> {
+        let conn = self.pool.get_shard(0)?;
+        let rows = if msg.has_group_id() {
+            conn.query(
+                "SELECT * FROM get_group_audit_v1($1)",
+                &[&(msg.get_group_id() as i64)],
+            )
+        } else {
+            conn.query(
+                "SELECT * FROM get_job_audit_v1($1)",
+                &[&(msg.get_job_id() as i64)],
+            )
+        }.map_err(Error::JobAuditGet)?;
+
+        let mut audits = RepeatedField::new();
+        for row in rows.iter() {
+            audits.push(row_to_job_audit(&row)?);
+        }
+        let mut response = jobsrv::JobAuditResponse::new();
+        response.set_audits(audits);
+        Ok(response)
+    }
+
     pub fn sync_jobs(&self) -> Result<Vec<jobsrv::Job>> {
         let mut jobs = Vec::new();
         let conn = self.pool.get_shard(0)?;
@@ -825,6 +1496,100 @@ impl DataStore {
     }
 }
 
+/// Record a job's current state in its audit log
+/// The time a listing is limited to, if its request was given one
+fn snapshot(created_before: &str) -> Result<Option<DateTime<UTC>>> {
+    if created_before.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(timestamp::parse(created_before)?))
+    }
+}
+
+fn insert_job_audit(conn: &postgres::Connection, job: &jobsrv::Job, audit: &Audit) -> Result<()> {
+    let worker = if job.has_worker() {
+        Some(job.get_worker())
+    } else {
+        None
+    };
+    let (requester_id, requester_name) = audit.requester();
+    conn.execute(
+        "SELECT insert_job_audit_v1($1, $2, $3, $4, $5, $6)",
+        &[
+            &(job.get_id() as i64),
+            &job.get_state().to_string(),
+            &audit.trigger.to_string(),
+            &requester_id,
+            &requester_name,
+            &worker,
+        ],
+    ).map_err(Error::JobAuditInsert)?;
+    Ok(())
+}
+
+/// Record a job group's current state in its audit log
+fn insert_group_audit(
+    conn: &postgres::Connection,
+    group_id: u64,
+    group_state: jobsrv::JobGroupState,
+    audit: &Audit,
+) -> Result<()> {
+    let (requester_id, requester_name) = audit.requester();
+    conn.execute(
+        "SELECT insert_group_audit_v1($1, $2, $3, $4, $5)",
+        &[
+            &(group_id as i64),
+            &group_state.to_string(),
+            &audit.trigger.to_string(),
+            &requester_id,
+            &requester_name,
+        ],
+    ).map_err(Error::JobAuditInsert)?;
+    Ok(())
+}
+
+/// Translate a database `job_audit` row to a `jobsrv::JobAudit`.
+///
+/// # Errors
+///
+/// * If the trigger is unknown
+fn row_to_job_audit(row: &postgres::rows::Row) -> Result<jobsrv::JobAudit> {
+    let mut audit = jobsrv::JobAudit::new();
+    let id: i64 = row.get("id");
+    audit.set_id(id as u64);
+    if let Some(Ok(job_id)) = row.get_opt::<&str, i64>("job_id") {
+        audit.set_job_id(job_id as u64);
+    }
+    if let Some(Ok(group_id)) = row.get_opt::<&str, i64>("group_id") {
+        audit.set_group_id(group_id as u64);
+    }
+    if let Some(Ok(from_state)) = row.get_opt::<&str, String>("from_state") {
+        audit.set_from_state(from_state);
+    }
+    let to_state: String = row.get("to_state");
+    audit.set_to_state(to_state);
+
+    let trigger: String = row.get("trigger");
+    audit.set_trigger(trigger.parse().map_err(
+        Error::UnknownJobAuditTrigger,
+    )?);
+
+    if let Some(Ok(requester_id)) = row.get_opt::<&str, i64>("requester_id") {
+        audit.set_requester_id(requester_id as u64);
+    }
+    if let Some(Ok(requester_name)) = row.get_opt::<&str, String>("requester_name") {
+        audit.set_requester_name(requester_name);
+    }
+    if let Some(Ok(worker)) = row.get_opt::<&str, String>("worker") {
+        audit.set_worker(worker);
+    }
+
+    let created_at = row.get::<&str, DateTime<UTC>>("created_at");
+    audit.set_created_at(created_at.to_rfc3339());
+
+    Ok(audit)
+}
+
 /// Translate a database `busy_workers` row to a `jobsrv::BusyWorker`.
 ///
 fn row_to_busy_worker(row: &postgres::rows::Row) -> Result<jobsrv::BusyWorker> {
@@ -919,11 +1684,12 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
 
     if let Some(Ok(err_msg)) = row.get_opt::<&str, String>("net_error_msg") {
         let err_code: i32 = row.get("net_error_code");
-        let mut err = NetError::new();
+        let mut err = jobsrv::JobError::new();
 
         if let Some(net_err_code) = ErrCode::from_i32(err_code) {
             err.set_code(net_err_code);
-            err.set_msg(err_msg);
+            err.set_message(err_msg);
+            err.set_category(jobsrv::JobErrorCategory::from(net_err_code));
             job.set_error(err);
         }
     }
@@ -938,5 +1704,65 @@ fn row_to_job(row: &postgres::rows::Row) -> Result<jobsrv::Job> {
         job.set_worker(worker);
     };
 
+    let priority: i32 = row.get("priority");
+    if let Some(priority) = jobsrv::JobPriority::from_i32(priority) {
+        job.set_priority(priority);
+    }
+
+    let target: String = row.get("target");
+    job.set_target(target);
+
+    let retries: i32 = row.get("retries");
+    job.set_retries(retries as u32);
+    let max_retries: i32 = row.get("max_retries");
+    job.set_max_retries(max_retries as u32);
+
+    let tags: Vec<String> = row.get("tags");
+    job.set_tags(RepeatedField::from_vec(tags));
+
+    if let Some(Ok(checksum)) = row.get_opt::<&str, String>("artifact_checksum") {
+        let size: i64 = row.get("artifact_size");
+        let mut artifact = jobsrv::JobArtifact::new();
+        artifact.set_checksum(checksum);
+        artifact.set_size(size as u64);
+        job.set_artifact(artifact);
+    }
+
+    if let Some(Ok(category)) = row.get_opt::<&str, String>("last_error_category") {
+        if let Ok(category) = category.parse::<jobsrv::JobErrorCategory>() {
+            job.set_last_error_category(category);
+        }
+    };
+
+    if let Some(Ok(expires_at)) = row.get_opt::<&str, DateTime<UTC>>("expires_at") {
+        job.set_expires_at(expires_at.to_rfc3339());
+    }
+
+    if let Some(Ok(build_timeout)) = row.get_opt::<&str, i32>("build_timeout") {
+        job.set_build_timeout(build_timeout as u32);
+    }
+
+    if let Some(Ok(worker_labels)) = row.get_opt::<&str, Vec<String>>("worker_labels") {
+        job.set_worker_labels(RepeatedField::from_vec(worker_labels));
+    }
+
+    if let Some(Ok(kind)) = row.get_opt::<&str, i32>("kind") {
+        if let Some(kind) = jobsrv::JobKind::from_i32(kind) {
+            job.set_kind(kind);
+        }
+    }
+
+    if let Some(Ok(image_digest)) = row.get_opt::<&str, String>("image_digest") {
+        job.set_image_digest(image_digest);
+    }
+
+    if let Some(Ok(verify_reproducible)) = row.get_opt::<&str, bool>("verify_reproducible") {
+        job.set_verify_reproducible(verify_reproducible);
+    }
+
+    if let Some(Ok(reproducible)) = row.get_opt::<&str, bool>("reproducible") {
+        job.set_reproducible(reproducible);
+    }
+
     Ok(job)
 }
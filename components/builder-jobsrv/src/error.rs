@@ -13,19 +13,23 @@
 // limitations under the License.
 
 use aws_sdk_rust;
+use bldr_core;
 use db;
 use extern_url;
 use hab_core;
 use hab_net;
+use hyper;
 use protocol;
 use postgres;
 use protobuf;
 use r2d2;
+use serde_json;
 use std::error;
 use std::fmt;
 use std::io;
 use std::num;
 use std::path::PathBuf;
+use std::process::ExitStatus;
 use std::result;
 use zmq;
 
@@ -55,20 +59,36 @@ pub enum Error {
     JobGraphPackageStats(postgres::error::Error),
     JobGraphPackagesGet(postgres::error::Error),
     JobGroupProjectSetState(postgres::error::Error),
+    JobGroupRetry(postgres::error::Error),
+    JobGroupScheduleDelete(postgres::error::Error),
+    JobGroupScheduleSetRun(postgres::error::Error),
+    JobGroupScheduleUpsert(postgres::error::Error),
+    JobGroupSchedulesGet(postgres::error::Error),
+    JobAuditGet(postgres::error::Error),
+    JobAuditInsert(postgres::error::Error),
     JobCreate(postgres::error::Error),
     JobGet(postgres::error::Error),
+    JobHistoryPrune(postgres::error::Error),
     JobLogArchive(u64, aws_sdk_rust::aws::errors::s3::S3Error),
+    JobLogDelete(u64, aws_sdk_rust::aws::errors::s3::S3Error),
     JobLogRetrieval(u64, aws_sdk_rust::aws::errors::s3::S3Error),
     JobMarkArchived(postgres::error::Error),
     JobPending(postgres::error::Error),
     JobReset(postgres::error::Error),
+    JobsByTagGet(postgres::error::Error),
     JobSetLogUrl(postgres::error::Error),
     JobSetState(postgres::error::Error),
     SyncJobs(postgres::error::Error),
     LogDirDoesNotExist(PathBuf, io::Error),
     LogDirIsNotDir(PathBuf),
     LogDirNotWritable(PathBuf),
+    MetricsServer(hyper::Error),
     NetError(hab_net::NetError),
+    NotificationPayload(serde_json::Error),
+    NotificationSecret(bldr_core::Error),
+    NotificationSendmail(io::Error),
+    NotificationSendmailStatus(ExitStatus),
+    OriginBuildUsage(postgres::error::Error),
     ParseVCSInstallationId(num::ParseIntError),
     ProjectJobsGet(postgres::error::Error),
     Protobuf(protobuf::ProtobufError),
@@ -78,6 +98,7 @@ pub enum Error {
     UnknownJobGroupState,
     UnknownJobGraphPackage,
     UnknownJobGroupProjectState,
+    UnknownJobAuditTrigger(protocol::ProtocolError),
     UnknownJobState(protocol::ProtocolError),
     Zmq(zmq::Error),
 }
@@ -136,11 +157,36 @@ impl fmt::Display for Error {
             Error::JobGroupProjectSetState(ref e) => {
                 format!("Database error setting project state, {}", e)
             }
+            Error::JobGroupRetry(ref e) => {
+                format!("Database error retrying a job group, {}", e)
+            }
+            Error::JobGroupScheduleDelete(ref e) => {
+                format!("Database error deleting a group schedule, {}", e)
+            }
+            Error::JobGroupScheduleSetRun(ref e) => {
+                format!("Database error recording a group schedule run, {}", e)
+            }
+            Error::JobGroupScheduleUpsert(ref e) => {
+                format!("Database error creating or updating a group schedule, {}", e)
+            }
+            Error::JobGroupSchedulesGet(ref e) => {
+                format!("Database error retrieving group schedules, {}", e)
+            }
+            Error::JobAuditGet(ref e) => format!("Database error getting job audit log, {}", e),
+            Error::JobAuditInsert(ref e) => {
+                format!("Database error recording a state transition, {}", e)
+            }
             Error::JobCreate(ref e) => format!("Database error creating a new job, {}", e),
             Error::JobGet(ref e) => format!("Database error getting job data, {}", e),
+            Error::JobHistoryPrune(ref e) => {
+                format!("Database error pruning job history, {}", e)
+            }
             Error::JobLogArchive(job_id, ref e) => {
                 format!("Log archiving error for job {}, {}", job_id, e)
             }
+            Error::JobLogDelete(job_id, ref e) => {
+                format!("Error deleting archived log for job {}, {}", job_id, e)
+            }
             Error::JobLogRetrieval(job_id, ref e) => {
                 format!("Log retrieval error for job {}, {}", job_id, e)
             }
@@ -149,6 +195,7 @@ impl fmt::Display for Error {
             }
             Error::JobPending(ref e) => format!("Database error getting pending jobs, {}", e),
             Error::JobReset(ref e) => format!("Database error reseting jobs, {}", e),
+            Error::JobsByTagGet(ref e) => format!("Database error getting jobs by tag, {}", e),
             Error::JobSetLogUrl(ref e) => format!("Database error setting job log URL, {}", e),
             Error::JobSetState(ref e) => format!("Database error setting job state, {}", e),
             Error::SyncJobs(ref e) => format!("Database error retrieving sync jobs, {}", e),
@@ -161,7 +208,23 @@ impl fmt::Display for Error {
             Error::LogDirNotWritable(ref path) => {
                 format!("Build log directory {:?} is not writable!", path)
             }
+            Error::MetricsServer(ref e) => format!("Unable to start the metrics server, {}", e),
             Error::NetError(ref e) => format!("{}", e),
+            Error::NotificationPayload(ref e) => {
+                format!("Unable to serialize notification payload, {}", e)
+            }
+            Error::NotificationSecret(ref e) => {
+                format!("Unable to decrypt notification webhook secret, {}", e)
+            }
+            Error::NotificationSendmail(ref e) => {
+                format!("Unable to run sendmail for a notification email, {}", e)
+            }
+            Error::NotificationSendmailStatus(ref status) => {
+                format!("Sendmail failed to send a notification email, {}", status)
+            }
+            Error::OriginBuildUsage(ref e) => {
+                format!("Database error retrieving origin build usage, {}", e)
+            }
             Error::ParseVCSInstallationId(ref e) => {
                 format!("VCS installation id could not be parsed as u64, {}", e)
             }
@@ -175,6 +238,7 @@ impl fmt::Display for Error {
             Error::UnknownJobGraphPackage => format!("Unknown Package"),
             Error::UnknownJobGroupProjectState => format!("Unknown Project State"),
             Error::UnknownVCS => format!("Unknown VCS"),
+            Error::UnknownJobAuditTrigger(ref e) => format!("{}", e),
             Error::UnknownJobState(ref e) => format!("{}", e),
             Error::Zmq(ref e) => format!("{}", e),
         };
@@ -209,24 +273,41 @@ impl error::Error for Error {
             Error::JobGraphPackageStats(ref err) => err.description(),
             Error::JobGraphPackagesGet(ref err) => err.description(),
             Error::JobGroupProjectSetState(ref err) => err.description(),
+            Error::JobGroupRetry(ref err) => err.description(),
+            Error::JobGroupScheduleDelete(ref err) => err.description(),
+            Error::JobGroupScheduleSetRun(ref err) => err.description(),
+            Error::JobGroupScheduleUpsert(ref err) => err.description(),
+            Error::JobGroupSchedulesGet(ref err) => err.description(),
+            Error::JobAuditGet(ref err) => err.description(),
+            Error::JobAuditInsert(ref err) => err.description(),
             Error::JobCreate(ref err) => err.description(),
             Error::JobGet(ref err) => err.description(),
+            Error::JobHistoryPrune(ref err) => err.description(),
             Error::JobLogArchive(_, ref err) => err.description(),
+            Error::JobLogDelete(_, ref err) => err.description(),
             Error::JobLogRetrieval(_, ref err) => err.description(),
             Error::JobMarkArchived(ref err) => err.description(),
             Error::JobPending(ref err) => err.description(),
             Error::JobReset(ref err) => err.description(),
+            Error::JobsByTagGet(ref err) => err.description(),
             Error::JobSetLogUrl(ref err) => err.description(),
             Error::JobSetState(ref err) => err.description(),
             Error::SyncJobs(ref err) => err.description(),
             Error::LogDirDoesNotExist(_, ref err) => err.description(),
             Error::LogDirIsNotDir(_) => "Build log directory is not a directory",
             Error::LogDirNotWritable(_) => "Build log directory is not writable",
+            Error::MetricsServer(ref err) => err.description(),
             Error::NetError(ref err) => err.description(),
+            Error::NotificationPayload(ref err) => err.description(),
+            Error::NotificationSecret(ref err) => err.description(),
+            Error::NotificationSendmail(ref err) => err.description(),
+            Error::NotificationSendmailStatus(_) => "Sendmail failed to send a notification email",
+            Error::OriginBuildUsage(ref err) => err.description(),
             Error::ParseVCSInstallationId(_) => "VCS installation id could not be parsed as u64",
             Error::ProjectJobsGet(ref err) => err.description(),
             Error::Protobuf(ref err) => err.description(),
             Error::Protocol(ref err) => err.description(),
+            Error::UnknownJobAuditTrigger(ref err) => err.description(),
             Error::UnknownJobState(ref err) => err.description(),
             Error::UnknownJobGroup => "Unknown Group",
             Error::UnknownJobGroupState => "Unknown Group State",
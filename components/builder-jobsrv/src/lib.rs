@@ -18,17 +18,22 @@
 extern crate aws_sdk_rust;
 extern crate builder_core as bldr_core;
 extern crate chrono;
+extern crate flate2;
+extern crate github_api_client;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
 extern crate habitat_net as hab_net;
 extern crate habitat_builder_db as db;
 extern crate hyper;
+extern crate hyper_openssl;
 #[macro_use]
 extern crate lazy_static;
 extern crate linked_hash_map;
 #[macro_use]
 extern crate log;
 extern crate postgres;
+#[macro_use]
+extern crate prometheus;
 extern crate protobuf;
 extern crate rand;
 extern crate time;
@@ -36,6 +41,8 @@ extern crate r2d2;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[macro_use]
+extern crate serde_json;
 extern crate sha2;
 extern crate toml;
 extern crate zmq;
@@ -0,0 +1,134 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    // Every state transition of a job or a job group. Job rows have a job_id and group rows have
+    // a group_id.
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS job_audit_id_seq;"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE TABLE IF NOT EXISTS job_audit (
+                                id bigint PRIMARY KEY DEFAULT next_id_v1('job_audit_id_seq'),
+                                job_id bigint,
+                                group_id bigint,
+                                from_state text,
+                                to_state text NOT NULL,
+                                trigger text NOT NULL,
+                                requester_id bigint,
+                                requester_name text,
+                                worker text,
+                                created_at timestamptz DEFAULT now()
+                         )"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE INDEX IF NOT EXISTS job_audit_job_id_index_v1 ON job_audit(job_id)"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE INDEX IF NOT EXISTS job_audit_group_id_index_v1 ON job_audit(group_id)"#,
+    )?;
+
+    // Record a job transition, unless the job is already in that state
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_job_audit_v1 (
+                                p_job_id bigint,
+                                p_to_state text,
+                                p_trigger text,
+                                p_requester_id bigint,
+                                p_requester_name text,
+                                p_worker text
+                            ) RETURNS void AS $$
+                            DECLARE
+                                last_state text;
+                            BEGIN
+                                SELECT to_state INTO last_state FROM job_audit
+                                WHERE job_id = p_job_id
+                                ORDER BY id DESC
+                                LIMIT 1;
+                                IF last_state IS DISTINCT FROM p_to_state THEN
+                                    INSERT INTO job_audit (job_id, from_state, to_state, trigger,
+                                                           requester_id, requester_name, worker)
+                                    VALUES (p_job_id, last_state, p_to_state, p_trigger,
+                                            p_requester_id, p_requester_name, p_worker);
+                                END IF;
+                            END
+                            $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+
+    // Record a group transition, unless the group is already in that state
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_group_audit_v1 (
+                                p_group_id bigint,
+                                p_to_state text,
+                                p_trigger text,
+                                p_requester_id bigint,
+                                p_requester_name text
+                            ) RETURNS void AS $$
+                            DECLARE
+                                last_state text;
+                            BEGIN
+                                SELECT to_state INTO last_state FROM job_audit
+                                WHERE group_id = p_group_id
+                                ORDER BY id DESC
+                                LIMIT 1;
+                                IF last_state IS DISTINCT FROM p_to_state THEN
+                                    INSERT INTO job_audit (group_id, from_state, to_state, trigger,
+                                                           requester_id, requester_name)
+                                    VALUES (p_group_id, last_state, p_to_state, p_trigger,
+                                            p_requester_id, p_requester_name);
+                                END IF;
+                            END
+                            $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_job_audit_v1 (p_job_id bigint) RETURNS SETOF job_audit AS $$
+                                SELECT * FROM job_audit
+                                WHERE job_id = p_job_id
+                                ORDER BY id;
+                            $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    // A group's own transitions along with those of every job it ran, including retried ones
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_group_audit_v1 (p_group_id bigint) RETURNS SETOF job_audit AS $$
+                                SELECT * FROM job_audit
+                                WHERE group_id = p_group_id
+                                OR job_id IN (
+                                    SELECT j.id FROM jobs j
+                                    INNER JOIN group_projects gp
+                                    ON gp.owner_id = j.owner_id AND gp.project_name = j.project_name
+                                    WHERE gp.owner_id = p_group_id
+                                )
+                                ORDER BY id;
+                            $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    Ok(())
+}
@@ -438,5 +438,788 @@ pub fn migrate(migrator: &mut Migrator) -> Result<()> {
                        WHERE job_state = 'Dispatched'
                      $$"#,
     )?;
+
+    // Add a priority column to the jobs table
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS priority INTEGER DEFAULT 0 NOT NULL"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION insert_job_v3 (
+                            p_owner_id bigint,
+                            p_project_id bigint,
+                            p_project_name text,
+                            p_project_owner_id bigint,
+                            p_project_plan_path text,
+                            p_vcs text,
+                            p_vcs_arguments text[],
+                            p_channel text,
+                            p_priority integer
+                            ) RETURNS SETOF jobs AS $$
+                                INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, priority)
+                                VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_priority)
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#)?;
+
+    // Get the next Pending job, taking the highest priority jobs first
+    migrator.migrate("jobsrv",
+         r#"CREATE OR REPLACE FUNCTION next_pending_job_v2 (p_worker text) RETURNS SETOF jobs AS
+                $$
+                DECLARE
+                    r jobs % rowtype;
+                BEGIN
+                    FOR r IN
+                        SELECT * FROM jobs
+                        WHERE job_state = 'Pending'
+                        ORDER BY priority DESC, created_at ASC
+                        FOR UPDATE SKIP LOCKED
+                        LIMIT 1
+                    LOOP
+                        UPDATE jobs SET job_state='Dispatched', scheduler_sync=false, worker=p_worker, updated_at=now()
+                        WHERE id=r.id
+                        RETURNING * INTO r;
+                        RETURN NEXT r;
+                    END LOOP;
+                  RETURN;
+                END
+                $$ LANGUAGE plpgsql VOLATILE"#)?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE INDEX IF NOT EXISTS pending_jobs_priority_index_v1 on jobs(priority DESC, created_at) WHERE job_state = 'Pending'"#,
+    )?;
+
+    // Add a target column to the jobs table
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS target TEXT DEFAULT 'x86_64-linux' NOT NULL"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION insert_job_v4 (
+                            p_owner_id bigint,
+                            p_project_id bigint,
+                            p_project_name text,
+                            p_project_owner_id bigint,
+                            p_project_plan_path text,
+                            p_vcs text,
+                            p_vcs_arguments text[],
+                            p_channel text,
+                            p_priority integer,
+                            p_target text
+                            ) RETURNS SETOF jobs AS $$
+                                INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, priority, target)
+                                VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_priority, p_target)
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#)?;
+
+    // Get the next Pending job which can be built on the given worker's target
+    migrator.migrate("jobsrv",
+         r#"CREATE OR REPLACE FUNCTION next_pending_job_v3 (p_worker text, p_target text) RETURNS SETOF jobs AS
+                $$
+                DECLARE
+                    r jobs % rowtype;
+                BEGIN
+                    FOR r IN
+                        SELECT * FROM jobs
+                        WHERE job_state = 'Pending'
+                        AND target = p_target
+                        ORDER BY priority DESC, created_at ASC
+                        FOR UPDATE SKIP LOCKED
+                        LIMIT 1
+                    LOOP
+                        UPDATE jobs SET job_state='Dispatched', scheduler_sync=false, worker=p_worker, updated_at=now()
+                        WHERE id=r.id
+                        RETURNING * INTO r;
+                        RETURN NEXT r;
+                    END LOOP;
+                  RETURN;
+                END
+                $$ LANGUAGE plpgsql VOLATILE"#)?;
+
+    // Track automatic retries of jobs which failed for transient reasons
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS retries INTEGER DEFAULT 0 NOT NULL"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS max_retries INTEGER DEFAULT 0 NOT NULL"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS last_error_category TEXT DEFAULT NULL"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION insert_job_v5 (
+                            p_owner_id bigint,
+                            p_project_id bigint,
+                            p_project_name text,
+                            p_project_owner_id bigint,
+                            p_project_plan_path text,
+                            p_vcs text,
+                            p_vcs_arguments text[],
+                            p_channel text,
+                            p_priority integer,
+                            p_target text,
+                            p_max_retries integer
+                            ) RETURNS SETOF jobs AS $$
+                                INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, priority, target, max_retries)
+                                VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_priority, p_target, p_max_retries)
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#)?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION update_job_v4(
+                       p_job_id bigint,
+                       p_state text,
+                       p_build_started_at timestamptz,
+                       p_build_finished_at timestamptz,
+                       p_package_ident text,
+                       p_err_code int,
+                       p_err_msg text,
+                       p_retries int,
+                       p_last_error_category text)
+                     RETURNS VOID
+                     LANGUAGE SQL VOLATILE AS $$
+                       UPDATE jobs
+                       SET job_state = p_state,
+                           scheduler_sync = false,
+                           sync_count = sync_count + 1,
+                           updated_at = now(),
+                           build_started_at = p_build_started_at,
+                           build_finished_at = p_build_finished_at,
+                           package_ident = p_package_ident,
+                           net_error_code = p_err_code,
+                           net_error_msg = p_err_msg,
+                           retries = p_retries,
+                           last_error_category = p_last_error_category
+                       WHERE id = p_job_id;
+                     $$"#,
+    )?;
+
+    // Return every jobs column so the listing has the same fields as a single job
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_jobs_for_project_v3(p_project_name TEXT, p_limit bigint, p_offset bigint)
+                     RETURNS TABLE (total_count bigint, id bigint, owner_id bigint, job_state text, created_at timestamptz,
+                                    build_started_at timestamptz, build_finished_at timestamptz, package_ident text,
+                                    project_id bigint, project_name text, project_owner_id bigint, project_plan_path text,
+                                    vcs text, vcs_arguments text[], net_error_msg text, net_error_code integer, archived boolean,
+                                    channel text, worker text, priority integer, target text, retries integer,
+                                    max_retries integer, last_error_category text)
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT COUNT(*) OVER () AS total_count, id, owner_id, job_state, created_at, build_started_at,
+                       build_finished_at, package_ident, project_id, project_name, project_owner_id, project_plan_path, vcs,
+                       vcs_arguments, net_error_msg, net_error_code, archived, channel, worker, priority, target, retries,
+                       max_retries, last_error_category
+                       FROM jobs
+                       WHERE project_name = p_project_name
+                       ORDER BY created_at DESC
+                       LIMIT p_limit
+                       OFFSET p_offset;
+                     $$"#,
+    )?;
+
+    // Tag jobs so that related jobs can be found across groups
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS tags TEXT[] DEFAULT '{}' NOT NULL"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE INDEX IF NOT EXISTS jobs_tags_index_v1 ON jobs USING GIN (tags)"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION insert_job_v6 (
+                            p_owner_id bigint,
+                            p_project_id bigint,
+                            p_project_name text,
+                            p_project_owner_id bigint,
+                            p_project_plan_path text,
+                            p_vcs text,
+                            p_vcs_arguments text[],
+                            p_channel text,
+                            p_priority integer,
+                            p_target text,
+                            p_max_retries integer,
+                            p_tags text[]
+                            ) RETURNS SETOF jobs AS $$
+                                INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, priority, target, max_retries, tags)
+                                VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_priority, p_target, p_max_retries, p_tags)
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#)?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_jobs_for_project_v4(p_project_name TEXT, p_limit bigint, p_offset bigint)
+                     RETURNS TABLE (total_count bigint, id bigint, owner_id bigint, job_state text, created_at timestamptz,
+                                    build_started_at timestamptz, build_finished_at timestamptz, package_ident text,
+                                    project_id bigint, project_name text, project_owner_id bigint, project_plan_path text,
+                                    vcs text, vcs_arguments text[], net_error_msg text, net_error_code integer, archived boolean,
+                                    channel text, worker text, priority integer, target text, retries integer,
+                                    max_retries integer, last_error_category text, tags text[])
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT COUNT(*) OVER () AS total_count, id, owner_id, job_state, created_at, build_started_at,
+                       build_finished_at, package_ident, project_id, project_name, project_owner_id, project_plan_path, vcs,
+                       vcs_arguments, net_error_msg, net_error_code, archived, channel, worker, priority, target, retries,
+                       max_retries, last_error_category, tags
+                       FROM jobs
+                       WHERE project_name = p_project_name
+                       ORDER BY created_at DESC
+                       LIMIT p_limit
+                       OFFSET p_offset;
+                     $$"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_jobs_by_tag_v1(p_tag TEXT, p_limit bigint, p_offset bigint)
+                     RETURNS TABLE (total_count bigint, id bigint, owner_id bigint, job_state text, created_at timestamptz,
+                                    build_started_at timestamptz, build_finished_at timestamptz, package_ident text,
+                                    project_id bigint, project_name text, project_owner_id bigint, project_plan_path text,
+                                    vcs text, vcs_arguments text[], net_error_msg text, net_error_code integer, archived boolean,
+                                    channel text, worker text, priority integer, target text, retries integer,
+                                    max_retries integer, last_error_category text, tags text[])
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT COUNT(*) OVER () AS total_count, id, owner_id, job_state, created_at, build_started_at,
+                       build_finished_at, package_ident, project_id, project_name, project_owner_id, project_plan_path, vcs,
+                       vcs_arguments, net_error_msg, net_error_code, archived, channel, worker, priority, target, retries,
+                       max_retries, last_error_category, tags
+                       FROM jobs
+                       WHERE tags @> ARRAY[p_tag]
+                       ORDER BY created_at DESC
+                       LIMIT p_limit
+                       OFFSET p_offset;
+                     $$"#,
+    )?;
+
+    // Record the package archive produced by a job
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS artifact_checksum TEXT DEFAULT NULL"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS artifact_size BIGINT DEFAULT NULL"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION update_job_v5(
+                       p_job_id bigint,
+                       p_state text,
+                       p_build_started_at timestamptz,
+                       p_build_finished_at timestamptz,
+                       p_package_ident text,
+                       p_err_code int,
+                       p_err_msg text,
+                       p_retries int,
+                       p_last_error_category text,
+                       p_artifact_checksum text,
+                       p_artifact_size bigint)
+                     RETURNS VOID
+                     LANGUAGE SQL VOLATILE AS $$
+                       UPDATE jobs
+                       SET job_state = p_state,
+                           scheduler_sync = false,
+                           sync_count = sync_count + 1,
+                           updated_at = now(),
+                           build_started_at = p_build_started_at,
+                           build_finished_at = p_build_finished_at,
+                           package_ident = p_package_ident,
+                           net_error_code = p_err_code,
+                           net_error_msg = p_err_msg,
+                           retries = p_retries,
+                           last_error_category = p_last_error_category,
+                           artifact_checksum = p_artifact_checksum,
+                           artifact_size = p_artifact_size
+                       WHERE id = p_job_id;
+                     $$"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_jobs_for_project_v5(p_project_name TEXT, p_limit bigint, p_offset bigint)
+                     RETURNS TABLE (total_count bigint, id bigint, owner_id bigint, job_state text, created_at timestamptz,
+                                    build_started_at timestamptz, build_finished_at timestamptz, package_ident text,
+                                    project_id bigint, project_name text, project_owner_id bigint, project_plan_path text,
+                                    vcs text, vcs_arguments text[], net_error_msg text, net_error_code integer, archived boolean,
+                                    channel text, worker text, priority integer, target text, retries integer,
+                                    max_retries integer, last_error_category text, tags text[], artifact_checksum text,
+                                    artifact_size bigint)
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT COUNT(*) OVER () AS total_count, id, owner_id, job_state, created_at, build_started_at,
+                       build_finished_at, package_ident, project_id, project_name, project_owner_id, project_plan_path, vcs,
+                       vcs_arguments, net_error_msg, net_error_code, archived, channel, worker, priority, target, retries,
+                       max_retries, last_error_category, tags, artifact_checksum, artifact_size
+                       FROM jobs
+                       WHERE project_name = p_project_name
+                       ORDER BY created_at DESC
+                       LIMIT p_limit
+                       OFFSET p_offset;
+                     $$"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_jobs_by_tag_v2(p_tag TEXT, p_limit bigint, p_offset bigint)
+                     RETURNS TABLE (total_count bigint, id bigint, owner_id bigint, job_state text, created_at timestamptz,
+                                    build_started_at timestamptz, build_finished_at timestamptz, package_ident text,
+                                    project_id bigint, project_name text, project_owner_id bigint, project_plan_path text,
+                                    vcs text, vcs_arguments text[], net_error_msg text, net_error_code integer, archived boolean,
+                                    channel text, worker text, priority integer, target text, retries integer,
+                                    max_retries integer, last_error_category text, tags text[], artifact_checksum text,
+                                    artifact_size bigint)
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT COUNT(*) OVER () AS total_count, id, owner_id, job_state, created_at, build_started_at,
+                       build_finished_at, package_ident, project_id, project_name, project_owner_id, project_plan_path, vcs,
+                       vcs_arguments, net_error_msg, net_error_code, archived, channel, worker, priority, target, retries,
+                       max_retries, last_error_category, tags, artifact_checksum, artifact_size
+                       FROM jobs
+                       WHERE tags @> ARRAY[p_tag]
+                       ORDER BY created_at DESC
+                       LIMIT p_limit
+                       OFFSET p_offset;
+                     $$"#,
+    )?;
+
+    // Fail jobs which sit waiting for a worker for too long
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS expires_at TIMESTAMPTZ DEFAULT NULL"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE INDEX IF NOT EXISTS waiting_jobs_expires_at_index_v1 ON jobs(expires_at) WHERE job_state IN ('Pending', 'Dispatched')"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION insert_job_v7 (
+                            p_owner_id bigint,
+                            p_project_id bigint,
+                            p_project_name text,
+                            p_project_owner_id bigint,
+                            p_project_plan_path text,
+                            p_vcs text,
+                            p_vcs_arguments text[],
+                            p_channel text,
+                            p_priority integer,
+                            p_target text,
+                            p_max_retries integer,
+                            p_tags text[],
+                            p_expires_at timestamptz
+                            ) RETURNS SETOF jobs AS $$
+                                INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, priority, target, max_retries, tags, expires_at)
+                                VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_priority, p_target, p_max_retries, p_tags, p_expires_at)
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#)?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_expired_jobs_v1()
+                     RETURNS SETOF jobs
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT *
+                       FROM jobs
+                       WHERE job_state IN ('Pending', 'Dispatched')
+                       AND expires_at < now()
+                       ORDER BY expires_at ASC;
+                     $$"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_jobs_for_project_v6(p_project_name TEXT, p_limit bigint, p_offset bigint)
+                     RETURNS TABLE (total_count bigint, id bigint, owner_id bigint, job_state text, created_at timestamptz,
+                                    build_started_at timestamptz, build_finished_at timestamptz, package_ident text,
+                                    project_id bigint, project_name text, project_owner_id bigint, project_plan_path text,
+                                    vcs text, vcs_arguments text[], net_error_msg text, net_error_code integer, archived boolean,
+                                    channel text, worker text, priority integer, target text, retries integer,
+                                    max_retries integer, last_error_category text, tags text[], artifact_checksum text,
+                                    artifact_size bigint, expires_at timestamptz)
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT COUNT(*) OVER () AS total_count, id, owner_id, job_state, created_at, build_started_at,
+                       build_finished_at, package_ident, project_id, project_name, project_owner_id, project_plan_path, vcs,
+                       vcs_arguments, net_error_msg, net_error_code, archived, channel, worker, priority, target, retries,
+                       max_retries, last_error_category, tags, artifact_checksum, artifact_size, expires_at
+                       FROM jobs
+                       WHERE project_name = p_project_name
+                       ORDER BY created_at DESC
+                       LIMIT p_limit
+                       OFFSET p_offset;
+                     $$"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_jobs_by_tag_v3(p_tag TEXT, p_limit bigint, p_offset bigint)
+                     RETURNS TABLE (total_count bigint, id bigint, owner_id bigint, job_state text, created_at timestamptz,
+                                    build_started_at timestamptz, build_finished_at timestamptz, package_ident text,
+                                    project_id bigint, project_name text, project_owner_id bigint, project_plan_path text,
+                                    vcs text, vcs_arguments text[], net_error_msg text, net_error_code integer, archived boolean,
+                                    channel text, worker text, priority integer, target text, retries integer,
+                                    max_retries integer, last_error_category text, tags text[], artifact_checksum text,
+                                    artifact_size bigint, expires_at timestamptz)
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT COUNT(*) OVER () AS total_count, id, owner_id, job_state, created_at, build_started_at,
+                       build_finished_at, package_ident, project_id, project_name, project_owner_id, project_plan_path, vcs,
+                       vcs_arguments, net_error_msg, net_error_code, archived, channel, worker, priority, target, retries,
+                       max_retries, last_error_category, tags, artifact_checksum, artifact_size, expires_at
+                       FROM jobs
+                       WHERE tags @> ARRAY[p_tag]
+                       ORDER BY created_at DESC
+                       LIMIT p_limit
+                       OFFSET p_offset;
+                     $$"#,
+    )?;
+
+    // Projects may override how long their builds can run for
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS build_timeout INTEGER DEFAULT NULL"#,
+    )?;
+    migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION insert_job_v8 (
+                            p_owner_id bigint,
+                            p_project_id bigint,
+                            p_project_name text,
+                            p_project_owner_id bigint,
+                            p_project_plan_path text,
+                            p_vcs text,
+                            p_vcs_arguments text[],
+                            p_channel text,
+                            p_priority integer,
+                            p_target text,
+                            p_max_retries integer,
+                            p_tags text[],
+                            p_expires_at timestamptz,
+                            p_build_timeout integer
+                            ) RETURNS SETOF jobs AS $$
+                                INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, priority, target, max_retries, tags, expires_at, build_timeout)
+                                VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_priority, p_target, p_max_retries, p_tags, p_expires_at, p_build_timeout)
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#)?;
+
+    // Job counts for the metrics endpoint
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION count_jobs_by_state_v1 ()
+                            RETURNS TABLE (job_state text, jobs bigint) AS $$
+                                SELECT job_state, COUNT(*) FROM jobs GROUP BY job_state;
+                            $$ LANGUAGE SQL STABLE"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION count_pending_jobs_by_target_v1 ()
+                            RETURNS TABLE (target text, jobs bigint) AS $$
+                                SELECT target, COUNT(*) FROM jobs
+                                WHERE job_state = 'Pending'
+                                GROUP BY target;
+                            $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    // Jobs which have been handed to a worker and may be orphaned if it's lost
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_running_jobs_v1()
+                     RETURNS SETOF jobs
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT *
+                       FROM jobs
+                       WHERE job_state IN ('Dispatched', 'Processing')
+                     $$"#,
+    )?;
+
+    // Get the next Pending job which can be built on the given worker's target, passing over the
+    // jobs of origins which are at their build quota
+    migrator.migrate("jobsrv",
+         r#"CREATE OR REPLACE FUNCTION next_pending_job_v4 (p_worker text, p_target text, p_excluded_origins text[]) RETURNS SETOF jobs AS
+                $$
+                DECLARE
+                    r jobs % rowtype;
+                BEGIN
+                    FOR r IN
+                        SELECT * FROM jobs
+                        WHERE job_state = 'Pending'
+                        AND target = p_target
+                        AND split_part(project_name, '/', 1) <> ALL(p_excluded_origins)
+                        ORDER BY priority DESC, created_at ASC
+                        FOR UPDATE SKIP LOCKED
+                        LIMIT 1
+                    LOOP
+                        UPDATE jobs SET job_state='Dispatched', scheduler_sync=false, worker=p_worker, updated_at=now()
+                        WHERE id=r.id
+                        RETURNING * INTO r;
+                        RETURN NEXT r;
+                    END LOOP;
+                  RETURN;
+                END
+                $$ LANGUAGE plpgsql VOLATILE"#)?;
+
+    // Count the workers each origin's jobs occupy and the builds each origin started in the last
+    // day, for one origin or all of them if none is given
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_build_usage_v1 (p_origin text)
+                            RETURNS TABLE (origin text, workers bigint, builds bigint) AS $$
+                                SELECT split_part(project_name, '/', 1) AS origin,
+                                    COUNT(*) FILTER (WHERE job_state IN ('Dispatched', 'Processing', 'CancelProcessing')),
+                                    COUNT(*) FILTER (WHERE job_state = 'Dispatched' OR build_started_at > now() - interval '1 day')
+                                FROM jobs
+                                WHERE (job_state IN ('Dispatched', 'Processing', 'CancelProcessing')
+                                    OR build_started_at > now() - interval '1 day')
+                                AND (p_origin IS NULL OR split_part(project_name, '/', 1) = p_origin)
+                                GROUP BY 1;
+                            $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    // Projects may require their builds to run on workers with particular labels
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS worker_labels TEXT[] DEFAULT '{}' NOT NULL"#,
+    )?;
+    migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION insert_job_v9 (
+                            p_owner_id bigint,
+                            p_project_id bigint,
+                            p_project_name text,
+                            p_project_owner_id bigint,
+                            p_project_plan_path text,
+                            p_vcs text,
+                            p_vcs_arguments text[],
+                            p_channel text,
+                            p_priority integer,
+                            p_target text,
+                            p_max_retries integer,
+                            p_tags text[],
+                            p_expires_at timestamptz,
+                            p_build_timeout integer,
+                            p_worker_labels text[]
+                            ) RETURNS SETOF jobs AS $$
+                                INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, priority, target, max_retries, tags, expires_at, build_timeout, worker_labels)
+                                VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_priority, p_target, p_max_retries, p_tags, p_expires_at, p_build_timeout, p_worker_labels)
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#)?;
+
+    // Get the next Pending job which can be built on the given worker's target and whose
+    // required labels the worker has, passing over the jobs of origins at their build quota
+    migrator.migrate("jobsrv",
+         r#"CREATE OR REPLACE FUNCTION next_pending_job_v5 (p_worker text, p_target text, p_excluded_origins text[], p_labels text[]) RETURNS SETOF jobs AS
+                $$
+                DECLARE
+                    r jobs % rowtype;
+                BEGIN
+                    FOR r IN
+                        SELECT * FROM jobs
+                        WHERE job_state = 'Pending'
+                        AND target = p_target
+                        AND split_part(project_name, '/', 1) <> ALL(p_excluded_origins)
+                        AND worker_labels <@ p_labels
+                        ORDER BY priority DESC, created_at ASC
+                        FOR UPDATE SKIP LOCKED
+                        LIMIT 1
+                    LOOP
+                        UPDATE jobs SET job_state='Dispatched', scheduler_sync=false, worker=p_worker, updated_at=now()
+                        WHERE id=r.id
+                        RETURNING * INTO r;
+                        RETURN NEXT r;
+                    END LOOP;
+                  RETURN;
+                END
+                $$ LANGUAGE plpgsql VOLATILE"#)?;
+
+    // Jobs may export their package to a Docker image once it's built
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS kind INTEGER DEFAULT 0 NOT NULL"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS image_digest TEXT DEFAULT NULL"#,
+    )?;
+    migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION insert_job_v10 (
+                            p_owner_id bigint,
+                            p_project_id bigint,
+                            p_project_name text,
+                            p_project_owner_id bigint,
+                            p_project_plan_path text,
+                            p_vcs text,
+                            p_vcs_arguments text[],
+                            p_channel text,
+                            p_priority integer,
+                            p_target text,
+                            p_max_retries integer,
+                            p_tags text[],
+                            p_expires_at timestamptz,
+                            p_build_timeout integer,
+                            p_worker_labels text[],
+                            p_kind integer
+                            ) RETURNS SETOF jobs AS $$
+                                INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, priority, target, max_retries, tags, expires_at, build_timeout, worker_labels, kind)
+                                VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_priority, p_target, p_max_retries, p_tags, p_expires_at, p_build_timeout, p_worker_labels, p_kind)
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#)?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION update_job_v6(
+                       p_job_id bigint,
+                       p_state text,
+                       p_build_started_at timestamptz,
+                       p_build_finished_at timestamptz,
+                       p_package_ident text,
+                       p_err_code int,
+                       p_err_msg text,
+                       p_retries int,
+                       p_last_error_category text,
+                       p_artifact_checksum text,
+                       p_artifact_size bigint,
+                       p_image_digest text)
+                     RETURNS VOID
+                     LANGUAGE SQL VOLATILE AS $$
+                       UPDATE jobs
+                       SET job_state = p_state,
+                           scheduler_sync = false,
+                           sync_count = sync_count + 1,
+                           updated_at = now(),
+                           build_started_at = p_build_started_at,
+                           build_finished_at = p_build_finished_at,
+                           package_ident = p_package_ident,
+                           net_error_code = p_err_code,
+                           net_error_msg = p_err_msg,
+                           retries = p_retries,
+                           last_error_category = p_last_error_category,
+                           artifact_checksum = p_artifact_checksum,
+                           artifact_size = p_artifact_size,
+                           image_digest = p_image_digest
+                       WHERE id = p_job_id;
+                     $$"#,
+    )?;
+
+    // Jobs of projects verifying reproducibility are built twice and record whether both builds
+    // had the same contents
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS verify_reproducible BOOL DEFAULT false NOT NULL"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE jobs ADD COLUMN IF NOT EXISTS reproducible BOOL DEFAULT NULL"#,
+    )?;
+    migrator.migrate("jobsrv",
+                         r#"CREATE OR REPLACE FUNCTION insert_job_v11 (
+                            p_owner_id bigint,
+                            p_project_id bigint,
+                            p_project_name text,
+                            p_project_owner_id bigint,
+                            p_project_plan_path text,
+                            p_vcs text,
+                            p_vcs_arguments text[],
+                            p_channel text,
+                            p_priority integer,
+                            p_target text,
+                            p_max_retries integer,
+                            p_tags text[],
+                            p_expires_at timestamptz,
+                            p_build_timeout integer,
+                            p_worker_labels text[],
+                            p_kind integer,
+                            p_verify_reproducible bool
+                            ) RETURNS SETOF jobs AS $$
+                                INSERT INTO jobs (owner_id, job_state, project_id, project_name, project_owner_id, project_plan_path, vcs, vcs_arguments, channel, priority, target, max_retries, tags, expires_at, build_timeout, worker_labels, kind, verify_reproducible)
+                                VALUES (p_owner_id, 'Pending', p_project_id, p_project_name, p_project_owner_id, p_project_plan_path, p_vcs, p_vcs_arguments, p_channel, p_priority, p_target, p_max_retries, p_tags, p_expires_at, p_build_timeout, p_worker_labels, p_kind, p_verify_reproducible)
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#)?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION update_job_v7(
+                       p_job_id bigint,
+                       p_state text,
+                       p_build_started_at timestamptz,
+                       p_build_finished_at timestamptz,
+                       p_package_ident text,
+                       p_err_code int,
+                       p_err_msg text,
+                       p_retries int,
+                       p_last_error_category text,
+                       p_artifact_checksum text,
+                       p_artifact_size bigint,
+                       p_image_digest text,
+                       p_reproducible bool)
+                     RETURNS VOID
+                     LANGUAGE SQL VOLATILE AS $$
+                       UPDATE jobs
+                       SET job_state = p_state,
+                           scheduler_sync = false,
+                           sync_count = sync_count + 1,
+                           updated_at = now(),
+                           build_started_at = p_build_started_at,
+                           build_finished_at = p_build_finished_at,
+                           package_ident = p_package_ident,
+                           net_error_code = p_err_code,
+                           net_error_msg = p_err_msg,
+                           retries = p_retries,
+                           last_error_category = p_last_error_category,
+                           artifact_checksum = p_artifact_checksum,
+                           artifact_size = p_artifact_size,
+                           image_digest = p_image_digest,
+                           reproducible = p_reproducible
+                       WHERE id = p_job_id;
+                     $$"#,
+    )?;
+
+    // Listings can leave out the jobs created after a given time, which keeps the pages of a
+    // listing from shifting while jobs are scheduled
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_jobs_for_project_v7(p_project_name TEXT, p_limit bigint, p_offset bigint, p_created_before timestamptz)
+                     RETURNS TABLE (total_count bigint, id bigint, owner_id bigint, job_state text, created_at timestamptz,
+                                    build_started_at timestamptz, build_finished_at timestamptz, package_ident text,
+                                    project_id bigint, project_name text, project_owner_id bigint, project_plan_path text,
+                                    vcs text, vcs_arguments text[], net_error_msg text, net_error_code integer, archived boolean,
+                                    channel text, worker text, priority integer, target text, retries integer,
+                                    max_retries integer, last_error_category text, tags text[], artifact_checksum text,
+                                    artifact_size bigint, expires_at timestamptz)
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT COUNT(*) OVER () AS total_count, id, owner_id, job_state, created_at, build_started_at,
+                       build_finished_at, package_ident, project_id, project_name, project_owner_id, project_plan_path, vcs,
+                       vcs_arguments, net_error_msg, net_error_code, archived, channel, worker, priority, target, retries,
+                       max_retries, last_error_category, tags, artifact_checksum, artifact_size, expires_at
+                       FROM jobs
+                       WHERE project_name = p_project_name
+                       AND (p_created_before IS NULL OR created_at <= p_created_before)
+                       ORDER BY created_at DESC
+                       LIMIT p_limit
+                       OFFSET p_offset;
+                     $$"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_jobs_by_tag_v4(p_tag TEXT, p_limit bigint, p_offset bigint, p_created_before timestamptz)
+                     RETURNS TABLE (total_count bigint, id bigint, owner_id bigint, job_state text, created_at timestamptz,
+                                    build_started_at timestamptz, build_finished_at timestamptz, package_ident text,
+                                    project_id bigint, project_name text, project_owner_id bigint, project_plan_path text,
+                                    vcs text, vcs_arguments text[], net_error_msg text, net_error_code integer, archived boolean,
+                                    channel text, worker text, priority integer, target text, retries integer,
+                                    max_retries integer, last_error_category text, tags text[], artifact_checksum text,
+                                    artifact_size bigint, expires_at timestamptz)
+                     LANGUAGE SQL STABLE AS $$
+                       SELECT COUNT(*) OVER () AS total_count, id, owner_id, job_state, created_at, build_started_at,
+                       build_finished_at, package_ident, project_id, project_name, project_owner_id, project_plan_path, vcs,
+                       vcs_arguments, net_error_msg, net_error_code, archived, channel, worker, priority, target, retries,
+                       max_retries, last_error_category, tags, artifact_checksum, artifact_size, expires_at
+                       FROM jobs
+                       WHERE tags @> ARRAY[p_tag]
+                       AND (p_created_before IS NULL OR created_at <= p_created_before)
+                       ORDER BY created_at DESC
+                       LIMIT p_limit
+                       OFFSET p_offset;
+                     $$"#,
+    )?;
     Ok(())
 }
@@ -12,5 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod audit;
 pub mod jobs;
+pub mod retention;
 pub mod scheduler;
@@ -0,0 +1,66 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::Result;
+
+pub fn migrate(migrator: &mut Migrator) -> Result<()> {
+    // Delete the finished groups created before the given time, along with their projects and
+    // audit records. Returns the number of groups deleted.
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION prune_job_groups_v1 (p_before timestamptz) RETURNS bigint AS $$
+                            DECLARE
+                                pruned bigint;
+                            BEGIN
+                                CREATE TEMPORARY TABLE pruned_groups AS
+                                    SELECT id FROM groups
+                                    WHERE group_state IN ('Complete', 'Failed', 'Canceled')
+                                    AND created_at < p_before;
+                                DELETE FROM group_projects WHERE owner_id IN (SELECT id FROM pruned_groups);
+                                DELETE FROM job_audit WHERE group_id IN (SELECT id FROM pruned_groups);
+                                DELETE FROM groups WHERE id IN (SELECT id FROM pruned_groups);
+                                SELECT COUNT(*) INTO pruned FROM pruned_groups;
+                                DROP TABLE pruned_groups;
+                                RETURN pruned;
+                            END
+                            $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+
+    // Delete the finished jobs created before the given time, along with their audit records.
+    // Jobs the scheduler hasn't seen yet and jobs of groups which still exist are kept. Returns
+    // the ids of the jobs deleted, so their logs can be removed too.
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION prune_jobs_v1 (p_before timestamptz) RETURNS SETOF bigint AS $$
+                            BEGIN
+                                CREATE TEMPORARY TABLE pruned_jobs AS
+                                    SELECT j.id FROM jobs j
+                                    WHERE j.job_state IN ('Complete', 'Failed', 'CancelComplete', 'Rejected')
+                                    AND j.created_at < p_before
+                                    AND j.scheduler_sync = true
+                                    AND COALESCE(j.sync_count, 0) <= 0
+                                    AND NOT EXISTS (SELECT 1 FROM group_projects gp WHERE gp.job_id = j.id);
+                                DELETE FROM job_audit WHERE job_id IN (SELECT id FROM pruned_jobs);
+                                DELETE FROM jobs WHERE id IN (SELECT id FROM pruned_jobs);
+                                RETURN QUERY SELECT id FROM pruned_jobs;
+                                DROP TABLE pruned_jobs;
+                                RETURN;
+                            END
+                            $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+
+    Ok(())
+}
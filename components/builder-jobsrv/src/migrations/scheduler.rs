@@ -338,5 +338,508 @@ pub fn migrate(migrator: &mut Migrator) -> Result<()> {
         $$ LANGUAGE SQL STABLE"#,
     )?;
 
+    // Add a priority column to the groups table
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE groups ADD COLUMN IF NOT EXISTS priority INTEGER DEFAULT 0 NOT NULL"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+         r#"CREATE OR REPLACE FUNCTION insert_group_v3 (
+                    root_project text,
+                    project_names text[],
+                    project_idents text[],
+                    group_priority integer
+                    ) RETURNS SETOF groups
+                      LANGUAGE SQL
+                      VOLATILE AS $$
+                      WITH my_group AS (
+                              INSERT INTO groups (project_name, group_state, priority)
+                              VALUES (root_project, 'Queued', group_priority) RETURNING *
+                          ), my_project AS (
+                              INSERT INTO group_projects (owner_id, project_name, project_ident, project_state)
+                              SELECT g.id, project_info.name, project_info.ident, 'NotStarted'
+                              FROM my_group AS g, unnest(project_names, project_idents) AS project_info(name, ident)
+                          )
+                      SELECT * FROM my_group;
+                    $$"#)?;
+
+    // Retrieve Pending groups, highest priority first, while atomically setting their state to
+    // Dispatched
+    migrator.migrate("jobsrv",
+                     r#"CREATE OR REPLACE FUNCTION pending_groups_v2 (integer) RETURNS SETOF groups AS
+                            $$
+                            DECLARE
+                                r groups % rowtype;
+                            BEGIN
+                                FOR r IN
+                                    SELECT * FROM groups
+                                    WHERE group_state = 'Pending'
+                                    ORDER BY priority DESC, created_at ASC
+                                    FOR UPDATE SKIP LOCKED
+                                    LIMIT $1
+                                LOOP
+                                    UPDATE groups SET group_state='Dispatching', updated_at=now() WHERE id=r.id RETURNING * INTO r;
+                                    RETURN NEXT r;
+                                END LOOP;
+                              RETURN;
+                            END
+                            $$ LANGUAGE plpgsql VOLATILE"#)?;
+
+    // Add a target column to the groups table
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE groups ADD COLUMN IF NOT EXISTS target TEXT DEFAULT 'x86_64-linux' NOT NULL"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+         r#"CREATE OR REPLACE FUNCTION insert_group_v4 (
+                    root_project text,
+                    project_names text[],
+                    project_idents text[],
+                    group_priority integer,
+                    group_target text
+                    ) RETURNS SETOF groups
+                      LANGUAGE SQL
+                      VOLATILE AS $$
+                      WITH my_group AS (
+                              INSERT INTO groups (project_name, group_state, priority, target)
+                              VALUES (root_project, 'Queued', group_priority, group_target) RETURNING *
+                          ), my_project AS (
+                              INSERT INTO group_projects (owner_id, project_name, project_ident, project_state)
+                              SELECT g.id, project_info.name, project_info.ident, 'NotStarted'
+                              FROM my_group AS g, unnest(project_names, project_idents) AS project_info(name, ident)
+                          )
+                      SELECT * FROM my_group;
+                    $$"#)?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_job_groups_for_origin_v2 (
+            op_origin text,
+            op_state text,
+            op_created_since timestamptz,
+            op_limit bigint,
+            op_offset bigint
+        ) RETURNS TABLE (total_count bigint, id bigint, group_state text, project_name text,
+                         created_at timestamptz, updated_at timestamptz, priority integer, target text) AS $$
+            SELECT COUNT(*) OVER () AS total_count, id, group_state, project_name, created_at,
+                   updated_at, priority, target
+            FROM groups
+            WHERE project_name LIKE (op_origin || '/%')
+            AND (op_state IS NULL OR group_state = op_state)
+            AND (op_created_since IS NULL OR created_at >= op_created_since)
+            ORDER BY created_at DESC
+            LIMIT op_limit
+            OFFSET op_offset
+        $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    // Add a tags column to the groups table, which is passed on to the group's jobs
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE groups ADD COLUMN IF NOT EXISTS tags TEXT[] DEFAULT '{}' NOT NULL"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+         r#"CREATE OR REPLACE FUNCTION insert_group_v5 (
+                    root_project text,
+                    project_names text[],
+                    project_idents text[],
+                    group_priority integer,
+                    group_target text,
+                    group_tags text[]
+                    ) RETURNS SETOF groups
+                      LANGUAGE SQL
+                      VOLATILE AS $$
+                      WITH my_group AS (
+                              INSERT INTO groups (project_name, group_state, priority, target, tags)
+                              VALUES (root_project, 'Queued', group_priority, group_target, group_tags) RETURNING *
+                          ), my_project AS (
+                              INSERT INTO group_projects (owner_id, project_name, project_ident, project_state)
+                              SELECT g.id, project_info.name, project_info.ident, 'NotStarted'
+                              FROM my_group AS g, unnest(project_names, project_idents) AS project_info(name, ident)
+                          )
+                      SELECT * FROM my_group;
+                    $$"#)?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_job_groups_for_origin_v3 (
+            op_origin text,
+            op_state text,
+            op_created_since timestamptz,
+            op_limit bigint,
+            op_offset bigint
+        ) RETURNS TABLE (total_count bigint, id bigint, group_state text, project_name text,
+                         created_at timestamptz, updated_at timestamptz, priority integer, target text,
+                         tags text[]) AS $$
+            SELECT COUNT(*) OVER () AS total_count, id, group_state, project_name, created_at,
+                   updated_at, priority, target, tags
+            FROM groups
+            WHERE project_name LIKE (op_origin || '/%')
+            AND (op_state IS NULL OR group_state = op_state)
+            AND (op_created_since IS NULL OR created_at >= op_created_since)
+            ORDER BY created_at DESC
+            LIMIT op_limit
+            OFFSET op_offset
+        $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    // Track when each project in a group started and finished building
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE group_projects ADD COLUMN IF NOT EXISTS build_started_at timestamptz"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE group_projects ADD COLUMN IF NOT EXISTS build_finished_at timestamptz"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+                      r#"CREATE OR REPLACE FUNCTION set_group_project_state_v2 (
+                            pid bigint,
+                            jid bigint,
+                            state text,
+                            p_build_started_at timestamptz,
+                            p_build_finished_at timestamptz
+                         ) RETURNS void AS $$
+                            UPDATE group_projects
+                            SET project_state=state, job_id=jid, build_started_at=p_build_started_at,
+                                build_finished_at=p_build_finished_at, updated_at=now()
+                            WHERE id=pid;
+                      $$ LANGUAGE SQL VOLATILE"#)?;
+
+    migrator.migrate("jobsrv",
+                       r#"CREATE OR REPLACE FUNCTION set_group_project_state_ident_v2 (
+                            pid bigint,
+                            jid bigint,
+                            state text,
+                            ident text,
+                            p_build_started_at timestamptz,
+                            p_build_finished_at timestamptz
+                          ) RETURNS void AS $$
+                            UPDATE group_projects
+                            SET project_state=state, job_id=jid, project_ident=ident,
+                                build_started_at=p_build_started_at,
+                                build_finished_at=p_build_finished_at, updated_at=now()
+                            WHERE id=pid;
+                       $$ LANGUAGE SQL VOLATILE"#)?;
+
+    // Count plans, builds and unique packages in an origin for each target
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_graph_package_target_stats_v1 (
+                              op_origin text
+                            ) RETURNS TABLE (target text, plans bigint, builds bigint, unique_packages bigint)
+                            LANGUAGE SQL
+                            STABLE AS $$
+                            WITH idents AS (
+                              SELECT target, regexp_split_to_array(ident, '/') AS parts
+                              FROM graph_packages
+                            ), plan_counts AS (
+                              SELECT i.target, COUNT(*) AS plans, COUNT(DISTINCT i.parts[2]) AS unique_packages
+                              FROM idents i
+                              WHERE i.parts[1] = op_origin
+                              GROUP BY i.target
+                            ), build_counts AS (
+                              SELECT g.target, COUNT(*) AS builds
+                              FROM group_projects gp
+                              INNER JOIN groups g ON g.id = gp.owner_id
+                              WHERE gp.project_ident ~ ('^' || op_origin || '/')
+                              GROUP BY g.target
+                            )
+                            SELECT COALESCE(p.target, b.target), COALESCE(p.plans, 0),
+                                   COALESCE(b.builds, 0), COALESCE(p.unique_packages, 0)
+                            FROM plan_counts p
+                            FULL OUTER JOIN build_counts b ON p.target = b.target
+                            ORDER BY 1
+                            $$"#,
+    )?;
+
+    // Recurring job groups, created on a cron-style schedule
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS group_schedules_id_seq;"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE TABLE IF NOT EXISTS group_schedules (
+                                id bigint PRIMARY KEY DEFAULT next_id_v1('group_schedules_id_seq'),
+                                origin text NOT NULL,
+                                package text NOT NULL,
+                                target text NOT NULL,
+                                priority integer NOT NULL DEFAULT 0,
+                                tags text[] NOT NULL DEFAULT '{}',
+                                packages text[] NOT NULL DEFAULT '{}',
+                                deps_only boolean NOT NULL DEFAULT false,
+                                origin_only boolean NOT NULL DEFAULT false,
+                                package_only boolean NOT NULL DEFAULT false,
+                                cron text NOT NULL,
+                                last_run_at timestamptz DEFAULT NULL,
+                                next_run_at timestamptz DEFAULT NULL,
+                                created_at timestamptz DEFAULT now(),
+                                updated_at timestamptz DEFAULT now(),
+                                UNIQUE (origin, package, target)
+                         )"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE INDEX IF NOT EXISTS group_schedules_next_run_at_index_v1 ON group_schedules(next_run_at)"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+                     r#"CREATE OR REPLACE FUNCTION upsert_group_schedule_v1 (
+                            p_origin text,
+                            p_package text,
+                            p_target text,
+                            p_priority integer,
+                            p_tags text[],
+                            p_packages text[],
+                            p_deps_only boolean,
+                            p_origin_only boolean,
+                            p_package_only boolean,
+                            p_cron text,
+                            p_next_run_at timestamptz
+                          ) RETURNS SETOF group_schedules AS $$
+                            INSERT INTO group_schedules (origin, package, target, priority, tags, packages, deps_only,
+                                                         origin_only, package_only, cron, next_run_at)
+                            VALUES (p_origin, p_package, p_target, p_priority, p_tags, p_packages, p_deps_only,
+                                    p_origin_only, p_package_only, p_cron, p_next_run_at)
+                            ON CONFLICT (origin, package, target) DO UPDATE
+                            SET priority=p_priority, tags=p_tags, packages=p_packages, deps_only=p_deps_only,
+                                origin_only=p_origin_only, package_only=p_package_only, cron=p_cron,
+                                next_run_at=p_next_run_at, updated_at=now()
+                            RETURNING *;
+                       $$ LANGUAGE SQL VOLATILE"#)?;
+
+    migrator.migrate("jobsrv",
+                     r#"CREATE OR REPLACE FUNCTION get_group_schedules_for_origin_v1 (
+                            p_origin text
+                          ) RETURNS SETOF group_schedules AS $$
+                            SELECT * FROM group_schedules
+                            WHERE origin = p_origin
+                            ORDER BY package, target;
+                       $$ LANGUAGE SQL STABLE"#)?;
+
+    migrator.migrate("jobsrv",
+                     r#"CREATE OR REPLACE FUNCTION delete_group_schedule_v1 (
+                            p_id bigint,
+                            p_origin text
+                          ) RETURNS SETOF group_schedules AS $$
+                            DELETE FROM group_schedules
+                            WHERE id = p_id AND origin = p_origin
+                            RETURNING *;
+                       $$ LANGUAGE SQL VOLATILE"#)?;
+
+    migrator.migrate("jobsrv",
+                     r#"CREATE OR REPLACE FUNCTION get_due_group_schedules_v1 () RETURNS SETOF group_schedules AS $$
+                            SELECT * FROM group_schedules
+                            WHERE next_run_at <= now()
+                            ORDER BY next_run_at;
+                       $$ LANGUAGE SQL STABLE"#)?;
+
+    migrator.migrate("jobsrv",
+                     r#"CREATE OR REPLACE FUNCTION set_group_schedule_run_v1 (
+                            p_id bigint,
+                            p_last_run_at timestamptz,
+                            p_next_run_at timestamptz
+                          ) RETURNS void AS $$
+                            UPDATE group_schedules
+                            SET last_run_at=p_last_run_at, next_run_at=p_next_run_at, updated_at=now()
+                            WHERE id = p_id;
+                       $$ LANGUAGE SQL VOLATILE"#)?;
+
+    // Position of a queued group in the fair share queue, kept up to date by the scheduler
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE groups ADD COLUMN IF NOT EXISTS queue_position integer"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_queued_groups_v2 () RETURNS SETOF groups AS $$
+                  SELECT * FROM groups
+                  WHERE group_state = 'Queued'
+                  ORDER BY created_at ASC
+            $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    migrator.migrate("jobsrv",
+                     r#"CREATE OR REPLACE FUNCTION count_active_groups_by_origin_v1 ()
+                          RETURNS TABLE (origin text, active bigint) AS $$
+                            SELECT split_part(project_name, '/', 1) AS origin, COUNT(*) AS active
+                            FROM groups
+                            WHERE group_state IN ('Pending', 'Dispatching')
+                            GROUP BY 1;
+                       $$ LANGUAGE SQL STABLE"#)?;
+
+    migrator.migrate("jobsrv",
+                     r#"CREATE OR REPLACE FUNCTION set_group_queue_position_v1 (
+                            gid bigint,
+                            position integer
+                          ) RETURNS void AS $$
+                            UPDATE groups SET queue_position=position, updated_at=now() WHERE id=gid;
+                       $$ LANGUAGE SQL VOLATILE"#)?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_job_groups_for_origin_v4 (
+            op_origin text,
+            op_state text,
+            op_created_since timestamptz,
+            op_limit bigint,
+            op_offset bigint
+        ) RETURNS TABLE (total_count bigint, id bigint, group_state text, project_name text,
+                         created_at timestamptz, updated_at timestamptz, priority integer, target text,
+                         tags text[], queue_position integer) AS $$
+            SELECT COUNT(*) OVER () AS total_count, id, group_state, project_name, created_at,
+                   updated_at, priority, target, tags, queue_position
+            FROM groups
+            WHERE project_name LIKE (op_origin || '/%')
+            AND (op_state IS NULL OR group_state = op_state)
+            AND (op_created_since IS NULL OR created_at >= op_created_since)
+            ORDER BY created_at DESC
+            LIMIT op_limit
+            OFFSET op_offset
+        $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    // Every persisted package gets a new revision, so a saved graph knows which packages it's
+    // missing
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS graph_packages_revision_seq"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE graph_packages ADD COLUMN IF NOT EXISTS revision bigint DEFAULT nextval('graph_packages_revision_seq') NOT NULL"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE INDEX IF NOT EXISTS graph_packages_revision_index_v1 ON graph_packages(revision)"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION upsert_graph_package_v2 (
+                                in_ident text,
+                                in_deps text[],
+                                in_target text
+                            ) RETURNS SETOF graph_packages AS $$
+                                INSERT INTO graph_packages (ident, deps, target)
+                                VALUES (in_ident, in_deps, in_target)
+                                ON CONFLICT(ident)
+                                DO UPDATE SET deps=in_deps, target=in_target,
+                                              revision=nextval('graph_packages_revision_seq')
+                                RETURNING *;
+                            $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_graph_packages_since_v1 (
+                                p_revision bigint
+                            ) RETURNS SETOF graph_packages AS $$
+                                SELECT * FROM graph_packages
+                                WHERE revision > p_revision
+                                ORDER BY revision;
+                            $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_graph_packages_revision_v1 () RETURNS bigint AS $$
+                                SELECT COALESCE(MAX(revision), 0) FROM graph_packages;
+                            $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION count_unfinished_groups_by_state_v1 ()
+                            RETURNS TABLE (group_state text, groups bigint) AS $$
+                                SELECT group_state, COUNT(*) FROM groups
+                                WHERE group_state IN ('Queued', 'Pending', 'Dispatching')
+                                GROUP BY group_state;
+                            $$ LANGUAGE SQL STABLE"#,
+    )?;
+
+    // Put a finished group's failed and skipped projects back to be dispatched again, and queue
+    // the group. Returns the number of projects to be built again.
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION retry_group_v1(in_gid bigint) RETURNS bigint AS $$
+            DECLARE
+                retried bigint;
+            BEGIN
+                UPDATE group_projects
+                    SET project_state='NotStarted', job_id=0, build_started_at=NULL,
+                        build_finished_at=NULL, updated_at=now()
+                    WHERE owner_id = in_gid
+                    AND project_state IN ('Failure', 'Skipped');
+                GET DIAGNOSTICS retried = ROW_COUNT;
+                UPDATE groups SET group_state='Queued', updated_at=now() WHERE id = in_gid;
+                RETURN retried;
+            END
+        $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+
+    // Projects whose plan can't be reached are skipped before they're dispatched, recording why
+    migrator.migrate(
+        "jobsrv",
+        r#"ALTER TABLE group_projects ADD COLUMN IF NOT EXISTS skip_reason text"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION skip_group_project_v1(in_gid bigint, in_pname text, in_reason text) RETURNS void AS $$
+                UPDATE group_projects SET project_state='Skipped', skip_reason=in_reason, updated_at=now()
+                WHERE owner_id = in_gid AND project_name = in_pname;
+        $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION retry_group_v2(in_gid bigint) RETURNS bigint AS $$
+            DECLARE
+                retried bigint;
+            BEGIN
+                UPDATE group_projects
+                    SET project_state='NotStarted', job_id=0, build_started_at=NULL,
+                        build_finished_at=NULL, skip_reason=NULL, updated_at=now()
+                    WHERE owner_id = in_gid
+                    AND project_state IN ('Failure', 'Skipped');
+                GET DIAGNOSTICS retried = ROW_COUNT;
+                UPDATE groups SET group_state='Queued', updated_at=now() WHERE id = in_gid;
+                RETURN retried;
+            END
+        $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+
+    migrator.migrate(
+        "jobsrv",
+        r#"CREATE OR REPLACE FUNCTION get_job_groups_for_origin_v5 (
+            op_origin text,
+            op_state text,
+            op_created_since timestamptz,
+            op_created_before timestamptz,
+            op_limit bigint,
+            op_offset bigint
+        ) RETURNS TABLE (total_count bigint, id bigint, group_state text, project_name text,
+                         created_at timestamptz, updated_at timestamptz, priority integer, target text,
+                         tags text[], queue_position integer) AS $$
+            SELECT COUNT(*) OVER () AS total_count, id, group_state, project_name, created_at,
+                   updated_at, priority, target, tags, queue_position
+            FROM groups
+            WHERE project_name LIKE (op_origin || '/%')
+            AND (op_state IS NULL OR group_state = op_state)
+            AND (op_created_since IS NULL OR created_at >= op_created_since)
+            AND (op_created_before IS NULL OR created_at <= op_created_before)
+            ORDER BY created_at DESC
+            LIMIT op_limit
+            OFFSET op_offset
+        $$ LANGUAGE SQL STABLE"#,
+    )?;
+
     Ok(())
 }
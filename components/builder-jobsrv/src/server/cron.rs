@@ -0,0 +1,118 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Creates job groups for the group schedules which are due to run.
+
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use bldr_core::cron::Schedule;
+use chrono::UTC;
+use hab_net::conn::RouteClient;
+use protocol::jobsrv;
+
+use data_store::DataStore;
+use error::Result;
+
+// Schedules have a resolution of one minute, so check a couple of times a minute
+const CRON_POLL_MS: u64 = 30_000;
+
+pub struct CronMgr {
+    datastore: DataStore,
+    route_conn: RouteClient,
+}
+
+impl CronMgr {
+    pub fn new(datastore: DataStore, router_pipe: Arc<String>) -> Result<Self> {
+        let route_conn = RouteClient::new()?;
+        route_conn.connect(&*router_pipe)?;
+
+        Ok(CronMgr {
+            datastore: datastore,
+            route_conn: route_conn,
+        })
+    }
+
+    pub fn start(datastore: DataStore, router_pipe: Arc<String>) -> Result<JoinHandle<()>> {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let mut cron_mgr = Self::new(datastore, router_pipe)?;
+        let handle = thread::Builder::new()
+            .name("cron".to_string())
+            .spawn(move || { cron_mgr.run(tx).unwrap(); })
+            .unwrap();
+        match rx.recv() {
+            Ok(()) => Ok(handle),
+            Err(e) => panic!("cron thread startup error, err={}", e),
+        }
+    }
+
+    fn run(&mut self, rz: mpsc::SyncSender<()>) -> Result<()> {
+        rz.send(()).unwrap();
+        loop {
+            if let Err(err) = self.process_schedules() {
+                warn!("Cron unable to process schedules: err {:?}", err);
+            }
+            thread::sleep(Duration::from_millis(CRON_POLL_MS));
+        }
+    }
+
+    fn process_schedules(&mut self) -> Result<()> {
+        let now = UTC::now();
+
+        for schedule in self.datastore.get_due_job_group_schedules()? {
+            // Move the schedule on before creating its group, so that a group which can't be
+            // created doesn't get retried on every poll
+            let next_run_at = match schedule.get_cron().parse::<Schedule>() {
+                Ok(cron) => cron.next_after(&now),
+                Err(err) => {
+                    warn!("Disabling group schedule {}, {}", schedule.get_id(), err);
+                    None
+                }
+            };
+            self.datastore.set_job_group_schedule_run(
+                schedule.get_id(),
+                &now,
+                next_run_at,
+            )?;
+
+            match self.route_conn.route_either::<jobsrv::JobGroupSpec,
+                                                 jobsrv::JobGroup,
+                                                 jobsrv::JobGroupCreateError>(
+                schedule.get_spec(),
+            ) {
+                Ok(Ok(group)) => {
+                    debug!(
+                        "Group schedule {} created job group {} for {}",
+                        schedule.get_id(),
+                        group.get_id(),
+                        group.get_project_name()
+                    );
+                }
+                Ok(Err(err)) => {
+                    warn!("Group schedule {} unable to run, {}", schedule.get_id(), err);
+                }
+                Err(err) => {
+                    warn!(
+                        "Group schedule {} unable to create a job group, err {:?}",
+                        schedule.get_id(),
+                        err
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
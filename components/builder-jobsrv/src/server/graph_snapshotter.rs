@@ -0,0 +1,101 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Periodically saves the package graph to disk, so that a restarted JobSrv only has to add the
+//! packages persisted since the last save instead of building the whole graph again.
+
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc, RwLock};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use bldr_core::target_graph::TargetGraph;
+use time::PreciseTime;
+
+use error::Result;
+
+pub struct GraphSnapshotter {
+    graph: Arc<RwLock<TargetGraph>>,
+    interval: Duration,
+    path: PathBuf,
+    // Revision of the last saved snapshot, `None` until the first save
+    saved_revision: Option<u64>,
+}
+
+impl GraphSnapshotter {
+    pub fn new(graph: Arc<RwLock<TargetGraph>>, path: PathBuf, interval_secs: u64) -> Self {
+        GraphSnapshotter {
+            graph: graph,
+            interval: Duration::from_secs(interval_secs),
+            path: path,
+            saved_revision: None,
+        }
+    }
+
+    pub fn start(
+        graph: Arc<RwLock<TargetGraph>>,
+        path: PathBuf,
+        interval_secs: u64,
+    ) -> Result<JoinHandle<()>> {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let mut snapshotter = Self::new(graph, path, interval_secs);
+        let handle = thread::Builder::new()
+            .name("graph-snapshotter".to_string())
+            .spawn(move || { snapshotter.run(tx).unwrap(); })
+            .unwrap();
+        match rx.recv() {
+            Ok(()) => Ok(handle),
+            Err(e) => panic!("graph-snapshotter thread startup error, err={}", e),
+        }
+    }
+
+    fn run(&mut self, rz: mpsc::SyncSender<()>) -> Result<()> {
+        rz.send(()).unwrap();
+        loop {
+            self.save();
+            thread::sleep(self.interval);
+        }
+    }
+
+    fn save(&mut self) {
+        // Take the snapshot under the lock, but write it out without holding up the handlers
+        let snapshot = {
+            let graph = self.graph.read().unwrap();
+            if self.saved_revision == Some(graph.revision()) {
+                return;
+            }
+            graph.snapshot()
+        };
+
+        let start_time = PreciseTime::now();
+        match snapshot.save(&self.path) {
+            Ok(()) => {
+                debug!(
+                    "Saved graph snapshot at revision {} to {} ({} sec)",
+                    snapshot.revision(),
+                    self.path.display(),
+                    start_time.to(PreciseTime::now())
+                );
+                self.saved_revision = Some(snapshot.revision());
+            }
+            Err(err) => {
+                warn!(
+                    "Unable to save graph snapshot to {}, err: {}",
+                    self.path.display(),
+                    err
+                );
+            }
+        }
+    }
+}
@@ -14,23 +14,34 @@
 
 //! A collection of handlers for the JobSrv dispatcher
 
+use std::collections::HashSet;
 use std::fs::OpenOptions;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
 
+use bldr_core;
+use bldr_core::cron::Schedule;
+use chrono::UTC;
 use hab_net::app::prelude::*;
 use protobuf::RepeatedField;
 use protocol::jobsrv;
 use protocol::net::{self, ErrCode};
 
 use super::ServerState;
+use super::pruner;
+use data_store::Audit;
 use error::{Error, Result};
 use time::PreciseTime;
 
 pub fn job_create(req: &mut Message, conn: &mut RouteConn, state: &mut ServerState) -> Result<()> {
     let msg = req.parse::<jobsrv::JobSpec>()?;
     let mut job: jobsrv::Job = msg.into();
-    let created_job = state.datastore.create_job(&mut job)?;
+    job.set_max_retries(state.job_max_retries);
+    job.set_ttl(state.job_ttl);
+    let created_job = state.datastore.create_job(
+        &mut job,
+        &Audit::new(jobsrv::JobAuditTrigger::User),
+    )?;
     debug!(
         "Job created: id={} owner_id={} state={:?}",
         created_job.get_id(),
@@ -80,6 +91,23 @@ pub fn project_jobs_get(
     Ok(())
 }
 
+pub fn jobs_by_tag_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobsByTagGet>()?;
+    match state.datastore.get_jobs_by_tag(&msg) {
+        Ok(ref jobs) => conn.route_reply(req, jobs)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:jobs-by-tag-get:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn job_log_get(req: &mut Message, conn: &mut RouteConn, state: &mut ServerState) -> Result<()> {
     let msg = req.parse::<jobsrv::JobLogGet>()?;
     let mut get = jobsrv::JobGet::new();
@@ -99,10 +127,63 @@ pub fn job_log_get(req: &mut Message, conn: &mut RouteConn, state: &mut ServerSt
         }
     };
 
+    reply_job_log(req, conn, state, &job, msg.get_start())
+}
+
+/// Replies at once with the log output from the `start` line on, which is the catch-up read of a
+/// subscriber following the job's log. The output after it is published on the log stream, so
+/// there's no need to hold the subscriber here until there is new output.
+pub fn job_log_subscribe(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobLogSubscribe>()?;
+    let mut get = jobsrv::JobGet::new();
+    get.set_id(msg.get_job_id());
+
+    let job = match state.datastore.get_job(&get) {
+        Ok(Some(job)) => job,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "jb:job-log-subscribe:1");
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-log-subscribe:2");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+
+    reply_job_log(req, conn, state, &job, msg.get_start())
+}
+
+/// Replies with the given job's log output from the `start` line on, reading it from the archive
+/// if the job's log has been archived and from the log directory otherwise. Archived logs are
+/// still read from the log directory while it keeps a local copy of them.
+fn reply_job_log(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+    job: &jobsrv::Job,
+    start: u64,
+) -> Result<()> {
     if job.get_is_archived() {
-        match state.archiver.retrieve(job.get_id()) {
+        let file = state.log_dir.log_file_path(job.get_id());
+        let local = if file.is_file() {
+            get_log_content(&file, 0)
+        } else {
+            None
+        };
+        let retrieved = match local {
+            Some(lines) => Ok(lines),
+            None => state.archiver.retrieve(job.get_id()),
+        };
+
+        match retrieved {
             Ok(lines) => {
-                let start = msg.get_start();
                 let num_lines = lines.len() as u64;
                 let segment;
 
@@ -138,8 +219,7 @@ pub fn job_log_get(req: &mut Message, conn: &mut RouteConn, state: &mut ServerSt
         }
     } else {
         // retrieve fragment from on-disk file
-        let start = msg.get_start();
-        let file = state.log_dir.log_file_path(msg.get_id());
+        let file = state.log_dir.log_file_path(job.get_id());
 
         match get_log_content(&file, start) {
             Some(content) => {
@@ -193,7 +273,10 @@ pub fn job_group_abort(
     let msg = req.parse::<jobsrv::JobGroupAbort>()?;
     debug!("job_group_abort message: {:?}", msg);
 
-    match state.datastore.abort_job_group(&msg) {
+    match state.datastore.abort_job_group(
+        &msg,
+        &Audit::new(jobsrv::JobAuditTrigger::User),
+    ) {
         Ok(()) => {
             warn!("Job Group {} aborted", msg.get_group_id());
             conn.route_reply(req, &net::NetOk::new())?
@@ -250,7 +333,8 @@ pub fn job_group_cancel(
     // Set the Group and NotStarted projects to Cancelled
     // TODO (SA): Make the state change code below a single DB call
 
-    state.datastore.cancel_job_group(group.get_id())?;
+    let audit = Audit::user(msg.get_requester_id(), msg.get_requester_name());
+    state.datastore.cancel_job_group(group.get_id(), &audit)?;
 
     // Set all the InProgress projects jobs to CancelPending
     for project in group.get_projects().iter().filter(|&ref p| {
@@ -265,7 +349,7 @@ pub fn job_group_cancel(
             Some(mut job) => {
                 debug!("Canceling job {:?}", job_id);
                 job.set_state(jobsrv::JobState::CancelPending);
-                state.datastore.update_job(&job)?;
+                state.datastore.update_job(&job, &audit)?;
             }
             None => {
                 warn!(
@@ -281,6 +365,218 @@ pub fn job_group_cancel(
     Ok(())
 }
 
+pub fn job_group_retry(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobGroupRetry>()?;
+    debug!("job_group_retry message: {:?}", msg);
+
+    let mut jgg = jobsrv::JobGroupGet::new();
+    jgg.set_group_id(msg.get_group_id());
+
+    let group = match state.datastore.get_job_group(&jgg) {
+        Ok(Some(group)) => group,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "jb:job-group-retry:1");
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-group-retry:2");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+
+    // Only a finished group can be retried, and only if it has something to retry
+    match group.get_state() {
+        jobsrv::JobGroupState::GroupComplete |
+        jobsrv::JobGroupState::GroupFailed => (),
+        _ => {
+            let err = NetError::new(ErrCode::ENTITY_CONFLICT, "jb:job-group-retry:3");
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    }
+    if !group.get_projects().iter().any(|p| {
+        p.get_state() == jobsrv::JobGroupProjectState::Failure
+    })
+    {
+        let err = NetError::new(ErrCode::ENTITY_CONFLICT, "jb:job-group-retry:4");
+        conn.route_reply(req, &*err)?;
+        return Ok(());
+    }
+
+    let audit = Audit::user(msg.get_requester_id(), msg.get_requester_name());
+    match state.datastore.retry_job_group(group.get_id(), &audit) {
+        Ok(retried) => {
+            debug!(
+                "Retrying {} projects of job group {}",
+                retried,
+                group.get_id()
+            );
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-group-retry:5");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    }
+    state.schedule_cli.notify()?;
+
+    match state.datastore.get_job_group(&jgg) {
+        Ok(Some(ref group)) => conn.route_reply(req, group)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "jb:job-group-retry:6");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-group-retry:7");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn job_cancel(req: &mut Message, conn: &mut RouteConn, state: &mut ServerState) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobCancel>()?;
+    debug!("job_cancel message: {:?}", msg);
+
+    let mut jg = jobsrv::JobGet::new();
+    jg.set_id(msg.get_job_id());
+
+    let mut job = match state.datastore.get_job(&jg) {
+        Ok(Some(job)) => job,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "jb:job-cancel:1");
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+        Err(err) => {
+            warn!(
+                "Failed to get job {} from datastore: {:?}",
+                msg.get_job_id(),
+                err
+            );
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-cancel:2");
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+
+    // Only jobs which have not yet reached a terminal state can be canceled. Pending jobs are
+    // moved to CancelPending as well so the worker manager completes the cancelation without
+    // ever dispatching them.
+    match job.get_state() {
+        jobsrv::JobState::Pending |
+        jobsrv::JobState::Dispatched |
+        jobsrv::JobState::Processing => {
+            debug!("Canceling job {:?}", job.get_id());
+            job.set_state(jobsrv::JobState::CancelPending);
+            state.datastore.update_job(
+                &job,
+                &Audit::user(msg.get_requester_id(), msg.get_requester_name()),
+            )?;
+        }
+        _ => {
+            let err = NetError::new(ErrCode::ENTITY_CONFLICT, "jb:job-cancel:3");
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    }
+
+    state.worker_mgr.notify_work()?;
+    conn.route_reply(req, &job)?;
+    Ok(())
+}
+
+pub fn worker_list_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    req.parse::<jobsrv::WorkerListGet>()?;
+    let workers = state.workers.read().unwrap().clone();
+    conn.route_reply(req, &workers)?;
+    Ok(())
+}
+
+pub fn job_group_schedule_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobGroupScheduleCreate>()?;
+    let next_run_at = match msg.get_cron().parse::<Schedule>() {
+        Ok(schedule) => schedule.next_after(&UTC::now()),
+        Err(err) => {
+            warn!("JobGroupScheduleCreate, {}", err);
+            None
+        }
+    };
+    let next_run_at = match next_run_at {
+        Some(next_run_at) => next_run_at,
+        None => {
+            let err = NetError::new(ErrCode::BUG, "jb:job-group-schedule-create:1");
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+
+    match state.datastore.upsert_job_group_schedule(&msg, &next_run_at) {
+        Ok(schedule) => conn.route_reply(req, &schedule)?,
+        Err(e) => {
+            warn!("job_group_schedule_create error: {:?}", e);
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-group-schedule-create:2");
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn job_group_schedule_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobGroupScheduleListGet>()?;
+    match state.datastore.get_job_group_schedules(&msg) {
+        Ok(response) => conn.route_reply(req, &response)?,
+        Err(e) => {
+            warn!("job_group_schedule_list error: {:?}", e);
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-group-schedule-list:1");
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn job_group_schedule_delete(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobGroupScheduleDelete>()?;
+    match state.datastore.delete_job_group_schedule(&msg) {
+        Ok(true) => conn.route_reply(req, &net::NetOk::new())?,
+        Ok(false) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "jb:job-group-schedule-delete:1");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            warn!("job_group_schedule_delete error: {:?}", e);
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-group-schedule-delete:2");
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn job_group_create(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -290,57 +586,187 @@ pub fn job_group_create(
     debug!("job_group_create message: {:?}", msg);
 
     let project_name = format!("{}/{}", msg.get_origin(), msg.get_package());
-    let mut projects = Vec::new();
+    let root_names = group_root_names(&msg);
 
-    // Get the ident for the root package
-    let mut start_time;
-    let mut end_time;
+    if state.graph.read().unwrap().graph(msg.get_target()).is_none() {
+        warn!(
+            "JobGroupSpec, no graph found for target {}",
+            msg.get_target()
+        );
+        let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "jb:job-group-create:2");
+        conn.route_reply(req, &*err)?;
+        return Ok(());
+    }
 
-    let project_ident = {
-        let mut target_graph = state.graph.write().unwrap();
-        let graph = match target_graph.graph_mut(msg.get_target()) {
-            Some(g) => g,
-            None => {
-                warn!(
-                    "JobGroupSpec, no graph found for target {}",
-                    msg.get_target()
-                );
-                let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "jb:job-group-create:2");
-                conn.route_reply(req, &*err)?;
-                return Ok(());
+    let projects = match group_projects(&msg, &root_names, state) {
+        Ok(projects) => projects,
+        Err((root_name, bldr_core::error::Error::DependencyCycle(cycle))) => {
+            let mut reply = jobsrv::JobGroupCreateError::new();
+            reply.set_project_name(root_name);
+            reply.set_cycle(RepeatedField::from_vec(cycle));
+            warn!("JobGroupSpec, {}", reply);
+            conn.route_reply(req, &reply)?;
+            return Ok(());
+        }
+        Err((_, e)) => {
+            let err = NetError::new(ErrCode::BUG, "jb:job-group-create:3");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+
+    let group = if projects.is_empty() {
+        debug!("No projects need building - group is complete");
+
+        let mut new_group = jobsrv::JobGroup::new();
+        let projects = RepeatedField::new();
+        new_group.set_id(0);
+        new_group.set_state(jobsrv::JobGroupState::GroupComplete);
+        new_group.set_projects(projects);
+        new_group
+    } else {
+        // If already have a queued job group (queue length: 1 per project),
+        // then return that group, else create a new job group. Batched groups
+        // span several roots, so they are always created fresh.
+        // TODO (SA) - update the group's projects instead of just returning the group
+        let queued = if root_names.len() == 1 {
+            state.datastore.get_queued_job_group(&project_name)?
+        } else {
+            None
+        };
+        let new_group = match queued {
+            Some(group) => {
+                debug!("JobGroupSpec, project {} is already queued", project_name);
+                group
             }
+            None => state.datastore.create_job_group(&msg, projects)?,
         };
+        state.schedule_cli.notify()?;
+        new_group
+    };
 
-        start_time = PreciseTime::now();
-        let ret = match graph.resolve(&project_name) {
-            Some(s) => s,
-            None => {
-                warn!("JobGroupSpec, project ident not found for {}", project_name);
-                // If a package has never been uploaded, we won't see it in the graph
-                // Carry on with stiff upper lip
-                String::from("")
+    conn.route_reply(req, &group)?;
+    Ok(())
+}
+
+pub fn job_group_preview(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobGroupPreview>()?;
+    debug!("job_group_preview message: {:?}", msg);
+
+    let spec = msg.get_spec();
+    if state.graph.read().unwrap().graph(spec.get_target()).is_none() {
+        warn!(
+            "JobGroupPreview, no graph found for target {}",
+            spec.get_target()
+        );
+        let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "jb:job-group-preview:1");
+        conn.route_reply(req, &*err)?;
+        return Ok(());
+    }
+
+    let projects = match group_projects(spec, &group_root_names(spec), state) {
+        Ok(projects) => projects,
+        Err((root_name, bldr_core::error::Error::DependencyCycle(cycle))) => {
+            let mut reply = jobsrv::JobGroupCreateError::new();
+            reply.set_project_name(root_name);
+            reply.set_cycle(RepeatedField::from_vec(cycle));
+            warn!("JobGroupPreview, {}", reply);
+            conn.route_reply(req, &reply)?;
+            return Ok(());
+        }
+        Err((_, e)) => {
+            let err = NetError::new(ErrCode::BUG, "jb:job-group-preview:2");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+
+    let mut reply = jobsrv::JobGroupPreviewResponse::new();
+    for (name, ident) in projects {
+        let mut project = jobsrv::JobGroupPreviewProject::new();
+        project.set_name(name);
+        project.set_ident(ident);
+        reply.mut_projects().push(project);
+    }
+    conn.route_reply(req, &reply)?;
+    Ok(())
+}
+
+// Returns the names of the root packages of a JobGroupSpec, the spec's package first
+fn group_root_names(msg: &jobsrv::JobGroupSpec) -> Vec<String> {
+    let mut root_names = vec![format!("{}/{}", msg.get_origin(), msg.get_package())];
+    for package in msg.get_packages() {
+        let name = format!("{}/{}", msg.get_origin(), package);
+        if !root_names.contains(&name) {
+            root_names.push(name);
+        }
+    }
+    root_names
+}
+
+// Returns the (name, ident) tuples that need building for a JobGroupSpec. The projects for every
+// root are merged into a single list, so that packages depending on more than one of the roots
+// are only built once. Errors are returned along with the root they were found from.
+fn group_projects(
+    msg: &jobsrv::JobGroupSpec,
+    root_names: &[String],
+    state: &ServerState,
+) -> ::std::result::Result<Vec<(String, String)>, (String, bldr_core::error::Error)> {
+    let mut projects = Vec::new();
+    let mut seen = HashSet::new();
+    for root_name in root_names.iter() {
+        let root_projects = group_projects_for_root(msg, root_name, state)
+            .map_err(|e| (root_name.clone(), e))?;
+        for project in root_projects {
+            if seen.insert(project.0.clone()) {
+                projects.push(project);
             }
-        };
-        end_time = PreciseTime::now();
-        ret
+        }
+    }
+    Ok(projects)
+}
+
+// Returns the (name, ident) tuples that need building for a single root
+// package of a JobGroupSpec: the root itself and/or its reverse dependencies
+fn group_projects_for_root(
+    msg: &jobsrv::JobGroupSpec,
+    root_name: &str,
+    state: &ServerState,
+) -> bldr_core::error::Result<Vec<(String, String)>> {
+    let mut projects = Vec::new();
+    let target_graph = state.graph.read().unwrap();
+    let graph = target_graph.graph(msg.get_target()).unwrap(); // Unwrap OK
+
+    // Get the ident for the root package
+    let mut start_time = PreciseTime::now();
+    let project_ident = match graph.resolve(root_name) {
+        Some(s) => s,
+        None => {
+            warn!("JobGroupSpec, project ident not found for {}", root_name);
+            // If a package has never been uploaded, we won't see it in the graph
+            // Carry on with stiff upper lip
+            String::from("")
+        }
     };
+    let mut end_time = PreciseTime::now();
     debug!("Resolved project name: {} sec\n", start_time.to(end_time));
 
     // Add the root package if needed
     if !msg.get_deps_only() || msg.get_package_only() {
-        projects.push((project_name.clone(), project_ident.clone()));
+        projects.push((root_name.to_string(), project_ident));
     }
 
     // Search the packages graph to find the reverse dependencies
     if !msg.get_package_only() {
-        let rdeps_opt = {
-            let target_graph = state.graph.read().unwrap();
-            let graph = target_graph.graph(msg.get_target()).unwrap(); // Unwrap OK
-            start_time = PreciseTime::now();
-            let ret = graph.rdeps(&project_name);
-            end_time = PreciseTime::now();
-            ret
-        };
+        start_time = PreciseTime::now();
+        let rdeps_opt = graph.rdeps(root_name)?;
+        end_time = PreciseTime::now();
 
         match rdeps_opt {
             Some(rdeps) => {
@@ -368,32 +794,7 @@ pub fn job_group_create(
         }
     }
 
-    let group = if projects.is_empty() {
-        debug!("No projects need building - group is complete");
-
-        let mut new_group = jobsrv::JobGroup::new();
-        let projects = RepeatedField::new();
-        new_group.set_id(0);
-        new_group.set_state(jobsrv::JobGroupState::GroupComplete);
-        new_group.set_projects(projects);
-        new_group
-    } else {
-        // If already have a queued job group (queue length: 1 per project),
-        // then return that group, else create a new job group
-        // TODO (SA) - update the group's projects instead of just returning the group
-        let new_group = match state.datastore.get_queued_job_group(&project_name)? {
-            Some(group) => {
-                debug!("JobGroupSpec, project {} is already queued", project_name);
-                group
-            }
-            None => state.datastore.create_job_group(&msg, projects)?,
-        };
-        state.schedule_cli.notify()?;
-        new_group
-    };
-
-    conn.route_reply(req, &group)?;
-    Ok(())
+    Ok(projects)
 }
 
 pub fn job_graph_package_reverse_dependencies_get(
@@ -419,7 +820,15 @@ pub fn job_graph_package_reverse_dependencies_get(
         }
     };
 
-    let rdeps = graph.rdeps(&ident);
+    let rdeps = match graph.rdeps(&ident) {
+        Ok(rdeps) => rdeps,
+        Err(e) => {
+            let err = NetError::new(ErrCode::ENTITY_CONFLICT, "jb:reverse-dependencies-get:2");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
     let mut rd_reply = jobsrv::JobGraphPackageReverseDependencies::new();
     rd_reply.set_origin(msg.get_origin().to_string());
     rd_reply.set_name(msg.get_name().to_string());
@@ -463,6 +872,31 @@ pub fn job_group_origin_get(
     Ok(())
 }
 
+pub fn origin_build_usage_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::OriginBuildUsageGet>()?;
+
+    match state.datastore.get_origin_build_usage(Some(msg.get_origin())) {
+        Ok(usages) => {
+            let mut usage = match usages.into_iter().next() {
+                Some(usage) => usage,
+                None => state.quotas.unused(msg.get_origin()),
+            };
+            state.quotas.apply(&mut usage);
+            conn.route_reply(req, &usage)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:origin-build-usage-get:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn job_group_get(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -495,6 +929,60 @@ pub fn job_group_get(
     Ok(())
 }
 
+pub fn job_audit_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobAuditGet>()?;
+    debug!("job_audit_get message: {:?}", msg);
+
+    match state.datastore.get_job_audit(&msg) {
+        Ok(ref audits) => conn.route_reply(req, audits)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-audit-get:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn job_history_prune(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> Result<()> {
+    let msg = req.parse::<jobsrv::JobHistoryPrune>()?;
+    debug!("job_history_prune message: {:?}", msg);
+
+    let retention_days = if msg.get_retention_days() > 0 {
+        msg.get_retention_days() as u64
+    } else {
+        state.job_retention_days
+    };
+    if retention_days == 0 {
+        let err = NetError::new(ErrCode::REMOTE_REJECTED, "jb:job-history-prune:1");
+        conn.route_reply(req, &*err)?;
+        return Ok(());
+    }
+
+    match pruner::prune(
+        &state.datastore,
+        &*state.archiver,
+        &state.log_dir,
+        retention_days,
+    ) {
+        Ok(ref pruned) => conn.route_reply(req, pruned)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "jb:job-history-prune:2");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn job_graph_package_create(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -502,11 +990,14 @@ pub fn job_graph_package_create(
 ) -> Result<()> {
     let msg = req.parse::<jobsrv::JobGraphPackageCreate>()?;
     debug!("job_graph_package_create message: {:?}", msg);
-    let package = state.datastore.create_job_graph_package(&msg)?;
 
-    // Extend the graph with new package
-    {
+    // Hold the graph lock while persisting the package, so the graph is extended in the same
+    // order as package revisions are handed out
+    let package = {
         let mut target_graph = state.graph.write().unwrap();
+        let package = state.datastore.create_job_graph_package(&msg)?;
+        target_graph.set_revision(package.get_revision());
+
         let graph = match target_graph.graph_mut(msg.get_target()) {
             Some(g) => g,
             None => {
@@ -520,6 +1011,7 @@ pub fn job_graph_package_create(
             }
         };
 
+        // Extend the graph with new package
         let start_time = PreciseTime::now();
         let (ncount, ecount) = graph.extend(&package);
         let end_time = PreciseTime::now();
@@ -530,6 +1022,8 @@ pub fn job_graph_package_create(
             ecount,
             start_time.to(end_time)
         );
+
+        package
     };
 
     conn.route_reply(req, &package)?;
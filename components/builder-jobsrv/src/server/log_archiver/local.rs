@@ -21,16 +21,20 @@
 //! `/archive/97/6e/48/3c/722477594578067456.log`, where `/archive` is
 //! the root of the archive on the filesystem. This is the same
 //! approach taken by Chef's `bookshelf` cookbook storage engine.
+//!
+//! Logs are stored gzip compressed next to that path, e.g.
+//! `722477594578067456.log.gz`. Logs archived before compression was
+//! introduced are found at the uncompressed path.
 
 use config::ArchiveCfg;
 use error::Result;
 use server::log_directory::LogDirectory;
 use sha2::{Sha256, Digest};
-use std::fs::{self, OpenOptions};
-use std::io::Read;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write};
 use std::path::PathBuf;
 
-use super::LogArchiver;
+use super::{compress, decode_lines, LogArchiver};
 
 /// Wraps a `PathBuf` representing the root of a local job log archive.
 pub struct LocalArchiver(PathBuf);
@@ -63,27 +67,49 @@ impl LocalArchiver {
 
         new_path
     }
+    /// Generate the path that a given job's compressed log will be
+    /// stored at.
+    pub fn compressed_archive_path(&self, job_id: u64) -> PathBuf {
+        self.archive_path(job_id).with_extension("log.gz")
+    }
 }
 
 impl LogArchiver for LocalArchiver {
     fn archive(&self, job_id: u64, file_path: &PathBuf) -> Result<()> {
-        let archive_path = self.archive_path(job_id);
+        let archive_path = self.compressed_archive_path(job_id);
         let parent_dir = &archive_path.parent().unwrap();
         fs::create_dir_all(parent_dir)?;
-        fs::copy(file_path, &archive_path)?;
+        let content = compress(file_path)?;
+        File::create(&archive_path)?.write_all(&content)?;
         Ok(())
     }
 
     fn retrieve(&self, job_id: u64) -> Result<Vec<String>> {
-        let log_file = self.archive_path(job_id);
         let mut buffer = Vec::new();
-        let mut file = OpenOptions::new().read(true).open(&log_file)?;
+        let mut file = match OpenOptions::new().read(true).open(
+            self.compressed_archive_path(job_id),
+        ) {
+            Ok(file) => file,
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => {
+                OpenOptions::new().read(true).open(
+                    self.archive_path(job_id),
+                )?
+            }
+            Err(e) => return Err(e.into()),
+        };
         file.read_to_end(&mut buffer)?;
-        let lines = String::from_utf8_lossy(buffer.as_slice())
-            .lines()
-            .map(|l| l.to_string())
-            .collect();
-        Ok(lines)
+        decode_lines(buffer.as_slice())
+    }
+
+    fn delete(&self, job_id: u64) -> Result<()> {
+        for path in &[self.compressed_archive_path(job_id), self.archive_path(job_id)] {
+            match fs::remove_file(path) {
+                Ok(()) => (),
+                Err(ref e) if e.kind() == io::ErrorKind::NotFound => (),
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(())
     }
 }
 
@@ -99,4 +125,12 @@ mod tests {
         let actual_path = archiver.archive_path(job_id);
         assert_eq!(actual_path, expected_path);
     }
+
+    #[test]
+    fn local_compressed_archive_path() {
+        let archiver = LocalArchiver(PathBuf::from("/archive"));
+        let job_id: u64 = 722543779847979008;
+        let expected_path = PathBuf::from("/archive/0a/6b/ef/ac/722543779847979008.log.gz");
+        assert_eq!(archiver.compressed_archive_path(job_id), expected_path);
+    }
 }
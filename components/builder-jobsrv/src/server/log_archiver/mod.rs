@@ -19,14 +19,26 @@
 //! job server. Once they are complete, however, we would like to
 //! store them elsewhere for safety; the job server should be
 //! stateless.
+//!
+//! Logs are gzip compressed before they're archived. Logs archived
+//! before compression was introduced are stored as plain text, and
+//! are still read back transparently.
 
 pub mod local;
 pub mod s3;
 
 use config::ArchiveCfg;
 use error::Result;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use std::fs::File;
+use std::io::{self, Read};
 use std::path::PathBuf;
 
+/// The first two bytes of any gzip stream
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
 /// Currently implemented log archiving backends
 #[derive(Clone, Debug, Deserialize, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -43,6 +55,11 @@ pub trait LogArchiver: Send {
     /// Given a `job_id`, retrieves the log output for that job from
     /// long-term storage.
     fn retrieve(&self, job_id: u64) -> Result<Vec<String>>;
+
+    /// Given a `job_id`, removes the log output for that job from
+    /// long-term storage. Removing a log which isn't archived is not
+    /// an error.
+    fn delete(&self, job_id: u64) -> Result<()>;
 }
 
 /// Create appropriate LogArchiver variant based on configuration values.
@@ -52,3 +69,54 @@ pub fn from_config(config: &ArchiveCfg) -> Result<Box<LogArchiver>> {
         ArchiveBackend::S3 => Ok(Box::new(s3::S3Archiver::new(config)?)),
     }
 }
+
+/// Reads the log at `file_path` and returns its gzip compressed contents.
+fn compress(file_path: &PathBuf) -> Result<Vec<u8>> {
+    let mut file = File::open(file_path)?;
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::Default);
+    io::copy(&mut file, &mut encoder)?;
+    Ok(encoder.finish()?)
+}
+
+/// Splits archived log content into lines, decompressing it first if it's gzip compressed.
+fn decode_lines(content: &[u8]) -> Result<Vec<String>> {
+    let mut buffer = Vec::new();
+    let content = if content.starts_with(&GZIP_MAGIC) {
+        GzDecoder::new(content)?.read_to_end(&mut buffer)?;
+        buffer.as_slice()
+    } else {
+        content
+    };
+    let lines = String::from_utf8_lossy(content)
+        .lines()
+        .map(|l| l.to_string())
+        .collect();
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+
+    #[test]
+    fn decode_compressed_and_plain_logs() {
+        let path = env::temp_dir().join(format!("log-archiver-test-{}.log", 42));
+        File::create(&path)
+            .unwrap()
+            .write_all(b"first line\nsecond line\n")
+            .unwrap();
+        let compressed = compress(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        let expected = vec!["first line".to_string(), "second line".to_string()];
+        assert!(compressed.starts_with(&GZIP_MAGIC));
+        assert_eq!(decode_lines(&compressed).unwrap(), expected);
+        assert_eq!(
+            decode_lines(b"first line\nsecond line\n").unwrap(),
+            expected
+        );
+    }
+}
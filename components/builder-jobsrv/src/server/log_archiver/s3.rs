@@ -17,29 +17,29 @@
 //!
 //! Has been tested against both AWS S3 and [Minio](https://minio.io).
 //!
-//! All job logs are stored in a single bucket, using the job's ID
-//! (with a `.log` extension) as the key.
+//! All job logs are stored gzip compressed in a single bucket, using
+//! the job's ID (with a `.log.gz` extension) as the key. Logs archived
+//! before compression was introduced use a `.log` extension.
 //!
 //! # Configuration
 //!
 //! Currently the archiver must be configured with both an access key
 //! ID and a secret access key.
 
-use std::fs::OpenOptions;
-use std::io::Read;
 use std::panic::{self, AssertUnwindSafe};
 use std::path::PathBuf;
 use std::str::FromStr;
 
 use aws_sdk_rust::aws::common::credentials::{DefaultCredentialsProvider, ParametersProvider};
 use aws_sdk_rust::aws::common::region::Region;
+use aws_sdk_rust::aws::errors::s3::S3Error;
 use aws_sdk_rust::aws::s3::endpoint::{Endpoint, Signature};
-use aws_sdk_rust::aws::s3::object::{GetObjectRequest, PutObjectRequest};
+use aws_sdk_rust::aws::s3::object::{DeleteObjectRequest, GetObjectRequest, PutObjectRequest};
 use aws_sdk_rust::aws::s3::s3client::S3Client;
 use extern_url;
 use hyper::client::Client as HyperClient;
 
-use super::LogArchiver;
+use super::{compress, decode_lines, LogArchiver};
 use VERSION;
 use config::ArchiveCfg;
 use error::{Result, Error};
@@ -106,88 +106,138 @@ impl S3Archiver {
     /// Generates the bucket key under which the job log will be
     /// stored.
     fn key(job_id: u64) -> String {
-        format!("{}.log", job_id)
+        format!("{}.log.gz", job_id)
     }
-}
 
-impl LogArchiver for S3Archiver {
-    fn archive(&self, job_id: u64, file_path: &PathBuf) -> Result<()> {
-        let mut buffer = Vec::new();
-        let mut put_object = PutObjectRequest::default();
-        put_object.bucket = self.bucket.clone();
-        put_object.key = Self::key(job_id);
+    /// Generates the bucket key under which job logs were stored
+    /// before they were compressed.
+    fn legacy_key(job_id: u64) -> String {
+        format!("{}.log", job_id)
+    }
 
-        let mut file = OpenOptions::new().read(true).open(file_path)?;
-        file.read_to_end(&mut buffer)?;
-        put_object.body = Some(buffer.as_slice());
+    fn delete_object(&self, job_id: u64, key: String) -> Result<()> {
+        let mut request = DeleteObjectRequest::default();
+        request.bucket = self.bucket.clone();
+        request.key = key;
 
-        // This panics if it can't resolve the URL (e.g.,
-        // there's a netsplit, your Minio goes down, S3 goes down (!)).
-        // We have to catch it, otherwise no more logs get captured!
-        //
-        // The code in the S3 library we're currently using isn't
-        // UnwindSafe, so we need to deal with that, too.
+        // As with the other requests, catch a potential panic if the
+        // object store cannot be reached
         let result = panic::catch_unwind(AssertUnwindSafe(
-            || self.client.put_object(&put_object, None),
+            || self.client.delete_object(&request, None),
         ));
 
         match result {
-            Ok(Ok(_)) => Ok(()), // normal result
-            Ok(Err(e)) => {
-                // This is a "normal", non-panicking error, e.g.,
-                // they're configured with a non-existent bucket.
-                Err(Error::JobLogArchive(job_id, e))
-            }
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(e)) => Err(Error::JobLogDelete(job_id, e)),
             Err(e) => {
                 let source = match e.downcast_ref::<String>() {
                     Some(string) => string.to_string(),
                     None => format!("{:?}", e),
                 };
                 Err(Error::CaughtPanic(
-                    format!("Failure to archive log for job {}", job_id),
+                    format!("Failure to delete archived log for job {}", job_id),
                     source,
                 ))
             }
         }
     }
 
-    fn retrieve(&self, job_id: u64) -> Result<Vec<String>> {
+    fn get_object(&self, job_id: u64, key: String) -> Result<Vec<u8>> {
         let mut request = GetObjectRequest::default();
         request.bucket = self.bucket.clone();
-        request.key = Self::key(job_id);
+        request.key = key;
 
         // As above when uploading a job file, we currently need to
         // catch a potential panic if the object store cannot be reached
         let result =
             panic::catch_unwind(AssertUnwindSafe(|| self.client.get_object(&request, None)));
 
-        let body = match result {
-            Ok(Ok(response)) => response.body, // normal result
+        match result {
+            Ok(Ok(response)) => Ok(response.body), // normal result
             Ok(Err(e)) => {
                 // This is a "normal", non-panicking error, e.g.,
                 // they're configured with a non-existent bucket.
-                return Err(Error::JobLogRetrieval(job_id, e));
+                Err(Error::JobLogRetrieval(job_id, e))
             }
             Err(e) => {
                 let source = match e.downcast_ref::<String>() {
                     Some(string) => string.to_string(),
                     None => format!("{:?}", e),
                 };
-                return Err(Error::CaughtPanic(
+                Err(Error::CaughtPanic(
                     format!(
                         "Failure to retrieve archived log for job {}",
                         job_id
                     ),
                     source,
-                ));
+                ))
             }
+        }
+    }
+}
+
+impl LogArchiver for S3Archiver {
+    fn archive(&self, job_id: u64, file_path: &PathBuf) -> Result<()> {
+        let buffer = compress(file_path)?;
+        let mut put_object = PutObjectRequest::default();
+        put_object.bucket = self.bucket.clone();
+        put_object.key = Self::key(job_id);
+        put_object.body = Some(buffer.as_slice());
+
+        // This panics if it can't resolve the URL (e.g.,
+        // there's a netsplit, your Minio goes down, S3 goes down (!)).
+        // We have to catch it, otherwise no more logs get captured!
+        //
+        // The code in the S3 library we're currently using isn't
+        // UnwindSafe, so we need to deal with that, too.
+        let result = panic::catch_unwind(AssertUnwindSafe(
+            || self.client.put_object(&put_object, None),
+        ));
+
+        match result {
+            Ok(Ok(_)) => Ok(()), // normal result
+            Ok(Err(e)) => {
+                // This is a "normal", non-panicking error, e.g.,
+                // they're configured with a non-existent bucket.
+                Err(Error::JobLogArchive(job_id, e))
+            }
+            Err(e) => {
+                let source = match e.downcast_ref::<String>() {
+                    Some(string) => string.to_string(),
+                    None => format!("{:?}", e),
+                };
+                Err(Error::CaughtPanic(
+                    format!("Failure to archive log for job {}", job_id),
+                    source,
+                ))
+            }
+        }
+    }
+
+    fn retrieve(&self, job_id: u64) -> Result<Vec<String>> {
+        let body = match self.get_object(job_id, Self::key(job_id)) {
+            Ok(body) => body,
+            // Not found under the current key, so try where it was
+            // stored before logs were compressed
+            Err(Error::JobLogRetrieval(_, ref e)) if is_missing(e) => {
+                self.get_object(job_id, Self::legacy_key(job_id))?
+            }
+            Err(e) => return Err(e),
         };
 
-        let lines = String::from_utf8_lossy(body.as_slice())
-            .lines()
-            .map(|l| l.to_string())
-            .collect();
+        decode_lines(body.as_slice())
+    }
 
-        Ok(lines)
+    fn delete(&self, job_id: u64) -> Result<()> {
+        // Deleting a key which doesn't exist succeeds, so logs stored
+        // under either key are removed
+        self.delete_object(job_id, Self::key(job_id))?;
+        self.delete_object(job_id, Self::legacy_key(job_id))
     }
 }
+
+/// Whether S3 answered that there's no object under the key asked for
+fn is_missing(err: &S3Error) -> bool {
+    let message = err.to_string();
+    message.contains("NoSuchKey") || message.contains("404")
+}
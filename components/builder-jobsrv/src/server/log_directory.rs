@@ -15,6 +15,7 @@
 use error::{Error, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
 
 /// Encapsulates the local filesystem directory in which in-process
 /// build job logs will be collected prior to being sent to long-term
@@ -59,4 +60,31 @@ impl LogDirectory {
     pub fn log_file_path(&self, job_id: u64) -> PathBuf {
         self.0.join(format!("{}.log", job_id))
     }
+
+    /// Returns the job IDs and paths of the log files which haven't
+    /// been written to for at least `age`.
+    pub fn logs_older_than(&self, age: Duration) -> Result<Vec<(u64, PathBuf)>> {
+        let now = SystemTime::now();
+        let mut logs = Vec::new();
+
+        for entry in fs::read_dir(&self.0)? {
+            let path = entry?.path();
+            let job_id = match path.file_name().and_then(|n| n.to_str()) {
+                Some(name) if name.ends_with(".log") => {
+                    match name.trim_right_matches(".log").parse::<u64>() {
+                        Ok(id) => id,
+                        Err(_) => continue,
+                    }
+                }
+                _ => continue,
+            };
+            let modified = fs::metadata(&path)?.modified()?;
+            match now.duration_since(modified) {
+                Ok(elapsed) if elapsed >= age => logs.push((job_id, path)),
+                _ => (),
+            }
+        }
+
+        Ok(logs)
+    }
 }
@@ -12,15 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::fs::{self, OpenOptions};
-use std::io::Write;
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
 use std::str;
 use std::sync::{mpsc, Arc};
 use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
 
 use hab_net::socket::DEFAULT_CONTEXT;
-use protobuf::parse_from_bytes;
-use protocol::jobsrv::{JobLogComplete, JobLogChunk};
+use protobuf::{parse_from_bytes, Message, RepeatedField};
+use protocol::jobsrv::{log_stream_topic, JobGet, JobLog, JobLogComplete, JobLogChunk};
 use server::log_archiver::{self, LogArchiver};
 use server::log_directory::LogDirectory;
 use zmq;
@@ -33,29 +36,43 @@ use error::Result;
 const LOG_LINE: &'static str = "L";
 /// ZMQ protocol frame to indicate a log has finished
 const LOG_COMPLETE: &'static str = "C";
+/// How often local copies of archived logs are checked against the retention period
+const PRUNE_INTERVAL_SECS: u64 = 3600;
 
 /// Listens for log messages from builders and consolidates output for
 /// both streaming to clients and long-term storage.
 pub struct LogIngester {
     intake_sock: zmq::Socket,
+    stream_sock: zmq::Socket,
     msg: zmq::Message,
     log_dir: Arc<LogDirectory>,
     log_ingestion_addr: String,
+    log_stream_addr: String,
+    /// Number of lines written to the log of each job whose log isn't complete yet
+    line_counts: HashMap<u64, u64>,
     data_store: DataStore,
     archiver: Box<LogArchiver>,
+    local_retention: Duration,
+    last_prune: Option<Instant>,
 }
 
 impl LogIngester {
     pub fn new(config: &Config, log_dir: Arc<LogDirectory>, data_store: DataStore) -> Result<Self> {
         let intake_sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::ROUTER)?;
         intake_sock.set_router_mandatory(true)?;
+        let stream_sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::PUB)?;
         Ok(LogIngester {
             intake_sock: intake_sock,
+            stream_sock: stream_sock,
             msg: zmq::Message::new()?,
             log_dir: log_dir,
             log_ingestion_addr: config.net.log_ingestion_addr(),
+            log_stream_addr: config.net.log_stream_addr(),
+            line_counts: HashMap::new(),
             data_store: data_store,
             archiver: log_archiver::from_config(&config.archive)?,
+            local_retention: Duration::from_secs(config.archive.local_retention_hours * 3600),
+            last_prune: None,
         })
     }
 
@@ -79,6 +96,8 @@ impl LogIngester {
     fn run(&mut self, rz: mpsc::SyncSender<()>) -> Result<()> {
         println!("Listening for log data on {}", self.log_ingestion_addr);
         self.intake_sock.bind(&self.log_ingestion_addr)?;
+        println!("Publishing log data on {}", self.log_stream_addr);
+        self.stream_sock.bind(&self.log_stream_addr)?;
         rz.send(()).unwrap();
         loop {
             // Right now we've got 3 frames per message:
@@ -95,6 +114,7 @@ impl LogIngester {
                     match parse_from_bytes::<JobLogChunk>(&self.msg) {
                         Ok(chunk) => {
                             let log_file = self.log_dir.log_file_path(chunk.get_job_id());
+                            let start = self.line_count(chunk.get_job_id(), &log_file);
 
                             // TODO: Consider caching file handles for
                             // currently-processing logs.
@@ -112,6 +132,16 @@ impl LogIngester {
                                     warn!("Could not open {:?} for appending! {:?}", log_file, e);
                                 }
                             }
+                            let lines: Vec<String> =
+                                chunk.get_content().lines().map(|l| l.to_string()).collect();
+                            let stop = start + lines.len() as u64;
+                            self.line_counts.insert(chunk.get_job_id(), stop);
+
+                            let mut log = JobLog::new();
+                            log.set_start(start);
+                            log.set_stop(stop);
+                            log.set_content(RepeatedField::from_vec(lines));
+                            self.publish(chunk.get_job_id(), &log)?;
                         }
                         Err(e) => {
                             warn!("ERROR parsing JobLogChunk: {:?}", e);
@@ -122,11 +152,20 @@ impl LogIngester {
                     self.intake_sock.recv(&mut self.msg, 0)?; // protobuf message frame
                     match parse_from_bytes::<JobLogComplete>(&self.msg) {
                         Ok(complete) => {
+                            let log_file = self.log_dir.log_file_path(complete.get_job_id());
+                            let stop = self.line_count(complete.get_job_id(), &log_file);
                             if let Err(e) = self.complete_log(&complete) {
                                 // TODO: Investigate error and attempt
                                 // to remediate as appropriate.
                                 warn!("Error completing log: {}", e);
                             }
+                            self.line_counts.remove(&complete.get_job_id());
+
+                            let mut log = JobLog::new();
+                            log.set_start(stop);
+                            log.set_stop(stop);
+                            log.set_is_complete(true);
+                            self.publish(complete.get_job_id(), &log)?;
                         }
                         Err(e) => {
                             warn!("ERROR parsing JobLogComplete: {:?}", e);
@@ -140,6 +179,29 @@ impl LogIngester {
         }
     }
 
+    /// Number of lines written to a job's log so far. It's counted from the log file the first
+    /// time, such as for a job whose log was started before JobSrv restarted.
+    fn line_count(&mut self, job_id: u64, log_file: &Path) -> u64 {
+        if let Some(count) = self.line_counts.get(&job_id) {
+            return *count;
+        }
+        let count = match File::open(log_file) {
+            Ok(file) => BufReader::new(file).lines().count() as u64,
+            Err(_) => 0,
+        };
+        self.line_counts.insert(job_id, count);
+        count
+    }
+
+    /// Publishes new output of a job's log to its followers. Followers read the log up to where
+    /// they subscribed first, and tell from the lines the output starts and stops at whether
+    /// they missed any of it.
+    fn publish(&self, job_id: u64, log: &JobLog) -> Result<()> {
+        self.stream_sock.send_str(&log_stream_topic(job_id), zmq::SNDMORE)?;
+        self.stream_sock.send(&log.write_to_bytes()?, 0)?;
+        Ok(())
+    }
+
     /// Factored out the above loop to take advantage of ?'s behavior
     /// in Result-returning functions to collapse deeply branching
     /// code.
@@ -155,7 +217,7 @@ impl LogIngester {
     /// This is also the _order_ in which these errors would occur, so
     /// a local log file is only removed after the log is successfully
     /// archived and marked as such in the database.
-    fn complete_log(&self, complete: &JobLogComplete) -> Result<()> {
+    fn complete_log(&mut self, complete: &JobLogComplete) -> Result<()> {
         let id = complete.get_job_id();
         debug!("Log complete for job {:?}", id);
         let log_file = self.log_dir.log_file_path(id);
@@ -163,8 +225,47 @@ impl LogIngester {
         self.archiver.archive(id, &log_file)?;
         debug!("Archived log for job {}", id);
         self.data_store.mark_as_archived(id)?;
-        fs::remove_file(&log_file)?;
-        debug!("Successfully deleted local log file {:?}", log_file);
+        if self.local_retention.as_secs() == 0 {
+            fs::remove_file(&log_file)?;
+            debug!("Successfully deleted local log file {:?}", log_file);
+        } else {
+            self.prune_logs();
+        }
         Ok(())
     }
+
+    /// Removes the local copies of archived logs which are older than the retention period.
+    /// Logs which haven't been archived, whether the job is still running or archiving failed,
+    /// are always kept.
+    fn prune_logs(&mut self) {
+        if let Some(last_prune) = self.last_prune {
+            if last_prune.elapsed() < Duration::from_secs(PRUNE_INTERVAL_SECS) {
+                return;
+            }
+        }
+        self.last_prune = Some(Instant::now());
+
+        let logs = match self.log_dir.logs_older_than(self.local_retention) {
+            Ok(logs) => logs,
+            Err(e) => {
+                warn!("Unable to list log files for pruning: {}", e);
+                return;
+            }
+        };
+
+        for (job_id, log_file) in logs {
+            let mut request = JobGet::new();
+            request.set_id(job_id);
+            match self.data_store.get_job(&request) {
+                Ok(Some(ref job)) if job.get_is_archived() => {
+                    match fs::remove_file(&log_file) {
+                        Ok(()) => debug!("Pruned local log file {:?}", log_file),
+                        Err(e) => warn!("Unable to prune log file {:?}: {}", log_file, e),
+                    }
+                }
+                Ok(_) => (),
+                Err(e) => warn!("Unable to check whether job {} is archived: {}", job_id, e),
+            }
+        }
+    }
 }
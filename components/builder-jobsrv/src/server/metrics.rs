@@ -0,0 +1,226 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serves the job server's metrics at `/metrics` in the Prometheus text format.
+//!
+//! Gauges are refreshed from the datastore and the shared server state on each scrape, while the
+//! dispatch latency and orphaned jobs are recorded by the worker manager as they happen.
+
+use std::sync::{mpsc, Arc, RwLock};
+use std::thread::{self, JoinHandle};
+
+use bldr_core::target_graph::TargetGraph;
+use chrono::UTC;
+use hyper::method::Method;
+use hyper::server::{Handler, Request, Response, Server};
+use hyper::status::StatusCode;
+use hyper::uri::RequestUri;
+use prometheus::{self, Counter, Encoder, GaugeVec, Histogram, TextEncoder};
+use protocol::{jobsrv, timestamp};
+
+use config::Config;
+use data_store::DataStore;
+use error::{Error, Result};
+
+lazy_static! {
+    static ref JOBS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "jobsrv_jobs",
+            "Number of jobs in each state."),
+        &["state"]).unwrap();
+
+    static ref JOB_QUEUE_DEPTH: GaugeVec = register_gauge_vec!(
+        opts!(
+            "jobsrv_job_queue_depth",
+            "Number of Pending jobs waiting for a worker, by target."),
+        &["target"]).unwrap();
+
+    static ref JOB_GROUPS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "jobsrv_job_groups",
+            "Number of Queued, Pending and Dispatching job groups."),
+        &["state"]).unwrap();
+
+    static ref WORKERS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "jobsrv_workers",
+            "Number of connected workers in each state."),
+        &["state"]).unwrap();
+
+    static ref WORKER_ARTIFACT_CACHE_LOOKUPS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "jobsrv_worker_artifact_cache_lookups",
+            "Number of build dependencies found in or missing from each worker's artifact cache."),
+        &["worker", "result"]).unwrap();
+
+    static ref GRAPH_PACKAGES: GaugeVec = register_gauge_vec!(
+        opts!(
+            "jobsrv_graph_packages",
+            "Number of packages in the dependency graph, by target."),
+        &["target"]).unwrap();
+
+    static ref GRAPH_DEPENDENCIES: GaugeVec = register_gauge_vec!(
+        opts!(
+            "jobsrv_graph_dependencies",
+            "Number of dependencies in the dependency graph, by target."),
+        &["target"]).unwrap();
+
+    static ref DISPATCH_LATENCY: Histogram = register_histogram!(
+        histogram_opts!(
+            "jobsrv_job_dispatch_latency_seconds",
+            "Time from a job's creation to its dispatch to a worker, in seconds.",
+            vec![1.0, 5.0, 15.0, 30.0, 60.0, 300.0, 900.0, 1800.0, 3600.0, 7200.0, 14400.0,
+                 43200.0, 86400.0])).unwrap();
+
+    static ref ORPHANED_JOBS: Counter = register_counter!(
+        opts!(
+            "jobsrv_jobs_orphaned_total",
+            "Number of running jobs lost along with their worker.")).unwrap();
+}
+
+/// Record the time a job waited to be dispatched, called once it has been sent to a worker.
+pub fn observe_dispatch(job: &jobsrv::Job) {
+    match timestamp::parse(job.get_created_at()) {
+        Ok(created_at) => {
+            let waited = UTC::now().signed_duration_since(created_at);
+            DISPATCH_LATENCY.observe(waited.num_milliseconds().max(0) as f64 / 1000.0);
+        }
+        Err(err) => debug!("Not recording dispatch latency of job {}, {}", job.get_id(), err),
+    }
+}
+
+/// Record a running job which was lost along with its worker.
+pub fn observe_orphaned_job() {
+    ORPHANED_JOBS.inc();
+}
+
+pub struct MetricsMgr {
+    datastore: DataStore,
+    graph: Arc<RwLock<TargetGraph>>,
+    workers: Arc<RwLock<jobsrv::WorkerListResponse>>,
+}
+
+impl MetricsMgr {
+    pub fn start(
+        cfg: &Config,
+        datastore: DataStore,
+        graph: Arc<RwLock<TargetGraph>>,
+        workers: Arc<RwLock<jobsrv::WorkerListResponse>>,
+    ) -> Result<JoinHandle<()>> {
+        let server = Server::http(cfg.net.metrics_addr()).map_err(
+            Error::MetricsServer,
+        )?;
+        let manager = MetricsMgr {
+            datastore: datastore,
+            graph: graph,
+            workers: workers,
+        };
+        let (tx, rx) = mpsc::sync_channel(1);
+        let handle = thread::Builder::new()
+            .name("metrics".to_string())
+            .spawn(move || { manager.run(server, tx).unwrap(); })
+            .unwrap();
+        match rx.recv() {
+            Ok(()) => Ok(handle),
+            Err(e) => panic!("metrics thread startup error, err={}", e),
+        }
+    }
+
+    fn run(self, server: Server, rz: mpsc::SyncSender<()>) -> Result<()> {
+        let _listening = server.handle_threads(self, 1).map_err(
+            Error::MetricsServer,
+        )?;
+        rz.send(()).unwrap();
+        // Dropping the listening guard waits on the server's threads, which run until exit
+        Ok(())
+    }
+
+    fn refresh(&self) -> Result<()> {
+        JOBS.reset();
+        for (state, count) in self.datastore.count_jobs_by_state()? {
+            JOBS.with_label_values(&[&state]).set(count as f64);
+        }
+
+        JOB_QUEUE_DEPTH.reset();
+        for (target, count) in self.datastore.count_pending_jobs_by_target()? {
+            JOB_QUEUE_DEPTH.with_label_values(&[&target]).set(count as f64);
+        }
+
+        JOB_GROUPS.reset();
+        for (state, count) in self.datastore.count_unfinished_job_groups_by_state()? {
+            JOB_GROUPS.with_label_values(&[&state]).set(count as f64);
+        }
+
+        let (mut ready, mut busy) = (0, 0);
+        WORKER_ARTIFACT_CACHE_LOOKUPS.reset();
+        for worker in self.workers.read().unwrap().get_workers() {
+            match worker.get_state() {
+                jobsrv::WorkerState::Ready => ready += 1,
+                jobsrv::WorkerState::Busy => busy += 1,
+            }
+            WORKER_ARTIFACT_CACHE_LOOKUPS
+                .with_label_values(&[worker.get_ident(), "hit"])
+                .set(worker.get_artifact_cache_hits() as f64);
+            WORKER_ARTIFACT_CACHE_LOOKUPS
+                .with_label_values(&[worker.get_ident(), "miss"])
+                .set(worker.get_artifact_cache_misses() as f64);
+        }
+        WORKERS.with_label_values(&["Ready"]).set(ready as f64);
+        WORKERS.with_label_values(&["Busy"]).set(busy as f64);
+
+        for stats in self.graph.read().unwrap().stats() {
+            let target = stats.target.to_string();
+            GRAPH_PACKAGES.with_label_values(&[&target]).set(
+                stats.node_count as f64,
+            );
+            GRAPH_DEPENDENCIES.with_label_values(&[&target]).set(
+                stats.edge_count as f64,
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl Handler for MetricsMgr {
+    fn handle(&self, req: Request, mut res: Response) {
+        let is_metrics = match req.uri {
+            RequestUri::AbsolutePath(ref path) => path.split('?').next() == Some("/metrics"),
+            _ => false,
+        };
+        if req.method != Method::Get || !is_metrics {
+            *res.status_mut() = StatusCode::NotFound;
+            return;
+        }
+
+        if let Err(err) = self.refresh() {
+            warn!("Unable to refresh metrics, err={:?}", err);
+            *res.status_mut() = StatusCode::InternalServerError;
+            return;
+        }
+
+        let mut buffer = vec![];
+        let encoder = TextEncoder::new();
+        let metric_families = prometheus::gather();
+        encoder.encode(&metric_families, &mut buffer).unwrap();
+
+        res.headers_mut().set_raw(
+            "Content-Type",
+            vec![encoder.format_type().as_bytes().to_vec()],
+        );
+        if let Err(err) = res.send(&buffer) {
+            debug!("Unable to send metrics, err={}", err);
+        }
+    }
+}
@@ -13,10 +13,17 @@
 // limitations under the License.
 
 pub mod log_archiver;
+mod cron;
+mod graph_snapshotter;
 mod handlers;
 mod worker_manager;
 mod log_directory;
 mod log_ingester;
+mod metrics;
+mod notifier;
+mod plan_check;
+mod pruner;
+mod quota;
 mod scheduler;
 
 use std::sync::RwLock;
@@ -25,11 +32,17 @@ use time::PreciseTime;
 use hab_net::app::prelude::*;
 use hab_net::conn::RouteClient;
 use protocol::jobsrv::*;
-use bldr_core::target_graph::TargetGraph;
+use bldr_core::target_graph::{TargetGraph, TargetGraphSnapshot};
 
+use self::cron::CronMgr;
+use self::graph_snapshotter::GraphSnapshotter;
 use self::log_archiver::LogArchiver;
 use self::log_directory::LogDirectory;
 use self::log_ingester::LogIngester;
+use self::metrics::MetricsMgr;
+use self::notifier::NotifierMgr;
+use self::pruner::Pruner;
+use self::quota::Quotas;
 use self::worker_manager::{WorkerMgr, WorkerMgrClient};
 use self::scheduler::{ScheduleMgr, ScheduleClient};
 use config::{ArchiveCfg, Config};
@@ -42,12 +55,28 @@ lazy_static! {
         map.register(JobSpec::descriptor_static(None), handlers::job_create);
         map.register(JobGet::descriptor_static(None), handlers::job_get);
         map.register(ProjectJobsGet::descriptor_static(None), handlers::project_jobs_get);
+        map.register(JobsByTagGet::descriptor_static(None), handlers::jobs_by_tag_get);
         map.register(JobLogGet::descriptor_static(None), handlers::job_log_get);
+        map.register(JobLogSubscribe::descriptor_static(None), handlers::job_log_subscribe);
         map.register(JobGroupSpec::descriptor_static(None), handlers::job_group_create);
+        map.register(JobGroupPreview::descriptor_static(None), handlers::job_group_preview);
         map.register(JobGroupAbort::descriptor_static(None), handlers::job_group_abort);
         map.register(JobGroupCancel::descriptor_static(None), handlers::job_group_cancel);
+        map.register(JobGroupRetry::descriptor_static(None), handlers::job_group_retry);
+        map.register(JobCancel::descriptor_static(None), handlers::job_cancel);
+        map.register(JobAuditGet::descriptor_static(None), handlers::job_audit_get);
+        map.register(JobHistoryPrune::descriptor_static(None), handlers::job_history_prune);
+        map.register(WorkerListGet::descriptor_static(None), handlers::worker_list_get);
         map.register(JobGroupGet::descriptor_static(None), handlers::job_group_get);
         map.register(JobGroupOriginGet::descriptor_static(None), handlers::job_group_origin_get);
+        map.register(OriginBuildUsageGet::descriptor_static(None),
+            handlers::origin_build_usage_get);
+        map.register(JobGroupScheduleCreate::descriptor_static(None),
+            handlers::job_group_schedule_create);
+        map.register(JobGroupScheduleListGet::descriptor_static(None),
+            handlers::job_group_schedule_list);
+        map.register(JobGroupScheduleDelete::descriptor_static(None),
+            handlers::job_group_schedule_delete);
         map.register(JobGraphPackageCreate::descriptor_static(None), handlers::job_graph_package_create);
         map.register(JobGraphPackagePreCreate::descriptor_static(None), handlers::job_graph_package_precreate);
         map.register(JobGraphPackageStatsGet::descriptor_static(None), handlers::job_graph_package_stats_get);
@@ -63,6 +92,11 @@ pub struct InitServerState {
     datastore: DataStore,
     graph: Arc<RwLock<TargetGraph>>,
     log_dir: Arc<LogDirectory>,
+    workers: Arc<RwLock<WorkerListResponse>>,
+    job_max_retries: u32,
+    job_ttl: u64,
+    job_retention_days: u64,
+    quotas: Quotas,
 }
 
 impl InitServerState {
@@ -73,6 +107,11 @@ impl InitServerState {
             datastore: datastore,
             graph: Arc::new(RwLock::new(graph)),
             log_dir: Arc::new(LogDirectory::new(cfg.log_dir)),
+            workers: Arc::new(RwLock::new(WorkerListResponse::new())),
+            job_max_retries: cfg.job_max_retries,
+            job_ttl: cfg.job_ttl,
+            job_retention_days: cfg.job_retention_days,
+            quotas: Quotas::new(cfg.quota),
         })
     }
 }
@@ -84,6 +123,11 @@ pub struct ServerState {
     graph: Arc<RwLock<TargetGraph>>,
     schedule_cli: ScheduleClient,
     log_dir: Arc<LogDirectory>,
+    workers: Arc<RwLock<WorkerListResponse>>,
+    job_max_retries: u32,
+    job_ttl: u64,
+    job_retention_days: u64,
+    quotas: Quotas,
 }
 
 impl AppState for ServerState {
@@ -98,6 +142,11 @@ impl AppState for ServerState {
             worker_mgr: WorkerMgrClient::default(),
             graph: init_state.graph,
             schedule_cli: ScheduleClient::default(),
+            workers: init_state.workers,
+            job_max_retries: init_state.job_max_retries,
+            job_ttl: init_state.job_ttl,
+            job_retention_days: init_state.job_retention_days,
+            quotas: init_state.quotas,
         };
         state.worker_mgr.connect()?;
         state.schedule_cli.connect()?;
@@ -119,35 +168,120 @@ impl Dispatcher for JobSrv {
         router_pipe: Arc<String>,
     ) -> Result<<Self::State as AppState>::InitState> {
         let datastore = DataStore::new(&config.datastore)?;
-        let mut graph = TargetGraph::new();
-        let packages = datastore.get_job_graph_packages()?;
-        let start_time = PreciseTime::now();
-        let res = graph.build(packages.into_iter());
-        let end_time = PreciseTime::now();
-        info!("Graph build stats ({} sec):", start_time.to(end_time));
-
-        for stat in res {
-            info!(
-                "Target {}: {} nodes, {} edges",
-                stat.target,
-                stat.node_count,
-                stat.edge_count,
-            );
-        }
-
+        let graph = load_graph(&config, &datastore)?;
         let state = InitServerState::new(config.clone(), datastore, graph)?;
 
         LogIngester::start(&config, state.log_dir.clone(), state.datastore.clone())?;
         let conn = RouteClient::new()?;
         conn.connect(&*router_pipe)?;
-        WorkerMgr::start(&config, state.datastore.clone(), conn)?;
-        ScheduleMgr::start(state.datastore.clone(), config.log_path, router_pipe)?;
+        NotifierMgr::start(
+            config.notifier.clone(),
+            config.key_dir.clone(),
+            router_pipe.clone(),
+        )?;
+        WorkerMgr::start(
+            &config,
+            state.datastore.clone(),
+            conn,
+            state.workers.clone(),
+        )?;
+        ScheduleMgr::start(
+            state.datastore.clone(),
+            config.log_path,
+            config.job_max_retries,
+            config.job_ttl,
+            config.max_groups_per_origin,
+            config.github.clone(),
+            router_pipe.clone(),
+        )?;
+        CronMgr::start(state.datastore.clone(), router_pipe)?;
+        GraphSnapshotter::start(
+            state.graph.clone(),
+            config.graph_snapshot_path.clone(),
+            config.graph_snapshot_interval,
+        )?;
+        if config.job_retention_days > 0 {
+            Pruner::start(&config, state.datastore.clone(), state.log_dir.clone())?;
+        }
+        MetricsMgr::start(
+            &config,
+            state.datastore.clone(),
+            state.graph.clone(),
+            state.workers.clone(),
+        )?;
         Ok(state)
     }
 
     fn dispatch_table() -> &'static DispatchTable<Self> {
         &DISPATCH_TABLE
     }
+
+    fn health_checks(state: &<Self::State as AppState>::InitState) -> HealthChecks {
+        vec![
+            (
+                "datastore",
+                Box::new(state.datastore.pool.clone()) as Box<HealthCheck>,
+            ),
+        ]
+    }
+}
+
+/// Restore the package graph from its last snapshot and add the packages persisted since, or
+/// build it from all of the packages if there's no usable snapshot.
+fn load_graph(config: &Config, datastore: &DataStore) -> Result<TargetGraph> {
+    let start_time = PreciseTime::now();
+    let revision = datastore.get_job_graph_packages_revision()?;
+
+    let mut graph = match TargetGraphSnapshot::load(&config.graph_snapshot_path) {
+        // A snapshot ahead of the datastore belongs to some other database, e.g. one which has
+        // since been restored from a backup
+        Ok(ref snapshot) if snapshot.revision() > revision => {
+            warn!(
+                "Graph snapshot revision {} is ahead of the datastore revision {}, rebuilding",
+                snapshot.revision(),
+                revision
+            );
+            TargetGraph::new()
+        }
+        Ok(snapshot) => {
+            match TargetGraph::restore(snapshot) {
+                Ok(graph) => graph,
+                Err(err) => {
+                    warn!("Unable to restore graph snapshot, rebuilding, err: {}", err);
+                    TargetGraph::new()
+                }
+            }
+        }
+        Err(err) => {
+            info!(
+                "No usable graph snapshot at {}, building the graph, err: {}",
+                config.graph_snapshot_path.display(),
+                err
+            );
+            TargetGraph::new()
+        }
+    };
+
+    let packages = datastore.get_job_graph_packages_since(graph.revision())?;
+    info!(
+        "Adding {} packages to the graph after revision {}",
+        packages.len(),
+        graph.revision()
+    );
+    let res = graph.build(packages.into_iter());
+    let end_time = PreciseTime::now();
+    info!("Graph build stats ({} sec):", start_time.to(end_time));
+
+    for stat in res {
+        info!(
+            "Target {}: {} nodes, {} edges",
+            stat.target,
+            stat.node_count,
+            stat.edge_count,
+        );
+    }
+
+    Ok(graph)
 }
 
 pub fn run(config: Config) -> AppResult<(), Error> {
@@ -0,0 +1,440 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tells origins about their finished jobs and job groups, through the webhooks and email
+//! addresses they subscribed with the OriginSrv. Webhooks are delivered on threads of their own,
+//! as failed deliveries are retried for a while.
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+
+use bldr_core;
+use bldr_core::webhook;
+use hab_net::conn::RouteClient;
+use hab_net::socket::DEFAULT_CONTEXT;
+use protobuf::{parse_from_bytes, Message};
+use protocol::jobsrv;
+use protocol::originsrv::{OriginNotification, OriginNotificationEvent, OriginNotificationKind,
+                          OriginNotificationListRequest, OriginNotificationListResponse};
+use serde_json;
+use zmq;
+
+use config::NotifierCfg;
+use error::{Error, Result};
+
+const NOTIFIER_ADDR: &'static str = "inproc://notifier";
+// First byte of a notice, telling what the encoded message after it is
+const JOB_NOTICE: u8 = b'J';
+const GROUP_NOTICE: u8 = b'G';
+
+pub struct NotifierClient {
+    socket: zmq::Socket,
+}
+
+impl NotifierClient {
+    pub fn connect(&mut self) -> Result<()> {
+        self.socket.connect(NOTIFIER_ADDR)?;
+        Ok(())
+    }
+
+    /// Notify the job's origin if the job has finished.
+    pub fn notify_job(&mut self, job: &jobsrv::Job) -> Result<()> {
+        self.send(JOB_NOTICE, job)
+    }
+
+    /// Notify the group's origin if the group has finished.
+    pub fn notify_group(&mut self, group: &jobsrv::JobGroup) -> Result<()> {
+        self.send(GROUP_NOTICE, group)
+    }
+
+    fn send<M: Message>(&mut self, notice: u8, msg: &M) -> Result<()> {
+        let mut frame = vec![notice];
+        frame.extend(msg.write_to_bytes()?);
+        self.socket.send(&frame, 0)?;
+        Ok(())
+    }
+}
+
+impl Default for NotifierClient {
+    fn default() -> NotifierClient {
+        let socket = (**DEFAULT_CONTEXT).as_mut().socket(zmq::DEALER).unwrap();
+        NotifierClient { socket: socket }
+    }
+}
+
+/// A job or job group which has reached a final state.
+#[derive(Debug)]
+enum Finished {
+    Job(jobsrv::Job),
+    Group(jobsrv::JobGroup),
+}
+
+impl Finished {
+    /// The event origins subscribe to for this build, if it's one they can be notified of.
+    fn event(&self) -> Option<OriginNotificationEvent> {
+        match *self {
+            Finished::Job(ref job) => {
+                match job.get_state() {
+                    jobsrv::JobState::Complete => Some(OriginNotificationEvent::JobComplete),
+                    jobsrv::JobState::Failed => Some(OriginNotificationEvent::JobFailed),
+                    _ => None,
+                }
+            }
+            Finished::Group(ref group) => {
+                match group.get_state() {
+                    // Groups run to completion when some of their projects fail, but subscribers
+                    // want to know about those failures
+                    jobsrv::JobGroupState::GroupComplete => {
+                        if group.get_projects().iter().any(|p| {
+                            p.get_state() == jobsrv::JobGroupProjectState::Failure
+                        })
+                        {
+                            Some(OriginNotificationEvent::GroupFailed)
+                        } else {
+                            Some(OriginNotificationEvent::GroupComplete)
+                        }
+                    }
+                    jobsrv::JobGroupState::GroupFailed => {
+                        Some(OriginNotificationEvent::GroupFailed)
+                    }
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn origin(&self) -> &str {
+        match *self {
+            Finished::Job(ref job) => job.get_project().get_origin_name(),
+            Finished::Group(ref group) => group.get_project_name().split('/').next().unwrap_or(""),
+        }
+    }
+
+    fn subject(&self, event: OriginNotificationEvent) -> String {
+        let outcome = match event {
+            OriginNotificationEvent::JobFailed |
+            OriginNotificationEvent::GroupFailed => "failed",
+            OriginNotificationEvent::JobComplete |
+            OriginNotificationEvent::GroupComplete => "completed",
+        };
+        match *self {
+            Finished::Job(ref job) => {
+                format!(
+                    "Job {} of {} {}",
+                    job.get_id(),
+                    job.get_project().get_name(),
+                    outcome
+                )
+            }
+            Finished::Group(ref group) => {
+                format!(
+                    "Job group {} of {} {}",
+                    group.get_id(),
+                    group.get_project_name(),
+                    outcome
+                )
+            }
+        }
+    }
+
+    /// JSON body posted to webhooks.
+    fn payload(&self, event: OriginNotificationEvent) -> Result<String> {
+        let payload = match *self {
+            Finished::Job(ref job) => {
+                json!({
+                    "event": event.to_string(),
+                    "job": job,
+                })
+            }
+            Finished::Group(ref group) => {
+                json!({
+                    "event": event.to_string(),
+                    "group": group,
+                })
+            }
+        };
+        serde_json::to_string(&payload).map_err(Error::NotificationPayload)
+    }
+
+    /// Plain text email, with its headers, handed to sendmail.
+    fn email(&self, event: OriginNotificationEvent, from: &str, to: &str) -> String {
+        let mut body = format!(
+            "From: {}\r\nTo: {}\r\nSubject: [Builder] {}\r\n\r\n{}.\r\n",
+            from,
+            to,
+            self.subject(event),
+            self.subject(event)
+        );
+        if let Finished::Group(ref group) = *self {
+            body.push_str("\r\n");
+            for project in group.get_projects() {
+                body.push_str(&format!(
+                    "{}: {:?}\r\n",
+                    project.get_name(),
+                    project.get_state()
+                ));
+            }
+        }
+        body
+    }
+}
+
+pub struct NotifierMgr {
+    config: NotifierCfg,
+    /// Where the Builder key pair decrypting the secrets of webhooks is
+    key_dir: PathBuf,
+    msg: zmq::Message,
+    route_conn: RouteClient,
+    socket: zmq::Socket,
+}
+
+impl NotifierMgr {
+    pub fn new(config: NotifierCfg, key_dir: PathBuf, router_pipe: Arc<String>) -> Result<Self> {
+        let socket = (**DEFAULT_CONTEXT).as_mut().socket(zmq::DEALER)?;
+        let route_conn = RouteClient::new()?;
+        route_conn.connect(&*router_pipe)?;
+
+        Ok(NotifierMgr {
+            config: config,
+            key_dir: key_dir,
+            msg: zmq::Message::new()?,
+            route_conn: route_conn,
+            socket: socket,
+        })
+    }
+
+    pub fn start(
+        config: NotifierCfg,
+        key_dir: PathBuf,
+        router_pipe: Arc<String>,
+    ) -> Result<JoinHandle<()>> {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let mut notifier = Self::new(config, key_dir, router_pipe)?;
+        let handle = thread::Builder::new()
+            .name("notifier".to_string())
+            .spawn(move || { notifier.run(tx).unwrap(); })
+            .unwrap();
+        match rx.recv() {
+            Ok(()) => Ok(handle),
+            Err(e) => panic!("notifier thread startup error, err={}", e),
+        }
+    }
+
+    fn run(&mut self, rz: mpsc::SyncSender<()>) -> Result<()> {
+        self.socket.bind(NOTIFIER_ADDR)?;
+        rz.send(()).unwrap();
+        loop {
+            let finished = match self.recv() {
+                Ok(Some(finished)) => finished,
+                Ok(None) => continue,
+                Err(err) => {
+                    warn!("Notifier unable to receive notice: err {:?}", err);
+                    continue;
+                }
+            };
+            if let Err(err) = self.notify(&finished) {
+                warn!(
+                    "Notifier unable to notify origin {}: err {:?}",
+                    finished.origin(),
+                    err
+                );
+            }
+        }
+    }
+
+    fn recv(&mut self) -> Result<Option<Finished>> {
+        self.socket.recv(&mut self.msg, 0)?;
+        let finished = match self.msg.first() {
+            Some(&JOB_NOTICE) => Finished::Job(parse_from_bytes(&self.msg[1..])?),
+            Some(&GROUP_NOTICE) => Finished::Group(parse_from_bytes(&self.msg[1..])?),
+            _ => {
+                warn!("Notifier received an unknown notice, ignoring");
+                return Ok(None);
+            }
+        };
+        Ok(Some(finished))
+    }
+
+    fn notify(&mut self, finished: &Finished) -> Result<()> {
+        let event = match finished.event() {
+            Some(event) => event,
+            None => return Ok(()),
+        };
+
+        let mut request = OriginNotificationListRequest::new();
+        request.set_origin(finished.origin().to_string());
+        let response = self.route_conn
+            .route::<OriginNotificationListRequest, OriginNotificationListResponse>(&request)?;
+
+        for notification in response.get_notifications() {
+            if !notification.wants(event) {
+                continue;
+            }
+            if let Err(err) = self.send(notification, finished, event) {
+                warn!(
+                    "Unable to send {} notification {} for {}, err {}",
+                    notification.get_kind(),
+                    notification.get_id(),
+                    finished.subject(event),
+                    err
+                );
+            }
+        }
+        Ok(())
+    }
+
+    fn send(
+        &self,
+        notification: &OriginNotification,
+        finished: &Finished,
+        event: OriginNotificationEvent,
+    ) -> Result<()> {
+        match notification.get_kind() {
+            OriginNotificationKind::Webhook => self.send_webhook(notification, finished, event),
+            OriginNotificationKind::Email => {
+                self.send_email(notification.get_target(), finished, event)
+            }
+        }
+    }
+
+    fn send_webhook(
+        &self,
+        notification: &OriginNotification,
+        finished: &Finished,
+        event: OriginNotificationEvent,
+    ) -> Result<()> {
+        let payload = finished.payload(event)?;
+        let secret = if notification.has_secret() {
+            let secret = bldr_core::integrations::decrypt(&self.key_dir, notification.get_secret())
+                .map_err(Error::NotificationSecret)?;
+            Some(secret)
+        } else {
+            None
+        };
+        webhook::spawn_delivery(
+            self.config.webhook(),
+            notification.get_target().to_string(),
+            secret,
+            event.to_string(),
+            payload,
+        );
+        Ok(())
+    }
+
+    fn send_email(
+        &self,
+        address: &str,
+        finished: &Finished,
+        event: OriginNotificationEvent,
+    ) -> Result<()> {
+        let message = finished.email(event, &self.config.email_from, address);
+        let mut child = Command::new(&self.config.sendmail_path)
+            .arg("-t")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .spawn()
+            .map_err(Error::NotificationSendmail)?;
+        if let Some(ref mut stdin) = child.stdin {
+            stdin.write_all(message.as_bytes()).map_err(
+                Error::NotificationSendmail,
+            )?;
+        }
+        // Close stdin so that sendmail sees the end of the message
+        child.stdin.take();
+        let status = child.wait().map_err(Error::NotificationSendmail)?;
+        if !status.success() {
+            return Err(Error::NotificationSendmailStatus(status));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use protobuf::RepeatedField;
+
+    fn group(state: jobsrv::JobGroupState, projects: &[jobsrv::JobGroupProjectState]) -> Finished {
+        let mut group = jobsrv::JobGroup::new();
+        group.set_id(1);
+        group.set_project_name("core/nginx".to_string());
+        group.set_state(state);
+        let projects = projects
+            .iter()
+            .map(|state| {
+                let mut project = jobsrv::JobGroupProject::new();
+                project.set_name("core/nginx".to_string());
+                project.set_state(*state);
+                project
+            })
+            .collect();
+        group.set_projects(RepeatedField::from_vec(projects));
+        Finished::Group(group)
+    }
+
+    #[test]
+    fn job_events() {
+        let mut job = jobsrv::Job::new();
+        job.set_state(jobsrv::JobState::Failed);
+        assert_eq!(
+            Finished::Job(job.clone()).event(),
+            Some(OriginNotificationEvent::JobFailed)
+        );
+        job.set_state(jobsrv::JobState::Complete);
+        assert_eq!(
+            Finished::Job(job.clone()).event(),
+            Some(OriginNotificationEvent::JobComplete)
+        );
+        job.set_state(jobsrv::JobState::CancelComplete);
+        assert_eq!(Finished::Job(job).event(), None);
+    }
+
+    #[test]
+    fn group_events() {
+        use protocol::jobsrv::JobGroupProjectState::*;
+        use protocol::jobsrv::JobGroupState::*;
+
+        assert_eq!(
+            group(GroupComplete, &[Success, Skipped]).event(),
+            Some(OriginNotificationEvent::GroupComplete)
+        );
+        assert_eq!(
+            group(GroupComplete, &[Success, Failure]).event(),
+            Some(OriginNotificationEvent::GroupFailed)
+        );
+        assert_eq!(
+            group(GroupFailed, &[Failure]).event(),
+            Some(OriginNotificationEvent::GroupFailed)
+        );
+        assert_eq!(group(GroupCanceled, &[Canceled]).event(), None);
+    }
+
+    #[test]
+    fn group_origin_and_payload() {
+        let finished = group(
+            jobsrv::JobGroupState::GroupComplete,
+            &[jobsrv::JobGroupProjectState::Success],
+        );
+        assert_eq!(finished.origin(), "core");
+        let payload = finished
+            .payload(OriginNotificationEvent::GroupComplete)
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(value["event"], "group_complete");
+        assert_eq!(value["group"]["id"], "1");
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Checks that a project's plan can still be reached on GitHub before a job is created for it.
+//!
+//! Projects whose repository was disconnected from the GitHub App, or whose plan was moved, are
+//! skipped instead of being dispatched to a worker only to fail on clone. Errors which don't tell
+//! us either way, such as GitHub being unavailable, let the project be dispatched as usual.
+
+use extern_url::Url;
+use github_api_client::{GitHubClient, HubError};
+use protocol::originsrv;
+
+/// Returns why a project's plan can't be reached, or `None` if it can or if it can't be told.
+pub fn unreachable_reason(
+    github: &GitHubClient,
+    project: &originsrv::OriginProject,
+) -> Option<String> {
+    if project.get_vcs_type() != "git" || !project.has_vcs_installation_id() {
+        return None;
+    }
+    let full_name = match repo_full_name(&github.web_url, project.get_vcs_data()) {
+        Some(full_name) => full_name,
+        None => return None,
+    };
+
+    let install_id = project.get_vcs_installation_id();
+    let token = match github.app_installation_token(install_id) {
+        Ok(token) => token,
+        Err(HubError::AppAuth(ref err)) if err.message == "Not Found" => {
+            return Some(format!(
+                "GitHub App installation {} no longer exists",
+                install_id
+            ));
+        }
+        Err(err) => {
+            warn!("Unable to check plan of {}, {}", project.get_name(), err);
+            return None;
+        }
+    };
+
+    let repo = match github.repo_by_name(&token, &full_name) {
+        Ok(Some(repo)) => repo,
+        Ok(None) => {
+            return Some(format!(
+                "Repository {} is no longer connected to the GitHub App",
+                full_name
+            ))
+        }
+        Err(err) => {
+            warn!("Unable to check plan of {}, {}", project.get_name(), err);
+            return None;
+        }
+    };
+
+    match github.contents(&token, repo.id, project.get_plan_path()) {
+        Ok(Some(_)) => None,
+        Ok(None) => Some(format!(
+            "Plan {} not found in repository {}",
+            project.get_plan_path(),
+            full_name
+        )),
+        Err(err) => {
+            warn!("Unable to check plan of {}, {}", project.get_name(), err);
+            None
+        }
+    }
+}
+
+/// The `owner/repo` name of a repository given its clone URL, if it's hosted on the GitHub
+/// instance at `web_url`.
+fn repo_full_name(web_url: &str, clone_url: &str) -> Option<String> {
+    let web_url = match Url::parse(web_url) {
+        Ok(url) => url,
+        Err(_) => return None,
+    };
+    let clone_url = match Url::parse(clone_url) {
+        Ok(url) => url,
+        Err(_) => return None,
+    };
+    if clone_url.host_str() != web_url.host_str() {
+        return None;
+    }
+    let path = clone_url.path().trim_matches('/');
+    let path = path.trim_right_matches(".git");
+    let parts: Vec<&str> = path.split('/').collect();
+    if parts.len() != 2 || parts.iter().any(|p| p.is_empty()) {
+        return None;
+    }
+    Some(path.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WEB_URL: &'static str = "https://github.com";
+
+    #[test]
+    fn full_name_from_clone_url() {
+        assert_eq!(
+            repo_full_name(WEB_URL, "https://github.com/habitat-sh/core-plans.git"),
+            Some("habitat-sh/core-plans".to_string())
+        );
+        assert_eq!(
+            repo_full_name(WEB_URL, "https://github.com/habitat-sh/core-plans"),
+            Some("habitat-sh/core-plans".to_string())
+        );
+    }
+
+    #[test]
+    fn full_name_from_other_hosts() {
+        assert_eq!(
+            repo_full_name(WEB_URL, "https://gitlab.com/habitat-sh/core-plans.git"),
+            None
+        );
+        assert_eq!(
+            repo_full_name(
+                "https://github.example.com",
+                "https://github.example.com/habitat-sh/core-plans.git",
+            ),
+            Some("habitat-sh/core-plans".to_string())
+        );
+    }
+
+    #[test]
+    fn full_name_from_malformed_url() {
+        assert_eq!(repo_full_name(WEB_URL, "not a url"), None);
+        assert_eq!(repo_full_name(WEB_URL, "https://github.com/habitat-sh"), None);
+        assert_eq!(
+            repo_full_name(WEB_URL, "https://github.com/habitat-sh/core-plans/tree/master"),
+            None
+        );
+    }
+}
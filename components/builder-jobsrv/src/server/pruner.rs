@@ -0,0 +1,123 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Deletes the job history which has passed the retention window, along with its logs, so the
+//! jobs database doesn't grow without bound.
+
+use std::fs;
+use std::io;
+use std::sync::{mpsc, Arc};
+use std::thread::{self, JoinHandle};
+use std::time::Duration as StdDuration;
+
+use chrono::{Duration, UTC};
+use protocol::jobsrv;
+
+use config::Config;
+use data_store::DataStore;
+use error::Result;
+
+use super::log_archiver::{self, LogArchiver};
+use super::log_directory::LogDirectory;
+
+pub struct Pruner {
+    datastore: DataStore,
+    archiver: Box<LogArchiver>,
+    log_dir: Arc<LogDirectory>,
+    retention_days: u64,
+    interval: StdDuration,
+}
+
+impl Pruner {
+    pub fn new(cfg: &Config, datastore: DataStore, log_dir: Arc<LogDirectory>) -> Result<Self> {
+        Ok(Pruner {
+            datastore: datastore,
+            archiver: log_archiver::from_config(&cfg.archive)?,
+            log_dir: log_dir,
+            retention_days: cfg.job_retention_days,
+            interval: StdDuration::from_secs(cfg.job_prune_interval),
+        })
+    }
+
+    pub fn start(
+        cfg: &Config,
+        datastore: DataStore,
+        log_dir: Arc<LogDirectory>,
+    ) -> Result<JoinHandle<()>> {
+        let (tx, rx) = mpsc::sync_channel(1);
+        let mut pruner = Self::new(cfg, datastore, log_dir)?;
+        let handle = thread::Builder::new()
+            .name("pruner".to_string())
+            .spawn(move || { pruner.run(tx).unwrap(); })
+            .unwrap();
+        match rx.recv() {
+            Ok(()) => Ok(handle),
+            Err(e) => panic!("pruner thread startup error, err={}", e),
+        }
+    }
+
+    fn run(&mut self, rz: mpsc::SyncSender<()>) -> Result<()> {
+        rz.send(()).unwrap();
+        loop {
+            match prune(
+                &self.datastore,
+                &*self.archiver,
+                &self.log_dir,
+                self.retention_days,
+            ) {
+                Ok(pruned) => {
+                    if pruned.get_groups_deleted() > 0 || pruned.get_jobs_deleted() > 0 {
+                        info!(
+                            "Pruned {} job groups and {} jobs older than {} days",
+                            pruned.get_groups_deleted(),
+                            pruned.get_jobs_deleted(),
+                            self.retention_days
+                        );
+                    }
+                }
+                Err(err) => warn!("Pruner unable to prune job history: err {:?}", err),
+            }
+            thread::sleep(self.interval);
+        }
+    }
+}
+
+/// Deletes the finished job groups and jobs created more than `retention_days` ago, along with
+/// their archived and local logs. A log which can't be deleted is left behind rather than
+/// failing the prune, since its job is already gone.
+pub fn prune(
+    datastore: &DataStore,
+    archiver: &LogArchiver,
+    log_dir: &LogDirectory,
+    retention_days: u64,
+) -> Result<jobsrv::JobHistoryPruneResponse> {
+    let before = UTC::now() - Duration::days(retention_days as i64);
+    let (groups_deleted, job_ids) = datastore.prune_job_history(&before)?;
+
+    for job_id in job_ids.iter() {
+        if let Err(err) = archiver.delete(*job_id) {
+            warn!("Unable to delete archived log of job {}, err={:?}", job_id, err);
+        }
+        match fs::remove_file(log_dir.log_file_path(*job_id)) {
+            Ok(()) => (),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => (),
+            Err(e) => warn!("Unable to delete log of job {}, err={}", job_id, e),
+        }
+    }
+
+    let mut pruned = jobsrv::JobHistoryPruneResponse::new();
+    pruned.set_groups_deleted(groups_deleted);
+    pruned.set_jobs_deleted(job_ids.len() as u64);
+    Ok(pruned)
+}
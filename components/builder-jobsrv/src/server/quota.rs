@@ -0,0 +1,147 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Build quotas of origins, limiting the builds an origin may start in a rolling day and the
+//! workers its jobs may occupy at once.
+//!
+//! Quotas are enforced when jobs are dispatched. The jobs of an origin at its quota stay Pending
+//! and are passed over in favor of other origins' jobs until the origin's usage drops.
+
+use protocol::jobsrv;
+
+use config::QuotaCfg;
+
+#[derive(Clone, Debug)]
+pub struct Quotas {
+    cfg: QuotaCfg,
+}
+
+impl Quotas {
+    pub fn new(cfg: QuotaCfg) -> Self {
+        Quotas { cfg: cfg }
+    }
+
+    /// The max builds per day and max workers of an origin, 0 for no limit
+    pub fn limits(&self, origin: &str) -> (u32, u32) {
+        let mut max_builds_per_day = self.cfg.max_builds_per_day;
+        let mut max_workers = self.cfg.max_workers;
+        if let Some(quota) = self.cfg.origins.get(origin) {
+            max_builds_per_day = quota.max_builds_per_day.unwrap_or(max_builds_per_day);
+            max_workers = quota.max_workers.unwrap_or(max_workers);
+        }
+        (max_builds_per_day, max_workers)
+    }
+
+    /// Fill in the quotas of an origin's usage
+    pub fn apply(&self, usage: &mut jobsrv::OriginBuildUsage) {
+        let (max_builds_per_day, max_workers) = self.limits(usage.get_origin());
+        usage.set_max_builds_per_day(max_builds_per_day);
+        usage.set_max_workers(max_workers);
+    }
+
+    /// Usage of an origin with nothing running or recently built
+    pub fn unused(&self, origin: &str) -> jobsrv::OriginBuildUsage {
+        let mut usage = jobsrv::OriginBuildUsage::new();
+        usage.set_origin(origin.to_string());
+        self.apply(&mut usage);
+        usage
+    }
+}
+
+/// Whether an origin has used up one of its quotas, so none of its jobs may be dispatched
+pub fn is_exhausted(usage: &jobsrv::OriginBuildUsage) -> bool {
+    (usage.get_max_workers() > 0 && usage.get_workers() >= usage.get_max_workers()) ||
+        (usage.get_max_builds_per_day() > 0 &&
+             usage.get_builds_last_day() >= usage.get_max_builds_per_day())
+}
+
+/// Count a job of the origin dispatched since its usage was retrieved
+pub fn record_dispatch(usage: &mut jobsrv::OriginBuildUsage) {
+    let workers = usage.get_workers();
+    let builds_last_day = usage.get_builds_last_day();
+    usage.set_workers(workers + 1);
+    usage.set_builds_last_day(builds_last_day + 1);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use config::OriginQuotaCfg;
+
+    use super::*;
+
+    fn quotas() -> Quotas {
+        let mut origins = HashMap::new();
+        origins.insert(
+            "core".to_string(),
+            OriginQuotaCfg {
+                max_builds_per_day: None,
+                max_workers: Some(0),
+            },
+        );
+        origins.insert(
+            "acme".to_string(),
+            OriginQuotaCfg {
+                max_builds_per_day: Some(10),
+                max_workers: None,
+            },
+        );
+        Quotas::new(QuotaCfg {
+            max_builds_per_day: 100,
+            max_workers: 2,
+            origins: origins,
+        })
+    }
+
+    #[test]
+    fn origin_limits() {
+        let quotas = quotas();
+        assert_eq!(quotas.limits("other"), (100, 2));
+        assert_eq!(quotas.limits("core"), (100, 0));
+        assert_eq!(quotas.limits("acme"), (10, 2));
+    }
+
+    #[test]
+    fn exhausted_quotas() {
+        let quotas = quotas();
+
+        let mut usage = quotas.unused("other");
+        assert!(!is_exhausted(&usage));
+        record_dispatch(&mut usage);
+        assert!(!is_exhausted(&usage));
+        record_dispatch(&mut usage);
+        assert!(is_exhausted(&usage));
+
+        // No limit on workers
+        let mut usage = quotas.unused("core");
+        usage.set_workers(50);
+        assert!(!is_exhausted(&usage));
+        usage.set_builds_last_day(100);
+        assert!(is_exhausted(&usage));
+
+        let mut usage = quotas.unused("acme");
+        usage.set_builds_last_day(10);
+        assert!(is_exhausted(&usage));
+    }
+
+    #[test]
+    fn no_quotas() {
+        let quotas = Quotas::new(QuotaCfg::default());
+        let mut usage = quotas.unused("core");
+        usage.set_workers(1000);
+        usage.set_builds_last_day(1000);
+        assert!(!is_exhausted(&usage));
+    }
+}
@@ -17,19 +17,23 @@ use std::path::Path;
 use std::sync::{mpsc, Arc};
 use std::thread::{self, JoinHandle};
 
+use github_api_client::{GitHubCfg, GitHubClient};
 use hab_net::ErrCode;
 use hab_net::conn::RouteClient;
 use hab_net::socket::DEFAULT_CONTEXT;
+use protobuf::ProtobufEnum;
 use zmq;
 
 use protocol::jobsrv;
 use protocol::originsrv;
-use data_store::DataStore;
+use data_store::{Audit, DataStore};
 use error::{Result, Error};
 
 use bldr_core::logger::Logger;
 use hab_core::channel::bldr_channel_name;
 
+use super::notifier::NotifierClient;
+use super::plan_check;
 use super::worker_manager::WorkerMgrClient;
 
 const SCHEDULER_ADDR: &'static str = "inproc://scheduler";
@@ -60,8 +64,13 @@ impl Default for ScheduleClient {
 
 pub struct ScheduleMgr {
     datastore: DataStore,
+    github: GitHubClient,
+    job_max_retries: u32,
+    job_ttl: u64,
     logger: Logger,
+    max_groups_per_origin: u32,
     msg: zmq::Message,
+    notifier: NotifierClient,
     route_conn: RouteClient,
     schedule_cli: ScheduleClient,
     socket: zmq::Socket,
@@ -69,7 +78,15 @@ pub struct ScheduleMgr {
 }
 
 impl ScheduleMgr {
-    pub fn new<T>(datastore: DataStore, log_path: T, router_pipe: Arc<String>) -> Result<Self>
+    pub fn new<T>(
+        datastore: DataStore,
+        log_path: T,
+        job_max_retries: u32,
+        job_ttl: u64,
+        max_groups_per_origin: u32,
+        github: GitHubCfg,
+        router_pipe: Arc<String>,
+    ) -> Result<Self>
     where
         T: AsRef<Path>,
     {
@@ -84,10 +101,18 @@ impl ScheduleMgr {
         let mut worker_mgr = WorkerMgrClient::default();
         worker_mgr.connect()?;
 
+        let mut notifier = NotifierClient::default();
+        notifier.connect()?;
+
         Ok(ScheduleMgr {
             datastore: datastore,
+            github: GitHubClient::new(github),
+            job_max_retries: job_max_retries,
+            job_ttl: job_ttl,
             logger: Logger::init(log_path, "builder-scheduler.log"),
+            max_groups_per_origin: max_groups_per_origin,
             msg: zmq::Message::new()?,
+            notifier: notifier,
             route_conn: route_conn,
             schedule_cli: schedule_cli,
             socket: socket,
@@ -98,13 +123,25 @@ impl ScheduleMgr {
     pub fn start<T>(
         datastore: DataStore,
         log_path: T,
+        job_max_retries: u32,
+        job_ttl: u64,
+        max_groups_per_origin: u32,
+        github: GitHubCfg,
         route_pipe: Arc<String>,
     ) -> Result<JoinHandle<()>>
     where
         T: AsRef<Path>,
     {
         let (tx, rx) = mpsc::sync_channel(1);
-        let mut schedule_mgr = Self::new(datastore, log_path, route_pipe)?;
+        let mut schedule_mgr = Self::new(
+            datastore,
+            log_path,
+            job_max_retries,
+            job_ttl,
+            max_groups_per_origin,
+            github,
+            route_pipe,
+        )?;
         let handle = thread::Builder::new()
             .name("scheduler".to_string())
             .spawn(move || { schedule_mgr.run(tx).unwrap(); })
@@ -159,12 +196,18 @@ impl ScheduleMgr {
     }
 
     fn process_queue(&mut self) -> Result<()> {
-        let groups = self.datastore.get_queued_job_groups()?;
+        let mut active = self.datastore.count_active_job_groups_by_origin()?;
+        let groups = fair_queue(self.datastore.get_queued_job_groups()?.into_vec(), &active);
+        let mut position = 0;
 
         for group in groups.iter() {
             assert!(group.get_state() == jobsrv::JobGroupState::GroupQueued);
 
-            if !self.datastore.is_job_group_active(group.get_project_name())? {
+            let origin = group_origin(group).to_string();
+            let at_limit = self.max_groups_per_origin > 0 &&
+                active.get(&origin).cloned().unwrap_or(0) >= self.max_groups_per_origin as u64;
+
+            if !at_limit && !self.datastore.is_job_group_active(group.get_project_name())? {
                 debug!(
                     "Setting group {} from queued to pending",
                     group.get_project_name()
@@ -172,6 +215,21 @@ impl ScheduleMgr {
                 self.datastore.set_job_group_state(
                     group.get_id(),
                     jobsrv::JobGroupState::GroupPending,
+                    &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+                )?;
+                self.datastore.set_job_group_queue_position(
+                    group.get_id(),
+                    None,
+                )?;
+                *active.entry(origin).or_insert(0) += 1;
+                continue;
+            }
+
+            position += 1;
+            if !group.has_queue_position() || group.get_queue_position() != position {
+                self.datastore.set_job_group_queue_position(
+                    group.get_id(),
+                    Some(position),
                 )?;
             }
         }
@@ -217,7 +275,7 @@ impl ScheduleMgr {
 
             assert!(project.get_state() == jobsrv::JobGroupProjectState::NotStarted);
 
-            match self.schedule_job(group.get_id(), project.get_name()) {
+            match self.schedule_job(group, project.get_name()) {
                 Ok(job_opt) => {
                     match job_opt {
                         Some(job) => self.datastore.set_job_group_job_state(&job).unwrap(),
@@ -259,6 +317,7 @@ impl ScheduleMgr {
                     self.datastore.set_job_group_state(
                         group.get_id(),
                         jobsrv::JobGroupState::GroupFailed,
+                        &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
                     )?;
                     self.datastore.set_job_group_project_state(
                         group.get_id(),
@@ -270,6 +329,7 @@ impl ScheduleMgr {
                     let mut updated_group = group.clone();
                     updated_group.set_state(jobsrv::JobGroupState::GroupFailed);
                     self.logger.log_group(&updated_group);
+                    self.notify_group(group.get_id());
 
                     break;
                 }
@@ -380,7 +440,12 @@ impl ScheduleMgr {
         Ok(skipped.keys().map(|s| s.to_string()).collect())
     }
 
-    fn schedule_job(&mut self, group_id: u64, project_name: &str) -> Result<Option<jobsrv::Job>> {
+    fn schedule_job(
+        &mut self,
+        group: &jobsrv::JobGroup,
+        project_name: &str,
+    ) -> Result<Option<jobsrv::Job>> {
+        let group_id = group.get_id();
         let mut project_get = originsrv::OriginProjectGet::new();
         project_get.set_name(String::from(project_name));
 
@@ -408,13 +473,36 @@ impl ScheduleMgr {
             }
         };
 
+        if let Some(reason) = plan_check::unreachable_reason(&self.github, &project) {
+            self.log_error(format!(
+                "Skipping project: {:?} (group: {}), {}",
+                project_name,
+                group_id,
+                reason
+            ));
+            self.datastore.skip_job_group_project(
+                group_id,
+                project_name,
+                &reason,
+            )?;
+            return Ok(None);
+        }
+
         let mut job_spec = jobsrv::JobSpec::new();
         job_spec.set_owner_id(group_id);
         job_spec.set_project(project);
         job_spec.set_channel(bldr_channel_name(group_id));
+        job_spec.set_priority(group.get_priority());
+        job_spec.set_target(group.get_target().to_string());
+        job_spec.set_tags(group.get_tags().to_vec().into());
 
         let mut job: jobsrv::Job = job_spec.into();
-        match self.datastore.create_job(&mut job) {
+        job.set_max_retries(self.job_max_retries);
+        job.set_ttl(self.job_ttl);
+        match self.datastore.create_job(
+            &mut job,
+            &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+        ) {
             Ok(job) => {
                 debug!("Job created: {:?}", job);
                 self.worker_mgr.notify_work()?;
@@ -490,7 +578,12 @@ impl ScheduleMgr {
 
                     match job.get_state() {
                         jobsrv::JobState::Complete |
-                        jobsrv::JobState::Failed |
+                        jobsrv::JobState::Failed => {
+                            if let Err(err) = self.notifier.notify_job(&job) {
+                                warn!("Unable to notify of job {}, err {:?}", job.get_id(), err);
+                            }
+                            self.update_group_state(job.get_owner_id())?
+                        }
                         jobsrv::JobState::CancelComplete => {
                             self.update_group_state(job.get_owner_id())?
                         }
@@ -520,6 +613,18 @@ impl ScheduleMgr {
         Ok(())
     }
 
+    /// Notify the group's origin of its final state. Failing to do so doesn't fail the group.
+    fn notify_group(&mut self, group_id: u64) {
+        // Fetch the group again, for the final states of its projects
+        let res = match self.get_group(group_id) {
+            Ok(group) => self.notifier.notify_group(&group),
+            Err(err) => Err(err),
+        };
+        if let Err(err) = res {
+            warn!("Unable to notify of job group {}, err {:?}", group_id, err);
+        }
+    }
+
     fn update_group_state(&mut self, group_id: u64) -> Result<()> {
         let group = self.get_group(group_id)?;
 
@@ -564,7 +669,11 @@ impl ScheduleMgr {
                 jobsrv::JobGroupState::GroupDispatching
             };
 
-            self.datastore.set_job_group_state(group_id, new_state)?;
+            self.datastore.set_job_group_state(
+                group_id,
+                new_state,
+                &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+            )?;
 
             if new_state == jobsrv::JobGroupState::GroupPending {
                 self.schedule_cli.notify()?;
@@ -574,6 +683,10 @@ impl ScheduleMgr {
                 updated_group.set_state(new_state);
                 self.logger.log_group(&updated_group);
             }
+
+            if new_state == jobsrv::JobGroupState::GroupComplete {
+                self.notify_group(group_id);
+            }
         } else {
             debug!(
                 "Skipping group update because state is {:?} for group id: {}",
@@ -585,3 +698,95 @@ impl ScheduleMgr {
         Ok(())
     }
 }
+
+fn group_origin(group: &jobsrv::JobGroup) -> &str {
+    group.get_project_name().split('/').next().unwrap_or("")
+}
+
+/// Order queued groups so that every origin gets a fair share of the build capacity.
+///
+/// Each origin's groups are taken highest priority first, then oldest first. The n-th of them is
+/// ranked as if the origin already had n more active groups, and groups are served lowest rank
+/// first. An origin with many active groups, or many queued ones, therefore can't hold back an
+/// origin which has few.
+fn fair_queue(
+    mut groups: Vec<jobsrv::JobGroup>,
+    active: &HashMap<String, u64>,
+) -> Vec<jobsrv::JobGroup> {
+    groups.sort_by(|a, b| {
+        b.get_priority().value().cmp(&a.get_priority().value()).then(
+            a.get_id().cmp(&b.get_id()),
+        )
+    });
+
+    let mut shares = active.clone();
+    let mut ranked: Vec<(u64, jobsrv::JobGroup)> = groups
+        .into_iter()
+        .map(|group| {
+            let share = shares.entry(group_origin(&group).to_string()).or_insert(0);
+            let rank = *share;
+            *share += 1;
+            (rank, group)
+        })
+        .collect();
+
+    // The sort is stable, so groups of equal rank stay in priority order
+    ranked.sort_by_key(|&(rank, _)| rank);
+    ranked.into_iter().map(|(_, group)| group).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queued(id: u64, project_name: &str, priority: jobsrv::JobPriority) -> jobsrv::JobGroup {
+        let mut group = jobsrv::JobGroup::new();
+        group.set_id(id);
+        group.set_state(jobsrv::JobGroupState::GroupQueued);
+        group.set_project_name(project_name.to_string());
+        group.set_priority(priority);
+        group
+    }
+
+    fn ids(groups: &[jobsrv::JobGroup]) -> Vec<u64> {
+        groups.iter().map(|g| g.get_id()).collect()
+    }
+
+    #[test]
+    fn fair_queue_interleaves_origins() {
+        let groups = vec![
+            queued(1, "core/a", jobsrv::JobPriority::Normal),
+            queued(2, "core/b", jobsrv::JobPriority::Normal),
+            queued(3, "core/c", jobsrv::JobPriority::Normal),
+            queued(4, "smith/a", jobsrv::JobPriority::Normal),
+            queued(5, "jones/a", jobsrv::JobPriority::Normal),
+            queued(6, "smith/b", jobsrv::JobPriority::Normal),
+        ];
+        assert_eq!(
+            ids(&fair_queue(groups, &HashMap::new())),
+            vec![1, 4, 5, 2, 6, 3]
+        );
+    }
+
+    #[test]
+    fn fair_queue_accounts_for_active_groups() {
+        let groups = vec![
+            queued(1, "core/a", jobsrv::JobPriority::Normal),
+            queued(2, "smith/a", jobsrv::JobPriority::Normal),
+            queued(3, "smith/b", jobsrv::JobPriority::Normal),
+        ];
+        let mut active = HashMap::new();
+        active.insert("core".to_string(), 2);
+        assert_eq!(ids(&fair_queue(groups, &active)), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn fair_queue_orders_by_priority_within_a_share() {
+        let groups = vec![
+            queued(1, "core/a", jobsrv::JobPriority::Normal),
+            queued(2, "core/b", jobsrv::JobPriority::Critical),
+            queued(3, "smith/a", jobsrv::JobPriority::Normal),
+        ];
+        assert_eq!(ids(&fair_queue(groups, &HashMap::new())), vec![2, 3, 1]);
+    }
+}
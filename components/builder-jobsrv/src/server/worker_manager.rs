@@ -12,32 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::path::PathBuf;
-use std::sync::mpsc;
+use std::sync::{mpsc, Arc, RwLock};
 use std::time::{Duration, Instant};
 use std::thread::{self, JoinHandle};
 
 use bldr_core;
 use bldr_core::job::Job;
+use chrono::{DateTime, UTC};
 use hab_net::conn::RouteClient;
 use hab_net::socket::DEFAULT_CONTEXT;
 use linked_hash_map::LinkedHashMap;
 use protobuf::{parse_from_bytes, Message, RepeatedField};
-use protocol::jobsrv;
-use protocol::originsrv::{OriginIntegrationRequest, OriginIntegrationResponse,
-                          OriginProjectIntegrationRequest, OriginProjectIntegrationResponse};
+use protocol::{jobsrv, version, PROTOCOL_VERSION};
+use protocol::net::{self, ErrCode};
+use protocol::originsrv::{OriginEncryptionKey, OriginEncryptionKeyGet, OriginIntegrationRequest,
+                          OriginIntegrationResponse, OriginProjectIntegrationRequest,
+                          OriginProjectIntegrationResponse, OriginSecretListRequest,
+                          OriginSecretListResponse};
 use zmq;
 
 use config::Config;
-use data_store::DataStore;
+use data_store::{Audit, DataStore};
 use error::Result;
 
+use super::metrics;
+use super::quota::{self, Quotas};
 use super::scheduler::ScheduleClient;
 
 const WORKER_MGR_ADDR: &'static str = "inproc://work-manager";
 const WORKER_TIMEOUT_MS: u64 = 33_000; // 33 sec
 const DEFAULT_POLL_TIMEOUT_MS: u64 = 60_000; // 60 secs
 const JOB_TIMEOUT_CONVERT_MS: u64 = 60_000; // Conversion from mins to milli-seconds
+// Workers stop builds which run past their timeout themselves. Only cancel the job from here if
+// the worker still hasn't finished it this many minutes later.
+const JOB_TIMEOUT_GRACE_MINS: u64 = 5;
 
 pub struct WorkerMgrClient {
     socket: zmq::Socket,
@@ -62,62 +73,162 @@ impl Default for WorkerMgrClient {
     }
 }
 
+/// A job dispatched to a worker which the worker hasn't finished yet
+#[derive(Debug)]
+pub struct WorkerJob {
+    pub dispatched_at: Instant,
+    pub expiry: Instant,
+}
+
 #[derive(Debug)]
 pub struct Worker {
     pub ident: String,
-    pub state: jobsrv::WorkerState,
+    pub targets: Vec<String>,
+    pub labels: Vec<String>,
+    pub disk_free: Option<u64>,
+    pub jobs_in_flight: u32,
+    /// Builds the worker can run at once
+    pub capacity: u32,
     pub expiry: Instant,
-    pub job_id: Option<u64>,
-    pub job_expiry: Option<Instant>,
+    pub jobs: BTreeMap<u64, WorkerJob>,
+    pub last_heartbeat: Option<DateTime<UTC>>,
+    /// Whether the worker has stopped taking jobs, such as before it's shut down
+    pub draining: bool,
+    /// Dependencies of builds found in the worker's artifact cache and those it had to download,
+    /// since the worker started
+    pub artifact_cache_lookups: (u64, u64),
 }
 
 impl Worker {
     pub fn new(ident: &str) -> Self {
         Worker {
             ident: ident.to_string(),
-            state: jobsrv::WorkerState::Ready,
+            targets: vec![jobsrv::DEFAULT_TARGET.to_string()],
+            labels: vec![],
+            disk_free: None,
+            jobs_in_flight: 0,
+            capacity: 1,
             expiry: Instant::now() + Duration::from_millis(WORKER_TIMEOUT_MS),
-            job_id: None,
-            job_expiry: None,
+            jobs: BTreeMap::new(),
+            last_heartbeat: None,
+            draining: false,
+            artifact_cache_lookups: (0, 0),
         }
     }
 
-    pub fn ready(&mut self) {
-        self.state = jobsrv::WorkerState::Ready;
-        self.expiry = Instant::now() + Duration::from_millis(WORKER_TIMEOUT_MS);
-        self.job_id = None;
-        self.job_expiry = None;
+    /// Ready while the worker can take another job, Busy once it's running as many as it can or
+    /// while it's draining
+    pub fn state(&self) -> jobsrv::WorkerState {
+        if !self.draining && (self.jobs.len() as u32) < self.capacity {
+            jobsrv::WorkerState::Ready
+        } else {
+            jobsrv::WorkerState::Busy
+        }
     }
 
+    /// Record a job dispatched to the worker
     pub fn busy(&mut self, job_id: u64, job_timeout: u64) {
-        self.state = jobsrv::WorkerState::Busy;
         self.expiry = Instant::now() + Duration::from_millis(WORKER_TIMEOUT_MS);
+        self.jobs.entry(job_id).or_insert_with(|| {
+            WorkerJob {
+                dispatched_at: Instant::now(),
+                expiry: Instant::now() +
+                    Duration::from_millis(
+                        (job_timeout + JOB_TIMEOUT_GRACE_MINS) * JOB_TIMEOUT_CONVERT_MS,
+                    ),
+            }
+        });
+    }
 
-        if self.job_id.is_none() {
-            self.job_id = Some(job_id);
-            self.job_expiry = Some(
-                Instant::now() +
-                    Duration::from_millis(job_timeout * JOB_TIMEOUT_CONVERT_MS),
-            );
-        } else {
-            assert!(self.job_id.unwrap() == job_id);
-        }
+    /// Record that the worker is done with a job
+    pub fn finish(&mut self, job_id: u64) {
+        self.jobs.remove(&job_id);
+    }
+
+    pub fn has_job(&self, job_id: u64) -> bool {
+        self.jobs.contains_key(&job_id)
+    }
+
+    pub fn job_ids(&self) -> Vec<u64> {
+        self.jobs.keys().cloned().collect()
     }
 
     pub fn refresh(&mut self) {
         self.expiry = Instant::now() + Duration::from_millis(WORKER_TIMEOUT_MS);
     }
 
+    /// Record the capacity and load reported in a worker's heartbeat
+    pub fn update_capacity(&mut self, heartbeat: &jobsrv::Heartbeat) {
+        self.targets = worker_targets(heartbeat);
+        self.labels = heartbeat.get_labels().to_vec();
+        self.labels.sort();
+        self.labels.dedup();
+        self.jobs_in_flight = heartbeat.get_jobs_in_flight();
+        // Older workers run a single build at a time and don't report their capacity
+        self.capacity = cmp::max(heartbeat.get_capacity(), 1);
+        self.draining = heartbeat.get_draining();
+        self.artifact_cache_lookups = (
+            heartbeat.get_artifact_cache_hits(),
+            heartbeat.get_artifact_cache_misses(),
+        );
+        self.disk_free = if heartbeat.has_disk_free() {
+            Some(heartbeat.get_disk_free())
+        } else {
+            None
+        };
+    }
+
+    /// Whether the worker has enough free disk (in megabytes) to be dispatched a job. Workers
+    /// which don't report their free disk are assumed to have enough.
+    pub fn has_capacity(&self, min_disk_free: u64) -> bool {
+        match self.disk_free {
+            Some(disk_free) => disk_free >= min_disk_free * 1024 * 1024,
+            None => true,
+        }
+    }
+
+    /// Whether the worker failed to pick up a dispatched job within a heartbeat interval
+    pub fn is_dispatch_stranded(&self, job_id: u64) -> bool {
+        match self.jobs.get(&job_id) {
+            Some(job) => {
+                job.dispatched_at + Duration::from_millis(WORKER_TIMEOUT_MS) < Instant::now()
+            }
+            None => false,
+        }
+    }
+
     pub fn is_expired(&self) -> bool {
         self.expiry < Instant::now()
     }
 
-    pub fn is_job_expired(&self) -> bool {
-        if self.job_expiry.is_some() {
-            self.job_expiry.unwrap() < Instant::now()
-        } else {
-            false
+    /// Jobs which have run on the worker for longer than they're allowed to
+    pub fn expired_jobs(&self) -> Vec<u64> {
+        let now = Instant::now();
+        self.jobs
+            .iter()
+            .filter(|&(_, job)| job.expiry < now)
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    pub fn status(&self) -> jobsrv::WorkerStatus {
+        let mut status = jobsrv::WorkerStatus::new();
+        status.set_ident(self.ident.clone());
+        status.set_targets(RepeatedField::from_vec(self.targets.clone()));
+        status.set_labels(RepeatedField::from_vec(self.labels.clone()));
+        status.set_state(self.state());
+        status.set_capacity(self.capacity);
+        status.set_draining(self.draining);
+        status.set_artifact_cache_hits(self.artifact_cache_lookups.0);
+        status.set_artifact_cache_misses(self.artifact_cache_lookups.1);
+        if let Some(job_id) = self.jobs.keys().next() {
+            status.set_job_id(*job_id);
+        }
+        status.set_job_ids(self.job_ids());
+        if let Some(last_heartbeat) = self.last_heartbeat {
+            status.set_last_heartbeat(last_heartbeat.to_rfc3339());
         }
+        status
     }
 }
 
@@ -130,14 +241,24 @@ pub struct WorkerMgr {
     work_mgr_sock: zmq::Socket,
     msg: zmq::Message,
     workers: LinkedHashMap<String, Worker>,
+    worker_list: Arc<RwLock<jobsrv::WorkerListResponse>>,
     worker_command: String,
     worker_heartbeat: String,
     schedule_cli: ScheduleClient,
     job_timeout: u64,
+    job_memory_limit_mb: u64,
+    job_cpu_limit_millicores: u32,
+    worker_min_disk_free: u64,
+    quotas: Quotas,
 }
 
 impl WorkerMgr {
-    pub fn new(cfg: &Config, datastore: DataStore, route_conn: RouteClient) -> Result<Self> {
+    pub fn new(
+        cfg: &Config,
+        datastore: DataStore,
+        route_conn: RouteClient,
+        worker_list: Arc<RwLock<jobsrv::WorkerListResponse>>,
+    ) -> Result<Self> {
         let hb_sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::SUB)?;
         let rq_sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::ROUTER)?;
         let work_mgr_sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::DEALER)?;
@@ -156,15 +277,25 @@ impl WorkerMgr {
             work_mgr_sock: work_mgr_sock,
             msg: zmq::Message::new()?,
             workers: LinkedHashMap::new(),
+            worker_list: worker_list,
             worker_command: cfg.net.worker_command_addr(),
             worker_heartbeat: cfg.net.worker_heartbeat_addr(),
             schedule_cli: schedule_cli,
             job_timeout: cfg.job_timeout,
+            job_memory_limit_mb: cfg.job_memory_limit_mb,
+            job_cpu_limit_millicores: cfg.job_cpu_limit_millicores,
+            worker_min_disk_free: cfg.worker_min_disk_free,
+            quotas: Quotas::new(cfg.quota.clone()),
         })
     }
 
-    pub fn start(cfg: &Config, datastore: DataStore, conn: RouteClient) -> Result<JoinHandle<()>> {
-        let mut manager = Self::new(cfg, datastore, conn)?;
+    pub fn start(
+        cfg: &Config,
+        datastore: DataStore,
+        conn: RouteClient,
+        worker_list: Arc<RwLock<jobsrv::WorkerListResponse>>,
+    ) -> Result<JoinHandle<()>> {
+        let mut manager = Self::new(cfg, datastore, conn, worker_list)?;
         let (tx, rx) = mpsc::sync_channel(1);
         let handle = thread::Builder::new()
             .name("worker-manager".to_string())
@@ -255,54 +386,91 @@ impl WorkerMgr {
                 if let Err(err) = self.process_cancelations() {
                     warn!("Worker-manager unable to process cancels: err {:?}", err);
                 }
+                if let Err(err) = self.expire_jobs() {
+                    warn!("Worker-manager unable to expire jobs: err {:?}", err);
+                }
                 if let Err(err) = self.process_work() {
                     warn!("Worker-manager unable to process work: err {:?}", err);
                 }
                 last_processed = now;
             }
+
+            self.publish_workers();
         }
     }
 
+    /// Share the current state of the workers with the request handlers
+    fn publish_workers(&mut self) {
+        let workers = self.workers.iter().map(|(_, w)| w.status()).collect();
+        self.worker_list.write().unwrap().set_workers(
+            RepeatedField::from_vec(workers),
+        );
+    }
+
     fn load_workers(&mut self) -> Result<()> {
         let workers = self.datastore.get_busy_workers()?;
 
         for worker in workers {
-            let mut bw = Worker::new(worker.get_ident());
-            bw.busy(worker.get_job_id(), self.job_timeout);
+            let job_timeout = self.build_timeout(worker.get_job_id())?;
+            let mut bw = self.workers.remove(worker.get_ident()).unwrap_or_else(|| {
+                Worker::new(worker.get_ident())
+            });
+            bw.busy(worker.get_job_id(), job_timeout);
+            // Assume the worker can run all the jobs it was handed until it says otherwise
+            bw.capacity = cmp::max(bw.capacity, bw.jobs.len() as u32);
             self.workers.insert(worker.get_ident().to_owned(), bw);
         }
 
         Ok(())
     }
 
-    fn save_worker(&mut self, worker: &Worker) -> Result<()> {
+    /// Returns the minutes the given job may run for, which is the default unless its project
+    /// overrides it.
+    fn build_timeout(&self, job_id: u64) -> Result<u64> {
+        let mut req = jobsrv::JobGet::new();
+        req.set_id(job_id);
+        match self.datastore.get_job(&req)? {
+            Some(ref job) if job.has_build_timeout() => Ok(job.get_build_timeout() as u64),
+            _ => Ok(self.job_timeout),
+        }
+    }
+
+    fn save_worker(&mut self, worker: &Worker, job_id: u64) -> Result<()> {
         let mut bw = jobsrv::BusyWorker::new();
         bw.set_ident(worker.ident.clone());
-        bw.set_job_id(worker.job_id.unwrap()); // unwrap Ok
+        bw.set_job_id(job_id);
 
         self.datastore.upsert_busy_worker(&bw)
     }
 
-    fn delete_worker(&mut self, worker: &Worker) -> Result<()> {
+    fn delete_worker(&mut self, worker: &Worker, job_id: u64) -> Result<()> {
         let mut bw = jobsrv::BusyWorker::new();
         bw.set_ident(worker.ident.clone());
-        bw.set_job_id(worker.job_id.unwrap()); // unwrap Ok
+        bw.set_job_id(job_id);
 
         self.datastore.delete_busy_worker(&bw)
     }
 
+    /// Forget every job of a worker, e.g. one which is going away
+    fn delete_worker_jobs(&mut self, worker: &Worker) -> Result<()> {
+        for job_id in worker.job_ids() {
+            self.delete_worker(worker, job_id)?;
+        }
+        Ok(())
+    }
+
+    /// Requeue the jobs handed to workers which are no longer known, e.g. jobs which were running
+    /// on a worker that went away while the job server was down.
     fn requeue_jobs(&mut self) -> Result<()> {
-        let jobs = self.datastore.get_dispatched_jobs()?;
+        let jobs = self.datastore.get_running_jobs()?;
 
-        for mut job in jobs {
+        for job in jobs {
             if self.workers
                 .iter()
-                .find(|t| t.1.job_id == Some(job.get_id()))
+                .find(|t| t.1.has_job(job.get_id()))
                 .is_none()
             {
-                warn!("Requeing job: {}", job.get_id());
-                job.set_state(jobsrv::JobState::Pending);
-                self.datastore.update_job(&job)?;
+                self.requeue_job(job.get_id())?;
             };
         }
 
@@ -322,14 +490,15 @@ impl WorkerMgr {
 
             // Find the worker processing this job
             // TODO (SA): Would be nice not doing an iterative search here
-            let worker_ident = match self.workers.iter().find(
-                |t| t.1.job_id == Some(job.get_id()),
-            ) {
+            let worker_ident = match self.workers.iter().find(|t| t.1.has_job(job.get_id())) {
                 Some(t) => t.0.clone(),
                 None => {
                     warn!("Did not find any workers with job id: {}", job.get_id());
                     job.set_state(jobsrv::JobState::CancelComplete);
-                    self.datastore.update_job(&job)?;
+                    self.datastore.update_job(
+                        &job,
+                        &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+                    )?;
                     continue;
                 }
             };
@@ -337,7 +506,10 @@ impl WorkerMgr {
             match self.worker_cancel_job(&job, &worker_ident) {
                 Ok(()) => {
                     job.set_state(jobsrv::JobState::CancelProcessing);
-                    self.datastore.update_job(&job)?;
+                    self.datastore.update_job(
+                        &job,
+                        &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+                    )?;
                 }
                 Err(err) => {
                     warn!(
@@ -346,7 +518,10 @@ impl WorkerMgr {
                         err
                     );
                     job.set_state(jobsrv::JobState::CancelComplete);
-                    self.datastore.update_job(&job)?;
+                    self.datastore.update_job(
+                        &job,
+                        &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+                    )?;
                 }
             }
         }
@@ -354,11 +529,47 @@ impl WorkerMgr {
         Ok(())
     }
 
+    /// Fail jobs which have waited for a worker past their expiry time. Jobs already handed to a
+    /// worker are left alone; the worker's own timeouts take care of those.
+    fn expire_jobs(&mut self) -> Result<()> {
+        let jobs = self.datastore.get_expired_jobs()?;
+
+        for mut job in jobs {
+            if self.workers
+                .iter()
+                .find(|t| t.1.has_job(job.get_id()))
+                .is_some()
+            {
+                continue;
+            }
+
+            warn!(
+                "Job {} expired in state {:?} at {}, marking as failed",
+                job.get_id(),
+                job.get_state(),
+                job.get_expires_at()
+            );
+            job.set_state(jobsrv::JobState::Failed);
+            job.set_error(
+                net::err(ErrCode::TIMEOUT, "jb:expire-jobs:1").into(),
+            );
+            job.set_last_error_category(jobsrv::JobErrorCategory::Transient);
+            self.datastore.update_job(
+                &job,
+                &Audit::new(jobsrv::JobAuditTrigger::Expired),
+            )?;
+            self.schedule_cli.notify()?;
+        }
+
+        Ok(())
+    }
+
     fn worker_cancel_job(&mut self, job: &Job, worker_ident: &str) -> Result<()> {
         debug!("Canceling job on worker {:?}: {:?}", worker_ident, job);
 
         let mut wc = jobsrv::WorkerCommand::new();
         wc.set_op(jobsrv::WorkerOperation::CancelJob);
+        wc.set_protocol_version(PROTOCOL_VERSION);
 
         self.rq_sock.send_str(&worker_ident, zmq::SNDMORE)?;
         self.rq_sock.send(&[], zmq::SNDMORE)?;
@@ -372,32 +583,69 @@ impl WorkerMgr {
     }
 
     fn process_work(&mut self) -> Result<()> {
+        // Targets for which there are no more pending jobs a worker with the given labels can take
+        let mut drained = HashSet::new();
+        // Usage of the origins with running or recent jobs, counted up as jobs are dispatched so
+        // that an origin can't go over its quota within one pass
+        let mut usage = HashMap::new();
+        for mut origin_usage in self.datastore.get_origin_build_usage(None)? {
+            self.quotas.apply(&mut origin_usage);
+            usage.insert(origin_usage.get_origin().to_string(), origin_usage);
+        }
+
         loop {
-            // Exit if we don't have any Ready workers
-            let worker_ident = match self.workers.iter().find(|t| {
-                t.1.state == jobsrv::WorkerState::Ready
-            }) {
-                Some(t) => t.0.clone(),
+            // Exit if we don't have any Ready workers for a target with pending jobs
+            let (worker_ident, target, labels) = match self.next_ready_worker(&drained) {
+                Some(t) => t,
                 None => return Ok(()),
             };
 
-            // Take one job from the pending list
-            let job_opt = self.datastore.next_pending_job(&worker_ident)?;
+            // Take one job for the worker's target from the pending list, passing over the jobs
+            // of origins at their quota
+            let exhausted: Vec<String> = usage
+                .values()
+                .filter(|u| quota::is_exhausted(u))
+                .map(|u| u.get_origin().to_string())
+                .collect();
+            let job_opt = self.datastore.next_pending_job(
+                &worker_ident,
+                &target,
+                &exhausted,
+                &labels,
+            )?;
             if job_opt.is_none() {
-                break;
+                drained.insert((target, labels));
+                continue;
             }
 
             let mut job = Job::new(job_opt.unwrap()); // unwrap Ok
 
             self.add_integrations_to_job(&mut job);
             self.add_project_integrations_to_job(&mut job);
+            self.add_secrets_to_job(&mut job);
+            if !job.has_build_timeout() {
+                job.set_build_timeout(self.job_timeout as u32);
+            }
+            if !job.has_memory_limit_mb() && self.job_memory_limit_mb > 0 {
+                job.set_memory_limit_mb(self.job_memory_limit_mb);
+            }
+            if !job.has_cpu_limit_millicores() && self.job_cpu_limit_millicores > 0 {
+                job.set_cpu_limit_millicores(self.job_cpu_limit_millicores);
+            }
 
             match self.worker_start_job(&job, &worker_ident) {
                 Ok(()) => {
                     let mut worker = self.workers.remove(&worker_ident).unwrap(); // unwrap Ok
-                    worker.busy(job.get_id(), self.job_timeout);
-                    self.save_worker(&worker)?;
+                    worker.busy(job.get_id(), job.get_build_timeout() as u64);
+                    self.save_worker(&worker, job.get_id())?;
                     self.workers.insert(worker_ident, worker);
+                    metrics::observe_dispatch(&job);
+
+                    let origin = job.get_project().get_origin_name();
+                    let quotas = &self.quotas;
+                    quota::record_dispatch(usage.entry(origin.to_string()).or_insert_with(
+                        || quotas.unused(origin),
+                    ));
                 }
                 Err(err) => {
                     warn!(
@@ -406,7 +654,10 @@ impl WorkerMgr {
                         err
                     );
                     job.set_state(jobsrv::JobState::Pending);
-                    self.datastore.update_job(&job)?;
+                    self.datastore.update_job(
+                        &job,
+                        &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+                    )?;
                     return Ok(()); // Exit instead of re-trying immediately
                 }
             }
@@ -414,11 +665,36 @@ impl WorkerMgr {
         Ok(())
     }
 
+    /// Find a worker with a free build slot and enough disk to take a job, along with a target it
+    /// can build that may still have pending jobs for a worker with its labels.
+    fn next_ready_worker(
+        &self,
+        drained: &HashSet<(String, Vec<String>)>,
+    ) -> Option<(String, String, Vec<String>)> {
+        for (ident, worker) in self.workers.iter() {
+            if worker.state() != jobsrv::WorkerState::Ready {
+                continue;
+            }
+            if !worker.has_capacity(self.worker_min_disk_free) {
+                debug!("Skipping worker {} with low disk space", ident);
+                continue;
+            }
+            let target = worker.targets.iter().find(|t| {
+                !drained.contains(&((*t).clone(), worker.labels.clone()))
+            });
+            if let Some(target) = target {
+                return Some((ident.clone(), target.clone(), worker.labels.clone()));
+            }
+        }
+        None
+    }
+
     fn worker_start_job(&mut self, job: &Job, worker_ident: &str) -> Result<()> {
         debug!("Dispatching job to worker {:?}: {:?}", worker_ident, job);
 
         let mut wc = jobsrv::WorkerCommand::new();
         wc.set_op(jobsrv::WorkerOperation::StartJob);
+        wc.set_protocol_version(PROTOCOL_VERSION);
 
         self.rq_sock.send_str(&worker_ident, zmq::SNDMORE)?;
         self.rq_sock.send(&[], zmq::SNDMORE)?;
@@ -465,6 +741,85 @@ impl WorkerMgr {
         }
     }
 
+    fn add_secrets_to_job(&mut self, job: &mut Job) {
+        let mut secrets = RepeatedField::new();
+        let mut request = OriginSecretListRequest::new();
+        request.set_origin(job.get_project().get_origin_name().to_string());
+
+        // Secrets are encrypted with the revision of the origin's encryption key which was its
+        // latest when they were stored, and the ones stored before origins had encryption keys
+        // with the builder key
+        let mut keys = HashMap::new();
+        match self.route_conn.route::<OriginSecretListRequest, OriginSecretListResponse>(
+            &request,
+        ) {
+            Ok(osl) => {
+                for s in osl.get_secrets() {
+                    let mut secret = s.clone();
+                    let revision = bldr_core::integrations::encrypted_key_revision(
+                        s.get_origin(),
+                        s.get_value(),
+                    );
+                    let decrypted = match revision {
+                        Some(revision) => {
+                            if !keys.contains_key(&revision) {
+                                let key = self.origin_encryption_key(s.get_origin(), &revision);
+                                keys.insert(revision.clone(), key);
+                            }
+                            match keys[&revision] {
+                                Some(ref key) => {
+                                    bldr_core::integrations::decrypt_with_key(
+                                        key.get_public_key(),
+                                        key.get_secret_key(),
+                                        s.get_value(),
+                                    )
+                                }
+                                None => {
+                                    warn!(
+                                        "No encryption key {} for secret {}",
+                                        revision,
+                                        s.get_name()
+                                    );
+                                    continue;
+                                }
+                            }
+                        }
+                        None => bldr_core::integrations::decrypt(&self.key_dir, s.get_value()),
+                    };
+                    match decrypted {
+                        Ok(plaintext) => secret.set_value(plaintext),
+                        Err(e) => {
+                            warn!("Error decrypting secret {}. e = {:?}", s.get_name(), e);
+                            continue;
+                        }
+                    }
+                    secrets.push(secret);
+                }
+                job.set_secrets(secrets);
+            }
+            Err(e) => {
+                debug!("Error fetching secrets. e = {:?}", e);
+            }
+        }
+    }
+
+    fn origin_encryption_key(
+        &mut self,
+        origin: &str,
+        revision: &str,
+    ) -> Option<OriginEncryptionKey> {
+        let mut request = OriginEncryptionKeyGet::new();
+        request.set_origin(origin.to_string());
+        request.set_revision(revision.to_string());
+        match self.route_conn.route::<OriginEncryptionKeyGet, OriginEncryptionKey>(&request) {
+            Ok(key) => Some(key),
+            Err(e) => {
+                debug!("Error fetching origin encryption key. e = {:?}", e);
+                None
+            }
+        }
+    }
+
     fn add_project_integrations_to_job(&mut self, job: &mut Job) {
         let mut integrations = RepeatedField::new();
         let mut req = OriginProjectIntegrationRequest::new();
@@ -500,33 +855,51 @@ impl WorkerMgr {
             let worker = self.workers.pop_front().unwrap().1;
             debug!("Expiring worker due to missed heartbeat: {:?}", worker);
 
-            if worker.state == jobsrv::WorkerState::Busy {
-                self.requeue_job(worker.job_id.unwrap())?; // unwrap Ok
-                self.delete_worker(&worker)?;
+            for job_id in worker.job_ids() {
+                self.requeue_job(job_id)?;
             }
+            self.delete_worker_jobs(&worker)?;
         }
 
         Ok(())
     }
 
+    /// Requeue a job orphaned by a lost worker. Losing the worker is a transient failure, so it
+    /// counts against the job's retries and the job fails once it has none left. The transition
+    /// is audited with the worker the job was lost on.
     fn requeue_job(&mut self, job_id: u64) -> Result<()> {
         let mut req = jobsrv::JobGet::new();
         req.set_id(job_id);
 
         match self.datastore.get_job(&req)? {
             Some(mut job) => {
+                let audit = Audit::new(jobsrv::JobAuditTrigger::WorkerLost);
                 match job.get_state() {
                     jobsrv::JobState::Processing |
                     jobsrv::JobState::Dispatched => {
-                        debug!("Requeing job {:?}", job_id);
-                        job.set_state(jobsrv::JobState::Pending);
-                        self.datastore.update_job(&job)?;
+                        warn!(
+                            "Job {} was orphaned by lost worker {}",
+                            job_id,
+                            job.get_worker()
+                        );
+                        metrics::observe_orphaned_job();
+                        if !self.retry_job(&mut job, &audit)? {
+                            warn!("Job {:?} has no retries left, marking as failed", job_id);
+                            job.set_state(jobsrv::JobState::Failed);
+                            job.set_error(
+                                net::err(ErrCode::REMOTE_UNAVAILABLE, "jb:requeue-job:1")
+                                    .into(),
+                            );
+                            job.set_last_error_category(jobsrv::JobErrorCategory::Transient);
+                            self.datastore.update_job(&job, &audit)?;
+                            self.schedule_cli.notify()?;
+                        }
                     }
                     jobsrv::JobState::CancelPending |
                     jobsrv::JobState::CancelProcessing => {
                         debug!("Marking orhpaned job as canceled: {:?}", job_id);
                         job.set_state(jobsrv::JobState::CancelComplete);
-                        self.datastore.update_job(&job)?;
+                        self.datastore.update_job(&job, &audit)?;
                     }
                     jobsrv::JobState::Pending |
                     jobsrv::JobState::Complete |
@@ -546,6 +919,29 @@ impl WorkerMgr {
         Ok(())
     }
 
+    /// Puts a job which failed for a transient reason back in the queue if it has any retries
+    /// left. Returns `false` if the job has used up its retries.
+    fn retry_job(&mut self, job: &mut jobsrv::Job, audit: &Audit) -> Result<bool> {
+        if !job.can_retry() {
+            return Ok(false);
+        }
+        let retries = job.get_retries() + 1;
+        debug!(
+            "Requeing job {:?} (retry {} of {})",
+            job.get_id(),
+            retries,
+            job.get_max_retries()
+        );
+        job.set_retries(retries);
+        job.set_last_error_category(jobsrv::JobErrorCategory::Transient);
+        job.set_state(jobsrv::JobState::Pending);
+        job.clear_error();
+        job.clear_build_started_at();
+        job.clear_build_finished_at();
+        self.datastore.update_job(job, audit)?;
+        Ok(true)
+    }
+
     fn cancel_job(&mut self, job_id: u64, worker_ident: &str) -> Result<()> {
         let mut req = jobsrv::JobGet::new();
         req.set_id(job_id);
@@ -556,7 +952,10 @@ impl WorkerMgr {
                 match self.worker_cancel_job(&job, &worker_ident) {
                     Ok(()) => {
                         job.set_state(jobsrv::JobState::CancelProcessing);
-                        self.datastore.update_job(&job)?;
+                        self.datastore.update_job(
+                            &job,
+                            &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+                        )?;
                     }
                     Err(err) => {
                         warn!(
@@ -565,7 +964,10 @@ impl WorkerMgr {
                             err
                         );
                         job.set_state(jobsrv::JobState::CancelComplete);
-                        self.datastore.update_job(&job)?;
+                        self.datastore.update_job(
+                            &job,
+                            &Audit::new(jobsrv::JobAuditTrigger::Scheduler),
+                        )?;
                     }
                 }
             }
@@ -618,14 +1020,50 @@ impl WorkerMgr {
 
         let worker_ident = heartbeat.get_endpoint().to_string();
 
+        // Don't take on workers we can't speak to; they'd silently drop what they don't understand
+        if let Err(err) = version::negotiate(heartbeat.get_protocol_version()) {
+            if let Some(worker) = self.workers.remove(&worker_ident) {
+                self.delete_worker_jobs(&worker)?;
+            }
+            error!("Ignoring heartbeat from worker {}, {}", worker_ident, err);
+            return Ok(());
+        }
+
+        if heartbeat.get_leaving() {
+            if let Some(worker) = self.workers.remove(&worker_ident) {
+                info!("Worker {} is leaving, deregistering it", worker_ident);
+                // A worker drains before it leaves, so it shouldn't have any jobs left
+                for job_id in worker.job_ids() {
+                    self.requeue_job(job_id)?;
+                }
+                self.delete_worker_jobs(&worker)?;
+            }
+            return Ok(());
+        }
+
+        // The jobs the worker says it's running. Older workers only report whether they're
+        // running their single job.
+        let running: Vec<u64> = if heartbeat.has_capacity() {
+            heartbeat.get_job_ids().to_vec()
+        } else if heartbeat.get_state() == jobsrv::WorkerState::Busy {
+            self.workers.get(&worker_ident).map_or(
+                vec![],
+                |worker| worker.job_ids(),
+            )
+        } else {
+            vec![]
+        };
+
         let mut worker = match self.workers.remove(&worker_ident) {
             Some(worker) => worker,
             None => {
-                if heartbeat.get_state() == jobsrv::WorkerState::Ready {
+                if heartbeat.get_state() == jobsrv::WorkerState::Ready &&
+                    heartbeat.get_job_ids().is_empty()
+                {
                     Worker::new(&worker_ident)
                 } else {
                     warn!(
-                        "Unexpacted Busy heartbeat from unknown worker {}",
+                        "Unexpacted busy heartbeat from unknown worker {}",
                         worker_ident
                     );
                     return Ok(()); // Something went wrong, don't process this HB
@@ -633,38 +1071,47 @@ impl WorkerMgr {
             }
         };
 
-        match (worker.state, heartbeat.get_state()) {
-            (jobsrv::WorkerState::Ready, jobsrv::WorkerState::Busy) => {
-                warn!(
-                    "Unexpected Busy heartbeat from known worker {}",
-                    worker_ident
-                );
-                return Ok(()); // Something went wrong, don't process this HB
-            }
-            (jobsrv::WorkerState::Busy, jobsrv::WorkerState::Busy) => {
-                let job_id = worker.job_id.unwrap(); // unwrap Ok
-                if worker.is_job_expired() {
+        for job_id in running.iter().filter(|id| !worker.has_job(**id)) {
+            warn!(
+                "Unexpected job {} in heartbeat from worker {}",
+                job_id,
+                worker_ident
+            );
+        }
+
+        let expired_jobs = worker.expired_jobs();
+        for job_id in worker.job_ids() {
+            if running.contains(&job_id) {
+                if expired_jobs.contains(&job_id) {
                     debug!("Canceling job due to timeout: {}", job_id);
                     self.cancel_job(job_id, &worker_ident)?;
-                };
-                worker.refresh();
-            }
-            (jobsrv::WorkerState::Busy, jobsrv::WorkerState::Ready) => {
-                if !self.is_job_complete(worker.job_id.unwrap())? {
-                    // Handle potential race condition where a Ready heartbeat
-                    // is received right *after* the job has been dispatched
-                    warn!(
-                        "Unexpected Ready heartbeat from incomplete job: {}",
-                        worker.job_id.unwrap()
-                    );
-                    worker.refresh();
-                } else {
-                    self.delete_worker(&worker)?;
-                    worker.ready();
                 }
+            } else if self.is_job_complete(job_id)? {
+                self.delete_worker(&worker, job_id)?;
+                worker.finish(job_id);
+            } else if worker.is_dispatch_stranded(job_id) {
+                // The worker never picked up the job it was dispatched, so put the job back in
+                // the queue rather than leaving it stranded
+                warn!(
+                    "Worker {} did not start job {}, requeueing",
+                    worker_ident,
+                    job_id
+                );
+                self.requeue_job(job_id)?;
+                self.delete_worker(&worker, job_id)?;
+                worker.finish(job_id);
+            } else {
+                // Handle potential race condition where a heartbeat without the job is
+                // received right *after* the job has been dispatched
+                warn!(
+                    "Unexpected heartbeat without incomplete job: {}",
+                    job_id
+                );
             }
-            _ => worker.ready(),
-        };
+        }
+        worker.refresh();
+        worker.update_capacity(&heartbeat);
+        worker.last_heartbeat = Some(UTC::now());
 
         assert!(!worker.is_expired());
         self.workers.insert(worker_ident, worker);
@@ -675,11 +1122,48 @@ impl WorkerMgr {
         self.rq_sock.recv(&mut self.msg, 0)?;
         self.rq_sock.recv(&mut self.msg, 0)?;
 
-        let job = Job::new(parse_from_bytes::<jobsrv::Job>(&self.msg)?);
+        let mut job = Job::new(parse_from_bytes::<jobsrv::Job>(&self.msg)?);
         debug!("Got job status: {:?}", job);
-        self.datastore.update_job(&job)?;
+
+        if job.get_state() == jobsrv::JobState::Failed && job.has_error() {
+            // Older workers don't categorize their errors, so always derive the category here
+            let category = jobsrv::JobErrorCategory::from(job.get_error().get_code());
+            job.mut_error().set_category(category);
+            if job.get_error().is_retryable() &&
+                self.retry_job(&mut job, &Audit::new(jobsrv::JobAuditTrigger::Retry))?
+            {
+                return Ok(());
+            }
+            job.set_last_error_category(category);
+        }
+
+        let trigger = if job.get_state() == jobsrv::JobState::Failed &&
+            job.get_error().get_code() == ErrCode::BUILD_TIMEOUT
+        {
+            jobsrv::JobAuditTrigger::TimedOut
+        } else {
+            jobsrv::JobAuditTrigger::Worker
+        };
+        self.datastore.update_job(&job, &Audit::new(trigger))?;
         self.schedule_cli.notify()?;
 
         Ok(())
     }
 }
+
+/// Returns the platform targets a worker advertised in its heartbeat. Older workers only report
+/// their OS, so fall back to the x86_64 target for that OS.
+fn worker_targets(heartbeat: &jobsrv::Heartbeat) -> Vec<String> {
+    if !heartbeat.get_targets().is_empty() {
+        return heartbeat.get_targets().to_vec();
+    }
+    if heartbeat.has_target() {
+        return vec![heartbeat.get_target().to_string()];
+    }
+    let target = match heartbeat.get_os() {
+        jobsrv::Os::Linux => "x86_64-linux",
+        jobsrv::Os::Darwin => "x86_64-darwin",
+        jobsrv::Os::Windows => "x86_64-windows",
+    };
+    vec![target.to_string()]
+}
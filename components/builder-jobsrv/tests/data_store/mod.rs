@@ -12,9 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+extern crate chrono;
 extern crate protobuf;
+use self::chrono::{Duration, UTC};
 use self::protobuf::RepeatedField;
-use jobsrv::data_store::DataStore;
+use jobsrv::data_store::{Audit, DataStore};
 use protocol::jobsrv;
 
 #[test]
@@ -33,7 +35,11 @@ fn create_job() {
     job.mut_project().set_name("core/habitat".to_string());
     let ds = datastore_test!(DataStore);
     ds.setup().expect("Failed to migrate data");
-    ds.create_job(&mut job).expect("Failed to create a job");
+    ds.create_job(&mut job, &scheduler()).expect("Failed to create a job");
+}
+
+fn scheduler() -> Audit {
+    Audit::new(jobsrv::JobAuditTrigger::Scheduler)
 }
 
 fn test_job() -> jobsrv::Job {
@@ -56,9 +62,9 @@ fn get_job() {
 
     let ds = datastore_test!(DataStore);
     ds.setup().expect("Failed to migrate data");
-    let rjob1 = ds.create_job(&mut job).expect("Failed to create a job");
-    let rjob2 = ds.create_job(&mut job2).expect("Failed to create a job");
-    let rjob3 = ds.create_job(&mut job3).expect("Failed to create a job");
+    let rjob1 = ds.create_job(&mut job, &scheduler()).expect("Failed to create a job");
+    let rjob2 = ds.create_job(&mut job2, &scheduler()).expect("Failed to create a job");
+    let rjob3 = ds.create_job(&mut job3, &scheduler()).expect("Failed to create a job");
     let mut get_job = jobsrv::JobGet::new();
     get_job.set_id(rjob1.get_id());
     let j1 = ds.get_job(&get_job).expect("Failed to get job 0").expect(
@@ -98,12 +104,12 @@ fn next_pending_job() {
     let mut job2 = test_job();
     let ds = datastore_test!(DataStore);
     ds.setup().expect("Failed to migrate data");
-    let rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
-    let rjob2 = ds.create_job(&mut job2).expect("Failed to create job");
+    let rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
 
     // Get one job, it should be FIFO, and it should have its status set to Dispatched,
     // and worker set to the passed in worker id
-    let pending_job = ds.next_pending_job("worker1").expect(
+    let pending_job = ds.next_pending_job("worker1", "x86_64-linux", &[], &[]).expect(
         "Failed to get pending job",
     );
     assert!(pending_job.is_some(), "Failed to find a pending job");
@@ -124,7 +130,7 @@ fn next_pending_job() {
 
     // Get second job, it should be FIFO, and it should have its status set to Dispatched,
     // and worker set to the passed in worker id
-    let pending_job_2 = ds.next_pending_job("worker2").expect(
+    let pending_job_2 = ds.next_pending_job("worker2", "x86_64-linux", &[], &[]).expect(
         "Failed to get pending job",
     );
     assert!(pending_job_2.is_some(), "Failed to find a pending job");
@@ -143,10 +149,142 @@ fn next_pending_job() {
     assert_eq!(job2_dispatched.get_worker(), "worker2");
 
     // No jobs returns an empty array
-    let no_job = ds.next_pending_job("worker3").expect(
+    let no_job = ds.next_pending_job("worker3", "x86_64-linux", &[], &[]).expect(
+        "Failed to get empty pending jobs",
+    );
+    assert!(no_job.is_none());
+}
+
+#[test]
+fn next_pending_job_by_priority() {
+    let mut job1 = test_job();
+    let mut job2 = test_job();
+    job2.set_priority(jobsrv::JobPriority::Critical);
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
+    assert_eq!(rjob2.get_priority(), jobsrv::JobPriority::Critical);
+
+    // The higher priority job should be dispatched first, even though it was created last
+    let pending_job = ds.next_pending_job("worker1", "x86_64-linux", &[], &[])
+        .expect("Failed to get pending job")
+        .expect("Failed to find a pending job");
+    assert_eq!(pending_job.get_id(), rjob2.get_id());
+
+    let pending_job_2 = ds.next_pending_job("worker2", "x86_64-linux", &[], &[])
+        .expect("Failed to get pending job")
+        .expect("Failed to find a pending job");
+    assert_eq!(pending_job_2.get_id(), rjob1.get_id());
+}
+
+#[test]
+fn next_pending_job_by_target() {
+    let mut job1 = test_job();
+    let mut job2 = test_job();
+    job2.set_target("x86_64-windows".to_string());
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
+    assert_eq!(rjob1.get_target(), "x86_64-linux");
+    assert_eq!(rjob2.get_target(), "x86_64-windows");
+
+    // A Windows worker should only be handed the Windows job
+    let pending_job = ds.next_pending_job("worker1", "x86_64-windows", &[], &[])
+        .expect("Failed to get pending job")
+        .expect("Failed to find a pending job");
+    assert_eq!(pending_job.get_id(), rjob2.get_id());
+
+    let no_job = ds.next_pending_job("worker1", "x86_64-windows", &[], &[]).expect(
         "Failed to get empty pending jobs",
     );
     assert!(no_job.is_none());
+
+    let pending_job_2 = ds.next_pending_job("worker2", "x86_64-linux", &[], &[])
+        .expect("Failed to get pending job")
+        .expect("Failed to find a pending job");
+    assert_eq!(pending_job_2.get_id(), rjob1.get_id());
+}
+
+#[test]
+fn next_pending_job_excluded_origins() {
+    let mut job1 = test_job();
+    let mut job2 = test_job();
+    job2.mut_project().set_name("acme/widget".to_string());
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
+
+    // The older job is passed over, as its origin is at its quota
+    let excluded = vec!["core".to_string()];
+    let pending_job = ds.next_pending_job("worker1", "x86_64-linux", &excluded, &[])
+        .expect("Failed to get pending job")
+        .expect("Failed to find a pending job");
+    assert_eq!(pending_job.get_id(), rjob2.get_id());
+
+    let no_job = ds.next_pending_job("worker2", "x86_64-linux", &excluded, &[])
+        .expect("Failed to get empty pending jobs");
+    assert!(no_job.is_none());
+}
+
+#[test]
+fn next_pending_job_worker_labels() {
+    let mut job1 = test_job();
+    job1.set_worker_labels(RepeatedField::from_vec(vec!["big-memory".to_string()]));
+    let mut job2 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
+    assert_eq!(rjob1.get_worker_labels(), &["big-memory".to_string()]);
+
+    // A worker without the label is passed the job which doesn't require it
+    let pending_job = ds.next_pending_job("worker1", "x86_64-linux", &[], &[])
+        .expect("Failed to get pending job")
+        .expect("Failed to find a pending job");
+    assert_eq!(pending_job.get_id(), rjob2.get_id());
+
+    let no_job = ds.next_pending_job("worker1", "x86_64-linux", &[], &[])
+        .expect("Failed to get empty pending jobs");
+    assert!(no_job.is_none());
+
+    let labels = vec!["big-memory".to_string(), "ssd".to_string()];
+    let pending_job_2 = ds.next_pending_job("worker2", "x86_64-linux", &[], &labels)
+        .expect("Failed to get pending job")
+        .expect("Failed to find a pending job");
+    assert_eq!(pending_job_2.get_id(), rjob1.get_id());
+}
+
+#[test]
+fn get_origin_build_usage() {
+    let mut job1 = test_job();
+    let mut job2 = test_job();
+    let mut job3 = test_job();
+    job3.mut_project().set_name("acme/widget".to_string());
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
+    ds.create_job(&mut job3, &scheduler()).expect("Failed to create job");
+
+    // Pending jobs don't count against an origin
+    let usage = ds.get_origin_build_usage(None).expect("Failed to get usage");
+    assert!(usage.is_empty());
+
+    ds.next_pending_job("worker1", "x86_64-linux", &[], &[])
+        .expect("Failed to get pending job")
+        .expect("Failed to find a pending job");
+
+    let usage = ds.get_origin_build_usage(None).expect("Failed to get usage");
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage[0].get_origin(), "core");
+    assert_eq!(usage[0].get_workers(), 1);
+    assert_eq!(usage[0].get_builds_last_day(), 1);
+
+    let usage = ds.get_origin_build_usage(Some("acme")).expect("Failed to get usage");
+    assert!(usage.is_empty());
 }
 
 #[test]
@@ -154,7 +292,7 @@ fn update_job() {
     let mut job1 = test_job();
     let ds = datastore_test!(DataStore);
     ds.setup().expect("Failed to migrate data");
-    let mut rjob1 = ds.create_job(&mut job1).expect("Failed to create job");
+    let mut rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
 
     let mut get_job = jobsrv::JobGet::new();
     get_job.set_id(rjob1.get_id());
@@ -164,13 +302,193 @@ fn update_job() {
     assert_eq!(pending_job.get_state(), jobsrv::JobState::Pending);
 
     rjob1.set_state(jobsrv::JobState::Failed);
-    ds.update_job(&rjob1).expect("Failed to update job state");
+    ds.update_job(&rjob1, &scheduler()).expect("Failed to update job state");
     let failed_job = ds.get_job(&get_job)
         .expect("Failed to get job from database")
         .expect("No job found");
     assert_eq!(failed_job.get_state(), jobsrv::JobState::Failed);
 }
 
+#[test]
+fn get_running_jobs() {
+    let mut job1 = test_job();
+    let mut job2 = test_job();
+    let mut job3 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let mut rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    let mut rjob2 = ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
+    ds.create_job(&mut job3, &scheduler()).expect("Failed to create job");
+
+    rjob1.set_state(jobsrv::JobState::Dispatched);
+    ds.update_job(&rjob1, &scheduler()).expect("Failed to update job state");
+    rjob2.set_state(jobsrv::JobState::Processing);
+    ds.update_job(&rjob2, &scheduler()).expect("Failed to update job state");
+
+    let mut ids: Vec<u64> = ds.get_running_jobs()
+        .expect("Failed to get running jobs")
+        .iter()
+        .map(|job| job.get_id())
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec![rjob1.get_id(), rjob2.get_id()]);
+}
+
+#[test]
+fn prune_job_history() {
+    let mut job1 = test_job();
+    let mut job2 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let mut rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
+    rjob1.set_state(jobsrv::JobState::Complete);
+    ds.update_job(&rjob1, &scheduler()).expect("Failed to update job state");
+    ds.set_job_sync(rjob1.get_id()).expect("Failed to sync job");
+
+    let mut msg = jobsrv::JobGroupSpec::new();
+    msg.set_origin(String::from("Foo"));
+    msg.set_package(String::from("Bar"));
+    let project_names = vec![(String::from("Foo/Bar"), String::from("Foo/Bar/0/Baz"))];
+    let group = ds.create_job_group(&msg, project_names).expect(
+        "Failed to create a group",
+    );
+    ds.cancel_job_group(group.get_id(), &scheduler()).expect(
+        "Failed to cancel group",
+    );
+
+    // Nothing is old enough yet
+    let (groups, jobs) = ds.prune_job_history(&(UTC::now() - Duration::days(1)))
+        .expect("Failed to prune job history");
+    assert_eq!(groups, 0);
+    assert!(jobs.is_empty());
+
+    let (groups, jobs) = ds.prune_job_history(&(UTC::now() + Duration::minutes(1)))
+        .expect("Failed to prune job history");
+    assert_eq!(groups, 1);
+    assert_eq!(jobs, vec![rjob1.get_id()]);
+
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    assert!(ds.get_job(&get_job).expect("Failed to get job").is_none());
+    // Unfinished jobs are kept
+    get_job.set_id(rjob2.get_id());
+    assert!(ds.get_job(&get_job).expect("Failed to get job").is_some());
+    let mut get_group = jobsrv::JobGroupGet::new();
+    get_group.set_group_id(group.get_id());
+    assert!(
+        ds.get_job_group(&get_group)
+            .expect("Failed to get group")
+            .is_none()
+    );
+}
+
+#[test]
+fn update_job_retries() {
+    let mut job1 = test_job();
+    job1.set_max_retries(3);
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let mut rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    assert_eq!(rjob1.get_retries(), 0);
+    assert_eq!(rjob1.get_max_retries(), 3);
+    assert!(!rjob1.has_last_error_category());
+
+    rjob1.set_retries(1);
+    rjob1.set_last_error_category(jobsrv::JobErrorCategory::Transient);
+    ds.update_job(&rjob1, &scheduler()).expect("Failed to update job retries");
+
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let retried_job = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(retried_job.get_retries(), 1);
+    assert_eq!(retried_job.get_max_retries(), 3);
+    assert_eq!(
+        retried_job.get_last_error_category(),
+        jobsrv::JobErrorCategory::Transient
+    );
+}
+
+#[test]
+fn update_job_image_digest() {
+    let mut job1 = test_job();
+    job1.set_kind(jobsrv::JobKind::DockerExport);
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let mut rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    assert_eq!(rjob1.get_kind(), jobsrv::JobKind::DockerExport);
+    assert!(!rjob1.has_image_digest());
+
+    rjob1.set_state(jobsrv::JobState::Complete);
+    rjob1.set_image_digest(String::from("sha256:0123456789abcdef"));
+    ds.update_job(&rjob1, &scheduler()).expect("Failed to update job");
+
+    let mut get_job = jobsrv::JobGet::new();
+    get_job.set_id(rjob1.get_id());
+    let exported_job = ds.get_job(&get_job)
+        .expect("Failed to get job from database")
+        .expect("No job found");
+    assert_eq!(exported_job.get_kind(), jobsrv::JobKind::DockerExport);
+    assert_eq!(exported_job.get_image_digest(), "sha256:0123456789abcdef");
+}
+
+#[test]
+fn get_expired_jobs() {
+    let mut job1 = test_job();
+    job1.set_expires_at(String::from("2017-06-01T00:00:00+00:00"));
+    let mut job2 = test_job();
+    job2.set_ttl(60);
+    let mut job3 = test_job();
+    let mut job4 = test_job();
+    job4.set_expires_at(String::from("2017-06-01T00:00:00+00:00"));
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
+    ds.create_job(&mut job3, &scheduler()).expect("Failed to create job");
+    let mut rjob4 = ds.create_job(&mut job4, &scheduler()).expect("Failed to create job");
+    assert_eq!(rjob1.get_expires_at(), "2017-06-01T00:00:00+00:00");
+
+    rjob4.set_state(jobsrv::JobState::Complete);
+    ds.update_job(&rjob4, &scheduler()).expect("Failed to update job state");
+
+    let expired = ds.get_expired_jobs().expect("Failed to get expired jobs");
+    assert_eq!(expired.len(), 1);
+    assert_eq!(expired[0].get_id(), rjob1.get_id());
+}
+
+#[test]
+fn get_jobs_by_tag() {
+    let mut job1 = test_job();
+    job1.set_tags(vec![String::from("nightly"), String::from("core")].into());
+    let mut job2 = test_job();
+    job2.set_tags(vec![String::from("nightly")].into());
+    let mut job3 = test_job();
+    let ds = datastore_test!(DataStore);
+    ds.setup().expect("Failed to migrate data");
+    let rjob1 = ds.create_job(&mut job1, &scheduler()).expect("Failed to create job");
+    let rjob2 = ds.create_job(&mut job2, &scheduler()).expect("Failed to create job");
+    ds.create_job(&mut job3, &scheduler()).expect("Failed to create job");
+    assert_eq!(rjob1.get_tags(), &["nightly", "core"]);
+
+    let mut msg = jobsrv::JobsByTagGet::new();
+    msg.set_tag(String::from("nightly"));
+    msg.set_start(0);
+    msg.set_stop(50);
+    let response = ds.get_jobs_by_tag(&msg).expect("Failed to get jobs by tag");
+    assert_eq!(response.get_count(), 2);
+    let ids: Vec<u64> = response.get_jobs().iter().map(|j| j.get_id()).collect();
+    assert!(ids.contains(&rjob1.get_id()));
+    assert!(ids.contains(&rjob2.get_id()));
+
+    msg.set_tag(String::from("core"));
+    let response = ds.get_jobs_by_tag(&msg).expect("Failed to get jobs by tag");
+    assert_eq!(response.get_count(), 1);
+    assert_eq!(response.get_jobs()[0].get_id(), rjob1.get_id());
+}
+
 #[test]
 fn create_job_group() {
     let project_names = vec![(String::from("Foo/Bar"), String::from("Foo/Bar/0/Baz"))];
@@ -184,6 +502,126 @@ fn create_job_group() {
     );
 }
 
+#[test]
+fn job_group_queue_positions() {
+    let ds = datastore_test!(DataStore);
+    let mut msg = jobsrv::JobGroupSpec::new();
+    msg.set_origin(String::from("core"));
+    msg.set_package(String::from("nginx"));
+    let queued = ds.create_job_group(
+        &msg,
+        vec![(String::from("core/nginx"), String::from("core/nginx/1.0/1"))],
+    ).expect("Failed to create a group");
+    msg.set_package(String::from("redis"));
+    let active = ds.create_job_group(
+        &msg,
+        vec![(String::from("core/redis"), String::from("core/redis/1.0/1"))],
+    ).expect("Failed to create a group");
+    ds.set_job_group_state(active.get_id(), jobsrv::JobGroupState::GroupPending, &scheduler())
+        .expect("Failed to set the group state");
+
+    let counts = ds.count_active_job_groups_by_origin().expect(
+        "Failed to count active groups",
+    );
+    assert_eq!(counts.get("core"), Some(&1));
+
+    ds.set_job_group_queue_position(queued.get_id(), Some(2))
+        .expect("Failed to set the queue position");
+    let mut get = jobsrv::JobGroupGet::new();
+    get.set_group_id(queued.get_id());
+    let group = ds.get_job_group(&get).expect("Failed to get the group").unwrap();
+    assert_eq!(group.get_queue_position(), 2);
+
+    ds.set_job_group_queue_position(queued.get_id(), None)
+        .expect("Failed to clear the queue position");
+    let group = ds.get_job_group(&get).expect("Failed to get the group").unwrap();
+    assert!(!group.has_queue_position());
+}
+
+#[test]
+fn skip_job_group_project() {
+    let ds = datastore_test!(DataStore);
+    let mut msg = jobsrv::JobGroupSpec::new();
+    msg.set_origin(String::from("core"));
+    msg.set_package(String::from("nginx"));
+    let group = ds.create_job_group(
+        &msg,
+        vec![(String::from("core/nginx"), String::from("core/nginx/1.0/1"))],
+    ).expect("Failed to create a group");
+
+    ds.skip_job_group_project(group.get_id(), "core/nginx", "Plan not found")
+        .expect("Failed to skip the project");
+    let mut get = jobsrv::JobGroupGet::new();
+    get.set_group_id(group.get_id());
+    let group = ds.get_job_group(&get).expect("Failed to get the group").unwrap();
+    let project = &group.get_projects()[0];
+    assert_eq!(project.get_state(), jobsrv::JobGroupProjectState::Skipped);
+    assert_eq!(project.get_skip_reason(), "Plan not found");
+}
+
+#[test]
+fn job_group_schedules() {
+    let ds = datastore_test!(DataStore);
+    let mut msg = jobsrv::JobGroupScheduleCreate::new();
+    msg.set_cron(String::from("0 2 * * *"));
+    msg.mut_spec().set_origin(String::from("core"));
+    msg.mut_spec().set_package(String::from("nginx"));
+    msg.mut_spec().set_tags(vec![String::from("nightly")].into());
+
+    let now = UTC::now();
+    let schedule = ds.upsert_job_group_schedule(&msg, &(now + Duration::hours(1)))
+        .expect("Failed to create a group schedule");
+    assert_eq!(schedule.get_spec().get_target(), jobsrv::DEFAULT_TARGET);
+    assert_eq!(schedule.get_spec().get_tags(), &["nightly"]);
+
+    // Scheduling the same package again replaces the schedule
+    msg.set_cron(String::from("0 3 * * *"));
+    let replaced = ds.upsert_job_group_schedule(&msg, &(now - Duration::minutes(1)))
+        .expect("Failed to replace a group schedule");
+    assert_eq!(replaced.get_id(), schedule.get_id());
+    assert_eq!(replaced.get_cron(), "0 3 * * *");
+
+    msg.mut_spec().set_origin(String::from("other"));
+    ds.upsert_job_group_schedule(&msg, &(now + Duration::hours(1)))
+        .expect("Failed to create a group schedule");
+
+    let mut list = jobsrv::JobGroupScheduleListGet::new();
+    list.set_origin(String::from("core"));
+    let schedules = ds.get_job_group_schedules(&list).expect(
+        "Failed to list group schedules",
+    );
+    assert_eq!(schedules.get_schedules().len(), 1);
+
+    let due = ds.get_due_job_group_schedules().expect(
+        "Failed to get due group schedules",
+    );
+    assert_eq!(due.len(), 1);
+    assert_eq!(due[0].get_id(), schedule.get_id());
+
+    ds.set_job_group_schedule_run(schedule.get_id(), &now, Some(now + Duration::days(1)))
+        .expect("Failed to record a group schedule run");
+    assert!(
+        ds.get_due_job_group_schedules()
+            .expect("Failed to get due group schedules")
+            .is_empty()
+    );
+
+    let mut delete = jobsrv::JobGroupScheduleDelete::new();
+    delete.set_id(schedule.get_id());
+    delete.set_origin(String::from("other"));
+    assert!(!ds.delete_job_group_schedule(&delete).expect(
+        "Failed to delete a group schedule",
+    ));
+    delete.set_origin(String::from("core"));
+    assert!(ds.delete_job_group_schedule(&delete).expect(
+        "Failed to delete a group schedule",
+    ));
+    let schedules = ds.get_job_group_schedules(&list).expect(
+        "Failed to list group schedules",
+    );
+    assert!(schedules.get_schedules().is_empty());
+}
+
 #[test]
 fn get_job_group() {
     let project_names = vec![(String::from("Foo/Bar"), String::from("Foo/Bar/0/Baz"))];
@@ -234,6 +672,67 @@ fn get_group_does_not_exist() {
     assert!(result.is_none());
 }
 
+#[test]
+fn get_job_group_origin() {
+    let project_names = vec![(String::from("Foo/Bar"), String::from("Foo/Bar/0/Baz"))];
+    let mut msg = jobsrv::JobGroupSpec::new();
+    msg.set_origin(String::from("Foo"));
+    msg.set_package(String::from("Bar"));
+
+    let ds = datastore_test!(DataStore);
+    let group1 = ds.create_job_group(&msg, project_names.clone()).expect(
+        "Failed to create a group",
+    );
+    let group2 = ds.create_job_group(&msg, project_names.clone()).expect(
+        "Failed to create a group",
+    );
+    let group3 = ds.create_job_group(&msg, project_names.clone()).expect(
+        "Failed to create a group",
+    );
+    ds.set_job_group_state(group2.get_id(), jobsrv::JobGroupState::GroupComplete, &scheduler())
+        .expect("Failed to set group state");
+
+    // Newest groups come first
+    let mut origin_get = jobsrv::JobGroupOriginGet::new();
+    origin_get.set_origin(String::from("Foo"));
+    origin_get.set_start(0);
+    origin_get.set_stop(1);
+    let response = ds.get_job_group_origin(&origin_get).expect(
+        "Failed to get origin groups",
+    );
+    assert_eq!(response.get_count(), 3);
+    assert_eq!(response.get_start(), 0);
+    assert_eq!(response.get_stop(), 1);
+    assert_eq!(response.get_job_groups().len(), 2);
+    assert_eq!(response.get_job_groups()[0].get_id(), group3.get_id());
+    assert_eq!(response.get_job_groups()[1].get_id(), group2.get_id());
+
+    origin_get.set_start(2);
+    origin_get.set_stop(3);
+    let response = ds.get_job_group_origin(&origin_get).expect(
+        "Failed to get origin groups",
+    );
+    assert_eq!(response.get_job_groups().len(), 1);
+    assert_eq!(response.get_job_groups()[0].get_id(), group1.get_id());
+    assert_eq!(response.get_stop(), 2);
+
+    origin_get.set_start(0);
+    origin_get.set_stop(49);
+    origin_get.set_state(jobsrv::JobGroupState::GroupComplete);
+    let response = ds.get_job_group_origin(&origin_get).expect(
+        "Failed to get origin groups",
+    );
+    assert_eq!(response.get_count(), 1);
+    assert_eq!(response.get_job_groups()[0].get_id(), group2.get_id());
+
+    origin_get.clear_state();
+    origin_get.set_created_since(String::from("2100-01-01T00:00:00+00:00"));
+    let response = ds.get_job_group_origin(&origin_get).expect(
+        "Failed to get origin groups",
+    );
+    assert_eq!(response.get_job_groups().len(), 0);
+}
+
 #[test]
 fn pending_groups() {
     let project_names = vec![(String::from("Foo/Bar"), String::from("Foo/Bar/0/Baz"))];
@@ -281,13 +780,13 @@ fn pending_groups() {
     );
 
     // Now set group states
-    ds.set_job_group_state(group1.get_id(), jobsrv::JobGroupState::GroupPending)
+    ds.set_job_group_state(group1.get_id(), jobsrv::JobGroupState::GroupPending, &scheduler())
         .expect("Failed to set group state");
 
-    ds.set_job_group_state(group2.get_id(), jobsrv::JobGroupState::GroupPending)
+    ds.set_job_group_state(group2.get_id(), jobsrv::JobGroupState::GroupPending, &scheduler())
         .expect("Failed to set group state");
 
-    ds.set_job_group_state(group3.get_id(), jobsrv::JobGroupState::GroupPending)
+    ds.set_job_group_state(group3.get_id(), jobsrv::JobGroupState::GroupPending, &scheduler())
         .expect("Failed to set group state");
 
     // Get one group, it should be FIFO, and it should have its state set to Dispatching
@@ -353,7 +852,7 @@ fn set_job_group_state() {
         jobsrv::JobGroupState::GroupQueued
     );
 
-    ds.set_job_group_state(group.get_id(), jobsrv::JobGroupState::GroupComplete)
+    ds.set_job_group_state(group.get_id(), jobsrv::JobGroupState::GroupComplete, &scheduler())
         .expect("Failed to update group state");
 
     let completed_group = ds.get_job_group(&get_msg)
@@ -365,6 +864,137 @@ fn set_job_group_state() {
     );
 }
 
+#[test]
+fn retry_job_group() {
+    let project_names = vec![
+        (String::from("Foo/Bar"), String::from("Foo/Bar/0/Baz")),
+        (String::from("Foo/Baz"), String::from("Foo/Baz/0/Baz")),
+        (String::from("Foo/Qux"), String::from("Foo/Qux/0/Baz")),
+    ];
+    let mut msg = jobsrv::JobGroupSpec::new();
+    msg.set_origin(String::from("Foo"));
+    msg.set_package(String::from("Bar"));
+
+    let ds = datastore_test!(DataStore);
+    let group = ds.create_job_group(&msg, project_names).expect(
+        "Failed to create a group",
+    );
+    ds.set_job_group_project_state(
+        group.get_id(),
+        "Foo/Bar",
+        jobsrv::JobGroupProjectState::Success,
+    ).expect("Failed to set project state");
+    ds.set_job_group_project_state(
+        group.get_id(),
+        "Foo/Baz",
+        jobsrv::JobGroupProjectState::Failure,
+    ).expect("Failed to set project state");
+    ds.set_job_group_project_state(
+        group.get_id(),
+        "Foo/Qux",
+        jobsrv::JobGroupProjectState::Skipped,
+    ).expect("Failed to set project state");
+    ds.set_job_group_state(group.get_id(), jobsrv::JobGroupState::GroupComplete, &scheduler())
+        .expect("Failed to update group state");
+
+    let retried = ds.retry_job_group(group.get_id(), &Audit::user(3, "wesker"))
+        .expect("Failed to retry group");
+    assert_eq!(retried, 2);
+
+    let mut get_msg = jobsrv::JobGroupGet::new();
+    get_msg.set_group_id(group.get_id());
+    let retried_group = ds.get_job_group(&get_msg)
+        .expect("Failed to get group from database")
+        .expect("No group found");
+    assert_eq!(retried_group.get_state(), jobsrv::JobGroupState::GroupQueued);
+
+    let mut states: Vec<(&str, jobsrv::JobGroupProjectState)> = retried_group
+        .get_projects()
+        .iter()
+        .map(|p| (p.get_name(), p.get_state()))
+        .collect();
+    states.sort_by(|a, b| a.0.cmp(b.0));
+    assert_eq!(
+        states,
+        vec![
+            ("Foo/Bar", jobsrv::JobGroupProjectState::Success),
+            ("Foo/Baz", jobsrv::JobGroupProjectState::NotStarted),
+            ("Foo/Qux", jobsrv::JobGroupProjectState::NotStarted),
+        ]
+    );
+}
+
+#[test]
+fn job_audit_log() {
+    let project_names = vec![(String::from("Foo/Bar"), String::from("Foo/Bar/0/Baz"))];
+    let mut msg = jobsrv::JobGroupSpec::new();
+    msg.set_origin(String::from("Foo"));
+    msg.set_package(String::from("Bar"));
+    msg.set_requester_id(7);
+    msg.set_requester_name(String::from("bobo"));
+
+    let ds = datastore_test!(DataStore);
+    let group = ds.create_job_group(&msg, project_names).expect(
+        "Failed to create a group",
+    );
+    ds.set_job_group_state(group.get_id(), jobsrv::JobGroupState::GroupPending, &scheduler())
+        .expect("Failed to set group state");
+    // Setting the same state again isn't a transition
+    ds.set_job_group_state(group.get_id(), jobsrv::JobGroupState::GroupPending, &scheduler())
+        .expect("Failed to set group state");
+
+    let mut job = test_job();
+    job.set_owner_id(group.get_id());
+    job.mut_project().set_name(String::from("Foo/Bar"));
+    ds.create_job(&mut job, &scheduler()).expect(
+        "Failed to create job",
+    );
+    let mut job = ds.next_pending_job("worker1", "x86_64-linux", &[], &[])
+        .expect("Failed to get pending job")
+        .expect("Failed to find a pending job");
+    job.set_state(jobsrv::JobState::CancelPending);
+    ds.update_job(&job, &Audit::user(3, "wesker")).expect(
+        "Failed to update job state",
+    );
+    ds.cancel_job_group(group.get_id(), &Audit::user(3, "wesker"))
+        .expect("Failed to cancel group");
+
+    let mut get_msg = jobsrv::JobAuditGet::new();
+    get_msg.set_job_id(job.get_id());
+    let audits = ds.get_job_audit(&get_msg).expect("Failed to get job audit");
+    let transitions: Vec<(&str, &str, jobsrv::JobAuditTrigger)> = audits
+        .get_audits()
+        .iter()
+        .map(|a| (a.get_from_state(), a.get_to_state(), a.get_trigger()))
+        .collect();
+    assert_eq!(
+        transitions,
+        vec![
+            ("", "Pending", jobsrv::JobAuditTrigger::Scheduler),
+            ("Pending", "Dispatched", jobsrv::JobAuditTrigger::Scheduler),
+            ("Dispatched", "CancelPending", jobsrv::JobAuditTrigger::User),
+        ]
+    );
+    assert_eq!(audits.get_audits()[1].get_worker(), "worker1");
+    assert_eq!(audits.get_audits()[2].get_requester_name(), "wesker");
+
+    let mut get_msg = jobsrv::JobAuditGet::new();
+    get_msg.set_group_id(group.get_id());
+    let audits = ds.get_job_audit(&get_msg).expect("Failed to get group audit");
+    let group_states: Vec<&str> = audits
+        .get_audits()
+        .iter()
+        .filter(|a| a.has_group_id())
+        .map(|a| a.get_to_state())
+        .collect();
+    assert_eq!(group_states, vec!["Queued", "Pending", "Canceled"]);
+    assert_eq!(audits.get_audits()[0].get_requester_id(), 7);
+    assert_eq!(
+        audits.get_audits().iter().filter(|a| a.has_job_id()).count(),
+        3
+    );
+}
+
 #[test]
 fn create_graph_package() {
     let mut msg = jobsrv::JobGraphPackageCreate::new();
@@ -390,6 +1020,48 @@ fn create_graph_package() {
     assert_eq!(packages.last().unwrap().get_ident(), "Foo/Bar/123/456");
 }
 
+#[test]
+fn get_graph_packages_since_revision() {
+    let ds = datastore_test!(DataStore);
+    assert_eq!(ds.get_job_graph_packages_revision().unwrap(), 0);
+
+    let mut revisions = Vec::new();
+    for ident in &["Foo/Bar/123/456", "Foo/Baz/321/654"] {
+        let mut msg = jobsrv::JobGraphPackageCreate::new();
+        msg.set_ident(String::from(*ident));
+        msg.set_target(String::from("x86_64-linux"));
+        let package = ds.create_job_graph_package(&msg).expect(
+            "Failed to create a graph package",
+        );
+        revisions.push(package.get_revision());
+    }
+    assert!(revisions[1] > revisions[0]);
+    assert_eq!(ds.get_job_graph_packages_revision().unwrap(), revisions[1]);
+
+    let packages = ds.get_job_graph_packages_since(revisions[0]).expect(
+        "Failed to get graph packages",
+    );
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0].get_ident(), "Foo/Baz/321/654");
+
+    // Updating a package gives it a new revision
+    let mut msg = jobsrv::JobGraphPackageCreate::new();
+    msg.set_ident(String::from("Foo/Bar/123/456"));
+    msg.set_target(String::from("x86_64-linux"));
+    msg.set_deps(RepeatedField::from_vec(vec![String::from("Foo/Baz/321/654")]));
+    let updated = ds.create_job_graph_package(&msg).expect(
+        "Failed to update a graph package",
+    );
+    assert!(updated.get_revision() > revisions[1]);
+
+    let packages = ds.get_job_graph_packages_since(revisions[1]).expect(
+        "Failed to get graph packages",
+    );
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0].get_ident(), "Foo/Bar/123/456");
+    assert_eq!(packages[0].get_deps(), &["Foo/Baz/321/654"]);
+}
+
 #[test]
 fn get_graph_stats() {
     let ds = datastore_test!(DataStore);
@@ -405,3 +1077,38 @@ fn get_graph_stats() {
     assert_eq!(stats.get_builds(), 0);
     assert_eq!(stats.get_unique_packages(), 0);
 }
+
+#[test]
+fn get_graph_stats_by_target() {
+    let ds = datastore_test!(DataStore);
+
+    for &(ident, target) in &[
+        ("Foo/Bar/123/456", "x86_64-linux"),
+        ("Foo/Bar/123/789", "x86_64-linux"),
+        ("Foo/Baz/321/654", "x86_64-windows"),
+    ]
+    {
+        let mut msg = jobsrv::JobGraphPackageCreate::new();
+        msg.set_ident(String::from(ident));
+        msg.set_target(String::from(target));
+        ds.create_job_graph_package(&msg).expect(
+            "Failed to create a graph package",
+        );
+    }
+
+    let mut stats_msg = jobsrv::JobGraphPackageStatsGet::new();
+    stats_msg.set_origin(String::from("Foo"));
+
+    let stats = ds.get_job_graph_package_stats(&stats_msg).expect(
+        "Failed to get graph stats",
+    );
+
+    let targets = stats.get_targets();
+    assert_eq!(targets.len(), 2);
+    assert_eq!(targets[0].get_target(), "x86_64-linux");
+    assert_eq!(targets[0].get_plans(), 2);
+    assert_eq!(targets[0].get_unique_packages(), 1);
+    assert_eq!(targets[1].get_target(), "x86_64-windows");
+    assert_eq!(targets[1].get_plans(), 1);
+    assert_eq!(targets[1].get_builds(), 0);
+}
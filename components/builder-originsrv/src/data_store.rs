@@ -18,6 +18,7 @@ use std::str::FromStr;
 use std::sync::Arc;
 
 use bldr_core::helpers::transition_visibility;
+use chrono::{DateTime, UTC};
 use db::async::{AsyncServer, EventOutcome};
 use db::config::{DataStoreCfg, ShardId};
 use db::error::{Error as DbError, Result as DbResult};
@@ -30,6 +31,7 @@ use postgres::rows::Rows;
 use protocol::{originsrv, sessionsrv, jobsrv};
 use protocol::net::NetOk;
 use protocol::originsrv::Pageable;
+use protocol::timestamp;
 use postgres;
 use protobuf;
 
@@ -84,6 +86,11 @@ impl DataStore {
         migrations::origin_projects::migrate(&mut migrator)?;
         migrations::origin_packages::migrate(&mut migrator)?;
         migrations::origin_channels::migrate(&mut migrator)?;
+        migrations::origin_notifications::migrate(&mut migrator)?;
+        migrations::origin_secrets::migrate(&mut migrator)?;
+        migrations::origin_encryption_keys::migrate(&mut migrator)?;
+        migrations::origin_promotion_requests::migrate(&mut migrator)?;
+        migrations::origin_package_downloads::migrate(&mut migrator)?;
 
         migrator.finish()?;
 
@@ -140,8 +147,14 @@ impl DataStore {
         let conn = self.pool.get(opc)?;
         let project = opc.get_project();
 
+        let build_timeout: Option<i32> = if project.has_build_timeout() {
+            Some(project.get_build_timeout() as i32)
+        } else {
+            None
+        };
+
         conn.execute(
-            "SELECT update_origin_project_v3($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+            "SELECT update_origin_project_v6($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
             &[
                 &(project.get_id() as i64),
                 &(project.get_origin_id() as i64),
@@ -152,6 +165,9 @@ impl DataStore {
                 &(project.get_owner_id() as i64),
                 &(project.get_vcs_installation_id() as i64),
                 &project.get_visibility().to_string(),
+                &build_timeout,
+                &project.get_worker_labels().to_vec(),
+                &project.get_verify_reproducible(),
             ],
         ).map_err(SrvError::OriginProjectUpdate)?;
 
@@ -233,6 +249,18 @@ impl DataStore {
             project.set_vcs_installation_id(install_id as u32);
         }
 
+        if let Some(Ok(build_timeout)) = row.get_opt::<&str, i32>("build_timeout") {
+            project.set_build_timeout(build_timeout as u32);
+        }
+
+        if let Some(Ok(worker_labels)) = row.get_opt::<&str, Vec<String>>("worker_labels") {
+            project.set_worker_labels(protobuf::RepeatedField::from_vec(worker_labels));
+        }
+
+        if let Some(Ok(verify_reproducible)) = row.get_opt::<&str, bool>("verify_reproducible") {
+            project.set_verify_reproducible(verify_reproducible);
+        }
+
         let pv: String = row.get("visibility");
         let pv2: originsrv::OriginPackageVisibility =
             pv.parse().map_err(SrvError::UnknownOriginPackageVisibility)?;
@@ -254,8 +282,13 @@ impl DataStore {
                 None
             }
         };
+        let build_timeout: Option<i32> = if project.has_build_timeout() {
+            Some(project.get_build_timeout() as i32)
+        } else {
+            None
+        };
         let rows = conn.query(
-            "SELECT * FROM insert_origin_project_v4($1, $2, $3, $4, $5, $6, $7, $8)",
+            "SELECT * FROM insert_origin_project_v7($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
             &[
                 &project.get_origin_name(),
                 &project.get_package_name(),
@@ -265,6 +298,9 @@ impl DataStore {
                 &(project.get_owner_id() as i64),
                 &install_id,
                 &project.get_visibility().to_string(),
+                &build_timeout,
+                &project.get_worker_labels().to_vec(),
+                &project.get_verify_reproducible(),
             ],
         ).map_err(SrvError::OriginProjectCreate)?;
         let row = rows.get(0);
@@ -395,6 +431,18 @@ impl DataStore {
         if rows.len() != 0 { Ok(true) } else { Ok(false) }
     }
 
+    pub fn check_origin_approver(
+        &self,
+        coar: &originsrv::CheckOriginApproverRequest,
+    ) -> SrvResult<bool> {
+        let conn = self.pool.get(coar)?;
+        let rows = &conn.query(
+            "SELECT * FROM check_origin_approver_v1($1, $2)",
+            &[&coar.get_origin_name(), &(coar.get_account_id() as i64)],
+        ).map_err(SrvError::OriginCheckApprover)?;
+        Ok(!rows.is_empty())
+    }
+
     pub fn my_origins(
         &self,
         mor: &originsrv::MyOriginsRequest,
@@ -586,6 +634,24 @@ impl DataStore {
         Ok(response)
     }
 
+    pub fn get_origin_invitation(
+        &self,
+        oig: &originsrv::OriginInvitationGet,
+    ) -> SrvResult<Option<originsrv::OriginInvitation>> {
+        let conn = self.pool.get(oig)?;
+        let rows = &conn.query(
+            "SELECT * FROM get_origin_invitation_v1($1)",
+            &[&(oig.get_invitation_id() as i64)],
+        ).map_err(SrvError::OriginInvitationGet)?;
+
+        if rows.len() != 0 {
+            let row = rows.get(0);
+            Ok(Some(self.row_to_origin_invitation(&row)))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn row_to_origin_invitation(&self, row: &postgres::rows::Row) -> originsrv::OriginInvitation {
         let mut oi = originsrv::OriginInvitation::new();
         let oi_id: i64 = row.get("id");
@@ -712,6 +778,8 @@ impl DataStore {
         opk.set_body(row.get("body"));
         let opk_owner_id: i64 = row.get("owner_id");
         opk.set_owner_id(opk_owner_id as u64);
+        let deprecated: Option<bool> = row.get("deprecated");
+        opk.set_deprecated(deprecated.unwrap_or(false));
         opk
     }
 
@@ -771,6 +839,26 @@ impl DataStore {
         Ok(response)
     }
 
+    pub fn deprecate_origin_public_keys(
+        &self,
+        opkd: &originsrv::OriginPublicKeyDeprecate,
+    ) -> SrvResult<originsrv::OriginPublicKeyListResponse> {
+        let conn = self.pool.get(opkd)?;
+        let rows = &conn.query(
+            "SELECT * FROM deprecate_origin_public_keys_v1($1, $2)",
+            &[&(opkd.get_origin_id() as i64), &opkd.get_revision()],
+        ).map_err(SrvError::OriginPublicKeyDeprecate)?;
+
+        let mut response = originsrv::OriginPublicKeyListResponse::new();
+        response.set_origin_id(opkd.get_origin_id());
+        let mut keys = protobuf::RepeatedField::new();
+        for row in rows {
+            keys.push(self.row_to_origin_public_key(row));
+        }
+        response.set_keys(keys);
+        Ok(response)
+    }
+
     fn row_to_origin(&self, row: postgres::rows::Row) -> SrvResult<originsrv::Origin> {
         let mut origin = originsrv::Origin::new();
         let oid: i64 = row.get("id");
@@ -882,9 +970,13 @@ impl DataStore {
     ) -> SrvResult<originsrv::OriginPackage> {
         let conn = self.pool.get(opc)?;
         let ident = opc.get_ident();
+        let exports: Vec<String> = opc.get_exports()
+            .iter()
+            .map(|export| format!("{}={}", export.get_name(), export.get_key()))
+            .collect();
 
         let rows = conn.query(
-            "SELECT * FROM insert_origin_package_v3($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)",
+            "SELECT * FROM insert_origin_package_v4($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)",
             &[
                 &(opc.get_origin_id() as i64),
                 &(opc.get_owner_id() as i64),
@@ -897,7 +989,10 @@ impl DataStore {
                 &self.into_delimited(opc.get_deps().to_vec()),
                 &self.into_delimited(opc.get_tdeps().to_vec()),
                 &self.into_delimited(opc.get_exposes().to_vec()),
-                &opc.get_visibility().to_string()
+                &opc.get_visibility().to_string(),
+                &self.into_delimited(exports),
+                &self.into_delimited(opc.get_licenses().to_vec()),
+                &opc.get_description(),
             ],
         ).map_err(SrvError::OriginPackageCreate)?;
 
@@ -930,6 +1025,128 @@ impl DataStore {
         }
     }
 
+    /// Yanks a release or restores a yanked one. Returns None if there's no such release.
+    pub fn yank_origin_package(
+        &self,
+        opy: &originsrv::OriginPackageYank,
+    ) -> SrvResult<Option<originsrv::OriginPackage>> {
+        let conn = self.pool.get(opy)?;
+        let rows = conn.query(
+            "SELECT * FROM yank_origin_package_v1($1, $2, $3)",
+            &[
+                &opy.get_ident().to_string(),
+                &opy.get_yanked(),
+                &opy.get_reason(),
+            ],
+        ).map_err(SrvError::OriginPackageYank)?;
+
+        if rows.len() != 0 {
+            let row = rows.get(0);
+            let pkg = self.row_to_origin_package(&row)?;
+            Ok(Some(pkg))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the releases of an origin which the depot's retention would delete, ordered by
+    /// ident.
+    pub fn get_origin_package_retention_candidates(
+        &self,
+        oprcg: &originsrv::OriginPackageRetentionCandidatesGet,
+    ) -> SrvResult<originsrv::OriginPackageRetentionCandidatesResponse> {
+        let conn = self.pool.get(oprcg)?;
+        let rows = conn.query(
+            "SELECT * FROM get_origin_package_retention_candidates_v1($1, $2, $3)",
+            &[
+                &oprcg.get_origin(),
+                &(oprcg.get_max_age_days() as i32),
+                &(oprcg.get_keep_releases() as i32),
+            ],
+        ).map_err(SrvError::OriginPackageRetentionCandidatesGet)?;
+
+        let mut response = originsrv::OriginPackageRetentionCandidatesResponse::new();
+        let mut packages = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            packages.push(self.row_to_origin_package(&row)?);
+        }
+        response.set_packages(packages);
+        Ok(response)
+    }
+
+    /// Deletes a release along with its channel memberships. Returns None if there's no such
+    /// release, or if it's in `stable`.
+    pub fn delete_origin_package(
+        &self,
+        opd: &originsrv::OriginPackageDelete,
+    ) -> SrvResult<Option<originsrv::OriginPackage>> {
+        let conn = self.pool.get(opd)?;
+        let rows = conn.query(
+            "SELECT * FROM delete_origin_package_v1($1)",
+            &[&opd.get_ident().to_string()],
+        ).map_err(SrvError::OriginPackageDelete)?;
+
+        if rows.len() != 0 {
+            let row = rows.get(0);
+            let pkg = self.row_to_origin_package(&row)?;
+            Ok(Some(pkg))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Counts a download of a package for today. The download is only counted under its channel
+    /// if the package is in that channel.
+    pub fn record_origin_package_download(
+        &self,
+        opdr: &originsrv::OriginPackageDownloadRecord,
+    ) -> SrvResult<()> {
+        let conn = self.pool.get(opdr)?;
+        conn.execute(
+            "SELECT record_origin_package_download_v1($1, $2)",
+            &[&opdr.get_ident().to_string(), &opdr.get_channel()],
+        ).map_err(SrvError::OriginPackageDownloadRecord)?;
+        Ok(())
+    }
+
+    pub fn get_origin_package_download_stats(
+        &self,
+        opdsg: &originsrv::OriginPackageDownloadStatsGet,
+    ) -> SrvResult<originsrv::OriginPackageDownloadStatsResponse> {
+        let conn = self.pool.get(opdsg)?;
+        let since = if opdsg.get_since().is_empty() {
+            None
+        } else {
+            Some(timestamp::parse(opdsg.get_since())?)
+        };
+        let rows = conn.query(
+            "SELECT * FROM get_origin_package_download_stats_v1($1, $2, $3)",
+            &[&opdsg.get_origin(), &opdsg.get_name(), &since],
+        ).map_err(SrvError::OriginPackageDownloadStats)?;
+
+        let mut response = originsrv::OriginPackageDownloadStatsResponse::new();
+        response.set_origin(opdsg.get_origin().to_string());
+        response.set_name(opdsg.get_name().to_string());
+        let mut total = 0;
+        let mut stats = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            let ident: String = row.get("ident");
+            let count: i64 = row.get("count");
+            let mut stat = originsrv::OriginPackageDownloadStat::new();
+            stat.set_ident(
+                originsrv::OriginPackageIdent::from_str(ident.as_str()).unwrap(),
+            );
+            stat.set_channel(row.get("channel"));
+            stat.set_day(row.get("day"));
+            stat.set_count(count as u64);
+            total += count as u64;
+            stats.push(stat);
+        }
+        response.set_total(total);
+        response.set_stats(stats);
+        Ok(response)
+    }
+
     pub fn get_origin_channel_package(
         &self,
         ocpg: &originsrv::OriginChannelPackageGet,
@@ -983,7 +1200,7 @@ impl DataStore {
     ) -> SrvResult<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(opc)?;
         let rows = conn.query(
-            "SELECT * FROM get_origin_package_latest_v5($1, $2, $3)",
+            "SELECT * FROM get_origin_package_latest_v6($1, $2, $3)",
             &[
                 &self.searchable_ident(opc.get_ident()),
                 &opc.get_target(),
@@ -1004,7 +1221,7 @@ impl DataStore {
     ) -> SrvResult<Option<originsrv::OriginPackageIdent>> {
         let conn = self.pool.get(ocpg)?;
         let rows = conn.query(
-            "SELECT * FROM get_origin_channel_package_latest_v5($1, $2, $3, $4, $5)",
+            "SELECT * FROM get_origin_channel_package_latest_v6($1, $2, $3, $4, $5)",
             &[
                 &ocpg.get_ident().get_origin(),
                 &ocpg.get_name(),
@@ -1128,9 +1345,9 @@ impl DataStore {
         let conn = self.pool.get(opl)?;
 
         let query = if *&opl.get_distinct() {
-            "SELECT * FROM get_origin_packages_for_origin_distinct_v4($1, $2, $3, $4)"
+            "SELECT * FROM get_origin_packages_for_origin_distinct_v6($1, $2, $3, $4, $5, $6)"
         } else {
-            "SELECT * FROM get_origin_packages_for_origin_v5($1, $2, $3, $4)"
+            "SELECT * FROM get_origin_packages_for_origin_v7($1, $2, $3, $4, $5, $6)"
         };
 
         let rows = conn.query(
@@ -1140,6 +1357,8 @@ impl DataStore {
                 &opl.limit(),
                 &(opl.get_start() as i64),
                 &self.vec_to_delimited_string(opl.get_visibilities()),
+                &opl.get_target(),
+                &self.snapshot(opl.get_created_before())?,
             ],
         ).map_err(SrvError::OriginPackageList)?;
 
@@ -1164,6 +1383,15 @@ impl DataStore {
         }
     }
 
+    /// The time a listing is limited to, if its request was given one
+    fn snapshot(&self, created_before: &str) -> SrvResult<Option<DateTime<UTC>>> {
+        if created_before.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(timestamp::parse(created_before)?))
+        }
+    }
+
     fn searchable_ident(&self, ident: &originsrv::OriginPackageIdent) -> String {
         let mut search_ident = ident.to_string();
 
@@ -1183,14 +1411,16 @@ impl DataStore {
         let conn = self.pool.get(opl)?;
 
         let rows = conn.query(
-            "SELECT * FROM get_origin_channel_packages_for_channel_v3($1, $2, $3, $4, $5, $6)",
+            "SELECT * FROM get_origin_channel_packages_for_channel_v5($1, $2, $3, $4, $5, $6, $7, $8)",
             &[
                 &opl.get_ident().get_origin(),
                 &opl.get_name(),
                 &self.searchable_ident(opl.get_ident()),
                 &self.vec_to_delimited_string(opl.get_visibilities()),
+                &opl.get_target(),
                 &opl.limit(),
                 &(opl.get_start() as i64),
+                &self.snapshot(opl.get_created_before())?,
             ],
         ).map_err(SrvError::OriginChannelPackageList)?;
 
@@ -1213,12 +1443,13 @@ impl DataStore {
     ) -> SrvResult<originsrv::OriginPackageUniqueListResponse> {
         let conn = self.pool.get(opl)?;
         let rows = conn.query(
-            "SELECT * FROM get_origin_packages_unique_for_origin_v4($1, $2, $3, $4)",
+            "SELECT * FROM get_origin_packages_unique_for_origin_v5($1, $2, $3, $4, $5)",
             &[
                 &opl.get_origin(),
                 &opl.limit(),
                 &(opl.get_start() as i64),
                 &self.vec_to_delimited_string(opl.get_visibilities()),
+                &self.snapshot(opl.get_created_before())?,
             ],
         ).map_err(SrvError::OriginPackageUniqueList)?;
 
@@ -1242,6 +1473,9 @@ impl DataStore {
         &self,
         ops: &originsrv::OriginPackageSearchRequest,
     ) -> SrvResult<originsrv::OriginPackageListResponse> {
+        if ops.is_filtered() {
+            return self.search_origin_packages_filtered(ops);
+        }
         let conn = self.pool.get(ops)?;
 
         let rows = if *&ops.get_distinct() {
@@ -1294,6 +1528,83 @@ impl DataStore {
         Ok(response)
     }
 
+    /// Searches packages matching the filters of the request, in the order it asks for. Distinct
+    /// searches return the origin and name of the matching packages, ordered by their newest or
+    /// oldest release.
+    fn search_origin_packages_filtered(
+        &self,
+        ops: &originsrv::OriginPackageSearchRequest,
+    ) -> SrvResult<originsrv::OriginPackageListResponse> {
+        let conn = self.pool.get(ops)?;
+        let order = match ops.get_order() {
+            originsrv::OriginPackageSearchOrder::Ident => "ident ASC",
+            originsrv::OriginPackageSearchOrder::Newest => "created_at DESC, ident ASC",
+            originsrv::OriginPackageSearchOrder::Oldest => "created_at ASC, ident ASC",
+        };
+        // Packages created after the snapshot of a listing are left out before they're grouped
+        let packages = if ops.get_distinct() {
+            "SELECT split_part(ident, '/', 1) || '/' || split_part(ident, '/', 2) AS ident,
+                    max(created_at) AS created_at
+             FROM search_origin_packages_v1($1, $2, $3, $4, $5, $6, $7)
+             WHERE $10::timestamptz IS NULL OR created_at <= $10
+             GROUP BY 1"
+        } else {
+            "SELECT * FROM search_origin_packages_v1($1, $2, $3, $4, $5, $6, $7)
+             WHERE $10::timestamptz IS NULL OR created_at <= $10"
+        };
+        let query = format!(
+            "SELECT COUNT(*) OVER () AS the_real_total, p.ident FROM ({}) p
+             ORDER BY {} LIMIT $8 OFFSET $9",
+            packages,
+            order
+        );
+        // The origin is ignored by distinct searches, which match the query against both the
+        // origin and name of packages
+        let origin = if ops.get_origin().is_empty() || ops.get_distinct() {
+            None
+        } else {
+            Some(ops.get_origin())
+        };
+        let filter = |value: &str| if value.is_empty() {
+            None
+        } else {
+            Some(value.to_string())
+        };
+        let built_after = if ops.has_built_after() {
+            Some(timestamp::parse(ops.get_built_after())?)
+        } else {
+            None
+        };
+
+        let rows = conn.query(
+            &query,
+            &[
+                &ops.get_query(),
+                &origin,
+                &self.vec_to_delimited_string(ops.get_my_origins()),
+                &filter(ops.get_target()),
+                &filter(ops.get_channel()),
+                &filter(ops.get_license()),
+                &built_after,
+                &ops.limit(),
+                &(ops.get_start() as i64),
+                &self.snapshot(ops.get_created_before())?,
+            ],
+        ).map_err(SrvError::OriginPackageSearch)?;
+
+        let mut response = originsrv::OriginPackageListResponse::new();
+        response.set_start(ops.get_start());
+        response.set_stop(self.last_index(ops, &rows));
+        let mut idents = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            let count: i64 = row.get("the_real_total");
+            response.set_count(count as u64);
+            idents.push(self.row_to_origin_package_ident(&row));
+        }
+        response.set_idents(idents);
+        Ok(response)
+    }
+
     fn into_delimited<T: Display>(&self, parts: Vec<T>) -> String {
         let mut buffer = String::new();
         for part in parts.iter() {
@@ -1353,12 +1664,44 @@ impl DataStore {
         package.set_exposes(exposes);
         package.set_deps(self.into_idents(row.get("deps")));
         package.set_tdeps(self.into_idents(row.get("tdeps")));
+        let exports: String = row.get("exports");
+        package.set_exports(
+            exports
+                .split(":")
+                .filter_map(|export| {
+                    let mut parts = export.splitn(2, '=');
+                    match (parts.next(), parts.next()) {
+                        (Some(name), Some(key)) => {
+                            let mut export = originsrv::OriginPackageExport::new();
+                            export.set_name(name.to_string());
+                            export.set_key(key.to_string());
+                            Some(export)
+                        }
+                        _ => None,
+                    }
+                })
+                .collect(),
+        );
+        let licenses: String = row.get("licenses");
+        package.set_licenses(
+            licenses
+                .split(":")
+                .filter(|license| !license.is_empty())
+                .map(|license| license.to_string())
+                .collect(),
+        );
+        package.set_description(row.get("description"));
 
         let pv: String = row.get("visibility");
         let pv2: originsrv::OriginPackageVisibility =
             pv.parse().map_err(SrvError::UnknownOriginPackageVisibility)?;
         package.set_visibility(pv2);
 
+        if let Some(yanked_at) = row.get::<&str, Option<DateTime<UTC>>>("yanked_at") {
+            package.set_yanked_at(yanked_at.to_rfc3339());
+            package.set_yank_reason(row.get("yank_reason"));
+        }
+
         Ok(package)
     }
 
@@ -1607,6 +1950,218 @@ impl DataStore {
         Ok(())
     }
 
+    pub fn create_origin_notification(
+        &self,
+        onc: &originsrv::OriginNotificationCreate,
+    ) -> SrvResult<originsrv::OriginNotification> {
+        let conn = self.pool.get(onc)?;
+        let notification = onc.get_notification();
+        let events: Vec<String> = notification
+            .get_events()
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+        let secret = if notification.has_secret() {
+            Some(notification.get_secret())
+        } else {
+            None
+        };
+
+        let rows = conn.query(
+            "SELECT * FROM insert_origin_notification_v2($1, $2, $3, $4, $5, $6)",
+            &[
+                &notification.get_origin(),
+                &notification.get_kind().to_string(),
+                &notification.get_target(),
+                &events,
+                &(notification.get_owner_id() as i64),
+                &secret,
+            ],
+        ).map_err(SrvError::OriginNotificationCreate)?;
+        let row = rows.iter().nth(0).expect(
+            "Insert returns row, but no row present",
+        );
+        self.row_to_origin_notification(&row)
+    }
+
+    pub fn get_origin_notifications(
+        &self,
+        onl: &originsrv::OriginNotificationListRequest,
+    ) -> SrvResult<originsrv::OriginNotificationListResponse> {
+        let conn = self.pool.get(onl)?;
+        let rows = &conn.query(
+            "SELECT * FROM get_origin_notifications_v1($1)",
+            &[&onl.get_origin()],
+        ).map_err(SrvError::OriginNotificationList)?;
+
+        let mut response = originsrv::OriginNotificationListResponse::new();
+        let mut notifications = protobuf::RepeatedField::new();
+        for row in rows {
+            notifications.push(self.row_to_origin_notification(&row)?);
+        }
+
+        response.set_notifications(notifications);
+        Ok(response)
+    }
+
+    pub fn delete_origin_notification(
+        &self,
+        ond: &originsrv::OriginNotificationDelete,
+    ) -> SrvResult<()> {
+        let conn = self.pool.get(ond)?;
+        conn.execute(
+            "SELECT delete_origin_notification_v1($1, $2)",
+            &[&ond.get_origin(), &(ond.get_id() as i64)],
+        ).map_err(SrvError::OriginNotificationDelete)?;
+        Ok(())
+    }
+
+    pub fn upsert_origin_secret(
+        &self,
+        osc: &originsrv::OriginSecretCreate,
+    ) -> SrvResult<originsrv::OriginSecret> {
+        let conn = self.pool.get(osc)?;
+        let secret = osc.get_secret();
+        let rows = conn.query(
+            "SELECT * FROM upsert_origin_secret_v1($1, $2, $3)",
+            &[
+                &secret.get_origin(),
+                &secret.get_name(),
+                &secret.get_value(),
+            ],
+        ).map_err(SrvError::OriginSecretCreate)?;
+        let row = rows.iter().nth(0).expect(
+            "Insert returns row, but no row present",
+        );
+        Ok(self.row_to_origin_secret(&row))
+    }
+
+    pub fn get_origin_secrets(
+        &self,
+        osl: &originsrv::OriginSecretListRequest,
+    ) -> SrvResult<originsrv::OriginSecretListResponse> {
+        let conn = self.pool.get(osl)?;
+        let rows = &conn.query(
+            "SELECT * FROM get_origin_secrets_v1($1)",
+            &[&osl.get_origin()],
+        ).map_err(SrvError::OriginSecretList)?;
+
+        let mut response = originsrv::OriginSecretListResponse::new();
+        let mut secrets = protobuf::RepeatedField::new();
+        for row in rows {
+            secrets.push(self.row_to_origin_secret(&row));
+        }
+
+        response.set_secrets(secrets);
+        Ok(response)
+    }
+
+    pub fn delete_origin_secret(&self, osd: &originsrv::OriginSecretDelete) -> SrvResult<()> {
+        let conn = self.pool.get(osd)?;
+        conn.execute(
+            "SELECT delete_origin_secret_v1($1, $2)",
+            &[&osd.get_origin(), &osd.get_name()],
+        ).map_err(SrvError::OriginSecretDelete)?;
+        Ok(())
+    }
+
+    pub fn create_origin_encryption_key(
+        &self,
+        oekc: &originsrv::OriginEncryptionKeyCreate,
+    ) -> SrvResult<originsrv::OriginEncryptionKey> {
+        let conn = self.pool.get(oekc)?;
+        let key = oekc.get_key();
+        let rows = conn.query(
+            "SELECT * FROM insert_origin_encryption_key_v1($1, $2, $3, $4, $5)",
+            &[
+                &(key.get_origin_id() as i64),
+                &key.get_origin(),
+                &key.get_revision(),
+                &key.get_public_key(),
+                &key.get_secret_key(),
+            ],
+        ).map_err(SrvError::OriginEncryptionKeyCreate)?;
+        let row = rows.iter().nth(0).expect(
+            "Insert returns row, but no row present",
+        );
+        Ok(self.row_to_origin_encryption_key(&row))
+    }
+
+    pub fn get_origin_encryption_key(
+        &self,
+        oek_get: &originsrv::OriginEncryptionKeyGet,
+    ) -> SrvResult<Option<originsrv::OriginEncryptionKey>> {
+        let conn = self.pool.get(oek_get)?;
+        let rows = if oek_get.has_revision() {
+            conn.query(
+                "SELECT * FROM get_origin_encryption_key_v1($1, $2)",
+                &[&oek_get.get_origin(), &oek_get.get_revision()],
+            )
+        } else {
+            conn.query(
+                "SELECT * FROM get_origin_encryption_key_latest_v1($1)",
+                &[&oek_get.get_origin()],
+            )
+        }.map_err(SrvError::OriginEncryptionKeyGet)?;
+        if rows.len() != 0 {
+            // We just checked - we know there is a value here
+            let row = rows.iter().nth(0).unwrap();
+            Ok(Some(self.row_to_origin_encryption_key(&row)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn row_to_origin_encryption_key(
+        &self,
+        row: &postgres::rows::Row,
+    ) -> originsrv::OriginEncryptionKey {
+        let mut key = originsrv::OriginEncryptionKey::new();
+        let origin_id: i64 = row.get("origin_id");
+        key.set_origin_id(origin_id as u64);
+        key.set_origin(row.get("origin"));
+        key.set_revision(row.get("revision"));
+        key.set_public_key(row.get("public_key"));
+        key.set_secret_key(row.get("secret_key"));
+        key
+    }
+
+    fn row_to_origin_secret(&self, row: &postgres::rows::Row) -> originsrv::OriginSecret {
+        let mut secret = originsrv::OriginSecret::new();
+        secret.set_origin(row.get("origin"));
+        secret.set_name(row.get("name"));
+        secret.set_value(row.get("value"));
+        secret
+    }
+
+    fn row_to_origin_notification(
+        &self,
+        row: &postgres::rows::Row,
+    ) -> SrvResult<originsrv::OriginNotification> {
+        let mut notification = originsrv::OriginNotification::new();
+        let id: i64 = row.get("id");
+        notification.set_id(id as u64);
+        notification.set_origin(row.get("origin"));
+        let kind: String = row.get("kind");
+        notification.set_kind(kind.parse().map_err(
+            SrvError::UnknownOriginNotificationSetting,
+        )?);
+        notification.set_target(row.get("target"));
+        let events: Vec<String> = row.get("events");
+        for event in events {
+            notification.mut_events().push(event.parse().map_err(
+                SrvError::UnknownOriginNotificationSetting,
+            )?);
+        }
+        if let Some(Ok(owner_id)) = row.get_opt::<&str, i64>("owner_id") {
+            notification.set_owner_id(owner_id as u64);
+        }
+        if let Some(Ok(secret)) = row.get_opt::<&str, String>("secret") {
+            notification.set_secret(secret);
+        }
+        Ok(notification)
+    }
+
     pub fn delete_origin_member(&self, omr: &originsrv::OriginMemberRemove) -> SrvResult<()> {
         let conn = self.pool.get(omr)?;
 
@@ -1617,6 +2172,181 @@ impl DataStore {
         Ok(())
     }
 
+    /// Returns false if the account isn't a member of the origin.
+    pub fn update_origin_member_approver(
+        &self,
+        omau: &originsrv::OriginMemberApproverUpdate,
+    ) -> SrvResult<bool> {
+        let conn = self.pool.get(omau)?;
+        let rows = conn.query(
+            "SELECT * FROM update_origin_member_approver_v1($1, $2, $3)",
+            &[
+                &(omau.get_origin_id() as i64),
+                &omau.get_account_name(),
+                &omau.get_approver(),
+            ],
+        ).map_err(SrvError::OriginMemberApproverUpdate)?;
+        Ok(!rows.is_empty())
+    }
+
+    pub fn create_origin_promotion_request(
+        &self,
+        oprc: &originsrv::OriginPromotionRequestCreate,
+    ) -> SrvResult<originsrv::OriginPromotionRequest> {
+        let conn = self.pool.get(oprc)?;
+        let promotion = oprc.get_promotion();
+        let idents = promotion.get_idents().to_vec();
+
+        let rows = conn.query(
+            "SELECT * FROM insert_origin_promotion_request_v1($1, $2, $3, $4, $5, $6)",
+            &[
+                &promotion.get_origin(),
+                &promotion.get_channel(),
+                &idents,
+                &(promotion.get_requester_id() as i64),
+                &promotion.get_requester_name(),
+                &oprc.get_comment(),
+            ],
+        ).map_err(SrvError::OriginPromotionRequestCreate)?;
+        let row = rows.iter().nth(0).expect(
+            "Insert returns row, but no row present",
+        );
+        let mut promotion = self.row_to_origin_promotion_request(&row)?;
+        let events = self.get_origin_promotion_request_events(&conn, promotion.get_id())?;
+        promotion.set_events(events);
+        Ok(promotion)
+    }
+
+    pub fn get_origin_promotion_request(
+        &self,
+        oprg: &originsrv::OriginPromotionRequestGet,
+    ) -> SrvResult<Option<originsrv::OriginPromotionRequest>> {
+        let conn = self.pool.get(oprg)?;
+        let rows = &conn.query(
+            "SELECT * FROM get_origin_promotion_request_v1($1, $2)",
+            &[&oprg.get_origin(), &(oprg.get_id() as i64)],
+        ).map_err(SrvError::OriginPromotionRequestGet)?;
+
+        match rows.iter().nth(0) {
+            Some(row) => {
+                let mut promotion = self.row_to_origin_promotion_request(&row)?;
+                let events = self.get_origin_promotion_request_events(&conn, promotion.get_id())?;
+                promotion.set_events(events);
+                Ok(Some(promotion))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn get_origin_promotion_requests(
+        &self,
+        oprl: &originsrv::OriginPromotionRequestListRequest,
+    ) -> SrvResult<originsrv::OriginPromotionRequestListResponse> {
+        let conn = self.pool.get(oprl)?;
+        let state = if oprl.has_state() {
+            Some(oprl.get_state().to_string())
+        } else {
+            None
+        };
+        let rows = &conn.query(
+            "SELECT * FROM get_origin_promotion_requests_v1($1, $2)",
+            &[&oprl.get_origin(), &state],
+        ).map_err(SrvError::OriginPromotionRequestList)?;
+
+        let mut response = originsrv::OriginPromotionRequestListResponse::new();
+        let mut promotions = protobuf::RepeatedField::new();
+        for row in rows {
+            promotions.push(self.row_to_origin_promotion_request(&row)?);
+        }
+
+        response.set_promotions(promotions);
+        Ok(response)
+    }
+
+    /// Moves a promotion request to another state and records who did so. Returns None if the
+    /// request isn't in the state it's moved from.
+    pub fn transition_origin_promotion_request(
+        &self,
+        oprt: &originsrv::OriginPromotionRequestTransition,
+    ) -> SrvResult<Option<originsrv::OriginPromotionRequest>> {
+        let conn = self.pool.get(oprt)?;
+        let rows = &conn.query(
+            "SELECT * FROM transition_origin_promotion_request_v1($1, $2, $3, $4, $5, $6, $7)",
+            &[
+                &oprt.get_origin(),
+                &(oprt.get_id() as i64),
+                &oprt.get_from_state().to_string(),
+                &oprt.get_to_state().to_string(),
+                &(oprt.get_account_id() as i64),
+                &oprt.get_account_name(),
+                &oprt.get_comment(),
+            ],
+        ).map_err(SrvError::OriginPromotionRequestTransition)?;
+
+        match rows.iter().nth(0) {
+            Some(row) => {
+                let mut promotion = self.row_to_origin_promotion_request(&row)?;
+                let events = self.get_origin_promotion_request_events(&conn, promotion.get_id())?;
+                promotion.set_events(events);
+                Ok(Some(promotion))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn get_origin_promotion_request_events(
+        &self,
+        conn: &postgres::Connection,
+        id: u64,
+    ) -> SrvResult<protobuf::RepeatedField<originsrv::OriginPromotionRequestEvent>> {
+        let rows = &conn.query(
+            "SELECT * FROM get_origin_promotion_request_events_v1($1)",
+            &[&(id as i64)],
+        ).map_err(SrvError::OriginPromotionRequestGet)?;
+
+        let mut events = protobuf::RepeatedField::new();
+        for row in rows {
+            let mut event = originsrv::OriginPromotionRequestEvent::new();
+            let state: String = row.get("state");
+            event.set_state(state.parse().map_err(
+                SrvError::UnknownOriginPromotionRequestState,
+            )?);
+            let account_id: i64 = row.get("account_id");
+            event.set_account_id(account_id as u64);
+            event.set_account_name(row.get("account_name"));
+            event.set_comment(row.get("comment"));
+            let created_at = row.get::<&str, DateTime<UTC>>("created_at");
+            event.set_created_at(created_at.to_rfc3339());
+            events.push(event);
+        }
+        Ok(events)
+    }
+
+    fn row_to_origin_promotion_request(
+        &self,
+        row: &postgres::rows::Row,
+    ) -> SrvResult<originsrv::OriginPromotionRequest> {
+        let mut promotion = originsrv::OriginPromotionRequest::new();
+        let id: i64 = row.get("id");
+        promotion.set_id(id as u64);
+        promotion.set_origin(row.get("origin"));
+        promotion.set_channel(row.get("channel"));
+        let idents: Vec<String> = row.get("idents");
+        promotion.set_idents(protobuf::RepeatedField::from_vec(idents));
+        let requester_id: i64 = row.get("requester_id");
+        promotion.set_requester_id(requester_id as u64);
+        promotion.set_requester_name(row.get("requester_name"));
+        let state: String = row.get("state");
+        promotion.set_state(state.parse().map_err(
+            SrvError::UnknownOriginPromotionRequestState,
+        )?);
+        let created_at = row.get::<&str, DateTime<UTC>>("created_at");
+        promotion.set_created_at(created_at.to_rfc3339());
+        let updated_at = row.get::<&str, DateTime<UTC>>("updated_at");
+        promotion.set_updated_at(updated_at.to_rfc3339());
+        Ok(promotion)
+    }
+
     fn rows_to_origin_integration_names(
         &self,
         rows: &postgres::rows::Rows,
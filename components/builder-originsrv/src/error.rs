@@ -49,12 +49,26 @@ pub enum SrvError {
     OriginChannelPackageLatestGet(postgres::error::Error),
     OriginChannelPackageList(postgres::error::Error),
     OriginCheckAccess(postgres::error::Error),
+    OriginCheckApprover(postgres::error::Error),
     OriginGet(postgres::error::Error),
+    OriginMemberApproverUpdate(postgres::error::Error),
     OriginMemberList(postgres::error::Error),
     OriginIntegrationCreate(postgres::error::Error),
     OriginIntegrationGetNames(postgres::error::Error),
     OriginIntegrationDelete(postgres::error::Error),
     OriginIntegrationRequest(postgres::error::Error),
+    OriginNotificationCreate(postgres::error::Error),
+    OriginNotificationDelete(postgres::error::Error),
+    OriginNotificationList(postgres::error::Error),
+    OriginPromotionRequestCreate(postgres::error::Error),
+    OriginPromotionRequestGet(postgres::error::Error),
+    OriginPromotionRequestList(postgres::error::Error),
+    OriginPromotionRequestTransition(postgres::error::Error),
+    OriginSecretCreate(postgres::error::Error),
+    OriginSecretDelete(postgres::error::Error),
+    OriginSecretList(postgres::error::Error),
+    OriginEncryptionKeyCreate(postgres::error::Error),
+    OriginEncryptionKeyGet(postgres::error::Error),
     OriginInvitationAccept(postgres::error::Error),
     OriginInvitationCreate(postgres::error::Error),
     OriginInvitationGet(postgres::error::Error),
@@ -65,6 +79,11 @@ pub enum SrvError {
     OriginInvitationValidate(postgres::error::Error),
     OriginMemberDelete(postgres::error::Error),
     OriginPackageCreate(postgres::error::Error),
+    OriginPackageDownloadRecord(postgres::error::Error),
+    OriginPackageDownloadStats(postgres::error::Error),
+    OriginPackageYank(postgres::error::Error),
+    OriginPackageRetentionCandidatesGet(postgres::error::Error),
+    OriginPackageDelete(postgres::error::Error),
     OriginPackageGet(postgres::error::Error),
     OriginPackageLatestGet(postgres::error::Error),
     OriginPackageChannelList(postgres::error::Error),
@@ -93,6 +112,7 @@ pub enum SrvError {
     OriginPublicKeyGet(postgres::error::Error),
     OriginPublicKeyLatestGet(postgres::error::Error),
     OriginPublicKeyListForOrigin(postgres::error::Error),
+    OriginPublicKeyDeprecate(postgres::error::Error),
     OriginUpdate(postgres::error::Error),
     OriginAccountList(postgres::error::Error),
     OriginAccountInOrigin(postgres::error::Error),
@@ -100,7 +120,9 @@ pub enum SrvError {
     SyncInvitations(postgres::error::Error),
     SyncInvitationsUpdate(postgres::error::Error),
     Protobuf(protobuf::ProtobufError),
+    UnknownOriginNotificationSetting(protocol::originsrv::Error),
     UnknownOriginPackageVisibility(protocol::originsrv::Error),
+    UnknownOriginPromotionRequestState(protocol::originsrv::Error),
     VisibilityCascade(postgres::error::Error),
 }
 
@@ -154,7 +176,13 @@ impl fmt::Display for SrvError {
             SrvError::OriginCheckAccess(ref e) => {
                 format!("Error checking access to origin in database, {}", e)
             }
+            SrvError::OriginCheckApprover(ref e) => {
+                format!("Error checking approvers of origin in database, {}", e)
+            }
             SrvError::OriginGet(ref e) => format!("Error getting origin from database, {}", e),
+            SrvError::OriginMemberApproverUpdate(ref e) => {
+                format!("Error updating origin member approver role in database, {}", e)
+            }
             SrvError::OriginMemberList(ref e) => {
                 format!("Error getting origin members from database, {}", e)
             }
@@ -170,6 +198,42 @@ impl fmt::Display for SrvError {
             SrvError::OriginIntegrationRequest(ref e) => {
                 format!("Error retrieving integration request from database, {}", e)
             }
+            SrvError::OriginNotificationCreate(ref e) => {
+                format!("Error creating notification in database, {}", e)
+            }
+            SrvError::OriginNotificationDelete(ref e) => {
+                format!("Error deleting notification from database, {}", e)
+            }
+            SrvError::OriginNotificationList(ref e) => {
+                format!("Error listing notifications from database, {}", e)
+            }
+            SrvError::OriginPromotionRequestCreate(ref e) => {
+                format!("Error creating promotion request in database, {}", e)
+            }
+            SrvError::OriginPromotionRequestGet(ref e) => {
+                format!("Error retrieving promotion request from database, {}", e)
+            }
+            SrvError::OriginPromotionRequestList(ref e) => {
+                format!("Error listing promotion requests from database, {}", e)
+            }
+            SrvError::OriginPromotionRequestTransition(ref e) => {
+                format!("Error updating promotion request in database, {}", e)
+            }
+            SrvError::OriginSecretCreate(ref e) => {
+                format!("Error creating secret in database, {}", e)
+            }
+            SrvError::OriginSecretDelete(ref e) => {
+                format!("Error deleting secret from database, {}", e)
+            }
+            SrvError::OriginSecretList(ref e) => {
+                format!("Error listing secrets from database, {}", e)
+            }
+            SrvError::OriginEncryptionKeyCreate(ref e) => {
+                format!("Error creating origin encryption key in database, {}", e)
+            }
+            SrvError::OriginEncryptionKeyGet(ref e) => {
+                format!("Error getting origin encryption key from database, {}", e)
+            }
             SrvError::OriginInvitationAccept(ref e) => {
                 format!("Error accepting origin invitation, {}", e)
             }
@@ -206,6 +270,21 @@ impl fmt::Display for SrvError {
             SrvError::OriginPackageCreate(ref e) => {
                 format!("Error creating package in database, {}", e)
             }
+            SrvError::OriginPackageDownloadRecord(ref e) => {
+                format!("Error recording package download in database, {}", e)
+            }
+            SrvError::OriginPackageDownloadStats(ref e) => {
+                format!("Error retrieving package download stats from database, {}", e)
+            }
+            SrvError::OriginPackageYank(ref e) => {
+                format!("Error yanking package in database, {}", e)
+            }
+            SrvError::OriginPackageRetentionCandidatesGet(ref e) => {
+                format!("Error retrieving package retention candidates from database, {}", e)
+            }
+            SrvError::OriginPackageDelete(ref e) => {
+                format!("Error deleting package from database, {}", e)
+            }
             SrvError::OriginMemberDelete(ref e) => {
                 format!("Error deleting member of origin in database, {}", e)
             }
@@ -308,6 +387,9 @@ impl fmt::Display for SrvError {
                     e
                 )
             }
+            SrvError::OriginPublicKeyDeprecate(ref e) => {
+                format!("Error deprecating origin public keys in database, {}", e)
+            }
             SrvError::OriginAccountList(ref e) => {
                 format!("Error getting list of origins for this account, {}", e)
             }
@@ -323,7 +405,9 @@ impl fmt::Display for SrvError {
             }
             SrvError::OriginUpdate(ref e) => format!("Error updating origin, {}", e),
             SrvError::Protobuf(ref e) => format!("{}", e),
+            SrvError::UnknownOriginNotificationSetting(ref e) => format!("{}", e),
             SrvError::UnknownOriginPackageVisibility(ref e) => format!("{}", e),
+            SrvError::UnknownOriginPromotionRequestState(ref e) => format!("{}", e),
             SrvError::VisibilityCascade(ref e) => format!("{}", e),
         };
         write!(f, "{}", msg)
@@ -354,13 +438,27 @@ impl error::Error for SrvError {
             SrvError::OriginChannelPackageLatestGet(ref err) => err.description(),
             SrvError::OriginChannelPackageList(ref err) => err.description(),
             SrvError::OriginCheckAccess(ref err) => err.description(),
+            SrvError::OriginCheckApprover(ref err) => err.description(),
             SrvError::OriginChannelDelete(ref err) => err.description(),
             SrvError::OriginGet(ref err) => err.description(),
+            SrvError::OriginMemberApproverUpdate(ref err) => err.description(),
             SrvError::OriginMemberList(ref err) => err.description(),
             SrvError::OriginIntegrationCreate(ref err) => err.description(),
             SrvError::OriginIntegrationGetNames(ref err) => err.description(),
             SrvError::OriginIntegrationDelete(ref err) => err.description(),
             SrvError::OriginIntegrationRequest(ref err) => err.description(),
+            SrvError::OriginNotificationCreate(ref err) => err.description(),
+            SrvError::OriginNotificationDelete(ref err) => err.description(),
+            SrvError::OriginNotificationList(ref err) => err.description(),
+            SrvError::OriginPromotionRequestCreate(ref err) => err.description(),
+            SrvError::OriginPromotionRequestGet(ref err) => err.description(),
+            SrvError::OriginPromotionRequestList(ref err) => err.description(),
+            SrvError::OriginPromotionRequestTransition(ref err) => err.description(),
+            SrvError::OriginSecretCreate(ref err) => err.description(),
+            SrvError::OriginSecretDelete(ref err) => err.description(),
+            SrvError::OriginSecretList(ref err) => err.description(),
+            SrvError::OriginEncryptionKeyCreate(ref err) => err.description(),
+            SrvError::OriginEncryptionKeyGet(ref err) => err.description(),
             SrvError::OriginInvitationAccept(ref err) => err.description(),
             SrvError::OriginInvitationCreate(ref err) => err.description(),
             SrvError::OriginInvitationGet(ref err) => err.description(),
@@ -371,6 +469,11 @@ impl error::Error for SrvError {
             SrvError::OriginInvitationValidate(ref err) => err.description(),
             SrvError::OriginMemberDelete(ref err) => err.description(),
             SrvError::OriginPackageCreate(ref err) => err.description(),
+            SrvError::OriginPackageDownloadRecord(ref err) => err.description(),
+            SrvError::OriginPackageDownloadStats(ref err) => err.description(),
+            SrvError::OriginPackageYank(ref err) => err.description(),
+            SrvError::OriginPackageRetentionCandidatesGet(ref err) => err.description(),
+            SrvError::OriginPackageDelete(ref err) => err.description(),
             SrvError::OriginPackageGet(ref err) => err.description(),
             SrvError::OriginPackageLatestGet(ref err) => err.description(),
             SrvError::OriginPackageChannelList(ref err) => err.description(),
@@ -399,6 +502,7 @@ impl error::Error for SrvError {
             SrvError::OriginPublicKeyGet(ref err) => err.description(),
             SrvError::OriginPublicKeyLatestGet(ref err) => err.description(),
             SrvError::OriginPublicKeyListForOrigin(ref err) => err.description(),
+            SrvError::OriginPublicKeyDeprecate(ref err) => err.description(),
             SrvError::OriginAccountList(ref err) => err.description(),
             SrvError::OriginAccountInOrigin(ref err) => err.description(),
             SrvError::OriginUpdate(ref err) => err.description(),
@@ -406,7 +510,9 @@ impl error::Error for SrvError {
             SrvError::SyncInvitations(ref err) => err.description(),
             SrvError::SyncInvitationsUpdate(ref err) => err.description(),
             SrvError::Protobuf(ref err) => err.description(),
+            SrvError::UnknownOriginNotificationSetting(ref err) => err.description(),
             SrvError::UnknownOriginPackageVisibility(ref err) => err.description(),
+            SrvError::UnknownOriginPromotionRequestState(ref err) => err.description(),
             SrvError::VisibilityCascade(ref err) => err.description(),
         }
     }
@@ -16,6 +16,7 @@
 #![cfg_attr(feature="clippy", plugin(clippy))]
 
 extern crate builder_core as bldr_core;
+extern crate chrono;
 extern crate habitat_builder_db as db;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
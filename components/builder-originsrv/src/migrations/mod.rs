@@ -18,6 +18,11 @@ pub mod origin_secret_keys;
 pub mod origin_public_keys;
 pub mod origin_invitations;
 pub mod origin_integrations;
+pub mod origin_notifications;
+pub mod origin_promotion_requests;
+pub mod origin_secrets;
+pub mod origin_encryption_keys;
 pub mod origin_projects;
 pub mod origin_packages;
+pub mod origin_package_downloads;
 pub mod origin_channels;
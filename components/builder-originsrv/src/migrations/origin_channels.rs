@@ -352,5 +352,88 @@ pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
                      DELETE FROM origin_channel_packages WHERE channel_id=opp_channel_id AND package_id = ANY(opp_package_ids);
                  $$"#,
     )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_channel_package_latest_v6 (
+                    op_origin text,
+                    op_channel text,
+                    op_ident text,
+                    op_target text,
+                    op_visibilities text
+                 ) RETURNS SETOF origin_packages AS $$
+                    BEGIN
+                        RETURN QUERY SELECT op.*
+                          FROM origin_packages op
+                          INNER JOIN origin_channel_packages ocp on ocp.package_id = op.id
+                          INNER JOIN origin_channels oc on ocp.channel_id = oc.id
+                          INNER JOIN origins o on oc.origin_id = o.id
+                          WHERE o.name = op_origin
+                          AND oc.name = op_channel
+                          AND op.target = op_target
+                          AND op.visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                          AND op.ident LIKE (op_ident  || '%')
+                          AND op.yanked_at IS NULL;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_channel_packages_for_channel_v4 (
+                    op_origin text,
+                    op_channel text,
+                    op_ident text,
+                    op_visibilities text,
+                    op_target text,
+                    op_limit bigint,
+                    op_offset bigint
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, op.ident
+                          FROM origin_packages op
+                          INNER JOIN origin_channel_packages ocp on ocp.package_id = op.id
+                          INNER JOIN origin_channels oc on ocp.channel_id = oc.id
+                          INNER JOIN origins o on oc.origin_id = o.id
+                          WHERE o.name = op_origin
+                          AND oc.name = op_channel
+                          AND op.visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                          AND (op_target = '' OR op.target = op_target)
+                          AND op.ident LIKE (op_ident  || '%')
+                          ORDER BY ident ASC
+                          LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_channel_packages_for_channel_v5 (
+                    op_origin text,
+                    op_channel text,
+                    op_ident text,
+                    op_visibilities text,
+                    op_target text,
+                    op_limit bigint,
+                    op_offset bigint,
+                    op_created_before timestamptz
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, op.ident
+                          FROM origin_packages op
+                          INNER JOIN origin_channel_packages ocp on ocp.package_id = op.id
+                          INNER JOIN origin_channels oc on ocp.channel_id = oc.id
+                          INNER JOIN origins o on oc.origin_id = o.id
+                          WHERE o.name = op_origin
+                          AND oc.name = op_channel
+                          AND op.visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                          AND (op_target = '' OR op.target = op_target)
+                          AND op.ident LIKE (op_ident  || '%')
+                          AND (op_created_before IS NULL OR ocp.created_at <= op_created_before)
+                          ORDER BY ident ASC
+                          LIMIT op_limit OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
     Ok(())
 }
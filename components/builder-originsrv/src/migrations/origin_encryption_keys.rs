@@ -0,0 +1,73 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS origin_encryption_key_id_seq;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_encryption_keys (
+                        id bigint PRIMARY KEY DEFAULT next_id_v1('origin_encryption_key_id_seq'),
+                        origin_id bigint REFERENCES origins(id) ON DELETE CASCADE,
+                        origin text NOT NULL,
+                        revision text NOT NULL,
+                        public_key text NOT NULL,
+                        secret_key text NOT NULL,
+                        created_at timestamptz DEFAULT now(),
+                        UNIQUE (origin, revision)
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_encryption_key_v1 (
+                        in_origin_id bigint,
+                        in_origin text,
+                        in_revision text,
+                        in_public_key text,
+                        in_secret_key text
+                 ) RETURNS SETOF origin_encryption_keys AS $$
+                        INSERT INTO origin_encryption_keys (origin_id, origin, revision, public_key, secret_key)
+                        VALUES (in_origin_id, in_origin, in_revision, in_public_key, in_secret_key)
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_encryption_key_latest_v1 (
+                        in_origin text
+                 ) RETURNS SETOF origin_encryption_keys AS $$
+                        SELECT * FROM origin_encryption_keys
+                        WHERE origin = in_origin
+                        ORDER BY revision DESC
+                        LIMIT 1
+                    $$ LANGUAGE SQL STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_encryption_key_v1 (
+                        in_origin text,
+                        in_revision text
+                 ) RETURNS SETOF origin_encryption_keys AS $$
+                        SELECT * FROM origin_encryption_keys
+                        WHERE origin = in_origin AND revision = in_revision
+                    $$ LANGUAGE SQL STABLE"#,
+    )?;
+    Ok(())
+}
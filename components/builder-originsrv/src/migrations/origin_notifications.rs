@@ -0,0 +1,119 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS origin_notification_id_seq;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_notifications (
+                        id bigint PRIMARY KEY DEFAULT next_id_v1('origin_notification_id_seq'),
+                        origin_id bigint REFERENCES origins(id) ON DELETE CASCADE,
+                        origin text NOT NULL,
+                        kind text NOT NULL,
+                        target text NOT NULL,
+                        events text[] NOT NULL DEFAULT '{}',
+                        owner_id bigint,
+                        created_at timestamptz DEFAULT now(),
+                        updated_at timestamptz DEFAULT now(),
+                        UNIQUE (origin, kind, target)
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_notification_v1 (
+                        in_origin text,
+                        in_kind text,
+                        in_target text,
+                        in_events text[],
+                        in_owner_id bigint
+                 ) RETURNS SETOF origin_notifications AS $$
+                        INSERT INTO origin_notifications(
+                                      origin_id,
+                                      origin,
+                                      kind,
+                                      target,
+                                      events,
+                                      owner_id)
+                        VALUES (
+                            (SELECT id FROM origins WHERE name = in_origin),
+                            in_origin,
+                            in_kind,
+                            in_target,
+                            in_events,
+                            in_owner_id)
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_notifications_v1 (
+                        in_origin text
+                 ) RETURNS SETOF origin_notifications AS $$
+                        SELECT * FROM origin_notifications
+                        WHERE origin = in_origin
+                        ORDER BY kind, target
+                    $$ LANGUAGE SQL STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION delete_origin_notification_v1 (
+                        in_origin text,
+                        in_id bigint
+                 ) RETURNS void AS $$
+                        DELETE FROM origin_notifications
+                        WHERE origin = in_origin AND id = in_id
+                    $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_notifications ADD COLUMN IF NOT EXISTS secret text;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_notification_v2 (
+                        in_origin text,
+                        in_kind text,
+                        in_target text,
+                        in_events text[],
+                        in_owner_id bigint,
+                        in_secret text
+                 ) RETURNS SETOF origin_notifications AS $$
+                        INSERT INTO origin_notifications(
+                                      origin_id,
+                                      origin,
+                                      kind,
+                                      target,
+                                      events,
+                                      owner_id,
+                                      secret)
+                        VALUES (
+                            (SELECT id FROM origins WHERE name = in_origin),
+                            in_origin,
+                            in_kind,
+                            in_target,
+                            in_events,
+                            in_owner_id,
+                            in_secret)
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_package_downloads (
+                        package_id bigint REFERENCES origin_packages(id) ON DELETE CASCADE,
+                        channel text NOT NULL DEFAULT '',
+                        day date NOT NULL,
+                        count bigint NOT NULL DEFAULT 0,
+                        PRIMARY KEY (package_id, channel, day)
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION record_origin_package_download_v1 (
+                        in_ident text,
+                        in_channel text
+                 ) RETURNS void AS $$
+                    BEGIN
+                        INSERT INTO origin_package_downloads (package_id, channel, day, count)
+                        SELECT op.id,
+                               CASE WHEN EXISTS (
+                                   SELECT 1 FROM origin_channel_packages ocp
+                                   INNER JOIN origin_channels oc ON oc.id = ocp.channel_id
+                                   WHERE ocp.package_id = op.id AND oc.name = in_channel)
+                               THEN in_channel ELSE '' END,
+                               (now() AT TIME ZONE 'UTC')::date,
+                               1
+                        FROM origin_packages op
+                        WHERE op.ident = in_ident
+                        ON CONFLICT ON CONSTRAINT origin_package_downloads_pkey
+                        DO UPDATE SET count = origin_package_downloads.count + 1;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_package_download_stats_v1 (
+                        in_origin text,
+                        in_name text,
+                        in_since timestamptz
+                 ) RETURNS TABLE(ident text, channel text, day text, count bigint) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT op.ident,
+                                            opd.channel,
+                                            to_char(opd.day, 'YYYY-MM-DD'),
+                                            opd.count
+                          FROM origin_package_downloads opd
+                          INNER JOIN origin_packages op ON op.id = opd.package_id
+                          INNER JOIN origins o ON o.id = op.origin_id
+                          WHERE o.name = in_origin
+                          AND op.name = in_name
+                          AND (in_since IS NULL OR opd.day >= (in_since AT TIME ZONE 'UTC')::date)
+                          ORDER BY opd.day DESC, op.ident DESC, opd.channel;
+                        RETURN;
+                    END
+                 $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    Ok(())
+}
@@ -734,5 +734,313 @@ pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
         "originsrv",
         r#"UPDATE origin_packages SET scheduler_sync = false "#,
     )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION search_origin_packages_v1 (
+                   op_query text,
+                   op_origin text,
+                   op_my_origins text,
+                   op_target text,
+                   op_channel text,
+                   op_license text,
+                   op_built_after timestamptz
+                 ) RETURNS TABLE(ident text, created_at timestamptz) AS $$
+                    DECLARE
+                      schema RECORD;
+                    BEGIN
+                      FOR schema IN EXECUTE
+                        format(
+                          'SELECT schema_name FROM information_schema.schemata WHERE left(schema_name, 6) = %L',
+                          'shard_'
+                        )
+                      LOOP
+                        RETURN QUERY EXECUTE
+                        format('SELECT op.ident, op.created_at
+                                FROM %1$I.origin_packages op INNER JOIN %1$I.origins o ON o.id = op.origin_id
+                                WHERE (CASE WHEN %2$L IS NULL THEN op.ident LIKE (''%%'' || %3$L || ''%%'')
+                                       ELSE o.name = %2$L AND op.name LIKE (''%%'' || %3$L || ''%%'') END)
+                                AND (op.visibility = ''public'' OR (op.visibility IN (''hidden'', ''private'') AND o.name = ANY(STRING_TO_ARRAY(%4$L, '',''))))
+                                AND (%5$L IS NULL OR op.target = %5$L)
+                                AND (%6$L IS NULL OR op.id IN (SELECT ocp.package_id FROM %1$I.origin_channel_packages ocp INNER JOIN %1$I.origin_channels oc ON oc.id = ocp.channel_id WHERE oc.name = %6$L))
+                                AND (%7$L IS NULL OR substring(op.manifest from ''__License__: ([^\n]*)'') ILIKE (''%%'' || %7$L || ''%%''))
+                                AND (%8$L IS NULL OR op.created_at > %8$L)',
+                          schema.schema_name, op_origin, op_query, op_my_origins, op_target,
+                          op_channel, op_license, op_built_after);
+                      END LOOP;
+                      RETURN;
+                    END;
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_packages ADD COLUMN IF NOT EXISTS yanked_at timestamptz"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_packages ADD COLUMN IF NOT EXISTS yank_reason text NOT NULL DEFAULT ''"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_package_latest_v6 (
+                    op_ident text,
+                    op_target text,
+                    op_visibilities text
+                 ) RETURNS SETOF origin_packages AS $$
+                    BEGIN
+                        RETURN QUERY SELECT *
+                        FROM origin_packages
+                        WHERE ident LIKE (op_ident  || '%')
+                        AND target = op_target
+                        AND visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                        AND yanked_at IS NULL;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION yank_origin_package_v1 (
+                    op_ident text,
+                    op_yanked bool,
+                    op_reason text
+                 ) RETURNS SETOF origin_packages AS $$
+                    UPDATE origin_packages
+                    SET yanked_at = CASE WHEN op_yanked THEN COALESCE(yanked_at, now()) ELSE NULL END,
+                        yank_reason = CASE WHEN op_yanked THEN op_reason ELSE '' END,
+                        updated_at = now()
+                    WHERE ident = op_ident
+                    RETURNING *;
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_packages_for_origin_v6 (
+                    op_ident text,
+                    op_limit bigint,
+                    op_offset bigint,
+                    op_visibilities text,
+                    op_target text
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, op.ident
+                          FROM origin_packages op
+                          WHERE op.ident LIKE (op_ident  || '%')
+                          AND op.visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                          AND (op_target = '' OR op.target = op_target)
+                          ORDER BY op.ident DESC
+                          LIMIT op_limit
+                          OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_packages_for_origin_distinct_v5 (
+                    op_ident text,
+                    op_limit bigint,
+                    op_offset bigint,
+                    op_visibilities text,
+                    op_target text
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(p.partial_ident[1] || '/' || p.partial_ident[2]) OVER () AS total_count, p.partial_ident[1] || '/' || p.partial_ident[2] AS ident
+                        FROM (SELECT regexp_split_to_array(op.ident, '/') as partial_ident
+                              FROM origin_packages op
+                              WHERE op.ident LIKE ('%' || op_ident || '%')
+                              AND op.visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                              AND (op_target = '' OR op.target = op_target)
+                              ) AS p
+                        GROUP BY (p.partial_ident[1] || '/' || p.partial_ident[2])
+                        LIMIT op_limit
+                        OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    // Listings can leave out the packages created after a given time, which keeps the pages of a
+    // listing from shifting while packages are uploaded
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_packages_for_origin_v7 (
+                    op_ident text,
+                    op_limit bigint,
+                    op_offset bigint,
+                    op_visibilities text,
+                    op_target text,
+                    op_created_before timestamptz
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, op.ident
+                          FROM origin_packages op
+                          WHERE op.ident LIKE (op_ident  || '%')
+                          AND op.visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                          AND (op_target = '' OR op.target = op_target)
+                          AND (op_created_before IS NULL OR op.created_at <= op_created_before)
+                          ORDER BY op.ident DESC
+                          LIMIT op_limit
+                          OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_packages_for_origin_distinct_v6 (
+                    op_ident text,
+                    op_limit bigint,
+                    op_offset bigint,
+                    op_visibilities text,
+                    op_target text,
+                    op_created_before timestamptz
+                 ) RETURNS TABLE(total_count bigint, ident text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(p.partial_ident[1] || '/' || p.partial_ident[2]) OVER () AS total_count, p.partial_ident[1] || '/' || p.partial_ident[2] AS ident
+                        FROM (SELECT regexp_split_to_array(op.ident, '/') as partial_ident
+                              FROM origin_packages op
+                              WHERE op.ident LIKE ('%' || op_ident || '%')
+                              AND op.visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                              AND (op_target = '' OR op.target = op_target)
+                              AND (op_created_before IS NULL OR op.created_at <= op_created_before)
+                              ) AS p
+                        GROUP BY (p.partial_ident[1] || '/' || p.partial_ident[2])
+                        ORDER BY 2
+                        LIMIT op_limit
+                        OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_packages_unique_for_origin_v5 (
+                   op_origin text,
+                   op_limit bigint,
+                   op_offset bigint,
+                   op_visibilities text,
+                   op_created_before timestamptz
+                 ) RETURNS TABLE(total_count bigint, name text) AS $$
+                    BEGIN
+                        RETURN QUERY SELECT COUNT(*) OVER () AS total_count, op.name
+                          FROM origins o INNER JOIN origin_packages op ON o.id = op.origin_id
+                          WHERE o.name = op_origin
+                          AND op.visibility = ANY(STRING_TO_ARRAY(op_visibilities, ','))
+                          AND (op_created_before IS NULL OR op.created_at <= op_created_before)
+                          GROUP BY op.name
+                          ORDER BY op.name ASC
+                          LIMIT op_limit
+                          OFFSET op_offset;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_packages ADD COLUMN IF NOT EXISTS exports text NOT NULL DEFAULT ''"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_packages ADD COLUMN IF NOT EXISTS licenses text NOT NULL DEFAULT ''"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_packages ADD COLUMN IF NOT EXISTS description text NOT NULL DEFAULT ''"#,
+    )?;
+    migrator.migrate("originsrv",
+                 r#"CREATE OR REPLACE FUNCTION insert_origin_package_v4 (
+                    op_origin_id bigint,
+                    op_owner_id bigint,
+                    op_name text,
+                    op_ident text,
+                    op_checksum text,
+                    op_manifest text,
+                    op_config text,
+                    op_target text,
+                    op_deps text,
+                    op_tdeps text,
+                    op_exposes text,
+                    op_visibility text,
+                    op_exports text,
+                    op_licenses text,
+                    op_description text
+                 ) RETURNS SETOF origin_packages AS $$
+                     DECLARE
+                        inserted_package origin_packages;
+                        channel_id bigint;
+                     BEGIN
+                         INSERT INTO origin_packages (origin_id, owner_id, name, ident, checksum, manifest, config, target, deps, tdeps, exposes, visibility, exports, licenses, description)
+                                VALUES (op_origin_id, op_owner_id, op_name, op_ident, op_checksum, op_manifest, op_config, op_target, op_deps, op_tdeps, op_exposes, op_visibility, op_exports, op_licenses, op_description)
+                                RETURNING * into inserted_package;
+
+                         SELECT id FROM origin_channels WHERE origin_id = op_origin_id AND name = 'unstable' INTO channel_id;
+                         PERFORM promote_origin_package_v1(channel_id, inserted_package.id);
+
+                         RETURN NEXT inserted_package;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#)?;
+    // The releases the depot's retention may delete. Releases in `stable`, the latest releases of
+    // a package for a target in each of its channels and releases of pending promotion requests
+    // are kept, as are releases which any package of any origin depends on, so a retained
+    // release can always be installed.
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_package_retention_candidates_v1 (
+                    op_origin text,
+                    op_max_age_days integer,
+                    op_keep_releases integer
+                 ) RETURNS SETOF origin_packages AS $$
+                    DECLARE
+                        shard text;
+                    BEGIN
+                        CREATE TEMPORARY TABLE retention_candidates AS
+                            WITH ranked AS (
+                                SELECT ocp.package_id, oc.name AS channel,
+                                       row_number() OVER (PARTITION BY ocp.channel_id, op.name, op.target ORDER BY op.created_at DESC) AS rank
+                                FROM origins o
+                                INNER JOIN origin_channels oc ON o.id = oc.origin_id
+                                INNER JOIN origin_channel_packages ocp ON oc.id = ocp.channel_id
+                                INNER JOIN origin_packages op ON op.id = ocp.package_id
+                                WHERE o.name = op_origin
+                            )
+                            SELECT op.* FROM origins o INNER JOIN origin_packages op ON o.id = op.origin_id
+                            WHERE o.name = op_origin
+                            AND op.created_at < now() - make_interval(days => op_max_age_days)
+                            AND NOT EXISTS (SELECT 1 FROM ranked r WHERE r.package_id = op.id
+                                            AND (r.channel = 'stable' OR r.rank <= op_keep_releases))
+                            AND NOT EXISTS (SELECT 1 FROM origin_promotion_requests opr
+                                            WHERE opr.origin_id = o.id AND opr.state = 'pending'
+                                            AND op.ident = ANY(opr.idents));
+                        FOR shard IN SELECT nspname FROM pg_namespace WHERE nspname LIKE 'shard\_%' LOOP
+                            EXECUTE format('DELETE FROM retention_candidates rc WHERE EXISTS (SELECT 1 FROM %I.origin_packages op WHERE op.id <> rc.id AND rc.ident = ANY(STRING_TO_ARRAY(op.tdeps, '':'')))', shard);
+                        END LOOP;
+                        RETURN QUERY SELECT * FROM retention_candidates ORDER BY ident;
+                        DROP TABLE retention_candidates;
+                        RETURN;
+                    END
+                    $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION delete_origin_package_v1 (
+                    op_ident text
+                 ) RETURNS SETOF origin_packages AS $$
+                    DECLARE
+                        pkg_id bigint;
+                    BEGIN
+                        SELECT op.id FROM origin_packages op WHERE op.ident = op_ident
+                        AND NOT EXISTS (SELECT 1 FROM origin_channel_packages ocp
+                                        INNER JOIN origin_channels oc ON oc.id = ocp.channel_id
+                                        WHERE ocp.package_id = op.id AND oc.name = 'stable')
+                        INTO pkg_id;
+                        IF pkg_id IS NULL THEN
+                            RETURN;
+                        END IF;
+                        DELETE FROM origin_channel_packages WHERE package_id = pkg_id;
+                        RETURN QUERY DELETE FROM origin_packages WHERE id = pkg_id RETURNING *;
+                        RETURN;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
     Ok(())
 }
@@ -600,5 +600,248 @@ pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
             END
         $$ LANGUAGE plpgsql VOLATILE"#,
     )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_projects ADD COLUMN IF NOT EXISTS build_timeout integer;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_project_v5 (
+                        project_origin_name text,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_vcs_installation_id bigint,
+                        project_visibility text,
+                        project_build_timeout integer
+                 ) RETURNS SETOF origin_projects AS $$
+                     BEGIN
+                         RETURN QUERY INSERT INTO origin_projects (origin_id,
+                                                      origin_name,
+                                                      package_name,
+                                                      name,
+                                                      plan_path,
+                                                      owner_id,
+                                                      vcs_type,
+                                                      vcs_data,
+                                                      vcs_installation_id,
+                                                      visibility,
+                                                      build_timeout)
+                                VALUES (
+                                    (SELECT id FROM origins where name = project_origin_name),
+                                    project_origin_name,
+                                    project_package_name,
+                                    project_origin_name || '/' || project_package_name,
+                                    project_plan_path,
+                                    project_owner_id,
+                                    project_vcs_type,
+                                    project_vcs_data,
+                                    project_vcs_installation_id,
+                                    project_visibility,
+                                    project_build_timeout)
+                                RETURNING *;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION update_origin_project_v4 (
+                        project_id bigint,
+                        project_origin_id bigint,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_vcs_installation_id bigint,
+                        project_visibility text,
+                        project_build_timeout integer
+                 ) RETURNS void AS $$
+                     BEGIN
+                        UPDATE origin_projects SET
+                            package_name = project_package_name,
+                            name = (SELECT name FROM origins WHERE id = project_origin_id) || '/' || project_package_name,
+                            plan_path = project_plan_path,
+                            vcs_type = project_vcs_type,
+                            vcs_data = project_vcs_data,
+                            owner_id = project_owner_id,
+                            updated_at = now(),
+                            vcs_installation_id = project_vcs_installation_id,
+                            visibility = project_visibility,
+                            build_timeout = project_build_timeout
+                            WHERE id = project_id;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_projects ADD COLUMN IF NOT EXISTS worker_labels text[] DEFAULT '{}' NOT NULL;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_project_v6 (
+                        project_origin_name text,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_vcs_installation_id bigint,
+                        project_visibility text,
+                        project_build_timeout integer,
+                        project_worker_labels text[]
+                 ) RETURNS SETOF origin_projects AS $$
+                     BEGIN
+                         RETURN QUERY INSERT INTO origin_projects (origin_id,
+                                                      origin_name,
+                                                      package_name,
+                                                      name,
+                                                      plan_path,
+                                                      owner_id,
+                                                      vcs_type,
+                                                      vcs_data,
+                                                      vcs_installation_id,
+                                                      visibility,
+                                                      build_timeout,
+                                                      worker_labels)
+                                VALUES (
+                                    (SELECT id FROM origins where name = project_origin_name),
+                                    project_origin_name,
+                                    project_package_name,
+                                    project_origin_name || '/' || project_package_name,
+                                    project_plan_path,
+                                    project_owner_id,
+                                    project_vcs_type,
+                                    project_vcs_data,
+                                    project_vcs_installation_id,
+                                    project_visibility,
+                                    project_build_timeout,
+                                    project_worker_labels)
+                                RETURNING *;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION update_origin_project_v5 (
+                        project_id bigint,
+                        project_origin_id bigint,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_vcs_installation_id bigint,
+                        project_visibility text,
+                        project_build_timeout integer,
+                        project_worker_labels text[]
+                 ) RETURNS void AS $$
+                     BEGIN
+                        UPDATE origin_projects SET
+                            package_name = project_package_name,
+                            name = (SELECT name FROM origins WHERE id = project_origin_id) || '/' || project_package_name,
+                            plan_path = project_plan_path,
+                            vcs_type = project_vcs_type,
+                            vcs_data = project_vcs_data,
+                            owner_id = project_owner_id,
+                            updated_at = now(),
+                            vcs_installation_id = project_vcs_installation_id,
+                            visibility = project_visibility,
+                            build_timeout = project_build_timeout,
+                            worker_labels = project_worker_labels
+                            WHERE id = project_id;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE IF EXISTS origin_projects ADD COLUMN IF NOT EXISTS verify_reproducible bool DEFAULT false NOT NULL;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_project_v7 (
+                        project_origin_name text,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_vcs_installation_id bigint,
+                        project_visibility text,
+                        project_build_timeout integer,
+                        project_worker_labels text[],
+                        project_verify_reproducible bool
+                 ) RETURNS SETOF origin_projects AS $$
+                     BEGIN
+                         RETURN QUERY INSERT INTO origin_projects (origin_id,
+                                                      origin_name,
+                                                      package_name,
+                                                      name,
+                                                      plan_path,
+                                                      owner_id,
+                                                      vcs_type,
+                                                      vcs_data,
+                                                      vcs_installation_id,
+                                                      visibility,
+                                                      build_timeout,
+                                                      worker_labels,
+                                                      verify_reproducible)
+                                VALUES (
+                                    (SELECT id FROM origins where name = project_origin_name),
+                                    project_origin_name,
+                                    project_package_name,
+                                    project_origin_name || '/' || project_package_name,
+                                    project_plan_path,
+                                    project_owner_id,
+                                    project_vcs_type,
+                                    project_vcs_data,
+                                    project_vcs_installation_id,
+                                    project_visibility,
+                                    project_build_timeout,
+                                    project_worker_labels,
+                                    project_verify_reproducible)
+                                RETURNING *;
+                         RETURN;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION update_origin_project_v6 (
+                        project_id bigint,
+                        project_origin_id bigint,
+                        project_package_name text,
+                        project_plan_path text,
+                        project_vcs_type text,
+                        project_vcs_data text,
+                        project_owner_id bigint,
+                        project_vcs_installation_id bigint,
+                        project_visibility text,
+                        project_build_timeout integer,
+                        project_worker_labels text[],
+                        project_verify_reproducible bool
+                 ) RETURNS void AS $$
+                     BEGIN
+                        UPDATE origin_projects SET
+                            package_name = project_package_name,
+                            name = (SELECT name FROM origins WHERE id = project_origin_id) || '/' || project_package_name,
+                            plan_path = project_plan_path,
+                            vcs_type = project_vcs_type,
+                            vcs_data = project_vcs_data,
+                            owner_id = project_owner_id,
+                            updated_at = now(),
+                            vcs_installation_id = project_vcs_installation_id,
+                            visibility = project_visibility,
+                            build_timeout = project_build_timeout,
+                            worker_labels = project_worker_labels,
+                            verify_reproducible = project_verify_reproducible
+                            WHERE id = project_id;
+                     END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
     Ok(())
 }
@@ -0,0 +1,158 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS origin_promotion_request_id_seq;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_promotion_requests (
+                        id bigint PRIMARY KEY DEFAULT next_id_v1('origin_promotion_request_id_seq'),
+                        origin_id bigint REFERENCES origins(id) ON DELETE CASCADE,
+                        origin text NOT NULL,
+                        channel text NOT NULL,
+                        idents text[] NOT NULL DEFAULT '{}',
+                        requester_id bigint NOT NULL,
+                        requester_name text NOT NULL,
+                        state text NOT NULL DEFAULT 'pending',
+                        created_at timestamptz DEFAULT now(),
+                        updated_at timestamptz DEFAULT now()
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_promotion_request_events (
+                        id bigserial PRIMARY KEY,
+                        request_id bigint REFERENCES origin_promotion_requests(id) ON DELETE CASCADE,
+                        state text NOT NULL,
+                        account_id bigint NOT NULL,
+                        account_name text NOT NULL,
+                        comment text NOT NULL DEFAULT '',
+                        created_at timestamptz DEFAULT now()
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_origin_promotion_request_v1 (
+                        in_origin text,
+                        in_channel text,
+                        in_idents text[],
+                        in_requester_id bigint,
+                        in_requester_name text,
+                        in_comment text
+                 ) RETURNS SETOF origin_promotion_requests AS $$
+                    DECLARE
+                        r origin_promotion_requests%rowtype;
+                    BEGIN
+                        INSERT INTO origin_promotion_requests(
+                                      origin_id,
+                                      origin,
+                                      channel,
+                                      idents,
+                                      requester_id,
+                                      requester_name)
+                        VALUES (
+                            (SELECT id FROM origins WHERE name = in_origin),
+                            in_origin,
+                            in_channel,
+                            in_idents,
+                            in_requester_id,
+                            in_requester_name)
+                        RETURNING * INTO r;
+
+                        INSERT INTO origin_promotion_request_events(
+                                      request_id,
+                                      state,
+                                      account_id,
+                                      account_name,
+                                      comment)
+                        VALUES (r.id, r.state, in_requester_id, in_requester_name, in_comment);
+
+                        RETURN NEXT r;
+                        RETURN;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_promotion_request_v1 (
+                        in_origin text,
+                        in_id bigint
+                 ) RETURNS SETOF origin_promotion_requests AS $$
+                        SELECT * FROM origin_promotion_requests
+                        WHERE origin = in_origin AND id = in_id
+                    $$ LANGUAGE SQL STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_promotion_requests_v1 (
+                        in_origin text,
+                        in_state text
+                 ) RETURNS SETOF origin_promotion_requests AS $$
+                        SELECT * FROM origin_promotion_requests
+                        WHERE origin = in_origin AND (in_state IS NULL OR state = in_state)
+                        ORDER BY created_at DESC
+                    $$ LANGUAGE SQL STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_promotion_request_events_v1 (
+                        in_request_id bigint
+                 ) RETURNS SETOF origin_promotion_request_events AS $$
+                        SELECT * FROM origin_promotion_request_events
+                        WHERE request_id = in_request_id
+                        ORDER BY id
+                    $$ LANGUAGE SQL STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION transition_origin_promotion_request_v1 (
+                        in_origin text,
+                        in_id bigint,
+                        in_from_state text,
+                        in_to_state text,
+                        in_account_id bigint,
+                        in_account_name text,
+                        in_comment text
+                 ) RETURNS SETOF origin_promotion_requests AS $$
+                    DECLARE
+                        r origin_promotion_requests%rowtype;
+                    BEGIN
+                        UPDATE origin_promotion_requests
+                        SET state = in_to_state, updated_at = now()
+                        WHERE origin = in_origin AND id = in_id AND state = in_from_state
+                        RETURNING * INTO r;
+
+                        IF FOUND THEN
+                            INSERT INTO origin_promotion_request_events(
+                                          request_id,
+                                          state,
+                                          account_id,
+                                          account_name,
+                                          comment)
+                            VALUES (r.id, r.state, in_account_id, in_account_name, in_comment);
+                            RETURN NEXT r;
+                        END IF;
+                        RETURN;
+                    END
+                 $$ LANGUAGE plpgsql VOLATILE"#,
+    )?;
+    Ok(())
+}
@@ -108,5 +108,22 @@ pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
                         ADD CONSTRAINT origin_public_keys_full_name_key
                         UNIQUE (full_name)"#,
     )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE origin_public_keys ADD COLUMN IF NOT EXISTS deprecated bool DEFAULT false"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION deprecate_origin_public_keys_v1 (
+                    opk_origin_id bigint,
+                    opk_revision text
+                 ) RETURNS SETOF origin_public_keys AS $$
+                        UPDATE origin_public_keys SET deprecated = true, updated_at = now()
+                        WHERE origin_id = opk_origin_id
+                          AND revision < opk_revision
+                          AND deprecated = false
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
     Ok(())
 }
@@ -0,0 +1,75 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS origin_secret_id_seq;"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE TABLE IF NOT EXISTS origin_secrets (
+                        id bigint PRIMARY KEY DEFAULT next_id_v1('origin_secret_id_seq'),
+                        origin_id bigint REFERENCES origins(id) ON DELETE CASCADE,
+                        origin text NOT NULL,
+                        name text NOT NULL,
+                        value text NOT NULL,
+                        created_at timestamptz DEFAULT now(),
+                        updated_at timestamptz DEFAULT now(),
+                        UNIQUE (origin, name)
+                        )"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION upsert_origin_secret_v1 (
+                        in_origin text,
+                        in_name text,
+                        in_value text
+                 ) RETURNS SETOF origin_secrets AS $$
+                        INSERT INTO origin_secrets (origin_id, origin, name, value)
+                        VALUES ((SELECT id FROM origins WHERE name = in_origin),
+                            in_origin,
+                            in_name,
+                            in_value)
+                        ON CONFLICT (origin, name)
+                        DO UPDATE SET value = excluded.value, updated_at = now()
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION get_origin_secrets_v1 (
+                        in_origin text
+                 ) RETURNS SETOF origin_secrets AS $$
+                        SELECT * FROM origin_secrets
+                        WHERE origin = in_origin
+                        ORDER BY name
+                    $$ LANGUAGE SQL STABLE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION delete_origin_secret_v1 (
+                        in_origin text,
+                        in_name text
+                 ) RETURNS void AS $$
+                        DELETE FROM origin_secrets
+                        WHERE origin = in_origin AND name = in_name
+                    $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    Ok(())
+}
@@ -178,5 +178,35 @@ pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
                     END;
                  $$ LANGUAGE plpgsql STABLE"#,
     )?;
+    migrator.migrate(
+        "originsrv",
+        r#"ALTER TABLE origin_members ADD COLUMN IF NOT EXISTS approver bool DEFAULT false"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION update_origin_member_approver_v1 (
+                    om_origin_id bigint,
+                    om_account_name text,
+                    om_approver bool
+                 ) RETURNS SETOF origin_members AS $$
+                        UPDATE origin_members SET approver = om_approver, updated_at = now()
+                        WHERE origin_id = om_origin_id AND account_name = om_account_name
+                        RETURNING *
+                 $$ LANGUAGE SQL VOLATILE"#,
+    )?;
+    migrator.migrate(
+        "originsrv",
+        r#"CREATE OR REPLACE FUNCTION check_origin_approver_v1 (
+                    om_origin_name text,
+                    om_account_id bigint
+                 ) RETURNS SETOF boolean AS $$
+                        SELECT true FROM origins
+                        WHERE name = om_origin_name AND owner_id = om_account_id
+                        UNION
+                        SELECT true FROM origin_members
+                        WHERE origin_name = om_origin_name AND account_id = om_account_id
+                              AND approver
+                 $$ LANGUAGE SQL STABLE"#,
+    )?;
     Ok(())
 }
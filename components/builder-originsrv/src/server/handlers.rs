@@ -95,6 +95,27 @@ pub fn origin_package_update(
     Ok(())
 }
 
+pub fn origin_check_approver(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::CheckOriginApproverRequest>()?;
+    match state.datastore.check_origin_approver(&msg) {
+        Ok(is_approver) => {
+            let mut reply = proto::CheckOriginApproverResponse::new();
+            reply.set_is_approver(is_approver);
+            conn.route_reply(req, &reply)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-check-approver:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_create(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -241,6 +262,234 @@ pub fn origin_integration_delete(
     Ok(())
 }
 
+pub fn origin_notification_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginNotificationCreate>()?;
+    match state.datastore.create_origin_notification(&msg) {
+        Ok(ref notification) => conn.route_reply(req, notification)?,
+        Err(SrvError::OriginNotificationCreate(PostgresError::Db(ref db)))
+            if db.code == UniqueViolation => {
+            let err = NetError::new(ErrCode::ENTITY_CONFLICT, "vt:origin-notification-create:1");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-notification-create:2");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_notification_delete(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginNotificationDelete>()?;
+    match state.datastore.delete_origin_notification(&msg) {
+        Ok(()) => conn.route_reply(req, &NetOk::new())?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-notification-delete:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_notification_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginNotificationListRequest>()?;
+    match state.datastore.get_origin_notifications(&msg) {
+        Ok(ref notifications) => conn.route_reply(req, notifications)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-notification-list:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_secret_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginSecretCreate>()?;
+    match state.datastore.upsert_origin_secret(&msg) {
+        Ok(ref secret) => conn.route_reply(req, secret)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-secret-create:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_secret_delete(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginSecretDelete>()?;
+    match state.datastore.delete_origin_secret(&msg) {
+        Ok(()) => conn.route_reply(req, &NetOk::new())?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-secret-delete:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_secret_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginSecretListRequest>()?;
+    match state.datastore.get_origin_secrets(&msg) {
+        Ok(ref secrets) => conn.route_reply(req, secrets)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-secret-list:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_encryption_key_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginEncryptionKeyCreate>()?;
+    match state.datastore.create_origin_encryption_key(&msg) {
+        Ok(ref key) => conn.route_reply(req, key)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-encryption-key-create:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_encryption_key_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginEncryptionKeyGet>()?;
+    match state.datastore.get_origin_encryption_key(&msg) {
+        Ok(Some(ref key)) => conn.route_reply(req, key)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-encryption-key-get:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-encryption-key-get:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_promotion_request_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPromotionRequestCreate>()?;
+    match state.datastore.create_origin_promotion_request(&msg) {
+        Ok(ref promotion) => conn.route_reply(req, promotion)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-promotion-request-create:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_promotion_request_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPromotionRequestGet>()?;
+    match state.datastore.get_origin_promotion_request(&msg) {
+        Ok(Some(ref promotion)) => conn.route_reply(req, promotion)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-promotion-request-get:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-promotion-request-get:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_promotion_request_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPromotionRequestListRequest>()?;
+    match state.datastore.get_origin_promotion_requests(&msg) {
+        Ok(ref promotions) => conn.route_reply(req, promotions)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-promotion-request-list:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_promotion_request_transition(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPromotionRequestTransition>()?;
+    match state.datastore.transition_origin_promotion_request(&msg) {
+        Ok(Some(ref promotion)) => conn.route_reply(req, promotion)?,
+        // Someone else got to the request first, or it doesn't exist
+        Ok(None) => {
+            let err = NetError::new(
+                ErrCode::ENTITY_CONFLICT,
+                "vt:origin-promotion-request-transition:0",
+            );
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(
+                ErrCode::DATA_STORE,
+                "vt:origin-promotion-request-transition:1",
+            );
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_invitation_accept(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -318,6 +567,27 @@ pub fn origin_invitation_create(
     Ok(())
 }
 
+pub fn origin_invitation_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginInvitationGet>()?;
+    match state.datastore.get_origin_invitation(&msg) {
+        Ok(Some(ref invitation)) => conn.route_reply(req, invitation)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-invitation-get:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-invitation-get:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_invitation_list(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -352,6 +622,27 @@ pub fn origin_member_list(
     Ok(())
 }
 
+pub fn origin_member_approver_update(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginMemberApproverUpdate>()?;
+    match state.datastore.update_origin_member_approver(&msg) {
+        Ok(true) => conn.route_reply(req, &NetOk::new())?,
+        Ok(false) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-member-approver:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-member-approver:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_secret_key_create(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -479,6 +770,23 @@ pub fn origin_public_key_list(
     Ok(())
 }
 
+pub fn origin_public_key_deprecate(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPublicKeyDeprecate>()?;
+    match state.datastore.deprecate_origin_public_keys(&msg) {
+        Ok(ref opklr) => conn.route_reply(req, opklr)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-public-key-deprecate:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn project_create(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -772,6 +1080,99 @@ pub fn origin_package_get(
     Ok(())
 }
 
+pub fn origin_package_yank(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPackageYank>()?;
+    match state.datastore.yank_origin_package(&msg) {
+        Ok(Some(ref package)) => conn.route_reply(req, package)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-package-yank:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-package-yank:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_retention_candidates_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPackageRetentionCandidatesGet>()?;
+    match state.datastore.get_origin_package_retention_candidates(&msg) {
+        Ok(ref candidates) => conn.route_reply(req, candidates)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-package-retention-get:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_delete(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPackageDelete>()?;
+    match state.datastore.delete_origin_package(&msg) {
+        Ok(Some(ref package)) => conn.route_reply(req, package)?,
+        Ok(None) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "vt:origin-package-delete:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-package-delete:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_download_record(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPackageDownloadRecord>()?;
+    match state.datastore.record_origin_package_download(&msg) {
+        Ok(()) => conn.route_reply(req, &NetOk::new())?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-package-download-record:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn origin_package_download_stats(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::OriginPackageDownloadStatsGet>()?;
+    match state.datastore.get_origin_package_download_stats(&msg) {
+        Ok(ref stats) => conn.route_reply(req, stats)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "vt:origin-package-download-stats:1");
+            error!("{}, {}", err, e);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn origin_channel_package_get(
     req: &mut Message,
     conn: &mut RouteConn,
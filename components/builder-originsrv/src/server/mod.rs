@@ -28,6 +28,8 @@ lazy_static! {
             handlers::origin_check_access);
         map.register(CheckOriginOwnerRequest::descriptor_static(None),
             handlers::origin_check_owner);
+        map.register(CheckOriginApproverRequest::descriptor_static(None),
+            handlers::origin_check_approver);
         map.register(OriginCreate::descriptor_static(None), handlers::origin_create);
         map.register(OriginUpdate::descriptor_static(None), handlers::origin_update);
         map.register(OriginGet::descriptor_static(None), handlers::origin_get);
@@ -43,6 +45,8 @@ lazy_static! {
             handlers::origin_invitation_accept);
         map.register(OriginInvitationCreate::descriptor_static(None),
             handlers::origin_invitation_create);
+        map.register(OriginInvitationGet::descriptor_static(None),
+            handlers::origin_invitation_get);
         map.register(OriginInvitationIgnoreRequest::descriptor_static(None),
             handlers::origin_invitation_ignore);
         map.register(OriginInvitationListRequest::descriptor_static(None),
@@ -51,8 +55,34 @@ lazy_static! {
             handlers::origin_invitation_rescind);
         map.register(OriginMemberListRequest::descriptor_static(None),
             handlers::origin_member_list);
+        map.register(OriginMemberApproverUpdate::descriptor_static(None),
+            handlers::origin_member_approver_update);
+        map.register(OriginNotificationCreate::descriptor_static(None),
+            handlers::origin_notification_create);
+        map.register(OriginNotificationDelete::descriptor_static(None),
+            handlers::origin_notification_delete);
+        map.register(OriginNotificationListRequest::descriptor_static(None),
+            handlers::origin_notification_list);
         map.register(OriginPackageUpdate::descriptor_static(None),
             handlers::origin_package_update);
+        map.register(OriginPromotionRequestCreate::descriptor_static(None),
+            handlers::origin_promotion_request_create);
+        map.register(OriginPromotionRequestGet::descriptor_static(None),
+            handlers::origin_promotion_request_get);
+        map.register(OriginPromotionRequestListRequest::descriptor_static(None),
+            handlers::origin_promotion_request_list);
+        map.register(OriginPromotionRequestTransition::descriptor_static(None),
+            handlers::origin_promotion_request_transition);
+        map.register(OriginSecretCreate::descriptor_static(None),
+            handlers::origin_secret_create);
+        map.register(OriginSecretDelete::descriptor_static(None),
+            handlers::origin_secret_delete);
+        map.register(OriginSecretListRequest::descriptor_static(None),
+            handlers::origin_secret_list);
+        map.register(OriginEncryptionKeyCreate::descriptor_static(None),
+            handlers::origin_encryption_key_create);
+        map.register(OriginEncryptionKeyGet::descriptor_static(None),
+            handlers::origin_encryption_key_get);
         map.register(OriginSecretKeyCreate::descriptor_static(None),
             handlers::origin_secret_key_create);
         map.register(OriginSecretKeyGet::descriptor_static(None),
@@ -65,6 +95,8 @@ lazy_static! {
             handlers::origin_public_key_latest_get);
         map.register(OriginPublicKeyListRequest::descriptor_static(None),
             handlers::origin_public_key_list);
+        map.register(OriginPublicKeyDeprecate::descriptor_static(None),
+            handlers::origin_public_key_deprecate);
         map.register(OriginProjectCreate::descriptor_static(None), handlers::project_create);
         map.register(OriginProjectDelete::descriptor_static(None), handlers::project_delete);
         map.register(OriginProjectGet::descriptor_static(None), handlers::project_get);
@@ -79,6 +111,10 @@ lazy_static! {
         map.register(OriginProjectIntegrationRequest::descriptor_static(None),
             handlers::origin_project_integration_request);
         map.register(OriginPackageCreate::descriptor_static(None), handlers::origin_package_create);
+        map.register(OriginPackageDownloadRecord::descriptor_static(None),
+            handlers::origin_package_download_record);
+        map.register(OriginPackageDownloadStatsGet::descriptor_static(None),
+            handlers::origin_package_download_stats);
         map.register(OriginPackageGet::descriptor_static(None), handlers::origin_package_get);
         map.register(OriginPackageLatestGet::descriptor_static(None),
             handlers::origin_package_latest_get);
@@ -97,6 +133,10 @@ lazy_static! {
             handlers::origin_package_group_demote);
         map.register(OriginPackagePromote::descriptor_static(None),
             handlers::origin_package_promote);
+        map.register(OriginPackageYank::descriptor_static(None), handlers::origin_package_yank);
+        map.register(OriginPackageRetentionCandidatesGet::descriptor_static(None),
+            handlers::origin_package_retention_candidates_get);
+        map.register(OriginPackageDelete::descriptor_static(None), handlers::origin_package_delete);
         map.register(OriginPackageUniqueListRequest::descriptor_static(None),
             handlers::origin_package_unique_list);
         map.register(OriginPackageSearchRequest::descriptor_static(None),
@@ -164,6 +204,15 @@ impl Dispatcher for OriginSrv {
     fn dispatch_table() -> &'static DispatchTable<Self> {
         &DISPATCH_TABLE
     }
+
+    fn health_checks(state: &<Self::State as AppState>::InitState) -> HealthChecks {
+        vec![
+            (
+                "datastore",
+                Box::new(state.datastore.pool.clone()) as Box<HealthCheck>,
+            ),
+        ]
+    }
 }
 
 pub fn run(config: Config) -> AppResult<(), SrvError> {
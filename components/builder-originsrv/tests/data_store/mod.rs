@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use protobuf;
-use protocol::originsrv;
+use protocol::{originsrv, timestamp};
 use originsrv::data_store::DataStore;
 
 use std::str::FromStr;
@@ -194,6 +194,66 @@ fn get_origin_secret_key() {
     assert_eq!(neurosis_key.get_owner_id(), oskc.get_owner_id());
 }
 
+#[test]
+fn get_origin_encryption_key() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let neurosis = ds.get_origin_by_name("neurosis")
+        .expect("Could not retrieve origin")
+        .expect("Origin does not exist");
+
+    let mut oek_get = originsrv::OriginEncryptionKeyGet::new();
+    oek_get.set_origin(String::from("neurosis"));
+    assert!(
+        ds.get_origin_encryption_key(&oek_get)
+            .expect("Failed to get origin encryption key from database")
+            .is_none()
+    );
+
+    let mut key = originsrv::OriginEncryptionKey::new();
+    key.set_origin_id(neurosis.get_id());
+    key.set_origin(String::from("neurosis"));
+    key.set_revision(String::from("20160612031944"));
+    key.set_public_key(String::from("public"));
+    key.set_secret_key(String::from("secret"));
+    let mut oekc = originsrv::OriginEncryptionKeyCreate::new();
+    oekc.set_key(key.clone());
+    ds.create_origin_encryption_key(&oekc).expect(
+        "Failed to create origin encryption key",
+    );
+    key.set_revision(String::from("20160612031945"));
+    oekc.set_key(key);
+    ds.create_origin_encryption_key(&oekc).expect(
+        "Failed to create origin encryption key",
+    );
+
+    let neurosis_key = ds.get_origin_encryption_key(&oek_get)
+        .expect("Failed to get origin encryption key from database")
+        .expect("No origin encryption key found in database");
+    assert_eq!(neurosis_key.get_origin(), "neurosis");
+    assert_eq!(neurosis_key.get_revision(), "20160612031945");
+    assert_eq!(neurosis_key.get_origin_id(), neurosis.get_id());
+    assert_eq!(neurosis_key.get_public_key(), "public");
+    assert_eq!(neurosis_key.get_secret_key(), "secret");
+
+    oek_get.set_revision(String::from("20160612031944"));
+    let old_key = ds.get_origin_encryption_key(&oek_get)
+        .expect("Failed to get origin encryption key from database")
+        .expect("No origin encryption key found in database");
+    assert_eq!(old_key.get_revision(), "20160612031944");
+    oek_get.set_revision(String::from("20160612031946"));
+    assert!(
+        ds.get_origin_encryption_key(&oek_get)
+            .expect("Failed to get origin encryption key from database")
+            .is_none()
+    );
+}
+
 #[test]
 fn create_origin_public_key() {
     let ds = datastore_test!(DataStore);
@@ -237,6 +297,64 @@ fn create_origin_public_key() {
     assert_eq!(key.get_body(), oskc.get_body());
 }
 
+#[test]
+fn deprecate_origin_public_keys() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let neurosis = ds.get_origin_by_name("neurosis")
+        .expect("Could not retrieve origin")
+        .expect("Origin does not exist");
+
+    for revision in &["20160612031944", "20160612031945", "20160612031946"] {
+        let mut opkc = originsrv::OriginPublicKeyCreate::new();
+        opkc.set_name(String::from("neurosis"));
+        opkc.set_revision(revision.to_string());
+        opkc.set_origin_id(neurosis.get_id());
+        opkc.set_owner_id(1);
+        opkc.set_body(String::from("very_public").into_bytes());
+        ds.create_origin_public_key(&opkc).expect(
+            "Failed to create origin public key",
+        );
+    }
+
+    let mut opkd = originsrv::OriginPublicKeyDeprecate::new();
+    opkd.set_origin_id(neurosis.get_id());
+    opkd.set_revision(String::from("20160612031946"));
+    let deprecated = ds.deprecate_origin_public_keys(&opkd).expect(
+        "Failed to deprecate origin public keys",
+    );
+    assert_eq!(deprecated.get_keys().len(), 2);
+
+    // Keys which are already deprecated are left alone
+    let deprecated = ds.deprecate_origin_public_keys(&opkd).expect(
+        "Failed to deprecate origin public keys",
+    );
+    assert_eq!(deprecated.get_keys().len(), 0);
+
+    let mut opklr = originsrv::OriginPublicKeyListRequest::new();
+    opklr.set_origin_id(neurosis.get_id());
+    let keys = ds.list_origin_public_keys_for_origin(&opklr).expect(
+        "Could not list the keys",
+    );
+    let states: Vec<(&str, bool)> = keys.get_keys()
+        .iter()
+        .map(|key| (key.get_revision(), key.get_deprecated()))
+        .collect();
+    assert_eq!(
+        states,
+        vec![
+            ("20160612031946", false),
+            ("20160612031945", true),
+            ("20160612031944", true),
+        ]
+    );
+}
+
 #[test]
 fn create_origin_public_key_handles_unique_constraint_violations_correctly() {
     let ds = datastore_test!(DataStore);
@@ -464,6 +582,14 @@ fn list_origin_invitations_for_origin() {
     assert_eq!(maynard.get_account_id(), 3);
     let noel = oi_list.get_invitations().iter().nth(2).unwrap();
     assert_eq!(noel.get_account_id(), 2);
+
+    let mut oig = originsrv::OriginInvitationGet::new();
+    oig.set_invitation_id(noel.get_id());
+    let invitation = ds.get_origin_invitation(&oig)
+        .expect("Could not get origin invitation from database")
+        .expect("Origin invitation does not exist");
+    assert_eq!(invitation.get_account_name(), "noel_gallagher");
+    assert_eq!(invitation.get_origin_name(), "neurosis");
 }
 
 #[test]
@@ -905,6 +1031,11 @@ fn update_origin_project() {
     project.set_vcs_data(String::from("svn://github.com/habitat-sh/core-plans"));
     op.set_vcs_installation_id(2);
     project.set_owner_id(2);
+    project.set_build_timeout(180);
+    project.set_worker_labels(protobuf::RepeatedField::from_vec(
+        vec![String::from("big-memory")],
+    ));
+    project.set_verify_reproducible(true);
 
     let mut opu = originsrv::OriginProjectUpdate::new();
     let updated_project = project.clone();
@@ -967,6 +1098,20 @@ fn update_origin_project() {
         sepultura.get_vcs_installation_id(),
         "Should have the same vcs installation id"
     );
+    assert_eq!(
+        sepultura.get_build_timeout(),
+        180,
+        "Should have the updated build timeout"
+    );
+    assert_eq!(
+        sepultura.get_worker_labels(),
+        &[String::from("big-memory")],
+        "Should have the updated worker labels"
+    );
+    assert!(
+        sepultura.get_verify_reproducible(),
+        "Should have reproducibility verification enabled"
+    );
 }
 
 #[test]
@@ -1025,10 +1170,23 @@ fn create_origin_package() {
     package.set_config("config".to_string());
     package.set_target("x86_64-linux".to_string());
     package.set_exposes(vec![1, 2]);
-
-    ds.create_origin_package(&package).expect(
+    let mut export = originsrv::OriginPackageExport::new();
+    export.set_name("port".to_string());
+    export.set_key("server.port".to_string());
+    package.set_exports(protobuf::RepeatedField::from_vec(vec![export]));
+    package.set_licenses(protobuf::RepeatedField::from_vec(
+        vec!["MPL-2.0".to_string(), "Apache-2.0".to_string()],
+    ));
+    package.set_description("CA certificates".to_string());
+
+    let created = ds.create_origin_package(&package).expect(
         "Failed to create origin package",
     );
+    assert_eq!(created.get_exports().len(), 1);
+    assert_eq!(created.get_exports()[0].get_name(), "port");
+    assert_eq!(created.get_exports()[0].get_key(), "server.port");
+    assert_eq!(created.get_licenses(), &["MPL-2.0", "Apache-2.0"]);
+    assert_eq!(created.get_description(), "CA certificates");
 }
 
 #[test]
@@ -1187,6 +1345,196 @@ fn get_latest_package() {
     assert!(result3.is_none());
 }
 
+#[test]
+fn yank_origin_package() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut oscc = originsrv::OriginChannelCreate::new();
+    oscc.set_origin_id(origin.get_id());
+    oscc.set_origin_name(origin.get_name().to_string());
+    oscc.set_name(String::from("stable"));
+    oscc.set_owner_id(1);
+    let stable = ds.create_origin_channel(&oscc).expect(
+        "Failed to create origin channel",
+    );
+
+    let ident1 = originsrv::OriginPackageIdent::from_str("core/cacerts/2017.01.17/20170209064044")
+        .unwrap();
+    let ident2 = originsrv::OriginPackageIdent::from_str("core/cacerts/2017.01.18/20170209064044")
+        .unwrap();
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    package.set_exposes(vec![1, 2]);
+    for ident in vec![ident1.clone(), ident2.clone()] {
+        package.set_ident(ident.clone());
+        let created = ds.create_origin_package(&package).expect(
+            "Failed to create origin package",
+        );
+        let mut opp = originsrv::OriginPackagePromote::new();
+        opp.set_channel_id(stable.get_id());
+        opp.set_package_id(created.get_id());
+        opp.set_ident(ident);
+        ds.promote_origin_package(&opp).expect(
+            "Could not promote package",
+        );
+    }
+
+    let mut opy = originsrv::OriginPackageYank::new();
+    opy.set_ident(ident2.clone());
+    opy.set_yanked(true);
+    opy.set_reason("segfaults on start".to_string());
+    let yanked = ds.yank_origin_package(&opy)
+        .expect("Could not yank package")
+        .unwrap();
+    assert!(yanked.is_yanked());
+    assert_eq!(yanked.get_yank_reason(), "segfaults on start");
+
+    let mut search_ident = originsrv::OriginPackageIdent::new();
+    search_ident.set_origin("core".to_string());
+    search_ident.set_name("cacerts".to_string());
+    let mut package_get = originsrv::OriginPackageLatestGet::new();
+    package_get.set_ident(search_ident.clone());
+    package_get.set_target("x86_64-linux".to_string());
+    package_get.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    let latest = ds.get_origin_package_latest(&package_get).unwrap();
+    assert_eq!(latest.unwrap().to_string(), ident1.to_string());
+
+    let mut channel_get = originsrv::OriginChannelPackageLatestGet::new();
+    channel_get.set_name("stable".to_string());
+    channel_get.set_ident(search_ident.clone());
+    channel_get.set_target("x86_64-linux".to_string());
+    channel_get.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    let latest = ds.get_origin_channel_package_latest(&channel_get).unwrap();
+    assert_eq!(latest.unwrap().to_string(), ident1.to_string());
+
+    // Still there for those asking for it by its ident
+    let mut opg = originsrv::OriginPackageGet::new();
+    opg.set_ident(ident2.clone());
+    opg.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    let pkg = ds.get_origin_package(&opg).unwrap().unwrap();
+    assert_eq!(pkg.get_yanked_at(), yanked.get_yanked_at());
+
+    opy.set_yanked(false);
+    opy.clear_reason();
+    let restored = ds.yank_origin_package(&opy)
+        .expect("Could not restore package")
+        .unwrap();
+    assert!(!restored.is_yanked());
+    let latest = ds.get_origin_package_latest(&package_get).unwrap();
+    assert_eq!(latest.unwrap().to_string(), ident2.to_string());
+
+    opy.set_ident(
+        originsrv::OriginPackageIdent::from_str("core/cacerts/1.0.0/20170209064044").unwrap(),
+    );
+    assert!(ds.yank_origin_package(&opy).unwrap().is_none());
+}
+
+#[test]
+fn origin_package_retention() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut ocg = originsrv::OriginChannelGet::new();
+    ocg.set_origin_name(origin.get_name().to_string());
+    ocg.set_name(String::from("stable"));
+    let stable = ds.get_origin_channel(&ocg)
+        .expect("Could not get the channel from the database")
+        .unwrap();
+
+    let mut idents = Vec::new();
+    for release in vec!["20170209064041", "20170209064042", "20170209064043", "20170209064044"] {
+        let ident = format!("core/cacerts/2017.01.17/{}", release);
+        idents.push(originsrv::OriginPackageIdent::from_str(&ident).unwrap());
+    }
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    package.set_exposes(vec![1, 2]);
+    for ident in idents.iter() {
+        package.set_ident(ident.clone());
+        let created = ds.create_origin_package(&package).expect(
+            "Failed to create origin package",
+        );
+        if ident == &idents[1] {
+            let mut opp = originsrv::OriginPackagePromote::new();
+            opp.set_channel_id(stable.get_id());
+            opp.set_package_id(created.get_id());
+            opp.set_ident(ident.clone());
+            ds.promote_origin_package(&opp).expect(
+                "Could not promote package",
+            );
+        }
+    }
+
+    // Depends on the first release, which has to stay
+    let mut tdeps = protobuf::RepeatedField::new();
+    tdeps.push(idents[0].clone());
+    package.set_ident(
+        originsrv::OriginPackageIdent::from_str("core/openssl/1.0.2l/20170209064045").unwrap(),
+    );
+    package.set_tdeps(tdeps);
+    ds.create_origin_package(&package).expect(
+        "Failed to create origin package",
+    );
+
+    let mut oprcg = originsrv::OriginPackageRetentionCandidatesGet::new();
+    oprcg.set_origin("core".to_string());
+    oprcg.set_max_age_days(1);
+    oprcg.set_keep_releases(1);
+    let candidates = ds.get_origin_package_retention_candidates(&oprcg).unwrap();
+    assert!(candidates.get_packages().is_empty());
+
+    oprcg.set_max_age_days(0);
+    let candidates = ds.get_origin_package_retention_candidates(&oprcg).unwrap();
+    assert_eq!(candidates.get_packages().len(), 1);
+    assert_eq!(
+        candidates.get_packages()[0].get_ident().to_string(),
+        idents[2].to_string()
+    );
+
+    oprcg.set_keep_releases(2);
+    let candidates = ds.get_origin_package_retention_candidates(&oprcg).unwrap();
+    assert!(candidates.get_packages().is_empty());
+
+    let mut opd = originsrv::OriginPackageDelete::new();
+    opd.set_ident(idents[2].clone());
+    let deleted = ds.delete_origin_package(&opd)
+        .expect("Could not delete package")
+        .unwrap();
+    assert_eq!(deleted.get_ident().to_string(), idents[2].to_string());
+    assert!(ds.delete_origin_package(&opd).unwrap().is_none());
+
+    // Nothing in stable is deleted
+    opd.set_ident(idents[1].clone());
+    assert!(ds.delete_origin_package(&opd).unwrap().is_none());
+    let mut opg = originsrv::OriginPackageGet::new();
+    opg.set_ident(idents[1].clone());
+    opg.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    assert!(ds.get_origin_package(&opg).unwrap().is_some());
+}
+
 #[test]
 fn list_origin_package_versions_for_origin() {
     let ds = datastore_test!(DataStore);
@@ -1396,6 +1744,151 @@ fn list_origin_package_for_origin() {
     assert_eq!(pkg3.to_string(), "core/cacerts");
 }
 
+#[test]
+fn list_origin_package_for_origin_by_target() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let aarch64_ident = originsrv::OriginPackageIdent::from_str("core/redis/3.2.4/20170514001355")
+        .unwrap();
+    let x86_64_ident = originsrv::OriginPackageIdent::from_str("core/redis/3.2.9/20170620211300")
+        .unwrap();
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_ident(aarch64_ident.clone());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("aarch64-linux".to_string());
+    package.set_exposes(vec![1, 2]);
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    package.set_ident(x86_64_ident.clone());
+    package.set_target("x86_64-linux".to_string());
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    let mut opl = originsrv::OriginPackageListRequest::new();
+    opl.set_ident(
+        originsrv::OriginPackageIdent::from_str("core/redis").unwrap(),
+    );
+    opl.set_start(0);
+    opl.set_stop(20);
+    opl.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    let result = ds.list_origin_package_for_origin(&opl).expect(
+        "Could not get the packages from the database",
+    );
+    assert_eq!(result.get_count(), 2);
+
+    opl.set_target("aarch64-linux".to_string());
+    let result = ds.list_origin_package_for_origin(&opl).expect(
+        "Could not get the packages from the database",
+    );
+    assert_eq!(result.get_count(), 1);
+    assert_eq!(
+        result.get_idents()[0].to_string(),
+        aarch64_ident.to_string()
+    );
+
+    opl.set_target("x86_64-windows".to_string());
+    let result = ds.list_origin_package_for_origin(&opl).expect(
+        "Could not get the packages from the database",
+    );
+    assert_eq!(result.get_count(), 0);
+
+    // The newer x86_64 release doesn't shadow the latest aarch64 one
+    let mut latest = originsrv::OriginPackageLatestGet::new();
+    latest.set_ident(
+        originsrv::OriginPackageIdent::from_str("core/redis").unwrap(),
+    );
+    latest.set_target("aarch64-linux".to_string());
+    latest.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    let result = ds.get_origin_package_latest(&latest)
+        .expect("Could not get the latest package")
+        .unwrap();
+    assert_eq!(result.to_string(), aarch64_ident.to_string());
+}
+
+#[test]
+fn list_origin_package_for_origin_snapshot() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let origin = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(origin.get_id());
+    package.set_ident(
+        originsrv::OriginPackageIdent::from_str("core/redis/3.2.4/20170514001355").unwrap(),
+    );
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    package.set_exposes(vec![1, 2]);
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+    let snapshot = timestamp::now();
+
+    // Packages uploaded while a listing is paged through don't show up in it
+    package.set_ident(
+        originsrv::OriginPackageIdent::from_str("core/redis/3.2.9/20170620211300").unwrap(),
+    );
+    ds.create_origin_package(&package.clone()).expect(
+        "Failed to create origin package",
+    );
+
+    let mut opl = originsrv::OriginPackageListRequest::new();
+    opl.set_ident(
+        originsrv::OriginPackageIdent::from_str("core/redis").unwrap(),
+    );
+    opl.set_start(0);
+    opl.set_stop(20);
+    opl.set_visibilities(vec![originsrv::OriginPackageVisibility::Public]);
+    let result = ds.list_origin_package_for_origin(&opl).expect(
+        "Could not get the packages from the database",
+    );
+    assert_eq!(result.get_count(), 2);
+
+    opl.set_created_before(snapshot.clone());
+    let result = ds.list_origin_package_for_origin(&opl).expect(
+        "Could not get the packages from the database",
+    );
+    assert_eq!(result.get_count(), 1);
+    assert_eq!(
+        result.get_idents()[0].to_string(),
+        "core/redis/3.2.4/20170514001355"
+    );
+
+    let mut ops = originsrv::OriginPackageSearchRequest::new();
+    ops.set_origin("core".to_string());
+    ops.set_query("red".to_string());
+    ops.set_start(0);
+    ops.set_stop(20);
+    ops.set_created_before(snapshot);
+    let result = ds.search_origin_package_for_origin(&ops).expect(
+        "Could not search the packages in the database",
+    );
+    assert_eq!(result.get_count(), 1);
+}
+
 #[test]
 fn list_origin_package_for_origin_unique() {
     let ds = datastore_test!(DataStore);
@@ -1711,6 +2204,189 @@ fn search_origin_package_for_origin() {
     assert_eq!(pkg6.to_string(), "josh/red_dog/2017.01.19/20170209064045");
 }
 
+#[test]
+fn search_origin_package_with_filters() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let core = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut oscc = originsrv::OriginChannelCreate::new();
+    oscc.set_origin_id(core.get_id());
+    oscc.set_origin_name(core.get_name().to_string());
+    oscc.set_name(String::from("stable"));
+    oscc.set_owner_id(1);
+    let stable = ds.create_origin_channel(&oscc).expect(
+        "Failed to create origin channel",
+    );
+
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(core.get_id());
+    package.set_checksum("checksum".to_string());
+    package.set_config("config".to_string());
+    package.set_exposes(vec![1, 2]);
+    // Created oldest first
+    let packages = vec![
+        ("core/zeal/1.0.0/20170209064044", "x86_64-linux", "gplv2+"),
+        ("core/red/1.0.0/20170209064044", "x86_64-windows", "apachev2"),
+        ("core/acid/1.0.0/20170209064044", "x86_64-linux", "apachev2 mit"),
+    ];
+    for &(ident, target, license) in packages.iter() {
+        let ident = originsrv::OriginPackageIdent::from_str(ident).unwrap();
+        package.set_ident(ident.clone());
+        package.set_target(target.to_string());
+        package.set_manifest(format!("# {}\n\n* __License__: {} \n", ident, license));
+        let created = ds.create_origin_package(&package).expect(
+            "Failed to create origin package",
+        );
+        if ident.get_name() != "zeal" {
+            let mut opp = originsrv::OriginPackagePromote::new();
+            opp.set_channel_id(stable.get_id());
+            opp.set_package_id(created.get_id());
+            opp.set_ident(ident);
+            ds.promote_origin_package(&opp).expect(
+                "Could not promote package",
+            );
+        }
+    }
+
+    let mut ops = originsrv::OriginPackageSearchRequest::new();
+    ops.set_query("".to_string());
+    ops.set_start(0);
+    ops.set_stop(20);
+    ops.set_target("x86_64-linux".to_string());
+    let result = ds.search_origin_package_for_origin(&ops).expect(
+        "Could not search the packages",
+    );
+    assert_eq!(result.get_count(), 2);
+    assert_eq!(
+        result.get_idents()[0].to_string(),
+        "core/acid/1.0.0/20170209064044"
+    );
+
+    ops.set_channel("stable".to_string());
+    let result = ds.search_origin_package_for_origin(&ops).expect(
+        "Could not search the packages",
+    );
+    assert_eq!(result.get_count(), 1);
+    assert_eq!(result.get_idents()[0].get_name(), "acid");
+
+    ops.clear_target();
+    ops.set_license("apache".to_string());
+    ops.set_order(originsrv::OriginPackageSearchOrder::Newest);
+    let result = ds.search_origin_package_for_origin(&ops).expect(
+        "Could not search the packages",
+    );
+    assert_eq!(result.get_count(), 2);
+    assert_eq!(result.get_idents()[0].get_name(), "acid");
+    assert_eq!(result.get_idents()[1].get_name(), "red");
+
+    ops.clear_channel();
+    ops.clear_license();
+    ops.set_built_after("2000-01-01T00:00:00Z".to_string());
+    ops.set_distinct(true);
+    let result = ds.search_origin_package_for_origin(&ops).expect(
+        "Could not search the packages",
+    );
+    assert_eq!(result.get_count(), 3);
+    assert_eq!(result.get_idents()[0].to_string(), "core/acid");
+
+    ops.set_built_after("2999-01-01T00:00:00Z".to_string());
+    let result = ds.search_origin_package_for_origin(&ops).expect(
+        "Could not search the packages",
+    );
+    assert_eq!(result.get_count(), 0);
+}
+
+#[test]
+fn record_origin_package_download() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("core"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    let core = ds.create_origin(&origin)
+        .expect("Should create origin")
+        .unwrap();
+
+    let mut oscc = originsrv::OriginChannelCreate::new();
+    oscc.set_origin_id(core.get_id());
+    oscc.set_origin_name(core.get_name().to_string());
+    oscc.set_name(String::from("stable"));
+    oscc.set_owner_id(1);
+    let stable = ds.create_origin_channel(&oscc).expect(
+        "Failed to create origin channel",
+    );
+
+    let ident = originsrv::OriginPackageIdent::from_str("core/cacerts/2017.01.17/20170209064044")
+        .unwrap();
+    let mut package = originsrv::OriginPackageCreate::new();
+    package.set_owner_id(1);
+    package.set_origin_id(core.get_id());
+    package.set_ident(ident.clone());
+    package.set_checksum("checksum".to_string());
+    package.set_manifest("manifest".to_string());
+    package.set_config("config".to_string());
+    package.set_target("x86_64-linux".to_string());
+    package.set_exposes(vec![1, 2]);
+    let created = ds.create_origin_package(&package).expect(
+        "Failed to create origin package",
+    );
+
+    let mut opp = originsrv::OriginPackagePromote::new();
+    opp.set_channel_id(stable.get_id());
+    opp.set_package_id(created.get_id());
+    opp.set_ident(ident.clone());
+    ds.promote_origin_package(&opp).expect(
+        "Could not promote package",
+    );
+
+    let mut opdr = originsrv::OriginPackageDownloadRecord::new();
+    opdr.set_ident(ident.clone());
+    opdr.set_channel("stable".to_string());
+    ds.record_origin_package_download(&opdr).expect(
+        "Could not record download",
+    );
+    ds.record_origin_package_download(&opdr).expect(
+        "Could not record download",
+    );
+    // Not in the channel, so it's counted as a download by ident
+    opdr.set_channel("unstable".to_string());
+    ds.record_origin_package_download(&opdr).expect(
+        "Could not record download",
+    );
+    opdr.clear_channel();
+    ds.record_origin_package_download(&opdr).expect(
+        "Could not record download",
+    );
+
+    let mut opdsg = originsrv::OriginPackageDownloadStatsGet::new();
+    opdsg.set_origin("core".to_string());
+    opdsg.set_name("cacerts".to_string());
+    let stats = ds.get_origin_package_download_stats(&opdsg).expect(
+        "Could not get download stats",
+    );
+    assert_eq!(stats.get_total(), 4);
+    assert_eq!(stats.get_stats().len(), 2);
+    assert_eq!(stats.get_stats()[0].get_ident(), &ident);
+    assert_eq!(stats.get_stats()[0].get_channel(), "");
+    assert_eq!(stats.get_stats()[0].get_count(), 2);
+    assert_eq!(stats.get_stats()[1].get_channel(), "stable");
+    assert_eq!(stats.get_stats()[1].get_count(), 2);
+
+    opdsg.set_since("2999-01-01T00:00:00Z".to_string());
+    let stats = ds.get_origin_package_download_stats(&opdsg).expect(
+        "Could not get download stats",
+    );
+    assert_eq!(stats.get_total(), 0);
+    assert!(stats.get_stats().is_empty());
+}
+
 #[test]
 fn create_origin_channel() {
     let ds = datastore_test!(DataStore);
@@ -2641,3 +3317,216 @@ fn delete_origin_channel_by_name() {
         "Channel package should not exist"
     );
 }
+
+#[test]
+fn upsert_and_delete_origin_secret() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let mut secret = originsrv::OriginSecret::new();
+    secret.set_origin(String::from("neurosis"));
+    secret.set_name(String::from("NPM_TOKEN"));
+    secret.set_value(String::from("first"));
+    let mut osc = originsrv::OriginSecretCreate::new();
+    osc.set_secret(secret.clone());
+    ds.upsert_origin_secret(&osc).expect("Should create secret");
+
+    // Setting a secret again replaces its value
+    secret.set_value(String::from("second"));
+    osc.set_secret(secret);
+    let updated = ds.upsert_origin_secret(&osc).expect("Should update secret");
+    assert_eq!(updated.get_value(), "second");
+
+    let mut osl = originsrv::OriginSecretListRequest::new();
+    osl.set_origin(String::from("neurosis"));
+    let secrets = ds.get_origin_secrets(&osl).expect("Should list secrets");
+    assert_eq!(secrets.get_secrets().len(), 1);
+    assert_eq!(secrets.get_secrets()[0].get_value(), "second");
+
+    let mut osd = originsrv::OriginSecretDelete::new();
+    osd.set_origin(String::from("neurosis"));
+    osd.set_name(String::from("NPM_TOKEN"));
+    ds.delete_origin_secret(&osd).expect("Should delete secret");
+    let secrets = ds.get_origin_secrets(&osl).expect("Should list secrets");
+    assert!(secrets.get_secrets().is_empty());
+}
+
+#[test]
+fn create_and_list_origin_notifications() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let mut notification = originsrv::OriginNotification::new();
+    notification.set_origin(String::from("neurosis"));
+    notification.set_kind(originsrv::OriginNotificationKind::Webhook);
+    notification.set_target(String::from("https://ci.example.com/hooks/builder"));
+    notification.set_events(vec![
+        originsrv::OriginNotificationEvent::PackageUpload,
+        originsrv::OriginNotificationEvent::ChannelPromote,
+    ]);
+    notification.set_owner_id(1);
+    notification.set_secret(String::from("encrypted"));
+    let mut onc = originsrv::OriginNotificationCreate::new();
+    onc.set_notification(notification.clone());
+    ds.create_origin_notification(&onc).expect(
+        "Should create notification",
+    );
+
+    // Notifications without a secret don't get one
+    notification.set_kind(originsrv::OriginNotificationKind::Email);
+    notification.set_target(String::from("builds@example.com"));
+    notification.clear_secret();
+    onc.set_notification(notification);
+    ds.create_origin_notification(&onc).expect(
+        "Should create notification",
+    );
+
+    let mut onl = originsrv::OriginNotificationListRequest::new();
+    onl.set_origin(String::from("neurosis"));
+    let response = ds.get_origin_notifications(&onl).expect(
+        "Should list notifications",
+    );
+    let notifications = response.get_notifications();
+    assert_eq!(notifications.len(), 2);
+    assert_eq!(notifications[0].get_kind(), originsrv::OriginNotificationKind::Email);
+    assert!(!notifications[0].has_secret());
+    assert_eq!(notifications[1].get_secret(), "encrypted");
+    assert!(notifications[1].wants(
+        originsrv::OriginNotificationEvent::ChannelPromote,
+    ));
+}
+
+#[test]
+fn check_origin_approver() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+    let neurosis = ds.get_origin_by_name("neurosis")
+        .expect("Could not retrieve origin")
+        .expect("Origin does not exist");
+
+    // Owners are always approvers
+    let mut coar = originsrv::CheckOriginApproverRequest::new();
+    coar.set_origin_name(String::from("neurosis"));
+    coar.set_account_id(1);
+    assert!(ds.check_origin_approver(&coar).expect(
+        "Should check approver",
+    ));
+    coar.set_account_id(2);
+    assert!(!ds.check_origin_approver(&coar).expect(
+        "Should check approver",
+    ));
+
+    let mut omau = originsrv::OriginMemberApproverUpdate::new();
+    omau.set_origin_id(neurosis.get_id());
+    omau.set_account_name(String::from("scottkelly"));
+    omau.set_approver(true);
+    assert!(ds.update_origin_member_approver(&omau).expect(
+        "Should update approver",
+    ));
+    omau.set_account_name(String::from("noelmiller"));
+    assert!(!ds.update_origin_member_approver(&omau).expect(
+        "Should update approver",
+    ));
+}
+
+#[test]
+fn review_origin_promotion_request() {
+    let ds = datastore_test!(DataStore);
+    let mut origin = originsrv::OriginCreate::new();
+    origin.set_name(String::from("neurosis"));
+    origin.set_owner_id(1);
+    origin.set_owner_name(String::from("scottkelly"));
+    ds.create_origin(&origin).expect("Should create origin");
+
+    let mut promotion = originsrv::OriginPromotionRequest::new();
+    promotion.set_origin(String::from("neurosis"));
+    promotion.set_channel(String::from("stable"));
+    promotion.set_idents(protobuf::RepeatedField::from_vec(vec![
+        String::from("neurosis/zeal/0.1.0/20170101000000"),
+        String::from("neurosis/sorrow/0.2.0/20170101000000"),
+    ]));
+    promotion.set_requester_id(2);
+    promotion.set_requester_name(String::from("noelmiller"));
+    let mut oprc = originsrv::OriginPromotionRequestCreate::new();
+    oprc.set_promotion(promotion);
+    oprc.set_comment(String::from("Ready for release"));
+    let promotion = ds.create_origin_promotion_request(&oprc).expect(
+        "Should create promotion request",
+    );
+    assert_eq!(
+        promotion.get_state(),
+        originsrv::OriginPromotionRequestState::Pending
+    );
+    assert_eq!(promotion.get_idents().len(), 2);
+    assert_eq!(promotion.get_events().len(), 1);
+    assert_eq!(promotion.get_events()[0].get_comment(), "Ready for release");
+
+    let mut oprt = originsrv::OriginPromotionRequestTransition::new();
+    oprt.set_origin(String::from("neurosis"));
+    oprt.set_id(promotion.get_id());
+    oprt.set_from_state(originsrv::OriginPromotionRequestState::Pending);
+    oprt.set_to_state(originsrv::OriginPromotionRequestState::Approved);
+    oprt.set_account_id(1);
+    oprt.set_account_name(String::from("scottkelly"));
+    ds.transition_origin_promotion_request(&oprt)
+        .expect("Should approve promotion request")
+        .expect("Promotion request should be pending");
+
+    // A request can only be reviewed once
+    oprt.set_to_state(originsrv::OriginPromotionRequestState::Rejected);
+    assert!(
+        ds.transition_origin_promotion_request(&oprt)
+            .expect("Should not reject promotion request")
+            .is_none()
+    );
+
+    oprt.set_from_state(originsrv::OriginPromotionRequestState::Approved);
+    oprt.set_to_state(originsrv::OriginPromotionRequestState::Promoted);
+    ds.transition_origin_promotion_request(&oprt)
+        .expect("Should finish promotion request")
+        .expect("Promotion request should be approved");
+
+    let mut oprg = originsrv::OriginPromotionRequestGet::new();
+    oprg.set_origin(String::from("neurosis"));
+    oprg.set_id(promotion.get_id());
+    let promotion = ds.get_origin_promotion_request(&oprg)
+        .expect("Should get promotion request")
+        .expect("Promotion request should exist");
+    assert_eq!(
+        promotion.get_state(),
+        originsrv::OriginPromotionRequestState::Promoted
+    );
+    let events = promotion.get_events();
+    assert_eq!(events.len(), 3);
+    assert_eq!(events[1].get_state(), originsrv::OriginPromotionRequestState::Approved);
+    assert_eq!(events[1].get_account_name(), "scottkelly");
+
+    let mut oprl = originsrv::OriginPromotionRequestListRequest::new();
+    oprl.set_origin(String::from("neurosis"));
+    assert_eq!(
+        ds.get_origin_promotion_requests(&oprl)
+            .expect("Should list promotion requests")
+            .get_promotions()
+            .len(),
+        1
+    );
+    oprl.set_state(originsrv::OriginPromotionRequestState::Pending);
+    assert!(
+        ds.get_origin_promotion_requests(&oprl)
+            .expect("Should list promotion requests")
+            .get_promotions()
+            .is_empty()
+    );
+}
@@ -0,0 +1,312 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Streaming removal of ANSI escape sequences from build output.
+//!
+//! Logs arrive in arbitrary chunks, so an escape sequence may be split across two of them. The
+//! `AnsiFilter` keeps track of where it is in a sequence between calls to `filter` so that split
+//! sequences are still removed in full.
+
+const BEL: u8 = 0x07;
+const ESC: u8 = 0x1b;
+const LF: u8 = b'\n';
+// Lead byte of the UTF-8 encoding of the C1 control characters
+const C1_LEAD: u8 = 0xc2;
+const C1_CSI: u8 = 0x9b;
+const C1_ST: u8 = 0x9c;
+const C1_OSC: u8 = 0x9d;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum State {
+    /// Plain output
+    Ground,
+    /// Plain output, holding a UTF-8 lead byte which may start a C1 control character
+    GroundLead,
+    /// Seen ESC
+    Escape,
+    /// Seen ESC followed by one or more intermediate bytes, e.g. the charset designation `ESC (`
+    EscapeIntermediate,
+    /// Inside a Control Sequence (`ESC [` or CSI), e.g. colors and cursor movement
+    Csi,
+    /// Inside a control string (`ESC ]` OSC, `ESC P` DCS, `ESC X` SOS, `ESC ^` PM, `ESC _` APC)
+    String,
+    /// Inside a control string, seen ESC which may start the String Terminator `ESC \`
+    StringEscape,
+    /// Inside a control string, holding a UTF-8 lead byte which may start the C1 String Terminator
+    StringLead,
+}
+
+/// Removes ANSI escape sequences from a stream of bytes.
+///
+/// Handles CSI sequences (colors, cursor movement, erasing), OSC and other control strings
+/// (window titles, hyperlinks), and two byte escapes such as charset designations. Both the 7-bit
+/// (`ESC [`) and the UTF-8 encoded 8-bit (`U+009B`) introducers are recognized. A control string
+/// which is never terminated ends at the next newline, so a stray introducer can't swallow the
+/// rest of a log.
+#[derive(Debug)]
+pub struct AnsiFilter {
+    state: State,
+}
+
+impl AnsiFilter {
+    pub fn new() -> Self {
+        AnsiFilter { state: State::Ground }
+    }
+
+    /// Append `input` to `out` with any escape sequences removed. Partial sequences at the end of
+    /// `input` are remembered and removed once the rest arrives in a later call.
+    pub fn filter(&mut self, input: &[u8], out: &mut Vec<u8>) {
+        out.reserve(input.len());
+        for &byte in input {
+            self.state = self.next(byte, out);
+        }
+    }
+
+    /// Strip escape sequences from a complete string.
+    pub fn filter_str(&mut self, input: &str) -> String {
+        let mut out = Vec::with_capacity(input.len());
+        self.filter(input.as_bytes(), &mut out);
+        self.finish(&mut out);
+        into_string(out)
+    }
+
+    /// Strip escape sequences from one line of a log whose lines come in chunks. A sequence left
+    /// partial at the end of the line is removed once the rest arrives with the next line, but a
+    /// control string which is never terminated ends with the line, as it would at a newline.
+    pub fn filter_line(&mut self, line: &str) -> String {
+        let mut out = Vec::with_capacity(line.len());
+        self.filter(line.as_bytes(), &mut out);
+        match self.state {
+            State::String | State::StringEscape | State::StringLead => self.state = State::Ground,
+            _ => (),
+        }
+        into_string(out)
+    }
+
+    /// Flush a byte held back at the end of the stream and return to plain output.
+    pub fn finish(&mut self, out: &mut Vec<u8>) {
+        if self.state == State::GroundLead {
+            out.push(C1_LEAD);
+        }
+        self.state = State::Ground;
+    }
+
+    fn next(&self, byte: u8, out: &mut Vec<u8>) -> State {
+        match self.state {
+            State::Ground => self.ground(byte, out),
+            State::GroundLead => {
+                match byte {
+                    C1_CSI => State::Csi,
+                    C1_OSC => State::String,
+                    _ => {
+                        out.push(C1_LEAD);
+                        self.ground(byte, out)
+                    }
+                }
+            }
+            State::Escape => {
+                match byte {
+                    b'[' => State::Csi,
+                    b']' | b'P' | b'X' | b'^' | b'_' => State::String,
+                    ESC => State::Escape,
+                    0x20...0x2f => State::EscapeIntermediate,
+                    0x30...0x7e => State::Ground,
+                    _ => self.ground(byte, out),
+                }
+            }
+            State::EscapeIntermediate => {
+                match byte {
+                    0x20...0x2f => State::EscapeIntermediate,
+                    0x30...0x7e => State::Ground,
+                    ESC => State::Escape,
+                    _ => self.ground(byte, out),
+                }
+            }
+            State::Csi => {
+                match byte {
+                    // Parameter and intermediate bytes
+                    0x20...0x3f => State::Csi,
+                    // Final byte
+                    0x40...0x7e => State::Ground,
+                    ESC => State::Escape,
+                    // Anything else aborts the sequence and is printed
+                    _ => self.ground(byte, out),
+                }
+            }
+            State::String => {
+                match byte {
+                    BEL => State::Ground,
+                    ESC => State::StringEscape,
+                    C1_LEAD => State::StringLead,
+                    LF => {
+                        out.push(LF);
+                        State::Ground
+                    }
+                    _ => State::String,
+                }
+            }
+            State::StringEscape => {
+                match byte {
+                    b'\\' => State::Ground,
+                    // Not a String Terminator, so this ESC starts a new sequence
+                    _ => {
+                        let escape = AnsiFilter { state: State::Escape };
+                        escape.next(byte, out)
+                    }
+                }
+            }
+            State::StringLead => {
+                match byte {
+                    C1_ST => State::Ground,
+                    _ => {
+                        let string = AnsiFilter { state: State::String };
+                        string.next(byte, out)
+                    }
+                }
+            }
+        }
+    }
+
+    fn ground(&self, byte: u8, out: &mut Vec<u8>) -> State {
+        match byte {
+            ESC => State::Escape,
+            C1_LEAD => State::GroundLead,
+            _ => {
+                out.push(byte);
+                State::Ground
+            }
+        }
+    }
+}
+
+impl Default for AnsiFilter {
+    fn default() -> Self {
+        AnsiFilter::new()
+    }
+}
+
+fn into_string(out: Vec<u8>) -> String {
+    match String::from_utf8(out) {
+        Ok(s) => s,
+        Err(e) => String::from_utf8_lossy(e.as_bytes()).into_owned(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn strip(input: &str) -> String {
+        AnsiFilter::new().filter_str(input)
+    }
+
+    #[test]
+    fn strips_colors() {
+        assert_eq!(
+            strip("\x1b[1;33m» Installing core/hab-backline\x1b[0m"),
+            "» Installing core/hab-backline"
+        );
+        assert_eq!(strip("\x1b[38;5;208morange\x1b[m"), "orange");
+    }
+
+    #[test]
+    fn strips_cursor_movement() {
+        assert_eq!(strip("\x1b[2K\x1b[1Gprogress\x1b[10A\x1b[s\x1b[u"), "progress");
+        assert_eq!(strip("\x1b[?25lhidden cursor\x1b[?25h"), "hidden cursor");
+    }
+
+    #[test]
+    fn strips_osc() {
+        assert_eq!(strip("\x1b]0;window title\x07output"), "output");
+        assert_eq!(
+            strip("\x1b]8;;http://example.com\x1b\\link\x1b]8;;\x1b\\"),
+            "link"
+        );
+        assert_eq!(strip("\u{9d}0;title\u{9c}output"), "output");
+    }
+
+    #[test]
+    fn strips_two_byte_escapes() {
+        assert_eq!(strip("\x1b(Bplain\x1b7\x1b8\x1b="), "plain");
+    }
+
+    #[test]
+    fn strips_8bit_csi() {
+        assert_eq!(strip("\u{9b}1;31mred\u{9b}0m"), "red");
+    }
+
+    #[test]
+    fn keeps_non_ascii_output() {
+        let input = "★ Imported secret origin key, Ûber ¢ents";
+        assert_eq!(strip(input), input);
+    }
+
+    #[test]
+    fn unterminated_string_ends_at_newline() {
+        assert_eq!(strip("\x1b]0;no terminator\nnext line"), "\nnext line");
+    }
+
+    #[test]
+    fn handles_sequences_split_across_chunks() {
+        let input = "\x1b[1;33mbold\x1b[0m \x1b]0;title\x1b\\done \u{9b}32mgreen\u{9b}0m ¢";
+        let expected = "bold done green ¢";
+        let bytes = input.as_bytes();
+
+        // Try every possible split point, including inside multibyte characters
+        for split in 0..bytes.len() + 1 {
+            let mut filter = AnsiFilter::new();
+            let mut out = Vec::new();
+            filter.filter(&bytes[..split], &mut out);
+            filter.filter(&bytes[split..], &mut out);
+            filter.finish(&mut out);
+            assert_eq!(String::from_utf8(out).unwrap(), expected, "split at {}", split);
+        }
+    }
+
+    #[test]
+    fn unterminated_string_ends_with_line() {
+        let mut filter = AnsiFilter::new();
+        assert_eq!(filter.filter_line("\x1b]0;no terminator"), "");
+        assert_eq!(filter.filter_line("next line\x1b["), "next line");
+        assert_eq!(filter.filter_line("1mbold"), "bold");
+    }
+
+    // A rough benchmark, run it with `cargo test --release -- --ignored --nocapture`
+    #[test]
+    #[ignore]
+    fn bench_multi_megabyte_log() {
+        let line = "\x1b[1;32m↓ Downloading\x1b[0m core/hab-backline/0.23.0/20170511220008 \
+                    \x1b[2K\x1b[1G[=====>     ] 50%\n";
+        let log = line.repeat(64 * 1024 * 1024 / line.len());
+        let mut out = Vec::with_capacity(log.len());
+
+        let mut filter = AnsiFilter::new();
+        let start = Instant::now();
+        for chunk in log.as_bytes().chunks(4096) {
+            filter.filter(chunk, &mut out);
+        }
+        filter.finish(&mut out);
+        let elapsed = start.elapsed();
+        let secs = elapsed.as_secs() as f64 + elapsed.subsec_nanos() as f64 / 1e9;
+
+        println!(
+            "filtered {} MB in {:.3}s ({:.1} MB/s)",
+            log.len() / (1024 * 1024),
+            secs,
+            (log.len() as f64 / (1024.0 * 1024.0)) / secs
+        );
+        assert!(out.len() < log.len());
+    }
+}
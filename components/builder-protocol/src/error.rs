@@ -21,14 +21,20 @@ use protobuf;
 
 #[derive(Debug)]
 pub enum ProtocolError {
+    BadAccessTokenScope(String),
+    BadJobAuditTrigger(String),
     BadJobGroupProjectState(String),
+    BadJobErrorCategory(String),
     BadJobGroupState(String),
+    BadJobPriority(String),
     BadJobState(String),
     BadSearchEntity(String),
     BadSearchKey(String),
+    BadTimestamp(String),
     Decode(protobuf::ProtobufError),
     Encode(protobuf::ProtobufError),
     IdentityDecode(FromUtf8Error),
+    IncompatibleVersion(u32, u32),
     MsgNotInitialized,
     NoControlFrame(String),
     NoProtocol(String),
@@ -40,10 +46,16 @@ pub type ProtocolResult<T> = result::Result<T, ProtocolError>;
 impl fmt::Display for ProtocolError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            ProtocolError::BadAccessTokenScope(ref e) => format!("Bad Access Token Scope {}", e),
+            ProtocolError::BadJobAuditTrigger(ref e) => format!("Bad Job Audit Trigger {}", e),
             ProtocolError::BadJobGroupProjectState(ref e) => {
                 format!("Bad Job Group Project State {}", e)
             }
+            ProtocolError::BadJobErrorCategory(ref e) => {
+                format!("Bad Job Error Category {}", e)
+            }
             ProtocolError::BadJobGroupState(ref e) => format!("Bad Job Group State {}", e),
+            ProtocolError::BadJobPriority(ref e) => format!("Bad Job Priority {}", e),
             ProtocolError::BadJobState(ref e) => format!("Bad Job State {}", e),
             ProtocolError::BadSearchEntity(ref e) => {
                 format!("Search not implemented for entity, {}", e)
@@ -51,11 +63,21 @@ impl fmt::Display for ProtocolError {
             ProtocolError::BadSearchKey(ref e) => {
                 format!("Search not implemented for entity with key, {}", e)
             }
+            ProtocolError::BadTimestamp(ref e) => format!("Bad RFC3339 timestamp {}", e),
             ProtocolError::Decode(ref e) => format!("Unable to decode protocol message, {}", e),
             ProtocolError::Encode(ref e) => format!("Unable to encode protocol message, {}", e),
             ProtocolError::IdentityDecode(ref e) => {
                 format!("Unable to decode identity message part, {}", e)
             }
+            ProtocolError::IncompatibleVersion(peer, ours) => {
+                format!(
+                    "Incompatible protocol version {}, this build speaks version {} and \
+                     supports versions back to {}",
+                    peer,
+                    ours,
+                    ::version::MIN_PROTOCOL_VERSION
+                )
+            }
             ProtocolError::MsgNotInitialized => {
                 format!("Message not ready for transport, is it missing it's header?")
             }
@@ -77,14 +99,22 @@ impl fmt::Display for ProtocolError {
 impl error::Error for ProtocolError {
     fn description(&self) -> &str {
         match *self {
+            ProtocolError::BadAccessTokenScope(_) => "Access token scope cannot be parsed",
+            ProtocolError::BadJobAuditTrigger(_) => "Job audit trigger cannot be parsed",
             ProtocolError::BadJobGroupProjectState(_) => "Job Group Project state cannot be parsed",
+            ProtocolError::BadJobErrorCategory(_) => "Job error category cannot be parsed",
             ProtocolError::BadJobGroupState(_) => "Job Group state cannot be parsed",
+            ProtocolError::BadJobPriority(_) => "Job priority cannot be parsed",
             ProtocolError::BadJobState(_) => "Job state cannot be parsed",
             ProtocolError::BadSearchEntity(_) => "Search not implemented for entity.",
             ProtocolError::BadSearchKey(_) => "Entity not indexed by the given key.",
+            ProtocolError::BadTimestamp(_) => "Timestamp cannot be parsed as RFC3339",
             ProtocolError::Decode(_) => "Unable to decode protocol message",
             ProtocolError::Encode(_) => "Unable to encode protocol message",
             ProtocolError::IdentityDecode(_) => "Unable to decode identity message part",
+            ProtocolError::IncompatibleVersion(_, _) => {
+                "Peer speaks an incompatible protocol version"
+            }
             ProtocolError::MsgNotInitialized => {
                 "Message not ready for transport, is it missing it's header?"
             }
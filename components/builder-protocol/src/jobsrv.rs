@@ -12,24 +12,38 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::result;
 use std::str::FromStr;
 use std::fmt;
 
+use ansi::AnsiFilter;
+use chrono::{Duration, UTC};
 use message::{Persistable, Routable};
+use message::net::{ErrCode, NetError};
 use message::originsrv::OriginPackage;
 use originsrv::Pageable;
 use protobuf::RepeatedField;
-use regex::Regex;
+use serde::{de, ser};
 use serde::ser::SerializeStruct;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use sharding::InstaId;
+use timestamp;
 
 use error::ProtocolError;
 pub use message::jobsrv::*;
 
 pub const GITHUB_PUSH_NOTIFY_ID: u64 = 23;
 
+/// Platform target used for jobs and workers which don't specify one
+pub const DEFAULT_TARGET: &'static str = "x86_64-linux";
+
+/// Returns the topic a job's new log output is published under on JobSrv's log stream. Topics are
+/// a fixed width so that subscribing to one job never matches another job's ID by prefix.
+pub fn log_stream_topic(job_id: u64) -> String {
+    format!("{:020}", job_id)
+}
+
 impl Into<Job> for JobSpec {
     fn into(mut self) -> Job {
         let mut job = Job::new();
@@ -39,6 +53,21 @@ impl Into<Job> for JobSpec {
         if self.has_channel() {
             job.set_channel(self.take_channel());
         }
+        job.set_priority(self.get_priority());
+        if self.has_target() {
+            job.set_target(self.take_target());
+        }
+        job.set_tags(self.take_tags());
+        job.set_kind(self.get_kind());
+        let build_timeout = job.get_project().get_build_timeout();
+        if build_timeout > 0 {
+            job.set_build_timeout(build_timeout);
+        }
+        let worker_labels = job.get_project().get_worker_labels().to_vec();
+        job.set_worker_labels(RepeatedField::from_vec(worker_labels));
+        if job.get_project().get_verify_reproducible() {
+            job.set_verify_reproducible(true);
+        }
         job
     }
 }
@@ -59,6 +88,14 @@ impl Routable for JobLogGet {
     }
 }
 
+impl Routable for JobLogSubscribe {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_job_id()))
+    }
+}
+
 impl Routable for JobGet {
     type H = InstaId;
 
@@ -80,6 +117,14 @@ impl Routable for Job {
 // going to route to the same, single place anyway). If we ever do run
 // multiple JobServers, though, this may need to be revisited (as will
 // other corners of the code).
+impl Routable for WorkerListGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
 impl Routable for ProjectJobsGet {
     type H = String;
 
@@ -92,6 +137,38 @@ impl Pageable for ProjectJobsGet {
     fn get_range(&self) -> [u64; 2] {
         [self.get_start(), self.get_stop()]
     }
+
+    fn set_range(&mut self, range: [u64; 2]) {
+        self.set_start(range[0]);
+        self.set_stop(range[1]);
+    }
+
+    fn set_snapshot(&mut self, created_before: String) {
+        self.set_created_before(created_before);
+    }
+}
+
+impl Routable for JobsByTagGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_tag().to_string())
+    }
+}
+
+impl Pageable for JobsByTagGet {
+    fn get_range(&self) -> [u64; 2] {
+        [self.get_start(), self.get_stop()]
+    }
+
+    fn set_range(&mut self, range: [u64; 2]) {
+        self.set_start(range[0]);
+        self.set_stop(range[1]);
+    }
+
+    fn set_snapshot(&mut self, created_before: String) {
+        self.set_created_before(created_before);
+    }
 }
 
 impl Serialize for Job {
@@ -99,7 +176,7 @@ impl Serialize for Job {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("job", 10)?;
+        let mut strukt = serializer.serialize_struct("job", 18)?;
 
         // Technically, an ID is a 64-bit integer, but that can cause
         // issues when processing it in JavaScript on the front-end,
@@ -116,7 +193,10 @@ impl Serialize for Job {
         // treat all our structs consistently.
         strukt.serialize_field("id", &self.get_id().to_string())?;
 
-        strukt.serialize_field("created_at", &self.get_created_at())?;
+        strukt.serialize_field(
+            "created_at",
+            &timestamp::normalize(self.get_created_at()).map_err(ser::Error::custom)?,
+        )?;
 
         // Technically, we could get the origin and name from the
         // package identifier, but we'll only have that if the job was
@@ -140,17 +220,18 @@ impl Serialize for Job {
         if self.has_build_started_at() {
             strukt.serialize_field(
                 "build_started_at",
-                &self.get_build_started_at(),
+                &timestamp::normalize(self.get_build_started_at()).map_err(ser::Error::custom)?,
             )?;
         }
         if self.has_build_finished_at() {
             strukt.serialize_field(
                 "build_finished_at",
-                &self.get_build_finished_at(),
+                &timestamp::normalize(self.get_build_finished_at()).map_err(ser::Error::custom)?,
             )?;
         }
 
         strukt.serialize_field("state", &self.get_state())?;
+        strukt.serialize_field("priority", &self.get_priority())?;
 
         if self.has_error() {
             strukt.serialize_field("error", self.get_error())?;
@@ -160,6 +241,105 @@ impl Serialize for Job {
             strukt.serialize_field("channel", self.get_channel())?;
         }
 
+        if self.has_target() {
+            strukt.serialize_field("target", self.get_target())?;
+        }
+
+        strukt.serialize_field("retries", &self.get_retries())?;
+        strukt.serialize_field("max_retries", &self.get_max_retries())?;
+
+        if self.has_last_error_category() {
+            strukt.serialize_field(
+                "last_error_category",
+                &self.get_last_error_category(),
+            )?;
+        }
+
+        strukt.serialize_field("tags", self.get_tags())?;
+
+        if self.has_artifact() {
+            strukt.serialize_field("artifact", self.get_artifact())?;
+        }
+
+        if self.has_expires_at() {
+            strukt.serialize_field(
+                "expires_at",
+                &timestamp::normalize(self.get_expires_at()).map_err(ser::Error::custom)?,
+            )?;
+        }
+
+        if self.has_build_timeout() {
+            strukt.serialize_field("build_timeout", &self.get_build_timeout())?;
+        }
+
+        if self.has_memory_limit_mb() {
+            strukt.serialize_field("memory_limit_mb", &self.get_memory_limit_mb())?;
+        }
+
+        if self.has_cpu_limit_millicores() {
+            strukt.serialize_field("cpu_limit_millicores", &self.get_cpu_limit_millicores())?;
+        }
+
+        strukt.serialize_field("worker_labels", self.get_worker_labels())?;
+        strukt.serialize_field("kind", &self.get_kind())?;
+
+        if self.has_image_digest() {
+            strukt.serialize_field("image_digest", self.get_image_digest())?;
+        }
+
+        if self.has_verify_reproducible() {
+            strukt.serialize_field("verify_reproducible", &self.get_verify_reproducible())?;
+        }
+
+        if self.has_reproducible() {
+            strukt.serialize_field("reproducible", &self.get_reproducible())?;
+        }
+
+        strukt.end()
+    }
+}
+
+serialize_enum!(WorkerState, {
+    0 => "Ready",
+    1 => "Busy",
+});
+
+impl Serialize for WorkerStatus {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("worker_status", 8)?;
+        strukt.serialize_field("ident", self.get_ident())?;
+        strukt.serialize_field("targets", self.get_targets())?;
+        strukt.serialize_field("labels", self.get_labels())?;
+        strukt.serialize_field("state", &self.get_state())?;
+        strukt.serialize_field("capacity", &self.get_capacity())?;
+        strukt.serialize_field("draining", &self.get_draining())?;
+        strukt.serialize_field("artifact_cache_hits", &self.get_artifact_cache_hits())?;
+        strukt.serialize_field("artifact_cache_misses", &self.get_artifact_cache_misses())?;
+        if self.has_job_id() {
+            strukt.serialize_field("job_id", &self.get_job_id().to_string())?;
+        }
+        let job_ids: Vec<String> = self.get_job_ids().iter().map(|id| id.to_string()).collect();
+        strukt.serialize_field("job_ids", &job_ids)?;
+        if self.has_last_heartbeat() {
+            strukt.serialize_field(
+                "last_heartbeat",
+                &timestamp::normalize(self.get_last_heartbeat()).map_err(ser::Error::custom)?,
+            )?;
+        }
+        strukt.end()
+    }
+}
+
+impl Serialize for WorkerListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("worker_list_response", 1)?;
+        strukt.serialize_field("workers", self.get_workers())?;
         strukt.end()
     }
 }
@@ -177,22 +357,13 @@ impl Serialize for ProjectJobsGetResponse {
 
 impl JobLog {
     /// Strip any ANSI control codes from the contents of the log
-    /// chunk. Useful mainly for removing color codes.
-    pub fn strip_ansi(&mut self) {
-        lazy_static! {
-            // https://github.com/chalk/ansi-regex/blob/master/index.js
-            static ref RE: Regex = Regex::new(
-                r"[\x1b\x9b][[()#;?]*(?:[0-9]{1,4}(?:;[0-9]{0,4})*)?[0-9A-PRZcf-nqry=><]")
-                .unwrap();
+    /// chunk. Useful mainly for removing color codes. Pass the same
+    /// `filter` for every chunk of a log, so that a sequence split
+    /// between two chunks is removed as well.
+    pub fn strip_ansi(&mut self, filter: &mut AnsiFilter) {
+        for line in self.mut_content().iter_mut() {
+            *line = filter.filter_line(line);
         }
-
-        let mut stripped = RepeatedField::new();
-        for line in self.get_content() {
-            let after = RE.replace_all(line, "");
-            stripped.push(after);
-        }
-
-        self.set_content(stripped);
     }
 }
 
@@ -216,25 +387,19 @@ impl Default for JobState {
     }
 }
 
-impl Serialize for JobState {
-    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match *self as u64 {
-            0 => serializer.serialize_str("Pending"),
-            1 => serializer.serialize_str("Processing"),
-            2 => serializer.serialize_str("Complete"),
-            3 => serializer.serialize_str("Rejected"),
-            4 => serializer.serialize_str("Failed"),
-            5 => serializer.serialize_str("Dispatched"),
-            6 => serializer.serialize_str("CancelPending"),
-            7 => serializer.serialize_str("CancelProcessing"),
-            8 => serializer.serialize_str("CancelComplete"),
-            _ => panic!("Unexpected enum value"),
-        }
-    }
-}
+serialize_enum!(JobState, {
+    0 => "Pending",
+    1 => "Processing",
+    2 => "Complete",
+    3 => "Rejected",
+    4 => "Failed",
+    5 => "Dispatched",
+    6 => "CancelPending",
+    7 => "CancelProcessing",
+    8 => "CancelComplete",
+});
+
+deserialize_enum!(JobState);
 
 impl FromStr for JobState {
     type Err = ProtocolError;
@@ -272,6 +437,214 @@ impl fmt::Display for JobState {
     }
 }
 
+impl Default for JobPriority {
+    fn default() -> JobPriority {
+        JobPriority::Normal
+    }
+}
+
+serialize_enum!(JobPriority, {
+    0 => "Normal",
+    1 => "High",
+    2 => "Critical",
+});
+
+deserialize_enum!(JobPriority);
+
+impl FromStr for JobPriority {
+    type Err = ProtocolError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "normal" => Ok(JobPriority::Normal),
+            "high" => Ok(JobPriority::High),
+            "critical" => Ok(JobPriority::Critical),
+            _ => Err(ProtocolError::BadJobPriority(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for JobPriority {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            JobPriority::Normal => "Normal",
+            JobPriority::High => "High",
+            JobPriority::Critical => "Critical",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl Default for JobKind {
+    fn default() -> JobKind {
+        JobKind::Build
+    }
+}
+
+serialize_enum!(JobKind, {
+    0 => "Build",
+    1 => "DockerExport",
+});
+
+impl Default for JobErrorCategory {
+    fn default() -> JobErrorCategory {
+        JobErrorCategory::Uncategorized
+    }
+}
+
+serialize_enum!(JobErrorCategory, {
+    0 => "Uncategorized",
+    1 => "Transient",
+    2 => "Permanent",
+    3 => "Plan",
+    4 => "Infrastructure",
+    5 => "ResourceLimit",
+});
+
+deserialize_enum!(JobErrorCategory);
+
+impl FromStr for JobErrorCategory {
+    type Err = ProtocolError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "uncategorized" => Ok(JobErrorCategory::Uncategorized),
+            "transient" => Ok(JobErrorCategory::Transient),
+            "permanent" => Ok(JobErrorCategory::Permanent),
+            "plan" => Ok(JobErrorCategory::Plan),
+            "infrastructure" => Ok(JobErrorCategory::Infrastructure),
+            "resourcelimit" => Ok(JobErrorCategory::ResourceLimit),
+            _ => Err(ProtocolError::BadJobErrorCategory(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for JobErrorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            JobErrorCategory::Uncategorized => "Uncategorized",
+            JobErrorCategory::Transient => "Transient",
+            JobErrorCategory::Permanent => "Permanent",
+            JobErrorCategory::Plan => "Plan",
+            JobErrorCategory::Infrastructure => "Infrastructure",
+            JobErrorCategory::ResourceLimit => "ResourceLimit",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl From<ErrCode> for JobErrorCategory {
+    /// Failures caused by the network or other remote services may succeed if the job is run
+    /// again, so they are transient. Failures of the plan itself or of the worker's environment
+    /// are not, and are told apart so users know whether the fix is theirs to make. Builds stopped
+    /// for going over the resources their job was given are neither, as they need larger limits.
+    fn from(code: ErrCode) -> Self {
+        match code {
+            ErrCode::TIMEOUT |
+            ErrCode::SOCK |
+            ErrCode::REMOTE_UNAVAILABLE |
+            ErrCode::SECRET_KEY_FETCH |
+            ErrCode::VCS_CLONE |
+            ErrCode::LOW_DISK_SPACE => JobErrorCategory::Transient,
+            ErrCode::BUILD | ErrCode::BUILD_TIMEOUT | ErrCode::BUILD_OFFLINE => {
+                JobErrorCategory::Plan
+            }
+            ErrCode::WORKSPACE_SETUP |
+            ErrCode::SECRET_KEY_IMPORT |
+            ErrCode::POST_PROCESSOR |
+            ErrCode::DATA_STORE |
+            ErrCode::SYS => JobErrorCategory::Infrastructure,
+            ErrCode::BUILD_OOM => JobErrorCategory::ResourceLimit,
+            _ => JobErrorCategory::Permanent,
+        }
+    }
+}
+
+impl From<NetError> for JobError {
+    fn from(mut err: NetError) -> Self {
+        let mut job_err = JobError::new();
+        job_err.set_code(err.get_code());
+        job_err.set_message(err.take_msg());
+        job_err.set_category(JobErrorCategory::from(err.get_code()));
+        job_err
+    }
+}
+
+impl JobError {
+    /// Returns true if running the job again may succeed.
+    pub fn is_retryable(&self) -> bool {
+        self.get_category() == JobErrorCategory::Transient
+    }
+}
+
+impl fmt::Display for JobError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "[err: {:?}, category: {}, msg: {}]",
+            self.get_code(),
+            self.get_category(),
+            self.get_message()
+        )
+    }
+}
+
+impl Serialize for JobError {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("job_error", 4)?;
+        strukt.serialize_field("code", &self.get_code())?;
+        strukt.serialize_field("message", self.get_message())?;
+        strukt.serialize_field("category", &self.get_category())?;
+        strukt.serialize_field("retryable", &self.is_retryable())?;
+        strukt.end()
+    }
+}
+
+impl Serialize for JobArtifact {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("job_artifact", 2)?;
+        strukt.serialize_field("checksum", self.get_checksum())?;
+        strukt.serialize_field("size", &self.get_size())?;
+        strukt.end()
+    }
+}
+
+impl Job {
+    /// Returns true if the job has not yet used up its allowed number of retries.
+    pub fn can_retry(&self) -> bool {
+        self.get_retries() < self.get_max_retries()
+    }
+
+    /// Set the job to expire if it's still waiting for a worker the given number of minutes from
+    /// now.
+    pub fn set_ttl(&mut self, minutes: u64) {
+        let expires_at = UTC::now() + Duration::minutes(minutes as i64);
+        self.set_expires_at(expires_at.to_rfc3339());
+    }
+}
+
+impl JobGroupProject {
+    /// Returns the number of seconds the project's build took, if it has finished.
+    pub fn duration_seconds(&self) -> Option<i64> {
+        if !self.has_build_started_at() || !self.has_build_finished_at() {
+            return None;
+        }
+        match (
+            timestamp::parse(self.get_build_started_at()),
+            timestamp::parse(self.get_build_finished_at()),
+        ) {
+            (Ok(started), Ok(finished)) => Some((finished - started).num_seconds()),
+            _ => None,
+        }
+    }
+}
+
 impl Persistable for Job {
     type Key = u64;
 
@@ -292,6 +665,97 @@ impl Routable for JobGroupSpec {
     }
 }
 
+impl fmt::Display for JobGroupCreateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Unable to create a job group for {}, dependency cycle: {}",
+            self.get_project_name(),
+            self.get_cycle().join(" -> ")
+        )?;
+        match self.get_cycle().first() {
+            Some(first) => write!(f, " -> {}", first),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Serialize for JobGroupCreateError {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("job_group_create_error", 3)?;
+        strukt.serialize_field("project_name", self.get_project_name())?;
+        strukt.serialize_field("cycle", self.get_cycle())?;
+        strukt.serialize_field("message", &self.to_string())?;
+        strukt.end()
+    }
+}
+
+impl Routable for JobGroupPreview {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(format!(
+            "{}/{}",
+            self.get_spec().get_origin(),
+            self.get_spec().get_package()
+        ))
+    }
+}
+
+impl Serialize for JobGroupPreviewProject {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("job_group_preview_project", 2)?;
+        strukt.serialize_field("name", self.get_name())?;
+        strukt.serialize_field("ident", self.get_ident())?;
+        strukt.end()
+    }
+}
+
+impl Serialize for JobGroupPreviewResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("job_group_preview_response", 1)?;
+        strukt.serialize_field("projects", self.get_projects())?;
+        strukt.end()
+    }
+}
+
+impl Routable for JobGroupScheduleCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(format!(
+            "{}/{}",
+            self.get_spec().get_origin(),
+            self.get_spec().get_package()
+        ))
+    }
+}
+
+impl Routable for JobGroupScheduleListGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Routable for JobGroupScheduleDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
 impl From<OriginPackage> for JobGraphPackage {
     fn from(value: OriginPackage) -> JobGraphPackage {
         let mut package = JobGraphPackage::new();
@@ -363,31 +827,102 @@ impl Routable for JobGroupCancel {
     }
 }
 
-impl Routable for JobGraphPackageCreate {
+impl Routable for JobGroupRetry {
     type H = String;
 
     fn route_key(&self) -> Option<Self::H> {
-        Some(self.get_ident().to_string())
+        Some(self.get_group_id().to_string())
     }
 }
 
-impl Routable for JobGraphPackagePreCreate {
-    type H = String;
+impl Routable for JobCancel {
+    type H = InstaId;
 
     fn route_key(&self) -> Option<Self::H> {
-        Some(self.get_ident().to_string())
+        Some(InstaId(self.get_job_id()))
     }
 }
 
-impl Routable for JobGroupOriginGet {
-    type H = String;
+impl Persistable for JobCancel {
+    type Key = u64;
 
-    fn route_key(&self) -> Option<Self::H> {
-        Some(self.get_origin().to_string())
+    fn primary_key(&self) -> Self::Key {
+        self.get_job_id()
+    }
+
+    fn set_primary_key(&mut self, value: Self::Key) {
+        self.set_job_id(value);
     }
 }
 
-impl Routable for JobGraphPackageStatsGet {
+impl Routable for JobAuditGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        if self.has_group_id() {
+            Some(self.get_group_id().to_string())
+        } else {
+            Some(self.get_job_id().to_string())
+        }
+    }
+}
+
+impl Routable for JobHistoryPrune {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        None
+    }
+}
+
+impl Routable for OriginBuildUsageGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Routable for JobGraphPackageCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_ident().to_string())
+    }
+}
+
+impl Routable for JobGraphPackagePreCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_ident().to_string())
+    }
+}
+
+impl Pageable for JobGroupOriginGet {
+    fn get_range(&self) -> [u64; 2] {
+        [self.get_start(), self.get_stop()]
+    }
+
+    fn set_range(&mut self, range: [u64; 2]) {
+        self.set_start(range[0]);
+        self.set_stop(range[1]);
+    }
+
+    fn set_snapshot(&mut self, created_before: String) {
+        self.set_created_before(created_before);
+    }
+}
+
+impl Routable for JobGroupOriginGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Routable for JobGraphPackageStatsGet {
     type H = String;
 
     fn route_key(&self) -> Option<Self::H> {
@@ -433,22 +968,16 @@ impl FromStr for JobGroupState {
     }
 }
 
-impl Serialize for JobGroupState {
-    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match *self as u64 {
-            0 => serializer.serialize_str("Pending"),
-            1 => serializer.serialize_str("Dispatching"),
-            2 => serializer.serialize_str("Complete"),
-            3 => serializer.serialize_str("Failed"),
-            4 => serializer.serialize_str("Queued"),
-            5 => serializer.serialize_str("Canceled"),
-            _ => panic!("Unexpected enum value"),
-        }
-    }
-}
+serialize_enum!(JobGroupState, {
+    0 => "Pending",
+    1 => "Dispatching",
+    2 => "Complete",
+    3 => "Failed",
+    4 => "Queued",
+    5 => "Canceled",
+});
+
+deserialize_enum!(JobGroupState);
 
 impl fmt::Display for JobGroupProjectState {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -480,20 +1009,132 @@ impl FromStr for JobGroupProjectState {
     }
 }
 
-impl Serialize for JobGroupProjectState {
+serialize_enum!(JobGroupProjectState, {
+    0 => "NotStarted",
+    1 => "InProgress",
+    2 => "Success",
+    3 => "Failure",
+    4 => "Skipped",
+    5 => "Canceled",
+});
+
+deserialize_enum!(JobGroupProjectState);
+
+impl fmt::Display for JobAuditTrigger {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            JobAuditTrigger::Scheduler => "Scheduler",
+            JobAuditTrigger::Worker => "Worker",
+            JobAuditTrigger::User => "User",
+            JobAuditTrigger::Expired => "Expired",
+            JobAuditTrigger::TimedOut => "TimedOut",
+            JobAuditTrigger::Retry => "Retry",
+            JobAuditTrigger::WorkerLost => "WorkerLost",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl FromStr for JobAuditTrigger {
+    type Err = ProtocolError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "scheduler" => Ok(JobAuditTrigger::Scheduler),
+            "worker" => Ok(JobAuditTrigger::Worker),
+            "user" => Ok(JobAuditTrigger::User),
+            "expired" => Ok(JobAuditTrigger::Expired),
+            "timedout" => Ok(JobAuditTrigger::TimedOut),
+            "retry" => Ok(JobAuditTrigger::Retry),
+            "workerlost" => Ok(JobAuditTrigger::WorkerLost),
+            _ => Err(ProtocolError::BadJobAuditTrigger(value.to_string())),
+        }
+    }
+}
+
+serialize_enum!(JobAuditTrigger, {
+    0 => "Scheduler",
+    1 => "Worker",
+    2 => "User",
+    3 => "Expired",
+    4 => "TimedOut",
+    5 => "Retry",
+    6 => "WorkerLost",
+});
+
+deserialize_enum!(JobAuditTrigger);
+
+impl Serialize for JobAudit {
     fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        match *self as u64 {
-            0 => serializer.serialize_str("NotStarted"),
-            1 => serializer.serialize_str("InProgress"),
-            2 => serializer.serialize_str("Success"),
-            3 => serializer.serialize_str("Failure"),
-            4 => serializer.serialize_str("Skipped"),
-            5 => serializer.serialize_str("Canceled"),
-            _ => panic!("Unexpected enum value"),
+        let mut strukt = serializer.serialize_struct("job_audit", 10)?;
+        strukt.serialize_field("id", &self.get_id().to_string())?;
+        if self.has_job_id() {
+            strukt.serialize_field("job_id", &self.get_job_id().to_string())?;
+        }
+        if self.has_group_id() {
+            strukt.serialize_field("group_id", &self.get_group_id().to_string())?;
+        }
+        if self.has_from_state() {
+            strukt.serialize_field("from_state", self.get_from_state())?;
+        }
+        strukt.serialize_field("to_state", self.get_to_state())?;
+        strukt.serialize_field("trigger", &self.get_trigger())?;
+        if self.has_requester_id() {
+            strukt.serialize_field(
+                "requester_id",
+                &self.get_requester_id().to_string(),
+            )?;
+            strukt.serialize_field("requester_name", self.get_requester_name())?;
         }
+        if self.has_worker() {
+            strukt.serialize_field("worker", self.get_worker())?;
+        }
+        strukt.serialize_field(
+            "created_at",
+            &timestamp::normalize(self.get_created_at()).map_err(ser::Error::custom)?,
+        )?;
+        strukt.end()
+    }
+}
+
+impl Serialize for JobAuditResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("job_audit_response", 1)?;
+        strukt.serialize_field("audits", self.get_audits())?;
+        strukt.end()
+    }
+}
+
+impl Serialize for JobHistoryPruneResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("job_history_prune_response", 2)?;
+        strukt.serialize_field("groups_deleted", &self.get_groups_deleted())?;
+        strukt.serialize_field("jobs_deleted", &self.get_jobs_deleted())?;
+        strukt.end()
+    }
+}
+
+impl Serialize for OriginBuildUsage {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_build_usage", 5)?;
+        strukt.serialize_field("origin", self.get_origin())?;
+        strukt.serialize_field("workers", &self.get_workers())?;
+        strukt.serialize_field("max_workers", &self.get_max_workers())?;
+        strukt.serialize_field("builds_last_day", &self.get_builds_last_day())?;
+        strukt.serialize_field("max_builds_per_day", &self.get_max_builds_per_day())?;
+        strukt.end()
     }
 }
 
@@ -502,7 +1143,7 @@ impl Serialize for JobGroupProject {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("job_group_project", 4)?;
+        let mut strukt = serializer.serialize_struct("job_group_project", 7)?;
         strukt.serialize_field("name", &self.get_name())?;
         strukt.serialize_field("ident", &self.get_ident())?;
         strukt.serialize_field("state", &self.get_state())?;
@@ -510,6 +1151,24 @@ impl Serialize for JobGroupProject {
             "job_id",
             &self.get_job_id().to_string(),
         )?;
+        if self.has_build_started_at() {
+            strukt.serialize_field(
+                "build_started_at",
+                &timestamp::normalize(self.get_build_started_at()).map_err(ser::Error::custom)?,
+            )?;
+        }
+        if self.has_build_finished_at() {
+            strukt.serialize_field(
+                "build_finished_at",
+                &timestamp::normalize(self.get_build_finished_at()).map_err(ser::Error::custom)?,
+            )?;
+        }
+        if let Some(duration) = self.duration_seconds() {
+            strukt.serialize_field("duration_seconds", &duration)?;
+        }
+        if self.has_skip_reason() {
+            strukt.serialize_field("skip_reason", self.get_skip_reason())?;
+        }
         strukt.end()
     }
 }
@@ -519,15 +1178,73 @@ impl Serialize for JobGroup {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("job_group", 5)?;
+        let mut strukt = serializer.serialize_struct("job_group", 9)?;
         strukt.serialize_field("id", &self.get_id().to_string())?;
         strukt.serialize_field("state", &self.get_state())?;
+        strukt.serialize_field("priority", &self.get_priority())?;
         strukt.serialize_field("projects", &self.get_projects())?;
-        strukt.serialize_field("created_at", &self.get_created_at())?;
+        strukt.serialize_field(
+            "created_at",
+            &timestamp::normalize(self.get_created_at()).map_err(ser::Error::custom)?,
+        )?;
         strukt.serialize_field(
             "project_name",
             &self.get_project_name(),
         )?;
+        strukt.serialize_field("target", &self.get_target())?;
+        strukt.serialize_field("tags", self.get_tags())?;
+        if self.has_queue_position() {
+            strukt.serialize_field("queue_position", &self.get_queue_position())?;
+        }
+        strukt.end()
+    }
+}
+
+impl Serialize for JobGroupSchedule {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let spec = self.get_spec();
+        let mut strukt = serializer.serialize_struct("job_group_schedule", 14)?;
+        strukt.serialize_field("id", &self.get_id().to_string())?;
+        strukt.serialize_field("origin", spec.get_origin())?;
+        strukt.serialize_field("package", spec.get_package())?;
+        strukt.serialize_field("target", spec.get_target())?;
+        strukt.serialize_field("priority", &spec.get_priority())?;
+        strukt.serialize_field("tags", spec.get_tags())?;
+        strukt.serialize_field("packages", spec.get_packages())?;
+        strukt.serialize_field("deps_only", &spec.get_deps_only())?;
+        strukt.serialize_field("origin_only", &spec.get_origin_only())?;
+        strukt.serialize_field("package_only", &spec.get_package_only())?;
+        strukt.serialize_field("cron", self.get_cron())?;
+        if self.has_last_run_at() {
+            strukt.serialize_field(
+                "last_run_at",
+                &timestamp::normalize(self.get_last_run_at()).map_err(ser::Error::custom)?,
+            )?;
+        }
+        if self.has_next_run_at() {
+            strukt.serialize_field(
+                "next_run_at",
+                &timestamp::normalize(self.get_next_run_at()).map_err(ser::Error::custom)?,
+            )?;
+        }
+        strukt.serialize_field(
+            "created_at",
+            &timestamp::normalize(self.get_created_at()).map_err(ser::Error::custom)?,
+        )?;
+        strukt.end()
+    }
+}
+
+impl Serialize for JobGroupScheduleListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("job_group_schedule_list_response", 1)?;
+        strukt.serialize_field("schedules", self.get_schedules())?;
         strukt.end()
     }
 }
@@ -553,22 +1270,368 @@ impl Serialize for JobGraphPackageStats {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("job_graph_package_stats", 2)?;
+        let targets: BTreeMap<&str, &JobGraphPackageTargetStats> = self.get_targets()
+            .iter()
+            .map(|t| (t.get_target(), t))
+            .collect();
+
+        let mut strukt = serializer.serialize_struct("job_graph_package_stats", 4)?;
         strukt.serialize_field("plans", &self.get_plans())?;
         strukt.serialize_field("builds", &self.get_builds())?;
         strukt.serialize_field(
             "unique_packages",
             &self.get_unique_packages(),
         )?;
+        strukt.serialize_field("targets", &targets)?;
         strukt.end()
     }
 }
 
+impl Serialize for JobGraphPackageTargetStats {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("job_graph_package_target_stats", 3)?;
+        strukt.serialize_field("plans", &self.get_plans())?;
+        strukt.serialize_field("builds", &self.get_builds())?;
+        strukt.serialize_field(
+            "unique_packages",
+            &self.get_unique_packages(),
+        )?;
+        strukt.end()
+    }
+}
+
+// The structs below mirror the JSON rendered by the `Serialize` implementations above so that
+// clients can read API responses back into protocol messages.
+
+#[derive(Deserialize)]
+struct JobJson {
+    id: String,
+    created_at: String,
+    origin: String,
+    name: String,
+    version: Option<String>,
+    release: Option<String>,
+    build_started_at: Option<String>,
+    build_finished_at: Option<String>,
+    state: JobState,
+    #[serde(default)]
+    priority: JobPriority,
+    error: Option<JobError>,
+    channel: Option<String>,
+    target: Option<String>,
+    #[serde(default)]
+    retries: u32,
+    #[serde(default)]
+    max_retries: u32,
+    last_error_category: Option<JobErrorCategory>,
+    #[serde(default)]
+    tags: Vec<String>,
+    artifact: Option<JobArtifact>,
+    expires_at: Option<String>,
+    build_timeout: Option<u32>,
+    #[serde(default)]
+    worker_labels: Vec<String>,
+    verify_reproducible: Option<bool>,
+    reproducible: Option<bool>,
+}
+
+impl<'de> Deserialize<'de> for Job {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = JobJson::deserialize(deserializer)?;
+        let mut job = Job::new();
+        job.set_id(json.id.parse::<u64>().map_err(de::Error::custom)?);
+        job.set_created_at(timestamp::normalize(&json.created_at).map_err(de::Error::custom)?);
+        if let (Some(version), Some(release)) = (json.version, json.release) {
+            let ident = job.mut_package_ident();
+            ident.set_origin(json.origin.clone());
+            ident.set_name(json.name.clone());
+            ident.set_version(version);
+            ident.set_release(release);
+        }
+        {
+            let project = job.mut_project();
+            project.set_name(format!("{}/{}", json.origin, json.name));
+            project.set_origin_name(json.origin);
+            project.set_package_name(json.name);
+        }
+        if let Some(build_started_at) = json.build_started_at {
+            job.set_build_started_at(
+                timestamp::normalize(&build_started_at).map_err(de::Error::custom)?,
+            );
+        }
+        if let Some(build_finished_at) = json.build_finished_at {
+            job.set_build_finished_at(
+                timestamp::normalize(&build_finished_at).map_err(de::Error::custom)?,
+            );
+        }
+        job.set_state(json.state);
+        job.set_priority(json.priority);
+        if let Some(error) = json.error {
+            job.set_error(error);
+        }
+        if let Some(channel) = json.channel {
+            job.set_channel(channel);
+        }
+        if let Some(target) = json.target {
+            job.set_target(target);
+        }
+        job.set_retries(json.retries);
+        job.set_max_retries(json.max_retries);
+        if let Some(category) = json.last_error_category {
+            job.set_last_error_category(category);
+        }
+        job.set_tags(RepeatedField::from_vec(json.tags));
+        if let Some(artifact) = json.artifact {
+            job.set_artifact(artifact);
+        }
+        if let Some(expires_at) = json.expires_at {
+            job.set_expires_at(timestamp::normalize(&expires_at).map_err(de::Error::custom)?);
+        }
+        if let Some(build_timeout) = json.build_timeout {
+            job.set_build_timeout(build_timeout);
+        }
+        job.set_worker_labels(RepeatedField::from_vec(json.worker_labels));
+        if let Some(verify_reproducible) = json.verify_reproducible {
+            job.set_verify_reproducible(verify_reproducible);
+        }
+        if let Some(reproducible) = json.reproducible {
+            job.set_reproducible(reproducible);
+        }
+        Ok(job)
+    }
+}
+
+#[derive(Deserialize)]
+struct JobArtifactJson {
+    checksum: String,
+    size: u64,
+}
+
+impl<'de> Deserialize<'de> for JobArtifact {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = JobArtifactJson::deserialize(deserializer)?;
+        let mut artifact = JobArtifact::new();
+        artifact.set_checksum(json.checksum);
+        artifact.set_size(json.size);
+        Ok(artifact)
+    }
+}
+
+#[derive(Deserialize)]
+struct JobErrorJson {
+    code: ErrCode,
+    message: String,
+    #[serde(default)]
+    category: JobErrorCategory,
+}
+
+impl<'de> Deserialize<'de> for JobError {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = JobErrorJson::deserialize(deserializer)?;
+        let mut err = JobError::new();
+        err.set_code(json.code);
+        err.set_message(json.message);
+        err.set_category(json.category);
+        Ok(err)
+    }
+}
+
+#[derive(Deserialize)]
+struct ProjectJobsGetResponseJson {
+    jobs: Vec<Job>,
+}
+
+impl<'de> Deserialize<'de> for ProjectJobsGetResponse {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = ProjectJobsGetResponseJson::deserialize(deserializer)?;
+        let mut response = ProjectJobsGetResponse::new();
+        response.set_jobs(RepeatedField::from_vec(json.jobs));
+        Ok(response)
+    }
+}
+
+#[derive(Deserialize)]
+struct JobLogJson {
+    start: u64,
+    stop: u64,
+    content: Vec<String>,
+    is_complete: bool,
+}
+
+impl<'de> Deserialize<'de> for JobLog {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = JobLogJson::deserialize(deserializer)?;
+        let mut log = JobLog::new();
+        log.set_start(json.start);
+        log.set_stop(json.stop);
+        log.set_content(RepeatedField::from_vec(json.content));
+        log.set_is_complete(json.is_complete);
+        Ok(log)
+    }
+}
+
+#[derive(Deserialize)]
+struct JobGroupProjectJson {
+    name: String,
+    ident: String,
+    state: JobGroupProjectState,
+    job_id: String,
+    build_started_at: Option<String>,
+    build_finished_at: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for JobGroupProject {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = JobGroupProjectJson::deserialize(deserializer)?;
+        let mut project = JobGroupProject::new();
+        project.set_name(json.name);
+        project.set_ident(json.ident);
+        project.set_state(json.state);
+        project.set_job_id(json.job_id.parse::<u64>().map_err(de::Error::custom)?);
+        if let Some(build_started_at) = json.build_started_at {
+            project.set_build_started_at(
+                timestamp::normalize(&build_started_at).map_err(de::Error::custom)?,
+            );
+        }
+        if let Some(build_finished_at) = json.build_finished_at {
+            project.set_build_finished_at(
+                timestamp::normalize(&build_finished_at).map_err(de::Error::custom)?,
+            );
+        }
+        Ok(project)
+    }
+}
+
+#[derive(Deserialize)]
+struct JobGroupJson {
+    id: String,
+    state: JobGroupState,
+    #[serde(default)]
+    priority: JobPriority,
+    projects: Vec<JobGroupProject>,
+    created_at: String,
+    project_name: String,
+    #[serde(default)]
+    target: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    queue_position: Option<u32>,
+}
+
+impl<'de> Deserialize<'de> for JobGroup {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = JobGroupJson::deserialize(deserializer)?;
+        let mut group = JobGroup::new();
+        group.set_id(json.id.parse::<u64>().map_err(de::Error::custom)?);
+        group.set_state(json.state);
+        group.set_priority(json.priority);
+        group.set_projects(RepeatedField::from_vec(json.projects));
+        group.set_created_at(timestamp::normalize(&json.created_at).map_err(de::Error::custom)?);
+        group.set_project_name(json.project_name);
+        group.set_target(json.target);
+        group.set_tags(RepeatedField::from_vec(json.tags));
+        if let Some(queue_position) = json.queue_position {
+            group.set_queue_position(queue_position);
+        }
+        Ok(group)
+    }
+}
+
+#[derive(Deserialize)]
+struct JobGraphPackageReverseDependenciesJson {
+    origin: String,
+    name: String,
+    rdeps: Vec<String>,
+}
+
+impl<'de> Deserialize<'de> for JobGraphPackageReverseDependencies {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = JobGraphPackageReverseDependenciesJson::deserialize(deserializer)?;
+        let mut rdeps = JobGraphPackageReverseDependencies::new();
+        rdeps.set_origin(json.origin);
+        rdeps.set_name(json.name);
+        rdeps.set_rdeps(RepeatedField::from_vec(json.rdeps));
+        Ok(rdeps)
+    }
+}
+
+#[derive(Deserialize)]
+struct JobGraphPackageStatsJson {
+    plans: u64,
+    builds: u64,
+    unique_packages: u64,
+    #[serde(default)]
+    targets: BTreeMap<String, JobGraphPackageTargetStatsJson>,
+}
+
+#[derive(Deserialize)]
+struct JobGraphPackageTargetStatsJson {
+    plans: u64,
+    builds: u64,
+    unique_packages: u64,
+}
+
+impl<'de> Deserialize<'de> for JobGraphPackageStats {
+    fn deserialize<D>(deserializer: D) -> result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = JobGraphPackageStatsJson::deserialize(deserializer)?;
+        let mut stats = JobGraphPackageStats::new();
+        stats.set_plans(json.plans);
+        stats.set_builds(json.builds);
+        stats.set_unique_packages(json.unique_packages);
+        let targets = json.targets
+            .into_iter()
+            .map(|(target, t)| {
+                let mut target_stats = JobGraphPackageTargetStats::new();
+                target_stats.set_target(target);
+                target_stats.set_plans(t.plans);
+                target_stats.set_builds(t.builds);
+                target_stats.set_unique_packages(t.unique_packages);
+                target_stats
+            })
+            .collect();
+        stats.set_targets(RepeatedField::from_vec(targets));
+        Ok(stats)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use std::iter::FromIterator;
 
+    use serde_json;
+    use net;
+
     #[test]
     fn test_ansi_stripping() {
         let mut log = JobLog::new();
@@ -587,7 +1650,7 @@ mod tests {
         let content = RepeatedField::from_iter(input_lines);
         log.set_content(content);
 
-        log.strip_ansi();
+        log.strip_ansi(&mut AnsiFilter::new());
 
         let stripped_lines: Vec<String> = log.get_content()
             .into_iter()
@@ -603,4 +1666,446 @@ mod tests {
         assert_eq!(stripped_lines, expected);
     }
 
+    #[test]
+    fn test_ansi_stripping_across_chunks() {
+        let mut first = JobLog::new();
+        first.set_content(RepeatedField::from_vec(
+            vec!["\x1b[1;33m» Installing core/hab-backline\x1b[".to_string()],
+        ));
+        let mut second = JobLog::new();
+        second.set_content(RepeatedField::from_vec(
+            vec!["0m\x1b[1;32m↓ Downloading\x1b[0m core/hab-backline".to_string()],
+        ));
+
+        let mut filter = AnsiFilter::new();
+        first.strip_ansi(&mut filter);
+        second.strip_ansi(&mut filter);
+
+        assert_eq!(first.get_content(), &["» Installing core/hab-backline".to_string()]);
+        assert_eq!(second.get_content(), &["↓ Downloading core/hab-backline".to_string()]);
+    }
+
+    #[test]
+    fn test_job_priority_from_spec() {
+        let mut spec = JobSpec::new();
+        let job: Job = spec.clone().into();
+        assert_eq!(job.get_priority(), JobPriority::Normal);
+
+        spec.set_priority(JobPriority::Critical);
+        let job: Job = spec.into();
+        assert_eq!(job.get_priority(), JobPriority::Critical);
+    }
+
+    #[test]
+    fn test_job_tags_from_spec() {
+        let mut spec = JobSpec::new();
+        let job: Job = spec.clone().into();
+        assert!(job.get_tags().is_empty());
+
+        spec.set_tags(RepeatedField::from_vec(vec!["nightly".to_string()]));
+        let job: Job = spec.into();
+        assert_eq!(job.get_tags(), &["nightly".to_string()]);
+    }
+
+    #[test]
+    fn test_job_target_from_spec() {
+        let mut spec = JobSpec::new();
+        let job: Job = spec.clone().into();
+        assert!(!job.has_target());
+
+        spec.set_target("x86_64-windows".to_string());
+        let job: Job = spec.into();
+        assert_eq!(job.get_target(), "x86_64-windows");
+    }
+
+    #[test]
+    fn test_job_build_timeout_from_spec() {
+        let mut spec = JobSpec::new();
+        spec.mut_project().set_build_timeout(0);
+        let job: Job = spec.clone().into();
+        assert!(!job.has_build_timeout());
+
+        spec.mut_project().set_build_timeout(180);
+        let job: Job = spec.into();
+        assert_eq!(job.get_build_timeout(), 180);
+    }
+
+    #[test]
+    fn test_job_kind_from_spec() {
+        let mut spec = JobSpec::new();
+        let job: Job = spec.clone().into();
+        assert_eq!(job.get_kind(), JobKind::Build);
+
+        spec.set_kind(JobKind::DockerExport);
+        let job: Job = spec.into();
+        assert_eq!(job.get_kind(), JobKind::DockerExport);
+    }
+
+    #[test]
+    fn test_job_worker_labels_from_spec() {
+        let mut spec = JobSpec::new();
+        let job: Job = spec.clone().into();
+        assert!(job.get_worker_labels().is_empty());
+
+        spec.mut_project().set_worker_labels(
+            RepeatedField::from_vec(vec!["big-memory".to_string()]),
+        );
+        let job: Job = spec.into();
+        assert_eq!(job.get_worker_labels(), &["big-memory".to_string()]);
+    }
+
+    #[test]
+    fn test_job_verify_reproducible_from_spec() {
+        let mut spec = JobSpec::new();
+        let job: Job = spec.clone().into();
+        assert!(!job.has_verify_reproducible());
+
+        spec.mut_project().set_verify_reproducible(true);
+        let job: Job = spec.into();
+        assert!(job.get_verify_reproducible());
+    }
+
+    #[test]
+    fn test_job_ttl() {
+        let mut job = Job::new();
+        job.set_ttl(60);
+        let expires_at = timestamp::parse(job.get_expires_at()).unwrap();
+        let minutes = (expires_at - UTC::now()).num_minutes();
+        assert!(minutes > 55 && minutes <= 60);
+    }
+
+    #[test]
+    fn test_worker_list_json() {
+        let mut busy = WorkerStatus::new();
+        busy.set_ident("worker-1".to_string());
+        busy.set_targets(RepeatedField::from_vec(vec!["x86_64-linux".to_string()]));
+        busy.set_labels(RepeatedField::from_vec(vec!["big-memory".to_string()]));
+        busy.set_state(WorkerState::Busy);
+        busy.set_capacity(2);
+        busy.set_job_id(1234567890123);
+        busy.set_job_ids(vec![1234567890123, 1234567890124]);
+        busy.set_last_heartbeat("2017-06-01T02:00:00+02:00".to_string());
+        let mut ready = WorkerStatus::new();
+        ready.set_ident("worker-2".to_string());
+        ready.set_state(WorkerState::Ready);
+        let mut list = WorkerListResponse::new();
+        list.set_workers(RepeatedField::from_vec(vec![busy, ready]));
+
+        let json = serde_json::to_value(&list).unwrap();
+        assert_eq!(json["workers"][0]["state"], "Busy");
+        assert_eq!(json["workers"][0]["labels"][0], "big-memory");
+        assert_eq!(json["workers"][0]["capacity"], 2);
+        assert_eq!(json["workers"][0]["job_id"], "1234567890123");
+        assert_eq!(json["workers"][0]["job_ids"][1], "1234567890124");
+        assert_eq!(
+            json["workers"][0]["last_heartbeat"],
+            "2017-06-01T00:00:00+00:00"
+        );
+        assert_eq!(json["workers"][1]["state"], "Ready");
+        assert!(json["workers"][1].get("job_id").is_none());
+        assert!(json["workers"][1]["job_ids"].as_array().unwrap().is_empty());
+        assert!(json["workers"][1].get("last_heartbeat").is_none());
+    }
+
+    #[test]
+    fn test_job_group_schedule_json() {
+        let mut schedule = JobGroupSchedule::new();
+        schedule.set_id(1234567890123);
+        schedule.set_cron("0 2 * * *".to_string());
+        schedule.set_created_at("2017-06-01T00:00:00Z".to_string());
+        schedule.set_next_run_at("2017-06-01T02:00:00+00:00".to_string());
+        {
+            let spec = schedule.mut_spec();
+            spec.set_origin("core".to_string());
+            spec.set_package("nginx".to_string());
+            spec.set_target("x86_64-linux".to_string());
+            spec.set_tags(RepeatedField::from_vec(vec!["nightly".to_string()]));
+        }
+
+        let json = serde_json::to_value(&schedule).unwrap();
+        assert_eq!(json["id"], "1234567890123");
+        assert_eq!(json["origin"], "core");
+        assert_eq!(json["package"], "nginx");
+        assert_eq!(json["priority"], "Normal");
+        assert_eq!(json["tags"][0], "nightly");
+        assert_eq!(json["cron"], "0 2 * * *");
+        assert_eq!(json["created_at"], "2017-06-01T00:00:00+00:00");
+        assert_eq!(json["next_run_at"], "2017-06-01T02:00:00+00:00");
+        assert!(json.get("last_run_at").is_none());
+    }
+
+    #[test]
+    fn test_job_priority_from_str() {
+        assert_eq!("high".parse::<JobPriority>().unwrap(), JobPriority::High);
+        assert_eq!(
+            "Critical".parse::<JobPriority>().unwrap(),
+            JobPriority::Critical
+        );
+        assert!("urgent".parse::<JobPriority>().is_err());
+    }
+
+    #[test]
+    fn test_job_audit_json() {
+        let mut audit = JobAudit::new();
+        audit.set_id(7);
+        audit.set_job_id(42);
+        audit.set_from_state("Processing".to_string());
+        audit.set_to_state("CancelPending".to_string());
+        audit.set_trigger("user".parse::<JobAuditTrigger>().unwrap());
+        audit.set_requester_id(3);
+        audit.set_requester_name("bobo".to_string());
+        audit.set_created_at("2017-06-01T10:00:00Z".to_string());
+
+        let json = serde_json::to_value(&audit).unwrap();
+        assert_eq!(json["job_id"], "42");
+        assert_eq!(json["from_state"], "Processing");
+        assert_eq!(json["trigger"], "User");
+        assert_eq!(json["requester_id"], "3");
+        assert!(json.get("group_id").is_none());
+        assert!(json.get("worker").is_none());
+        assert!("bored".parse::<JobAuditTrigger>().is_err());
+    }
+
+    #[test]
+    fn test_job_json_round_trip() {
+        let mut job = Job::new();
+        job.set_id(1234567890123);
+        job.set_created_at("2017-06-01T00:00:00+00:00".to_string());
+        {
+            let project = job.mut_project();
+            project.set_name("core/nginx".to_string());
+            project.set_origin_name("core".to_string());
+            project.set_package_name("nginx".to_string());
+        }
+        {
+            let ident = job.mut_package_ident();
+            ident.set_origin("core".to_string());
+            ident.set_name("nginx".to_string());
+            ident.set_version("1.13.0".to_string());
+            ident.set_release("20170601000000".to_string());
+        }
+        job.set_build_started_at("2017-06-01T00:01:00+00:00".to_string());
+        job.set_build_finished_at("2017-06-01T00:02:00+00:00".to_string());
+        job.set_expires_at("2017-06-02T00:00:00+00:00".to_string());
+        job.set_build_timeout(90);
+        {
+            let artifact = job.mut_artifact();
+            artifact.set_checksum("0123456789abcdef".to_string());
+            artifact.set_size(1048576);
+        }
+        job.set_state(JobState::Failed);
+        job.set_priority(JobPriority::High);
+        job.set_error(net::err(ErrCode::BUILD, "wk:run:5").into());
+        job.set_channel("bldr-1234567890123".to_string());
+        job.set_target("x86_64-linux".to_string());
+        job.set_retries(1);
+        job.set_max_retries(3);
+        job.set_last_error_category(JobErrorCategory::Plan);
+        job.set_tags(RepeatedField::from_vec(
+            vec!["security-rebuild".to_string(), "nightly".to_string()],
+        ));
+        job.set_worker_labels(RepeatedField::from_vec(vec!["big-memory".to_string()]));
+        job.set_verify_reproducible(true);
+        job.set_reproducible(false);
+
+        let json = serde_json::to_string(&job).unwrap();
+        let parsed: Job = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, job);
+    }
+
+    #[test]
+    fn test_job_json_malformed_timestamp() {
+        let mut job = Job::new();
+        job.set_id(1);
+        job.set_created_at("last tuesday".to_string());
+        assert!(serde_json::to_string(&job).is_err());
+
+        job.set_created_at("2017-06-01T02:00:00+02:00".to_string());
+        let json = serde_json::to_value(&job).unwrap();
+        assert_eq!(json["created_at"], "2017-06-01T00:00:00+00:00");
+
+        let bad = json.to_string().replace("2017-06-01T00:00:00+00:00", "2017-06-01 00:00");
+        assert!(serde_json::from_str::<Job>(&bad).is_err());
+    }
+
+    #[test]
+    fn test_job_json_round_trip_minimal() {
+        let mut job = Job::new();
+        job.set_id(42);
+        job.set_created_at("2017-06-01T00:00:00+00:00".to_string());
+        {
+            let project = job.mut_project();
+            project.set_name("core/nginx".to_string());
+            project.set_origin_name("core".to_string());
+            project.set_package_name("nginx".to_string());
+        }
+        job.set_state(JobState::Pending);
+        job.set_priority(JobPriority::Normal);
+        job.set_retries(0);
+        job.set_max_retries(0);
+
+        let json = serde_json::to_string(&job).unwrap();
+        let parsed: Job = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, job);
+        assert!(!parsed.has_package_ident());
+        assert!(!parsed.has_error());
+    }
+
+    #[test]
+    fn test_job_log_json_round_trip() {
+        let mut log = JobLog::new();
+        log.set_start(0);
+        log.set_stop(2);
+        log.set_content(RepeatedField::from_vec(
+            vec!["one".to_string(), "two".to_string()],
+        ));
+        log.set_is_complete(true);
+
+        let json = serde_json::to_string(&log).unwrap();
+        let parsed: JobLog = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, log);
+    }
+
+    #[test]
+    fn test_job_group_json_round_trip() {
+        let mut project = JobGroupProject::new();
+        project.set_name("core/nginx".to_string());
+        project.set_ident("core/nginx/1.13.0/20170601000000".to_string());
+        project.set_state(JobGroupProjectState::Success);
+        project.set_job_id(1234567890123);
+        project.set_build_started_at("2017-06-01T00:01:00+00:00".to_string());
+        project.set_build_finished_at("2017-06-01T00:03:30+00:00".to_string());
+
+        let mut group = JobGroup::new();
+        group.set_id(9876543210987);
+        group.set_state(JobGroupState::GroupComplete);
+        group.set_priority(JobPriority::Critical);
+        group.set_projects(RepeatedField::from_vec(vec![project]));
+        group.set_created_at("2017-06-01T00:00:00+00:00".to_string());
+        group.set_project_name("core/nginx".to_string());
+        group.set_target("x86_64-linux".to_string());
+
+        let json = serde_json::to_string(&group).unwrap();
+        assert!(!json.contains("queue_position"));
+        let parsed: JobGroup = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, group);
+
+        group.set_state(JobGroupState::GroupQueued);
+        group.set_queue_position(3);
+        let json = serde_json::to_string(&group).unwrap();
+        assert!(json.contains(r#""queue_position":3"#));
+        let parsed: JobGroup = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, group);
+    }
+
+    #[test]
+    fn test_job_group_project_duration() {
+        let mut project = JobGroupProject::new();
+        assert_eq!(project.duration_seconds(), None);
+        project.set_build_started_at("2017-06-01T00:01:00+00:00".to_string());
+        assert_eq!(project.duration_seconds(), None);
+        project.set_build_finished_at("2017-06-01T00:03:30+00:00".to_string());
+        assert_eq!(project.duration_seconds(), Some(150));
+
+        let json = serde_json::to_value(&project).unwrap();
+        assert_eq!(json["duration_seconds"], 150);
+    }
+
+    #[test]
+    fn test_job_graph_json_round_trip() {
+        let mut stats = JobGraphPackageStats::new();
+        stats.set_plans(10);
+        stats.set_builds(20);
+        stats.set_unique_packages(5);
+        let mut linux = JobGraphPackageTargetStats::new();
+        linux.set_target("x86_64-linux".to_string());
+        linux.set_plans(8);
+        linux.set_builds(18);
+        linux.set_unique_packages(5);
+        let mut windows = JobGraphPackageTargetStats::new();
+        windows.set_target("x86_64-windows".to_string());
+        windows.set_plans(2);
+        windows.set_builds(2);
+        windows.set_unique_packages(2);
+        stats.set_targets(RepeatedField::from_vec(vec![linux, windows]));
+        let json = serde_json::to_string(&stats).unwrap();
+        let parsed: JobGraphPackageStats = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, stats);
+
+        let mut rdeps = JobGraphPackageReverseDependencies::new();
+        rdeps.set_origin("core".to_string());
+        rdeps.set_name("glibc".to_string());
+        rdeps.set_rdeps(RepeatedField::from_vec(vec!["core/gcc".to_string()]));
+        let json = serde_json::to_string(&rdeps).unwrap();
+        let parsed: JobGraphPackageReverseDependencies = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, rdeps);
+    }
+
+    #[test]
+    fn test_job_state_json_rejects_unknown() {
+        assert!(serde_json::from_str::<JobState>("\"Exploded\"").is_err());
+        assert_eq!(
+            serde_json::from_str::<JobState>("\"CancelPending\"").unwrap(),
+            JobState::CancelPending
+        );
+    }
+
+    #[test]
+    fn test_job_error_category() {
+        assert_eq!(
+            JobErrorCategory::from(ErrCode::VCS_CLONE),
+            JobErrorCategory::Transient
+        );
+        assert_eq!(
+            JobErrorCategory::from(ErrCode::BUILD),
+            JobErrorCategory::Plan
+        );
+        assert_eq!(
+            JobErrorCategory::from(ErrCode::WORKSPACE_SETUP),
+            JobErrorCategory::Infrastructure
+        );
+        assert_eq!(
+            JobErrorCategory::from(ErrCode::BUILD_OOM),
+            JobErrorCategory::ResourceLimit
+        );
+        assert_eq!(
+            JobErrorCategory::from(ErrCode::INVALID_INTEGRATIONS),
+            JobErrorCategory::Permanent
+        );
+    }
+
+    #[test]
+    fn test_job_error_from_net_error() {
+        let err: JobError = net::err(ErrCode::VCS_CLONE, "wk:run:4").into();
+        assert_eq!(err.get_code(), ErrCode::VCS_CLONE);
+        assert_eq!(err.get_message(), "wk:run:4");
+        assert_eq!(err.get_category(), JobErrorCategory::Transient);
+        assert!(err.is_retryable());
+
+        let err: JobError = net::err(ErrCode::BUILD, "wk:run:5").into();
+        assert!(!err.is_retryable());
+    }
+
+    #[test]
+    fn test_job_error_json() {
+        let err: JobError = net::err(ErrCode::BUILD, "wk:run:5").into();
+        let json = serde_json::to_string(&err).unwrap();
+        assert_eq!(
+            json,
+            r#"{"code":1004,"message":"wk:run:5","category":"Plan","retryable":false}"#
+        );
+        let parsed: JobError = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, err);
+    }
+
+    #[test]
+    fn test_job_can_retry() {
+        let mut job = Job::new();
+        assert!(!job.can_retry());
+        job.set_max_retries(2);
+        job.set_retries(1);
+        assert!(job.can_retry());
+        job.set_retries(2);
+        assert!(!job.can_retry());
+    }
 }
@@ -15,17 +15,21 @@
 #![cfg_attr(feature="clippy", feature(plugin))]
 #![cfg_attr(feature="clippy", plugin(clippy))]
 
+extern crate chrono;
 extern crate fnv;
 extern crate habitat_core as hab_core;
-#[macro_use]
-extern crate lazy_static;
 extern crate protobuf;
-extern crate regex;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+#[cfg(test)]
+extern crate serde_json;
 extern crate time;
 
+#[macro_use]
+mod macros;
+
+pub mod ansi;
 pub mod error;
 pub mod jobsrv;
 pub mod message;
@@ -34,8 +38,11 @@ pub mod routesrv;
 pub mod search;
 pub mod sessionsrv;
 pub mod sharding;
+pub mod timestamp;
+pub mod version;
 pub mod originsrv;
 
 pub use self::error::{ProtocolError, ProtocolResult};
 pub use self::message::{Message, Protocol, Persistable, Routable, RouteKey};
 pub use self::sharding::{ShardId, SHARD_COUNT, InstaId};
+pub use self::version::{MIN_PROTOCOL_VERSION, PROTOCOL_VERSION};
@@ -0,0 +1,86 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// Implements `Serialize` for a protobuf enum, rendering each listed value as the given string.
+///
+/// Values without a mapping, such as variants added by a newer version of the protocol, are
+/// rendered as `"Unknown(<n>)"` instead of panicking so that older nodes can still respond.
+///
+/// ```ignore
+/// serialize_enum!(JobState, {
+///     0 => "Pending",
+///     1 => "Processing",
+/// });
+/// ```
+#[macro_export]
+macro_rules! serialize_enum {
+    ($t:ty, { $($v:pat => $s:expr),* $(,)* }) => {
+        impl ::serde::Serialize for $t {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                match *self as u64 {
+                    $($v => serializer.serialize_str($s),)*
+                    n => serializer.serialize_str(&format!("Unknown({})", n)),
+                }
+            }
+        }
+    };
+}
+
+/// Implements `Deserialize` for a protobuf enum from its string representation, using the
+/// enum's `FromStr` implementation.
+#[macro_export]
+macro_rules! deserialize_enum {
+    ($t:ty) => {
+        impl<'de> ::serde::Deserialize<'de> for $t {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let value = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                value.parse::<$t>().map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json;
+
+    #[derive(Clone, Copy)]
+    enum Color {
+        Red = 0,
+        Blue = 1,
+    }
+
+    serialize_enum!(Color, {
+        0 => "Red",
+    });
+
+    #[test]
+    fn serialize_known_value() {
+        assert_eq!(serde_json::to_string(&Color::Red).unwrap(), "\"Red\"");
+    }
+
+    #[test]
+    fn serialize_unknown_value() {
+        assert_eq!(
+            serde_json::to_string(&Color::Blue).unwrap(),
+            "\"Unknown(1)\""
+        );
+    }
+}
@@ -25,6 +25,7 @@ use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 pub struct WorkerCommand {
     // message fields
     op: ::std::option::Option<WorkerOperation>,
+    protocol_version: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -74,6 +75,33 @@ impl WorkerCommand {
     fn mut_op_for_reflect(&mut self) -> &mut ::std::option::Option<WorkerOperation> {
         &mut self.op
     }
+
+    // optional uint32 protocol_version = 2;
+
+    pub fn clear_protocol_version(&mut self) {
+        self.protocol_version = ::std::option::Option::None;
+    }
+
+    pub fn has_protocol_version(&self) -> bool {
+        self.protocol_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_protocol_version(&mut self, v: u32) {
+        self.protocol_version = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version.unwrap_or(0)
+    }
+
+    fn get_protocol_version_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.protocol_version
+    }
+
+    fn mut_protocol_version_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.protocol_version
+    }
 }
 
 impl ::protobuf::Message for WorkerCommand {
@@ -92,6 +120,13 @@ impl ::protobuf::Message for WorkerCommand {
                     let tmp = is.read_enum()?;
                     self.op = ::std::option::Option::Some(tmp);
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.protocol_version = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -107,6 +142,9 @@ impl ::protobuf::Message for WorkerCommand {
         if let Some(v) = self.op {
             my_size += ::protobuf::rt::enum_size(1, v);
         }
+        if let Some(v) = self.protocol_version {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -116,6 +154,9 @@ impl ::protobuf::Message for WorkerCommand {
         if let Some(v) = self.op {
             os.write_enum(1, v.value())?;
         }
+        if let Some(v) = self.protocol_version {
+            os.write_uint32(2, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -165,6 +206,11 @@ impl ::protobuf::MessageStatic for WorkerCommand {
                     WorkerCommand::get_op_for_reflect,
                     WorkerCommand::mut_op_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "protocol_version",
+                    WorkerCommand::get_protocol_version_for_reflect,
+                    WorkerCommand::mut_protocol_version_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<WorkerCommand>(
                     "WorkerCommand",
                     fields,
@@ -178,6 +224,7 @@ impl ::protobuf::MessageStatic for WorkerCommand {
 impl ::protobuf::Clear for WorkerCommand {
     fn clear(&mut self) {
         self.clear_op();
+        self.clear_protocol_version();
         self.unknown_fields.clear();
     }
 }
@@ -200,6 +247,18 @@ pub struct Heartbeat {
     endpoint: ::protobuf::SingularField<::std::string::String>,
     os: ::std::option::Option<Os>,
     state: ::std::option::Option<WorkerState>,
+    target: ::protobuf::SingularField<::std::string::String>,
+    disk_free: ::std::option::Option<u64>,
+    jobs_in_flight: ::std::option::Option<u32>,
+    targets: ::protobuf::RepeatedField<::std::string::String>,
+    protocol_version: ::std::option::Option<u32>,
+    labels: ::protobuf::RepeatedField<::std::string::String>,
+    capacity: ::std::option::Option<u32>,
+    job_ids: ::std::vec::Vec<u64>,
+    draining: ::std::option::Option<bool>,
+    leaving: ::std::option::Option<bool>,
+    artifact_cache_hits: ::std::option::Option<u64>,
+    artifact_cache_misses: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -320,289 +379,368 @@ impl Heartbeat {
     fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<WorkerState> {
         &mut self.state
     }
-}
 
-impl ::protobuf::Message for Heartbeat {
-    fn is_initialized(&self) -> bool {
-        true
+    // optional string target = 4;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.endpoint)?;
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_enum()?;
-                    self.os = ::std::option::Option::Some(tmp);
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_enum()?;
-                    self.state = ::std::option::Option::Some(tmp);
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if let Some(ref v) = self.endpoint.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
-        }
-        if let Some(v) = self.os {
-            my_size += ::protobuf::rt::enum_size(2, v);
-        }
-        if let Some(v) = self.state {
-            my_size += ::protobuf::rt::enum_size(3, v);
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.endpoint.as_ref() {
-            os.write_string(1, &v)?;
-        }
-        if let Some(v) = self.os {
-            os.write_enum(2, v.value())?;
-        }
-        if let Some(v) = self.state {
-            os.write_enum(3, v.value())?;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
         }
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
+        self.target.as_mut().unwrap()
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
-    }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
-    }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
-        self
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    // optional uint64 disk_free = 5;
+
+    pub fn clear_disk_free(&mut self) {
+        self.disk_free = ::std::option::Option::None;
     }
-}
 
-impl ::protobuf::MessageStatic for Heartbeat {
-    fn new() -> Heartbeat {
-        Heartbeat::new()
+    pub fn has_disk_free(&self) -> bool {
+        self.disk_free.is_some()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Heartbeat>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "endpoint",
-                    Heartbeat::get_endpoint_for_reflect,
-                    Heartbeat::mut_endpoint_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Os>>(
-                    "os",
-                    Heartbeat::get_os_for_reflect,
-                    Heartbeat::mut_os_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerState>>(
-                    "state",
-                    Heartbeat::get_state_for_reflect,
-                    Heartbeat::mut_state_for_reflect,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<Heartbeat>(
-                    "Heartbeat",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
+    // Param is passed by value, moved
+    pub fn set_disk_free(&mut self, v: u64) {
+        self.disk_free = ::std::option::Option::Some(v);
     }
-}
 
-impl ::protobuf::Clear for Heartbeat {
-    fn clear(&mut self) {
-        self.clear_endpoint();
-        self.clear_os();
-        self.clear_state();
-        self.unknown_fields.clear();
+    pub fn get_disk_free(&self) -> u64 {
+        self.disk_free.unwrap_or(0)
     }
-}
 
-impl ::std::fmt::Debug for Heartbeat {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    fn get_disk_free_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.disk_free
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for Heartbeat {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn mut_disk_free_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.disk_free
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct BusyWorker {
-    // message fields
-    ident: ::protobuf::SingularField<::std::string::String>,
-    job_id: ::std::option::Option<u64>,
-    quarantined: ::std::option::Option<bool>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
-}
+    // optional uint32 jobs_in_flight = 6;
 
-// see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for BusyWorker {}
+    pub fn clear_jobs_in_flight(&mut self) {
+        self.jobs_in_flight = ::std::option::Option::None;
+    }
 
-impl BusyWorker {
-    pub fn new() -> BusyWorker {
-        ::std::default::Default::default()
+    pub fn has_jobs_in_flight(&self) -> bool {
+        self.jobs_in_flight.is_some()
     }
 
-    pub fn default_instance() -> &'static BusyWorker {
-        static mut instance: ::protobuf::lazy::Lazy<BusyWorker> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const BusyWorker,
-        };
-        unsafe {
-            instance.get(BusyWorker::new)
-        }
+    // Param is passed by value, moved
+    pub fn set_jobs_in_flight(&mut self, v: u32) {
+        self.jobs_in_flight = ::std::option::Option::Some(v);
     }
 
-    // optional string ident = 1;
+    pub fn get_jobs_in_flight(&self) -> u32 {
+        self.jobs_in_flight.unwrap_or(0)
+    }
 
-    pub fn clear_ident(&mut self) {
-        self.ident.clear();
+    fn get_jobs_in_flight_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.jobs_in_flight
     }
 
-    pub fn has_ident(&self) -> bool {
-        self.ident.is_some()
+    fn mut_jobs_in_flight_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.jobs_in_flight
+    }
+
+    // repeated string targets = 7;
+
+    pub fn clear_targets(&mut self) {
+        self.targets.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_ident(&mut self, v: ::std::string::String) {
-        self.ident = ::protobuf::SingularField::some(v);
+    pub fn set_targets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.targets = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ident(&mut self) -> &mut ::std::string::String {
-        if self.ident.is_none() {
-            self.ident.set_default();
-        }
-        self.ident.as_mut().unwrap()
+    pub fn mut_targets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.targets
     }
 
     // Take field
-    pub fn take_ident(&mut self) -> ::std::string::String {
-        self.ident.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_targets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.targets, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_ident(&self) -> &str {
-        match self.ident.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_targets(&self) -> &[::std::string::String] {
+        &self.targets
     }
 
-    fn get_ident_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.ident
+    fn get_targets_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.targets
     }
 
-    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.ident
+    fn mut_targets_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.targets
     }
 
-    // optional uint64 job_id = 2;
+    // optional uint32 protocol_version = 8;
 
-    pub fn clear_job_id(&mut self) {
-        self.job_id = ::std::option::Option::None;
+    pub fn clear_protocol_version(&mut self) {
+        self.protocol_version = ::std::option::Option::None;
     }
 
-    pub fn has_job_id(&self) -> bool {
-        self.job_id.is_some()
+    pub fn has_protocol_version(&self) -> bool {
+        self.protocol_version.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_job_id(&mut self, v: u64) {
-        self.job_id = ::std::option::Option::Some(v);
+    pub fn set_protocol_version(&mut self, v: u32) {
+        self.protocol_version = ::std::option::Option::Some(v);
     }
 
-    pub fn get_job_id(&self) -> u64 {
-        self.job_id.unwrap_or(0)
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version.unwrap_or(0)
     }
 
-    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.job_id
+    fn get_protocol_version_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.protocol_version
     }
 
-    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.job_id
+    fn mut_protocol_version_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.protocol_version
     }
 
-    // optional bool quarantined = 3;
+    // repeated string labels = 9;
 
-    pub fn clear_quarantined(&mut self) {
-        self.quarantined = ::std::option::Option::None;
+    pub fn clear_labels(&mut self) {
+        self.labels.clear();
     }
 
-    pub fn has_quarantined(&self) -> bool {
-        self.quarantined.is_some()
+    // Param is passed by value, moved
+    pub fn set_labels(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.labels = v;
     }
 
-    // Param is passed by value, moved
-    pub fn set_quarantined(&mut self, v: bool) {
-        self.quarantined = ::std::option::Option::Some(v);
+    // Mutable pointer to the field.
+    pub fn mut_labels(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.labels
     }
 
-    pub fn get_quarantined(&self) -> bool {
-        self.quarantined.unwrap_or(false)
+    // Take field
+    pub fn take_labels(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.labels, ::protobuf::RepeatedField::new())
     }
 
-    fn get_quarantined_for_reflect(&self) -> &::std::option::Option<bool> {
-        &self.quarantined
+    pub fn get_labels(&self) -> &[::std::string::String] {
+        &self.labels
     }
 
-    fn mut_quarantined_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
-        &mut self.quarantined
+    fn get_labels_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.labels
+    }
+
+    fn mut_labels_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.labels
+    }
+
+    // optional uint32 capacity = 10;
+
+    pub fn clear_capacity(&mut self) {
+        self.capacity = ::std::option::Option::None;
+    }
+
+    pub fn has_capacity(&self) -> bool {
+        self.capacity.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_capacity(&mut self, v: u32) {
+        self.capacity = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_capacity(&self) -> u32 {
+        self.capacity.unwrap_or(0)
+    }
+
+    fn get_capacity_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.capacity
+    }
+
+    fn mut_capacity_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.capacity
+    }
+
+    // repeated uint64 job_ids = 11;
+
+    pub fn clear_job_ids(&mut self) {
+        self.job_ids.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_ids(&mut self, v: ::std::vec::Vec<u64>) {
+        self.job_ids = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_job_ids(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.job_ids
+    }
+
+    // Take field
+    pub fn take_job_ids(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.job_ids, ::std::vec::Vec::new())
+    }
+
+    pub fn get_job_ids(&self) -> &[u64] {
+        &self.job_ids
+    }
+
+    fn get_job_ids_for_reflect(&self) -> &::std::vec::Vec<u64> {
+        &self.job_ids
+    }
+
+    fn mut_job_ids_for_reflect(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.job_ids
+    }
+
+    // optional bool draining = 12;
+
+    pub fn clear_draining(&mut self) {
+        self.draining = ::std::option::Option::None;
+    }
+
+    pub fn has_draining(&self) -> bool {
+        self.draining.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_draining(&mut self, v: bool) {
+        self.draining = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_draining(&self) -> bool {
+        self.draining.unwrap_or(false)
+    }
+
+    fn get_draining_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.draining
+    }
+
+    fn mut_draining_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.draining
+    }
+
+    // optional bool leaving = 13;
+
+    pub fn clear_leaving(&mut self) {
+        self.leaving = ::std::option::Option::None;
+    }
+
+    pub fn has_leaving(&self) -> bool {
+        self.leaving.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leaving(&mut self, v: bool) {
+        self.leaving = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_leaving(&self) -> bool {
+        self.leaving.unwrap_or(false)
+    }
+
+    fn get_leaving_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.leaving
+    }
+
+    fn mut_leaving_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.leaving
+    }
+
+    // optional uint64 artifact_cache_hits = 14;
+
+    pub fn clear_artifact_cache_hits(&mut self) {
+        self.artifact_cache_hits = ::std::option::Option::None;
+    }
+
+    pub fn has_artifact_cache_hits(&self) -> bool {
+        self.artifact_cache_hits.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact_cache_hits(&mut self, v: u64) {
+        self.artifact_cache_hits = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_artifact_cache_hits(&self) -> u64 {
+        self.artifact_cache_hits.unwrap_or(0)
+    }
+
+    fn get_artifact_cache_hits_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.artifact_cache_hits
+    }
+
+    fn mut_artifact_cache_hits_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.artifact_cache_hits
+    }
+
+    // optional uint64 artifact_cache_misses = 15;
+
+    pub fn clear_artifact_cache_misses(&mut self) {
+        self.artifact_cache_misses = ::std::option::Option::None;
+    }
+
+    pub fn has_artifact_cache_misses(&self) -> bool {
+        self.artifact_cache_misses.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact_cache_misses(&mut self, v: u64) {
+        self.artifact_cache_misses = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_artifact_cache_misses(&self) -> u64 {
+        self.artifact_cache_misses.unwrap_or(0)
+    }
+
+    fn get_artifact_cache_misses_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.artifact_cache_misses
+    }
+
+    fn mut_artifact_cache_misses_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.artifact_cache_misses
     }
 }
 
-impl ::protobuf::Message for BusyWorker {
+impl ::protobuf::Message for Heartbeat {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -612,21 +750,89 @@ impl ::protobuf::Message for BusyWorker {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ident)?;
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.endpoint)?;
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
-                    self.job_id = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_enum()?;
+                    self.os = ::std::option::Option::Some(tmp);
                 },
                 3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.disk_free = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.jobs_in_flight = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.targets)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.protocol_version = ::std::option::Option::Some(tmp);
+                },
+                9 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.labels)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.capacity = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.job_ids)?;
+                },
+                12 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_bool()?;
-                    self.quarantined = ::std::option::Option::Some(tmp);
+                    self.draining = ::std::option::Option::Some(tmp);
+                },
+                13 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.leaving = ::std::option::Option::Some(tmp);
+                },
+                14 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.artifact_cache_hits = ::std::option::Option::Some(tmp);
+                },
+                15 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.artifact_cache_misses = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -640,29 +846,101 @@ impl ::protobuf::Message for BusyWorker {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.ident.as_ref() {
+        if let Some(ref v) = self.endpoint.as_ref() {
             my_size += ::protobuf::rt::string_size(1, &v);
         }
-        if let Some(v) = self.job_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.os {
+            my_size += ::protobuf::rt::enum_size(2, v);
         }
-        if let Some(v) = self.quarantined {
+        if let Some(v) = self.state {
+            my_size += ::protobuf::rt::enum_size(3, v);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.disk_free {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.jobs_in_flight {
+            my_size += ::protobuf::rt::value_size(6, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.targets {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        if let Some(v) = self.protocol_version {
+            my_size += ::protobuf::rt::value_size(8, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.labels {
+            my_size += ::protobuf::rt::string_size(9, &value);
+        };
+        if let Some(v) = self.capacity {
+            my_size += ::protobuf::rt::value_size(10, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.job_ids {
+            my_size += ::protobuf::rt::value_size(11, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.draining {
+            my_size += 2;
+        }
+        if let Some(v) = self.leaving {
             my_size += 2;
         }
+        if let Some(v) = self.artifact_cache_hits {
+            my_size += ::protobuf::rt::value_size(14, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.artifact_cache_misses {
+            my_size += ::protobuf::rt::value_size(15, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.ident.as_ref() {
+        if let Some(ref v) = self.endpoint.as_ref() {
             os.write_string(1, &v)?;
         }
-        if let Some(v) = self.job_id {
-            os.write_uint64(2, v)?;
+        if let Some(v) = self.os {
+            os.write_enum(2, v.value())?;
         }
-        if let Some(v) = self.quarantined {
-            os.write_bool(3, v)?;
+        if let Some(v) = self.state {
+            os.write_enum(3, v.value())?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(v) = self.disk_free {
+            os.write_uint64(5, v)?;
+        }
+        if let Some(v) = self.jobs_in_flight {
+            os.write_uint32(6, v)?;
+        }
+        for v in &self.targets {
+            os.write_string(7, &v)?;
+        };
+        if let Some(v) = self.protocol_version {
+            os.write_uint32(8, v)?;
+        }
+        for v in &self.labels {
+            os.write_string(9, &v)?;
+        };
+        if let Some(v) = self.capacity {
+            os.write_uint32(10, v)?;
+        }
+        for v in &self.job_ids {
+            os.write_uint64(11, *v)?;
+        };
+        if let Some(v) = self.draining {
+            os.write_bool(12, v)?;
+        }
+        if let Some(v) = self.leaving {
+            os.write_bool(13, v)?;
+        }
+        if let Some(v) = self.artifact_cache_hits {
+            os.write_uint64(14, v)?;
+        }
+        if let Some(v) = self.artifact_cache_misses {
+            os.write_uint64(15, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -695,12 +973,12 @@ impl ::protobuf::Message for BusyWorker {
     }
 }
 
-impl ::protobuf::MessageStatic for BusyWorker {
-    fn new() -> BusyWorker {
-        BusyWorker::new()
+impl ::protobuf::MessageStatic for Heartbeat {
+    fn new() -> Heartbeat {
+        Heartbeat::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<BusyWorker>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<Heartbeat>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -709,636 +987,792 @@ impl ::protobuf::MessageStatic for BusyWorker {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "ident",
-                    BusyWorker::get_ident_for_reflect,
-                    BusyWorker::mut_ident_for_reflect,
+                    "endpoint",
+                    Heartbeat::get_endpoint_for_reflect,
+                    Heartbeat::mut_endpoint_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<Os>>(
+                    "os",
+                    Heartbeat::get_os_for_reflect,
+                    Heartbeat::mut_os_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerState>>(
+                    "state",
+                    Heartbeat::get_state_for_reflect,
+                    Heartbeat::mut_state_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    Heartbeat::get_target_for_reflect,
+                    Heartbeat::mut_target_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "job_id",
-                    BusyWorker::get_job_id_for_reflect,
-                    BusyWorker::mut_job_id_for_reflect,
+                    "disk_free",
+                    Heartbeat::get_disk_free_for_reflect,
+                    Heartbeat::mut_disk_free_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "jobs_in_flight",
+                    Heartbeat::get_jobs_in_flight_for_reflect,
+                    Heartbeat::mut_jobs_in_flight_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "targets",
+                    Heartbeat::get_targets_for_reflect,
+                    Heartbeat::mut_targets_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "protocol_version",
+                    Heartbeat::get_protocol_version_for_reflect,
+                    Heartbeat::mut_protocol_version_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "labels",
+                    Heartbeat::get_labels_for_reflect,
+                    Heartbeat::mut_labels_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "capacity",
+                    Heartbeat::get_capacity_for_reflect,
+                    Heartbeat::mut_capacity_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "job_ids",
+                    Heartbeat::get_job_ids_for_reflect,
+                    Heartbeat::mut_job_ids_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "quarantined",
-                    BusyWorker::get_quarantined_for_reflect,
-                    BusyWorker::mut_quarantined_for_reflect,
+                    "draining",
+                    Heartbeat::get_draining_for_reflect,
+                    Heartbeat::mut_draining_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<BusyWorker>(
-                    "BusyWorker",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
-        }
-    }
-}
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "leaving",
+                    Heartbeat::get_leaving_for_reflect,
+                    Heartbeat::mut_leaving_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "artifact_cache_hits",
+                    Heartbeat::get_artifact_cache_hits_for_reflect,
+                    Heartbeat::mut_artifact_cache_hits_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "artifact_cache_misses",
+                    Heartbeat::get_artifact_cache_misses_for_reflect,
+                    Heartbeat::mut_artifact_cache_misses_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Heartbeat>(
+                    "Heartbeat",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
 
-impl ::protobuf::Clear for BusyWorker {
+impl ::protobuf::Clear for Heartbeat {
     fn clear(&mut self) {
-        self.clear_ident();
-        self.clear_job_id();
-        self.clear_quarantined();
+        self.clear_endpoint();
+        self.clear_os();
+        self.clear_state();
+        self.clear_target();
+        self.clear_disk_free();
+        self.clear_jobs_in_flight();
+        self.clear_targets();
+        self.clear_protocol_version();
+        self.clear_labels();
+        self.clear_capacity();
+        self.clear_job_ids();
+        self.clear_draining();
+        self.clear_leaving();
+        self.clear_artifact_cache_hits();
+        self.clear_artifact_cache_misses();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for BusyWorker {
+impl ::std::fmt::Debug for Heartbeat {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for BusyWorker {
+impl ::protobuf::reflect::ProtobufValue for Heartbeat {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct Job {
+pub struct BusyWorker {
     // message fields
-    id: ::std::option::Option<u64>,
-    owner_id: ::std::option::Option<u64>,
-    state: ::std::option::Option<JobState>,
-    project: ::protobuf::SingularPtrField<super::originsrv::OriginProject>,
-    error: ::protobuf::SingularPtrField<super::net::NetError>,
-    created_at: ::protobuf::SingularField<::std::string::String>,
-    build_started_at: ::protobuf::SingularField<::std::string::String>,
-    build_finished_at: ::protobuf::SingularField<::std::string::String>,
-    package_ident: ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent>,
-    is_archived: ::std::option::Option<bool>,
-    integrations: ::protobuf::RepeatedField<super::originsrv::OriginIntegration>,
-    channel: ::protobuf::SingularField<::std::string::String>,
-    project_integrations: ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration>,
-    worker: ::protobuf::SingularField<::std::string::String>,
+    ident: ::protobuf::SingularField<::std::string::String>,
+    job_id: ::std::option::Option<u64>,
+    quarantined: ::std::option::Option<bool>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for Job {}
+unsafe impl ::std::marker::Sync for BusyWorker {}
 
-impl Job {
-    pub fn new() -> Job {
+impl BusyWorker {
+    pub fn new() -> BusyWorker {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static Job {
-        static mut instance: ::protobuf::lazy::Lazy<Job> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static BusyWorker {
+        static mut instance: ::protobuf::lazy::Lazy<BusyWorker> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const Job,
+            ptr: 0 as *const BusyWorker,
         };
         unsafe {
-            instance.get(Job::new)
+            instance.get(BusyWorker::new)
         }
     }
 
-    // optional uint64 id = 1;
+    // optional string ident = 1;
 
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+    pub fn set_ident(&mut self, v: ::std::string::String) {
+        self.ident = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&mut self) -> &mut ::std::string::String {
+        if self.ident.is_none() {
+            self.ident.set_default();
+        }
+        self.ident.as_mut().unwrap()
     }
 
-    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.id
+    // Take field
+    pub fn take_ident(&mut self) -> ::std::string::String {
+        self.ident.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.id
+    pub fn get_ident(&self) -> &str {
+        match self.ident.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    // optional uint64 owner_id = 2;
+    fn get_ident_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.ident
+    }
 
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.ident
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    // optional uint64 job_id = 2;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
     }
 
-    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.owner_id
+    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.job_id
     }
 
-    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.owner_id
+    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.job_id
     }
 
-    // optional .jobsrv.JobState state = 3;
+    // optional bool quarantined = 3;
 
-    pub fn clear_state(&mut self) {
-        self.state = ::std::option::Option::None;
+    pub fn clear_quarantined(&mut self) {
+        self.quarantined = ::std::option::Option::None;
     }
 
-    pub fn has_state(&self) -> bool {
-        self.state.is_some()
+    pub fn has_quarantined(&self) -> bool {
+        self.quarantined.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_state(&mut self, v: JobState) {
-        self.state = ::std::option::Option::Some(v);
+    pub fn set_quarantined(&mut self, v: bool) {
+        self.quarantined = ::std::option::Option::Some(v);
     }
 
-    pub fn get_state(&self) -> JobState {
-        self.state.unwrap_or(JobState::Pending)
+    pub fn get_quarantined(&self) -> bool {
+        self.quarantined.unwrap_or(false)
     }
 
-    fn get_state_for_reflect(&self) -> &::std::option::Option<JobState> {
-        &self.state
+    fn get_quarantined_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.quarantined
     }
 
-    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<JobState> {
-        &mut self.state
+    fn mut_quarantined_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.quarantined
     }
+}
 
-    // optional .originsrv.OriginProject project = 4;
-
-    pub fn clear_project(&mut self) {
-        self.project.clear();
+impl ::protobuf::Message for BusyWorker {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    pub fn has_project(&self) -> bool {
-        self.project.is_some()
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ident)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.quarantined = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_project(&mut self, v: super::originsrv::OriginProject) {
-        self.project = ::protobuf::SingularPtrField::some(v);
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.ident.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.job_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.quarantined {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_project(&mut self) -> &mut super::originsrv::OriginProject {
-        if self.project.is_none() {
-            self.project.set_default();
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.ident.as_ref() {
+            os.write_string(1, &v)?;
         }
-        self.project.as_mut().unwrap()
+        if let Some(v) = self.job_id {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.quarantined {
+            os.write_bool(3, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Take field
-    pub fn take_project(&mut self) -> super::originsrv::OriginProject {
-        self.project.take().unwrap_or_else(|| super::originsrv::OriginProject::new())
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    pub fn get_project(&self) -> &super::originsrv::OriginProject {
-        self.project.as_ref().unwrap_or_else(|| super::originsrv::OriginProject::default_instance())
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginProject> {
-        &self.project
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginProject> {
-        &mut self.project
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
-
-    // optional .net.NetError error = 5;
-
-    pub fn clear_error(&mut self) {
-        self.error.clear();
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
     }
-
-    pub fn has_error(&self) -> bool {
-        self.error.is_some()
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    // Param is passed by value, moved
-    pub fn set_error(&mut self, v: super::net::NetError) {
-        self.error = ::protobuf::SingularPtrField::some(v);
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
+}
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_error(&mut self) -> &mut super::net::NetError {
-        if self.error.is_none() {
-            self.error.set_default();
+impl ::protobuf::MessageStatic for BusyWorker {
+    fn new() -> BusyWorker {
+        BusyWorker::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<BusyWorker>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "ident",
+                    BusyWorker::get_ident_for_reflect,
+                    BusyWorker::mut_ident_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "job_id",
+                    BusyWorker::get_job_id_for_reflect,
+                    BusyWorker::mut_job_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "quarantined",
+                    BusyWorker::get_quarantined_for_reflect,
+                    BusyWorker::mut_quarantined_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<BusyWorker>(
+                    "BusyWorker",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
         }
-        self.error.as_mut().unwrap()
     }
+}
 
-    // Take field
-    pub fn take_error(&mut self) -> super::net::NetError {
-        self.error.take().unwrap_or_else(|| super::net::NetError::new())
+impl ::protobuf::Clear for BusyWorker {
+    fn clear(&mut self) {
+        self.clear_ident();
+        self.clear_job_id();
+        self.clear_quarantined();
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn get_error(&self) -> &super::net::NetError {
-        self.error.as_ref().unwrap_or_else(|| super::net::NetError::default_instance())
+impl ::std::fmt::Debug for BusyWorker {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    fn get_error_for_reflect(&self) -> &::protobuf::SingularPtrField<super::net::NetError> {
-        &self.error
+impl ::protobuf::reflect::ProtobufValue for BusyWorker {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    fn mut_error_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::net::NetError> {
-        &mut self.error
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerStatus {
+    // message fields
+    ident: ::protobuf::SingularField<::std::string::String>,
+    targets: ::protobuf::RepeatedField<::std::string::String>,
+    state: ::std::option::Option<WorkerState>,
+    job_id: ::std::option::Option<u64>,
+    last_heartbeat: ::protobuf::SingularField<::std::string::String>,
+    labels: ::protobuf::RepeatedField<::std::string::String>,
+    capacity: ::std::option::Option<u32>,
+    job_ids: ::std::vec::Vec<u64>,
+    draining: ::std::option::Option<bool>,
+    artifact_cache_hits: ::std::option::Option<u64>,
+    artifact_cache_misses: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for WorkerStatus {}
+
+impl WorkerStatus {
+    pub fn new() -> WorkerStatus {
+        ::std::default::Default::default()
     }
 
-    // optional string created_at = 6;
+    pub fn default_instance() -> &'static WorkerStatus {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerStatus> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerStatus,
+        };
+        unsafe {
+            instance.get(WorkerStatus::new)
+        }
+    }
 
-    pub fn clear_created_at(&mut self) {
-        self.created_at.clear();
+    // optional string ident = 1;
+
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
     }
 
-    pub fn has_created_at(&self) -> bool {
-        self.created_at.is_some()
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_created_at(&mut self, v: ::std::string::String) {
-        self.created_at = ::protobuf::SingularField::some(v);
+    pub fn set_ident(&mut self, v: ::std::string::String) {
+        self.ident = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_created_at(&mut self) -> &mut ::std::string::String {
-        if self.created_at.is_none() {
-            self.created_at.set_default();
+    pub fn mut_ident(&mut self) -> &mut ::std::string::String {
+        if self.ident.is_none() {
+            self.ident.set_default();
         }
-        self.created_at.as_mut().unwrap()
+        self.ident.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_created_at(&mut self) -> ::std::string::String {
-        self.created_at.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_ident(&mut self) -> ::std::string::String {
+        self.ident.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_created_at(&self) -> &str {
-        match self.created_at.as_ref() {
+    pub fn get_ident(&self) -> &str {
+        match self.ident.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_created_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.created_at
+    fn get_ident_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.ident
     }
 
-    fn mut_created_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.created_at
+    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.ident
     }
 
-    // optional string build_started_at = 7;
-
-    pub fn clear_build_started_at(&mut self) {
-        self.build_started_at.clear();
-    }
+    // repeated string targets = 2;
 
-    pub fn has_build_started_at(&self) -> bool {
-        self.build_started_at.is_some()
+    pub fn clear_targets(&mut self) {
+        self.targets.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_build_started_at(&mut self, v: ::std::string::String) {
-        self.build_started_at = ::protobuf::SingularField::some(v);
+    pub fn set_targets(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.targets = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_build_started_at(&mut self) -> &mut ::std::string::String {
-        if self.build_started_at.is_none() {
-            self.build_started_at.set_default();
-        }
-        self.build_started_at.as_mut().unwrap()
+    pub fn mut_targets(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.targets
     }
 
     // Take field
-    pub fn take_build_started_at(&mut self) -> ::std::string::String {
-        self.build_started_at.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_targets(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.targets, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_build_started_at(&self) -> &str {
-        match self.build_started_at.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_targets(&self) -> &[::std::string::String] {
+        &self.targets
     }
 
-    fn get_build_started_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.build_started_at
+    fn get_targets_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.targets
     }
 
-    fn mut_build_started_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.build_started_at
+    fn mut_targets_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.targets
     }
 
-    // optional string build_finished_at = 8;
+    // optional .jobsrv.WorkerState state = 3;
 
-    pub fn clear_build_finished_at(&mut self) {
-        self.build_finished_at.clear();
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
     }
 
-    pub fn has_build_finished_at(&self) -> bool {
-        self.build_finished_at.is_some()
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_build_finished_at(&mut self, v: ::std::string::String) {
-        self.build_finished_at = ::protobuf::SingularField::some(v);
+    pub fn set_state(&mut self, v: WorkerState) {
+        self.state = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_build_finished_at(&mut self) -> &mut ::std::string::String {
-        if self.build_finished_at.is_none() {
-            self.build_finished_at.set_default();
-        }
-        self.build_finished_at.as_mut().unwrap()
+    pub fn get_state(&self) -> WorkerState {
+        self.state.unwrap_or(WorkerState::Ready)
     }
 
-    // Take field
-    pub fn take_build_finished_at(&mut self) -> ::std::string::String {
-        self.build_finished_at.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_state_for_reflect(&self) -> &::std::option::Option<WorkerState> {
+        &self.state
     }
 
-    pub fn get_build_finished_at(&self) -> &str {
-        match self.build_finished_at.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<WorkerState> {
+        &mut self.state
     }
 
-    fn get_build_finished_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.build_finished_at
+    // optional uint64 job_id = 4;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
     }
 
-    fn mut_build_finished_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.build_finished_at
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
     }
 
-    // optional .originsrv.OriginPackageIdent package_ident = 9;
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
 
-    pub fn clear_package_ident(&mut self) {
-        self.package_ident.clear();
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
     }
 
-    pub fn has_package_ident(&self) -> bool {
-        self.package_ident.is_some()
+    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.job_id
+    }
+
+    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.job_id
+    }
+
+    // optional string last_heartbeat = 5;
+
+    pub fn clear_last_heartbeat(&mut self) {
+        self.last_heartbeat.clear();
+    }
+
+    pub fn has_last_heartbeat(&self) -> bool {
+        self.last_heartbeat.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_package_ident(&mut self, v: super::originsrv::OriginPackageIdent) {
-        self.package_ident = ::protobuf::SingularPtrField::some(v);
+    pub fn set_last_heartbeat(&mut self, v: ::std::string::String) {
+        self.last_heartbeat = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_package_ident(&mut self) -> &mut super::originsrv::OriginPackageIdent {
-        if self.package_ident.is_none() {
-            self.package_ident.set_default();
+    pub fn mut_last_heartbeat(&mut self) -> &mut ::std::string::String {
+        if self.last_heartbeat.is_none() {
+            self.last_heartbeat.set_default();
         }
-        self.package_ident.as_mut().unwrap()
+        self.last_heartbeat.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_package_ident(&mut self) -> super::originsrv::OriginPackageIdent {
-        self.package_ident.take().unwrap_or_else(|| super::originsrv::OriginPackageIdent::new())
+    pub fn take_last_heartbeat(&mut self) -> ::std::string::String {
+        self.last_heartbeat.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_package_ident(&self) -> &super::originsrv::OriginPackageIdent {
-        self.package_ident.as_ref().unwrap_or_else(|| super::originsrv::OriginPackageIdent::default_instance())
+    pub fn get_last_heartbeat(&self) -> &str {
+        match self.last_heartbeat.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_package_ident_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
-        &self.package_ident
+    fn get_last_heartbeat_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.last_heartbeat
     }
 
-    fn mut_package_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
-        &mut self.package_ident
+    fn mut_last_heartbeat_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.last_heartbeat
     }
 
-    // optional bool is_archived = 11;
+    // repeated string labels = 6;
 
-    pub fn clear_is_archived(&mut self) {
-        self.is_archived = ::std::option::Option::None;
+    pub fn clear_labels(&mut self) {
+        self.labels.clear();
     }
 
-    pub fn has_is_archived(&self) -> bool {
-        self.is_archived.is_some()
+    // Param is passed by value, moved
+    pub fn set_labels(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.labels = v;
     }
 
-    // Param is passed by value, moved
-    pub fn set_is_archived(&mut self, v: bool) {
-        self.is_archived = ::std::option::Option::Some(v);
+    // Mutable pointer to the field.
+    pub fn mut_labels(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.labels
     }
 
-    pub fn get_is_archived(&self) -> bool {
-        self.is_archived.unwrap_or(false)
+    // Take field
+    pub fn take_labels(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.labels, ::protobuf::RepeatedField::new())
     }
 
-    fn get_is_archived_for_reflect(&self) -> &::std::option::Option<bool> {
-        &self.is_archived
+    pub fn get_labels(&self) -> &[::std::string::String] {
+        &self.labels
     }
 
-    fn mut_is_archived_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
-        &mut self.is_archived
+    fn get_labels_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.labels
     }
 
-    // repeated .originsrv.OriginIntegration integrations = 12;
+    fn mut_labels_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.labels
+    }
 
-    pub fn clear_integrations(&mut self) {
-        self.integrations.clear();
+    // optional uint32 capacity = 7;
+
+    pub fn clear_capacity(&mut self) {
+        self.capacity = ::std::option::Option::None;
+    }
+
+    pub fn has_capacity(&self) -> bool {
+        self.capacity.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_integrations(&mut self, v: ::protobuf::RepeatedField<super::originsrv::OriginIntegration>) {
-        self.integrations = v;
+    pub fn set_capacity(&mut self, v: u32) {
+        self.capacity = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_integrations(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginIntegration> {
-        &mut self.integrations
-    }
-
-    // Take field
-    pub fn take_integrations(&mut self) -> ::protobuf::RepeatedField<super::originsrv::OriginIntegration> {
-        ::std::mem::replace(&mut self.integrations, ::protobuf::RepeatedField::new())
+    pub fn get_capacity(&self) -> u32 {
+        self.capacity.unwrap_or(0)
     }
 
-    pub fn get_integrations(&self) -> &[super::originsrv::OriginIntegration] {
-        &self.integrations
-    }
-
-    fn get_integrations_for_reflect(&self) -> &::protobuf::RepeatedField<super::originsrv::OriginIntegration> {
-        &self.integrations
+    fn get_capacity_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.capacity
     }
 
-    fn mut_integrations_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginIntegration> {
-        &mut self.integrations
+    fn mut_capacity_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.capacity
     }
 
-    // optional string channel = 13;
-
-    pub fn clear_channel(&mut self) {
-        self.channel.clear();
-    }
+    // repeated uint64 job_ids = 8;
 
-    pub fn has_channel(&self) -> bool {
-        self.channel.is_some()
+    pub fn clear_job_ids(&mut self) {
+        self.job_ids.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_channel(&mut self, v: ::std::string::String) {
-        self.channel = ::protobuf::SingularField::some(v);
+    pub fn set_job_ids(&mut self, v: ::std::vec::Vec<u64>) {
+        self.job_ids = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
-        if self.channel.is_none() {
-            self.channel.set_default();
-        }
-        self.channel.as_mut().unwrap()
+    pub fn mut_job_ids(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.job_ids
     }
 
     // Take field
-    pub fn take_channel(&mut self) -> ::std::string::String {
-        self.channel.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_job_ids(&mut self) -> ::std::vec::Vec<u64> {
+        ::std::mem::replace(&mut self.job_ids, ::std::vec::Vec::new())
     }
 
-    pub fn get_channel(&self) -> &str {
-        match self.channel.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_job_ids(&self) -> &[u64] {
+        &self.job_ids
     }
 
-    fn get_channel_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.channel
+    fn get_job_ids_for_reflect(&self) -> &::std::vec::Vec<u64> {
+        &self.job_ids
     }
 
-    fn mut_channel_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.channel
+    fn mut_job_ids_for_reflect(&mut self) -> &mut ::std::vec::Vec<u64> {
+        &mut self.job_ids
     }
 
-    // repeated .originsrv.OriginProjectIntegration project_integrations = 14;
+    // optional bool draining = 9;
 
-    pub fn clear_project_integrations(&mut self) {
-        self.project_integrations.clear();
+    pub fn clear_draining(&mut self) {
+        self.draining = ::std::option::Option::None;
+    }
+
+    pub fn has_draining(&self) -> bool {
+        self.draining.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_project_integrations(&mut self, v: ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration>) {
-        self.project_integrations = v;
+    pub fn set_draining(&mut self, v: bool) {
+        self.draining = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_project_integrations(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration> {
-        &mut self.project_integrations
+    pub fn get_draining(&self) -> bool {
+        self.draining.unwrap_or(false)
     }
 
-    // Take field
-    pub fn take_project_integrations(&mut self) -> ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration> {
-        ::std::mem::replace(&mut self.project_integrations, ::protobuf::RepeatedField::new())
+    fn get_draining_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.draining
     }
 
-    pub fn get_project_integrations(&self) -> &[super::originsrv::OriginProjectIntegration] {
-        &self.project_integrations
+    fn mut_draining_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.draining
     }
 
-    fn get_project_integrations_for_reflect(&self) -> &::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration> {
-        &self.project_integrations
+    // optional uint64 artifact_cache_hits = 10;
+
+    pub fn clear_artifact_cache_hits(&mut self) {
+        self.artifact_cache_hits = ::std::option::Option::None;
     }
 
-    fn mut_project_integrations_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration> {
-        &mut self.project_integrations
+    pub fn has_artifact_cache_hits(&self) -> bool {
+        self.artifact_cache_hits.is_some()
     }
 
-    // optional string worker = 15;
+    // Param is passed by value, moved
+    pub fn set_artifact_cache_hits(&mut self, v: u64) {
+        self.artifact_cache_hits = ::std::option::Option::Some(v);
+    }
 
-    pub fn clear_worker(&mut self) {
-        self.worker.clear();
+    pub fn get_artifact_cache_hits(&self) -> u64 {
+        self.artifact_cache_hits.unwrap_or(0)
     }
 
-    pub fn has_worker(&self) -> bool {
-        self.worker.is_some()
+    fn get_artifact_cache_hits_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.artifact_cache_hits
     }
 
-    // Param is passed by value, moved
-    pub fn set_worker(&mut self, v: ::std::string::String) {
-        self.worker = ::protobuf::SingularField::some(v);
+    fn mut_artifact_cache_hits_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.artifact_cache_hits
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_worker(&mut self) -> &mut ::std::string::String {
-        if self.worker.is_none() {
-            self.worker.set_default();
-        }
-        self.worker.as_mut().unwrap()
+    // optional uint64 artifact_cache_misses = 11;
+
+    pub fn clear_artifact_cache_misses(&mut self) {
+        self.artifact_cache_misses = ::std::option::Option::None;
     }
 
-    // Take field
-    pub fn take_worker(&mut self) -> ::std::string::String {
-        self.worker.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn has_artifact_cache_misses(&self) -> bool {
+        self.artifact_cache_misses.is_some()
     }
 
-    pub fn get_worker(&self) -> &str {
-        match self.worker.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    // Param is passed by value, moved
+    pub fn set_artifact_cache_misses(&mut self, v: u64) {
+        self.artifact_cache_misses = ::std::option::Option::Some(v);
     }
 
-    fn get_worker_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.worker
+    pub fn get_artifact_cache_misses(&self) -> u64 {
+        self.artifact_cache_misses.unwrap_or(0)
     }
 
-    fn mut_worker_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.worker
+    fn get_artifact_cache_misses_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.artifact_cache_misses
+    }
+
+    fn mut_artifact_cache_misses_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.artifact_cache_misses
     }
 }
 
-impl ::protobuf::Message for Job {
+impl ::protobuf::Message for WorkerStatus {
     fn is_initialized(&self) -> bool {
-        for v in &self.project {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.error {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.package_ident {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.integrations {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.project_integrations {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -1347,18 +1781,10 @@ impl ::protobuf::Message for Job {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.id = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ident)?;
                 },
                 2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.owner_id = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.targets)?;
                 },
                 3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
@@ -1368,41 +1794,48 @@ impl ::protobuf::Message for Job {
                     self.state = ::std::option::Option::Some(tmp);
                 },
                 4 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.job_id = ::std::option::Option::Some(tmp);
                 },
                 5 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.error)?;
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.last_heartbeat)?;
                 },
                 6 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_at)?;
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.labels)?;
                 },
                 7 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_started_at)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.capacity = ::std::option::Option::Some(tmp);
                 },
                 8 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_finished_at)?;
+                    ::protobuf::rt::read_repeated_uint64_into(wire_type, is, &mut self.job_ids)?;
                 },
                 9 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.package_ident)?;
-                },
-                11 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_bool()?;
-                    self.is_archived = ::std::option::Option::Some(tmp);
-                },
-                12 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.integrations)?;
+                    self.draining = ::std::option::Option::Some(tmp);
                 },
-                13 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.channel)?;
-                },
-                14 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.project_integrations)?;
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.artifact_cache_hits = ::std::option::Option::Some(tmp);
                 },
-                15 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.worker)?;
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.artifact_cache_misses = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1416,52 +1849,38 @@ impl ::protobuf::Message for Job {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.owner_id {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.ident.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
         }
+        for value in &self.targets {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
         if let Some(v) = self.state {
             my_size += ::protobuf::rt::enum_size(3, v);
         }
-        if let Some(ref v) = self.project.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if let Some(ref v) = self.error.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if let Some(ref v) = self.created_at.as_ref() {
-            my_size += ::protobuf::rt::string_size(6, &v);
-        }
-        if let Some(ref v) = self.build_started_at.as_ref() {
-            my_size += ::protobuf::rt::string_size(7, &v);
+        if let Some(v) = self.job_id {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.build_finished_at.as_ref() {
-            my_size += ::protobuf::rt::string_size(8, &v);
+        if let Some(ref v) = self.last_heartbeat.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
         }
-        if let Some(ref v) = self.package_ident.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        for value in &self.labels {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        if let Some(v) = self.capacity {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.is_archived {
+        for value in &self.job_ids {
+            my_size += ::protobuf::rt::value_size(8, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        if let Some(v) = self.draining {
             my_size += 2;
         }
-        for value in &self.integrations {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if let Some(ref v) = self.channel.as_ref() {
-            my_size += ::protobuf::rt::string_size(13, &v);
+        if let Some(v) = self.artifact_cache_hits {
+            my_size += ::protobuf::rt::value_size(10, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.project_integrations {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if let Some(ref v) = self.worker.as_ref() {
-            my_size += ::protobuf::rt::string_size(15, &v);
+        if let Some(v) = self.artifact_cache_misses {
+            my_size += ::protobuf::rt::value_size(11, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -1469,57 +1888,38 @@ impl ::protobuf::Message for Job {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
-            os.write_uint64(1, v)?;
-        }
-        if let Some(v) = self.owner_id {
-            os.write_uint64(2, v)?;
+        if let Some(ref v) = self.ident.as_ref() {
+            os.write_string(1, &v)?;
         }
+        for v in &self.targets {
+            os.write_string(2, &v)?;
+        };
         if let Some(v) = self.state {
             os.write_enum(3, v.value())?;
         }
-        if let Some(ref v) = self.project.as_ref() {
-            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if let Some(v) = self.job_id {
+            os.write_uint64(4, v)?;
         }
-        if let Some(ref v) = self.error.as_ref() {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if let Some(ref v) = self.last_heartbeat.as_ref() {
+            os.write_string(5, &v)?;
         }
-        if let Some(ref v) = self.created_at.as_ref() {
+        for v in &self.labels {
             os.write_string(6, &v)?;
+        };
+        if let Some(v) = self.capacity {
+            os.write_uint32(7, v)?;
         }
-        if let Some(ref v) = self.build_started_at.as_ref() {
-            os.write_string(7, &v)?;
-        }
-        if let Some(ref v) = self.build_finished_at.as_ref() {
-            os.write_string(8, &v)?;
-        }
-        if let Some(ref v) = self.package_ident.as_ref() {
-            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        for v in &self.job_ids {
+            os.write_uint64(8, *v)?;
+        };
+        if let Some(v) = self.draining {
+            os.write_bool(9, v)?;
         }
-        if let Some(v) = self.is_archived {
-            os.write_bool(11, v)?;
+        if let Some(v) = self.artifact_cache_hits {
+            os.write_uint64(10, v)?;
         }
-        for v in &self.integrations {
-            os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if let Some(ref v) = self.channel.as_ref() {
-            os.write_string(13, &v)?;
-        }
-        for v in &self.project_integrations {
-            os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if let Some(ref v) = self.worker.as_ref() {
-            os.write_string(15, &v)?;
+        if let Some(v) = self.artifact_cache_misses {
+            os.write_uint64(11, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -1552,12 +1952,12 @@ impl ::protobuf::Message for Job {
     }
 }
 
-impl ::protobuf::MessageStatic for Job {
-    fn new() -> Job {
-        Job::new()
+impl ::protobuf::MessageStatic for WorkerStatus {
+    fn new() -> WorkerStatus {
+        WorkerStatus::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<Job>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<WorkerStatus>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -1565,78 +1965,63 @@ impl ::protobuf::MessageStatic for Job {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "id",
-                    Job::get_id_for_reflect,
-                    Job::mut_id_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "ident",
+                    WorkerStatus::get_ident_for_reflect,
+                    WorkerStatus::mut_ident_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "owner_id",
-                    Job::get_owner_id_for_reflect,
-                    Job::mut_owner_id_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "targets",
+                    WorkerStatus::get_targets_for_reflect,
+                    WorkerStatus::mut_targets_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobState>>(
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<WorkerState>>(
                     "state",
-                    Job::get_state_for_reflect,
-                    Job::mut_state_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginProject>>(
-                    "project",
-                    Job::get_project_for_reflect,
-                    Job::mut_project_for_reflect,
+                    WorkerStatus::get_state_for_reflect,
+                    WorkerStatus::mut_state_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::net::NetError>>(
-                    "error",
-                    Job::get_error_for_reflect,
-                    Job::mut_error_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "job_id",
+                    WorkerStatus::get_job_id_for_reflect,
+                    WorkerStatus::mut_job_id_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "created_at",
-                    Job::get_created_at_for_reflect,
-                    Job::mut_created_at_for_reflect,
+                    "last_heartbeat",
+                    WorkerStatus::get_last_heartbeat_for_reflect,
+                    WorkerStatus::mut_last_heartbeat_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "build_started_at",
-                    Job::get_build_started_at_for_reflect,
-                    Job::mut_build_started_at_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "labels",
+                    WorkerStatus::get_labels_for_reflect,
+                    WorkerStatus::mut_labels_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "build_finished_at",
-                    Job::get_build_finished_at_for_reflect,
-                    Job::mut_build_finished_at_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "capacity",
+                    WorkerStatus::get_capacity_for_reflect,
+                    WorkerStatus::mut_capacity_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginPackageIdent>>(
-                    "package_ident",
-                    Job::get_package_ident_for_reflect,
-                    Job::mut_package_ident_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "job_ids",
+                    WorkerStatus::get_job_ids_for_reflect,
+                    WorkerStatus::mut_job_ids_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "is_archived",
-                    Job::get_is_archived_for_reflect,
-                    Job::mut_is_archived_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginIntegration>>(
-                    "integrations",
-                    Job::get_integrations_for_reflect,
-                    Job::mut_integrations_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "channel",
-                    Job::get_channel_for_reflect,
-                    Job::mut_channel_for_reflect,
+                    "draining",
+                    WorkerStatus::get_draining_for_reflect,
+                    WorkerStatus::mut_draining_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginProjectIntegration>>(
-                    "project_integrations",
-                    Job::get_project_integrations_for_reflect,
-                    Job::mut_project_integrations_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "artifact_cache_hits",
+                    WorkerStatus::get_artifact_cache_hits_for_reflect,
+                    WorkerStatus::mut_artifact_cache_hits_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "worker",
-                    Job::get_worker_for_reflect,
-                    Job::mut_worker_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "artifact_cache_misses",
+                    WorkerStatus::get_artifact_cache_misses_for_reflect,
+                    WorkerStatus::mut_artifact_cache_misses_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<Job>(
-                    "Job",
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerStatus>(
+                    "WorkerStatus",
                     fields,
                     file_descriptor_proto()
                 )
@@ -1645,95 +2030,228 @@ impl ::protobuf::MessageStatic for Job {
     }
 }
 
-impl ::protobuf::Clear for Job {
+impl ::protobuf::Clear for WorkerStatus {
     fn clear(&mut self) {
-        self.clear_id();
-        self.clear_owner_id();
+        self.clear_ident();
+        self.clear_targets();
         self.clear_state();
-        self.clear_project();
-        self.clear_error();
-        self.clear_created_at();
-        self.clear_build_started_at();
-        self.clear_build_finished_at();
-        self.clear_package_ident();
-        self.clear_is_archived();
-        self.clear_integrations();
-        self.clear_channel();
-        self.clear_project_integrations();
-        self.clear_worker();
+        self.clear_job_id();
+        self.clear_last_heartbeat();
+        self.clear_labels();
+        self.clear_capacity();
+        self.clear_job_ids();
+        self.clear_draining();
+        self.clear_artifact_cache_hits();
+        self.clear_artifact_cache_misses();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for Job {
+impl ::std::fmt::Debug for WorkerStatus {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for Job {
+impl ::protobuf::reflect::ProtobufValue for WorkerStatus {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobGet {
-    // message fields
-    id: ::std::option::Option<u64>,
+pub struct WorkerListGet {
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobGet {}
+unsafe impl ::std::marker::Sync for WorkerListGet {}
 
-impl JobGet {
-    pub fn new() -> JobGet {
+impl WorkerListGet {
+    pub fn new() -> WorkerListGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobGet {
-        static mut instance: ::protobuf::lazy::Lazy<JobGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static WorkerListGet {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerListGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobGet,
+            ptr: 0 as *const WorkerListGet,
         };
         unsafe {
-            instance.get(JobGet::new)
+            instance.get(WorkerListGet::new)
         }
     }
+}
 
-    // optional uint64 id = 1;
+impl ::protobuf::Message for WorkerListGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
 
-    pub fn clear_id(&mut self) {
-        self.id = ::std::option::Option::None;
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    pub fn has_id(&self) -> bool {
-        self.id.is_some()
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for WorkerListGet {
+    fn new() -> WorkerListGet {
+        WorkerListGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<WorkerListGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let fields = ::std::vec::Vec::new();
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerListGet>(
+                    "WorkerListGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerListGet {
+    fn clear(&mut self) {
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerListGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerListGet {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct WorkerListResponse {
+    // message fields
+    workers: ::protobuf::RepeatedField<WorkerStatus>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for WorkerListResponse {}
+
+impl WorkerListResponse {
+    pub fn new() -> WorkerListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static WorkerListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<WorkerListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const WorkerListResponse,
+        };
+        unsafe {
+            instance.get(WorkerListResponse::new)
+        }
+    }
+
+    // repeated .jobsrv.WorkerStatus workers = 1;
+
+    pub fn clear_workers(&mut self) {
+        self.workers.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_id(&mut self, v: u64) {
-        self.id = ::std::option::Option::Some(v);
+    pub fn set_workers(&mut self, v: ::protobuf::RepeatedField<WorkerStatus>) {
+        self.workers = v;
     }
 
-    pub fn get_id(&self) -> u64 {
-        self.id.unwrap_or(0)
+    // Mutable pointer to the field.
+    pub fn mut_workers(&mut self) -> &mut ::protobuf::RepeatedField<WorkerStatus> {
+        &mut self.workers
     }
 
-    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.id
+    // Take field
+    pub fn take_workers(&mut self) -> ::protobuf::RepeatedField<WorkerStatus> {
+        ::std::mem::replace(&mut self.workers, ::protobuf::RepeatedField::new())
     }
 
-    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.id
+    pub fn get_workers(&self) -> &[WorkerStatus] {
+        &self.workers
+    }
+
+    fn get_workers_for_reflect(&self) -> &::protobuf::RepeatedField<WorkerStatus> {
+        &self.workers
+    }
+
+    fn mut_workers_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<WorkerStatus> {
+        &mut self.workers
     }
 }
 
-impl ::protobuf::Message for JobGet {
+impl ::protobuf::Message for WorkerListResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.workers {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1742,11 +2260,7 @@ impl ::protobuf::Message for JobGet {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.id = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.workers)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -1760,18 +2274,21 @@ impl ::protobuf::Message for JobGet {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
+        for value in &self.workers {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
-            os.write_uint64(1, v)?;
-        }
+        for v in &self.workers {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1801,204 +2318,8812 @@ impl ::protobuf::Message for JobGet {
     fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
         ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
-}
+}
+
+impl ::protobuf::MessageStatic for WorkerListResponse {
+    fn new() -> WorkerListResponse {
+        WorkerListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<WorkerListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<WorkerStatus>>(
+                    "workers",
+                    WorkerListResponse::get_workers_for_reflect,
+                    WorkerListResponse::mut_workers_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<WorkerListResponse>(
+                    "WorkerListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for WorkerListResponse {
+    fn clear(&mut self) {
+        self.clear_workers();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for WorkerListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for WorkerListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct Job {
+    // message fields
+    id: ::std::option::Option<u64>,
+    owner_id: ::std::option::Option<u64>,
+    state: ::std::option::Option<JobState>,
+    project: ::protobuf::SingularPtrField<super::originsrv::OriginProject>,
+    error: ::protobuf::SingularPtrField<JobError>,
+    created_at: ::protobuf::SingularField<::std::string::String>,
+    build_started_at: ::protobuf::SingularField<::std::string::String>,
+    build_finished_at: ::protobuf::SingularField<::std::string::String>,
+    package_ident: ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent>,
+    is_archived: ::std::option::Option<bool>,
+    integrations: ::protobuf::RepeatedField<super::originsrv::OriginIntegration>,
+    channel: ::protobuf::SingularField<::std::string::String>,
+    project_integrations: ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration>,
+    worker: ::protobuf::SingularField<::std::string::String>,
+    priority: ::std::option::Option<JobPriority>,
+    target: ::protobuf::SingularField<::std::string::String>,
+    retries: ::std::option::Option<u32>,
+    max_retries: ::std::option::Option<u32>,
+    last_error_category: ::std::option::Option<JobErrorCategory>,
+    tags: ::protobuf::RepeatedField<::std::string::String>,
+    artifact: ::protobuf::SingularPtrField<JobArtifact>,
+    expires_at: ::protobuf::SingularField<::std::string::String>,
+    build_timeout: ::std::option::Option<u32>,
+    worker_labels: ::protobuf::RepeatedField<::std::string::String>,
+    kind: ::std::option::Option<JobKind>,
+    image_digest: ::protobuf::SingularField<::std::string::String>,
+    secrets: ::protobuf::RepeatedField<super::originsrv::OriginSecret>,
+    memory_limit_mb: ::std::option::Option<u64>,
+    cpu_limit_millicores: ::std::option::Option<u32>,
+    verify_reproducible: ::std::option::Option<bool>,
+    reproducible: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for Job {}
+
+impl Job {
+    pub fn new() -> Job {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static Job {
+        static mut instance: ::protobuf::lazy::Lazy<Job> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const Job,
+        };
+        unsafe {
+            instance.get(Job::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional uint64 owner_id = 2;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.owner_id
+    }
+
+    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.owner_id
+    }
+
+    // optional .jobsrv.JobState state = 3;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: JobState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> JobState {
+        self.state.unwrap_or(JobState::Pending)
+    }
+
+    fn get_state_for_reflect(&self) -> &::std::option::Option<JobState> {
+        &self.state
+    }
+
+    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<JobState> {
+        &mut self.state
+    }
+
+    // optional .originsrv.OriginProject project = 4;
+
+    pub fn clear_project(&mut self) {
+        self.project.clear();
+    }
+
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: super::originsrv::OriginProject) {
+        self.project = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut super::originsrv::OriginProject {
+        if self.project.is_none() {
+            self.project.set_default();
+        }
+        self.project.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project(&mut self) -> super::originsrv::OriginProject {
+        self.project.take().unwrap_or_else(|| super::originsrv::OriginProject::new())
+    }
+
+    pub fn get_project(&self) -> &super::originsrv::OriginProject {
+        self.project.as_ref().unwrap_or_else(|| super::originsrv::OriginProject::default_instance())
+    }
+
+    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginProject> {
+        &self.project
+    }
+
+    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginProject> {
+        &mut self.project
+    }
+
+    // optional .jobsrv.JobError error = 5;
+
+    pub fn clear_error(&mut self) {
+        self.error.clear();
+    }
+
+    pub fn has_error(&self) -> bool {
+        self.error.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_error(&mut self, v: JobError) {
+        self.error = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_error(&mut self) -> &mut JobError {
+        if self.error.is_none() {
+            self.error.set_default();
+        }
+        self.error.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_error(&mut self) -> JobError {
+        self.error.take().unwrap_or_else(|| JobError::new())
+    }
+
+    pub fn get_error(&self) -> &JobError {
+        self.error.as_ref().unwrap_or_else(|| JobError::default_instance())
+    }
+
+    fn get_error_for_reflect(&self) -> &::protobuf::SingularPtrField<JobError> {
+        &self.error
+    }
+
+    fn mut_error_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<JobError> {
+        &mut self.error
+    }
+
+    // optional string created_at = 6;
+
+    pub fn clear_created_at(&mut self) {
+        self.created_at.clear();
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: ::std::string::String) {
+        self.created_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_at(&mut self) -> &mut ::std::string::String {
+        if self.created_at.is_none() {
+            self.created_at.set_default();
+        }
+        self.created_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_at(&mut self) -> ::std::string::String {
+        self.created_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_created_at(&self) -> &str {
+        match self.created_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_created_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.created_at
+    }
+
+    fn mut_created_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.created_at
+    }
+
+    // optional string build_started_at = 7;
+
+    pub fn clear_build_started_at(&mut self) {
+        self.build_started_at.clear();
+    }
+
+    pub fn has_build_started_at(&self) -> bool {
+        self.build_started_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_started_at(&mut self, v: ::std::string::String) {
+        self.build_started_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_started_at(&mut self) -> &mut ::std::string::String {
+        if self.build_started_at.is_none() {
+            self.build_started_at.set_default();
+        }
+        self.build_started_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_build_started_at(&mut self) -> ::std::string::String {
+        self.build_started_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_build_started_at(&self) -> &str {
+        match self.build_started_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_build_started_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.build_started_at
+    }
+
+    fn mut_build_started_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.build_started_at
+    }
+
+    // optional string build_finished_at = 8;
+
+    pub fn clear_build_finished_at(&mut self) {
+        self.build_finished_at.clear();
+    }
+
+    pub fn has_build_finished_at(&self) -> bool {
+        self.build_finished_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_finished_at(&mut self, v: ::std::string::String) {
+        self.build_finished_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_finished_at(&mut self) -> &mut ::std::string::String {
+        if self.build_finished_at.is_none() {
+            self.build_finished_at.set_default();
+        }
+        self.build_finished_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_build_finished_at(&mut self) -> ::std::string::String {
+        self.build_finished_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_build_finished_at(&self) -> &str {
+        match self.build_finished_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_build_finished_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.build_finished_at
+    }
+
+    fn mut_build_finished_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.build_finished_at
+    }
+
+    // optional .originsrv.OriginPackageIdent package_ident = 9;
+
+    pub fn clear_package_ident(&mut self) {
+        self.package_ident.clear();
+    }
+
+    pub fn has_package_ident(&self) -> bool {
+        self.package_ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_package_ident(&mut self, v: super::originsrv::OriginPackageIdent) {
+        self.package_ident = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_package_ident(&mut self) -> &mut super::originsrv::OriginPackageIdent {
+        if self.package_ident.is_none() {
+            self.package_ident.set_default();
+        }
+        self.package_ident.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_package_ident(&mut self) -> super::originsrv::OriginPackageIdent {
+        self.package_ident.take().unwrap_or_else(|| super::originsrv::OriginPackageIdent::new())
+    }
+
+    pub fn get_package_ident(&self) -> &super::originsrv::OriginPackageIdent {
+        self.package_ident.as_ref().unwrap_or_else(|| super::originsrv::OriginPackageIdent::default_instance())
+    }
+
+    fn get_package_ident_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
+        &self.package_ident
+    }
+
+    fn mut_package_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginPackageIdent> {
+        &mut self.package_ident
+    }
+
+    // optional bool is_archived = 11;
+
+    pub fn clear_is_archived(&mut self) {
+        self.is_archived = ::std::option::Option::None;
+    }
+
+    pub fn has_is_archived(&self) -> bool {
+        self.is_archived.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_archived(&mut self, v: bool) {
+        self.is_archived = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_is_archived(&self) -> bool {
+        self.is_archived.unwrap_or(false)
+    }
+
+    fn get_is_archived_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.is_archived
+    }
+
+    fn mut_is_archived_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.is_archived
+    }
+
+    // repeated .originsrv.OriginIntegration integrations = 12;
+
+    pub fn clear_integrations(&mut self) {
+        self.integrations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_integrations(&mut self, v: ::protobuf::RepeatedField<super::originsrv::OriginIntegration>) {
+        self.integrations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_integrations(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginIntegration> {
+        &mut self.integrations
+    }
+
+    // Take field
+    pub fn take_integrations(&mut self) -> ::protobuf::RepeatedField<super::originsrv::OriginIntegration> {
+        ::std::mem::replace(&mut self.integrations, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_integrations(&self) -> &[super::originsrv::OriginIntegration] {
+        &self.integrations
+    }
+
+    fn get_integrations_for_reflect(&self) -> &::protobuf::RepeatedField<super::originsrv::OriginIntegration> {
+        &self.integrations
+    }
+
+    fn mut_integrations_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginIntegration> {
+        &mut self.integrations
+    }
+
+    // optional string channel = 13;
+
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    pub fn has_channel(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        if self.channel.is_none() {
+            self.channel.set_default();
+        }
+        self.channel.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        self.channel.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_channel(&self) -> &str {
+        match self.channel.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_channel_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.channel
+    }
+
+    fn mut_channel_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.channel
+    }
+
+    // repeated .originsrv.OriginProjectIntegration project_integrations = 14;
+
+    pub fn clear_project_integrations(&mut self) {
+        self.project_integrations.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project_integrations(&mut self, v: ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration>) {
+        self.project_integrations = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_project_integrations(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration> {
+        &mut self.project_integrations
+    }
+
+    // Take field
+    pub fn take_project_integrations(&mut self) -> ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration> {
+        ::std::mem::replace(&mut self.project_integrations, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_project_integrations(&self) -> &[super::originsrv::OriginProjectIntegration] {
+        &self.project_integrations
+    }
+
+    fn get_project_integrations_for_reflect(&self) -> &::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration> {
+        &self.project_integrations
+    }
+
+    fn mut_project_integrations_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginProjectIntegration> {
+        &mut self.project_integrations
+    }
+
+    // optional string worker = 15;
+
+    pub fn clear_worker(&mut self) {
+        self.worker.clear();
+    }
+
+    pub fn has_worker(&self) -> bool {
+        self.worker.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker(&mut self, v: ::std::string::String) {
+        self.worker = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker(&mut self) -> &mut ::std::string::String {
+        if self.worker.is_none() {
+            self.worker.set_default();
+        }
+        self.worker.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_worker(&mut self) -> ::std::string::String {
+        self.worker.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_worker(&self) -> &str {
+        match self.worker.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_worker_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.worker
+    }
+
+    fn mut_worker_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.worker
+    }
+
+    // optional .jobsrv.JobPriority priority = 16;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: JobPriority) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> JobPriority {
+        self.priority.unwrap_or(JobPriority::Normal)
+    }
+
+    fn get_priority_for_reflect(&self) -> &::std::option::Option<JobPriority> {
+        &self.priority
+    }
+
+    fn mut_priority_for_reflect(&mut self) -> &mut ::std::option::Option<JobPriority> {
+        &mut self.priority
+    }
+
+    // optional string target = 17;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
+
+    // optional uint32 retries = 18;
+
+    pub fn clear_retries(&mut self) {
+        self.retries = ::std::option::Option::None;
+    }
+
+    pub fn has_retries(&self) -> bool {
+        self.retries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_retries(&mut self, v: u32) {
+        self.retries = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_retries(&self) -> u32 {
+        self.retries.unwrap_or(0)
+    }
+
+    fn get_retries_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.retries
+    }
+
+    fn mut_retries_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.retries
+    }
+
+    // optional uint32 max_retries = 19;
+
+    pub fn clear_max_retries(&mut self) {
+        self.max_retries = ::std::option::Option::None;
+    }
+
+    pub fn has_max_retries(&self) -> bool {
+        self.max_retries.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_retries(&mut self, v: u32) {
+        self.max_retries = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_max_retries(&self) -> u32 {
+        self.max_retries.unwrap_or(0)
+    }
+
+    fn get_max_retries_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.max_retries
+    }
+
+    fn mut_max_retries_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.max_retries
+    }
+
+    // optional .jobsrv.JobErrorCategory last_error_category = 20;
+
+    pub fn clear_last_error_category(&mut self) {
+        self.last_error_category = ::std::option::Option::None;
+    }
+
+    pub fn has_last_error_category(&self) -> bool {
+        self.last_error_category.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_error_category(&mut self, v: JobErrorCategory) {
+        self.last_error_category = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_last_error_category(&self) -> JobErrorCategory {
+        self.last_error_category.unwrap_or(JobErrorCategory::Uncategorized)
+    }
+
+    fn get_last_error_category_for_reflect(&self) -> &::std::option::Option<JobErrorCategory> {
+        &self.last_error_category
+    }
+
+    fn mut_last_error_category_for_reflect(&mut self) -> &mut ::std::option::Option<JobErrorCategory> {
+        &mut self.last_error_category
+    }
+
+    // repeated string tags = 21;
+
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+
+    fn get_tags_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.tags
+    }
+
+    fn mut_tags_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // optional .jobsrv.JobArtifact artifact = 22;
+
+    pub fn clear_artifact(&mut self) {
+        self.artifact.clear();
+    }
+
+    pub fn has_artifact(&self) -> bool {
+        self.artifact.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_artifact(&mut self, v: JobArtifact) {
+        self.artifact = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_artifact(&mut self) -> &mut JobArtifact {
+        if self.artifact.is_none() {
+            self.artifact.set_default();
+        }
+        self.artifact.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_artifact(&mut self) -> JobArtifact {
+        self.artifact.take().unwrap_or_else(|| JobArtifact::new())
+    }
+
+    pub fn get_artifact(&self) -> &JobArtifact {
+        self.artifact.as_ref().unwrap_or_else(|| JobArtifact::default_instance())
+    }
+
+    fn get_artifact_for_reflect(&self) -> &::protobuf::SingularPtrField<JobArtifact> {
+        &self.artifact
+    }
+
+    fn mut_artifact_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<JobArtifact> {
+        &mut self.artifact
+    }
+
+    // optional string expires_at = 23;
+
+    pub fn clear_expires_at(&mut self) {
+        self.expires_at.clear();
+    }
+
+    pub fn has_expires_at(&self) -> bool {
+        self.expires_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_expires_at(&mut self, v: ::std::string::String) {
+        self.expires_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_expires_at(&mut self) -> &mut ::std::string::String {
+        if self.expires_at.is_none() {
+            self.expires_at.set_default();
+        }
+        self.expires_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_expires_at(&mut self) -> ::std::string::String {
+        self.expires_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_expires_at(&self) -> &str {
+        match self.expires_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_expires_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.expires_at
+    }
+
+    fn mut_expires_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.expires_at
+    }
+
+    // optional uint32 build_timeout = 24;
+
+    pub fn clear_build_timeout(&mut self) {
+        self.build_timeout = ::std::option::Option::None;
+    }
+
+    pub fn has_build_timeout(&self) -> bool {
+        self.build_timeout.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_build_timeout(&mut self, v: u32) {
+        self.build_timeout = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_build_timeout(&self) -> u32 {
+        self.build_timeout.unwrap_or(0)
+    }
+
+    fn get_build_timeout_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.build_timeout
+    }
+
+    fn mut_build_timeout_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.build_timeout
+    }
+
+    // repeated string worker_labels = 25;
+
+    pub fn clear_worker_labels(&mut self) {
+        self.worker_labels.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_worker_labels(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.worker_labels = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_worker_labels(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.worker_labels
+    }
+
+    // Take field
+    pub fn take_worker_labels(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.worker_labels, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_worker_labels(&self) -> &[::std::string::String] {
+        &self.worker_labels
+    }
+
+    fn get_worker_labels_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.worker_labels
+    }
+
+    fn mut_worker_labels_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.worker_labels
+    }
+
+    // optional .jobsrv.JobKind kind = 26;
+
+    pub fn clear_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kind(&self) -> bool {
+        self.kind.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: JobKind) {
+        self.kind = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_kind(&self) -> JobKind {
+        self.kind.unwrap_or(JobKind::Build)
+    }
+
+    fn get_kind_for_reflect(&self) -> &::std::option::Option<JobKind> {
+        &self.kind
+    }
+
+    fn mut_kind_for_reflect(&mut self) -> &mut ::std::option::Option<JobKind> {
+        &mut self.kind
+    }
+
+    // optional string image_digest = 27;
+
+    pub fn clear_image_digest(&mut self) {
+        self.image_digest.clear();
+    }
+
+    pub fn has_image_digest(&self) -> bool {
+        self.image_digest.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_image_digest(&mut self, v: ::std::string::String) {
+        self.image_digest = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_image_digest(&mut self) -> &mut ::std::string::String {
+        if self.image_digest.is_none() {
+            self.image_digest.set_default();
+        }
+        self.image_digest.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_image_digest(&mut self) -> ::std::string::String {
+        self.image_digest.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_image_digest(&self) -> &str {
+        match self.image_digest.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_image_digest_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.image_digest
+    }
+
+    fn mut_image_digest_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.image_digest
+    }
+
+    // repeated .originsrv.OriginSecret secrets = 28;
+
+    pub fn clear_secrets(&mut self) {
+        self.secrets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_secrets(&mut self, v: ::protobuf::RepeatedField<super::originsrv::OriginSecret>) {
+        self.secrets = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_secrets(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginSecret> {
+        &mut self.secrets
+    }
+
+    // Take field
+    pub fn take_secrets(&mut self) -> ::protobuf::RepeatedField<super::originsrv::OriginSecret> {
+        ::std::mem::replace(&mut self.secrets, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_secrets(&self) -> &[super::originsrv::OriginSecret] {
+        &self.secrets
+    }
+
+    fn get_secrets_for_reflect(&self) -> &::protobuf::RepeatedField<super::originsrv::OriginSecret> {
+        &self.secrets
+    }
+
+    fn mut_secrets_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<super::originsrv::OriginSecret> {
+        &mut self.secrets
+    }
+
+    // optional uint64 memory_limit_mb = 29;
+
+    pub fn clear_memory_limit_mb(&mut self) {
+        self.memory_limit_mb = ::std::option::Option::None;
+    }
+
+    pub fn has_memory_limit_mb(&self) -> bool {
+        self.memory_limit_mb.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_memory_limit_mb(&mut self, v: u64) {
+        self.memory_limit_mb = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_memory_limit_mb(&self) -> u64 {
+        self.memory_limit_mb.unwrap_or(0)
+    }
+
+    fn get_memory_limit_mb_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.memory_limit_mb
+    }
+
+    fn mut_memory_limit_mb_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.memory_limit_mb
+    }
+
+    // optional uint32 cpu_limit_millicores = 30;
+
+    pub fn clear_cpu_limit_millicores(&mut self) {
+        self.cpu_limit_millicores = ::std::option::Option::None;
+    }
+
+    pub fn has_cpu_limit_millicores(&self) -> bool {
+        self.cpu_limit_millicores.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cpu_limit_millicores(&mut self, v: u32) {
+        self.cpu_limit_millicores = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_cpu_limit_millicores(&self) -> u32 {
+        self.cpu_limit_millicores.unwrap_or(0)
+    }
+
+    fn get_cpu_limit_millicores_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.cpu_limit_millicores
+    }
+
+    fn mut_cpu_limit_millicores_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.cpu_limit_millicores
+    }
+
+    // optional bool verify_reproducible = 31;
+
+    pub fn clear_verify_reproducible(&mut self) {
+        self.verify_reproducible = ::std::option::Option::None;
+    }
+
+    pub fn has_verify_reproducible(&self) -> bool {
+        self.verify_reproducible.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_verify_reproducible(&mut self, v: bool) {
+        self.verify_reproducible = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_verify_reproducible(&self) -> bool {
+        self.verify_reproducible.unwrap_or(false)
+    }
+
+    fn get_verify_reproducible_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.verify_reproducible
+    }
+
+    fn mut_verify_reproducible_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.verify_reproducible
+    }
+
+    // optional bool reproducible = 32;
+
+    pub fn clear_reproducible(&mut self) {
+        self.reproducible = ::std::option::Option::None;
+    }
+
+    pub fn has_reproducible(&self) -> bool {
+        self.reproducible.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reproducible(&mut self, v: bool) {
+        self.reproducible = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_reproducible(&self) -> bool {
+        self.reproducible.unwrap_or(false)
+    }
+
+    fn get_reproducible_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.reproducible
+    }
+
+    fn mut_reproducible_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.reproducible
+    }
+}
+
+impl ::protobuf::Message for Job {
+    fn is_initialized(&self) -> bool {
+        for v in &self.project {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.error {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.package_ident {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.integrations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.project_integrations {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.artifact {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.secrets {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.error)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_at)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_started_at)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_finished_at)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.package_ident)?;
+                },
+                11 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_archived = ::std::option::Option::Some(tmp);
+                },
+                12 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.integrations)?;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.channel)?;
+                },
+                14 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.project_integrations)?;
+                },
+                15 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.worker)?;
+                },
+                16 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                17 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
+                18 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.retries = ::std::option::Option::Some(tmp);
+                },
+                19 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_retries = ::std::option::Option::Some(tmp);
+                },
+                20 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.last_error_category = ::std::option::Option::Some(tmp);
+                },
+                21 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                22 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.artifact)?;
+                },
+                23 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.expires_at)?;
+                },
+                24 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.build_timeout = ::std::option::Option::Some(tmp);
+                },
+                25 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.worker_labels)?;
+                },
+                26 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.kind = ::std::option::Option::Some(tmp);
+                },
+                27 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.image_digest)?;
+                },
+                28 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.secrets)?;
+                },
+                29 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.memory_limit_mb = ::std::option::Option::Some(tmp);
+                },
+                30 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.cpu_limit_millicores = ::std::option::Option::Some(tmp);
+                },
+                31 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.verify_reproducible = ::std::option::Option::Some(tmp);
+                },
+                32 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.reproducible = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.owner_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.state {
+            my_size += ::protobuf::rt::enum_size(3, v);
+        }
+        if let Some(ref v) = self.project.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.error.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.created_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(ref v) = self.build_started_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        if let Some(ref v) = self.build_finished_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        if let Some(ref v) = self.package_ident.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(v) = self.is_archived {
+            my_size += 2;
+        }
+        for value in &self.integrations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.channel.as_ref() {
+            my_size += ::protobuf::rt::string_size(13, &v);
+        }
+        for value in &self.project_integrations {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.worker.as_ref() {
+            my_size += ::protobuf::rt::string_size(15, &v);
+        }
+        if let Some(v) = self.priority {
+            my_size += ::protobuf::rt::enum_size(16, v);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(17, &v);
+        }
+        if let Some(v) = self.retries {
+            my_size += ::protobuf::rt::value_size(18, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_retries {
+            my_size += ::protobuf::rt::value_size(19, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.last_error_category {
+            my_size += ::protobuf::rt::enum_size(20, v);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(21, &value);
+        };
+        if let Some(ref v) = self.artifact.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.expires_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(23, &v);
+        }
+        if let Some(v) = self.build_timeout {
+            my_size += ::protobuf::rt::value_size(24, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.worker_labels {
+            my_size += ::protobuf::rt::string_size(25, &value);
+        };
+        if let Some(v) = self.kind {
+            my_size += ::protobuf::rt::enum_size(26, v);
+        }
+        if let Some(ref v) = self.image_digest.as_ref() {
+            my_size += ::protobuf::rt::string_size(27, &v);
+        }
+        for value in &self.secrets {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.memory_limit_mb {
+            my_size += ::protobuf::rt::value_size(29, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.cpu_limit_millicores {
+            my_size += ::protobuf::rt::value_size(30, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.verify_reproducible {
+            my_size += 3;
+        }
+        if let Some(v) = self.reproducible {
+            my_size += 3;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.owner_id {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.state {
+            os.write_enum(3, v.value())?;
+        }
+        if let Some(ref v) = self.project.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.error.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.created_at.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        if let Some(ref v) = self.build_started_at.as_ref() {
+            os.write_string(7, &v)?;
+        }
+        if let Some(ref v) = self.build_finished_at.as_ref() {
+            os.write_string(8, &v)?;
+        }
+        if let Some(ref v) = self.package_ident.as_ref() {
+            os.write_tag(9, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(v) = self.is_archived {
+            os.write_bool(11, v)?;
+        }
+        for v in &self.integrations {
+            os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.channel.as_ref() {
+            os.write_string(13, &v)?;
+        }
+        for v in &self.project_integrations {
+            os.write_tag(14, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.worker.as_ref() {
+            os.write_string(15, &v)?;
+        }
+        if let Some(v) = self.priority {
+            os.write_enum(16, v.value())?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(17, &v)?;
+        }
+        if let Some(v) = self.retries {
+            os.write_uint32(18, v)?;
+        }
+        if let Some(v) = self.max_retries {
+            os.write_uint32(19, v)?;
+        }
+        if let Some(v) = self.last_error_category {
+            os.write_enum(20, v.value())?;
+        }
+        for v in &self.tags {
+            os.write_string(21, &v)?;
+        };
+        if let Some(ref v) = self.artifact.as_ref() {
+            os.write_tag(22, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.expires_at.as_ref() {
+            os.write_string(23, &v)?;
+        }
+        if let Some(v) = self.build_timeout {
+            os.write_uint32(24, v)?;
+        }
+        for v in &self.worker_labels {
+            os.write_string(25, &v)?;
+        };
+        if let Some(v) = self.kind {
+            os.write_enum(26, v.value())?;
+        }
+        if let Some(ref v) = self.image_digest.as_ref() {
+            os.write_string(27, &v)?;
+        }
+        for v in &self.secrets {
+            os.write_tag(28, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.memory_limit_mb {
+            os.write_uint64(29, v)?;
+        }
+        if let Some(v) = self.cpu_limit_millicores {
+            os.write_uint32(30, v)?;
+        }
+        if let Some(v) = self.verify_reproducible {
+            os.write_bool(31, v)?;
+        }
+        if let Some(v) = self.reproducible {
+            os.write_bool(32, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for Job {
+    fn new() -> Job {
+        Job::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<Job>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    Job::get_id_for_reflect,
+                    Job::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "owner_id",
+                    Job::get_owner_id_for_reflect,
+                    Job::mut_owner_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobState>>(
+                    "state",
+                    Job::get_state_for_reflect,
+                    Job::mut_state_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginProject>>(
+                    "project",
+                    Job::get_project_for_reflect,
+                    Job::mut_project_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobError>>(
+                    "error",
+                    Job::get_error_for_reflect,
+                    Job::mut_error_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "created_at",
+                    Job::get_created_at_for_reflect,
+                    Job::mut_created_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "build_started_at",
+                    Job::get_build_started_at_for_reflect,
+                    Job::mut_build_started_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "build_finished_at",
+                    Job::get_build_finished_at_for_reflect,
+                    Job::mut_build_finished_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginPackageIdent>>(
+                    "package_ident",
+                    Job::get_package_ident_for_reflect,
+                    Job::mut_package_ident_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "is_archived",
+                    Job::get_is_archived_for_reflect,
+                    Job::mut_is_archived_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginIntegration>>(
+                    "integrations",
+                    Job::get_integrations_for_reflect,
+                    Job::mut_integrations_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    Job::get_channel_for_reflect,
+                    Job::mut_channel_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginProjectIntegration>>(
+                    "project_integrations",
+                    Job::get_project_integrations_for_reflect,
+                    Job::mut_project_integrations_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker",
+                    Job::get_worker_for_reflect,
+                    Job::mut_worker_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobPriority>>(
+                    "priority",
+                    Job::get_priority_for_reflect,
+                    Job::mut_priority_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    Job::get_target_for_reflect,
+                    Job::mut_target_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "retries",
+                    Job::get_retries_for_reflect,
+                    Job::mut_retries_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "max_retries",
+                    Job::get_max_retries_for_reflect,
+                    Job::mut_max_retries_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobErrorCategory>>(
+                    "last_error_category",
+                    Job::get_last_error_category_for_reflect,
+                    Job::mut_last_error_category_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "tags",
+                    Job::get_tags_for_reflect,
+                    Job::mut_tags_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobArtifact>>(
+                    "artifact",
+                    Job::get_artifact_for_reflect,
+                    Job::mut_artifact_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "expires_at",
+                    Job::get_expires_at_for_reflect,
+                    Job::mut_expires_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "build_timeout",
+                    Job::get_build_timeout_for_reflect,
+                    Job::mut_build_timeout_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker_labels",
+                    Job::get_worker_labels_for_reflect,
+                    Job::mut_worker_labels_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobKind>>(
+                    "kind",
+                    Job::get_kind_for_reflect,
+                    Job::mut_kind_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "image_digest",
+                    Job::get_image_digest_for_reflect,
+                    Job::mut_image_digest_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginSecret>>(
+                    "secrets",
+                    Job::get_secrets_for_reflect,
+                    Job::mut_secrets_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "memory_limit_mb",
+                    Job::get_memory_limit_mb_for_reflect,
+                    Job::mut_memory_limit_mb_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "cpu_limit_millicores",
+                    Job::get_cpu_limit_millicores_for_reflect,
+                    Job::mut_cpu_limit_millicores_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "verify_reproducible",
+                    Job::get_verify_reproducible_for_reflect,
+                    Job::mut_verify_reproducible_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "reproducible",
+                    Job::get_reproducible_for_reflect,
+                    Job::mut_reproducible_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<Job>(
+                    "Job",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for Job {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_owner_id();
+        self.clear_state();
+        self.clear_project();
+        self.clear_error();
+        self.clear_created_at();
+        self.clear_build_started_at();
+        self.clear_build_finished_at();
+        self.clear_package_ident();
+        self.clear_is_archived();
+        self.clear_integrations();
+        self.clear_channel();
+        self.clear_project_integrations();
+        self.clear_worker();
+        self.clear_priority();
+        self.clear_target();
+        self.clear_retries();
+        self.clear_max_retries();
+        self.clear_last_error_category();
+        self.clear_tags();
+        self.clear_artifact();
+        self.clear_expires_at();
+        self.clear_build_timeout();
+        self.clear_worker_labels();
+        self.clear_kind();
+        self.clear_image_digest();
+        self.clear_secrets();
+        self.clear_memory_limit_mb();
+        self.clear_cpu_limit_millicores();
+        self.clear_verify_reproducible();
+        self.clear_reproducible();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Job {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Job {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobArtifact {
+    // message fields
+    checksum: ::protobuf::SingularField<::std::string::String>,
+    size: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobArtifact {}
+
+impl JobArtifact {
+    pub fn new() -> JobArtifact {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobArtifact {
+        static mut instance: ::protobuf::lazy::Lazy<JobArtifact> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobArtifact,
+        };
+        unsafe {
+            instance.get(JobArtifact::new)
+        }
+    }
+
+    // optional string checksum = 1;
+
+    pub fn clear_checksum(&mut self) {
+        self.checksum.clear();
+    }
+
+    pub fn has_checksum(&self) -> bool {
+        self.checksum.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checksum(&mut self, v: ::std::string::String) {
+        self.checksum = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checksum(&mut self) -> &mut ::std::string::String {
+        if self.checksum.is_none() {
+            self.checksum.set_default();
+        }
+        self.checksum.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_checksum(&mut self) -> ::std::string::String {
+        self.checksum.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_checksum(&self) -> &str {
+        match self.checksum.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_checksum_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.checksum
+    }
+
+    fn mut_checksum_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.checksum
+    }
+
+    // optional uint64 size = 2;
+
+    pub fn clear_size(&mut self) {
+        self.size = ::std::option::Option::None;
+    }
+
+    pub fn has_size(&self) -> bool {
+        self.size.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_size(&mut self, v: u64) {
+        self.size = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_size(&self) -> u64 {
+        self.size.unwrap_or(0)
+    }
+
+    fn get_size_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.size
+    }
+
+    fn mut_size_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.size
+    }
+}
+
+impl ::protobuf::Message for JobArtifact {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.checksum)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.size = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.checksum.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.size {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.checksum.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.size {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobArtifact {
+    fn new() -> JobArtifact {
+        JobArtifact::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobArtifact>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "checksum",
+                    JobArtifact::get_checksum_for_reflect,
+                    JobArtifact::mut_checksum_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "size",
+                    JobArtifact::get_size_for_reflect,
+                    JobArtifact::mut_size_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobArtifact>(
+                    "JobArtifact",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobArtifact {
+    fn clear(&mut self) {
+        self.clear_checksum();
+        self.clear_size();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobArtifact {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobArtifact {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobError {
+    // message fields
+    code: ::std::option::Option<super::net::ErrCode>,
+    message: ::protobuf::SingularField<::std::string::String>,
+    category: ::std::option::Option<JobErrorCategory>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobError {}
+
+impl JobError {
+    pub fn new() -> JobError {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobError {
+        static mut instance: ::protobuf::lazy::Lazy<JobError> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobError,
+        };
+        unsafe {
+            instance.get(JobError::new)
+        }
+    }
+
+    // optional .net.ErrCode code = 1;
+
+    pub fn clear_code(&mut self) {
+        self.code = ::std::option::Option::None;
+    }
+
+    pub fn has_code(&self) -> bool {
+        self.code.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_code(&mut self, v: super::net::ErrCode) {
+        self.code = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_code(&self) -> super::net::ErrCode {
+        self.code.unwrap_or(super::net::ErrCode::BUG)
+    }
+
+    fn get_code_for_reflect(&self) -> &::std::option::Option<super::net::ErrCode> {
+        &self.code
+    }
+
+    fn mut_code_for_reflect(&mut self) -> &mut ::std::option::Option<super::net::ErrCode> {
+        &mut self.code
+    }
+
+    // optional string message = 2;
+
+    pub fn clear_message(&mut self) {
+        self.message.clear();
+    }
+
+    pub fn has_message(&self) -> bool {
+        self.message.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_message(&mut self, v: ::std::string::String) {
+        self.message = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_message(&mut self) -> &mut ::std::string::String {
+        if self.message.is_none() {
+            self.message.set_default();
+        }
+        self.message.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_message(&mut self) -> ::std::string::String {
+        self.message.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_message(&self) -> &str {
+        match self.message.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_message_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.message
+    }
+
+    fn mut_message_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.message
+    }
+
+    // optional .jobsrv.JobErrorCategory category = 3;
+
+    pub fn clear_category(&mut self) {
+        self.category = ::std::option::Option::None;
+    }
+
+    pub fn has_category(&self) -> bool {
+        self.category.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_category(&mut self, v: JobErrorCategory) {
+        self.category = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_category(&self) -> JobErrorCategory {
+        self.category.unwrap_or(JobErrorCategory::Uncategorized)
+    }
+
+    fn get_category_for_reflect(&self) -> &::std::option::Option<JobErrorCategory> {
+        &self.category
+    }
+
+    fn mut_category_for_reflect(&mut self) -> &mut ::std::option::Option<JobErrorCategory> {
+        &mut self.category
+    }
+}
+
+impl ::protobuf::Message for JobError {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.code = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.message)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.category = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.code {
+            my_size += ::protobuf::rt::enum_size(1, v);
+        }
+        if let Some(ref v) = self.message.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.category {
+            my_size += ::protobuf::rt::enum_size(3, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.code {
+            os.write_enum(1, v.value())?;
+        }
+        if let Some(ref v) = self.message.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.category {
+            os.write_enum(3, v.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobError {
+    fn new() -> JobError {
+        JobError::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobError>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<super::net::ErrCode>>(
+                    "code",
+                    JobError::get_code_for_reflect,
+                    JobError::mut_code_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "message",
+                    JobError::get_message_for_reflect,
+                    JobError::mut_message_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobErrorCategory>>(
+                    "category",
+                    JobError::get_category_for_reflect,
+                    JobError::mut_category_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobError>(
+                    "JobError",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobError {
+    fn clear(&mut self) {
+        self.clear_code();
+        self.clear_message();
+        self.clear_category();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobError {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGet {
+    // message fields
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGet {}
+
+impl JobGet {
+    pub fn new() -> JobGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGet,
+        };
+        unsafe {
+            instance.get(JobGet::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+}
+
+impl ::protobuf::Message for JobGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGet {
+    fn new() -> JobGet {
+        JobGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    JobGet::get_id_for_reflect,
+                    JobGet::mut_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGet>(
+                    "JobGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGet {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGet {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobSpec {
+    // message fields
+    owner_id: ::std::option::Option<u64>,
+    project: ::protobuf::SingularPtrField<super::originsrv::OriginProject>,
+    channel: ::protobuf::SingularField<::std::string::String>,
+    priority: ::std::option::Option<JobPriority>,
+    target: ::protobuf::SingularField<::std::string::String>,
+    tags: ::protobuf::RepeatedField<::std::string::String>,
+    kind: ::std::option::Option<JobKind>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobSpec {}
+
+impl JobSpec {
+    pub fn new() -> JobSpec {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobSpec {
+        static mut instance: ::protobuf::lazy::Lazy<JobSpec> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobSpec,
+        };
+        unsafe {
+            instance.get(JobSpec::new)
+        }
+    }
+
+    // optional uint64 owner_id = 1;
+
+    pub fn clear_owner_id(&mut self) {
+        self.owner_id = ::std::option::Option::None;
+    }
+
+    pub fn has_owner_id(&self) -> bool {
+        self.owner_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_owner_id(&mut self, v: u64) {
+        self.owner_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_owner_id(&self) -> u64 {
+        self.owner_id.unwrap_or(0)
+    }
+
+    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.owner_id
+    }
+
+    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.owner_id
+    }
+
+    // optional .originsrv.OriginProject project = 2;
+
+    pub fn clear_project(&mut self) {
+        self.project.clear();
+    }
+
+    pub fn has_project(&self) -> bool {
+        self.project.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project(&mut self, v: super::originsrv::OriginProject) {
+        self.project = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project(&mut self) -> &mut super::originsrv::OriginProject {
+        if self.project.is_none() {
+            self.project.set_default();
+        }
+        self.project.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project(&mut self) -> super::originsrv::OriginProject {
+        self.project.take().unwrap_or_else(|| super::originsrv::OriginProject::new())
+    }
+
+    pub fn get_project(&self) -> &super::originsrv::OriginProject {
+        self.project.as_ref().unwrap_or_else(|| super::originsrv::OriginProject::default_instance())
+    }
+
+    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginProject> {
+        &self.project
+    }
+
+    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginProject> {
+        &mut self.project
+    }
+
+    // optional string channel = 3;
+
+    pub fn clear_channel(&mut self) {
+        self.channel.clear();
+    }
+
+    pub fn has_channel(&self) -> bool {
+        self.channel.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_channel(&mut self, v: ::std::string::String) {
+        self.channel = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
+        if self.channel.is_none() {
+            self.channel.set_default();
+        }
+        self.channel.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_channel(&mut self) -> ::std::string::String {
+        self.channel.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_channel(&self) -> &str {
+        match self.channel.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_channel_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.channel
+    }
+
+    fn mut_channel_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.channel
+    }
+
+    // optional .jobsrv.JobPriority priority = 4;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: JobPriority) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> JobPriority {
+        self.priority.unwrap_or(JobPriority::Normal)
+    }
+
+    fn get_priority_for_reflect(&self) -> &::std::option::Option<JobPriority> {
+        &self.priority
+    }
+
+    fn mut_priority_for_reflect(&mut self) -> &mut ::std::option::Option<JobPriority> {
+        &mut self.priority
+    }
+
+    // optional string target = 5;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
+
+    // repeated string tags = 6;
+
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+
+    fn get_tags_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.tags
+    }
+
+    fn mut_tags_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // optional .jobsrv.JobKind kind = 7;
+
+    pub fn clear_kind(&mut self) {
+        self.kind = ::std::option::Option::None;
+    }
+
+    pub fn has_kind(&self) -> bool {
+        self.kind.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_kind(&mut self, v: JobKind) {
+        self.kind = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_kind(&self) -> JobKind {
+        self.kind.unwrap_or(JobKind::Build)
+    }
+
+    fn get_kind_for_reflect(&self) -> &::std::option::Option<JobKind> {
+        &self.kind
+    }
+
+    fn mut_kind_for_reflect(&mut self) -> &mut ::std::option::Option<JobKind> {
+        &mut self.kind
+    }
+}
+
+impl ::protobuf::Message for JobSpec {
+    fn is_initialized(&self) -> bool {
+        for v in &self.project {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.owner_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.channel)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.kind = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.owner_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.project.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.channel.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(v) = self.priority {
+            my_size += ::protobuf::rt::enum_size(4, v);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(6, &value);
+        };
+        if let Some(v) = self.kind {
+            my_size += ::protobuf::rt::enum_size(7, v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.owner_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.project.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.channel.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(v) = self.priority {
+            os.write_enum(4, v.value())?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(5, &v)?;
+        }
+        for v in &self.tags {
+            os.write_string(6, &v)?;
+        };
+        if let Some(v) = self.kind {
+            os.write_enum(7, v.value())?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobSpec {
+    fn new() -> JobSpec {
+        JobSpec::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobSpec>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "owner_id",
+                    JobSpec::get_owner_id_for_reflect,
+                    JobSpec::mut_owner_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginProject>>(
+                    "project",
+                    JobSpec::get_project_for_reflect,
+                    JobSpec::mut_project_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "channel",
+                    JobSpec::get_channel_for_reflect,
+                    JobSpec::mut_channel_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobPriority>>(
+                    "priority",
+                    JobSpec::get_priority_for_reflect,
+                    JobSpec::mut_priority_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    JobSpec::get_target_for_reflect,
+                    JobSpec::mut_target_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "tags",
+                    JobSpec::get_tags_for_reflect,
+                    JobSpec::mut_tags_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobKind>>(
+                    "kind",
+                    JobSpec::get_kind_for_reflect,
+                    JobSpec::mut_kind_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobSpec>(
+                    "JobSpec",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobSpec {
+    fn clear(&mut self) {
+        self.clear_owner_id();
+        self.clear_project();
+        self.clear_channel();
+        self.clear_priority();
+        self.clear_target();
+        self.clear_tags();
+        self.clear_kind();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobSpec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobSpec {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProjectJobsGet {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    created_before: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ProjectJobsGet {}
+
+impl ProjectJobsGet {
+    pub fn new() -> ProjectJobsGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ProjectJobsGet {
+        static mut instance: ::protobuf::lazy::Lazy<ProjectJobsGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProjectJobsGet,
+        };
+        unsafe {
+            instance.get(ProjectJobsGet::new)
+        }
+    }
+
+    // optional string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+
+    // optional uint64 stop = 3;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
+    }
+
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
+    }
+
+    // optional string created_before = 4;
+
+    pub fn clear_created_before(&mut self) {
+        self.created_before.clear();
+    }
+
+    pub fn has_created_before(&self) -> bool {
+        self.created_before.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_before(&mut self, v: ::std::string::String) {
+        self.created_before = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_before(&mut self) -> &mut ::std::string::String {
+        if self.created_before.is_none() {
+            self.created_before.set_default();
+        }
+        self.created_before.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_before(&mut self) -> ::std::string::String {
+        self.created_before.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_created_before(&self) -> &str {
+        match self.created_before.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_created_before_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.created_before
+    }
+
+    fn mut_created_before_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.created_before
+    }
+}
+
+impl ::protobuf::Message for ProjectJobsGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_before)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.created_before.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.start {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(ref v) = self.created_before.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ProjectJobsGet {
+    fn new() -> ProjectJobsGet {
+        ProjectJobsGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ProjectJobsGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    ProjectJobsGet::get_name_for_reflect,
+                    ProjectJobsGet::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    ProjectJobsGet::get_start_for_reflect,
+                    ProjectJobsGet::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    ProjectJobsGet::get_stop_for_reflect,
+                    ProjectJobsGet::mut_stop_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "created_before",
+                    ProjectJobsGet::get_created_before_for_reflect,
+                    ProjectJobsGet::mut_created_before_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ProjectJobsGet>(
+                    "ProjectJobsGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProjectJobsGet {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_start();
+        self.clear_stop();
+        self.clear_created_before();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProjectJobsGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProjectJobsGet {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ProjectJobsGetResponse {
+    // message fields
+    jobs: ::protobuf::RepeatedField<Job>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    count: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for ProjectJobsGetResponse {}
+
+impl ProjectJobsGetResponse {
+    pub fn new() -> ProjectJobsGetResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static ProjectJobsGetResponse {
+        static mut instance: ::protobuf::lazy::Lazy<ProjectJobsGetResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ProjectJobsGetResponse,
+        };
+        unsafe {
+            instance.get(ProjectJobsGetResponse::new)
+        }
+    }
+
+    // repeated .jobsrv.Job jobs = 1;
+
+    pub fn clear_jobs(&mut self) {
+        self.jobs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_jobs(&mut self, v: ::protobuf::RepeatedField<Job>) {
+        self.jobs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_jobs(&mut self) -> &mut ::protobuf::RepeatedField<Job> {
+        &mut self.jobs
+    }
+
+    // Take field
+    pub fn take_jobs(&mut self) -> ::protobuf::RepeatedField<Job> {
+        ::std::mem::replace(&mut self.jobs, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_jobs(&self) -> &[Job] {
+        &self.jobs
+    }
+
+    fn get_jobs_for_reflect(&self) -> &::protobuf::RepeatedField<Job> {
+        &self.jobs
+    }
+
+    fn mut_jobs_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Job> {
+        &mut self.jobs
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+
+    // optional uint64 stop = 3;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
+    }
+
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
+    }
+
+    // optional uint64 count = 4;
+
+    pub fn clear_count(&mut self) {
+        self.count = ::std::option::Option::None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.count = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count.unwrap_or(0)
+    }
+
+    fn get_count_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.count
+    }
+
+    fn mut_count_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.count
+    }
+}
+
+impl ::protobuf::Message for ProjectJobsGetResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.jobs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.jobs)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.count = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.jobs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.count {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.jobs {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.start {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.count {
+            os.write_uint64(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for ProjectJobsGetResponse {
+    fn new() -> ProjectJobsGetResponse {
+        ProjectJobsGetResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<ProjectJobsGetResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Job>>(
+                    "jobs",
+                    ProjectJobsGetResponse::get_jobs_for_reflect,
+                    ProjectJobsGetResponse::mut_jobs_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    ProjectJobsGetResponse::get_start_for_reflect,
+                    ProjectJobsGetResponse::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    ProjectJobsGetResponse::get_stop_for_reflect,
+                    ProjectJobsGetResponse::mut_stop_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "count",
+                    ProjectJobsGetResponse::get_count_for_reflect,
+                    ProjectJobsGetResponse::mut_count_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<ProjectJobsGetResponse>(
+                    "ProjectJobsGetResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for ProjectJobsGetResponse {
+    fn clear(&mut self) {
+        self.clear_jobs();
+        self.clear_start();
+        self.clear_stop();
+        self.clear_count();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ProjectJobsGetResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ProjectJobsGetResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobsByTagGet {
+    // message fields
+    tag: ::protobuf::SingularField<::std::string::String>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    created_before: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobsByTagGet {}
+
+impl JobsByTagGet {
+    pub fn new() -> JobsByTagGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobsByTagGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobsByTagGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobsByTagGet,
+        };
+        unsafe {
+            instance.get(JobsByTagGet::new)
+        }
+    }
+
+    // optional string tag = 1;
+
+    pub fn clear_tag(&mut self) {
+        self.tag.clear();
+    }
+
+    pub fn has_tag(&self) -> bool {
+        self.tag.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tag(&mut self, v: ::std::string::String) {
+        self.tag = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_tag(&mut self) -> &mut ::std::string::String {
+        if self.tag.is_none() {
+            self.tag.set_default();
+        }
+        self.tag.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_tag(&mut self) -> ::std::string::String {
+        self.tag.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_tag(&self) -> &str {
+        match self.tag.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_tag_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.tag
+    }
+
+    fn mut_tag_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.tag
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+
+    // optional uint64 stop = 3;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
+    }
+
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
+    }
+
+    // optional string created_before = 4;
+
+    pub fn clear_created_before(&mut self) {
+        self.created_before.clear();
+    }
+
+    pub fn has_created_before(&self) -> bool {
+        self.created_before.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_before(&mut self, v: ::std::string::String) {
+        self.created_before = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_before(&mut self) -> &mut ::std::string::String {
+        if self.created_before.is_none() {
+            self.created_before.set_default();
+        }
+        self.created_before.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_before(&mut self) -> ::std::string::String {
+        self.created_before.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_created_before(&self) -> &str {
+        match self.created_before.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_created_before_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.created_before
+    }
+
+    fn mut_created_before_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.created_before
+    }
+}
+
+impl ::protobuf::Message for JobsByTagGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.tag)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_before)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.tag.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.created_before.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.tag.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.start {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(ref v) = self.created_before.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobsByTagGet {
+    fn new() -> JobsByTagGet {
+        JobsByTagGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobsByTagGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "tag",
+                    JobsByTagGet::get_tag_for_reflect,
+                    JobsByTagGet::mut_tag_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    JobsByTagGet::get_start_for_reflect,
+                    JobsByTagGet::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    JobsByTagGet::get_stop_for_reflect,
+                    JobsByTagGet::mut_stop_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "created_before",
+                    JobsByTagGet::get_created_before_for_reflect,
+                    JobsByTagGet::mut_created_before_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobsByTagGet>(
+                    "JobsByTagGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobsByTagGet {
+    fn clear(&mut self) {
+        self.clear_tag();
+        self.clear_start();
+        self.clear_stop();
+        self.clear_created_before();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobsByTagGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobsByTagGet {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobsByTagGetResponse {
+    // message fields
+    jobs: ::protobuf::RepeatedField<Job>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    count: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobsByTagGetResponse {}
+
+impl JobsByTagGetResponse {
+    pub fn new() -> JobsByTagGetResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobsByTagGetResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobsByTagGetResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobsByTagGetResponse,
+        };
+        unsafe {
+            instance.get(JobsByTagGetResponse::new)
+        }
+    }
+
+    // repeated .jobsrv.Job jobs = 1;
+
+    pub fn clear_jobs(&mut self) {
+        self.jobs.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_jobs(&mut self, v: ::protobuf::RepeatedField<Job>) {
+        self.jobs = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_jobs(&mut self) -> &mut ::protobuf::RepeatedField<Job> {
+        &mut self.jobs
+    }
+
+    // Take field
+    pub fn take_jobs(&mut self) -> ::protobuf::RepeatedField<Job> {
+        ::std::mem::replace(&mut self.jobs, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_jobs(&self) -> &[Job] {
+        &self.jobs
+    }
+
+    fn get_jobs_for_reflect(&self) -> &::protobuf::RepeatedField<Job> {
+        &self.jobs
+    }
+
+    fn mut_jobs_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Job> {
+        &mut self.jobs
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+
+    // optional uint64 stop = 3;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
+    }
+
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
+    }
+
+    // optional uint64 count = 4;
+
+    pub fn clear_count(&mut self) {
+        self.count = ::std::option::Option::None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.count = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count.unwrap_or(0)
+    }
+
+    fn get_count_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.count
+    }
+
+    fn mut_count_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.count
+    }
+}
+
+impl ::protobuf::Message for JobsByTagGetResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.jobs {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.jobs)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.count = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.jobs {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.count {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.jobs {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(v) = self.start {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.count {
+            os.write_uint64(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobsByTagGetResponse {
+    fn new() -> JobsByTagGetResponse {
+        JobsByTagGetResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobsByTagGetResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Job>>(
+                    "jobs",
+                    JobsByTagGetResponse::get_jobs_for_reflect,
+                    JobsByTagGetResponse::mut_jobs_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    JobsByTagGetResponse::get_start_for_reflect,
+                    JobsByTagGetResponse::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    JobsByTagGetResponse::get_stop_for_reflect,
+                    JobsByTagGetResponse::mut_stop_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "count",
+                    JobsByTagGetResponse::get_count_for_reflect,
+                    JobsByTagGetResponse::mut_count_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobsByTagGetResponse>(
+                    "JobsByTagGetResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobsByTagGetResponse {
+    fn clear(&mut self) {
+        self.clear_jobs();
+        self.clear_start();
+        self.clear_stop();
+        self.clear_count();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobsByTagGetResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobsByTagGetResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobLogChunk {
+    // message fields
+    job_id: ::std::option::Option<u64>,
+    seq: ::std::option::Option<u64>,
+    content: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLogChunk {}
+
+impl JobLogChunk {
+    pub fn new() -> JobLogChunk {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLogChunk {
+        static mut instance: ::protobuf::lazy::Lazy<JobLogChunk> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLogChunk,
+        };
+        unsafe {
+            instance.get(JobLogChunk::new)
+        }
+    }
+
+    // optional uint64 job_id = 1;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.job_id
+    }
+
+    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.job_id
+    }
+
+    // optional uint64 seq = 2;
+
+    pub fn clear_seq(&mut self) {
+        self.seq = ::std::option::Option::None;
+    }
+
+    pub fn has_seq(&self) -> bool {
+        self.seq.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_seq(&mut self, v: u64) {
+        self.seq = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_seq(&self) -> u64 {
+        self.seq.unwrap_or(0)
+    }
+
+    fn get_seq_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.seq
+    }
+
+    fn mut_seq_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.seq
+    }
+
+    // optional string content = 3;
+
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    pub fn has_content(&self) -> bool {
+        self.content.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::std::string::String) {
+        self.content = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_content(&mut self) -> &mut ::std::string::String {
+        if self.content.is_none() {
+            self.content.set_default();
+        }
+        self.content.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::std::string::String {
+        self.content.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_content(&self) -> &str {
+        match self.content.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_content_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.content
+    }
+
+    fn mut_content_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.content
+    }
+}
+
+impl ::protobuf::Message for JobLogChunk {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.seq = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.job_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.seq {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.content.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.job_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.seq {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(ref v) = self.content.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLogChunk {
+    fn new() -> JobLogChunk {
+        JobLogChunk::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLogChunk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "job_id",
+                    JobLogChunk::get_job_id_for_reflect,
+                    JobLogChunk::mut_job_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "seq",
+                    JobLogChunk::get_seq_for_reflect,
+                    JobLogChunk::mut_seq_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "content",
+                    JobLogChunk::get_content_for_reflect,
+                    JobLogChunk::mut_content_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLogChunk>(
+                    "JobLogChunk",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLogChunk {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_seq();
+        self.clear_content();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobLogChunk {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobLogChunk {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobLogComplete {
+    // message fields
+    job_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLogComplete {}
+
+impl JobLogComplete {
+    pub fn new() -> JobLogComplete {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLogComplete {
+        static mut instance: ::protobuf::lazy::Lazy<JobLogComplete> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLogComplete,
+        };
+        unsafe {
+            instance.get(JobLogComplete::new)
+        }
+    }
+
+    // optional uint64 job_id = 1;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.job_id
+    }
+
+    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.job_id
+    }
+}
+
+impl ::protobuf::Message for JobLogComplete {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.job_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.job_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLogComplete {
+    fn new() -> JobLogComplete {
+        JobLogComplete::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLogComplete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "job_id",
+                    JobLogComplete::get_job_id_for_reflect,
+                    JobLogComplete::mut_job_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLogComplete>(
+                    "JobLogComplete",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLogComplete {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobLogComplete {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobLogComplete {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobLogGet {
+    // message fields
+    id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLogGet {}
+
+impl JobLogGet {
+    pub fn new() -> JobLogGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLogGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobLogGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLogGet,
+        };
+        unsafe {
+            instance.get(JobLogGet::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+}
+
+impl ::protobuf::Message for JobLogGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.start {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLogGet {
+    fn new() -> JobLogGet {
+        JobLogGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLogGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    JobLogGet::get_id_for_reflect,
+                    JobLogGet::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    JobLogGet::get_start_for_reflect,
+                    JobLogGet::mut_start_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLogGet>(
+                    "JobLogGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLogGet {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_start();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobLogGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobLogGet {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobLogSubscribe {
+    // message fields
+    job_id: ::std::option::Option<u64>,
+    start: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLogSubscribe {}
+
+impl JobLogSubscribe {
+    pub fn new() -> JobLogSubscribe {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLogSubscribe {
+        static mut instance: ::protobuf::lazy::Lazy<JobLogSubscribe> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLogSubscribe,
+        };
+        unsafe {
+            instance.get(JobLogSubscribe::new)
+        }
+    }
+
+    // optional uint64 job_id = 1;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.job_id
+    }
+
+    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.job_id
+    }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+}
+
+impl ::protobuf::Message for JobLogSubscribe {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.job_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.job_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.start {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLogSubscribe {
+    fn new() -> JobLogSubscribe {
+        JobLogSubscribe::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLogSubscribe>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "job_id",
+                    JobLogSubscribe::get_job_id_for_reflect,
+                    JobLogSubscribe::mut_job_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    JobLogSubscribe::get_start_for_reflect,
+                    JobLogSubscribe::mut_start_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLogSubscribe>(
+                    "JobLogSubscribe",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLogSubscribe {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_start();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobLogSubscribe {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobLogSubscribe {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobLog {
+    // message fields
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    content: ::protobuf::RepeatedField<::std::string::String>,
+    is_complete: ::std::option::Option<bool>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobLog {}
+
+impl JobLog {
+    pub fn new() -> JobLog {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobLog {
+        static mut instance: ::protobuf::lazy::Lazy<JobLog> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobLog,
+        };
+        unsafe {
+            instance.get(JobLog::new)
+        }
+    }
+
+    // optional uint64 start = 1;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+
+    // optional uint64 stop = 2;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
+    }
+
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
+    }
+
+    // repeated string content = 3;
+
+    pub fn clear_content(&mut self) {
+        self.content.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_content(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.content = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_content(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.content
+    }
+
+    // Take field
+    pub fn take_content(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.content, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_content(&self) -> &[::std::string::String] {
+        &self.content
+    }
+
+    fn get_content_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.content
+    }
+
+    fn mut_content_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.content
+    }
+
+    // optional bool is_complete = 4;
+
+    pub fn clear_is_complete(&mut self) {
+        self.is_complete = ::std::option::Option::None;
+    }
+
+    pub fn has_is_complete(&self) -> bool {
+        self.is_complete.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_is_complete(&mut self, v: bool) {
+        self.is_complete = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_is_complete(&self) -> bool {
+        self.is_complete.unwrap_or(false)
+    }
+
+    fn get_is_complete_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.is_complete
+    }
+
+    fn mut_is_complete_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.is_complete
+    }
+}
+
+impl ::protobuf::Message for JobLog {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.content)?;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.is_complete = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.content {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        if let Some(v) = self.is_complete {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.start {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(2, v)?;
+        }
+        for v in &self.content {
+            os.write_string(3, &v)?;
+        };
+        if let Some(v) = self.is_complete {
+            os.write_bool(4, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobLog {
+    fn new() -> JobLog {
+        JobLog::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobLog>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    JobLog::get_start_for_reflect,
+                    JobLog::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    JobLog::get_stop_for_reflect,
+                    JobLog::mut_stop_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "content",
+                    JobLog::get_content_for_reflect,
+                    JobLog::mut_content_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "is_complete",
+                    JobLog::get_is_complete_for_reflect,
+                    JobLog::mut_is_complete_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobLog>(
+                    "JobLog",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobLog {
+    fn clear(&mut self) {
+        self.clear_start();
+        self.clear_stop();
+        self.clear_content();
+        self.clear_is_complete();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobLog {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobLog {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupSpec {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    package: ::protobuf::SingularField<::std::string::String>,
+    deps_only: ::std::option::Option<bool>,
+    target: ::protobuf::SingularField<::std::string::String>,
+    origin_only: ::std::option::Option<bool>,
+    package_only: ::std::option::Option<bool>,
+    priority: ::std::option::Option<JobPriority>,
+    tags: ::protobuf::RepeatedField<::std::string::String>,
+    packages: ::protobuf::RepeatedField<::std::string::String>,
+    requester_id: ::std::option::Option<u64>,
+    requester_name: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupSpec {}
+
+impl JobGroupSpec {
+    pub fn new() -> JobGroupSpec {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupSpec {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupSpec> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupSpec,
+        };
+        unsafe {
+            instance.get(JobGroupSpec::new)
+        }
+    }
+
+    // optional string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin
+    }
+
+    // optional string package = 2;
+
+    pub fn clear_package(&mut self) {
+        self.package.clear();
+    }
+
+    pub fn has_package(&self) -> bool {
+        self.package.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_package(&mut self, v: ::std::string::String) {
+        self.package = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_package(&mut self) -> &mut ::std::string::String {
+        if self.package.is_none() {
+            self.package.set_default();
+        }
+        self.package.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_package(&mut self) -> ::std::string::String {
+        self.package.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_package(&self) -> &str {
+        match self.package.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_package_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.package
+    }
+
+    fn mut_package_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.package
+    }
+
+    // optional bool deps_only = 3;
+
+    pub fn clear_deps_only(&mut self) {
+        self.deps_only = ::std::option::Option::None;
+    }
+
+    pub fn has_deps_only(&self) -> bool {
+        self.deps_only.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_deps_only(&mut self, v: bool) {
+        self.deps_only = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_deps_only(&self) -> bool {
+        self.deps_only.unwrap_or(false)
+    }
+
+    fn get_deps_only_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.deps_only
+    }
+
+    fn mut_deps_only_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.deps_only
+    }
+
+    // optional string target = 4;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
+
+    // optional bool origin_only = 5;
+
+    pub fn clear_origin_only(&mut self) {
+        self.origin_only = ::std::option::Option::None;
+    }
+
+    pub fn has_origin_only(&self) -> bool {
+        self.origin_only.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin_only(&mut self, v: bool) {
+        self.origin_only = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_origin_only(&self) -> bool {
+        self.origin_only.unwrap_or(false)
+    }
+
+    fn get_origin_only_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.origin_only
+    }
+
+    fn mut_origin_only_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.origin_only
+    }
+
+    // optional bool package_only = 6;
+
+    pub fn clear_package_only(&mut self) {
+        self.package_only = ::std::option::Option::None;
+    }
+
+    pub fn has_package_only(&self) -> bool {
+        self.package_only.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_package_only(&mut self, v: bool) {
+        self.package_only = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_package_only(&self) -> bool {
+        self.package_only.unwrap_or(false)
+    }
+
+    fn get_package_only_for_reflect(&self) -> &::std::option::Option<bool> {
+        &self.package_only
+    }
+
+    fn mut_package_only_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
+        &mut self.package_only
+    }
+
+    // optional .jobsrv.JobPriority priority = 7;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: JobPriority) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> JobPriority {
+        self.priority.unwrap_or(JobPriority::Normal)
+    }
+
+    fn get_priority_for_reflect(&self) -> &::std::option::Option<JobPriority> {
+        &self.priority
+    }
+
+    fn mut_priority_for_reflect(&mut self) -> &mut ::std::option::Option<JobPriority> {
+        &mut self.priority
+    }
+
+    // repeated string tags = 8;
+
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+
+    fn get_tags_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.tags
+    }
+
+    fn mut_tags_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // repeated string packages = 9;
+
+    pub fn clear_packages(&mut self) {
+        self.packages.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_packages(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.packages = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_packages(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.packages
+    }
+
+    // Take field
+    pub fn take_packages(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.packages, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_packages(&self) -> &[::std::string::String] {
+        &self.packages
+    }
+
+    fn get_packages_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.packages
+    }
+
+    fn mut_packages_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.packages
+    }
+
+    // optional uint64 requester_id = 10;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+
+    fn get_requester_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.requester_id
+    }
+
+    fn mut_requester_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.requester_id
+    }
+
+    // optional string requester_name = 11;
+
+    pub fn clear_requester_name(&mut self) {
+        self.requester_name.clear();
+    }
+
+    pub fn has_requester_name(&self) -> bool {
+        self.requester_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_name(&mut self, v: ::std::string::String) {
+        self.requester_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_requester_name(&mut self) -> &mut ::std::string::String {
+        if self.requester_name.is_none() {
+            self.requester_name.set_default();
+        }
+        self.requester_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_requester_name(&mut self) -> ::std::string::String {
+        self.requester_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_requester_name(&self) -> &str {
+        match self.requester_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_requester_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.requester_name
+    }
+
+    fn mut_requester_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.requester_name
+    }
+}
+
+impl ::protobuf::Message for JobGroupSpec {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.package)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.deps_only = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.origin_only = ::std::option::Option::Some(tmp);
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.package_only = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.packages)?;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.requester_name)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.package.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        if let Some(v) = self.deps_only {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(v) = self.origin_only {
+            my_size += 2;
+        }
+        if let Some(v) = self.package_only {
+            my_size += 2;
+        }
+        if let Some(v) = self.priority {
+            my_size += ::protobuf::rt::enum_size(7, v);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        for value in &self.packages {
+            my_size += ::protobuf::rt::string_size(9, &value);
+        };
+        if let Some(v) = self.requester_id {
+            my_size += ::protobuf::rt::value_size(10, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.requester_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(11, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.package.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.deps_only {
+            os.write_bool(3, v)?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(v) = self.origin_only {
+            os.write_bool(5, v)?;
+        }
+        if let Some(v) = self.package_only {
+            os.write_bool(6, v)?;
+        }
+        if let Some(v) = self.priority {
+            os.write_enum(7, v.value())?;
+        }
+        for v in &self.tags {
+            os.write_string(8, &v)?;
+        };
+        for v in &self.packages {
+            os.write_string(9, &v)?;
+        };
+        if let Some(v) = self.requester_id {
+            os.write_uint64(10, v)?;
+        }
+        if let Some(ref v) = self.requester_name.as_ref() {
+            os.write_string(11, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupSpec {
+    fn new() -> JobGroupSpec {
+        JobGroupSpec::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupSpec>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin",
+                    JobGroupSpec::get_origin_for_reflect,
+                    JobGroupSpec::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "package",
+                    JobGroupSpec::get_package_for_reflect,
+                    JobGroupSpec::mut_package_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "deps_only",
+                    JobGroupSpec::get_deps_only_for_reflect,
+                    JobGroupSpec::mut_deps_only_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    JobGroupSpec::get_target_for_reflect,
+                    JobGroupSpec::mut_target_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "origin_only",
+                    JobGroupSpec::get_origin_only_for_reflect,
+                    JobGroupSpec::mut_origin_only_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                    "package_only",
+                    JobGroupSpec::get_package_only_for_reflect,
+                    JobGroupSpec::mut_package_only_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobPriority>>(
+                    "priority",
+                    JobGroupSpec::get_priority_for_reflect,
+                    JobGroupSpec::mut_priority_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "tags",
+                    JobGroupSpec::get_tags_for_reflect,
+                    JobGroupSpec::mut_tags_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "packages",
+                    JobGroupSpec::get_packages_for_reflect,
+                    JobGroupSpec::mut_packages_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "requester_id",
+                    JobGroupSpec::get_requester_id_for_reflect,
+                    JobGroupSpec::mut_requester_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requester_name",
+                    JobGroupSpec::get_requester_name_for_reflect,
+                    JobGroupSpec::mut_requester_name_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupSpec>(
+                    "JobGroupSpec",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupSpec {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_package();
+        self.clear_deps_only();
+        self.clear_target();
+        self.clear_origin_only();
+        self.clear_package_only();
+        self.clear_priority();
+        self.clear_tags();
+        self.clear_packages();
+        self.clear_requester_id();
+        self.clear_requester_name();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupSpec {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupSpec {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupCreateError {
+    // message fields
+    project_name: ::protobuf::SingularField<::std::string::String>,
+    cycle: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupCreateError {}
+
+impl JobGroupCreateError {
+    pub fn new() -> JobGroupCreateError {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupCreateError {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupCreateError> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupCreateError,
+        };
+        unsafe {
+            instance.get(JobGroupCreateError::new)
+        }
+    }
+
+    // optional string project_name = 1;
+
+    pub fn clear_project_name(&mut self) {
+        self.project_name.clear();
+    }
+
+    pub fn has_project_name(&self) -> bool {
+        self.project_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_project_name(&mut self, v: ::std::string::String) {
+        self.project_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_project_name(&mut self) -> &mut ::std::string::String {
+        if self.project_name.is_none() {
+            self.project_name.set_default();
+        }
+        self.project_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_project_name(&mut self) -> ::std::string::String {
+        self.project_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_project_name(&self) -> &str {
+        match self.project_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_project_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.project_name
+    }
+
+    fn mut_project_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.project_name
+    }
+
+    // repeated string cycle = 2;
+
+    pub fn clear_cycle(&mut self) {
+        self.cycle.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cycle(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.cycle = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cycle(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.cycle
+    }
+
+    // Take field
+    pub fn take_cycle(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.cycle, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_cycle(&self) -> &[::std::string::String] {
+        &self.cycle
+    }
+
+    fn get_cycle_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.cycle
+    }
+
+    fn mut_cycle_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.cycle
+    }
+}
+
+impl ::protobuf::Message for JobGroupCreateError {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.project_name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.cycle)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.project_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        for value in &self.cycle {
+            my_size += ::protobuf::rt::string_size(2, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.project_name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        for v in &self.cycle {
+            os.write_string(2, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupCreateError {
+    fn new() -> JobGroupCreateError {
+        JobGroupCreateError::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupCreateError>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "project_name",
+                    JobGroupCreateError::get_project_name_for_reflect,
+                    JobGroupCreateError::mut_project_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "cycle",
+                    JobGroupCreateError::get_cycle_for_reflect,
+                    JobGroupCreateError::mut_cycle_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupCreateError>(
+                    "JobGroupCreateError",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupCreateError {
+    fn clear(&mut self) {
+        self.clear_project_name();
+        self.clear_cycle();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupCreateError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupCreateError {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupPreview {
+    // message fields
+    spec: ::protobuf::SingularPtrField<JobGroupSpec>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupPreview {}
+
+impl JobGroupPreview {
+    pub fn new() -> JobGroupPreview {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupPreview {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupPreview> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupPreview,
+        };
+        unsafe {
+            instance.get(JobGroupPreview::new)
+        }
+    }
+
+    // optional .jobsrv.JobGroupSpec spec = 1;
+
+    pub fn clear_spec(&mut self) {
+        self.spec.clear();
+    }
+
+    pub fn has_spec(&self) -> bool {
+        self.spec.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_spec(&mut self, v: JobGroupSpec) {
+        self.spec = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_spec(&mut self) -> &mut JobGroupSpec {
+        if self.spec.is_none() {
+            self.spec.set_default();
+        }
+        self.spec.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_spec(&mut self) -> JobGroupSpec {
+        self.spec.take().unwrap_or_else(|| JobGroupSpec::new())
+    }
+
+    pub fn get_spec(&self) -> &JobGroupSpec {
+        self.spec.as_ref().unwrap_or_else(|| JobGroupSpec::default_instance())
+    }
+
+    fn get_spec_for_reflect(&self) -> &::protobuf::SingularPtrField<JobGroupSpec> {
+        &self.spec
+    }
+
+    fn mut_spec_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<JobGroupSpec> {
+        &mut self.spec
+    }
+}
+
+impl ::protobuf::Message for JobGroupPreview {
+    fn is_initialized(&self) -> bool {
+        for v in &self.spec {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.spec)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.spec.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.spec.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupPreview {
+    fn new() -> JobGroupPreview {
+        JobGroupPreview::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupPreview>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobGroupSpec>>(
+                    "spec",
+                    JobGroupPreview::get_spec_for_reflect,
+                    JobGroupPreview::mut_spec_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupPreview>(
+                    "JobGroupPreview",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupPreview {
+    fn clear(&mut self) {
+        self.clear_spec();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupPreview {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupPreview {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupPreviewProject {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    ident: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupPreviewProject {}
+
+impl JobGroupPreviewProject {
+    pub fn new() -> JobGroupPreviewProject {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupPreviewProject {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupPreviewProject> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupPreviewProject,
+        };
+        unsafe {
+            instance.get(JobGroupPreviewProject::new)
+        }
+    }
+
+    // optional string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // optional string ident = 2;
+
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
+    }
+
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ident(&mut self, v: ::std::string::String) {
+        self.ident = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&mut self) -> &mut ::std::string::String {
+        if self.ident.is_none() {
+            self.ident.set_default();
+        }
+        self.ident.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ident(&mut self) -> ::std::string::String {
+        self.ident.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ident(&self) -> &str {
+        match self.ident.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_ident_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.ident
+    }
+
+    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.ident
+    }
+}
+
+impl ::protobuf::Message for JobGroupPreviewProject {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ident)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.ident.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.ident.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupPreviewProject {
+    fn new() -> JobGroupPreviewProject {
+        JobGroupPreviewProject::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupPreviewProject>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    JobGroupPreviewProject::get_name_for_reflect,
+                    JobGroupPreviewProject::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "ident",
+                    JobGroupPreviewProject::get_ident_for_reflect,
+                    JobGroupPreviewProject::mut_ident_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupPreviewProject>(
+                    "JobGroupPreviewProject",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupPreviewProject {
+    fn clear(&mut self) {
+        self.clear_name();
+        self.clear_ident();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupPreviewProject {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupPreviewProject {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupPreviewResponse {
+    // message fields
+    projects: ::protobuf::RepeatedField<JobGroupPreviewProject>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupPreviewResponse {}
+
+impl JobGroupPreviewResponse {
+    pub fn new() -> JobGroupPreviewResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupPreviewResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupPreviewResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupPreviewResponse,
+        };
+        unsafe {
+            instance.get(JobGroupPreviewResponse::new)
+        }
+    }
+
+    // repeated .jobsrv.JobGroupPreviewProject projects = 1;
+
+    pub fn clear_projects(&mut self) {
+        self.projects.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_projects(&mut self, v: ::protobuf::RepeatedField<JobGroupPreviewProject>) {
+        self.projects = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_projects(&mut self) -> &mut ::protobuf::RepeatedField<JobGroupPreviewProject> {
+        &mut self.projects
+    }
+
+    // Take field
+    pub fn take_projects(&mut self) -> ::protobuf::RepeatedField<JobGroupPreviewProject> {
+        ::std::mem::replace(&mut self.projects, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_projects(&self) -> &[JobGroupPreviewProject] {
+        &self.projects
+    }
+
+    fn get_projects_for_reflect(&self) -> &::protobuf::RepeatedField<JobGroupPreviewProject> {
+        &self.projects
+    }
+
+    fn mut_projects_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<JobGroupPreviewProject> {
+        &mut self.projects
+    }
+}
+
+impl ::protobuf::Message for JobGroupPreviewResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.projects {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.projects)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.projects {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.projects {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupPreviewResponse {
+    fn new() -> JobGroupPreviewResponse {
+        JobGroupPreviewResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupPreviewResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobGroupPreviewProject>>(
+                    "projects",
+                    JobGroupPreviewResponse::get_projects_for_reflect,
+                    JobGroupPreviewResponse::mut_projects_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupPreviewResponse>(
+                    "JobGroupPreviewResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupPreviewResponse {
+    fn clear(&mut self) {
+        self.clear_projects();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupPreviewResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupPreviewResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupSchedule {
+    // message fields
+    id: ::std::option::Option<u64>,
+    spec: ::protobuf::SingularPtrField<JobGroupSpec>,
+    cron: ::protobuf::SingularField<::std::string::String>,
+    last_run_at: ::protobuf::SingularField<::std::string::String>,
+    next_run_at: ::protobuf::SingularField<::std::string::String>,
+    created_at: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupSchedule {}
+
+impl JobGroupSchedule {
+    pub fn new() -> JobGroupSchedule {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupSchedule {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupSchedule> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupSchedule,
+        };
+        unsafe {
+            instance.get(JobGroupSchedule::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional .jobsrv.JobGroupSpec spec = 2;
+
+    pub fn clear_spec(&mut self) {
+        self.spec.clear();
+    }
+
+    pub fn has_spec(&self) -> bool {
+        self.spec.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_spec(&mut self, v: JobGroupSpec) {
+        self.spec = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_spec(&mut self) -> &mut JobGroupSpec {
+        if self.spec.is_none() {
+            self.spec.set_default();
+        }
+        self.spec.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_spec(&mut self) -> JobGroupSpec {
+        self.spec.take().unwrap_or_else(|| JobGroupSpec::new())
+    }
+
+    pub fn get_spec(&self) -> &JobGroupSpec {
+        self.spec.as_ref().unwrap_or_else(|| JobGroupSpec::default_instance())
+    }
+
+    fn get_spec_for_reflect(&self) -> &::protobuf::SingularPtrField<JobGroupSpec> {
+        &self.spec
+    }
+
+    fn mut_spec_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<JobGroupSpec> {
+        &mut self.spec
+    }
+
+    // optional string cron = 3;
+
+    pub fn clear_cron(&mut self) {
+        self.cron.clear();
+    }
+
+    pub fn has_cron(&self) -> bool {
+        self.cron.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cron(&mut self, v: ::std::string::String) {
+        self.cron = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cron(&mut self) -> &mut ::std::string::String {
+        if self.cron.is_none() {
+            self.cron.set_default();
+        }
+        self.cron.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cron(&mut self) -> ::std::string::String {
+        self.cron.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_cron(&self) -> &str {
+        match self.cron.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_cron_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.cron
+    }
+
+    fn mut_cron_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.cron
+    }
+
+    // optional string last_run_at = 4;
+
+    pub fn clear_last_run_at(&mut self) {
+        self.last_run_at.clear();
+    }
+
+    pub fn has_last_run_at(&self) -> bool {
+        self.last_run_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_run_at(&mut self, v: ::std::string::String) {
+        self.last_run_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_last_run_at(&mut self) -> &mut ::std::string::String {
+        if self.last_run_at.is_none() {
+            self.last_run_at.set_default();
+        }
+        self.last_run_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_last_run_at(&mut self) -> ::std::string::String {
+        self.last_run_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_last_run_at(&self) -> &str {
+        match self.last_run_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_last_run_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.last_run_at
+    }
+
+    fn mut_last_run_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.last_run_at
+    }
+
+    // optional string next_run_at = 5;
+
+    pub fn clear_next_run_at(&mut self) {
+        self.next_run_at.clear();
+    }
+
+    pub fn has_next_run_at(&self) -> bool {
+        self.next_run_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_next_run_at(&mut self, v: ::std::string::String) {
+        self.next_run_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_next_run_at(&mut self) -> &mut ::std::string::String {
+        if self.next_run_at.is_none() {
+            self.next_run_at.set_default();
+        }
+        self.next_run_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_next_run_at(&mut self) -> ::std::string::String {
+        self.next_run_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_next_run_at(&self) -> &str {
+        match self.next_run_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_next_run_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.next_run_at
+    }
+
+    fn mut_next_run_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.next_run_at
+    }
+
+    // optional string created_at = 6;
+
+    pub fn clear_created_at(&mut self) {
+        self.created_at.clear();
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: ::std::string::String) {
+        self.created_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_at(&mut self) -> &mut ::std::string::String {
+        if self.created_at.is_none() {
+            self.created_at.set_default();
+        }
+        self.created_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_at(&mut self) -> ::std::string::String {
+        self.created_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_created_at(&self) -> &str {
+        match self.created_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_created_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.created_at
+    }
+
+    fn mut_created_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.created_at
+    }
+}
+
+impl ::protobuf::Message for JobGroupSchedule {
+    fn is_initialized(&self) -> bool {
+        for v in &self.spec {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.spec)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.cron)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.last_run_at)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.next_run_at)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_at)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.spec.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.cron.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        if let Some(ref v) = self.last_run_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(ref v) = self.next_run_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(ref v) = self.created_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.spec.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.cron.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        if let Some(ref v) = self.last_run_at.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(ref v) = self.next_run_at.as_ref() {
+            os.write_string(5, &v)?;
+        }
+        if let Some(ref v) = self.created_at.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupSchedule {
+    fn new() -> JobGroupSchedule {
+        JobGroupSchedule::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupSchedule>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    JobGroupSchedule::get_id_for_reflect,
+                    JobGroupSchedule::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobGroupSpec>>(
+                    "spec",
+                    JobGroupSchedule::get_spec_for_reflect,
+                    JobGroupSchedule::mut_spec_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "cron",
+                    JobGroupSchedule::get_cron_for_reflect,
+                    JobGroupSchedule::mut_cron_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "last_run_at",
+                    JobGroupSchedule::get_last_run_at_for_reflect,
+                    JobGroupSchedule::mut_last_run_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "next_run_at",
+                    JobGroupSchedule::get_next_run_at_for_reflect,
+                    JobGroupSchedule::mut_next_run_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "created_at",
+                    JobGroupSchedule::get_created_at_for_reflect,
+                    JobGroupSchedule::mut_created_at_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupSchedule>(
+                    "JobGroupSchedule",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupSchedule {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_spec();
+        self.clear_cron();
+        self.clear_last_run_at();
+        self.clear_next_run_at();
+        self.clear_created_at();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupSchedule {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupSchedule {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupScheduleCreate {
+    // message fields
+    spec: ::protobuf::SingularPtrField<JobGroupSpec>,
+    cron: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupScheduleCreate {}
+
+impl JobGroupScheduleCreate {
+    pub fn new() -> JobGroupScheduleCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupScheduleCreate {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupScheduleCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupScheduleCreate,
+        };
+        unsafe {
+            instance.get(JobGroupScheduleCreate::new)
+        }
+    }
+
+    // optional .jobsrv.JobGroupSpec spec = 1;
+
+    pub fn clear_spec(&mut self) {
+        self.spec.clear();
+    }
+
+    pub fn has_spec(&self) -> bool {
+        self.spec.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_spec(&mut self, v: JobGroupSpec) {
+        self.spec = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_spec(&mut self) -> &mut JobGroupSpec {
+        if self.spec.is_none() {
+            self.spec.set_default();
+        }
+        self.spec.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_spec(&mut self) -> JobGroupSpec {
+        self.spec.take().unwrap_or_else(|| JobGroupSpec::new())
+    }
+
+    pub fn get_spec(&self) -> &JobGroupSpec {
+        self.spec.as_ref().unwrap_or_else(|| JobGroupSpec::default_instance())
+    }
+
+    fn get_spec_for_reflect(&self) -> &::protobuf::SingularPtrField<JobGroupSpec> {
+        &self.spec
+    }
+
+    fn mut_spec_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<JobGroupSpec> {
+        &mut self.spec
+    }
+
+    // optional string cron = 2;
+
+    pub fn clear_cron(&mut self) {
+        self.cron.clear();
+    }
+
+    pub fn has_cron(&self) -> bool {
+        self.cron.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cron(&mut self, v: ::std::string::String) {
+        self.cron = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cron(&mut self) -> &mut ::std::string::String {
+        if self.cron.is_none() {
+            self.cron.set_default();
+        }
+        self.cron.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cron(&mut self) -> ::std::string::String {
+        self.cron.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_cron(&self) -> &str {
+        match self.cron.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_cron_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.cron
+    }
+
+    fn mut_cron_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.cron
+    }
+}
+
+impl ::protobuf::Message for JobGroupScheduleCreate {
+    fn is_initialized(&self) -> bool {
+        for v in &self.spec {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.spec)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.cron)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.spec.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.cron.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.spec.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.cron.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupScheduleCreate {
+    fn new() -> JobGroupScheduleCreate {
+        JobGroupScheduleCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupScheduleCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobGroupSpec>>(
+                    "spec",
+                    JobGroupScheduleCreate::get_spec_for_reflect,
+                    JobGroupScheduleCreate::mut_spec_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "cron",
+                    JobGroupScheduleCreate::get_cron_for_reflect,
+                    JobGroupScheduleCreate::mut_cron_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupScheduleCreate>(
+                    "JobGroupScheduleCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupScheduleCreate {
+    fn clear(&mut self) {
+        self.clear_spec();
+        self.clear_cron();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupScheduleCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupScheduleCreate {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupScheduleListGet {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupScheduleListGet {}
+
+impl JobGroupScheduleListGet {
+    pub fn new() -> JobGroupScheduleListGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupScheduleListGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupScheduleListGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupScheduleListGet,
+        };
+        unsafe {
+            instance.get(JobGroupScheduleListGet::new)
+        }
+    }
+
+    // optional string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin
+    }
+}
+
+impl ::protobuf::Message for JobGroupScheduleListGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupScheduleListGet {
+    fn new() -> JobGroupScheduleListGet {
+        JobGroupScheduleListGet::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupScheduleListGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin",
+                    JobGroupScheduleListGet::get_origin_for_reflect,
+                    JobGroupScheduleListGet::mut_origin_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupScheduleListGet>(
+                    "JobGroupScheduleListGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupScheduleListGet {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupScheduleListGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupScheduleListGet {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupScheduleListResponse {
+    // message fields
+    schedules: ::protobuf::RepeatedField<JobGroupSchedule>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupScheduleListResponse {}
+
+impl JobGroupScheduleListResponse {
+    pub fn new() -> JobGroupScheduleListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupScheduleListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupScheduleListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupScheduleListResponse,
+        };
+        unsafe {
+            instance.get(JobGroupScheduleListResponse::new)
+        }
+    }
+
+    // repeated .jobsrv.JobGroupSchedule schedules = 1;
+
+    pub fn clear_schedules(&mut self) {
+        self.schedules.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_schedules(&mut self, v: ::protobuf::RepeatedField<JobGroupSchedule>) {
+        self.schedules = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_schedules(&mut self) -> &mut ::protobuf::RepeatedField<JobGroupSchedule> {
+        &mut self.schedules
+    }
+
+    // Take field
+    pub fn take_schedules(&mut self) -> ::protobuf::RepeatedField<JobGroupSchedule> {
+        ::std::mem::replace(&mut self.schedules, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_schedules(&self) -> &[JobGroupSchedule] {
+        &self.schedules
+    }
+
+    fn get_schedules_for_reflect(&self) -> &::protobuf::RepeatedField<JobGroupSchedule> {
+        &self.schedules
+    }
+
+    fn mut_schedules_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<JobGroupSchedule> {
+        &mut self.schedules
+    }
+}
+
+impl ::protobuf::Message for JobGroupScheduleListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.schedules {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.schedules)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.schedules {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.schedules {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupScheduleListResponse {
+    fn new() -> JobGroupScheduleListResponse {
+        JobGroupScheduleListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupScheduleListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobGroupSchedule>>(
+                    "schedules",
+                    JobGroupScheduleListResponse::get_schedules_for_reflect,
+                    JobGroupScheduleListResponse::mut_schedules_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupScheduleListResponse>(
+                    "JobGroupScheduleListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupScheduleListResponse {
+    fn clear(&mut self) {
+        self.clear_schedules();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupScheduleListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupScheduleListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupScheduleDelete {
+    // message fields
+    id: ::std::option::Option<u64>,
+    origin: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupScheduleDelete {}
+
+impl JobGroupScheduleDelete {
+    pub fn new() -> JobGroupScheduleDelete {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupScheduleDelete {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupScheduleDelete> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupScheduleDelete,
+        };
+        unsafe {
+            instance.get(JobGroupScheduleDelete::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional string origin = 2;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin
+    }
+}
+
+impl ::protobuf::Message for JobGroupScheduleDelete {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.origin.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGroupScheduleDelete {
+    fn new() -> JobGroupScheduleDelete {
+        JobGroupScheduleDelete::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGroupScheduleDelete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    JobGroupScheduleDelete::get_id_for_reflect,
+                    JobGroupScheduleDelete::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin",
+                    JobGroupScheduleDelete::get_origin_for_reflect,
+                    JobGroupScheduleDelete::mut_origin_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupScheduleDelete>(
+                    "JobGroupScheduleDelete",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGroupScheduleDelete {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_origin();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGroupScheduleDelete {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGroupScheduleDelete {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGroupProject {
+    // message fields
+    name: ::protobuf::SingularField<::std::string::String>,
+    ident: ::protobuf::SingularField<::std::string::String>,
+    state: ::std::option::Option<JobGroupProjectState>,
+    job_id: ::std::option::Option<u64>,
+    target: ::protobuf::SingularField<::std::string::String>,
+    build_started_at: ::protobuf::SingularField<::std::string::String>,
+    build_finished_at: ::protobuf::SingularField<::std::string::String>,
+    skip_reason: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGroupProject {}
+
+impl JobGroupProject {
+    pub fn new() -> JobGroupProject {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGroupProject {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupProject> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGroupProject,
+        };
+        unsafe {
+            instance.get(JobGroupProject::new)
+        }
+    }
+
+    // optional string name = 1;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // optional string ident = 2;
+
+    pub fn clear_ident(&mut self) {
+        self.ident.clear();
+    }
+
+    pub fn has_ident(&self) -> bool {
+        self.ident.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ident(&mut self, v: ::std::string::String) {
+        self.ident = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ident(&mut self) -> &mut ::std::string::String {
+        if self.ident.is_none() {
+            self.ident.set_default();
+        }
+        self.ident.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ident(&mut self) -> ::std::string::String {
+        self.ident.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_ident(&self) -> &str {
+        match self.ident.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_ident_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.ident
+    }
+
+    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.ident
+    }
+
+    // optional .jobsrv.JobGroupProjectState state = 3;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: JobGroupProjectState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> JobGroupProjectState {
+        self.state.unwrap_or(JobGroupProjectState::NotStarted)
+    }
+
+    fn get_state_for_reflect(&self) -> &::std::option::Option<JobGroupProjectState> {
+        &self.state
+    }
+
+    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<JobGroupProjectState> {
+        &mut self.state
+    }
+
+    // optional uint64 job_id = 4;
+
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
+    }
+
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
+    }
+
+    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.job_id
+    }
+
+    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.job_id
+    }
+
+    // optional string target = 5;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
 
-impl ::protobuf::MessageStatic for JobGet {
-    fn new() -> JobGet {
-        JobGet::new()
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "id",
-                    JobGet::get_id_for_reflect,
-                    JobGet::mut_id_for_reflect,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobGet>(
-                    "JobGet",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
         }
+        self.target.as_mut().unwrap()
     }
-}
 
-impl ::protobuf::Clear for JobGet {
-    fn clear(&mut self) {
-        self.clear_id();
-        self.unknown_fields.clear();
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
     }
-}
 
-impl ::std::fmt::Debug for JobGet {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for JobGet {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct JobSpec {
-    // message fields
-    owner_id: ::std::option::Option<u64>,
-    project: ::protobuf::SingularPtrField<super::originsrv::OriginProject>,
-    channel: ::protobuf::SingularField<::std::string::String>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
-}
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
 
-// see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobSpec {}
+    // optional string build_started_at = 6;
 
-impl JobSpec {
-    pub fn new() -> JobSpec {
-        ::std::default::Default::default()
+    pub fn clear_build_started_at(&mut self) {
+        self.build_started_at.clear();
     }
 
-    pub fn default_instance() -> &'static JobSpec {
-        static mut instance: ::protobuf::lazy::Lazy<JobSpec> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobSpec,
-        };
-        unsafe {
-            instance.get(JobSpec::new)
-        }
+    pub fn has_build_started_at(&self) -> bool {
+        self.build_started_at.is_some()
     }
 
-    // optional uint64 owner_id = 1;
-
-    pub fn clear_owner_id(&mut self) {
-        self.owner_id = ::std::option::Option::None;
+    // Param is passed by value, moved
+    pub fn set_build_started_at(&mut self, v: ::std::string::String) {
+        self.build_started_at = ::protobuf::SingularField::some(v);
     }
 
-    pub fn has_owner_id(&self) -> bool {
-        self.owner_id.is_some()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_build_started_at(&mut self) -> &mut ::std::string::String {
+        if self.build_started_at.is_none() {
+            self.build_started_at.set_default();
+        }
+        self.build_started_at.as_mut().unwrap()
     }
 
-    // Param is passed by value, moved
-    pub fn set_owner_id(&mut self, v: u64) {
-        self.owner_id = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_build_started_at(&mut self) -> ::std::string::String {
+        self.build_started_at.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_owner_id(&self) -> u64 {
-        self.owner_id.unwrap_or(0)
+    pub fn get_build_started_at(&self) -> &str {
+        match self.build_started_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_owner_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.owner_id
+    fn get_build_started_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.build_started_at
     }
 
-    fn mut_owner_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.owner_id
+    fn mut_build_started_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.build_started_at
     }
 
-    // optional .originsrv.OriginProject project = 2;
+    // optional string build_finished_at = 7;
 
-    pub fn clear_project(&mut self) {
-        self.project.clear();
+    pub fn clear_build_finished_at(&mut self) {
+        self.build_finished_at.clear();
     }
 
-    pub fn has_project(&self) -> bool {
-        self.project.is_some()
+    pub fn has_build_finished_at(&self) -> bool {
+        self.build_finished_at.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_project(&mut self, v: super::originsrv::OriginProject) {
-        self.project = ::protobuf::SingularPtrField::some(v);
+    pub fn set_build_finished_at(&mut self, v: ::std::string::String) {
+        self.build_finished_at = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_project(&mut self) -> &mut super::originsrv::OriginProject {
-        if self.project.is_none() {
-            self.project.set_default();
+    pub fn mut_build_finished_at(&mut self) -> &mut ::std::string::String {
+        if self.build_finished_at.is_none() {
+            self.build_finished_at.set_default();
         }
-        self.project.as_mut().unwrap()
+        self.build_finished_at.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_project(&mut self) -> super::originsrv::OriginProject {
-        self.project.take().unwrap_or_else(|| super::originsrv::OriginProject::new())
+    pub fn take_build_finished_at(&mut self) -> ::std::string::String {
+        self.build_finished_at.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_project(&self) -> &super::originsrv::OriginProject {
-        self.project.as_ref().unwrap_or_else(|| super::originsrv::OriginProject::default_instance())
+    pub fn get_build_finished_at(&self) -> &str {
+        match self.build_finished_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_project_for_reflect(&self) -> &::protobuf::SingularPtrField<super::originsrv::OriginProject> {
-        &self.project
+    fn get_build_finished_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.build_finished_at
     }
 
-    fn mut_project_for_reflect(&mut self) -> &mut ::protobuf::SingularPtrField<super::originsrv::OriginProject> {
-        &mut self.project
+    fn mut_build_finished_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.build_finished_at
     }
 
-    // optional string channel = 3;
+    // optional string skip_reason = 8;
 
-    pub fn clear_channel(&mut self) {
-        self.channel.clear();
+    pub fn clear_skip_reason(&mut self) {
+        self.skip_reason.clear();
     }
 
-    pub fn has_channel(&self) -> bool {
-        self.channel.is_some()
+    pub fn has_skip_reason(&self) -> bool {
+        self.skip_reason.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_channel(&mut self, v: ::std::string::String) {
-        self.channel = ::protobuf::SingularField::some(v);
+    pub fn set_skip_reason(&mut self, v: ::std::string::String) {
+        self.skip_reason = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_channel(&mut self) -> &mut ::std::string::String {
-        if self.channel.is_none() {
-            self.channel.set_default();
+    pub fn mut_skip_reason(&mut self) -> &mut ::std::string::String {
+        if self.skip_reason.is_none() {
+            self.skip_reason.set_default();
         }
-        self.channel.as_mut().unwrap()
+        self.skip_reason.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_channel(&mut self) -> ::std::string::String {
-        self.channel.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_skip_reason(&mut self) -> ::std::string::String {
+        self.skip_reason.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_channel(&self) -> &str {
-        match self.channel.as_ref() {
+    pub fn get_skip_reason(&self) -> &str {
+        match self.skip_reason.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_channel_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.channel
+    fn get_skip_reason_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.skip_reason
     }
 
-    fn mut_channel_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.channel
+    fn mut_skip_reason_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.skip_reason
     }
 }
 
-impl ::protobuf::Message for JobSpec {
+impl ::protobuf::Message for JobGroupProject {
     fn is_initialized(&self) -> bool {
-        for v in &self.project {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -2007,17 +11132,36 @@ impl ::protobuf::Message for JobSpec {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ident)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.owner_id = ::std::option::Option::Some(tmp);
+                    self.job_id = ::std::option::Option::Some(tmp);
                 },
-                2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.project)?;
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
                 },
-                3 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.channel)?;
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_started_at)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.build_finished_at)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.skip_reason)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2031,15 +11175,29 @@ impl ::protobuf::Message for JobSpec {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.owner_id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
         }
-        if let Some(ref v) = self.project.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if let Some(ref v) = self.ident.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
         }
-        if let Some(ref v) = self.channel.as_ref() {
-            my_size += ::protobuf::rt::string_size(3, &v);
+        if let Some(v) = self.state {
+            my_size += ::protobuf::rt::enum_size(3, v);
+        }
+        if let Some(v) = self.job_id {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(ref v) = self.build_started_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(ref v) = self.build_finished_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        if let Some(ref v) = self.skip_reason.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2047,16 +11205,29 @@ impl ::protobuf::Message for JobSpec {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.owner_id {
-            os.write_uint64(1, v)?;
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.ident.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        if let Some(v) = self.state {
+            os.write_enum(3, v.value())?;
+        }
+        if let Some(v) = self.job_id {
+            os.write_uint64(4, v)?;
         }
-        if let Some(ref v) = self.project.as_ref() {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(5, &v)?;
         }
-        if let Some(ref v) = self.channel.as_ref() {
-            os.write_string(3, &v)?;
+        if let Some(ref v) = self.build_started_at.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        if let Some(ref v) = self.build_finished_at.as_ref() {
+            os.write_string(7, &v)?;
+        }
+        if let Some(ref v) = self.skip_reason.as_ref() {
+            os.write_string(8, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2089,12 +11260,12 @@ impl ::protobuf::Message for JobSpec {
     }
 }
 
-impl ::protobuf::MessageStatic for JobSpec {
-    fn new() -> JobSpec {
-        JobSpec::new()
+impl ::protobuf::MessageStatic for JobGroupProject {
+    fn new() -> JobGroupProject {
+        JobGroupProject::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobSpec>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobGroupProject>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2102,23 +11273,48 @@ impl ::protobuf::MessageStatic for JobSpec {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    JobGroupProject::get_name_for_reflect,
+                    JobGroupProject::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "ident",
+                    JobGroupProject::get_ident_for_reflect,
+                    JobGroupProject::mut_ident_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobGroupProjectState>>(
+                    "state",
+                    JobGroupProject::get_state_for_reflect,
+                    JobGroupProject::mut_state_for_reflect,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "owner_id",
-                    JobSpec::get_owner_id_for_reflect,
-                    JobSpec::mut_owner_id_for_reflect,
+                    "job_id",
+                    JobGroupProject::get_job_id_for_reflect,
+                    JobGroupProject::mut_job_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<super::originsrv::OriginProject>>(
-                    "project",
-                    JobSpec::get_project_for_reflect,
-                    JobSpec::mut_project_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    JobGroupProject::get_target_for_reflect,
+                    JobGroupProject::mut_target_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "channel",
-                    JobSpec::get_channel_for_reflect,
-                    JobSpec::mut_channel_for_reflect,
+                    "build_started_at",
+                    JobGroupProject::get_build_started_at_for_reflect,
+                    JobGroupProject::mut_build_started_at_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobSpec>(
-                    "JobSpec",
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "build_finished_at",
+                    JobGroupProject::get_build_finished_at_for_reflect,
+                    JobGroupProject::mut_build_finished_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "skip_reason",
+                    JobGroupProject::get_skip_reason_for_reflect,
+                    JobGroupProject::mut_skip_reason_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupProject>(
+                    "JobGroupProject",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2127,156 +11323,88 @@ impl ::protobuf::MessageStatic for JobSpec {
     }
 }
 
-impl ::protobuf::Clear for JobSpec {
+impl ::protobuf::Clear for JobGroupProject {
     fn clear(&mut self) {
-        self.clear_owner_id();
-        self.clear_project();
-        self.clear_channel();
+        self.clear_name();
+        self.clear_ident();
+        self.clear_state();
+        self.clear_job_id();
+        self.clear_target();
+        self.clear_build_started_at();
+        self.clear_build_finished_at();
+        self.clear_skip_reason();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobSpec {
+impl ::std::fmt::Debug for JobGroupProject {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobSpec {
+impl ::protobuf::reflect::ProtobufValue for JobGroupProject {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ProjectJobsGet {
+pub struct JobGroupAbort {
     // message fields
-    name: ::protobuf::SingularField<::std::string::String>,
-    start: ::std::option::Option<u64>,
-    stop: ::std::option::Option<u64>,
+    group_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for ProjectJobsGet {}
+unsafe impl ::std::marker::Sync for JobGroupAbort {}
 
-impl ProjectJobsGet {
-    pub fn new() -> ProjectJobsGet {
+impl JobGroupAbort {
+    pub fn new() -> JobGroupAbort {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static ProjectJobsGet {
-        static mut instance: ::protobuf::lazy::Lazy<ProjectJobsGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobGroupAbort {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupAbort> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ProjectJobsGet,
+            ptr: 0 as *const JobGroupAbort,
         };
         unsafe {
-            instance.get(ProjectJobsGet::new)
-        }
-    }
-
-    // optional string name = 1;
-
-    pub fn clear_name(&mut self) {
-        self.name.clear();
-    }
-
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        }
-        self.name.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
-    }
-
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
+            instance.get(JobGroupAbort::new)
         }
     }
 
-    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.name
-    }
-
-    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.name
-    }
-
-    // optional uint64 start = 2;
-
-    pub fn clear_start(&mut self) {
-        self.start = ::std::option::Option::None;
-    }
-
-    pub fn has_start(&self) -> bool {
-        self.start.is_some()
-    }
-
-    // Param is passed by value, moved
-    pub fn set_start(&mut self, v: u64) {
-        self.start = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_start(&self) -> u64 {
-        self.start.unwrap_or(0)
-    }
-
-    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.start
-    }
-
-    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.start
-    }
-
-    // optional uint64 stop = 3;
+    // optional uint64 group_id = 1;
 
-    pub fn clear_stop(&mut self) {
-        self.stop = ::std::option::Option::None;
+    pub fn clear_group_id(&mut self) {
+        self.group_id = ::std::option::Option::None;
     }
 
-    pub fn has_stop(&self) -> bool {
-        self.stop.is_some()
+    pub fn has_group_id(&self) -> bool {
+        self.group_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_stop(&mut self, v: u64) {
-        self.stop = ::std::option::Option::Some(v);
+    pub fn set_group_id(&mut self, v: u64) {
+        self.group_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_stop(&self) -> u64 {
-        self.stop.unwrap_or(0)
+    pub fn get_group_id(&self) -> u64 {
+        self.group_id.unwrap_or(0)
     }
 
-    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.stop
+    fn get_group_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.group_id
     }
 
-    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.stop
+    fn mut_group_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.group_id
     }
 }
 
-impl ::protobuf::Message for ProjectJobsGet {
+impl ::protobuf::Message for JobGroupAbort {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2286,21 +11414,11 @@ impl ::protobuf::Message for ProjectJobsGet {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.start = ::std::option::Option::Some(tmp);
-                },
-                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.stop = ::std::option::Option::Some(tmp);
+                    self.group_id = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2314,14 +11432,8 @@ impl ::protobuf::Message for ProjectJobsGet {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.name.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
-        }
-        if let Some(v) = self.start {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.stop {
-            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.group_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2329,14 +11441,8 @@ impl ::protobuf::Message for ProjectJobsGet {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.name.as_ref() {
-            os.write_string(1, &v)?;
-        }
-        if let Some(v) = self.start {
-            os.write_uint64(2, v)?;
-        }
-        if let Some(v) = self.stop {
-            os.write_uint64(3, v)?;
+        if let Some(v) = self.group_id {
+            os.write_uint64(1, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2369,12 +11475,12 @@ impl ::protobuf::Message for ProjectJobsGet {
     }
 }
 
-impl ::protobuf::MessageStatic for ProjectJobsGet {
-    fn new() -> ProjectJobsGet {
-        ProjectJobsGet::new()
+impl ::protobuf::MessageStatic for JobGroupAbort {
+    fn new() -> JobGroupAbort {
+        JobGroupAbort::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<ProjectJobsGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobGroupAbort>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2382,23 +11488,13 @@ impl ::protobuf::MessageStatic for ProjectJobsGet {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    ProjectJobsGet::get_name_for_reflect,
-                    ProjectJobsGet::mut_name_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "start",
-                    ProjectJobsGet::get_start_for_reflect,
-                    ProjectJobsGet::mut_start_for_reflect,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "stop",
-                    ProjectJobsGet::get_stop_for_reflect,
-                    ProjectJobsGet::mut_stop_for_reflect,
+                    "group_id",
+                    JobGroupAbort::get_group_id_for_reflect,
+                    JobGroupAbort::mut_group_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ProjectJobsGet>(
-                    "ProjectJobsGet",
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupAbort>(
+                    "JobGroupAbort",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2407,179 +11503,155 @@ impl ::protobuf::MessageStatic for ProjectJobsGet {
     }
 }
 
-impl ::protobuf::Clear for ProjectJobsGet {
+impl ::protobuf::Clear for JobGroupAbort {
     fn clear(&mut self) {
-        self.clear_name();
-        self.clear_start();
-        self.clear_stop();
+        self.clear_group_id();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ProjectJobsGet {
+impl ::std::fmt::Debug for JobGroupAbort {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ProjectJobsGet {
+impl ::protobuf::reflect::ProtobufValue for JobGroupAbort {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct ProjectJobsGetResponse {
+pub struct JobGroupCancel {
     // message fields
-    jobs: ::protobuf::RepeatedField<Job>,
-    start: ::std::option::Option<u64>,
-    stop: ::std::option::Option<u64>,
-    count: ::std::option::Option<u64>,
+    group_id: ::std::option::Option<u64>,
+    requester_id: ::std::option::Option<u64>,
+    requester_name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for ProjectJobsGetResponse {}
+unsafe impl ::std::marker::Sync for JobGroupCancel {}
 
-impl ProjectJobsGetResponse {
-    pub fn new() -> ProjectJobsGetResponse {
+impl JobGroupCancel {
+    pub fn new() -> JobGroupCancel {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static ProjectJobsGetResponse {
-        static mut instance: ::protobuf::lazy::Lazy<ProjectJobsGetResponse> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobGroupCancel {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupCancel> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ProjectJobsGetResponse,
+            ptr: 0 as *const JobGroupCancel,
         };
         unsafe {
-            instance.get(ProjectJobsGetResponse::new)
+            instance.get(JobGroupCancel::new)
         }
     }
 
-    // repeated .jobsrv.Job jobs = 1;
-
-    pub fn clear_jobs(&mut self) {
-        self.jobs.clear();
-    }
+    // optional uint64 group_id = 1;
 
-    // Param is passed by value, moved
-    pub fn set_jobs(&mut self, v: ::protobuf::RepeatedField<Job>) {
-        self.jobs = v;
+    pub fn clear_group_id(&mut self) {
+        self.group_id = ::std::option::Option::None;
     }
 
-    // Mutable pointer to the field.
-    pub fn mut_jobs(&mut self) -> &mut ::protobuf::RepeatedField<Job> {
-        &mut self.jobs
+    pub fn has_group_id(&self) -> bool {
+        self.group_id.is_some()
     }
 
-    // Take field
-    pub fn take_jobs(&mut self) -> ::protobuf::RepeatedField<Job> {
-        ::std::mem::replace(&mut self.jobs, ::protobuf::RepeatedField::new())
+    // Param is passed by value, moved
+    pub fn set_group_id(&mut self, v: u64) {
+        self.group_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_jobs(&self) -> &[Job] {
-        &self.jobs
+    pub fn get_group_id(&self) -> u64 {
+        self.group_id.unwrap_or(0)
     }
 
-    fn get_jobs_for_reflect(&self) -> &::protobuf::RepeatedField<Job> {
-        &self.jobs
+    fn get_group_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.group_id
     }
 
-    fn mut_jobs_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<Job> {
-        &mut self.jobs
+    fn mut_group_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.group_id
     }
 
-    // optional uint64 start = 2;
+    // optional uint64 requester_id = 2;
 
-    pub fn clear_start(&mut self) {
-        self.start = ::std::option::Option::None;
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
     }
 
-    pub fn has_start(&self) -> bool {
-        self.start.is_some()
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_start(&mut self, v: u64) {
-        self.start = ::std::option::Option::Some(v);
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_start(&self) -> u64 {
-        self.start.unwrap_or(0)
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
     }
 
-    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.start
+    fn get_requester_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.requester_id
     }
 
-    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.start
+    fn mut_requester_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.requester_id
     }
 
-    // optional uint64 stop = 3;
+    // optional string requester_name = 3;
 
-    pub fn clear_stop(&mut self) {
-        self.stop = ::std::option::Option::None;
+    pub fn clear_requester_name(&mut self) {
+        self.requester_name.clear();
     }
 
-    pub fn has_stop(&self) -> bool {
-        self.stop.is_some()
+    pub fn has_requester_name(&self) -> bool {
+        self.requester_name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_stop(&mut self, v: u64) {
-        self.stop = ::std::option::Option::Some(v);
-    }
-
-    pub fn get_stop(&self) -> u64 {
-        self.stop.unwrap_or(0)
-    }
-
-    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.stop
-    }
-
-    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.stop
-    }
-
-    // optional uint64 count = 4;
-
-    pub fn clear_count(&mut self) {
-        self.count = ::std::option::Option::None;
+    pub fn set_requester_name(&mut self, v: ::std::string::String) {
+        self.requester_name = ::protobuf::SingularField::some(v);
     }
 
-    pub fn has_count(&self) -> bool {
-        self.count.is_some()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_requester_name(&mut self) -> &mut ::std::string::String {
+        if self.requester_name.is_none() {
+            self.requester_name.set_default();
+        }
+        self.requester_name.as_mut().unwrap()
     }
 
-    // Param is passed by value, moved
-    pub fn set_count(&mut self, v: u64) {
-        self.count = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_requester_name(&mut self) -> ::std::string::String {
+        self.requester_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_count(&self) -> u64 {
-        self.count.unwrap_or(0)
+    pub fn get_requester_name(&self) -> &str {
+        match self.requester_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_count_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.count
+    fn get_requester_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.requester_name
     }
 
-    fn mut_count_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.count
+    fn mut_requester_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.requester_name
     }
 }
 
-impl ::protobuf::Message for ProjectJobsGetResponse {
+impl ::protobuf::Message for JobGroupCancel {
     fn is_initialized(&self) -> bool {
-        for v in &self.jobs {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -2588,28 +11660,21 @@ impl ::protobuf::Message for ProjectJobsGetResponse {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.jobs)?;
-                },
-                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.start = ::std::option::Option::Some(tmp);
+                    self.group_id = ::std::option::Option::Some(tmp);
                 },
-                3 => {
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.stop = ::std::option::Option::Some(tmp);
+                    self.requester_id = ::std::option::Option::Some(tmp);
                 },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.count = ::std::option::Option::Some(tmp);
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.requester_name)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2623,18 +11688,14 @@ impl ::protobuf::Message for ProjectJobsGetResponse {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.jobs {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        if let Some(v) = self.start {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.group_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.stop {
-            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(v) = self.requester_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.count {
-            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.requester_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -2642,19 +11703,14 @@ impl ::protobuf::Message for ProjectJobsGetResponse {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.jobs {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if let Some(v) = self.start {
-            os.write_uint64(2, v)?;
+        if let Some(v) = self.group_id {
+            os.write_uint64(1, v)?;
         }
-        if let Some(v) = self.stop {
-            os.write_uint64(3, v)?;
+        if let Some(v) = self.requester_id {
+            os.write_uint64(2, v)?;
         }
-        if let Some(v) = self.count {
-            os.write_uint64(4, v)?;
+        if let Some(ref v) = self.requester_name.as_ref() {
+            os.write_string(3, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -2687,12 +11743,12 @@ impl ::protobuf::Message for ProjectJobsGetResponse {
     }
 }
 
-impl ::protobuf::MessageStatic for ProjectJobsGetResponse {
-    fn new() -> ProjectJobsGetResponse {
-        ProjectJobsGetResponse::new()
+impl ::protobuf::MessageStatic for JobGroupCancel {
+    fn new() -> JobGroupCancel {
+        JobGroupCancel::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<ProjectJobsGetResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobGroupCancel>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2700,28 +11756,23 @@ impl ::protobuf::MessageStatic for ProjectJobsGetResponse {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Job>>(
-                    "jobs",
-                    ProjectJobsGetResponse::get_jobs_for_reflect,
-                    ProjectJobsGetResponse::mut_jobs_for_reflect,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "start",
-                    ProjectJobsGetResponse::get_start_for_reflect,
-                    ProjectJobsGetResponse::mut_start_for_reflect,
+                    "group_id",
+                    JobGroupCancel::get_group_id_for_reflect,
+                    JobGroupCancel::mut_group_id_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "stop",
-                    ProjectJobsGetResponse::get_stop_for_reflect,
-                    ProjectJobsGetResponse::mut_stop_for_reflect,
+                    "requester_id",
+                    JobGroupCancel::get_requester_id_for_reflect,
+                    JobGroupCancel::mut_requester_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "count",
-                    ProjectJobsGetResponse::get_count_for_reflect,
-                    ProjectJobsGetResponse::mut_count_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requester_name",
+                    JobGroupCancel::get_requester_name_for_reflect,
+                    JobGroupCancel::mut_requester_name_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<ProjectJobsGetResponse>(
-                    "ProjectJobsGetResponse",
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupCancel>(
+                    "JobGroupCancel",
                     fields,
                     file_descriptor_proto()
                 )
@@ -2730,157 +11781,156 @@ impl ::protobuf::MessageStatic for ProjectJobsGetResponse {
     }
 }
 
-impl ::protobuf::Clear for ProjectJobsGetResponse {
+impl ::protobuf::Clear for JobGroupCancel {
     fn clear(&mut self) {
-        self.clear_jobs();
-        self.clear_start();
-        self.clear_stop();
-        self.clear_count();
+        self.clear_group_id();
+        self.clear_requester_id();
+        self.clear_requester_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for ProjectJobsGetResponse {
+impl ::std::fmt::Debug for JobGroupCancel {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for ProjectJobsGetResponse {
+impl ::protobuf::reflect::ProtobufValue for JobGroupCancel {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobLogChunk {
+pub struct JobGroupRetry {
     // message fields
-    job_id: ::std::option::Option<u64>,
-    seq: ::std::option::Option<u64>,
-    content: ::protobuf::SingularField<::std::string::String>,
+    group_id: ::std::option::Option<u64>,
+    requester_id: ::std::option::Option<u64>,
+    requester_name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobLogChunk {}
+unsafe impl ::std::marker::Sync for JobGroupRetry {}
 
-impl JobLogChunk {
-    pub fn new() -> JobLogChunk {
+impl JobGroupRetry {
+    pub fn new() -> JobGroupRetry {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobLogChunk {
-        static mut instance: ::protobuf::lazy::Lazy<JobLogChunk> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobGroupRetry {
+        static mut instance: ::protobuf::lazy::Lazy<JobGroupRetry> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobLogChunk,
+            ptr: 0 as *const JobGroupRetry,
         };
         unsafe {
-            instance.get(JobLogChunk::new)
+            instance.get(JobGroupRetry::new)
         }
     }
 
-    // optional uint64 job_id = 1;
+    // optional uint64 group_id = 1;
 
-    pub fn clear_job_id(&mut self) {
-        self.job_id = ::std::option::Option::None;
+    pub fn clear_group_id(&mut self) {
+        self.group_id = ::std::option::Option::None;
     }
 
-    pub fn has_job_id(&self) -> bool {
-        self.job_id.is_some()
+    pub fn has_group_id(&self) -> bool {
+        self.group_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_job_id(&mut self, v: u64) {
-        self.job_id = ::std::option::Option::Some(v);
+    pub fn set_group_id(&mut self, v: u64) {
+        self.group_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_job_id(&self) -> u64 {
-        self.job_id.unwrap_or(0)
+    pub fn get_group_id(&self) -> u64 {
+        self.group_id.unwrap_or(0)
     }
 
-    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.job_id
+    fn get_group_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.group_id
     }
 
-    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.job_id
+    fn mut_group_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.group_id
     }
 
-    // optional uint64 seq = 2;
+    // optional uint64 requester_id = 2;
 
-    pub fn clear_seq(&mut self) {
-        self.seq = ::std::option::Option::None;
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
     }
 
-    pub fn has_seq(&self) -> bool {
-        self.seq.is_some()
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_seq(&mut self, v: u64) {
-        self.seq = ::std::option::Option::Some(v);
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_seq(&self) -> u64 {
-        self.seq.unwrap_or(0)
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
     }
 
-    fn get_seq_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.seq
+    fn get_requester_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.requester_id
     }
 
-    fn mut_seq_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.seq
+    fn mut_requester_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.requester_id
     }
 
-    // optional string content = 3;
+    // optional string requester_name = 3;
 
-    pub fn clear_content(&mut self) {
-        self.content.clear();
+    pub fn clear_requester_name(&mut self) {
+        self.requester_name.clear();
     }
 
-    pub fn has_content(&self) -> bool {
-        self.content.is_some()
+    pub fn has_requester_name(&self) -> bool {
+        self.requester_name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_content(&mut self, v: ::std::string::String) {
-        self.content = ::protobuf::SingularField::some(v);
+    pub fn set_requester_name(&mut self, v: ::std::string::String) {
+        self.requester_name = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_content(&mut self) -> &mut ::std::string::String {
-        if self.content.is_none() {
-            self.content.set_default();
+    pub fn mut_requester_name(&mut self) -> &mut ::std::string::String {
+        if self.requester_name.is_none() {
+            self.requester_name.set_default();
         }
-        self.content.as_mut().unwrap()
+        self.requester_name.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_content(&mut self) -> ::std::string::String {
-        self.content.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_requester_name(&mut self) -> ::std::string::String {
+        self.requester_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_content(&self) -> &str {
-        match self.content.as_ref() {
+    pub fn get_requester_name(&self) -> &str {
+        match self.requester_name.as_ref() {
             Some(v) => &v,
             None => "",
         }
     }
 
-    fn get_content_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.content
+    fn get_requester_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.requester_name
     }
 
-    fn mut_content_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.content
+    fn mut_requester_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.requester_name
     }
 }
 
-impl ::protobuf::Message for JobLogChunk {
+impl ::protobuf::Message for JobGroupRetry {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -2894,17 +11944,17 @@ impl ::protobuf::Message for JobLogChunk {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.job_id = ::std::option::Option::Some(tmp);
+                    self.group_id = ::std::option::Option::Some(tmp);
                 },
                 2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.seq = ::std::option::Option::Some(tmp);
+                    self.requester_id = ::std::option::Option::Some(tmp);
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.content)?;
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.requester_name)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -2918,13 +11968,13 @@ impl ::protobuf::Message for JobLogChunk {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.job_id {
+        if let Some(v) = self.group_id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.seq {
+        if let Some(v) = self.requester_id {
             my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.content.as_ref() {
+        if let Some(ref v) = self.requester_name.as_ref() {
             my_size += ::protobuf::rt::string_size(3, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
@@ -2933,13 +11983,13 @@ impl ::protobuf::Message for JobLogChunk {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.job_id {
+        if let Some(v) = self.group_id {
             os.write_uint64(1, v)?;
         }
-        if let Some(v) = self.seq {
+        if let Some(v) = self.requester_id {
             os.write_uint64(2, v)?;
         }
-        if let Some(ref v) = self.content.as_ref() {
+        if let Some(ref v) = self.requester_name.as_ref() {
             os.write_string(3, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
@@ -2973,12 +12023,12 @@ impl ::protobuf::Message for JobLogChunk {
     }
 }
 
-impl ::protobuf::MessageStatic for JobLogChunk {
-    fn new() -> JobLogChunk {
-        JobLogChunk::new()
+impl ::protobuf::MessageStatic for JobGroupRetry {
+    fn new() -> JobGroupRetry {
+        JobGroupRetry::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobLogChunk>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobGroupRetry>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -2987,22 +12037,22 @@ impl ::protobuf::MessageStatic for JobLogChunk {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "job_id",
-                    JobLogChunk::get_job_id_for_reflect,
-                    JobLogChunk::mut_job_id_for_reflect,
+                    "group_id",
+                    JobGroupRetry::get_group_id_for_reflect,
+                    JobGroupRetry::mut_group_id_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "seq",
-                    JobLogChunk::get_seq_for_reflect,
-                    JobLogChunk::mut_seq_for_reflect,
+                    "requester_id",
+                    JobGroupRetry::get_requester_id_for_reflect,
+                    JobGroupRetry::mut_requester_id_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "content",
-                    JobLogChunk::get_content_for_reflect,
-                    JobLogChunk::mut_content_for_reflect,
+                    "requester_name",
+                    JobGroupRetry::get_requester_name_for_reflect,
+                    JobGroupRetry::mut_requester_name_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobLogChunk>(
-                    "JobLogChunk",
+                ::protobuf::reflect::MessageDescriptor::new::<JobGroupRetry>(
+                    "JobGroupRetry",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3011,51 +12061,53 @@ impl ::protobuf::MessageStatic for JobLogChunk {
     }
 }
 
-impl ::protobuf::Clear for JobLogChunk {
+impl ::protobuf::Clear for JobGroupRetry {
     fn clear(&mut self) {
-        self.clear_job_id();
-        self.clear_seq();
-        self.clear_content();
+        self.clear_group_id();
+        self.clear_requester_id();
+        self.clear_requester_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobLogChunk {
+impl ::std::fmt::Debug for JobGroupRetry {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobLogChunk {
+impl ::protobuf::reflect::ProtobufValue for JobGroupRetry {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobLogComplete {
+pub struct JobCancel {
     // message fields
     job_id: ::std::option::Option<u64>,
+    requester_id: ::std::option::Option<u64>,
+    requester_name: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobLogComplete {}
+unsafe impl ::std::marker::Sync for JobCancel {}
 
-impl JobLogComplete {
-    pub fn new() -> JobLogComplete {
+impl JobCancel {
+    pub fn new() -> JobCancel {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobLogComplete {
-        static mut instance: ::protobuf::lazy::Lazy<JobLogComplete> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobCancel {
+        static mut instance: ::protobuf::lazy::Lazy<JobCancel> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobLogComplete,
+            ptr: 0 as *const JobCancel,
         };
         unsafe {
-            instance.get(JobLogComplete::new)
+            instance.get(JobCancel::new)
         }
     }
 
@@ -3085,9 +12137,80 @@ impl JobLogComplete {
     fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
         &mut self.job_id
     }
+
+    // optional uint64 requester_id = 2;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
+    }
+
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
+    }
+
+    fn get_requester_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.requester_id
+    }
+
+    fn mut_requester_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.requester_id
+    }
+
+    // optional string requester_name = 3;
+
+    pub fn clear_requester_name(&mut self) {
+        self.requester_name.clear();
+    }
+
+    pub fn has_requester_name(&self) -> bool {
+        self.requester_name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_requester_name(&mut self, v: ::std::string::String) {
+        self.requester_name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_requester_name(&mut self) -> &mut ::std::string::String {
+        if self.requester_name.is_none() {
+            self.requester_name.set_default();
+        }
+        self.requester_name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_requester_name(&mut self) -> ::std::string::String {
+        self.requester_name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_requester_name(&self) -> &str {
+        match self.requester_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_requester_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.requester_name
+    }
+
+    fn mut_requester_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.requester_name
+    }
 }
 
-impl ::protobuf::Message for JobLogComplete {
+impl ::protobuf::Message for JobCancel {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3103,6 +12226,16 @@ impl ::protobuf::Message for JobLogComplete {
                     let tmp = is.read_uint64()?;
                     self.job_id = ::std::option::Option::Some(tmp);
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.requester_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.requester_name)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3118,6 +12251,12 @@ impl ::protobuf::Message for JobLogComplete {
         if let Some(v) = self.job_id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(v) = self.requester_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.requester_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3127,6 +12266,12 @@ impl ::protobuf::Message for JobLogComplete {
         if let Some(v) = self.job_id {
             os.write_uint64(1, v)?;
         }
+        if let Some(v) = self.requester_id {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(ref v) = self.requester_name.as_ref() {
+            os.write_string(3, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3158,12 +12303,12 @@ impl ::protobuf::Message for JobLogComplete {
     }
 }
 
-impl ::protobuf::MessageStatic for JobLogComplete {
-    fn new() -> JobLogComplete {
-        JobLogComplete::new()
+impl ::protobuf::MessageStatic for JobCancel {
+    fn new() -> JobCancel {
+        JobCancel::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobLogComplete>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobCancel>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3173,11 +12318,21 @@ impl ::protobuf::MessageStatic for JobLogComplete {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "job_id",
-                    JobLogComplete::get_job_id_for_reflect,
-                    JobLogComplete::mut_job_id_for_reflect,
+                    JobCancel::get_job_id_for_reflect,
+                    JobCancel::mut_job_id_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobLogComplete>(
-                    "JobLogComplete",
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "requester_id",
+                    JobCancel::get_requester_id_for_reflect,
+                    JobCancel::mut_requester_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requester_name",
+                    JobCancel::get_requester_name_for_reflect,
+                    JobCancel::mut_requester_name_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobCancel>(
+                    "JobCancel",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3186,50 +12341,60 @@ impl ::protobuf::MessageStatic for JobLogComplete {
     }
 }
 
-impl ::protobuf::Clear for JobLogComplete {
+impl ::protobuf::Clear for JobCancel {
     fn clear(&mut self) {
         self.clear_job_id();
+        self.clear_requester_id();
+        self.clear_requester_name();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobLogComplete {
+impl ::std::fmt::Debug for JobCancel {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobLogComplete {
+impl ::protobuf::reflect::ProtobufValue for JobCancel {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobLogGet {
+pub struct JobAudit {
     // message fields
     id: ::std::option::Option<u64>,
-    start: ::std::option::Option<u64>,
+    job_id: ::std::option::Option<u64>,
+    group_id: ::std::option::Option<u64>,
+    from_state: ::protobuf::SingularField<::std::string::String>,
+    to_state: ::protobuf::SingularField<::std::string::String>,
+    trigger: ::std::option::Option<JobAuditTrigger>,
+    requester_id: ::std::option::Option<u64>,
+    requester_name: ::protobuf::SingularField<::std::string::String>,
+    worker: ::protobuf::SingularField<::std::string::String>,
+    created_at: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobLogGet {}
+unsafe impl ::std::marker::Sync for JobAudit {}
 
-impl JobLogGet {
-    pub fn new() -> JobLogGet {
+impl JobAudit {
+    pub fn new() -> JobAudit {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobLogGet {
-        static mut instance: ::protobuf::lazy::Lazy<JobLogGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobAudit {
+        static mut instance: ::protobuf::lazy::Lazy<JobAudit> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobLogGet,
+            ptr: 0 as *const JobAudit,
         };
         unsafe {
-            instance.get(JobLogGet::new)
+            instance.get(JobAudit::new)
         }
     }
 
@@ -3260,317 +12425,336 @@ impl JobLogGet {
         &mut self.id
     }
 
-    // optional uint64 start = 2;
+    // optional uint64 job_id = 2;
 
-    pub fn clear_start(&mut self) {
-        self.start = ::std::option::Option::None;
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
     }
 
-    pub fn has_start(&self) -> bool {
-        self.start.is_some()
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_start(&mut self, v: u64) {
-        self.start = ::std::option::Option::Some(v);
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_start(&self) -> u64 {
-        self.start.unwrap_or(0)
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
     }
 
-    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.start
+    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.job_id
     }
 
-    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.start
+    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.job_id
     }
-}
 
-impl ::protobuf::Message for JobLogGet {
-    fn is_initialized(&self) -> bool {
-        true
+    // optional uint64 group_id = 3;
+
+    pub fn clear_group_id(&mut self) {
+        self.group_id = ::std::option::Option::None;
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.id = ::std::option::Option::Some(tmp);
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.start = ::std::option::Option::Some(tmp);
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    pub fn has_group_id(&self) -> bool {
+        self.group_id.is_some()
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if let Some(v) = self.id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(v) = self.start {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    // Param is passed by value, moved
+    pub fn set_group_id(&mut self, v: u64) {
+        self.group_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_group_id(&self) -> u64 {
+        self.group_id.unwrap_or(0)
+    }
+
+    fn get_group_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.group_id
+    }
+
+    fn mut_group_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.group_id
+    }
+
+    // optional string from_state = 4;
+
+    pub fn clear_from_state(&mut self) {
+        self.from_state.clear();
+    }
+
+    pub fn has_from_state(&self) -> bool {
+        self.from_state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_from_state(&mut self, v: ::std::string::String) {
+        self.from_state = ::protobuf::SingularField::some(v);
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.id {
-            os.write_uint64(1, v)?;
-        }
-        if let Some(v) = self.start {
-            os.write_uint64(2, v)?;
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_from_state(&mut self) -> &mut ::std::string::String {
+        if self.from_state.is_none() {
+            self.from_state.set_default();
         }
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
+        self.from_state.as_mut().unwrap()
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    // Take field
+    pub fn take_from_state(&mut self) -> ::std::string::String {
+        self.from_state.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    pub fn get_from_state(&self) -> &str {
+        match self.from_state.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
+    fn get_from_state_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.from_state
     }
 
-    fn as_any(&self) -> &::std::any::Any {
-        self as &::std::any::Any
+    fn mut_from_state_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.from_state
     }
-    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
-        self as &mut ::std::any::Any
+
+    // optional string to_state = 5;
+
+    pub fn clear_to_state(&mut self) {
+        self.to_state.clear();
     }
-    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
-        self
+
+    pub fn has_to_state(&self) -> bool {
+        self.to_state.is_some()
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    // Param is passed by value, moved
+    pub fn set_to_state(&mut self, v: ::std::string::String) {
+        self.to_state = ::protobuf::SingularField::some(v);
     }
-}
 
-impl ::protobuf::MessageStatic for JobLogGet {
-    fn new() -> JobLogGet {
-        JobLogGet::new()
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_to_state(&mut self) -> &mut ::std::string::String {
+        if self.to_state.is_none() {
+            self.to_state.set_default();
+        }
+        self.to_state.as_mut().unwrap()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobLogGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
-        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
-        };
-        unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "id",
-                    JobLogGet::get_id_for_reflect,
-                    JobLogGet::mut_id_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "start",
-                    JobLogGet::get_start_for_reflect,
-                    JobLogGet::mut_start_for_reflect,
-                ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobLogGet>(
-                    "JobLogGet",
-                    fields,
-                    file_descriptor_proto()
-                )
-            })
+    // Take field
+    pub fn take_to_state(&mut self) -> ::std::string::String {
+        self.to_state.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_to_state(&self) -> &str {
+        match self.to_state.as_ref() {
+            Some(v) => &v,
+            None => "",
         }
     }
-}
 
-impl ::protobuf::Clear for JobLogGet {
-    fn clear(&mut self) {
-        self.clear_id();
-        self.clear_start();
-        self.unknown_fields.clear();
+    fn get_to_state_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.to_state
     }
-}
 
-impl ::std::fmt::Debug for JobLogGet {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+    fn mut_to_state_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.to_state
     }
-}
 
-impl ::protobuf::reflect::ProtobufValue for JobLogGet {
-    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
-        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    // optional .jobsrv.JobAuditTrigger trigger = 6;
+
+    pub fn clear_trigger(&mut self) {
+        self.trigger = ::std::option::Option::None;
     }
-}
 
-#[derive(PartialEq,Clone,Default)]
-pub struct JobLog {
-    // message fields
-    start: ::std::option::Option<u64>,
-    stop: ::std::option::Option<u64>,
-    content: ::protobuf::RepeatedField<::std::string::String>,
-    is_complete: ::std::option::Option<bool>,
-    // special fields
-    unknown_fields: ::protobuf::UnknownFields,
-    cached_size: ::protobuf::CachedSize,
-}
+    pub fn has_trigger(&self) -> bool {
+        self.trigger.is_some()
+    }
 
-// see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobLog {}
+    // Param is passed by value, moved
+    pub fn set_trigger(&mut self, v: JobAuditTrigger) {
+        self.trigger = ::std::option::Option::Some(v);
+    }
 
-impl JobLog {
-    pub fn new() -> JobLog {
-        ::std::default::Default::default()
+    pub fn get_trigger(&self) -> JobAuditTrigger {
+        self.trigger.unwrap_or(JobAuditTrigger::Scheduler)
     }
 
-    pub fn default_instance() -> &'static JobLog {
-        static mut instance: ::protobuf::lazy::Lazy<JobLog> = ::protobuf::lazy::Lazy {
-            lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobLog,
-        };
-        unsafe {
-            instance.get(JobLog::new)
-        }
+    fn get_trigger_for_reflect(&self) -> &::std::option::Option<JobAuditTrigger> {
+        &self.trigger
     }
 
-    // optional uint64 start = 1;
+    fn mut_trigger_for_reflect(&mut self) -> &mut ::std::option::Option<JobAuditTrigger> {
+        &mut self.trigger
+    }
 
-    pub fn clear_start(&mut self) {
-        self.start = ::std::option::Option::None;
+    // optional uint64 requester_id = 7;
+
+    pub fn clear_requester_id(&mut self) {
+        self.requester_id = ::std::option::Option::None;
     }
 
-    pub fn has_start(&self) -> bool {
-        self.start.is_some()
+    pub fn has_requester_id(&self) -> bool {
+        self.requester_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_start(&mut self, v: u64) {
-        self.start = ::std::option::Option::Some(v);
+    pub fn set_requester_id(&mut self, v: u64) {
+        self.requester_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_start(&self) -> u64 {
-        self.start.unwrap_or(0)
+    pub fn get_requester_id(&self) -> u64 {
+        self.requester_id.unwrap_or(0)
     }
 
-    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.start
+    fn get_requester_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.requester_id
     }
 
-    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.start
+    fn mut_requester_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.requester_id
     }
 
-    // optional uint64 stop = 2;
+    // optional string requester_name = 8;
 
-    pub fn clear_stop(&mut self) {
-        self.stop = ::std::option::Option::None;
+    pub fn clear_requester_name(&mut self) {
+        self.requester_name.clear();
     }
 
-    pub fn has_stop(&self) -> bool {
-        self.stop.is_some()
+    pub fn has_requester_name(&self) -> bool {
+        self.requester_name.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_stop(&mut self, v: u64) {
-        self.stop = ::std::option::Option::Some(v);
+    pub fn set_requester_name(&mut self, v: ::std::string::String) {
+        self.requester_name = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_stop(&self) -> u64 {
-        self.stop.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_requester_name(&mut self) -> &mut ::std::string::String {
+        if self.requester_name.is_none() {
+            self.requester_name.set_default();
+        }
+        self.requester_name.as_mut().unwrap()
     }
 
-    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.stop
+    // Take field
+    pub fn take_requester_name(&mut self) -> ::std::string::String {
+        self.requester_name.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.stop
+    pub fn get_requester_name(&self) -> &str {
+        match self.requester_name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    // repeated string content = 3;
+    fn get_requester_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.requester_name
+    }
 
-    pub fn clear_content(&mut self) {
-        self.content.clear();
+    fn mut_requester_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.requester_name
+    }
+
+    // optional string worker = 9;
+
+    pub fn clear_worker(&mut self) {
+        self.worker.clear();
+    }
+
+    pub fn has_worker(&self) -> bool {
+        self.worker.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_content(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
-        self.content = v;
+    pub fn set_worker(&mut self, v: ::std::string::String) {
+        self.worker = ::protobuf::SingularField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_content(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.content
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_worker(&mut self) -> &mut ::std::string::String {
+        if self.worker.is_none() {
+            self.worker.set_default();
+        }
+        self.worker.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_content(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
-        ::std::mem::replace(&mut self.content, ::protobuf::RepeatedField::new())
+    pub fn take_worker(&mut self) -> ::std::string::String {
+        self.worker.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    pub fn get_content(&self) -> &[::std::string::String] {
-        &self.content
+    pub fn get_worker(&self) -> &str {
+        match self.worker.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
     }
 
-    fn get_content_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
-        &self.content
+    fn get_worker_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.worker
     }
 
-    fn mut_content_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
-        &mut self.content
+    fn mut_worker_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.worker
     }
 
-    // optional bool is_complete = 4;
+    // optional string created_at = 10;
 
-    pub fn clear_is_complete(&mut self) {
-        self.is_complete = ::std::option::Option::None;
+    pub fn clear_created_at(&mut self) {
+        self.created_at.clear();
     }
 
-    pub fn has_is_complete(&self) -> bool {
-        self.is_complete.is_some()
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_is_complete(&mut self, v: bool) {
-        self.is_complete = ::std::option::Option::Some(v);
+    pub fn set_created_at(&mut self, v: ::std::string::String) {
+        self.created_at = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_is_complete(&self) -> bool {
-        self.is_complete.unwrap_or(false)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_at(&mut self) -> &mut ::std::string::String {
+        if self.created_at.is_none() {
+            self.created_at.set_default();
+        }
+        self.created_at.as_mut().unwrap()
     }
 
-    fn get_is_complete_for_reflect(&self) -> &::std::option::Option<bool> {
-        &self.is_complete
+    // Take field
+    pub fn take_created_at(&mut self) -> ::std::string::String {
+        self.created_at.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_is_complete_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
-        &mut self.is_complete
+    pub fn get_created_at(&self) -> &str {
+        match self.created_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_created_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.created_at
+    }
+
+    fn mut_created_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.created_at
     }
 }
 
-impl ::protobuf::Message for JobLog {
+impl ::protobuf::Message for JobAudit {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -3583,25 +12767,51 @@ impl ::protobuf::Message for JobLog {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
-                    self.start = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.group_id = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.from_state)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.to_state)?;
+                },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.trigger = ::std::option::Option::Some(tmp);
                 },
-                2 => {
+                7 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.stop = ::std::option::Option::Some(tmp);
+                    self.requester_id = ::std::option::Option::Some(tmp);
                 },
-                3 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.content)?;
+                8 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.requester_name)?;
                 },
-                4 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.is_complete = ::std::option::Option::Some(tmp);
+                9 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.worker)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_at)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3615,17 +12825,35 @@ impl ::protobuf::Message for JobLog {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.start {
+        if let Some(v) = self.id {
             my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(v) = self.stop {
+        if let Some(v) = self.job_id {
             my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        for value in &self.content {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
-        if let Some(v) = self.is_complete {
-            my_size += 2;
+        if let Some(v) = self.group_id {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.from_state.as_ref() {
+            my_size += ::protobuf::rt::string_size(4, &v);
+        }
+        if let Some(ref v) = self.to_state.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(v) = self.trigger {
+            my_size += ::protobuf::rt::enum_size(6, v);
+        }
+        if let Some(v) = self.requester_id {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.requester_name.as_ref() {
+            my_size += ::protobuf::rt::string_size(8, &v);
+        }
+        if let Some(ref v) = self.worker.as_ref() {
+            my_size += ::protobuf::rt::string_size(9, &v);
+        }
+        if let Some(ref v) = self.created_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(10, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -3633,17 +12861,35 @@ impl ::protobuf::Message for JobLog {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.start {
+        if let Some(v) = self.id {
             os.write_uint64(1, v)?;
         }
-        if let Some(v) = self.stop {
+        if let Some(v) = self.job_id {
             os.write_uint64(2, v)?;
         }
-        for v in &self.content {
-            os.write_string(3, &v)?;
-        };
-        if let Some(v) = self.is_complete {
-            os.write_bool(4, v)?;
+        if let Some(v) = self.group_id {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(ref v) = self.from_state.as_ref() {
+            os.write_string(4, &v)?;
+        }
+        if let Some(ref v) = self.to_state.as_ref() {
+            os.write_string(5, &v)?;
+        }
+        if let Some(v) = self.trigger {
+            os.write_enum(6, v.value())?;
+        }
+        if let Some(v) = self.requester_id {
+            os.write_uint64(7, v)?;
+        }
+        if let Some(ref v) = self.requester_name.as_ref() {
+            os.write_string(8, &v)?;
+        }
+        if let Some(ref v) = self.worker.as_ref() {
+            os.write_string(9, &v)?;
+        }
+        if let Some(ref v) = self.created_at.as_ref() {
+            os.write_string(10, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -3676,12 +12922,12 @@ impl ::protobuf::Message for JobLog {
     }
 }
 
-impl ::protobuf::MessageStatic for JobLog {
-    fn new() -> JobLog {
-        JobLog::new()
+impl ::protobuf::MessageStatic for JobAudit {
+    fn new() -> JobAudit {
+        JobAudit::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobLog>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobAudit>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -3690,27 +12936,57 @@ impl ::protobuf::MessageStatic for JobLog {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "start",
-                    JobLog::get_start_for_reflect,
-                    JobLog::mut_start_for_reflect,
+                    "id",
+                    JobAudit::get_id_for_reflect,
+                    JobAudit::mut_id_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "stop",
-                    JobLog::get_stop_for_reflect,
-                    JobLog::mut_stop_for_reflect,
+                    "job_id",
+                    JobAudit::get_job_id_for_reflect,
+                    JobAudit::mut_job_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "content",
-                    JobLog::get_content_for_reflect,
-                    JobLog::mut_content_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "group_id",
+                    JobAudit::get_group_id_for_reflect,
+                    JobAudit::mut_group_id_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "is_complete",
-                    JobLog::get_is_complete_for_reflect,
-                    JobLog::mut_is_complete_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "from_state",
+                    JobAudit::get_from_state_for_reflect,
+                    JobAudit::mut_from_state_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobLog>(
-                    "JobLog",
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "to_state",
+                    JobAudit::get_to_state_for_reflect,
+                    JobAudit::mut_to_state_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobAuditTrigger>>(
+                    "trigger",
+                    JobAudit::get_trigger_for_reflect,
+                    JobAudit::mut_trigger_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "requester_id",
+                    JobAudit::get_requester_id_for_reflect,
+                    JobAudit::mut_requester_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "requester_name",
+                    JobAudit::get_requester_name_for_reflect,
+                    JobAudit::mut_requester_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "worker",
+                    JobAudit::get_worker_for_reflect,
+                    JobAudit::mut_worker_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "created_at",
+                    JobAudit::get_created_at_for_reflect,
+                    JobAudit::mut_created_at_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobAudit>(
+                    "JobAudit",
                     fields,
                     file_descriptor_proto()
                 )
@@ -3719,276 +12995,322 @@ impl ::protobuf::MessageStatic for JobLog {
     }
 }
 
-impl ::protobuf::Clear for JobLog {
+impl ::protobuf::Clear for JobAudit {
     fn clear(&mut self) {
-        self.clear_start();
-        self.clear_stop();
-        self.clear_content();
-        self.clear_is_complete();
+        self.clear_id();
+        self.clear_job_id();
+        self.clear_group_id();
+        self.clear_from_state();
+        self.clear_to_state();
+        self.clear_trigger();
+        self.clear_requester_id();
+        self.clear_requester_name();
+        self.clear_worker();
+        self.clear_created_at();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobLog {
+impl ::std::fmt::Debug for JobAudit {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobLog {
+impl ::protobuf::reflect::ProtobufValue for JobAudit {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobGroupSpec {
+pub struct JobAuditGet {
     // message fields
-    origin: ::protobuf::SingularField<::std::string::String>,
-    package: ::protobuf::SingularField<::std::string::String>,
-    deps_only: ::std::option::Option<bool>,
-    target: ::protobuf::SingularField<::std::string::String>,
-    origin_only: ::std::option::Option<bool>,
-    package_only: ::std::option::Option<bool>,
+    job_id: ::std::option::Option<u64>,
+    group_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobGroupSpec {}
+unsafe impl ::std::marker::Sync for JobAuditGet {}
 
-impl JobGroupSpec {
-    pub fn new() -> JobGroupSpec {
+impl JobAuditGet {
+    pub fn new() -> JobAuditGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobGroupSpec {
-        static mut instance: ::protobuf::lazy::Lazy<JobGroupSpec> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobAuditGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobAuditGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobGroupSpec,
+            ptr: 0 as *const JobAuditGet,
         };
         unsafe {
-            instance.get(JobGroupSpec::new)
+            instance.get(JobAuditGet::new)
         }
     }
 
-    // optional string origin = 1;
+    // optional uint64 job_id = 1;
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn clear_job_id(&mut self) {
+        self.job_id = ::std::option::Option::None;
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
+    pub fn has_job_id(&self) -> bool {
+        self.job_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: ::std::string::String) {
-        self.origin = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        }
-        self.origin.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_origin(&mut self) -> ::std::string::String {
-        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_job_id(&mut self, v: u64) {
+        self.job_id = ::std::option::Option::Some(v);
     }
 
-    pub fn get_origin(&self) -> &str {
-        match self.origin.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_job_id(&self) -> u64 {
+        self.job_id.unwrap_or(0)
     }
 
-    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.origin
+    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.job_id
     }
 
-    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.origin
+    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.job_id
     }
 
-    // optional string package = 2;
+    // optional uint64 group_id = 2;
 
-    pub fn clear_package(&mut self) {
-        self.package.clear();
+    pub fn clear_group_id(&mut self) {
+        self.group_id = ::std::option::Option::None;
     }
 
-    pub fn has_package(&self) -> bool {
-        self.package.is_some()
+    pub fn has_group_id(&self) -> bool {
+        self.group_id.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_package(&mut self, v: ::std::string::String) {
-        self.package = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_package(&mut self) -> &mut ::std::string::String {
-        if self.package.is_none() {
-            self.package.set_default();
-        }
-        self.package.as_mut().unwrap()
+    pub fn set_group_id(&mut self, v: u64) {
+        self.group_id = ::std::option::Option::Some(v);
     }
 
-    // Take field
-    pub fn take_package(&mut self) -> ::std::string::String {
-        self.package.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn get_group_id(&self) -> u64 {
+        self.group_id.unwrap_or(0)
     }
 
-    pub fn get_package(&self) -> &str {
-        match self.package.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn get_group_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.group_id
     }
 
-    fn get_package_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.package
+    fn mut_group_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.group_id
     }
+}
 
-    fn mut_package_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.package
+impl ::protobuf::Message for JobAuditGet {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    // optional bool deps_only = 3;
-
-    pub fn clear_deps_only(&mut self) {
-        self.deps_only = ::std::option::Option::None;
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.job_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.group_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    pub fn has_deps_only(&self) -> bool {
-        self.deps_only.is_some()
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.job_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.group_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    // Param is passed by value, moved
-    pub fn set_deps_only(&mut self, v: bool) {
-        self.deps_only = ::std::option::Option::Some(v);
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.job_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.group_id {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    pub fn get_deps_only(&self) -> bool {
-        self.deps_only.unwrap_or(false)
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    fn get_deps_only_for_reflect(&self) -> &::std::option::Option<bool> {
-        &self.deps_only
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    fn mut_deps_only_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
-        &mut self.deps_only
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    // optional string target = 4;
-
-    pub fn clear_target(&mut self) {
-        self.target.clear();
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
-
-    pub fn has_target(&self) -> bool {
-        self.target.is_some()
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
     }
-
-    // Param is passed by value, moved
-    pub fn set_target(&mut self, v: ::std::string::String) {
-        self.target = ::protobuf::SingularField::some(v);
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_target(&mut self) -> &mut ::std::string::String {
-        if self.target.is_none() {
-            self.target.set_default();
-        }
-        self.target.as_mut().unwrap()
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
+}
 
-    // Take field
-    pub fn take_target(&mut self) -> ::std::string::String {
-        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+impl ::protobuf::MessageStatic for JobAuditGet {
+    fn new() -> JobAuditGet {
+        JobAuditGet::new()
     }
 
-    pub fn get_target(&self) -> &str {
-        match self.target.as_ref() {
-            Some(v) => &v,
-            None => "",
+    fn descriptor_static(_: ::std::option::Option<JobAuditGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "job_id",
+                    JobAuditGet::get_job_id_for_reflect,
+                    JobAuditGet::mut_job_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "group_id",
+                    JobAuditGet::get_group_id_for_reflect,
+                    JobAuditGet::mut_group_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobAuditGet>(
+                    "JobAuditGet",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
         }
     }
+}
 
-    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.target
+impl ::protobuf::Clear for JobAuditGet {
+    fn clear(&mut self) {
+        self.clear_job_id();
+        self.clear_group_id();
+        self.unknown_fields.clear();
     }
+}
 
-    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.target
+impl ::std::fmt::Debug for JobAuditGet {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // optional bool origin_only = 5;
-
-    pub fn clear_origin_only(&mut self) {
-        self.origin_only = ::std::option::Option::None;
+impl ::protobuf::reflect::ProtobufValue for JobAuditGet {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    pub fn has_origin_only(&self) -> bool {
-        self.origin_only.is_some()
-    }
+#[derive(PartialEq,Clone,Default)]
+pub struct JobAuditResponse {
+    // message fields
+    audits: ::protobuf::RepeatedField<JobAudit>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
 
-    // Param is passed by value, moved
-    pub fn set_origin_only(&mut self, v: bool) {
-        self.origin_only = ::std::option::Option::Some(v);
-    }
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobAuditResponse {}
 
-    pub fn get_origin_only(&self) -> bool {
-        self.origin_only.unwrap_or(false)
+impl JobAuditResponse {
+    pub fn new() -> JobAuditResponse {
+        ::std::default::Default::default()
     }
 
-    fn get_origin_only_for_reflect(&self) -> &::std::option::Option<bool> {
-        &self.origin_only
+    pub fn default_instance() -> &'static JobAuditResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobAuditResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobAuditResponse,
+        };
+        unsafe {
+            instance.get(JobAuditResponse::new)
+        }
     }
 
-    fn mut_origin_only_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
-        &mut self.origin_only
-    }
+    // repeated .jobsrv.JobAudit audits = 1;
 
-    // optional bool package_only = 6;
+    pub fn clear_audits(&mut self) {
+        self.audits.clear();
+    }
 
-    pub fn clear_package_only(&mut self) {
-        self.package_only = ::std::option::Option::None;
+    // Param is passed by value, moved
+    pub fn set_audits(&mut self, v: ::protobuf::RepeatedField<JobAudit>) {
+        self.audits = v;
     }
 
-    pub fn has_package_only(&self) -> bool {
-        self.package_only.is_some()
+    // Mutable pointer to the field.
+    pub fn mut_audits(&mut self) -> &mut ::protobuf::RepeatedField<JobAudit> {
+        &mut self.audits
     }
 
-    // Param is passed by value, moved
-    pub fn set_package_only(&mut self, v: bool) {
-        self.package_only = ::std::option::Option::Some(v);
+    // Take field
+    pub fn take_audits(&mut self) -> ::protobuf::RepeatedField<JobAudit> {
+        ::std::mem::replace(&mut self.audits, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_package_only(&self) -> bool {
-        self.package_only.unwrap_or(false)
+    pub fn get_audits(&self) -> &[JobAudit] {
+        &self.audits
     }
 
-    fn get_package_only_for_reflect(&self) -> &::std::option::Option<bool> {
-        &self.package_only
+    fn get_audits_for_reflect(&self) -> &::protobuf::RepeatedField<JobAudit> {
+        &self.audits
     }
 
-    fn mut_package_only_for_reflect(&mut self) -> &mut ::std::option::Option<bool> {
-        &mut self.package_only
+    fn mut_audits_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<JobAudit> {
+        &mut self.audits
     }
 }
 
-impl ::protobuf::Message for JobGroupSpec {
+impl ::protobuf::Message for JobAuditResponse {
     fn is_initialized(&self) -> bool {
+        for v in &self.audits {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -3997,34 +13319,7 @@ impl ::protobuf::Message for JobGroupSpec {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.package)?;
-                },
-                3 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.deps_only = ::std::option::Option::Some(tmp);
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
-                },
-                5 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.origin_only = ::std::option::Option::Some(tmp);
-                },
-                6 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_bool()?;
-                    self.package_only = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.audits)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4038,48 +13333,21 @@ impl ::protobuf::Message for JobGroupSpec {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.origin.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
-        }
-        if let Some(ref v) = self.package.as_ref() {
-            my_size += ::protobuf::rt::string_size(2, &v);
-        }
-        if let Some(v) = self.deps_only {
-            my_size += 2;
-        }
-        if let Some(ref v) = self.target.as_ref() {
-            my_size += ::protobuf::rt::string_size(4, &v);
-        }
-        if let Some(v) = self.origin_only {
-            my_size += 2;
-        }
-        if let Some(v) = self.package_only {
-            my_size += 2;
-        }
+        for value in &self.audits {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.origin.as_ref() {
-            os.write_string(1, &v)?;
-        }
-        if let Some(ref v) = self.package.as_ref() {
-            os.write_string(2, &v)?;
-        }
-        if let Some(v) = self.deps_only {
-            os.write_bool(3, v)?;
-        }
-        if let Some(ref v) = self.target.as_ref() {
-            os.write_string(4, &v)?;
-        }
-        if let Some(v) = self.origin_only {
-            os.write_bool(5, v)?;
-        }
-        if let Some(v) = self.package_only {
-            os.write_bool(6, v)?;
-        }
+        for v in &self.audits {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4111,51 +13379,26 @@ impl ::protobuf::Message for JobGroupSpec {
     }
 }
 
-impl ::protobuf::MessageStatic for JobGroupSpec {
-    fn new() -> JobGroupSpec {
-        JobGroupSpec::new()
+impl ::protobuf::MessageStatic for JobAuditResponse {
+    fn new() -> JobAuditResponse {
+        JobAuditResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobGroupSpec>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobAuditResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
         };
         unsafe {
-            descriptor.get(|| {
-                let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin",
-                    JobGroupSpec::get_origin_for_reflect,
-                    JobGroupSpec::mut_origin_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "package",
-                    JobGroupSpec::get_package_for_reflect,
-                    JobGroupSpec::mut_package_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "deps_only",
-                    JobGroupSpec::get_deps_only_for_reflect,
-                    JobGroupSpec::mut_deps_only_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "target",
-                    JobGroupSpec::get_target_for_reflect,
-                    JobGroupSpec::mut_target_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "origin_only",
-                    JobGroupSpec::get_origin_only_for_reflect,
-                    JobGroupSpec::mut_origin_only_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
-                    "package_only",
-                    JobGroupSpec::get_package_only_for_reflect,
-                    JobGroupSpec::mut_package_only_for_reflect,
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobAudit>>(
+                    "audits",
+                    JobAuditResponse::get_audits_for_reflect,
+                    JobAuditResponse::mut_audits_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobGroupSpec>(
-                    "JobGroupSpec",
+                ::protobuf::reflect::MessageDescriptor::new::<JobAuditResponse>(
+                    "JobAuditResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4164,249 +13407,282 @@ impl ::protobuf::MessageStatic for JobGroupSpec {
     }
 }
 
-impl ::protobuf::Clear for JobGroupSpec {
+impl ::protobuf::Clear for JobAuditResponse {
     fn clear(&mut self) {
-        self.clear_origin();
-        self.clear_package();
-        self.clear_deps_only();
-        self.clear_target();
-        self.clear_origin_only();
-        self.clear_package_only();
+        self.clear_audits();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobGroupSpec {
+impl ::std::fmt::Debug for JobAuditResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobGroupSpec {
+impl ::protobuf::reflect::ProtobufValue for JobAuditResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobGroupProject {
+pub struct JobHistoryPrune {
     // message fields
-    name: ::protobuf::SingularField<::std::string::String>,
-    ident: ::protobuf::SingularField<::std::string::String>,
-    state: ::std::option::Option<JobGroupProjectState>,
-    job_id: ::std::option::Option<u64>,
-    target: ::protobuf::SingularField<::std::string::String>,
+    retention_days: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobGroupProject {}
+unsafe impl ::std::marker::Sync for JobHistoryPrune {}
 
-impl JobGroupProject {
-    pub fn new() -> JobGroupProject {
+impl JobHistoryPrune {
+    pub fn new() -> JobHistoryPrune {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobGroupProject {
-        static mut instance: ::protobuf::lazy::Lazy<JobGroupProject> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobHistoryPrune {
+        static mut instance: ::protobuf::lazy::Lazy<JobHistoryPrune> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobGroupProject,
+            ptr: 0 as *const JobHistoryPrune,
         };
         unsafe {
-            instance.get(JobGroupProject::new)
+            instance.get(JobHistoryPrune::new)
         }
     }
 
-    // optional string name = 1;
+    // optional uint32 retention_days = 1;
 
-    pub fn clear_name(&mut self) {
-        self.name.clear();
+    pub fn clear_retention_days(&mut self) {
+        self.retention_days = ::std::option::Option::None;
     }
 
-    pub fn has_name(&self) -> bool {
-        self.name.is_some()
+    pub fn has_retention_days(&self) -> bool {
+        self.retention_days.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_name(&mut self, v: ::std::string::String) {
-        self.name = ::protobuf::SingularField::some(v);
+    pub fn set_retention_days(&mut self, v: u32) {
+        self.retention_days = ::std::option::Option::Some(v);
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_name(&mut self) -> &mut ::std::string::String {
-        if self.name.is_none() {
-            self.name.set_default();
-        }
-        self.name.as_mut().unwrap()
+    pub fn get_retention_days(&self) -> u32 {
+        self.retention_days.unwrap_or(0)
     }
 
-    // Take field
-    pub fn take_name(&mut self) -> ::std::string::String {
-        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    fn get_retention_days_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.retention_days
     }
 
-    pub fn get_name(&self) -> &str {
-        match self.name.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn mut_retention_days_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.retention_days
     }
+}
 
-    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.name
+impl ::protobuf::Message for JobHistoryPrune {
+    fn is_initialized(&self) -> bool {
+        true
     }
 
-    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.name
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.retention_days = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
     }
 
-    // optional string ident = 2;
-
-    pub fn clear_ident(&mut self) {
-        self.ident.clear();
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.retention_days {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
-    pub fn has_ident(&self) -> bool {
-        self.ident.is_some()
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.retention_days {
+            os.write_uint32(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
     }
 
-    // Param is passed by value, moved
-    pub fn set_ident(&mut self, v: ::std::string::String) {
-        self.ident = ::protobuf::SingularField::some(v);
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
     }
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ident(&mut self) -> &mut ::std::string::String {
-        if self.ident.is_none() {
-            self.ident.set_default();
-        }
-        self.ident.as_mut().unwrap()
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
     }
 
-    // Take field
-    pub fn take_ident(&mut self) -> ::std::string::String {
-        self.ident.take().unwrap_or_else(|| ::std::string::String::new())
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
     }
 
-    pub fn get_ident(&self) -> &str {
-        match self.ident.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
     }
-
-    fn get_ident_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.ident
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
     }
 
-    fn mut_ident_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.ident
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
     }
+}
 
-    // optional .jobsrv.JobGroupProjectState state = 3;
+impl ::protobuf::MessageStatic for JobHistoryPrune {
+    fn new() -> JobHistoryPrune {
+        JobHistoryPrune::new()
+    }
 
-    pub fn clear_state(&mut self) {
-        self.state = ::std::option::Option::None;
+    fn descriptor_static(_: ::std::option::Option<JobHistoryPrune>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "retention_days",
+                    JobHistoryPrune::get_retention_days_for_reflect,
+                    JobHistoryPrune::mut_retention_days_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobHistoryPrune>(
+                    "JobHistoryPrune",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
     }
+}
 
-    pub fn has_state(&self) -> bool {
-        self.state.is_some()
+impl ::protobuf::Clear for JobHistoryPrune {
+    fn clear(&mut self) {
+        self.clear_retention_days();
+        self.unknown_fields.clear();
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_state(&mut self, v: JobGroupProjectState) {
-        self.state = ::std::option::Option::Some(v);
+impl ::std::fmt::Debug for JobHistoryPrune {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    pub fn get_state(&self) -> JobGroupProjectState {
-        self.state.unwrap_or(JobGroupProjectState::NotStarted)
+impl ::protobuf::reflect::ProtobufValue for JobHistoryPrune {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
+}
 
-    fn get_state_for_reflect(&self) -> &::std::option::Option<JobGroupProjectState> {
-        &self.state
+#[derive(PartialEq,Clone,Default)]
+pub struct JobHistoryPruneResponse {
+    // message fields
+    groups_deleted: ::std::option::Option<u64>,
+    jobs_deleted: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobHistoryPruneResponse {}
+
+impl JobHistoryPruneResponse {
+    pub fn new() -> JobHistoryPruneResponse {
+        ::std::default::Default::default()
     }
 
-    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<JobGroupProjectState> {
-        &mut self.state
+    pub fn default_instance() -> &'static JobHistoryPruneResponse {
+        static mut instance: ::protobuf::lazy::Lazy<JobHistoryPruneResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobHistoryPruneResponse,
+        };
+        unsafe {
+            instance.get(JobHistoryPruneResponse::new)
+        }
     }
 
-    // optional uint64 job_id = 4;
+    // optional uint64 groups_deleted = 1;
 
-    pub fn clear_job_id(&mut self) {
-        self.job_id = ::std::option::Option::None;
+    pub fn clear_groups_deleted(&mut self) {
+        self.groups_deleted = ::std::option::Option::None;
     }
 
-    pub fn has_job_id(&self) -> bool {
-        self.job_id.is_some()
+    pub fn has_groups_deleted(&self) -> bool {
+        self.groups_deleted.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_job_id(&mut self, v: u64) {
-        self.job_id = ::std::option::Option::Some(v);
+    pub fn set_groups_deleted(&mut self, v: u64) {
+        self.groups_deleted = ::std::option::Option::Some(v);
     }
 
-    pub fn get_job_id(&self) -> u64 {
-        self.job_id.unwrap_or(0)
+    pub fn get_groups_deleted(&self) -> u64 {
+        self.groups_deleted.unwrap_or(0)
     }
 
-    fn get_job_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.job_id
+    fn get_groups_deleted_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.groups_deleted
     }
 
-    fn mut_job_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.job_id
+    fn mut_groups_deleted_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.groups_deleted
     }
 
-    // optional string target = 5;
+    // optional uint64 jobs_deleted = 2;
 
-    pub fn clear_target(&mut self) {
-        self.target.clear();
+    pub fn clear_jobs_deleted(&mut self) {
+        self.jobs_deleted = ::std::option::Option::None;
     }
 
-    pub fn has_target(&self) -> bool {
-        self.target.is_some()
+    pub fn has_jobs_deleted(&self) -> bool {
+        self.jobs_deleted.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_target(&mut self, v: ::std::string::String) {
-        self.target = ::protobuf::SingularField::some(v);
-    }
-
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_target(&mut self) -> &mut ::std::string::String {
-        if self.target.is_none() {
-            self.target.set_default();
-        }
-        self.target.as_mut().unwrap()
-    }
-
-    // Take field
-    pub fn take_target(&mut self) -> ::std::string::String {
-        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn set_jobs_deleted(&mut self, v: u64) {
+        self.jobs_deleted = ::std::option::Option::Some(v);
     }
 
-    pub fn get_target(&self) -> &str {
-        match self.target.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_jobs_deleted(&self) -> u64 {
+        self.jobs_deleted.unwrap_or(0)
     }
 
-    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.target
+    fn get_jobs_deleted_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.jobs_deleted
     }
 
-    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.target
+    fn mut_jobs_deleted_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.jobs_deleted
     }
 }
 
-impl ::protobuf::Message for JobGroupProject {
+impl ::protobuf::Message for JobHistoryPruneResponse {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4416,27 +13692,18 @@ impl ::protobuf::Message for JobGroupProject {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.ident)?;
-                },
-                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_enum()?;
-                    self.state = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_uint64()?;
+                    self.groups_deleted = ::std::option::Option::Some(tmp);
                 },
-                4 => {
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
-                    self.job_id = ::std::option::Option::Some(tmp);
-                },
-                5 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                    self.jobs_deleted = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4444,26 +13711,17 @@ impl ::protobuf::Message for JobGroupProject {
             };
         }
         ::std::result::Result::Ok(())
-    }
-
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if let Some(ref v) = self.name.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
-        }
-        if let Some(ref v) = self.ident.as_ref() {
-            my_size += ::protobuf::rt::string_size(2, &v);
-        }
-        if let Some(v) = self.state {
-            my_size += ::protobuf::rt::enum_size(3, v);
-        }
-        if let Some(v) = self.job_id {
-            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.groups_deleted {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
-        if let Some(ref v) = self.target.as_ref() {
-            my_size += ::protobuf::rt::string_size(5, &v);
+        if let Some(v) = self.jobs_deleted {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4471,20 +13729,11 @@ impl ::protobuf::Message for JobGroupProject {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.name.as_ref() {
-            os.write_string(1, &v)?;
-        }
-        if let Some(ref v) = self.ident.as_ref() {
-            os.write_string(2, &v)?;
-        }
-        if let Some(v) = self.state {
-            os.write_enum(3, v.value())?;
-        }
-        if let Some(v) = self.job_id {
-            os.write_uint64(4, v)?;
+        if let Some(v) = self.groups_deleted {
+            os.write_uint64(1, v)?;
         }
-        if let Some(ref v) = self.target.as_ref() {
-            os.write_string(5, &v)?;
+        if let Some(v) = self.jobs_deleted {
+            os.write_uint64(2, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4517,12 +13766,12 @@ impl ::protobuf::Message for JobGroupProject {
     }
 }
 
-impl ::protobuf::MessageStatic for JobGroupProject {
-    fn new() -> JobGroupProject {
-        JobGroupProject::new()
+impl ::protobuf::MessageStatic for JobHistoryPruneResponse {
+    fn new() -> JobHistoryPruneResponse {
+        JobHistoryPruneResponse::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobGroupProject>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobHistoryPruneResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4530,33 +13779,18 @@ impl ::protobuf::MessageStatic for JobGroupProject {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    JobGroupProject::get_name_for_reflect,
-                    JobGroupProject::mut_name_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "ident",
-                    JobGroupProject::get_ident_for_reflect,
-                    JobGroupProject::mut_ident_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobGroupProjectState>>(
-                    "state",
-                    JobGroupProject::get_state_for_reflect,
-                    JobGroupProject::mut_state_for_reflect,
-                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "job_id",
-                    JobGroupProject::get_job_id_for_reflect,
-                    JobGroupProject::mut_job_id_for_reflect,
+                    "groups_deleted",
+                    JobHistoryPruneResponse::get_groups_deleted_for_reflect,
+                    JobHistoryPruneResponse::mut_groups_deleted_for_reflect,
                 ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "target",
-                    JobGroupProject::get_target_for_reflect,
-                    JobGroupProject::mut_target_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "jobs_deleted",
+                    JobHistoryPruneResponse::get_jobs_deleted_for_reflect,
+                    JobHistoryPruneResponse::mut_jobs_deleted_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobGroupProject>(
-                    "JobGroupProject",
+                ::protobuf::reflect::MessageDescriptor::new::<JobHistoryPruneResponse>(
+                    "JobHistoryPruneResponse",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4565,85 +13799,99 @@ impl ::protobuf::MessageStatic for JobGroupProject {
     }
 }
 
-impl ::protobuf::Clear for JobGroupProject {
+impl ::protobuf::Clear for JobHistoryPruneResponse {
     fn clear(&mut self) {
-        self.clear_name();
-        self.clear_ident();
-        self.clear_state();
-        self.clear_job_id();
-        self.clear_target();
+        self.clear_groups_deleted();
+        self.clear_jobs_deleted();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobGroupProject {
+impl ::std::fmt::Debug for JobHistoryPruneResponse {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobGroupProject {
+impl ::protobuf::reflect::ProtobufValue for JobHistoryPruneResponse {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobGroupAbort {
+pub struct OriginBuildUsageGet {
     // message fields
-    group_id: ::std::option::Option<u64>,
+    origin: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobGroupAbort {}
+unsafe impl ::std::marker::Sync for OriginBuildUsageGet {}
 
-impl JobGroupAbort {
-    pub fn new() -> JobGroupAbort {
+impl OriginBuildUsageGet {
+    pub fn new() -> OriginBuildUsageGet {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobGroupAbort {
-        static mut instance: ::protobuf::lazy::Lazy<JobGroupAbort> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginBuildUsageGet {
+        static mut instance: ::protobuf::lazy::Lazy<OriginBuildUsageGet> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobGroupAbort,
+            ptr: 0 as *const OriginBuildUsageGet,
         };
         unsafe {
-            instance.get(JobGroupAbort::new)
+            instance.get(OriginBuildUsageGet::new)
         }
     }
 
-    // optional uint64 group_id = 1;
+    // optional string origin = 1;
 
-    pub fn clear_group_id(&mut self) {
-        self.group_id = ::std::option::Option::None;
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
     }
 
-    pub fn has_group_id(&self) -> bool {
-        self.group_id.is_some()
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_group_id(&mut self, v: u64) {
-        self.group_id = ::std::option::Option::Some(v);
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_group_id(&self) -> u64 {
-        self.group_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
     }
 
-    fn get_group_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.group_id
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_group_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.group_id
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin
     }
 }
 
-impl ::protobuf::Message for JobGroupAbort {
+impl ::protobuf::Message for OriginBuildUsageGet {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4653,11 +13901,7 @@ impl ::protobuf::Message for JobGroupAbort {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_uint64()?;
-                    self.group_id = ::std::option::Option::Some(tmp);
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4671,8 +13915,8 @@ impl ::protobuf::Message for JobGroupAbort {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.group_id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.origin.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4680,8 +13924,8 @@ impl ::protobuf::Message for JobGroupAbort {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.group_id {
-            os.write_uint64(1, v)?;
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_string(1, &v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4714,12 +13958,12 @@ impl ::protobuf::Message for JobGroupAbort {
     }
 }
 
-impl ::protobuf::MessageStatic for JobGroupAbort {
-    fn new() -> JobGroupAbort {
-        JobGroupAbort::new()
+impl ::protobuf::MessageStatic for OriginBuildUsageGet {
+    fn new() -> OriginBuildUsageGet {
+        OriginBuildUsageGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobGroupAbort>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginBuildUsageGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4727,13 +13971,13 @@ impl ::protobuf::MessageStatic for JobGroupAbort {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "group_id",
-                    JobGroupAbort::get_group_id_for_reflect,
-                    JobGroupAbort::mut_group_id_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin",
+                    OriginBuildUsageGet::get_origin_for_reflect,
+                    OriginBuildUsageGet::mut_origin_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobGroupAbort>(
-                    "JobGroupAbort",
+                ::protobuf::reflect::MessageDescriptor::new::<OriginBuildUsageGet>(
+                    "OriginBuildUsageGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4742,81 +13986,210 @@ impl ::protobuf::MessageStatic for JobGroupAbort {
     }
 }
 
-impl ::protobuf::Clear for JobGroupAbort {
+impl ::protobuf::Clear for OriginBuildUsageGet {
     fn clear(&mut self) {
-        self.clear_group_id();
+        self.clear_origin();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobGroupAbort {
+impl ::std::fmt::Debug for OriginBuildUsageGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobGroupAbort {
+impl ::protobuf::reflect::ProtobufValue for OriginBuildUsageGet {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobGroupCancel {
+pub struct OriginBuildUsage {
     // message fields
-    group_id: ::std::option::Option<u64>,
+    origin: ::protobuf::SingularField<::std::string::String>,
+    workers: ::std::option::Option<u32>,
+    max_workers: ::std::option::Option<u32>,
+    builds_last_day: ::std::option::Option<u32>,
+    max_builds_per_day: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobGroupCancel {}
+unsafe impl ::std::marker::Sync for OriginBuildUsage {}
 
-impl JobGroupCancel {
-    pub fn new() -> JobGroupCancel {
+impl OriginBuildUsage {
+    pub fn new() -> OriginBuildUsage {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobGroupCancel {
-        static mut instance: ::protobuf::lazy::Lazy<JobGroupCancel> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static OriginBuildUsage {
+        static mut instance: ::protobuf::lazy::Lazy<OriginBuildUsage> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobGroupCancel,
+            ptr: 0 as *const OriginBuildUsage,
         };
         unsafe {
-            instance.get(JobGroupCancel::new)
+            instance.get(OriginBuildUsage::new)
         }
     }
 
-    // optional uint64 group_id = 1;
+    // optional string origin = 1;
 
-    pub fn clear_group_id(&mut self) {
-        self.group_id = ::std::option::Option::None;
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
     }
 
-    pub fn has_group_id(&self) -> bool {
-        self.group_id.is_some()
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_group_id(&mut self, v: u64) {
-        self.group_id = ::std::option::Option::Some(v);
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
     }
 
-    pub fn get_group_id(&self) -> u64 {
-        self.group_id.unwrap_or(0)
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
     }
 
-    fn get_group_id_for_reflect(&self) -> &::std::option::Option<u64> {
-        &self.group_id
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
     }
 
-    fn mut_group_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
-        &mut self.group_id
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin
+    }
+
+    // optional uint32 workers = 2;
+
+    pub fn clear_workers(&mut self) {
+        self.workers = ::std::option::Option::None;
+    }
+
+    pub fn has_workers(&self) -> bool {
+        self.workers.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_workers(&mut self, v: u32) {
+        self.workers = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_workers(&self) -> u32 {
+        self.workers.unwrap_or(0)
+    }
+
+    fn get_workers_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.workers
+    }
+
+    fn mut_workers_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.workers
+    }
+
+    // optional uint32 max_workers = 3;
+
+    pub fn clear_max_workers(&mut self) {
+        self.max_workers = ::std::option::Option::None;
+    }
+
+    pub fn has_max_workers(&self) -> bool {
+        self.max_workers.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_workers(&mut self, v: u32) {
+        self.max_workers = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_max_workers(&self) -> u32 {
+        self.max_workers.unwrap_or(0)
+    }
+
+    fn get_max_workers_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.max_workers
+    }
+
+    fn mut_max_workers_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.max_workers
+    }
+
+    // optional uint32 builds_last_day = 4;
+
+    pub fn clear_builds_last_day(&mut self) {
+        self.builds_last_day = ::std::option::Option::None;
+    }
+
+    pub fn has_builds_last_day(&self) -> bool {
+        self.builds_last_day.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_builds_last_day(&mut self, v: u32) {
+        self.builds_last_day = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_builds_last_day(&self) -> u32 {
+        self.builds_last_day.unwrap_or(0)
+    }
+
+    fn get_builds_last_day_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.builds_last_day
+    }
+
+    fn mut_builds_last_day_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.builds_last_day
+    }
+
+    // optional uint32 max_builds_per_day = 5;
+
+    pub fn clear_max_builds_per_day(&mut self) {
+        self.max_builds_per_day = ::std::option::Option::None;
+    }
+
+    pub fn has_max_builds_per_day(&self) -> bool {
+        self.max_builds_per_day.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_builds_per_day(&mut self, v: u32) {
+        self.max_builds_per_day = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_max_builds_per_day(&self) -> u32 {
+        self.max_builds_per_day.unwrap_or(0)
+    }
+
+    fn get_max_builds_per_day_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.max_builds_per_day
+    }
+
+    fn mut_max_builds_per_day_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.max_builds_per_day
     }
 }
 
-impl ::protobuf::Message for JobGroupCancel {
+impl ::protobuf::Message for OriginBuildUsage {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4826,11 +14199,35 @@ impl ::protobuf::Message for JobGroupCancel {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                },
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
-                    let tmp = is.read_uint64()?;
-                    self.group_id = ::std::option::Option::Some(tmp);
+                    let tmp = is.read_uint32()?;
+                    self.workers = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_workers = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.builds_last_day = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_builds_per_day = ::std::option::Option::Some(tmp);
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4844,8 +14241,20 @@ impl ::protobuf::Message for JobGroupCancel {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(v) = self.group_id {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        if let Some(ref v) = self.origin.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(v) = self.workers {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_workers {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.builds_last_day {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.max_builds_per_day {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4853,8 +14262,20 @@ impl ::protobuf::Message for JobGroupCancel {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(v) = self.group_id {
-            os.write_uint64(1, v)?;
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(v) = self.workers {
+            os.write_uint32(2, v)?;
+        }
+        if let Some(v) = self.max_workers {
+            os.write_uint32(3, v)?;
+        }
+        if let Some(v) = self.builds_last_day {
+            os.write_uint32(4, v)?;
+        }
+        if let Some(v) = self.max_builds_per_day {
+            os.write_uint32(5, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4887,12 +14308,12 @@ impl ::protobuf::Message for JobGroupCancel {
     }
 }
 
-impl ::protobuf::MessageStatic for JobGroupCancel {
-    fn new() -> JobGroupCancel {
-        JobGroupCancel::new()
+impl ::protobuf::MessageStatic for OriginBuildUsage {
+    fn new() -> OriginBuildUsage {
+        OriginBuildUsage::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobGroupCancel>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<OriginBuildUsage>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -4900,13 +14321,33 @@ impl ::protobuf::MessageStatic for JobGroupCancel {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
-                    "group_id",
-                    JobGroupCancel::get_group_id_for_reflect,
-                    JobGroupCancel::mut_group_id_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin",
+                    OriginBuildUsage::get_origin_for_reflect,
+                    OriginBuildUsage::mut_origin_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobGroupCancel>(
-                    "JobGroupCancel",
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "workers",
+                    OriginBuildUsage::get_workers_for_reflect,
+                    OriginBuildUsage::mut_workers_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "max_workers",
+                    OriginBuildUsage::get_max_workers_for_reflect,
+                    OriginBuildUsage::mut_max_workers_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "builds_last_day",
+                    OriginBuildUsage::get_builds_last_day_for_reflect,
+                    OriginBuildUsage::mut_builds_last_day_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "max_builds_per_day",
+                    OriginBuildUsage::get_max_builds_per_day_for_reflect,
+                    OriginBuildUsage::mut_max_builds_per_day_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<OriginBuildUsage>(
+                    "OriginBuildUsage",
                     fields,
                     file_descriptor_proto()
                 )
@@ -4915,20 +14356,24 @@ impl ::protobuf::MessageStatic for JobGroupCancel {
     }
 }
 
-impl ::protobuf::Clear for JobGroupCancel {
+impl ::protobuf::Clear for OriginBuildUsage {
     fn clear(&mut self) {
-        self.clear_group_id();
+        self.clear_origin();
+        self.clear_workers();
+        self.clear_max_workers();
+        self.clear_builds_last_day();
+        self.clear_max_builds_per_day();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobGroupCancel {
+impl ::std::fmt::Debug for OriginBuildUsage {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobGroupCancel {
+impl ::protobuf::reflect::ProtobufValue for OriginBuildUsage {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -5111,6 +14556,11 @@ impl ::protobuf::reflect::ProtobufValue for JobGroupGet {
 pub struct JobGroupOriginGet {
     // message fields
     origin: ::protobuf::SingularField<::std::string::String>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    state: ::std::option::Option<JobGroupState>,
+    created_since: ::protobuf::SingularField<::std::string::String>,
+    created_before: ::protobuf::SingularField<::std::string::String>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -5177,6 +14627,175 @@ impl JobGroupOriginGet {
     fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
         &mut self.origin
     }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+
+    // optional uint64 stop = 3;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
+    }
+
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
+    }
+
+    // optional .jobsrv.JobGroupState state = 4;
+
+    pub fn clear_state(&mut self) {
+        self.state = ::std::option::Option::None;
+    }
+
+    pub fn has_state(&self) -> bool {
+        self.state.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_state(&mut self, v: JobGroupState) {
+        self.state = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_state(&self) -> JobGroupState {
+        self.state.unwrap_or(JobGroupState::GroupPending)
+    }
+
+    fn get_state_for_reflect(&self) -> &::std::option::Option<JobGroupState> {
+        &self.state
+    }
+
+    fn mut_state_for_reflect(&mut self) -> &mut ::std::option::Option<JobGroupState> {
+        &mut self.state
+    }
+
+    // optional string created_since = 5;
+
+    pub fn clear_created_since(&mut self) {
+        self.created_since.clear();
+    }
+
+    pub fn has_created_since(&self) -> bool {
+        self.created_since.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_since(&mut self, v: ::std::string::String) {
+        self.created_since = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_since(&mut self) -> &mut ::std::string::String {
+        if self.created_since.is_none() {
+            self.created_since.set_default();
+        }
+        self.created_since.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_since(&mut self) -> ::std::string::String {
+        self.created_since.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_created_since(&self) -> &str {
+        match self.created_since.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_created_since_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.created_since
+    }
+
+    fn mut_created_since_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.created_since
+    }
+
+    // optional string created_before = 6;
+
+    pub fn clear_created_before(&mut self) {
+        self.created_before.clear();
+    }
+
+    pub fn has_created_before(&self) -> bool {
+        self.created_before.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_before(&mut self, v: ::std::string::String) {
+        self.created_before = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_before(&mut self) -> &mut ::std::string::String {
+        if self.created_before.is_none() {
+            self.created_before.set_default();
+        }
+        self.created_before.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_before(&mut self) -> ::std::string::String {
+        self.created_before.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_created_before(&self) -> &str {
+        match self.created_before.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_created_before_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.created_before
+    }
+
+    fn mut_created_before_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.created_before
+    }
 }
 
 impl ::protobuf::Message for JobGroupOriginGet {
@@ -5191,6 +14810,33 @@ impl ::protobuf::Message for JobGroupOriginGet {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.state = ::std::option::Option::Some(tmp);
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_since)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_before)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5206,6 +14852,21 @@ impl ::protobuf::Message for JobGroupOriginGet {
         if let Some(ref v) = self.origin.as_ref() {
             my_size += ::protobuf::rt::string_size(1, &v);
         }
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.state {
+            my_size += ::protobuf::rt::enum_size(4, v);
+        }
+        if let Some(ref v) = self.created_since.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(ref v) = self.created_before.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -5215,6 +14876,21 @@ impl ::protobuf::Message for JobGroupOriginGet {
         if let Some(ref v) = self.origin.as_ref() {
             os.write_string(1, &v)?;
         }
+        if let Some(v) = self.start {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.state {
+            os.write_enum(4, v.value())?;
+        }
+        if let Some(ref v) = self.created_since.as_ref() {
+            os.write_string(5, &v)?;
+        }
+        if let Some(ref v) = self.created_before.as_ref() {
+            os.write_string(6, &v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5260,9 +14936,34 @@ impl ::protobuf::MessageStatic for JobGroupOriginGet {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin",
-                    JobGroupOriginGet::get_origin_for_reflect,
-                    JobGroupOriginGet::mut_origin_for_reflect,
+                    "origin",
+                    JobGroupOriginGet::get_origin_for_reflect,
+                    JobGroupOriginGet::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    JobGroupOriginGet::get_start_for_reflect,
+                    JobGroupOriginGet::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    JobGroupOriginGet::get_stop_for_reflect,
+                    JobGroupOriginGet::mut_stop_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobGroupState>>(
+                    "state",
+                    JobGroupOriginGet::get_state_for_reflect,
+                    JobGroupOriginGet::mut_state_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "created_since",
+                    JobGroupOriginGet::get_created_since_for_reflect,
+                    JobGroupOriginGet::mut_created_since_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "created_before",
+                    JobGroupOriginGet::get_created_before_for_reflect,
+                    JobGroupOriginGet::mut_created_before_for_reflect,
                 ));
                 ::protobuf::reflect::MessageDescriptor::new::<JobGroupOriginGet>(
                     "JobGroupOriginGet",
@@ -5277,6 +14978,11 @@ impl ::protobuf::MessageStatic for JobGroupOriginGet {
 impl ::protobuf::Clear for JobGroupOriginGet {
     fn clear(&mut self) {
         self.clear_origin();
+        self.clear_start();
+        self.clear_stop();
+        self.clear_state();
+        self.clear_created_since();
+        self.clear_created_before();
         self.unknown_fields.clear();
     }
 }
@@ -5297,6 +15003,9 @@ impl ::protobuf::reflect::ProtobufValue for JobGroupOriginGet {
 pub struct JobGroupOriginResponse {
     // message fields
     job_groups: ::protobuf::RepeatedField<JobGroup>,
+    start: ::std::option::Option<u64>,
+    stop: ::std::option::Option<u64>,
+    count: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -5352,6 +15061,87 @@ impl JobGroupOriginResponse {
     fn mut_job_groups_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<JobGroup> {
         &mut self.job_groups
     }
+
+    // optional uint64 start = 2;
+
+    pub fn clear_start(&mut self) {
+        self.start = ::std::option::Option::None;
+    }
+
+    pub fn has_start(&self) -> bool {
+        self.start.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_start(&mut self, v: u64) {
+        self.start = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_start(&self) -> u64 {
+        self.start.unwrap_or(0)
+    }
+
+    fn get_start_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.start
+    }
+
+    fn mut_start_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.start
+    }
+
+    // optional uint64 stop = 3;
+
+    pub fn clear_stop(&mut self) {
+        self.stop = ::std::option::Option::None;
+    }
+
+    pub fn has_stop(&self) -> bool {
+        self.stop.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_stop(&mut self, v: u64) {
+        self.stop = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_stop(&self) -> u64 {
+        self.stop.unwrap_or(0)
+    }
+
+    fn get_stop_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.stop
+    }
+
+    fn mut_stop_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.stop
+    }
+
+    // optional uint64 count = 4;
+
+    pub fn clear_count(&mut self) {
+        self.count = ::std::option::Option::None;
+    }
+
+    pub fn has_count(&self) -> bool {
+        self.count.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_count(&mut self, v: u64) {
+        self.count = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_count(&self) -> u64 {
+        self.count.unwrap_or(0)
+    }
+
+    fn get_count_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.count
+    }
+
+    fn mut_count_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.count
+    }
 }
 
 impl ::protobuf::Message for JobGroupOriginResponse {
@@ -5371,6 +15161,27 @@ impl ::protobuf::Message for JobGroupOriginResponse {
                 1 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.job_groups)?;
                 },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.start = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.stop = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.count = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5387,6 +15198,15 @@ impl ::protobuf::Message for JobGroupOriginResponse {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if let Some(v) = self.start {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.stop {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.count {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -5398,6 +15218,15 @@ impl ::protobuf::Message for JobGroupOriginResponse {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if let Some(v) = self.start {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(v) = self.stop {
+            os.write_uint64(3, v)?;
+        }
+        if let Some(v) = self.count {
+            os.write_uint64(4, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5447,6 +15276,21 @@ impl ::protobuf::MessageStatic for JobGroupOriginResponse {
                     JobGroupOriginResponse::get_job_groups_for_reflect,
                     JobGroupOriginResponse::mut_job_groups_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "start",
+                    JobGroupOriginResponse::get_start_for_reflect,
+                    JobGroupOriginResponse::mut_start_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "stop",
+                    JobGroupOriginResponse::get_stop_for_reflect,
+                    JobGroupOriginResponse::mut_stop_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "count",
+                    JobGroupOriginResponse::get_count_for_reflect,
+                    JobGroupOriginResponse::mut_count_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<JobGroupOriginResponse>(
                     "JobGroupOriginResponse",
                     fields,
@@ -5460,6 +15304,9 @@ impl ::protobuf::MessageStatic for JobGroupOriginResponse {
 impl ::protobuf::Clear for JobGroupOriginResponse {
     fn clear(&mut self) {
         self.clear_job_groups();
+        self.clear_start();
+        self.clear_stop();
+        self.clear_count();
         self.unknown_fields.clear();
     }
 }
@@ -5484,6 +15331,10 @@ pub struct JobGroup {
     projects: ::protobuf::RepeatedField<JobGroupProject>,
     created_at: ::protobuf::SingularField<::std::string::String>,
     project_name: ::protobuf::SingularField<::std::string::String>,
+    priority: ::std::option::Option<JobPriority>,
+    target: ::protobuf::SingularField<::std::string::String>,
+    tags: ::protobuf::RepeatedField<::std::string::String>,
+    queue_position: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -5681,6 +15532,137 @@ impl JobGroup {
     fn mut_project_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
         &mut self.project_name
     }
+
+    // optional .jobsrv.JobPriority priority = 6;
+
+    pub fn clear_priority(&mut self) {
+        self.priority = ::std::option::Option::None;
+    }
+
+    pub fn has_priority(&self) -> bool {
+        self.priority.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_priority(&mut self, v: JobPriority) {
+        self.priority = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_priority(&self) -> JobPriority {
+        self.priority.unwrap_or(JobPriority::Normal)
+    }
+
+    fn get_priority_for_reflect(&self) -> &::std::option::Option<JobPriority> {
+        &self.priority
+    }
+
+    fn mut_priority_for_reflect(&mut self) -> &mut ::std::option::Option<JobPriority> {
+        &mut self.priority
+    }
+
+    // optional string target = 7;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
+
+    // repeated string tags = 8;
+
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tags(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.tags = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tags(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // Take field
+    pub fn take_tags(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.tags, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tags(&self) -> &[::std::string::String] {
+        &self.tags
+    }
+
+    fn get_tags_for_reflect(&self) -> &::protobuf::RepeatedField<::std::string::String> {
+        &self.tags
+    }
+
+    fn mut_tags_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.tags
+    }
+
+    // optional uint32 queue_position = 9;
+
+    pub fn clear_queue_position(&mut self) {
+        self.queue_position = ::std::option::Option::None;
+    }
+
+    pub fn has_queue_position(&self) -> bool {
+        self.queue_position.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_queue_position(&mut self, v: u32) {
+        self.queue_position = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_queue_position(&self) -> u32 {
+        self.queue_position.unwrap_or(0)
+    }
+
+    fn get_queue_position_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.queue_position
+    }
+
+    fn mut_queue_position_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.queue_position
+    }
 }
 
 impl ::protobuf::Message for JobGroup {
@@ -5720,6 +15702,26 @@ impl ::protobuf::Message for JobGroup {
                 5 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.project_name)?;
                 },
+                6 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_enum()?;
+                    self.priority = ::std::option::Option::Some(tmp);
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.tags)?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.queue_position = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5748,6 +15750,18 @@ impl ::protobuf::Message for JobGroup {
         if let Some(ref v) = self.project_name.as_ref() {
             my_size += ::protobuf::rt::string_size(5, &v);
         }
+        if let Some(v) = self.priority {
+            my_size += ::protobuf::rt::enum_size(6, v);
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        for value in &self.tags {
+            my_size += ::protobuf::rt::string_size(8, &value);
+        };
+        if let Some(v) = self.queue_position {
+            my_size += ::protobuf::rt::value_size(9, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -5771,6 +15785,18 @@ impl ::protobuf::Message for JobGroup {
         if let Some(ref v) = self.project_name.as_ref() {
             os.write_string(5, &v)?;
         }
+        if let Some(v) = self.priority {
+            os.write_enum(6, v.value())?;
+        }
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(7, &v)?;
+        }
+        for v in &self.tags {
+            os.write_string(8, &v)?;
+        };
+        if let Some(v) = self.queue_position {
+            os.write_uint32(9, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5840,6 +15866,26 @@ impl ::protobuf::MessageStatic for JobGroup {
                     JobGroup::get_project_name_for_reflect,
                     JobGroup::mut_project_name_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeEnum<JobPriority>>(
+                    "priority",
+                    JobGroup::get_priority_for_reflect,
+                    JobGroup::mut_priority_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    JobGroup::get_target_for_reflect,
+                    JobGroup::mut_target_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "tags",
+                    JobGroup::get_tags_for_reflect,
+                    JobGroup::mut_tags_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "queue_position",
+                    JobGroup::get_queue_position_for_reflect,
+                    JobGroup::mut_queue_position_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<JobGroup>(
                     "JobGroup",
                     fields,
@@ -5857,6 +15903,10 @@ impl ::protobuf::Clear for JobGroup {
         self.clear_projects();
         self.clear_created_at();
         self.clear_project_name();
+        self.clear_priority();
+        self.clear_target();
+        self.clear_tags();
+        self.clear_queue_position();
         self.unknown_fields.clear();
     }
 }
@@ -5879,6 +15929,7 @@ pub struct JobGraphPackage {
     ident: ::protobuf::SingularField<::std::string::String>,
     deps: ::protobuf::RepeatedField<::std::string::String>,
     target: ::protobuf::SingularField<::std::string::String>,
+    revision: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -6022,6 +16073,33 @@ impl JobGraphPackage {
     fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
         &mut self.target
     }
+
+    // optional uint64 revision = 4;
+
+    pub fn clear_revision(&mut self) {
+        self.revision = ::std::option::Option::None;
+    }
+
+    pub fn has_revision(&self) -> bool {
+        self.revision.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_revision(&mut self, v: u64) {
+        self.revision = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_revision(&self) -> u64 {
+        self.revision.unwrap_or(0)
+    }
+
+    fn get_revision_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.revision
+    }
+
+    fn mut_revision_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.revision
+    }
 }
 
 impl ::protobuf::Message for JobGraphPackage {
@@ -6042,6 +16120,13 @@ impl ::protobuf::Message for JobGraphPackage {
                 3 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
                 },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.revision = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -6063,6 +16148,9 @@ impl ::protobuf::Message for JobGraphPackage {
         if let Some(ref v) = self.target.as_ref() {
             my_size += ::protobuf::rt::string_size(3, &v);
         }
+        if let Some(v) = self.revision {
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -6078,6 +16166,9 @@ impl ::protobuf::Message for JobGraphPackage {
         if let Some(ref v) = self.target.as_ref() {
             os.write_string(3, &v)?;
         }
+        if let Some(v) = self.revision {
+            os.write_uint64(4, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -6137,6 +16228,11 @@ impl ::protobuf::MessageStatic for JobGraphPackage {
                     JobGraphPackage::get_target_for_reflect,
                     JobGraphPackage::mut_target_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "revision",
+                    JobGraphPackage::get_revision_for_reflect,
+                    JobGraphPackage::mut_revision_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<JobGraphPackage>(
                     "JobGraphPackage",
                     fields,
@@ -6152,6 +16248,7 @@ impl ::protobuf::Clear for JobGraphPackage {
         self.clear_ident();
         self.clear_deps();
         self.clear_target();
+        self.clear_revision();
         self.unknown_fields.clear();
     }
 }
@@ -7227,12 +17324,222 @@ impl ::protobuf::Message for JobGraphPackageReverseDependencies {
                 1 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
                 },
-                2 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
-                },
-                3 => {
-                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.rdeps)?;
-                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.rdeps)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.origin.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        for value in &self.rdeps {
+            my_size += ::protobuf::rt::string_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.origin.as_ref() {
+            os.write_string(1, &v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        for v in &self.rdeps {
+            os.write_string(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for JobGraphPackageReverseDependencies {
+    fn new() -> JobGraphPackageReverseDependencies {
+        JobGraphPackageReverseDependencies::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<JobGraphPackageReverseDependencies>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "origin",
+                    JobGraphPackageReverseDependencies::get_origin_for_reflect,
+                    JobGraphPackageReverseDependencies::mut_origin_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    JobGraphPackageReverseDependencies::get_name_for_reflect,
+                    JobGraphPackageReverseDependencies::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "rdeps",
+                    JobGraphPackageReverseDependencies::get_rdeps_for_reflect,
+                    JobGraphPackageReverseDependencies::mut_rdeps_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGraphPackageReverseDependencies>(
+                    "JobGraphPackageReverseDependencies",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for JobGraphPackageReverseDependencies {
+    fn clear(&mut self) {
+        self.clear_origin();
+        self.clear_name();
+        self.clear_rdeps();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for JobGraphPackageReverseDependencies {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobGraphPackageReverseDependencies {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct JobGraphPackageStatsGet {
+    // message fields
+    origin: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for JobGraphPackageStatsGet {}
+
+impl JobGraphPackageStatsGet {
+    pub fn new() -> JobGraphPackageStatsGet {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static JobGraphPackageStatsGet {
+        static mut instance: ::protobuf::lazy::Lazy<JobGraphPackageStatsGet> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const JobGraphPackageStatsGet,
+        };
+        unsafe {
+            instance.get(JobGraphPackageStatsGet::new)
+        }
+    }
+
+    // optional string origin = 1;
+
+    pub fn clear_origin(&mut self) {
+        self.origin.clear();
+    }
+
+    pub fn has_origin(&self) -> bool {
+        self.origin.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_origin(&mut self, v: ::std::string::String) {
+        self.origin = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
+        if self.origin.is_none() {
+            self.origin.set_default();
+        }
+        self.origin.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_origin(&mut self) -> ::std::string::String {
+        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_origin(&self) -> &str {
+        match self.origin.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.origin
+    }
+
+    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.origin
+    }
+}
+
+impl ::protobuf::Message for JobGraphPackageStatsGet {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -7248,12 +17555,6 @@ impl ::protobuf::Message for JobGraphPackageReverseDependencies {
         if let Some(ref v) = self.origin.as_ref() {
             my_size += ::protobuf::rt::string_size(1, &v);
         }
-        if let Some(ref v) = self.name.as_ref() {
-            my_size += ::protobuf::rt::string_size(2, &v);
-        }
-        for value in &self.rdeps {
-            my_size += ::protobuf::rt::string_size(3, &value);
-        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -7263,12 +17564,6 @@ impl ::protobuf::Message for JobGraphPackageReverseDependencies {
         if let Some(ref v) = self.origin.as_ref() {
             os.write_string(1, &v)?;
         }
-        if let Some(ref v) = self.name.as_ref() {
-            os.write_string(2, &v)?;
-        }
-        for v in &self.rdeps {
-            os.write_string(3, &v)?;
-        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -7300,12 +17595,12 @@ impl ::protobuf::Message for JobGraphPackageReverseDependencies {
     }
 }
 
-impl ::protobuf::MessageStatic for JobGraphPackageReverseDependencies {
-    fn new() -> JobGraphPackageReverseDependencies {
-        JobGraphPackageReverseDependencies::new()
+impl ::protobuf::MessageStatic for JobGraphPackageStatsGet {
+    fn new() -> JobGraphPackageStatsGet {
+        JobGraphPackageStatsGet::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobGraphPackageReverseDependencies>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobGraphPackageStatsGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -7315,21 +17610,11 @@ impl ::protobuf::MessageStatic for JobGraphPackageReverseDependencies {
                 let mut fields = ::std::vec::Vec::new();
                 fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
                     "origin",
-                    JobGraphPackageReverseDependencies::get_origin_for_reflect,
-                    JobGraphPackageReverseDependencies::mut_origin_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "name",
-                    JobGraphPackageReverseDependencies::get_name_for_reflect,
-                    JobGraphPackageReverseDependencies::mut_name_for_reflect,
-                ));
-                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "rdeps",
-                    JobGraphPackageReverseDependencies::get_rdeps_for_reflect,
-                    JobGraphPackageReverseDependencies::mut_rdeps_for_reflect,
+                    JobGraphPackageStatsGet::get_origin_for_reflect,
+                    JobGraphPackageStatsGet::mut_origin_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobGraphPackageReverseDependencies>(
-                    "JobGraphPackageReverseDependencies",
+                ::protobuf::reflect::MessageDescriptor::new::<JobGraphPackageStatsGet>(
+                    "JobGraphPackageStatsGet",
                     fields,
                     file_descriptor_proto()
                 )
@@ -7338,101 +17623,177 @@ impl ::protobuf::MessageStatic for JobGraphPackageReverseDependencies {
     }
 }
 
-impl ::protobuf::Clear for JobGraphPackageReverseDependencies {
+impl ::protobuf::Clear for JobGraphPackageStatsGet {
     fn clear(&mut self) {
         self.clear_origin();
-        self.clear_name();
-        self.clear_rdeps();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobGraphPackageReverseDependencies {
+impl ::std::fmt::Debug for JobGraphPackageStatsGet {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobGraphPackageReverseDependencies {
+impl ::protobuf::reflect::ProtobufValue for JobGraphPackageStatsGet {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobGraphPackageStatsGet {
+pub struct JobGraphPackageStats {
     // message fields
-    origin: ::protobuf::SingularField<::std::string::String>,
+    plans: ::std::option::Option<u64>,
+    builds: ::std::option::Option<u64>,
+    unique_packages: ::std::option::Option<u64>,
+    targets: ::protobuf::RepeatedField<JobGraphPackageTargetStats>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobGraphPackageStatsGet {}
+unsafe impl ::std::marker::Sync for JobGraphPackageStats {}
 
-impl JobGraphPackageStatsGet {
-    pub fn new() -> JobGraphPackageStatsGet {
+impl JobGraphPackageStats {
+    pub fn new() -> JobGraphPackageStats {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobGraphPackageStatsGet {
-        static mut instance: ::protobuf::lazy::Lazy<JobGraphPackageStatsGet> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobGraphPackageStats {
+        static mut instance: ::protobuf::lazy::Lazy<JobGraphPackageStats> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobGraphPackageStatsGet,
+            ptr: 0 as *const JobGraphPackageStats,
         };
         unsafe {
-            instance.get(JobGraphPackageStatsGet::new)
+            instance.get(JobGraphPackageStats::new)
         }
     }
 
-    // optional string origin = 1;
+    // optional uint64 plans = 1;
 
-    pub fn clear_origin(&mut self) {
-        self.origin.clear();
+    pub fn clear_plans(&mut self) {
+        self.plans = ::std::option::Option::None;
     }
 
-    pub fn has_origin(&self) -> bool {
-        self.origin.is_some()
+    pub fn has_plans(&self) -> bool {
+        self.plans.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_origin(&mut self, v: ::std::string::String) {
-        self.origin = ::protobuf::SingularField::some(v);
+    pub fn set_plans(&mut self, v: u64) {
+        self.plans = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_plans(&self) -> u64 {
+        self.plans.unwrap_or(0)
+    }
+
+    fn get_plans_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.plans
+    }
+
+    fn mut_plans_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.plans
+    }
+
+    // optional uint64 builds = 2;
+
+    pub fn clear_builds(&mut self) {
+        self.builds = ::std::option::Option::None;
+    }
+
+    pub fn has_builds(&self) -> bool {
+        self.builds.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_builds(&mut self, v: u64) {
+        self.builds = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_builds(&self) -> u64 {
+        self.builds.unwrap_or(0)
+    }
+
+    fn get_builds_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.builds
+    }
+
+    fn mut_builds_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.builds
+    }
+
+    // optional uint64 unique_packages = 3;
+
+    pub fn clear_unique_packages(&mut self) {
+        self.unique_packages = ::std::option::Option::None;
+    }
+
+    pub fn has_unique_packages(&self) -> bool {
+        self.unique_packages.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unique_packages(&mut self, v: u64) {
+        self.unique_packages = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_unique_packages(&self) -> u64 {
+        self.unique_packages.unwrap_or(0)
+    }
+
+    fn get_unique_packages_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.unique_packages
+    }
+
+    fn mut_unique_packages_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.unique_packages
+    }
+
+    // repeated .jobsrv.JobGraphPackageTargetStats targets = 4;
+
+    pub fn clear_targets(&mut self) {
+        self.targets.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_targets(&mut self, v: ::protobuf::RepeatedField<JobGraphPackageTargetStats>) {
+        self.targets = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_origin(&mut self) -> &mut ::std::string::String {
-        if self.origin.is_none() {
-            self.origin.set_default();
-        }
-        self.origin.as_mut().unwrap()
+    pub fn mut_targets(&mut self) -> &mut ::protobuf::RepeatedField<JobGraphPackageTargetStats> {
+        &mut self.targets
     }
 
     // Take field
-    pub fn take_origin(&mut self) -> ::std::string::String {
-        self.origin.take().unwrap_or_else(|| ::std::string::String::new())
+    pub fn take_targets(&mut self) -> ::protobuf::RepeatedField<JobGraphPackageTargetStats> {
+        ::std::mem::replace(&mut self.targets, ::protobuf::RepeatedField::new())
     }
 
-    pub fn get_origin(&self) -> &str {
-        match self.origin.as_ref() {
-            Some(v) => &v,
-            None => "",
-        }
+    pub fn get_targets(&self) -> &[JobGraphPackageTargetStats] {
+        &self.targets
     }
 
-    fn get_origin_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
-        &self.origin
+    fn get_targets_for_reflect(&self) -> &::protobuf::RepeatedField<JobGraphPackageTargetStats> {
+        &self.targets
     }
 
-    fn mut_origin_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
-        &mut self.origin
+    fn mut_targets_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<JobGraphPackageTargetStats> {
+        &mut self.targets
     }
 }
 
-impl ::protobuf::Message for JobGraphPackageStatsGet {
+impl ::protobuf::Message for JobGraphPackageStats {
     fn is_initialized(&self) -> bool {
+        for v in &self.targets {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -7441,7 +17802,28 @@ impl ::protobuf::Message for JobGraphPackageStatsGet {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.origin)?;
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.plans = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.builds = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.unique_packages = ::std::option::Option::Some(tmp);
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.targets)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -7455,18 +17837,39 @@ impl ::protobuf::Message for JobGraphPackageStatsGet {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.origin.as_ref() {
-            my_size += ::protobuf::rt::string_size(1, &v);
+        if let Some(v) = self.plans {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
         }
+        if let Some(v) = self.builds {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.unique_packages {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.targets {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.origin.as_ref() {
-            os.write_string(1, &v)?;
+        if let Some(v) = self.plans {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.builds {
+            os.write_uint64(2, v)?;
         }
+        if let Some(v) = self.unique_packages {
+            os.write_uint64(3, v)?;
+        }
+        for v in &self.targets {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -7498,12 +17901,12 @@ impl ::protobuf::Message for JobGraphPackageStatsGet {
     }
 }
 
-impl ::protobuf::MessageStatic for JobGraphPackageStatsGet {
-    fn new() -> JobGraphPackageStatsGet {
-        JobGraphPackageStatsGet::new()
+impl ::protobuf::MessageStatic for JobGraphPackageStats {
+    fn new() -> JobGraphPackageStats {
+        JobGraphPackageStats::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobGraphPackageStatsGet>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobGraphPackageStats>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -7511,13 +17914,28 @@ impl ::protobuf::MessageStatic for JobGraphPackageStatsGet {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
-                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                    "origin",
-                    JobGraphPackageStatsGet::get_origin_for_reflect,
-                    JobGraphPackageStatsGet::mut_origin_for_reflect,
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "plans",
+                    JobGraphPackageStats::get_plans_for_reflect,
+                    JobGraphPackageStats::mut_plans_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "builds",
+                    JobGraphPackageStats::get_builds_for_reflect,
+                    JobGraphPackageStats::mut_builds_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "unique_packages",
+                    JobGraphPackageStats::get_unique_packages_for_reflect,
+                    JobGraphPackageStats::mut_unique_packages_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobGraphPackageStatsGet>(
-                    "JobGraphPackageStatsGet",
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<JobGraphPackageTargetStats>>(
+                    "targets",
+                    JobGraphPackageStats::get_targets_for_reflect,
+                    JobGraphPackageStats::mut_targets_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<JobGraphPackageStats>(
+                    "JobGraphPackageStats",
                     fields,
                     file_descriptor_proto()
                 )
@@ -7526,28 +17944,32 @@ impl ::protobuf::MessageStatic for JobGraphPackageStatsGet {
     }
 }
 
-impl ::protobuf::Clear for JobGraphPackageStatsGet {
+impl ::protobuf::Clear for JobGraphPackageStats {
     fn clear(&mut self) {
-        self.clear_origin();
+        self.clear_plans();
+        self.clear_builds();
+        self.clear_unique_packages();
+        self.clear_targets();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for JobGraphPackageStatsGet {
+impl ::std::fmt::Debug for JobGraphPackageStats {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobGraphPackageStatsGet {
+impl ::protobuf::reflect::ProtobufValue for JobGraphPackageStats {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct JobGraphPackageStats {
+pub struct JobGraphPackageTargetStats {
     // message fields
+    target: ::protobuf::SingularField<::std::string::String>,
     plans: ::std::option::Option<u64>,
     builds: ::std::option::Option<u64>,
     unique_packages: ::std::option::Option<u64>,
@@ -7557,24 +17979,68 @@ pub struct JobGraphPackageStats {
 }
 
 // see codegen.rs for the explanation why impl Sync explicitly
-unsafe impl ::std::marker::Sync for JobGraphPackageStats {}
+unsafe impl ::std::marker::Sync for JobGraphPackageTargetStats {}
 
-impl JobGraphPackageStats {
-    pub fn new() -> JobGraphPackageStats {
+impl JobGraphPackageTargetStats {
+    pub fn new() -> JobGraphPackageTargetStats {
         ::std::default::Default::default()
     }
 
-    pub fn default_instance() -> &'static JobGraphPackageStats {
-        static mut instance: ::protobuf::lazy::Lazy<JobGraphPackageStats> = ::protobuf::lazy::Lazy {
+    pub fn default_instance() -> &'static JobGraphPackageTargetStats {
+        static mut instance: ::protobuf::lazy::Lazy<JobGraphPackageTargetStats> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
-            ptr: 0 as *const JobGraphPackageStats,
+            ptr: 0 as *const JobGraphPackageTargetStats,
         };
         unsafe {
-            instance.get(JobGraphPackageStats::new)
+            instance.get(JobGraphPackageTargetStats::new)
         }
     }
 
-    // optional uint64 plans = 1;
+    // optional string target = 1;
+
+    pub fn clear_target(&mut self) {
+        self.target.clear();
+    }
+
+    pub fn has_target(&self) -> bool {
+        self.target.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_target(&mut self, v: ::std::string::String) {
+        self.target = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_target(&mut self) -> &mut ::std::string::String {
+        if self.target.is_none() {
+            self.target.set_default();
+        }
+        self.target.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_target(&mut self) -> ::std::string::String {
+        self.target.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_target(&self) -> &str {
+        match self.target.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_target_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.target
+    }
+
+    fn mut_target_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.target
+    }
+
+    // optional uint64 plans = 2;
 
     pub fn clear_plans(&mut self) {
         self.plans = ::std::option::Option::None;
@@ -7601,7 +18067,7 @@ impl JobGraphPackageStats {
         &mut self.plans
     }
 
-    // optional uint64 builds = 2;
+    // optional uint64 builds = 3;
 
     pub fn clear_builds(&mut self) {
         self.builds = ::std::option::Option::None;
@@ -7628,7 +18094,7 @@ impl JobGraphPackageStats {
         &mut self.builds
     }
 
-    // optional uint64 unique_packages = 3;
+    // optional uint64 unique_packages = 4;
 
     pub fn clear_unique_packages(&mut self) {
         self.unique_packages = ::std::option::Option::None;
@@ -7656,7 +18122,7 @@ impl JobGraphPackageStats {
     }
 }
 
-impl ::protobuf::Message for JobGraphPackageStats {
+impl ::protobuf::Message for JobGraphPackageTargetStats {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -7666,20 +18132,23 @@ impl ::protobuf::Message for JobGraphPackageStats {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.target)?;
+                },
+                2 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
                     self.plans = ::std::option::Option::Some(tmp);
                 },
-                2 => {
+                3 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
                     let tmp = is.read_uint64()?;
                     self.builds = ::std::option::Option::Some(tmp);
                 },
-                3 => {
+                4 => {
                     if wire_type != ::protobuf::wire_format::WireTypeVarint {
                         return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
                     }
@@ -7698,14 +18167,17 @@ impl ::protobuf::Message for JobGraphPackageStats {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
+        if let Some(ref v) = self.target.as_ref() {
+            my_size += ::protobuf::rt::string_size(1, &v);
+        }
         if let Some(v) = self.plans {
-            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
         }
         if let Some(v) = self.builds {
-            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
         }
         if let Some(v) = self.unique_packages {
-            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+            my_size += ::protobuf::rt::value_size(4, v, ::protobuf::wire_format::WireTypeVarint);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -7713,14 +18185,17 @@ impl ::protobuf::Message for JobGraphPackageStats {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.target.as_ref() {
+            os.write_string(1, &v)?;
+        }
         if let Some(v) = self.plans {
-            os.write_uint64(1, v)?;
+            os.write_uint64(2, v)?;
         }
         if let Some(v) = self.builds {
-            os.write_uint64(2, v)?;
+            os.write_uint64(3, v)?;
         }
         if let Some(v) = self.unique_packages {
-            os.write_uint64(3, v)?;
+            os.write_uint64(4, v)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -7753,12 +18228,12 @@ impl ::protobuf::Message for JobGraphPackageStats {
     }
 }
 
-impl ::protobuf::MessageStatic for JobGraphPackageStats {
-    fn new() -> JobGraphPackageStats {
-        JobGraphPackageStats::new()
+impl ::protobuf::MessageStatic for JobGraphPackageTargetStats {
+    fn new() -> JobGraphPackageTargetStats {
+        JobGraphPackageTargetStats::new()
     }
 
-    fn descriptor_static(_: ::std::option::Option<JobGraphPackageStats>) -> &'static ::protobuf::reflect::MessageDescriptor {
+    fn descriptor_static(_: ::std::option::Option<JobGraphPackageTargetStats>) -> &'static ::protobuf::reflect::MessageDescriptor {
         static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
             lock: ::protobuf::lazy::ONCE_INIT,
             ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
@@ -7766,23 +18241,28 @@ impl ::protobuf::MessageStatic for JobGraphPackageStats {
         unsafe {
             descriptor.get(|| {
                 let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "target",
+                    JobGraphPackageTargetStats::get_target_for_reflect,
+                    JobGraphPackageTargetStats::mut_target_for_reflect,
+                ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "plans",
-                    JobGraphPackageStats::get_plans_for_reflect,
-                    JobGraphPackageStats::mut_plans_for_reflect,
+                    JobGraphPackageTargetStats::get_plans_for_reflect,
+                    JobGraphPackageTargetStats::mut_plans_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "builds",
-                    JobGraphPackageStats::get_builds_for_reflect,
-                    JobGraphPackageStats::mut_builds_for_reflect,
+                    JobGraphPackageTargetStats::get_builds_for_reflect,
+                    JobGraphPackageTargetStats::mut_builds_for_reflect,
                 ));
                 fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
                     "unique_packages",
-                    JobGraphPackageStats::get_unique_packages_for_reflect,
-                    JobGraphPackageStats::mut_unique_packages_for_reflect,
+                    JobGraphPackageTargetStats::get_unique_packages_for_reflect,
+                    JobGraphPackageTargetStats::mut_unique_packages_for_reflect,
                 ));
-                ::protobuf::reflect::MessageDescriptor::new::<JobGraphPackageStats>(
-                    "JobGraphPackageStats",
+                ::protobuf::reflect::MessageDescriptor::new::<JobGraphPackageTargetStats>(
+                    "JobGraphPackageTargetStats",
                     fields,
                     file_descriptor_proto()
                 )
@@ -7791,8 +18271,9 @@ impl ::protobuf::MessageStatic for JobGraphPackageStats {
     }
 }
 
-impl ::protobuf::Clear for JobGraphPackageStats {
+impl ::protobuf::Clear for JobGraphPackageTargetStats {
     fn clear(&mut self) {
+        self.clear_target();
         self.clear_plans();
         self.clear_builds();
         self.clear_unique_packages();
@@ -7800,13 +18281,13 @@ impl ::protobuf::Clear for JobGraphPackageStats {
     }
 }
 
-impl ::std::fmt::Debug for JobGraphPackageStats {
+impl ::std::fmt::Debug for JobGraphPackageTargetStats {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for JobGraphPackageStats {
+impl ::protobuf::reflect::ProtobufValue for JobGraphPackageTargetStats {
     fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
         ::protobuf::reflect::ProtobufValueRef::Message(self)
     }
@@ -8032,6 +18513,168 @@ impl ::protobuf::reflect::ProtobufValue for JobState {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum JobPriority {
+    Normal = 0,
+    High = 1,
+    Critical = 2,
+}
+
+impl ::protobuf::ProtobufEnum for JobPriority {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JobPriority> {
+        match value {
+            0 => ::std::option::Option::Some(JobPriority::Normal),
+            1 => ::std::option::Option::Some(JobPriority::High),
+            2 => ::std::option::Option::Some(JobPriority::Critical),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [JobPriority] = &[
+            JobPriority::Normal,
+            JobPriority::High,
+            JobPriority::Critical,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<JobPriority>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("JobPriority", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for JobPriority {
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobPriority {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum JobKind {
+    Build = 0,
+    DockerExport = 1,
+}
+
+impl ::protobuf::ProtobufEnum for JobKind {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JobKind> {
+        match value {
+            0 => ::std::option::Option::Some(JobKind::Build),
+            1 => ::std::option::Option::Some(JobKind::DockerExport),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [JobKind] = &[
+            JobKind::Build,
+            JobKind::DockerExport,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<JobKind>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("JobKind", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for JobKind {
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobKind {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum JobErrorCategory {
+    Uncategorized = 0,
+    Transient = 1,
+    Permanent = 2,
+    Plan = 3,
+    Infrastructure = 4,
+    ResourceLimit = 5,
+}
+
+impl ::protobuf::ProtobufEnum for JobErrorCategory {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JobErrorCategory> {
+        match value {
+            0 => ::std::option::Option::Some(JobErrorCategory::Uncategorized),
+            1 => ::std::option::Option::Some(JobErrorCategory::Transient),
+            2 => ::std::option::Option::Some(JobErrorCategory::Permanent),
+            3 => ::std::option::Option::Some(JobErrorCategory::Plan),
+            4 => ::std::option::Option::Some(JobErrorCategory::Infrastructure),
+            5 => ::std::option::Option::Some(JobErrorCategory::ResourceLimit),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [JobErrorCategory] = &[
+            JobErrorCategory::Uncategorized,
+            JobErrorCategory::Transient,
+            JobErrorCategory::Permanent,
+            JobErrorCategory::Plan,
+            JobErrorCategory::Infrastructure,
+            JobErrorCategory::ResourceLimit,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<JobErrorCategory>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("JobErrorCategory", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for JobErrorCategory {
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobErrorCategory {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum JobGroupProjectState {
     NotStarted = 0,
@@ -8154,98 +18797,293 @@ impl ::protobuf::reflect::ProtobufValue for JobGroupState {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum JobAuditTrigger {
+    Scheduler = 0,
+    Worker = 1,
+    User = 2,
+    Expired = 3,
+    TimedOut = 4,
+    Retry = 5,
+    WorkerLost = 6,
+}
+
+impl ::protobuf::ProtobufEnum for JobAuditTrigger {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<JobAuditTrigger> {
+        match value {
+            0 => ::std::option::Option::Some(JobAuditTrigger::Scheduler),
+            1 => ::std::option::Option::Some(JobAuditTrigger::Worker),
+            2 => ::std::option::Option::Some(JobAuditTrigger::User),
+            3 => ::std::option::Option::Some(JobAuditTrigger::Expired),
+            4 => ::std::option::Option::Some(JobAuditTrigger::TimedOut),
+            5 => ::std::option::Option::Some(JobAuditTrigger::Retry),
+            6 => ::std::option::Option::Some(JobAuditTrigger::WorkerLost),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [JobAuditTrigger] = &[
+            JobAuditTrigger::Scheduler,
+            JobAuditTrigger::Worker,
+            JobAuditTrigger::User,
+            JobAuditTrigger::Expired,
+            JobAuditTrigger::TimedOut,
+            JobAuditTrigger::Retry,
+            JobAuditTrigger::WorkerLost,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<JobAuditTrigger>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("JobAuditTrigger", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for JobAuditTrigger {
+}
+
+impl ::protobuf::reflect::ProtobufValue for JobAuditTrigger {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x16protocols/jobsrv.proto\x12\x06jobsrv\x1a\x13protocols/net.proto\
-    \x1a\x19protocols/originsrv.proto\"8\n\rWorkerCommand\x12'\n\x02op\x18\
-    \x01\x20\x01(\x0e2\x17.jobsrv.WorkerOperationR\x02op\"n\n\tHeartbeat\x12\
-    \x1a\n\x08endpoint\x18\x01\x20\x01(\tR\x08endpoint\x12\x1a\n\x02os\x18\
-    \x02\x20\x01(\x0e2\n.jobsrv.OsR\x02os\x12)\n\x05state\x18\x03\x20\x01(\
-    \x0e2\x13.jobsrv.WorkerStateR\x05state\"[\n\nBusyWorker\x12\x14\n\x05ide\
-    nt\x18\x01\x20\x01(\tR\x05ident\x12\x15\n\x06job_id\x18\x02\x20\x01(\x04\
-    R\x05jobId\x12\x20\n\x0bquarantined\x18\x03\x20\x01(\x08R\x0bquarantined\
-    \"\xe6\x04\n\x03Job\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x19\
-    \n\x08owner_id\x18\x02\x20\x01(\x04R\x07ownerId\x12&\n\x05state\x18\x03\
-    \x20\x01(\x0e2\x10.jobsrv.JobStateR\x05state\x122\n\x07project\x18\x04\
-    \x20\x01(\x0b2\x18.originsrv.OriginProjectR\x07project\x12#\n\x05error\
-    \x18\x05\x20\x01(\x0b2\r.net.NetErrorR\x05error\x12\x1d\n\ncreated_at\
-    \x18\x06\x20\x01(\tR\tcreatedAt\x12(\n\x10build_started_at\x18\x07\x20\
-    \x01(\tR\x0ebuildStartedAt\x12*\n\x11build_finished_at\x18\x08\x20\x01(\
-    \tR\x0fbuildFinishedAt\x12B\n\rpackage_ident\x18\t\x20\x01(\x0b2\x1d.ori\
-    ginsrv.OriginPackageIdentR\x0cpackageIdent\x12\x1f\n\x0bis_archived\x18\
-    \x0b\x20\x01(\x08R\nisArchived\x12@\n\x0cintegrations\x18\x0c\x20\x03(\
-    \x0b2\x1c.originsrv.OriginIntegrationR\x0cintegrations\x12\x18\n\x07chan\
-    nel\x18\r\x20\x01(\tR\x07channel\x12V\n\x14project_integrations\x18\x0e\
-    \x20\x03(\x0b2#.originsrv.OriginProjectIntegrationR\x13projectIntegratio\
-    ns\x12\x16\n\x06worker\x18\x0f\x20\x01(\tR\x06workerJ\x04\x08\n\x10\x0bR\
-    \x07log_url\"\x18\n\x06JobGet\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02i\
-    d\"r\n\x07JobSpec\x12\x19\n\x08owner_id\x18\x01\x20\x01(\x04R\x07ownerId\
-    \x122\n\x07project\x18\x02\x20\x01(\x0b2\x18.originsrv.OriginProjectR\
-    \x07project\x12\x18\n\x07channel\x18\x03\x20\x01(\tR\x07channel\"N\n\x0e\
-    ProjectJobsGet\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\
+    \x1a\x19protocols/originsrv.proto\"c\n\rWorkerCommand\x12'\n\x02op\x18\
+    \x01\x20\x01(\x0e2\x17.jobsrv.WorkerOperationR\x02op\x12)\n\x10protocol_\
+    version\x18\x02\x20\x01(\rR\x0fprotocolVersion\"\xf5\x03\n\tHeartbeat\
+    \x12\x1a\n\x08endpoint\x18\x01\x20\x01(\tR\x08endpoint\x12\x1a\n\x02os\
+    \x18\x02\x20\x01(\x0e2\n.jobsrv.OsR\x02os\x12)\n\x05state\x18\x03\x20\
+    \x01(\x0e2\x13.jobsrv.WorkerStateR\x05state\x12\x16\n\x06target\x18\x04\
+    \x20\x01(\tR\x06target\x12\x1b\n\tdisk_free\x18\x05\x20\x01(\x04R\x08dis\
+    kFree\x12$\n\x0ejobs_in_flight\x18\x06\x20\x01(\rR\x0cjobsInFlight\x12\
+    \x18\n\x07targets\x18\x07\x20\x03(\tR\x07targets\x12)\n\x10protocol_vers\
+    ion\x18\x08\x20\x01(\rR\x0fprotocolVersion\x12\x16\n\x06labels\x18\t\x20\
+    \x03(\tR\x06labels\x12\x1a\n\x08capacity\x18\n\x20\x01(\rR\x08capacity\
+    \x12\x17\n\x07job_ids\x18\x0b\x20\x03(\x04R\x06jobIds\x12\x1a\n\x08drain\
+    ing\x18\x0c\x20\x01(\x08R\x08draining\x12\x18\n\x07leaving\x18\r\x20\x01\
+    (\x08R\x07leaving\x12.\n\x13artifact_cache_hits\x18\x0e\x20\x01(\x04R\
+    \x11artifactCacheHits\x122\n\x15artifact_cache_misses\x18\x0f\x20\x01(\
+    \x04R\x13artifactCacheMisses\"[\n\nBusyWorker\x12\x14\n\x05ident\x18\x01\
+    \x20\x01(\tR\x05ident\x12\x15\n\x06job_id\x18\x02\x20\x01(\x04R\x05jobId\
+    \x12\x20\n\x0bquarantined\x18\x03\x20\x01(\x08R\x0bquarantined\"\xf4\x02\
+    \n\x0cWorkerStatus\x12\x14\n\x05ident\x18\x01\x20\x01(\tR\x05ident\x12\
+    \x18\n\x07targets\x18\x02\x20\x03(\tR\x07targets\x12)\n\x05state\x18\x03\
+    \x20\x01(\x0e2\x13.jobsrv.WorkerStateR\x05state\x12\x15\n\x06job_id\x18\
+    \x04\x20\x01(\x04R\x05jobId\x12%\n\x0elast_heartbeat\x18\x05\x20\x01(\tR\
+    \rlastHeartbeat\x12\x16\n\x06labels\x18\x06\x20\x03(\tR\x06labels\x12\
+    \x1a\n\x08capacity\x18\x07\x20\x01(\rR\x08capacity\x12\x17\n\x07job_ids\
+    \x18\x08\x20\x03(\x04R\x06jobIds\x12\x1a\n\x08draining\x18\t\x20\x01(\
+    \x08R\x08draining\x12.\n\x13artifact_cache_hits\x18\n\x20\x01(\x04R\x11a\
+    rtifactCacheHits\x122\n\x15artifact_cache_misses\x18\x0b\x20\x01(\x04R\
+    \x13artifactCacheMisses\"\x0f\n\rWorkerListGet\"D\n\x12WorkerListRespons\
+    e\x12.\n\x07workers\x18\x01\x20\x03(\x0b2\x14.jobsrv.WorkerStatusR\x07wo\
+    rkers\"\x8f\n\n\x03Job\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\
+    \x19\n\x08owner_id\x18\x02\x20\x01(\x04R\x07ownerId\x12&\n\x05state\x18\
+    \x03\x20\x01(\x0e2\x10.jobsrv.JobStateR\x05state\x122\n\x07project\x18\
+    \x04\x20\x01(\x0b2\x18.originsrv.OriginProjectR\x07project\x12&\n\x05err\
+    or\x18\x05\x20\x01(\x0b2\x10.jobsrv.JobErrorR\x05error\x12\x1d\n\ncreate\
+    d_at\x18\x06\x20\x01(\tR\tcreatedAt\x12(\n\x10build_started_at\x18\x07\
+    \x20\x01(\tR\x0ebuildStartedAt\x12*\n\x11build_finished_at\x18\x08\x20\
+    \x01(\tR\x0fbuildFinishedAt\x12B\n\rpackage_ident\x18\t\x20\x01(\x0b2\
+    \x1d.originsrv.OriginPackageIdentR\x0cpackageIdent\x12\x1f\n\x0bis_archi\
+    ved\x18\x0b\x20\x01(\x08R\nisArchived\x12@\n\x0cintegrations\x18\x0c\x20\
+    \x03(\x0b2\x1c.originsrv.OriginIntegrationR\x0cintegrations\x12\x18\n\
+    \x07channel\x18\r\x20\x01(\tR\x07channel\x12V\n\x14project_integrations\
+    \x18\x0e\x20\x03(\x0b2#.originsrv.OriginProjectIntegrationR\x13projectIn\
+    tegrations\x12\x16\n\x06worker\x18\x0f\x20\x01(\tR\x06worker\x12/\n\x08p\
+    riority\x18\x10\x20\x01(\x0e2\x13.jobsrv.JobPriorityR\x08priority\x12\
+    \x16\n\x06target\x18\x11\x20\x01(\tR\x06target\x12\x18\n\x07retries\x18\
+    \x12\x20\x01(\rR\x07retries\x12\x1f\n\x0bmax_retries\x18\x13\x20\x01(\rR\
+    \nmaxRetries\x12H\n\x13last_error_category\x18\x14\x20\x01(\x0e2\x18.job\
+    srv.JobErrorCategoryR\x11lastErrorCategory\x12\x12\n\x04tags\x18\x15\x20\
+    \x03(\tR\x04tags\x12/\n\x08artifact\x18\x16\x20\x01(\x0b2\x13.jobsrv.Job\
+    ArtifactR\x08artifact\x12\x1d\n\nexpires_at\x18\x17\x20\x01(\tR\texpires\
+    At\x12#\n\rbuild_timeout\x18\x18\x20\x01(\rR\x0cbuildTimeout\x12#\n\rwor\
+    ker_labels\x18\x19\x20\x03(\tR\x0cworkerLabels\x12#\n\x04kind\x18\x1a\
+    \x20\x01(\x0e2\x0f.jobsrv.JobKindR\x04kind\x12!\n\x0cimage_digest\x18\
+    \x1b\x20\x01(\tR\x0bimageDigest\x121\n\x07secrets\x18\x1c\x20\x03(\x0b2\
+    \x17.originsrv.OriginSecretR\x07secrets\x12&\n\x0fmemory_limit_mb\x18\
+    \x1d\x20\x01(\x04R\rmemoryLimitMb\x120\n\x14cpu_limit_millicores\x18\x1e\
+    \x20\x01(\rR\x12cpuLimitMillicores\x12/\n\x13verify_reproducible\x18\x1f\
+    \x20\x01(\x08R\x12verifyReproducible\x12\"\n\x0creproducible\x18\x20\x20\
+    \x01(\x08R\x0creproducibleJ\x04\x08\n\x10\x0bR\x07log_url\"=\n\x0bJobArt\
+    ifact\x12\x1a\n\x08checksum\x18\x01\x20\x01(\tR\x08checksum\x12\x12\n\
+    \x04size\x18\x02\x20\x01(\x04R\x04size\"|\n\x08JobError\x12\x20\n\x04cod\
+    e\x18\x01\x20\x01(\x0e2\x0c.net.ErrCodeR\x04code\x12\x18\n\x07message\
+    \x18\x02\x20\x01(\tR\x07message\x124\n\x08category\x18\x03\x20\x01(\x0e2\
+    \x18.jobsrv.JobErrorCategoryR\x08category\"\x18\n\x06JobGet\x12\x0e\n\
+    \x02id\x18\x01\x20\x01(\x04R\x02id\"\xf4\x01\n\x07JobSpec\x12\x19\n\x08o\
+    wner_id\x18\x01\x20\x01(\x04R\x07ownerId\x122\n\x07project\x18\x02\x20\
+    \x01(\x0b2\x18.originsrv.OriginProjectR\x07project\x12\x18\n\x07channel\
+    \x18\x03\x20\x01(\tR\x07channel\x12/\n\x08priority\x18\x04\x20\x01(\x0e2\
+    \x13.jobsrv.JobPriorityR\x08priority\x12\x16\n\x06target\x18\x05\x20\x01\
+    (\tR\x06target\x12\x12\n\x04tags\x18\x06\x20\x03(\tR\x04tags\x12#\n\x04k\
+    ind\x18\x07\x20\x01(\x0e2\x0f.jobsrv.JobKindR\x04kind\"u\n\x0eProjectJob\
+    sGet\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\x05start\
+    \x18\x02\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\
+    \x04stop\x12%\n\x0ecreated_before\x18\x04\x20\x01(\tR\rcreatedBefore\"y\
+    \n\x16ProjectJobsGetResponse\x12\x1f\n\x04jobs\x18\x01\x20\x03(\x0b2\x0b\
+    .jobsrv.JobR\x04jobs\x12\x14\n\x05start\x18\x02\x20\x01(\x04R\x05start\
+    \x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\x04stop\x12\x14\n\x05count\x18\
+    \x04\x20\x01(\x04R\x05count\"q\n\x0cJobsByTagGet\x12\x10\n\x03tag\x18\
+    \x01\x20\x01(\tR\x03tag\x12\x14\n\x05start\x18\x02\x20\x01(\x04R\x05star\
+    t\x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\x04stop\x12%\n\x0ecreated_befo\
+    re\x18\x04\x20\x01(\tR\rcreatedBefore\"w\n\x14JobsByTagGetResponse\x12\
+    \x1f\n\x04jobs\x18\x01\x20\x03(\x0b2\x0b.jobsrv.JobR\x04jobs\x12\x14\n\
     \x05start\x18\x02\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x03\x20\
-    \x01(\x04R\x04stop\"y\n\x16ProjectJobsGetResponse\x12\x1f\n\x04jobs\x18\
-    \x01\x20\x03(\x0b2\x0b.jobsrv.JobR\x04jobs\x12\x14\n\x05start\x18\x02\
-    \x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\x04stop\
-    \x12\x14\n\x05count\x18\x04\x20\x01(\x04R\x05count\"P\n\x0bJobLogChunk\
-    \x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\x05jobId\x12\x10\n\x03seq\x18\
-    \x02\x20\x01(\x04R\x03seq\x12\x18\n\x07content\x18\x03\x20\x01(\tR\x07co\
-    ntent\"'\n\x0eJobLogComplete\x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\
-    \x05jobId\"1\n\tJobLogGet\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\
+    \x01(\x04R\x04stop\x12\x14\n\x05count\x18\x04\x20\x01(\x04R\x05count\"P\
+    \n\x0bJobLogChunk\x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\x05jobId\x12\
+    \x10\n\x03seq\x18\x02\x20\x01(\x04R\x03seq\x12\x18\n\x07content\x18\x03\
+    \x20\x01(\tR\x07content\"'\n\x0eJobLogComplete\x12\x15\n\x06job_id\x18\
+    \x01\x20\x01(\x04R\x05jobId\"1\n\tJobLogGet\x12\x0e\n\x02id\x18\x01\x20\
+    \x01(\x04R\x02id\x12\x14\n\x05start\x18\x02\x20\x01(\x04R\x05start\">\n\
+    \x0fJobLogSubscribe\x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\x05jobId\
     \x12\x14\n\x05start\x18\x02\x20\x01(\x04R\x05start\"m\n\x06JobLog\x12\
     \x14\n\x05start\x18\x01\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x02\
     \x20\x01(\x04R\x04stop\x12\x18\n\x07content\x18\x03\x20\x03(\tR\x07conte\
-    nt\x12\x1f\n\x0bis_complete\x18\x04\x20\x01(\x08R\nisComplete\"\xb9\x01\
+    nt\x12\x1f\n\x0bis_complete\x18\x04\x20\x01(\x08R\nisComplete\"\xe4\x02\
     \n\x0cJobGroupSpec\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\
     \x18\n\x07package\x18\x02\x20\x01(\tR\x07package\x12\x1b\n\tdeps_only\
     \x18\x03\x20\x01(\x08R\x08depsOnly\x12\x16\n\x06target\x18\x04\x20\x01(\
     \tR\x06target\x12\x1f\n\x0borigin_only\x18\x05\x20\x01(\x08R\noriginOnly\
-    \x12!\n\x0cpackage_only\x18\x06\x20\x01(\x08R\x0bpackageOnly\"\x9e\x01\n\
-    \x0fJobGroupProject\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\
-    \x14\n\x05ident\x18\x02\x20\x01(\tR\x05ident\x122\n\x05state\x18\x03\x20\
-    \x01(\x0e2\x1c.jobsrv.JobGroupProjectStateR\x05state\x12\x15\n\x06job_id\
-    \x18\x04\x20\x01(\x04R\x05jobId\x12\x16\n\x06target\x18\x05\x20\x01(\tR\
-    \x06target\"*\n\rJobGroupAbort\x12\x19\n\x08group_id\x18\x01\x20\x01(\
-    \x04R\x07groupId\"+\n\x0eJobGroupCancel\x12\x19\n\x08group_id\x18\x01\
-    \x20\x01(\x04R\x07groupId\"(\n\x0bJobGroupGet\x12\x19\n\x08group_id\x18\
-    \x01\x20\x01(\x04R\x07groupId\"+\n\x11JobGroupOriginGet\x12\x16\n\x06ori\
-    gin\x18\x01\x20\x01(\tR\x06origin\"I\n\x16JobGroupOriginResponse\x12/\n\
-    \njob_groups\x18\x01\x20\x03(\x0b2\x10.jobsrv.JobGroupR\tjobGroups\"\xbe\
-    \x01\n\x08JobGroup\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12+\n\
-    \x05state\x18\x02\x20\x01(\x0e2\x15.jobsrv.JobGroupStateR\x05state\x123\
-    \n\x08projects\x18\x03\x20\x03(\x0b2\x17.jobsrv.JobGroupProjectR\x08proj\
-    ects\x12\x1d\n\ncreated_at\x18\x04\x20\x01(\tR\tcreatedAt\x12!\n\x0cproj\
-    ect_name\x18\x05\x20\x01(\tR\x0bprojectName\"S\n\x0fJobGraphPackage\x12\
-    \x14\n\x05ident\x18\x01\x20\x01(\tR\x05ident\x12\x12\n\x04deps\x18\x02\
-    \x20\x03(\tR\x04deps\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06target\"\
-    \\\n\x18JobGraphPackagePreCreate\x12\x14\n\x05ident\x18\x01\x20\x01(\tR\
+    \x12!\n\x0cpackage_only\x18\x06\x20\x01(\x08R\x0bpackageOnly\x12/\n\x08p\
+    riority\x18\x07\x20\x01(\x0e2\x13.jobsrv.JobPriorityR\x08priority\x12\
+    \x12\n\x04tags\x18\x08\x20\x03(\tR\x04tags\x12\x1a\n\x08packages\x18\t\
+    \x20\x03(\tR\x08packages\x12!\n\x0crequester_id\x18\n\x20\x01(\x04R\x0br\
+    equesterId\x12%\n\x0erequester_name\x18\x0b\x20\x01(\tR\rrequesterName\"\
+    N\n\x13JobGroupCreateError\x12!\n\x0cproject_name\x18\x01\x20\x01(\tR\
+    \x0bprojectName\x12\x14\n\x05cycle\x18\x02\x20\x03(\tR\x05cycle\";\n\x0f\
+    JobGroupPreview\x12(\n\x04spec\x18\x01\x20\x01(\x0b2\x14.jobsrv.JobGroup\
+    SpecR\x04spec\"B\n\x16JobGroupPreviewProject\x12\x12\n\x04name\x18\x01\
+    \x20\x01(\tR\x04name\x12\x14\n\x05ident\x18\x02\x20\x01(\tR\x05ident\"U\
+    \n\x17JobGroupPreviewResponse\x12:\n\x08projects\x18\x01\x20\x03(\x0b2\
+    \x1e.jobsrv.JobGroupPreviewProjectR\x08projects\"\xbf\x01\n\x10JobGroupS\
+    chedule\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12(\n\x04spec\x18\
+    \x02\x20\x01(\x0b2\x14.jobsrv.JobGroupSpecR\x04spec\x12\x12\n\x04cron\
+    \x18\x03\x20\x01(\tR\x04cron\x12\x1e\n\x0blast_run_at\x18\x04\x20\x01(\t\
+    R\tlastRunAt\x12\x1e\n\x0bnext_run_at\x18\x05\x20\x01(\tR\tnextRunAt\x12\
+    \x1d\n\ncreated_at\x18\x06\x20\x01(\tR\tcreatedAt\"V\n\x16JobGroupSchedu\
+    leCreate\x12(\n\x04spec\x18\x01\x20\x01(\x0b2\x14.jobsrv.JobGroupSpecR\
+    \x04spec\x12\x12\n\x04cron\x18\x02\x20\x01(\tR\x04cron\"1\n\x17JobGroupS\
+    cheduleListGet\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\"V\n\
+    \x1cJobGroupScheduleListResponse\x126\n\tschedules\x18\x01\x20\x03(\x0b2\
+    \x18.jobsrv.JobGroupScheduleR\tschedules\"@\n\x16JobGroupScheduleDelete\
+    \x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x16\n\x06origin\x18\x02\
+    \x20\x01(\tR\x06origin\"\x95\x02\n\x0fJobGroupProject\x12\x12\n\x04name\
+    \x18\x01\x20\x01(\tR\x04name\x12\x14\n\x05ident\x18\x02\x20\x01(\tR\x05i\
+    dent\x122\n\x05state\x18\x03\x20\x01(\x0e2\x1c.jobsrv.JobGroupProjectSta\
+    teR\x05state\x12\x15\n\x06job_id\x18\x04\x20\x01(\x04R\x05jobId\x12\x16\
+    \n\x06target\x18\x05\x20\x01(\tR\x06target\x12(\n\x10build_started_at\
+    \x18\x06\x20\x01(\tR\x0ebuildStartedAt\x12*\n\x11build_finished_at\x18\
+    \x07\x20\x01(\tR\x0fbuildFinishedAt\x12\x1f\n\x0bskip_reason\x18\x08\x20\
+    \x01(\tR\nskipReason\"*\n\rJobGroupAbort\x12\x19\n\x08group_id\x18\x01\
+    \x20\x01(\x04R\x07groupId\"u\n\x0eJobGroupCancel\x12\x19\n\x08group_id\
+    \x18\x01\x20\x01(\x04R\x07groupId\x12!\n\x0crequester_id\x18\x02\x20\x01\
+    (\x04R\x0brequesterId\x12%\n\x0erequester_name\x18\x03\x20\x01(\tR\rrequ\
+    esterName\"t\n\rJobGroupRetry\x12\x19\n\x08group_id\x18\x01\x20\x01(\x04\
+    R\x07groupId\x12!\n\x0crequester_id\x18\x02\x20\x01(\x04R\x0brequesterId\
+    \x12%\n\x0erequester_name\x18\x03\x20\x01(\tR\rrequesterName\"l\n\tJobCa\
+    ncel\x12\x15\n\x06job_id\x18\x01\x20\x01(\x04R\x05jobId\x12!\n\x0creques\
+    ter_id\x18\x02\x20\x01(\x04R\x0brequesterId\x12%\n\x0erequester_name\x18\
+    \x03\x20\x01(\tR\rrequesterName\"\xba\x02\n\x08JobAudit\x12\x0e\n\x02id\
+    \x18\x01\x20\x01(\x04R\x02id\x12\x15\n\x06job_id\x18\x02\x20\x01(\x04R\
+    \x05jobId\x12\x19\n\x08group_id\x18\x03\x20\x01(\x04R\x07groupId\x12\x1d\
+    \n\nfrom_state\x18\x04\x20\x01(\tR\tfromState\x12\x19\n\x08to_state\x18\
+    \x05\x20\x01(\tR\x07toState\x121\n\x07trigger\x18\x06\x20\x01(\x0e2\x17.\
+    jobsrv.JobAuditTriggerR\x07trigger\x12!\n\x0crequester_id\x18\x07\x20\
+    \x01(\x04R\x0brequesterId\x12%\n\x0erequester_name\x18\x08\x20\x01(\tR\r\
+    requesterName\x12\x16\n\x06worker\x18\t\x20\x01(\tR\x06worker\x12\x1d\n\
+    \ncreated_at\x18\n\x20\x01(\tR\tcreatedAt\"?\n\x0bJobAuditGet\x12\x15\n\
+    \x06job_id\x18\x01\x20\x01(\x04R\x05jobId\x12\x19\n\x08group_id\x18\x02\
+    \x20\x01(\x04R\x07groupId\"<\n\x10JobAuditResponse\x12(\n\x06audits\x18\
+    \x01\x20\x03(\x0b2\x10.jobsrv.JobAuditR\x06audits\"8\n\x0fJobHistoryPrun\
+    e\x12%\n\x0eretention_days\x18\x01\x20\x01(\rR\rretentionDays\"c\n\x17Jo\
+    bHistoryPruneResponse\x12%\n\x0egroups_deleted\x18\x01\x20\x01(\x04R\rgr\
+    oupsDeleted\x12!\n\x0cjobs_deleted\x18\x02\x20\x01(\x04R\x0bjobsDeleted\
+    \"-\n\x13OriginBuildUsageGet\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06\
+    origin\"\xba\x01\n\x10OriginBuildUsage\x12\x16\n\x06origin\x18\x01\x20\
+    \x01(\tR\x06origin\x12\x18\n\x07workers\x18\x02\x20\x01(\rR\x07workers\
+    \x12\x1f\n\x0bmax_workers\x18\x03\x20\x01(\rR\nmaxWorkers\x12&\n\x0fbuil\
+    ds_last_day\x18\x04\x20\x01(\rR\rbuildsLastDay\x12+\n\x12max_builds_per_\
+    day\x18\x05\x20\x01(\rR\x0fmaxBuildsPerDay\"(\n\x0bJobGroupGet\x12\x19\n\
+    \x08group_id\x18\x01\x20\x01(\x04R\x07groupId\"\xce\x01\n\x11JobGroupOri\
+    ginGet\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\x14\n\x05st\
+    art\x18\x02\x20\x01(\x04R\x05start\x12\x12\n\x04stop\x18\x03\x20\x01(\
+    \x04R\x04stop\x12+\n\x05state\x18\x04\x20\x01(\x0e2\x15.jobsrv.JobGroupS\
+    tateR\x05state\x12#\n\rcreated_since\x18\x05\x20\x01(\tR\x0ccreatedSince\
+    \x12%\n\x0ecreated_before\x18\x06\x20\x01(\tR\rcreatedBefore\"\x89\x01\n\
+    \x16JobGroupOriginResponse\x12/\n\njob_groups\x18\x01\x20\x03(\x0b2\x10.\
+    jobsrv.JobGroupR\tjobGroups\x12\x14\n\x05start\x18\x02\x20\x01(\x04R\x05\
+    start\x12\x12\n\x04stop\x18\x03\x20\x01(\x04R\x04stop\x12\x14\n\x05count\
+    \x18\x04\x20\x01(\x04R\x05count\"\xc2\x02\n\x08JobGroup\x12\x0e\n\x02id\
+    \x18\x01\x20\x01(\x04R\x02id\x12+\n\x05state\x18\x02\x20\x01(\x0e2\x15.j\
+    obsrv.JobGroupStateR\x05state\x123\n\x08projects\x18\x03\x20\x03(\x0b2\
+    \x17.jobsrv.JobGroupProjectR\x08projects\x12\x1d\n\ncreated_at\x18\x04\
+    \x20\x01(\tR\tcreatedAt\x12!\n\x0cproject_name\x18\x05\x20\x01(\tR\x0bpr\
+    ojectName\x12/\n\x08priority\x18\x06\x20\x01(\x0e2\x13.jobsrv.JobPriorit\
+    yR\x08priority\x12\x16\n\x06target\x18\x07\x20\x01(\tR\x06target\x12\x12\
+    \n\x04tags\x18\x08\x20\x03(\tR\x04tags\x12%\n\x0equeue_position\x18\t\
+    \x20\x01(\rR\rqueuePosition\"o\n\x0fJobGraphPackage\x12\x14\n\x05ident\
+    \x18\x01\x20\x01(\tR\x05ident\x12\x12\n\x04deps\x18\x02\x20\x03(\tR\x04d\
+    eps\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06target\x12\x1a\n\x08revis\
+    ion\x18\x04\x20\x01(\x04R\x08revision\"\\\n\x18JobGraphPackagePreCreate\
+    \x12\x14\n\x05ident\x18\x01\x20\x01(\tR\x05ident\x12\x12\n\x04deps\x18\
+    \x02\x20\x03(\tR\x04deps\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06targ\
+    et\"Y\n\x15JobGraphPackageCreate\x12\x14\n\x05ident\x18\x01\x20\x01(\tR\
     \x05ident\x12\x12\n\x04deps\x18\x02\x20\x03(\tR\x04deps\x12\x16\n\x06tar\
-    get\x18\x03\x20\x01(\tR\x06target\"Y\n\x15JobGraphPackageCreate\x12\x14\
-    \n\x05ident\x18\x01\x20\x01(\tR\x05ident\x12\x12\n\x04deps\x18\x02\x20\
-    \x03(\tR\x04deps\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06target\"k\n%\
-    JobGraphPackageReverseDependenciesGet\x12\x16\n\x06origin\x18\x01\x20\
-    \x01(\tR\x06origin\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\x12\x16\
-    \n\x06target\x18\x03\x20\x01(\tR\x06target\"f\n\"JobGraphPackageReverseD\
-    ependencies\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\x12\n\
-    \x04name\x18\x02\x20\x01(\tR\x04name\x12\x14\n\x05rdeps\x18\x03\x20\x03(\
-    \tR\x05rdeps\"1\n\x17JobGraphPackageStatsGet\x12\x16\n\x06origin\x18\x01\
-    \x20\x01(\tR\x06origin\"m\n\x14JobGraphPackageStats\x12\x14\n\x05plans\
-    \x18\x01\x20\x01(\x04R\x05plans\x12\x16\n\x06builds\x18\x02\x20\x01(\x04\
-    R\x06builds\x12'\n\x0funique_packages\x18\x03\x20\x01(\x04R\x0euniquePac\
-    kages*(\n\x02Os\x12\t\n\x05Linux\x10\x01\x12\n\n\x06Darwin\x10\x02\x12\
-    \x0b\n\x07Windows\x10\x03*\"\n\x0bWorkerState\x12\t\n\x05Ready\x10\0\x12\
-    \x08\n\x04Busy\x10\x01*.\n\x0fWorkerOperation\x12\x0c\n\x08StartJob\x10\
-    \0\x12\r\n\tCancelJob\x10\x01*\x9c\x01\n\x08JobState\x12\x0b\n\x07Pendin\
-    g\x10\0\x12\x0e\n\nProcessing\x10\x01\x12\x0c\n\x08Complete\x10\x02\x12\
-    \x0c\n\x08Rejected\x10\x03\x12\n\n\x06Failed\x10\x04\x12\x0e\n\nDispatch\
-    ed\x10\x05\x12\x11\n\rCancelPending\x10\x06\x12\x14\n\x10CancelProcessin\
-    g\x10\x07\x12\x12\n\x0eCancelComplete\x10\x08*k\n\x14JobGroupProjectStat\
-    e\x12\x0e\n\nNotStarted\x10\0\x12\x0e\n\nInProgress\x10\x01\x12\x0b\n\
-    \x07Success\x10\x02\x12\x0b\n\x07Failure\x10\x03\x12\x0b\n\x07Skipped\
-    \x10\x04\x12\x0c\n\x08Canceled\x10\x05*\x7f\n\rJobGroupState\x12\x10\n\
-    \x0cGroupPending\x10\0\x12\x14\n\x10GroupDispatching\x10\x01\x12\x11\n\r\
-    GroupComplete\x10\x02\x12\x0f\n\x0bGroupFailed\x10\x03\x12\x0f\n\x0bGrou\
-    pQueued\x10\x04\x12\x11\n\rGroupCanceled\x10\x05J\xff?\n\x07\x12\x05\0\0\
-    \xd6\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\
+    get\x18\x03\x20\x01(\tR\x06target\"k\n%JobGraphPackageReverseDependencie\
+    sGet\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\x12\x12\n\x04name\
+    \x18\x02\x20\x01(\tR\x04name\x12\x16\n\x06target\x18\x03\x20\x01(\tR\x06\
+    target\"f\n\"JobGraphPackageReverseDependencies\x12\x16\n\x06origin\x18\
+    \x01\x20\x01(\tR\x06origin\x12\x12\n\x04name\x18\x02\x20\x01(\tR\x04name\
+    \x12\x14\n\x05rdeps\x18\x03\x20\x03(\tR\x05rdeps\"1\n\x17JobGraphPackage\
+    StatsGet\x12\x16\n\x06origin\x18\x01\x20\x01(\tR\x06origin\"\xab\x01\n\
+    \x14JobGraphPackageStats\x12\x14\n\x05plans\x18\x01\x20\x01(\x04R\x05pla\
+    ns\x12\x16\n\x06builds\x18\x02\x20\x01(\x04R\x06builds\x12'\n\x0funique_\
+    packages\x18\x03\x20\x01(\x04R\x0euniquePackages\x12<\n\x07targets\x18\
+    \x04\x20\x03(\x0b2\".jobsrv.JobGraphPackageTargetStatsR\x07targets\"\x8b\
+    \x01\n\x1aJobGraphPackageTargetStats\x12\x16\n\x06target\x18\x01\x20\x01\
+    (\tR\x06target\x12\x14\n\x05plans\x18\x02\x20\x01(\x04R\x05plans\x12\x16\
+    \n\x06builds\x18\x03\x20\x01(\x04R\x06builds\x12'\n\x0funique_packages\
+    \x18\x04\x20\x01(\x04R\x0euniquePackages*(\n\x02Os\x12\t\n\x05Linux\x10\
+    \x01\x12\n\n\x06Darwin\x10\x02\x12\x0b\n\x07Windows\x10\x03*\"\n\x0bWork\
+    erState\x12\t\n\x05Ready\x10\0\x12\x08\n\x04Busy\x10\x01*.\n\x0fWorkerOp\
+    eration\x12\x0c\n\x08StartJob\x10\0\x12\r\n\tCancelJob\x10\x01*\x9c\x01\
+    \n\x08JobState\x12\x0b\n\x07Pending\x10\0\x12\x0e\n\nProcessing\x10\x01\
+    \x12\x0c\n\x08Complete\x10\x02\x12\x0c\n\x08Rejected\x10\x03\x12\n\n\x06\
+    Failed\x10\x04\x12\x0e\n\nDispatched\x10\x05\x12\x11\n\rCancelPending\
+    \x10\x06\x12\x14\n\x10CancelProcessing\x10\x07\x12\x12\n\x0eCancelComple\
+    te\x10\x08*1\n\x0bJobPriority\x12\n\n\x06Normal\x10\0\x12\x08\n\x04High\
+    \x10\x01\x12\x0c\n\x08Critical\x10\x02*&\n\x07JobKind\x12\t\n\x05Build\
+    \x10\0\x12\x10\n\x0cDockerExport\x10\x01*t\n\x10JobErrorCategory\x12\x11\
+    \n\rUncategorized\x10\0\x12\r\n\tTransient\x10\x01\x12\r\n\tPermanent\
+    \x10\x02\x12\x08\n\x04Plan\x10\x03\x12\x12\n\x0eInfrastructure\x10\x04\
+    \x12\x11\n\rResourceLimit\x10\x05*k\n\x14JobGroupProjectState\x12\x0e\n\
+    \nNotStarted\x10\0\x12\x0e\n\nInProgress\x10\x01\x12\x0b\n\x07Success\
+    \x10\x02\x12\x0b\n\x07Failure\x10\x03\x12\x0b\n\x07Skipped\x10\x04\x12\
+    \x0c\n\x08Canceled\x10\x05*\x7f\n\rJobGroupState\x12\x10\n\x0cGroupPendi\
+    ng\x10\0\x12\x14\n\x10GroupDispatching\x10\x01\x12\x11\n\rGroupComplete\
+    \x10\x02\x12\x0f\n\x0bGroupFailed\x10\x03\x12\x0f\n\x0bGroupQueued\x10\
+    \x04\x12\x11\n\rGroupCanceled\x10\x05*l\n\x0fJobAuditTrigger\x12\r\n\tSc\
+    heduler\x10\0\x12\n\n\x06Worker\x10\x01\x12\x08\n\x04User\x10\x02\x12\
+    \x0b\n\x07Expired\x10\x03\x12\x0c\n\x08TimedOut\x10\x04\x12\t\n\x05Retry\
+    \x10\x05\x12\x0e\n\nWorkerLost\x10\x06J\xf4\xb6\x01\n\x07\x12\x05\0\0\
+    \xe8\x03\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\
     \x08\x0e\n\t\n\x02\x03\0\x12\x03\x02\x07\x1c\n\t\n\x02\x03\x01\x12\x03\
     \x03\x07\"\n\n\n\x02\x05\0\x12\x04\x05\0\t\x01\n\n\n\x03\x05\0\x01\x12\
     \x03\x05\x05\x07\n\x0b\n\x04\x05\0\x02\0\x12\x03\x06\x02\x0c\n\x0c\n\x05\
@@ -8284,357 +19122,992 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x05\x05\x03\x02\x07\x01\x12\x03\x1d\x02\x12\n\x0c\n\x05\x05\x03\x02\x07\
     \x02\x12\x03\x1d\x15\x16\n\x0b\n\x04\x05\x03\x02\x08\x12\x03\x1e\x02\x15\
     \n\x0c\n\x05\x05\x03\x02\x08\x01\x12\x03\x1e\x02\x10\n\x0c\n\x05\x05\x03\
-    \x02\x08\x02\x12\x03\x1e\x13\x14\n\n\n\x02\x04\0\x12\x04!\0#\x01\n\n\n\
-    \x03\x04\0\x01\x12\x03!\x08\x15\n\x0b\n\x04\x04\0\x02\0\x12\x03\"\x02\"\
-    \n\x0c\n\x05\x04\0\x02\0\x04\x12\x03\"\x02\n\n\x0c\n\x05\x04\0\x02\0\x06\
-    \x12\x03\"\x0b\x1a\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\"\x1b\x1d\n\x0c\n\
-    \x05\x04\0\x02\0\x03\x12\x03\"\x20!\n\n\n\x02\x04\x01\x12\x04%\0)\x01\n\
-    \n\n\x03\x04\x01\x01\x12\x03%\x08\x11\n\x0b\n\x04\x04\x01\x02\0\x12\x03&\
-    \x02\x1f\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\
-    \x01\x02\0\x05\x12\x03&\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03&\
-    \x12\x1a\n\x0c\n\x05\x04\x01\x02\0\x03\x12\x03&\x1d\x1e\n\x0b\n\x04\x04\
-    \x01\x02\x01\x12\x03'\x02\x15\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03'\
-    \x02\n\n\x0c\n\x05\x04\x01\x02\x01\x06\x12\x03'\x0b\r\n\x0c\n\x05\x04\
-    \x01\x02\x01\x01\x12\x03'\x0e\x10\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\
-    \x03'\x13\x14\n\x0b\n\x04\x04\x01\x02\x02\x12\x03(\x02!\n\x0c\n\x05\x04\
-    \x01\x02\x02\x04\x12\x03(\x02\n\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\x03(\
-    \x0b\x16\n\x0c\n\x05\x04\x01\x02\x02\x01\x12\x03(\x17\x1c\n\x0c\n\x05\
-    \x04\x01\x02\x02\x03\x12\x03(\x1f\x20\n\n\n\x02\x04\x02\x12\x04+\0/\x01\
-    \n\n\n\x03\x04\x02\x01\x12\x03+\x08\x12\n\x0b\n\x04\x04\x02\x02\0\x12\
-    \x03,\x02\x1c\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03,\x02\n\n\x0c\n\x05\
-    \x04\x02\x02\0\x05\x12\x03,\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\
-    \x03,\x12\x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03,\x1a\x1b\n\x0b\n\x04\
-    \x04\x02\x02\x01\x12\x03-\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\
-    \x03-\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03-\x0b\x11\n\x0c\n\x05\
-    \x04\x02\x02\x01\x01\x12\x03-\x12\x18\n\x0c\n\x05\x04\x02\x02\x01\x03\
-    \x12\x03-\x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\x12\x03.\x02\x20\n\x0c\n\
-    \x05\x04\x02\x02\x02\x04\x12\x03.\x02\n\n\x0c\n\x05\x04\x02\x02\x02\x05\
-    \x12\x03.\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03.\x10\x1b\n\x0c\
-    \n\x05\x04\x02\x02\x02\x03\x12\x03.\x1e\x1f\n\n\n\x02\x04\x03\x12\x041\0\
-    B\x01\n\n\n\x03\x04\x03\x01\x12\x031\x08\x0b\n\n\n\x03\x04\x03\t\x12\x03\
-    2\x0b\x0e\n\x0b\n\x04\x04\x03\t\0\x12\x032\x0b\r\n\x0c\n\x05\x04\x03\t\0\
-    \x01\x12\x032\x0b\r\n\x0c\n\x05\x04\x03\t\0\x02\x12\x032\x0b\r\n\n\n\x03\
-    \x04\x03\n\x12\x033\x0b\x15\n\x0b\n\x04\x04\x03\n\0\x12\x033\x0b\x14\n\
-    \x0b\n\x04\x04\x03\x02\0\x12\x034\x02\x19\n\x0c\n\x05\x04\x03\x02\0\x04\
-    \x12\x034\x02\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x034\x0b\x11\n\x0c\n\
-    \x05\x04\x03\x02\0\x01\x12\x034\x12\x14\n\x0c\n\x05\x04\x03\x02\0\x03\
-    \x12\x034\x17\x18\n\x0b\n\x04\x04\x03\x02\x01\x12\x035\x02\x1f\n\x0c\n\
-    \x05\x04\x03\x02\x01\x04\x12\x035\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x05\
-    \x12\x035\x0b\x11\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x035\x12\x1a\n\x0c\
-    \n\x05\x04\x03\x02\x01\x03\x12\x035\x1d\x1e\n\x0b\n\x04\x04\x03\x02\x02\
-    \x12\x036\x02\x1e\n\x0c\n\x05\x04\x03\x02\x02\x04\x12\x036\x02\n\n\x0c\n\
-    \x05\x04\x03\x02\x02\x06\x12\x036\x0b\x13\n\x0c\n\x05\x04\x03\x02\x02\
-    \x01\x12\x036\x14\x19\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x036\x1c\x1d\n\
-    \x0b\n\x04\x04\x03\x02\x03\x12\x037\x02/\n\x0c\n\x05\x04\x03\x02\x03\x04\
-    \x12\x037\x02\n\n\x0c\n\x05\x04\x03\x02\x03\x06\x12\x037\x0b\"\n\x0c\n\
-    \x05\x04\x03\x02\x03\x01\x12\x037#*\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\
-    \x037-.\n\x0b\n\x04\x04\x03\x02\x04\x12\x038\x02\"\n\x0c\n\x05\x04\x03\
-    \x02\x04\x04\x12\x038\x02\n\n\x0c\n\x05\x04\x03\x02\x04\x06\x12\x038\x0b\
-    \x17\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x038\x18\x1d\n\x0c\n\x05\x04\
-    \x03\x02\x04\x03\x12\x038\x20!\n%\n\x04\x04\x03\x02\x05\x12\x039\x02!\"\
-    \x18\x20RFC3339-formatted\x20time\n\n\x0c\n\x05\x04\x03\x02\x05\x04\x12\
-    \x039\x02\n\n\x0c\n\x05\x04\x03\x02\x05\x05\x12\x039\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\x05\x01\x12\x039\x12\x1c\n\x0c\n\x05\x04\x03\x02\x05\x03\
-    \x12\x039\x1f\x20\n%\n\x04\x04\x03\x02\x06\x12\x03:\x02'\"\x18\x20RFC333\
-    9-formatted\x20time\n\n\x0c\n\x05\x04\x03\x02\x06\x04\x12\x03:\x02\n\n\
-    \x0c\n\x05\x04\x03\x02\x06\x05\x12\x03:\x0b\x11\n\x0c\n\x05\x04\x03\x02\
-    \x06\x01\x12\x03:\x12\"\n\x0c\n\x05\x04\x03\x02\x06\x03\x12\x03:%&\n\x0b\
-    \n\x04\x04\x03\x02\x07\x12\x03;\x02(\n\x0c\n\x05\x04\x03\x02\x07\x04\x12\
-    \x03;\x02\n\n\x0c\n\x05\x04\x03\x02\x07\x05\x12\x03;\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\x07\x01\x12\x03;\x12#\n\x0c\n\x05\x04\x03\x02\x07\x03\x12\
-    \x03;&'\n\x0b\n\x04\x04\x03\x02\x08\x12\x03<\x02:\n\x0c\n\x05\x04\x03\
-    \x02\x08\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\x03\x02\x08\x06\x12\x03<\x0b\
-    '\n\x0c\n\x05\x04\x03\x02\x08\x01\x12\x03<(5\n\x0c\n\x05\x04\x03\x02\x08\
-    \x03\x12\x03<89\n\x0b\n\x04\x04\x03\x02\t\x12\x03=\x02!\n\x0c\n\x05\x04\
-    \x03\x02\t\x04\x12\x03=\x02\n\n\x0c\n\x05\x04\x03\x02\t\x05\x12\x03=\x0b\
-    \x0f\n\x0c\n\x05\x04\x03\x02\t\x01\x12\x03=\x10\x1b\n\x0c\n\x05\x04\x03\
-    \x02\t\x03\x12\x03=\x1e\x20\n\x0b\n\x04\x04\x03\x02\n\x12\x03>\x029\n\
-    \x0c\n\x05\x04\x03\x02\n\x04\x12\x03>\x02\n\n\x0c\n\x05\x04\x03\x02\n\
-    \x06\x12\x03>\x0b&\n\x0c\n\x05\x04\x03\x02\n\x01\x12\x03>'3\n\x0c\n\x05\
-    \x04\x03\x02\n\x03\x12\x03>68\n\x0b\n\x04\x04\x03\x02\x0b\x12\x03?\x02\
-    \x1f\n\x0c\n\x05\x04\x03\x02\x0b\x04\x12\x03?\x02\n\n\x0c\n\x05\x04\x03\
-    \x02\x0b\x05\x12\x03?\x0b\x11\n\x0c\n\x05\x04\x03\x02\x0b\x01\x12\x03?\
-    \x12\x19\n\x0c\n\x05\x04\x03\x02\x0b\x03\x12\x03?\x1c\x1e\n\x0b\n\x04\
-    \x04\x03\x02\x0c\x12\x03@\x02H\n\x0c\n\x05\x04\x03\x02\x0c\x04\x12\x03@\
-    \x02\n\n\x0c\n\x05\x04\x03\x02\x0c\x06\x12\x03@\x0b-\n\x0c\n\x05\x04\x03\
-    \x02\x0c\x01\x12\x03@.B\n\x0c\n\x05\x04\x03\x02\x0c\x03\x12\x03@EG\n\x0b\
-    \n\x04\x04\x03\x02\r\x12\x03A\x02\x1e\n\x0c\n\x05\x04\x03\x02\r\x04\x12\
-    \x03A\x02\n\n\x0c\n\x05\x04\x03\x02\r\x05\x12\x03A\x0b\x11\n\x0c\n\x05\
-    \x04\x03\x02\r\x01\x12\x03A\x12\x18\n\x0c\n\x05\x04\x03\x02\r\x03\x12\
-    \x03A\x1b\x1d\n\n\n\x02\x04\x04\x12\x04D\0F\x01\n\n\n\x03\x04\x04\x01\
-    \x12\x03D\x08\x0e\n\x0b\n\x04\x04\x04\x02\0\x12\x03E\x02\x19\n\x0c\n\x05\
-    \x04\x04\x02\0\x04\x12\x03E\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03E\
-    \x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03E\x12\x14\n\x0c\n\x05\x04\
-    \x04\x02\0\x03\x12\x03E\x17\x18\n\n\n\x02\x04\x05\x12\x04H\0L\x01\n\n\n\
-    \x03\x04\x05\x01\x12\x03H\x08\x0f\n\x0b\n\x04\x04\x05\x02\0\x12\x03I\x02\
-    \x1f\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x03I\x02\n\n\x0c\n\x05\x04\x05\
-    \x02\0\x05\x12\x03I\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03I\x12\
-    \x1a\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x03I\x1d\x1e\n\x0b\n\x04\x04\x05\
-    \x02\x01\x12\x03J\x02/\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03J\x02\n\n\
-    \x0c\n\x05\x04\x05\x02\x01\x06\x12\x03J\x0b\"\n\x0c\n\x05\x04\x05\x02\
-    \x01\x01\x12\x03J#*\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03J-.\n\x0b\n\
-    \x04\x04\x05\x02\x02\x12\x03K\x02\x1e\n\x0c\n\x05\x04\x05\x02\x02\x04\
-    \x12\x03K\x02\n\n\x0c\n\x05\x04\x05\x02\x02\x05\x12\x03K\x0b\x11\n\x0c\n\
-    \x05\x04\x05\x02\x02\x01\x12\x03K\x12\x19\n\x0c\n\x05\x04\x05\x02\x02\
-    \x03\x12\x03K\x1c\x1d\n\n\n\x02\x04\x06\x12\x04N\0R\x01\n\n\n\x03\x04\
-    \x06\x01\x12\x03N\x08\x16\n\x0b\n\x04\x04\x06\x02\0\x12\x03O\x02\x1b\n\
-    \x0c\n\x05\x04\x06\x02\0\x04\x12\x03O\x02\n\n\x0c\n\x05\x04\x06\x02\0\
-    \x05\x12\x03O\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03O\x12\x16\n\
-    \x0c\n\x05\x04\x06\x02\0\x03\x12\x03O\x19\x1a\n\x0b\n\x04\x04\x06\x02\
-    \x01\x12\x03P\x02\x1c\n\x0c\n\x05\x04\x06\x02\x01\x04\x12\x03P\x02\n\n\
-    \x0c\n\x05\x04\x06\x02\x01\x05\x12\x03P\x0b\x11\n\x0c\n\x05\x04\x06\x02\
-    \x01\x01\x12\x03P\x12\x17\n\x0c\n\x05\x04\x06\x02\x01\x03\x12\x03P\x1a\
-    \x1b\n\x0b\n\x04\x04\x06\x02\x02\x12\x03Q\x02\x1b\n\x0c\n\x05\x04\x06\
-    \x02\x02\x04\x12\x03Q\x02\n\n\x0c\n\x05\x04\x06\x02\x02\x05\x12\x03Q\x0b\
-    \x11\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03Q\x12\x16\n\x0c\n\x05\x04\
-    \x06\x02\x02\x03\x12\x03Q\x19\x1a\n\n\n\x02\x04\x07\x12\x04T\0Y\x01\n\n\
-    \n\x03\x04\x07\x01\x12\x03T\x08\x1e\n\x0b\n\x04\x04\x07\x02\0\x12\x03U\
-    \x02\x18\n\x0c\n\x05\x04\x07\x02\0\x04\x12\x03U\x02\n\n\x0c\n\x05\x04\
-    \x07\x02\0\x06\x12\x03U\x0b\x0e\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03U\
-    \x0f\x13\n\x0c\n\x05\x04\x07\x02\0\x03\x12\x03U\x16\x17\n\x0b\n\x04\x04\
-    \x07\x02\x01\x12\x03V\x02\x1c\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x03V\
-    \x02\n\n\x0c\n\x05\x04\x07\x02\x01\x05\x12\x03V\x0b\x11\n\x0c\n\x05\x04\
-    \x07\x02\x01\x01\x12\x03V\x12\x17\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\
-    \x03V\x1a\x1b\n\x0b\n\x04\x04\x07\x02\x02\x12\x03W\x02\x1b\n\x0c\n\x05\
-    \x04\x07\x02\x02\x04\x12\x03W\x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\
-    \x03W\x0b\x11\n\x0c\n\x05\x04\x07\x02\x02\x01\x12\x03W\x12\x16\n\x0c\n\
-    \x05\x04\x07\x02\x02\x03\x12\x03W\x19\x1a\n\x0b\n\x04\x04\x07\x02\x03\
-    \x12\x03X\x02\x1c\n\x0c\n\x05\x04\x07\x02\x03\x04\x12\x03X\x02\n\n\x0c\n\
-    \x05\x04\x07\x02\x03\x05\x12\x03X\x0b\x11\n\x0c\n\x05\x04\x07\x02\x03\
-    \x01\x12\x03X\x12\x17\n\x0c\n\x05\x04\x07\x02\x03\x03\x12\x03X\x1a\x1b\n\
-    \n\n\x02\x04\x08\x12\x04[\0_\x01\n\n\n\x03\x04\x08\x01\x12\x03[\x08\x13\
-    \n\x0b\n\x04\x04\x08\x02\0\x12\x03\\\x02\x1d\n\x0c\n\x05\x04\x08\x02\0\
-    \x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\x03\\\x0b\x11\n\
-    \x0c\n\x05\x04\x08\x02\0\x01\x12\x03\\\x12\x18\n\x0c\n\x05\x04\x08\x02\0\
-    \x03\x12\x03\\\x1b\x1c\n+\n\x04\x04\x08\x02\x01\x12\x03]\x02\x1a\"\x1e\
-    \x20Chunk\x20ordering\x20(line\x20number)\n\n\x0c\n\x05\x04\x08\x02\x01\
-    \x04\x12\x03]\x02\n\n\x0c\n\x05\x04\x08\x02\x01\x05\x12\x03]\x0b\x11\n\
-    \x0c\n\x05\x04\x08\x02\x01\x01\x12\x03]\x12\x15\n\x0c\n\x05\x04\x08\x02\
-    \x01\x03\x12\x03]\x18\x19\n5\n\x04\x04\x08\x02\x02\x12\x03^\x02\x1e\"(\
-    \x20Log\x20content\x20(TODO:\x20Make\x20repeatedfield)\n\n\x0c\n\x05\x04\
-    \x08\x02\x02\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03^\
-    \x0b\x11\n\x0c\n\x05\x04\x08\x02\x02\x01\x12\x03^\x12\x19\n\x0c\n\x05\
-    \x04\x08\x02\x02\x03\x12\x03^\x1c\x1d\n\n\n\x02\x04\t\x12\x04a\0c\x01\n\
-    \n\n\x03\x04\t\x01\x12\x03a\x08\x16\n\x0b\n\x04\x04\t\x02\0\x12\x03b\x02\
-    \x1d\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03b\x02\n\n\x0c\n\x05\x04\t\x02\0\
-    \x05\x12\x03b\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03b\x12\x18\n\x0c\
-    \n\x05\x04\t\x02\0\x03\x12\x03b\x1b\x1c\n\n\n\x02\x04\n\x12\x04e\0h\x01\
-    \n\n\n\x03\x04\n\x01\x12\x03e\x08\x11\n\x0b\n\x04\x04\n\x02\0\x12\x03f\
-    \x02\x19\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03f\x02\n\n\x0c\n\x05\x04\n\
-    \x02\0\x05\x12\x03f\x0b\x11\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03f\x12\x14\
-    \n\x0c\n\x05\x04\n\x02\0\x03\x12\x03f\x17\x18\n.\n\x04\x04\n\x02\x01\x12\
-    \x03g\x02\x1c\"!\x20Zero-indexed\x20line\x20of\x20log\x20output\n\n\x0c\
-    \n\x05\x04\n\x02\x01\x04\x12\x03g\x02\n\n\x0c\n\x05\x04\n\x02\x01\x05\
-    \x12\x03g\x0b\x11\n\x0c\n\x05\x04\n\x02\x01\x01\x12\x03g\x12\x17\n\x0c\n\
-    \x05\x04\n\x02\x01\x03\x12\x03g\x1a\x1b\n\n\n\x02\x04\x0b\x12\x04j\0o\
-    \x01\n\n\n\x03\x04\x0b\x01\x12\x03j\x08\x0e\n,\n\x04\x04\x0b\x02\0\x12\
-    \x03k\x02\x1c\"\x1f\x20Zero-indexed\x20(inclusive)\x20line\n\n\x0c\n\x05\
-    \x04\x0b\x02\0\x04\x12\x03k\x02\n\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03k\
-    \x0b\x11\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03k\x12\x17\n\x0c\n\x05\x04\
-    \x0b\x02\0\x03\x12\x03k\x1a\x1b\n,\n\x04\x04\x0b\x02\x01\x12\x03l\x02\
-    \x1b\"\x1f\x20Zero-indexed\x20(exclusive)\x20line\n\n\x0c\n\x05\x04\x0b\
-    \x02\x01\x04\x12\x03l\x02\n\n\x0c\n\x05\x04\x0b\x02\x01\x05\x12\x03l\x0b\
-    \x11\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03l\x12\x16\n\x0c\n\x05\x04\
-    \x0b\x02\x01\x03\x12\x03l\x19\x1a\n\x0b\n\x04\x04\x0b\x02\x02\x12\x03m\
-    \x02\x1e\n\x0c\n\x05\x04\x0b\x02\x02\x04\x12\x03m\x02\n\n\x0c\n\x05\x04\
-    \x0b\x02\x02\x05\x12\x03m\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x02\x01\x12\
-    \x03m\x12\x19\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\x03m\x1c\x1d\n\x0b\n\
-    \x04\x04\x0b\x02\x03\x12\x03n\x02\x20\n\x0c\n\x05\x04\x0b\x02\x03\x04\
-    \x12\x03n\x02\n\n\x0c\n\x05\x04\x0b\x02\x03\x05\x12\x03n\x0b\x0f\n\x0c\n\
-    \x05\x04\x0b\x02\x03\x01\x12\x03n\x10\x1b\n\x0c\n\x05\x04\x0b\x02\x03\
-    \x03\x12\x03n\x1e\x1f\n\n\n\x02\x04\x0c\x12\x04q\0x\x01\n\n\n\x03\x04\
-    \x0c\x01\x12\x03q\x08\x14\n\x0b\n\x04\x04\x0c\x02\0\x12\x03r\x02\x1d\n\
-    \x0c\n\x05\x04\x0c\x02\0\x04\x12\x03r\x02\n\n\x0c\n\x05\x04\x0c\x02\0\
-    \x05\x12\x03r\x0b\x11\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03r\x12\x18\n\
-    \x0c\n\x05\x04\x0c\x02\0\x03\x12\x03r\x1b\x1c\n\x0b\n\x04\x04\x0c\x02\
-    \x01\x12\x03s\x02\x1e\n\x0c\n\x05\x04\x0c\x02\x01\x04\x12\x03s\x02\n\n\
-    \x0c\n\x05\x04\x0c\x02\x01\x05\x12\x03s\x0b\x11\n\x0c\n\x05\x04\x0c\x02\
-    \x01\x01\x12\x03s\x12\x19\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03s\x1c\
-    \x1d\n\x0b\n\x04\x04\x0c\x02\x02\x12\x03t\x02\x1e\n\x0c\n\x05\x04\x0c\
-    \x02\x02\x04\x12\x03t\x02\n\n\x0c\n\x05\x04\x0c\x02\x02\x05\x12\x03t\x0b\
-    \x0f\n\x0c\n\x05\x04\x0c\x02\x02\x01\x12\x03t\x10\x19\n\x0c\n\x05\x04\
-    \x0c\x02\x02\x03\x12\x03t\x1c\x1d\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03u\
-    \x02\x1d\n\x0c\n\x05\x04\x0c\x02\x03\x04\x12\x03u\x02\n\n\x0c\n\x05\x04\
-    \x0c\x02\x03\x05\x12\x03u\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\
-    \x03u\x12\x18\n\x0c\n\x05\x04\x0c\x02\x03\x03\x12\x03u\x1b\x1c\n\x0b\n\
-    \x04\x04\x0c\x02\x04\x12\x03v\x02\x20\n\x0c\n\x05\x04\x0c\x02\x04\x04\
-    \x12\x03v\x02\n\n\x0c\n\x05\x04\x0c\x02\x04\x05\x12\x03v\x0b\x0f\n\x0c\n\
-    \x05\x04\x0c\x02\x04\x01\x12\x03v\x10\x1b\n\x0c\n\x05\x04\x0c\x02\x04\
-    \x03\x12\x03v\x1e\x1f\n\x0b\n\x04\x04\x0c\x02\x05\x12\x03w\x02!\n\x0c\n\
-    \x05\x04\x0c\x02\x05\x04\x12\x03w\x02\n\n\x0c\n\x05\x04\x0c\x02\x05\x05\
-    \x12\x03w\x0b\x0f\n\x0c\n\x05\x04\x0c\x02\x05\x01\x12\x03w\x10\x1c\n\x0c\
-    \n\x05\x04\x0c\x02\x05\x03\x12\x03w\x1f\x20\n\x0b\n\x02\x05\x04\x12\x05z\
-    \0\x81\x01\x01\n\n\n\x03\x05\x04\x01\x12\x03z\x05\x19\n\x0b\n\x04\x05\
-    \x04\x02\0\x12\x03{\x02\x11\n\x0c\n\x05\x05\x04\x02\0\x01\x12\x03{\x02\
-    \x0c\n\x0c\n\x05\x05\x04\x02\0\x02\x12\x03{\x0f\x10\n\x0b\n\x04\x05\x04\
-    \x02\x01\x12\x03|\x02\x11\n\x0c\n\x05\x05\x04\x02\x01\x01\x12\x03|\x02\
-    \x0c\n\x0c\n\x05\x05\x04\x02\x01\x02\x12\x03|\x0f\x10\n\x0b\n\x04\x05\
-    \x04\x02\x02\x12\x03}\x02\x0e\n\x0c\n\x05\x05\x04\x02\x02\x01\x12\x03}\
-    \x02\t\n\x0c\n\x05\x05\x04\x02\x02\x02\x12\x03}\x0c\r\n\x0b\n\x04\x05\
-    \x04\x02\x03\x12\x03~\x02\x0e\n\x0c\n\x05\x05\x04\x02\x03\x01\x12\x03~\
-    \x02\t\n\x0c\n\x05\x05\x04\x02\x03\x02\x12\x03~\x0c\r\n\x0b\n\x04\x05\
-    \x04\x02\x04\x12\x03\x7f\x02\x0e\n\x0c\n\x05\x05\x04\x02\x04\x01\x12\x03\
-    \x7f\x02\t\n\x0c\n\x05\x05\x04\x02\x04\x02\x12\x03\x7f\x0c\r\n\x0c\n\x04\
-    \x05\x04\x02\x05\x12\x04\x80\x01\x02\x0f\n\r\n\x05\x05\x04\x02\x05\x01\
-    \x12\x04\x80\x01\x02\n\n\r\n\x05\x05\x04\x02\x05\x02\x12\x04\x80\x01\r\
-    \x0e\n\x0c\n\x02\x04\r\x12\x06\x83\x01\0\x89\x01\x01\n\x0b\n\x03\x04\r\
-    \x01\x12\x04\x83\x01\x08\x17\n\x0c\n\x04\x04\r\x02\0\x12\x04\x84\x01\x02\
-    \x1b\n\r\n\x05\x04\r\x02\0\x04\x12\x04\x84\x01\x02\n\n\r\n\x05\x04\r\x02\
-    \0\x05\x12\x04\x84\x01\x0b\x11\n\r\n\x05\x04\r\x02\0\x01\x12\x04\x84\x01\
-    \x12\x16\n\r\n\x05\x04\r\x02\0\x03\x12\x04\x84\x01\x19\x1a\n\x0c\n\x04\
-    \x04\r\x02\x01\x12\x04\x85\x01\x02\x1c\n\r\n\x05\x04\r\x02\x01\x04\x12\
-    \x04\x85\x01\x02\n\n\r\n\x05\x04\r\x02\x01\x05\x12\x04\x85\x01\x0b\x11\n\
-    \r\n\x05\x04\r\x02\x01\x01\x12\x04\x85\x01\x12\x17\n\r\n\x05\x04\r\x02\
-    \x01\x03\x12\x04\x85\x01\x1a\x1b\n\x0c\n\x04\x04\r\x02\x02\x12\x04\x86\
-    \x01\x02*\n\r\n\x05\x04\r\x02\x02\x04\x12\x04\x86\x01\x02\n\n\r\n\x05\
-    \x04\r\x02\x02\x06\x12\x04\x86\x01\x0b\x1f\n\r\n\x05\x04\r\x02\x02\x01\
-    \x12\x04\x86\x01\x20%\n\r\n\x05\x04\r\x02\x02\x03\x12\x04\x86\x01()\n\
-    \x0c\n\x04\x04\r\x02\x03\x12\x04\x87\x01\x02\x1d\n\r\n\x05\x04\r\x02\x03\
-    \x04\x12\x04\x87\x01\x02\n\n\r\n\x05\x04\r\x02\x03\x05\x12\x04\x87\x01\
-    \x0b\x11\n\r\n\x05\x04\r\x02\x03\x01\x12\x04\x87\x01\x12\x18\n\r\n\x05\
-    \x04\r\x02\x03\x03\x12\x04\x87\x01\x1b\x1c\n\x0c\n\x04\x04\r\x02\x04\x12\
-    \x04\x88\x01\x02\x1d\n\r\n\x05\x04\r\x02\x04\x04\x12\x04\x88\x01\x02\n\n\
-    \r\n\x05\x04\r\x02\x04\x05\x12\x04\x88\x01\x0b\x11\n\r\n\x05\x04\r\x02\
-    \x04\x01\x12\x04\x88\x01\x12\x18\n\r\n\x05\x04\r\x02\x04\x03\x12\x04\x88\
-    \x01\x1b\x1c\n\x0c\n\x02\x05\x05\x12\x06\x8b\x01\0\x92\x01\x01\n\x0b\n\
-    \x03\x05\x05\x01\x12\x04\x8b\x01\x05\x12\n\x0c\n\x04\x05\x05\x02\0\x12\
-    \x04\x8c\x01\x02\x13\n\r\n\x05\x05\x05\x02\0\x01\x12\x04\x8c\x01\x02\x0e\
-    \n\r\n\x05\x05\x05\x02\0\x02\x12\x04\x8c\x01\x11\x12\n\x0c\n\x04\x05\x05\
-    \x02\x01\x12\x04\x8d\x01\x02\x17\n\r\n\x05\x05\x05\x02\x01\x01\x12\x04\
-    \x8d\x01\x02\x12\n\r\n\x05\x05\x05\x02\x01\x02\x12\x04\x8d\x01\x15\x16\n\
-    \x0c\n\x04\x05\x05\x02\x02\x12\x04\x8e\x01\x02\x14\n\r\n\x05\x05\x05\x02\
-    \x02\x01\x12\x04\x8e\x01\x02\x0f\n\r\n\x05\x05\x05\x02\x02\x02\x12\x04\
-    \x8e\x01\x12\x13\n\x0c\n\x04\x05\x05\x02\x03\x12\x04\x8f\x01\x02\x12\n\r\
-    \n\x05\x05\x05\x02\x03\x01\x12\x04\x8f\x01\x02\r\n\r\n\x05\x05\x05\x02\
-    \x03\x02\x12\x04\x8f\x01\x10\x11\n\x0c\n\x04\x05\x05\x02\x04\x12\x04\x90\
-    \x01\x02\x12\n\r\n\x05\x05\x05\x02\x04\x01\x12\x04\x90\x01\x02\r\n\r\n\
-    \x05\x05\x05\x02\x04\x02\x12\x04\x90\x01\x10\x11\n\x0c\n\x04\x05\x05\x02\
-    \x05\x12\x04\x91\x01\x02\x14\n\r\n\x05\x05\x05\x02\x05\x01\x12\x04\x91\
-    \x01\x02\x0f\n\r\n\x05\x05\x05\x02\x05\x02\x12\x04\x91\x01\x12\x13\n\x0c\
-    \n\x02\x04\x0e\x12\x06\x94\x01\0\x96\x01\x01\n\x0b\n\x03\x04\x0e\x01\x12\
-    \x04\x94\x01\x08\x15\n\x0c\n\x04\x04\x0e\x02\0\x12\x04\x95\x01\x02\x1f\n\
-    \r\n\x05\x04\x0e\x02\0\x04\x12\x04\x95\x01\x02\n\n\r\n\x05\x04\x0e\x02\0\
-    \x05\x12\x04\x95\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\x95\x01\
-    \x12\x1a\n\r\n\x05\x04\x0e\x02\0\x03\x12\x04\x95\x01\x1d\x1e\n\x0c\n\x02\
-    \x04\x0f\x12\x06\x98\x01\0\x9a\x01\x01\n\x0b\n\x03\x04\x0f\x01\x12\x04\
-    \x98\x01\x08\x16\n\x0c\n\x04\x04\x0f\x02\0\x12\x04\x99\x01\x02\x1f\n\r\n\
-    \x05\x04\x0f\x02\0\x04\x12\x04\x99\x01\x02\n\n\r\n\x05\x04\x0f\x02\0\x05\
-    \x12\x04\x99\x01\x0b\x11\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\x99\x01\x12\
-    \x1a\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\x99\x01\x1d\x1e\n\x0c\n\x02\x04\
-    \x10\x12\x06\x9c\x01\0\x9e\x01\x01\n\x0b\n\x03\x04\x10\x01\x12\x04\x9c\
-    \x01\x08\x13\n\x0c\n\x04\x04\x10\x02\0\x12\x04\x9d\x01\x02\x1f\n\r\n\x05\
-    \x04\x10\x02\0\x04\x12\x04\x9d\x01\x02\n\n\r\n\x05\x04\x10\x02\0\x05\x12\
-    \x04\x9d\x01\x0b\x11\n\r\n\x05\x04\x10\x02\0\x01\x12\x04\x9d\x01\x12\x1a\
-    \n\r\n\x05\x04\x10\x02\0\x03\x12\x04\x9d\x01\x1d\x1e\n\x0c\n\x02\x04\x11\
-    \x12\x06\xa0\x01\0\xa2\x01\x01\n\x0b\n\x03\x04\x11\x01\x12\x04\xa0\x01\
-    \x08\x19\n\x0c\n\x04\x04\x11\x02\0\x12\x04\xa1\x01\x02\x1d\n\r\n\x05\x04\
-    \x11\x02\0\x04\x12\x04\xa1\x01\x02\n\n\r\n\x05\x04\x11\x02\0\x05\x12\x04\
-    \xa1\x01\x0b\x11\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\xa1\x01\x12\x18\n\r\
-    \n\x05\x04\x11\x02\0\x03\x12\x04\xa1\x01\x1b\x1c\n\x0c\n\x02\x04\x12\x12\
-    \x06\xa4\x01\0\xa6\x01\x01\n\x0b\n\x03\x04\x12\x01\x12\x04\xa4\x01\x08\
-    \x1e\n\x0c\n\x04\x04\x12\x02\0\x12\x04\xa5\x01\x02#\n\r\n\x05\x04\x12\
-    \x02\0\x04\x12\x04\xa5\x01\x02\n\n\r\n\x05\x04\x12\x02\0\x06\x12\x04\xa5\
-    \x01\x0b\x13\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\xa5\x01\x14\x1e\n\r\n\
-    \x05\x04\x12\x02\0\x03\x12\x04\xa5\x01!\"\n\x0c\n\x02\x04\x13\x12\x06\
-    \xa8\x01\0\xae\x01\x01\n\x0b\n\x03\x04\x13\x01\x12\x04\xa8\x01\x08\x10\n\
-    \x0c\n\x04\x04\x13\x02\0\x12\x04\xa9\x01\x02\x19\n\r\n\x05\x04\x13\x02\0\
-    \x04\x12\x04\xa9\x01\x02\n\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\xa9\x01\
-    \x0b\x11\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\xa9\x01\x12\x14\n\r\n\x05\
-    \x04\x13\x02\0\x03\x12\x04\xa9\x01\x17\x18\n\x0c\n\x04\x04\x13\x02\x01\
-    \x12\x04\xaa\x01\x02#\n\r\n\x05\x04\x13\x02\x01\x04\x12\x04\xaa\x01\x02\
-    \n\n\r\n\x05\x04\x13\x02\x01\x06\x12\x04\xaa\x01\x0b\x18\n\r\n\x05\x04\
-    \x13\x02\x01\x01\x12\x04\xaa\x01\x19\x1e\n\r\n\x05\x04\x13\x02\x01\x03\
-    \x12\x04\xaa\x01!\"\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\xab\x01\x02(\n\r\
-    \n\x05\x04\x13\x02\x02\x04\x12\x04\xab\x01\x02\n\n\r\n\x05\x04\x13\x02\
-    \x02\x06\x12\x04\xab\x01\x0b\x1a\n\r\n\x05\x04\x13\x02\x02\x01\x12\x04\
-    \xab\x01\x1b#\n\r\n\x05\x04\x13\x02\x02\x03\x12\x04\xab\x01&'\n\x0c\n\
-    \x04\x04\x13\x02\x03\x12\x04\xac\x01\x02!\n\r\n\x05\x04\x13\x02\x03\x04\
-    \x12\x04\xac\x01\x02\n\n\r\n\x05\x04\x13\x02\x03\x05\x12\x04\xac\x01\x0b\
-    \x11\n\r\n\x05\x04\x13\x02\x03\x01\x12\x04\xac\x01\x12\x1c\n\r\n\x05\x04\
-    \x13\x02\x03\x03\x12\x04\xac\x01\x1f\x20\n\x0c\n\x04\x04\x13\x02\x04\x12\
-    \x04\xad\x01\x02#\n\r\n\x05\x04\x13\x02\x04\x04\x12\x04\xad\x01\x02\n\n\
-    \r\n\x05\x04\x13\x02\x04\x05\x12\x04\xad\x01\x0b\x11\n\r\n\x05\x04\x13\
-    \x02\x04\x01\x12\x04\xad\x01\x12\x1e\n\r\n\x05\x04\x13\x02\x04\x03\x12\
-    \x04\xad\x01!\"\n\x0c\n\x02\x04\x14\x12\x06\xb0\x01\0\xb4\x01\x01\n\x0b\
-    \n\x03\x04\x14\x01\x12\x04\xb0\x01\x08\x17\n\x0c\n\x04\x04\x14\x02\0\x12\
-    \x04\xb1\x01\x02\x1c\n\r\n\x05\x04\x14\x02\0\x04\x12\x04\xb1\x01\x02\n\n\
-    \r\n\x05\x04\x14\x02\0\x05\x12\x04\xb1\x01\x0b\x11\n\r\n\x05\x04\x14\x02\
-    \0\x01\x12\x04\xb1\x01\x12\x17\n\r\n\x05\x04\x14\x02\0\x03\x12\x04\xb1\
-    \x01\x1a\x1b\n\x0c\n\x04\x04\x14\x02\x01\x12\x04\xb2\x01\x02\x1b\n\r\n\
-    \x05\x04\x14\x02\x01\x04\x12\x04\xb2\x01\x02\n\n\r\n\x05\x04\x14\x02\x01\
-    \x05\x12\x04\xb2\x01\x0b\x11\n\r\n\x05\x04\x14\x02\x01\x01\x12\x04\xb2\
-    \x01\x12\x16\n\r\n\x05\x04\x14\x02\x01\x03\x12\x04\xb2\x01\x19\x1a\n\x0c\
-    \n\x04\x04\x14\x02\x02\x12\x04\xb3\x01\x02\x1d\n\r\n\x05\x04\x14\x02\x02\
-    \x04\x12\x04\xb3\x01\x02\n\n\r\n\x05\x04\x14\x02\x02\x05\x12\x04\xb3\x01\
-    \x0b\x11\n\r\n\x05\x04\x14\x02\x02\x01\x12\x04\xb3\x01\x12\x18\n\r\n\x05\
-    \x04\x14\x02\x02\x03\x12\x04\xb3\x01\x1b\x1c\n\x0c\n\x02\x04\x15\x12\x06\
-    \xb6\x01\0\xba\x01\x01\n\x0b\n\x03\x04\x15\x01\x12\x04\xb6\x01\x08\x20\n\
-    \x0c\n\x04\x04\x15\x02\0\x12\x04\xb7\x01\x02\x1c\n\r\n\x05\x04\x15\x02\0\
-    \x04\x12\x04\xb7\x01\x02\n\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\xb7\x01\
-    \x0b\x11\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\xb7\x01\x12\x17\n\r\n\x05\
-    \x04\x15\x02\0\x03\x12\x04\xb7\x01\x1a\x1b\n\x0c\n\x04\x04\x15\x02\x01\
-    \x12\x04\xb8\x01\x02\x1b\n\r\n\x05\x04\x15\x02\x01\x04\x12\x04\xb8\x01\
-    \x02\n\n\r\n\x05\x04\x15\x02\x01\x05\x12\x04\xb8\x01\x0b\x11\n\r\n\x05\
-    \x04\x15\x02\x01\x01\x12\x04\xb8\x01\x12\x16\n\r\n\x05\x04\x15\x02\x01\
-    \x03\x12\x04\xb8\x01\x19\x1a\n\x0c\n\x04\x04\x15\x02\x02\x12\x04\xb9\x01\
-    \x02\x1d\n\r\n\x05\x04\x15\x02\x02\x04\x12\x04\xb9\x01\x02\n\n\r\n\x05\
-    \x04\x15\x02\x02\x05\x12\x04\xb9\x01\x0b\x11\n\r\n\x05\x04\x15\x02\x02\
-    \x01\x12\x04\xb9\x01\x12\x18\n\r\n\x05\x04\x15\x02\x02\x03\x12\x04\xb9\
-    \x01\x1b\x1c\n\x0c\n\x02\x04\x16\x12\x06\xbc\x01\0\xc0\x01\x01\n\x0b\n\
-    \x03\x04\x16\x01\x12\x04\xbc\x01\x08\x1d\n\x0c\n\x04\x04\x16\x02\0\x12\
-    \x04\xbd\x01\x02\x1c\n\r\n\x05\x04\x16\x02\0\x04\x12\x04\xbd\x01\x02\n\n\
-    \r\n\x05\x04\x16\x02\0\x05\x12\x04\xbd\x01\x0b\x11\n\r\n\x05\x04\x16\x02\
-    \0\x01\x12\x04\xbd\x01\x12\x17\n\r\n\x05\x04\x16\x02\0\x03\x12\x04\xbd\
-    \x01\x1a\x1b\n\x0c\n\x04\x04\x16\x02\x01\x12\x04\xbe\x01\x02\x1b\n\r\n\
-    \x05\x04\x16\x02\x01\x04\x12\x04\xbe\x01\x02\n\n\r\n\x05\x04\x16\x02\x01\
-    \x05\x12\x04\xbe\x01\x0b\x11\n\r\n\x05\x04\x16\x02\x01\x01\x12\x04\xbe\
-    \x01\x12\x16\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\xbe\x01\x19\x1a\n\x0c\
-    \n\x04\x04\x16\x02\x02\x12\x04\xbf\x01\x02\x1d\n\r\n\x05\x04\x16\x02\x02\
-    \x04\x12\x04\xbf\x01\x02\n\n\r\n\x05\x04\x16\x02\x02\x05\x12\x04\xbf\x01\
-    \x0b\x11\n\r\n\x05\x04\x16\x02\x02\x01\x12\x04\xbf\x01\x12\x18\n\r\n\x05\
-    \x04\x16\x02\x02\x03\x12\x04\xbf\x01\x1b\x1c\n\x0c\n\x02\x04\x17\x12\x06\
-    \xc2\x01\0\xc6\x01\x01\n\x0b\n\x03\x04\x17\x01\x12\x04\xc2\x01\x08-\n\
-    \x0c\n\x04\x04\x17\x02\0\x12\x04\xc3\x01\x02\x1d\n\r\n\x05\x04\x17\x02\0\
-    \x04\x12\x04\xc3\x01\x02\n\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xc3\x01\
-    \x0b\x11\n\r\n\x05\x04\x17\x02\0\x01\x12\x04\xc3\x01\x12\x18\n\r\n\x05\
-    \x04\x17\x02\0\x03\x12\x04\xc3\x01\x1b\x1c\n\x0c\n\x04\x04\x17\x02\x01\
-    \x12\x04\xc4\x01\x02\x1b\n\r\n\x05\x04\x17\x02\x01\x04\x12\x04\xc4\x01\
-    \x02\n\n\r\n\x05\x04\x17\x02\x01\x05\x12\x04\xc4\x01\x0b\x11\n\r\n\x05\
-    \x04\x17\x02\x01\x01\x12\x04\xc4\x01\x12\x16\n\r\n\x05\x04\x17\x02\x01\
-    \x03\x12\x04\xc4\x01\x19\x1a\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\xc5\x01\
-    \x02\x1d\n\r\n\x05\x04\x17\x02\x02\x04\x12\x04\xc5\x01\x02\n\n\r\n\x05\
-    \x04\x17\x02\x02\x05\x12\x04\xc5\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x02\
-    \x01\x12\x04\xc5\x01\x12\x18\n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\xc5\
-    \x01\x1b\x1c\n\x0c\n\x02\x04\x18\x12\x06\xc8\x01\0\xcc\x01\x01\n\x0b\n\
-    \x03\x04\x18\x01\x12\x04\xc8\x01\x08*\n\x0c\n\x04\x04\x18\x02\0\x12\x04\
-    \xc9\x01\x02\x1d\n\r\n\x05\x04\x18\x02\0\x04\x12\x04\xc9\x01\x02\n\n\r\n\
-    \x05\x04\x18\x02\0\x05\x12\x04\xc9\x01\x0b\x11\n\r\n\x05\x04\x18\x02\0\
-    \x01\x12\x04\xc9\x01\x12\x18\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\xc9\x01\
-    \x1b\x1c\n\x0c\n\x04\x04\x18\x02\x01\x12\x04\xca\x01\x02\x1b\n\r\n\x05\
-    \x04\x18\x02\x01\x04\x12\x04\xca\x01\x02\n\n\r\n\x05\x04\x18\x02\x01\x05\
-    \x12\x04\xca\x01\x0b\x11\n\r\n\x05\x04\x18\x02\x01\x01\x12\x04\xca\x01\
-    \x12\x16\n\r\n\x05\x04\x18\x02\x01\x03\x12\x04\xca\x01\x19\x1a\n\x0c\n\
-    \x04\x04\x18\x02\x02\x12\x04\xcb\x01\x02\x1c\n\r\n\x05\x04\x18\x02\x02\
-    \x04\x12\x04\xcb\x01\x02\n\n\r\n\x05\x04\x18\x02\x02\x05\x12\x04\xcb\x01\
-    \x0b\x11\n\r\n\x05\x04\x18\x02\x02\x01\x12\x04\xcb\x01\x12\x17\n\r\n\x05\
-    \x04\x18\x02\x02\x03\x12\x04\xcb\x01\x1a\x1b\n\x0c\n\x02\x04\x19\x12\x06\
-    \xce\x01\0\xd0\x01\x01\n\x0b\n\x03\x04\x19\x01\x12\x04\xce\x01\x08\x1f\n\
-    \x0c\n\x04\x04\x19\x02\0\x12\x04\xcf\x01\x02\x1d\n\r\n\x05\x04\x19\x02\0\
-    \x04\x12\x04\xcf\x01\x02\n\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\xcf\x01\
-    \x0b\x11\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\xcf\x01\x12\x18\n\r\n\x05\
-    \x04\x19\x02\0\x03\x12\x04\xcf\x01\x1b\x1c\n\x0c\n\x02\x04\x1a\x12\x06\
-    \xd2\x01\0\xd6\x01\x01\n\x0b\n\x03\x04\x1a\x01\x12\x04\xd2\x01\x08\x1c\n\
-    \x0c\n\x04\x04\x1a\x02\0\x12\x04\xd3\x01\x02\x1c\n\r\n\x05\x04\x1a\x02\0\
-    \x04\x12\x04\xd3\x01\x02\n\n\r\n\x05\x04\x1a\x02\0\x05\x12\x04\xd3\x01\
-    \x0b\x11\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\xd3\x01\x12\x17\n\r\n\x05\
-    \x04\x1a\x02\0\x03\x12\x04\xd3\x01\x1a\x1b\n\x0c\n\x04\x04\x1a\x02\x01\
-    \x12\x04\xd4\x01\x02\x1d\n\r\n\x05\x04\x1a\x02\x01\x04\x12\x04\xd4\x01\
-    \x02\n\n\r\n\x05\x04\x1a\x02\x01\x05\x12\x04\xd4\x01\x0b\x11\n\r\n\x05\
-    \x04\x1a\x02\x01\x01\x12\x04\xd4\x01\x12\x18\n\r\n\x05\x04\x1a\x02\x01\
-    \x03\x12\x04\xd4\x01\x1b\x1c\n\x0c\n\x04\x04\x1a\x02\x02\x12\x04\xd5\x01\
-    \x02&\n\r\n\x05\x04\x1a\x02\x02\x04\x12\x04\xd5\x01\x02\n\n\r\n\x05\x04\
-    \x1a\x02\x02\x05\x12\x04\xd5\x01\x0b\x11\n\r\n\x05\x04\x1a\x02\x02\x01\
-    \x12\x04\xd5\x01\x12!\n\r\n\x05\x04\x1a\x02\x02\x03\x12\x04\xd5\x01$%\
+    \x02\x08\x02\x12\x03\x1e\x13\x14\n\n\n\x02\x05\x04\x12\x04!\0%\x01\n\n\n\
+    \x03\x05\x04\x01\x12\x03!\x05\x10\n\x0b\n\x04\x05\x04\x02\0\x12\x03\"\
+    \x02\r\n\x0c\n\x05\x05\x04\x02\0\x01\x12\x03\"\x02\x08\n\x0c\n\x05\x05\
+    \x04\x02\0\x02\x12\x03\"\x0b\x0c\n\x0b\n\x04\x05\x04\x02\x01\x12\x03#\
+    \x02\x0b\n\x0c\n\x05\x05\x04\x02\x01\x01\x12\x03#\x02\x06\n\x0c\n\x05\
+    \x05\x04\x02\x01\x02\x12\x03#\t\n\n\x0b\n\x04\x05\x04\x02\x02\x12\x03$\
+    \x02\x0f\n\x0c\n\x05\x05\x04\x02\x02\x01\x12\x03$\x02\n\n\x0c\n\x05\x05\
+    \x04\x02\x02\x02\x12\x03$\r\x0e\n\n\n\x02\x05\x05\x12\x04'\0*\x01\n\n\n\
+    \x03\x05\x05\x01\x12\x03'\x05\x0c\n\x0b\n\x04\x05\x05\x02\0\x12\x03(\x02\
+    \x0c\n\x0c\n\x05\x05\x05\x02\0\x01\x12\x03(\x02\x07\n\x0c\n\x05\x05\x05\
+    \x02\0\x02\x12\x03(\n\x0b\nS\n\x04\x05\x05\x02\x01\x12\x03)\x02\x13\"F\
+    \x20Build,\x20then\x20export\x20to\x20a\x20Docker\x20image\x20pushed\x20\
+    to\x20the\x20origin's\x20registry\n\n\x0c\n\x05\x05\x05\x02\x01\x01\x12\
+    \x03)\x02\x0e\n\x0c\n\x05\x05\x05\x02\x01\x02\x12\x03)\x11\x12\n\n\n\x02\
+    \x05\x06\x12\x04,\03\x01\n\n\n\x03\x05\x06\x01\x12\x03,\x05\x15\n\x0b\n\
+    \x04\x05\x06\x02\0\x12\x03-\x02\x14\n\x0c\n\x05\x05\x06\x02\0\x01\x12\
+    \x03-\x02\x0f\n\x0c\n\x05\x05\x06\x02\0\x02\x12\x03-\x12\x13\n\x0b\n\x04\
+    \x05\x06\x02\x01\x12\x03.\x02\x10\n\x0c\n\x05\x05\x06\x02\x01\x01\x12\
+    \x03.\x02\x0b\n\x0c\n\x05\x05\x06\x02\x01\x02\x12\x03.\x0e\x0f\n\x0b\n\
+    \x04\x05\x06\x02\x02\x12\x03/\x02\x10\n\x0c\n\x05\x05\x06\x02\x02\x01\
+    \x12\x03/\x02\x0b\n\x0c\n\x05\x05\x06\x02\x02\x02\x12\x03/\x0e\x0f\n\x0b\
+    \n\x04\x05\x06\x02\x03\x12\x030\x02\x0b\n\x0c\n\x05\x05\x06\x02\x03\x01\
+    \x12\x030\x02\x06\n\x0c\n\x05\x05\x06\x02\x03\x02\x12\x030\t\n\n\x0b\n\
+    \x04\x05\x06\x02\x04\x12\x031\x02\x15\n\x0c\n\x05\x05\x06\x02\x04\x01\
+    \x12\x031\x02\x10\n\x0c\n\x05\x05\x06\x02\x04\x02\x12\x031\x13\x14\n\x0b\
+    \n\x04\x05\x06\x02\x05\x12\x032\x02\x14\n\x0c\n\x05\x05\x06\x02\x05\x01\
+    \x12\x032\x02\x0f\n\x0c\n\x05\x05\x06\x02\x05\x02\x12\x032\x12\x13\n\n\n\
+    \x02\x04\0\x12\x045\08\x01\n\n\n\x03\x04\0\x01\x12\x035\x08\x15\n\x0b\n\
+    \x04\x04\0\x02\0\x12\x036\x02\"\n\x0c\n\x05\x04\0\x02\0\x04\x12\x036\x02\
+    \n\n\x0c\n\x05\x04\0\x02\0\x06\x12\x036\x0b\x1a\n\x0c\n\x05\x04\0\x02\0\
+    \x01\x12\x036\x1b\x1d\n\x0c\n\x05\x04\0\x02\0\x03\x12\x036\x20!\n\x0b\n\
+    \x04\x04\0\x02\x01\x12\x037\x02'\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x037\
+    \x02\n\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x037\x0b\x11\n\x0c\n\x05\x04\0\
+    \x02\x01\x01\x12\x037\x12\"\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x037%&\n\n\
+    \n\x02\x04\x01\x12\x04:\0J\x01\n\n\n\x03\x04\x01\x01\x12\x03:\x08\x11\n\
+    \x0b\n\x04\x04\x01\x02\0\x12\x03;\x02\x1f\n\x0c\n\x05\x04\x01\x02\0\x04\
+    \x12\x03;\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03;\x0b\x11\n\x0c\n\
+    \x05\x04\x01\x02\0\x01\x12\x03;\x12\x1a\n\x0c\n\x05\x04\x01\x02\0\x03\
+    \x12\x03;\x1d\x1e\n\x0b\n\x04\x04\x01\x02\x01\x12\x03<\x02\x15\n\x0c\n\
+    \x05\x04\x01\x02\x01\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\x01\x02\x01\x06\
+    \x12\x03<\x0b\r\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03<\x0e\x10\n\x0c\n\
+    \x05\x04\x01\x02\x01\x03\x12\x03<\x13\x14\n\x0b\n\x04\x04\x01\x02\x02\
+    \x12\x03=\x02!\n\x0c\n\x05\x04\x01\x02\x02\x04\x12\x03=\x02\n\n\x0c\n\
+    \x05\x04\x01\x02\x02\x06\x12\x03=\x0b\x16\n\x0c\n\x05\x04\x01\x02\x02\
+    \x01\x12\x03=\x17\x1c\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03=\x1f\x20\n\
+    \x0b\n\x04\x04\x01\x02\x03\x12\x03>\x02\x1d\n\x0c\n\x05\x04\x01\x02\x03\
+    \x04\x12\x03>\x02\n\n\x0c\n\x05\x04\x01\x02\x03\x05\x12\x03>\x0b\x11\n\
+    \x0c\n\x05\x04\x01\x02\x03\x01\x12\x03>\x12\x18\n\x0c\n\x05\x04\x01\x02\
+    \x03\x03\x12\x03>\x1b\x1c\n3\n\x04\x04\x01\x02\x04\x12\x03?\x02\x20\"&\
+    \x20Bytes\x20free\x20in\x20the\x20worker's\x20data\x20path\n\n\x0c\n\x05\
+    \x04\x01\x02\x04\x04\x12\x03?\x02\n\n\x0c\n\x05\x04\x01\x02\x04\x05\x12\
+    \x03?\x0b\x11\n\x0c\n\x05\x04\x01\x02\x04\x01\x12\x03?\x12\x1b\n\x0c\n\
+    \x05\x04\x01\x02\x04\x03\x12\x03?\x1e\x1f\n\x0b\n\x04\x04\x01\x02\x05\
+    \x12\x03@\x02%\n\x0c\n\x05\x04\x01\x02\x05\x04\x12\x03@\x02\n\n\x0c\n\
+    \x05\x04\x01\x02\x05\x05\x12\x03@\x0b\x11\n\x0c\n\x05\x04\x01\x02\x05\
+    \x01\x12\x03@\x12\x20\n\x0c\n\x05\x04\x01\x02\x05\x03\x12\x03@#$\n8\n\
+    \x04\x04\x01\x02\x06\x12\x03A\x02\x1e\"+\x20All\x20platform\x20targets\
+    \x20the\x20worker\x20can\x20build\n\n\x0c\n\x05\x04\x01\x02\x06\x04\x12\
+    \x03A\x02\n\n\x0c\n\x05\x04\x01\x02\x06\x05\x12\x03A\x0b\x11\n\x0c\n\x05\
+    \x04\x01\x02\x06\x01\x12\x03A\x12\x19\n\x0c\n\x05\x04\x01\x02\x06\x03\
+    \x12\x03A\x1c\x1d\n\x0b\n\x04\x04\x01\x02\x07\x12\x03B\x02'\n\x0c\n\x05\
+    \x04\x01\x02\x07\x04\x12\x03B\x02\n\n\x0c\n\x05\x04\x01\x02\x07\x05\x12\
+    \x03B\x0b\x11\n\x0c\n\x05\x04\x01\x02\x07\x01\x12\x03B\x12\"\n\x0c\n\x05\
+    \x04\x01\x02\x07\x03\x12\x03B%&\n@\n\x04\x04\x01\x02\x08\x12\x03C\x02\
+    \x1d\"3\x20Capabilities\x20of\x20the\x20worker\x20which\x20jobs\x20may\
+    \x20require\n\n\x0c\n\x05\x04\x01\x02\x08\x04\x12\x03C\x02\n\n\x0c\n\x05\
+    \x04\x01\x02\x08\x05\x12\x03C\x0b\x11\n\x0c\n\x05\x04\x01\x02\x08\x01\
+    \x12\x03C\x12\x18\n\x0c\n\x05\x04\x01\x02\x08\x03\x12\x03C\x1b\x1c\n0\n\
+    \x04\x04\x01\x02\t\x12\x03D\x02\x20\"#\x20Builds\x20the\x20worker\x20can\
+    \x20run\x20at\x20once\n\n\x0c\n\x05\x04\x01\x02\t\x04\x12\x03D\x02\n\n\
+    \x0c\n\x05\x04\x01\x02\t\x05\x12\x03D\x0b\x11\n\x0c\n\x05\x04\x01\x02\t\
+    \x01\x12\x03D\x12\x1a\n\x0c\n\x05\x04\x01\x02\t\x03\x12\x03D\x1d\x1f\n)\
+    \n\x04\x04\x01\x02\n\x12\x03E\x02\x1f\"\x1c\x20Jobs\x20the\x20worker\x20\
+    is\x20running\n\n\x0c\n\x05\x04\x01\x02\n\x04\x12\x03E\x02\n\n\x0c\n\x05\
+    \x04\x01\x02\n\x05\x12\x03E\x0b\x11\n\x0c\n\x05\x04\x01\x02\n\x01\x12\
+    \x03E\x12\x19\n\x0c\n\x05\x04\x01\x02\n\x03\x12\x03E\x1c\x1e\nN\n\x04\
+    \x04\x01\x02\x0b\x12\x03F\x02\x1e\"A\x20The\x20worker\x20takes\x20no\x20\
+    more\x20jobs\x20and\x20leaves\x20once\x20its\x20jobs\x20are\x20done\n\n\
+    \x0c\n\x05\x04\x01\x02\x0b\x04\x12\x03F\x02\n\n\x0c\n\x05\x04\x01\x02\
+    \x0b\x05\x12\x03F\x0b\x0f\n\x0c\n\x05\x04\x01\x02\x0b\x01\x12\x03F\x10\
+    \x18\n\x0c\n\x05\x04\x01\x02\x0b\x03\x12\x03F\x1b\x1d\nA\n\x04\x04\x01\
+    \x02\x0c\x12\x03G\x02\x1d\"4\x20The\x20worker\x20is\x20shutting\x20down\
+    \x20and\x20is\x20to\x20be\x20forgotten\n\n\x0c\n\x05\x04\x01\x02\x0c\x04\
+    \x12\x03G\x02\n\n\x0c\n\x05\x04\x01\x02\x0c\x05\x12\x03G\x0b\x0f\n\x0c\n\
+    \x05\x04\x01\x02\x0c\x01\x12\x03G\x10\x17\n\x0c\n\x05\x04\x01\x02\x0c\
+    \x03\x12\x03G\x1a\x1c\n@\n\x04\x04\x01\x02\r\x12\x03H\x02+\"3\x20Depende\
+    ncies\x20found\x20in\x20the\x20worker's\x20artifact\x20cache\n\n\x0c\n\
+    \x05\x04\x01\x02\r\x04\x12\x03H\x02\n\n\x0c\n\x05\x04\x01\x02\r\x05\x12\
+    \x03H\x0b\x11\n\x0c\n\x05\x04\x01\x02\r\x01\x12\x03H\x12%\n\x0c\n\x05\
+    \x04\x01\x02\r\x03\x12\x03H(*\n6\n\x04\x04\x01\x02\x0e\x12\x03I\x02-\")\
+    \x20Dependencies\x20the\x20worker\x20had\x20to\x20download\n\n\x0c\n\x05\
+    \x04\x01\x02\x0e\x04\x12\x03I\x02\n\n\x0c\n\x05\x04\x01\x02\x0e\x05\x12\
+    \x03I\x0b\x11\n\x0c\n\x05\x04\x01\x02\x0e\x01\x12\x03I\x12'\n\x0c\n\x05\
+    \x04\x01\x02\x0e\x03\x12\x03I*,\n\n\n\x02\x04\x02\x12\x04L\0P\x01\n\n\n\
+    \x03\x04\x02\x01\x12\x03L\x08\x12\n\x0b\n\x04\x04\x02\x02\0\x12\x03M\x02\
+    \x1c\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03M\x02\n\n\x0c\n\x05\x04\x02\
+    \x02\0\x05\x12\x03M\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03M\x12\
+    \x17\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03M\x1a\x1b\n\x0b\n\x04\x04\x02\
+    \x02\x01\x12\x03N\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\x04\x12\x03N\x02\n\
+    \n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03N\x0b\x11\n\x0c\n\x05\x04\x02\
+    \x02\x01\x01\x12\x03N\x12\x18\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03N\
+    \x1b\x1c\n\x0b\n\x04\x04\x02\x02\x02\x12\x03O\x02\x20\n\x0c\n\x05\x04\
+    \x02\x02\x02\x04\x12\x03O\x02\n\n\x0c\n\x05\x04\x02\x02\x02\x05\x12\x03O\
+    \x0b\x0f\n\x0c\n\x05\x04\x02\x02\x02\x01\x12\x03O\x10\x1b\n\x0c\n\x05\
+    \x04\x02\x02\x02\x03\x12\x03O\x1e\x1f\nU\n\x02\x04\x03\x12\x04S\0_\x01\
+    \x1aI\x20A\x20worker\x20connected\x20to\x20the\x20JobServer,\x20as\x20la\
+    st\x20seen\x20by\x20the\x20worker\x20manager\n\n\n\n\x03\x04\x03\x01\x12\
+    \x03S\x08\x14\n\x0b\n\x04\x04\x03\x02\0\x12\x03T\x02\x1c\n\x0c\n\x05\x04\
+    \x03\x02\0\x04\x12\x03T\x02\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03T\x0b\
+    \x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03T\x12\x17\n\x0c\n\x05\x04\x03\
+    \x02\0\x03\x12\x03T\x1a\x1b\n\x0b\n\x04\x04\x03\x02\x01\x12\x03U\x02\x1e\
+    \n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03U\x02\n\n\x0c\n\x05\x04\x03\x02\
+    \x01\x05\x12\x03U\x0b\x11\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\x03U\x12\
+    \x19\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03U\x1c\x1d\n\x0b\n\x04\x04\
+    \x03\x02\x02\x12\x03V\x02!\n\x0c\n\x05\x04\x03\x02\x02\x04\x12\x03V\x02\
+    \n\n\x0c\n\x05\x04\x03\x02\x02\x06\x12\x03V\x0b\x16\n\x0c\n\x05\x04\x03\
+    \x02\x02\x01\x12\x03V\x17\x1c\n\x0c\n\x05\x04\x03\x02\x02\x03\x12\x03V\
+    \x1f\x20\nF\n\x04\x04\x03\x02\x03\x12\x03W\x02\x1d\"9\x20First\x20of\x20\
+    job_ids,\x20set\x20while\x20the\x20worker\x20is\x20running\x20a\x20job\n\
+    \n\x0c\n\x05\x04\x03\x02\x03\x04\x12\x03W\x02\n\n\x0c\n\x05\x04\x03\x02\
+    \x03\x05\x12\x03W\x0b\x11\n\x0c\n\x05\x04\x03\x02\x03\x01\x12\x03W\x12\
+    \x18\n\x0c\n\x05\x04\x03\x02\x03\x03\x12\x03W\x1b\x1c\n%\n\x04\x04\x03\
+    \x02\x04\x12\x03X\x02%\"\x18\x20RFC3339-formatted\x20time\n\n\x0c\n\x05\
+    \x04\x03\x02\x04\x04\x12\x03X\x02\n\n\x0c\n\x05\x04\x03\x02\x04\x05\x12\
+    \x03X\x0b\x11\n\x0c\n\x05\x04\x03\x02\x04\x01\x12\x03X\x12\x20\n\x0c\n\
+    \x05\x04\x03\x02\x04\x03\x12\x03X#$\n\x0b\n\x04\x04\x03\x02\x05\x12\x03Y\
+    \x02\x1d\n\x0c\n\x05\x04\x03\x02\x05\x04\x12\x03Y\x02\n\n\x0c\n\x05\x04\
+    \x03\x02\x05\x05\x12\x03Y\x0b\x11\n\x0c\n\x05\x04\x03\x02\x05\x01\x12\
+    \x03Y\x12\x18\n\x0c\n\x05\x04\x03\x02\x05\x03\x12\x03Y\x1b\x1c\n0\n\x04\
+    \x04\x03\x02\x06\x12\x03Z\x02\x1f\"#\x20Builds\x20the\x20worker\x20can\
+    \x20run\x20at\x20once\n\n\x0c\n\x05\x04\x03\x02\x06\x04\x12\x03Z\x02\n\n\
+    \x0c\n\x05\x04\x03\x02\x06\x05\x12\x03Z\x0b\x11\n\x0c\n\x05\x04\x03\x02\
+    \x06\x01\x12\x03Z\x12\x1a\n\x0c\n\x05\x04\x03\x02\x06\x03\x12\x03Z\x1d\
+    \x1e\nE\n\x04\x04\x03\x02\x07\x12\x03[\x02\x1e\"8\x20Jobs\x20dispatched\
+    \x20to\x20the\x20worker\x20which\x20it\x20hasn't\x20finished\n\n\x0c\n\
+    \x05\x04\x03\x02\x07\x04\x12\x03[\x02\n\n\x0c\n\x05\x04\x03\x02\x07\x05\
+    \x12\x03[\x0b\x11\n\x0c\n\x05\x04\x03\x02\x07\x01\x12\x03[\x12\x19\n\x0c\
+    \n\x05\x04\x03\x02\x07\x03\x12\x03[\x1c\x1d\n,\n\x04\x04\x03\x02\x08\x12\
+    \x03\\\x02\x1d\"\x1f\x20The\x20worker\x20takes\x20no\x20more\x20jobs\n\n\
+    \x0c\n\x05\x04\x03\x02\x08\x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\x03\x02\
+    \x08\x05\x12\x03\\\x0b\x0f\n\x0c\n\x05\x04\x03\x02\x08\x01\x12\x03\\\x10\
+    \x18\n\x0c\n\x05\x04\x03\x02\x08\x03\x12\x03\\\x1b\x1c\n\x0b\n\x04\x04\
+    \x03\x02\t\x12\x03]\x02+\n\x0c\n\x05\x04\x03\x02\t\x04\x12\x03]\x02\n\n\
+    \x0c\n\x05\x04\x03\x02\t\x05\x12\x03]\x0b\x11\n\x0c\n\x05\x04\x03\x02\t\
+    \x01\x12\x03]\x12%\n\x0c\n\x05\x04\x03\x02\t\x03\x12\x03](*\n\x0b\n\x04\
+    \x04\x03\x02\n\x12\x03^\x02-\n\x0c\n\x05\x04\x03\x02\n\x04\x12\x03^\x02\
+    \n\n\x0c\n\x05\x04\x03\x02\n\x05\x12\x03^\x0b\x11\n\x0c\n\x05\x04\x03\
+    \x02\n\x01\x12\x03^\x12'\n\x0c\n\x05\x04\x03\x02\n\x03\x12\x03^*,\n\t\n\
+    \x02\x04\x04\x12\x03a\0\x18\n\n\n\x03\x04\x04\x01\x12\x03a\x08\x15\n\n\n\
+    \x02\x04\x05\x12\x04c\0e\x01\n\n\n\x03\x04\x05\x01\x12\x03c\x08\x1a\n\
+    \x0b\n\x04\x04\x05\x02\0\x12\x03d\x02$\n\x0c\n\x05\x04\x05\x02\0\x04\x12\
+    \x03d\x02\n\n\x0c\n\x05\x04\x05\x02\0\x06\x12\x03d\x0b\x17\n\x0c\n\x05\
+    \x04\x05\x02\0\x01\x12\x03d\x18\x1f\n\x0c\n\x05\x04\x05\x02\0\x03\x12\
+    \x03d\"#\n\x0b\n\x02\x04\x06\x12\x05g\0\x89\x01\x01\n\n\n\x03\x04\x06\
+    \x01\x12\x03g\x08\x0b\n\n\n\x03\x04\x06\t\x12\x03h\x0b\x0e\n\x0b\n\x04\
+    \x04\x06\t\0\x12\x03h\x0b\r\n\x0c\n\x05\x04\x06\t\0\x01\x12\x03h\x0b\r\n\
+    \x0c\n\x05\x04\x06\t\0\x02\x12\x03h\x0b\r\n\n\n\x03\x04\x06\n\x12\x03i\
+    \x0b\x15\n\x0b\n\x04\x04\x06\n\0\x12\x03i\x0b\x14\n\x0b\n\x04\x04\x06\
+    \x02\0\x12\x03j\x02\x19\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x03j\x02\n\n\
+    \x0c\n\x05\x04\x06\x02\0\x05\x12\x03j\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\
+    \x01\x12\x03j\x12\x14\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x03j\x17\x18\n\
+    \x0b\n\x04\x04\x06\x02\x01\x12\x03k\x02\x1f\n\x0c\n\x05\x04\x06\x02\x01\
+    \x04\x12\x03k\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03k\x0b\x11\n\
+    \x0c\n\x05\x04\x06\x02\x01\x01\x12\x03k\x12\x1a\n\x0c\n\x05\x04\x06\x02\
+    \x01\x03\x12\x03k\x1d\x1e\n\x0b\n\x04\x04\x06\x02\x02\x12\x03l\x02\x1e\n\
+    \x0c\n\x05\x04\x06\x02\x02\x04\x12\x03l\x02\n\n\x0c\n\x05\x04\x06\x02\
+    \x02\x06\x12\x03l\x0b\x13\n\x0c\n\x05\x04\x06\x02\x02\x01\x12\x03l\x14\
+    \x19\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03l\x1c\x1d\n\x0b\n\x04\x04\
+    \x06\x02\x03\x12\x03m\x02/\n\x0c\n\x05\x04\x06\x02\x03\x04\x12\x03m\x02\
+    \n\n\x0c\n\x05\x04\x06\x02\x03\x06\x12\x03m\x0b\"\n\x0c\n\x05\x04\x06\
+    \x02\x03\x01\x12\x03m#*\n\x0c\n\x05\x04\x06\x02\x03\x03\x12\x03m-.\n\x0b\
+    \n\x04\x04\x06\x02\x04\x12\x03n\x02\x1e\n\x0c\n\x05\x04\x06\x02\x04\x04\
+    \x12\x03n\x02\n\n\x0c\n\x05\x04\x06\x02\x04\x06\x12\x03n\x0b\x13\n\x0c\n\
+    \x05\x04\x06\x02\x04\x01\x12\x03n\x14\x19\n\x0c\n\x05\x04\x06\x02\x04\
+    \x03\x12\x03n\x1c\x1d\n%\n\x04\x04\x06\x02\x05\x12\x03o\x02!\"\x18\x20RF\
+    C3339-formatted\x20time\n\n\x0c\n\x05\x04\x06\x02\x05\x04\x12\x03o\x02\n\
+    \n\x0c\n\x05\x04\x06\x02\x05\x05\x12\x03o\x0b\x11\n\x0c\n\x05\x04\x06\
+    \x02\x05\x01\x12\x03o\x12\x1c\n\x0c\n\x05\x04\x06\x02\x05\x03\x12\x03o\
+    \x1f\x20\n%\n\x04\x04\x06\x02\x06\x12\x03p\x02'\"\x18\x20RFC3339-formatt\
+    ed\x20time\n\n\x0c\n\x05\x04\x06\x02\x06\x04\x12\x03p\x02\n\n\x0c\n\x05\
+    \x04\x06\x02\x06\x05\x12\x03p\x0b\x11\n\x0c\n\x05\x04\x06\x02\x06\x01\
+    \x12\x03p\x12\"\n\x0c\n\x05\x04\x06\x02\x06\x03\x12\x03p%&\n\x0b\n\x04\
+    \x04\x06\x02\x07\x12\x03q\x02(\n\x0c\n\x05\x04\x06\x02\x07\x04\x12\x03q\
+    \x02\n\n\x0c\n\x05\x04\x06\x02\x07\x05\x12\x03q\x0b\x11\n\x0c\n\x05\x04\
+    \x06\x02\x07\x01\x12\x03q\x12#\n\x0c\n\x05\x04\x06\x02\x07\x03\x12\x03q&\
+    '\n\x0b\n\x04\x04\x06\x02\x08\x12\x03r\x02:\n\x0c\n\x05\x04\x06\x02\x08\
+    \x04\x12\x03r\x02\n\n\x0c\n\x05\x04\x06\x02\x08\x06\x12\x03r\x0b'\n\x0c\
+    \n\x05\x04\x06\x02\x08\x01\x12\x03r(5\n\x0c\n\x05\x04\x06\x02\x08\x03\
+    \x12\x03r89\n\x0b\n\x04\x04\x06\x02\t\x12\x03s\x02!\n\x0c\n\x05\x04\x06\
+    \x02\t\x04\x12\x03s\x02\n\n\x0c\n\x05\x04\x06\x02\t\x05\x12\x03s\x0b\x0f\
+    \n\x0c\n\x05\x04\x06\x02\t\x01\x12\x03s\x10\x1b\n\x0c\n\x05\x04\x06\x02\
+    \t\x03\x12\x03s\x1e\x20\n\x0b\n\x04\x04\x06\x02\n\x12\x03t\x029\n\x0c\n\
+    \x05\x04\x06\x02\n\x04\x12\x03t\x02\n\n\x0c\n\x05\x04\x06\x02\n\x06\x12\
+    \x03t\x0b&\n\x0c\n\x05\x04\x06\x02\n\x01\x12\x03t'3\n\x0c\n\x05\x04\x06\
+    \x02\n\x03\x12\x03t68\n\x0b\n\x04\x04\x06\x02\x0b\x12\x03u\x02\x1f\n\x0c\
+    \n\x05\x04\x06\x02\x0b\x04\x12\x03u\x02\n\n\x0c\n\x05\x04\x06\x02\x0b\
+    \x05\x12\x03u\x0b\x11\n\x0c\n\x05\x04\x06\x02\x0b\x01\x12\x03u\x12\x19\n\
+    \x0c\n\x05\x04\x06\x02\x0b\x03\x12\x03u\x1c\x1e\n\x0b\n\x04\x04\x06\x02\
+    \x0c\x12\x03v\x02H\n\x0c\n\x05\x04\x06\x02\x0c\x04\x12\x03v\x02\n\n\x0c\
+    \n\x05\x04\x06\x02\x0c\x06\x12\x03v\x0b-\n\x0c\n\x05\x04\x06\x02\x0c\x01\
+    \x12\x03v.B\n\x0c\n\x05\x04\x06\x02\x0c\x03\x12\x03vEG\n\x0b\n\x04\x04\
+    \x06\x02\r\x12\x03w\x02\x1e\n\x0c\n\x05\x04\x06\x02\r\x04\x12\x03w\x02\n\
+    \n\x0c\n\x05\x04\x06\x02\r\x05\x12\x03w\x0b\x11\n\x0c\n\x05\x04\x06\x02\
+    \r\x01\x12\x03w\x12\x18\n\x0c\n\x05\x04\x06\x02\r\x03\x12\x03w\x1b\x1d\n\
+    \x0b\n\x04\x04\x06\x02\x0e\x12\x03x\x02%\n\x0c\n\x05\x04\x06\x02\x0e\x04\
+    \x12\x03x\x02\n\n\x0c\n\x05\x04\x06\x02\x0e\x06\x12\x03x\x0b\x16\n\x0c\n\
+    \x05\x04\x06\x02\x0e\x01\x12\x03x\x17\x1f\n\x0c\n\x05\x04\x06\x02\x0e\
+    \x03\x12\x03x\"$\n\x0b\n\x04\x04\x06\x02\x0f\x12\x03y\x02\x1e\n\x0c\n\
+    \x05\x04\x06\x02\x0f\x04\x12\x03y\x02\n\n\x0c\n\x05\x04\x06\x02\x0f\x05\
+    \x12\x03y\x0b\x11\n\x0c\n\x05\x04\x06\x02\x0f\x01\x12\x03y\x12\x18\n\x0c\
+    \n\x05\x04\x06\x02\x0f\x03\x12\x03y\x1b\x1d\n\x0b\n\x04\x04\x06\x02\x10\
+    \x12\x03z\x02\x1f\n\x0c\n\x05\x04\x06\x02\x10\x04\x12\x03z\x02\n\n\x0c\n\
+    \x05\x04\x06\x02\x10\x05\x12\x03z\x0b\x11\n\x0c\n\x05\x04\x06\x02\x10\
+    \x01\x12\x03z\x12\x19\n\x0c\n\x05\x04\x06\x02\x10\x03\x12\x03z\x1c\x1e\n\
+    \x0b\n\x04\x04\x06\x02\x11\x12\x03{\x02#\n\x0c\n\x05\x04\x06\x02\x11\x04\
+    \x12\x03{\x02\n\n\x0c\n\x05\x04\x06\x02\x11\x05\x12\x03{\x0b\x11\n\x0c\n\
+    \x05\x04\x06\x02\x11\x01\x12\x03{\x12\x1d\n\x0c\n\x05\x04\x06\x02\x11\
+    \x03\x12\x03{\x20\"\n\x0b\n\x04\x04\x06\x02\x12\x12\x03|\x025\n\x0c\n\
+    \x05\x04\x06\x02\x12\x04\x12\x03|\x02\n\n\x0c\n\x05\x04\x06\x02\x12\x06\
+    \x12\x03|\x0b\x1b\n\x0c\n\x05\x04\x06\x02\x12\x01\x12\x03|\x1c/\n\x0c\n\
+    \x05\x04\x06\x02\x12\x03\x12\x03|24\n\x0b\n\x04\x04\x06\x02\x13\x12\x03}\
+    \x02\x1c\n\x0c\n\x05\x04\x06\x02\x13\x04\x12\x03}\x02\n\n\x0c\n\x05\x04\
+    \x06\x02\x13\x05\x12\x03}\x0b\x11\n\x0c\n\x05\x04\x06\x02\x13\x01\x12\
+    \x03}\x12\x16\n\x0c\n\x05\x04\x06\x02\x13\x03\x12\x03}\x19\x1b\n\x0b\n\
+    \x04\x04\x06\x02\x14\x12\x03~\x02%\n\x0c\n\x05\x04\x06\x02\x14\x04\x12\
+    \x03~\x02\n\n\x0c\n\x05\x04\x06\x02\x14\x06\x12\x03~\x0b\x16\n\x0c\n\x05\
+    \x04\x06\x02\x14\x01\x12\x03~\x17\x1f\n\x0c\n\x05\x04\x06\x02\x14\x03\
+    \x12\x03~\"$\n%\n\x04\x04\x06\x02\x15\x12\x03\x7f\x02\"\"\x18\x20RFC3339\
+    -formatted\x20time\n\n\x0c\n\x05\x04\x06\x02\x15\x04\x12\x03\x7f\x02\n\n\
+    \x0c\n\x05\x04\x06\x02\x15\x05\x12\x03\x7f\x0b\x11\n\x0c\n\x05\x04\x06\
+    \x02\x15\x01\x12\x03\x7f\x12\x1c\n\x0c\n\x05\x04\x06\x02\x15\x03\x12\x03\
+    \x7f\x1f!\n\x17\n\x04\x04\x06\x02\x16\x12\x04\x80\x01\x02%\"\t\x20minute\
+    s\n\n\r\n\x05\x04\x06\x02\x16\x04\x12\x04\x80\x01\x02\n\n\r\n\x05\x04\
+    \x06\x02\x16\x05\x12\x04\x80\x01\x0b\x11\n\r\n\x05\x04\x06\x02\x16\x01\
+    \x12\x04\x80\x01\x12\x1f\n\r\n\x05\x04\x06\x02\x16\x03\x12\x04\x80\x01\"\
+    $\nB\n\x04\x04\x06\x02\x17\x12\x04\x81\x01\x02%\"4\x20Labels\x20a\x20wor\
+    ker\x20must\x20have\x20to\x20be\x20dispatched\x20the\x20job\n\n\r\n\x05\
+    \x04\x06\x02\x17\x04\x12\x04\x81\x01\x02\n\n\r\n\x05\x04\x06\x02\x17\x05\
+    \x12\x04\x81\x01\x0b\x11\n\r\n\x05\x04\x06\x02\x17\x01\x12\x04\x81\x01\
+    \x12\x1f\n\r\n\x05\x04\x06\x02\x17\x03\x12\x04\x81\x01\"$\n\x0c\n\x04\
+    \x04\x06\x02\x18\x12\x04\x82\x01\x02\x1d\n\r\n\x05\x04\x06\x02\x18\x04\
+    \x12\x04\x82\x01\x02\n\n\r\n\x05\x04\x06\x02\x18\x06\x12\x04\x82\x01\x0b\
+    \x12\n\r\n\x05\x04\x06\x02\x18\x01\x12\x04\x82\x01\x13\x17\n\r\n\x05\x04\
+    \x06\x02\x18\x03\x12\x04\x82\x01\x1a\x1c\nG\n\x04\x04\x06\x02\x19\x12\
+    \x04\x83\x01\x02$\"9\x20Digest\x20of\x20the\x20Docker\x20image\x20pushed\
+    \x20by\x20a\x20DockerExport\x20job\n\n\r\n\x05\x04\x06\x02\x19\x04\x12\
+    \x04\x83\x01\x02\n\n\r\n\x05\x04\x06\x02\x19\x05\x12\x04\x83\x01\x0b\x11\
+    \n\r\n\x05\x04\x06\x02\x19\x01\x12\x04\x83\x01\x12\x1e\n\r\n\x05\x04\x06\
+    \x02\x19\x03\x12\x04\x83\x01!#\n5\n\x04\x04\x06\x02\x1a\x12\x04\x84\x01\
+    \x02/\"'\x20The\x20origin's\x20secrets,\x20set\x20on\x20dispatch\n\n\r\n\
+    \x05\x04\x06\x02\x1a\x04\x12\x04\x84\x01\x02\n\n\r\n\x05\x04\x06\x02\x1a\
+    \x06\x12\x04\x84\x01\x0b!\n\r\n\x05\x04\x06\x02\x1a\x01\x12\x04\x84\x01\
+    \")\n\r\n\x05\x04\x06\x02\x1a\x03\x12\x04\x84\x01,.\n8\n\x04\x04\x06\x02\
+    \x1b\x12\x04\x85\x01\x02'\"*\x20Memory\x20the\x20build\x20may\x20use,\
+    \x200\x20for\x20no\x20limit\n\n\r\n\x05\x04\x06\x02\x1b\x04\x12\x04\x85\
+    \x01\x02\n\n\r\n\x05\x04\x06\x02\x1b\x05\x12\x04\x85\x01\x0b\x11\n\r\n\
+    \x05\x04\x06\x02\x1b\x01\x12\x04\x85\x01\x12!\n\r\n\x05\x04\x06\x02\x1b\
+    \x03\x12\x04\x85\x01$&\n:\n\x04\x04\x06\x02\x1c\x12\x04\x86\x01\x02,\",\
+    \x20CPU\x20time\x20the\x20build\x20may\x20use,\x200\x20for\x20no\x20limi\
+    t\n\n\r\n\x05\x04\x06\x02\x1c\x04\x12\x04\x86\x01\x02\n\n\r\n\x05\x04\
+    \x06\x02\x1c\x05\x12\x04\x86\x01\x0b\x11\n\r\n\x05\x04\x06\x02\x1c\x01\
+    \x12\x04\x86\x01\x12&\n\r\n\x05\x04\x06\x02\x1c\x03\x12\x04\x86\x01)+\n3\
+    \n\x04\x04\x06\x02\x1d\x12\x04\x87\x01\x02)\"%\x20Build\x20twice\x20and\
+    \x20compare\x20the\x20outputs\n\n\r\n\x05\x04\x06\x02\x1d\x04\x12\x04\
+    \x87\x01\x02\n\n\r\n\x05\x04\x06\x02\x1d\x05\x12\x04\x87\x01\x0b\x0f\n\r\
+    \n\x05\x04\x06\x02\x1d\x01\x12\x04\x87\x01\x10#\n\r\n\x05\x04\x06\x02\
+    \x1d\x03\x12\x04\x87\x01&(\nJ\n\x04\x04\x06\x02\x1e\x12\x04\x88\x01\x02\
+    \"\"<\x20Whether\x20both\x20builds\x20of\x20a\x20verified\x20job\x20had\
+    \x20the\x20same\x20outputs\n\n\r\n\x05\x04\x06\x02\x1e\x04\x12\x04\x88\
+    \x01\x02\n\n\r\n\x05\x04\x06\x02\x1e\x05\x12\x04\x88\x01\x0b\x0f\n\r\n\
+    \x05\x04\x06\x02\x1e\x01\x12\x04\x88\x01\x10\x1c\n\r\n\x05\x04\x06\x02\
+    \x1e\x03\x12\x04\x88\x01\x1f!\nB\n\x02\x04\x07\x12\x06\x8c\x01\0\x8f\x01\
+    \x01\x1a4\x20The\x20package\x20archive\x20produced\x20by\x20a\x20success\
+    ful\x20build\n\n\x0b\n\x03\x04\x07\x01\x12\x04\x8c\x01\x08\x13\n/\n\x04\
+    \x04\x07\x02\0\x12\x04\x8d\x01\x02\x1f\"!\x20blake2b\x20hex\x20digest\
+    \x20of\x20the\x20.hart\n\n\r\n\x05\x04\x07\x02\0\x04\x12\x04\x8d\x01\x02\
+    \n\n\r\n\x05\x04\x07\x02\0\x05\x12\x04\x8d\x01\x0b\x11\n\r\n\x05\x04\x07\
+    \x02\0\x01\x12\x04\x8d\x01\x12\x1a\n\r\n\x05\x04\x07\x02\0\x03\x12\x04\
+    \x8d\x01\x1d\x1e\n\x18\n\x04\x04\x07\x02\x01\x12\x04\x8e\x01\x02\x1b\"\n\
+    \x20in\x20bytes\n\n\r\n\x05\x04\x07\x02\x01\x04\x12\x04\x8e\x01\x02\n\n\
+    \r\n\x05\x04\x07\x02\x01\x05\x12\x04\x8e\x01\x0b\x11\n\r\n\x05\x04\x07\
+    \x02\x01\x01\x12\x04\x8e\x01\x12\x16\n\r\n\x05\x04\x07\x02\x01\x03\x12\
+    \x04\x8e\x01\x19\x1a\na\n\x02\x04\x08\x12\x06\x92\x01\0\x96\x01\x01\x1aS\
+    \x20Wire\x20compatible\x20with\x20`net.NetError`,\x20which\x20older\x20w\
+    orkers\x20report\x20job\x20failures\x20with\n\n\x0b\n\x03\x04\x08\x01\
+    \x12\x04\x92\x01\x08\x10\n\x0c\n\x04\x04\x08\x02\0\x12\x04\x93\x01\x02\
+    \x20\n\r\n\x05\x04\x08\x02\0\x04\x12\x04\x93\x01\x02\n\n\r\n\x05\x04\x08\
+    \x02\0\x06\x12\x04\x93\x01\x0b\x16\n\r\n\x05\x04\x08\x02\0\x01\x12\x04\
+    \x93\x01\x17\x1b\n\r\n\x05\x04\x08\x02\0\x03\x12\x04\x93\x01\x1e\x1f\n\
+    \x0c\n\x04\x04\x08\x02\x01\x12\x04\x94\x01\x02\x1e\n\r\n\x05\x04\x08\x02\
+    \x01\x04\x12\x04\x94\x01\x02\n\n\r\n\x05\x04\x08\x02\x01\x05\x12\x04\x94\
+    \x01\x0b\x11\n\r\n\x05\x04\x08\x02\x01\x01\x12\x04\x94\x01\x12\x19\n\r\n\
+    \x05\x04\x08\x02\x01\x03\x12\x04\x94\x01\x1c\x1d\n\x0c\n\x04\x04\x08\x02\
+    \x02\x12\x04\x95\x01\x02)\n\r\n\x05\x04\x08\x02\x02\x04\x12\x04\x95\x01\
+    \x02\n\n\r\n\x05\x04\x08\x02\x02\x06\x12\x04\x95\x01\x0b\x1b\n\r\n\x05\
+    \x04\x08\x02\x02\x01\x12\x04\x95\x01\x1c$\n\r\n\x05\x04\x08\x02\x02\x03\
+    \x12\x04\x95\x01'(\n\x0c\n\x02\x04\t\x12\x06\x98\x01\0\x9a\x01\x01\n\x0b\
+    \n\x03\x04\t\x01\x12\x04\x98\x01\x08\x0e\n\x0c\n\x04\x04\t\x02\0\x12\x04\
+    \x99\x01\x02\x19\n\r\n\x05\x04\t\x02\0\x04\x12\x04\x99\x01\x02\n\n\r\n\
+    \x05\x04\t\x02\0\x05\x12\x04\x99\x01\x0b\x11\n\r\n\x05\x04\t\x02\0\x01\
+    \x12\x04\x99\x01\x12\x14\n\r\n\x05\x04\t\x02\0\x03\x12\x04\x99\x01\x17\
+    \x18\n\x0c\n\x02\x04\n\x12\x06\x9c\x01\0\xa4\x01\x01\n\x0b\n\x03\x04\n\
+    \x01\x12\x04\x9c\x01\x08\x0f\n\x0c\n\x04\x04\n\x02\0\x12\x04\x9d\x01\x02\
+    \x1f\n\r\n\x05\x04\n\x02\0\x04\x12\x04\x9d\x01\x02\n\n\r\n\x05\x04\n\x02\
+    \0\x05\x12\x04\x9d\x01\x0b\x11\n\r\n\x05\x04\n\x02\0\x01\x12\x04\x9d\x01\
+    \x12\x1a\n\r\n\x05\x04\n\x02\0\x03\x12\x04\x9d\x01\x1d\x1e\n\x0c\n\x04\
+    \x04\n\x02\x01\x12\x04\x9e\x01\x02/\n\r\n\x05\x04\n\x02\x01\x04\x12\x04\
+    \x9e\x01\x02\n\n\r\n\x05\x04\n\x02\x01\x06\x12\x04\x9e\x01\x0b\"\n\r\n\
+    \x05\x04\n\x02\x01\x01\x12\x04\x9e\x01#*\n\r\n\x05\x04\n\x02\x01\x03\x12\
+    \x04\x9e\x01-.\n\x0c\n\x04\x04\n\x02\x02\x12\x04\x9f\x01\x02\x1e\n\r\n\
+    \x05\x04\n\x02\x02\x04\x12\x04\x9f\x01\x02\n\n\r\n\x05\x04\n\x02\x02\x05\
+    \x12\x04\x9f\x01\x0b\x11\n\r\n\x05\x04\n\x02\x02\x01\x12\x04\x9f\x01\x12\
+    \x19\n\r\n\x05\x04\n\x02\x02\x03\x12\x04\x9f\x01\x1c\x1d\n\x0c\n\x04\x04\
+    \n\x02\x03\x12\x04\xa0\x01\x02$\n\r\n\x05\x04\n\x02\x03\x04\x12\x04\xa0\
+    \x01\x02\n\n\r\n\x05\x04\n\x02\x03\x06\x12\x04\xa0\x01\x0b\x16\n\r\n\x05\
+    \x04\n\x02\x03\x01\x12\x04\xa0\x01\x17\x1f\n\r\n\x05\x04\n\x02\x03\x03\
+    \x12\x04\xa0\x01\"#\n\x0c\n\x04\x04\n\x02\x04\x12\x04\xa1\x01\x02\x1d\n\
+    \r\n\x05\x04\n\x02\x04\x04\x12\x04\xa1\x01\x02\n\n\r\n\x05\x04\n\x02\x04\
+    \x05\x12\x04\xa1\x01\x0b\x11\n\r\n\x05\x04\n\x02\x04\x01\x12\x04\xa1\x01\
+    \x12\x18\n\r\n\x05\x04\n\x02\x04\x03\x12\x04\xa1\x01\x1b\x1c\n\x0c\n\x04\
+    \x04\n\x02\x05\x12\x04\xa2\x01\x02\x1b\n\r\n\x05\x04\n\x02\x05\x04\x12\
+    \x04\xa2\x01\x02\n\n\r\n\x05\x04\n\x02\x05\x05\x12\x04\xa2\x01\x0b\x11\n\
+    \r\n\x05\x04\n\x02\x05\x01\x12\x04\xa2\x01\x12\x16\n\r\n\x05\x04\n\x02\
+    \x05\x03\x12\x04\xa2\x01\x19\x1a\n\x0c\n\x04\x04\n\x02\x06\x12\x04\xa3\
+    \x01\x02\x1c\n\r\n\x05\x04\n\x02\x06\x04\x12\x04\xa3\x01\x02\n\n\r\n\x05\
+    \x04\n\x02\x06\x06\x12\x04\xa3\x01\x0b\x12\n\r\n\x05\x04\n\x02\x06\x01\
+    \x12\x04\xa3\x01\x13\x17\n\r\n\x05\x04\n\x02\x06\x03\x12\x04\xa3\x01\x1a\
+    \x1b\n\x0c\n\x02\x04\x0b\x12\x06\xa6\x01\0\xab\x01\x01\n\x0b\n\x03\x04\
+    \x0b\x01\x12\x04\xa6\x01\x08\x16\n\x0c\n\x04\x04\x0b\x02\0\x12\x04\xa7\
+    \x01\x02\x1b\n\r\n\x05\x04\x0b\x02\0\x04\x12\x04\xa7\x01\x02\n\n\r\n\x05\
+    \x04\x0b\x02\0\x05\x12\x04\xa7\x01\x0b\x11\n\r\n\x05\x04\x0b\x02\0\x01\
+    \x12\x04\xa7\x01\x12\x16\n\r\n\x05\x04\x0b\x02\0\x03\x12\x04\xa7\x01\x19\
+    \x1a\n\x0c\n\x04\x04\x0b\x02\x01\x12\x04\xa8\x01\x02\x1c\n\r\n\x05\x04\
+    \x0b\x02\x01\x04\x12\x04\xa8\x01\x02\n\n\r\n\x05\x04\x0b\x02\x01\x05\x12\
+    \x04\xa8\x01\x0b\x11\n\r\n\x05\x04\x0b\x02\x01\x01\x12\x04\xa8\x01\x12\
+    \x17\n\r\n\x05\x04\x0b\x02\x01\x03\x12\x04\xa8\x01\x1a\x1b\n\x0c\n\x04\
+    \x04\x0b\x02\x02\x12\x04\xa9\x01\x02\x1b\n\r\n\x05\x04\x0b\x02\x02\x04\
+    \x12\x04\xa9\x01\x02\n\n\r\n\x05\x04\x0b\x02\x02\x05\x12\x04\xa9\x01\x0b\
+    \x11\n\r\n\x05\x04\x0b\x02\x02\x01\x12\x04\xa9\x01\x12\x16\n\r\n\x05\x04\
+    \x0b\x02\x02\x03\x12\x04\xa9\x01\x19\x1a\n9\n\x04\x04\x0b\x02\x03\x12\
+    \x04\xaa\x01\x02%\"+\x20RFC\x203339\x20timestamp,\x20leaves\x20newer\x20\
+    jobs\x20out\n\n\r\n\x05\x04\x0b\x02\x03\x04\x12\x04\xaa\x01\x02\n\n\r\n\
+    \x05\x04\x0b\x02\x03\x05\x12\x04\xaa\x01\x0b\x11\n\r\n\x05\x04\x0b\x02\
+    \x03\x01\x12\x04\xaa\x01\x12\x20\n\r\n\x05\x04\x0b\x02\x03\x03\x12\x04\
+    \xaa\x01#$\n\x0c\n\x02\x04\x0c\x12\x06\xad\x01\0\xb2\x01\x01\n\x0b\n\x03\
+    \x04\x0c\x01\x12\x04\xad\x01\x08\x1e\n\x0c\n\x04\x04\x0c\x02\0\x12\x04\
+    \xae\x01\x02\x18\n\r\n\x05\x04\x0c\x02\0\x04\x12\x04\xae\x01\x02\n\n\r\n\
+    \x05\x04\x0c\x02\0\x06\x12\x04\xae\x01\x0b\x0e\n\r\n\x05\x04\x0c\x02\0\
+    \x01\x12\x04\xae\x01\x0f\x13\n\r\n\x05\x04\x0c\x02\0\x03\x12\x04\xae\x01\
+    \x16\x17\n\x0c\n\x04\x04\x0c\x02\x01\x12\x04\xaf\x01\x02\x1c\n\r\n\x05\
+    \x04\x0c\x02\x01\x04\x12\x04\xaf\x01\x02\n\n\r\n\x05\x04\x0c\x02\x01\x05\
+    \x12\x04\xaf\x01\x0b\x11\n\r\n\x05\x04\x0c\x02\x01\x01\x12\x04\xaf\x01\
+    \x12\x17\n\r\n\x05\x04\x0c\x02\x01\x03\x12\x04\xaf\x01\x1a\x1b\n\x0c\n\
+    \x04\x04\x0c\x02\x02\x12\x04\xb0\x01\x02\x1b\n\r\n\x05\x04\x0c\x02\x02\
+    \x04\x12\x04\xb0\x01\x02\n\n\r\n\x05\x04\x0c\x02\x02\x05\x12\x04\xb0\x01\
+    \x0b\x11\n\r\n\x05\x04\x0c\x02\x02\x01\x12\x04\xb0\x01\x12\x16\n\r\n\x05\
+    \x04\x0c\x02\x02\x03\x12\x04\xb0\x01\x19\x1a\n\x0c\n\x04\x04\x0c\x02\x03\
+    \x12\x04\xb1\x01\x02\x1c\n\r\n\x05\x04\x0c\x02\x03\x04\x12\x04\xb1\x01\
+    \x02\n\n\r\n\x05\x04\x0c\x02\x03\x05\x12\x04\xb1\x01\x0b\x11\n\r\n\x05\
+    \x04\x0c\x02\x03\x01\x12\x04\xb1\x01\x12\x17\n\r\n\x05\x04\x0c\x02\x03\
+    \x03\x12\x04\xb1\x01\x1a\x1b\n\x0c\n\x02\x04\r\x12\x06\xb4\x01\0\xb9\x01\
+    \x01\n\x0b\n\x03\x04\r\x01\x12\x04\xb4\x01\x08\x14\n\x0c\n\x04\x04\r\x02\
+    \0\x12\x04\xb5\x01\x02\x1a\n\r\n\x05\x04\r\x02\0\x04\x12\x04\xb5\x01\x02\
+    \n\n\r\n\x05\x04\r\x02\0\x05\x12\x04\xb5\x01\x0b\x11\n\r\n\x05\x04\r\x02\
+    \0\x01\x12\x04\xb5\x01\x12\x15\n\r\n\x05\x04\r\x02\0\x03\x12\x04\xb5\x01\
+    \x18\x19\n\x0c\n\x04\x04\r\x02\x01\x12\x04\xb6\x01\x02\x1c\n\r\n\x05\x04\
+    \r\x02\x01\x04\x12\x04\xb6\x01\x02\n\n\r\n\x05\x04\r\x02\x01\x05\x12\x04\
+    \xb6\x01\x0b\x11\n\r\n\x05\x04\r\x02\x01\x01\x12\x04\xb6\x01\x12\x17\n\r\
+    \n\x05\x04\r\x02\x01\x03\x12\x04\xb6\x01\x1a\x1b\n\x0c\n\x04\x04\r\x02\
+    \x02\x12\x04\xb7\x01\x02\x1b\n\r\n\x05\x04\r\x02\x02\x04\x12\x04\xb7\x01\
+    \x02\n\n\r\n\x05\x04\r\x02\x02\x05\x12\x04\xb7\x01\x0b\x11\n\r\n\x05\x04\
+    \r\x02\x02\x01\x12\x04\xb7\x01\x12\x16\n\r\n\x05\x04\r\x02\x02\x03\x12\
+    \x04\xb7\x01\x19\x1a\n9\n\x04\x04\r\x02\x03\x12\x04\xb8\x01\x02%\"+\x20R\
+    FC\x203339\x20timestamp,\x20leaves\x20newer\x20jobs\x20out\n\n\r\n\x05\
+    \x04\r\x02\x03\x04\x12\x04\xb8\x01\x02\n\n\r\n\x05\x04\r\x02\x03\x05\x12\
+    \x04\xb8\x01\x0b\x11\n\r\n\x05\x04\r\x02\x03\x01\x12\x04\xb8\x01\x12\x20\
+    \n\r\n\x05\x04\r\x02\x03\x03\x12\x04\xb8\x01#$\n\x0c\n\x02\x04\x0e\x12\
+    \x06\xbb\x01\0\xc0\x01\x01\n\x0b\n\x03\x04\x0e\x01\x12\x04\xbb\x01\x08\
+    \x1c\n\x0c\n\x04\x04\x0e\x02\0\x12\x04\xbc\x01\x02\x18\n\r\n\x05\x04\x0e\
+    \x02\0\x04\x12\x04\xbc\x01\x02\n\n\r\n\x05\x04\x0e\x02\0\x06\x12\x04\xbc\
+    \x01\x0b\x0e\n\r\n\x05\x04\x0e\x02\0\x01\x12\x04\xbc\x01\x0f\x13\n\r\n\
+    \x05\x04\x0e\x02\0\x03\x12\x04\xbc\x01\x16\x17\n\x0c\n\x04\x04\x0e\x02\
+    \x01\x12\x04\xbd\x01\x02\x1c\n\r\n\x05\x04\x0e\x02\x01\x04\x12\x04\xbd\
+    \x01\x02\n\n\r\n\x05\x04\x0e\x02\x01\x05\x12\x04\xbd\x01\x0b\x11\n\r\n\
+    \x05\x04\x0e\x02\x01\x01\x12\x04\xbd\x01\x12\x17\n\r\n\x05\x04\x0e\x02\
+    \x01\x03\x12\x04\xbd\x01\x1a\x1b\n\x0c\n\x04\x04\x0e\x02\x02\x12\x04\xbe\
+    \x01\x02\x1b\n\r\n\x05\x04\x0e\x02\x02\x04\x12\x04\xbe\x01\x02\n\n\r\n\
+    \x05\x04\x0e\x02\x02\x05\x12\x04\xbe\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\
+    \x02\x01\x12\x04\xbe\x01\x12\x16\n\r\n\x05\x04\x0e\x02\x02\x03\x12\x04\
+    \xbe\x01\x19\x1a\n\x0c\n\x04\x04\x0e\x02\x03\x12\x04\xbf\x01\x02\x1c\n\r\
+    \n\x05\x04\x0e\x02\x03\x04\x12\x04\xbf\x01\x02\n\n\r\n\x05\x04\x0e\x02\
+    \x03\x05\x12\x04\xbf\x01\x0b\x11\n\r\n\x05\x04\x0e\x02\x03\x01\x12\x04\
+    \xbf\x01\x12\x17\n\r\n\x05\x04\x0e\x02\x03\x03\x12\x04\xbf\x01\x1a\x1b\n\
+    \x0c\n\x02\x04\x0f\x12\x06\xc2\x01\0\xc6\x01\x01\n\x0b\n\x03\x04\x0f\x01\
+    \x12\x04\xc2\x01\x08\x13\n\x0c\n\x04\x04\x0f\x02\0\x12\x04\xc3\x01\x02\
+    \x1d\n\r\n\x05\x04\x0f\x02\0\x04\x12\x04\xc3\x01\x02\n\n\r\n\x05\x04\x0f\
+    \x02\0\x05\x12\x04\xc3\x01\x0b\x11\n\r\n\x05\x04\x0f\x02\0\x01\x12\x04\
+    \xc3\x01\x12\x18\n\r\n\x05\x04\x0f\x02\0\x03\x12\x04\xc3\x01\x1b\x1c\n,\
+    \n\x04\x04\x0f\x02\x01\x12\x04\xc4\x01\x02\x1a\"\x1e\x20Chunk\x20orderin\
+    g\x20(line\x20number)\n\n\r\n\x05\x04\x0f\x02\x01\x04\x12\x04\xc4\x01\
+    \x02\n\n\r\n\x05\x04\x0f\x02\x01\x05\x12\x04\xc4\x01\x0b\x11\n\r\n\x05\
+    \x04\x0f\x02\x01\x01\x12\x04\xc4\x01\x12\x15\n\r\n\x05\x04\x0f\x02\x01\
+    \x03\x12\x04\xc4\x01\x18\x19\n6\n\x04\x04\x0f\x02\x02\x12\x04\xc5\x01\
+    \x02\x1e\"(\x20Log\x20content\x20(TODO:\x20Make\x20repeatedfield)\n\n\r\
+    \n\x05\x04\x0f\x02\x02\x04\x12\x04\xc5\x01\x02\n\n\r\n\x05\x04\x0f\x02\
+    \x02\x05\x12\x04\xc5\x01\x0b\x11\n\r\n\x05\x04\x0f\x02\x02\x01\x12\x04\
+    \xc5\x01\x12\x19\n\r\n\x05\x04\x0f\x02\x02\x03\x12\x04\xc5\x01\x1c\x1d\n\
+    \x0c\n\x02\x04\x10\x12\x06\xc8\x01\0\xca\x01\x01\n\x0b\n\x03\x04\x10\x01\
+    \x12\x04\xc8\x01\x08\x16\n\x0c\n\x04\x04\x10\x02\0\x12\x04\xc9\x01\x02\
+    \x1d\n\r\n\x05\x04\x10\x02\0\x04\x12\x04\xc9\x01\x02\n\n\r\n\x05\x04\x10\
+    \x02\0\x05\x12\x04\xc9\x01\x0b\x11\n\r\n\x05\x04\x10\x02\0\x01\x12\x04\
+    \xc9\x01\x12\x18\n\r\n\x05\x04\x10\x02\0\x03\x12\x04\xc9\x01\x1b\x1c\n\
+    \x0c\n\x02\x04\x11\x12\x06\xcc\x01\0\xcf\x01\x01\n\x0b\n\x03\x04\x11\x01\
+    \x12\x04\xcc\x01\x08\x11\n\x0c\n\x04\x04\x11\x02\0\x12\x04\xcd\x01\x02\
+    \x19\n\r\n\x05\x04\x11\x02\0\x04\x12\x04\xcd\x01\x02\n\n\r\n\x05\x04\x11\
+    \x02\0\x05\x12\x04\xcd\x01\x0b\x11\n\r\n\x05\x04\x11\x02\0\x01\x12\x04\
+    \xcd\x01\x12\x14\n\r\n\x05\x04\x11\x02\0\x03\x12\x04\xcd\x01\x17\x18\n/\
+    \n\x04\x04\x11\x02\x01\x12\x04\xce\x01\x02\x1c\"!\x20Zero-indexed\x20lin\
+    e\x20of\x20log\x20output\n\n\r\n\x05\x04\x11\x02\x01\x04\x12\x04\xce\x01\
+    \x02\n\n\r\n\x05\x04\x11\x02\x01\x05\x12\x04\xce\x01\x0b\x11\n\r\n\x05\
+    \x04\x11\x02\x01\x01\x12\x04\xce\x01\x12\x17\n\r\n\x05\x04\x11\x02\x01\
+    \x03\x12\x04\xce\x01\x1a\x1b\n\xc9\x01\n\x02\x04\x12\x12\x06\xd3\x01\0\
+    \xd6\x01\x01\x1a\xba\x01\x20Start\x20following\x20a\x20job's\x20log.\x20\
+    Replied\x20to\x20at\x20once\x20with\x20a\x20`JobLog`\x20of\x20the\x20out\
+    put\x20from\x20`start`\x20on;\n\x20new\x20output\x20is\x20then\x20publis\
+    hed\x20as\x20`JobLog`s\x20on\x20JobSrv's\x20log\x20stream,\x20under\x20t\
+    he\x20job's\x20topic.\n\n\x0b\n\x03\x04\x12\x01\x12\x04\xd3\x01\x08\x17\
+    \n\x0c\n\x04\x04\x12\x02\0\x12\x04\xd4\x01\x02\x1d\n\r\n\x05\x04\x12\x02\
+    \0\x04\x12\x04\xd4\x01\x02\n\n\r\n\x05\x04\x12\x02\0\x05\x12\x04\xd4\x01\
+    \x0b\x11\n\r\n\x05\x04\x12\x02\0\x01\x12\x04\xd4\x01\x12\x18\n\r\n\x05\
+    \x04\x12\x02\0\x03\x12\x04\xd4\x01\x1b\x1c\n/\n\x04\x04\x12\x02\x01\x12\
+    \x04\xd5\x01\x02\x1c\"!\x20Zero-indexed\x20line\x20of\x20log\x20output\n\
+    \n\r\n\x05\x04\x12\x02\x01\x04\x12\x04\xd5\x01\x02\n\n\r\n\x05\x04\x12\
+    \x02\x01\x05\x12\x04\xd5\x01\x0b\x11\n\r\n\x05\x04\x12\x02\x01\x01\x12\
+    \x04\xd5\x01\x12\x17\n\r\n\x05\x04\x12\x02\x01\x03\x12\x04\xd5\x01\x1a\
+    \x1b\n\x0c\n\x02\x04\x13\x12\x06\xd8\x01\0\xdd\x01\x01\n\x0b\n\x03\x04\
+    \x13\x01\x12\x04\xd8\x01\x08\x0e\n-\n\x04\x04\x13\x02\0\x12\x04\xd9\x01\
+    \x02\x1c\"\x1f\x20Zero-indexed\x20(inclusive)\x20line\n\n\r\n\x05\x04\
+    \x13\x02\0\x04\x12\x04\xd9\x01\x02\n\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\
+    \xd9\x01\x0b\x11\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\xd9\x01\x12\x17\n\r\
+    \n\x05\x04\x13\x02\0\x03\x12\x04\xd9\x01\x1a\x1b\n-\n\x04\x04\x13\x02\
+    \x01\x12\x04\xda\x01\x02\x1b\"\x1f\x20Zero-indexed\x20(exclusive)\x20lin\
+    e\n\n\r\n\x05\x04\x13\x02\x01\x04\x12\x04\xda\x01\x02\n\n\r\n\x05\x04\
+    \x13\x02\x01\x05\x12\x04\xda\x01\x0b\x11\n\r\n\x05\x04\x13\x02\x01\x01\
+    \x12\x04\xda\x01\x12\x16\n\r\n\x05\x04\x13\x02\x01\x03\x12\x04\xda\x01\
+    \x19\x1a\n\x0c\n\x04\x04\x13\x02\x02\x12\x04\xdb\x01\x02\x1e\n\r\n\x05\
+    \x04\x13\x02\x02\x04\x12\x04\xdb\x01\x02\n\n\r\n\x05\x04\x13\x02\x02\x05\
+    \x12\x04\xdb\x01\x0b\x11\n\r\n\x05\x04\x13\x02\x02\x01\x12\x04\xdb\x01\
+    \x12\x19\n\r\n\x05\x04\x13\x02\x02\x03\x12\x04\xdb\x01\x1c\x1d\n\x0c\n\
+    \x04\x04\x13\x02\x03\x12\x04\xdc\x01\x02\x20\n\r\n\x05\x04\x13\x02\x03\
+    \x04\x12\x04\xdc\x01\x02\n\n\r\n\x05\x04\x13\x02\x03\x05\x12\x04\xdc\x01\
+    \x0b\x0f\n\r\n\x05\x04\x13\x02\x03\x01\x12\x04\xdc\x01\x10\x1b\n\r\n\x05\
+    \x04\x13\x02\x03\x03\x12\x04\xdc\x01\x1e\x1f\n\x0c\n\x02\x04\x14\x12\x06\
+    \xdf\x01\0\xed\x01\x01\n\x0b\n\x03\x04\x14\x01\x12\x04\xdf\x01\x08\x14\n\
+    \x0c\n\x04\x04\x14\x02\0\x12\x04\xe0\x01\x02\x1d\n\r\n\x05\x04\x14\x02\0\
+    \x04\x12\x04\xe0\x01\x02\n\n\r\n\x05\x04\x14\x02\0\x05\x12\x04\xe0\x01\
+    \x0b\x11\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\xe0\x01\x12\x18\n\r\n\x05\
+    \x04\x14\x02\0\x03\x12\x04\xe0\x01\x1b\x1c\n\x0c\n\x04\x04\x14\x02\x01\
+    \x12\x04\xe1\x01\x02\x1e\n\r\n\x05\x04\x14\x02\x01\x04\x12\x04\xe1\x01\
+    \x02\n\n\r\n\x05\x04\x14\x02\x01\x05\x12\x04\xe1\x01\x0b\x11\n\r\n\x05\
+    \x04\x14\x02\x01\x01\x12\x04\xe1\x01\x12\x19\n\r\n\x05\x04\x14\x02\x01\
+    \x03\x12\x04\xe1\x01\x1c\x1d\n\x0c\n\x04\x04\x14\x02\x02\x12\x04\xe2\x01\
+    \x02\x1e\n\r\n\x05\x04\x14\x02\x02\x04\x12\x04\xe2\x01\x02\n\n\r\n\x05\
+    \x04\x14\x02\x02\x05\x12\x04\xe2\x01\x0b\x0f\n\r\n\x05\x04\x14\x02\x02\
+    \x01\x12\x04\xe2\x01\x10\x19\n\r\n\x05\x04\x14\x02\x02\x03\x12\x04\xe2\
+    \x01\x1c\x1d\n\x0c\n\x04\x04\x14\x02\x03\x12\x04\xe3\x01\x02\x1d\n\r\n\
+    \x05\x04\x14\x02\x03\x04\x12\x04\xe3\x01\x02\n\n\r\n\x05\x04\x14\x02\x03\
+    \x05\x12\x04\xe3\x01\x0b\x11\n\r\n\x05\x04\x14\x02\x03\x01\x12\x04\xe3\
+    \x01\x12\x18\n\r\n\x05\x04\x14\x02\x03\x03\x12\x04\xe3\x01\x1b\x1c\n\x0c\
+    \n\x04\x04\x14\x02\x04\x12\x04\xe4\x01\x02\x20\n\r\n\x05\x04\x14\x02\x04\
+    \x04\x12\x04\xe4\x01\x02\n\n\r\n\x05\x04\x14\x02\x04\x05\x12\x04\xe4\x01\
+    \x0b\x0f\n\r\n\x05\x04\x14\x02\x04\x01\x12\x04\xe4\x01\x10\x1b\n\r\n\x05\
+    \x04\x14\x02\x04\x03\x12\x04\xe4\x01\x1e\x1f\n\x0c\n\x04\x04\x14\x02\x05\
+    \x12\x04\xe5\x01\x02!\n\r\n\x05\x04\x14\x02\x05\x04\x12\x04\xe5\x01\x02\
+    \n\n\r\n\x05\x04\x14\x02\x05\x05\x12\x04\xe5\x01\x0b\x0f\n\r\n\x05\x04\
+    \x14\x02\x05\x01\x12\x04\xe5\x01\x10\x1c\n\r\n\x05\x04\x14\x02\x05\x03\
+    \x12\x04\xe5\x01\x1f\x20\n\x0c\n\x04\x04\x14\x02\x06\x12\x04\xe6\x01\x02\
+    $\n\r\n\x05\x04\x14\x02\x06\x04\x12\x04\xe6\x01\x02\n\n\r\n\x05\x04\x14\
+    \x02\x06\x06\x12\x04\xe6\x01\x0b\x16\n\r\n\x05\x04\x14\x02\x06\x01\x12\
+    \x04\xe6\x01\x17\x1f\n\r\n\x05\x04\x14\x02\x06\x03\x12\x04\xe6\x01\"#\n\
+    \x0c\n\x04\x04\x14\x02\x07\x12\x04\xe7\x01\x02\x1b\n\r\n\x05\x04\x14\x02\
+    \x07\x04\x12\x04\xe7\x01\x02\n\n\r\n\x05\x04\x14\x02\x07\x05\x12\x04\xe7\
+    \x01\x0b\x11\n\r\n\x05\x04\x14\x02\x07\x01\x12\x04\xe7\x01\x12\x16\n\r\n\
+    \x05\x04\x14\x02\x07\x03\x12\x04\xe7\x01\x19\x1a\nS\n\x04\x04\x14\x02\
+    \x08\x12\x04\xe9\x01\x02\x1f\x1aE\x20Additional\x20root\x20packages\x20i\
+    n\x20the\x20same\x20origin,\x20merged\x20into\x20this\x20group\n\n\r\n\
+    \x05\x04\x14\x02\x08\x04\x12\x04\xe9\x01\x02\n\n\r\n\x05\x04\x14\x02\x08\
+    \x05\x12\x04\xe9\x01\x0b\x11\n\r\n\x05\x04\x14\x02\x08\x01\x12\x04\xe9\
+    \x01\x12\x1a\n\r\n\x05\x04\x14\x02\x08\x03\x12\x04\xe9\x01\x1d\x1e\n]\n\
+    \x04\x04\x14\x02\t\x12\x04\xeb\x01\x02$\x1aO\x20Account\x20which\x20requ\
+    ested\x20the\x20group,\x20unset\x20for\x20groups\x20created\x20by\x20bui\
+    lder\x20itself\n\n\r\n\x05\x04\x14\x02\t\x04\x12\x04\xeb\x01\x02\n\n\r\n\
+    \x05\x04\x14\x02\t\x05\x12\x04\xeb\x01\x0b\x11\n\r\n\x05\x04\x14\x02\t\
+    \x01\x12\x04\xeb\x01\x12\x1e\n\r\n\x05\x04\x14\x02\t\x03\x12\x04\xeb\x01\
+    !#\n\x0c\n\x04\x04\x14\x02\n\x12\x04\xec\x01\x02&\n\r\n\x05\x04\x14\x02\
+    \n\x04\x12\x04\xec\x01\x02\n\n\r\n\x05\x04\x14\x02\n\x05\x12\x04\xec\x01\
+    \x0b\x11\n\r\n\x05\x04\x14\x02\n\x01\x12\x04\xec\x01\x12\x20\n\r\n\x05\
+    \x04\x14\x02\n\x03\x12\x04\xec\x01#%\nN\n\x02\x04\x15\x12\x06\xf0\x01\0\
+    \xf5\x01\x01\x1a@\x20Reply\x20to\x20a\x20JobGroupSpec\x20which\x20can't\
+    \x20be\x20turned\x20into\x20a\x20job\x20group\n\n\x0b\n\x03\x04\x15\x01\
+    \x12\x04\xf0\x01\x08\x1b\n\x0c\n\x04\x04\x15\x02\0\x12\x04\xf1\x01\x02#\
+    \n\r\n\x05\x04\x15\x02\0\x04\x12\x04\xf1\x01\x02\n\n\r\n\x05\x04\x15\x02\
+    \0\x05\x12\x04\xf1\x01\x0b\x11\n\r\n\x05\x04\x15\x02\0\x01\x12\x04\xf1\
+    \x01\x12\x1e\n\r\n\x05\x04\x15\x02\0\x03\x12\x04\xf1\x01!\"\n\x91\x01\n\
+    \x04\x04\x15\x02\x01\x12\x04\xf4\x01\x02\x1c\x1a\x82\x01\x20Packages\x20\
+    which\x20depend\x20on\x20each\x20other\x20in\x20a\x20cycle.\x20Each\x20p\
+    ackage\x20depends\x20on\x20the\x20next\x20one\x20and\x20the\n\x20last\
+    \x20package\x20depends\x20on\x20the\x20first.\n\n\r\n\x05\x04\x15\x02\
+    \x01\x04\x12\x04\xf4\x01\x02\n\n\r\n\x05\x04\x15\x02\x01\x05\x12\x04\xf4\
+    \x01\x0b\x11\n\r\n\x05\x04\x15\x02\x01\x01\x12\x04\xf4\x01\x12\x17\n\r\n\
+    \x05\x04\x15\x02\x01\x03\x12\x04\xf4\x01\x1a\x1b\ng\n\x02\x04\x16\x12\
+    \x06\xf8\x01\0\xfa\x01\x01\x1aY\x20Expands\x20a\x20JobGroupSpec\x20into\
+    \x20the\x20packages\x20it\x20would\x20rebuild,\x20without\x20creating\
+    \x20a\x20job\x20group\n\n\x0b\n\x03\x04\x16\x01\x12\x04\xf8\x01\x08\x17\
+    \n\x0c\n\x04\x04\x16\x02\0\x12\x04\xf9\x01\x02!\n\r\n\x05\x04\x16\x02\0\
+    \x04\x12\x04\xf9\x01\x02\n\n\r\n\x05\x04\x16\x02\0\x06\x12\x04\xf9\x01\
+    \x0b\x17\n\r\n\x05\x04\x16\x02\0\x01\x12\x04\xf9\x01\x18\x1c\n\r\n\x05\
+    \x04\x16\x02\0\x03\x12\x04\xf9\x01\x1f\x20\n\x0c\n\x02\x04\x17\x12\x06\
+    \xfc\x01\0\xff\x01\x01\n\x0b\n\x03\x04\x17\x01\x12\x04\xfc\x01\x08\x1e\n\
+    \x0c\n\x04\x04\x17\x02\0\x12\x04\xfd\x01\x02\x1b\n\r\n\x05\x04\x17\x02\0\
+    \x04\x12\x04\xfd\x01\x02\n\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xfd\x01\
+    \x0b\x11\n\r\n\x05\x04\x17\x02\0\x01\x12\x04\xfd\x01\x12\x16\n\r\n\x05\
+    \x04\x17\x02\0\x03\x12\x04\xfd\x01\x19\x1a\n\x0c\n\x04\x04\x17\x02\x01\
+    \x12\x04\xfe\x01\x02\x1c\n\r\n\x05\x04\x17\x02\x01\x04\x12\x04\xfe\x01\
+    \x02\n\n\r\n\x05\x04\x17\x02\x01\x05\x12\x04\xfe\x01\x0b\x11\n\r\n\x05\
+    \x04\x17\x02\x01\x01\x12\x04\xfe\x01\x12\x17\n\r\n\x05\x04\x17\x02\x01\
+    \x03\x12\x04\xfe\x01\x1a\x1b\nZ\n\x02\x04\x18\x12\x06\x82\x02\0\x84\x02\
+    \x01\x1aL\x20Packages\x20a\x20JobGroupPreview\x20would\x20rebuild,\x20in\
+    \x20the\x20order\x20they\x20would\x20be\x20built\n\n\x0b\n\x03\x04\x18\
+    \x01\x12\x04\x82\x02\x08\x1f\n\x0c\n\x04\x04\x18\x02\0\x12\x04\x83\x02\
+    \x02/\n\r\n\x05\x04\x18\x02\0\x04\x12\x04\x83\x02\x02\n\n\r\n\x05\x04\
+    \x18\x02\0\x06\x12\x04\x83\x02\x0b!\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\
+    \x83\x02\"*\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\x83\x02-.\nU\n\x02\x04\
+    \x19\x12\x06\x87\x02\0\x8e\x02\x01\x1aG\x20A\x20job\x20group\x20created\
+    \x20automatically\x20on\x20a\x20recurring,\x20cron-style\x20schedule\n\n\
+    \x0b\n\x03\x04\x19\x01\x12\x04\x87\x02\x08\x18\n\x0c\n\x04\x04\x19\x02\0\
+    \x12\x04\x88\x02\x02\x19\n\r\n\x05\x04\x19\x02\0\x04\x12\x04\x88\x02\x02\
+    \n\n\r\n\x05\x04\x19\x02\0\x05\x12\x04\x88\x02\x0b\x11\n\r\n\x05\x04\x19\
+    \x02\0\x01\x12\x04\x88\x02\x12\x14\n\r\n\x05\x04\x19\x02\0\x03\x12\x04\
+    \x88\x02\x17\x18\n\x0c\n\x04\x04\x19\x02\x01\x12\x04\x89\x02\x02!\n\r\n\
+    \x05\x04\x19\x02\x01\x04\x12\x04\x89\x02\x02\n\n\r\n\x05\x04\x19\x02\x01\
+    \x06\x12\x04\x89\x02\x0b\x17\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\x89\
+    \x02\x18\x1c\n\r\n\x05\x04\x19\x02\x01\x03\x12\x04\x89\x02\x1f\x20\nB\n\
+    \x04\x04\x19\x02\x02\x12\x04\x8a\x02\x02\x1b\"4\x20minute\x20hour\x20day\
+    -of-month\x20month\x20day-of-week,\x20in\x20UTC\n\n\r\n\x05\x04\x19\x02\
+    \x02\x04\x12\x04\x8a\x02\x02\n\n\r\n\x05\x04\x19\x02\x02\x05\x12\x04\x8a\
+    \x02\x0b\x11\n\r\n\x05\x04\x19\x02\x02\x01\x12\x04\x8a\x02\x12\x16\n\r\n\
+    \x05\x04\x19\x02\x02\x03\x12\x04\x8a\x02\x19\x1a\n&\n\x04\x04\x19\x02\
+    \x03\x12\x04\x8b\x02\x02\"\"\x18\x20RFC3339-formatted\x20time\n\n\r\n\
+    \x05\x04\x19\x02\x03\x04\x12\x04\x8b\x02\x02\n\n\r\n\x05\x04\x19\x02\x03\
+    \x05\x12\x04\x8b\x02\x0b\x11\n\r\n\x05\x04\x19\x02\x03\x01\x12\x04\x8b\
+    \x02\x12\x1d\n\r\n\x05\x04\x19\x02\x03\x03\x12\x04\x8b\x02\x20!\n&\n\x04\
+    \x04\x19\x02\x04\x12\x04\x8c\x02\x02\"\"\x18\x20RFC3339-formatted\x20tim\
+    e\n\n\r\n\x05\x04\x19\x02\x04\x04\x12\x04\x8c\x02\x02\n\n\r\n\x05\x04\
+    \x19\x02\x04\x05\x12\x04\x8c\x02\x0b\x11\n\r\n\x05\x04\x19\x02\x04\x01\
+    \x12\x04\x8c\x02\x12\x1d\n\r\n\x05\x04\x19\x02\x04\x03\x12\x04\x8c\x02\
+    \x20!\n&\n\x04\x04\x19\x02\x05\x12\x04\x8d\x02\x02!\"\x18\x20RFC3339-for\
+    matted\x20time\n\n\r\n\x05\x04\x19\x02\x05\x04\x12\x04\x8d\x02\x02\n\n\r\
+    \n\x05\x04\x19\x02\x05\x05\x12\x04\x8d\x02\x0b\x11\n\r\n\x05\x04\x19\x02\
+    \x05\x01\x12\x04\x8d\x02\x12\x1c\n\r\n\x05\x04\x19\x02\x05\x03\x12\x04\
+    \x8d\x02\x1f\x20\n\\\n\x02\x04\x1a\x12\x06\x91\x02\0\x94\x02\x01\x1aN\
+    \x20Creates\x20a\x20schedule,\x20or\x20replaces\x20the\x20schedule\x20fo\
+    r\x20the\x20same\x20package\x20and\x20target\n\n\x0b\n\x03\x04\x1a\x01\
+    \x12\x04\x91\x02\x08\x1e\n\x0c\n\x04\x04\x1a\x02\0\x12\x04\x92\x02\x02!\
+    \n\r\n\x05\x04\x1a\x02\0\x04\x12\x04\x92\x02\x02\n\n\r\n\x05\x04\x1a\x02\
+    \0\x06\x12\x04\x92\x02\x0b\x17\n\r\n\x05\x04\x1a\x02\0\x01\x12\x04\x92\
+    \x02\x18\x1c\n\r\n\x05\x04\x1a\x02\0\x03\x12\x04\x92\x02\x1f\x20\n\x0c\n\
+    \x04\x04\x1a\x02\x01\x12\x04\x93\x02\x02\x1b\n\r\n\x05\x04\x1a\x02\x01\
+    \x04\x12\x04\x93\x02\x02\n\n\r\n\x05\x04\x1a\x02\x01\x05\x12\x04\x93\x02\
+    \x0b\x11\n\r\n\x05\x04\x1a\x02\x01\x01\x12\x04\x93\x02\x12\x16\n\r\n\x05\
+    \x04\x1a\x02\x01\x03\x12\x04\x93\x02\x19\x1a\n\x0c\n\x02\x04\x1b\x12\x06\
+    \x96\x02\0\x98\x02\x01\n\x0b\n\x03\x04\x1b\x01\x12\x04\x96\x02\x08\x1f\n\
+    \x0c\n\x04\x04\x1b\x02\0\x12\x04\x97\x02\x02\x1d\n\r\n\x05\x04\x1b\x02\0\
+    \x04\x12\x04\x97\x02\x02\n\n\r\n\x05\x04\x1b\x02\0\x05\x12\x04\x97\x02\
+    \x0b\x11\n\r\n\x05\x04\x1b\x02\0\x01\x12\x04\x97\x02\x12\x18\n\r\n\x05\
+    \x04\x1b\x02\0\x03\x12\x04\x97\x02\x1b\x1c\n\x0c\n\x02\x04\x1c\x12\x06\
+    \x9a\x02\0\x9c\x02\x01\n\x0b\n\x03\x04\x1c\x01\x12\x04\x9a\x02\x08$\n\
+    \x0c\n\x04\x04\x1c\x02\0\x12\x04\x9b\x02\x02*\n\r\n\x05\x04\x1c\x02\0\
+    \x04\x12\x04\x9b\x02\x02\n\n\r\n\x05\x04\x1c\x02\0\x06\x12\x04\x9b\x02\
+    \x0b\x1b\n\r\n\x05\x04\x1c\x02\0\x01\x12\x04\x9b\x02\x1c%\n\r\n\x05\x04\
+    \x1c\x02\0\x03\x12\x04\x9b\x02()\n\x0c\n\x02\x04\x1d\x12\x06\x9e\x02\0\
+    \xa1\x02\x01\n\x0b\n\x03\x04\x1d\x01\x12\x04\x9e\x02\x08\x1e\n\x0c\n\x04\
+    \x04\x1d\x02\0\x12\x04\x9f\x02\x02\x19\n\r\n\x05\x04\x1d\x02\0\x04\x12\
+    \x04\x9f\x02\x02\n\n\r\n\x05\x04\x1d\x02\0\x05\x12\x04\x9f\x02\x0b\x11\n\
+    \r\n\x05\x04\x1d\x02\0\x01\x12\x04\x9f\x02\x12\x14\n\r\n\x05\x04\x1d\x02\
+    \0\x03\x12\x04\x9f\x02\x17\x18\n\x0c\n\x04\x04\x1d\x02\x01\x12\x04\xa0\
+    \x02\x02\x1d\n\r\n\x05\x04\x1d\x02\x01\x04\x12\x04\xa0\x02\x02\n\n\r\n\
+    \x05\x04\x1d\x02\x01\x05\x12\x04\xa0\x02\x0b\x11\n\r\n\x05\x04\x1d\x02\
+    \x01\x01\x12\x04\xa0\x02\x12\x18\n\r\n\x05\x04\x1d\x02\x01\x03\x12\x04\
+    \xa0\x02\x1b\x1c\n\x0c\n\x02\x05\x07\x12\x06\xa3\x02\0\xaa\x02\x01\n\x0b\
+    \n\x03\x05\x07\x01\x12\x04\xa3\x02\x05\x19\n\x0c\n\x04\x05\x07\x02\0\x12\
+    \x04\xa4\x02\x02\x11\n\r\n\x05\x05\x07\x02\0\x01\x12\x04\xa4\x02\x02\x0c\
+    \n\r\n\x05\x05\x07\x02\0\x02\x12\x04\xa4\x02\x0f\x10\n\x0c\n\x04\x05\x07\
+    \x02\x01\x12\x04\xa5\x02\x02\x11\n\r\n\x05\x05\x07\x02\x01\x01\x12\x04\
+    \xa5\x02\x02\x0c\n\r\n\x05\x05\x07\x02\x01\x02\x12\x04\xa5\x02\x0f\x10\n\
+    \x0c\n\x04\x05\x07\x02\x02\x12\x04\xa6\x02\x02\x0e\n\r\n\x05\x05\x07\x02\
+    \x02\x01\x12\x04\xa6\x02\x02\t\n\r\n\x05\x05\x07\x02\x02\x02\x12\x04\xa6\
+    \x02\x0c\r\n\x0c\n\x04\x05\x07\x02\x03\x12\x04\xa7\x02\x02\x0e\n\r\n\x05\
+    \x05\x07\x02\x03\x01\x12\x04\xa7\x02\x02\t\n\r\n\x05\x05\x07\x02\x03\x02\
+    \x12\x04\xa7\x02\x0c\r\n\x0c\n\x04\x05\x07\x02\x04\x12\x04\xa8\x02\x02\
+    \x0e\n\r\n\x05\x05\x07\x02\x04\x01\x12\x04\xa8\x02\x02\t\n\r\n\x05\x05\
+    \x07\x02\x04\x02\x12\x04\xa8\x02\x0c\r\n\x0c\n\x04\x05\x07\x02\x05\x12\
+    \x04\xa9\x02\x02\x0f\n\r\n\x05\x05\x07\x02\x05\x01\x12\x04\xa9\x02\x02\n\
+    \n\r\n\x05\x05\x07\x02\x05\x02\x12\x04\xa9\x02\r\x0e\n\x0c\n\x02\x04\x1e\
+    \x12\x06\xac\x02\0\xb5\x02\x01\n\x0b\n\x03\x04\x1e\x01\x12\x04\xac\x02\
+    \x08\x17\n\x0c\n\x04\x04\x1e\x02\0\x12\x04\xad\x02\x02\x1b\n\r\n\x05\x04\
+    \x1e\x02\0\x04\x12\x04\xad\x02\x02\n\n\r\n\x05\x04\x1e\x02\0\x05\x12\x04\
+    \xad\x02\x0b\x11\n\r\n\x05\x04\x1e\x02\0\x01\x12\x04\xad\x02\x12\x16\n\r\
+    \n\x05\x04\x1e\x02\0\x03\x12\x04\xad\x02\x19\x1a\n\x0c\n\x04\x04\x1e\x02\
+    \x01\x12\x04\xae\x02\x02\x1c\n\r\n\x05\x04\x1e\x02\x01\x04\x12\x04\xae\
+    \x02\x02\n\n\r\n\x05\x04\x1e\x02\x01\x05\x12\x04\xae\x02\x0b\x11\n\r\n\
+    \x05\x04\x1e\x02\x01\x01\x12\x04\xae\x02\x12\x17\n\r\n\x05\x04\x1e\x02\
+    \x01\x03\x12\x04\xae\x02\x1a\x1b\n\x0c\n\x04\x04\x1e\x02\x02\x12\x04\xaf\
+    \x02\x02*\n\r\n\x05\x04\x1e\x02\x02\x04\x12\x04\xaf\x02\x02\n\n\r\n\x05\
+    \x04\x1e\x02\x02\x06\x12\x04\xaf\x02\x0b\x1f\n\r\n\x05\x04\x1e\x02\x02\
+    \x01\x12\x04\xaf\x02\x20%\n\r\n\x05\x04\x1e\x02\x02\x03\x12\x04\xaf\x02(\
+    )\n\x0c\n\x04\x04\x1e\x02\x03\x12\x04\xb0\x02\x02\x1d\n\r\n\x05\x04\x1e\
+    \x02\x03\x04\x12\x04\xb0\x02\x02\n\n\r\n\x05\x04\x1e\x02\x03\x05\x12\x04\
+    \xb0\x02\x0b\x11\n\r\n\x05\x04\x1e\x02\x03\x01\x12\x04\xb0\x02\x12\x18\n\
+    \r\n\x05\x04\x1e\x02\x03\x03\x12\x04\xb0\x02\x1b\x1c\n\x0c\n\x04\x04\x1e\
+    \x02\x04\x12\x04\xb1\x02\x02\x1d\n\r\n\x05\x04\x1e\x02\x04\x04\x12\x04\
+    \xb1\x02\x02\n\n\r\n\x05\x04\x1e\x02\x04\x05\x12\x04\xb1\x02\x0b\x11\n\r\
+    \n\x05\x04\x1e\x02\x04\x01\x12\x04\xb1\x02\x12\x18\n\r\n\x05\x04\x1e\x02\
+    \x04\x03\x12\x04\xb1\x02\x1b\x1c\n&\n\x04\x04\x1e\x02\x05\x12\x04\xb2\
+    \x02\x02'\"\x18\x20RFC3339-formatted\x20time\n\n\r\n\x05\x04\x1e\x02\x05\
+    \x04\x12\x04\xb2\x02\x02\n\n\r\n\x05\x04\x1e\x02\x05\x05\x12\x04\xb2\x02\
+    \x0b\x11\n\r\n\x05\x04\x1e\x02\x05\x01\x12\x04\xb2\x02\x12\"\n\r\n\x05\
+    \x04\x1e\x02\x05\x03\x12\x04\xb2\x02%&\n\x0c\n\x04\x04\x1e\x02\x06\x12\
+    \x04\xb3\x02\x02(\n\r\n\x05\x04\x1e\x02\x06\x04\x12\x04\xb3\x02\x02\n\n\
+    \r\n\x05\x04\x1e\x02\x06\x05\x12\x04\xb3\x02\x0b\x11\n\r\n\x05\x04\x1e\
+    \x02\x06\x01\x12\x04\xb3\x02\x12#\n\r\n\x05\x04\x1e\x02\x06\x03\x12\x04\
+    \xb3\x02&'\nD\n\x04\x04\x1e\x02\x07\x12\x04\xb4\x02\x02\"\"6\x20Why\x20t\
+    he\x20project\x20was\x20skipped,\x20if\x20it\x20couldn't\x20be\x20built\
+    \n\n\r\n\x05\x04\x1e\x02\x07\x04\x12\x04\xb4\x02\x02\n\n\r\n\x05\x04\x1e\
+    \x02\x07\x05\x12\x04\xb4\x02\x0b\x11\n\r\n\x05\x04\x1e\x02\x07\x01\x12\
+    \x04\xb4\x02\x12\x1d\n\r\n\x05\x04\x1e\x02\x07\x03\x12\x04\xb4\x02\x20!\
+    \n\x0c\n\x02\x05\x08\x12\x06\xb7\x02\0\xbe\x02\x01\n\x0b\n\x03\x05\x08\
+    \x01\x12\x04\xb7\x02\x05\x12\n\x0c\n\x04\x05\x08\x02\0\x12\x04\xb8\x02\
+    \x02\x13\n\r\n\x05\x05\x08\x02\0\x01\x12\x04\xb8\x02\x02\x0e\n\r\n\x05\
+    \x05\x08\x02\0\x02\x12\x04\xb8\x02\x11\x12\n\x0c\n\x04\x05\x08\x02\x01\
+    \x12\x04\xb9\x02\x02\x17\n\r\n\x05\x05\x08\x02\x01\x01\x12\x04\xb9\x02\
+    \x02\x12\n\r\n\x05\x05\x08\x02\x01\x02\x12\x04\xb9\x02\x15\x16\n\x0c\n\
+    \x04\x05\x08\x02\x02\x12\x04\xba\x02\x02\x14\n\r\n\x05\x05\x08\x02\x02\
+    \x01\x12\x04\xba\x02\x02\x0f\n\r\n\x05\x05\x08\x02\x02\x02\x12\x04\xba\
+    \x02\x12\x13\n\x0c\n\x04\x05\x08\x02\x03\x12\x04\xbb\x02\x02\x12\n\r\n\
+    \x05\x05\x08\x02\x03\x01\x12\x04\xbb\x02\x02\r\n\r\n\x05\x05\x08\x02\x03\
+    \x02\x12\x04\xbb\x02\x10\x11\n\x0c\n\x04\x05\x08\x02\x04\x12\x04\xbc\x02\
+    \x02\x12\n\r\n\x05\x05\x08\x02\x04\x01\x12\x04\xbc\x02\x02\r\n\r\n\x05\
+    \x05\x08\x02\x04\x02\x12\x04\xbc\x02\x10\x11\n\x0c\n\x04\x05\x08\x02\x05\
+    \x12\x04\xbd\x02\x02\x14\n\r\n\x05\x05\x08\x02\x05\x01\x12\x04\xbd\x02\
+    \x02\x0f\n\r\n\x05\x05\x08\x02\x05\x02\x12\x04\xbd\x02\x12\x13\n\x0c\n\
+    \x02\x04\x1f\x12\x06\xc0\x02\0\xc2\x02\x01\n\x0b\n\x03\x04\x1f\x01\x12\
+    \x04\xc0\x02\x08\x15\n\x0c\n\x04\x04\x1f\x02\0\x12\x04\xc1\x02\x02\x1f\n\
+    \r\n\x05\x04\x1f\x02\0\x04\x12\x04\xc1\x02\x02\n\n\r\n\x05\x04\x1f\x02\0\
+    \x05\x12\x04\xc1\x02\x0b\x11\n\r\n\x05\x04\x1f\x02\0\x01\x12\x04\xc1\x02\
+    \x12\x1a\n\r\n\x05\x04\x1f\x02\0\x03\x12\x04\xc1\x02\x1d\x1e\n\x0c\n\x02\
+    \x04\x20\x12\x06\xc4\x02\0\xc8\x02\x01\n\x0b\n\x03\x04\x20\x01\x12\x04\
+    \xc4\x02\x08\x16\n\x0c\n\x04\x04\x20\x02\0\x12\x04\xc5\x02\x02\x1f\n\r\n\
+    \x05\x04\x20\x02\0\x04\x12\x04\xc5\x02\x02\n\n\r\n\x05\x04\x20\x02\0\x05\
+    \x12\x04\xc5\x02\x0b\x11\n\r\n\x05\x04\x20\x02\0\x01\x12\x04\xc5\x02\x12\
+    \x1a\n\r\n\x05\x04\x20\x02\0\x03\x12\x04\xc5\x02\x1d\x1e\n\x0c\n\x04\x04\
+    \x20\x02\x01\x12\x04\xc6\x02\x02#\n\r\n\x05\x04\x20\x02\x01\x04\x12\x04\
+    \xc6\x02\x02\n\n\r\n\x05\x04\x20\x02\x01\x05\x12\x04\xc6\x02\x0b\x11\n\r\
+    \n\x05\x04\x20\x02\x01\x01\x12\x04\xc6\x02\x12\x1e\n\r\n\x05\x04\x20\x02\
+    \x01\x03\x12\x04\xc6\x02!\"\n\x0c\n\x04\x04\x20\x02\x02\x12\x04\xc7\x02\
+    \x02%\n\r\n\x05\x04\x20\x02\x02\x04\x12\x04\xc7\x02\x02\n\n\r\n\x05\x04\
+    \x20\x02\x02\x05\x12\x04\xc7\x02\x0b\x11\n\r\n\x05\x04\x20\x02\x02\x01\
+    \x12\x04\xc7\x02\x12\x20\n\r\n\x05\x04\x20\x02\x02\x03\x12\x04\xc7\x02#$\
+    \n\x89\x01\n\x02\x04!\x12\x06\xcc\x02\0\xd0\x02\x01\x1a{\x20Give\x20the\
+    \x20failed\x20projects\x20of\x20a\x20finished\x20job\x20group,\x20along\
+    \x20with\x20the\x20projects\x20skipped\x20because\x20of\n\x20them,\x20an\
+    other\x20dispatch\x20pass\n\n\x0b\n\x03\x04!\x01\x12\x04\xcc\x02\x08\x15\
+    \n\x0c\n\x04\x04!\x02\0\x12\x04\xcd\x02\x02\x1f\n\r\n\x05\x04!\x02\0\x04\
+    \x12\x04\xcd\x02\x02\n\n\r\n\x05\x04!\x02\0\x05\x12\x04\xcd\x02\x0b\x11\
+    \n\r\n\x05\x04!\x02\0\x01\x12\x04\xcd\x02\x12\x1a\n\r\n\x05\x04!\x02\0\
+    \x03\x12\x04\xcd\x02\x1d\x1e\n\x0c\n\x04\x04!\x02\x01\x12\x04\xce\x02\
+    \x02#\n\r\n\x05\x04!\x02\x01\x04\x12\x04\xce\x02\x02\n\n\r\n\x05\x04!\
+    \x02\x01\x05\x12\x04\xce\x02\x0b\x11\n\r\n\x05\x04!\x02\x01\x01\x12\x04\
+    \xce\x02\x12\x1e\n\r\n\x05\x04!\x02\x01\x03\x12\x04\xce\x02!\"\n\x0c\n\
+    \x04\x04!\x02\x02\x12\x04\xcf\x02\x02%\n\r\n\x05\x04!\x02\x02\x04\x12\
+    \x04\xcf\x02\x02\n\n\r\n\x05\x04!\x02\x02\x05\x12\x04\xcf\x02\x0b\x11\n\
+    \r\n\x05\x04!\x02\x02\x01\x12\x04\xcf\x02\x12\x20\n\r\n\x05\x04!\x02\x02\
+    \x03\x12\x04\xcf\x02#$\n\x0c\n\x02\x04\"\x12\x06\xd2\x02\0\xd6\x02\x01\n\
+    \x0b\n\x03\x04\"\x01\x12\x04\xd2\x02\x08\x11\n\x0c\n\x04\x04\"\x02\0\x12\
+    \x04\xd3\x02\x02\x1d\n\r\n\x05\x04\"\x02\0\x04\x12\x04\xd3\x02\x02\n\n\r\
+    \n\x05\x04\"\x02\0\x05\x12\x04\xd3\x02\x0b\x11\n\r\n\x05\x04\"\x02\0\x01\
+    \x12\x04\xd3\x02\x12\x18\n\r\n\x05\x04\"\x02\0\x03\x12\x04\xd3\x02\x1b\
+    \x1c\n\x0c\n\x04\x04\"\x02\x01\x12\x04\xd4\x02\x02#\n\r\n\x05\x04\"\x02\
+    \x01\x04\x12\x04\xd4\x02\x02\n\n\r\n\x05\x04\"\x02\x01\x05\x12\x04\xd4\
+    \x02\x0b\x11\n\r\n\x05\x04\"\x02\x01\x01\x12\x04\xd4\x02\x12\x1e\n\r\n\
+    \x05\x04\"\x02\x01\x03\x12\x04\xd4\x02!\"\n\x0c\n\x04\x04\"\x02\x02\x12\
+    \x04\xd5\x02\x02%\n\r\n\x05\x04\"\x02\x02\x04\x12\x04\xd5\x02\x02\n\n\r\
+    \n\x05\x04\"\x02\x02\x05\x12\x04\xd5\x02\x0b\x11\n\r\n\x05\x04\"\x02\x02\
+    \x01\x12\x04\xd5\x02\x12\x20\n\r\n\x05\x04\"\x02\x02\x03\x12\x04\xd5\x02\
+    #$\n>\n\x02\x05\t\x12\x06\xd9\x02\0\xe1\x02\x01\x1a0\x20What\x20caused\
+    \x20a\x20job\x20or\x20job\x20group\x20to\x20change\x20state\n\n\x0b\n\
+    \x03\x05\t\x01\x12\x04\xd9\x02\x05\x14\n\x0c\n\x04\x05\t\x02\0\x12\x04\
+    \xda\x02\x02\x10\n\r\n\x05\x05\t\x02\0\x01\x12\x04\xda\x02\x02\x0b\n\r\n\
+    \x05\x05\t\x02\0\x02\x12\x04\xda\x02\x0e\x0f\n\x0c\n\x04\x05\t\x02\x01\
+    \x12\x04\xdb\x02\x02\r\n\r\n\x05\x05\t\x02\x01\x01\x12\x04\xdb\x02\x02\
+    \x08\n\r\n\x05\x05\t\x02\x01\x02\x12\x04\xdb\x02\x0b\x0c\n\x0c\n\x04\x05\
+    \t\x02\x02\x12\x04\xdc\x02\x02\x0b\n\r\n\x05\x05\t\x02\x02\x01\x12\x04\
+    \xdc\x02\x02\x06\n\r\n\x05\x05\t\x02\x02\x02\x12\x04\xdc\x02\t\n\n\x0c\n\
+    \x04\x05\t\x02\x03\x12\x04\xdd\x02\x02\x0e\n\r\n\x05\x05\t\x02\x03\x01\
+    \x12\x04\xdd\x02\x02\t\n\r\n\x05\x05\t\x02\x03\x02\x12\x04\xdd\x02\x0c\r\
+    \n\x0c\n\x04\x05\t\x02\x04\x12\x04\xde\x02\x02\x0f\n\r\n\x05\x05\t\x02\
+    \x04\x01\x12\x04\xde\x02\x02\n\n\r\n\x05\x05\t\x02\x04\x02\x12\x04\xde\
+    \x02\r\x0e\n\x0c\n\x04\x05\t\x02\x05\x12\x04\xdf\x02\x02\x0c\n\r\n\x05\
+    \x05\t\x02\x05\x01\x12\x04\xdf\x02\x02\x07\n\r\n\x05\x05\t\x02\x05\x02\
+    \x12\x04\xdf\x02\n\x0b\n\x0c\n\x04\x05\t\x02\x06\x12\x04\xe0\x02\x02\x11\
+    \n\r\n\x05\x05\t\x02\x06\x01\x12\x04\xe0\x02\x02\x0c\n\r\n\x05\x05\t\x02\
+    \x06\x02\x12\x04\xe0\x02\x0f\x10\nH\n\x02\x04#\x12\x06\xe4\x02\0\xef\x02\
+    \x01\x1a:\x20A\x20single\x20state\x20transition\x20of\x20either\x20a\x20\
+    job\x20or\x20a\x20job\x20group\n\n\x0b\n\x03\x04#\x01\x12\x04\xe4\x02\
+    \x08\x10\n\x0c\n\x04\x04#\x02\0\x12\x04\xe5\x02\x02\x19\n\r\n\x05\x04#\
+    \x02\0\x04\x12\x04\xe5\x02\x02\n\n\r\n\x05\x04#\x02\0\x05\x12\x04\xe5\
+    \x02\x0b\x11\n\r\n\x05\x04#\x02\0\x01\x12\x04\xe5\x02\x12\x14\n\r\n\x05\
+    \x04#\x02\0\x03\x12\x04\xe5\x02\x17\x18\n\x0c\n\x04\x04#\x02\x01\x12\x04\
+    \xe6\x02\x02\x1d\n\r\n\x05\x04#\x02\x01\x04\x12\x04\xe6\x02\x02\n\n\r\n\
+    \x05\x04#\x02\x01\x05\x12\x04\xe6\x02\x0b\x11\n\r\n\x05\x04#\x02\x01\x01\
+    \x12\x04\xe6\x02\x12\x18\n\r\n\x05\x04#\x02\x01\x03\x12\x04\xe6\x02\x1b\
+    \x1c\n\x0c\n\x04\x04#\x02\x02\x12\x04\xe7\x02\x02\x1f\n\r\n\x05\x04#\x02\
+    \x02\x04\x12\x04\xe7\x02\x02\n\n\r\n\x05\x04#\x02\x02\x05\x12\x04\xe7\
+    \x02\x0b\x11\n\r\n\x05\x04#\x02\x02\x01\x12\x04\xe7\x02\x12\x1a\n\r\n\
+    \x05\x04#\x02\x02\x03\x12\x04\xe7\x02\x1d\x1e\n)\n\x04\x04#\x02\x03\x12\
+    \x04\xe8\x02\x02!\"\x1b\x20Unset\x20for\x20the\x20first\x20state\n\n\r\n\
+    \x05\x04#\x02\x03\x04\x12\x04\xe8\x02\x02\n\n\r\n\x05\x04#\x02\x03\x05\
+    \x12\x04\xe8\x02\x0b\x11\n\r\n\x05\x04#\x02\x03\x01\x12\x04\xe8\x02\x12\
+    \x1c\n\r\n\x05\x04#\x02\x03\x03\x12\x04\xe8\x02\x1f\x20\n\x0c\n\x04\x04#\
+    \x02\x04\x12\x04\xe9\x02\x02\x1f\n\r\n\x05\x04#\x02\x04\x04\x12\x04\xe9\
+    \x02\x02\n\n\r\n\x05\x04#\x02\x04\x05\x12\x04\xe9\x02\x0b\x11\n\r\n\x05\
+    \x04#\x02\x04\x01\x12\x04\xe9\x02\x12\x1a\n\r\n\x05\x04#\x02\x04\x03\x12\
+    \x04\xe9\x02\x1d\x1e\n\x0c\n\x04\x04#\x02\x05\x12\x04\xea\x02\x02'\n\r\n\
+    \x05\x04#\x02\x05\x04\x12\x04\xea\x02\x02\n\n\r\n\x05\x04#\x02\x05\x06\
+    \x12\x04\xea\x02\x0b\x1a\n\r\n\x05\x04#\x02\x05\x01\x12\x04\xea\x02\x1b\
+    \"\n\r\n\x05\x04#\x02\x05\x03\x12\x04\xea\x02%&\n\x0c\n\x04\x04#\x02\x06\
+    \x12\x04\xeb\x02\x02#\n\r\n\x05\x04#\x02\x06\x04\x12\x04\xeb\x02\x02\n\n\
+    \r\n\x05\x04#\x02\x06\x05\x12\x04\xeb\x02\x0b\x11\n\r\n\x05\x04#\x02\x06\
+    \x01\x12\x04\xeb\x02\x12\x1e\n\r\n\x05\x04#\x02\x06\x03\x12\x04\xeb\x02!\
+    \"\n\x0c\n\x04\x04#\x02\x07\x12\x04\xec\x02\x02%\n\r\n\x05\x04#\x02\x07\
+    \x04\x12\x04\xec\x02\x02\n\n\r\n\x05\x04#\x02\x07\x05\x12\x04\xec\x02\
+    \x0b\x11\n\r\n\x05\x04#\x02\x07\x01\x12\x04\xec\x02\x12\x20\n\r\n\x05\
+    \x04#\x02\x07\x03\x12\x04\xec\x02#$\n\x0c\n\x04\x04#\x02\x08\x12\x04\xed\
+    \x02\x02\x1d\n\r\n\x05\x04#\x02\x08\x04\x12\x04\xed\x02\x02\n\n\r\n\x05\
+    \x04#\x02\x08\x05\x12\x04\xed\x02\x0b\x11\n\r\n\x05\x04#\x02\x08\x01\x12\
+    \x04\xed\x02\x12\x18\n\r\n\x05\x04#\x02\x08\x03\x12\x04\xed\x02\x1b\x1c\
+    \n&\n\x04\x04#\x02\t\x12\x04\xee\x02\x02\"\"\x18\x20RFC3339-formatted\
+    \x20time\n\n\r\n\x05\x04#\x02\t\x04\x12\x04\xee\x02\x02\n\n\r\n\x05\x04#\
+    \x02\t\x05\x12\x04\xee\x02\x0b\x11\n\r\n\x05\x04#\x02\t\x01\x12\x04\xee\
+    \x02\x12\x1c\n\r\n\x05\x04#\x02\t\x03\x12\x04\xee\x02\x1f!\na\n\x02\x04$\
+    \x12\x06\xf2\x02\0\xf5\x02\x01\x1aS\x20Get\x20the\x20audit\x20log\x20of\
+    \x20either\x20a\x20job\x20or\x20a\x20job\x20group,\x20including\x20the\
+    \x20jobs\x20in\x20the\x20group\n\n\x0b\n\x03\x04$\x01\x12\x04\xf2\x02\
+    \x08\x13\n\x0c\n\x04\x04$\x02\0\x12\x04\xf3\x02\x02\x1d\n\r\n\x05\x04$\
+    \x02\0\x04\x12\x04\xf3\x02\x02\n\n\r\n\x05\x04$\x02\0\x05\x12\x04\xf3\
+    \x02\x0b\x11\n\r\n\x05\x04$\x02\0\x01\x12\x04\xf3\x02\x12\x18\n\r\n\x05\
+    \x04$\x02\0\x03\x12\x04\xf3\x02\x1b\x1c\n\x0c\n\x04\x04$\x02\x01\x12\x04\
+    \xf4\x02\x02\x1f\n\r\n\x05\x04$\x02\x01\x04\x12\x04\xf4\x02\x02\n\n\r\n\
+    \x05\x04$\x02\x01\x05\x12\x04\xf4\x02\x0b\x11\n\r\n\x05\x04$\x02\x01\x01\
+    \x12\x04\xf4\x02\x12\x1a\n\r\n\x05\x04$\x02\x01\x03\x12\x04\xf4\x02\x1d\
+    \x1e\n\x0c\n\x02\x04%\x12\x06\xf7\x02\0\xf9\x02\x01\n\x0b\n\x03\x04%\x01\
+    \x12\x04\xf7\x02\x08\x18\n\x0c\n\x04\x04%\x02\0\x12\x04\xf8\x02\x02\x1f\
+    \n\r\n\x05\x04%\x02\0\x04\x12\x04\xf8\x02\x02\n\n\r\n\x05\x04%\x02\0\x06\
+    \x12\x04\xf8\x02\x0b\x13\n\r\n\x05\x04%\x02\0\x01\x12\x04\xf8\x02\x14\
+    \x1a\n\r\n\x05\x04%\x02\0\x03\x12\x04\xf8\x02\x1d\x1e\nt\n\x02\x04&\x12\
+    \x06\xfd\x02\0\x80\x03\x01\x1af\x20Delete\x20the\x20finished\x20jobs\x20\
+    and\x20job\x20groups,\x20along\x20with\x20their\x20logs,\x20created\x20b\
+    efore\x20the\x20retention\n\x20window\n\n\x0b\n\x03\x04&\x01\x12\x04\xfd\
+    \x02\x08\x17\n_\n\x04\x04&\x02\0\x12\x04\xff\x02\x02%\x1aQ\x20Days\x20of\
+    \x20history\x20to\x20keep,\x20the\x20job\x20server's\x20configured\x20re\
+    tention\x20is\x20used\x20if\x20unset\n\n\r\n\x05\x04&\x02\0\x04\x12\x04\
+    \xff\x02\x02\n\n\r\n\x05\x04&\x02\0\x05\x12\x04\xff\x02\x0b\x11\n\r\n\
+    \x05\x04&\x02\0\x01\x12\x04\xff\x02\x12\x20\n\r\n\x05\x04&\x02\0\x03\x12\
+    \x04\xff\x02#$\n\x0c\n\x02\x04'\x12\x06\x82\x03\0\x85\x03\x01\n\x0b\n\
+    \x03\x04'\x01\x12\x04\x82\x03\x08\x1f\n\x0c\n\x04\x04'\x02\0\x12\x04\x83\
+    \x03\x02%\n\r\n\x05\x04'\x02\0\x04\x12\x04\x83\x03\x02\n\n\r\n\x05\x04'\
+    \x02\0\x05\x12\x04\x83\x03\x0b\x11\n\r\n\x05\x04'\x02\0\x01\x12\x04\x83\
+    \x03\x12\x20\n\r\n\x05\x04'\x02\0\x03\x12\x04\x83\x03#$\n\x0c\n\x04\x04'\
+    \x02\x01\x12\x04\x84\x03\x02#\n\r\n\x05\x04'\x02\x01\x04\x12\x04\x84\x03\
+    \x02\n\n\r\n\x05\x04'\x02\x01\x05\x12\x04\x84\x03\x0b\x11\n\r\n\x05\x04'\
+    \x02\x01\x01\x12\x04\x84\x03\x12\x1e\n\r\n\x05\x04'\x02\x01\x03\x12\x04\
+    \x84\x03!\"\nN\n\x02\x04(\x12\x06\x88\x03\0\x8a\x03\x01\x1a@\x20Get\x20a\
+    n\x20origin's\x20use\x20of\x20the\x20build\x20workers\x20along\x20with\
+    \x20its\x20quotas\n\n\x0b\n\x03\x04(\x01\x12\x04\x88\x03\x08\x1b\n\x0c\n\
+    \x04\x04(\x02\0\x12\x04\x89\x03\x02\x1d\n\r\n\x05\x04(\x02\0\x04\x12\x04\
+    \x89\x03\x02\n\n\r\n\x05\x04(\x02\0\x05\x12\x04\x89\x03\x0b\x11\n\r\n\
+    \x05\x04(\x02\0\x01\x12\x04\x89\x03\x12\x18\n\r\n\x05\x04(\x02\0\x03\x12\
+    \x04\x89\x03\x1b\x1c\n(\n\x02\x04)\x12\x06\x8d\x03\0\x95\x03\x01\x1a\x1a\
+    \x20A\x20quota\x20of\x200\x20is\x20no\x20limit\n\n\x0b\n\x03\x04)\x01\
+    \x12\x04\x8d\x03\x08\x18\n\x0c\n\x04\x04)\x02\0\x12\x04\x8e\x03\x02\x1d\
+    \n\r\n\x05\x04)\x02\0\x04\x12\x04\x8e\x03\x02\n\n\r\n\x05\x04)\x02\0\x05\
+    \x12\x04\x8e\x03\x0b\x11\n\r\n\x05\x04)\x02\0\x01\x12\x04\x8e\x03\x12\
+    \x18\n\r\n\x05\x04)\x02\0\x03\x12\x04\x8e\x03\x1b\x1c\n=\n\x04\x04)\x02\
+    \x01\x12\x04\x90\x03\x02\x1e\x1a/\x20Workers\x20occupied\x20by\x20the\
+    \x20origin's\x20running\x20jobs\n\n\r\n\x05\x04)\x02\x01\x04\x12\x04\x90\
+    \x03\x02\n\n\r\n\x05\x04)\x02\x01\x05\x12\x04\x90\x03\x0b\x11\n\r\n\x05\
+    \x04)\x02\x01\x01\x12\x04\x90\x03\x12\x19\n\r\n\x05\x04)\x02\x01\x03\x12\
+    \x04\x90\x03\x1c\x1d\n\x0c\n\x04\x04)\x02\x02\x12\x04\x91\x03\x02\"\n\r\
+    \n\x05\x04)\x02\x02\x04\x12\x04\x91\x03\x02\n\n\r\n\x05\x04)\x02\x02\x05\
+    \x12\x04\x91\x03\x0b\x11\n\r\n\x05\x04)\x02\x02\x01\x12\x04\x91\x03\x12\
+    \x1d\n\r\n\x05\x04)\x02\x02\x03\x12\x04\x91\x03\x20!\n9\n\x04\x04)\x02\
+    \x03\x12\x04\x93\x03\x02&\x1a+\x20Builds\x20the\x20origin\x20started\x20\
+    in\x20the\x20last\x20day\n\n\r\n\x05\x04)\x02\x03\x04\x12\x04\x93\x03\
+    \x02\n\n\r\n\x05\x04)\x02\x03\x05\x12\x04\x93\x03\x0b\x11\n\r\n\x05\x04)\
+    \x02\x03\x01\x12\x04\x93\x03\x12!\n\r\n\x05\x04)\x02\x03\x03\x12\x04\x93\
+    \x03$%\n\x0c\n\x04\x04)\x02\x04\x12\x04\x94\x03\x02)\n\r\n\x05\x04)\x02\
+    \x04\x04\x12\x04\x94\x03\x02\n\n\r\n\x05\x04)\x02\x04\x05\x12\x04\x94\
+    \x03\x0b\x11\n\r\n\x05\x04)\x02\x04\x01\x12\x04\x94\x03\x12$\n\r\n\x05\
+    \x04)\x02\x04\x03\x12\x04\x94\x03'(\n\x0c\n\x02\x04*\x12\x06\x97\x03\0\
+    \x99\x03\x01\n\x0b\n\x03\x04*\x01\x12\x04\x97\x03\x08\x13\n\x0c\n\x04\
+    \x04*\x02\0\x12\x04\x98\x03\x02\x1f\n\r\n\x05\x04*\x02\0\x04\x12\x04\x98\
+    \x03\x02\n\n\r\n\x05\x04*\x02\0\x05\x12\x04\x98\x03\x0b\x11\n\r\n\x05\
+    \x04*\x02\0\x01\x12\x04\x98\x03\x12\x1a\n\r\n\x05\x04*\x02\0\x03\x12\x04\
+    \x98\x03\x1d\x1e\n\x0c\n\x02\x04+\x12\x06\x9b\x03\0\xa2\x03\x01\n\x0b\n\
+    \x03\x04+\x01\x12\x04\x9b\x03\x08\x19\n\x0c\n\x04\x04+\x02\0\x12\x04\x9c\
+    \x03\x02\x1d\n\r\n\x05\x04+\x02\0\x04\x12\x04\x9c\x03\x02\n\n\r\n\x05\
+    \x04+\x02\0\x05\x12\x04\x9c\x03\x0b\x11\n\r\n\x05\x04+\x02\0\x01\x12\x04\
+    \x9c\x03\x12\x18\n\r\n\x05\x04+\x02\0\x03\x12\x04\x9c\x03\x1b\x1c\n\x0c\
+    \n\x04\x04+\x02\x01\x12\x04\x9d\x03\x02\x1c\n\r\n\x05\x04+\x02\x01\x04\
+    \x12\x04\x9d\x03\x02\n\n\r\n\x05\x04+\x02\x01\x05\x12\x04\x9d\x03\x0b\
+    \x11\n\r\n\x05\x04+\x02\x01\x01\x12\x04\x9d\x03\x12\x17\n\r\n\x05\x04+\
+    \x02\x01\x03\x12\x04\x9d\x03\x1a\x1b\n\x0c\n\x04\x04+\x02\x02\x12\x04\
+    \x9e\x03\x02\x1b\n\r\n\x05\x04+\x02\x02\x04\x12\x04\x9e\x03\x02\n\n\r\n\
+    \x05\x04+\x02\x02\x05\x12\x04\x9e\x03\x0b\x11\n\r\n\x05\x04+\x02\x02\x01\
+    \x12\x04\x9e\x03\x12\x16\n\r\n\x05\x04+\x02\x02\x03\x12\x04\x9e\x03\x19\
+    \x1a\n0\n\x04\x04+\x02\x03\x12\x04\x9f\x03\x02#\"\"\x20Only\x20return\
+    \x20groups\x20in\x20this\x20state\n\n\r\n\x05\x04+\x02\x03\x04\x12\x04\
+    \x9f\x03\x02\n\n\r\n\x05\x04+\x02\x03\x06\x12\x04\x9f\x03\x0b\x18\n\r\n\
+    \x05\x04+\x02\x03\x01\x12\x04\x9f\x03\x19\x1e\n\r\n\x05\x04+\x02\x03\x03\
+    \x12\x04\x9f\x03!\"\n&\n\x04\x04+\x02\x04\x12\x04\xa0\x03\x02$\"\x18\x20\
+    RFC3339-formatted\x20time\n\n\r\n\x05\x04+\x02\x04\x04\x12\x04\xa0\x03\
+    \x02\n\n\r\n\x05\x04+\x02\x04\x05\x12\x04\xa0\x03\x0b\x11\n\r\n\x05\x04+\
+    \x02\x04\x01\x12\x04\xa0\x03\x12\x1f\n\r\n\x05\x04+\x02\x04\x03\x12\x04\
+    \xa0\x03\"#\n?\n\x04\x04+\x02\x05\x12\x04\xa1\x03\x02%\"1\x20RFC3339-for\
+    matted\x20time,\x20leaves\x20newer\x20groups\x20out\n\n\r\n\x05\x04+\x02\
+    \x05\x04\x12\x04\xa1\x03\x02\n\n\r\n\x05\x04+\x02\x05\x05\x12\x04\xa1\
+    \x03\x0b\x11\n\r\n\x05\x04+\x02\x05\x01\x12\x04\xa1\x03\x12\x20\n\r\n\
+    \x05\x04+\x02\x05\x03\x12\x04\xa1\x03#$\n\x0c\n\x02\x04,\x12\x06\xa4\x03\
+    \0\xa9\x03\x01\n\x0b\n\x03\x04,\x01\x12\x04\xa4\x03\x08\x1e\n\x0c\n\x04\
+    \x04,\x02\0\x12\x04\xa5\x03\x02#\n\r\n\x05\x04,\x02\0\x04\x12\x04\xa5\
+    \x03\x02\n\n\r\n\x05\x04,\x02\0\x06\x12\x04\xa5\x03\x0b\x13\n\r\n\x05\
+    \x04,\x02\0\x01\x12\x04\xa5\x03\x14\x1e\n\r\n\x05\x04,\x02\0\x03\x12\x04\
+    \xa5\x03!\"\n\x0c\n\x04\x04,\x02\x01\x12\x04\xa6\x03\x02\x1c\n\r\n\x05\
+    \x04,\x02\x01\x04\x12\x04\xa6\x03\x02\n\n\r\n\x05\x04,\x02\x01\x05\x12\
+    \x04\xa6\x03\x0b\x11\n\r\n\x05\x04,\x02\x01\x01\x12\x04\xa6\x03\x12\x17\
+    \n\r\n\x05\x04,\x02\x01\x03\x12\x04\xa6\x03\x1a\x1b\n\x0c\n\x04\x04,\x02\
+    \x02\x12\x04\xa7\x03\x02\x1b\n\r\n\x05\x04,\x02\x02\x04\x12\x04\xa7\x03\
+    \x02\n\n\r\n\x05\x04,\x02\x02\x05\x12\x04\xa7\x03\x0b\x11\n\r\n\x05\x04,\
+    \x02\x02\x01\x12\x04\xa7\x03\x12\x16\n\r\n\x05\x04,\x02\x02\x03\x12\x04\
+    \xa7\x03\x19\x1a\n\x0c\n\x04\x04,\x02\x03\x12\x04\xa8\x03\x02\x1c\n\r\n\
+    \x05\x04,\x02\x03\x04\x12\x04\xa8\x03\x02\n\n\r\n\x05\x04,\x02\x03\x05\
+    \x12\x04\xa8\x03\x0b\x11\n\r\n\x05\x04,\x02\x03\x01\x12\x04\xa8\x03\x12\
+    \x17\n\r\n\x05\x04,\x02\x03\x03\x12\x04\xa8\x03\x1a\x1b\n\x0c\n\x02\x04-\
+    \x12\x06\xab\x03\0\xb6\x03\x01\n\x0b\n\x03\x04-\x01\x12\x04\xab\x03\x08\
+    \x10\n\x0c\n\x04\x04-\x02\0\x12\x04\xac\x03\x02\x19\n\r\n\x05\x04-\x02\0\
+    \x04\x12\x04\xac\x03\x02\n\n\r\n\x05\x04-\x02\0\x05\x12\x04\xac\x03\x0b\
+    \x11\n\r\n\x05\x04-\x02\0\x01\x12\x04\xac\x03\x12\x14\n\r\n\x05\x04-\x02\
+    \0\x03\x12\x04\xac\x03\x17\x18\n\x0c\n\x04\x04-\x02\x01\x12\x04\xad\x03\
+    \x02#\n\r\n\x05\x04-\x02\x01\x04\x12\x04\xad\x03\x02\n\n\r\n\x05\x04-\
+    \x02\x01\x06\x12\x04\xad\x03\x0b\x18\n\r\n\x05\x04-\x02\x01\x01\x12\x04\
+    \xad\x03\x19\x1e\n\r\n\x05\x04-\x02\x01\x03\x12\x04\xad\x03!\"\n\x0c\n\
+    \x04\x04-\x02\x02\x12\x04\xae\x03\x02(\n\r\n\x05\x04-\x02\x02\x04\x12\
+    \x04\xae\x03\x02\n\n\r\n\x05\x04-\x02\x02\x06\x12\x04\xae\x03\x0b\x1a\n\
+    \r\n\x05\x04-\x02\x02\x01\x12\x04\xae\x03\x1b#\n\r\n\x05\x04-\x02\x02\
+    \x03\x12\x04\xae\x03&'\n\x0c\n\x04\x04-\x02\x03\x12\x04\xaf\x03\x02!\n\r\
+    \n\x05\x04-\x02\x03\x04\x12\x04\xaf\x03\x02\n\n\r\n\x05\x04-\x02\x03\x05\
+    \x12\x04\xaf\x03\x0b\x11\n\r\n\x05\x04-\x02\x03\x01\x12\x04\xaf\x03\x12\
+    \x1c\n\r\n\x05\x04-\x02\x03\x03\x12\x04\xaf\x03\x1f\x20\n\x0c\n\x04\x04-\
+    \x02\x04\x12\x04\xb0\x03\x02#\n\r\n\x05\x04-\x02\x04\x04\x12\x04\xb0\x03\
+    \x02\n\n\r\n\x05\x04-\x02\x04\x05\x12\x04\xb0\x03\x0b\x11\n\r\n\x05\x04-\
+    \x02\x04\x01\x12\x04\xb0\x03\x12\x1e\n\r\n\x05\x04-\x02\x04\x03\x12\x04\
+    \xb0\x03!\"\n\x0c\n\x04\x04-\x02\x05\x12\x04\xb1\x03\x02$\n\r\n\x05\x04-\
+    \x02\x05\x04\x12\x04\xb1\x03\x02\n\n\r\n\x05\x04-\x02\x05\x06\x12\x04\
+    \xb1\x03\x0b\x16\n\r\n\x05\x04-\x02\x05\x01\x12\x04\xb1\x03\x17\x1f\n\r\
+    \n\x05\x04-\x02\x05\x03\x12\x04\xb1\x03\"#\n\x0c\n\x04\x04-\x02\x06\x12\
+    \x04\xb2\x03\x02\x1d\n\r\n\x05\x04-\x02\x06\x04\x12\x04\xb2\x03\x02\n\n\
+    \r\n\x05\x04-\x02\x06\x05\x12\x04\xb2\x03\x0b\x11\n\r\n\x05\x04-\x02\x06\
+    \x01\x12\x04\xb2\x03\x12\x18\n\r\n\x05\x04-\x02\x06\x03\x12\x04\xb2\x03\
+    \x1b\x1c\n\x0c\n\x04\x04-\x02\x07\x12\x04\xb3\x03\x02\x1b\n\r\n\x05\x04-\
+    \x02\x07\x04\x12\x04\xb3\x03\x02\n\n\r\n\x05\x04-\x02\x07\x05\x12\x04\
+    \xb3\x03\x0b\x11\n\r\n\x05\x04-\x02\x07\x01\x12\x04\xb3\x03\x12\x16\n\r\
+    \n\x05\x04-\x02\x07\x03\x12\x04\xb3\x03\x19\x1a\nQ\n\x04\x04-\x02\x08\
+    \x12\x04\xb5\x03\x02%\x1aC\x20Position\x20of\x20a\x20queued\x20group\x20\
+    in\x20the\x20fair\x20share\x20queue,\x20starting\x20at\x201\n\n\r\n\x05\
+    \x04-\x02\x08\x04\x12\x04\xb5\x03\x02\n\n\r\n\x05\x04-\x02\x08\x05\x12\
+    \x04\xb5\x03\x0b\x11\n\r\n\x05\x04-\x02\x08\x01\x12\x04\xb5\x03\x12\x20\
+    \n\r\n\x05\x04-\x02\x08\x03\x12\x04\xb5\x03#$\n\x0c\n\x02\x04.\x12\x06\
+    \xb8\x03\0\xbe\x03\x01\n\x0b\n\x03\x04.\x01\x12\x04\xb8\x03\x08\x17\n\
+    \x0c\n\x04\x04.\x02\0\x12\x04\xb9\x03\x02\x1c\n\r\n\x05\x04.\x02\0\x04\
+    \x12\x04\xb9\x03\x02\n\n\r\n\x05\x04.\x02\0\x05\x12\x04\xb9\x03\x0b\x11\
+    \n\r\n\x05\x04.\x02\0\x01\x12\x04\xb9\x03\x12\x17\n\r\n\x05\x04.\x02\0\
+    \x03\x12\x04\xb9\x03\x1a\x1b\n\x0c\n\x04\x04.\x02\x01\x12\x04\xba\x03\
+    \x02\x1b\n\r\n\x05\x04.\x02\x01\x04\x12\x04\xba\x03\x02\n\n\r\n\x05\x04.\
+    \x02\x01\x05\x12\x04\xba\x03\x0b\x11\n\r\n\x05\x04.\x02\x01\x01\x12\x04\
+    \xba\x03\x12\x16\n\r\n\x05\x04.\x02\x01\x03\x12\x04\xba\x03\x19\x1a\n\
+    \x0c\n\x04\x04.\x02\x02\x12\x04\xbb\x03\x02\x1d\n\r\n\x05\x04.\x02\x02\
+    \x04\x12\x04\xbb\x03\x02\n\n\r\n\x05\x04.\x02\x02\x05\x12\x04\xbb\x03\
+    \x0b\x11\n\r\n\x05\x04.\x02\x02\x01\x12\x04\xbb\x03\x12\x18\n\r\n\x05\
+    \x04.\x02\x02\x03\x12\x04\xbb\x03\x1b\x1c\n[\n\x04\x04.\x02\x03\x12\x04\
+    \xbd\x03\x02\x1f\x1aM\x20Increases\x20every\x20time\x20a\x20package\x20i\
+    s\x20persisted,\x20used\x20to\x20catch\x20a\x20saved\x20graph\x20up\n\n\
+    \r\n\x05\x04.\x02\x03\x04\x12\x04\xbd\x03\x02\n\n\r\n\x05\x04.\x02\x03\
+    \x05\x12\x04\xbd\x03\x0b\x11\n\r\n\x05\x04.\x02\x03\x01\x12\x04\xbd\x03\
+    \x12\x1a\n\r\n\x05\x04.\x02\x03\x03\x12\x04\xbd\x03\x1d\x1e\n\x0c\n\x02\
+    \x04/\x12\x06\xc0\x03\0\xc4\x03\x01\n\x0b\n\x03\x04/\x01\x12\x04\xc0\x03\
+    \x08\x20\n\x0c\n\x04\x04/\x02\0\x12\x04\xc1\x03\x02\x1c\n\r\n\x05\x04/\
+    \x02\0\x04\x12\x04\xc1\x03\x02\n\n\r\n\x05\x04/\x02\0\x05\x12\x04\xc1\
+    \x03\x0b\x11\n\r\n\x05\x04/\x02\0\x01\x12\x04\xc1\x03\x12\x17\n\r\n\x05\
+    \x04/\x02\0\x03\x12\x04\xc1\x03\x1a\x1b\n\x0c\n\x04\x04/\x02\x01\x12\x04\
+    \xc2\x03\x02\x1b\n\r\n\x05\x04/\x02\x01\x04\x12\x04\xc2\x03\x02\n\n\r\n\
+    \x05\x04/\x02\x01\x05\x12\x04\xc2\x03\x0b\x11\n\r\n\x05\x04/\x02\x01\x01\
+    \x12\x04\xc2\x03\x12\x16\n\r\n\x05\x04/\x02\x01\x03\x12\x04\xc2\x03\x19\
+    \x1a\n\x0c\n\x04\x04/\x02\x02\x12\x04\xc3\x03\x02\x1d\n\r\n\x05\x04/\x02\
+    \x02\x04\x12\x04\xc3\x03\x02\n\n\r\n\x05\x04/\x02\x02\x05\x12\x04\xc3\
+    \x03\x0b\x11\n\r\n\x05\x04/\x02\x02\x01\x12\x04\xc3\x03\x12\x18\n\r\n\
+    \x05\x04/\x02\x02\x03\x12\x04\xc3\x03\x1b\x1c\n\x0c\n\x02\x040\x12\x06\
+    \xc6\x03\0\xca\x03\x01\n\x0b\n\x03\x040\x01\x12\x04\xc6\x03\x08\x1d\n\
+    \x0c\n\x04\x040\x02\0\x12\x04\xc7\x03\x02\x1c\n\r\n\x05\x040\x02\0\x04\
+    \x12\x04\xc7\x03\x02\n\n\r\n\x05\x040\x02\0\x05\x12\x04\xc7\x03\x0b\x11\
+    \n\r\n\x05\x040\x02\0\x01\x12\x04\xc7\x03\x12\x17\n\r\n\x05\x040\x02\0\
+    \x03\x12\x04\xc7\x03\x1a\x1b\n\x0c\n\x04\x040\x02\x01\x12\x04\xc8\x03\
+    \x02\x1b\n\r\n\x05\x040\x02\x01\x04\x12\x04\xc8\x03\x02\n\n\r\n\x05\x040\
+    \x02\x01\x05\x12\x04\xc8\x03\x0b\x11\n\r\n\x05\x040\x02\x01\x01\x12\x04\
+    \xc8\x03\x12\x16\n\r\n\x05\x040\x02\x01\x03\x12\x04\xc8\x03\x19\x1a\n\
+    \x0c\n\x04\x040\x02\x02\x12\x04\xc9\x03\x02\x1d\n\r\n\x05\x040\x02\x02\
+    \x04\x12\x04\xc9\x03\x02\n\n\r\n\x05\x040\x02\x02\x05\x12\x04\xc9\x03\
+    \x0b\x11\n\r\n\x05\x040\x02\x02\x01\x12\x04\xc9\x03\x12\x18\n\r\n\x05\
+    \x040\x02\x02\x03\x12\x04\xc9\x03\x1b\x1c\n\x0c\n\x02\x041\x12\x06\xcc\
+    \x03\0\xd0\x03\x01\n\x0b\n\x03\x041\x01\x12\x04\xcc\x03\x08-\n\x0c\n\x04\
+    \x041\x02\0\x12\x04\xcd\x03\x02\x1d\n\r\n\x05\x041\x02\0\x04\x12\x04\xcd\
+    \x03\x02\n\n\r\n\x05\x041\x02\0\x05\x12\x04\xcd\x03\x0b\x11\n\r\n\x05\
+    \x041\x02\0\x01\x12\x04\xcd\x03\x12\x18\n\r\n\x05\x041\x02\0\x03\x12\x04\
+    \xcd\x03\x1b\x1c\n\x0c\n\x04\x041\x02\x01\x12\x04\xce\x03\x02\x1b\n\r\n\
+    \x05\x041\x02\x01\x04\x12\x04\xce\x03\x02\n\n\r\n\x05\x041\x02\x01\x05\
+    \x12\x04\xce\x03\x0b\x11\n\r\n\x05\x041\x02\x01\x01\x12\x04\xce\x03\x12\
+    \x16\n\r\n\x05\x041\x02\x01\x03\x12\x04\xce\x03\x19\x1a\n\x0c\n\x04\x041\
+    \x02\x02\x12\x04\xcf\x03\x02\x1d\n\r\n\x05\x041\x02\x02\x04\x12\x04\xcf\
+    \x03\x02\n\n\r\n\x05\x041\x02\x02\x05\x12\x04\xcf\x03\x0b\x11\n\r\n\x05\
+    \x041\x02\x02\x01\x12\x04\xcf\x03\x12\x18\n\r\n\x05\x041\x02\x02\x03\x12\
+    \x04\xcf\x03\x1b\x1c\n\x0c\n\x02\x042\x12\x06\xd2\x03\0\xd6\x03\x01\n\
+    \x0b\n\x03\x042\x01\x12\x04\xd2\x03\x08*\n\x0c\n\x04\x042\x02\0\x12\x04\
+    \xd3\x03\x02\x1d\n\r\n\x05\x042\x02\0\x04\x12\x04\xd3\x03\x02\n\n\r\n\
+    \x05\x042\x02\0\x05\x12\x04\xd3\x03\x0b\x11\n\r\n\x05\x042\x02\0\x01\x12\
+    \x04\xd3\x03\x12\x18\n\r\n\x05\x042\x02\0\x03\x12\x04\xd3\x03\x1b\x1c\n\
+    \x0c\n\x04\x042\x02\x01\x12\x04\xd4\x03\x02\x1b\n\r\n\x05\x042\x02\x01\
+    \x04\x12\x04\xd4\x03\x02\n\n\r\n\x05\x042\x02\x01\x05\x12\x04\xd4\x03\
+    \x0b\x11\n\r\n\x05\x042\x02\x01\x01\x12\x04\xd4\x03\x12\x16\n\r\n\x05\
+    \x042\x02\x01\x03\x12\x04\xd4\x03\x19\x1a\n\x0c\n\x04\x042\x02\x02\x12\
+    \x04\xd5\x03\x02\x1c\n\r\n\x05\x042\x02\x02\x04\x12\x04\xd5\x03\x02\n\n\
+    \r\n\x05\x042\x02\x02\x05\x12\x04\xd5\x03\x0b\x11\n\r\n\x05\x042\x02\x02\
+    \x01\x12\x04\xd5\x03\x12\x17\n\r\n\x05\x042\x02\x02\x03\x12\x04\xd5\x03\
+    \x1a\x1b\n\x0c\n\x02\x043\x12\x06\xd8\x03\0\xda\x03\x01\n\x0b\n\x03\x043\
+    \x01\x12\x04\xd8\x03\x08\x1f\n\x0c\n\x04\x043\x02\0\x12\x04\xd9\x03\x02\
+    \x1d\n\r\n\x05\x043\x02\0\x04\x12\x04\xd9\x03\x02\n\n\r\n\x05\x043\x02\0\
+    \x05\x12\x04\xd9\x03\x0b\x11\n\r\n\x05\x043\x02\0\x01\x12\x04\xd9\x03\
+    \x12\x18\n\r\n\x05\x043\x02\0\x03\x12\x04\xd9\x03\x1b\x1c\n\x0c\n\x02\
+    \x044\x12\x06\xdc\x03\0\xe1\x03\x01\n\x0b\n\x03\x044\x01\x12\x04\xdc\x03\
+    \x08\x1c\n\x0c\n\x04\x044\x02\0\x12\x04\xdd\x03\x02\x1c\n\r\n\x05\x044\
+    \x02\0\x04\x12\x04\xdd\x03\x02\n\n\r\n\x05\x044\x02\0\x05\x12\x04\xdd\
+    \x03\x0b\x11\n\r\n\x05\x044\x02\0\x01\x12\x04\xdd\x03\x12\x17\n\r\n\x05\
+    \x044\x02\0\x03\x12\x04\xdd\x03\x1a\x1b\n\x0c\n\x04\x044\x02\x01\x12\x04\
+    \xde\x03\x02\x1d\n\r\n\x05\x044\x02\x01\x04\x12\x04\xde\x03\x02\n\n\r\n\
+    \x05\x044\x02\x01\x05\x12\x04\xde\x03\x0b\x11\n\r\n\x05\x044\x02\x01\x01\
+    \x12\x04\xde\x03\x12\x18\n\r\n\x05\x044\x02\x01\x03\x12\x04\xde\x03\x1b\
+    \x1c\n\x0c\n\x04\x044\x02\x02\x12\x04\xdf\x03\x02&\n\r\n\x05\x044\x02\
+    \x02\x04\x12\x04\xdf\x03\x02\n\n\r\n\x05\x044\x02\x02\x05\x12\x04\xdf\
+    \x03\x0b\x11\n\r\n\x05\x044\x02\x02\x01\x12\x04\xdf\x03\x12!\n\r\n\x05\
+    \x044\x02\x02\x03\x12\x04\xdf\x03$%\n\x0c\n\x04\x044\x02\x03\x12\x04\xe0\
+    \x03\x022\n\r\n\x05\x044\x02\x03\x04\x12\x04\xe0\x03\x02\n\n\r\n\x05\x04\
+    4\x02\x03\x06\x12\x04\xe0\x03\x0b%\n\r\n\x05\x044\x02\x03\x01\x12\x04\
+    \xe0\x03&-\n\r\n\x05\x044\x02\x03\x03\x12\x04\xe0\x0301\n\x0c\n\x02\x045\
+    \x12\x06\xe3\x03\0\xe8\x03\x01\n\x0b\n\x03\x045\x01\x12\x04\xe3\x03\x08\
+    \"\n\x0c\n\x04\x045\x02\0\x12\x04\xe4\x03\x02\x1d\n\r\n\x05\x045\x02\0\
+    \x04\x12\x04\xe4\x03\x02\n\n\r\n\x05\x045\x02\0\x05\x12\x04\xe4\x03\x0b\
+    \x11\n\r\n\x05\x045\x02\0\x01\x12\x04\xe4\x03\x12\x18\n\r\n\x05\x045\x02\
+    \0\x03\x12\x04\xe4\x03\x1b\x1c\n\x0c\n\x04\x045\x02\x01\x12\x04\xe5\x03\
+    \x02\x1c\n\r\n\x05\x045\x02\x01\x04\x12\x04\xe5\x03\x02\n\n\r\n\x05\x045\
+    \x02\x01\x05\x12\x04\xe5\x03\x0b\x11\n\r\n\x05\x045\x02\x01\x01\x12\x04\
+    \xe5\x03\x12\x17\n\r\n\x05\x045\x02\x01\x03\x12\x04\xe5\x03\x1a\x1b\n\
+    \x0c\n\x04\x045\x02\x02\x12\x04\xe6\x03\x02\x1d\n\r\n\x05\x045\x02\x02\
+    \x04\x12\x04\xe6\x03\x02\n\n\r\n\x05\x045\x02\x02\x05\x12\x04\xe6\x03\
+    \x0b\x11\n\r\n\x05\x045\x02\x02\x01\x12\x04\xe6\x03\x12\x18\n\r\n\x05\
+    \x045\x02\x02\x03\x12\x04\xe6\x03\x1b\x1c\n\x0c\n\x04\x045\x02\x03\x12\
+    \x04\xe7\x03\x02&\n\r\n\x05\x045\x02\x03\x04\x12\x04\xe7\x03\x02\n\n\r\n\
+    \x05\x045\x02\x03\x05\x12\x04\xe7\x03\x0b\x11\n\r\n\x05\x045\x02\x03\x01\
+    \x12\x04\xe7\x03\x12!\n\r\n\x05\x045\x02\x03\x03\x12\x04\xe7\x03$%\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
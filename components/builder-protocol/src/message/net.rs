@@ -1173,6 +1173,7 @@ pub enum ErrCode {
     BAD_TOKEN = 12,
     REMOTE_UNAVAILABLE = 13,
     SYS = 14,
+    RATE_LIMITED = 15,
     WORKSPACE_SETUP = 1000,
     SECRET_KEY_FETCH = 1001,
     SECRET_KEY_IMPORT = 1002,
@@ -1180,8 +1181,14 @@ pub enum ErrCode {
     BUILD = 1004,
     POST_PROCESSOR = 1005,
     INVALID_INTEGRATIONS = 1006,
+    BUILD_TIMEOUT = 1007,
+    BUILD_OFFLINE = 1008,
+    LOW_DISK_SPACE = 1009,
+    DOCKER_EXPORT = 1010,
+    BUILD_OOM = 1011,
     REG_CONFLICT = 2000,
     REG_NOT_FOUND = 2001,
+    REG_INCOMPATIBLE_VERSION = 2002,
     GROUP_NOT_COMPLETE = 3000,
     PARTIAL_JOB_GROUP_PROMOTE = 3001,
 }
@@ -1207,6 +1214,7 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             12 => ::std::option::Option::Some(ErrCode::BAD_TOKEN),
             13 => ::std::option::Option::Some(ErrCode::REMOTE_UNAVAILABLE),
             14 => ::std::option::Option::Some(ErrCode::SYS),
+            15 => ::std::option::Option::Some(ErrCode::RATE_LIMITED),
             1000 => ::std::option::Option::Some(ErrCode::WORKSPACE_SETUP),
             1001 => ::std::option::Option::Some(ErrCode::SECRET_KEY_FETCH),
             1002 => ::std::option::Option::Some(ErrCode::SECRET_KEY_IMPORT),
@@ -1214,8 +1222,14 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             1004 => ::std::option::Option::Some(ErrCode::BUILD),
             1005 => ::std::option::Option::Some(ErrCode::POST_PROCESSOR),
             1006 => ::std::option::Option::Some(ErrCode::INVALID_INTEGRATIONS),
+            1007 => ::std::option::Option::Some(ErrCode::BUILD_TIMEOUT),
+            1008 => ::std::option::Option::Some(ErrCode::BUILD_OFFLINE),
+            1009 => ::std::option::Option::Some(ErrCode::LOW_DISK_SPACE),
+            1010 => ::std::option::Option::Some(ErrCode::DOCKER_EXPORT),
+            1011 => ::std::option::Option::Some(ErrCode::BUILD_OOM),
             2000 => ::std::option::Option::Some(ErrCode::REG_CONFLICT),
             2001 => ::std::option::Option::Some(ErrCode::REG_NOT_FOUND),
+            2002 => ::std::option::Option::Some(ErrCode::REG_INCOMPATIBLE_VERSION),
             3000 => ::std::option::Option::Some(ErrCode::GROUP_NOT_COMPLETE),
             3001 => ::std::option::Option::Some(ErrCode::PARTIAL_JOB_GROUP_PROMOTE),
             _ => ::std::option::Option::None
@@ -1238,6 +1252,7 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             ErrCode::BAD_TOKEN,
             ErrCode::REMOTE_UNAVAILABLE,
             ErrCode::SYS,
+            ErrCode::RATE_LIMITED,
             ErrCode::WORKSPACE_SETUP,
             ErrCode::SECRET_KEY_FETCH,
             ErrCode::SECRET_KEY_IMPORT,
@@ -1245,8 +1260,14 @@ impl ::protobuf::ProtobufEnum for ErrCode {
             ErrCode::BUILD,
             ErrCode::POST_PROCESSOR,
             ErrCode::INVALID_INTEGRATIONS,
+            ErrCode::BUILD_TIMEOUT,
+            ErrCode::BUILD_OFFLINE,
+            ErrCode::LOW_DISK_SPACE,
+            ErrCode::DOCKER_EXPORT,
+            ErrCode::BUILD_OOM,
             ErrCode::REG_CONFLICT,
             ErrCode::REG_NOT_FOUND,
+            ErrCode::REG_INCOMPATIBLE_VERSION,
             ErrCode::GROUP_NOT_COMPLETE,
             ErrCode::PARTIAL_JOB_GROUP_PROMOTE,
         ];
@@ -1286,121 +1307,139 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     \x01(\x0e2\x0c.net.ErrCodeR\x04code\x12\x10\n\x03msg\x18\x02\x20\x01(\tR\
     \x03msg\"\x07\n\x05NetOk*L\n\x08Protocol\x12\x07\n\x03Net\x10\0\x12\x0c\
     \n\x08RouteSrv\x10\x01\x12\x0e\n\nSessionSrv\x10\x02\x12\r\n\tOriginSrv\
-    \x10\x03\x12\n\n\x06JobSrv\x10\x04*\xe6\x03\n\x07ErrCode\x12\x07\n\x03BU\
+    \x10\x03\x12\n\n\x06JobSrv\x10\x04*\xf8\x04\n\x07ErrCode\x12\x07\n\x03BU\
     G\x10\0\x12\x0b\n\x07TIMEOUT\x10\x01\x12\x13\n\x0fREMOTE_REJECTED\x10\
     \x02\x12\x14\n\x10BAD_REMOTE_REPLY\x10\x03\x12\x14\n\x10ENTITY_NOT_FOUND\
     \x10\x04\x12\x0c\n\x08NO_SHARD\x10\x06\x12\x11\n\rACCESS_DENIED\x10\x07\
     \x12\x13\n\x0fSESSION_EXPIRED\x10\x08\x12\x13\n\x0fENTITY_CONFLICT\x10\t\
     \x12\x08\n\x04SOCK\x10\n\x12\x0e\n\nDATA_STORE\x10\x0b\x12\r\n\tBAD_TOKE\
     N\x10\x0c\x12\x16\n\x12REMOTE_UNAVAILABLE\x10\r\x12\x07\n\x03SYS\x10\x0e\
-    \x12\x14\n\x0fWORKSPACE_SETUP\x10\xe8\x07\x12\x15\n\x10SECRET_KEY_FETCH\
-    \x10\xe9\x07\x12\x16\n\x11SECRET_KEY_IMPORT\x10\xea\x07\x12\x0e\n\tVCS_C\
-    LONE\x10\xeb\x07\x12\n\n\x05BUILD\x10\xec\x07\x12\x13\n\x0ePOST_PROCESSO\
-    R\x10\xed\x07\x12\x19\n\x14INVALID_INTEGRATIONS\x10\xee\x07\x12\x11\n\
+    \x12\x10\n\x0cRATE_LIMITED\x10\x0f\x12\x14\n\x0fWORKSPACE_SETUP\x10\xe8\
+    \x07\x12\x15\n\x10SECRET_KEY_FETCH\x10\xe9\x07\x12\x16\n\x11SECRET_KEY_I\
+    MPORT\x10\xea\x07\x12\x0e\n\tVCS_CLONE\x10\xeb\x07\x12\n\n\x05BUILD\x10\
+    \xec\x07\x12\x13\n\x0ePOST_PROCESSOR\x10\xed\x07\x12\x19\n\x14INVALID_IN\
+    TEGRATIONS\x10\xee\x07\x12\x12\n\rBUILD_TIMEOUT\x10\xef\x07\x12\x12\n\rB\
+    UILD_OFFLINE\x10\xf0\x07\x12\x13\n\x0eLOW_DISK_SPACE\x10\xf1\x07\x12\x12\
+    \n\rDOCKER_EXPORT\x10\xf2\x07\x12\x0e\n\tBUILD_OOM\x10\xf3\x07\x12\x11\n\
     \x0cREG_CONFLICT\x10\xd0\x0f\x12\x12\n\rREG_NOT_FOUND\x10\xd1\x0f\x12\
-    \x17\n\x12GROUP_NOT_COMPLETE\x10\xb8\x17\x12\x1e\n\x19PARTIAL_JOB_GROUP_\
-    PROMOTE\x10\xb9\x17J\xac\x10\n\x06\x12\x04\0\0C\x10\n\x08\n\x01\x0c\x12\
-    \x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\x08\x0b\n\n\n\x02\x05\0\x12\x04\
-    \x03\0\t\x01\n\n\n\x03\x05\0\x01\x12\x03\x03\x05\r\n\x0b\n\x04\x05\0\x02\
-    \0\x12\x03\x04\x02\n\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x04\x02\x05\n\
-    \x0c\n\x05\x05\0\x02\0\x02\x12\x03\x04\x08\t\n\x0b\n\x04\x05\0\x02\x01\
-    \x12\x03\x05\x02\x0f\n\x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x05\x02\n\n\
-    \x0c\n\x05\x05\0\x02\x01\x02\x12\x03\x05\r\x0e\n\x0b\n\x04\x05\0\x02\x02\
-    \x12\x03\x06\x02\x11\n\x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x06\x02\x0c\n\
-    \x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x06\x0f\x10\n\x0b\n\x04\x05\0\x02\
-    \x03\x12\x03\x07\x02\x10\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x07\x02\
-    \x0b\n\x0c\n\x05\x05\0\x02\x03\x02\x12\x03\x07\x0e\x0f\n\x0b\n\x04\x05\0\
-    \x02\x04\x12\x03\x08\x02\r\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x08\x02\
-    \x08\n\x0c\n\x05\x05\0\x02\x04\x02\x12\x03\x08\x0b\x0c\n\n\n\x02\x05\x01\
-    \x12\x04\x0b\0,\x01\n\n\n\x03\x05\x01\x01\x12\x03\x0b\x05\x0c\n\x16\n\
-    \x04\x05\x01\x02\0\x12\x03\r\x02\n\x1a\t\x20Generic\n\n\x0c\n\x05\x05\
-    \x01\x02\0\x01\x12\x03\r\x02\x05\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\r\
-    \x08\t\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\x0e\x02\x0e\n\x0c\n\x05\x05\
-    \x01\x02\x01\x01\x12\x03\x0e\x02\t\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\
-    \x03\x0e\x0c\r\n\x0b\n\x04\x05\x01\x02\x02\x12\x03\x0f\x02\x16\n\x0c\n\
-    \x05\x05\x01\x02\x02\x01\x12\x03\x0f\x02\x11\n\x0c\n\x05\x05\x01\x02\x02\
-    \x02\x12\x03\x0f\x14\x15\n\x0b\n\x04\x05\x01\x02\x03\x12\x03\x10\x02\x17\
-    \n\x0c\n\x05\x05\x01\x02\x03\x01\x12\x03\x10\x02\x12\n\x0c\n\x05\x05\x01\
-    \x02\x03\x02\x12\x03\x10\x15\x16\n\x0b\n\x04\x05\x01\x02\x04\x12\x03\x11\
-    \x02\x17\n\x0c\n\x05\x05\x01\x02\x04\x01\x12\x03\x11\x02\x12\n\x0c\n\x05\
-    \x05\x01\x02\x04\x02\x12\x03\x11\x15\x16\n\x0b\n\x04\x05\x01\x02\x05\x12\
-    \x03\x12\x02\x0f\n\x0c\n\x05\x05\x01\x02\x05\x01\x12\x03\x12\x02\n\n\x0c\
-    \n\x05\x05\x01\x02\x05\x02\x12\x03\x12\r\x0e\n\x0b\n\x04\x05\x01\x02\x06\
-    \x12\x03\x13\x02\x14\n\x0c\n\x05\x05\x01\x02\x06\x01\x12\x03\x13\x02\x0f\
-    \n\x0c\n\x05\x05\x01\x02\x06\x02\x12\x03\x13\x12\x13\n\x0b\n\x04\x05\x01\
-    \x02\x07\x12\x03\x14\x02\x16\n\x0c\n\x05\x05\x01\x02\x07\x01\x12\x03\x14\
-    \x02\x11\n\x0c\n\x05\x05\x01\x02\x07\x02\x12\x03\x14\x14\x15\n\x0b\n\x04\
-    \x05\x01\x02\x08\x12\x03\x15\x02\x16\n\x0c\n\x05\x05\x01\x02\x08\x01\x12\
-    \x03\x15\x02\x11\n\x0c\n\x05\x05\x01\x02\x08\x02\x12\x03\x15\x14\x15\n\
-    \x0b\n\x04\x05\x01\x02\t\x12\x03\x16\x02\x0c\n\x0c\n\x05\x05\x01\x02\t\
-    \x01\x12\x03\x16\x02\x06\n\x0c\n\x05\x05\x01\x02\t\x02\x12\x03\x16\t\x0b\
-    \n\x0b\n\x04\x05\x01\x02\n\x12\x03\x17\x02\x12\n\x0c\n\x05\x05\x01\x02\n\
-    \x01\x12\x03\x17\x02\x0c\n\x0c\n\x05\x05\x01\x02\n\x02\x12\x03\x17\x0f\
-    \x11\n\x0b\n\x04\x05\x01\x02\x0b\x12\x03\x18\x02\x11\n\x0c\n\x05\x05\x01\
-    \x02\x0b\x01\x12\x03\x18\x02\x0b\n\x0c\n\x05\x05\x01\x02\x0b\x02\x12\x03\
-    \x18\x0e\x10\n\x0b\n\x04\x05\x01\x02\x0c\x12\x03\x19\x02\x1a\n\x0c\n\x05\
-    \x05\x01\x02\x0c\x01\x12\x03\x19\x02\x14\n\x0c\n\x05\x05\x01\x02\x0c\x02\
-    \x12\x03\x19\x17\x19\n\x0b\n\x04\x05\x01\x02\r\x12\x03\x1a\x02\x0b\n\x0c\
-    \n\x05\x05\x01\x02\r\x01\x12\x03\x1a\x02\x05\n\x0c\n\x05\x05\x01\x02\r\
-    \x02\x12\x03\x1a\x08\n\n\x15\n\x04\x05\x01\x02\x0e\x12\x03\x1d\x02\x19\
-    \x1a\x08\x20Worker\n\n\x0c\n\x05\x05\x01\x02\x0e\x01\x12\x03\x1d\x02\x11\
-    \n\x0c\n\x05\x05\x01\x02\x0e\x02\x12\x03\x1d\x14\x18\n\x0b\n\x04\x05\x01\
-    \x02\x0f\x12\x03\x1e\x02\x1a\n\x0c\n\x05\x05\x01\x02\x0f\x01\x12\x03\x1e\
-    \x02\x12\n\x0c\n\x05\x05\x01\x02\x0f\x02\x12\x03\x1e\x15\x19\n\x0b\n\x04\
-    \x05\x01\x02\x10\x12\x03\x1f\x02\x1b\n\x0c\n\x05\x05\x01\x02\x10\x01\x12\
-    \x03\x1f\x02\x13\n\x0c\n\x05\x05\x01\x02\x10\x02\x12\x03\x1f\x16\x1a\n\
-    \x0b\n\x04\x05\x01\x02\x11\x12\x03\x20\x02\x13\n\x0c\n\x05\x05\x01\x02\
-    \x11\x01\x12\x03\x20\x02\x0b\n\x0c\n\x05\x05\x01\x02\x11\x02\x12\x03\x20\
-    \x0e\x12\n\x0b\n\x04\x05\x01\x02\x12\x12\x03!\x02\x0f\n\x0c\n\x05\x05\
-    \x01\x02\x12\x01\x12\x03!\x02\x07\n\x0c\n\x05\x05\x01\x02\x12\x02\x12\
-    \x03!\n\x0e\n\x0b\n\x04\x05\x01\x02\x13\x12\x03\"\x02\x18\n\x0c\n\x05\
-    \x05\x01\x02\x13\x01\x12\x03\"\x02\x10\n\x0c\n\x05\x05\x01\x02\x13\x02\
-    \x12\x03\"\x13\x17\n\x0b\n\x04\x05\x01\x02\x14\x12\x03#\x02\x1e\n\x0c\n\
-    \x05\x05\x01\x02\x14\x01\x12\x03#\x02\x16\n\x0c\n\x05\x05\x01\x02\x14\
-    \x02\x12\x03#\x19\x1d\n\x17\n\x04\x05\x01\x02\x15\x12\x03&\x02\x16\x1a\n\
-    \x20RouteSrv\n\n\x0c\n\x05\x05\x01\x02\x15\x01\x12\x03&\x02\x0e\n\x0c\n\
-    \x05\x05\x01\x02\x15\x02\x12\x03&\x11\x15\n\x0b\n\x04\x05\x01\x02\x16\
-    \x12\x03'\x02\x17\n\x0c\n\x05\x05\x01\x02\x16\x01\x12\x03'\x02\x0f\n\x0c\
-    \n\x05\x05\x01\x02\x16\x02\x12\x03'\x12\x16\n\x18\n\x04\x05\x01\x02\x17\
-    \x12\x03*\x02\x1c\x1a\x0b\x20Scheduler\n\n\x0c\n\x05\x05\x01\x02\x17\x01\
-    \x12\x03*\x02\x14\n\x0c\n\x05\x05\x01\x02\x17\x02\x12\x03*\x17\x1b\n\x0b\
-    \n\x04\x05\x01\x02\x18\x12\x03+\x02#\n\x0c\n\x05\x05\x01\x02\x18\x01\x12\
-    \x03+\x02\x1b\n\x0c\n\x05\x05\x01\x02\x18\x02\x12\x03+\x1e\"\n\n\n\x02\
-    \x04\0\x12\x04.\02\x01\n\n\n\x03\x04\0\x01\x12\x03.\x08\x0e\n\x0b\n\x04\
-    \x04\0\x02\0\x12\x03/\x02!\n\x0c\n\x05\x04\0\x02\0\x04\x12\x03/\x02\n\n\
-    \x0c\n\x05\x04\0\x02\0\x05\x12\x03/\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\
-    \x12\x03/\x12\x1c\n\x0c\n\x05\x04\0\x02\0\x03\x12\x03/\x1f\x20\n\x0b\n\
-    \x04\x04\0\x02\x01\x12\x030\x02\x1f\n\x0c\n\x05\x04\0\x02\x01\x04\x12\
-    \x030\x02\n\n\x0c\n\x05\x04\0\x02\x01\x05\x12\x030\x0b\x0f\n\x0c\n\x05\
-    \x04\0\x02\x01\x01\x12\x030\x10\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\
-    \x030\x1d\x1e\n\x0b\n\x04\x04\0\x02\x02\x12\x031\x02\x18\n\x0c\n\x05\x04\
-    \0\x02\x02\x04\x12\x031\x02\n\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x031\x0b\
-    \x0f\n\x0c\n\x05\x04\0\x02\x02\x01\x12\x031\x10\x13\n\x0c\n\x05\x04\0\
-    \x02\x02\x03\x12\x031\x16\x17\n\n\n\x02\x04\x01\x12\x044\07\x01\n\n\n\
-    \x03\x04\x01\x01\x12\x034\x08\x11\n\x0b\n\x04\x04\x01\x02\0\x12\x035\x02\
-    !\n\x0c\n\x05\x04\x01\x02\0\x04\x12\x035\x02\n\n\x0c\n\x05\x04\x01\x02\0\
-    \x06\x12\x035\x0b\x13\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x035\x14\x1c\n\
-    \x0c\n\x05\x04\x01\x02\0\x03\x12\x035\x1f\x20\n\x0b\n\x04\x04\x01\x02\
-    \x01\x12\x036\x02\x1b\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x036\x02\n\n\
-    \x0c\n\x05\x04\x01\x02\x01\x05\x12\x036\x0b\x11\n\x0c\n\x05\x04\x01\x02\
-    \x01\x01\x12\x036\x12\x16\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x036\x19\
-    \x1a\n\n\n\x02\x04\x02\x12\x049\0<\x01\n\n\n\x03\x04\x02\x01\x12\x039\
-    \x08\x0b\n\x0b\n\x04\x04\x02\x02\0\x12\x03:\x02\x19\n\x0c\n\x05\x04\x02\
-    \x02\0\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03:\x0b\x11\
-    \n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03:\x12\x14\n\x0c\n\x05\x04\x02\x02\
-    \0\x03\x12\x03:\x17\x18\n\x0b\n\x04\x04\x02\x02\x01\x12\x03;\x02\x1d\n\
-    \x0c\n\x05\x04\x02\x02\x01\x04\x12\x03;\x02\n\n\x0c\n\x05\x04\x02\x02\
-    \x01\x05\x12\x03;\x0b\x0f\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03;\x10\
-    \x18\n\x0c\n\x05\x04\x02\x02\x01\x03\x12\x03;\x1b\x1c\n\n\n\x02\x04\x03\
-    \x12\x04>\0A\x01\n\n\n\x03\x04\x03\x01\x12\x03>\x08\x10\n\x0b\n\x04\x04\
-    \x03\x02\0\x12\x03?\x02\x1c\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03?\x02\n\
-    \n\x0c\n\x05\x04\x03\x02\0\x06\x12\x03?\x0b\x12\n\x0c\n\x05\x04\x03\x02\
-    \0\x01\x12\x03?\x13\x17\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03?\x1a\x1b\n\
-    \x0b\n\x04\x04\x03\x02\x01\x12\x03@\x02\x1a\n\x0c\n\x05\x04\x03\x02\x01\
-    \x04\x12\x03@\x02\n\n\x0c\n\x05\x04\x03\x02\x01\x05\x12\x03@\x0b\x11\n\
-    \x0c\n\x05\x04\x03\x02\x01\x01\x12\x03@\x12\x15\n\x0c\n\x05\x04\x03\x02\
-    \x01\x03\x12\x03@\x18\x19\n\t\n\x02\x04\x04\x12\x03C\0\x10\n\n\n\x03\x04\
-    \x04\x01\x12\x03C\x08\r\
+    \x1d\n\x18REG_INCOMPATIBLE_VERSION\x10\xd2\x0f\x12\x17\n\x12GROUP_NOT_CO\
+    MPLETE\x10\xb8\x17\x12\x1e\n\x19PARTIAL_JOB_GROUP_PROMOTE\x10\xb9\x17J\
+    \xcb\x12\n\x06\x12\x04\0\0J\x10\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\
+    \x01\x02\x12\x03\x01\x08\x0b\n\n\n\x02\x05\0\x12\x04\x03\0\t\x01\n\n\n\
+    \x03\x05\0\x01\x12\x03\x03\x05\r\n\x0b\n\x04\x05\0\x02\0\x12\x03\x04\x02\
+    \n\n\x0c\n\x05\x05\0\x02\0\x01\x12\x03\x04\x02\x05\n\x0c\n\x05\x05\0\x02\
+    \0\x02\x12\x03\x04\x08\t\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x05\x02\x0f\n\
+    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x05\x02\n\n\x0c\n\x05\x05\0\x02\x01\
+    \x02\x12\x03\x05\r\x0e\n\x0b\n\x04\x05\0\x02\x02\x12\x03\x06\x02\x11\n\
+    \x0c\n\x05\x05\0\x02\x02\x01\x12\x03\x06\x02\x0c\n\x0c\n\x05\x05\0\x02\
+    \x02\x02\x12\x03\x06\x0f\x10\n\x0b\n\x04\x05\0\x02\x03\x12\x03\x07\x02\
+    \x10\n\x0c\n\x05\x05\0\x02\x03\x01\x12\x03\x07\x02\x0b\n\x0c\n\x05\x05\0\
+    \x02\x03\x02\x12\x03\x07\x0e\x0f\n\x0b\n\x04\x05\0\x02\x04\x12\x03\x08\
+    \x02\r\n\x0c\n\x05\x05\0\x02\x04\x01\x12\x03\x08\x02\x08\n\x0c\n\x05\x05\
+    \0\x02\x04\x02\x12\x03\x08\x0b\x0c\n\n\n\x02\x05\x01\x12\x04\x0b\03\x01\
+    \n\n\n\x03\x05\x01\x01\x12\x03\x0b\x05\x0c\n\x16\n\x04\x05\x01\x02\0\x12\
+    \x03\r\x02\n\x1a\t\x20Generic\n\n\x0c\n\x05\x05\x01\x02\0\x01\x12\x03\r\
+    \x02\x05\n\x0c\n\x05\x05\x01\x02\0\x02\x12\x03\r\x08\t\n\x0b\n\x04\x05\
+    \x01\x02\x01\x12\x03\x0e\x02\x0e\n\x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\
+    \x0e\x02\t\n\x0c\n\x05\x05\x01\x02\x01\x02\x12\x03\x0e\x0c\r\n\x0b\n\x04\
+    \x05\x01\x02\x02\x12\x03\x0f\x02\x16\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\
+    \x03\x0f\x02\x11\n\x0c\n\x05\x05\x01\x02\x02\x02\x12\x03\x0f\x14\x15\n\
+    \x0b\n\x04\x05\x01\x02\x03\x12\x03\x10\x02\x17\n\x0c\n\x05\x05\x01\x02\
+    \x03\x01\x12\x03\x10\x02\x12\n\x0c\n\x05\x05\x01\x02\x03\x02\x12\x03\x10\
+    \x15\x16\n\x0b\n\x04\x05\x01\x02\x04\x12\x03\x11\x02\x17\n\x0c\n\x05\x05\
+    \x01\x02\x04\x01\x12\x03\x11\x02\x12\n\x0c\n\x05\x05\x01\x02\x04\x02\x12\
+    \x03\x11\x15\x16\n\x0b\n\x04\x05\x01\x02\x05\x12\x03\x12\x02\x0f\n\x0c\n\
+    \x05\x05\x01\x02\x05\x01\x12\x03\x12\x02\n\n\x0c\n\x05\x05\x01\x02\x05\
+    \x02\x12\x03\x12\r\x0e\n\x0b\n\x04\x05\x01\x02\x06\x12\x03\x13\x02\x14\n\
+    \x0c\n\x05\x05\x01\x02\x06\x01\x12\x03\x13\x02\x0f\n\x0c\n\x05\x05\x01\
+    \x02\x06\x02\x12\x03\x13\x12\x13\n\x0b\n\x04\x05\x01\x02\x07\x12\x03\x14\
+    \x02\x16\n\x0c\n\x05\x05\x01\x02\x07\x01\x12\x03\x14\x02\x11\n\x0c\n\x05\
+    \x05\x01\x02\x07\x02\x12\x03\x14\x14\x15\n\x0b\n\x04\x05\x01\x02\x08\x12\
+    \x03\x15\x02\x16\n\x0c\n\x05\x05\x01\x02\x08\x01\x12\x03\x15\x02\x11\n\
+    \x0c\n\x05\x05\x01\x02\x08\x02\x12\x03\x15\x14\x15\n\x0b\n\x04\x05\x01\
+    \x02\t\x12\x03\x16\x02\x0c\n\x0c\n\x05\x05\x01\x02\t\x01\x12\x03\x16\x02\
+    \x06\n\x0c\n\x05\x05\x01\x02\t\x02\x12\x03\x16\t\x0b\n\x0b\n\x04\x05\x01\
+    \x02\n\x12\x03\x17\x02\x12\n\x0c\n\x05\x05\x01\x02\n\x01\x12\x03\x17\x02\
+    \x0c\n\x0c\n\x05\x05\x01\x02\n\x02\x12\x03\x17\x0f\x11\n\x0b\n\x04\x05\
+    \x01\x02\x0b\x12\x03\x18\x02\x11\n\x0c\n\x05\x05\x01\x02\x0b\x01\x12\x03\
+    \x18\x02\x0b\n\x0c\n\x05\x05\x01\x02\x0b\x02\x12\x03\x18\x0e\x10\n\x0b\n\
+    \x04\x05\x01\x02\x0c\x12\x03\x19\x02\x1a\n\x0c\n\x05\x05\x01\x02\x0c\x01\
+    \x12\x03\x19\x02\x14\n\x0c\n\x05\x05\x01\x02\x0c\x02\x12\x03\x19\x17\x19\
+    \n\x0b\n\x04\x05\x01\x02\r\x12\x03\x1a\x02\x0b\n\x0c\n\x05\x05\x01\x02\r\
+    \x01\x12\x03\x1a\x02\x05\n\x0c\n\x05\x05\x01\x02\r\x02\x12\x03\x1a\x08\n\
+    \n\x0b\n\x04\x05\x01\x02\x0e\x12\x03\x1b\x02\x14\n\x0c\n\x05\x05\x01\x02\
+    \x0e\x01\x12\x03\x1b\x02\x0e\n\x0c\n\x05\x05\x01\x02\x0e\x02\x12\x03\x1b\
+    \x11\x13\n\x15\n\x04\x05\x01\x02\x0f\x12\x03\x1e\x02\x19\x1a\x08\x20Work\
+    er\n\n\x0c\n\x05\x05\x01\x02\x0f\x01\x12\x03\x1e\x02\x11\n\x0c\n\x05\x05\
+    \x01\x02\x0f\x02\x12\x03\x1e\x14\x18\n\x0b\n\x04\x05\x01\x02\x10\x12\x03\
+    \x1f\x02\x1a\n\x0c\n\x05\x05\x01\x02\x10\x01\x12\x03\x1f\x02\x12\n\x0c\n\
+    \x05\x05\x01\x02\x10\x02\x12\x03\x1f\x15\x19\n\x0b\n\x04\x05\x01\x02\x11\
+    \x12\x03\x20\x02\x1b\n\x0c\n\x05\x05\x01\x02\x11\x01\x12\x03\x20\x02\x13\
+    \n\x0c\n\x05\x05\x01\x02\x11\x02\x12\x03\x20\x16\x1a\n\x0b\n\x04\x05\x01\
+    \x02\x12\x12\x03!\x02\x13\n\x0c\n\x05\x05\x01\x02\x12\x01\x12\x03!\x02\
+    \x0b\n\x0c\n\x05\x05\x01\x02\x12\x02\x12\x03!\x0e\x12\n\x0b\n\x04\x05\
+    \x01\x02\x13\x12\x03\"\x02\x0f\n\x0c\n\x05\x05\x01\x02\x13\x01\x12\x03\"\
+    \x02\x07\n\x0c\n\x05\x05\x01\x02\x13\x02\x12\x03\"\n\x0e\n\x0b\n\x04\x05\
+    \x01\x02\x14\x12\x03#\x02\x18\n\x0c\n\x05\x05\x01\x02\x14\x01\x12\x03#\
+    \x02\x10\n\x0c\n\x05\x05\x01\x02\x14\x02\x12\x03#\x13\x17\n\x0b\n\x04\
+    \x05\x01\x02\x15\x12\x03$\x02\x1e\n\x0c\n\x05\x05\x01\x02\x15\x01\x12\
+    \x03$\x02\x16\n\x0c\n\x05\x05\x01\x02\x15\x02\x12\x03$\x19\x1d\n\x0b\n\
+    \x04\x05\x01\x02\x16\x12\x03%\x02\x17\n\x0c\n\x05\x05\x01\x02\x16\x01\
+    \x12\x03%\x02\x0f\n\x0c\n\x05\x05\x01\x02\x16\x02\x12\x03%\x12\x16\n\x0b\
+    \n\x04\x05\x01\x02\x17\x12\x03&\x02\x17\n\x0c\n\x05\x05\x01\x02\x17\x01\
+    \x12\x03&\x02\x0f\n\x0c\n\x05\x05\x01\x02\x17\x02\x12\x03&\x12\x16\n\x0b\
+    \n\x04\x05\x01\x02\x18\x12\x03'\x02\x18\n\x0c\n\x05\x05\x01\x02\x18\x01\
+    \x12\x03'\x02\x10\n\x0c\n\x05\x05\x01\x02\x18\x02\x12\x03'\x13\x17\n\x0b\
+    \n\x04\x05\x01\x02\x19\x12\x03(\x02\x17\n\x0c\n\x05\x05\x01\x02\x19\x01\
+    \x12\x03(\x02\x0f\n\x0c\n\x05\x05\x01\x02\x19\x02\x12\x03(\x12\x16\n\x0b\
+    \n\x04\x05\x01\x02\x1a\x12\x03)\x02\x13\n\x0c\n\x05\x05\x01\x02\x1a\x01\
+    \x12\x03)\x02\x0b\n\x0c\n\x05\x05\x01\x02\x1a\x02\x12\x03)\x0e\x12\n\x17\
+    \n\x04\x05\x01\x02\x1b\x12\x03,\x02\x16\x1a\n\x20RouteSrv\n\n\x0c\n\x05\
+    \x05\x01\x02\x1b\x01\x12\x03,\x02\x0e\n\x0c\n\x05\x05\x01\x02\x1b\x02\
+    \x12\x03,\x11\x15\n\x0b\n\x04\x05\x01\x02\x1c\x12\x03-\x02\x17\n\x0c\n\
+    \x05\x05\x01\x02\x1c\x01\x12\x03-\x02\x0f\n\x0c\n\x05\x05\x01\x02\x1c\
+    \x02\x12\x03-\x12\x16\n\x0b\n\x04\x05\x01\x02\x1d\x12\x03.\x02\"\n\x0c\n\
+    \x05\x05\x01\x02\x1d\x01\x12\x03.\x02\x1a\n\x0c\n\x05\x05\x01\x02\x1d\
+    \x02\x12\x03.\x1d!\n\x18\n\x04\x05\x01\x02\x1e\x12\x031\x02\x1c\x1a\x0b\
+    \x20Scheduler\n\n\x0c\n\x05\x05\x01\x02\x1e\x01\x12\x031\x02\x14\n\x0c\n\
+    \x05\x05\x01\x02\x1e\x02\x12\x031\x17\x1b\n\x0b\n\x04\x05\x01\x02\x1f\
+    \x12\x032\x02#\n\x0c\n\x05\x05\x01\x02\x1f\x01\x12\x032\x02\x1b\n\x0c\n\
+    \x05\x05\x01\x02\x1f\x02\x12\x032\x1e\"\n\n\n\x02\x04\0\x12\x045\09\x01\
+    \n\n\n\x03\x04\0\x01\x12\x035\x08\x0e\n\x0b\n\x04\x04\0\x02\0\x12\x036\
+    \x02!\n\x0c\n\x05\x04\0\x02\0\x04\x12\x036\x02\n\n\x0c\n\x05\x04\0\x02\0\
+    \x05\x12\x036\x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x036\x12\x1c\n\x0c\
+    \n\x05\x04\0\x02\0\x03\x12\x036\x1f\x20\n\x0b\n\x04\x04\0\x02\x01\x12\
+    \x037\x02\x1f\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x037\x02\n\n\x0c\n\x05\
+    \x04\0\x02\x01\x05\x12\x037\x0b\x0f\n\x0c\n\x05\x04\0\x02\x01\x01\x12\
+    \x037\x10\x1a\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x037\x1d\x1e\n\x0b\n\x04\
+    \x04\0\x02\x02\x12\x038\x02\x18\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x038\
+    \x02\n\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x038\x0b\x0f\n\x0c\n\x05\x04\0\
+    \x02\x02\x01\x12\x038\x10\x13\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x038\x16\
+    \x17\n\n\n\x02\x04\x01\x12\x04;\0>\x01\n\n\n\x03\x04\x01\x01\x12\x03;\
+    \x08\x11\n\x0b\n\x04\x04\x01\x02\0\x12\x03<\x02!\n\x0c\n\x05\x04\x01\x02\
+    \0\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\x01\x02\0\x06\x12\x03<\x0b\x13\n\
+    \x0c\n\x05\x04\x01\x02\0\x01\x12\x03<\x14\x1c\n\x0c\n\x05\x04\x01\x02\0\
+    \x03\x12\x03<\x1f\x20\n\x0b\n\x04\x04\x01\x02\x01\x12\x03=\x02\x1b\n\x0c\
+    \n\x05\x04\x01\x02\x01\x04\x12\x03=\x02\n\n\x0c\n\x05\x04\x01\x02\x01\
+    \x05\x12\x03=\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03=\x12\x16\n\
+    \x0c\n\x05\x04\x01\x02\x01\x03\x12\x03=\x19\x1a\n\n\n\x02\x04\x02\x12\
+    \x04@\0C\x01\n\n\n\x03\x04\x02\x01\x12\x03@\x08\x0b\n\x0b\n\x04\x04\x02\
+    \x02\0\x12\x03A\x02\x19\n\x0c\n\x05\x04\x02\x02\0\x04\x12\x03A\x02\n\n\
+    \x0c\n\x05\x04\x02\x02\0\x05\x12\x03A\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\
+    \x01\x12\x03A\x12\x14\n\x0c\n\x05\x04\x02\x02\0\x03\x12\x03A\x17\x18\n\
+    \x0b\n\x04\x04\x02\x02\x01\x12\x03B\x02\x1d\n\x0c\n\x05\x04\x02\x02\x01\
+    \x04\x12\x03B\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\x03B\x0b\x0f\n\
+    \x0c\n\x05\x04\x02\x02\x01\x01\x12\x03B\x10\x18\n\x0c\n\x05\x04\x02\x02\
+    \x01\x03\x12\x03B\x1b\x1c\n\n\n\x02\x04\x03\x12\x04E\0H\x01\n\n\n\x03\
+    \x04\x03\x01\x12\x03E\x08\x10\n\x0b\n\x04\x04\x03\x02\0\x12\x03F\x02\x1c\
+    \n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03F\x02\n\n\x0c\n\x05\x04\x03\x02\0\
+    \x06\x12\x03F\x0b\x12\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03F\x13\x17\n\
+    \x0c\n\x05\x04\x03\x02\0\x03\x12\x03F\x1a\x1b\n\x0b\n\x04\x04\x03\x02\
+    \x01\x12\x03G\x02\x1a\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03G\x02\n\n\
+    \x0c\n\x05\x04\x03\x02\x01\x05\x12\x03G\x0b\x11\n\x0c\n\x05\x04\x03\x02\
+    \x01\x01\x12\x03G\x12\x15\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03G\x18\
+    \x19\n\t\n\x02\x04\x04\x12\x03J\0\x10\n\n\n\x03\x04\x04\x01\x12\x03J\x08\
+    \r\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -276,6 +276,7 @@ pub struct Registration {
     // message fields
     protocol: ::std::option::Option<super::net::Protocol>,
     shards: ::std::vec::Vec<u32>,
+    protocol_version: ::std::option::Option<u32>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -358,6 +359,33 @@ impl Registration {
     fn mut_shards_for_reflect(&mut self) -> &mut ::std::vec::Vec<u32> {
         &mut self.shards
     }
+
+    // optional uint32 protocol_version = 3;
+
+    pub fn clear_protocol_version(&mut self) {
+        self.protocol_version = ::std::option::Option::None;
+    }
+
+    pub fn has_protocol_version(&self) -> bool {
+        self.protocol_version.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_protocol_version(&mut self, v: u32) {
+        self.protocol_version = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_protocol_version(&self) -> u32 {
+        self.protocol_version.unwrap_or(0)
+    }
+
+    fn get_protocol_version_for_reflect(&self) -> &::std::option::Option<u32> {
+        &self.protocol_version
+    }
+
+    fn mut_protocol_version_for_reflect(&mut self) -> &mut ::std::option::Option<u32> {
+        &mut self.protocol_version
+    }
 }
 
 impl ::protobuf::Message for Registration {
@@ -379,6 +407,13 @@ impl ::protobuf::Message for Registration {
                 2 => {
                     ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.shards)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.protocol_version = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -397,6 +432,9 @@ impl ::protobuf::Message for Registration {
         if !self.shards.is_empty() {
             my_size += ::protobuf::rt::vec_packed_varint_size(2, &self.shards);
         }
+        if let Some(v) = self.protocol_version {
+            my_size += ::protobuf::rt::value_size(3, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -414,6 +452,9 @@ impl ::protobuf::Message for Registration {
                 os.write_uint32_no_tag(*v)?;
             };
         }
+        if let Some(v) = self.protocol_version {
+            os.write_uint32(3, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -468,6 +509,11 @@ impl ::protobuf::MessageStatic for Registration {
                     Registration::get_shards_for_reflect,
                     Registration::mut_shards_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                    "protocol_version",
+                    Registration::get_protocol_version_for_reflect,
+                    Registration::mut_protocol_version_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Registration>(
                     "Registration",
                     fields,
@@ -482,6 +528,7 @@ impl ::protobuf::Clear for Registration {
     fn clear(&mut self) {
         self.clear_protocol();
         self.clear_shards();
+        self.clear_protocol_version();
         self.unknown_fields.clear();
     }
 }
@@ -500,26 +547,30 @@ impl ::protobuf::reflect::ProtobufValue for Registration {
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x18protocols/routesrv.proto\x12\x08routesrv\x1a\x13protocols/net.prot\
-    o\"\x0c\n\nDisconnect\"\x0b\n\tHeartbeat\"U\n\x0cRegistration\x12)\n\x08\
-    protocol\x18\x01\x20\x01(\x0e2\r.net.ProtocolR\x08protocol\x12\x1a\n\x06\
-    shards\x18\x02\x20\x03(\rR\x06shardsB\x02\x10\x01J\xe1\x02\n\x06\x12\x04\
-    \0\0\n\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\x01\
-    \x07\x1c\n\x08\n\x01\x02\x12\x03\x02\x08\x10\n\t\n\x02\x04\0\x12\x03\x04\
-    \0\x15\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x12\n\t\n\x02\x04\x01\x12\x03\
-    \x05\0\x14\n\n\n\x03\x04\x01\x01\x12\x03\x05\x08\x11\n\n\n\x02\x04\x02\
-    \x12\x04\x07\0\n\x01\n\n\n\x03\x04\x02\x01\x12\x03\x07\x08\x14\n\x0b\n\
-    \x04\x04\x02\x02\0\x12\x03\x08\x02%\n\x0c\n\x05\x04\x02\x02\0\x04\x12\
-    \x03\x08\x02\n\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x08\x0b\x17\n\x0c\n\
-    \x05\x04\x02\x02\0\x01\x12\x03\x08\x18\x20\n\x0c\n\x05\x04\x02\x02\0\x03\
-    \x12\x03\x08#$\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\t\x02+\n\x0c\n\x05\
-    \x04\x02\x02\x01\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\x12\
-    \x03\t\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\t\x12\x18\n\x0c\n\
-    \x05\x04\x02\x02\x01\x03\x12\x03\t\x1b\x1c\n\x0c\n\x05\x04\x02\x02\x01\
-    \x08\x12\x03\t\x1d*\n\x0f\n\x08\x04\x02\x02\x01\x08\xe7\x07\0\x12\x03\t\
-    \x1e)\n\x10\n\t\x04\x02\x02\x01\x08\xe7\x07\0\x02\x12\x03\t\x1e$\n\x11\n\
-    \n\x04\x02\x02\x01\x08\xe7\x07\0\x02\0\x12\x03\t\x1e$\n\x12\n\x0b\x04\
-    \x02\x02\x01\x08\xe7\x07\0\x02\0\x01\x12\x03\t\x1e$\n\x10\n\t\x04\x02\
-    \x02\x01\x08\xe7\x07\0\x03\x12\x03\t%)\
+    o\"\x0c\n\nDisconnect\"\x0b\n\tHeartbeat\"\x80\x01\n\x0cRegistration\x12\
+    )\n\x08protocol\x18\x01\x20\x01(\x0e2\r.net.ProtocolR\x08protocol\x12\
+    \x1a\n\x06shards\x18\x02\x20\x03(\rR\x06shardsB\x02\x10\x01\x12)\n\x10pr\
+    otocol_version\x18\x03\x20\x01(\rR\x0fprotocolVersionJ\xa6\x03\n\x06\x12\
+    \x04\0\0\x0b\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\t\n\x02\x03\0\x12\x03\
+    \x01\x07\x1c\n\x08\n\x01\x02\x12\x03\x02\x08\x10\n\t\n\x02\x04\0\x12\x03\
+    \x04\0\x15\n\n\n\x03\x04\0\x01\x12\x03\x04\x08\x12\n\t\n\x02\x04\x01\x12\
+    \x03\x05\0\x14\n\n\n\x03\x04\x01\x01\x12\x03\x05\x08\x11\n\n\n\x02\x04\
+    \x02\x12\x04\x07\0\x0b\x01\n\n\n\x03\x04\x02\x01\x12\x03\x07\x08\x14\n\
+    \x0b\n\x04\x04\x02\x02\0\x12\x03\x08\x02%\n\x0c\n\x05\x04\x02\x02\0\x04\
+    \x12\x03\x08\x02\n\n\x0c\n\x05\x04\x02\x02\0\x06\x12\x03\x08\x0b\x17\n\
+    \x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x08\x18\x20\n\x0c\n\x05\x04\x02\x02\
+    \0\x03\x12\x03\x08#$\n\x0b\n\x04\x04\x02\x02\x01\x12\x03\t\x02+\n\x0c\n\
+    \x05\x04\x02\x02\x01\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\x02\x02\x01\x05\
+    \x12\x03\t\x0b\x11\n\x0c\n\x05\x04\x02\x02\x01\x01\x12\x03\t\x12\x18\n\
+    \x0c\n\x05\x04\x02\x02\x01\x03\x12\x03\t\x1b\x1c\n\x0c\n\x05\x04\x02\x02\
+    \x01\x08\x12\x03\t\x1d*\n\x0f\n\x08\x04\x02\x02\x01\x08\xe7\x07\0\x12\
+    \x03\t\x1e)\n\x10\n\t\x04\x02\x02\x01\x08\xe7\x07\0\x02\x12\x03\t\x1e$\n\
+    \x11\n\n\x04\x02\x02\x01\x08\xe7\x07\0\x02\0\x12\x03\t\x1e$\n\x12\n\x0b\
+    \x04\x02\x02\x01\x08\xe7\x07\0\x02\0\x01\x12\x03\t\x1e$\n\x10\n\t\x04\
+    \x02\x02\x01\x08\xe7\x07\0\x03\x12\x03\t%)\n\x0b\n\x04\x04\x02\x02\x02\
+    \x12\x03\n\x02'\n\x0c\n\x05\x04\x02\x02\x02\x04\x12\x03\n\x02\n\n\x0c\n\
+    \x05\x04\x02\x02\x02\x05\x12\x03\n\x0b\x11\n\x0c\n\x05\x04\x02\x02\x02\
+    \x01\x12\x03\n\x12\"\n\x0c\n\x05\x04\x02\x02\x02\x03\x12\x03\n%&\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
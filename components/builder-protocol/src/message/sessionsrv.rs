@@ -21,6 +21,1420 @@
 use protobuf::Message as Message_imported_for_functions;
 use protobuf::ProtobufEnum as ProtobufEnum_imported_for_functions;
 
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessToken {
+    // message fields
+    id: ::std::option::Option<u64>,
+    account_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    scopes: ::std::vec::Vec<AccessTokenScope>,
+    created_at: ::protobuf::SingularField<::std::string::String>,
+    last_used_at: ::protobuf::SingularField<::std::string::String>,
+    token: ::protobuf::SingularField<::std::string::String>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessToken {}
+
+impl AccessToken {
+    pub fn new() -> AccessToken {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessToken {
+        static mut instance: ::protobuf::lazy::Lazy<AccessToken> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessToken,
+        };
+        unsafe {
+            instance.get(AccessToken::new)
+        }
+    }
+
+    // optional uint64 id = 1;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+
+    // optional uint64 account_id = 2;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // optional string name = 3;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // repeated .sessionsrv.AccessTokenScope scopes = 4;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::std::vec::Vec<AccessTokenScope>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::std::vec::Vec<AccessTokenScope> {
+        ::std::mem::replace(&mut self.scopes, ::std::vec::Vec::new())
+    }
+
+    pub fn get_scopes(&self) -> &[AccessTokenScope] {
+        &self.scopes
+    }
+
+    fn get_scopes_for_reflect(&self) -> &::std::vec::Vec<AccessTokenScope> {
+        &self.scopes
+    }
+
+    fn mut_scopes_for_reflect(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
+
+    // optional string created_at = 5;
+
+    pub fn clear_created_at(&mut self) {
+        self.created_at.clear();
+    }
+
+    pub fn has_created_at(&self) -> bool {
+        self.created_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_created_at(&mut self, v: ::std::string::String) {
+        self.created_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_created_at(&mut self) -> &mut ::std::string::String {
+        if self.created_at.is_none() {
+            self.created_at.set_default();
+        }
+        self.created_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_created_at(&mut self) -> ::std::string::String {
+        self.created_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_created_at(&self) -> &str {
+        match self.created_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_created_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.created_at
+    }
+
+    fn mut_created_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.created_at
+    }
+
+    // optional string last_used_at = 6;
+
+    pub fn clear_last_used_at(&mut self) {
+        self.last_used_at.clear();
+    }
+
+    pub fn has_last_used_at(&self) -> bool {
+        self.last_used_at.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_last_used_at(&mut self, v: ::std::string::String) {
+        self.last_used_at = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_last_used_at(&mut self) -> &mut ::std::string::String {
+        if self.last_used_at.is_none() {
+            self.last_used_at.set_default();
+        }
+        self.last_used_at.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_last_used_at(&mut self) -> ::std::string::String {
+        self.last_used_at.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_last_used_at(&self) -> &str {
+        match self.last_used_at.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_last_used_at_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.last_used_at
+    }
+
+    fn mut_last_used_at_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.last_used_at
+    }
+
+    // optional string token = 7;
+
+    pub fn clear_token(&mut self) {
+        self.token.clear();
+    }
+
+    pub fn has_token(&self) -> bool {
+        self.token.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_token(&mut self, v: ::std::string::String) {
+        self.token = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_token(&mut self) -> &mut ::std::string::String {
+        if self.token.is_none() {
+            self.token.set_default();
+        }
+        self.token.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_token(&mut self) -> ::std::string::String {
+        self.token.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_token(&self) -> &str {
+        match self.token.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_token_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.token
+    }
+
+    fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.token
+    }
+}
+
+impl ::protobuf::Message for AccessToken {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_enum_into(wire_type, is, &mut self.scopes)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.created_at)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.last_used_at)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.token)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(3, &v);
+        }
+        for value in &self.scopes {
+            my_size += ::protobuf::rt::enum_size(4, *value);
+        };
+        if let Some(ref v) = self.created_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(5, &v);
+        }
+        if let Some(ref v) = self.last_used_at.as_ref() {
+            my_size += ::protobuf::rt::string_size(6, &v);
+        }
+        if let Some(ref v) = self.token.as_ref() {
+            my_size += ::protobuf::rt::string_size(7, &v);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.account_id {
+            os.write_uint64(2, v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(3, &v)?;
+        }
+        for v in &self.scopes {
+            os.write_enum(4, v.value())?;
+        };
+        if let Some(ref v) = self.created_at.as_ref() {
+            os.write_string(5, &v)?;
+        }
+        if let Some(ref v) = self.last_used_at.as_ref() {
+            os.write_string(6, &v)?;
+        }
+        if let Some(ref v) = self.token.as_ref() {
+            os.write_string(7, &v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessToken {
+    fn new() -> AccessToken {
+        AccessToken::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessToken>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    AccessToken::get_id_for_reflect,
+                    AccessToken::mut_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessToken::get_account_id_for_reflect,
+                    AccessToken::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    AccessToken::get_name_for_reflect,
+                    AccessToken::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeEnum<AccessTokenScope>>(
+                    "scopes",
+                    AccessToken::get_scopes_for_reflect,
+                    AccessToken::mut_scopes_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "created_at",
+                    AccessToken::get_created_at_for_reflect,
+                    AccessToken::mut_created_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "last_used_at",
+                    AccessToken::get_last_used_at_for_reflect,
+                    AccessToken::mut_last_used_at_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "token",
+                    AccessToken::get_token_for_reflect,
+                    AccessToken::mut_token_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessToken>(
+                    "AccessToken",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessToken {
+    fn clear(&mut self) {
+        self.clear_id();
+        self.clear_account_id();
+        self.clear_name();
+        self.clear_scopes();
+        self.clear_created_at();
+        self.clear_last_used_at();
+        self.clear_token();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccessToken {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessToken {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessTokenCreate {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    name: ::protobuf::SingularField<::std::string::String>,
+    scopes: ::std::vec::Vec<AccessTokenScope>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenCreate {}
+
+impl AccessTokenCreate {
+    pub fn new() -> AccessTokenCreate {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenCreate {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenCreate> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenCreate,
+        };
+        unsafe {
+            instance.get(AccessTokenCreate::new)
+        }
+    }
+
+    // optional uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // optional string name = 2;
+
+    pub fn clear_name(&mut self) {
+        self.name.clear();
+    }
+
+    pub fn has_name(&self) -> bool {
+        self.name.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_name(&mut self, v: ::std::string::String) {
+        self.name = ::protobuf::SingularField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_name(&mut self) -> &mut ::std::string::String {
+        if self.name.is_none() {
+            self.name.set_default();
+        }
+        self.name.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_name(&mut self) -> ::std::string::String {
+        self.name.take().unwrap_or_else(|| ::std::string::String::new())
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self.name.as_ref() {
+            Some(v) => &v,
+            None => "",
+        }
+    }
+
+    fn get_name_for_reflect(&self) -> &::protobuf::SingularField<::std::string::String> {
+        &self.name
+    }
+
+    fn mut_name_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
+        &mut self.name
+    }
+
+    // repeated .sessionsrv.AccessTokenScope scopes = 3;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::std::vec::Vec<AccessTokenScope>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::std::vec::Vec<AccessTokenScope> {
+        ::std::mem::replace(&mut self.scopes, ::std::vec::Vec::new())
+    }
+
+    pub fn get_scopes(&self) -> &[AccessTokenScope] {
+        &self.scopes
+    }
+
+    fn get_scopes_for_reflect(&self) -> &::std::vec::Vec<AccessTokenScope> {
+        &self.scopes
+    }
+
+    fn mut_scopes_for_reflect(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
+}
+
+impl ::protobuf::Message for AccessTokenCreate {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.name)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_enum_into(wire_type, is, &mut self.scopes)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            my_size += ::protobuf::rt::string_size(2, &v);
+        }
+        for value in &self.scopes {
+            my_size += ::protobuf::rt::enum_size(3, *value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(ref v) = self.name.as_ref() {
+            os.write_string(2, &v)?;
+        }
+        for v in &self.scopes {
+            os.write_enum(3, v.value())?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenCreate {
+    fn new() -> AccessTokenCreate {
+        AccessTokenCreate::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenCreate>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessTokenCreate::get_account_id_for_reflect,
+                    AccessTokenCreate::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_singular_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                    "name",
+                    AccessTokenCreate::get_name_for_reflect,
+                    AccessTokenCreate::mut_name_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeEnum<AccessTokenScope>>(
+                    "scopes",
+                    AccessTokenCreate::get_scopes_for_reflect,
+                    AccessTokenCreate::mut_scopes_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenCreate>(
+                    "AccessTokenCreate",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenCreate {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_name();
+        self.clear_scopes();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenCreate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessTokenCreate {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessTokenListRequest {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenListRequest {}
+
+impl AccessTokenListRequest {
+    pub fn new() -> AccessTokenListRequest {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenListRequest {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenListRequest> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenListRequest,
+        };
+        unsafe {
+            instance.get(AccessTokenListRequest::new)
+        }
+    }
+
+    // optional uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+}
+
+impl ::protobuf::Message for AccessTokenListRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenListRequest {
+    fn new() -> AccessTokenListRequest {
+        AccessTokenListRequest::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenListRequest>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessTokenListRequest::get_account_id_for_reflect,
+                    AccessTokenListRequest::mut_account_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenListRequest>(
+                    "AccessTokenListRequest",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenListRequest {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenListRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessTokenListRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessTokenListResponse {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    tokens: ::protobuf::RepeatedField<AccessToken>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenListResponse {}
+
+impl AccessTokenListResponse {
+    pub fn new() -> AccessTokenListResponse {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenListResponse {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenListResponse> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenListResponse,
+        };
+        unsafe {
+            instance.get(AccessTokenListResponse::new)
+        }
+    }
+
+    // optional uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // repeated .sessionsrv.AccessToken tokens = 2;
+
+    pub fn clear_tokens(&mut self) {
+        self.tokens.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tokens(&mut self, v: ::protobuf::RepeatedField<AccessToken>) {
+        self.tokens = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tokens(&mut self) -> &mut ::protobuf::RepeatedField<AccessToken> {
+        &mut self.tokens
+    }
+
+    // Take field
+    pub fn take_tokens(&mut self) -> ::protobuf::RepeatedField<AccessToken> {
+        ::std::mem::replace(&mut self.tokens, ::protobuf::RepeatedField::new())
+    }
+
+    pub fn get_tokens(&self) -> &[AccessToken] {
+        &self.tokens
+    }
+
+    fn get_tokens_for_reflect(&self) -> &::protobuf::RepeatedField<AccessToken> {
+        &self.tokens
+    }
+
+    fn mut_tokens_for_reflect(&mut self) -> &mut ::protobuf::RepeatedField<AccessToken> {
+        &mut self.tokens
+    }
+}
+
+impl ::protobuf::Message for AccessTokenListResponse {
+    fn is_initialized(&self) -> bool {
+        for v in &self.tokens {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tokens)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.tokens {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        for v in &self.tokens {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenListResponse {
+    fn new() -> AccessTokenListResponse {
+        AccessTokenListResponse::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenListResponse>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessTokenListResponse::get_account_id_for_reflect,
+                    AccessTokenListResponse::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AccessToken>>(
+                    "tokens",
+                    AccessTokenListResponse::get_tokens_for_reflect,
+                    AccessTokenListResponse::mut_tokens_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenListResponse>(
+                    "AccessTokenListResponse",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenListResponse {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_tokens();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenListResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessTokenListResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AccessTokenRevoke {
+    // message fields
+    account_id: ::std::option::Option<u64>,
+    id: ::std::option::Option<u64>,
+    // special fields
+    unknown_fields: ::protobuf::UnknownFields,
+    cached_size: ::protobuf::CachedSize,
+}
+
+// see codegen.rs for the explanation why impl Sync explicitly
+unsafe impl ::std::marker::Sync for AccessTokenRevoke {}
+
+impl AccessTokenRevoke {
+    pub fn new() -> AccessTokenRevoke {
+        ::std::default::Default::default()
+    }
+
+    pub fn default_instance() -> &'static AccessTokenRevoke {
+        static mut instance: ::protobuf::lazy::Lazy<AccessTokenRevoke> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const AccessTokenRevoke,
+        };
+        unsafe {
+            instance.get(AccessTokenRevoke::new)
+        }
+    }
+
+    // optional uint64 account_id = 1;
+
+    pub fn clear_account_id(&mut self) {
+        self.account_id = ::std::option::Option::None;
+    }
+
+    pub fn has_account_id(&self) -> bool {
+        self.account_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_account_id(&mut self, v: u64) {
+        self.account_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_account_id(&self) -> u64 {
+        self.account_id.unwrap_or(0)
+    }
+
+    fn get_account_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.account_id
+    }
+
+    fn mut_account_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.account_id
+    }
+
+    // optional uint64 id = 2;
+
+    pub fn clear_id(&mut self) {
+        self.id = ::std::option::Option::None;
+    }
+
+    pub fn has_id(&self) -> bool {
+        self.id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_id(&mut self, v: u64) {
+        self.id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_id(&self) -> u64 {
+        self.id.unwrap_or(0)
+    }
+
+    fn get_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.id
+    }
+
+    fn mut_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.id
+    }
+}
+
+impl ::protobuf::Message for AccessTokenRevoke {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.account_id = ::std::option::Option::Some(tmp);
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.id = ::std::option::Option::Some(tmp);
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(v) = self.account_id {
+            my_size += ::protobuf::rt::value_size(1, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(v) = self.id {
+            my_size += ::protobuf::rt::value_size(2, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream) -> ::protobuf::ProtobufResult<()> {
+        if let Some(v) = self.account_id {
+            os.write_uint64(1, v)?;
+        }
+        if let Some(v) = self.id {
+            os.write_uint64(2, v)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &::std::any::Any {
+        self as &::std::any::Any
+    }
+    fn as_any_mut(&mut self) -> &mut ::std::any::Any {
+        self as &mut ::std::any::Any
+    }
+    fn into_any(self: Box<Self>) -> ::std::boxed::Box<::std::any::Any> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        ::protobuf::MessageStatic::descriptor_static(None::<Self>)
+    }
+}
+
+impl ::protobuf::MessageStatic for AccessTokenRevoke {
+    fn new() -> AccessTokenRevoke {
+        AccessTokenRevoke::new()
+    }
+
+    fn descriptor_static(_: ::std::option::Option<AccessTokenRevoke>) -> &'static ::protobuf::reflect::MessageDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::MessageDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::MessageDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                let mut fields = ::std::vec::Vec::new();
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "account_id",
+                    AccessTokenRevoke::get_account_id_for_reflect,
+                    AccessTokenRevoke::mut_account_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "id",
+                    AccessTokenRevoke::get_id_for_reflect,
+                    AccessTokenRevoke::mut_id_for_reflect,
+                ));
+                ::protobuf::reflect::MessageDescriptor::new::<AccessTokenRevoke>(
+                    "AccessTokenRevoke",
+                    fields,
+                    file_descriptor_proto()
+                )
+            })
+        }
+    }
+}
+
+impl ::protobuf::Clear for AccessTokenRevoke {
+    fn clear(&mut self) {
+        self.clear_account_id();
+        self.clear_id();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AccessTokenRevoke {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessTokenRevoke {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct Account {
     // message fields
@@ -4460,6 +5874,8 @@ pub struct Session {
     token: ::protobuf::SingularField<::std::string::String>,
     flags: ::std::option::Option<u32>,
     oauth_token: ::protobuf::SingularField<::std::string::String>,
+    access_token_id: ::std::option::Option<u64>,
+    scopes: ::std::vec::Vec<AccessTokenScope>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -4712,6 +6128,66 @@ impl Session {
     fn mut_oauth_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::string::String> {
         &mut self.oauth_token
     }
+
+    // optional uint64 access_token_id = 7;
+
+    pub fn clear_access_token_id(&mut self) {
+        self.access_token_id = ::std::option::Option::None;
+    }
+
+    pub fn has_access_token_id(&self) -> bool {
+        self.access_token_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_access_token_id(&mut self, v: u64) {
+        self.access_token_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_access_token_id(&self) -> u64 {
+        self.access_token_id.unwrap_or(0)
+    }
+
+    fn get_access_token_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.access_token_id
+    }
+
+    fn mut_access_token_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.access_token_id
+    }
+
+    // repeated .sessionsrv.AccessTokenScope scopes = 8;
+
+    pub fn clear_scopes(&mut self) {
+        self.scopes.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_scopes(&mut self, v: ::std::vec::Vec<AccessTokenScope>) {
+        self.scopes = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_scopes(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
+
+    // Take field
+    pub fn take_scopes(&mut self) -> ::std::vec::Vec<AccessTokenScope> {
+        ::std::mem::replace(&mut self.scopes, ::std::vec::Vec::new())
+    }
+
+    pub fn get_scopes(&self) -> &[AccessTokenScope] {
+        &self.scopes
+    }
+
+    fn get_scopes_for_reflect(&self) -> &::std::vec::Vec<AccessTokenScope> {
+        &self.scopes
+    }
+
+    fn mut_scopes_for_reflect(&mut self) -> &mut ::std::vec::Vec<AccessTokenScope> {
+        &mut self.scopes
+    }
 }
 
 impl ::protobuf::Message for Session {
@@ -4749,6 +6225,16 @@ impl ::protobuf::Message for Session {
                 6 => {
                     ::protobuf::rt::read_singular_string_into(wire_type, is, &mut self.oauth_token)?;
                 },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.access_token_id = ::std::option::Option::Some(tmp);
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_enum_into(wire_type, is, &mut self.scopes)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -4779,6 +6265,12 @@ impl ::protobuf::Message for Session {
         if let Some(ref v) = self.oauth_token.as_ref() {
             my_size += ::protobuf::rt::string_size(6, &v);
         }
+        if let Some(v) = self.access_token_id {
+            my_size += ::protobuf::rt::value_size(7, v, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.scopes {
+            my_size += ::protobuf::rt::enum_size(8, *value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -4803,6 +6295,12 @@ impl ::protobuf::Message for Session {
         if let Some(ref v) = self.oauth_token.as_ref() {
             os.write_string(6, &v)?;
         }
+        if let Some(v) = self.access_token_id {
+            os.write_uint64(7, v)?;
+        }
+        for v in &self.scopes {
+            os.write_enum(8, v.value())?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4877,6 +6375,16 @@ impl ::protobuf::MessageStatic for Session {
                     Session::get_oauth_token_for_reflect,
                     Session::mut_oauth_token_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "access_token_id",
+                    Session::get_access_token_id_for_reflect,
+                    Session::mut_access_token_id_for_reflect,
+                ));
+                fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeEnum<AccessTokenScope>>(
+                    "scopes",
+                    Session::get_scopes_for_reflect,
+                    Session::mut_scopes_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<Session>(
                     "Session",
                     fields,
@@ -4895,6 +6403,8 @@ impl ::protobuf::Clear for Session {
         self.clear_token();
         self.clear_flags();
         self.clear_oauth_token();
+        self.clear_access_token_id();
+        self.clear_scopes();
         self.unknown_fields.clear();
     }
 }
@@ -5569,6 +7079,7 @@ pub struct SessionToken {
     extern_id: ::std::option::Option<u32>,
     provider: ::std::option::Option<OAuthProvider>,
     token: ::protobuf::SingularField<::std::vec::Vec<u8>>,
+    access_token_id: ::std::option::Option<u64>,
     // special fields
     unknown_fields: ::protobuf::UnknownFields,
     cached_size: ::protobuf::CachedSize,
@@ -5716,6 +7227,33 @@ impl SessionToken {
     fn mut_token_for_reflect(&mut self) -> &mut ::protobuf::SingularField<::std::vec::Vec<u8>> {
         &mut self.token
     }
+
+    // optional uint64 access_token_id = 5;
+
+    pub fn clear_access_token_id(&mut self) {
+        self.access_token_id = ::std::option::Option::None;
+    }
+
+    pub fn has_access_token_id(&self) -> bool {
+        self.access_token_id.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_access_token_id(&mut self, v: u64) {
+        self.access_token_id = ::std::option::Option::Some(v);
+    }
+
+    pub fn get_access_token_id(&self) -> u64 {
+        self.access_token_id.unwrap_or(0)
+    }
+
+    fn get_access_token_id_for_reflect(&self) -> &::std::option::Option<u64> {
+        &self.access_token_id
+    }
+
+    fn mut_access_token_id_for_reflect(&mut self) -> &mut ::std::option::Option<u64> {
+        &mut self.access_token_id
+    }
 }
 
 impl ::protobuf::Message for SessionToken {
@@ -5751,6 +7289,13 @@ impl ::protobuf::Message for SessionToken {
                 4 => {
                     ::protobuf::rt::read_singular_bytes_into(wire_type, is, &mut self.token)?;
                 },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.access_token_id = ::std::option::Option::Some(tmp);
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -5775,6 +7320,9 @@ impl ::protobuf::Message for SessionToken {
         if let Some(ref v) = self.token.as_ref() {
             my_size += ::protobuf::rt::bytes_size(4, &v);
         }
+        if let Some(v) = self.access_token_id {
+            my_size += ::protobuf::rt::value_size(5, v, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -5793,6 +7341,9 @@ impl ::protobuf::Message for SessionToken {
         if let Some(ref v) = self.token.as_ref() {
             os.write_bytes(4, &v)?;
         }
+        if let Some(v) = self.access_token_id {
+            os.write_uint64(5, v)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5857,6 +7408,11 @@ impl ::protobuf::MessageStatic for SessionToken {
                     SessionToken::get_token_for_reflect,
                     SessionToken::mut_token_for_reflect,
                 ));
+                fields.push(::protobuf::reflect::accessor::make_option_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                    "access_token_id",
+                    SessionToken::get_access_token_id_for_reflect,
+                    SessionToken::mut_access_token_id_for_reflect,
+                ));
                 ::protobuf::reflect::MessageDescriptor::new::<SessionToken>(
                     "SessionToken",
                     fields,
@@ -5873,6 +7429,7 @@ impl ::protobuf::Clear for SessionToken {
         self.clear_extern_id();
         self.clear_provider();
         self.clear_token();
+        self.clear_access_token_id();
         self.unknown_fields.clear();
     }
 }
@@ -5892,6 +7449,8 @@ impl ::protobuf::reflect::ProtobufValue for SessionToken {
 #[derive(Clone,PartialEq,Eq,Debug,Hash)]
 pub enum OAuthProvider {
     GitHub = 0,
+    GitLab = 1,
+    Oidc = 2,
 }
 
 impl ::protobuf::ProtobufEnum for OAuthProvider {
@@ -5902,6 +7461,8 @@ impl ::protobuf::ProtobufEnum for OAuthProvider {
     fn from_i32(value: i32) -> ::std::option::Option<OAuthProvider> {
         match value {
             0 => ::std::option::Option::Some(OAuthProvider::GitHub),
+            1 => ::std::option::Option::Some(OAuthProvider::GitLab),
+            2 => ::std::option::Option::Some(OAuthProvider::Oidc),
             _ => ::std::option::Option::None
         }
     }
@@ -5909,6 +7470,8 @@ impl ::protobuf::ProtobufEnum for OAuthProvider {
     fn values() -> &'static [Self] {
         static values: &'static [OAuthProvider] = &[
             OAuthProvider::GitHub,
+            OAuthProvider::GitLab,
+            OAuthProvider::Oidc,
         ];
         values
     }
@@ -5935,8 +7498,74 @@ impl ::protobuf::reflect::ProtobufValue for OAuthProvider {
     }
 }
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum AccessTokenScope {
+    PackageUpload = 0,
+    JobSchedule = 1,
+    OriginAdmin = 2,
+}
+
+impl ::protobuf::ProtobufEnum for AccessTokenScope {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<AccessTokenScope> {
+        match value {
+            0 => ::std::option::Option::Some(AccessTokenScope::PackageUpload),
+            1 => ::std::option::Option::Some(AccessTokenScope::JobSchedule),
+            2 => ::std::option::Option::Some(AccessTokenScope::OriginAdmin),
+            _ => ::std::option::Option::None
+        }
+    }
+
+    fn values() -> &'static [Self] {
+        static values: &'static [AccessTokenScope] = &[
+            AccessTokenScope::PackageUpload,
+            AccessTokenScope::JobSchedule,
+            AccessTokenScope::OriginAdmin,
+        ];
+        values
+    }
+
+    fn enum_descriptor_static(_: ::std::option::Option<AccessTokenScope>) -> &'static ::protobuf::reflect::EnumDescriptor {
+        static mut descriptor: ::protobuf::lazy::Lazy<::protobuf::reflect::EnumDescriptor> = ::protobuf::lazy::Lazy {
+            lock: ::protobuf::lazy::ONCE_INIT,
+            ptr: 0 as *const ::protobuf::reflect::EnumDescriptor,
+        };
+        unsafe {
+            descriptor.get(|| {
+                ::protobuf::reflect::EnumDescriptor::new("AccessTokenScope", file_descriptor_proto())
+            })
+        }
+    }
+}
+
+impl ::std::marker::Copy for AccessTokenScope {
+}
+
+impl ::protobuf::reflect::ProtobufValue for AccessTokenScope {
+    fn as_ref(&self) -> ::protobuf::reflect::ProtobufValueRef {
+        ::protobuf::reflect::ProtobufValueRef::Enum(self.descriptor())
+    }
+}
+
 static file_descriptor_proto_data: &'static [u8] = b"\
-    \n\x1aprotocols/sessionsrv.proto\x12\nsessionsrv\"C\n\x07Account\x12\x0e\
+    \n\x1aprotocols/sessionsrv.proto\x12\nsessionsrv\"\xdd\x01\n\x0bAccessTo\
+    ken\x12\x0e\n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x1d\n\naccount_id\
+    \x18\x02\x20\x01(\x04R\taccountId\x12\x12\n\x04name\x18\x03\x20\x01(\tR\
+    \x04name\x124\n\x06scopes\x18\x04\x20\x03(\x0e2\x1c.sessionsrv.AccessTok\
+    enScopeR\x06scopes\x12\x1d\n\ncreated_at\x18\x05\x20\x01(\tR\tcreatedAt\
+    \x12\x20\n\x0clast_used_at\x18\x06\x20\x01(\tR\nlastUsedAt\x12\x14\n\x05\
+    token\x18\x07\x20\x01(\tR\x05token\"|\n\x11AccessTokenCreate\x12\x1d\n\n\
+    account_id\x18\x01\x20\x01(\x04R\taccountId\x12\x12\n\x04name\x18\x02\
+    \x20\x01(\tR\x04name\x124\n\x06scopes\x18\x03\x20\x03(\x0e2\x1c.sessions\
+    rv.AccessTokenScopeR\x06scopes\"7\n\x16AccessTokenListRequest\x12\x1d\n\
+    \naccount_id\x18\x01\x20\x01(\x04R\taccountId\"i\n\x17AccessTokenListRes\
+    ponse\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccountId\x12/\n\x06t\
+    okens\x18\x02\x20\x03(\x0b2\x17.sessionsrv.AccessTokenR\x06tokens\"B\n\
+    \x11AccessTokenRevoke\x12\x1d\n\naccount_id\x18\x01\x20\x01(\x04R\taccou\
+    ntId\x12\x0e\n\x02id\x18\x02\x20\x01(\x04R\x02id\"C\n\x07Account\x12\x0e\
     \n\x02id\x18\x01\x20\x01(\x04R\x02id\x12\x14\n\x05email\x18\x02\x20\x01(\
     \tR\x05email\x12\x12\n\x04name\x18\x03\x20\x01(\tR\x04name\"?\n\x13Accou\
     ntFindOrCreate\x12\x12\n\x04name\x18\x01\x20\x01(\tR\x04name\x12\x14\n\
@@ -5978,253 +7607,353 @@ static file_descriptor_proto_data: &'static [u8] = b"\
     ccount_id\x18\x01\x20\x01(\x04R\taccountId\x12\x18\n\x07origins\x18\x02\
     \x20\x03(\tR\x07origins\"U\n\x13AccountOriginRemove\x12!\n\x0caccount_na\
     me\x18\x01\x20\x01(\tR\x0baccountName\x12\x1b\n\torigin_id\x18\x02\x20\
-    \x01(\x04R\x08originId\"\x90\x01\n\x07Session\x12\x0e\n\x02id\x18\x01\
+    \x01(\x04R\x08originId\"\xee\x01\n\x07Session\x12\x0e\n\x02id\x18\x01\
     \x20\x01(\x04R\x02id\x12\x14\n\x05email\x18\x02\x20\x01(\tR\x05email\x12\
     \x12\n\x04name\x18\x03\x20\x01(\tR\x04name\x12\x14\n\x05token\x18\x04\
     \x20\x01(\tR\x05token\x12\x14\n\x05flags\x18\x05\x20\x01(\rR\x05flags\
-    \x12\x1f\n\x0boauth_token\x18\x06\x20\x01(\tR\noauthToken\"\xc0\x01\n\rS\
-    essionCreate\x12\x14\n\x05token\x18\x01\x20\x01(\tR\x05token\x12\x1b\n\t\
-    extern_id\x18\x02\x20\x01(\rR\x08externId\x12\x14\n\x05email\x18\x03\x20\
-    \x01(\tR\x05email\x12\x12\n\x04name\x18\x04\x20\x01(\tR\x04name\x125\n\
-    \x08provider\x18\x05\x20\x01(\x0e2\x19.sessionsrv.OAuthProviderR\x08prov\
-    ider\x12\x1b\n\tapp_token\x18\x06\x20\x01(\tR\x08appToken\"<\n\nSessionG\
-    et\x12.\n\x05token\x18\x01\x20\x01(\x0b2\x18.sessionsrv.SessionTokenR\
-    \x05token\"\x97\x01\n\x0cSessionToken\x12\x1d\n\naccount_id\x18\x01\x20\
-    \x01(\x04R\taccountId\x12\x1b\n\textern_id\x18\x02\x20\x01(\rR\x08extern\
-    Id\x125\n\x08provider\x18\x03\x20\x01(\x0e2\x19.sessionsrv.OAuthProvider\
-    R\x08provider\x12\x14\n\x05token\x18\x04\x20\x01(\x0cR\x05token*\x1b\n\r\
-    OAuthProvider\x12\n\n\x06GitHub\x10\0J\xb0&\n\x07\x12\x05\0\0\x83\x01\
-    \x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\x12\x03\x01\x08\x12\
-    \n\n\n\x02\x05\0\x12\x04\x03\0\x05\x01\n\n\n\x03\x05\0\x01\x12\x03\x03\
-    \x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x04\x02\r\n\x0c\n\x05\x05\0\x02\
-    \0\x01\x12\x03\x04\x02\x08\n\x0c\n\x05\x05\0\x02\0\x02\x12\x03\x04\x0b\
-    \x0c\n\n\n\x02\x04\0\x12\x04\x07\0\x0b\x01\n\n\n\x03\x04\0\x01\x12\x03\
-    \x07\x08\x0f\n\x0b\n\x04\x04\0\x02\0\x12\x03\x08\x02\x19\n\x0c\n\x05\x04\
-    \0\x02\0\x04\x12\x03\x08\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x08\
-    \x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x08\x12\x14\n\x0c\n\x05\x04\
-    \0\x02\0\x03\x12\x03\x08\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\t\x02\
-    \x1c\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\t\x02\n\n\x0c\n\x05\x04\0\x02\
-    \x01\x05\x12\x03\t\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\t\x12\
-    \x17\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\t\x1a\x1b\n\x0b\n\x04\x04\0\
-    \x02\x02\x12\x03\n\x02\x1b\n\x0c\n\x05\x04\0\x02\x02\x04\x12\x03\n\x02\n\
-    \n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\n\x0b\x11\n\x0c\n\x05\x04\0\x02\
-    \x02\x01\x12\x03\n\x12\x16\n\x0c\n\x05\x04\0\x02\x02\x03\x12\x03\n\x19\
-    \x1a\n\n\n\x02\x04\x01\x12\x04\r\0\x10\x01\n\n\n\x03\x04\x01\x01\x12\x03\
-    \r\x08\x1b\n\x0b\n\x04\x04\x01\x02\0\x12\x03\x0e\x02\x1b\n\x0c\n\x05\x04\
-    \x01\x02\0\x04\x12\x03\x0e\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\
-    \x0e\x0b\x11\n\x0c\n\x05\x04\x01\x02\0\x01\x12\x03\x0e\x12\x16\n\x0c\n\
-    \x05\x04\x01\x02\0\x03\x12\x03\x0e\x19\x1a\n\x0b\n\x04\x04\x01\x02\x01\
-    \x12\x03\x0f\x02\x1c\n\x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\x0f\x02\n\n\
-    \x0c\n\x05\x04\x01\x02\x01\x05\x12\x03\x0f\x0b\x11\n\x0c\n\x05\x04\x01\
-    \x02\x01\x01\x12\x03\x0f\x12\x17\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\
-    \x0f\x1a\x1b\n+\n\x02\x04\x02\x12\x04\x13\0\x15\x01\x1a\x1f\x20get\x20an\
-    \x20account\x20by\x20GH\x20username\n\n\n\n\x03\x04\x02\x01\x12\x03\x13\
-    \x08\x12\n\x0b\n\x04\x04\x02\x02\0\x12\x03\x14\x02\x1b\n\x0c\n\x05\x04\
-    \x02\x02\0\x04\x12\x03\x14\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03\
-    \x14\x0b\x11\n\x0c\n\x05\x04\x02\x02\0\x01\x12\x03\x14\x12\x16\n\x0c\n\
-    \x05\x04\x02\x02\0\x03\x12\x03\x14\x19\x1a\n\n\n\x02\x04\x03\x12\x04\x17\
-    \0\x19\x01\n\n\n\x03\x04\x03\x01\x12\x03\x17\x08\x14\n\x0b\n\x04\x04\x03\
-    \x02\0\x12\x03\x18\x02\x19\n\x0c\n\x05\x04\x03\x02\0\x04\x12\x03\x18\x02\
-    \n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\x03\x18\x0b\x11\n\x0c\n\x05\x04\x03\
-    \x02\0\x01\x12\x03\x18\x12\x14\n\x0c\n\x05\x04\x03\x02\0\x03\x12\x03\x18\
-    \x17\x18\n\n\n\x02\x04\x04\x12\x04\x1b\0\x1e\x01\n\n\n\x03\x04\x04\x01\
-    \x12\x03\x1b\x08\x15\n\x0b\n\x04\x04\x04\x02\0\x12\x03\x1c\x02\x1b\n\x0c\
-    \n\x05\x04\x04\x02\0\x04\x12\x03\x1c\x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\
-    \x12\x03\x1c\x0b\x11\n\x0c\n\x05\x04\x04\x02\0\x01\x12\x03\x1c\x12\x16\n\
-    \x0c\n\x05\x04\x04\x02\0\x03\x12\x03\x1c\x19\x1a\n\x0b\n\x04\x04\x04\x02\
-    \x01\x12\x03\x1d\x02\x1c\n\x0c\n\x05\x04\x04\x02\x01\x04\x12\x03\x1d\x02\
-    \n\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03\x1d\x0b\x11\n\x0c\n\x05\x04\
-    \x04\x02\x01\x01\x12\x03\x1d\x12\x17\n\x0c\n\x05\x04\x04\x02\x01\x03\x12\
-    \x03\x1d\x1a\x1b\n\n\n\x02\x04\x05\x12\x04\x20\0#\x01\n\n\n\x03\x04\x05\
-    \x01\x12\x03\x20\x08\x15\n\x0b\n\x04\x04\x05\x02\0\x12\x03!\x02\x19\n\
-    \x0c\n\x05\x04\x05\x02\0\x04\x12\x03!\x02\n\n\x0c\n\x05\x04\x05\x02\0\
-    \x05\x12\x03!\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\x03!\x12\x14\n\
-    \x0c\n\x05\x04\x05\x02\0\x03\x12\x03!\x17\x18\n\x0b\n\x04\x04\x05\x02\
-    \x01\x12\x03\"\x02\x1c\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\x03\"\x02\n\n\
-    \x0c\n\x05\x04\x05\x02\x01\x05\x12\x03\"\x0b\x11\n\x0c\n\x05\x04\x05\x02\
-    \x01\x01\x12\x03\"\x12\x17\n\x0c\n\x05\x04\x05\x02\x01\x03\x12\x03\"\x1a\
-    \x1b\n\n\n\x02\x04\x06\x12\x04%\0-\x01\n\n\n\x03\x04\x06\x01\x12\x03%\
-    \x08\x1f\n\x0b\n\x04\x04\x06\x02\0\x12\x03&\x02\x19\n\x0c\n\x05\x04\x06\
-    \x02\0\x04\x12\x03&\x02\n\n\x0c\n\x05\x04\x06\x02\0\x05\x12\x03&\x0b\x11\
-    \n\x0c\n\x05\x04\x06\x02\0\x01\x12\x03&\x12\x14\n\x0c\n\x05\x04\x06\x02\
-    \0\x03\x12\x03&\x17\x18\n\x0b\n\x04\x04\x06\x02\x01\x12\x03'\x02+\n\x0c\
-    \n\x05\x04\x06\x02\x01\x04\x12\x03'\x02\n\n\x0c\n\x05\x04\x06\x02\x01\
-    \x05\x12\x03'\x0b\x11\n\x0c\n\x05\x04\x06\x02\x01\x01\x12\x03'\x12&\n\
-    \x0c\n\x05\x04\x06\x02\x01\x03\x12\x03')*\n\x0b\n\x04\x04\x06\x02\x02\
-    \x12\x03(\x02!\n\x0c\n\x05\x04\x06\x02\x02\x04\x12\x03(\x02\n\n\x0c\n\
-    \x05\x04\x06\x02\x02\x05\x12\x03(\x0b\x11\n\x0c\n\x05\x04\x06\x02\x02\
-    \x01\x12\x03(\x12\x1c\n\x0c\n\x05\x04\x06\x02\x02\x03\x12\x03(\x1f\x20\n\
-    \x0b\n\x04\x04\x06\x02\x03\x12\x03)\x02#\n\x0c\n\x05\x04\x06\x02\x03\x04\
-    \x12\x03)\x02\n\n\x0c\n\x05\x04\x06\x02\x03\x05\x12\x03)\x0b\x11\n\x0c\n\
-    \x05\x04\x06\x02\x03\x01\x12\x03)\x12\x1e\n\x0c\n\x05\x04\x06\x02\x03\
-    \x03\x12\x03)!\"\n\x0b\n\x04\x04\x06\x02\x04\x12\x03*\x02\x20\n\x0c\n\
-    \x05\x04\x06\x02\x04\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\x06\x02\x04\x05\
-    \x12\x03*\x0b\x11\n\x0c\n\x05\x04\x06\x02\x04\x01\x12\x03*\x12\x1b\n\x0c\
-    \n\x05\x04\x06\x02\x04\x03\x12\x03*\x1e\x1f\n\x0b\n\x04\x04\x06\x02\x05\
-    \x12\x03+\x02\"\n\x0c\n\x05\x04\x06\x02\x05\x04\x12\x03+\x02\n\n\x0c\n\
-    \x05\x04\x06\x02\x05\x05\x12\x03+\x0b\x11\n\x0c\n\x05\x04\x06\x02\x05\
-    \x01\x12\x03+\x12\x1d\n\x0c\n\x05\x04\x06\x02\x05\x03\x12\x03+\x20!\n\
-    \x0b\n\x04\x04\x06\x02\x06\x12\x03,\x02\x1f\n\x0c\n\x05\x04\x06\x02\x06\
-    \x04\x12\x03,\x02\n\n\x0c\n\x05\x04\x06\x02\x06\x05\x12\x03,\x0b\x11\n\
-    \x0c\n\x05\x04\x06\x02\x06\x01\x12\x03,\x12\x1a\n\x0c\n\x05\x04\x06\x02\
-    \x06\x03\x12\x03,\x1d\x1e\n\n\n\x02\x04\x07\x12\x04/\06\x01\n\n\n\x03\
-    \x04\x07\x01\x12\x03/\x08%\n\x0b\n\x04\x04\x07\x02\0\x12\x030\x02+\n\x0c\
-    \n\x05\x04\x07\x02\0\x04\x12\x030\x02\n\n\x0c\n\x05\x04\x07\x02\0\x05\
-    \x12\x030\x0b\x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x030\x12&\n\x0c\n\
-    \x05\x04\x07\x02\0\x03\x12\x030)*\n\x0b\n\x04\x04\x07\x02\x01\x12\x031\
-    \x02!\n\x0c\n\x05\x04\x07\x02\x01\x04\x12\x031\x02\n\n\x0c\n\x05\x04\x07\
-    \x02\x01\x05\x12\x031\x0b\x11\n\x0c\n\x05\x04\x07\x02\x01\x01\x12\x031\
-    \x12\x1c\n\x0c\n\x05\x04\x07\x02\x01\x03\x12\x031\x1f\x20\n\x0b\n\x04\
-    \x04\x07\x02\x02\x12\x032\x02#\n\x0c\n\x05\x04\x07\x02\x02\x04\x12\x032\
-    \x02\n\n\x0c\n\x05\x04\x07\x02\x02\x05\x12\x032\x0b\x11\n\x0c\n\x05\x04\
-    \x07\x02\x02\x01\x12\x032\x12\x1e\n\x0c\n\x05\x04\x07\x02\x02\x03\x12\
-    \x032!\"\n\x0b\n\x04\x04\x07\x02\x03\x12\x033\x02\x20\n\x0c\n\x05\x04\
-    \x07\x02\x03\x04\x12\x033\x02\n\n\x0c\n\x05\x04\x07\x02\x03\x05\x12\x033\
-    \x0b\x11\n\x0c\n\x05\x04\x07\x02\x03\x01\x12\x033\x12\x1b\n\x0c\n\x05\
-    \x04\x07\x02\x03\x03\x12\x033\x1e\x1f\n\x0b\n\x04\x04\x07\x02\x04\x12\
-    \x034\x02\"\n\x0c\n\x05\x04\x07\x02\x04\x04\x12\x034\x02\n\n\x0c\n\x05\
-    \x04\x07\x02\x04\x05\x12\x034\x0b\x11\n\x0c\n\x05\x04\x07\x02\x04\x01\
-    \x12\x034\x12\x1d\n\x0c\n\x05\x04\x07\x02\x04\x03\x12\x034\x20!\n\x0b\n\
-    \x04\x04\x07\x02\x05\x12\x035\x02\x1f\n\x0c\n\x05\x04\x07\x02\x05\x04\
-    \x12\x035\x02\n\n\x0c\n\x05\x04\x07\x02\x05\x05\x12\x035\x0b\x11\n\x0c\n\
-    \x05\x04\x07\x02\x05\x01\x12\x035\x12\x1a\n\x0c\n\x05\x04\x07\x02\x05\
-    \x03\x12\x035\x1d\x1e\n\n\n\x02\x04\x08\x12\x048\0=\x01\n\n\n\x03\x04\
-    \x08\x01\x12\x038\x08,\n\x0b\n\x04\x04\x08\x02\0\x12\x039\x02!\n\x0c\n\
-    \x05\x04\x08\x02\0\x04\x12\x039\x02\n\n\x0c\n\x05\x04\x08\x02\0\x05\x12\
-    \x039\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x039\x12\x1c\n\x0c\n\x05\
-    \x04\x08\x02\0\x03\x12\x039\x1f\x20\n\x0b\n\x04\x04\x08\x02\x01\x12\x03:\
-    \x02\x20\n\x0c\n\x05\x04\x08\x02\x01\x04\x12\x03:\x02\n\n\x0c\n\x05\x04\
-    \x08\x02\x01\x05\x12\x03:\x0b\x11\n\x0c\n\x05\x04\x08\x02\x01\x01\x12\
-    \x03:\x12\x1b\n\x0c\n\x05\x04\x08\x02\x01\x03\x12\x03:\x1e\x1f\n\x0b\n\
-    \x04\x04\x08\x02\x02\x12\x03;\x02\"\n\x0c\n\x05\x04\x08\x02\x02\x04\x12\
-    \x03;\x02\n\n\x0c\n\x05\x04\x08\x02\x02\x05\x12\x03;\x0b\x11\n\x0c\n\x05\
-    \x04\x08\x02\x02\x01\x12\x03;\x12\x1d\n\x0c\n\x05\x04\x08\x02\x02\x03\
-    \x12\x03;\x20!\n\x0b\n\x04\x04\x08\x02\x03\x12\x03<\x02\x1b\n\x0c\n\x05\
-    \x04\x08\x02\x03\x04\x12\x03<\x02\n\n\x0c\n\x05\x04\x08\x02\x03\x05\x12\
-    \x03<\x0b\x0f\n\x0c\n\x05\x04\x08\x02\x03\x01\x12\x03<\x10\x16\n\x0c\n\
-    \x05\x04\x08\x02\x03\x03\x12\x03<\x19\x1a\n\n\n\x02\x04\t\x12\x04?\0B\
-    \x01\n\n\n\x03\x04\t\x01\x12\x03?\x08,\n\x0b\n\x04\x04\t\x02\0\x12\x03@\
-    \x02!\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03@\x02\n\n\x0c\n\x05\x04\t\x02\0\
-    \x05\x12\x03@\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03@\x12\x1c\n\x0c\
-    \n\x05\x04\t\x02\0\x03\x12\x03@\x1f\x20\n\x0b\n\x04\x04\t\x02\x01\x12\
-    \x03A\x02$\n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03A\x02\n\n\x0c\n\x05\x04\
-    \t\x02\x01\x05\x12\x03A\x0b\x11\n\x0c\n\x05\x04\t\x02\x01\x01\x12\x03A\
-    \x12\x1f\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03A\"#\n\n\n\x02\x04\n\x12\
-    \x04D\0G\x01\n\n\n\x03\x04\n\x01\x12\x03D\x08-\n\x0b\n\x04\x04\n\x02\0\
-    \x12\x03E\x02!\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03E\x02\n\n\x0c\n\x05\
-    \x04\n\x02\0\x05\x12\x03E\x0b\x11\n\x0c\n\x05\x04\n\x02\0\x01\x12\x03E\
-    \x12\x1c\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03E\x1f\x20\n\x0b\n\x04\x04\n\
-    \x02\x01\x12\x03F\x02$\n\x0c\n\x05\x04\n\x02\x01\x04\x12\x03F\x02\n\n\
-    \x0c\n\x05\x04\n\x02\x01\x05\x12\x03F\x0b\x11\n\x0c\n\x05\x04\n\x02\x01\
-    \x01\x12\x03F\x12\x1f\n\x0c\n\x05\x04\n\x02\x01\x03\x12\x03F\"#\n\n\n\
-    \x02\x04\x0b\x12\x04I\0K\x01\n\n\n\x03\x04\x0b\x01\x12\x03I\x08$\n\x0b\n\
-    \x04\x04\x0b\x02\0\x12\x03J\x02!\n\x0c\n\x05\x04\x0b\x02\0\x04\x12\x03J\
-    \x02\n\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\x03J\x0b\x11\n\x0c\n\x05\x04\
-    \x0b\x02\0\x01\x12\x03J\x12\x1c\n\x0c\n\x05\x04\x0b\x02\0\x03\x12\x03J\
-    \x1f\x20\n\n\n\x02\x04\x0c\x12\x04M\0P\x01\n\n\n\x03\x04\x0c\x01\x12\x03\
-    M\x08%\n\x0b\n\x04\x04\x0c\x02\0\x12\x03N\x02!\n\x0c\n\x05\x04\x0c\x02\0\
-    \x04\x12\x03N\x02\n\n\x0c\n\x05\x04\x0c\x02\0\x05\x12\x03N\x0b\x11\n\x0c\
-    \n\x05\x04\x0c\x02\0\x01\x12\x03N\x12\x1c\n\x0c\n\x05\x04\x0c\x02\0\x03\
-    \x12\x03N\x1f\x20\n\x0b\n\x04\x04\x0c\x02\x01\x12\x03O\x023\n\x0c\n\x05\
-    \x04\x0c\x02\x01\x04\x12\x03O\x02\n\n\x0c\n\x05\x04\x0c\x02\x01\x06\x12\
-    \x03O\x0b\"\n\x0c\n\x05\x04\x0c\x02\x01\x01\x12\x03O#.\n\x0c\n\x05\x04\
-    \x0c\x02\x01\x03\x12\x03O12\n\n\n\x02\x04\r\x12\x04R\0W\x01\n\n\n\x03\
-    \x04\r\x01\x12\x03R\x08\x1b\n\x0b\n\x04\x04\r\x02\0\x12\x03S\x02!\n\x0c\
-    \n\x05\x04\r\x02\0\x04\x12\x03S\x02\n\n\x0c\n\x05\x04\r\x02\0\x05\x12\
-    \x03S\x0b\x11\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03S\x12\x1c\n\x0c\n\x05\
-    \x04\r\x02\0\x03\x12\x03S\x1f\x20\n\x0b\n\x04\x04\r\x02\x01\x12\x03T\x02\
-    #\n\x0c\n\x05\x04\r\x02\x01\x04\x12\x03T\x02\n\n\x0c\n\x05\x04\r\x02\x01\
-    \x05\x12\x03T\x0b\x11\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03T\x12\x1e\n\
-    \x0c\n\x05\x04\r\x02\x01\x03\x12\x03T!\"\n\x0b\n\x04\x04\r\x02\x02\x12\
-    \x03U\x02\x20\n\x0c\n\x05\x04\r\x02\x02\x04\x12\x03U\x02\n\n\x0c\n\x05\
-    \x04\r\x02\x02\x05\x12\x03U\x0b\x11\n\x0c\n\x05\x04\r\x02\x02\x01\x12\
-    \x03U\x12\x1b\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03U\x1e\x1f\n\x0b\n\x04\
-    \x04\r\x02\x03\x12\x03V\x02\"\n\x0c\n\x05\x04\r\x02\x03\x04\x12\x03V\x02\
-    \n\n\x0c\n\x05\x04\r\x02\x03\x05\x12\x03V\x0b\x11\n\x0c\n\x05\x04\r\x02\
-    \x03\x01\x12\x03V\x12\x1d\n\x0c\n\x05\x04\r\x02\x03\x03\x12\x03V\x20!\n\
-    \n\n\x02\x04\x0e\x12\x04Y\0[\x01\n\n\n\x03\x04\x0e\x01\x12\x03Y\x08\x20\
-    \n\x0b\n\x04\x04\x0e\x02\0\x12\x03Z\x02!\n\x0c\n\x05\x04\x0e\x02\0\x04\
-    \x12\x03Z\x02\n\n\x0c\n\x05\x04\x0e\x02\0\x05\x12\x03Z\x0b\x11\n\x0c\n\
-    \x05\x04\x0e\x02\0\x01\x12\x03Z\x12\x1c\n\x0c\n\x05\x04\x0e\x02\0\x03\
-    \x12\x03Z\x1f\x20\n\n\n\x02\x04\x0f\x12\x04]\0`\x01\n\n\n\x03\x04\x0f\
-    \x01\x12\x03]\x08!\n\x0b\n\x04\x04\x0f\x02\0\x12\x03^\x02!\n\x0c\n\x05\
-    \x04\x0f\x02\0\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x0f\x02\0\x05\x12\x03^\
-    \x0b\x11\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\x03^\x12\x1c\n\x0c\n\x05\x04\
-    \x0f\x02\0\x03\x12\x03^\x1f\x20\n\x0b\n\x04\x04\x0f\x02\x01\x12\x03_\x02\
-    \x1e\n\x0c\n\x05\x04\x0f\x02\x01\x04\x12\x03_\x02\n\n\x0c\n\x05\x04\x0f\
-    \x02\x01\x05\x12\x03_\x0b\x11\n\x0c\n\x05\x04\x0f\x02\x01\x01\x12\x03_\
-    \x12\x19\n\x0c\n\x05\x04\x0f\x02\x01\x03\x12\x03_\x1c\x1d\n\n\n\x02\x04\
-    \x10\x12\x04b\0e\x01\n\n\n\x03\x04\x10\x01\x12\x03b\x08\x1b\n\x0b\n\x04\
-    \x04\x10\x02\0\x12\x03c\x02#\n\x0c\n\x05\x04\x10\x02\0\x04\x12\x03c\x02\
-    \n\n\x0c\n\x05\x04\x10\x02\0\x05\x12\x03c\x0b\x11\n\x0c\n\x05\x04\x10\
-    \x02\0\x01\x12\x03c\x12\x1e\n\x0c\n\x05\x04\x10\x02\0\x03\x12\x03c!\"\n\
-    \x0b\n\x04\x04\x10\x02\x01\x12\x03d\x02\x20\n\x0c\n\x05\x04\x10\x02\x01\
-    \x04\x12\x03d\x02\n\n\x0c\n\x05\x04\x10\x02\x01\x05\x12\x03d\x0b\x11\n\
-    \x0c\n\x05\x04\x10\x02\x01\x01\x12\x03d\x12\x1b\n\x0c\n\x05\x04\x10\x02\
-    \x01\x03\x12\x03d\x1e\x1f\n\n\n\x02\x04\x11\x12\x04g\0o\x01\n\n\n\x03\
-    \x04\x11\x01\x12\x03g\x08\x0f\n\x0b\n\x04\x04\x11\x02\0\x12\x03h\x02\x19\
-    \n\x0c\n\x05\x04\x11\x02\0\x04\x12\x03h\x02\n\n\x0c\n\x05\x04\x11\x02\0\
-    \x05\x12\x03h\x0b\x11\n\x0c\n\x05\x04\x11\x02\0\x01\x12\x03h\x12\x14\n\
-    \x0c\n\x05\x04\x11\x02\0\x03\x12\x03h\x17\x18\n\x0b\n\x04\x04\x11\x02\
-    \x01\x12\x03i\x02\x1c\n\x0c\n\x05\x04\x11\x02\x01\x04\x12\x03i\x02\n\n\
-    \x0c\n\x05\x04\x11\x02\x01\x05\x12\x03i\x0b\x11\n\x0c\n\x05\x04\x11\x02\
-    \x01\x01\x12\x03i\x12\x17\n\x0c\n\x05\x04\x11\x02\x01\x03\x12\x03i\x1a\
-    \x1b\n\x0b\n\x04\x04\x11\x02\x02\x12\x03j\x02\x1b\n\x0c\n\x05\x04\x11\
-    \x02\x02\x04\x12\x03j\x02\n\n\x0c\n\x05\x04\x11\x02\x02\x05\x12\x03j\x0b\
-    \x11\n\x0c\n\x05\x04\x11\x02\x02\x01\x12\x03j\x12\x16\n\x0c\n\x05\x04\
-    \x11\x02\x02\x03\x12\x03j\x19\x1a\nC\n\x04\x04\x11\x02\x03\x12\x03l\x02\
-    \x1c\x1a6\x20base64\x20encoded\x20binary\x20representation\x20of\x20Sess\
-    ionToken\n\n\x0c\n\x05\x04\x11\x02\x03\x04\x12\x03l\x02\n\n\x0c\n\x05\
-    \x04\x11\x02\x03\x05\x12\x03l\x0b\x11\n\x0c\n\x05\x04\x11\x02\x03\x01\
-    \x12\x03l\x12\x17\n\x0c\n\x05\x04\x11\x02\x03\x03\x12\x03l\x1a\x1b\n\x0b\
-    \n\x04\x04\x11\x02\x04\x12\x03m\x02\x1c\n\x0c\n\x05\x04\x11\x02\x04\x04\
-    \x12\x03m\x02\n\n\x0c\n\x05\x04\x11\x02\x04\x05\x12\x03m\x0b\x11\n\x0c\n\
-    \x05\x04\x11\x02\x04\x01\x12\x03m\x12\x17\n\x0c\n\x05\x04\x11\x02\x04\
-    \x03\x12\x03m\x1a\x1b\n\x0b\n\x04\x04\x11\x02\x05\x12\x03n\x02\"\n\x0c\n\
-    \x05\x04\x11\x02\x05\x04\x12\x03n\x02\n\n\x0c\n\x05\x04\x11\x02\x05\x05\
-    \x12\x03n\x0b\x11\n\x0c\n\x05\x04\x11\x02\x05\x01\x12\x03n\x12\x1d\n\x0c\
-    \n\x05\x04\x11\x02\x05\x03\x12\x03n\x20!\n\n\n\x02\x04\x12\x12\x04q\0x\
-    \x01\n\n\n\x03\x04\x12\x01\x12\x03q\x08\x15\n\x0b\n\x04\x04\x12\x02\0\
-    \x12\x03r\x02\x1c\n\x0c\n\x05\x04\x12\x02\0\x04\x12\x03r\x02\n\n\x0c\n\
-    \x05\x04\x12\x02\0\x05\x12\x03r\x0b\x11\n\x0c\n\x05\x04\x12\x02\0\x01\
-    \x12\x03r\x12\x17\n\x0c\n\x05\x04\x12\x02\0\x03\x12\x03r\x1a\x1b\n\x0b\n\
-    \x04\x04\x12\x02\x01\x12\x03s\x02\x20\n\x0c\n\x05\x04\x12\x02\x01\x04\
-    \x12\x03s\x02\n\n\x0c\n\x05\x04\x12\x02\x01\x05\x12\x03s\x0b\x11\n\x0c\n\
-    \x05\x04\x12\x02\x01\x01\x12\x03s\x12\x1b\n\x0c\n\x05\x04\x12\x02\x01\
-    \x03\x12\x03s\x1e\x1f\n\x0b\n\x04\x04\x12\x02\x02\x12\x03t\x02\x1c\n\x0c\
-    \n\x05\x04\x12\x02\x02\x04\x12\x03t\x02\n\n\x0c\n\x05\x04\x12\x02\x02\
-    \x05\x12\x03t\x0b\x11\n\x0c\n\x05\x04\x12\x02\x02\x01\x12\x03t\x12\x17\n\
-    \x0c\n\x05\x04\x12\x02\x02\x03\x12\x03t\x1a\x1b\n\x0b\n\x04\x04\x12\x02\
-    \x03\x12\x03u\x02\x1b\n\x0c\n\x05\x04\x12\x02\x03\x04\x12\x03u\x02\n\n\
-    \x0c\n\x05\x04\x12\x02\x03\x05\x12\x03u\x0b\x11\n\x0c\n\x05\x04\x12\x02\
-    \x03\x01\x12\x03u\x12\x16\n\x0c\n\x05\x04\x12\x02\x03\x03\x12\x03u\x19\
-    \x1a\n\x0b\n\x04\x04\x12\x02\x04\x12\x03v\x02&\n\x0c\n\x05\x04\x12\x02\
-    \x04\x04\x12\x03v\x02\n\n\x0c\n\x05\x04\x12\x02\x04\x06\x12\x03v\x0b\x18\
-    \n\x0c\n\x05\x04\x12\x02\x04\x01\x12\x03v\x19!\n\x0c\n\x05\x04\x12\x02\
-    \x04\x03\x12\x03v$%\n\x0b\n\x04\x04\x12\x02\x05\x12\x03w\x02\x20\n\x0c\n\
-    \x05\x04\x12\x02\x05\x04\x12\x03w\x02\n\n\x0c\n\x05\x04\x12\x02\x05\x05\
-    \x12\x03w\x0b\x11\n\x0c\n\x05\x04\x12\x02\x05\x01\x12\x03w\x12\x1b\n\x0c\
-    \n\x05\x04\x12\x02\x05\x03\x12\x03w\x1e\x1f\n\n\n\x02\x04\x13\x12\x04z\0\
-    |\x01\n\n\n\x03\x04\x13\x01\x12\x03z\x08\x12\n\x0b\n\x04\x04\x13\x02\0\
-    \x12\x03{\x02\"\n\x0c\n\x05\x04\x13\x02\0\x04\x12\x03{\x02\n\n\x0c\n\x05\
-    \x04\x13\x02\0\x06\x12\x03{\x0b\x17\n\x0c\n\x05\x04\x13\x02\0\x01\x12\
-    \x03{\x18\x1d\n\x0c\n\x05\x04\x13\x02\0\x03\x12\x03{\x20!\n\x0b\n\x02\
-    \x04\x14\x12\x05~\0\x83\x01\x01\n\n\n\x03\x04\x14\x01\x12\x03~\x08\x14\n\
-    \x0b\n\x04\x04\x14\x02\0\x12\x03\x7f\x02!\n\x0c\n\x05\x04\x14\x02\0\x04\
-    \x12\x03\x7f\x02\n\n\x0c\n\x05\x04\x14\x02\0\x05\x12\x03\x7f\x0b\x11\n\
-    \x0c\n\x05\x04\x14\x02\0\x01\x12\x03\x7f\x12\x1c\n\x0c\n\x05\x04\x14\x02\
-    \0\x03\x12\x03\x7f\x1f\x20\n\x0c\n\x04\x04\x14\x02\x01\x12\x04\x80\x01\
-    \x02\x20\n\r\n\x05\x04\x14\x02\x01\x04\x12\x04\x80\x01\x02\n\n\r\n\x05\
-    \x04\x14\x02\x01\x05\x12\x04\x80\x01\x0b\x11\n\r\n\x05\x04\x14\x02\x01\
-    \x01\x12\x04\x80\x01\x12\x1b\n\r\n\x05\x04\x14\x02\x01\x03\x12\x04\x80\
-    \x01\x1e\x1f\n\x0c\n\x04\x04\x14\x02\x02\x12\x04\x81\x01\x02&\n\r\n\x05\
-    \x04\x14\x02\x02\x04\x12\x04\x81\x01\x02\n\n\r\n\x05\x04\x14\x02\x02\x06\
-    \x12\x04\x81\x01\x0b\x18\n\r\n\x05\x04\x14\x02\x02\x01\x12\x04\x81\x01\
-    \x19!\n\r\n\x05\x04\x14\x02\x02\x03\x12\x04\x81\x01$%\n\x0c\n\x04\x04\
-    \x14\x02\x03\x12\x04\x82\x01\x02\x1b\n\r\n\x05\x04\x14\x02\x03\x04\x12\
-    \x04\x82\x01\x02\n\n\r\n\x05\x04\x14\x02\x03\x05\x12\x04\x82\x01\x0b\x10\
-    \n\r\n\x05\x04\x14\x02\x03\x01\x12\x04\x82\x01\x11\x16\n\r\n\x05\x04\x14\
-    \x02\x03\x03\x12\x04\x82\x01\x19\x1a\
+    \x12\x1f\n\x0boauth_token\x18\x06\x20\x01(\tR\noauthToken\x12&\n\x0facce\
+    ss_token_id\x18\x07\x20\x01(\x04R\raccessTokenId\x124\n\x06scopes\x18\
+    \x08\x20\x03(\x0e2\x1c.sessionsrv.AccessTokenScopeR\x06scopes\"\xc0\x01\
+    \n\rSessionCreate\x12\x14\n\x05token\x18\x01\x20\x01(\tR\x05token\x12\
+    \x1b\n\textern_id\x18\x02\x20\x01(\rR\x08externId\x12\x14\n\x05email\x18\
+    \x03\x20\x01(\tR\x05email\x12\x12\n\x04name\x18\x04\x20\x01(\tR\x04name\
+    \x125\n\x08provider\x18\x05\x20\x01(\x0e2\x19.sessionsrv.OAuthProviderR\
+    \x08provider\x12\x1b\n\tapp_token\x18\x06\x20\x01(\tR\x08appToken\"<\n\n\
+    SessionGet\x12.\n\x05token\x18\x01\x20\x01(\x0b2\x18.sessionsrv.SessionT\
+    okenR\x05token\"\xbf\x01\n\x0cSessionToken\x12\x1d\n\naccount_id\x18\x01\
+    \x20\x01(\x04R\taccountId\x12\x1b\n\textern_id\x18\x02\x20\x01(\rR\x08ex\
+    ternId\x125\n\x08provider\x18\x03\x20\x01(\x0e2\x19.sessionsrv.OAuthProv\
+    iderR\x08provider\x12\x14\n\x05token\x18\x04\x20\x01(\x0cR\x05token\x12&\
+    \n\x0faccess_token_id\x18\x05\x20\x01(\x04R\raccessTokenId*1\n\rOAuthPro\
+    vider\x12\n\n\x06GitHub\x10\0\x12\n\n\x06GitLab\x10\x01\x12\x08\n\x04Oid\
+    c\x10\x02*G\n\x10AccessTokenScope\x12\x11\n\rPackageUpload\x10\0\x12\x0f\
+    \n\x0bJobSchedule\x10\x01\x12\x0f\n\x0bOriginAdmin\x10\x02J\xfe6\n\x07\
+    \x12\x05\0\0\xb3\x01\x01\n\x08\n\x01\x0c\x12\x03\0\0\x12\n\x08\n\x01\x02\
+    \x12\x03\x01\x08\x12\n\n\n\x02\x05\0\x12\x04\x03\0\x08\x01\n\n\n\x03\x05\
+    \0\x01\x12\x03\x03\x05\x12\n\x0b\n\x04\x05\0\x02\0\x12\x03\x04\x02\r\n\
+    \x0c\n\x05\x05\0\x02\0\x01\x12\x03\x04\x02\x08\n\x0c\n\x05\x05\0\x02\0\
+    \x02\x12\x03\x04\x0b\x0c\n\x0b\n\x04\x05\0\x02\x01\x12\x03\x05\x02\r\n\
+    \x0c\n\x05\x05\0\x02\x01\x01\x12\x03\x05\x02\x08\n\x0c\n\x05\x05\0\x02\
+    \x01\x02\x12\x03\x05\x0b\x0c\n*\n\x04\x05\0\x02\x02\x12\x03\x07\x02\x0b\
+    \x1a\x1d\x20Any\x20OpenID\x20Connect\x20provider\n\n\x0c\n\x05\x05\0\x02\
+    \x02\x01\x12\x03\x07\x02\x06\n\x0c\n\x05\x05\0\x02\x02\x02\x12\x03\x07\t\
+    \n\n:\n\x02\x05\x01\x12\x04\x0b\0\x0f\x01\x1a.\x20What\x20a\x20personal\
+    \x20access\x20token\x20may\x20be\x20used\x20for\n\n\n\n\x03\x05\x01\x01\
+    \x12\x03\x0b\x05\x15\n\x0b\n\x04\x05\x01\x02\0\x12\x03\x0c\x02\x14\n\x0c\
+    \n\x05\x05\x01\x02\0\x01\x12\x03\x0c\x02\x0f\n\x0c\n\x05\x05\x01\x02\0\
+    \x02\x12\x03\x0c\x12\x13\n\x0b\n\x04\x05\x01\x02\x01\x12\x03\r\x02\x12\n\
+    \x0c\n\x05\x05\x01\x02\x01\x01\x12\x03\r\x02\r\n\x0c\n\x05\x05\x01\x02\
+    \x01\x02\x12\x03\r\x10\x11\n\x0b\n\x04\x05\x01\x02\x02\x12\x03\x0e\x02\
+    \x12\n\x0c\n\x05\x05\x01\x02\x02\x01\x12\x03\x0e\x02\r\n\x0c\n\x05\x05\
+    \x01\x02\x02\x02\x12\x03\x0e\x10\x11\n\xa5\x01\n\x02\x04\0\x12\x04\x13\0\
+    \x1c\x01\x1a\x98\x01\x20A\x20long-lived\x20token\x20to\x20authenticate\
+    \x20as\x20an\x20account\x20with,\x20limited\x20to\x20its\x20scopes.\x20O\
+    nly\x20a\x20hash\x20of\n\x20the\x20token\x20is\x20stored,\x20so\x20it's\
+    \x20only\x20returned\x20when\x20created.\n\n\n\n\x03\x04\0\x01\x12\x03\
+    \x13\x08\x13\n\x0b\n\x04\x04\0\x02\0\x12\x03\x14\x02\x19\n\x0c\n\x05\x04\
+    \0\x02\0\x04\x12\x03\x14\x02\n\n\x0c\n\x05\x04\0\x02\0\x05\x12\x03\x14\
+    \x0b\x11\n\x0c\n\x05\x04\0\x02\0\x01\x12\x03\x14\x12\x14\n\x0c\n\x05\x04\
+    \0\x02\0\x03\x12\x03\x14\x17\x18\n\x0b\n\x04\x04\0\x02\x01\x12\x03\x15\
+    \x02!\n\x0c\n\x05\x04\0\x02\x01\x04\x12\x03\x15\x02\n\n\x0c\n\x05\x04\0\
+    \x02\x01\x05\x12\x03\x15\x0b\x11\n\x0c\n\x05\x04\0\x02\x01\x01\x12\x03\
+    \x15\x12\x1c\n\x0c\n\x05\x04\0\x02\x01\x03\x12\x03\x15\x1f\x20\n\x0b\n\
+    \x04\x04\0\x02\x02\x12\x03\x16\x02\x1b\n\x0c\n\x05\x04\0\x02\x02\x04\x12\
+    \x03\x16\x02\n\n\x0c\n\x05\x04\0\x02\x02\x05\x12\x03\x16\x0b\x11\n\x0c\n\
+    \x05\x04\0\x02\x02\x01\x12\x03\x16\x12\x16\n\x0c\n\x05\x04\0\x02\x02\x03\
+    \x12\x03\x16\x19\x1a\n\x0b\n\x04\x04\0\x02\x03\x12\x03\x17\x02'\n\x0c\n\
+    \x05\x04\0\x02\x03\x04\x12\x03\x17\x02\n\n\x0c\n\x05\x04\0\x02\x03\x06\
+    \x12\x03\x17\x0b\x1b\n\x0c\n\x05\x04\0\x02\x03\x01\x12\x03\x17\x1c\"\n\
+    \x0c\n\x05\x04\0\x02\x03\x03\x12\x03\x17%&\n\x0b\n\x04\x04\0\x02\x04\x12\
+    \x03\x18\x02!\n\x0c\n\x05\x04\0\x02\x04\x04\x12\x03\x18\x02\n\n\x0c\n\
+    \x05\x04\0\x02\x04\x05\x12\x03\x18\x0b\x11\n\x0c\n\x05\x04\0\x02\x04\x01\
+    \x12\x03\x18\x12\x1c\n\x0c\n\x05\x04\0\x02\x04\x03\x12\x03\x18\x1f\x20\n\
+    \x0b\n\x04\x04\0\x02\x05\x12\x03\x19\x02#\n\x0c\n\x05\x04\0\x02\x05\x04\
+    \x12\x03\x19\x02\n\n\x0c\n\x05\x04\0\x02\x05\x05\x12\x03\x19\x0b\x11\n\
+    \x0c\n\x05\x04\0\x02\x05\x01\x12\x03\x19\x12\x1e\n\x0c\n\x05\x04\0\x02\
+    \x05\x03\x12\x03\x19!\"\nC\n\x04\x04\0\x02\x06\x12\x03\x1b\x02\x1c\x1a6\
+    \x20base64\x20encoded\x20binary\x20representation\x20of\x20SessionToken\
+    \n\n\x0c\n\x05\x04\0\x02\x06\x04\x12\x03\x1b\x02\n\n\x0c\n\x05\x04\0\x02\
+    \x06\x05\x12\x03\x1b\x0b\x11\n\x0c\n\x05\x04\0\x02\x06\x01\x12\x03\x1b\
+    \x12\x17\n\x0c\n\x05\x04\0\x02\x06\x03\x12\x03\x1b\x1a\x1b\n\n\n\x02\x04\
+    \x01\x12\x04\x1e\0\"\x01\n\n\n\x03\x04\x01\x01\x12\x03\x1e\x08\x19\n\x0b\
+    \n\x04\x04\x01\x02\0\x12\x03\x1f\x02!\n\x0c\n\x05\x04\x01\x02\0\x04\x12\
+    \x03\x1f\x02\n\n\x0c\n\x05\x04\x01\x02\0\x05\x12\x03\x1f\x0b\x11\n\x0c\n\
+    \x05\x04\x01\x02\0\x01\x12\x03\x1f\x12\x1c\n\x0c\n\x05\x04\x01\x02\0\x03\
+    \x12\x03\x1f\x1f\x20\n\x0b\n\x04\x04\x01\x02\x01\x12\x03\x20\x02\x1b\n\
+    \x0c\n\x05\x04\x01\x02\x01\x04\x12\x03\x20\x02\n\n\x0c\n\x05\x04\x01\x02\
+    \x01\x05\x12\x03\x20\x0b\x11\n\x0c\n\x05\x04\x01\x02\x01\x01\x12\x03\x20\
+    \x12\x16\n\x0c\n\x05\x04\x01\x02\x01\x03\x12\x03\x20\x19\x1a\n\x0b\n\x04\
+    \x04\x01\x02\x02\x12\x03!\x02'\n\x0c\n\x05\x04\x01\x02\x02\x04\x12\x03!\
+    \x02\n\n\x0c\n\x05\x04\x01\x02\x02\x06\x12\x03!\x0b\x1b\n\x0c\n\x05\x04\
+    \x01\x02\x02\x01\x12\x03!\x1c\"\n\x0c\n\x05\x04\x01\x02\x02\x03\x12\x03!\
+    %&\n\n\n\x02\x04\x02\x12\x04$\0&\x01\n\n\n\x03\x04\x02\x01\x12\x03$\x08\
+    \x1e\n\x0b\n\x04\x04\x02\x02\0\x12\x03%\x02!\n\x0c\n\x05\x04\x02\x02\0\
+    \x04\x12\x03%\x02\n\n\x0c\n\x05\x04\x02\x02\0\x05\x12\x03%\x0b\x11\n\x0c\
+    \n\x05\x04\x02\x02\0\x01\x12\x03%\x12\x1c\n\x0c\n\x05\x04\x02\x02\0\x03\
+    \x12\x03%\x1f\x20\n\n\n\x02\x04\x03\x12\x04(\0+\x01\n\n\n\x03\x04\x03\
+    \x01\x12\x03(\x08\x1f\n\x0b\n\x04\x04\x03\x02\0\x12\x03)\x02!\n\x0c\n\
+    \x05\x04\x03\x02\0\x04\x12\x03)\x02\n\n\x0c\n\x05\x04\x03\x02\0\x05\x12\
+    \x03)\x0b\x11\n\x0c\n\x05\x04\x03\x02\0\x01\x12\x03)\x12\x1c\n\x0c\n\x05\
+    \x04\x03\x02\0\x03\x12\x03)\x1f\x20\n\x0b\n\x04\x04\x03\x02\x01\x12\x03*\
+    \x02\"\n\x0c\n\x05\x04\x03\x02\x01\x04\x12\x03*\x02\n\n\x0c\n\x05\x04\
+    \x03\x02\x01\x06\x12\x03*\x0b\x16\n\x0c\n\x05\x04\x03\x02\x01\x01\x12\
+    \x03*\x17\x1d\n\x0c\n\x05\x04\x03\x02\x01\x03\x12\x03*\x20!\n\n\n\x02\
+    \x04\x04\x12\x04-\00\x01\n\n\n\x03\x04\x04\x01\x12\x03-\x08\x19\n\x0b\n\
+    \x04\x04\x04\x02\0\x12\x03.\x02!\n\x0c\n\x05\x04\x04\x02\0\x04\x12\x03.\
+    \x02\n\n\x0c\n\x05\x04\x04\x02\0\x05\x12\x03.\x0b\x11\n\x0c\n\x05\x04\
+    \x04\x02\0\x01\x12\x03.\x12\x1c\n\x0c\n\x05\x04\x04\x02\0\x03\x12\x03.\
+    \x1f\x20\n\x0b\n\x04\x04\x04\x02\x01\x12\x03/\x02\x19\n\x0c\n\x05\x04\
+    \x04\x02\x01\x04\x12\x03/\x02\n\n\x0c\n\x05\x04\x04\x02\x01\x05\x12\x03/\
+    \x0b\x11\n\x0c\n\x05\x04\x04\x02\x01\x01\x12\x03/\x12\x14\n\x0c\n\x05\
+    \x04\x04\x02\x01\x03\x12\x03/\x17\x18\n\n\n\x02\x04\x05\x12\x042\06\x01\
+    \n\n\n\x03\x04\x05\x01\x12\x032\x08\x0f\n\x0b\n\x04\x04\x05\x02\0\x12\
+    \x033\x02\x19\n\x0c\n\x05\x04\x05\x02\0\x04\x12\x033\x02\n\n\x0c\n\x05\
+    \x04\x05\x02\0\x05\x12\x033\x0b\x11\n\x0c\n\x05\x04\x05\x02\0\x01\x12\
+    \x033\x12\x14\n\x0c\n\x05\x04\x05\x02\0\x03\x12\x033\x17\x18\n\x0b\n\x04\
+    \x04\x05\x02\x01\x12\x034\x02\x1c\n\x0c\n\x05\x04\x05\x02\x01\x04\x12\
+    \x034\x02\n\n\x0c\n\x05\x04\x05\x02\x01\x05\x12\x034\x0b\x11\n\x0c\n\x05\
+    \x04\x05\x02\x01\x01\x12\x034\x12\x17\n\x0c\n\x05\x04\x05\x02\x01\x03\
+    \x12\x034\x1a\x1b\n\x0b\n\x04\x04\x05\x02\x02\x12\x035\x02\x1b\n\x0c\n\
+    \x05\x04\x05\x02\x02\x04\x12\x035\x02\n\n\x0c\n\x05\x04\x05\x02\x02\x05\
+    \x12\x035\x0b\x11\n\x0c\n\x05\x04\x05\x02\x02\x01\x12\x035\x12\x16\n\x0c\
+    \n\x05\x04\x05\x02\x02\x03\x12\x035\x19\x1a\n\n\n\x02\x04\x06\x12\x048\0\
+    ;\x01\n\n\n\x03\x04\x06\x01\x12\x038\x08\x1b\n\x0b\n\x04\x04\x06\x02\0\
+    \x12\x039\x02\x1b\n\x0c\n\x05\x04\x06\x02\0\x04\x12\x039\x02\n\n\x0c\n\
+    \x05\x04\x06\x02\0\x05\x12\x039\x0b\x11\n\x0c\n\x05\x04\x06\x02\0\x01\
+    \x12\x039\x12\x16\n\x0c\n\x05\x04\x06\x02\0\x03\x12\x039\x19\x1a\n\x0b\n\
+    \x04\x04\x06\x02\x01\x12\x03:\x02\x1c\n\x0c\n\x05\x04\x06\x02\x01\x04\
+    \x12\x03:\x02\n\n\x0c\n\x05\x04\x06\x02\x01\x05\x12\x03:\x0b\x11\n\x0c\n\
+    \x05\x04\x06\x02\x01\x01\x12\x03:\x12\x17\n\x0c\n\x05\x04\x06\x02\x01\
+    \x03\x12\x03:\x1a\x1b\n+\n\x02\x04\x07\x12\x04>\0@\x01\x1a\x1f\x20get\
+    \x20an\x20account\x20by\x20GH\x20username\n\n\n\n\x03\x04\x07\x01\x12\
+    \x03>\x08\x12\n\x0b\n\x04\x04\x07\x02\0\x12\x03?\x02\x1b\n\x0c\n\x05\x04\
+    \x07\x02\0\x04\x12\x03?\x02\n\n\x0c\n\x05\x04\x07\x02\0\x05\x12\x03?\x0b\
+    \x11\n\x0c\n\x05\x04\x07\x02\0\x01\x12\x03?\x12\x16\n\x0c\n\x05\x04\x07\
+    \x02\0\x03\x12\x03?\x19\x1a\n\n\n\x02\x04\x08\x12\x04B\0D\x01\n\n\n\x03\
+    \x04\x08\x01\x12\x03B\x08\x14\n\x0b\n\x04\x04\x08\x02\0\x12\x03C\x02\x19\
+    \n\x0c\n\x05\x04\x08\x02\0\x04\x12\x03C\x02\n\n\x0c\n\x05\x04\x08\x02\0\
+    \x05\x12\x03C\x0b\x11\n\x0c\n\x05\x04\x08\x02\0\x01\x12\x03C\x12\x14\n\
+    \x0c\n\x05\x04\x08\x02\0\x03\x12\x03C\x17\x18\n\n\n\x02\x04\t\x12\x04F\0\
+    I\x01\n\n\n\x03\x04\t\x01\x12\x03F\x08\x15\n\x0b\n\x04\x04\t\x02\0\x12\
+    \x03G\x02\x1b\n\x0c\n\x05\x04\t\x02\0\x04\x12\x03G\x02\n\n\x0c\n\x05\x04\
+    \t\x02\0\x05\x12\x03G\x0b\x11\n\x0c\n\x05\x04\t\x02\0\x01\x12\x03G\x12\
+    \x16\n\x0c\n\x05\x04\t\x02\0\x03\x12\x03G\x19\x1a\n\x0b\n\x04\x04\t\x02\
+    \x01\x12\x03H\x02\x1c\n\x0c\n\x05\x04\t\x02\x01\x04\x12\x03H\x02\n\n\x0c\
+    \n\x05\x04\t\x02\x01\x05\x12\x03H\x0b\x11\n\x0c\n\x05\x04\t\x02\x01\x01\
+    \x12\x03H\x12\x17\n\x0c\n\x05\x04\t\x02\x01\x03\x12\x03H\x1a\x1b\n\n\n\
+    \x02\x04\n\x12\x04K\0N\x01\n\n\n\x03\x04\n\x01\x12\x03K\x08\x15\n\x0b\n\
+    \x04\x04\n\x02\0\x12\x03L\x02\x19\n\x0c\n\x05\x04\n\x02\0\x04\x12\x03L\
+    \x02\n\n\x0c\n\x05\x04\n\x02\0\x05\x12\x03L\x0b\x11\n\x0c\n\x05\x04\n\
+    \x02\0\x01\x12\x03L\x12\x14\n\x0c\n\x05\x04\n\x02\0\x03\x12\x03L\x17\x18\
+    \n\x0b\n\x04\x04\n\x02\x01\x12\x03M\x02\x1c\n\x0c\n\x05\x04\n\x02\x01\
+    \x04\x12\x03M\x02\n\n\x0c\n\x05\x04\n\x02\x01\x05\x12\x03M\x0b\x11\n\x0c\
+    \n\x05\x04\n\x02\x01\x01\x12\x03M\x12\x17\n\x0c\n\x05\x04\n\x02\x01\x03\
+    \x12\x03M\x1a\x1b\n\n\n\x02\x04\x0b\x12\x04P\0X\x01\n\n\n\x03\x04\x0b\
+    \x01\x12\x03P\x08\x1f\n\x0b\n\x04\x04\x0b\x02\0\x12\x03Q\x02\x19\n\x0c\n\
+    \x05\x04\x0b\x02\0\x04\x12\x03Q\x02\n\n\x0c\n\x05\x04\x0b\x02\0\x05\x12\
+    \x03Q\x0b\x11\n\x0c\n\x05\x04\x0b\x02\0\x01\x12\x03Q\x12\x14\n\x0c\n\x05\
+    \x04\x0b\x02\0\x03\x12\x03Q\x17\x18\n\x0b\n\x04\x04\x0b\x02\x01\x12\x03R\
+    \x02+\n\x0c\n\x05\x04\x0b\x02\x01\x04\x12\x03R\x02\n\n\x0c\n\x05\x04\x0b\
+    \x02\x01\x05\x12\x03R\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x01\x01\x12\x03R\
+    \x12&\n\x0c\n\x05\x04\x0b\x02\x01\x03\x12\x03R)*\n\x0b\n\x04\x04\x0b\x02\
+    \x02\x12\x03S\x02!\n\x0c\n\x05\x04\x0b\x02\x02\x04\x12\x03S\x02\n\n\x0c\
+    \n\x05\x04\x0b\x02\x02\x05\x12\x03S\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x02\
+    \x01\x12\x03S\x12\x1c\n\x0c\n\x05\x04\x0b\x02\x02\x03\x12\x03S\x1f\x20\n\
+    \x0b\n\x04\x04\x0b\x02\x03\x12\x03T\x02#\n\x0c\n\x05\x04\x0b\x02\x03\x04\
+    \x12\x03T\x02\n\n\x0c\n\x05\x04\x0b\x02\x03\x05\x12\x03T\x0b\x11\n\x0c\n\
+    \x05\x04\x0b\x02\x03\x01\x12\x03T\x12\x1e\n\x0c\n\x05\x04\x0b\x02\x03\
+    \x03\x12\x03T!\"\n\x0b\n\x04\x04\x0b\x02\x04\x12\x03U\x02\x20\n\x0c\n\
+    \x05\x04\x0b\x02\x04\x04\x12\x03U\x02\n\n\x0c\n\x05\x04\x0b\x02\x04\x05\
+    \x12\x03U\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x04\x01\x12\x03U\x12\x1b\n\x0c\
+    \n\x05\x04\x0b\x02\x04\x03\x12\x03U\x1e\x1f\n\x0b\n\x04\x04\x0b\x02\x05\
+    \x12\x03V\x02\"\n\x0c\n\x05\x04\x0b\x02\x05\x04\x12\x03V\x02\n\n\x0c\n\
+    \x05\x04\x0b\x02\x05\x05\x12\x03V\x0b\x11\n\x0c\n\x05\x04\x0b\x02\x05\
+    \x01\x12\x03V\x12\x1d\n\x0c\n\x05\x04\x0b\x02\x05\x03\x12\x03V\x20!\n\
+    \x0b\n\x04\x04\x0b\x02\x06\x12\x03W\x02\x1f\n\x0c\n\x05\x04\x0b\x02\x06\
+    \x04\x12\x03W\x02\n\n\x0c\n\x05\x04\x0b\x02\x06\x05\x12\x03W\x0b\x11\n\
+    \x0c\n\x05\x04\x0b\x02\x06\x01\x12\x03W\x12\x1a\n\x0c\n\x05\x04\x0b\x02\
+    \x06\x03\x12\x03W\x1d\x1e\n\n\n\x02\x04\x0c\x12\x04Z\0a\x01\n\n\n\x03\
+    \x04\x0c\x01\x12\x03Z\x08%\n\x0b\n\x04\x04\x0c\x02\0\x12\x03[\x02+\n\x0c\
+    \n\x05\x04\x0c\x02\0\x04\x12\x03[\x02\n\n\x0c\n\x05\x04\x0c\x02\0\x05\
+    \x12\x03[\x0b\x11\n\x0c\n\x05\x04\x0c\x02\0\x01\x12\x03[\x12&\n\x0c\n\
+    \x05\x04\x0c\x02\0\x03\x12\x03[)*\n\x0b\n\x04\x04\x0c\x02\x01\x12\x03\\\
+    \x02!\n\x0c\n\x05\x04\x0c\x02\x01\x04\x12\x03\\\x02\n\n\x0c\n\x05\x04\
+    \x0c\x02\x01\x05\x12\x03\\\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x01\x01\x12\
+    \x03\\\x12\x1c\n\x0c\n\x05\x04\x0c\x02\x01\x03\x12\x03\\\x1f\x20\n\x0b\n\
+    \x04\x04\x0c\x02\x02\x12\x03]\x02#\n\x0c\n\x05\x04\x0c\x02\x02\x04\x12\
+    \x03]\x02\n\n\x0c\n\x05\x04\x0c\x02\x02\x05\x12\x03]\x0b\x11\n\x0c\n\x05\
+    \x04\x0c\x02\x02\x01\x12\x03]\x12\x1e\n\x0c\n\x05\x04\x0c\x02\x02\x03\
+    \x12\x03]!\"\n\x0b\n\x04\x04\x0c\x02\x03\x12\x03^\x02\x20\n\x0c\n\x05\
+    \x04\x0c\x02\x03\x04\x12\x03^\x02\n\n\x0c\n\x05\x04\x0c\x02\x03\x05\x12\
+    \x03^\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x03\x01\x12\x03^\x12\x1b\n\x0c\n\
+    \x05\x04\x0c\x02\x03\x03\x12\x03^\x1e\x1f\n\x0b\n\x04\x04\x0c\x02\x04\
+    \x12\x03_\x02\"\n\x0c\n\x05\x04\x0c\x02\x04\x04\x12\x03_\x02\n\n\x0c\n\
+    \x05\x04\x0c\x02\x04\x05\x12\x03_\x0b\x11\n\x0c\n\x05\x04\x0c\x02\x04\
+    \x01\x12\x03_\x12\x1d\n\x0c\n\x05\x04\x0c\x02\x04\x03\x12\x03_\x20!\n\
+    \x0b\n\x04\x04\x0c\x02\x05\x12\x03`\x02\x1f\n\x0c\n\x05\x04\x0c\x02\x05\
+    \x04\x12\x03`\x02\n\n\x0c\n\x05\x04\x0c\x02\x05\x05\x12\x03`\x0b\x11\n\
+    \x0c\n\x05\x04\x0c\x02\x05\x01\x12\x03`\x12\x1a\n\x0c\n\x05\x04\x0c\x02\
+    \x05\x03\x12\x03`\x1d\x1e\n\n\n\x02\x04\r\x12\x04c\0h\x01\n\n\n\x03\x04\
+    \r\x01\x12\x03c\x08,\n\x0b\n\x04\x04\r\x02\0\x12\x03d\x02!\n\x0c\n\x05\
+    \x04\r\x02\0\x04\x12\x03d\x02\n\n\x0c\n\x05\x04\r\x02\0\x05\x12\x03d\x0b\
+    \x11\n\x0c\n\x05\x04\r\x02\0\x01\x12\x03d\x12\x1c\n\x0c\n\x05\x04\r\x02\
+    \0\x03\x12\x03d\x1f\x20\n\x0b\n\x04\x04\r\x02\x01\x12\x03e\x02\x20\n\x0c\
+    \n\x05\x04\r\x02\x01\x04\x12\x03e\x02\n\n\x0c\n\x05\x04\r\x02\x01\x05\
+    \x12\x03e\x0b\x11\n\x0c\n\x05\x04\r\x02\x01\x01\x12\x03e\x12\x1b\n\x0c\n\
+    \x05\x04\r\x02\x01\x03\x12\x03e\x1e\x1f\n\x0b\n\x04\x04\r\x02\x02\x12\
+    \x03f\x02\"\n\x0c\n\x05\x04\r\x02\x02\x04\x12\x03f\x02\n\n\x0c\n\x05\x04\
+    \r\x02\x02\x05\x12\x03f\x0b\x11\n\x0c\n\x05\x04\r\x02\x02\x01\x12\x03f\
+    \x12\x1d\n\x0c\n\x05\x04\r\x02\x02\x03\x12\x03f\x20!\n\x0b\n\x04\x04\r\
+    \x02\x03\x12\x03g\x02\x1b\n\x0c\n\x05\x04\r\x02\x03\x04\x12\x03g\x02\n\n\
+    \x0c\n\x05\x04\r\x02\x03\x05\x12\x03g\x0b\x0f\n\x0c\n\x05\x04\r\x02\x03\
+    \x01\x12\x03g\x10\x16\n\x0c\n\x05\x04\r\x02\x03\x03\x12\x03g\x19\x1a\n\n\
+    \n\x02\x04\x0e\x12\x04j\0m\x01\n\n\n\x03\x04\x0e\x01\x12\x03j\x08,\n\x0b\
+    \n\x04\x04\x0e\x02\0\x12\x03k\x02!\n\x0c\n\x05\x04\x0e\x02\0\x04\x12\x03\
+    k\x02\n\n\x0c\n\x05\x04\x0e\x02\0\x05\x12\x03k\x0b\x11\n\x0c\n\x05\x04\
+    \x0e\x02\0\x01\x12\x03k\x12\x1c\n\x0c\n\x05\x04\x0e\x02\0\x03\x12\x03k\
+    \x1f\x20\n\x0b\n\x04\x04\x0e\x02\x01\x12\x03l\x02$\n\x0c\n\x05\x04\x0e\
+    \x02\x01\x04\x12\x03l\x02\n\n\x0c\n\x05\x04\x0e\x02\x01\x05\x12\x03l\x0b\
+    \x11\n\x0c\n\x05\x04\x0e\x02\x01\x01\x12\x03l\x12\x1f\n\x0c\n\x05\x04\
+    \x0e\x02\x01\x03\x12\x03l\"#\n\n\n\x02\x04\x0f\x12\x04o\0r\x01\n\n\n\x03\
+    \x04\x0f\x01\x12\x03o\x08-\n\x0b\n\x04\x04\x0f\x02\0\x12\x03p\x02!\n\x0c\
+    \n\x05\x04\x0f\x02\0\x04\x12\x03p\x02\n\n\x0c\n\x05\x04\x0f\x02\0\x05\
+    \x12\x03p\x0b\x11\n\x0c\n\x05\x04\x0f\x02\0\x01\x12\x03p\x12\x1c\n\x0c\n\
+    \x05\x04\x0f\x02\0\x03\x12\x03p\x1f\x20\n\x0b\n\x04\x04\x0f\x02\x01\x12\
+    \x03q\x02$\n\x0c\n\x05\x04\x0f\x02\x01\x04\x12\x03q\x02\n\n\x0c\n\x05\
+    \x04\x0f\x02\x01\x05\x12\x03q\x0b\x11\n\x0c\n\x05\x04\x0f\x02\x01\x01\
+    \x12\x03q\x12\x1f\n\x0c\n\x05\x04\x0f\x02\x01\x03\x12\x03q\"#\n\n\n\x02\
+    \x04\x10\x12\x04t\0v\x01\n\n\n\x03\x04\x10\x01\x12\x03t\x08$\n\x0b\n\x04\
+    \x04\x10\x02\0\x12\x03u\x02!\n\x0c\n\x05\x04\x10\x02\0\x04\x12\x03u\x02\
+    \n\n\x0c\n\x05\x04\x10\x02\0\x05\x12\x03u\x0b\x11\n\x0c\n\x05\x04\x10\
+    \x02\0\x01\x12\x03u\x12\x1c\n\x0c\n\x05\x04\x10\x02\0\x03\x12\x03u\x1f\
+    \x20\n\n\n\x02\x04\x11\x12\x04x\0{\x01\n\n\n\x03\x04\x11\x01\x12\x03x\
+    \x08%\n\x0b\n\x04\x04\x11\x02\0\x12\x03y\x02!\n\x0c\n\x05\x04\x11\x02\0\
+    \x04\x12\x03y\x02\n\n\x0c\n\x05\x04\x11\x02\0\x05\x12\x03y\x0b\x11\n\x0c\
+    \n\x05\x04\x11\x02\0\x01\x12\x03y\x12\x1c\n\x0c\n\x05\x04\x11\x02\0\x03\
+    \x12\x03y\x1f\x20\n\x0b\n\x04\x04\x11\x02\x01\x12\x03z\x023\n\x0c\n\x05\
+    \x04\x11\x02\x01\x04\x12\x03z\x02\n\n\x0c\n\x05\x04\x11\x02\x01\x06\x12\
+    \x03z\x0b\"\n\x0c\n\x05\x04\x11\x02\x01\x01\x12\x03z#.\n\x0c\n\x05\x04\
+    \x11\x02\x01\x03\x12\x03z12\n\x0b\n\x02\x04\x12\x12\x05}\0\x82\x01\x01\n\
+    \n\n\x03\x04\x12\x01\x12\x03}\x08\x1b\n\x0b\n\x04\x04\x12\x02\0\x12\x03~\
+    \x02!\n\x0c\n\x05\x04\x12\x02\0\x04\x12\x03~\x02\n\n\x0c\n\x05\x04\x12\
+    \x02\0\x05\x12\x03~\x0b\x11\n\x0c\n\x05\x04\x12\x02\0\x01\x12\x03~\x12\
+    \x1c\n\x0c\n\x05\x04\x12\x02\0\x03\x12\x03~\x1f\x20\n\x0b\n\x04\x04\x12\
+    \x02\x01\x12\x03\x7f\x02#\n\x0c\n\x05\x04\x12\x02\x01\x04\x12\x03\x7f\
+    \x02\n\n\x0c\n\x05\x04\x12\x02\x01\x05\x12\x03\x7f\x0b\x11\n\x0c\n\x05\
+    \x04\x12\x02\x01\x01\x12\x03\x7f\x12\x1e\n\x0c\n\x05\x04\x12\x02\x01\x03\
+    \x12\x03\x7f!\"\n\x0c\n\x04\x04\x12\x02\x02\x12\x04\x80\x01\x02\x20\n\r\
+    \n\x05\x04\x12\x02\x02\x04\x12\x04\x80\x01\x02\n\n\r\n\x05\x04\x12\x02\
+    \x02\x05\x12\x04\x80\x01\x0b\x11\n\r\n\x05\x04\x12\x02\x02\x01\x12\x04\
+    \x80\x01\x12\x1b\n\r\n\x05\x04\x12\x02\x02\x03\x12\x04\x80\x01\x1e\x1f\n\
+    \x0c\n\x04\x04\x12\x02\x03\x12\x04\x81\x01\x02\"\n\r\n\x05\x04\x12\x02\
+    \x03\x04\x12\x04\x81\x01\x02\n\n\r\n\x05\x04\x12\x02\x03\x05\x12\x04\x81\
+    \x01\x0b\x11\n\r\n\x05\x04\x12\x02\x03\x01\x12\x04\x81\x01\x12\x1d\n\r\n\
+    \x05\x04\x12\x02\x03\x03\x12\x04\x81\x01\x20!\n\x0c\n\x02\x04\x13\x12\
+    \x06\x84\x01\0\x86\x01\x01\n\x0b\n\x03\x04\x13\x01\x12\x04\x84\x01\x08\
+    \x20\n\x0c\n\x04\x04\x13\x02\0\x12\x04\x85\x01\x02!\n\r\n\x05\x04\x13\
+    \x02\0\x04\x12\x04\x85\x01\x02\n\n\r\n\x05\x04\x13\x02\0\x05\x12\x04\x85\
+    \x01\x0b\x11\n\r\n\x05\x04\x13\x02\0\x01\x12\x04\x85\x01\x12\x1c\n\r\n\
+    \x05\x04\x13\x02\0\x03\x12\x04\x85\x01\x1f\x20\n\x0c\n\x02\x04\x14\x12\
+    \x06\x88\x01\0\x8b\x01\x01\n\x0b\n\x03\x04\x14\x01\x12\x04\x88\x01\x08!\
+    \n\x0c\n\x04\x04\x14\x02\0\x12\x04\x89\x01\x02!\n\r\n\x05\x04\x14\x02\0\
+    \x04\x12\x04\x89\x01\x02\n\n\r\n\x05\x04\x14\x02\0\x05\x12\x04\x89\x01\
+    \x0b\x11\n\r\n\x05\x04\x14\x02\0\x01\x12\x04\x89\x01\x12\x1c\n\r\n\x05\
+    \x04\x14\x02\0\x03\x12\x04\x89\x01\x1f\x20\n\x0c\n\x04\x04\x14\x02\x01\
+    \x12\x04\x8a\x01\x02\x1e\n\r\n\x05\x04\x14\x02\x01\x04\x12\x04\x8a\x01\
+    \x02\n\n\r\n\x05\x04\x14\x02\x01\x05\x12\x04\x8a\x01\x0b\x11\n\r\n\x05\
+    \x04\x14\x02\x01\x01\x12\x04\x8a\x01\x12\x19\n\r\n\x05\x04\x14\x02\x01\
+    \x03\x12\x04\x8a\x01\x1c\x1d\n\x0c\n\x02\x04\x15\x12\x06\x8d\x01\0\x90\
+    \x01\x01\n\x0b\n\x03\x04\x15\x01\x12\x04\x8d\x01\x08\x1b\n\x0c\n\x04\x04\
+    \x15\x02\0\x12\x04\x8e\x01\x02#\n\r\n\x05\x04\x15\x02\0\x04\x12\x04\x8e\
+    \x01\x02\n\n\r\n\x05\x04\x15\x02\0\x05\x12\x04\x8e\x01\x0b\x11\n\r\n\x05\
+    \x04\x15\x02\0\x01\x12\x04\x8e\x01\x12\x1e\n\r\n\x05\x04\x15\x02\0\x03\
+    \x12\x04\x8e\x01!\"\n\x0c\n\x04\x04\x15\x02\x01\x12\x04\x8f\x01\x02\x20\
+    \n\r\n\x05\x04\x15\x02\x01\x04\x12\x04\x8f\x01\x02\n\n\r\n\x05\x04\x15\
+    \x02\x01\x05\x12\x04\x8f\x01\x0b\x11\n\r\n\x05\x04\x15\x02\x01\x01\x12\
+    \x04\x8f\x01\x12\x1b\n\r\n\x05\x04\x15\x02\x01\x03\x12\x04\x8f\x01\x1e\
+    \x1f\n\x0c\n\x02\x04\x16\x12\x06\x92\x01\0\x9d\x01\x01\n\x0b\n\x03\x04\
+    \x16\x01\x12\x04\x92\x01\x08\x0f\n\x0c\n\x04\x04\x16\x02\0\x12\x04\x93\
+    \x01\x02\x19\n\r\n\x05\x04\x16\x02\0\x04\x12\x04\x93\x01\x02\n\n\r\n\x05\
+    \x04\x16\x02\0\x05\x12\x04\x93\x01\x0b\x11\n\r\n\x05\x04\x16\x02\0\x01\
+    \x12\x04\x93\x01\x12\x14\n\r\n\x05\x04\x16\x02\0\x03\x12\x04\x93\x01\x17\
+    \x18\n\x0c\n\x04\x04\x16\x02\x01\x12\x04\x94\x01\x02\x1c\n\r\n\x05\x04\
+    \x16\x02\x01\x04\x12\x04\x94\x01\x02\n\n\r\n\x05\x04\x16\x02\x01\x05\x12\
+    \x04\x94\x01\x0b\x11\n\r\n\x05\x04\x16\x02\x01\x01\x12\x04\x94\x01\x12\
+    \x17\n\r\n\x05\x04\x16\x02\x01\x03\x12\x04\x94\x01\x1a\x1b\n\x0c\n\x04\
+    \x04\x16\x02\x02\x12\x04\x95\x01\x02\x1b\n\r\n\x05\x04\x16\x02\x02\x04\
+    \x12\x04\x95\x01\x02\n\n\r\n\x05\x04\x16\x02\x02\x05\x12\x04\x95\x01\x0b\
+    \x11\n\r\n\x05\x04\x16\x02\x02\x01\x12\x04\x95\x01\x12\x16\n\r\n\x05\x04\
+    \x16\x02\x02\x03\x12\x04\x95\x01\x19\x1a\nD\n\x04\x04\x16\x02\x03\x12\
+    \x04\x97\x01\x02\x1c\x1a6\x20base64\x20encoded\x20binary\x20representati\
+    on\x20of\x20SessionToken\n\n\r\n\x05\x04\x16\x02\x03\x04\x12\x04\x97\x01\
+    \x02\n\n\r\n\x05\x04\x16\x02\x03\x05\x12\x04\x97\x01\x0b\x11\n\r\n\x05\
+    \x04\x16\x02\x03\x01\x12\x04\x97\x01\x12\x17\n\r\n\x05\x04\x16\x02\x03\
+    \x03\x12\x04\x97\x01\x1a\x1b\n\x0c\n\x04\x04\x16\x02\x04\x12\x04\x98\x01\
+    \x02\x1c\n\r\n\x05\x04\x16\x02\x04\x04\x12\x04\x98\x01\x02\n\n\r\n\x05\
+    \x04\x16\x02\x04\x05\x12\x04\x98\x01\x0b\x11\n\r\n\x05\x04\x16\x02\x04\
+    \x01\x12\x04\x98\x01\x12\x17\n\r\n\x05\x04\x16\x02\x04\x03\x12\x04\x98\
+    \x01\x1a\x1b\n\x0c\n\x04\x04\x16\x02\x05\x12\x04\x99\x01\x02\"\n\r\n\x05\
+    \x04\x16\x02\x05\x04\x12\x04\x99\x01\x02\n\n\r\n\x05\x04\x16\x02\x05\x05\
+    \x12\x04\x99\x01\x0b\x11\n\r\n\x05\x04\x16\x02\x05\x01\x12\x04\x99\x01\
+    \x12\x1d\n\r\n\x05\x04\x16\x02\x05\x03\x12\x04\x99\x01\x20!\nQ\n\x04\x04\
+    \x16\x02\x06\x12\x04\x9b\x01\x02&\x1aC\x20Set\x20if\x20the\x20session\
+    \x20was\x20authenticated\x20with\x20a\x20personal\x20access\x20token\n\n\
+    \r\n\x05\x04\x16\x02\x06\x04\x12\x04\x9b\x01\x02\n\n\r\n\x05\x04\x16\x02\
+    \x06\x05\x12\x04\x9b\x01\x0b\x11\n\r\n\x05\x04\x16\x02\x06\x01\x12\x04\
+    \x9b\x01\x12!\n\r\n\x05\x04\x16\x02\x06\x03\x12\x04\x9b\x01$%\n\x0c\n\
+    \x04\x04\x16\x02\x07\x12\x04\x9c\x01\x02'\n\r\n\x05\x04\x16\x02\x07\x04\
+    \x12\x04\x9c\x01\x02\n\n\r\n\x05\x04\x16\x02\x07\x06\x12\x04\x9c\x01\x0b\
+    \x1b\n\r\n\x05\x04\x16\x02\x07\x01\x12\x04\x9c\x01\x1c\"\n\r\n\x05\x04\
+    \x16\x02\x07\x03\x12\x04\x9c\x01%&\n\x0c\n\x02\x04\x17\x12\x06\x9f\x01\0\
+    \xa6\x01\x01\n\x0b\n\x03\x04\x17\x01\x12\x04\x9f\x01\x08\x15\n\x0c\n\x04\
+    \x04\x17\x02\0\x12\x04\xa0\x01\x02\x1c\n\r\n\x05\x04\x17\x02\0\x04\x12\
+    \x04\xa0\x01\x02\n\n\r\n\x05\x04\x17\x02\0\x05\x12\x04\xa0\x01\x0b\x11\n\
+    \r\n\x05\x04\x17\x02\0\x01\x12\x04\xa0\x01\x12\x17\n\r\n\x05\x04\x17\x02\
+    \0\x03\x12\x04\xa0\x01\x1a\x1b\n\x0c\n\x04\x04\x17\x02\x01\x12\x04\xa1\
+    \x01\x02\x20\n\r\n\x05\x04\x17\x02\x01\x04\x12\x04\xa1\x01\x02\n\n\r\n\
+    \x05\x04\x17\x02\x01\x05\x12\x04\xa1\x01\x0b\x11\n\r\n\x05\x04\x17\x02\
+    \x01\x01\x12\x04\xa1\x01\x12\x1b\n\r\n\x05\x04\x17\x02\x01\x03\x12\x04\
+    \xa1\x01\x1e\x1f\n\x0c\n\x04\x04\x17\x02\x02\x12\x04\xa2\x01\x02\x1c\n\r\
+    \n\x05\x04\x17\x02\x02\x04\x12\x04\xa2\x01\x02\n\n\r\n\x05\x04\x17\x02\
+    \x02\x05\x12\x04\xa2\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x02\x01\x12\x04\
+    \xa2\x01\x12\x17\n\r\n\x05\x04\x17\x02\x02\x03\x12\x04\xa2\x01\x1a\x1b\n\
+    \x0c\n\x04\x04\x17\x02\x03\x12\x04\xa3\x01\x02\x1b\n\r\n\x05\x04\x17\x02\
+    \x03\x04\x12\x04\xa3\x01\x02\n\n\r\n\x05\x04\x17\x02\x03\x05\x12\x04\xa3\
+    \x01\x0b\x11\n\r\n\x05\x04\x17\x02\x03\x01\x12\x04\xa3\x01\x12\x16\n\r\n\
+    \x05\x04\x17\x02\x03\x03\x12\x04\xa3\x01\x19\x1a\n\x0c\n\x04\x04\x17\x02\
+    \x04\x12\x04\xa4\x01\x02&\n\r\n\x05\x04\x17\x02\x04\x04\x12\x04\xa4\x01\
+    \x02\n\n\r\n\x05\x04\x17\x02\x04\x06\x12\x04\xa4\x01\x0b\x18\n\r\n\x05\
+    \x04\x17\x02\x04\x01\x12\x04\xa4\x01\x19!\n\r\n\x05\x04\x17\x02\x04\x03\
+    \x12\x04\xa4\x01$%\n\x0c\n\x04\x04\x17\x02\x05\x12\x04\xa5\x01\x02\x20\n\
+    \r\n\x05\x04\x17\x02\x05\x04\x12\x04\xa5\x01\x02\n\n\r\n\x05\x04\x17\x02\
+    \x05\x05\x12\x04\xa5\x01\x0b\x11\n\r\n\x05\x04\x17\x02\x05\x01\x12\x04\
+    \xa5\x01\x12\x1b\n\r\n\x05\x04\x17\x02\x05\x03\x12\x04\xa5\x01\x1e\x1f\n\
+    \x0c\n\x02\x04\x18\x12\x06\xa8\x01\0\xaa\x01\x01\n\x0b\n\x03\x04\x18\x01\
+    \x12\x04\xa8\x01\x08\x12\n\x0c\n\x04\x04\x18\x02\0\x12\x04\xa9\x01\x02\"\
+    \n\r\n\x05\x04\x18\x02\0\x04\x12\x04\xa9\x01\x02\n\n\r\n\x05\x04\x18\x02\
+    \0\x06\x12\x04\xa9\x01\x0b\x17\n\r\n\x05\x04\x18\x02\0\x01\x12\x04\xa9\
+    \x01\x18\x1d\n\r\n\x05\x04\x18\x02\0\x03\x12\x04\xa9\x01\x20!\n\x0c\n\
+    \x02\x04\x19\x12\x06\xac\x01\0\xb3\x01\x01\n\x0b\n\x03\x04\x19\x01\x12\
+    \x04\xac\x01\x08\x14\n\x0c\n\x04\x04\x19\x02\0\x12\x04\xad\x01\x02!\n\r\
+    \n\x05\x04\x19\x02\0\x04\x12\x04\xad\x01\x02\n\n\r\n\x05\x04\x19\x02\0\
+    \x05\x12\x04\xad\x01\x0b\x11\n\r\n\x05\x04\x19\x02\0\x01\x12\x04\xad\x01\
+    \x12\x1c\n\r\n\x05\x04\x19\x02\0\x03\x12\x04\xad\x01\x1f\x20\n\x0c\n\x04\
+    \x04\x19\x02\x01\x12\x04\xae\x01\x02\x20\n\r\n\x05\x04\x19\x02\x01\x04\
+    \x12\x04\xae\x01\x02\n\n\r\n\x05\x04\x19\x02\x01\x05\x12\x04\xae\x01\x0b\
+    \x11\n\r\n\x05\x04\x19\x02\x01\x01\x12\x04\xae\x01\x12\x1b\n\r\n\x05\x04\
+    \x19\x02\x01\x03\x12\x04\xae\x01\x1e\x1f\n\x0c\n\x04\x04\x19\x02\x02\x12\
+    \x04\xaf\x01\x02&\n\r\n\x05\x04\x19\x02\x02\x04\x12\x04\xaf\x01\x02\n\n\
+    \r\n\x05\x04\x19\x02\x02\x06\x12\x04\xaf\x01\x0b\x18\n\r\n\x05\x04\x19\
+    \x02\x02\x01\x12\x04\xaf\x01\x19!\n\r\n\x05\x04\x19\x02\x02\x03\x12\x04\
+    \xaf\x01$%\n\x0c\n\x04\x04\x19\x02\x03\x12\x04\xb0\x01\x02\x1b\n\r\n\x05\
+    \x04\x19\x02\x03\x04\x12\x04\xb0\x01\x02\n\n\r\n\x05\x04\x19\x02\x03\x05\
+    \x12\x04\xb0\x01\x0b\x10\n\r\n\x05\x04\x19\x02\x03\x01\x12\x04\xb0\x01\
+    \x11\x16\n\r\n\x05\x04\x19\x02\x03\x03\x12\x04\xb0\x01\x19\x1a\n.\n\x04\
+    \x04\x19\x02\x04\x12\x04\xb2\x01\x02&\x1a\x20\x20Set\x20for\x20personal\
+    \x20access\x20tokens\n\n\r\n\x05\x04\x19\x02\x04\x04\x12\x04\xb2\x01\x02\
+    \n\n\r\n\x05\x04\x19\x02\x04\x05\x12\x04\xb2\x01\x0b\x11\n\r\n\x05\x04\
+    \x19\x02\x04\x01\x12\x04\xb2\x01\x12!\n\r\n\x05\x04\x19\x02\x04\x03\x12\
+    \x04\xb2\x01$%\
 ";
 
 static mut file_descriptor_proto_lazy: ::protobuf::lazy::Lazy<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::lazy::Lazy {
@@ -16,7 +16,7 @@ use std::fmt;
 use std::str::FromStr;
 
 use protobuf::core::ProtobufEnum;
-use serde::{Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use serde::ser::SerializeStruct;
 
 pub use message::{ErrCode, Message, NetError, NetOk, Protocol, RouteInfo, Txn};
@@ -41,6 +41,18 @@ impl Serialize for ErrCode {
     }
 }
 
+impl<'de> Deserialize<'de> for ErrCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = u64::deserialize(deserializer)?;
+        ErrCode::from_i32(value as i32).ok_or_else(|| {
+            de::Error::custom(format!("unknown error code {}", value))
+        })
+    }
+}
+
 impl fmt::Display for NetError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "[err: {:?}, msg: {}]", self.get_code(), self.get_msg())
@@ -59,6 +71,22 @@ impl Serialize for NetError {
     }
 }
 
+#[derive(Deserialize)]
+struct NetErrorJson {
+    code: ErrCode,
+    msg: String,
+}
+
+impl<'de> Deserialize<'de> for NetError {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let json = NetErrorJson::deserialize(deserializer)?;
+        Ok(err(json.code, json.msg))
+    }
+}
+
 impl fmt::Display for Protocol {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         // If you add a new value here, you *must* update the `FromStr` implementation for
@@ -13,6 +13,7 @@ use std::str::FromStr;
 use hab_core;
 use hab_core::package::{self, Identifiable, FromArchive, PackageArchive};
 use hab_core::package::ident::version_sort;
+use protobuf::RepeatedField;
 
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
@@ -23,13 +24,27 @@ use sharding::InstaId;
 
 #[derive(Debug)]
 pub enum Error {
+    BadOriginNotificationEvent(String),
+    BadOriginNotificationKind(String),
+    BadOriginPackageSearchOrder(String),
     BadOriginPackageVisibility,
+    BadOriginPromotionRequestState(String),
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
-            Error::BadOriginPackageVisibility => "Bad Origin Package Visibility",
+            Error::BadOriginNotificationEvent(ref e) => {
+                format!("Bad Origin Notification Event, {}", e)
+            }
+            Error::BadOriginNotificationKind(ref e) => format!("Bad Origin Notification Kind, {}", e),
+            Error::BadOriginPackageSearchOrder(ref e) => {
+                format!("Bad Origin Package Search Order, {}", e)
+            }
+            Error::BadOriginPackageVisibility => "Bad Origin Package Visibility".to_string(),
+            Error::BadOriginPromotionRequestState(ref e) => {
+                format!("Bad Origin Promotion Request State, {}", e)
+            }
         };
         write!(f, "{}", msg)
     }
@@ -38,7 +53,13 @@ impl fmt::Display for Error {
 impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
+            Error::BadOriginNotificationEvent(_) => "Origin notification event cannot be parsed",
+            Error::BadOriginNotificationKind(_) => "Origin notification kind cannot be parsed",
+            Error::BadOriginPackageSearchOrder(_) => "Origin package search order cannot be parsed",
             Error::BadOriginPackageVisibility => "Origin package visibility cannot be parsed",
+            Error::BadOriginPromotionRequestState(_) => {
+                "Origin promotion request state cannot be parsed"
+            }
         }
     }
 }
@@ -46,6 +67,12 @@ impl error::Error for Error {
 pub trait Pageable {
     fn get_range(&self) -> [u64; 2];
 
+    fn set_range(&mut self, range: [u64; 2]);
+
+    /// Leaves out the rows created after the given RFC3339 timestamp, so that the pages of a
+    /// listing don't shift while rows are added to it.
+    fn set_snapshot(&mut self, created_before: String);
+
     fn limit(&self) -> i64 {
         (self.get_range()[1] - self.get_range()[0] + 1) as i64
     }
@@ -112,25 +139,25 @@ impl Routable for CheckOriginOwnerRequest {
     }
 }
 
+impl Routable for CheckOriginApproverRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin_name().to_string())
+    }
+}
+
 impl Default for OriginPackageVisibility {
     fn default() -> OriginPackageVisibility {
         OriginPackageVisibility::Public
     }
 }
 
-impl Serialize for OriginPackageVisibility {
-    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match *self as u64 {
-            1 => serializer.serialize_str("public"),
-            2 => serializer.serialize_str("private"),
-            3 => serializer.serialize_str("hidden"),
-            _ => panic!("Unexpected enum value"),
-        }
-    }
-}
+serialize_enum!(OriginPackageVisibility, {
+    1 => "public",
+    2 => "private",
+    3 => "hidden",
+});
 
 impl FromStr for OriginPackageVisibility {
     type Err = Error;
@@ -290,6 +317,15 @@ impl Pageable for OriginChannelPackageListRequest {
     fn get_range(&self) -> [u64; 2] {
         [self.get_start(), self.get_stop()]
     }
+
+    fn set_range(&mut self, range: [u64; 2]) {
+        self.set_start(range[0]);
+        self.set_stop(range[1]);
+    }
+
+    fn set_snapshot(&mut self, created_before: String) {
+        self.set_created_before(created_before);
+    }
 }
 
 impl Routable for OriginChannelPackageListRequest {
@@ -457,6 +493,14 @@ impl Routable for OriginInvitationCreate {
     }
 }
 
+impl Routable for OriginInvitationGet {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_invitation_id()))
+    }
+}
+
 impl Routable for OriginInvitationListRequest {
     type H = InstaId;
 
@@ -515,10 +559,11 @@ impl Serialize for OriginKeyIdent {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("origin_key", 3)?;
+        let mut strukt = serializer.serialize_struct("origin_key", 4)?;
         strukt.serialize_field("origin", self.get_origin())?;
         strukt.serialize_field("revision", self.get_revision())?;
         strukt.serialize_field("location", self.get_location())?;
+        strukt.serialize_field("deprecated", &self.get_deprecated())?;
         strukt.end()
     }
 }
@@ -557,6 +602,14 @@ impl Routable for OriginMemberRemove {
     }
 }
 
+impl Routable for OriginMemberApproverUpdate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
 impl fmt::Display for Origin {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.get_name().fmt(f)
@@ -584,14 +637,18 @@ impl FromArchive for OriginPackage {
         let config = archive.config()?;
         let checksum = archive.checksum()?;
         let target = archive.target()?;
+        let exports = archive_exports(archive)?;
 
         let mut package = OriginPackage::new();
         package.set_ident(ident);
+        package.set_description(manifest_description(&manifest));
+        package.set_licenses(RepeatedField::from_vec(manifest_licenses(&manifest)));
         package.set_manifest(manifest);
         package.set_target(target.to_string());
         package.set_deps(deps);
         package.set_tdeps(tdeps);
         package.set_exposes(exposes);
+        package.set_exports(exports);
         if let Some(cfg) = config {
             package.set_config(cfg);
         }
@@ -600,6 +657,51 @@ impl FromArchive for OriginPackage {
     }
 }
 
+/// The configuration keys an archive exports, ordered by the name they're exported as
+fn archive_exports(
+    archive: &mut PackageArchive,
+) -> hab_core::Result<RepeatedField<OriginPackageExport>> {
+    let mut exports: Vec<OriginPackageExport> = archive
+        .exports()?
+        .into_iter()
+        .map(|(name, key)| {
+            let mut export = OriginPackageExport::new();
+            export.set_name(name);
+            export.set_key(key);
+            export
+        })
+        .collect();
+    exports.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+    Ok(RepeatedField::from_vec(exports))
+}
+
+/// The `pkg_description` of a plan, which its manifest has between the title and the list of
+/// package details
+fn manifest_description(manifest: &str) -> String {
+    manifest
+        .lines()
+        .skip(1)
+        .take_while(|line| !line.starts_with("* __"))
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// The `pkg_license` entries of a plan, as listed in its manifest
+fn manifest_licenses(manifest: &str) -> Vec<String> {
+    manifest
+        .lines()
+        .find(|line| line.starts_with("* __License__:"))
+        .map(|line| {
+            line["* __License__:".len()..]
+                .split_whitespace()
+                .map(|license| license.to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 impl Into<package::PackageIdent> for OriginPackage {
     fn into(self) -> package::PackageIdent {
         self.get_ident().clone().into()
@@ -611,7 +713,7 @@ impl Serialize for OriginPackage {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("origin_package", 8)?;
+        let mut strukt = serializer.serialize_struct("origin_package", 15)?;
         strukt.serialize_field("ident", self.get_ident())?;
         strukt.serialize_field("checksum", self.get_checksum())?;
         strukt.serialize_field("manifest", self.get_manifest())?;
@@ -619,12 +721,63 @@ impl Serialize for OriginPackage {
         strukt.serialize_field("deps", self.get_deps())?;
         strukt.serialize_field("tdeps", self.get_tdeps())?;
         strukt.serialize_field("exposes", self.get_exposes())?;
+        strukt.serialize_field("exports", self.get_exports())?;
+        strukt.serialize_field("licenses", self.get_licenses())?;
+        strukt.serialize_field("description", self.get_description())?;
         strukt.serialize_field("config", self.get_config())?;
         strukt.serialize_field("visibility", &self.get_visibility())?;
+        strukt.serialize_field("yanked", &self.is_yanked())?;
+        if self.is_yanked() {
+            strukt.serialize_field("yanked_at", self.get_yanked_at())?;
+            strukt.serialize_field("yank_reason", self.get_yank_reason())?;
+        }
+        strukt.end()
+    }
+}
+
+impl Serialize for OriginPackageExport {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_package_export", 2)?;
+        strukt.serialize_field("name", self.get_name())?;
+        strukt.serialize_field("key", self.get_key())?;
         strukt.end()
     }
 }
 
+impl OriginPackage {
+    /// Whether the release was pulled from circulation
+    pub fn is_yanked(&self) -> bool {
+        !self.get_yanked_at().is_empty()
+    }
+}
+
+impl Routable for OriginPackageYank {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
+impl Routable for OriginPackageRetentionCandidatesGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_origin()))
+    }
+}
+
+impl Routable for OriginPackageDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
 impl FromArchive for OriginPackageCreate {
     type Error = hab_core::Error;
 
@@ -640,14 +793,18 @@ impl FromArchive for OriginPackageCreate {
         let config = archive.config()?;
         let checksum = archive.checksum()?;
         let target = archive.target()?;
+        let exports = archive_exports(archive)?;
 
         let mut package = OriginPackageCreate::new();
         package.set_ident(ident);
+        package.set_description(manifest_description(&manifest));
+        package.set_licenses(RepeatedField::from_vec(manifest_licenses(&manifest)));
         package.set_manifest(manifest);
         package.set_target(target.to_string());
         package.set_deps(deps);
         package.set_tdeps(tdeps);
         package.set_exposes(exposes);
+        package.set_exports(exports);
         if let Some(cfg) = config {
             package.set_config(cfg);
         }
@@ -933,6 +1090,15 @@ impl Pageable for OriginPackageListRequest {
     fn get_range(&self) -> [u64; 2] {
         [self.get_start(), self.get_stop()]
     }
+
+    fn set_range(&mut self, range: [u64; 2]) {
+        self.set_start(range[0]);
+        self.set_stop(range[1]);
+    }
+
+    fn set_snapshot(&mut self, created_before: String) {
+        self.set_created_before(created_before);
+    }
 }
 
 impl Routable for OriginPackageListRequest {
@@ -995,6 +1161,24 @@ impl Pageable for OriginPackageSearchRequest {
     fn get_range(&self) -> [u64; 2] {
         [self.get_start(), self.get_stop()]
     }
+
+    fn set_range(&mut self, range: [u64; 2]) {
+        self.set_start(range[0]);
+        self.set_stop(range[1]);
+    }
+
+    fn set_snapshot(&mut self, created_before: String) {
+        self.set_created_before(created_before);
+    }
+}
+
+impl OriginPackageSearchRequest {
+    /// Whether the search filters packages or orders them by anything but their identifier.
+    pub fn is_filtered(&self) -> bool {
+        self.has_target() || self.has_channel() || self.has_license() ||
+            self.has_built_after() || self.has_created_before() ||
+            self.get_order() != OriginPackageSearchOrder::Ident
+    }
 }
 
 impl Routable for OriginPackageSearchRequest {
@@ -1005,10 +1189,83 @@ impl Routable for OriginPackageSearchRequest {
     }
 }
 
+impl Routable for OriginPackageDownloadRecord {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_ident().get_origin()))
+    }
+}
+
+impl Serialize for OriginPackageDownloadStat {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_package_download_stat", 4)?;
+        strukt.serialize_field("ident", self.get_ident())?;
+        if self.get_channel().is_empty() {
+            strukt.serialize_field("channel", &None::<String>)?;
+        } else {
+            strukt.serialize_field("channel", self.get_channel())?;
+        }
+        strukt.serialize_field("day", self.get_day())?;
+        strukt.serialize_field("count", &self.get_count())?;
+        strukt.end()
+    }
+}
+
+impl Routable for OriginPackageDownloadStatsGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(String::from(self.get_origin()))
+    }
+}
+
+impl Serialize for OriginPackageDownloadStatsResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct(
+            "origin_package_download_stats_response",
+            4,
+        )?;
+        strukt.serialize_field("origin", self.get_origin())?;
+        strukt.serialize_field("name", self.get_name())?;
+        strukt.serialize_field("total", &self.get_total())?;
+        strukt.serialize_field("stats", self.get_stats())?;
+        strukt.end()
+    }
+}
+
+impl FromStr for OriginPackageSearchOrder {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "ident" => Ok(OriginPackageSearchOrder::Ident),
+            "newest" => Ok(OriginPackageSearchOrder::Newest),
+            "oldest" => Ok(OriginPackageSearchOrder::Oldest),
+            _ => Err(Error::BadOriginPackageSearchOrder(value.to_string())),
+        }
+    }
+}
+
 impl Pageable for OriginPackageUniqueListRequest {
     fn get_range(&self) -> [u64; 2] {
         [self.get_start(), self.get_stop()]
     }
+
+    fn set_range(&mut self, range: [u64; 2]) {
+        self.set_start(range[0]);
+        self.set_stop(range[1]);
+    }
+
+    fn set_snapshot(&mut self, created_before: String) {
+        self.set_created_before(created_before);
+    }
 }
 
 impl Routable for OriginPackageUniqueListRequest {
@@ -1048,6 +1305,14 @@ impl Serialize for OriginProject {
             &self.get_vcs_installation_id().to_string(),
         )?;
         state.serialize_field("visibility", &self.get_visibility())?;
+        if self.has_build_timeout() {
+            state.serialize_field("build_timeout", &self.get_build_timeout())?;
+        }
+        state.serialize_field("worker_labels", self.get_worker_labels())?;
+        state.serialize_field(
+            "verify_reproducible",
+            &self.get_verify_reproducible(),
+        )?;
         state.end()
     }
 }
@@ -1130,7 +1395,7 @@ impl Serialize for OriginPublicKey {
     where
         S: Serializer,
     {
-        let mut strukt = serializer.serialize_struct("origin_public_key", 6)?;
+        let mut strukt = serializer.serialize_struct("origin_public_key", 7)?;
         strukt.serialize_field("id", &self.get_id().to_string())?;
         strukt.serialize_field(
             "origin_id",
@@ -1143,6 +1408,7 @@ impl Serialize for OriginPublicKey {
             "owner_id",
             &self.get_owner_id().to_string(),
         )?;
+        strukt.serialize_field("deprecated", &self.get_deprecated())?;
         strukt.end()
     }
 }
@@ -1179,6 +1445,14 @@ impl Routable for OriginPublicKeyListRequest {
     }
 }
 
+impl Routable for OriginPublicKeyDeprecate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_origin_id()))
+    }
+}
+
 impl Routable for OriginPublicKeyListResponse {
     type H = InstaId;
 
@@ -1243,10 +1517,376 @@ impl Routable for OriginSecretKeyGet {
     }
 }
 
+serialize_enum!(OriginNotificationKind, {
+    1 => "webhook",
+    2 => "email",
+});
+
+impl FromStr for OriginNotificationKind {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "webhook" => Ok(OriginNotificationKind::Webhook),
+            "email" => Ok(OriginNotificationKind::Email),
+            _ => Err(Error::BadOriginNotificationKind(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for OriginNotificationKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            OriginNotificationKind::Webhook => "webhook",
+            OriginNotificationKind::Email => "email",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+serialize_enum!(OriginNotificationEvent, {
+    1 => "job_failed",
+    2 => "job_complete",
+    3 => "group_failed",
+    4 => "group_complete",
+    5 => "package_upload",
+    6 => "channel_promote",
+    7 => "channel_demote",
+});
+
+impl FromStr for OriginNotificationEvent {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "job_failed" => Ok(OriginNotificationEvent::JobFailed),
+            "job_complete" => Ok(OriginNotificationEvent::JobComplete),
+            "group_failed" => Ok(OriginNotificationEvent::GroupFailed),
+            "group_complete" => Ok(OriginNotificationEvent::GroupComplete),
+            "package_upload" => Ok(OriginNotificationEvent::PackageUpload),
+            "channel_promote" => Ok(OriginNotificationEvent::ChannelPromote),
+            "channel_demote" => Ok(OriginNotificationEvent::ChannelDemote),
+            _ => Err(Error::BadOriginNotificationEvent(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for OriginNotificationEvent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            OriginNotificationEvent::JobFailed => "job_failed",
+            OriginNotificationEvent::JobComplete => "job_complete",
+            OriginNotificationEvent::GroupFailed => "group_failed",
+            OriginNotificationEvent::GroupComplete => "group_complete",
+            OriginNotificationEvent::PackageUpload => "package_upload",
+            OriginNotificationEvent::ChannelPromote => "channel_promote",
+            OriginNotificationEvent::ChannelDemote => "channel_demote",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl OriginNotification {
+    /// Returns true if the notification should be sent when the given event happens.
+    pub fn wants(&self, event: OriginNotificationEvent) -> bool {
+        self.get_events().contains(&event)
+    }
+}
+
+impl Serialize for OriginNotification {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_notification", 6)?;
+        strukt.serialize_field("id", &self.get_id().to_string())?;
+        strukt.serialize_field("origin", self.get_origin())?;
+        strukt.serialize_field("kind", &self.get_kind())?;
+        strukt.serialize_field("target", self.get_target())?;
+        strukt.serialize_field("events", self.get_events())?;
+        // The secret itself is never handed out again
+        strukt.serialize_field("signed", &self.has_secret())?;
+        strukt.end()
+    }
+}
+
+impl Routable for OriginNotificationCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_notification().get_origin().to_string())
+    }
+}
+
+impl Routable for OriginNotificationDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Routable for OriginNotificationListRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Serialize for OriginNotificationListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_notification_list_response", 1)?;
+        strukt.serialize_field(
+            "notifications",
+            self.get_notifications(),
+        )?;
+        strukt.end()
+    }
+}
+
+impl OriginSecret {
+    /// Returns true if `name` can be used as the name of a secret. Secrets are exposed to builds
+    /// as environment variables, so their names must be valid shell variable names.
+    pub fn is_valid_name(name: &str) -> bool {
+        let mut chars = name.chars();
+        match chars.next() {
+            Some('a'...'z') | Some('A'...'Z') | Some('_') => (),
+            _ => return false,
+        }
+        chars.all(|c| match c {
+            'a'...'z' | 'A'...'Z' | '0'...'9' | '_' => true,
+            _ => false,
+        })
+    }
+}
+
+// The value of a secret is never rendered, only its name.
+impl Serialize for OriginSecret {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_secret", 2)?;
+        strukt.serialize_field("origin", self.get_origin())?;
+        strukt.serialize_field("name", self.get_name())?;
+        strukt.end()
+    }
+}
+
+impl Routable for OriginSecretCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_secret().get_origin().to_string())
+    }
+}
+
+impl Routable for OriginSecretDelete {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Routable for OriginSecretListRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+// Only the public half of an origin's encryption key is ever rendered.
+impl Serialize for OriginEncryptionKey {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_encryption_key", 3)?;
+        strukt.serialize_field("origin", self.get_origin())?;
+        strukt.serialize_field("revision", self.get_revision())?;
+        strukt.serialize_field("public_key", self.get_public_key())?;
+        strukt.end()
+    }
+}
+
+impl Routable for OriginEncryptionKeyCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_key().get_origin().to_string())
+    }
+}
+
+impl Routable for OriginEncryptionKeyGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Serialize for OriginSecretListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_secret_list_response", 1)?;
+        strukt.serialize_field("secrets", self.get_secrets())?;
+        strukt.end()
+    }
+}
+
+serialize_enum!(OriginPromotionRequestState, {
+    1 => "pending",
+    2 => "approved",
+    3 => "rejected",
+    4 => "promoted",
+    5 => "failed",
+});
+
+impl FromStr for OriginPromotionRequestState {
+    type Err = Error;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "pending" => Ok(OriginPromotionRequestState::Pending),
+            "approved" => Ok(OriginPromotionRequestState::Approved),
+            "rejected" => Ok(OriginPromotionRequestState::Rejected),
+            "promoted" => Ok(OriginPromotionRequestState::Promoted),
+            "failed" => Ok(OriginPromotionRequestState::Failed),
+            _ => Err(Error::BadOriginPromotionRequestState(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for OriginPromotionRequestState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            OriginPromotionRequestState::Pending => "pending",
+            OriginPromotionRequestState::Approved => "approved",
+            OriginPromotionRequestState::Rejected => "rejected",
+            OriginPromotionRequestState::Promoted => "promoted",
+            OriginPromotionRequestState::Failed => "failed",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl Serialize for OriginPromotionRequestEvent {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_promotion_request_event", 4)?;
+        strukt.serialize_field("state", &self.get_state())?;
+        strukt.serialize_field("account_name", self.get_account_name())?;
+        strukt.serialize_field("comment", self.get_comment())?;
+        strukt.serialize_field("created_at", self.get_created_at())?;
+        strukt.end()
+    }
+}
+
+impl Serialize for OriginPromotionRequest {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("origin_promotion_request", 9)?;
+        strukt.serialize_field("id", &self.get_id().to_string())?;
+        strukt.serialize_field("origin", self.get_origin())?;
+        strukt.serialize_field("channel", self.get_channel())?;
+        strukt.serialize_field("idents", self.get_idents())?;
+        strukt.serialize_field("requester_name", self.get_requester_name())?;
+        strukt.serialize_field("state", &self.get_state())?;
+        strukt.serialize_field("events", self.get_events())?;
+        strukt.serialize_field("created_at", self.get_created_at())?;
+        strukt.serialize_field("updated_at", self.get_updated_at())?;
+        strukt.end()
+    }
+}
+
+impl Routable for OriginPromotionRequestCreate {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_promotion().get_origin().to_string())
+    }
+}
+
+impl Routable for OriginPromotionRequestGet {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Routable for OriginPromotionRequestListRequest {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
+impl Serialize for OriginPromotionRequestListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct(
+            "origin_promotion_request_list_response",
+            1,
+        )?;
+        strukt.serialize_field("promotions", self.get_promotions())?;
+        strukt.end()
+    }
+}
+
+impl Routable for OriginPromotionRequestTransition {
+    type H = String;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(self.get_origin().to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const MANIFEST: &'static str = "# core / redis
+Persistent key-value database, with built-in net interface
+
+* __Maintainer__: The Habitat Maintainers <humans@habitat.sh>
+* __Version__: 3.2.4
+* __License__: BSD-3-Clause MIT
+* __Source__: [redis-3.2.4.tar.gz](http://download.redis.io/releases/redis-3.2.4.tar.gz)
+
+# Plan
+";
+
+    #[test]
+    fn description_from_manifest() {
+        assert_eq!(
+            manifest_description(MANIFEST),
+            "Persistent key-value database, with built-in net interface"
+        );
+        assert_eq!(manifest_description(""), "");
+    }
+
+    #[test]
+    fn licenses_from_manifest() {
+        assert_eq!(manifest_licenses(MANIFEST), vec!["BSD-3-Clause", "MIT"]);
+        assert!(manifest_licenses("# core / redis\n").is_empty());
+    }
+
     #[test]
     fn sort_origin_package_versions() {
         let a = vec!["4.0.2", "3.2.4", "3.2.3", "3.2.11", "3.2.10", "3.2.1"];
@@ -1286,4 +1926,150 @@ mod tests {
         );
         assert_eq!(vec!["3.6.12", "3.6.10", "3.6.6", "3.6.5"], r);
     }
+
+    #[test]
+    fn origin_notification_json() {
+        let mut notification = OriginNotification::new();
+        notification.set_id(1234567890123);
+        notification.set_origin("core".to_string());
+        notification.set_kind("Email".parse().unwrap());
+        notification.set_target("builds@example.com".to_string());
+        notification.set_events(vec![
+            OriginNotificationEvent::JobFailed,
+            OriginNotificationEvent::GroupComplete,
+        ]);
+
+        let json = ::serde_json::to_value(&notification).unwrap();
+        assert_eq!(json["id"], "1234567890123");
+        assert_eq!(json["kind"], "email");
+        assert_eq!(json["events"][0], "job_failed");
+        assert_eq!(json["events"][1], "group_complete");
+        assert!(notification.wants(OriginNotificationEvent::JobFailed));
+        assert!(!notification.wants(OriginNotificationEvent::JobComplete));
+        assert!("sms".parse::<OriginNotificationKind>().is_err());
+        assert!("job_canceled".parse::<OriginNotificationEvent>().is_err());
+        assert_eq!(json["signed"], false);
+
+        notification.set_secret("c2VjcmV0".to_string());
+        let json = ::serde_json::to_value(&notification).unwrap();
+        assert_eq!(json["signed"], true);
+        assert!(json.get("secret").is_none());
+        assert_eq!(
+            "channel_promote".parse::<OriginNotificationEvent>().unwrap(),
+            OriginNotificationEvent::ChannelPromote
+        );
+    }
+
+    #[test]
+    fn origin_secret_json() {
+        let mut secret = OriginSecret::new();
+        secret.set_origin("core".to_string());
+        secret.set_name("NPM_TOKEN".to_string());
+        secret.set_value("encrypted".to_string());
+
+        let json = ::serde_json::to_value(&secret).unwrap();
+        assert_eq!(json["name"], "NPM_TOKEN");
+        assert!(json.get("value").is_none());
+
+        let mut key = OriginEncryptionKey::new();
+        key.set_origin("core".to_string());
+        key.set_revision("20171016184020".to_string());
+        key.set_public_key("BOX-PUB-1\ncore-20171016184020\n\nkey".to_string());
+        key.set_secret_key("BOX-SEC-1\ncore-20171016184020\n\nkey".to_string());
+        let json = ::serde_json::to_value(&key).unwrap();
+        assert_eq!(json["revision"], "20171016184020");
+        assert!(json.get("secret_key").is_none());
+    }
+
+    #[test]
+    fn origin_secret_names() {
+        assert!(OriginSecret::is_valid_name("NPM_TOKEN"));
+        assert!(OriginSecret::is_valid_name("_license_key2"));
+        assert!(!OriginSecret::is_valid_name(""));
+        assert!(!OriginSecret::is_valid_name("2FA_CODE"));
+        assert!(!OriginSecret::is_valid_name("NPM-TOKEN"));
+        assert!(!OriginSecret::is_valid_name("KEY=VALUE"));
+    }
+
+    #[test]
+    fn origin_promotion_request_json() {
+        let mut event = OriginPromotionRequestEvent::new();
+        event.set_state(OriginPromotionRequestState::Pending);
+        event.set_account_id(1);
+        event.set_account_name("bobo".to_string());
+        let mut promotion = OriginPromotionRequest::new();
+        promotion.set_id(1234567890123);
+        promotion.set_origin("core".to_string());
+        promotion.set_channel("stable".to_string());
+        promotion.set_idents(
+            vec!["core/redis/3.2.4/20170514001355".to_string()].into(),
+        );
+        promotion.set_requester_id(1);
+        promotion.set_state(OriginPromotionRequestState::Pending);
+        promotion.set_events(vec![event].into());
+
+        let json = ::serde_json::to_value(&promotion).unwrap();
+        assert_eq!(json["id"], "1234567890123");
+        assert_eq!(json["state"], "pending");
+        assert_eq!(json["idents"][0], "core/redis/3.2.4/20170514001355");
+        assert_eq!(json["events"][0]["account_name"], "bobo");
+        assert!(json.get("requester_id").is_none());
+        assert_eq!(
+            "Rejected".parse::<OriginPromotionRequestState>().unwrap(),
+            OriginPromotionRequestState::Rejected
+        );
+        assert!("merged".parse::<OriginPromotionRequestState>().is_err());
+    }
+
+    #[test]
+    fn origin_package_search_filters() {
+        let mut request = OriginPackageSearchRequest::new();
+        request.set_query("redis".to_string());
+        request.set_distinct(true);
+        assert!(!request.is_filtered());
+        request.set_order("Newest".parse().unwrap());
+        assert!(request.is_filtered());
+        request.set_order(OriginPackageSearchOrder::Ident);
+        request.set_channel("stable".to_string());
+        assert!(request.is_filtered());
+        assert!("popular".parse::<OriginPackageSearchOrder>().is_err());
+    }
+
+    #[test]
+    fn origin_package_yanked_json() {
+        let mut package = OriginPackage::new();
+        package.set_ident(
+            OriginPackageIdent::from_str("core/redis/3.2.4/20170514001355").unwrap(),
+        );
+        let json = ::serde_json::to_value(&package).unwrap();
+        assert_eq!(json["yanked"], false);
+        assert!(json.get("yank_reason").is_none());
+
+        package.set_yanked_at("2017-10-02T00:00:00+00:00".to_string());
+        package.set_yank_reason("segfaults on start".to_string());
+        let json = ::serde_json::to_value(&package).unwrap();
+        assert_eq!(json["yanked"], true);
+        assert_eq!(json["yanked_at"], "2017-10-02T00:00:00+00:00");
+        assert_eq!(json["yank_reason"], "segfaults on start");
+    }
+
+    #[test]
+    fn origin_package_download_stat_json() {
+        let mut stat = OriginPackageDownloadStat::new();
+        stat.set_ident(
+            OriginPackageIdent::from_str("core/redis/3.2.4/20170514001355").unwrap(),
+        );
+        stat.set_day("2017-10-02".to_string());
+        stat.set_count(3);
+
+        let json = ::serde_json::to_value(&stat).unwrap();
+        assert_eq!(json["ident"]["release"], "20170514001355");
+        assert!(json["channel"].is_null());
+        assert_eq!(json["day"], "2017-10-02");
+        assert_eq!(json["count"], 3);
+
+        stat.set_channel("stable".to_string());
+        let json = ::serde_json::to_value(&stat).unwrap();
+        assert_eq!(json["channel"], "stable");
+    }
 }
@@ -12,11 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt;
 use std::result;
+use std::str::FromStr;
 
 use serde::{Serialize, Serializer};
 use serde::ser::SerializeStruct;
 
+use error::ProtocolError;
 use message::{Persistable, Routable};
 
 use sharding::InstaId;
@@ -31,10 +34,100 @@ impl Routable for SessionCreate {
 }
 
 impl Routable for SessionGet {
-    type H = u32;
+    type H = u64;
+
+    /// Sessions are kept by the server their extern id routes to, while personal access tokens are
+    /// kept in the shard of their account.
+    fn route_key(&self) -> Option<Self::H> {
+        if self.get_token().has_access_token_id() {
+            Some(InstaId(self.get_token().get_account_id()).shard())
+        } else {
+            Some(self.get_token().get_extern_id() as u64)
+        }
+    }
+}
+
+impl Routable for AccessTokenCreate {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_account_id()))
+    }
+}
+
+impl Routable for AccessTokenListRequest {
+    type H = InstaId;
 
     fn route_key(&self) -> Option<Self::H> {
-        Some(self.get_token().get_extern_id())
+        Some(InstaId(self.get_account_id()))
+    }
+}
+
+impl Routable for AccessTokenRevoke {
+    type H = InstaId;
+
+    fn route_key(&self) -> Option<Self::H> {
+        Some(InstaId(self.get_account_id()))
+    }
+}
+
+impl FromStr for AccessTokenScope {
+    type Err = ProtocolError;
+
+    fn from_str(value: &str) -> result::Result<Self, Self::Err> {
+        match value.to_lowercase().as_ref() {
+            "package:upload" => Ok(AccessTokenScope::PackageUpload),
+            "job:schedule" => Ok(AccessTokenScope::JobSchedule),
+            "origin:admin" => Ok(AccessTokenScope::OriginAdmin),
+            _ => Err(ProtocolError::BadAccessTokenScope(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for AccessTokenScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match *self {
+            AccessTokenScope::PackageUpload => "package:upload",
+            AccessTokenScope::JobSchedule => "job:schedule",
+            AccessTokenScope::OriginAdmin => "origin:admin",
+        };
+        write!(f, "{}", value)
+    }
+}
+
+impl Serialize for AccessToken {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("access_token", 6)?;
+        strukt.serialize_field("id", &self.get_id().to_string())?;
+        strukt.serialize_field("name", self.get_name())?;
+        let scopes: Vec<String> = self.get_scopes().iter().map(|s| s.to_string()).collect();
+        strukt.serialize_field("scopes", &scopes)?;
+        strukt.serialize_field("created_at", self.get_created_at())?;
+        if self.has_last_used_at() {
+            strukt.serialize_field("last_used_at", self.get_last_used_at())?;
+        }
+        if self.has_token() {
+            strukt.serialize_field("token", self.get_token())?;
+        }
+        strukt.end()
+    }
+}
+
+impl Serialize for AccessTokenListResponse {
+    fn serialize<S>(&self, serializer: S) -> result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut strukt = serializer.serialize_struct("access_token_list_response", 2)?;
+        strukt.serialize_field(
+            "account_id",
+            &self.get_account_id().to_string(),
+        )?;
+        strukt.serialize_field("tokens", self.get_tokens())?;
+        strukt.end()
     }
 }
 
@@ -270,3 +363,38 @@ impl Serialize for Session {
         strukt.end()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn access_token_scopes() {
+        for scope in &[
+            AccessTokenScope::PackageUpload,
+            AccessTokenScope::JobSchedule,
+            AccessTokenScope::OriginAdmin,
+        ]
+        {
+            assert_eq!(
+                scope.to_string().parse::<AccessTokenScope>().unwrap(),
+                *scope
+            );
+        }
+        assert!("package:delete".parse::<AccessTokenScope>().is_err());
+    }
+
+    #[test]
+    fn session_get_route_key() {
+        let mut token = SessionToken::new();
+        token.set_account_id(InstaId::generate(12).0);
+        token.set_extern_id(4242);
+        let mut get = SessionGet::new();
+        get.set_token(token.clone());
+        assert_eq!(get.route_key(), Some(4242));
+
+        token.set_access_token_id(7);
+        get.set_token(token);
+        assert_eq!(get.route_key(), Some(12));
+    }
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for the timestamps carried as RFC3339 strings in protocol messages.
+//!
+//! Protobuf has no native timestamp type in the version of the protocol we speak, so messages
+//! store their timestamps as strings. These helpers make sure that whatever is put into or read
+//! out of those strings is a valid RFC3339 timestamp.
+
+use chrono::{DateTime, UTC};
+
+use error::{ProtocolError, ProtocolResult};
+
+/// Parse an RFC3339 formatted timestamp into a UTC date time.
+pub fn parse(value: &str) -> ProtocolResult<DateTime<UTC>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|t| t.with_timezone(&UTC))
+        .map_err(|e| ProtocolError::BadTimestamp(format!("{}, {}", value, e)))
+}
+
+/// The current time as an RFC3339 timestamp.
+pub fn now() -> String {
+    UTC::now().to_rfc3339()
+}
+
+/// Validate an RFC3339 formatted timestamp and return it re-rendered in UTC.
+///
+/// An empty string is passed through untouched as it represents an unset timestamp.
+pub fn normalize(value: &str) -> ProtocolResult<String> {
+    if value.is_empty() {
+        return Ok(String::new());
+    }
+    parse(value).map(|t| t.to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rfc3339() {
+        let t = parse("2017-06-01T00:01:00+00:00").unwrap();
+        assert_eq!(t.to_rfc3339(), "2017-06-01T00:01:00+00:00");
+    }
+
+    #[test]
+    fn normalize_to_utc() {
+        assert_eq!(
+            normalize("2017-06-01T02:01:00+02:00").unwrap(),
+            "2017-06-01T00:01:00+00:00"
+        );
+        assert_eq!(
+            normalize("2017-06-01T00:01:00Z").unwrap(),
+            "2017-06-01T00:01:00+00:00"
+        );
+        assert_eq!(normalize("").unwrap(), "");
+    }
+
+    #[test]
+    fn reject_malformed() {
+        assert!(parse("yesterday").is_err());
+        assert!(normalize("2017-06-01 00:01:00+00").is_err());
+        assert!(normalize("2017-13-01T00:01:00Z").is_err());
+    }
+}
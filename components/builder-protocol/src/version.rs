@@ -0,0 +1,76 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Versioning of the protocol spoken between Builder services and workers.
+//!
+//! Peers advertise the version they speak in their handshake messages: a worker's
+//! `jobsrv::Heartbeat`, JobSrv's `jobsrv::WorkerCommand` and a service's `routesrv::Registration`.
+//! Peers which predate versioning don't send one and are treated as speaking version 1.
+
+use std::cmp;
+
+use error::{ProtocolError, ProtocolResult};
+
+/// Version of the protocol spoken by this build.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Oldest protocol version this build can still interoperate with.
+pub const MIN_PROTOCOL_VERSION: u32 = 1;
+
+/// Negotiate the protocol version to speak with a peer which advertised the given version.
+///
+/// Both peers speak the older of their two versions. Only the newer peer knows whether it can
+/// still speak that version, so it's the one that refuses to interoperate.
+///
+/// # Errors
+///
+/// * If the peer's version is older than the oldest version this build supports
+pub fn negotiate(peer: u32) -> ProtocolResult<u32> {
+    negotiate_with(peer, PROTOCOL_VERSION, MIN_PROTOCOL_VERSION)
+}
+
+fn negotiate_with(peer: u32, ours: u32, min: u32) -> ProtocolResult<u32> {
+    let peer = cmp::max(peer, 1);
+    let version = cmp::min(peer, ours);
+    if version < min {
+        return Err(ProtocolError::IncompatibleVersion(peer, ours));
+    }
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate_current() {
+        assert_eq!(negotiate(PROTOCOL_VERSION).unwrap(), PROTOCOL_VERSION);
+    }
+
+    #[test]
+    fn negotiate_unversioned_peer() {
+        assert_eq!(negotiate_with(0, 3, 1).unwrap(), 1);
+        assert!(negotiate_with(0, 3, 2).is_err());
+    }
+
+    #[test]
+    fn negotiate_older_and_newer_peers() {
+        assert_eq!(negotiate_with(2, 3, 2).unwrap(), 2);
+        assert_eq!(negotiate_with(4, 3, 2).unwrap(), 3);
+        match negotiate_with(1, 3, 2) {
+            Err(ProtocolError::IncompatibleVersion(1, 3)) => (),
+            other => panic!("unexpected negotiation result {:?}", other),
+        }
+    }
+}
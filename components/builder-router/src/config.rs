@@ -14,7 +14,7 @@
 
 //! Configuration for a Habitat RouteSrv service
 
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::str::FromStr;
 
 use hab_core::config::ConfigFile;
@@ -30,12 +30,20 @@ pub struct Config {
     pub listen: IpAddr,
     /// Port for receiving routable messages from services and gateways
     pub port: u16,
+    /// Metrics HTTP server's listening address
+    pub metrics_listen: IpAddr,
+    /// Metrics HTTP server's port
+    pub metrics_port: u16,
 }
 
 impl Config {
     pub fn addr(&self) -> String {
         format!("tcp://{}:{}", self.listen, self.port)
     }
+
+    pub fn metrics_addr(&self) -> SocketAddr {
+        SocketAddr::new(self.metrics_listen, self.metrics_port)
+    }
 }
 
 impl Default for Config {
@@ -43,6 +51,8 @@ impl Default for Config {
         Config {
             listen: IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
             port: DEFAULT_ROUTER_PORT,
+            metrics_listen: IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)),
+            metrics_port: 9637,
         }
     }
 }
@@ -70,11 +80,14 @@ mod tests {
         let content = r#"
         listen = "0:0:0:0:0:0:0:1"
         port = 9000
+        metrics_listen = "3.3.3.3"
+        metrics_port = 9090
         "#;
 
         let config = Config::from_str(&content).unwrap();
         assert_eq!(&format!("{}", config.listen), "::1");
         assert_eq!(config.port, 9000);
+        assert_eq!(&format!("{}", config.metrics_addr()), "3.3.3.3:9090");
     }
 
     #[test]
@@ -85,5 +98,6 @@ mod tests {
 
         let config = Config::from_str(&content).unwrap();
         assert_eq!(&format!("{}", config.listen), "172.18.0.1");
+        assert_eq!(config.metrics_port, 9637);
     }
 }
@@ -17,6 +17,7 @@ use std::fmt;
 use std::result;
 
 use hab_core;
+use hyper;
 use protocol;
 
 use conn::ConnErr;
@@ -27,6 +28,7 @@ pub type Result<T> = result::Result<T, Error>;
 pub enum Error {
     Connection(ConnErr),
     HabitatCore(hab_core::Error),
+    MetricsServer(hyper::Error),
     Protocol(protocol::ProtocolError),
 }
 
@@ -35,6 +37,7 @@ impl fmt::Display for Error {
         match *self {
             Error::Connection(ref e) => write!(f, "{}", e),
             Error::HabitatCore(ref e) => write!(f, "{}", e),
+            Error::MetricsServer(ref e) => write!(f, "Unable to start the metrics server, {}", e),
             Error::Protocol(ref e) => write!(f, "{}", e),
         }
     }
@@ -45,6 +48,7 @@ impl error::Error for Error {
         match *self {
             Error::Connection(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::MetricsServer(ref err) => err.description(),
             Error::Protocol(ref err) => err.description(),
         }
     }
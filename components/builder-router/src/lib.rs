@@ -18,8 +18,13 @@
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as hab_core;
 extern crate habitat_net as hab_net;
+extern crate hyper;
+#[macro_use]
+extern crate lazy_static;
 #[macro_use]
 extern crate log;
+#[macro_use]
+extern crate prometheus;
 extern crate protobuf;
 extern crate rand;
 #[macro_use]
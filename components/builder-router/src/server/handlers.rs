@@ -14,6 +14,7 @@
 
 use hab_net::{ErrCode, NetError};
 use protocol::routesrv;
+use protocol::version;
 use protocol::message::Message;
 
 use super::ServerMap;
@@ -43,6 +44,12 @@ pub fn on_registration(
 ) -> Result<()> {
     let mut body = message.parse::<routesrv::Registration>()?;
     debug!("OnRegistration, {:?}", body);
+    if let Err(e) = version::negotiate(body.get_protocol_version()) {
+        let err = NetError::new(ErrCode::REG_INCOMPATIBLE_VERSION, "rt:register:2");
+        warn!("{}, {}", err, e);
+        conn.route_reply(message, &*err)?;
+        return Ok(());
+    }
     let protocol = body.get_protocol();
     let shards = body.take_shards();
     if !servers.add(protocol, message.sender().unwrap().to_vec(), shards) {
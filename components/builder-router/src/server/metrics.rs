@@ -0,0 +1,218 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serves the router's metrics at `/metrics` in the Prometheus text format.
+//!
+//! Every transaction routed to a service is timed until its reply passes back through the router,
+//! which makes the router the one place showing how quickly each backend answers, how often it
+//! fails and how many of its servers are connected.
+
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use hyper::method::Method;
+use hyper::server::{Handler, Request, Response, Server};
+use hyper::status::StatusCode;
+use hyper::uri::RequestUri;
+use prometheus::{self, CounterVec, Encoder, GaugeVec, HistogramVec, TextEncoder};
+use protocol::message::{Message, Protocol};
+
+use config::Config;
+use error::{Error, Result};
+use super::ServerMap;
+
+/// Transactions without a reply after this long are no longer waited on
+const TXN_TIMEOUT_SECS: u64 = 60;
+/// How often transactions are checked for having gone unanswered
+const TXN_EXPIRE_INTERVAL_SECS: u64 = 1;
+
+lazy_static! {
+    static ref MESSAGES: CounterVec = register_counter_vec!(
+        opts!(
+            "router_messages_total",
+            "Number of messages routed to each service."),
+        &["protocol"]).unwrap();
+
+    static ref UNROUTABLE_MESSAGES: CounterVec = register_counter_vec!(
+        opts!(
+            "router_unroutable_messages_total",
+            "Number of messages for which no server hosted the shard they were routed to."),
+        &["protocol"]).unwrap();
+
+    static ref ERROR_REPLIES: CounterVec = register_counter_vec!(
+        opts!(
+            "router_error_replies_total",
+            "Number of transactions each service replied to with an error."),
+        &["protocol"]).unwrap();
+
+    static ref REPLY_LATENCY: HistogramVec = register_histogram_vec!(
+        histogram_opts!(
+            "router_reply_latency_seconds",
+            "Time from routing a transaction to a service to forwarding its reply, in seconds.",
+            vec![0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0]),
+        &["protocol"]).unwrap();
+
+    static ref SERVERS: GaugeVec = register_gauge_vec!(
+        opts!(
+            "router_connected_servers",
+            "Number of servers registered for each service."),
+        &["protocol"]).unwrap();
+}
+
+/// Transactions routed to a service and waiting for their reply, by the identity of the client
+/// which sent them and their id.
+pub struct Transactions {
+    pending: HashMap<(Vec<u8>, u64), (Protocol, Instant)>,
+    last_expired: Instant,
+}
+
+impl Transactions {
+    pub fn new() -> Self {
+        Transactions {
+            pending: HashMap::new(),
+            last_expired: Instant::now(),
+        }
+    }
+
+    /// Record a message routed to a service, timing it if it's a transaction.
+    pub fn routed(&mut self, message: &Message, protocol: Protocol) {
+        MESSAGES.with_label_values(&[&label(protocol)]).inc();
+        if let (Some(originator), Some(txn)) = (message.originator(), message.txn()) {
+            self.pending.insert(
+                (originator.to_vec(), txn.id()),
+                (protocol, Instant::now()),
+            );
+        }
+    }
+
+    /// Record a message which couldn't be routed because no server hosts its shard.
+    pub fn unroutable(&self, protocol: Protocol) {
+        UNROUTABLE_MESSAGES.with_label_values(&[&label(protocol)]).inc();
+    }
+
+    /// Record the reply to a transaction, called once the reply is on its way to the client.
+    pub fn replied(&mut self, message: &Message) {
+        let key = match (message.originator(), message.txn()) {
+            (Some(originator), Some(txn)) => (originator.to_vec(), txn.id()),
+            _ => return,
+        };
+        if let Some((protocol, started)) = self.pending.remove(&key) {
+            let protocol = label(protocol);
+            REPLY_LATENCY.with_label_values(&[&protocol]).observe(
+                seconds(started.elapsed()),
+            );
+            if message.message_id() == "NetError" {
+                ERROR_REPLIES.with_label_values(&[&protocol]).inc();
+            }
+        }
+    }
+
+    /// Stop waiting on the transactions which went unanswered for too long.
+    pub fn expire(&mut self) {
+        if self.last_expired.elapsed() < Duration::from_secs(TXN_EXPIRE_INTERVAL_SECS) {
+            return;
+        }
+        self.last_expired = Instant::now();
+        let timeout = Duration::from_secs(TXN_TIMEOUT_SECS);
+        self.pending.retain(|_, &mut (_, started)| started.elapsed() < timeout);
+    }
+}
+
+/// Refresh the number of servers registered for each service.
+pub fn observe_servers(servers: &ServerMap) {
+    SERVERS.reset();
+    for (protocol, count) in servers.server_counts() {
+        SERVERS.with_label_values(&[&label(protocol)]).set(count as f64);
+    }
+}
+
+/// Start serving the metrics gathered by the router on a thread of its own.
+pub fn start(config: &Config) -> Result<JoinHandle<()>> {
+    let server = Server::http(config.metrics_addr()).map_err(
+        Error::MetricsServer,
+    )?;
+    let (tx, rx) = mpsc::sync_channel(1);
+    let handle = thread::Builder::new()
+        .name("metrics".to_string())
+        .spawn(move || { run(server, tx).unwrap(); })
+        .unwrap();
+    match rx.recv() {
+        Ok(()) => Ok(handle),
+        Err(e) => panic!("metrics thread startup error, err={}", e),
+    }
+}
+
+fn run(server: Server, rz: mpsc::SyncSender<()>) -> Result<()> {
+    let _listening = server.handle_threads(MetricsHandler, 1).map_err(
+        Error::MetricsServer,
+    )?;
+    rz.send(()).unwrap();
+    // Dropping the listening guard waits on the server's threads, which run until exit
+    Ok(())
+}
+
+struct MetricsHandler;
+
+impl Handler for MetricsHandler {
+    fn handle(&self, req: Request, mut res: Response) {
+        let is_metrics = match req.uri {
+            RequestUri::AbsolutePath(ref path) => path.split('?').next() == Some("/metrics"),
+            _ => false,
+        };
+        if req.method != Method::Get || !is_metrics {
+            *res.status_mut() = StatusCode::NotFound;
+            return;
+        }
+
+        let mut buffer = vec![];
+        let encoder = TextEncoder::new();
+        let metric_families = prometheus::gather();
+        encoder.encode(&metric_families, &mut buffer).unwrap();
+
+        res.headers_mut().set_raw(
+            "Content-Type",
+            vec![encoder.format_type().as_bytes().to_vec()],
+        );
+        if let Err(err) = res.send(&buffer) {
+            debug!("Unable to send metrics, err={}", err);
+        }
+    }
+}
+
+/// The name of a service as used in the labels of its metrics, e.g. `originsrv`
+fn label(protocol: Protocol) -> String {
+    format!("{:?}", protocol).to_lowercase()
+}
+
+fn seconds(duration: Duration) -> f64 {
+    duration.as_secs() as f64 + duration.subsec_nanos() as f64 / 1_000_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn protocol_label() {
+        assert_eq!(label(Protocol::OriginSrv), "originsrv");
+        assert_eq!(label(Protocol::JobSrv), "jobsrv");
+    }
+
+    #[test]
+    fn duration_seconds() {
+        assert_eq!(seconds(Duration::from_millis(1500)), 1.5);
+    }
+}
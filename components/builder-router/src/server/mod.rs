@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod handlers;
+mod metrics;
 
 use std::collections::HashMap;
 
@@ -27,6 +28,7 @@ use zmq;
 use config::Config;
 use conn::{ConnErr, ConnEvent, SrvConn};
 use error::{Error, Result};
+use self::metrics::Transactions;
 
 const SERVER_TTL: i64 = PING_INTERVAL_MS + 5_000;
 
@@ -40,6 +42,8 @@ pub struct Server {
     rng: rand::ThreadRng,
     /// Map of all registered servers and, if applicable, the shards they are hosting.
     servers: ServerMap,
+    /// Transactions routed to a service which haven't been replied to yet.
+    transactions: Transactions,
 }
 
 impl Server {
@@ -49,16 +53,18 @@ impl Server {
             context: zmq::Context::new(),
             rng: rand::thread_rng(),
             servers: ServerMap::default(),
+            transactions: Transactions::new(),
         }
     }
 
     /// Forward a protocol message containing a transaction reply to the originator.
-    fn forward_reply(&self, conn: &SrvConn, message: &mut Message) {
+    fn forward_reply(&mut self, conn: &SrvConn, message: &mut Message) {
         message.identities.remove(0);
         trace!("route-message, transaction reply, {:?}", message);
         if let Err(err) = conn.forward_reply(message) {
             error!("{}", err);
         }
+        self.transactions.replied(message);
     }
 
     /// Handle a protocol message intended for this RouteSrv.
@@ -73,7 +79,9 @@ impl Server {
                 return Ok(());
             }
         };
-        handler(conn, message, &mut self.servers)
+        let result = handler(conn, message, &mut self.servers);
+        metrics::observe_servers(&self.servers);
+        result
     }
 
     /// Handle routing of a protocol message to a connected service or delegate to `handle_message`
@@ -86,13 +94,15 @@ impl Server {
                 }
             }
             Some(Protocol::Net) => warn!("route-message, unroutable message, {}", message),
-            Some(_) => {
-                if let Some(identity) = self.select_shard(message) {
-                    if let Err(err) = conn.forward(message, identity.to_vec()) {
+            Some(protocol) => {
+                if let Some(identity) = self.select_shard(message).map(|id| id.to_vec()) {
+                    self.transactions.routed(message, protocol);
+                    if let Err(err) = conn.forward(message, identity) {
                         error!("{}", err);
                     }
                     return;
                 }
+                self.transactions.unroutable(protocol);
                 let err = NetError::new(ErrCode::NO_SHARD, "rt:route:2");
                 error!("{}", err);
                 message.populate_reply(&*err).unwrap();
@@ -109,6 +119,7 @@ impl Server {
         let mut conn = SrvConn::new(&mut self.context)?;
         let mut message = Message::default();
         conn.bind(&self.config.addr())?;
+        metrics::start(&self.config)?;
         println!("Listening on ({})", self.config.addr());
         info!("builder-router is ready to go.");
         loop {
@@ -134,7 +145,10 @@ impl Server {
                 Err(ConnErr::Timeout) => (),
                 Err(err) => error!("{}", err),
             }
-            self.servers.expire();
+            if self.servers.expire() {
+                metrics::observe_servers(&self.servers);
+            }
+            self.transactions.expire();
         }
         Ok(())
     }
@@ -199,7 +213,8 @@ impl ServerMap {
         );
     }
 
-    pub fn expire(&mut self) {
+    /// Drops the registrations which weren't renewed in time, returning whether there were any.
+    pub fn expire(&mut self) -> bool {
         let now = time::clock_time();
         let mut expired = vec![];
         self.timestamps.retain(|id, last| if *last <= now {
@@ -215,6 +230,7 @@ impl ServerMap {
         for net_ident in expired.iter() {
             self.drop(net_ident);
         }
+        !expired.is_empty()
     }
 
     pub fn get(&self, protocol: &Protocol, shard: &ShardId) -> Option<&[u8]> {
@@ -224,6 +240,19 @@ impl ServerMap {
             .and_then(|s| Some(s.as_slice()))
     }
 
+    /// The number of servers registered for each protocol.
+    pub fn server_counts(&self) -> Vec<(Protocol, usize)> {
+        self.reg
+            .iter()
+            .map(|(protocol, shards)| {
+                let mut servers = shards.values().collect::<Vec<&Vec<u8>>>();
+                servers.sort();
+                servers.dedup();
+                (*protocol, servers.len())
+            })
+            .collect()
+    }
+
     pub fn next_expiration(&self) -> i64 {
         let mut timestamps = self.timestamps.values().collect::<Vec<&i64>>();
         timestamps.sort_by(|av, bv| av.cmp(bv));
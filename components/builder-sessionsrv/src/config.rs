@@ -32,8 +32,10 @@ impl Default for Config {
     fn default() -> Self {
         let mut datastore = DataStoreCfg::default();
         datastore.database = String::from("builder_sessionsrv");
+        let mut app = AppCfg::default();
+        app.health_port = 9639;
         Config {
-            app: AppCfg::default(),
+            app: app,
             datastore: datastore,
             github: GitHubCfg::default(),
             permissions: PermissionsCfg::default(),
@@ -16,6 +16,7 @@
 
 use std::sync::Arc;
 
+use chrono::{DateTime, UTC};
 use db::config::{DataStoreCfg, ShardId};
 use db::pool::Pool;
 use db::migration::Migrator;
@@ -51,6 +52,7 @@ impl DataStore {
         migrations::accounts::migrate(&mut migrator)?;
         migrations::sessions::migrate(&mut migrator)?;
         migrations::invitations::migrate(&mut migrator)?;
+        migrations::access_tokens::migrate(&mut migrator)?;
 
         migrator.finish()?;
 
@@ -278,6 +280,110 @@ impl DataStore {
         Ok(response)
     }
 
+    /// Stores a personal access token by the hash of its secret.
+    pub fn create_access_token(
+        &self,
+        request: &sessionsrv::AccessTokenCreate,
+        token_hash: &str,
+    ) -> SrvResult<sessionsrv::AccessToken> {
+        let conn = self.pool.get(request)?;
+        let scopes: Vec<String> = request.get_scopes().iter().map(|s| s.to_string()).collect();
+        let rows = conn.query(
+            "SELECT * FROM insert_account_token_v1($1, $2, $3, $4)",
+            &[
+                &(request.get_account_id() as i64),
+                &request.get_name(),
+                &scopes,
+                &token_hash,
+            ],
+        ).map_err(SrvError::AccessTokenCreate)?;
+        self.row_to_access_token(rows.get(0))
+    }
+
+    pub fn get_access_tokens(
+        &self,
+        request: &sessionsrv::AccessTokenListRequest,
+    ) -> SrvResult<sessionsrv::AccessTokenListResponse> {
+        let conn = self.pool.get(request)?;
+        let rows = conn.query(
+            "SELECT * FROM get_account_tokens_v1($1)",
+            &[&(request.get_account_id() as i64)],
+        ).map_err(SrvError::AccessTokenList)?;
+        let mut response = sessionsrv::AccessTokenListResponse::new();
+        response.set_account_id(request.get_account_id());
+        let mut tokens = protobuf::RepeatedField::new();
+        for row in rows.iter() {
+            tokens.push(self.row_to_access_token(row)?);
+        }
+        response.set_tokens(tokens);
+        Ok(response)
+    }
+
+    /// Looks up the personal access token a session is authenticated with, along with the hash of
+    /// its secret.
+    pub fn get_access_token(
+        &self,
+        request: &sessionsrv::SessionGet,
+    ) -> SrvResult<Option<(sessionsrv::AccessToken, String)>> {
+        let conn = self.pool.get(request)?;
+        let rows = conn.query(
+            "SELECT * FROM get_account_token_v1($1)",
+            &[&(request.get_token().get_access_token_id() as i64)],
+        ).map_err(SrvError::AccessTokenGet)?;
+        if rows.len() != 0 {
+            let row = rows.get(0);
+            let token_hash: String = row.get("token_hash");
+            Ok(Some((self.row_to_access_token(row)?, token_hash)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Marks a personal access token as used, once a session was authenticated with it.
+    pub fn touch_access_token(&self, request: &sessionsrv::SessionGet) -> SrvResult<()> {
+        let conn = self.pool.get(request)?;
+        conn.execute(
+            "SELECT touch_account_token_v1($1)",
+            &[&(request.get_token().get_access_token_id() as i64)],
+        ).map_err(SrvError::AccessTokenGet)?;
+        Ok(())
+    }
+
+    /// Deletes a personal access token, returning whether the account had it.
+    pub fn revoke_access_token(&self, request: &sessionsrv::AccessTokenRevoke) -> SrvResult<bool> {
+        let conn = self.pool.get(request)?;
+        let rows = conn.query(
+            "SELECT * FROM delete_account_token_v1($1, $2)",
+            &[
+                &(request.get_account_id() as i64),
+                &(request.get_id() as i64),
+            ],
+        ).map_err(SrvError::AccessTokenRevoke)?;
+        Ok(rows.len() != 0)
+    }
+
+    fn row_to_access_token(
+        &self,
+        row: postgres::rows::Row,
+    ) -> SrvResult<sessionsrv::AccessToken> {
+        let mut token = sessionsrv::AccessToken::new();
+        let id: i64 = row.get("id");
+        token.set_id(id as u64);
+        let account_id: i64 = row.get("account_id");
+        token.set_account_id(account_id as u64);
+        token.set_name(row.get("name"));
+        let scopes: Vec<String> = row.get("scopes");
+        for scope in scopes {
+            token.mut_scopes().push(scope.parse()?);
+        }
+        let created_at = row.get::<&str, DateTime<UTC>>("created_at");
+        token.set_created_at(created_at.to_rfc3339());
+        if let Some(last_used_at) = row.get::<&str, Option<DateTime<UTC>>>("last_used_at") {
+            token.set_last_used_at(last_used_at.to_rfc3339());
+        }
+        Ok(token)
+    }
+
     fn row_to_account(&self, row: postgres::rows::Row) -> sessionsrv::Account {
         let mut account = sessionsrv::Account::new();
         let id: i64 = row.get("id");
@@ -14,6 +14,7 @@
 
 use std::error;
 use std::fmt;
+use std::io;
 use std::num;
 
 use db;
@@ -27,6 +28,11 @@ pub type SrvResult<T> = Result<T, SrvError>;
 
 #[derive(Debug)]
 pub enum SrvError {
+    AccessTokenCreate(postgres::error::Error),
+    AccessTokenGenerate(io::Error),
+    AccessTokenGet(postgres::error::Error),
+    AccessTokenList(postgres::error::Error),
+    AccessTokenRevoke(postgres::error::Error),
     AccountCreate(postgres::error::Error),
     AccountGet(postgres::error::Error),
     AccountGetById(postgres::error::Error),
@@ -57,6 +63,21 @@ pub enum SrvError {
 impl fmt::Display for SrvError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let msg = match *self {
+            SrvError::AccessTokenCreate(ref e) => {
+                format!("Error creating access token in database, {}", e)
+            }
+            SrvError::AccessTokenGenerate(ref e) => {
+                format!("Error generating access token, {}", e)
+            }
+            SrvError::AccessTokenGet(ref e) => {
+                format!("Error getting access token from database, {}", e)
+            }
+            SrvError::AccessTokenList(ref e) => {
+                format!("Error listing access tokens in database, {}", e)
+            }
+            SrvError::AccessTokenRevoke(ref e) => {
+                format!("Error revoking access token in database, {}", e)
+            }
             SrvError::AccountCreate(ref e) => format!("Error creating account in database, {}", e),
             SrvError::AccountGet(ref e) => format!("Error getting account from database, {}", e),
             SrvError::AccountGetById(ref e) => {
@@ -114,6 +135,11 @@ impl fmt::Display for SrvError {
 impl error::Error for SrvError {
     fn description(&self) -> &str {
         match *self {
+            SrvError::AccessTokenCreate(ref err) => err.description(),
+            SrvError::AccessTokenGenerate(ref err) => err.description(),
+            SrvError::AccessTokenGet(ref err) => err.description(),
+            SrvError::AccessTokenList(ref err) => err.description(),
+            SrvError::AccessTokenRevoke(ref err) => err.description(),
             SrvError::AccountCreate(ref err) => err.description(),
             SrvError::AccountGet(ref err) => err.description(),
             SrvError::AccountGetById(ref err) => err.description(),
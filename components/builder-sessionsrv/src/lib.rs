@@ -16,6 +16,8 @@
 #![cfg_attr(feature="clippy", plugin(clippy))]
 
 extern crate base64;
+extern crate chrono;
+extern crate constant_time_eq;
 extern crate github_api_client;
 extern crate habitat_builder_db as db;
 extern crate habitat_builder_protocol as protocol;
@@ -28,6 +30,7 @@ extern crate lazy_static;
 extern crate log;
 extern crate postgres;
 extern crate protobuf;
+extern crate rand;
 extern crate r2d2;
 extern crate serde;
 #[macro_use]
@@ -0,0 +1,99 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use db::migration::Migrator;
+
+use error::SrvResult;
+
+pub fn migrate(migrator: &mut Migrator) -> SrvResult<()> {
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE SEQUENCE IF NOT EXISTS account_tokens_id_seq;"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE TABLE IF NOT EXISTS account_tokens (
+                        id bigint PRIMARY KEY DEFAULT next_id_v1('account_tokens_id_seq'),
+                        account_id bigint REFERENCES accounts(id) ON DELETE CASCADE,
+                        name text,
+                        scopes text[],
+                        token_hash text,
+                        created_at timestamptz DEFAULT now(),
+                        last_used_at timestamptz
+                        )"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION insert_account_token_v1 (
+                    p_account_id bigint,
+                    p_name text,
+                    p_scopes text[],
+                    p_token_hash text
+                 ) RETURNS SETOF account_tokens
+                 LANGUAGE SQL
+                 VOLATILE AS $$
+                    INSERT INTO account_tokens (account_id, name, scopes, token_hash)
+                    VALUES (p_account_id, p_name, p_scopes, p_token_hash)
+                    RETURNING *;
+                 $$"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION get_account_tokens_v1 (
+                    p_account_id bigint
+                 ) RETURNS SETOF account_tokens
+                 LANGUAGE SQL
+                 STABLE AS $$
+                    SELECT * FROM account_tokens
+                    WHERE account_id = p_account_id
+                    ORDER BY created_at DESC;
+                 $$"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION get_account_token_v1 (
+                    p_id bigint
+                 ) RETURNS SETOF account_tokens
+                 LANGUAGE SQL
+                 STABLE AS $$
+                    SELECT * FROM account_tokens
+                    WHERE id = p_id;
+                 $$"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION touch_account_token_v1 (
+                    p_id bigint
+                 ) RETURNS void
+                 LANGUAGE SQL
+                 VOLATILE AS $$
+                    UPDATE account_tokens SET last_used_at = now()
+                    WHERE id = p_id;
+                 $$"#,
+    )?;
+    migrator.migrate(
+        "accountsrv",
+        r#"CREATE OR REPLACE FUNCTION delete_account_token_v1 (
+                    p_account_id bigint,
+                    p_id bigint
+                 ) RETURNS SETOF account_tokens
+                 LANGUAGE SQL
+                 VOLATILE AS $$
+                    DELETE FROM account_tokens
+                    WHERE account_id = p_account_id AND id = p_id
+                    RETURNING *;
+                 $$"#,
+    )?;
+    Ok(())
+}
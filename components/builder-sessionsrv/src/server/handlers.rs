@@ -14,14 +14,20 @@
 
 use std::env;
 
+use constant_time_eq::constant_time_eq;
+use hab_core::crypto::hash;
 use hab_net::app::prelude::*;
 use hab_net::privilege::{self, FeatureFlags};
+use rand::{OsRng, Rng};
 
 use protocol::net;
 use protocol::sessionsrv as proto;
 
 use super::{encode_token, ServerState, Session};
-use error::SrvResult;
+use error::{SrvError, SrvResult};
+
+/// Number of random bytes in the secret of a personal access token
+const ACCESS_TOKEN_SECRET_LEN: usize = 32;
 
 pub fn account_get_id(
     req: &mut Message,
@@ -126,7 +132,9 @@ pub fn session_create(
     let mut flags = FeatureFlags::default();
     if env::var_os("HAB_FUNC_TEST").is_some() {
         flags = FeatureFlags::all();
-    } else {
+    } else if msg.get_provider() == proto::OAuthProvider::GitHub {
+        // Permissions are granted by membership of GitHub teams, which only users signing in
+        // with GitHub can be checked for. Users of other providers get the default ones.
         assign_permissions(msg.get_name(), &mut flags, state)
     }
 
@@ -158,6 +166,9 @@ pub fn session_get(
     state: &mut ServerState,
 ) -> SrvResult<()> {
     let msg = req.parse::<proto::SessionGet>()?;
+    if msg.get_token().has_access_token_id() {
+        return access_token_session_get(req, conn, state, &msg);
+    }
     let token = encode_token(msg.get_token())?;
     let expire_session = {
         match state.sessions.read().unwrap().get(token.as_str()) {
@@ -184,6 +195,147 @@ pub fn session_get(
     Ok(())
 }
 
+/// Replies with a session for the account of a personal access token, limited to the token's
+/// scopes. Sessions of access tokens are never given any feature flags.
+fn access_token_session_get(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+    msg: &proto::SessionGet,
+) -> SrvResult<()> {
+    let token = msg.get_token();
+    let access_token = match state.datastore.get_access_token(msg) {
+        Ok(Some((access_token, token_hash))) => {
+            let presented_hash = hash::hash_bytes(token.get_token());
+            if access_token.get_account_id() == token.get_account_id() &&
+                constant_time_eq(presented_hash.as_bytes(), token_hash.as_bytes())
+            {
+                Some(access_token)
+            } else {
+                None
+            }
+        }
+        Ok(None) => None,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-session-get:0");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+    let mut access_token = match access_token {
+        Some(access_token) => access_token,
+        None => {
+            let err = NetError::new(ErrCode::SESSION_EXPIRED, "ss:access-token-session-get:1");
+            conn.route_reply(req, &*err)?;
+            return Ok(());
+        }
+    };
+    // Only a token which was actually presented is marked as used
+    if let Err(e) = state.datastore.touch_access_token(msg) {
+        warn!("Unable to mark access token {} as used, {}", access_token.get_id(), e);
+    }
+    let mut account_get = proto::AccountGetId::new();
+    account_get.set_id(access_token.get_account_id());
+    match state.datastore.get_account_by_id(&account_get) {
+        Ok(Some(mut account)) => {
+            let mut session = proto::Session::new();
+            session.set_id(account.get_id());
+            session.set_email(account.take_email());
+            session.set_name(account.take_name());
+            session.set_token(encode_token(token)?);
+            session.set_flags(FeatureFlags::default().bits());
+            session.set_access_token_id(access_token.get_id());
+            session.set_scopes(access_token.take_scopes());
+            conn.route_reply(req, &session)?;
+        }
+        Ok(None) => {
+            let err = NetError::new(ErrCode::SESSION_EXPIRED, "ss:access-token-session-get:2");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-session-get:3");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn access_token_create(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::AccessTokenCreate>()?;
+    let secret = generate_secret()?;
+    match state.datastore.create_access_token(
+        &msg,
+        &hash::hash_bytes(&secret),
+    ) {
+        Ok(mut access_token) => {
+            let mut token = proto::SessionToken::new();
+            token.set_account_id(access_token.get_account_id());
+            token.set_access_token_id(access_token.get_id());
+            token.set_token(secret);
+            access_token.set_token(encode_token(&token)?);
+            conn.route_reply(req, &access_token)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-create:0");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn access_token_list(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::AccessTokenListRequest>()?;
+    match state.datastore.get_access_tokens(&msg) {
+        Ok(response) => conn.route_reply(req, &response)?,
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-list:0");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+pub fn access_token_revoke(
+    req: &mut Message,
+    conn: &mut RouteConn,
+    state: &mut ServerState,
+) -> SrvResult<()> {
+    let msg = req.parse::<proto::AccessTokenRevoke>()?;
+    match state.datastore.revoke_access_token(&msg) {
+        Ok(true) => conn.route_reply(req, &net::NetOk::new())?,
+        Ok(false) => {
+            let err = NetError::new(ErrCode::ENTITY_NOT_FOUND, "ss:access-token-revoke:0");
+            conn.route_reply(req, &*err)?;
+        }
+        Err(e) => {
+            let err = NetError::new(ErrCode::DATA_STORE, "ss:access-token-revoke:1");
+            error!("{}, {}", e, err);
+            conn.route_reply(req, &*err)?;
+        }
+    }
+    Ok(())
+}
+
+/// Generates the secret of a personal access token from the operating system's random source
+fn generate_secret() -> SrvResult<Vec<u8>> {
+    let mut rng = OsRng::new().map_err(SrvError::AccessTokenGenerate)?;
+    let mut secret = vec![0u8; ACCESS_TOKEN_SECRET_LEN];
+    rng.fill_bytes(&mut secret);
+    Ok(secret)
+}
+
 pub fn account_origin_invitation_create(
     req: &mut Message,
     conn: &mut RouteConn,
@@ -61,6 +61,12 @@ lazy_static! {
             handlers::account_origin_create);
         map.register(proto::AccountOriginRemove::descriptor_static(None),
             handlers::account_origin_remove);
+        map.register(proto::AccessTokenCreate::descriptor_static(None),
+            handlers::access_token_create);
+        map.register(proto::AccessTokenListRequest::descriptor_static(None),
+            handlers::access_token_list);
+        map.register(proto::AccessTokenRevoke::descriptor_static(None),
+            handlers::access_token_revoke);
         map
     };
 
@@ -193,6 +199,15 @@ impl Dispatcher for SessionSrv {
     fn dispatch_table() -> &'static DispatchTable<Self> {
         &DISPATCH_TABLE
     }
+
+    fn health_checks(state: &<Self::State as AppState>::InitState) -> HealthChecks {
+        vec![
+            (
+                "datastore",
+                Box::new(state.datastore.pool.clone()) as Box<HealthCheck>,
+            ),
+        ]
+    }
 }
 
 pub fn encode_token(token: &proto::SessionToken) -> SrvResult<String> {
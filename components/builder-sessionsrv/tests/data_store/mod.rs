@@ -98,3 +98,50 @@ fn delete_origin() {
 
     assert_eq!(0, accounts2.get_origins().len());
 }
+
+#[test]
+fn access_tokens() {
+    let ds = datastore_test!(DataStore);
+    let bobo = create_bobo_account(&ds);
+
+    let mut atc = sessionsrv::AccessTokenCreate::new();
+    atc.set_account_id(bobo.get_id());
+    atc.set_name(String::from("ci"));
+    atc.set_scopes(vec![
+        sessionsrv::AccessTokenScope::PackageUpload,
+        sessionsrv::AccessTokenScope::JobSchedule,
+    ]);
+    let token = ds.create_access_token(&atc, "c0ffee").expect(
+        "could not create access token",
+    );
+    assert_eq!(token.get_account_id(), bobo.get_id());
+    assert_eq!(token.get_name(), "ci");
+    assert_eq!(token.get_scopes(), atc.get_scopes());
+    assert!(!token.has_last_used_at());
+
+    let mut st = sessionsrv::SessionToken::new();
+    st.set_account_id(bobo.get_id());
+    st.set_access_token_id(token.get_id());
+    let mut sg = sessionsrv::SessionGet::new();
+    sg.set_token(st);
+    let (found, token_hash) = ds.get_access_token(&sg)
+        .expect("could not get access token")
+        .expect("access token should exist");
+    assert_eq!(found.get_id(), token.get_id());
+    assert_eq!(token_hash, "c0ffee");
+    assert!(found.has_last_used_at());
+
+    let mut atl = sessionsrv::AccessTokenListRequest::new();
+    atl.set_account_id(bobo.get_id());
+    let list = ds.get_access_tokens(&atl).expect(
+        "could not list access tokens",
+    );
+    assert_eq!(list.get_tokens().len(), 1);
+
+    let mut atr = sessionsrv::AccessTokenRevoke::new();
+    atr.set_account_id(bobo.get_id());
+    atr.set_id(token.get_id());
+    assert!(ds.revoke_access_token(&atr).expect("could not revoke access token"));
+    assert!(!ds.revoke_access_token(&atr).expect("could not revoke access token"));
+    assert!(ds.get_access_token(&sg).expect("could not get access token").is_none());
+}
@@ -93,7 +93,29 @@ export function fetchBuildLog(id: string, token: string, start = 0) {
       dispatch(clearBuildLog());
     }
 
-    new BuilderApiClient(token)
+    const client = new BuilderApiClient(token);
+
+    if (getState().builds.selected.stream && client.canFollowBuildLog()) {
+      return client
+        .followBuildLog(
+          id,
+          start,
+          (data) => dispatch(populateBuildLog(data)),
+          () => getState().builds.selected.stream
+        )
+        .then((complete) => {
+          if (complete) {
+            const log = getState().builds.selected.log;
+            const stop = log.stop || 0;
+            dispatch(populateBuildLog({ start: stop, stop: stop, content: [], is_complete: true }));
+            dispatch(fetchBuild(id, token));
+          }
+        })
+        .catch((error) => dispatch(populateBuildLog(null, error)));
+    }
+
+    // Browsers unable to follow the log poll it instead
+    client
       .getBuildLog(id, start)
       .then((data) => {
         dispatch(populateBuildLog(data));
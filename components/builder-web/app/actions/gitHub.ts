@@ -29,6 +29,7 @@ import { DANGER, WARNING } from './notifications';
 import { GitHubApiClient } from '../client/github-api';
 import { setBldrSessionToken } from './sessions';
 import { Browser } from '../browser';
+import { signsInWithGitHub } from '../util';
 
 const uuid = require('uuid').v4;
 const gitHubTokenAuthUrl = `${config['habitat_api_url']}/v1/authenticate`;
@@ -46,7 +47,10 @@ export function authenticate(gitHubToken: string, bldrToken: string) {
 
     if (gitHubToken) {
       dispatch(setGitHubAuthToken(gitHubToken));
+    }
 
+    // Users of other OAuth providers get their username from their profile
+    if (gitHubToken && signsInWithGitHub()) {
       fetch(`${config['github_api_url']}/user?access_token=${gitHubToken}`).then(response => {
         if (response.ok) {
           return response.json();
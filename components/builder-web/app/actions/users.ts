@@ -16,6 +16,7 @@ import { authenticate, removeSession, loadGitHubSessionState, loadBldrSessionSta
 import { addNotification, SUCCESS, DANGER } from './notifications';
 import { BuilderApiClient } from '../client/builder-api';
 import { Browser } from '../browser';
+import { signsInWithGitHub } from '../util';
 
 export const POPULATE_PROFILE = 'POPULATE_PROFILE';
 export const SET_PRIVILEGES = 'SET_PRIVILEGES';
@@ -30,6 +31,10 @@ export function fetchProfile(token: string) {
       .then(data => {
         dispatch(populateProfile(data));
         notifySegment(data);
+
+        if (!signsInWithGitHub()) {
+          dispatch(setCurrentUsername(data.name));
+        }
       })
       .catch(err => { });
   };
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-import { BuilderApiClient } from './builder-api';
+import { BuilderApiClient, parseEvent } from './builder-api';
 
 describe('BuilderApiClient', () => {
 
@@ -62,4 +62,21 @@ describe('BuilderApiClient', () => {
       });
     });
   });
+
+  describe('parseEvent', () => {
+    it('reads the name and data of an event', () => {
+      expect(parseEvent('event: log\nid: 2\ndata: {"stop":2}')).toEqual({
+        name: 'log',
+        data: '{"stop":2}'
+      });
+    });
+
+    it('joins data split across lines', () => {
+      expect(parseEvent('event: complete\ndata: a\ndata: b').data).toEqual('a\nb');
+    });
+
+    it('reads nothing from comments', () => {
+      expect(parseEvent(': keepalive')).toEqual({ name: '', data: '' });
+    });
+  });
 });
@@ -269,6 +269,80 @@ export class BuilderApiClient {
     });
   }
 
+  // Whether the browser can read a response as it arrives, which following a build log needs
+  public canFollowBuildLog() {
+    return typeof window['ReadableStream'] !== 'undefined' &&
+      typeof window['TextDecoder'] !== 'undefined';
+  }
+
+  // Follows a build log as server-sent events, calling `onLog` with every new portion of it.
+  // Resolves with whether the log is complete, or with false if `isFollowing` stopped returning
+  // true or the connection was closed first.
+  public followBuildLog(
+    id: string, start: number, onLog: (log) => void, isFollowing: () => boolean
+  ) {
+    return new Promise((resolve, reject) => {
+      fetch(`${this.urlPrefix}/jobs/${id}/log/stream?start=${start}&color=true`, {
+        method: 'GET',
+        headers: this.headers
+      })
+        .then(response => this.handleUnauthorized(response, reject))
+        .then(response => {
+          if (!response.ok) {
+            reject(new Error(response.statusText));
+            return;
+          }
+
+          const reader = response['body'].getReader();
+          const decoder = new window['TextDecoder']('utf-8');
+          let buffer = '';
+
+          const read = () => {
+            if (!isFollowing()) {
+              reader.cancel();
+              resolve(false);
+              return;
+            }
+
+            reader.read().then(chunk => {
+              if (chunk.done) {
+                resolve(false);
+                return;
+              }
+
+              buffer += decoder.decode(chunk.value, { stream: true });
+              const messages = buffer.split('\n\n');
+              buffer = messages.pop();
+
+              for (const message of messages) {
+                const event = parseEvent(message);
+
+                if (event.name === 'log') {
+                  onLog(JSON.parse(event.data));
+                }
+                else if (event.name === 'complete') {
+                  reader.cancel();
+                  resolve(true);
+                  return;
+                }
+                else if (event.name === 'error') {
+                  reader.cancel();
+                  reject(new Error(JSON.parse(event.data).msg));
+                  return;
+                }
+              }
+
+              read();
+            })
+            .catch(error => reject(error));
+          };
+
+          read();
+        })
+        .catch(error => this.handleError(error, reject));
+    });
+  }
+
   public getBuilds(origin: string, name: string) {
     return new Promise((resolve, reject) => {
       fetch(`${this.urlPrefix}/projects/${origin}/${name}/jobs`, {
@@ -724,3 +798,20 @@ export class BuilderApiClient {
     return response;
   }
 }
+
+// Reads the name and data of a server-sent event. Comments, such as keepalives, have neither.
+export function parseEvent(message: string) {
+  let name = '';
+  let data = [];
+
+  message.split('\n').forEach((line) => {
+    if (line.indexOf('event:') === 0) {
+      name = line.slice(6).trim();
+    }
+    else if (line.indexOf('data:') === 0) {
+      data.push(line.slice(5).replace(/^ /, ''));
+    }
+  });
+
+  return { name: name, data: data.join('\n') };
+}
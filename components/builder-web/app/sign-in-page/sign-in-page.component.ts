@@ -15,7 +15,7 @@
 import { Component, OnDestroy } from '@angular/core';
 import { AppStore } from '../app.store';
 import { setLayout, signOut } from '../actions/index';
-import { createGitHubLoginUrl } from '../util';
+import { createLoginUrl, signsInWithGitHub } from '../util';
 import config from '../config';
 
 @Component({
@@ -36,8 +36,16 @@ export class SignInPageComponent implements OnDestroy {
     return `${config['github_web_url']}/join`;
   }
 
-  get gitHubLoginUrl() {
-    return createGitHubLoginUrl(this.store.getState().gitHub.authState);
+  get loginUrl() {
+    return createLoginUrl(this.store.getState().gitHub.authState);
+  }
+
+  get providerName() {
+    return signsInWithGitHub() ? 'GitHub' : (config['oauth_provider_name'] || 'Single Sign-On');
+  }
+
+  get signsInWithGitHub() {
+    return signsInWithGitHub();
   }
 
   ngOnDestroy() {
@@ -13,8 +13,37 @@
 // limitations under the License.
 
 import * as util from './util';
+import config from './config';
 
 describe('util', () => {
+  describe('createLoginUrl', () => {
+    afterEach(() => {
+      delete config['oauth_provider'];
+      delete config['oauth_authorize_url'];
+      delete config['oauth_client_id'];
+    });
+
+    describe('when signing in with GitHub', () => {
+      it('returns the GitHub login URL', () => {
+        config['github_web_url'] = 'https://github.com';
+        expect(util.createLoginUrl('abc')).toMatch(/^https:\/\/github.com\/login\/oauth\/authorize\?/);
+      });
+    });
+
+    describe('when signing in with an OpenID Connect provider', () => {
+      it('returns the authorization URL of the provider', () => {
+        config['oauth_provider'] = 'oidc';
+        config['oauth_authorize_url'] = 'https://sso.example.com/authorize';
+        config['oauth_client_id'] = 'builder';
+        const url = util.createLoginUrl('abc');
+        expect(url).toMatch(/^https:\/\/sso.example.com\/authorize\?client_id=builder&/);
+        expect(url).toContain('response_type=code');
+        expect(url).toContain('scope=openid%20profile%20email');
+        expect(url).toContain('state=abc');
+      });
+    });
+  });
+
   describe('packageString', () => {
     describe('with a fully qualified identifier', () => {
       it('returns the string', () => {
@@ -32,6 +32,30 @@ export function createGitHubLoginUrl(state) {
   return `${urlPrefix}?${queryString}`;
 }
 
+// Whether users sign in with GitHub, rather than another OAuth provider
+export function signsInWithGitHub() {
+  return !config['oauth_provider'] || config['oauth_provider'] === 'github';
+}
+
+// Create a login URL of the OAuth provider users sign in with
+export function createLoginUrl(state) {
+  if (signsInWithGitHub()) {
+    return createGitHubLoginUrl(state);
+  }
+
+  const params = {
+    client_id: config['oauth_client_id'],
+    redirect_uri: `${window.location.protocol}//${window.location.host}/`,
+    response_type: 'code',
+    scope: config['oauth_provider'] === 'gitlab' ? 'read_user' : 'openid profile email',
+    state
+  };
+  const queryString = Object.keys(params).map((k) =>
+    `${k}=${encodeURIComponent(params[k])}`).
+    join('&');
+  return `${config['oauth_authorize_url']}?${queryString}`;
+}
+
 // Pretty print a time
 // Print a number of seconds as minutes and seconds
 export function duration(s) {
@@ -22,6 +22,14 @@ habitatConfig({
     github_app_url: "https://github.com/apps/habitat-builder-dev",
     // The Habitat Builder GitHub app ID
     github_app_id: "5629",
+    // The OAuth provider users sign in with, one of "github", "gitlab" or "oidc"
+    oauth_provider: "github",
+    // The authorization URL of the OAuth provider, unless signing in with GitHub
+    oauth_authorize_url: "",
+    // The client ID of Builder with the OAuth provider, unless signing in with GitHub
+    oauth_client_id: "",
+    // The name of the OAuth provider shown on the sign-in page, unless signing in with GitHub
+    oauth_provider_name: "",
     // The URL for the Habitat source code
     source_code_url: "https://github.com/habitat-sh/habitat",
     // The URL for tutorials
@@ -25,6 +25,22 @@ use error::Error;
 
 pub type JobSrvCfg = Vec<JobSrvAddr>;
 
+/// Where studio builds are run
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum BuildRunner {
+    /// In a chroot on the worker's host, contained by airlock if it's enabled
+    Studio,
+    /// In a Docker container, keeping plan code away from the worker's host
+    Docker,
+}
+
+impl Default for BuildRunner {
+    fn default() -> Self {
+        BuildRunner::Studio
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
 #[serde(default)]
 pub struct Config {
@@ -50,6 +66,43 @@ pub struct Config {
     pub airlock_enabled: bool,
     pub network_interface: Option<String>,
     pub network_gateway: Option<IpAddr>,
+    /// Labels advertised to the job server, such as `big-memory`. Jobs of projects requiring
+    /// labels are only dispatched to workers advertising all of them.
+    pub labels: Vec<String>,
+    /// Size in megabytes the cache of dependency artifacts shared by builds is kept under. The
+    /// cache is disabled if 0.
+    pub artifact_cache_size_mb: u64,
+    /// Builds run at once, each in its own studio
+    pub capacity: u32,
+    /// Where studio builds are run
+    pub build_runner: BuildRunner,
+    /// Image of the containers builds are run in by the Docker runner
+    pub studio_image: String,
+    /// Drop the network access of builds once their dependencies and sources are downloaded.
+    /// Requires airlock networking of studio builds, which Docker builds don't have, so they keep
+    /// their network access.
+    pub network_lockdown: bool,
+    /// Most lines of a build's log output coalesced into one message to the job server
+    pub log_batch_lines: usize,
+    /// Milliseconds a line of log output waits for more lines before it's sent to the job server
+    pub log_batch_ms: u64,
+    /// Mask anything looking like a credential, such as AWS keys and tokens, in build logs. The
+    /// values of the origin's secrets are masked regardless.
+    pub log_redaction: bool,
+    /// Regular expressions of anything else to mask in build logs. Only the first capture group
+    /// is masked if the expression has one.
+    pub log_redact_patterns: Vec<String>,
+    /// Free disk in megabytes under which jobs are rejected, once stale workspaces and cached
+    /// artifacts have been removed. The check is disabled if 0.
+    pub min_disk_free_mb: u64,
+    /// Channel watched for newer releases of the worker, which it updates itself to once its
+    /// running jobs are done. Self-update is disabled if unset.
+    pub update_channel: Option<String>,
+    /// Seconds between checks of the update channel
+    pub update_interval: u64,
+    /// Where the cgroup filesystem is mounted. Builds of jobs with memory or CPU limits are run in
+    /// control groups created under it, unless the Docker runner enforces the limits.
+    pub cgroup_root: PathBuf,
 }
 
 impl Config {
@@ -77,9 +130,23 @@ impl Default for Config {
             jobsrv: vec![JobSrvAddr::default()],
             features_enabled: "".to_string(),
             github: GitHubCfg::default(),
-            airlock_enabled: true,
+            airlock_enabled: cfg!(target_os = "linux"),
             network_interface: None,
             network_gateway: None,
+            labels: vec![],
+            artifact_cache_size_mb: 10240,
+            capacity: 1,
+            build_runner: BuildRunner::default(),
+            studio_image: String::from("habitat/default-studio-x86_64-linux:latest"),
+            network_lockdown: false,
+            log_batch_lines: 100,
+            log_batch_ms: 250,
+            log_redaction: true,
+            log_redact_patterns: vec![],
+            min_disk_free_mb: 1024,
+            update_channel: None,
+            update_interval: 300,
+            cgroup_root: PathBuf::from("/sys/fs/cgroup"),
         }
     }
 }
@@ -121,6 +188,20 @@ mod tests {
         features_enabled = "FOO,BAR"
         network_interface = "eth1"
         network_gateway = "192.168.10.1"
+        labels = ["big-memory", "ssd"]
+        artifact_cache_size_mb = 2048
+        capacity = 4
+        build_runner = "docker"
+        studio_image = "habitat/default-studio-x86_64-linux:0.50.0"
+        network_lockdown = true
+        log_batch_lines = 20
+        log_batch_ms = 500
+        log_redaction = false
+        log_redact_patterns = ["npm_[A-Za-z0-9]{36}"]
+        min_disk_free_mb = 2048
+        update_channel = "stable"
+        update_interval = 60
+        cgroup_root = "/mnt/cgroup"
 
         [[jobsrv]]
         host = "1:1:1:1:1:1:1:1"
@@ -135,6 +216,23 @@ mod tests {
 
         let config = Config::from_raw(&content).unwrap();
         assert_eq!(&config.auth_token, "mytoken");
+        assert_eq!(config.labels, vec!["big-memory", "ssd"]);
+        assert_eq!(config.artifact_cache_size_mb, 2048);
+        assert_eq!(config.capacity, 4);
+        assert_eq!(config.build_runner, BuildRunner::Docker);
+        assert_eq!(config.network_lockdown, true);
+        assert_eq!(config.log_batch_lines, 20);
+        assert_eq!(config.log_batch_ms, 500);
+        assert_eq!(config.log_redaction, false);
+        assert_eq!(config.log_redact_patterns, vec!["npm_[A-Za-z0-9]{36}"]);
+        assert_eq!(config.min_disk_free_mb, 2048);
+        assert_eq!(config.update_channel, Some(String::from("stable")));
+        assert_eq!(config.update_interval, 60);
+        assert_eq!(config.cgroup_root, PathBuf::from("/mnt/cgroup"));
+        assert_eq!(
+            &config.studio_image,
+            "habitat/default-studio-x86_64-linux:0.50.0"
+        );
         assert_eq!(&format!("{}", config.data_path.display()), "/path/to/data");
         assert_eq!(&format!("{}", config.log_path.display()), "/path/to/logs");
         assert_eq!(&format!("{}", config.jobsrv[0].host), "1:1:1:1:1:1:1:1");
@@ -0,0 +1,181 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keeps enough free disk on the worker for the builds it accepts.
+//!
+//! Before a job is started the free space of the volume holding the data path is checked. When
+//! it's under the configured minimum, the workspaces left behind by builds which are no longer
+//! running are removed, followed by the least recently used entries of the artifact cache. If
+//! that doesn't free enough space the job is rejected, so that JobSrv dispatches it to another
+//! worker rather than it failing part way through its build.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use config::Config;
+use error::{Error, Result};
+use runner::ArtifactCache;
+
+pub struct DiskMgr {
+    data_path: PathBuf,
+    min_free_bytes: u64,
+    artifact_cache: Option<ArtifactCache>,
+}
+
+impl DiskMgr {
+    pub fn new(config: &Config) -> Self {
+        DiskMgr {
+            data_path: config.data_path.clone(),
+            min_free_bytes: config.min_disk_free_mb * 1024 * 1024,
+            artifact_cache: ArtifactCache::from_config(config),
+        }
+    }
+
+    /// Checks that there is enough free disk to start a build, reclaiming space if there isn't.
+    /// The workspaces of the `running` jobs are left alone.
+    pub fn check(&self, running: &HashSet<u64>) -> Result<()> {
+        if self.min_free_bytes == 0 {
+            return Ok(());
+        }
+        let free = match disk_free(&self.data_path) {
+            Some(free) => free,
+            None => return Ok(()),
+        };
+        if free >= self.min_free_bytes {
+            return Ok(());
+        }
+
+        warn!(
+            "Free disk of {} bytes is under the minimum of {} bytes, reclaiming space",
+            free,
+            self.min_free_bytes
+        );
+        self.remove_stale_workspaces(running);
+        if let Some(free) = disk_free(&self.data_path) {
+            if free >= self.min_free_bytes {
+                return Ok(());
+            }
+            if let Some(ref cache) = self.artifact_cache {
+                match cache.trim(self.min_free_bytes - free) {
+                    Ok(freed) => debug!("Evicted {} bytes of cached artifacts", freed),
+                    Err(err) => warn!("Unable to evict cached artifacts, err={}", err),
+                }
+            }
+        }
+
+        match disk_free(&self.data_path) {
+            Some(free) if free < self.min_free_bytes => {
+                Err(Error::LowDiskSpace(free, self.min_free_bytes))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Removes the workspaces of jobs which aren't running. A workspace is normally torn down
+    /// with its build, but not its output, and not at all if the worker stopped part way through.
+    fn remove_stale_workspaces(&self, running: &HashSet<u64>) {
+        let entries = match fs::read_dir(&self.data_path) {
+            Ok(entries) => entries,
+            Err(err) => {
+                warn!(
+                    "Unable to read data path {}, err={}",
+                    self.data_path.display(),
+                    err
+                );
+                return;
+            }
+        };
+        for path in entries.filter_map(|e| e.ok()).map(|e| e.path()) {
+            if !path.is_dir() {
+                continue;
+            }
+            match workspace_job_id(&path) {
+                Some(id) if !running.contains(&id) => {
+                    debug!("Removing stale workspace, {}", path.display());
+                    if let Err(err) = fs::remove_dir_all(&path) {
+                        warn!(
+                            "Unable to remove stale workspace {}, err={}",
+                            path.display(),
+                            err
+                        );
+                    }
+                }
+                _ => (),
+            }
+        }
+    }
+}
+
+/// Returns the number of bytes available to unprivileged users on the volume containing `path`
+#[cfg(unix)]
+pub fn disk_free(path: &Path) -> Option<u64> {
+    use std::ffi::CString;
+    use std::mem;
+    use std::os::unix::ffi::OsStrExt;
+    use libc;
+
+    let cpath = match CString::new(path.as_os_str().as_bytes()) {
+        Ok(p) => p,
+        Err(_) => return None,
+    };
+    unsafe {
+        let mut stat: libc::statvfs = mem::zeroed();
+        if libc::statvfs(cpath.as_ptr(), &mut stat) != 0 {
+            return None;
+        }
+        Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+    }
+}
+
+#[cfg(not(unix))]
+pub fn disk_free(_path: &Path) -> Option<u64> {
+    None
+}
+
+/// The id of the job a workspace belongs to, if `path` is one. Workspaces are named after their
+/// job's id.
+fn workspace_job_id(path: &Path) -> Option<u64> {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn removes_stale_workspaces() {
+        let dir = env::temp_dir().join("bldr-disk-mgr");
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        for name in &["1", "2", "artifact-cache"] {
+            fs::create_dir_all(dir.join(name).join("out")).unwrap();
+        }
+        let mut config = Config::default();
+        config.data_path = dir.clone();
+        let mgr = DiskMgr::new(&config);
+
+        let mut running = HashSet::new();
+        running.insert(2);
+        mgr.remove_stale_workspaces(&running);
+        assert!(!dir.join("1").exists());
+        assert!(dir.join("2").exists());
+        assert!(dir.join("artifact-cache").exists());
+    }
+}
@@ -21,11 +21,13 @@ use std::result;
 use std::sync::mpsc;
 
 use bldr_core;
+use depot_client;
 use hab_core;
 use git2;
 use github_api_client;
 use protobuf;
 use protocol;
+use regex;
 use retry;
 use url;
 use zmq;
@@ -38,28 +40,45 @@ pub type Result<T> = result::Result<T, Error>;
 pub enum Error {
     AirlockNetworking(PathBuf, io::Error),
     AirlockFailure(process::ExitStatus),
+    AirlockUnsupported,
+    ArtifactCache(PathBuf, io::Error),
+    ArtifactMetadata(PathBuf, io::Error),
     BuildEnvFile(PathBuf, io::Error),
     BuildFailure(i32),
+    BuildOutOfMemory(u64),
+    BuildTimeout(u32),
     BuilderCore(bldr_core::Error),
     CannotAddCreds,
+    Cgroup(PathBuf, io::Error),
     Chown(PathBuf, u32, u32, io::Error),
     ChownWait(io::Error),
+    ExportFailure(i32),
     Exporter(io::Error),
     Git(git2::Error),
     GithubAppAuthErr(github_api_client::HubError),
     HabitatCore(hab_core::Error),
+    InsecureBldrUrl(String),
     InvalidIntegrations(String),
+    LogRedactPattern(String, regex::Error),
+    LogSpool(PathBuf, io::Error),
+    LowDiskSpace(u64, u64),
     NoAuthTokenError,
     NoNetworkGatewayError,
     NoNetworkInterfaceError,
     NotHTTPSCloneUrl(url::Url),
+    OfflineBuildFailure(i32),
     NoStudioGroup,
     NoStudioUser,
     Protobuf(protobuf::ProtobufError),
     Protocol(protocol::ProtocolError),
+    ReproducibilityCheck(PathBuf, io::Error),
     Retry(retry::RetryError),
     StudioBuild(PathBuf, io::Error),
     StudioTeardown(PathBuf, io::Error),
+    UpdateCheck(depot_client::Error),
+    UpdateInstall(String, io::Error),
+    UpdateInstallFailure(String, process::ExitStatus),
+    UpdateRestart(PathBuf, io::Error),
     UrlParseError(url::ParseError),
     WorkspaceSetup(String, io::Error),
     WorkspaceTeardown(String, io::Error),
@@ -74,6 +93,7 @@ impl fmt::Display for Error {
             Error::AirlockFailure(ref e) => {
                 format!("Airlock networking exited with non-zero exit code, {}", e)
             }
+            Error::AirlockUnsupported => format!("Airlock is only available on Linux workers"),
             Error::AirlockNetworking(ref p, ref e) => {
                 format!(
                     "Error while running airlock networking command for {}, err={}",
@@ -81,6 +101,16 @@ impl fmt::Display for Error {
                     e
                 )
             }
+            Error::ArtifactCache(ref p, ref e) => {
+                format!("Unable to use artifact cache at {}, {}", p.display(), e)
+            }
+            Error::ArtifactMetadata(ref p, ref e) => {
+                format!(
+                    "Unable to read metadata of build artifact, {}, {}",
+                    p.display(),
+                    e
+                )
+            }
             Error::BuildEnvFile(ref p, ref e) => {
                 format!(
                     "Unable to read workspace build env file, {}, {}",
@@ -91,8 +121,15 @@ impl fmt::Display for Error {
             Error::BuildFailure(ref e) => {
                 format!("Build studio exited with non-zero exit code, {}", e)
             }
+            Error::BuildOutOfMemory(ref m) => {
+                format!("Build ran out of memory, exceeding its limit of {} MB", m)
+            }
+            Error::BuildTimeout(ref m) => format!("Build exceeded its timeout of {} minutes", m),
             Error::BuilderCore(ref e) => format!("{}", e),
             Error::CannotAddCreds => format!("Cannot add credentials to url"),
+            Error::Cgroup(ref p, ref e) => {
+                format!("Unable to set up build cgroup at {}, {}", p.display(), e)
+            }
             Error::Chown(ref p, ref u, ref g, ref e) => {
                 format!(
                     "Unable to recursively chown path, {} with '{}:{}', {}",
@@ -103,13 +140,32 @@ impl fmt::Display for Error {
                 )
             }
             Error::ChownWait(ref e) => format!("Unable to complete chown process, {}", e),
+            Error::ExportFailure(ref e) => {
+                format!("Docker export exited with a non-zero exit code, {}", e)
+            }
             Error::Exporter(ref e) => {
                 format!("Unable to spawn or pipe data from exporter proc, {}", e)
             }
             Error::Git(ref e) => format!("{}", e),
             Error::GithubAppAuthErr(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
+            Error::InsecureBldrUrl(ref u) => {
+                format!("Refusing to fetch secret keys over an unencrypted connection, {}", u)
+            }
             Error::InvalidIntegrations(ref s) => format!("Invalid integration: {}", s),
+            Error::LogRedactPattern(ref p, ref e) => {
+                format!("Invalid log redaction pattern {}, {}", p, e)
+            }
+            Error::LogSpool(ref p, ref e) => {
+                format!("Unable to spool log output at {}, {}", p.display(), e)
+            }
+            Error::LowDiskSpace(free, min) => {
+                format!(
+                    "Free disk of {} bytes is under the minimum of {} bytes",
+                    free,
+                    min
+                )
+            }
             Error::NoAuthTokenError => format!("No auth_token config specified"),
             Error::NoNetworkGatewayError => format!("No network_gateway config specified"),
             Error::NoNetworkInterfaceError => format!("No network_interface config specified"),
@@ -119,6 +175,13 @@ impl fmt::Display for Error {
                     e
                 )
             }
+            Error::OfflineBuildFailure(ref e) => {
+                format!(
+                    "Build studio exited with non-zero exit code after its network access was \
+                    dropped, {}",
+                    e
+                )
+            }
             Error::NoStudioGroup => {
                 format!("System is missing studio group, {}", studio::STUDIO_GROUP)
             }
@@ -127,6 +190,13 @@ impl fmt::Display for Error {
             }
             Error::Protobuf(ref e) => format!("{}", e),
             Error::Protocol(ref e) => format!("{}", e),
+            Error::ReproducibilityCheck(ref p, ref e) => {
+                format!(
+                    "Unable to compare the contents of builds at {}, {}",
+                    p.display(),
+                    e
+                )
+            }
             Error::Retry(ref e) => format!("{}", e),
             Error::StudioBuild(ref p, ref e) => {
                 format!(
@@ -142,6 +212,16 @@ impl fmt::Display for Error {
                     e
                 )
             }
+            Error::UpdateCheck(ref e) => format!("Unable to find the latest release, {}", e),
+            Error::UpdateInstall(ref i, ref e) => {
+                format!("Unable to run the installer of release {}, {}", i, e)
+            }
+            Error::UpdateInstallFailure(ref i, ref e) => {
+                format!("Installing release {} exited with non-zero exit code, {}", i, e)
+            }
+            Error::UpdateRestart(ref p, ref e) => {
+                format!("Unable to restart as {}, {}", p.display(), e)
+            }
             Error::UrlParseError(ref e) => format!("{}", e),
             Error::WorkspaceSetup(ref p, ref e) => {
                 format!("Error while setting up workspace at {}, err={}", p, e)
@@ -161,31 +241,52 @@ impl error::Error for Error {
     fn description(&self) -> &str {
         match *self {
             Error::AirlockFailure(_) => "Airlock networking exited with a non-zero exit code",
+            Error::AirlockUnsupported => "Airlock is only available on Linux workers",
             Error::AirlockNetworking(_, _) => "IO Error while running airlock networking command",
+            Error::ArtifactCache(_, _) => "Unable to use artifact cache",
+            Error::ArtifactMetadata(_, _) => "Unable to read metadata of build artifact",
             Error::BuildEnvFile(_, _) => "Unable to read workspace build env file",
             Error::BuildFailure(_) => "Build studio exited with a non-zero exit code",
+            Error::BuildOutOfMemory(_) => "Build ran out of memory",
+            Error::BuildTimeout(_) => "Build exceeded its timeout",
             Error::BuilderCore(ref err) => err.description(),
             Error::CannotAddCreds => "Cannot add credentials to url",
+            Error::Cgroup(_, _) => "Unable to set up build cgroup",
             Error::Chown(_, _, _, _) => "Unable to recursively chown path",
             Error::ChownWait(_) => "Unable to complete chown process",
+            Error::ExportFailure(_) => "Docker export exited with a non-zero exit code",
             Error::Exporter(_) => "IO Error while spawning or piping data from exporter proc",
             Error::Git(ref err) => err.description(),
             Error::GithubAppAuthErr(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
+            Error::InsecureBldrUrl(_) => {
+                "Refusing to fetch secret keys over an unencrypted connection"
+            }
             Error::InvalidIntegrations(_) => "Invalid integrations detected",
+            Error::LogRedactPattern(_, _) => "Invalid log redaction pattern",
+            Error::LogSpool(_, _) => "IO Error while spooling log output",
+            Error::LowDiskSpace(_, _) => "Free disk is under the minimum to run a build",
             Error::NoAuthTokenError => "No auth_token config specified",
             Error::NoNetworkGatewayError => "No network_gateway config specified",
             Error::NoNetworkInterfaceError => "No network_interface config specified",
             Error::NotHTTPSCloneUrl(_) => "Only HTTPS clone urls are supported",
+            Error::OfflineBuildFailure(_) => {
+                "Build studio exited with a non-zero exit code after its network access was dropped"
+            }
             Error::NoStudioGroup => "System missing group to run studio",
             Error::NoStudioUser => "System missing user to run studio",
             Error::Protobuf(ref err) => err.description(),
             Error::Protocol(ref err) => err.description(),
+            Error::ReproducibilityCheck(_, _) => "IO Error while comparing the contents of builds",
             Error::Retry(ref err) => err.description(),
             Error::StudioBuild(_, _) => "IO Error while running studio build",
             Error::StudioTeardown(_, _) => "IO Error while tearing down studio",
             Error::WorkspaceSetup(_, _) => "IO Error while creating workspace on disk",
             Error::WorkspaceTeardown(_, _) => "IO Error while destroying workspace on disk",
+            Error::UpdateCheck(ref err) => err.description(),
+            Error::UpdateInstall(_, _) => "IO Error while running the installer of a release",
+            Error::UpdateInstallFailure(_, _) => "Installing a release exited with non-zero code",
+            Error::UpdateRestart(_, _) => "IO Error while restarting as a new release",
             Error::Zmq(ref err) => err.description(),
             Error::UrlParseError(ref err) => err.description(),
             Error::Mpsc(ref err) => err.description(),
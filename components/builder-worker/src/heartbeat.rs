@@ -12,15 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::path::PathBuf;
 use std::sync::mpsc;
 use std::time::Duration;
 use std::thread::{self, JoinHandle};
 
+use hab_core::package::PackageTarget;
 use hab_net::socket::DEFAULT_CONTEXT;
-use protocol::{message, jobsrv as proto};
+use protocol::{message, jobsrv as proto, PROTOCOL_VERSION};
 use zmq;
 
 use config::Config;
+use disk::disk_free;
+use runner::artifact_cache;
 use error::Result;
 
 /// Polling timeout for HeartbeatMgr
@@ -47,6 +51,10 @@ fn worker_os() -> proto::Os {
     proto::Os::Darwin
 }
 
+fn worker_targets() -> Vec<String> {
+    vec![PackageTarget::default().to_string()]
+}
+
 #[derive(PartialEq)]
 enum PulseState {
     Pause,
@@ -76,12 +84,17 @@ pub struct HeartbeatCli {
 }
 
 impl HeartbeatCli {
-    /// Create a new HeartbeatMgr client
-    pub fn new(net_ident: String) -> Self {
+    /// Create a new HeartbeatMgr client for a worker which runs up to `capacity` builds at once
+    pub fn new(net_ident: String, labels: Vec<String>, capacity: u32) -> Self {
         let sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::REQ).unwrap();
         let mut state = proto::Heartbeat::new();
         state.set_endpoint(net_ident);
         state.set_os(worker_os());
+        state.set_target(PackageTarget::default().to_string());
+        state.set_targets(worker_targets().into());
+        state.set_labels(labels.into());
+        state.set_capacity(capacity);
+        state.set_protocol_version(PROTOCOL_VERSION);
         HeartbeatCli {
             msg: zmq::Message::new().unwrap(),
             sock: sock,
@@ -95,22 +108,26 @@ impl HeartbeatCli {
         Ok(())
     }
 
-    /// Set the `HeartbeatMgr` state to busy
-    pub fn set_busy(&mut self) -> Result<()> {
-        self.state.set_state(proto::WorkerState::Busy);
-        self.sock.send_str(PulseState::Pulse.as_ref(), zmq::SNDMORE)?;
-        self.sock.send(&message::encode(&self.state)?, 0)?;
-        self.sock.recv(&mut self.msg, 0)?;
-        Ok(())
+    /// Set the jobs the worker is running. The `HeartbeatMgr` state is busy once the worker is
+    /// running as many jobs as it can, and ready otherwise.
+    pub fn set_jobs(&mut self, job_ids: Vec<u64>) -> Result<()> {
+        self.state.set_jobs_in_flight(job_ids.len() as u32);
+        self.state.set_job_ids(job_ids);
+        self.send()
     }
 
-    /// Set the `HeartbeatMgr` state to ready
-    pub fn set_ready(&mut self) -> Result<()> {
-        self.state.set_state(proto::WorkerState::Ready);
-        self.sock.send_str(PulseState::Pulse.as_ref(), zmq::SNDMORE)?;
-        self.sock.send(&message::encode(&self.state)?, 0)?;
-        self.sock.recv(&mut self.msg, 0)?;
-        Ok(())
+    /// Stop taking jobs. The `HeartbeatMgr` state stays busy from now on, so that JobSrv doesn't
+    /// dispatch any more jobs to the worker.
+    pub fn drain(&mut self) -> Result<()> {
+        self.state.set_draining(true);
+        self.send()
+    }
+
+    /// Tell JobSrv that the worker is shutting down, so that it's forgotten rather than waited on
+    /// until it misses its heartbeats.
+    pub fn leave(&mut self) -> Result<()> {
+        self.state.set_leaving(true);
+        self.send()
     }
 
     /// Pause the heartbeats until next state is set
@@ -119,6 +136,21 @@ impl HeartbeatCli {
         self.sock.recv(&mut self.msg, 0)?;
         Ok(())
     }
+
+    fn send(&mut self) -> Result<()> {
+        let state = if self.state.get_draining() ||
+            self.state.get_jobs_in_flight() >= self.state.get_capacity()
+        {
+            proto::WorkerState::Busy
+        } else {
+            proto::WorkerState::Ready
+        };
+        self.state.set_state(state);
+        self.sock.send_str(PulseState::Pulse.as_ref(), zmq::SNDMORE)?;
+        self.sock.send(&message::encode(&self.state)?, 0)?;
+        self.sock.recv(&mut self.msg, 0)?;
+        Ok(())
+    }
 }
 
 /// Maintains and broadcasts health and state of the Worker server to consumers
@@ -130,13 +162,19 @@ pub struct HeartbeatMgr {
     heartbeat: proto::Heartbeat,
     msg: zmq::Message,
     state: PulseState,
+    data_path: PathBuf,
 }
 
 impl HeartbeatMgr {
     /// Start the HeartbeatMgr
     pub fn start(config: &Config, net_ident: String) -> Result<JoinHandle<()>> {
         let (tx, rx) = mpsc::sync_channel(0);
-        let mut heartbeat = Self::new(net_ident)?;
+        let mut heartbeat = Self::new(
+            net_ident,
+            config.data_path.clone(),
+            config.labels.clone(),
+            config.capacity,
+        )?;
         let jobsrv_addrs = config.jobsrv_addrs();
         let handle = thread::Builder::new()
             .name("heartbeat".to_string())
@@ -148,7 +186,12 @@ impl HeartbeatMgr {
         }
     }
 
-    fn new(net_ident: String) -> Result<Self> {
+    fn new(
+        net_ident: String,
+        data_path: PathBuf,
+        labels: Vec<String>,
+        capacity: u32,
+    ) -> Result<Self> {
         let pub_sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::PUB)?;
         let cli_sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::REP)?;
         pub_sock.set_immediate(true)?;
@@ -157,6 +200,11 @@ impl HeartbeatMgr {
         let mut heartbeat = proto::Heartbeat::new();
         heartbeat.set_endpoint(net_ident);
         heartbeat.set_os(worker_os());
+        heartbeat.set_target(PackageTarget::default().to_string());
+        heartbeat.set_targets(worker_targets().into());
+        heartbeat.set_labels(labels.into());
+        heartbeat.set_capacity(capacity);
+        heartbeat.set_protocol_version(PROTOCOL_VERSION);
         heartbeat.set_state(proto::WorkerState::Ready);
         Ok(HeartbeatMgr {
             state: PulseState::default(),
@@ -164,6 +212,7 @@ impl HeartbeatMgr {
             cli_sock: cli_sock,
             heartbeat: heartbeat,
             msg: zmq::Message::new().unwrap(),
+            data_path: data_path,
         })
     }
 
@@ -212,6 +261,13 @@ impl HeartbeatMgr {
 
     // Broadcast to subscribers the HeartbeatMgr health and state
     fn pulse(&mut self) -> Result<()> {
+        match disk_free(&self.data_path) {
+            Some(bytes) => self.heartbeat.set_disk_free(bytes),
+            None => self.heartbeat.clear_disk_free(),
+        }
+        let (hits, misses) = artifact_cache::lookups();
+        self.heartbeat.set_artifact_cache_hits(hits);
+        self.heartbeat.set_artifact_cache_misses(misses);
         debug!("heartbeat pulsed: {:?}", self.heartbeat);
         self.pub_sock.send(&message::encode(&self.heartbeat)?, 0)?;
         Ok(())
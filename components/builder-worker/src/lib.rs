@@ -28,9 +28,11 @@ extern crate habitat_depot_client as depot_client;
 extern crate habitat_net as hab_net;
 #[macro_use]
 extern crate lazy_static;
+extern crate libc;
 #[macro_use]
 extern crate log;
 extern crate protobuf;
+extern crate regex;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
@@ -44,11 +46,13 @@ extern crate retry;
 extern crate hyper;
 
 pub mod config;
+pub mod disk;
 pub mod error;
 pub mod heartbeat;
 pub mod log_forwarder;
 pub mod runner;
 pub mod server;
+pub mod updater;
 pub mod vcs;
 
 pub use self::config::Config;
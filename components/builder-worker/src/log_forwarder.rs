@@ -12,18 +12,46 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+//! Forwards the log output of builds to the job server.
+//!
+//! Lines are coalesced into one chunk per job until a batch is full or has waited long enough, so
+//! that chatty builds don't flood the job server with tiny messages. Messages which the job server
+//! can't take right away are spooled to disk and forwarded in order once it catches up, so that
+//! log volume never stalls a build.
+
+use std::collections::HashMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
 use bldr_core::logger::Logger;
 use config::Config;
 use error::{Error, Result};
 use hab_net::socket::DEFAULT_CONTEXT;
-use std::sync::mpsc;
-use std::thread::{self, JoinHandle};
-use std::time::Duration;
+use protobuf::{parse_from_bytes, Message};
+use protocol::jobsrv::{JobLogChunk, JobLogComplete};
 use zmq;
 
 /// In-memory zmq address for LogForwarder
 pub const INPROC_ADDR: &'static str = "inproc://logger";
 
+/// ZMQ protocol frame to indicate a log line is being sent
+const LOG_LINE: &'static str = "L";
+/// ZMQ protocol frame to indicate a log has finished
+const LOG_COMPLETE: &'static str = "C";
+/// Directory under the data path holding the spool of messages waiting to be forwarded
+const SPOOL_DIR: &'static str = "log-spool";
+
+/// Lines of a job's log waiting to be forwarded as one chunk
+struct Batch {
+    chunk: JobLogChunk,
+    lines: usize,
+    started: Instant,
+}
+
 pub struct LogForwarder {
     /// The socket on which log data is received from workers.
     pub intake_sock: zmq::Socket,
@@ -32,6 +60,14 @@ pub struct LogForwarder {
     pub output_sock: zmq::Socket,
     /// Log file for debugging this process.
     logger: Logger,
+    /// Lines waiting to be forwarded, by job
+    batches: HashMap<u64, Batch>,
+    /// Messages the job server couldn't take when they were forwarded
+    spool: Spool,
+    /// Most lines coalesced into one chunk
+    batch_lines: usize,
+    /// Longest a line waits to be forwarded while its batch fills up
+    batch_wait: Duration,
 }
 
 impl LogForwarder {
@@ -45,10 +81,16 @@ impl LogForwarder {
         let mut logger = Logger::init(&config.log_path, "log_forwarder.log");
         logger.log_ident("log_forwarder");
 
+        let spool_path = config.data_path.join(SPOOL_DIR).join("log_forwarder.spool");
+
         Ok(LogForwarder {
             intake_sock: intake_sock,
             output_sock: output_sock,
             logger: logger,
+            batches: HashMap::new(),
+            spool: Spool::create(spool_path)?,
+            batch_lines: config.log_batch_lines,
+            batch_wait: Duration::from_millis(config.log_batch_ms),
         })
     }
 
@@ -89,18 +131,332 @@ impl LogForwarder {
         thread::sleep(Duration::from_millis(100));
 
         self.logger.log(
-            "Starting proxy between log_pipe and jobsrv",
+            "Starting to forward from log_pipe to jobsrv",
         );
 
         // If we ever have multiple JobServers these need to be sent to, then we might need some
         // additional logic.
-        if let Err(e) = zmq::proxy(&mut self.intake_sock, &mut self.output_sock) {
+        loop {
+            let timeout = self.poll_timeout();
+            let (intake_ready, output_ready) = {
+                let mut items = [
+                    self.intake_sock.as_poll_item(zmq::POLLIN),
+                    self.output_sock.as_poll_item(zmq::POLLOUT),
+                ];
+                // Only wait for the job server to take messages while some are spooled
+                let polled = if self.spool.is_empty() { 1 } else { 2 };
+                zmq::poll(&mut items[..polled], timeout)?;
+                (
+                    items[0].get_revents() & zmq::POLLIN > 0,
+                    polled > 1 && items[1].get_revents() & zmq::POLLOUT > 0,
+                )
+            };
+            if output_ready {
+                self.drain_spool()?;
+            }
+            if intake_ready {
+                self.receive()?;
+            }
+            self.flush_expired()?;
+        }
+    }
+
+    /// Milliseconds to wait for the next message before the oldest batch is due, or -1 to wait
+    /// indefinitely.
+    fn poll_timeout(&self) -> i64 {
+        let now = Instant::now();
+        self.batches
+            .values()
+            .map(|batch| {
+                let due = batch.started + self.batch_wait;
+                if due > now {
+                    let wait = due - now;
+                    wait.as_secs() as i64 * 1000 + wait.subsec_nanos() as i64 / 1_000_000
+                } else {
+                    0
+                }
+            })
+            .min()
+            .unwrap_or(-1)
+    }
+
+    fn receive(&mut self) -> Result<()> {
+        let code = self.intake_sock.recv_bytes(0)?;
+        let body = self.intake_sock.recv_bytes(0)?;
+        if code == LOG_LINE.as_bytes() {
+            match parse_from_bytes::<JobLogChunk>(&body) {
+                Ok(chunk) => self.add_line(chunk)?,
+                Err(e) => warn!("Error parsing JobLogChunk: {:?}", e),
+            }
+        } else if code == LOG_COMPLETE.as_bytes() {
+            match parse_from_bytes::<JobLogComplete>(&body) {
+                Ok(complete) => {
+                    // The log's remaining lines must reach the job server before it's completed
+                    self.flush(complete.get_job_id())?;
+                    self.forward(LOG_COMPLETE, body)?;
+                }
+                Err(e) => warn!("Error parsing JobLogComplete: {:?}", e),
+            }
+        } else {
+            warn!("Unrecognized log protocol code: {:?}", code);
+        }
+        Ok(())
+    }
+
+    /// Adds a line to its job's batch, forwarding the batch once it's full.
+    fn add_line(&mut self, chunk: JobLogChunk) -> Result<()> {
+        let job_id = chunk.get_job_id();
+        let full = {
+            let batch = self.batches.entry(job_id).or_insert_with(|| {
+                let mut batch_chunk = JobLogChunk::new();
+                batch_chunk.set_job_id(job_id);
+                // Coalesced chunks are ordered by their first line
+                batch_chunk.set_seq(chunk.get_seq());
+                Batch {
+                    chunk: batch_chunk,
+                    lines: 0,
+                    started: Instant::now(),
+                }
+            });
+            batch.chunk.mut_content().push_str(chunk.get_content());
+            batch.lines += 1;
+            batch.lines >= self.batch_lines
+        };
+        if full {
+            self.flush(job_id)?;
+        }
+        Ok(())
+    }
+
+    /// Forwards the batches which have waited long enough.
+    fn flush_expired(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let expired: Vec<u64> = self.batches
+            .iter()
+            .filter(|&(_, batch)| now.duration_since(batch.started) >= self.batch_wait)
+            .map(|(job_id, _)| *job_id)
+            .collect();
+        for job_id in expired {
+            self.flush(job_id)?;
+        }
+        Ok(())
+    }
+
+    /// Forwards the batch of a job's lines, if it has one.
+    fn flush(&mut self, job_id: u64) -> Result<()> {
+        match self.batches.remove(&job_id) {
+            Some(batch) => self.forward(LOG_LINE, batch.chunk.write_to_bytes()?),
+            None => Ok(()),
+        }
+    }
+
+    /// Sends a message to the job server, or spools it if the job server can't take it right
+    /// away. Messages are spooled behind any which already are so that they stay in order.
+    fn forward(&mut self, code: &str, body: Vec<u8>) -> Result<()> {
+        if self.spool.is_empty() && self.try_send(code.as_bytes(), &body)? {
+            return Ok(());
+        }
+        if let Err(e) = self.spool.push(code.as_bytes(), &body) {
+            // Losing log output beats blocking the builds producing it
+            warn!("Unable to spool log message, dropping it, err={}", e);
             self.logger.log(
-                format!("ZMQ proxy returned an error: {:?}", e)
+                format!("Unable to spool log message, dropping it, err={}", e)
                     .as_ref(),
             );
-            return Err(Error::Zmq(e));
         }
         Ok(())
     }
+
+    /// Sends spooled messages until the job server can't take any more, or none are left.
+    fn drain_spool(&mut self) -> Result<()> {
+        loop {
+            let (code, body) = match self.spool.peek() {
+                Ok(Some(message)) => message,
+                Ok(None) => return Ok(()),
+                Err(e) => {
+                    warn!("Unable to read log spool, discarding it, err={}", e);
+                    return self.spool.clear();
+                }
+            };
+            if !self.try_send(&code, &body)? {
+                return Ok(());
+            }
+            self.spool.pop()?;
+        }
+    }
+
+    /// Returns whether the job server took the message.
+    fn try_send(&mut self, code: &[u8], body: &[u8]) -> Result<bool> {
+        match self.output_sock.send(code, zmq::SNDMORE | zmq::DONTWAIT) {
+            Ok(()) => (),
+            Err(zmq::Error::EAGAIN) => return Ok(false),
+            Err(e) => return Err(Error::Zmq(e)),
+        }
+        // The remaining frames of a message are always taken once its first frame was
+        self.output_sock.send(body, 0)?;
+        Ok(true)
+    }
+}
+
+/// Messages waiting to be forwarded, kept on disk in the order they were received. Each record is
+/// a one byte protocol code, the length of the message as a big endian `u32` and the message.
+struct Spool {
+    path: PathBuf,
+    file: File,
+    /// Offset of the oldest record
+    head: u64,
+    /// Offset past the newest record
+    tail: u64,
+}
+
+impl Spool {
+    /// Creates an empty spool at `path`, discarding one left behind by a previous run since the
+    /// jobs it belonged to are long gone.
+    fn create<T>(path: T) -> Result<Self>
+    where
+        T: Into<PathBuf>,
+    {
+        let path = path.into();
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(
+                |e| Error::LogSpool(path.clone(), e),
+            )?;
+        }
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .map_err(|e| Error::LogSpool(path.clone(), e))?;
+        Ok(Spool {
+            path: path,
+            file: file,
+            head: 0,
+            tail: 0,
+        })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.head == self.tail
+    }
+
+    fn push(&mut self, code: &[u8], body: &[u8]) -> io::Result<()> {
+        let mut record = Vec::with_capacity(5 + body.len());
+        record.push(code[0]);
+        record.extend_from_slice(&be_bytes(body.len() as u32));
+        record.extend_from_slice(body);
+        self.file.seek(SeekFrom::Start(self.tail))?;
+        self.file.write_all(&record)?;
+        self.tail += record.len() as u64;
+        Ok(())
+    }
+
+    /// Returns the oldest message without removing it.
+    fn peek(&mut self) -> io::Result<Option<(Vec<u8>, Vec<u8>)>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+        self.file.seek(SeekFrom::Start(self.head))?;
+        let mut prefix = [0; 5];
+        self.file.read_exact(&mut prefix)?;
+        let len = from_be_bytes(&prefix[1..]) as usize;
+        let mut body = vec![0; len];
+        self.file.read_exact(&mut body)?;
+        Ok(Some((vec![prefix[0]], body)))
+    }
+
+    /// Removes the oldest message, reclaiming the spool's space once it's empty.
+    fn pop(&mut self) -> Result<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        let mut prefix = [0; 5];
+        let res = self.file.seek(SeekFrom::Start(self.head)).and_then(|_| {
+            self.file.read_exact(&mut prefix)
+        });
+        if let Err(e) = res {
+            return Err(Error::LogSpool(self.path.clone(), e));
+        }
+        self.head += 5 + from_be_bytes(&prefix[1..]) as u64;
+        if self.is_empty() {
+            self.clear()?;
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self) -> Result<()> {
+        self.file.set_len(0).map_err(
+            |e| Error::LogSpool(self.path.clone(), e),
+        )?;
+        self.head = 0;
+        self.tail = 0;
+        Ok(())
+    }
+}
+
+fn be_bytes(n: u32) -> [u8; 4] {
+    [(n >> 24) as u8, (n >> 16) as u8, (n >> 8) as u8, n as u8]
+}
+
+fn from_be_bytes(bytes: &[u8]) -> u32 {
+    bytes.iter().fold(0, |n, b| (n << 8) | *b as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn spool(name: &str) -> Spool {
+        Spool::create(env::temp_dir().join(format!("bldr-log-spool-{}", name))).unwrap()
+    }
+
+    #[test]
+    fn spooled_messages_stay_in_order() {
+        let mut spool = spool("order");
+        assert!(spool.is_empty());
+        spool.push(LOG_LINE.as_bytes(), b"first").unwrap();
+        spool.push(LOG_COMPLETE.as_bytes(), b"second").unwrap();
+
+        assert_eq!(
+            spool.peek().unwrap(),
+            Some((LOG_LINE.as_bytes().to_vec(), b"first".to_vec()))
+        );
+        // Peeking doesn't remove the message
+        assert_eq!(
+            spool.peek().unwrap(),
+            Some((LOG_LINE.as_bytes().to_vec(), b"first".to_vec()))
+        );
+        spool.pop().unwrap();
+        assert_eq!(
+            spool.peek().unwrap(),
+            Some((LOG_COMPLETE.as_bytes().to_vec(), b"second".to_vec()))
+        );
+        spool.pop().unwrap();
+        assert!(spool.is_empty());
+        assert_eq!(spool.peek().unwrap(), None);
+    }
+
+    #[test]
+    fn empty_spool_is_reclaimed() {
+        let mut spool = spool("reclaim");
+        spool.push(LOG_LINE.as_bytes(), &vec![1; 1024]).unwrap();
+        spool.pop().unwrap();
+        assert_eq!(fs::metadata(&spool.path).unwrap().len(), 0);
+
+        spool.push(LOG_LINE.as_bytes(), b"again").unwrap();
+        assert_eq!(
+            spool.peek().unwrap(),
+            Some((LOG_LINE.as_bytes().to_vec(), b"again".to_vec()))
+        );
+    }
+
+    #[test]
+    fn length_prefix_round_trips() {
+        for n in &[0, 1, 255, 256, 65_536, u32::max_value()] {
+            assert_eq!(from_be_bytes(&be_bytes(*n)), *n);
+        }
+    }
 }
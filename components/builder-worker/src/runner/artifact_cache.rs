@@ -0,0 +1,357 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A cache of dependency artifacts shared by the builds run on a worker.
+//!
+//! Studios are torn down after every job, along with the artifacts they downloaded. Before a build
+//! the cached artifacts are linked into the studio's artifact cache so that they aren't downloaded
+//! again, and afterwards the artifacts the studio downloaded are added to the cache.
+//!
+//! Entries are stored as `<cache>/<checksum>/<artifact file name>`, so they're keyed by the
+//! artifact's ident and target, which make up its file name, together with its checksum. Studios
+//! get hard links to the entries, which means that reading an artifact from a studio updates the
+//! access time of the entry. Once the cache is over its size, the entries which were accessed the
+//! longest ago are evicted first.
+
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+use std::time::UNIX_EPOCH;
+
+use hab_core::package::PackageArchive;
+
+use config::Config;
+use error::{Error, Result};
+
+/// Extension of artifact file names
+const ARTIFACT_EXT: &'static str = "hart";
+/// Directory under the data path of the cache
+const ARTIFACT_CACHE_DIR: &'static str = "artifact-cache";
+
+/// Dependency artifacts of builds which were already cached, since the worker started
+static HITS: AtomicUsize = ATOMIC_USIZE_INIT;
+/// Dependency artifacts of builds which had to be downloaded, since the worker started
+static MISSES: AtomicUsize = ATOMIC_USIZE_INIT;
+
+lazy_static! {
+    /// Held while the cache is read or changed, since builds running at once share it
+    static ref CACHE_LOCK: Mutex<()> = Mutex::new(());
+}
+
+pub struct ArtifactCache {
+    path: PathBuf,
+    max_bytes: u64,
+}
+
+impl ArtifactCache {
+    /// Creates a cache at `path` which is kept under `max_size_mb` megabytes.
+    pub fn new<T>(path: T, max_size_mb: u64) -> Self
+    where
+        T: Into<PathBuf>,
+    {
+        ArtifactCache {
+            path: path.into(),
+            max_bytes: max_size_mb * 1024 * 1024,
+        }
+    }
+
+    /// Returns the cache of a worker, or `None` if it's disabled.
+    pub fn from_config(config: &Config) -> Option<Self> {
+        match config.artifact_cache_size_mb {
+            0 => None,
+            size => Some(ArtifactCache::new(
+                config.data_path.join(ARTIFACT_CACHE_DIR),
+                size,
+            )),
+        }
+    }
+
+    /// Whether an artifact with the file name `file_name` is cached.
+    pub fn contains(&self, file_name: &str) -> Result<bool> {
+        let _lock = CACHE_LOCK.lock().unwrap();
+        Ok(self.entries()?.iter().any(|entry| {
+            entry.file_name().map_or(false, |name| name == file_name)
+        }))
+    }
+
+    /// Links every cached artifact into the artifact cache directory of a studio. Returns the
+    /// number of artifacts linked.
+    pub fn populate(&self, dst: &Path) -> Result<usize> {
+        let _lock = CACHE_LOCK.lock().unwrap();
+        fs::create_dir_all(dst).map_err(
+            |e| Error::ArtifactCache(dst.to_path_buf(), e),
+        )?;
+        let mut linked = HashSet::new();
+        for entry in self.entries()? {
+            let file_name = entry.file_name().unwrap().to_os_string(); // Unwrap OK
+            if linked.contains(&file_name) {
+                continue;
+            }
+            let target = dst.join(&file_name);
+            if target.exists() {
+                continue;
+            }
+            link_or_copy(&entry, &target)?;
+            linked.insert(file_name);
+        }
+        Ok(linked.len())
+    }
+
+    /// Adds the artifacts in the artifact cache directory of a studio which aren't cached yet,
+    /// then evicts the least recently used entries until the cache fits in its size. Returns the
+    /// number of artifacts added.
+    pub fn store(&self, src: &Path) -> Result<usize> {
+        let _lock = CACHE_LOCK.lock().unwrap();
+        let mut cached = HashSet::new();
+        for entry in self.entries()? {
+            let metadata = fs::metadata(&entry).map_err(
+                |e| Error::ArtifactCache(entry.clone(), e),
+            )?;
+            if let Some(id) = file_id(&metadata) {
+                cached.insert(id);
+            }
+        }
+
+        let mut stored = 0;
+        for artifact in artifacts_in(src)? {
+            let metadata = fs::metadata(&artifact).map_err(
+                |e| Error::ArtifactCache(artifact.clone(), e),
+            )?;
+            // Artifacts linked in from the cache are the entries themselves
+            if file_id(&metadata).map_or(false, |id| cached.contains(&id)) {
+                continue;
+            }
+            let checksum = PackageArchive::new(artifact.clone()).checksum()?;
+            let entry_dir = self.path.join(checksum);
+            let entry = entry_dir.join(artifact.file_name().unwrap()); // Unwrap OK
+            if entry.exists() {
+                continue;
+            }
+            fs::create_dir_all(&entry_dir).map_err(
+                |e| Error::ArtifactCache(entry_dir.clone(), e),
+            )?;
+            link_or_copy(&artifact, &entry)?;
+            stored += 1;
+        }
+
+        self.evict(self.max_bytes)?;
+        Ok(stored)
+    }
+
+    /// Evicts the least recently used entries until at least `bytes` bytes are freed, or the
+    /// cache is empty. Returns the number of bytes freed.
+    pub fn trim(&self, bytes: u64) -> Result<u64> {
+        let _lock = CACHE_LOCK.lock().unwrap();
+        let mut total = 0;
+        for entry in self.entries()? {
+            let metadata = fs::metadata(&entry).map_err(
+                |e| Error::ArtifactCache(entry.clone(), e),
+            )?;
+            total += metadata.len();
+        }
+        self.evict(total.saturating_sub(bytes))
+    }
+
+    /// Removes the least recently used entries until the cache is no larger than `max_bytes`.
+    /// Returns the number of bytes freed.
+    fn evict(&self, max_bytes: u64) -> Result<u64> {
+        let mut entries = Vec::new();
+        let mut total = 0;
+        for entry in self.entries()? {
+            let metadata = fs::metadata(&entry).map_err(
+                |e| Error::ArtifactCache(entry.clone(), e),
+            )?;
+            total += metadata.len();
+            let accessed = metadata.accessed().unwrap_or(UNIX_EPOCH);
+            entries.push((accessed, metadata.len(), entry));
+        }
+        entries.sort();
+
+        let mut freed = 0;
+        for (_, size, entry) in entries {
+            if total <= max_bytes {
+                break;
+            }
+            debug!("Evicting cached artifact, {}", entry.display());
+            let entry_dir = entry.parent().unwrap(); // Unwrap OK
+            fs::remove_dir_all(entry_dir).map_err(|e| {
+                Error::ArtifactCache(entry_dir.to_path_buf(), e)
+            })?;
+            total -= size;
+            freed += size;
+        }
+        Ok(freed)
+    }
+
+    /// Paths of the artifacts of every entry in the cache
+    fn entries(&self) -> Result<Vec<PathBuf>> {
+        let mut entries = Vec::new();
+        if !self.path.exists() {
+            return Ok(entries);
+        }
+        for entry_dir in read_dir(&self.path)? {
+            if entry_dir.is_dir() {
+                entries.extend(artifacts_in(&entry_dir)?);
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// Records the dependency artifacts of a build which were found in the cache and those which
+/// weren't.
+pub fn record_lookups(hits: usize, misses: usize) {
+    HITS.fetch_add(hits, Ordering::Relaxed);
+    MISSES.fetch_add(misses, Ordering::Relaxed);
+}
+
+/// Dependency artifacts found in the cache and missing from it since the worker started
+pub fn lookups() -> (u64, u64) {
+    (
+        HITS.load(Ordering::Relaxed) as u64,
+        MISSES.load(Ordering::Relaxed) as u64,
+    )
+}
+
+/// Identifies a file by its device and inode, telling hard links to the same file apart from
+/// copies. Without one, artifacts linked in from the cache are checksummed to find their entry.
+#[cfg(unix)]
+fn file_id(metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_id(_metadata: &fs::Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Paths of the artifacts in a directory
+fn artifacts_in(dir: &Path) -> Result<Vec<PathBuf>> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+    Ok(
+        read_dir(dir)?
+            .into_iter()
+            .filter(|p| {
+                p.is_file() && p.extension().map_or(false, |ext| ext == ARTIFACT_EXT)
+            })
+            .collect(),
+    )
+}
+
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    let entries = fs::read_dir(dir).map_err(
+        |e| Error::ArtifactCache(dir.to_path_buf(), e),
+    )?;
+    for entry in entries {
+        let entry = entry.map_err(|e| Error::ArtifactCache(dir.to_path_buf(), e))?;
+        paths.push(entry.path());
+    }
+    Ok(paths)
+}
+
+/// Hard links `src` to `dst`, falling back to a copy if they're on different filesystems.
+fn link_or_copy(src: &Path, dst: &Path) -> Result<()> {
+    match fs::hard_link(src, dst) {
+        Ok(()) => Ok(()),
+        Err(_) => {
+            fs::copy(src, dst).map(|_| ()).map_err(
+                |e: io::Error| Error::ArtifactCache(dst.to_path_buf(), e),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::io::Write;
+
+    use super::*;
+
+    const CORE_GLIBC: &'static str = "core-glibc-2.22-20170513201042-x86_64-linux.hart";
+    const CORE_ZLIB: &'static str = "core-zlib-1.2.8-20170513201911-x86_64-linux.hart";
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("bldr-artifact-cache-{}", name));
+        if dir.exists() {
+            fs::remove_dir_all(&dir).unwrap();
+        }
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_artifact(dir: &Path, file_name: &str, size: usize) {
+        fs::create_dir_all(dir).unwrap();
+        let mut file = fs::File::create(dir.join(file_name)).unwrap();
+        file.write_all(&vec![file_name.len() as u8; size]).unwrap();
+    }
+
+    #[test]
+    fn stores_and_populates_artifacts() {
+        let dir = test_dir("populate");
+        let cache = ArtifactCache::new(dir.join("cache"), 10);
+
+        let first = dir.join("first");
+        write_artifact(&first, CORE_GLIBC, 1024);
+        write_artifact(&first, CORE_ZLIB, 1024);
+        write_artifact(&first, "README", 1024);
+        assert_eq!(cache.store(&first).unwrap(), 2);
+        // Storing the same artifacts again doesn't add entries
+        assert_eq!(cache.store(&first).unwrap(), 0);
+
+        assert!(cache.contains(CORE_GLIBC).unwrap());
+        assert!(!cache.contains("README").unwrap());
+
+        let second = dir.join("second");
+        assert_eq!(cache.populate(&second).unwrap(), 2);
+        assert!(second.join(CORE_GLIBC).is_file());
+        assert!(second.join(CORE_ZLIB).is_file());
+        // Linked artifacts are already cached
+        assert_eq!(cache.store(&second).unwrap(), 0);
+    }
+
+    #[test]
+    fn evicts_artifacts_over_size() {
+        let dir = test_dir("evict");
+        let cache = ArtifactCache::new(dir.join("cache"), 1);
+
+        let studio = dir.join("studio");
+        write_artifact(&studio, CORE_GLIBC, 768 * 1024);
+        write_artifact(&studio, CORE_ZLIB, 768 * 1024);
+        assert_eq!(cache.store(&studio).unwrap(), 2);
+        assert_eq!(cache.entries().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn trims_artifacts() {
+        let dir = test_dir("trim");
+        let cache = ArtifactCache::new(dir.join("cache"), 10);
+
+        let studio = dir.join("studio");
+        write_artifact(&studio, CORE_GLIBC, 1024);
+        write_artifact(&studio, CORE_ZLIB, 1024);
+        assert_eq!(cache.store(&studio).unwrap(), 2);
+        assert_eq!(cache.trim(512).unwrap(), 1024);
+        assert_eq!(cache.entries().unwrap().len(), 1);
+        assert_eq!(cache.trim(4096).unwrap(), 1024);
+        assert!(cache.entries().unwrap().is_empty());
+    }
+}
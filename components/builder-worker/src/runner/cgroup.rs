@@ -0,0 +1,283 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Limits the memory and CPU time of builds with control groups.
+//!
+//! A build given limits by its job runs in a control group of its own, named after the job. The
+//! Studio joins the group right before it's exec'd, so everything the build starts is accounted to
+//! it. Both the unified hierarchy of cgroup v2 and the per-controller hierarchies of cgroup v1 are
+//! supported. Once the build exits the group is checked for processes killed for going over the
+//! memory limit, and it's removed when dropped.
+
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use bldr_core::job::Job;
+
+use error::{Error, Result};
+
+/// Group under the cgroup root holding the groups of every build
+const CGROUP_PARENT: &'static str = "bldr-worker";
+/// Period, in microseconds, the CPU quota of a build is measured over
+const CPU_PERIOD_US: u64 = 100_000;
+
+/// The resources a job's build may use
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Limits {
+    pub memory_mb: Option<u64>,
+    pub cpu_millicores: Option<u32>,
+}
+
+impl Limits {
+    pub fn from_job(job: &Job) -> Self {
+        Limits {
+            memory_mb: match job.get_memory_limit_mb() {
+                0 => None,
+                mb => Some(mb),
+            },
+            cpu_millicores: match job.get_cpu_limit_millicores() {
+                0 => None,
+                millicores => Some(millicores),
+            },
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.memory_mb.is_none() && self.cpu_millicores.is_none()
+    }
+
+    /// The limit in the form taken by `docker run --cpus`
+    pub fn cpus(&self) -> Option<String> {
+        self.cpu_millicores.map(
+            |millicores| format!("{}.{:03}", millicores / 1000, millicores % 1000),
+        )
+    }
+}
+
+pub struct Cgroup {
+    unified: bool,
+    memory: Option<PathBuf>,
+    cpu: Option<PathBuf>,
+}
+
+impl Cgroup {
+    /// Creates the control group `name` under the cgroup filesystem mounted at `root`, limited to
+    /// `limits`.
+    pub fn create(root: &Path, name: &str, limits: &Limits) -> Result<Self> {
+        let cgroup = if root.join("cgroup.controllers").is_file() {
+            let dir = root.join(CGROUP_PARENT).join(name);
+            create_dir(&dir)?;
+            // Controllers are only available to a group once its parents enable them for it
+            for parent in &[root.to_path_buf(), root.join(CGROUP_PARENT)] {
+                write(&parent.join("cgroup.subtree_control"), "+memory +cpu")?;
+            }
+            Cgroup {
+                unified: true,
+                memory: limits.memory_mb.map(|_| dir.clone()),
+                cpu: limits.cpu_millicores.map(|_| dir.clone()),
+            }
+        } else {
+            let memory = match limits.memory_mb {
+                Some(_) => Some(root.join("memory").join(CGROUP_PARENT).join(name)),
+                None => None,
+            };
+            let cpu = match limits.cpu_millicores {
+                Some(_) => Some(root.join("cpu").join(CGROUP_PARENT).join(name)),
+                None => None,
+            };
+            for dir in memory.iter().chain(cpu.iter()) {
+                create_dir(dir)?;
+            }
+            Cgroup {
+                unified: false,
+                memory: memory,
+                cpu: cpu,
+            }
+        };
+        cgroup.limit(limits)?;
+        Ok(cgroup)
+    }
+
+    fn limit(&self, limits: &Limits) -> Result<()> {
+        if let (Some(dir), Some(mb)) = (self.memory.as_ref(), limits.memory_mb) {
+            let bytes = (mb * 1024 * 1024).to_string();
+            if self.unified {
+                write(&dir.join("memory.max"), &bytes)?;
+                // Builds aren't allowed to get around the limit by swapping
+                write(&dir.join("memory.swap.max"), "0").ok();
+            } else {
+                write(&dir.join("memory.limit_in_bytes"), &bytes)?;
+            }
+        }
+        if let (Some(dir), Some(millicores)) = (self.cpu.as_ref(), limits.cpu_millicores) {
+            let quota = cpu_quota_us(millicores);
+            if self.unified {
+                write(
+                    &dir.join("cpu.max"),
+                    &format!("{} {}", quota, CPU_PERIOD_US),
+                )?;
+            } else {
+                write(&dir.join("cpu.cfs_period_us"), &CPU_PERIOD_US.to_string())?;
+                write(&dir.join("cpu.cfs_quota_us"), &quota.to_string())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Makes the command's process join the group before it's exec'd, so that nothing it starts
+    /// runs outside of it. Hooks registered on the command before this one run first.
+    #[cfg(unix)]
+    pub fn join(&self, cmd: &mut Command) {
+        use std::ffi::CString;
+        use std::io;
+        use std::os::unix::ffi::OsStrExt;
+        use std::os::unix::process::CommandExt;
+        use libc;
+
+        // Allocated up front, as only async-signal-safe calls may be made between fork and exec
+        let procs: Vec<CString> = self.dirs()
+            .iter()
+            .map(|dir| {
+                CString::new(dir.join("cgroup.procs").as_os_str().as_bytes()).unwrap()
+            })
+            .collect();
+        cmd.before_exec(move || {
+            for path in procs.iter() {
+                unsafe {
+                    let fd = libc::open(path.as_ptr(), libc::O_WRONLY);
+                    if fd < 0 {
+                        return Err(io::Error::last_os_error());
+                    }
+                    let written = libc::write(fd, b"0".as_ptr() as *const libc::c_void, 1);
+                    libc::close(fd);
+                    if written != 1 {
+                        return Err(io::Error::last_os_error());
+                    }
+                }
+            }
+            Ok(())
+        });
+    }
+
+    #[cfg(windows)]
+    pub fn join(&self, _cmd: &mut Command) {}
+
+    /// Number of processes in the group killed for going over its memory limit
+    pub fn oom_kills(&self) -> u64 {
+        let dir = match self.memory {
+            Some(ref dir) => dir,
+            None => return 0,
+        };
+        let file = if self.unified {
+            "memory.events"
+        } else {
+            "memory.oom_control"
+        };
+        let mut content = String::new();
+        match fs::File::open(dir.join(file)).and_then(|mut f| f.read_to_string(&mut content)) {
+            Ok(_) => oom_kills(&content),
+            Err(err) => {
+                warn!(
+                    "Unable to read OOM kills of cgroup {}, err={}",
+                    dir.display(),
+                    err
+                );
+                0
+            }
+        }
+    }
+
+    fn dirs(&self) -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = self.memory.iter().chain(self.cpu.iter()).cloned().collect();
+        dirs.dedup();
+        dirs
+    }
+}
+
+impl Drop for Cgroup {
+    /// Removes the group, which only succeeds once every process in it has exited.
+    fn drop(&mut self) {
+        for dir in self.dirs() {
+            if let Err(err) = fs::remove_dir(&dir) {
+                warn!("Unable to remove cgroup {}, err={}", dir.display(), err);
+            }
+        }
+    }
+}
+
+/// Microseconds of CPU time a build limited to `millicores` may use in every period
+fn cpu_quota_us(millicores: u32) -> u64 {
+    millicores as u64 * CPU_PERIOD_US / 1000
+}
+
+/// Reads the `oom_kill` counter out of `memory.events` or `memory.oom_control`
+fn oom_kills(content: &str) -> u64 {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some("oom_kill"), Some(count)) => count.parse().ok(),
+                _ => None,
+            }
+        })
+        .next()
+        .unwrap_or(0)
+}
+
+fn create_dir(dir: &Path) -> Result<()> {
+    fs::create_dir_all(dir).map_err(
+        |e| Error::Cgroup(dir.to_path_buf(), e),
+    )
+}
+
+fn write(path: &Path, value: &str) -> Result<()> {
+    fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(value.as_bytes()))
+        .map_err(|e| Error::Cgroup(path.to_path_buf(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use protocol::jobsrv;
+
+    use super::*;
+
+    #[test]
+    fn limits_from_job() {
+        let mut job = Job::new(jobsrv::Job::new());
+        assert!(Limits::from_job(&job).is_empty());
+
+        job.set_memory_limit_mb(2048);
+        job.set_cpu_limit_millicores(1500);
+        let limits = Limits::from_job(&job);
+        assert_eq!(limits.memory_mb, Some(2048));
+        assert_eq!(limits.cpu_millicores, Some(1500));
+        assert_eq!(limits.cpus(), Some(String::from("1.500")));
+        assert_eq!(cpu_quota_us(1500), 150_000);
+    }
+
+    #[test]
+    fn oom_kills_from_events() {
+        let events = "low 0\nhigh 0\nmax 12\noom 2\noom_kill 2\n";
+        assert_eq!(oom_kills(events), 2);
+        let oom_control = "oom_kill_disable 0\nunder_oom 0\noom_kill 1\n";
+        assert_eq!(oom_kills(oom_control), 1);
+        assert_eq!(oom_kills("oom_kill_disable 0\nunder_oom 0\n"), 0);
+    }
+}
@@ -71,14 +71,14 @@ impl<'a> DockerExporter<'a> {
     }
 
     /// Spawns a Docker export command, pipes output streams to the given `LogPipe` and returns the
-    /// process' `ExitStatus`.
+    /// process' `ExitStatus`, along with the digest of the image it pushed if one was reported.
     ///
     /// # Errors
     ///
     /// * If the child process can't be spawned
     /// * If the calling thread can't wait on the child process
     /// * If the `LogPipe` fails to pipe output
-    pub fn export(&self, log_pipe: &mut LogPipe) -> Result<ExitStatus> {
+    pub fn export(&self, log_pipe: &mut LogPipe) -> Result<(ExitStatus, Option<String>)> {
         let dockerd = self.spawn_dockerd().map_err(Error::Exporter)?;
         let exit_status = self.run_export(log_pipe);
         self.teardown_dockerd(dockerd).err().map(|e| {
@@ -87,7 +87,7 @@ impl<'a> DockerExporter<'a> {
         exit_status
     }
 
-    fn run_export(&self, log_pipe: &mut LogPipe) -> Result<ExitStatus> {
+    fn run_export(&self, log_pipe: &mut LogPipe) -> Result<(ExitStatus, Option<String>)> {
         let sock = self.dockerd_sock();
 
         let mut cmd = Command::new(&*DOCKER_EXPORTER_PROGRAM);
@@ -149,10 +149,15 @@ impl<'a> DockerExporter<'a> {
 
         debug!("spawning docker export command");
         let mut child = cmd.spawn().map_err(Error::Exporter)?;
-        log_pipe.pipe(&mut child)?;
+        let mut digest = None;
+        log_pipe.pipe_with(&mut child, |line| {
+            if let Some(d) = pushed_digest(line) {
+                digest = Some(d);
+            }
+        })?;
         let exit_status = child.wait().map_err(Error::Exporter)?;
         debug!("completed docker export command, status={:?}", exit_status);
-        Ok(exit_status)
+        Ok((exit_status, digest))
     }
 
     fn spawn_dockerd(&self) -> io::Result<Child> {
@@ -239,3 +244,39 @@ impl<'a> DockerExporter<'a> {
         )
     }
 }
+
+/// Returns the image digest reported by a line of `docker push` output, such as
+/// `latest: digest: sha256:7d3f... size: 1573`.
+fn pushed_digest(line: &str) -> Option<String> {
+    let start = match line.find("digest: ") {
+        Some(idx) => idx + "digest: ".len(),
+        None => return None,
+    };
+    match line[start..].split_whitespace().next() {
+        Some(digest) if digest.starts_with("sha256:") => Some(digest.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digest_from_push_output() {
+        assert_eq!(
+            pushed_digest("latest: digest: sha256:7d3fcb0a size: 1573"),
+            Some("sha256:7d3fcb0a".to_string())
+        );
+        assert_eq!(
+            pushed_digest("1.0.0-20171101000000: digest: sha256:7d3fcb0a size: 1573"),
+            Some("sha256:7d3fcb0a".to_string())
+        );
+    }
+
+    #[test]
+    fn digest_from_other_output() {
+        assert_eq!(pushed_digest("5bef08742407: Pushed"), None);
+        assert_eq!(pushed_digest("digest: unknown"), None);
+    }
+}
@@ -19,6 +19,7 @@ use protobuf::Message;
 use protocol::jobsrv::{JobLogComplete, JobLogChunk};
 use std::io::{BufRead, BufReader};
 use std::process;
+use super::redactor::Redactor;
 use super::workspace::Workspace;
 use zmq;
 
@@ -36,10 +37,13 @@ pub struct LogPipe {
     sock: zmq::Socket,
     logger: Logger,
     line_count: u64,
+    redactor: Redactor,
 }
 
 impl LogPipe {
-    pub fn new(workspace: &Workspace) -> Self {
+    /// Creates a pipe for the log of the workspace's job, masked by `redactor` and the values of
+    /// the job's secrets.
+    pub fn new(workspace: &Workspace, redactor: &Redactor) -> Self {
         let sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::PUSH).unwrap();
         sock.set_immediate(true).unwrap();
         sock.set_linger(5000).unwrap();
@@ -55,6 +59,7 @@ impl LogPipe {
             sock,
             logger,
             line_count: 0,
+            redactor: redactor.with_secrets(workspace.secrets()),
         }
     }
 
@@ -64,10 +69,18 @@ impl LogPipe {
     /// Contents of STDOUT are streamed before any from STDERR (if
     /// any).
     pub fn pipe(&mut self, process: &mut process::Child) -> Result<()> {
+        self.pipe_with(process, |_| ())
+    }
+
+    /// Like `pipe`, calling `on_line` with each line of output as it's streamed.
+    pub fn pipe_with<F>(&mut self, process: &mut process::Child, on_line: F) -> Result<()>
+    where
+        F: FnMut(&str),
+    {
         self.logger.log("About to log stdout");
         if let Some(ref mut stdout) = process.stdout {
             let reader = BufReader::new(stdout);
-            self.stream_lines(reader)?;
+            self.stream_lines(reader, on_line)?;
         }
         self.logger.log("Finished logging stdout");
         Ok(())
@@ -75,7 +88,7 @@ impl LogPipe {
 
     pub fn pipe_stdout(&mut self, content: &[u8]) -> Result<()> {
         self.logger.log("About to log stdout");
-        self.stream_lines(BufReader::new(content))?;
+        self.stream_lines(BufReader::new(content), |_| ())?;
         self.logger.log("Finished logging stdout");
         Ok(())
     }
@@ -86,11 +99,16 @@ impl LogPipe {
     /// `line_count` is the line number to start with when generating
     /// JobLogChunk messages. This allows us to send multiple output
     /// to the same job (i.e. standard output and standard error).
-    fn stream_lines<B: BufRead>(&mut self, reader: B) -> Result<()> {
+    fn stream_lines<B, F>(&mut self, reader: B, mut on_line: F) -> Result<()>
+    where
+        B: BufRead,
+        F: FnMut(&str),
+    {
         for line in reader.lines() {
             self.line_count += 1;
-            let mut l: String = line.unwrap();
+            let mut l = self.redactor.redact(line.unwrap());
             self.logger.log(format!("{}", l).as_ref());
+            on_line(&l);
             l = l + EOL_MARKER;
 
             let mut chunk = JobLogChunk::new();
@@ -13,21 +13,29 @@
 // limitations under the License.
 
 pub mod studio;
+pub mod artifact_cache;
+mod cgroup;
 mod docker;
 mod log_pipe;
 mod postprocessor;
+mod prefetch;
 mod publisher;
+mod redactor;
+mod reproducibility;
 mod toml_builder;
 mod util;
+mod watchdog;
 mod workspace;
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::fs;
 use std::sync::{mpsc, Arc};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::thread::{self, JoinHandle};
 
-pub use protocol::jobsrv::JobState;
+pub use protocol::jobsrv::{JobKind, JobState};
+pub use self::artifact_cache::ArtifactCache;
 use bldr_core::job::Job;
 use bldr_core::logger::Logger;
 use chrono::UTC;
@@ -44,7 +52,9 @@ use zmq;
 use {PRODUCT, VERSION};
 use self::log_pipe::LogPipe;
 use self::postprocessor::post_process;
-use self::studio::{key_path, Studio, STUDIO_GROUP, STUDIO_USER};
+use self::prefetch::Prefetcher;
+use self::redactor::Redactor;
+use self::studio::{Studio, STUDIO_GROUP, STUDIO_USER};
 use self::docker::DockerExporter;
 use self::workspace::Workspace;
 use config::Config;
@@ -60,6 +70,8 @@ const NONINTERACTIVE_ENVVAR: &'static str = "HAB_NONINTERACTIVE";
 
 /// Environment variable to enable or disable debug output in runner's studio
 const RUNNER_DEBUG_ENVVAR: &'static str = "BUILDER_RUNNER_DEBUG";
+/// Prefix of the environment variables the origin's secrets are handed to the studio in
+const SECRET_ENVVAR_PREFIX: &'static str = "HAB_STUDIO_SECRET_";
 /// In-memory zmq address of Job RunnerMgr
 const INPROC_ADDR: &'static str = "inproc://runner";
 /// Protocol message to indicate the Job Runner has received a work request
@@ -71,6 +83,9 @@ const WORK_START: &'static str = "S";
 /// Protocol message to indicate the Runner Cli is sending a cancel request
 const WORK_CANCEL: &'static str = "X";
 
+/// Suffix of secret origin key file names
+const SECRET_KEY_SUFFIX: &'static str = ".sig.key";
+
 pub const RETRIES: u64 = 10;
 pub const RETRY_WAIT: u64 = 60000;
 
@@ -80,10 +95,19 @@ pub struct Runner {
     workspace: Workspace,
     logger: Logger,
     cancel: Arc<AtomicBool>,
+    artifact_cache: Option<ArtifactCache>,
+    prefetch: Option<JoinHandle<()>>,
+    redactor: Redactor,
 }
 
 impl Runner {
-    pub fn new(job: Job, config: Arc<Config>, net_ident: &str, cancel: Arc<AtomicBool>) -> Self {
+    pub fn new(
+        job: Job,
+        config: Arc<Config>,
+        net_ident: &str,
+        cancel: Arc<AtomicBool>,
+        redactor: Redactor,
+    ) -> Self {
         let depot_cli = depot_client::Client::new(&config.bldr_url, PRODUCT, VERSION, None)
             .unwrap();
 
@@ -97,6 +121,9 @@ impl Runner {
             depot_cli: depot_cli,
             logger: logger,
             cancel: cancel,
+            artifact_cache: ArtifactCache::from_config(&config),
+            prefetch: None,
+            redactor: redactor,
         }
     }
 
@@ -220,7 +247,11 @@ impl Runner {
         Ok(())
     }
 
-    fn do_build(&mut self, tx: &mpsc::Sender<Job>) -> Result<PackageArchive> {
+    fn do_build(
+        &mut self,
+        tx: &mpsc::Sender<Job>,
+        log_pipe: &mut LogPipe,
+    ) -> Result<PackageArchive> {
         self.check_cancel(tx)?;
 
         self.workspace.job.set_build_started_at(
@@ -233,7 +264,7 @@ impl Runner {
         // to "Complete" (or "Failed", etc.). As a result, we won't
         // get the `build_started_at` time set until the job is actually
         // finished.
-        let mut archive = match self.build() {
+        let mut archive = match self.build(log_pipe) {
             Ok(archive) => {
                 self.workspace.job.set_build_finished_at(
                     UTC::now().to_rfc3339(),
@@ -251,7 +282,24 @@ impl Runner {
                 );
                 debug!("{}", msg);
                 self.logger.log(&msg);
-                self.fail(net::err(ErrCode::BUILD, "wk:run:5"));
+                match err {
+                    Error::JobCanceled => {
+                        debug!("Runner canceled build of job id: {}", self.job().get_id());
+                        self.cancel();
+                        self.cleanup();
+                    }
+                    Error::BuildTimeout(_) => {
+                        self.fail(net::err(ErrCode::BUILD_TIMEOUT, "wk:run:9"))
+                    }
+                    Error::OfflineBuildFailure(_) => {
+                        self.fail(net::err(ErrCode::BUILD_OFFLINE, "wk:run:10"))
+                    }
+                    Error::BuildOutOfMemory(_) => {
+                        self.fail(net::err(ErrCode::BUILD_OOM, "wk:run:12"))
+                    }
+                    Error::Cgroup(_, _) => self.fail(net::err(ErrCode::SYS, "wk:run:13")),
+                    _ => self.fail(net::err(ErrCode::BUILD, "wk:run:5")),
+                }
                 tx.send(self.job().clone()).map_err(Error::Mpsc)?;
                 return Err(err);
             }
@@ -261,9 +309,79 @@ impl Runner {
         let ident = OriginPackageIdent::from(archive.ident().unwrap());
         self.workspace.job.set_package_ident(ident);
 
+        // Report what was built so consumers can verify the artifact they download. Failing to
+        // compute this shouldn't fail an otherwise good build.
+        match artifact(&archive) {
+            Ok(artifact) => self.workspace.job.set_artifact(artifact),
+            Err(err) => {
+                warn!(
+                    "Failed to compute artifact metadata, path={}, err={:?}",
+                    archive.path.display(),
+                    err
+                )
+            }
+        }
+
         Ok(archive)
     }
 
+    fn do_verify_reproducible(
+        &mut self,
+        tx: &mpsc::Sender<Job>,
+        log_pipe: &mut LogPipe,
+        archive: &PackageArchive,
+    ) -> Result<()> {
+        if !self.job().get_verify_reproducible() {
+            return Ok(());
+        }
+        self.check_cancel(tx)?;
+
+        match self.verify_reproducible(log_pipe, archive) {
+            Ok(reproducible) => self.workspace.job.set_reproducible(reproducible),
+            Err(Error::JobCanceled) => {
+                debug!("Runner canceled build of job id: {}", self.job().get_id());
+                self.cancel();
+                self.cleanup();
+                tx.send(self.job().clone()).map_err(Error::Mpsc)?;
+                return Err(Error::JobCanceled);
+            }
+            // The package was built, it just isn't known whether its build is reproducible
+            Err(err) => {
+                let msg = format!(
+                    "Failed to verify reproducibility of {}, err={:?}",
+                    self.workspace.job.get_project().get_name(),
+                    err
+                );
+                warn!("{}", msg);
+                self.logger.log(&msg);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn do_export(&mut self, tx: &mpsc::Sender<Job>, log_pipe: &mut LogPipe) -> Result<()> {
+        if !self.exports_image() {
+            return Ok(());
+        }
+        self.check_cancel(tx)?;
+
+        if let Err(err) = self.export(log_pipe) {
+            let msg = format!(
+                "Failed docker export for {}, err={:?}",
+                self.workspace.job.get_project().get_name(),
+                err
+            );
+            debug!("{}", msg);
+            self.logger.log(&msg);
+            self.fail(net::err(ErrCode::DOCKER_EXPORT, "wk:run:11"));
+            tx.send(self.job().clone()).map_err(Error::Mpsc)?;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
     fn do_postprocess(
         &mut self,
         tx: &mpsc::Sender<Job>,
@@ -300,12 +418,25 @@ impl Runner {
     }
 
     pub fn run(mut self, tx: mpsc::Sender<Job>) -> Result<()> {
+        // Overlaps downloading the build's dependencies with setting up its workspace
+        self.prefetch = Prefetcher::start(
+            self.job(),
+            &self.config,
+            self.workspace.prefetch().to_path_buf(),
+            self.cancel.clone(),
+        );
         self.do_validate(&tx)?;
         self.do_setup(&tx)?;
         self.do_install_key(&tx)?;
         self.do_clone(&tx)?;
 
-        let archive = self.do_build(&tx)?;
+        let archive = {
+            let mut log_pipe = LogPipe::new(&self.workspace, &self.redactor);
+            let archive = self.do_build(&tx, &mut log_pipe)?;
+            self.do_verify_reproducible(&tx, &mut log_pipe, &archive)?;
+            self.do_export(&tx, &mut log_pipe)?;
+            archive
+        };
         self.do_postprocess(&tx, archive)?;
 
         self.cleanup();
@@ -316,6 +447,9 @@ impl Runner {
     }
 
     fn install_origin_secret_key(&mut self) -> Result<()> {
+        if !util::is_secure_url(&self.config.bldr_url) {
+            return Err(Error::InsecureBldrUrl(self.config.bldr_url.clone()));
+        }
         match retry(
             RETRIES,
             RETRY_WAIT,
@@ -323,7 +457,7 @@ impl Runner {
                 self.depot_cli.fetch_origin_secret_key(
                     self.job().origin(),
                     &self.config.auth_token,
-                    key_path(),
+                    self.workspace.keys(),
                 )
             },
             |res| {
@@ -354,8 +488,7 @@ impl Runner {
         }
     }
 
-    fn build(&mut self) -> Result<PackageArchive> {
-        let mut log_pipe = LogPipe::new(&self.workspace);
+    fn build(&mut self, log_pipe: &mut LogPipe) -> Result<PackageArchive> {
         log_pipe.pipe_stdout(b"\n--- BEGIN: Studio build ---\n")?;
         let networking = match (
             self.config.network_interface.as_ref(),
@@ -366,13 +499,26 @@ impl Runner {
             (None, Some(_)) => return Err(Error::NoNetworkInterfaceError),
             (Some(_), None) => return Err(Error::NoNetworkGatewayError),
         };
-        let mut status = Studio::new(
-            &self.workspace,
-            &self.config.bldr_url,
-            &self.config.auth_token,
-            self.config.airlock_enabled,
-            networking,
-        ).build(&mut log_pipe)?;
+        self.wait_for_prefetch();
+        self.populate_studio_artifacts();
+        let (studio_result, locked_down) = {
+            let studio = Studio::new(
+                &self.workspace,
+                &self.config.bldr_url,
+                &self.config.auth_token,
+                self.config.airlock_enabled,
+                networking,
+                self.config.build_runner,
+                &self.config.studio_image,
+                self.config.network_lockdown,
+                &self.config.cgroup_root,
+                self.cancel.clone(),
+            );
+            (studio.build(log_pipe), studio.locked_down())
+        };
+        self.cache_studio_artifacts();
+        self.discard_secret_keys();
+        let status = studio_result?;
         log_pipe.pipe_stdout(b"\n--- END: Studio build ---\n")?;
 
         if fs::rename(self.workspace.src().join("results"), self.workspace.out()).is_err() {
@@ -383,29 +529,167 @@ impl Runner {
             let ident = self.workspace.attempted_build()?;
             let op_ident = OriginPackageIdent::from(ident);
             self.workspace.job.set_package_ident(op_ident);
+            if locked_down {
+                return Err(Error::OfflineBuildFailure(status.code().unwrap_or(-1)));
+            }
             return Err(Error::BuildFailure(status.code().unwrap_or(-1)));
         }
-        if self.has_docker_integration() {
-            // TODO fn: This check should be updated in PackageArchive is check for run hooks.
-            if self.workspace.last_built()?.is_a_service() {
-                debug!("Found runnable package, running docker export");
-                log_pipe.pipe_stdout(b"\n--- BEGIN: Docker export ---\n")?;
-                status = DockerExporter::new(
-                    util::docker_exporter_spec(&self.workspace),
-                    &self.workspace,
-                    &self.config.bldr_url,
-                ).export(&mut log_pipe)?;
-                log_pipe.pipe_stdout(b"\n--- END: Docker export ---\n")?;
-            } else {
-                debug!("Package not runnable, skipping docker export");
+
+        self.workspace.last_built()
+    }
+
+    /// Builds the package a second time in a fresh studio and compares the contents of both
+    /// builds, returning whether they're the same. The outputs of the first build are kept aside
+    /// meanwhile and put back afterwards, so that it's the first build which is exported and
+    /// uploaded.
+    fn verify_reproducible(
+        &mut self,
+        log_pipe: &mut LogPipe,
+        archive: &PackageArchive,
+    ) -> Result<bool> {
+        log_pipe.pipe_stdout(
+            b"\n--- BEGIN: Reproducibility check, building again in a fresh studio ---\n",
+        )?;
+        let dir = self.workspace.reproducibility().to_path_buf();
+        let first_out = dir.join("out");
+        fs::create_dir_all(&dir).map_err(|e| {
+            Error::ReproducibilityCheck(dir.clone(), e)
+        })?;
+        fs::rename(self.workspace.out(), &first_out).map_err(|e| {
+            Error::ReproducibilityCheck(first_out.clone(), e)
+        })?;
+        let first_archive = PackageArchive::new(first_out.join(archive.file_name()));
+        // A failed build records what it attempted to build on the job, which is the first build
+        let package_ident = self.workspace.job.get_package_ident().clone();
+
+        let result = self.rebuild(log_pipe).and_then(|second_archive| {
+            reproducibility::compare(&first_archive, &second_archive, &dir)
+        });
+
+        self.workspace.job.set_package_ident(package_ident);
+        if self.workspace.out().exists() {
+            if let Err(err) = fs::remove_dir_all(self.workspace.out()) {
+                warn!(
+                    "Failed to remove output of second build {}, err={:?}",
+                    self.workspace.out().display(),
+                    err
+                );
             }
         }
+        fs::rename(&first_out, self.workspace.out()).map_err(|e| {
+            Error::ReproducibilityCheck(first_out.clone(), e)
+        })?;
+        if let Err(err) = fs::remove_dir_all(&dir) {
+            warn!(
+                "Failed to remove reproducibility check dir {}, err={:?}",
+                dir.display(),
+                err
+            );
+        }
 
-        if status.success() {
-            self.workspace.last_built()
+        let differences = result?;
+        if differences.is_empty() {
+            log_pipe.pipe_stdout(
+                b"\nBoth builds have the same contents, the build is reproducible\n",
+            )?;
         } else {
-            // TED: leaving this as a build failure until we move the export process out of build
-            Err(Error::BuildFailure(status.code().unwrap_or(-1)))
+            let mut msg = format!(
+                "\nThe build is not reproducible, {} files differ between builds:\n",
+                differences.len()
+            );
+            for path in differences.iter() {
+                msg.push_str(&format!("  {}\n", path));
+            }
+            log_pipe.pipe_stdout(msg.as_bytes())?;
+        }
+        log_pipe.pipe_stdout(b"\n--- END: Reproducibility check ---\n")?;
+        Ok(differences.is_empty())
+    }
+
+    /// Runs the build again from the same source, in a new studio with the origin's secret key
+    /// installed again.
+    fn rebuild(&mut self, log_pipe: &mut LogPipe) -> Result<PackageArchive> {
+        if self.workspace.studio().exists() {
+            fs::remove_dir_all(self.workspace.studio()).map_err(|e| {
+                Error::WorkspaceTeardown(format!("{}", self.workspace.studio().display()), e)
+            })?;
+        }
+        self.create_key_cache()?;
+        self.install_origin_secret_key()?;
+        self.build(log_pipe)
+    }
+
+    /// Exports the built package to a Docker image and pushes it to the registry of the origin's
+    /// integration, recording the image's digest on the job.
+    fn export(&mut self, log_pipe: &mut LogPipe) -> Result<()> {
+        // TODO fn: This check should be updated in PackageArchive is check for run hooks.
+        if !self.workspace.last_built()?.is_a_service() {
+            debug!("Package not runnable, skipping docker export");
+            log_pipe.pipe_stdout(
+                b"\nPackage has no run hook, skipping Docker export\n",
+            )?;
+            return Ok(());
+        }
+
+        debug!("Found runnable package, running docker export");
+        log_pipe.pipe_stdout(b"\n--- BEGIN: Docker export ---\n")?;
+        let (status, digest) = DockerExporter::new(
+            util::docker_exporter_spec(&self.workspace),
+            &self.workspace,
+            &self.config.bldr_url,
+        ).export(log_pipe)?;
+        log_pipe.pipe_stdout(b"\n--- END: Docker export ---\n")?;
+        if !status.success() {
+            return Err(Error::ExportFailure(status.code().unwrap_or(-1)));
+        }
+
+        match digest {
+            Some(digest) => self.workspace.job.set_image_digest(digest),
+            None => warn!("Docker export didn't report the digest of the pushed image"),
+        }
+        Ok(())
+    }
+
+    /// Waits for the build's dependencies to be prefetched into the artifact cache, so that the
+    /// studio is populated with them.
+    fn wait_for_prefetch(&mut self) {
+        if let Some(handle) = self.prefetch.take() {
+            if handle.join().is_err() {
+                warn!(
+                    "Prefetching dependencies of job {} panicked",
+                    self.job().get_id()
+                );
+            }
+        }
+    }
+
+    /// Links the cached dependency artifacts into the studio, so that the build doesn't download
+    /// them again. The build goes ahead without them if this fails.
+    fn populate_studio_artifacts(&self) {
+        let cache = match self.artifact_cache {
+            Some(ref cache) => cache,
+            None => return,
+        };
+        let dst = self.workspace.studio_artifacts();
+        let res = cache.populate(&dst).and_then(|linked| {
+            if self.config.airlock_enabled {
+                util::chown_recursive(&dst, studio::studio_uid(), studio::studio_gid())?;
+            }
+            Ok(linked)
+        });
+        match res {
+            Ok(linked) => debug!("Linked {} cached artifacts into studio", linked),
+            Err(err) => warn!("Unable to link cached artifacts into studio, err={}", err),
+        }
+    }
+
+    /// Adds the dependency artifacts the studio downloaded to the cache.
+    fn cache_studio_artifacts(&self) {
+        if let Some(ref cache) = self.artifact_cache {
+            match cache.store(&self.workspace.studio_artifacts()) {
+                Ok(stored) => debug!("Cached {} artifacts downloaded by studio", stored),
+                Err(err) => warn!("Unable to cache artifacts downloaded by studio, err={}", err),
+            }
         }
     }
 
@@ -422,7 +706,7 @@ impl Runner {
     fn fail(&mut self, err: net::NetError) {
         self.teardown();
         self.workspace.job.set_state(JobState::Failed);
-        self.workspace.job.set_error(err);
+        self.workspace.job.set_error(err.into());
         self.logger.log_worker_job(&self.workspace.job);
     }
 
@@ -463,18 +747,30 @@ impl Runner {
             perm::set_owner(self.workspace.src(), STUDIO_USER, STUDIO_GROUP)?;
         }
 
-        if let Some(err) = fs::create_dir_all(key_path()).err() {
+        self.create_key_cache()?;
+
+        let hab_home = (&*studio::STUDIO_HOME).lock().unwrap().join(".hab");
+        if let Some(err) = fs::create_dir_all(&hab_home).err() {
+            return Err(Error::WorkspaceSetup(format!("{}", hab_home.display()), err));
+        }
+        util::chown_recursive(hab_home, studio::studio_uid(), studio::studio_gid())?;
+
+        Ok(())
+    }
+
+    /// Creates the key cache the origin's secret key is installed into for the build.
+    fn create_key_cache(&self) -> Result<()> {
+        // Only the build user may read the origin's secret key
+        if let Some(err) = fs::create_dir_all(self.workspace.keys()).err() {
             return Err(Error::WorkspaceSetup(
-                format!("{}", key_path().display()),
+                format!("{}", self.workspace.keys().display()),
                 err,
             ));
         }
-        util::chown_recursive(
-            (&*studio::STUDIO_HOME).lock().unwrap().join(".hab"),
-            studio::studio_uid(),
-            studio::studio_gid(),
-        )?;
-
+        perm::set_permissions(self.workspace.keys(), 0o700)?;
+        if self.config.airlock_enabled {
+            perm::set_owner(self.workspace.keys(), STUDIO_USER, STUDIO_GROUP)?;
+        }
         Ok(())
     }
 
@@ -493,19 +789,64 @@ impl Runner {
                 err
             );
         }
-        // TODO fn: purge the secret origin key from worker
+        self.discard_secret_keys();
+    }
+
+    /// Removes the origin's secret key from the workspace, and from the studio which imported it.
+    /// The key is fetched again for every build, so none is kept on the worker once it's signed
+    /// the build's artifact.
+    fn discard_secret_keys(&self) {
+        if self.workspace.keys().exists() {
+            if let Some(err) = fs::remove_dir_all(self.workspace.keys()).err() {
+                warn!(
+                    "Failed to remove key cache {}, err: {:?}",
+                    self.workspace.keys().display(),
+                    err
+                );
+            }
+        }
+        let studio_keys = self.workspace.studio().join("hab").join("cache").join("keys");
+        let entries = match fs::read_dir(&studio_keys) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.to_string_lossy().ends_with(SECRET_KEY_SUFFIX) {
+                continue;
+            }
+            if let Some(err) = fs::remove_file(&path).err() {
+                warn!(
+                    "Failed to remove secret key {}, err: {:?}",
+                    path.display(),
+                    err
+                );
+            }
+        }
     }
 
-    /// Determines whether or not there is a Docker integration for the job.
+    /// Determines whether the built package is exported to a Docker image. Besides `DockerExport`
+    /// jobs, job servers which predate job kinds expect any project with a Docker integration to
+    /// be exported.
     ///
     /// TODO fn: remember that for the time being we are only expecting a Docker export integration
     /// and we are assuming that any calls to this method will happen after the integration data
     /// has been validated.
-    fn has_docker_integration(&self) -> bool {
-        !self.workspace.job.get_project_integrations().is_empty()
+    fn exports_image(&self) -> bool {
+        self.workspace.job.get_kind() == JobKind::DockerExport ||
+            !self.workspace.job.get_project_integrations().is_empty()
     }
 }
 
+/// Reply sent by the Job Runner to a `RunnerCli`
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RunnerReply {
+    /// A work request was received
+    Ack,
+    /// A job was completed, failed or canceled
+    Complete,
+}
+
 /// Client for sending and receiving messages to and from the Job Runner
 pub struct RunnerCli {
     sock: zmq::Socket,
@@ -533,26 +874,18 @@ impl RunnerCli {
         Ok(())
     }
 
-    /// Wait until client receives a work received acknowledgement by the Runner and return
-    /// the assigned JobID.
-    pub fn recv_ack(&mut self) -> Result<&zmq::Message> {
-        self.sock.recv(&mut self.msg, 0)?;
-        if Some(WORK_ACK) != self.msg.as_str() {
-            unreachable!("wk:run:1, received unexpected response from runner");
-        }
-        self.sock.recv(&mut self.msg, 0)?;
-        Ok(&self.msg)
-    }
-
-    /// Wait until client receives a work complete message by the Runner and return an encoded
-    /// representation of the job.
-    pub fn recv_complete(&mut self) -> Result<&zmq::Message> {
+    /// Wait until client receives a reply from the Runner and return it along with an encoded
+    /// representation of the job. Replies for different jobs may arrive in any order, since
+    /// several jobs can run at once.
+    pub fn recv(&mut self) -> Result<(RunnerReply, &zmq::Message)> {
         self.sock.recv(&mut self.msg, 0)?;
-        if Some(WORK_COMPLETE) != self.msg.as_str() {
-            unreachable!("wk:run:2, received unexpected response from runner");
-        }
+        let reply = match self.msg.as_str() {
+            Some(WORK_ACK) => RunnerReply::Ack,
+            Some(WORK_COMPLETE) => RunnerReply::Complete,
+            _ => unreachable!("wk:run:1, received unexpected response from runner"),
+        };
         self.sock.recv(&mut self.msg, 0)?;
-        Ok(&self.msg)
+        Ok((reply, &self.msg))
     }
 
     /// Send a message to the Job Runner to start a Job
@@ -571,20 +904,22 @@ impl RunnerCli {
 }
 
 /// Receives work notifications from a `RunnerCli` and performs long-running tasks in a
-/// separate thread.
+/// separate thread for each job.
 pub struct RunnerMgr {
     config: Arc<Config>,
     net_ident: Arc<String>,
     msg: zmq::Message,
     sock: zmq::Socket,
-    cancel: Arc<AtomicBool>,
+    /// Cancel flags of the jobs being run, by job id
+    cancels: HashMap<u64, Arc<AtomicBool>>,
+    redactor: Redactor,
 }
 
 impl RunnerMgr {
     /// Start the Job Runner
     pub fn start(config: Arc<Config>, net_ident: Arc<String>) -> Result<JoinHandle<()>> {
         let (tx, rx) = mpsc::sync_channel(0);
-        let mut runner = Self::new(config, net_ident).unwrap();
+        let mut runner = Self::new(config, net_ident)?;
         let handle = thread::Builder::new()
             .name("runner".to_string())
             .spawn(move || { runner.run(tx).unwrap(); })
@@ -597,12 +932,14 @@ impl RunnerMgr {
 
     fn new(config: Arc<Config>, net_ident: Arc<String>) -> Result<Self> {
         let sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::DEALER)?;
+        let redactor = Redactor::from_config(&config)?;
         Ok(RunnerMgr {
             config: config,
             msg: zmq::Message::new().unwrap(),
             net_ident: net_ident,
             sock: sock,
-            cancel: Arc::new(AtomicBool::new(false)),
+            cancels: HashMap::new(),
+            redactor: redactor,
         })
     }
 
@@ -631,12 +968,13 @@ impl RunnerMgr {
 
                 match &op[..] {
                     WORK_START => {
-                        self.cancel.store(false, Ordering::SeqCst);
                         self.send_ack(&job)?;
                         self.spawn_job(job, tx.clone())?;
                     }
                     WORK_CANCEL => {
-                        self.cancel.store(true, Ordering::SeqCst);
+                        if let Some(cancel) = self.cancels.get(&job.get_id()) {
+                            cancel.store(true, Ordering::SeqCst);
+                        }
                         job.set_state(jobsrv::JobState::CancelProcessing);
                         self.send_ack(&job)?;
                     }
@@ -644,21 +982,23 @@ impl RunnerMgr {
                 }
             }
 
-            let res = rx.try_recv();
-            if res.is_ok() {
-                let job: Job = res.unwrap();
+            while let Ok(job) = rx.try_recv() {
                 debug!("Got result from spawned runner: {:?}", job);
+                self.cancels.remove(&job.get_id());
                 self.send_complete(&job)?;
             }
         }
     }
 
     fn spawn_job(&mut self, job: Job, tx: mpsc::Sender<Job>) -> Result<()> {
+        let cancel = Arc::new(AtomicBool::new(false));
+        self.cancels.insert(job.get_id(), cancel.clone());
         let runner = Runner::new(
             job,
             self.config.clone(),
             &self.net_ident,
-            self.cancel.clone(),
+            cancel,
+            self.redactor.clone(),
         );
 
         let _ = thread::Builder::new()
@@ -690,6 +1030,19 @@ impl RunnerMgr {
     }
 }
 
+/// Returns the checksum and size of a built package archive.
+fn artifact(archive: &PackageArchive) -> Result<jobsrv::JobArtifact> {
+    let checksum = archive.checksum()?;
+    let size = fs::metadata(&archive.path)
+        .map_err(|e| Error::ArtifactMetadata(archive.path.clone(), e))?
+        .len();
+
+    let mut artifact = jobsrv::JobArtifact::new();
+    artifact.set_checksum(checksum);
+    artifact.set_size(size);
+    Ok(artifact)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,273 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Downloads the dependencies of a build while its workspace is being set up.
+//!
+//! The dependencies of a plan are only resolved by plan-build, well into the build. Plans rarely
+//! change them between releases though, so as soon as a job is accepted the dependencies declared
+//! in the manifest of the project's latest release are looked up, in the job's channel or else
+//! the stable channel, and downloaded along with their runtime dependencies. They're added to the
+//! worker's artifact cache, which the studio is populated from once the prefetch is done.
+//! Dependencies the plan no longer declares only cost their download, while the ones it added
+//! since are downloaded by the build as usual.
+
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, JoinHandle};
+
+use bldr_core::job::Job;
+use depot_client::{self, NoProgress};
+use hab_core::channel::STABLE_CHANNEL;
+use hab_core::package::PackageIdent;
+use protocol::originsrv::OriginPackage;
+
+use config::Config;
+use runner::artifact_cache::{self, ArtifactCache};
+use {PRODUCT, VERSION};
+
+/// Manifest lines listing the dependencies declared by a plan
+const MANIFEST_DEPS: &'static [&'static str] =
+    &["* __Build Dependencies__:", "* __Dependencies__:"];
+
+pub struct Prefetcher {
+    depot_cli: depot_client::Client,
+    cache: ArtifactCache,
+    auth_token: String,
+    channel: String,
+    project: PackageIdent,
+    dst: PathBuf,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Prefetcher {
+    /// Starts downloading the dependencies of the job's build into `dst` in the background.
+    /// Nothing is prefetched if the artifact cache is disabled, as there'd be nowhere to keep them.
+    pub fn start(
+        job: &Job,
+        config: &Config,
+        dst: PathBuf,
+        cancel: Arc<AtomicBool>,
+    ) -> Option<JoinHandle<()>> {
+        let cache = match ArtifactCache::from_config(config) {
+            Some(cache) => cache,
+            None => return None,
+        };
+        let project = match PackageIdent::from_str(job.get_project().get_name()) {
+            Ok(project) => project,
+            Err(_) => return None,
+        };
+        let depot_cli = match depot_client::Client::new(
+            &config.bldr_url,
+            PRODUCT,
+            VERSION,
+            None,
+        ) {
+            Ok(depot_cli) => depot_cli,
+            Err(err) => {
+                warn!("Unable to prefetch dependencies of {}, err={}", project, err);
+                return None;
+            }
+        };
+        let channel = if job.has_channel() {
+            job.get_channel().to_string()
+        } else {
+            STABLE_CHANNEL.to_string()
+        };
+        let prefetcher = Prefetcher {
+            depot_cli: depot_cli,
+            cache: cache,
+            auth_token: config.auth_token.clone(),
+            channel: channel,
+            project: project,
+            dst: dst,
+            cancel: cancel,
+        };
+        let handle = thread::Builder::new()
+            .name(format!("prefetch-{}", job.get_id()))
+            .spawn(move || prefetcher.run())
+            .unwrap();
+        Some(handle)
+    }
+
+    fn run(self) {
+        let deps = match self.show_package(&self.project) {
+            Ok(package) => declared_deps(package.get_manifest()),
+            Err(err) => {
+                debug!(
+                    "Not prefetching dependencies of {}, no release found, err={}",
+                    self.project,
+                    err
+                );
+                return;
+            }
+        };
+
+        let (mut hits, mut misses) = (0, 0);
+        for ident in self.resolve(&deps) {
+            if self.cancel.load(Ordering::SeqCst) {
+                break;
+            }
+            let file_name = match ident.archive_name() {
+                Some(file_name) => file_name,
+                None => continue,
+            };
+            match self.cache.contains(&file_name) {
+                Ok(true) => {
+                    hits += 1;
+                    continue;
+                }
+                Ok(false) => (),
+                Err(err) => {
+                    warn!("Unable to look up cached artifacts, err={}", err);
+                    break;
+                }
+            }
+            match self.depot_cli.fetch_package(
+                &ident,
+                self.token(),
+                &self.dst,
+                None::<NoProgress>,
+            ) {
+                Ok(_) => misses += 1,
+                Err(err) => warn!("Unable to prefetch {}, err={}", ident, err),
+            }
+        }
+        artifact_cache::record_lookups(hits, misses);
+
+        if misses > 0 {
+            if let Err(err) = self.cache.store(&self.dst) {
+                warn!("Unable to cache prefetched artifacts, err={}", err);
+            }
+        }
+        if self.dst.exists() {
+            if let Err(err) = fs::remove_dir_all(&self.dst) {
+                warn!(
+                    "Failed to remove prefetch dir {}, err={:?}",
+                    self.dst.display(),
+                    err
+                );
+            }
+        }
+        debug!(
+            "Prefetched dependencies of {}, cached={}, downloaded={}",
+            self.project,
+            hits,
+            misses
+        );
+    }
+
+    /// Fully qualified identifiers of the latest releases of `deps`, followed by their runtime
+    /// dependencies
+    fn resolve(&self, deps: &[PackageIdent]) -> Vec<PackageIdent> {
+        let mut resolved = Vec::new();
+        for dep in deps {
+            if self.cancel.load(Ordering::SeqCst) {
+                break;
+            }
+            let package = match self.show_package(dep) {
+                Ok(package) => package,
+                Err(err) => {
+                    debug!("Not prefetching {}, err={}", dep, err);
+                    continue;
+                }
+            };
+            let idents = Some(package.get_ident()).into_iter().chain(
+                package.get_tdeps().iter(),
+            );
+            for ident in idents {
+                let ident: PackageIdent = ident.clone().into();
+                if !resolved.contains(&ident) {
+                    resolved.push(ident);
+                }
+            }
+        }
+        resolved
+    }
+
+    /// Token to authenticate with Builder as, needed to see the packages of private origins
+    fn token(&self) -> Option<&str> {
+        if self.auth_token.is_empty() {
+            None
+        } else {
+            Some(&self.auth_token)
+        }
+    }
+
+    /// Latest release of a package in the job's channel, or in the stable channel if there's none
+    /// in the job's channel, like plan-build installs dependencies.
+    fn show_package(&self, ident: &PackageIdent) -> depot_client::Result<OriginPackage> {
+        let token = self.token();
+        match self.depot_cli.show_package(ident, Some(&self.channel), token) {
+            Ok(package) => Ok(package),
+            Err(_) if self.channel != STABLE_CHANNEL => {
+                self.depot_cli.show_package(ident, Some(STABLE_CHANNEL), token)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// The build and runtime dependencies a plan declared, read from the manifest of its artifact
+fn declared_deps(manifest: &str) -> Vec<PackageIdent> {
+    let mut deps = Vec::new();
+    for line in manifest.lines() {
+        let list = match MANIFEST_DEPS.iter().find(|prefix| line.starts_with(*prefix)) {
+            Some(prefix) => &line[prefix.len()..],
+            None => continue,
+        };
+        for dep in list.trim().trim_matches('`').split_whitespace() {
+            if !dep.contains('/') {
+                continue;
+            }
+            if let Ok(ident) = PackageIdent::from_str(dep) {
+                if !deps.contains(&ident) {
+                    deps.push(ident);
+                }
+            }
+        }
+    }
+    deps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declared_deps_from_manifest() {
+        let manifest = r#"# core / redis
+A persistent key-value database
+
+* __Maintainer__: The Habitat Maintainers <humans@habitat.sh>
+* __Version__: 3.2.4
+* __Release__: 20170514001355
+* __Build Dependencies__: `core/make core/gcc core/glibc `
+* __Dependencies__: `core/glibc `
+* __Interpreters__: no interpreters or undefined
+"#;
+        let deps: Vec<String> = declared_deps(manifest)
+            .iter()
+            .map(|ident| ident.to_string())
+            .collect();
+        assert_eq!(deps, vec!["core/make", "core/gcc", "core/glibc"]);
+
+        let manifest = r#"* __Build Dependencies__: no build dependencies or undefined
+* __Dependencies__: no runtime dependencies or undefined
+"#;
+        assert!(declared_deps(manifest).is_empty());
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compares the contents of two builds of a package, to verify that its build is reproducible.
+//!
+//! Artifacts are signed and stamped with the time they were built, so they never match byte for
+//! byte. Instead both artifacts are unpacked and the content hashes of the files they install are
+//! compared. Every build gets a release of its own, which plan-build writes into the package's
+//! metadata and often into its files too, such as the run paths of binaries. The release is
+//! replaced by a placeholder before a file is hashed, so that only differences in what was built
+//! count. The `FILES` and `MANIFEST` metadata files are left out, as they record the checksums and
+//! build details of the artifact itself.
+
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+use hab_core::crypto::hash;
+use hab_core::fs as hab_fs;
+use hab_core::package::PackageArchive;
+
+use error::{Error, Result};
+
+/// Metadata files describing the artifact rather than what was built
+const EXCLUDED_METADATA: &'static [&'static str] = &["FILES", "MANIFEST"];
+/// Replaces the build's release in the files being compared
+const RELEASE_PLACEHOLDER: &'static [u8] = b"@RELEASE@";
+
+/// Unpacks both artifacts under `dir` and returns the files whose contents differ between them,
+/// including the ones only installed by one of them. Both builds are reproducible if there are
+/// none.
+pub fn compare(first: &PackageArchive, second: &PackageArchive, dir: &Path) -> Result<Vec<String>> {
+    let first = content_digests(first, &dir.join("first"))?;
+    let second = content_digests(second, &dir.join("second"))?;
+    Ok(differences(&first, &second))
+}
+
+/// Content hashes of the files installed by an artifact, by their path relative to the package's
+/// install path
+fn content_digests(archive: &PackageArchive, root: &Path) -> Result<BTreeMap<String, String>> {
+    let mut archive = PackageArchive::new(archive.path.clone());
+    let ident = archive.ident()?;
+    archive.unpack(Some(root))?;
+    let release = ident.release.clone().unwrap_or_default();
+    let pkg_path = hab_fs::pkg_install_path(&ident, Some(root));
+
+    let mut digests = BTreeMap::new();
+    let mut dirs = vec![pkg_path.clone()];
+    while let Some(dir) = dirs.pop() {
+        let entries = fs::read_dir(&dir).map_err(
+            |e| Error::ReproducibilityCheck(dir.clone(), e),
+        )?;
+        for entry in entries {
+            let path = entry
+                .map_err(|e| Error::ReproducibilityCheck(dir.clone(), e))?
+                .path();
+            let relative = path.strip_prefix(&pkg_path)
+                .unwrap() // Unwrap OK, the path was read from under the package's
+                .to_string_lossy()
+                .into_owned();
+            if EXCLUDED_METADATA.contains(&relative.as_str()) {
+                continue;
+            }
+            let metadata = fs::symlink_metadata(&path).map_err(|e| {
+                Error::ReproducibilityCheck(path.clone(), e)
+            })?;
+            let content = if metadata.file_type().is_symlink() {
+                let target = fs::read_link(&path).map_err(|e| {
+                    Error::ReproducibilityCheck(path.clone(), e)
+                })?;
+                target.to_string_lossy().into_owned().into_bytes()
+            } else if metadata.is_dir() {
+                dirs.push(path);
+                continue;
+            } else {
+                let mut content = Vec::new();
+                File::open(&path)
+                    .and_then(|mut f| f.read_to_end(&mut content))
+                    .map_err(|e| Error::ReproducibilityCheck(path.clone(), e))?;
+                content
+            };
+            digests.insert(
+                relative,
+                hash::hash_bytes(&normalize(&content, release.as_bytes())),
+            );
+        }
+    }
+    Ok(digests)
+}
+
+/// The paths whose hashes differ between two builds, or which only one of them has
+fn differences(first: &BTreeMap<String, String>, second: &BTreeMap<String, String>) -> Vec<String> {
+    let mut paths: Vec<String> = first
+        .iter()
+        .filter(|&(path, digest)| second.get(path) != Some(digest))
+        .map(|(path, _)| path.clone())
+        .chain(second.keys().filter(|path| !first.contains_key(*path)).cloned())
+        .collect();
+    paths.sort();
+    paths
+}
+
+/// Replaces every occurrence of the build's release in `content` with a placeholder
+fn normalize(content: &[u8], release: &[u8]) -> Vec<u8> {
+    if release.is_empty() {
+        return content.to_vec();
+    }
+    let mut normalized = Vec::with_capacity(content.len());
+    let mut i = 0;
+    while i < content.len() {
+        if content[i..].starts_with(release) {
+            normalized.extend_from_slice(RELEASE_PLACEHOLDER);
+            i += release.len();
+        } else {
+            normalized.push(content[i]);
+            i += 1;
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_release() {
+        let content = b"/hab/pkgs/core/redis/3.2.4/20170514001355/lib:20170514001355";
+        assert_eq!(
+            normalize(content, b"20170514001355"),
+            b"/hab/pkgs/core/redis/3.2.4/@RELEASE@/lib:@RELEASE@".to_vec()
+        );
+        assert_eq!(normalize(content, b""), content.to_vec());
+    }
+
+    #[test]
+    fn differences_between_builds() {
+        let mut first = BTreeMap::new();
+        first.insert("IDENT".to_string(), "a".to_string());
+        first.insert("bin/redis-server".to_string(), "b".to_string());
+        first.insert("lib/old.so".to_string(), "c".to_string());
+        let mut second = first.clone();
+        assert!(differences(&first, &second).is_empty());
+
+        second.insert("bin/redis-server".to_string(), "d".to_string());
+        second.remove("lib/old.so");
+        second.insert("lib/new.so".to_string(), "e".to_string());
+        assert_eq!(
+            differences(&first, &second),
+            vec!["bin/redis-server", "lib/new.so", "lib/old.so"]
+        );
+    }
+}
@@ -12,12 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cell::Cell;
 use std::net::IpAddr;
+#[cfg(unix)]
 use std::os::unix::process::CommandExt;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf, MAIN_SEPARATOR};
 use std::process::{Command, ExitStatus, Stdio};
-use std::sync::Mutex;
-use std::sync::atomic::{AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicUsize, ATOMIC_USIZE_INIT, Ordering};
+use std::time::Duration;
 
 use hab_core::channel::{BLDR_CHANNEL_ENVVAR, STABLE_CHANNEL};
 use hab_core::env;
@@ -25,9 +28,12 @@ use hab_core::fs;
 use hab_core::url::BLDR_URL_ENVVAR;
 use hab_core::AUTH_TOKEN_ENVVAR;
 
+use config::BuildRunner;
 use error::{Error, Result};
+use runner::cgroup::{Cgroup, Limits};
 use runner::log_pipe::LogPipe;
-use runner::{NONINTERACTIVE_ENVVAR, RUNNER_DEBUG_ENVVAR};
+use runner::{NONINTERACTIVE_ENVVAR, RUNNER_DEBUG_ENVVAR, SECRET_ENVVAR_PREFIX};
+use runner::watchdog::{self, Stopped, Watchdog};
 use runner::workspace::Workspace;
 
 pub static STUDIO_UID: AtomicUsize = ATOMIC_USIZE_INIT;
@@ -35,6 +41,36 @@ pub static STUDIO_GID: AtomicUsize = ATOMIC_USIZE_INIT;
 pub const DEBUG_ENVVARS: &'static [&'static str] = &["RUST_LOG", "DEBUG"];
 pub const STUDIO_USER: &'static str = "krangschnak";
 pub const STUDIO_GROUP: &'static str = "krangschnak";
+/// File names of plans, which are given to the Studio by the directory containing them
+const PLAN_FILE_NAMES: &'static [&'static str] = &["plan.sh", "plan.ps1"];
+/// Environment variables which Windows programs, such as the PowerShell Studio, need to run
+#[cfg(windows)]
+const WINDOWS_ENVVARS: &'static [&'static str] = &[
+    "ComSpec",
+    "PATHEXT",
+    "PSModulePath",
+    "SystemDrive",
+    "SystemRoot",
+    "TEMP",
+    "TMP",
+    "USERPROFILE",
+];
+/// Output of plan-build once everything the build needs has been downloaded
+const DOWNLOADS_COMPLETE_MARKER: &'static str = "Downloads complete";
+/// Environment variables passed from the `docker` client into the build container
+const DOCKER_ENVVARS: &'static [&'static str] = &[
+    "DEBUG",
+    "RUST_LOG",
+    "TERM",
+    NONINTERACTIVE_ENVVAR,
+    BLDR_CHANNEL_ENVVAR,
+    BLDR_URL_ENVVAR,
+    AUTH_TOKEN_ENVVAR,
+];
+/// Environment variables the `docker` client itself needs to reach the Docker daemon and find its
+/// configuration
+const DOCKER_CLIENT_ENVVARS: &'static [&'static str] =
+    &["DOCKER_HOST", "DOCKER_CONFIG", "DOCKER_CERT_PATH", "DOCKER_TLS_VERIFY", "HOME"];
 
 lazy_static! {
     /// Absolute path to the Studio program
@@ -54,16 +90,31 @@ pub struct Studio<'a> {
     auth_token: &'a str,
     airlock_enabled: bool,
     networking: Option<(&'a str, &'a IpAddr)>,
+    runner: BuildRunner,
+    studio_image: &'a str,
+    network_lockdown: bool,
+    cgroup_root: &'a Path,
+    locked_down: Cell<bool>,
+    cancel: Arc<AtomicBool>,
 }
 
 impl<'a> Studio<'a> {
-    /// Creates a new Studio runner for a given `Workspace` and Builder URL.
+    /// Creates a new Studio runner for a given `Workspace` and Builder URL. The build is run in a
+    /// container of `studio_image` if `runner` is Docker, and stopped if `cancel` is set while
+    /// it's running. With `network_lockdown`, the build's network namespace loses its network
+    /// access once plan-build reports that its downloads are complete. The job's memory and CPU
+    /// limits are enforced by the container, or otherwise by a control group under `cgroup_root`.
     pub fn new(
         workspace: &'a Workspace,
         bldr_url: &'a str,
         auth_token: &'a str,
         airlock_enabled: bool,
         networking: Option<(&'a str, &'a IpAddr)>,
+        runner: BuildRunner,
+        studio_image: &'a str,
+        network_lockdown: bool,
+        cgroup_root: &'a Path,
+        cancel: Arc<AtomicBool>,
     ) -> Self {
         Studio {
             workspace,
@@ -71,19 +122,33 @@ impl<'a> Studio<'a> {
             auth_token,
             airlock_enabled,
             networking,
+            runner,
+            studio_image,
+            network_lockdown,
+            cgroup_root,
+            locked_down: Cell::new(false),
+            cancel,
         }
     }
 
     /// Spawns a Studio build command, pipes output streams to the given `LogPipe` and returns the
-    /// process' `ExitStatus`.
+    /// process' `ExitStatus`. The Studio and everything it started are stopped if the job is
+    /// canceled, or once the job's build timeout has passed.
     ///
     /// # Errors
     ///
     /// * If the child process can't be spawned
     /// * If the calling thread can't wait on the child process
     /// * If the `LogPipe` fails to pipe output
+    /// * If the build ran past the job's timeout
+    /// * If the job was canceled during the build
+    /// * If the build's network access couldn't be dropped
+    /// * If the build's control group couldn't be set up
+    /// * If the build ran out of memory
     pub fn build(&self, log_pipe: &mut LogPipe) -> Result<ExitStatus> {
-        if self.networking.is_some() {
+        let limits = Limits::from_job(&self.workspace.job);
+        let cgroup = self.create_cgroup(&limits)?;
+        if self.airlock_networking() {
             self.create_network_namespace()?;
         } else {
             info!("Airlock networking is not configured, skipping network creation");
@@ -95,7 +160,7 @@ impl<'a> Studio<'a> {
             STABLE_CHANNEL
         };
 
-        let mut cmd = self.studio_command()?;
+        let mut cmd = self.studio_command(&limits, cgroup.as_ref())?;
         cmd.current_dir(self.workspace.src());
         if let Some(val) = env::var_os(RUNNER_DEBUG_ENVVAR) {
             cmd.env("DEBUG", val);
@@ -131,34 +196,198 @@ impl<'a> Studio<'a> {
         );
         cmd.env(BLDR_URL_ENVVAR, self.bldr_url);
         cmd.env(AUTH_TOKEN_ENVVAR, self.auth_token);
+        // The Studio passes these on to plan-build, which only exposes the ones the plan declares
+        for secret in self.workspace.secrets() {
+            cmd.env(secret_envvar(secret.get_name()), secret.get_value());
+        }
+        watchdog::new_process_group(&mut cmd);
 
         debug!("spawning studio build command");
         let mut child = cmd.spawn().map_err(|e| {
             Error::StudioBuild(self.workspace.studio().to_path_buf(), e)
         })?;
-        log_pipe.pipe(&mut child)?;
+        let pid = child.id();
+        let watchdog = Watchdog::start(pid, self.build_timeout(), self.cancel.clone());
+        let mut lockdown_err = None;
+        log_pipe.pipe_with(&mut child, |line| {
+            if !self.network_lockdown || !self.airlock_networking() || self.locked_down.get() ||
+                lockdown_err.is_some() || !line.contains(DOWNLOADS_COMPLETE_MARKER)
+            {
+                return;
+            }
+            match self.lockdown_network_namespace() {
+                Ok(()) => self.locked_down.set(true),
+                Err(err) => {
+                    // Letting the build go on with its network access would defeat the lockdown
+                    error!("Unable to drop network access of build, stopping it, err={}", err);
+                    watchdog::kill_group(pid);
+                    lockdown_err = Some(err);
+                }
+            }
+        })?;
         let exit_status = child.wait().map_err(|e| {
             Error::StudioBuild(self.workspace.studio().to_path_buf(), e)
         })?;
+        let stopped = watchdog.stop();
         debug!("completed studio build command, status={:?}", exit_status);
+        let oom_killed = match cgroup {
+            Some(ref cgroup) => cgroup.oom_kills() > 0,
+            None => {
+                self.runner == BuildRunner::Docker && limits.memory_mb.is_some() &&
+                    self.container_oom_killed()
+            }
+        };
+        if self.runner == BuildRunner::Docker {
+            // The container outlives the `docker` client if the client was killed
+            self.remove_container();
+        }
 
-        if self.networking.is_some() {
+        if self.airlock_networking() {
             self.destroy_network_namespace()?;
         } else {
             info!("Airlock networking is not configured, skipping network destruction");
         }
 
+        if let Some(err) = lockdown_err {
+            log_pipe.pipe_stdout(b"\n--- Unable to drop network access, build stopped ---\n")?;
+            return Err(err);
+        }
+
+        match stopped {
+            Some(Stopped::TimedOut) => {
+                let minutes = self.workspace.job.get_build_timeout();
+                log_pipe.pipe_stdout(
+                    format!("\n--- Build timed out after {} minutes ---\n", minutes)
+                        .as_bytes(),
+                )?;
+                return Err(Error::BuildTimeout(minutes));
+            }
+            Some(Stopped::Canceled) => {
+                log_pipe.pipe_stdout(b"\n--- Build canceled ---\n")?;
+                return Err(Error::JobCanceled);
+            }
+            None => (),
+        }
+
+        if oom_killed {
+            let mb = limits.memory_mb.unwrap_or(0);
+            log_pipe.pipe_stdout(
+                format!(
+                    "\n--- Build ran out of memory, exceeding its limit of {} MB ---\n",
+                    mb
+                ).as_bytes(),
+            )?;
+            return Err(Error::BuildOutOfMemory(mb));
+        }
+
         Ok(exit_status)
     }
 
-    fn studio_command(&self) -> Result<Command> {
-        if self.airlock_enabled {
+    /// Returns how long the build may run for, if the job is limited.
+    fn build_timeout(&self) -> Option<Duration> {
+        match self.workspace.job.get_build_timeout() {
+            0 => None,
+            minutes => Some(Duration::from_secs(minutes as u64 * 60)),
+        }
+    }
+
+    /// Whether the build's network access was dropped before it exited
+    pub fn locked_down(&self) -> bool {
+        self.locked_down.get()
+    }
+
+    /// Whether the build is run in a network namespace set up by airlock
+    fn airlock_networking(&self) -> bool {
+        self.runner == BuildRunner::Studio && self.networking.is_some()
+    }
+
+    /// Name of the container the build is run in by the Docker runner
+    fn container_name(&self) -> String {
+        format!("bldr-build-{}", self.workspace.job.get_id())
+    }
+
+    /// Creates the control group enforcing the job's limits on a build run by the Studio runner.
+    /// Builds run by the Docker runner are limited by their container instead.
+    fn create_cgroup(&self, limits: &Limits) -> Result<Option<Cgroup>> {
+        if limits.is_empty() || self.runner == BuildRunner::Docker {
+            return Ok(None);
+        }
+        if cfg!(not(target_os = "linux")) {
+            warn!("Resource limits are only enforced on Linux workers, running build unlimited");
+            return Ok(None);
+        }
+        let name = format!("job-{}", self.workspace.job.get_id());
+        Cgroup::create(self.cgroup_root, &name, limits).map(Some)
+    }
+
+    fn studio_command(&self, limits: &Limits, cgroup: Option<&Cgroup>) -> Result<Command> {
+        if self.runner == BuildRunner::Docker {
+            let mut cmd = Command::new("docker");
+            cmd.env_clear();
+            inherit_platform_env(&mut cmd);
+            for var in DOCKER_CLIENT_ENVVARS {
+                if let Some(val) = env::var_os(var) {
+                    cmd.env(var, val);
+                }
+            }
+            cmd.arg("run");
+            // A container which may be killed for running out of memory is kept until it's been
+            // inspected, then removed with the other containers of finished builds
+            if limits.memory_mb.is_none() {
+                cmd.arg("--rm");
+            }
+            cmd.arg("--name");
+            cmd.arg(self.container_name());
+            cmd.arg("--security-opt");
+            cmd.arg("no-new-privileges");
+            cmd.arg("--volume");
+            cmd.arg(format!("{}:/src", self.workspace.src().display()));
+            cmd.arg("--workdir");
+            cmd.arg("/src");
+            cmd.arg("--volume");
+            cmd.arg(format!(
+                "{}:/hab/cache/artifacts",
+                self.workspace.studio_artifacts().display()
+            ));
+            cmd.arg("--volume");
+            cmd.arg(format!(
+                "{}:/hab/cache/keys:ro",
+                self.workspace.keys().display()
+            ));
+            for var in DOCKER_ENVVARS {
+                cmd.arg("--env");
+                cmd.arg(var);
+            }
+            for secret in self.workspace.secrets() {
+                cmd.arg("--env");
+                cmd.arg(secret_envvar(secret.get_name()));
+            }
+            if let Some(mb) = limits.memory_mb {
+                cmd.arg("--memory");
+                cmd.arg(format!("{}m", mb));
+                // Builds aren't allowed to get around the limit by swapping
+                cmd.arg("--memory-swap");
+                cmd.arg(format!("{}m", mb));
+            }
+            if let Some(cpus) = limits.cpus() {
+                cmd.arg("--cpus");
+                cmd.arg(cpus);
+            }
+            cmd.arg(self.studio_image);
+
+            info!("Running Studio in a container of {}", self.studio_image);
+            Ok(cmd)
+        } else if self.airlock_enabled {
             let mut cmd = Command::new("airlock");
-            cmd.uid(studio_uid());
-            cmd.gid(studio_gid());
+            // The build joins its control group while it still has the worker's privileges
+            if let Some(cgroup) = cgroup {
+                cgroup.join(&mut cmd);
+            }
+            run_as_studio_user(&mut cmd);
             cmd.env_clear();
             cmd.env("HOME", &*STUDIO_HOME.lock().unwrap()); // Sets `$HOME` for build user
             cmd.env("USER", STUDIO_USER); // Sets `$USER` for build user
+            cmd.env("HAB_CACHE_KEY_PATH", self.workspace.keys()); // Sets key cache to job's
             cmd.arg("run");
             cmd.arg("--fs-root");
             cmd.arg(self.workspace.studio());
@@ -174,16 +403,73 @@ impl<'a> Studio<'a> {
             Ok(cmd)
         } else {
             let mut cmd = Command::new(&*STUDIO_PROGRAM);
+            if let Some(cgroup) = cgroup {
+                cgroup.join(&mut cmd);
+            }
             cmd.env_clear();
-            debug!("HAB_CACHE_KEY_PATH: {:?}", key_path());
-            cmd.env("NO_ARTIFACT_PATH", "true"); // Disables artifact cache mounting
-            cmd.env("HAB_CACHE_KEY_PATH", key_path()); // Sets key cache to build user's home
+            inherit_platform_env(&mut cmd);
+            debug!("HAB_CACHE_KEY_PATH: {:?}", self.workspace.keys());
+            // Mounts the workspace's artifact cache rather than the host's
+            cmd.env("ARTIFACT_PATH", self.workspace.studio_artifacts());
+            cmd.env("HAB_CACHE_KEY_PATH", self.workspace.keys()); // Sets key cache to job's
 
             info!("Airlock is not enabled, running uncontained Studio");
             Ok(cmd)
         }
     }
 
+    /// Whether the build's container was killed for going over its memory limit
+    fn container_oom_killed(&self) -> bool {
+        let mut cmd = Command::new("docker");
+        cmd.arg("inspect");
+        cmd.arg("--format");
+        cmd.arg("{{.State.OOMKilled}}");
+        cmd.arg(self.container_name());
+        cmd.stderr(Stdio::null());
+        debug!("spawning docker container inspect command, cmd={:?}", &cmd);
+        match cmd.output() {
+            Ok(ref output) if output.status.success() => {
+                String::from_utf8_lossy(&output.stdout).trim() == "true"
+            }
+            Ok(output) => {
+                warn!(
+                    "Unable to inspect container {}, status={}",
+                    self.container_name(),
+                    output.status
+                );
+                false
+            }
+            Err(err) => {
+                warn!(
+                    "Failed to inspect container {}, err={:?}",
+                    self.container_name(),
+                    err
+                );
+                false
+            }
+        }
+    }
+
+    /// Forcibly removes the build's container. It's normally removed by the `docker` client once
+    /// the build exits, unless it has a memory limit, so a container which is already gone isn't
+    /// an error.
+    fn remove_container(&self) {
+        let mut cmd = Command::new("docker");
+        cmd.arg("rm");
+        cmd.arg("--force");
+        cmd.arg(self.container_name());
+        cmd.stdout(Stdio::null());
+        cmd.stderr(Stdio::null());
+        debug!("spawning docker container removal command, cmd={:?}", &cmd);
+        if let Err(err) = cmd.status() {
+            warn!(
+                "Failed to remove container {}, err={:?}",
+                self.container_name(),
+                err
+            );
+        }
+    }
+
     fn create_network_namespace(&self) -> Result<()> {
         let mut cmd = Command::new("airlock");
         cmd.arg("netns");
@@ -217,6 +503,35 @@ impl<'a> Studio<'a> {
         }
     }
 
+    fn lockdown_network_namespace(&self) -> Result<()> {
+        let mut cmd = Command::new("airlock");
+        cmd.arg("netns");
+        cmd.arg("lockdown");
+        cmd.arg("--ns-dir");
+        cmd.arg(self.workspace.ns_dir());
+        cmd.stdout(Stdio::null());
+        debug!(
+            "building airlock networking lockdown command, cmd={:?}",
+            &cmd
+        );
+
+        debug!("spawning airlock networking lockdown command");
+        let exit_status = cmd.status().map_err(|e| {
+            Error::AirlockNetworking(self.workspace.ns_dir().to_path_buf(), e)
+        })?;
+        debug!(
+            "completed airlock networking lockdown command, status={:?}",
+            exit_status
+        );
+
+        if exit_status.success() {
+            info!("Dropped network access of build, ns_dir={}", self.workspace.ns_dir().display());
+            Ok(())
+        } else {
+            Err(Error::AirlockFailure(exit_status))
+        }
+    }
+
     fn destroy_network_namespace(&self) -> Result<()> {
         let mut cmd = Command::new("airlock");
         cmd.arg("netns");
@@ -264,17 +579,53 @@ pub fn set_studio_uid(uid: u32) {
     STUDIO_UID.store(uid as usize, Ordering::Relaxed);
 }
 
-pub fn key_path() -> PathBuf {
-    (&*STUDIO_HOME).lock().unwrap().join(format!(
-        ".{}",
-        fs::CACHE_KEY_PATH
-    ))
+/// Runs the command as the studio user. The user is switched to right before the command is
+/// exec'd, after the hooks registered on the command before, which still need the worker's
+/// privileges. Builds on Windows run as the worker's own user, as there's no airlock to contain
+/// them.
+#[cfg(unix)]
+fn run_as_studio_user(cmd: &mut Command) {
+    use std::io;
+    use std::ptr;
+    use libc;
+
+    let (uid, gid) = (studio_uid(), studio_gid());
+    cmd.before_exec(move || unsafe {
+        if libc::setgroups(0, ptr::null()) != 0 || libc::setgid(gid) != 0 ||
+            libc::setuid(uid) != 0
+        {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    });
+}
+
+#[cfg(windows)]
+fn run_as_studio_user(_cmd: &mut Command) {}
+
+/// Passes the worker's environment variables which the platform needs programs to have on to the
+/// command.
+#[cfg(unix)]
+fn inherit_platform_env(_cmd: &mut Command) {}
+
+#[cfg(windows)]
+fn inherit_platform_env(cmd: &mut Command) {
+    for var in WINDOWS_ENVVARS {
+        if let Some(val) = env::var_os(var) {
+            cmd.env(var, val);
+        }
+    }
+}
+
+/// Name of the environment variable a secret is handed to the Studio in
+fn secret_envvar(name: &str) -> String {
+    format!("{}{}", SECRET_ENVVAR_PREFIX, name)
 }
 
 /// Returns a path argument suitable to pass to a Studio build command.
 fn build_path(plan_path: &str) -> String {
     let mut parts: Vec<_> = plan_path.split("/").collect();
-    if parts.last().map_or("", |p| *p) == "plan.sh" {
+    if PLAN_FILE_NAMES.contains(&parts.last().map_or("", |p| *p)) {
         parts.pop();
     }
     if parts.last().map_or("", |p| *p) == "habitat" {
@@ -284,13 +635,90 @@ fn build_path(plan_path: &str) -> String {
     if parts.is_empty() {
         String::from(".")
     } else {
-        parts.join("/")
+        parts.join(&MAIN_SEPARATOR.to_string())
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::build_path;
+    use std::path::MAIN_SEPARATOR;
+
+    use super::*;
+    use bldr_core::job::Job;
+    use protobuf::RepeatedField;
+    use protocol::jobsrv;
+    use protocol::originsrv::OriginSecret;
+
+    fn docker_run(limits: &Limits) -> String {
+        let mut job = jobsrv::Job::new();
+        job.set_id(42);
+        let mut secret = OriginSecret::new();
+        secret.set_name("AWS_SECRET".to_string());
+        secret.set_value("shhh".to_string());
+        job.set_secrets(RepeatedField::from_vec(vec![secret]));
+        let workspace = Workspace::new("/data", Job::new(job));
+        let studio = Studio::new(
+            &workspace,
+            "https://bldr.habitat.sh",
+            "token",
+            false,
+            None,
+            BuildRunner::Docker,
+            "habitat/default-studio:latest",
+            false,
+            Path::new("/sys/fs/cgroup"),
+            Arc::new(AtomicBool::new(false)),
+        );
+        let cmd = studio.studio_command(limits, None).unwrap();
+        format!("{:?}", cmd)
+    }
+
+    /// A `--volume` argument as it's shown in the command's debug output
+    fn volume(host: PathBuf, container: &str) -> String {
+        format!(r#""--volume" {:?}"#, format!("{}:{}", host.display(), container))
+    }
+
+    #[test]
+    fn docker_run_args() {
+        let cmd = docker_run(&Limits {
+            memory_mb: None,
+            cpu_millicores: None,
+        });
+        assert!(cmd.contains(r#""docker" "run" "--rm" "--name" "bldr-build-42""#));
+        let root = Path::new("/data").join("42");
+        assert!(cmd.contains(&volume(root.join("src"), "/src")));
+        let artifacts = root.join("studio").join("hab").join("cache").join("artifacts");
+        assert!(cmd.contains(&volume(artifacts, "/hab/cache/artifacts")));
+        assert!(cmd.contains(&volume(root.join("keys"), "/hab/cache/keys:ro")));
+        for var in DOCKER_ENVVARS {
+            assert!(cmd.contains(&format!(r#""--env" "{}""#, var)));
+        }
+        assert!(cmd.contains(r#""--env" "HAB_STUDIO_SECRET_AWS_SECRET""#));
+        // Only names are passed, the values come from the environment of the `docker` client
+        assert!(!cmd.contains("shhh"));
+        assert!(!cmd.contains("--memory"));
+        assert!(!cmd.contains("--cpus"));
+        assert!(cmd.ends_with(r#""habitat/default-studio:latest""#));
+    }
+
+    #[test]
+    fn docker_run_args_with_limits() {
+        let cmd = docker_run(&Limits {
+            memory_mb: Some(2048),
+            cpu_millicores: Some(1500),
+        });
+        // Kept to be inspected for having been killed for running out of memory
+        assert!(!cmd.contains("--rm"));
+        assert!(cmd.contains(r#""--memory" "2048m" "--memory-swap" "2048m""#));
+        assert!(cmd.contains(r#""--cpus" "1.500""#));
+
+        let cmd = docker_run(&Limits {
+            memory_mb: None,
+            cpu_millicores: Some(500),
+        });
+        assert!(cmd.contains(r#""--rm""#));
+        assert!(cmd.contains(r#""--cpus" "0.500""#));
+    }
 
     #[test]
     fn build_path_with_plan_sh() {
@@ -307,10 +735,15 @@ mod tests {
         assert_eq!("haaay", build_path("haaay/plan.sh"));
     }
 
+    #[test]
+    fn build_path_with_habitat_plan_ps1() {
+        assert_eq!(".", build_path("habitat/plan.ps1"));
+    }
+
     #[test]
     fn build_path_with_subdir_habitat_plan_sh() {
         assert_eq!(
-            "components/yep",
+            format!("components{}yep", MAIN_SEPARATOR),
             build_path("components/yep/habitat/plan.sh")
         );
     }
@@ -13,17 +13,21 @@
 // limitations under the License.
 
 use std::path::Path;
+#[cfg(unix)]
 use std::process::Command;
 
 use serde_json::{self, Value as JsonValue};
+use url::{Host, Url};
 
 use error::{Error, Result};
+use runner::JobKind;
 use runner::docker::DockerExporterSpec;
 use runner::workspace::Workspace;
 
 // TODO fn: The horror... well, it's not that bad. There isn't a quick win for recursive chown'ing
 // a path, so we'll use the `chown` binary as provided by busybox and guarenteed by the Supervisor.
 // I'm wincing here right now, honest.
+#[cfg(unix)]
 pub fn chown_recursive<P: AsRef<Path>>(path: P, uid: u32, gid: u32) -> Result<()> {
     let mut cmd = Command::new("chown");
     cmd.arg("-R"); // Recursively apply ownership
@@ -40,6 +44,30 @@ pub fn chown_recursive<P: AsRef<Path>>(path: P, uid: u32, gid: u32) -> Result<()
     Ok(())
 }
 
+/// Builds on Windows run as the worker's own user, so the files they use already belong to it.
+#[cfg(windows)]
+pub fn chown_recursive<P: AsRef<Path>>(_path: P, _uid: u32, _gid: u32) -> Result<()> {
+    Ok(())
+}
+
+/// Whether secrets may be sent to or fetched from `url`, which they may be over HTTPS or to the
+/// worker's own host.
+pub fn is_secure_url(url: &str) -> bool {
+    let url = match Url::parse(url) {
+        Ok(url) => url,
+        Err(_) => return false,
+    };
+    if url.scheme() == "https" {
+        return true;
+    }
+    match url.host() {
+        Some(Host::Domain(domain)) => domain == "localhost",
+        Some(Host::Ipv4(addr)) => addr.is_loopback(),
+        Some(Host::Ipv6(addr)) => addr.is_loopback(),
+        None => false,
+    }
+}
+
 // TODO fn: Here's a sure sign you want more data integrity throughout the system. Most of this
 // validation should happen way, way upstream, but that's future refactoring work. Also, if the
 // payloads are known structures then serde derive and structs will make better work of things.
@@ -51,6 +79,11 @@ pub fn validate_integrations(workspace: &Workspace) -> Result<()> {
     {
         let prj_integrations = workspace.job.get_project_integrations();
         if prj_integrations.is_empty() {
+            if workspace.job.get_kind() == JobKind::DockerExport {
+                return Err(Error::InvalidIntegrations(format!(
+                    "missing Docker export options from project integrations"
+                )));
+            }
             // No project integrations, that's cool, we're done!
             return Ok(());
         }
@@ -250,3 +283,31 @@ fn get_optional_args(opts: &JsonValue, arg: String) -> Option<String> {
         None => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn https_urls_are_secure() {
+        assert!(is_secure_url("https://vault.example.com/v1/secret"));
+        assert!(is_secure_url("https://10.0.0.1:8200/v1/secret"));
+    }
+
+    #[test]
+    fn local_urls_are_secure() {
+        assert!(is_secure_url("http://localhost:8200/v1/secret"));
+        assert!(is_secure_url("http://127.0.0.1:8200/v1/secret"));
+        assert!(is_secure_url("http://127.1.2.3/v1/secret"));
+        assert!(is_secure_url("http://[::1]:8200/v1/secret"));
+    }
+
+    #[test]
+    fn plain_http_urls_are_not_secure() {
+        assert!(!is_secure_url("http://vault.example.com/v1/secret"));
+        assert!(!is_secure_url("http://10.0.0.1:8200/v1/secret"));
+        assert!(!is_secure_url("http://[fe80::1]/v1/secret"));
+        assert!(!is_secure_url("http://localhost.example.com/v1/secret"));
+        assert!(!is_secure_url("not a url"));
+    }
+}
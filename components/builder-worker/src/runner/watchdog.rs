@@ -0,0 +1,253 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Stops build processes which run for longer than they're allowed to, or whose job was canceled.
+
+use std::cmp;
+#[cfg(unix)]
+use std::io;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
+use std::process::Command;
+#[cfg(windows)]
+use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+#[cfg(unix)]
+use libc;
+
+/// Time a process group has to exit after being asked to terminate before it's killed
+const KILL_GRACE_SECS: u64 = 10;
+/// How often the watchdog checks whether the job was canceled
+const CANCEL_POLL_MS: u64 = 500;
+/// Process creation flag starting a new process group, from `WinBase.h`
+#[cfg(windows)]
+const CREATE_NEW_PROCESS_GROUP: u32 = 0x0000_0200;
+
+/// Run the command in a new process group led by the spawned child, so that the child and
+/// everything it starts can be signaled together.
+#[cfg(unix)]
+pub fn new_process_group(cmd: &mut Command) {
+    cmd.before_exec(|| if unsafe { libc::setpgid(0, 0) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    });
+}
+
+/// Run the command in a new process group, so that the child's process tree can be killed
+/// together.
+#[cfg(windows)]
+pub fn new_process_group(cmd: &mut Command) {
+    cmd.creation_flags(CREATE_NEW_PROCESS_GROUP);
+}
+
+/// Why a watched process group was terminated
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Stopped {
+    TimedOut,
+    Canceled,
+}
+
+/// Terminates a process group once a timeout has passed or its job was canceled, unless stopped
+/// first.
+pub struct Watchdog {
+    tx: Option<mpsc::Sender<()>>,
+    handle: Option<JoinHandle<Option<Stopped>>>,
+}
+
+impl Watchdog {
+    /// Start watching the process group led by `pid`. The group is sent `SIGTERM` once `timeout`
+    /// has passed or `cancel` is set, followed by `SIGKILL` if it hasn't been stopped shortly
+    /// after. On Windows the process tree is killed right away.
+    pub fn start(pid: u32, timeout: Option<Duration>, cancel: Arc<AtomicBool>) -> Self {
+        let (tx, rx) = mpsc::channel::<()>();
+        let handle = thread::Builder::new()
+            .name("build_watchdog".to_string())
+            .spawn(move || {
+                let reason = match wait_for_stop(&rx, timeout, &cancel) {
+                    Some(reason) => reason,
+                    None => return None,
+                };
+                match reason {
+                    Stopped::TimedOut => {
+                        warn!("Build process group {} timed out, terminating", pid)
+                    }
+                    Stopped::Canceled => {
+                        warn!("Build process group {} was canceled, terminating", pid)
+                    }
+                }
+                terminate_group(pid);
+                if rx.recv_timeout(Duration::from_secs(KILL_GRACE_SECS)) ==
+                    Err(RecvTimeoutError::Timeout)
+                {
+                    warn!("Build process group {} still running, killing", pid);
+                    kill_group(pid);
+                }
+                Some(reason)
+            })
+            .unwrap();
+
+        Watchdog {
+            tx: Some(tx),
+            handle: Some(handle),
+        }
+    }
+
+    /// Stop watching, which must happen once the process has been waited on. Returns why the
+    /// process group was terminated, if it was.
+    pub fn stop(mut self) -> Option<Stopped> {
+        self.tx.take();
+        match self.handle.take() {
+            Some(handle) => handle.join().unwrap_or(None),
+            None => None,
+        }
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        // Disconnecting wakes the watchdog thread, which then exits without signaling
+        self.tx.take();
+    }
+}
+
+/// Waits until the process group should be terminated, or returns `None` once the watchdog is
+/// stopped.
+fn wait_for_stop(
+    rx: &mpsc::Receiver<()>,
+    timeout: Option<Duration>,
+    cancel: &AtomicBool,
+) -> Option<Stopped> {
+    let started = Instant::now();
+    loop {
+        if cancel.load(Ordering::SeqCst) {
+            return Some(Stopped::Canceled);
+        }
+        let mut wait = Duration::from_millis(CANCEL_POLL_MS);
+        if let Some(timeout) = timeout {
+            let elapsed = started.elapsed();
+            if elapsed >= timeout {
+                return Some(Stopped::TimedOut);
+            }
+            wait = cmp::min(wait, timeout - elapsed);
+        }
+        if rx.recv_timeout(wait) != Err(RecvTimeoutError::Timeout) {
+            return None;
+        }
+    }
+}
+
+/// Kills the process group led by `pid` right away.
+#[cfg(unix)]
+pub fn kill_group(pid: u32) {
+    signal_group(pid, libc::SIGKILL);
+}
+
+/// Kills the process tree rooted at `pid` right away. Windows has no signals for processes to
+/// exit gracefully on, so the tree is killed outright.
+#[cfg(windows)]
+pub fn kill_group(pid: u32) {
+    let mut cmd = Command::new("taskkill");
+    cmd.arg("/T"); // Kill the whole tree
+    cmd.arg("/F");
+    cmd.arg("/PID");
+    cmd.arg(pid.to_string());
+    cmd.stdout(Stdio::null());
+    cmd.stderr(Stdio::null());
+    match cmd.status() {
+        Ok(status) if status.success() => (),
+        Ok(status) => warn!("Unable to kill build process tree {}, status={}", pid, status),
+        Err(err) => warn!("Unable to kill build process tree {}, err={}", pid, err),
+    }
+}
+
+#[cfg(unix)]
+fn terminate_group(pid: u32) {
+    signal_group(pid, libc::SIGTERM);
+}
+
+#[cfg(windows)]
+fn terminate_group(pid: u32) {
+    kill_group(pid);
+}
+
+#[cfg(unix)]
+fn signal_group(pid: u32, signal: libc::c_int) {
+    if unsafe { libc::kill(-(pid as libc::pid_t), signal) } != 0 {
+        warn!(
+            "Unable to signal build process group {}, err={}",
+            pid,
+            io::Error::last_os_error()
+        );
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    fn spawn(script: &str) -> ::std::process::Child {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c").arg(script);
+        new_process_group(&mut cmd);
+        cmd.spawn().unwrap()
+    }
+
+    #[test]
+    fn terminates_process_group_after_timeout() {
+        let start = Instant::now();
+        let mut child = spawn("sleep 60 & sleep 60");
+        let watchdog = Watchdog::start(
+            child.id(),
+            Some(Duration::from_millis(100)),
+            Arc::new(AtomicBool::new(false)),
+        );
+        let status = child.wait().unwrap();
+        assert_eq!(watchdog.stop(), Some(Stopped::TimedOut));
+        assert!(!status.success());
+        assert!(start.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn terminates_process_group_when_canceled() {
+        let start = Instant::now();
+        let cancel = Arc::new(AtomicBool::new(false));
+        let mut child = spawn("sleep 60 & sleep 60");
+        let watchdog = Watchdog::start(child.id(), None, cancel.clone());
+        cancel.store(true, Ordering::SeqCst);
+        let status = child.wait().unwrap();
+        assert_eq!(watchdog.stop(), Some(Stopped::Canceled));
+        assert!(!status.success());
+        assert!(start.elapsed() < Duration::from_secs(30));
+    }
+
+    #[test]
+    fn stopped_before_timeout() {
+        let mut child = spawn("true");
+        let watchdog = Watchdog::start(
+            child.id(),
+            Some(Duration::from_secs(60)),
+            Arc::new(AtomicBool::new(false)),
+        );
+        assert!(child.wait().unwrap().success());
+        assert_eq!(watchdog.stop(), None);
+    }
+}
@@ -17,31 +17,42 @@ use std::io::{self, Read};
 use std::path::{Path, PathBuf};
 
 use hab_core::package::{PackageArchive, PackageIdent};
+use protocol::originsrv::OriginSecret;
 
 use super::Job;
 use error::{Error, Result};
 
 pub struct Workspace {
     pub job: Job,
+    secrets: Vec<OriginSecret>,
     out: PathBuf,
     src: PathBuf,
     studio: PathBuf,
     ns_dir: PathBuf,
+    keys: PathBuf,
+    prefetch: PathBuf,
+    reproducibility: PathBuf,
     root: PathBuf,
 }
 
 impl Workspace {
-    pub fn new<T>(data_path: T, job: Job) -> Self
+    pub fn new<T>(data_path: T, mut job: Job) -> Self
     where
         T: AsRef<Path>,
     {
         let root = data_path.as_ref().join(job.get_id().to_string());
+        // Kept off the job so that they aren't sent back to the JobSrv with its updates
+        let secrets = job.take_secrets().into_vec();
         Workspace {
             job: job,
+            secrets: secrets,
             out: root.join("out"),
             src: root.join("src"),
             studio: root.join("studio"),
             ns_dir: root.join("airlock-ns"),
+            keys: root.join("keys"),
+            prefetch: root.join("prefetch"),
+            reproducibility: root.join("reproducibility"),
             root: root,
         }
     }
@@ -95,11 +106,39 @@ impl Workspace {
         &self.studio
     }
 
+    /// Artifact cache directory of the studio, which dependencies are installed from and
+    /// downloaded into
+    pub fn studio_artifacts(&self) -> PathBuf {
+        self.studio.join("hab").join("cache").join("artifacts")
+    }
+
     /// Directory containing the airlock namespace state for the build
     pub fn ns_dir(&self) -> &Path {
         &self.ns_dir
     }
 
+    /// Key cache directory holding the origin's secret key while the build needs it
+    pub fn keys(&self) -> &Path {
+        &self.keys
+    }
+
+    /// Directory the build's dependencies are downloaded into before they're added to the
+    /// artifact cache
+    pub fn prefetch(&self) -> &Path {
+        &self.prefetch
+    }
+
+    /// Directory the outputs of the first build are kept in while the build is repeated to verify
+    /// that it's reproducible
+    pub fn reproducibility(&self) -> &Path {
+        &self.reproducibility
+    }
+
+    /// Secrets of the job's origin, with their values decrypted
+    pub fn secrets(&self) -> &[OriginSecret] {
+        &self.secrets
+    }
+
     fn last_build_env(&self) -> PathBuf {
         self.out().join("last_build.env")
     }
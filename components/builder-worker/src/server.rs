@@ -12,32 +12,43 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::iter::FromIterator;
 use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
 
+use hab_core::os::process::Signal;
+use hab_core::os::signals::{self, SignalEvent};
+use hab_core::package::PackageIdent;
 use hab_core::users;
 use hab_net;
 use hab_net::socket::DEFAULT_CONTEXT;
-use protocol::{message, jobsrv};
+use protocol::{message, jobsrv, version};
+use protocol::net::{self, ErrCode};
 use zmq;
 
-use config::Config;
+use config::{BuildRunner, Config};
+use disk::DiskMgr;
 use error::{Error, Result};
 use feat;
 use heartbeat::{HeartbeatCli, HeartbeatMgr};
 use log_forwarder::LogForwarder;
-use runner::{studio, RunnerCli, RunnerMgr};
+use runner::{studio, RunnerCli, RunnerMgr, RunnerReply};
+use updater::{self, UpdateCli, UpdateMgr};
 
-enum State {
-    Ready,
-    Busy,
-}
+/// How long the log output of the last jobs and the final heartbeat are given to reach the job
+/// server before the worker exits or restarts
+const LEAVE_GRACE_SECS: u64 = 10;
+/// Polling timeout of the main loop, between checks for signals
+const SIGNAL_CHECK_MS: i64 = 1_000;
 
-impl Default for State {
-    fn default() -> State {
-        State::Ready
-    }
+/// What a draining worker does once its running jobs are done
+enum Drain {
+    /// Exit, such as for maintenance of its host
+    Exit,
+    /// Restart as a newer release
+    Restart(PackageIdent),
 }
 
 pub struct Server {
@@ -46,7 +57,12 @@ pub struct Server {
     fe_sock: zmq::Socket,
     hb_cli: HeartbeatCli,
     runner_cli: RunnerCli,
-    state: State,
+    update_cli: UpdateCli,
+    disk_mgr: DiskMgr,
+    /// Set once the worker stops taking jobs, to leave once the ones it's running are done
+    drain: Option<Drain>,
+    /// Ids of the jobs currently being run by this worker
+    jobs: HashSet<u64>,
     msg: zmq::Message,
     net_ident: Arc<String>,
 }
@@ -55,15 +71,19 @@ impl Server {
     pub fn new(config: Config) -> Result<Self> {
         let net_ident = hab_net::socket::srv_ident();
         let fe_sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::DEALER)?;
-        let hb_cli = HeartbeatCli::new(net_ident.clone());
+        let hb_cli = HeartbeatCli::new(net_ident.clone(), config.labels.clone(), config.capacity);
         let runner_cli = RunnerCli::new();
+        let disk_mgr = DiskMgr::new(&config);
         fe_sock.set_identity(net_ident.as_bytes())?;
         Ok(Server {
             config: Arc::new(config),
             fe_sock: fe_sock,
             hb_cli: hb_cli,
             runner_cli: runner_cli,
-            state: State::default(),
+            update_cli: UpdateCli::new(),
+            disk_mgr: disk_mgr,
+            drain: None,
+            jobs: HashSet::new(),
             msg: zmq::Message::new()?,
             net_ident: Arc::new(net_ident),
         })
@@ -91,12 +111,32 @@ impl Server {
             );
             return Err(Error::NoNetworkInterfaceError);
         }
+        if cfg!(not(target_os = "linux")) && self.config.airlock_enabled {
+            error!(
+                "ERROR: 'airlock_enabled' was set, but airlock is only available on Linux \
+                   workers."
+            );
+            return Err(Error::AirlockUnsupported);
+        }
+        if self.config.network_lockdown &&
+            (self.config.network_interface.is_none() ||
+                 self.config.build_runner != BuildRunner::Studio)
+        {
+            warn!(
+                "'network_lockdown' requires airlock networking of studio builds, builds will \
+                   keep their network access"
+            );
+        }
         init_users()?;
         self.enable_features_from_config();
+        signals::init();
 
         HeartbeatMgr::start(&self.config, (&*self.net_ident).clone())?;
         RunnerMgr::start(self.config.clone(), self.net_ident.clone())?;
         LogForwarder::start(&self.config)?;
+        if UpdateMgr::start(&self.config)?.is_some() {
+            self.update_cli.connect()?;
+        }
         self.hb_cli.connect()?;
         self.runner_cli.connect()?;
         for (_, queue, _) in self.config.jobsrv_addrs() {
@@ -106,27 +146,66 @@ impl Server {
 
         let mut fe_msg = false;
         let mut runner_msg = false;
+        let mut update_msg = false;
         info!("builder-worker is ready to go.");
         loop {
             {
                 let mut items = [
                     self.fe_sock.as_poll_item(1),
                     self.runner_cli.as_poll_item(1),
+                    self.update_cli.as_poll_item(1),
                 ];
-                zmq::poll(&mut items, -1)?;
+                zmq::poll(&mut items, SIGNAL_CHECK_MS)?;
                 if items[0].get_revents() & zmq::POLLIN > 0 {
                     fe_msg = true;
                 }
                 if items[1].get_revents() & zmq::POLLIN > 0 {
                     runner_msg = true;
                 }
+                if items[2].get_revents() & zmq::POLLIN > 0 {
+                    update_msg = true;
+                }
+            }
+            match signals::check_for_signal() {
+                Some(SignalEvent::Shutdown) => {
+                    warn!("Shutting down with {} running jobs", self.jobs.len());
+                    self.hb_cli.leave()?;
+                    return Ok(());
+                }
+                Some(SignalEvent::Passthrough(Signal::USR1)) => {
+                    info!("Draining, exiting once {} running jobs are done", self.jobs.len());
+                    self.start_drain(Drain::Exit)?;
+                }
+                _ => (),
+            }
+            if update_msg {
+                let ident = self.update_cli.recv()?;
+                info!(
+                    "Release {} installed, restarting once {} running jobs are done",
+                    ident,
+                    self.jobs.len()
+                );
+                // A drain to exit isn't turned into a restart
+                if self.drain.is_none() {
+                    self.start_drain(Drain::Restart(ident))?;
+                }
+                update_msg = false;
             }
             if runner_msg {
-                {
-                    let reply = self.runner_cli.recv_complete()?;
+                let completed = {
+                    let (kind, reply) = self.runner_cli.recv()?;
                     self.fe_sock.send(reply, 0)?;
+                    match kind {
+                        RunnerReply::Complete => {
+                            Some(message::decode::<jobsrv::Job>(reply)?.get_id())
+                        }
+                        RunnerReply::Ack => None,
+                    }
+                };
+                if let Some(job_id) = completed {
+                    self.jobs.remove(&job_id);
+                    self.update_heartbeat()?;
                 }
-                self.set_ready()?;
                 runner_msg = false;
             }
             if fe_msg {
@@ -136,63 +215,98 @@ impl Server {
                 let wc = message::decode::<jobsrv::WorkerCommand>(&self.msg)?;
                 self.fe_sock.recv(&mut self.msg, 0)?; // Receive Job msg
 
-                match self.state {
-                    State::Ready => {
-                        match wc.get_op() {
-                            jobsrv::WorkerOperation::StartJob => self.start_job()?,
-                            jobsrv::WorkerOperation::CancelJob => {
-                                warn!("Received unexpected Cancel for Ready worker")
-                            }
-                        }
+                if let Err(err) = version::negotiate(wc.get_protocol_version()) {
+                    error!("Rejecting command from JobSrv, {}", err);
+                    if wc.get_op() == jobsrv::WorkerOperation::StartJob {
+                        self.reject_job(None)?;
                     }
-                    State::Busy => {
-                        match wc.get_op() {
-                            jobsrv::WorkerOperation::StartJob => self.reject_job()?,
-                            jobsrv::WorkerOperation::CancelJob => self.cancel_job()?,
+                    fe_msg = false;
+                    continue;
+                }
+
+                match wc.get_op() {
+                    jobsrv::WorkerOperation::StartJob => {
+                        if self.drain.is_some() ||
+                            (self.jobs.len() as u32) >= self.config.capacity
+                        {
+                            self.reject_job(None)?
+                        } else if let Err(err) = self.disk_mgr.check(&self.jobs) {
+                            error!("Rejecting job, {}", err);
+                            let err = net::err(ErrCode::LOW_DISK_SPACE, "wk:srv:1");
+                            self.reject_job(Some(err))?
+                        } else {
+                            self.start_job()?
                         }
                     }
+                    jobsrv::WorkerOperation::CancelJob => self.cancel_job()?,
                 }
                 fe_msg = false;
             }
+            if self.drain.is_some() && self.jobs.is_empty() {
+                return self.leave();
+            }
         }
     }
 
+    /// Hand a job to the runner. The runner's acknowledgement is relayed to JobSrv once it's
+    /// received.
     fn start_job(&mut self) -> Result<()> {
+        let job = message::decode::<jobsrv::Job>(&self.msg)?;
+        self.jobs.insert(job.get_id());
         self.runner_cli.start_job(&self.msg)?;
-        {
-            let reply = self.runner_cli.recv_ack()?;
-            self.fe_sock.send(reply, 0)?;
-        }
-        self.set_busy()?;
-        Ok(())
+        self.update_heartbeat()
     }
 
     fn cancel_job(&mut self) -> Result<()> {
-        self.runner_cli.cancel_job(&self.msg)?;
-        {
-            let reply = self.runner_cli.recv_ack()?;
-            self.fe_sock.send(reply, 0)?;
+        // Cancelations target a single job; make sure we don't interrupt a different job that
+        // was dispatched to us after the cancel was requested.
+        let mut job = message::decode::<jobsrv::Job>(&self.msg)?;
+        if !self.jobs.contains(&job.get_id()) {
+            warn!(
+                "Received Cancel for job {} which is not running on this worker",
+                job.get_id()
+            );
+            job.set_state(jobsrv::JobState::CancelComplete);
+            self.fe_sock.send(&message::encode(&job)?, 0)?;
+            return Ok(());
         }
-        Ok(())
+        self.runner_cli.cancel_job(&self.msg)
     }
 
-    fn reject_job(&mut self) -> Result<()> {
+    /// Hand a job back to JobSrv to be dispatched again, along with why it was rejected if that's
+    /// anything other than the worker being busy.
+    fn reject_job(&mut self, err: Option<net::NetError>) -> Result<()> {
         let mut reply = message::decode::<jobsrv::Job>(&self.msg)?;
         reply.set_state(jobsrv::JobState::Rejected);
+        if let Some(err) = err {
+            reply.set_error(err.into());
+        }
         self.fe_sock.send(&message::encode(&reply)?, 0)?;
         Ok(())
     }
 
-    fn set_busy(&mut self) -> Result<()> {
-        self.hb_cli.set_busy()?;
-        self.state = State::Busy;
-        Ok(())
+    /// Stop taking jobs. Jobs dispatched before JobSrv sees the worker draining are rejected.
+    fn start_drain(&mut self, drain: Drain) -> Result<()> {
+        self.drain = Some(drain);
+        self.hb_cli.drain()
     }
 
-    fn set_ready(&mut self) -> Result<()> {
-        self.hb_cli.set_ready()?;
-        self.state = State::Ready;
-        Ok(())
+    /// Deregister from JobSrv once the worker is drained, then exit or restart as a newer release.
+    fn leave(&mut self) -> Result<()> {
+        self.hb_cli.leave()?;
+        thread::sleep(Duration::from_secs(LEAVE_GRACE_SECS));
+        match self.drain.take() {
+            Some(Drain::Restart(ident)) => Err(updater::restart(&ident)),
+            _ => {
+                info!("builder-worker drained, exiting");
+                Ok(())
+            }
+        }
+    }
+
+    fn update_heartbeat(&mut self) -> Result<()> {
+        let job_ids = self.jobs.iter().cloned().collect();
+        self.hb_cli.set_jobs(job_ids)
     }
 
     fn enable_features_from_config(&self) {
@@ -218,6 +332,7 @@ pub fn run(config: Config) -> Result<()> {
     Server::new(config)?.run()
 }
 
+#[cfg(unix)]
 fn init_users() -> Result<()> {
     let uid = users::get_uid_by_name(studio::STUDIO_USER).ok_or(
         Error::NoStudioUser,
@@ -233,3 +348,11 @@ fn init_users() -> Result<()> {
     studio::set_studio_gid(gid);
     Ok(())
 }
+
+/// Builds on Windows run as the worker's own user, whose home holds the Studio's caches.
+#[cfg(windows)]
+fn init_users() -> Result<()> {
+    let mut home = studio::STUDIO_HOME.lock().unwrap();
+    *home = users::get_home_for_current_user().ok_or(Error::NoStudioUser)?;
+    Ok(())
+}
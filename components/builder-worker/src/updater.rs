@@ -0,0 +1,252 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keeps the worker up to date with the releases promoted to a channel.
+//!
+//! The `UpdateMgr` periodically looks for a newer release of the worker's package in the
+//! configured channel. Once it finds one it installs it and hands its identifier to the server,
+//! which stops taking jobs, waits for the ones it's running to finish and then replaces itself
+//! with the new release. The new release reconnects to the job servers like any other worker
+//! starting up.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+use std::sync::mpsc;
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use depot_client;
+use hab_core::fs;
+use hab_core::package::PackageIdent;
+use hab_net::socket::DEFAULT_CONTEXT;
+use zmq;
+
+use config::Config;
+use error::{Error, Result};
+use {PRODUCT, VERSION};
+
+/// In-memory zmq address of the UpdateMgr
+const INPROC_ADDR: &'static str = "inproc://updater";
+/// Name of the worker's program in its package
+const WORKER_PROGRAM: &'static str = "bldr-worker";
+
+/// Client for receiving the releases installed by the `UpdateMgr`
+pub struct UpdateCli {
+    sock: zmq::Socket,
+    msg: zmq::Message,
+}
+
+impl UpdateCli {
+    pub fn new() -> Self {
+        let sock = (**DEFAULT_CONTEXT).as_mut().socket(zmq::PULL).unwrap();
+        UpdateCli {
+            sock: sock,
+            msg: zmq::Message::new().unwrap(),
+        }
+    }
+
+    pub fn as_poll_item(&self, events: i16) -> zmq::PollItem {
+        self.sock.as_poll_item(events)
+    }
+
+    /// Connect to the `UpdateMgr`
+    pub fn connect(&mut self) -> Result<()> {
+        self.sock.connect(INPROC_ADDR)?;
+        Ok(())
+    }
+
+    /// Receive the identifier of an installed release
+    pub fn recv(&mut self) -> Result<PackageIdent> {
+        self.sock.recv(&mut self.msg, 0)?;
+        let ident = PackageIdent::from_str(self.msg.as_str().unwrap_or(""))?;
+        Ok(ident)
+    }
+}
+
+pub struct UpdateMgr {
+    sock: zmq::Socket,
+    depot_cli: depot_client::Client,
+    channel: String,
+    interval: Duration,
+    current: PackageIdent,
+}
+
+impl UpdateMgr {
+    /// Start the UpdateMgr, unless the worker has no update channel or doesn't run from an
+    /// installed package, which has nothing to be updated.
+    pub fn start(config: &Config) -> Result<Option<JoinHandle<()>>> {
+        let channel = match config.update_channel {
+            Some(ref channel) => channel.clone(),
+            None => return Ok(None),
+        };
+        let current = match env::current_exe().ok().and_then(|exe| installed_ident(&exe)) {
+            Some(ident) => ident,
+            None => {
+                warn!("Not running from an installed package, self-update is disabled");
+                return Ok(None);
+            }
+        };
+        let depot_cli = depot_client::Client::new(&config.bldr_url, PRODUCT, VERSION, None)
+            .map_err(Error::UpdateCheck)?;
+        let mut updater = UpdateMgr {
+            sock: (**DEFAULT_CONTEXT).as_mut().socket(zmq::PUSH)?,
+            depot_cli: depot_cli,
+            channel: channel,
+            interval: Duration::from_secs(config.update_interval),
+            current: current,
+        };
+
+        let (tx, rx) = mpsc::sync_channel(0);
+        let handle = thread::Builder::new()
+            .name("updater".to_string())
+            .spawn(move || { updater.run(tx).unwrap(); })
+            .unwrap();
+        match rx.recv() {
+            Ok(()) => Ok(Some(handle)),
+            Err(e) => panic!("updater thread startup error, err={}", e),
+        }
+    }
+
+    fn run(&mut self, rz: mpsc::SyncSender<()>) -> Result<()> {
+        self.sock.bind(INPROC_ADDR)?;
+        rz.send(()).unwrap();
+        info!(
+            "Watching channel {} for releases newer than {}",
+            self.channel,
+            self.current
+        );
+        loop {
+            thread::sleep(self.interval);
+            let latest = match self.latest() {
+                Ok(Some(latest)) => latest,
+                Ok(None) => continue,
+                Err(err) => {
+                    warn!("Unable to check for a newer release, err={}", err);
+                    continue;
+                }
+            };
+            info!("Installing newer release, {}", latest);
+            if let Err(err) = self.install(&latest) {
+                warn!("Unable to install release {}, err={}", latest, err);
+                continue;
+            }
+            self.sock.send_str(&latest.to_string(), 0)?;
+            // The server replaces the worker with the new release once its jobs are done
+            return Ok(());
+        }
+    }
+
+    /// Returns the latest release in the channel if it's newer than the running one
+    fn latest(&self) -> Result<Option<PackageIdent>> {
+        let ident = PackageIdent::new(
+            self.current.origin.clone(),
+            self.current.name.clone(),
+            None,
+            None,
+        );
+        let package = self.depot_cli
+            .show_package(&ident, Some(&self.channel), None)
+            .map_err(Error::UpdateCheck)?;
+        let latest: PackageIdent = package.into();
+        if latest > self.current {
+            Ok(Some(latest))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn install(&self, ident: &PackageIdent) -> Result<()> {
+        let hab = fs::find_command("hab").unwrap_or(PathBuf::from("hab"));
+        let status = Command::new(hab)
+            .arg("pkg")
+            .arg("install")
+            .arg("--channel")
+            .arg(&self.channel)
+            .arg(ident.to_string())
+            .status()
+            .map_err(|e| Error::UpdateInstall(ident.to_string(), e))?;
+        if !status.success() {
+            return Err(Error::UpdateInstallFailure(ident.to_string(), status));
+        }
+        Ok(())
+    }
+}
+
+/// Replaces the running worker with an installed release, keeping its arguments. Only returns
+/// if the release couldn't be started.
+pub fn restart(ident: &PackageIdent) -> Error {
+    let program = fs::pkg_install_path(ident, None::<&Path>)
+        .join("bin")
+        .join(WORKER_PROGRAM);
+    info!("Restarting as {}", program.display());
+    let mut cmd = Command::new(&program);
+    cmd.args(env::args_os().skip(1));
+    replace_process(cmd, program)
+}
+
+#[cfg(unix)]
+fn replace_process(mut cmd: Command, program: PathBuf) -> Error {
+    use std::os::unix::process::CommandExt;
+    Error::UpdateRestart(program, cmd.exec())
+}
+
+#[cfg(windows)]
+fn replace_process(mut cmd: Command, program: PathBuf) -> Error {
+    use std::process;
+    match cmd.spawn() {
+        Ok(_) => process::exit(0),
+        Err(err) => Error::UpdateRestart(program, err),
+    }
+}
+
+/// The identifier of the package `exe` was installed from, if it was.
+fn installed_ident(exe: &Path) -> Option<PackageIdent> {
+    let relative = match exe.strip_prefix(fs::pkg_root_path(None::<&Path>)) {
+        Ok(relative) => relative,
+        Err(_) => return None,
+    };
+    let parts: Vec<&str> = relative
+        .components()
+        .take(4)
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect();
+    if parts.len() != 4 {
+        return None;
+    }
+    Some(PackageIdent::new(
+        parts[0],
+        parts[1],
+        Some(parts[2]),
+        Some(parts[3]),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ident_of_installed_program() {
+        let exe = fs::pkg_root_path(None::<&Path>).join(
+            "core/builder-worker/6349/20170922152301/bin/bldr-worker",
+        );
+        assert_eq!(
+            installed_ident(&exe).unwrap().to_string(),
+            "core/builder-worker/6349/20170922152301"
+        );
+        assert!(installed_ident(Path::new("/usr/local/bin/bldr-worker")).is_none());
+    }
+}
@@ -28,6 +28,9 @@
 //!
 //! This would install the `3.0.1` version of redis.
 //!
+//! With `HAB_OFFLINE=true`, packages and their dependencies are only installed from the local
+//! artifact cache and Builder is never contacted, which is meant for sites without network access.
+//!
 //! # Internals
 //!
 //! * Download the artifact
@@ -58,6 +61,8 @@ use retry::retry;
 
 pub const RETRIES: u64 = 5;
 pub const RETRY_WAIT: u64 = 3000;
+/// Installs packages from the local artifact cache only when set to `true`
+pub const OFFLINE_ENVVAR: &'static str = "HAB_OFFLINE";
 
 /// Represents a locally-available `.hart` file for package
 /// installation purposes only.
@@ -170,6 +175,9 @@ impl AsRef<PackageIdent> for InstallSource {
 /// In either case, however, any dependencies of will be retrieved
 /// from Builder (if they're not already cached locally).
 ///
+/// Builder isn't contacted at all when `OFFLINE_ENVVAR` is set, in
+/// which case everything has to be installed or cached already.
+///
 /// At the end of this function, the specified package and all its
 /// dependencies will be installed on the system.
 
@@ -223,6 +231,8 @@ where
 
 struct InstallTask<'a> {
     depot_client: Client,
+    /// Whether to install from the local artifact cache only
+    offline: bool,
     fs_root_path: &'a Path,
     /// The path to the local artifact cache (e.g., /hab/cache/artifacts)
     artifact_cache_path: &'a Path,
@@ -240,6 +250,7 @@ impl<'a> InstallTask<'a> {
     ) -> Result<Self> {
         Ok(InstallTask {
             depot_client: Client::new(url, product, version, Some(fs_root_path))?,
+            offline: is_offline(),
             fs_root_path: fs_root_path,
             artifact_cache_path: artifact_cache_path,
             key_cache_path: key_cache_path,
@@ -275,11 +286,20 @@ impl<'a> InstallTask<'a> {
         } else {
             ui.begin(format!("Installing {}", &ident))?;
         }
+        if self.offline {
+            ui.status(Status::Using, "installed packages and cached artifacts only (offline)")?;
+        }
 
         // The "target_ident" will be the fully-qualified identifier
         // of the package we will ultimately install, once we
         // determine if we need to get a more recent version or not.
-        let target_ident = if !ident.fully_qualified() {
+        let target_ident = if self.offline {
+            if ident.fully_qualified() {
+                ident
+            } else {
+                self.latest_local_pkg_ident_for(&ident)?
+            }
+        } else if !ident.fully_qualified() {
             match self.fetch_latest_pkg_ident_for(&ident, channel, token) {
                 Ok(latest_ident) => latest_ident,
                 Err(Error::DepotClient(APIError(StatusCode::NotFound, _))) => {
@@ -414,6 +434,15 @@ impl<'a> InstallTask<'a> {
         ident: &PackageIdent,
         token: Option<&str>,
     ) -> Result<PackageInstall> {
+        if self.offline {
+            let missing = self.missing_artifacts(ident)?;
+            if !missing.is_empty() {
+                return Err(Error::OfflineArtifactsMissing(
+                    self.artifact_cache_path.display().to_string(),
+                    missing,
+                ));
+            }
+        }
         // TODO (CM): rename artifact to archive
         let mut artifact = self.get_cached_artifact(ui, ident, token)?;
 
@@ -478,10 +507,14 @@ impl<'a> InstallTask<'a> {
         token: Option<&str>,
     ) -> Result<PackageArchive> {
         if self.is_artifact_cached(&ident)? {
-            debug!(
-                "Found {} in artifact cache, skipping remote download",
-                ident
-            );
+            if !self.offline {
+                self.refresh_cached_artifact(ui, ident, token);
+            }
+        } else if self.offline {
+            return Err(Error::OfflineArtifactsMissing(
+                self.artifact_cache_path.display().to_string(),
+                vec![fully_qualified_archive_name(ident)?],
+            ));
         } else {
             if retry(
                 RETRIES,
@@ -531,6 +564,71 @@ impl<'a> InstallTask<'a> {
         Ok(self.artifact_cache_path.join(name))
     }
 
+    /// The latest release satisfying the identifier among the installed packages and the cached
+    /// artifacts, for when Builder can't be asked.
+    fn latest_local_pkg_ident_for(&self, ident: &PackageIdent) -> Result<PackageIdent> {
+        let mut candidates = Vec::new();
+        if let Some(package) = self.installed_package(ident) {
+            candidates.push(package.ident);
+        }
+        if let Ok(entries) = fs::read_dir(self.artifact_cache_path) {
+            for entry in entries.filter_map(|entry| entry.ok()) {
+                let path = entry.path();
+                if path.extension().map_or(true, |ext| ext != "hart") {
+                    continue;
+                }
+                let mut archive = PackageArchive::new(path);
+                let cached = match archive.ident() {
+                    Ok(cached) => cached,
+                    Err(_) => continue,
+                };
+                if cached.satisfies(ident) &&
+                    archive.target().map_or(false, |target| target.validate().is_ok())
+                {
+                    candidates.push(cached);
+                }
+            }
+        }
+        candidates.into_iter().max().ok_or(
+            Error::OfflinePackageNotFound(
+                ident.to_string(),
+            ),
+        )
+    }
+
+    /// The file names of the artifacts an offline install of the package would need, which are
+    /// neither installed nor cached. The dependencies of a package whose own artifact is missing
+    /// can't be known.
+    fn missing_artifacts(&self, ident: &PackageIdent) -> Result<Vec<String>> {
+        if self.installed_package(ident).is_some() {
+            return Ok(Vec::new());
+        }
+        if !self.is_artifact_cached(ident)? {
+            return Ok(vec![fully_qualified_archive_name(ident)?]);
+        }
+        let mut artifact = PackageArchive::new(self.cached_artifact_path(ident)?);
+        let mut missing = Vec::new();
+        match artifact.package_type()? {
+            PackageType::Standalone => {
+                for dependency in artifact.tdeps()?.iter() {
+                    if self.installed_package(dependency).is_none() &&
+                        !self.is_artifact_cached(dependency)?
+                    {
+                        missing.push(fully_qualified_archive_name(dependency)?);
+                    }
+                }
+            }
+            PackageType::Composite => {
+                for service in artifact.resolved_services()? {
+                    missing.extend(self.missing_artifacts(&service)?);
+                }
+            }
+        }
+        missing.sort();
+        missing.dedup();
+        Ok(missing)
+    }
+
     fn fetch_latest_pkg_ident_for(
         &self,
         ident: &PackageIdent,
@@ -548,6 +646,9 @@ impl<'a> InstallTask<'a> {
     /// the artifact is cached locally.
     fn fetch_artifact(&self, ui: &mut UI, ident: &PackageIdent, token: Option<&str>) -> Result<()> {
         ui.status(Status::Downloading, ident)?;
+        if self.fetch_artifact_delta(ui, ident, token) {
+            return Ok(());
+        }
         match self.depot_client.fetch_package(
             ident,
             token,
@@ -566,6 +667,75 @@ impl<'a> InstallTask<'a> {
         }
     }
 
+    /// Checks the cached artifact against the depot's by its checksum, and downloads the depot's
+    /// one only if they differ. The cached artifact is kept as is when the depot can't be asked.
+    fn refresh_cached_artifact(&self, ui: &mut UI, ident: &PackageIdent, token: Option<&str>) {
+        let cached = match self.cached_artifact_path(ident) {
+            Ok(path) => path,
+            Err(_) => return,
+        };
+        match self.depot_client.fetch_package_unless_cached(
+            ident,
+            token,
+            &cached,
+            self.artifact_cache_path,
+            ui.progress(),
+        ) {
+            Ok(None) => {
+                debug!(
+                    "Found {} in artifact cache, skipping remote download",
+                    ident
+                )
+            }
+            Ok(Some(_)) => debug!("Replaced {} in artifact cache with the depot's", ident),
+            Err(err) => {
+                debug!(
+                    "Unable to check {} in artifact cache against the depot, using it as is, \
+                     err={}",
+                    ident,
+                    err
+                )
+            }
+        }
+    }
+
+    /// Puts the artifact together from the cached artifact of the installed release of the
+    /// package and a delta downloaded from the depot, which saves downloading the whole artifact
+    /// again. Returns whether the artifact is now cached.
+    fn fetch_artifact_delta(&self, ui: &mut UI, ident: &PackageIdent, token: Option<&str>) -> bool {
+        let installed = PackageIdent::new(ident.origin.clone(), ident.name.clone(), None, None);
+        let from = match self.installed_package(&installed) {
+            Some(package) => package.ident,
+            None => return false,
+        };
+        let from_path = match self.cached_artifact_path(&from) {
+            Ok(path) => path,
+            Err(_) => return false,
+        };
+        if &from == ident || !from_path.is_file() {
+            return false;
+        }
+        match self.depot_client.fetch_package_delta(
+            ident,
+            &from,
+            &from_path,
+            token,
+            self.artifact_cache_path,
+            ui.progress(),
+        ) {
+            Ok(_) => true,
+            Err(err) => {
+                debug!(
+                    "Unable to fetch {} as a delta from {}, err={}",
+                    ident,
+                    from,
+                    err
+                );
+                false
+            }
+        }
+    }
+
     fn fetch_origin_key(&self, ui: &mut UI, name_with_rev: &str) -> Result<()> {
         ui.status(
             Status::Downloading,
@@ -621,6 +791,9 @@ impl<'a> InstallTask<'a> {
 
         let nwr = artifact::artifact_signer(&artifact.path)?;
         if let Err(_) = SigKeyPair::get_public_key_path(&nwr, self.key_cache_path) {
+            if self.offline {
+                return Err(Error::OfflineOriginKeyNotFound(nwr));
+            }
             self.fetch_origin_key(ui, &nwr)?;
         }
 
@@ -630,6 +803,13 @@ impl<'a> InstallTask<'a> {
     }
 }
 
+/// Whether packages are to be installed from the local artifact cache only
+fn is_offline() -> bool {
+    env::var(OFFLINE_ENVVAR)
+        .map(|val| val == "true" || val == "1")
+        .unwrap_or(false)
+}
+
 /// Adapter function wrapping `PackageIdent::archive_name` that
 /// returns an error if the identifier is not fully-qualified
 /// (only fully-qualified identifiers can yield an archive name).
@@ -640,3 +820,201 @@ fn fully_qualified_archive_name(ident: &PackageIdent) -> Result<String> {
         ),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs::{self, File};
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use hcore::fs::pkg_install_path;
+    use hcore::package::{PackageIdent, PackageTarget};
+    use tempdir::TempDir;
+
+    use super::*;
+
+    fn fixtures() -> PathBuf {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("fixtures")
+    }
+
+    /// Artifact of a release of `offline/standalone`, which depends on `offline/dep`, built for
+    /// the system's own target or for a foreign one
+    fn standalone_fixture(version: &str, release: &str, native: bool) -> PathBuf {
+        let platform = if cfg!(windows) == native {
+            "windows"
+        } else {
+            "linux"
+        };
+        fixtures().join(format!(
+            "offline-standalone-{}-{}-x86_64-{}.hart",
+            version,
+            release,
+            platform
+        ))
+    }
+
+    /// Artifact of `offline/composite`, whose services are `offline/standalone` and
+    /// `offline/other`
+    fn composite_fixture() -> PathBuf {
+        fixtures().join("offline-composite-1.0.0-20170101000000-x86_64-linux.hart")
+    }
+
+    fn ident(ident: &str) -> PackageIdent {
+        PackageIdent::from_str(ident).unwrap()
+    }
+
+    /// Copies an artifact into the cache under the file name the install looks it up by
+    fn cache(cache_path: &Path, artifact: &Path, ident: &PackageIdent) {
+        fs::copy(artifact, cache_path.join(ident.archive_name().unwrap())).unwrap();
+    }
+
+    fn install(fs_root_path: &Path, ident: &PackageIdent) {
+        let path = pkg_install_path(ident, Some(fs_root_path));
+        fs::create_dir_all(&path).unwrap();
+        let mut target = File::create(path.join("TARGET")).unwrap();
+        write!(target, "{}", PackageTarget::default()).unwrap();
+    }
+
+    fn task<'a>(fs_root_path: &'a Path, artifact_cache_path: &'a Path) -> InstallTask<'a> {
+        InstallTask::new(
+            "https://bldr.habitat.sh",
+            "hab-common-test",
+            "0.0.0",
+            fs_root_path,
+            artifact_cache_path,
+            fs_root_path,
+        ).unwrap()
+    }
+
+    fn is_offline_not_found(result: Result<PackageIdent>) -> bool {
+        match result {
+            Err(Error::OfflinePackageNotFound(_)) => true,
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn latest_local_pkg_ident_for_cached_artifacts() {
+        let fs_root = TempDir::new("fs-root").unwrap();
+        let cache_dir = TempDir::new("artifact-cache").unwrap();
+        let cache_path = cache_dir.path();
+        let native = standalone_fixture("1.0.0", "20170101000000", true);
+        fs::copy(&native, cache_path.join(native.file_name().unwrap())).unwrap();
+        // Newer, but built for another target
+        let foreign = standalone_fixture("2.0.0", "20170201000000", false);
+        fs::copy(&foreign, cache_path.join(foreign.file_name().unwrap())).unwrap();
+        // Newer, but not an artifact
+        let partial = standalone_fixture("2.0.0", "20170201000000", true);
+        fs::copy(&partial, cache_path.join("offline-standalone-2.0.0.hart.part")).unwrap();
+
+        let task = task(fs_root.path(), cache_path);
+        assert_eq!(
+            task.latest_local_pkg_ident_for(&ident("offline/standalone"))
+                .unwrap(),
+            ident("offline/standalone/1.0.0/20170101000000")
+        );
+        assert_eq!(
+            task.latest_local_pkg_ident_for(&ident("offline/standalone/1.0.0"))
+                .unwrap(),
+            ident("offline/standalone/1.0.0/20170101000000")
+        );
+        assert!(is_offline_not_found(
+            task.latest_local_pkg_ident_for(&ident("offline/standalone/2.0.0")),
+        ));
+        assert!(is_offline_not_found(
+            task.latest_local_pkg_ident_for(&ident("offline/other")),
+        ));
+    }
+
+    #[test]
+    fn latest_local_pkg_ident_for_installed_packages() {
+        let fs_root = TempDir::new("fs-root").unwrap();
+        let cache_dir = TempDir::new("artifact-cache").unwrap();
+        let cached = ident("offline/standalone/1.0.0/20170101000000");
+        cache(
+            cache_dir.path(),
+            &standalone_fixture("1.0.0", "20170101000000", true),
+            &cached,
+        );
+        let task = task(fs_root.path(), cache_dir.path());
+
+        // An older installed release loses to the cached one
+        install(
+            fs_root.path(),
+            &ident("offline/standalone/0.9.0/20161201000000"),
+        );
+        assert_eq!(
+            task.latest_local_pkg_ident_for(&ident("offline/standalone"))
+                .unwrap(),
+            cached
+        );
+
+        // A newer installed release wins, though nothing of it is cached
+        let installed = ident("offline/standalone/3.0.0/20170301000000");
+        install(fs_root.path(), &installed);
+        assert_eq!(
+            task.latest_local_pkg_ident_for(&ident("offline/standalone"))
+                .unwrap(),
+            installed
+        );
+    }
+
+    #[test]
+    fn missing_artifacts_of_composite_packages() {
+        let fs_root = TempDir::new("fs-root").unwrap();
+        let cache_dir = TempDir::new("artifact-cache").unwrap();
+        let composite = ident("offline/composite/1.0.0/20170101000000");
+        let standalone = ident("offline/standalone/1.0.0/20170101000000");
+        let dep = ident("offline/dep/1.0.0/20170101000000");
+        let other = ident("offline/other/1.0.0/20170101000000");
+        let task = task(fs_root.path(), cache_dir.path());
+
+        // Nothing more can be known without the composite's own artifact
+        assert_eq!(
+            task.missing_artifacts(&composite).unwrap(),
+            vec![composite.archive_name().unwrap()]
+        );
+
+        // The services are looked through, as well as the dependencies of each
+        cache(cache_dir.path(), &composite_fixture(), &composite);
+        cache(
+            cache_dir.path(),
+            &standalone_fixture("1.0.0", "20170101000000", true),
+            &standalone,
+        );
+        assert_eq!(
+            task.missing_artifacts(&composite).unwrap(),
+            vec![dep.archive_name().unwrap(), other.archive_name().unwrap()]
+        );
+
+        // Installed packages don't need their artifacts
+        install(fs_root.path(), &dep);
+        assert_eq!(
+            task.missing_artifacts(&composite).unwrap(),
+            vec![other.archive_name().unwrap()]
+        );
+        install(fs_root.path(), &composite);
+        assert!(task.missing_artifacts(&composite).unwrap().is_empty());
+    }
+
+    #[test]
+    fn offline_from_env() {
+        for &(val, offline) in
+            &[
+                ("true", true),
+                ("1", true),
+                ("false", false),
+                ("0", false),
+                ("yes", false),
+                ("", false),
+            ]
+        {
+            env::set_var(OFFLINE_ENVVAR, val);
+            assert_eq!(is_offline(), offline, "{}={}", OFFLINE_ENVVAR, val);
+        }
+        env::remove_var(OFFLINE_ENVVAR);
+        assert!(!is_offline());
+    }
+}
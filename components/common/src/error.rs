@@ -39,6 +39,11 @@ pub enum Error {
     HabitatCore(hcore::Error),
     /// Occurs when making lower level IO calls.
     IO(io::Error),
+    /// Occurs when installing offline and artifacts are neither installed nor cached, by the path
+    /// of the artifact cache and the file names of the missing artifacts.
+    OfflineArtifactsMissing(String, Vec<String>),
+    OfflineOriginKeyNotFound(String),
+    OfflinePackageNotFound(String),
     RootRequired,
     StrFromUtf8Error(str::Utf8Error),
     StringFromUtf8Error(string::FromUtf8Error),
@@ -75,6 +80,26 @@ impl fmt::Display for Error {
             Error::FileNameError => format!("Failed to extract a filename"),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
+            Error::OfflineArtifactsMissing(ref cache, ref artifacts) => {
+                format!(
+                    "Can't install offline, the following artifacts are missing from {}:\n  {}",
+                    cache,
+                    artifacts.join("\n  ")
+                )
+            }
+            Error::OfflineOriginKeyNotFound(ref key) => {
+                format!(
+                    "Can't install offline, the public origin key {} is missing from the key \
+                     cache",
+                    key
+                )
+            }
+            Error::OfflinePackageNotFound(ref ident) => {
+                format!(
+                    "Can't install offline, no installed package or cached artifact satisfies {}",
+                    ident
+                )
+            }
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation".to_string()
             }
@@ -106,6 +131,16 @@ impl error::Error for Error {
             Error::FileNameError => "Failed to extract a filename from a path",
             Error::HabitatCore(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
+            Error::OfflineArtifactsMissing(_, _) => {
+                "Can't install offline, artifacts are missing from the artifact cache"
+            }
+            Error::OfflineOriginKeyNotFound(_) => {
+                "Can't install offline, a public origin key is missing from the key cache"
+            }
+            Error::OfflinePackageNotFound(_) => {
+                "Can't install offline, no installed package or cached artifact satisfies the \
+                 package identifier"
+            }
             Error::RootRequired => {
                 "Root or administrator permissions required to complete operation"
             }
@@ -24,6 +24,8 @@ extern crate log;
 extern crate pbr;
 extern crate regex;
 extern crate retry;
+extern crate serde;
+extern crate serde_json;
 #[cfg(test)]
 extern crate tempdir;
 extern crate term;
@@ -22,6 +22,8 @@ use uuid::Uuid;
 use ansi_term::Colour;
 use depot_client::DisplayProgress;
 use pbr;
+use serde::Serialize;
+use serde_json;
 use term::terminfo::TermInfo;
 use term::{Terminal, TerminfoTerminal};
 
@@ -32,6 +34,15 @@ pub const NONINTERACTIVE_ENVVAR: &'static str = "HAB_NONINTERACTIVE";
 
 pub const NOCOLORING_ENVVAR: &'static str = "HAB_NOCOLORING";
 
+/// How commands print their results
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    /// Text meant to be read by people
+    Human,
+    /// A JSON document on the standard output, with all other messages on the standard error
+    Json,
+}
+
 pub enum Status {
     Applying,
     Cached,
@@ -92,12 +103,39 @@ impl Status {
 
 pub struct UI {
     shell: Shell,
+    format: OutputFormat,
 }
 
 impl UI {
     /// Creates a new `UI` from a `Shell`.
     pub fn new(shell: Shell) -> Self {
-        UI { shell: shell }
+        UI {
+            shell: shell,
+            format: OutputFormat::Human,
+        }
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    /// Sets how commands print their results. Only the results go to the standard output with
+    /// `OutputFormat::Json`, so that it can be parsed by scripts.
+    pub fn set_format(&mut self, format: OutputFormat) {
+        self.format = format;
+    }
+
+    pub fn is_json(&self) -> bool {
+        self.format == OutputFormat::Json
+    }
+
+    /// Prints the result of a command as JSON on the standard output.
+    pub fn json<T: Serialize>(&mut self, value: &T) -> Result<()> {
+        let ref mut stream = self.shell.out;
+        // Unwrap OK, the results of commands only have string keys
+        write!(stream, "{}\n", serde_json::to_string_pretty(value).unwrap())?;
+        stream.flush()?;
+        Ok(())
     }
 
     /// Creates a new default `UI` with a coloring strategy and tty hinting.
@@ -148,11 +186,11 @@ impl UI {
     }
 
     pub fn begin<T: ToString>(&mut self, message: T) -> Result<()> {
-        Self::write_heading(&mut self.shell.out, Colour::Yellow, '»', message)
+        Self::write_heading(self.chatter(), Colour::Yellow, '»', message)
     }
 
     pub fn end<T: ToString>(&mut self, message: T) -> Result<()> {
-        Self::write_heading(&mut self.shell.out, Colour::Blue, '★', message)
+        Self::write_heading(self.chatter(), Colour::Blue, '★', message)
     }
 
     pub fn is_a_tty(&self) -> bool {
@@ -160,7 +198,7 @@ impl UI {
     }
 
     pub fn status<T: fmt::Display>(&mut self, status: Status, message: T) -> Result<()> {
-        let ref mut stream = self.shell.out;
+        let stream = self.chatter();
         let (symbol, status_str, color) = status.parts();
         match stream.is_colored() {
             true => {
@@ -234,7 +272,7 @@ impl UI {
     }
 
     pub fn progress(&mut self) -> Option<ProgressBar> {
-        if self.shell.out.is_a_terminal() {
+        if self.shell.out.is_a_terminal() && !self.is_json() {
             Some(ProgressBar::default())
         } else {
             None
@@ -242,7 +280,7 @@ impl UI {
     }
 
     pub fn title(&mut self, text: &str) -> Result<()> {
-        let ref mut stream = self.shell.out;
+        let stream = self.chatter();
         match stream.is_colored() {
             true => {
                 write!(stream, "{}\n", Colour::Green.bold().paint(text))?;
@@ -270,7 +308,7 @@ impl UI {
     }
 
     pub fn heading(&mut self, text: &str) -> Result<()> {
-        let ref mut stream = self.shell.out;
+        let stream = self.chatter();
         match stream.is_colored() {
             true => {
                 write!(stream, "{}\n\n", Colour::Green.bold().paint(text))?;
@@ -284,18 +322,21 @@ impl UI {
     }
 
     pub fn para(&mut self, text: &str) -> Result<()> {
-        Self::print_wrapped(&mut self.shell.out, text, 75, 2)
+        Self::print_wrapped(self.chatter(), text, 75, 2)
     }
 
     pub fn br(&mut self) -> Result<()> {
-        let ref mut stream = self.shell.out;
+        let stream = self.chatter();
         write!(stream, "\n")?;
         stream.flush()?;
         Ok(())
     }
 
     pub fn prompt_yes_no(&mut self, question: &str, default: Option<bool>) -> Result<bool> {
-        let ref mut stream = self.shell.out;
+        let stream = match self.format {
+            OutputFormat::Human => &mut self.shell.out,
+            OutputFormat::Json => &mut self.shell.err,
+        };
         let choice = match default {
             Some(yes) => {
                 if yes {
@@ -363,7 +404,10 @@ impl UI {
     }
 
     pub fn prompt_ask(&mut self, question: &str, default: Option<&str>) -> Result<String> {
-        let ref mut stream = self.shell.out;
+        let stream = match self.format {
+            OutputFormat::Human => &mut self.shell.out,
+            OutputFormat::Json => &mut self.shell.err,
+        };
         let choice = match default {
             Some(d) => {
                 match stream.is_colored() {
@@ -443,6 +487,15 @@ impl UI {
         Ok(out)
     }
 
+    /// The stream for the messages telling how a command goes, which leave the standard output
+    /// to the results in JSON
+    fn chatter(&mut self) -> &mut OutputStream {
+        match self.format {
+            OutputFormat::Human => &mut self.shell.out,
+            OutputFormat::Json => &mut self.shell.err,
+        }
+    }
+
     fn write_heading<T: ToString>(
         stream: &mut OutputStream,
         color: Colour,
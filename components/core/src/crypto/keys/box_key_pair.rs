@@ -45,6 +45,52 @@ impl BoxKeyPair {
         Ok(Self::new(name, revision, Some(pk), Some(sk)))
     }
 
+    /// Generates the pair an origin's secrets are encrypted to, named after the origin like its
+    /// signing keys.
+    pub fn generate_pair_for_origin(origin: &str) -> Result<Self> {
+        let revision = mk_revision_string()?;
+        let (pk, sk) = box_::gen_keypair();
+        Ok(Self::new(origin.to_string(), revision, Some(pk), Some(sk)))
+    }
+
+    /// Reads a pair from the contents of its public key file and, optionally, of its secret key
+    /// file, for keys which aren't kept in a key cache.
+    pub fn from_key_strings(public: &str, secret: Option<&str>) -> Result<Self> {
+        let (name_with_rev, body) = Self::parse_key_str(public, PUBLIC_BOX_KEY_VERSION)?;
+        let pk = match BoxPublicKey::from_slice(&body) {
+            Some(pk) => pk,
+            None => {
+                return Err(Error::CryptoError(
+                    format!("Can't read box public key for {}", name_with_rev),
+                ))
+            }
+        };
+        let sk = match secret {
+            Some(secret) => {
+                let (secret_name_with_rev, body) =
+                    Self::parse_key_str(secret, SECRET_BOX_KEY_VERSION)?;
+                if secret_name_with_rev != name_with_rev {
+                    return Err(Error::CryptoError(format!(
+                        "Secret key {} doesn't belong to public key {}",
+                        secret_name_with_rev,
+                        name_with_rev
+                    )));
+                }
+                match BoxSecretKey::from_slice(&body) {
+                    Some(sk) => Some(sk),
+                    None => {
+                        return Err(Error::CryptoError(
+                            format!("Can't read box secret key for {}", name_with_rev),
+                        ))
+                    }
+                }
+            }
+            None => None,
+        };
+        let (name, rev) = parse_name_with_rev(&name_with_rev)?;
+        Ok(Self::new(name, rev, Some(pk), sk))
+    }
+
     pub fn generate_pair_for_user(user: &str) -> Result<Self> {
         let revision = mk_revision_string()?;
         let keyname = Self::mk_key_name_for_user(user, &revision);
@@ -317,6 +363,47 @@ impl BoxKeyPair {
         }
     }
 
+    /// Decrypt data encrypted to this pair without a sender, for pairs which aren't kept in a key
+    /// cache.
+    pub fn decrypt_anonymous(&self, payload: &[u8]) -> Result<Vec<u8>> {
+        let mut lines = str::from_utf8(payload)?.lines();
+        match lines.next() {
+            Some(val) if val == ANONYMOUS_BOX_FORMAT_VERSION => (),
+            Some(val) => return Err(Error::CryptoError(format!("Unsupported version: {}", val))),
+            None => {
+                return Err(Error::CryptoError(
+                    "Corrupt payload, can't read file version".to_string(),
+                ));
+            }
+        }
+        match lines.next() {
+            Some(val) if val == self.name_with_rev() => (),
+            Some(val) => {
+                return Err(Error::CryptoError(
+                    format!("Payload was encrypted to {}, not {}", val, self.name_with_rev()),
+                ))
+            }
+            None => {
+                return Err(Error::CryptoError(
+                    "Corrupt payload, can't read sender key name".to_string(),
+                ));
+            }
+        }
+        let ciphertext = match lines.next() {
+            Some(val) => {
+                base64::decode(val).map_err(|e| {
+                    Error::CryptoError(format!("Can't decode ciphertext: {}", e))
+                })?
+            }
+            None => {
+                return Err(Error::CryptoError(
+                    "Corrupt payload, can't read ciphertext".to_string(),
+                ));
+            }
+        };
+        Self::decrypt_anonymous_box(&ciphertext, self.public()?, self.secret()?)
+    }
+
     pub fn to_pair_files<P: AsRef<Path> + ?Sized>(&self, path: &P) -> Result<()> {
         let public_keyfile = mk_key_filename(path, self.name_with_rev(), PUBLIC_KEY_SUFFIX);
         let secret_keyfile = mk_key_filename(path, self.name_with_rev(), SECRET_BOX_KEY_SUFFIX);
@@ -394,6 +481,37 @@ impl BoxKeyPair {
         }
     }
 
+    /// Returns the name with revision and the decoded body of the contents of a key file.
+    fn parse_key_str(content: &str, version: &str) -> Result<(String, Vec<u8>)> {
+        let mut lines = content.lines();
+        match lines.next() {
+            Some(val) if val == version => (),
+            Some(val) => return Err(Error::CryptoError(format!("Unsupported version: {}", val))),
+            None => return Err(Error::CryptoError("Malformed key string".to_string())),
+        }
+        let name_with_rev = match lines.next() {
+            Some(val) => val.to_string(),
+            None => {
+                return Err(Error::CryptoError(
+                    "Malformed key string, can't read key name".to_string(),
+                ))
+            }
+        };
+        let body = match lines.nth(1) {
+            Some(val) => {
+                base64::decode(val.trim()).map_err(|e| {
+                    Error::CryptoError(format!("Can't decode key {}: {}", name_with_rev, e))
+                })?
+            }
+            None => {
+                return Err(Error::CryptoError(
+                    format!("Malformed key string, can't read key for {}", name_with_rev),
+                ))
+            }
+        };
+        Ok((name_with_rev, body))
+    }
+
     fn mk_key_name_for_service(org: &str, service_group: &str, revision: &str) -> String {
         format!("{}@{}-{}", service_group, org, revision)
     }
@@ -692,6 +810,25 @@ mod test {
         assert!(ciphertext.is_ok());
     }
 
+    #[test]
+    fn encrypt_and_decrypt_with_key_strings() {
+        let origin = BoxKeyPair::generate_pair_for_origin("acme").unwrap();
+        let public = origin.to_public_string().unwrap();
+        let secret = origin.to_secret_string().unwrap();
+
+        let sender = BoxKeyPair::from_key_strings(&public, None).unwrap();
+        assert_eq!(sender.name_with_rev(), origin.name_with_rev());
+        let ciphertext = sender.encrypt("Coyote's license key".as_bytes(), None).unwrap();
+
+        let receiver = BoxKeyPair::from_key_strings(&public, Some(&secret)).unwrap();
+        let message = receiver.decrypt_anonymous(&ciphertext).unwrap();
+        assert_eq!(message, "Coyote's license key".as_bytes());
+
+        let other = BoxKeyPair::generate_pair_for_origin("other").unwrap();
+        assert!(other.decrypt_anonymous(&ciphertext).is_err());
+        assert!(BoxKeyPair::from_key_strings(&secret, None).is_err());
+    }
+
     #[test]
     fn encrypt_and_decrypt_minimal_keys() {
         let full_cache = TempDir::new("full_cache").unwrap();
@@ -24,7 +24,7 @@ use libarchive::archive::{Entry, ReadFilter, ReadFormat, ExtractOption, ExtractO
 use regex::Regex;
 
 use super::{Identifiable, PackageIdent, PackageTarget};
-use super::metadata::{MetaFile, PackageType};
+use super::metadata::{parse_key_value, MetaFile, PackageType};
 use error::{Error, Result};
 use crypto::{artifact, hash};
 
@@ -43,6 +43,9 @@ lazy_static! {
         map.insert(MetaFile::TDeps,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::TDeps)).unwrap());
+        map.insert(MetaFile::Exports,
+                   Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
+                              MetaFile::Exports)).unwrap());
         map.insert(MetaFile::Exposes,
                    Regex::new(&format!(r"^/?hab/pkgs/([^/]+)/([^/]+)/([^/]+)/([^/]+)/{}$",
                               MetaFile::Exposes)).unwrap());
@@ -154,6 +157,23 @@ impl PackageArchive {
         self.read_deps(MetaFile::TDeps)
     }
 
+    /// Returns the configuration keys the package exports to its peers, by the name they're
+    /// exported as.
+    ///
+    /// # Failures
+    ///
+    /// * If the archive cannot be read
+    /// * If the `EXPORTS` metafile is malformed
+    pub fn exports(&mut self) -> Result<HashMap<String, String>> {
+        match self.read_metadata(MetaFile::Exports) {
+            Ok(Some(data)) => {
+                parse_key_value(data).map_err(|_| Error::MetaFileMalformed(MetaFile::Exports))
+            }
+            Ok(None) => Ok(HashMap::new()),
+            Err(e) => Err(e),
+        }
+    }
+
     pub fn exposes(&mut self) -> Result<Vec<u16>> {
         match self.read_metadata(MetaFile::Exposes) {
             Ok(Some(data)) => {
@@ -84,6 +84,19 @@ impl PackageInstall {
         }
     }
 
+    /// Returns the identifiers of every installed package.
+    ///
+    /// An optional `fs_root` path may be provided to list the packages of a filesystem not
+    /// currently rooted at `/`.
+    pub fn list(fs_root_path: Option<&Path>) -> Result<Vec<PackageIdent>> {
+        let fs_root_path = fs_root_path.map_or(PathBuf::from("/"), |p| p.into());
+        let package_root_path = fs::pkg_root_path(Some(&fs_root_path));
+        if !package_root_path.exists() {
+            return Ok(vec![]);
+        }
+        Self::package_list(&package_root_path)
+    }
+
     fn resolve_package_install<T>(
         ident: &PackageIdent,
         fs_root_path: Option<T>,
@@ -318,7 +331,8 @@ impl PackageInstall {
         }
     }
 
-    fn deps(&self) -> Result<Vec<PackageIdent>> {
+    /// Returns the direct dependencies of the package.
+    pub fn deps(&self) -> Result<Vec<PackageIdent>> {
         self.read_deps(MetaFile::Deps)
     }
 
@@ -32,3 +32,23 @@ pub fn bldr_url_from_env() -> Option<String> {
 pub fn default_bldr_url() -> String {
     bldr_url_from_env().unwrap_or(DEFAULT_BLDR_URL.to_string())
 }
+
+/// Splits a Builder URL value into the URLs of its mirrors. The value may list several mirrors
+/// separated by commas, which are tried in order when one is unavailable.
+pub fn bldr_urls(value: &str) -> Vec<&str> {
+    value.split(',').map(|url| url.trim()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bldr_urls_lists_mirrors() {
+        assert_eq!(bldr_urls(DEFAULT_BLDR_URL), vec![DEFAULT_BLDR_URL]);
+        assert_eq!(
+            bldr_urls("https://bldr.example.com, https://mirror.example.com"),
+            vec!["https://bldr.example.com", "https://mirror.example.com"]
+        );
+    }
+}
@@ -224,6 +224,26 @@ impl GitHubClient {
         Ok(Some(value))
     }
 
+    /// Returns a repository given its full name, `owner/repo`, if it's visible with the token.
+    pub fn repo_by_name(&self, token: &str, full_name: &str) -> HubResult<Option<Repository>> {
+        let url = Url::parse(&format!("{}/repos/{}", self.url, full_name))
+            .map_err(HubError::HttpClientParse)?;
+        let mut rep = http_get(url, Some(token))?;
+        let mut body = String::new();
+        rep.read_to_string(&mut body)?;
+        debug!("GitHub response body, {}", body);
+        match rep.status {
+            StatusCode::NotFound => return Ok(None),
+            StatusCode::Ok => (),
+            status => {
+                let err: HashMap<String, String> = serde_json::from_str(&body)?;
+                return Err(HubError::ApiError(status, err));
+            }
+        }
+        let value = serde_json::from_str(&body)?;
+        Ok(Some(value))
+    }
+
     pub fn repositories(&self, token: &str, install_id: u32) -> HubResult<Vec<Repository>> {
         let mut url = Url::parse(&format!(
             "{}/user/installations/{}/repositories",
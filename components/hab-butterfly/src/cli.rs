@@ -82,6 +82,12 @@ fn sub_config_apply() -> App<'static, 'static> {
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
         (@arg ORG: --org +takes_value "Name of service organization to use for encryption")
         (@arg USER: -u --user +takes_value "Name of a user key to use for encryption")
+        (@arg DRY_RUN: --("dry-run")
+            "Render the service's templates with the configuration and report what would change, \
+            without applying it")
+        (@arg LISTEN_HTTP: --("listen-http") +takes_value
+            "The listen address of the local Supervisor's HTTP gateway to render the templates \
+            with (default: 127.0.0.1:9631)")
     )
 }
 
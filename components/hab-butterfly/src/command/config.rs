@@ -23,9 +23,25 @@ pub mod apply {
     use common::ui::{Status, UI};
     use hcore::crypto::{SymKey, BoxKeyPair};
     use hcore::service::ServiceGroup;
+    use http_client::{self, ApiClient};
+    use http_client::util::decoded_response;
+    use hyper::status::StatusCode;
     use toml;
 
     use error::{Error, Result};
+    use {PRODUCT, VERSION};
+
+    pub const DEFAULT_LISTEN_HTTP: &'static str = "127.0.0.1:9631";
+
+    /// What the Supervisor reports applying a configuration would do
+    #[derive(Deserialize)]
+    struct DryRun {
+        config_files: Vec<String>,
+        hooks: Vec<String>,
+        restart: bool,
+        reload: bool,
+        reconfigure: bool,
+    }
 
     pub fn start(
         ui: &mut UI,
@@ -46,33 +62,7 @@ pub mod apply {
             format!("service configuration"),
         )?;
 
-        let mut body = Vec::new();
-
-        match file_path {
-            Some(p) => {
-                let mut file = File::open(&p)?;
-                file.read_to_end(&mut body)?;
-            }
-            None => {
-                io::stdin().read_to_end(&mut body)?;
-            }
-        };
-
-        match toml::de::from_slice::<toml::value::Value>(&body) {
-            Ok(_) => {
-                ui.status(
-                    Status::Verified,
-                    "this configuration is valid TOML",
-                )?
-            }
-            Err(err) => {
-                ui.fatal("Invalid TOML")?;
-                ui.br()?;
-                ui.warn(&err)?;
-                ui.br()?;
-                return Err(Error::TomlDeserializeError(err));
-            }
-        }
+        let mut body = read_config(ui, file_path)?;
 
         let mut encrypted = false;
         if service_pair.is_some() && user_pair.is_some() {
@@ -111,4 +101,102 @@ pub mod apply {
         ui.end("Applied configuration")?;
         Ok(())
     }
+
+    /// Has the local Supervisor render the templates of a service with the configuration, and
+    /// reports what applying it would change. Nothing is sent to the ring.
+    pub fn dry_run(
+        ui: &mut UI,
+        sg: &ServiceGroup,
+        file_path: Option<&Path>,
+        listen_http: &str,
+    ) -> Result<()> {
+        ui.begin(format!("Trying out configuration for {}", sg))?;
+        let body = read_config(ui, file_path)?;
+
+        ui.status(Status::Verifying, format!("templates of {}", sg))?;
+        let client = ApiClient::new(&format!("http://{}", listen_http), PRODUCT, VERSION, None)?;
+        let path = match sg.org() {
+            Some(org) => format!("services/{}/{}/{}/config/dry-run", sg.service(), sg.group(), org),
+            None => format!("services/{}/{}/config/dry-run", sg.service(), sg.group()),
+        };
+        let mut res = client.post(&path).body(&body[..]).send().map_err(
+            http_client::Error::from,
+        )?;
+        let status = res.status;
+        let dry_run: DryRun = match status {
+            StatusCode::Ok => decoded_response(res)?,
+            StatusCode::NotFound => return Err(Error::ServiceNotLoaded(sg.to_string())),
+            StatusCode::Forbidden => {
+                return Err(Error::SupervisorGateway(format!(
+                    "{} only renders templates for clients on its own host",
+                    listen_http
+                )))
+            }
+            StatusCode::UnprocessableEntity => {
+                let mut message = String::new();
+                res.read_to_string(&mut message)?;
+                return Err(Error::ConfigRenderFailed(message));
+            }
+            status => {
+                return Err(Error::SupervisorGateway(
+                    format!("POST /{} replied {}", path, status),
+                ))
+            }
+        };
+        ui.status(Status::Verified, "the templates render with this configuration")?;
+
+        if dry_run.config_files.is_empty() && dry_run.hooks.is_empty() {
+            ui.end(format!("Nothing would change for {}", sg))?;
+            return Ok(());
+        }
+        for file in dry_run.config_files.iter() {
+            ui.status(Status::Custom('~', "Changes".to_string()), format!("config/{}", file))?;
+        }
+        for hook in dry_run.hooks.iter() {
+            ui.status(Status::Custom('~', "Changes".to_string()), format!("hooks/{}", hook))?;
+        }
+        if dry_run.restart {
+            ui.warn(format!("{} would be restarted, as it has no reload hook", sg))?;
+        }
+        if dry_run.reload {
+            ui.status(Status::Using, "the reload hook of the service")?;
+        }
+        if dry_run.reconfigure {
+            ui.status(Status::Using, "the reconfigure hook of the service")?;
+        }
+        ui.end("Configuration not applied, as this was a dry run")?;
+        Ok(())
+    }
+
+    /// Reads the configuration from the file, or from the standard input, and verifies it's TOML.
+    fn read_config(ui: &mut UI, file_path: Option<&Path>) -> Result<Vec<u8>> {
+        let mut body = Vec::new();
+
+        match file_path {
+            Some(p) => {
+                let mut file = File::open(&p)?;
+                file.read_to_end(&mut body)?;
+            }
+            None => {
+                io::stdin().read_to_end(&mut body)?;
+            }
+        };
+
+        match toml::de::from_slice::<toml::value::Value>(&body) {
+            Ok(_) => {
+                ui.status(
+                    Status::Verified,
+                    "this configuration is valid TOML",
+                )?
+            }
+            Err(err) => {
+                ui.fatal("Invalid TOML")?;
+                ui.br()?;
+                ui.warn(&err)?;
+                ui.br()?;
+                return Err(Error::TomlDeserializeError(err));
+            }
+        }
+        Ok(body)
+    }
 }
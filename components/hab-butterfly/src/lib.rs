@@ -19,10 +19,15 @@ extern crate hab;
 extern crate habitat_core as hcore;
 extern crate habitat_common as common;
 extern crate habitat_butterfly as butterfly;
+extern crate habitat_http_client as http_client;
 
 #[macro_use]
 extern crate clap;
+extern crate hyper;
 extern crate log;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
 extern crate toml;
 
 pub use hab::config;
@@ -139,6 +139,16 @@ fn sub_config_apply(ui: &mut UI, m: &ArgMatches) -> Result<()> {
         Some("-") | None => None,
         Some(p) => Some(Path::new(p)),
     };
+    if m.is_present("DRY_RUN") {
+        let mut sg = ServiceGroup::from_str(m.value_of("SERVICE_GROUP").unwrap())?;
+        if let Some(org) = org_param_or_env(&m) {
+            sg.set_org(org);
+        }
+        let listen_http = m.value_of("LISTEN_HTTP").unwrap_or(
+            command::config::apply::DEFAULT_LISTEN_HTTP,
+        );
+        return command::config::apply::dry_run(ui, &sg, file_path, listen_http);
+    }
 
     init();
     let cache = default_cache_key_path(Some(&*FS_ROOT));
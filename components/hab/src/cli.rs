@@ -17,7 +17,10 @@ use std::result;
 use std::str::FromStr;
 
 use clap::{App, AppSettings, Arg};
+use depot_client::Rate;
 use hcore::crypto::keys::PairType;
+use hcore::package::PackageTarget;
+use hcore::url::bldr_urls;
 use regex::Regex;
 use url::Url;
 
@@ -41,18 +44,27 @@ pub fn get() -> App<'static, 'static> {
         (author: "\nAuthors: The Habitat Maintainers <humans@habitat.sh>\n")
         (@setting VersionlessSubcommands)
         (@setting ArgRequiredElseHelp)
+        (@arg JSON: --json +global
+            "Print the results as JSON for scripts, for the commands supporting it")
         (@subcommand cli =>
             (about: "Commands relating to Habitat runtime config")
             (aliases: &["cl"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_cli_setup().aliases(&["s", "se", "set", "setu"]))
             (subcommand: sub_cli_completers().aliases(&["c", "co", "com", "comp"]))
+            (@subcommand candidates =>
+                (about: "Prints the identifiers completing a command line")
+                (@setting Hidden)
+                (@arg KIND: +required +takes_value {valid_candidates}
+                    "What to complete, either `packages' or `services'")
+            )
         )
         (@subcommand config =>
             (about: "Commands relating to Habitat runtime config")
             (aliases: &["co", "con", "conf", "confi"])
             (@setting ArgRequiredElseHelp)
             (subcommand: sub_config_apply().aliases(&["a", "ap", "app", "appl"]))
+            (subcommand: sub_config_show().aliases(&["s", "sh", "sho"]))
         )
         (@subcommand file =>
             (about: "Commands relating to Habitat files")
@@ -138,19 +150,33 @@ pub fn get() -> App<'static, 'static> {
                         "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
                     (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
                 )
+                (@subcommand log =>
+                    (about: "Print the log of a build job")
+                    (aliases: &["l", "lo"])
+                    (@arg JOB_ID: +required +takes_value
+                        "The id of a job of a group returned from \"hab bldr job start\" \
+                        (ex: 771100000000000000)")
+                    (@arg FOLLOW: -f --follow
+                        "Keep printing the log as the job goes on until it's done")
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
                 (@subcommand status =>
                     (@group status =>
                         (@attributes +required)
                         (@arg GROUP_ID: +required +takes_value
-                            "The group id that was returned from \"hab bldr job start\" \
-                            (ex: 771100000000000000)")
+                            "The group id that was returned from \"hab bldr job start\", or the \
+                            id of one of its jobs (ex: 771100000000000000)")
                         (@arg ORIGIN: -o --origin +takes_value
                             "You can see the status of every group in an origin by providing this value")
                     )
-                    (about: "Get the status of a job group")
+                    (about: "Get the status of a job group or of a job")
                     (aliases: &["stat", "statu"])
                     (@arg BLDR_URL: -u --url +takes_value {valid_url}
                         "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value
+                        "Authentication token for Builder, needed for the status of a job")
                 )
             )
             (@subcommand encrypt =>
@@ -160,9 +186,35 @@ pub fn get() -> App<'static, 'static> {
                 (@arg BLDR_URL: -u --url +takes_value {valid_url}
                     "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
             )
+            (@subcommand export =>
+                (about: "Exports packages of an origin, their dependencies, channels and origin \
+                    keys to a signed bundle, for importing into a Builder without network access")
+                (aliases: &["ex", "exp", "expo", "expor"])
+                (@arg ORIGIN: +required +takes_value "The origin to export packages of")
+                (@arg PKG_IDENT: +required +multiple +takes_value
+                    "One or more package identifiers (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg CHANNEL: --channel -c +takes_value
+                    "Export the latest releases in the specified release channel (default: none)")
+                (@arg PKG_TARGET: --target -t +takes_value
+                    "Export releases for the specified target (ex: x86_64-linux)")
+                (@arg DEST_DIR: -d --dest +takes_value
+                    "Directory to download the bundle to (default: current directory)")
+                (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                    "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+            )
+            (@subcommand import =>
+                (about: "Imports a bundle exported from another Builder. The public key of the \
+                    origin the bundle was exported from must be uploaded first")
+                (aliases: &["i", "im", "imp", "impo", "impor"])
+                (@arg BUNDLE: +required {file_exists} "A filepath to an exported bundle")
+                (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                    "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+            )
         )
         (@subcommand origin =>
-            (about: "Commands relating to Habitat origin keys")
+            (about: "Commands relating to Habitat origin keys and secrets")
             (aliases: &["o", "or", "ori", "orig", "origi"])
             (@setting ArgRequiredElseHelp)
             (@subcommand key =>
@@ -197,6 +249,22 @@ pub fn get() -> App<'static, 'static> {
                         contents and writes the key to disk")
                     (aliases: &["i", "im", "imp", "impo", "impor"])
                 )
+                (@subcommand list =>
+                    (about: "Lists the origin keys in HAB_CACHE_KEY_PATH")
+                    (aliases: &["l", "li", "lis", "ls"])
+                    (@arg ORIGIN: "The origin name")
+                )
+                (@subcommand rotate =>
+                    (about: "Generates a new origin key revision, uploads its public key to \
+                        Builder and re-encrypts the origin's secrets with a new encryption key")
+                    (aliases: &["r", "ro", "rot", "rota", "rotat"])
+                    (@arg ORIGIN: "The origin name")
+                    (@arg DEPRECATE: -d --deprecate
+                        "Deprecate the older key revisions of the origin in Builder")
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
                 (@subcommand upload =>
                     (@group upload =>
                         (@attributes +required)
@@ -215,6 +283,40 @@ pub fn get() -> App<'static, 'static> {
                     (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
                 )
             )
+            (@subcommand secret =>
+                (about: "Commands related to the secrets of an origin, which are handed to its \
+                    builds in Builder")
+                (@setting ArgRequiredElseHelp)
+                (@subcommand upload =>
+                    (about: "Encrypts a secret with the encryption key of an origin and uploads \
+                        it to Builder")
+                    (@arg KEY_NAME: +required +takes_value "The name of the variable the \
+                        secret is set as in builds (ex: \"AWS_ACCESS_KEY_ID\")")
+                    (@arg SECRET: +required +takes_value "The value of the secret")
+                    (@arg ORIGIN: -o --origin +takes_value "The origin the secret belongs to. \
+                        Default is from 'HAB_ORIGIN' or cli.toml")
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
+                (@subcommand list =>
+                    (about: "Lists the names of the secrets of an origin")
+                    (@arg ORIGIN: -o --origin +takes_value "The origin the secrets belong to. \
+                        Default is from 'HAB_ORIGIN' or cli.toml")
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
+                (@subcommand delete =>
+                    (about: "Deletes a secret of an origin")
+                    (@arg KEY_NAME: +required +takes_value "The name of the secret")
+                    (@arg ORIGIN: -o --origin +takes_value "The origin the secret belongs to. \
+                        Default is from 'HAB_ORIGIN' or cli.toml")
+                    (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                        "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                    (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                )
+            )
         )
         (@subcommand pkg =>
             (about: "Commands relating to Habitat packages")
@@ -280,6 +382,14 @@ pub fn get() -> App<'static, 'static> {
             )
             (subcommand: sub_pkg_install().aliases(
                 &["i", "in", "ins", "inst", "insta", "instal"]))
+            (@subcommand list =>
+                (about: "Lists the installed packages")
+                (aliases: &["l", "li", "lis", "ls"])
+                (@arg PKG_FILTER: +takes_value
+                    "Only list the packages matching an origin, optionally followed by a name, \
+                    version and release, any of which may use * and ? wildcards \
+                    (ex: core, core/redis, 'core/*ssl*')")
+            )
             (@subcommand path =>
                 (about: "Prints the path to a specific installed release of a package")
                 (aliases: &["p", "pa", "pat"])
@@ -313,6 +423,16 @@ pub fn get() -> App<'static, 'static> {
                     "The destination path to the signed Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
             )
+            (@subcommand uninstall =>
+                (about: "Removes an installed package, and optionally the dependencies nothing \
+                    else needs")
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg DEPS: --deps
+                    "Also remove the installed dependencies no other package or service needs")
+                (@arg FORCE: -f --force
+                    "Remove the package even if another package or a loaded service needs it")
+            )
             (@subcommand upload =>
                 (about: "Uploads a local Habitat Artifact to Builder")
                 (aliases: &["u", "up", "upl", "uplo", "uploa"])
@@ -326,6 +446,8 @@ pub fn get() -> App<'static, 'static> {
                 (@arg HART_FILE: +required +multiple {file_exists}
                     "One or more filepaths to a Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@arg THROTTLE: --throttle +takes_value {valid_rate}
+                    "Upload no faster than the given rate (ex: 5MB/s) (default: unlimited)")
             )
             (@subcommand promote =>
                 (about: "Promote a package to a specified channel")
@@ -349,13 +471,49 @@ pub fn get() -> App<'static, 'static> {
                     "Demote from the specified release channel")
                 (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
             )
+            (@subcommand dependencies =>
+                (about: "Prints the dependency tree of an installed package, or of a package in \
+                    Builder when it isn't installed")
+                (aliases: &["deps"])
+                (@arg PKG_IDENT: +required +takes_value
+                    "A package identifier (ex: core/redis, core/busybox-static/1.42.2)")
+                (@arg REVERSE: -r --reverse
+                    "Print the installed packages depending on the package instead")
+                (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                    "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                (@arg CHANNEL: --channel -c +takes_value
+                    "Look the package up in the specified release channel (default: stable)")
+                (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+            )
+            (@subcommand download =>
+                (about: "Downloads Habitat Artifacts with all of their dependencies, and the \
+                    public origin keys to verify them, without installing anything")
+                (aliases: &["dow", "down", "downl", "downlo", "downloa"])
+                (@arg PKG_IDENT: +required +multiple
+                    "One or more Habitat package identifiers (ex: acme/redis)")
+                (@arg PKG_TARGET: --target -t +takes_value {valid_target}
+                    "Download artifacts built for the given target (ex: x86_64-linux) \
+                    (default: the target of this system)")
+                (@arg DOWNLOAD_DIRECTORY: --download-directory +takes_value
+                    "Download into the given directory, with artifacts in its `artifacts` \
+                    and keys in its `keys` directory (default: the current directory)")
+                (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                    "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
+                (@arg CHANNEL: --channel -c +takes_value
+                    "Download from the specified release channel (default: stable)")
+                (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+                (@arg THROTTLE: --throttle +takes_value {valid_rate}
+                    "Download no faster than the given rate (ex: 5MB/s) (default: unlimited)")
+            )
             (@subcommand channels =>
-                (about: "Find out what channels a package belongs to")
+                (about: "Find out what channels a package belongs to, or list the channels of an \
+                    origin with how many packages are in each")
                 (aliases: &["ch", "cha", "chan", "chann", "channe", "channel"])
                 (@arg BLDR_URL: -u --url +takes_value {valid_url}
                     "Specify an alternate Builder endpoint (default: https://bldr.habitat.sh)")
-                (@arg PKG_IDENT: +required +takes_value
-                    "A fully qualified package identifier (ex: core/busybox-static/1.42.2/20170513215502)")
+                (@arg PKG_IDENT_OR_ORIGIN: +required +takes_value
+                    "A fully qualified package identifier \
+                    (ex: core/busybox-static/1.42.2/20170513215502) or an origin (ex: core)")
                 (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
             )
             (@subcommand verify =>
@@ -364,6 +522,12 @@ pub fn get() -> App<'static, 'static> {
                 (@arg SOURCE: +required {file_exists}
                     "A path to a Habitat Artifact \
                     (ex: /home/acme-redis-3.0.7-21120102031201-x86_64-linux.hart)")
+                (@arg ORIGIN_KEY: --("origin-key") +takes_value {file_exists}
+                    "Only trust the public origin key at this path, instead of the ones in \
+                    HAB_CACHE_KEY_PATH (ex: /home/acme-20160810182414.pub)")
+                (@arg BLDR_URL: -u --url +takes_value {valid_url}
+                    "Specify an alternate Builder endpoint to download a missing origin key from \
+                    (default: https://bldr.habitat.sh)")
             )
             (@subcommand header =>
                 (about: "Returns the Habitat Artifact header")
@@ -561,7 +725,8 @@ fn sub_cli_completers() -> App<'static, 'static> {
         Arg::with_name("SHELL")
             .help(
                 "The name of the shell you want to generate the command-completion. Supported \
-               Shells: bash, fish, zsh, powershell",
+               Shells: bash, fish, zsh, powershell. Installed packages and loaded services are \
+               completed in all of them but powershell",
             )
             .short("s")
             .long("shell")
@@ -586,6 +751,25 @@ fn sub_config_apply() -> App<'static, 'static> {
         (@arg FILE: {file_exists_or_stdin}
             "Path to local file on disk (ex: /tmp/config.toml, default: <stdin>)")
         (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg DRY_RUN: --("dry-run")
+            "Render the service's templates with the configuration and report what would change, \
+            without applying it")
+        (@arg LISTEN_HTTP: --("listen-http") +takes_value
+            "The listen address of the local Supervisor's HTTP gateway to render the templates \
+            with (default: 127.0.0.1:9631)")
+    )
+}
+
+fn sub_config_show() -> App<'static, 'static> {
+    clap_app!(@subcommand show =>
+        (about: "Shows the configuration a loaded service is currently running with")
+        (@arg SERVICE_GROUP: +required {valid_service_group}
+            "Target service group (ex: redis.default)")
+        (@arg ORG: --org +takes_value "Name of service organization")
+        (@arg DIFF_DEFAULT: --("diff-default")
+            "Only show the settings which differ from the package's default.toml")
+        (@arg LISTEN_HTTP: --("listen-http") +takes_value
+            "The listen address of the Supervisor's HTTP gateway (default: 127.0.0.1:9631)")
     )
 }
 
@@ -647,6 +831,11 @@ fn sub_pkg_install() -> App<'static, 'static> {
         (@arg BINLINK: -b --binlink "Binlink all binaries from installed package(s)")
         (@arg FORCE: -f --force "Overwrite existing binlinks")
         (@arg AUTH_TOKEN: -z --auth +takes_value "Authentication token for Builder")
+        (@arg THROTTLE: --throttle +takes_value {valid_rate}
+            "Download no faster than the given rate (ex: 5MB/s) (default: unlimited)")
+        (@arg OFFLINE: --offline
+            "Install from installed packages and the local artifact cache only, without \
+            contacting Builder (default: false, or true if HAB_OFFLINE=true)")
     )
 }
 
@@ -662,6 +851,13 @@ fn file_exists_or_stdin(val: String) -> result::Result<(), String> {
     if val == "-" { Ok(()) } else { file_exists(val) }
 }
 
+fn valid_candidates(val: String) -> result::Result<(), String> {
+    match val.as_str() {
+        "packages" | "services" => Ok(()),
+        _ => Err(format!("Completion candidates must be packages or services, not {}", val)),
+    }
+}
+
 fn valid_pair_type(val: String) -> result::Result<(), String> {
     match PairType::from_str(&val) {
         Ok(_) => Ok(()),
@@ -683,9 +879,25 @@ fn valid_service_group(val: String) -> result::Result<(), String> {
     }
 }
 
-fn valid_url(val: String) -> result::Result<(), String> {
-    match Url::parse(&val) {
+fn valid_rate(val: String) -> result::Result<(), String> {
+    match Rate::from_str(&val) {
         Ok(_) => Ok(()),
-        Err(_) => Err(format!("URL: '{}' is not valid", &val)),
+        Err(e) => Err(format!("THROTTLE: {}", e)),
+    }
+}
+
+fn valid_target(val: String) -> result::Result<(), String> {
+    match PackageTarget::from_str(&val) {
+        Ok(_) => Ok(()),
+        Err(_) => Err(format!("PKG_TARGET: '{}' is invalid", &val)),
+    }
+}
+
+fn valid_url(val: String) -> result::Result<(), String> {
+    for url in bldr_urls(&val) {
+        if Url::parse(url).is_err() {
+            return Err(format!("URL: '{}' is not valid", url));
+        }
     }
+    Ok(())
 }
@@ -0,0 +1,60 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+pub fn start(
+    ui: &mut UI,
+    bldr_url: &str,
+    origin: &str,
+    packages: &[String],
+    channel: Option<&str>,
+    target: Option<&str>,
+    token: &str,
+    dst_path: &Path,
+) -> Result<()> {
+    let depot_client = Client::new(bldr_url, PRODUCT, VERSION, None)?;
+    ui.begin(format!("Exporting a bundle of {}", origin))?;
+
+    ui.status(Status::Creating, format!("bundle of {}", packages.join(", ")))?;
+    let exported = depot_client.export_bundle(
+        origin,
+        packages,
+        channel,
+        target,
+        token,
+    )?;
+    for package in exported.packages.iter() {
+        ui.status(Status::Using, package)?;
+    }
+    ui.status(Status::Created, format!("bundle {}", exported.checksum))?;
+
+    ui.status(Status::Downloading, format!("bundle {}", exported.checksum))?;
+    let path = depot_client.fetch_bundle(
+        origin,
+        &exported.checksum,
+        token,
+        dst_path,
+        ui.progress(),
+    )?;
+
+    ui.end(format!("Bundle exported to {}", path.display()))?;
+    Ok(())
+}
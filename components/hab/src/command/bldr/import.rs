@@ -0,0 +1,48 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+pub fn start(ui: &mut UI, bldr_url: &str, bundle: &Path, token: &str) -> Result<()> {
+    let depot_client = Client::new(bldr_url, PRODUCT, VERSION, None)?;
+    ui.begin(format!("Importing bundle {}", bundle.display()))?;
+
+    ui.status(Status::Uploading, bundle.display())?;
+    let imported = depot_client.import_bundle(bundle, token, ui.progress())?;
+    for key in imported.keys_imported.iter() {
+        ui.status(Status::Uploaded, format!("public origin key {}", key))?;
+    }
+    for package in imported.packages_imported.iter() {
+        ui.status(Status::Uploaded, package)?;
+    }
+    for package in imported.packages_skipped.iter() {
+        ui.status(
+            Status::Using,
+            format!("{} which already exists in the depot", package),
+        )?;
+    }
+
+    ui.end(format!(
+        "Imported {} packages and {} keys",
+        imported.packages_imported.len(),
+        imported.keys_imported.len()
+    ))?;
+    Ok(())
+}
@@ -0,0 +1,50 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use api_client;
+use common::ui::{Status, UI};
+
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+/// Prints the log of a job, and with `follow` keeps printing it as the job goes on until it's
+/// done.
+pub fn start(ui: &mut UI, bldr_url: &str, job_id: &str, token: &str, follow: bool) -> Result<()> {
+    let api_client = api_client::Client::new(bldr_url, PRODUCT, VERSION, None)
+        .map_err(Error::APIClient)?;
+    let id = match job_id.parse::<u64>() {
+        Ok(id) => id,
+        Err(e) => {
+            ui.fatal(format!("Failed to parse job id: {}", e))?;
+            return Err(Error::ParseIntError(e));
+        }
+    };
+
+    if follow {
+        ui.status(Status::Determining, format!("log of Job {}", job_id))?;
+        api_client
+            .job_log_follow(id, 0, token, |log| {
+                for line in log.content.iter() {
+                    println!("{}", line);
+                }
+            })
+            .map_err(Error::APIClient)
+    } else {
+        let log = api_client.job_log(id, 0, token).map_err(Error::APIClient)?;
+        for line in log.content.iter() {
+            println!("{}", line);
+        }
+        Ok(())
+    }
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod cancel;
+pub mod log;
 pub mod promote;
 pub mod start;
 pub mod status;
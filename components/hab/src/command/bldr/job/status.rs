@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use api_client;
 use depot_client;
 use common::ui::{Status, UI};
+use hyper::status::StatusCode;
 
 use {PRODUCT, VERSION};
 use error::{Error, Result};
@@ -23,16 +25,53 @@ pub fn start(
     bldr_url: &str,
     group_id: Option<&str>,
     origin: Option<&str>,
+    token: Option<&str>,
 ) -> Result<()> {
     let depot_client = depot_client::Client::new(bldr_url, PRODUCT, VERSION, None)
         .map_err(Error::DepotClient)?;
 
     if origin.is_some() {
         do_origin_status(ui, &depot_client, origin.unwrap())?;
-    } else {
-        do_job_group_status(ui, &depot_client, group_id.unwrap())?;
+        return Ok(());
     }
+    // The id is taken for a job's when there's no job group with it
+    match do_job_group_status(ui, &depot_client, group_id.unwrap()) {
+        Err(Error::ScheduleStatus(depot_client::Error::APIError(StatusCode::NotFound, _)))
+            if token.is_some() => do_job_status(ui, bldr_url, group_id.unwrap(), token.unwrap()),
+        result => result,
+    }
+}
+
+fn do_job_status(ui: &mut UI, bldr_url: &str, job_id: &str, token: &str) -> Result<()> {
+    let api_client = api_client::Client::new(bldr_url, PRODUCT, VERSION, None)
+        .map_err(Error::APIClient)?;
+    let id = job_id.parse::<u64>().map_err(Error::ParseIntError)?;
 
+    ui.status(Status::Determining, format!("status of Job {}", job_id))?;
+    let job = api_client.job_show(id, token).map_err(Error::APIClient)?;
+    if ui.is_json() {
+        ui.json(&job)?;
+        return Ok(());
+    }
+    let ident = match (job.version, job.release) {
+        (Some(version), Some(release)) => {
+            format!("{}/{}/{}/{}", job.origin, job.name, version, release)
+        }
+        _ => format!("{}/{}", job.origin, job.name),
+    };
+    println!("");
+    println!("Job {}: {}", job.id, ident);
+    println!("State:    {}", job.state);
+    let times = [
+        ("Created", job.created_at),
+        ("Started", job.build_started_at),
+        ("Finished", job.build_finished_at),
+    ];
+    for &(label, ref time) in times.iter() {
+        if let Some(ref time) = *time {
+            println!("{:<9} {}", format!("{}:", label), time);
+        }
+    }
     Ok(())
 }
 
@@ -56,6 +95,9 @@ fn do_job_group_status(
 
     match depot_client.get_schedule(gid) {
         Ok(status) => {
+            if ui.is_json() {
+                return Ok(ui.json(&status)?);
+            }
             println!("");
             println!("{}", status.to_string());
             Ok(())
@@ -70,6 +112,12 @@ fn do_origin_status(ui: &mut UI, depot_client: &depot_client::Client, origin: &s
         format!("status of all job groups in {} origin", origin),
     )?;
 
+    if ui.is_json() {
+        let statuses = depot_client.get_origin_schedules(origin).map_err(
+            Error::ScheduleStatus,
+        )?;
+        return Ok(ui.json(&statuses)?);
+    }
     match depot_client.get_origin_schedule(origin) {
         Ok(status) => {
             println!("{}", status);
@@ -14,3 +14,5 @@
 
 pub mod job;
 pub mod encrypt;
+pub mod export;
+pub mod import;
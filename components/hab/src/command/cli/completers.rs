@@ -0,0 +1,195 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Generate the command-line completers of a shell.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab cli completers --shell bash > /etc/bash_completion.d/hab
+//! ```
+//! This will write the completers generated from the definition of the `hab` command line, along
+//! with the ones completing the identifiers of installed packages and loaded services.
+//!
+//! Notes:
+//!    The identifiers are looked up as completion happens by calling the hidden
+//!    `hab cli candidates` command. PowerShell only gets the completers generated by clap.
+//!
+
+use std::io::{self, Write};
+use std::path::Path;
+
+use clap::Shell;
+use hcore::package::PackageInstall;
+
+use cli;
+use command::service;
+use error::Result;
+
+/// The subcommands taking the identifier of an installed package
+const PACKAGE_COMMANDS: &'static [(&'static str, &'static str)] = &[
+    ("pkg", "binds"),
+    ("pkg", "binlink"),
+    ("pkg", "config"),
+    ("pkg", "dependencies"),
+    ("pkg", "env"),
+    ("pkg", "exec"),
+    ("pkg", "path"),
+    ("pkg", "uninstall"),
+    ("svc", "load"),
+    ("svc", "start"),
+];
+
+/// The subcommands taking the identifier of a loaded service
+const SERVICE_COMMANDS: &'static [(&'static str, &'static str)] = &[
+    ("svc", "status"),
+    ("svc", "stop"),
+    ("svc", "unload"),
+];
+
+/// Writes the completers of `shell` to the standard output.
+pub fn start(shell: Shell) -> Result<()> {
+    let mut out = io::stdout();
+    cli::get().gen_completions_to("hab", shell, &mut out);
+    if let Some(script) = dynamic_completers(shell) {
+        out.write_all(script.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Prints the candidates completing the identifier of an installed package, or of a loaded
+/// service with `services`, one per line.
+pub fn candidates(fs_root_path: &Path, services: bool) -> Result<()> {
+    let mut idents: Vec<String> = if services {
+        service::loaded_services(fs_root_path)?
+    } else {
+        PackageInstall::list(Some(fs_root_path))?
+    }.iter()
+        .map(|ident| ident.to_string())
+        .collect();
+    idents.sort();
+    idents.dedup();
+    for ident in idents.iter() {
+        println!("{}", ident);
+    }
+    Ok(())
+}
+
+/// The script hooking the completion of package and service identifiers into the completers
+/// generated by clap
+fn dynamic_completers(shell: Shell) -> Option<String> {
+    match shell {
+        Shell::Bash => Some(format!(
+            r#"
+_hab_dynamic() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}"
+    if [[ $COMP_CWORD -eq 3 ]]; then
+        case "${{COMP_WORDS[1]}} ${{COMP_WORDS[2]}}" in
+            {})
+                COMPREPLY=( $(compgen -W "$(hab cli candidates packages 2>/dev/null)" -- "$cur") )
+                return 0
+                ;;
+            {})
+                COMPREPLY=( $(compgen -W "$(hab cli candidates services 2>/dev/null)" -- "$cur") )
+                return 0
+                ;;
+        esac
+    fi
+    _hab "$@"
+}}
+complete -F _hab_dynamic -o bashdefault -o default hab
+"#,
+            case_patterns(PACKAGE_COMMANDS),
+            case_patterns(SERVICE_COMMANDS)
+        )),
+        Shell::Zsh => Some(format!(
+            r#"
+_hab_dynamic() {{
+    if (( CURRENT == 4 )); then
+        case "$words[2] $words[3]" in
+            ({})
+                compadd -- ${{(f)"$(hab cli candidates packages 2>/dev/null)"}}
+                return
+                ;;
+            ({})
+                compadd -- ${{(f)"$(hab cli candidates services 2>/dev/null)"}}
+                return
+                ;;
+        esac
+    fi
+    _hab "$@"
+}}
+compdef _hab_dynamic hab
+"#,
+            case_patterns(PACKAGE_COMMANDS),
+            case_patterns(SERVICE_COMMANDS)
+        )),
+        Shell::Fish => {
+            let mut script = String::from("\n");
+            let kinds = [(PACKAGE_COMMANDS, "packages"), (SERVICE_COMMANDS, "services")];
+            for &(commands, kind) in kinds.iter() {
+                for &(parent, command) in commands.iter() {
+                    script.push_str(&format!(
+                        "complete -c hab -n \"__fish_seen_subcommand_from {}; and \
+                         __fish_seen_subcommand_from {}\" -f -a \"(hab cli candidates {} \
+                         2>/dev/null)\"\n",
+                        parent,
+                        command,
+                        kind
+                    ));
+                }
+            }
+            Some(script)
+        }
+        Shell::PowerShell => None,
+    }
+}
+
+/// The alternatives of a `case` pattern matching the given subcommands
+fn case_patterns(commands: &[(&str, &str)]) -> String {
+    commands
+        .iter()
+        .map(|&(parent, command)| format!("\"{} {}\"", parent, command))
+        .collect::<Vec<String>>()
+        .join("|")
+}
+
+#[cfg(test)]
+mod test {
+    use clap::Shell;
+
+    use super::{case_patterns, dynamic_completers};
+
+    #[test]
+    fn case_pattern_alternatives() {
+        assert_eq!(
+            case_patterns(&[("svc", "stop"), ("svc", "unload")]),
+            "\"svc stop\"|\"svc unload\""
+        );
+    }
+
+    #[test]
+    fn dynamic_completers_by_shell() {
+        let bash = dynamic_completers(Shell::Bash).unwrap();
+        assert!(bash.contains("\"pkg path\"|\"pkg uninstall\"|\"svc load\""));
+        assert!(bash.contains("complete -F _hab_dynamic"));
+        assert!(dynamic_completers(Shell::Zsh).unwrap().contains(
+            "compdef _hab_dynamic hab",
+        ));
+        assert!(dynamic_completers(Shell::Fish).unwrap().contains(
+            "__fish_seen_subcommand_from svc; and __fish_seen_subcommand_from unload",
+        ));
+        assert!(dynamic_completers(Shell::PowerShell).is_none());
+    }
+}
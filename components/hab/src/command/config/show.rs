@@ -0,0 +1,187 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shows the configuration a service loaded by the local Supervisor is running with.
+//!
+//! The Supervisor merges a service's configuration from its package's `default.toml`, the
+//! environment, its `user.toml` and the configuration gossiped to its service group. Both the
+//! merged configuration and the defaults it was merged onto are read from the Supervisor's HTTP
+//! gateway, so that only the settings which were changed from the defaults can be shown.
+
+use std::collections::BTreeMap;
+
+use common::ui::UI;
+use hcore::service::ServiceGroup;
+use http_client::{self, ApiClient};
+use http_client::util::decoded_response;
+use hyper::status::StatusCode;
+use toml;
+
+use error::{Error, Result};
+use {PRODUCT, VERSION};
+
+pub const DEFAULT_LISTEN_HTTP: &'static str = "127.0.0.1:9631";
+
+/// A setting whose value differs from the one in the package's `default.toml`
+#[derive(Debug, PartialEq, Serialize)]
+struct ConfigChange {
+    key: String,
+    default: Option<toml::Value>,
+    value: Option<toml::Value>,
+}
+
+/// Prints the configuration of a service group, or with `diff_default` only its settings which
+/// differ from the defaults.
+pub fn start(
+    ui: &mut UI,
+    service_group: &ServiceGroup,
+    listen_http: &str,
+    diff_default: bool,
+) -> Result<()> {
+    let client = ApiClient::new(&format!("http://{}", listen_http), PRODUCT, VERSION, None)?;
+    let path = match service_group.org() {
+        Some(org) => {
+            format!(
+                "services/{}/{}/{}/config",
+                service_group.service(),
+                service_group.group(),
+                org
+            )
+        }
+        None => format!("services/{}/{}/config", service_group.service(), service_group.group()),
+    };
+    let config = fetch(&client, &path, service_group)?;
+    if !diff_default {
+        if ui.is_json() {
+            ui.json(&config)?;
+        } else {
+            print!("{}", toml::to_string(&config)?);
+        }
+        return Ok(());
+    }
+
+    let default = fetch(&client, &format!("{}/default", path), service_group)?;
+    let changes = diff(&default, &config);
+    if ui.is_json() {
+        ui.json(&changes)?;
+        return Ok(());
+    }
+    if changes.is_empty() {
+        ui.warn(format!("{} is running with its default configuration", service_group))?;
+        return Ok(());
+    }
+    for change in changes.iter() {
+        if let Some(ref default) = change.default {
+            println!("- {} = {}", change.key, default);
+        }
+        if let Some(ref value) = change.value {
+            println!("+ {} = {}", change.key, value);
+        }
+    }
+    Ok(())
+}
+
+fn fetch(client: &ApiClient, path: &str, service_group: &ServiceGroup) -> Result<toml::Value> {
+    let res = client.get(path).send().map_err(http_client::Error::from)?;
+    match res.status {
+        StatusCode::Ok => Ok(decoded_response(res)?),
+        StatusCode::NotFound => Err(Error::ServiceNotLoaded(service_group.to_string())),
+        status => Err(Error::SupervisorGateway(format!("GET /{} replied {}", path, status))),
+    }
+}
+
+/// The settings whose values differ between both configurations, by their dotted keys
+fn diff(default: &toml::Value, config: &toml::Value) -> Vec<ConfigChange> {
+    let mut defaults = BTreeMap::new();
+    flatten("", default, &mut defaults);
+    let mut values = BTreeMap::new();
+    flatten("", config, &mut values);
+
+    let mut changes: Vec<ConfigChange> = values
+        .iter()
+        .filter(|&(key, value)| defaults.get(key) != Some(value))
+        .map(|(key, value)| {
+            ConfigChange {
+                key: key.clone(),
+                default: defaults.get(key).cloned(),
+                value: Some(value.clone()),
+            }
+        })
+        .collect();
+    for (key, default) in defaults.iter().filter(|&(key, _)| !values.contains_key(key)) {
+        changes.push(ConfigChange {
+            key: key.clone(),
+            default: Some(default.clone()),
+            value: None,
+        });
+    }
+    changes.sort_by(|a, b| a.key.cmp(&b.key));
+    changes
+}
+
+/// Collects the values of all settings, with the keys of nested tables joined by dots
+fn flatten(prefix: &str, value: &toml::Value, settings: &mut BTreeMap<String, toml::Value>) {
+    match *value {
+        toml::Value::Table(ref table) => {
+            for (key, value) in table.iter() {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten(&key, value, settings);
+            }
+        }
+        _ => {
+            settings.insert(prefix.to_string(), value.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_against_default() {
+        let default: toml::Value = "port = 6379\n[tls]\nenabled = false\nciphers = \"HIGH\"\n"
+            .parse()
+            .unwrap();
+        assert!(diff(&default, &default).is_empty());
+
+        let config: toml::Value = "port = 6380\nname = \"cache\"\n[tls]\nenabled = false\n"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            diff(&default, &config),
+            vec![
+                ConfigChange {
+                    key: "name".to_string(),
+                    default: None,
+                    value: Some(toml::Value::String("cache".to_string())),
+                },
+                ConfigChange {
+                    key: "port".to_string(),
+                    default: Some(toml::Value::Integer(6379)),
+                    value: Some(toml::Value::Integer(6380)),
+                },
+                ConfigChange {
+                    key: "tls.ciphers".to_string(),
+                    default: Some(toml::Value::String("HIGH".to_string())),
+                    value: None,
+                },
+            ]
+        );
+    }
+}
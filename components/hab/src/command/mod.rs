@@ -14,6 +14,7 @@
 
 pub mod butterfly;
 pub mod cli;
+pub mod config;
 pub mod launcher;
 pub mod origin;
 pub mod pkg;
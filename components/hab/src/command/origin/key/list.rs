@@ -0,0 +1,60 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use common::ui::UI;
+use hcore::crypto::SigKeyPair;
+
+use error::Result;
+
+/// A key pair of an origin, and which of its keys are in the key cache
+#[derive(Serialize)]
+struct OriginKey {
+    name_with_rev: String,
+    public: bool,
+    secret: bool,
+}
+
+/// Prints the key pairs of an origin found in the key cache, newest first.
+pub fn start(ui: &mut UI, origin: &str, cache: &Path) -> Result<()> {
+    let keys: Vec<OriginKey> = SigKeyPair::get_pairs_for(origin, cache, None)?
+        .iter()
+        .map(|pair| {
+            OriginKey {
+                name_with_rev: pair.name_with_rev(),
+                public: pair.public().is_ok(),
+                secret: pair.secret().is_ok(),
+            }
+        })
+        .collect();
+
+    if ui.is_json() {
+        ui.json(&keys)?;
+        return Ok(());
+    }
+    if keys.is_empty() {
+        ui.warn(format!("No origin keys found for {}", origin))?;
+        return Ok(());
+    }
+    for key in keys.iter() {
+        let kinds = match (key.public, key.secret) {
+            (true, true) => "public, secret",
+            (true, false) => "public",
+            _ => "secret",
+        };
+        println!("{} ({})", key.name_with_rev, kinds);
+    }
+    Ok(())
+}
@@ -16,6 +16,8 @@ pub mod download;
 pub mod export;
 pub mod generate;
 pub mod import;
+pub mod list;
+pub mod rotate;
 pub mod upload_latest;
 pub mod upload;
 
@@ -0,0 +1,87 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Rotates the keys of an origin in one go: a new signing key revision is generated and its
+//! public key uploaded to Builder, the secrets of the origin are re-encrypted with a new revision
+//! of its encryption key, and older signing key revisions are optionally deprecated.
+
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+use hcore::crypto::SigKeyPair;
+use hcore::package::ident;
+use hcore::Error::InvalidOrigin;
+
+use error::{Error, Result};
+use {PRODUCT, VERSION};
+
+pub fn start(
+    ui: &mut UI,
+    bldr_url: &str,
+    token: &str,
+    origin: &str,
+    deprecate: bool,
+    cache: &Path,
+) -> Result<()> {
+    if !ident::is_valid_origin_name(origin) {
+        return Err(Error::from(InvalidOrigin(origin.to_string())));
+    }
+    let depot_client = Client::new(bldr_url, PRODUCT, VERSION, None)?;
+    ui.begin(format!("Rotating origin keys for {}", origin))?;
+
+    let pair = SigKeyPair::generate_pair_for_origin(origin)?;
+    pair.to_pair_files(cache)?;
+    ui.status(Status::Created, pair.name_with_rev())?;
+
+    let public_keyfile = SigKeyPair::get_public_key_path(&pair.name_with_rev(), cache)?;
+    ui.status(Status::Uploading, public_keyfile.display())?;
+    depot_client.put_origin_key(
+        origin,
+        &pair.rev,
+        &public_keyfile,
+        token,
+        ui.progress(),
+    )?;
+    ui.status(Status::Uploaded, pair.name_with_rev())?;
+
+    ui.status(Status::Encrypting, "origin secrets with a new encryption key")?;
+    let rotation = depot_client.rotate_origin_encryption_key(origin, token)?;
+    for name in rotation.reencrypted.iter() {
+        ui.status(Status::Encrypting, format!("{} with {}-{}", name, origin, rotation.revision))?;
+    }
+    for name in rotation.skipped.iter() {
+        ui.warn(format!(
+            "Secret {} is encrypted with the Builder key by an older release and was left as \
+             it is. Upload it again to encrypt it with the origin's key.",
+            name
+        ))?;
+    }
+
+    if deprecate {
+        let deprecated = depot_client.deprecate_origin_keys(origin, token)?;
+        if deprecated.is_empty() {
+            ui.status(Status::Using, "no older key revisions to deprecate")?;
+        }
+        for key in deprecated {
+            ui.status(
+                Status::Custom('✓', "Deprecated".to_string()),
+                format!("{}-{}", key.get_origin(), key.get_revision()),
+            )?;
+        }
+    }
+
+    ui.end(format!("Rotated origin key {}.", pair.name_with_rev()))?;
+    Ok(())
+}
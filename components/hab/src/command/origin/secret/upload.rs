@@ -0,0 +1,49 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use base64;
+use common::ui::{Status, UI};
+use depot_client::Client;
+use hcore::crypto::BoxKeyPair;
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+pub fn start(
+    ui: &mut UI,
+    bldr_url: &str,
+    token: &str,
+    origin: &str,
+    key: &str,
+    secret: &str,
+) -> Result<()> {
+    let depot_client = Client::new(bldr_url, PRODUCT, VERSION, None)?;
+
+    ui.status(
+        Status::Downloading,
+        format!("encryption key of origin {}", origin),
+    )?;
+    let public_key = depot_client.fetch_origin_encryption_key(origin, token)?;
+    let kp = BoxKeyPair::from_key_strings(&public_key, None)?;
+
+    ui.status(Status::Encrypting, format!("value for key {}", key))?;
+    let ciphertext = kp.encrypt(secret.as_bytes(), None)?;
+    let encrypted = base64::encode(&ciphertext);
+
+    ui.status(Status::Uploading, format!("secret for key {}", key))?;
+    depot_client.create_origin_secret(origin, token, key, &encrypted)?;
+    ui.status(Status::Uploaded, format!("secret for key {}", key))?;
+
+    Ok(())
+}
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-//! Find out what channels a package belongs to.
+//! Find out what channels a package belongs to, or what channels an origin has.
 //!
 //! # Examples
 //!
@@ -22,6 +22,12 @@
 //! This will return a list of all the channels that acme/redis/2.0.7/2112010203120101
 //! is in.
 //!
+//! ```bash
+//! $ hab pkg channels acme
+//! ```
+//! This will return a list of all the channels of the acme origin, along with how many packages
+//! are in each of them.
+//!
 //! Notes:
 //!    The package should already have been uploaded to Builder.
 //!    If the specified package does not exist, this will fail.
@@ -52,3 +58,26 @@ pub fn start(ui: &mut UI, url: &str, ident: &PackageIdent, token: Option<&str>)
 
     Ok(())
 }
+
+/// Return a list of the channels of an origin with how many packages are in each.
+///
+/// # Failures
+///
+/// * Fails if it cannot find the specified origin in Builder.
+pub fn start_origin(ui: &mut UI, url: &str, origin: &str, token: Option<&str>) -> Result<()> {
+    let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
+
+    ui.begin(format!("Retrieving channels of {}", origin))?;
+    let channels = depot_client.list_channels_with_counts(origin, false, token)?;
+    let width = channels.iter().map(|c| c.name.len()).max().unwrap_or(0);
+    for channel in &channels {
+        println!(
+            "{:width$}  {} packages",
+            channel.name,
+            channel.package_count,
+            width = width
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Print the dependency tree of a package.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg dependencies core/redis
+//! ```
+//! This will print the dependencies of the latest installed release of core/redis, each one
+//! followed by its own dependencies. A package which isn't installed is looked up in Builder.
+//!
+//! ```bash
+//! $ hab pkg dependencies core/openssl --reverse
+//! ```
+//! This will print the installed packages which depend on core/openssl, each one followed by the
+//! installed packages depending on it in turn.
+//!
+//! Notes:
+//!    A package appearing more than once in the tree is only expanded the first time, and is
+//!    marked with `(*)` after that.
+//!
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use common::ui::UI;
+use depot_client::Client;
+use hcore::package::{PackageIdent, PackageInstall};
+
+use {PRODUCT, VERSION};
+use error::Result;
+
+/// Print the dependency tree of a package, or with `reverse` the tree of the installed packages
+/// depending on it.
+///
+/// # Failures
+///
+/// * The package is neither installed nor in the channel of Builder
+/// * The package isn't installed when asking for the reverse dependencies
+pub fn start(
+    ui: &mut UI,
+    ident: &PackageIdent,
+    fs_root_path: &Path,
+    url: &str,
+    channel: &str,
+    token: Option<&str>,
+    reverse: bool,
+) -> Result<()> {
+    if reverse {
+        let ident = PackageInstall::load(ident, Some(fs_root_path))?.ident().clone();
+        ui.begin(format!("Finding the installed packages depending on {}", &ident))?;
+        let dependents = installed_dependents(fs_root_path)?;
+        let lines = tree(&ident, |package| {
+            Ok(dependents.get(package).cloned().unwrap_or(vec![]))
+        })?;
+        print_tree(&lines);
+        return Ok(());
+    }
+
+    match PackageInstall::load(ident, Some(fs_root_path)) {
+        Ok(pkg_install) => {
+            let ident = pkg_install.ident().clone();
+            ui.begin(format!("Resolving the dependencies of {}", &ident))?;
+            let lines = tree(&ident, |package| {
+                Ok(PackageInstall::load(package, Some(fs_root_path))?.deps()?)
+            })?;
+            print_tree(&lines);
+        }
+        Err(_) => {
+            let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
+            ui.begin(format!(
+                "Resolving the dependencies of {} in the {} channel of Builder",
+                ident,
+                channel
+            ))?;
+            let remote = depot_client.show_package(ident, Some(channel), token)?;
+            let ident: PackageIdent = remote.get_ident().clone().into();
+            let mut deps = HashMap::new();
+            deps.insert(ident.clone(), to_idents(remote.get_deps()));
+            let lines = tree(&ident, |package| {
+                if let Some(package_deps) = deps.get(package) {
+                    return Ok(package_deps.clone());
+                }
+                let package_deps =
+                    to_idents(depot_client.show_package(package, None, token)?.get_deps());
+                deps.insert(package.clone(), package_deps.clone());
+                Ok(package_deps)
+            })?;
+            print_tree(&lines);
+        }
+    }
+    Ok(())
+}
+
+/// The installed packages directly depending on each installed package
+fn installed_dependents(fs_root_path: &Path) -> Result<HashMap<PackageIdent, Vec<PackageIdent>>> {
+    let mut dependents: HashMap<PackageIdent, Vec<PackageIdent>> = HashMap::new();
+    for ident in PackageInstall::list(Some(fs_root_path))? {
+        let deps = match PackageInstall::load(&ident, Some(fs_root_path)).and_then(|p| p.deps()) {
+            Ok(deps) => deps,
+            Err(err) => {
+                debug!("Unable to read the dependencies of {}, err={}", ident, err);
+                continue;
+            }
+        };
+        for dep in deps {
+            dependents.entry(dep).or_insert(vec![]).push(ident.clone());
+        }
+    }
+    for idents in dependents.values_mut() {
+        idents.sort_by(|a, b| a.origin.cmp(&b.origin).then_with(|| a.cmp(b)));
+    }
+    Ok(dependents)
+}
+
+fn to_idents<T>(idents: &[T]) -> Vec<PackageIdent>
+where
+    T: Clone + Into<PackageIdent>,
+{
+    idents.iter().map(|ident| ident.clone().into()).collect()
+}
+
+/// The lines of the tree below `root`, indented by their depth, with the children of a package
+/// given by `children`. A package is only expanded the first time it appears.
+fn tree<F>(root: &PackageIdent, mut children: F) -> Result<Vec<String>>
+where
+    F: FnMut(&PackageIdent) -> Result<Vec<PackageIdent>>,
+{
+    let mut lines = vec![root.to_string()];
+    let mut expanded = HashSet::new();
+    expanded.insert(root.clone());
+    let mut stack: Vec<(PackageIdent, usize)> = children(root)?
+        .into_iter()
+        .rev()
+        .map(|ident| (ident, 1))
+        .collect();
+    while let Some((ident, depth)) = stack.pop() {
+        let indent = "    ".repeat(depth);
+        if !expanded.insert(ident.clone()) {
+            lines.push(format!("{}{} (*)", indent, ident));
+            continue;
+        }
+        lines.push(format!("{}{}", indent, ident));
+        for child in children(&ident)?.into_iter().rev() {
+            stack.push((child, depth + 1));
+        }
+    }
+    Ok(lines)
+}
+
+fn print_tree(lines: &[String]) {
+    for line in lines {
+        println!("{}", line);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use super::tree;
+
+    fn ident(ident: &str) -> PackageIdent {
+        PackageIdent::from_str(ident).unwrap()
+    }
+
+    #[test]
+    fn dependency_tree() {
+        let glibc = ident("core/glibc/2.22/20170513201042");
+        let openssl = ident("core/openssl/1.0.2l/20170513215106");
+        let nginx = ident("core/nginx/1.11.10/20170513215300");
+        let mut deps = HashMap::new();
+        deps.insert(nginx.clone(), vec![openssl.clone(), glibc.clone()]);
+        deps.insert(openssl.clone(), vec![glibc.clone()]);
+        deps.insert(glibc.clone(), vec![]);
+
+        let lines = tree(&nginx, |package| Ok(deps[package].clone())).unwrap();
+        assert_eq!(
+            lines,
+            vec![
+                "core/nginx/1.11.10/20170513215300",
+                "    core/openssl/1.0.2l/20170513215106",
+                "        core/glibc/2.22/20170513201042",
+                "    core/glibc/2.22/20170513201042 (*)",
+            ]
+        );
+        assert_eq!(
+            tree(&glibc, |package| Ok(deps[package].clone())).unwrap(),
+            vec!["core/glibc/2.22/20170513201042"]
+        );
+    }
+}
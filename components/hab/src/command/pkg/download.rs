@@ -0,0 +1,300 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Downloads packages from Builder without installing them.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg download core/redis --target x86_64-linux --download-directory ./bundle
+//! ```
+//!
+//! Will download the latest `core/redis` built for `x86_64-linux`, the artifacts of all of its
+//! transitive dependencies, and the public origin keys they are signed with. Artifacts end up in
+//! `./bundle/artifacts` and keys in `./bundle/keys`, which is the layout expected when installing
+//! on a host that can't reach Builder.
+
+use std::fs;
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use depot_client::Client;
+use error::{Error, Result};
+use hcore;
+use hcore::crypto::artifact;
+use hcore::crypto::SigKeyPair;
+use hcore::crypto::keys::parse_name_with_rev;
+use hcore::package::{PackageArchive, PackageIdent, PackageTarget};
+use {PRODUCT, VERSION};
+
+/// Downloads the artifacts of the given packages built for `target`, of all their transitive
+/// dependencies, and the public origin keys needed to verify them into `download_dir`. Packages
+/// already downloaded there are reused.
+///
+/// # Failures
+///
+/// * A package isn't in Builder for the given target
+/// * An artifact or origin key can't be downloaded or written to `download_dir`
+/// * A downloaded artifact can't be verified
+pub fn start(
+    ui: &mut UI,
+    url: &str,
+    channel: Option<&str>,
+    idents: &[PackageIdent],
+    target: &PackageTarget,
+    download_dir: &Path,
+    token: Option<&str>,
+) -> Result<()> {
+    let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
+    let artifacts_dir = download_dir.join("artifacts");
+    let keys_dir = download_dir.join("keys");
+    fs::create_dir_all(&artifacts_dir)?;
+    fs::create_dir_all(&keys_dir)?;
+
+    let mut downloaded: Vec<(PackageIdent, PackageArchive)> = Vec::new();
+    for ident in idents {
+        ui.begin(format!("Downloading {} for {}", ident, target))?;
+        let ident = if ident.fully_qualified() {
+            ident.clone()
+        } else {
+            ui.status(Status::Determining, format!("latest version of {}", ident))?;
+            depot_client
+                .show_package_for_target(ident, channel, Some(target), token)?
+                .into()
+        };
+        fetch_artifacts(
+            ui,
+            &depot_client,
+            &ident,
+            target,
+            &artifacts_dir,
+            token,
+            &mut downloaded,
+        )?;
+    }
+
+    ui.begin("Downloading public origin keys")?;
+    let mut signers: Vec<String> = Vec::new();
+    for &(ref ident, ref archive) in downloaded.iter() {
+        let name_with_rev = artifact::artifact_signer(&archive.path)?;
+        if !signers.contains(&name_with_rev) {
+            fetch_origin_key(ui, &depot_client, &name_with_rev, &keys_dir)?;
+            signers.push(name_with_rev);
+        }
+        ui.status(Status::Verifying, ident)?;
+        archive.verify(&keys_dir)?;
+        ui.status(Status::Verified, ident)?;
+    }
+
+    ui.end(format!(
+        "Downloaded {} artifacts and {} public origin keys into {}",
+        downloaded.len(),
+        signers.len(),
+        download_dir.display()
+    ))?;
+    Ok(())
+}
+
+/// Downloads the artifacts of a fully qualified package and of its transitive dependencies into
+/// `artifacts_dir`, adding them to `downloaded`. Packages which are in `downloaded` already are
+/// skipped.
+fn fetch_artifacts(
+    ui: &mut UI,
+    depot_client: &Client,
+    ident: &PackageIdent,
+    target: &PackageTarget,
+    artifacts_dir: &Path,
+    token: Option<&str>,
+    downloaded: &mut Vec<(PackageIdent, PackageArchive)>,
+) -> Result<()> {
+    if is_downloaded(downloaded, ident) {
+        return Ok(());
+    }
+    let mut archive = fetch_artifact(ui, depot_client, ident, target, artifacts_dir, token)?;
+    let tdeps = archive.tdeps()?;
+    downloaded.push((ident.clone(), archive));
+    for dep in tdeps {
+        if is_downloaded(downloaded, &dep) {
+            continue;
+        }
+        let archive = fetch_artifact(ui, depot_client, &dep, target, artifacts_dir, token)?;
+        downloaded.push((dep, archive));
+    }
+    Ok(())
+}
+
+fn is_downloaded(downloaded: &[(PackageIdent, PackageArchive)], ident: &PackageIdent) -> bool {
+    downloaded.iter().any(|&(ref d, _)| d == ident)
+}
+
+/// Downloads the artifact of a fully qualified package into `artifacts_dir`, unless it's there
+/// already, and checks that it was built for `target`.
+fn fetch_artifact(
+    ui: &mut UI,
+    depot_client: &Client,
+    ident: &PackageIdent,
+    target: &PackageTarget,
+    artifacts_dir: &Path,
+    token: Option<&str>,
+) -> Result<PackageArchive> {
+    // Unwrap OK, the ident is fully qualified
+    let path = artifacts_dir.join(ident.archive_name_with_target(target).unwrap());
+    let mut archive = if path.is_file() {
+        ui.status(Status::Using, format!("{} from {}", ident, artifacts_dir.display()))?;
+        PackageArchive::new(path)
+    } else {
+        ui.status(Status::Downloading, ident)?;
+        depot_client.fetch_package(
+            ident,
+            token,
+            artifacts_dir,
+            ui.progress(),
+        )?
+    };
+    let artifact_target = archive.target()?;
+    if &artifact_target != target {
+        return Err(Error::HabitatCore(hcore::Error::TargetMatchError(format!(
+            "{} is built for {}, not for {}",
+            ident,
+            artifact_target,
+            target
+        ))));
+    }
+    Ok(archive)
+}
+
+/// Downloads a public origin key into `keys_dir`, unless it's there already.
+fn fetch_origin_key(
+    ui: &mut UI,
+    depot_client: &Client,
+    name_with_rev: &str,
+    keys_dir: &Path,
+) -> Result<()> {
+    if SigKeyPair::get_public_key_path(name_with_rev, keys_dir).is_ok() {
+        ui.status(
+            Status::Using,
+            format!("{} public origin key", name_with_rev),
+        )?;
+        return Ok(());
+    }
+    ui.status(
+        Status::Downloading,
+        format!("{} public origin key", name_with_rev),
+    )?;
+    let (name, rev) = parse_name_with_rev(name_with_rev)?;
+    depot_client.fetch_origin_key(
+        &name,
+        &rev,
+        keys_dir,
+        ui.progress(),
+    )?;
+    ui.status(
+        Status::Cached,
+        format!("{} public origin key", name_with_rev),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use std::str::FromStr;
+
+    use common::ui::UI;
+    use depot_client::Client;
+    use hcore;
+    use hcore::package::{PackageArchive, PackageIdent, PackageTarget};
+    use tempdir::TempDir;
+
+    use error::Error;
+    use super::fetch_artifacts;
+    use {PRODUCT, VERSION};
+
+    fn ident(ident: &str) -> PackageIdent {
+        PackageIdent::from_str(ident).unwrap()
+    }
+
+    fn linux() -> PackageTarget {
+        PackageTarget::from_str("x86_64-linux").unwrap()
+    }
+
+    /// Puts the fixture artifact of a package into `artifacts_dir` as if it was built for
+    /// `target`, so that it isn't downloaded
+    fn place(artifacts_dir: &Path, ident: &PackageIdent, target: &PackageTarget) -> PathBuf {
+        let fixture = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join(ident.archive_name_with_target(&linux()).unwrap());
+        let path = artifacts_dir.join(ident.archive_name_with_target(target).unwrap());
+        fs::copy(fixture, &path).unwrap();
+        path
+    }
+
+    fn fetch(
+        idents: &[PackageIdent],
+        target: &PackageTarget,
+        artifacts_dir: &Path,
+    ) -> Result<Vec<(PackageIdent, PackageArchive)>, Error> {
+        let mut ui = UI::with_sinks();
+        let depot_client = Client::new("http://127.0.0.1:1", PRODUCT, VERSION, None).unwrap();
+        let mut downloaded = Vec::new();
+        for ident in idents {
+            fetch_artifacts(
+                &mut ui,
+                &depot_client,
+                ident,
+                target,
+                artifacts_dir,
+                None,
+                &mut downloaded,
+            )?;
+        }
+        Ok(downloaded)
+    }
+
+    #[test]
+    fn packages_and_dependencies_are_fetched_once() {
+        let dir = TempDir::new("download").unwrap();
+        let app = ident("core/app/1.0.0/20170101000000");
+        let lib = ident("core/lib/1.0.0/20170101000000");
+        let glibc = ident("core/glibc/2.22/20170101000000");
+        let mut paths = Vec::new();
+        for ident in &[&app, &lib, &glibc] {
+            paths.push(place(dir.path(), ident, &linux()));
+        }
+
+        let downloaded = fetch(&[lib.clone(), app.clone(), lib.clone()], &linux(), dir.path())
+            .unwrap();
+        let idents: Vec<&PackageIdent> = downloaded.iter().map(|&(ref i, _)| i).collect();
+        assert_eq!(idents, vec![&lib, &glibc, &app]);
+        let archive_paths: Vec<&PathBuf> = downloaded.iter().map(|&(_, ref a)| &a.path).collect();
+        assert_eq!(archive_paths, vec![&paths[1], &paths[2], &paths[0]]);
+    }
+
+    #[test]
+    fn artifacts_built_for_another_target_are_rejected() {
+        let dir = TempDir::new("download").unwrap();
+        let windows = PackageTarget::from_str("x86_64-windows").unwrap();
+        let glibc = ident("core/glibc/2.22/20170101000000");
+        // Named for Windows, but built for Linux
+        place(dir.path(), &glibc, &windows);
+
+        match fetch(&[glibc], &windows, dir.path()) {
+            Err(Error::HabitatCore(hcore::Error::TargetMatchError(_))) => (),
+            Err(e) => panic!("Unexpected error, err={}", e),
+            Ok(_) => panic!("Artifact built for another target was accepted"),
+        }
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! List the installed packages.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg list 'core/*ssl*'
+//! ```
+//! This will print the identifiers of every installed release of the packages in the `core`
+//! origin whose names contain `ssl`.
+//!
+//! Notes:
+//!    Each part of the filter may use `*` to match any run of characters and `?` to match a
+//!    single one. Parts missing from the filter match everything.
+//!
+
+use std::path::Path;
+
+use common::ui::UI;
+use hcore::package::{PackageIdent, PackageInstall};
+
+use error::{Error, Result};
+
+/// Prints the installed packages matching `filter`, one per line or as a JSON array.
+pub fn start(ui: &mut UI, filter: Option<&str>, fs_root_path: &Path) -> Result<()> {
+    let filter: Vec<&str> = filter.map_or(vec![], |f| f.split('/').collect());
+    if filter.len() > 4 {
+        return Err(Error::ArgumentError(
+            "The filter has at most 4 parts, origin/name/version/release",
+        ));
+    }
+    let mut idents: Vec<PackageIdent> = PackageInstall::list(Some(fs_root_path))?
+        .into_iter()
+        .filter(|ident| matches(&filter, ident))
+        .collect();
+    idents.sort_by(|a, b| a.origin.cmp(&b.origin).then_with(|| a.cmp(b)));
+
+    if ui.is_json() {
+        ui.json(&idents)?;
+    } else {
+        for ident in idents.iter() {
+            println!("{}", ident);
+        }
+    }
+    Ok(())
+}
+
+/// Whether each part of the filter matches the same part of the identifier
+fn matches(filter: &[&str], ident: &PackageIdent) -> bool {
+    let parts = [
+        Some(ident.origin.as_str()),
+        Some(ident.name.as_str()),
+        ident.version.as_ref().map(|v| v.as_str()),
+        ident.release.as_ref().map(|r| r.as_str()),
+    ];
+    filter.iter().zip(parts.iter()).all(|(pattern, part)| {
+        glob_match(pattern.as_bytes(), part.unwrap_or("").as_bytes())
+    })
+}
+
+/// Matches `text` against a pattern where `*` stands for any run of characters and `?` for any
+/// single one
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some((&b'*', rest)) => (0..text.len() + 1).any(|i| glob_match(rest, &text[i..])),
+        Some((&b'?', rest)) => !text.is_empty() && glob_match(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && glob_match(rest, &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use super::{glob_match, matches};
+
+    #[test]
+    fn glob_patterns() {
+        assert!(glob_match(b"openssl", b"openssl"));
+        assert!(glob_match(b"*ssl*", b"openssl"));
+        assert!(glob_match(b"*", b""));
+        assert!(glob_match(b"1.?.2", b"1.0.2"));
+        assert!(!glob_match(b"ssl", b"openssl"));
+        assert!(!glob_match(b"1.?.2", b"1.10.2"));
+        assert!(!glob_match(b"open*x", b"openssl"));
+    }
+
+    #[test]
+    fn filter_parts() {
+        let ident = PackageIdent::from_str("core/openssl/1.0.2l/20170513215106").unwrap();
+        assert!(matches(&[], &ident));
+        assert!(matches(&["core"], &ident));
+        assert!(matches(&["c*", "*ssl"], &ident));
+        assert!(matches(&["core", "openssl", "1.0.*", "2017*"], &ident));
+        assert!(!matches(&["acme"], &ident));
+        assert!(!matches(&["core", "openssl", "1.1.*"], &ident));
+    }
+}
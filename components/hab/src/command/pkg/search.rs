@@ -12,13 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use common::ui::UI;
 use error::Result;
 use depot_client::Client;
 use {PRODUCT, VERSION};
 
-pub fn start(st: &str, url: &str, token: Option<&str>) -> Result<()> {
+pub fn start(ui: &mut UI, st: &str, url: &str, token: Option<&str>) -> Result<()> {
     let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
     let (packages, more) = depot_client.search_package(st, token)?;
+    if ui.is_json() {
+        if more {
+            ui.warn(format!(
+                "Search returned too many items, only showing the first {}",
+                packages.len()
+            ))?;
+        }
+        ui.json(&packages)?;
+        return Ok(());
+    }
     match packages.len() {
         0 => println!("No packages found that match '{}'", st),
         _ => {
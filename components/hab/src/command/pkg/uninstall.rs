@@ -0,0 +1,264 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Uninstall a package.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg uninstall acme/redis/2.0.7/2112010203120101 --deps
+//! ```
+//! This will remove acme/redis/2.0.7/2112010203120101 along with the dependencies it had which
+//! no other installed package or loaded service needs.
+//!
+//! Notes:
+//!    A package which another installed package depends on, or which a service loaded in a
+//!    Supervisor runs, is only uninstalled with `--force`.
+//!
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+use common::ui::{Status, UI};
+use hcore::fs as hfs;
+use hcore::package::{PackageIdent, PackageInstall};
+
+use command::service;
+use error::{Error, Result};
+
+/// Uninstall the latest installed release of a package satisfying `ident`, and the dependencies
+/// no longer needed by anything else with `deps`.
+///
+/// # Failures
+///
+/// * The package isn't installed
+/// * Another installed package or a loaded service needs the package, unless `force` is given
+/// * The package's files can't be removed
+pub fn start(
+    ui: &mut UI,
+    ident: &PackageIdent,
+    fs_root_path: &Path,
+    deps: bool,
+    force: bool,
+) -> Result<()> {
+    let pkg_install = PackageInstall::load(ident, Some(fs_root_path))?;
+    let ident = pkg_install.ident().clone();
+    ui.begin(format!("Uninstalling {}", &ident))?;
+
+    let installed = installed_tdeps(fs_root_path)?;
+    let services = service_idents(fs_root_path)?;
+    let users = users_of(&ident, &installed, &services, &HashSet::new());
+    if !users.is_empty() {
+        if !force {
+            return Err(Error::PackageInUse(ident.to_string(), users));
+        }
+        ui.warn(format!(
+            "Uninstalling {} although it's needed by {}",
+            &ident,
+            users.join(", ")
+        ))?;
+    }
+
+    let mut uninstalled = vec![ident.clone()];
+    if deps {
+        uninstalled.extend(orphans(&ident, &installed, &services));
+    }
+    for package in uninstalled.iter() {
+        ui.status(Status::Deleting, package)?;
+        remove_package(package, fs_root_path)?;
+    }
+    ui.end(format!(
+        "Uninstalled {} package{}",
+        uninstalled.len(),
+        if uninstalled.len() == 1 { "" } else { "s" }
+    ))?;
+    Ok(())
+}
+
+/// The transitive dependencies of every installed package. A package whose dependencies can't
+/// be read is taken to have none.
+fn installed_tdeps(fs_root_path: &Path) -> Result<HashMap<PackageIdent, Vec<PackageIdent>>> {
+    let mut installed = HashMap::new();
+    for ident in PackageInstall::list(Some(fs_root_path))? {
+        let tdeps = match PackageInstall::load(&ident, Some(fs_root_path)).and_then(|p| p.tdeps()) {
+            Ok(tdeps) => tdeps,
+            Err(err) => {
+                debug!("Unable to read the dependencies of {}, err={}", ident, err);
+                vec![]
+            }
+        };
+        installed.insert(ident, tdeps);
+    }
+    Ok(installed)
+}
+
+/// The installed packages run by the services loaded in any Supervisor
+fn service_idents(fs_root_path: &Path) -> Result<Vec<PackageIdent>> {
+    let mut idents = Vec::new();
+    for ident in service::loaded_services(fs_root_path)? {
+        if let Ok(pkg_install) = PackageInstall::load(&ident, Some(fs_root_path)) {
+            idents.push(pkg_install.ident().clone());
+        }
+    }
+    Ok(idents)
+}
+
+/// What still needs `ident` once the packages in `uninstalled` are gone, which are the installed
+/// packages depending on it and the loaded services running it
+fn users_of(
+    ident: &PackageIdent,
+    installed: &HashMap<PackageIdent, Vec<PackageIdent>>,
+    services: &[PackageIdent],
+    uninstalled: &HashSet<PackageIdent>,
+) -> Vec<String> {
+    let mut users: Vec<String> = installed
+        .iter()
+        .filter(|&(package, tdeps)| {
+            package != ident && !uninstalled.contains(package) && tdeps.contains(ident)
+        })
+        .map(|(package, _)| package.to_string())
+        .collect();
+    users.sort();
+    users.extend(services.iter().filter(|s| *s == ident).map(|s| {
+        format!("the service running {}", s)
+    }));
+    users
+}
+
+/// The installed dependencies of `ident` which nothing needs once it's uninstalled, in an order
+/// they can be uninstalled in
+fn orphans(
+    ident: &PackageIdent,
+    installed: &HashMap<PackageIdent, Vec<PackageIdent>>,
+    services: &[PackageIdent],
+) -> Vec<PackageIdent> {
+    let candidates: Vec<&PackageIdent> = installed
+        .get(ident)
+        .map_or(vec![], |tdeps| tdeps.iter().collect())
+        .into_iter()
+        .filter(|dep| installed.contains_key(*dep))
+        .collect();
+    let mut uninstalled = HashSet::new();
+    uninstalled.insert(ident.clone());
+    let mut orphans = Vec::new();
+    // A dependency is only an orphan once the other orphans depending on it are gone
+    loop {
+        let mut found = false;
+        for dep in candidates.iter() {
+            if !uninstalled.contains(*dep) &&
+                users_of(dep, installed, services, &uninstalled).is_empty()
+            {
+                uninstalled.insert((*dep).clone());
+                orphans.push((*dep).clone());
+                found = true;
+            }
+        }
+        if !found {
+            return orphans;
+        }
+    }
+}
+
+/// Removes the files of a package, along with the directories of its version, name and origin
+/// once nothing else is in them
+fn remove_package(ident: &PackageIdent, fs_root_path: &Path) -> Result<()> {
+    let path = hfs::pkg_install_path(ident, Some(fs_root_path));
+    fs::remove_dir_all(&path)?;
+    let root = hfs::pkg_root_path(Some(fs_root_path));
+    let mut dir = path.parent();
+    while let Some(d) = dir {
+        if d == root || fs::remove_dir(d).is_err() {
+            break;
+        }
+        dir = d.parent();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{HashMap, HashSet};
+    use std::str::FromStr;
+
+    use hcore::package::PackageIdent;
+
+    use super::{orphans, users_of};
+
+    fn ident(ident: &str) -> PackageIdent {
+        PackageIdent::from_str(ident).unwrap()
+    }
+
+    fn installed() -> HashMap<PackageIdent, Vec<PackageIdent>> {
+        let mut installed = HashMap::new();
+        installed.insert(ident("core/glibc/2.22/20170513201042"), vec![]);
+        installed.insert(
+            ident("core/openssl/1.0.2l/20170513215106"),
+            vec![ident("core/glibc/2.22/20170513201042")],
+        );
+        installed.insert(
+            ident("core/redis/3.2.4/20170514001355"),
+            vec![ident("core/glibc/2.22/20170513201042")],
+        );
+        installed.insert(
+            ident("core/nginx/1.11.10/20170513215300"),
+            vec![
+                ident("core/glibc/2.22/20170513201042"),
+                ident("core/openssl/1.0.2l/20170513215106"),
+            ],
+        );
+        installed
+    }
+
+    #[test]
+    fn users_of_package() {
+        let installed = installed();
+        let glibc = ident("core/glibc/2.22/20170513201042");
+        let redis = ident("core/redis/3.2.4/20170514001355");
+        assert_eq!(
+            users_of(&glibc, &installed, &[], &HashSet::new()),
+            vec![
+                "core/nginx/1.11.10/20170513215300",
+                "core/openssl/1.0.2l/20170513215106",
+                "core/redis/3.2.4/20170514001355",
+            ]
+        );
+        assert!(users_of(&redis, &installed, &[], &HashSet::new()).is_empty());
+        assert_eq!(
+            users_of(&redis, &installed, &[redis.clone()], &HashSet::new()),
+            vec!["the service running core/redis/3.2.4/20170514001355"]
+        );
+    }
+
+    #[test]
+    fn orphaned_dependencies() {
+        let mut installed = installed();
+        let nginx = ident("core/nginx/1.11.10/20170513215300");
+        let redis = ident("core/redis/3.2.4/20170514001355");
+        assert_eq!(
+            orphans(&nginx, &installed, &[]),
+            vec![ident("core/openssl/1.0.2l/20170513215106")]
+        );
+
+        installed.remove(&redis);
+        assert_eq!(
+            orphans(&nginx, &installed, &[]),
+            vec![
+                ident("core/openssl/1.0.2l/20170513215106"),
+                ident("core/glibc/2.22/20170513201042"),
+            ]
+        );
+        assert!(orphans(&redis, &installed, &[]).is_empty());
+    }
+}
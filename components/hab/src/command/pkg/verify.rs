@@ -12,16 +12,101 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+
+//! Verify a Habitat Artifact.
+//!
+//! # Examples
+//!
+//! ```bash
+//! $ hab pkg verify acme-redis-3.0.7-21120102031201-x86_64-linux.hart
+//! ```
+//! This will check the checksum of the artifact's contents against its signature, using the
+//! public key of its origin in the key cache, which is downloaded from Builder when missing.
+//!
+//! ```bash
+//! $ hab pkg verify acme-redis-3.0.7-21120102031201-x86_64-linux.hart \
+//!     --origin-key acme-20160810182414.pub
+//! ```
+//! This will only trust the given public origin key, leaving the key cache aside.
+//!
+
+use std::fs::File;
+use std::io::Read;
 use std::path::Path;
 
 use common::ui::{Status, UI};
-use hcore::crypto::artifact;
+use depot_client::Client;
+use hcore;
+use hcore::crypto::{artifact, SigKeyPair};
+use hcore::crypto::keys::{parse_name_with_rev, PairType};
+use tempdir::TempDir;
 
-use error::Result;
+use {PRODUCT, VERSION};
+use error::{Error, Result};
+
+/// The outcome of a verification, as printed with `--json`
+#[derive(Serialize)]
+struct Verified {
+    artifact: String,
+    checksum: String,
+    signer: String,
+}
 
-pub fn start(ui: &mut UI, src: &Path, cache: &Path) -> Result<()> {
+/// Verifies the signature and checksum of an artifact against the public key of its origin, which
+/// is `origin_key` when given.
+///
+/// # Failures
+///
+/// * The artifact isn't signed by the given key
+/// * The key of the artifact's signer isn't cached and can't be downloaded from Builder
+/// * The contents of the artifact don't match its signature
+pub fn start(
+    ui: &mut UI,
+    src: &Path,
+    cache: &Path,
+    origin_key: Option<&Path>,
+    url: &str,
+) -> Result<()> {
     ui.begin(format!("Verifying artifact {}", &src.display()))?;
-    let (name_with_rev, hash) = artifact::verify(src, cache)?;
+    let signer = artifact::artifact_signer(&src)?;
+    let (name_with_rev, hash) = match origin_key {
+        Some(origin_key) => {
+            // Only the given key is trusted, so it's the only one in the key cache used
+            let keys = TempDir::new("hab-verify")?;
+            let mut content = String::new();
+            File::open(origin_key)?.read_to_string(&mut content)?;
+            let (pair_type, key_name_with_rev, _) = SigKeyPair::parse_key_str(&content)?;
+            if pair_type != PairType::Public {
+                return Err(crypto_error(
+                    format!("{} isn't a public origin key", origin_key.display()),
+                ));
+            }
+            if key_name_with_rev != signer {
+                return Err(crypto_error(format!(
+                    "Artifact was signed with {}, not {}",
+                    signer,
+                    key_name_with_rev
+                )));
+            }
+            SigKeyPair::write_file_from_str(&content, keys.path())?;
+            artifact::verify(src, keys.path())?
+        }
+        None => {
+            if SigKeyPair::get_public_key_path(&signer, cache).is_err() {
+                fetch_origin_key(ui, url, &signer, cache)?;
+            }
+            artifact::verify(src, cache)?
+        }
+    };
+
+    if ui.is_json() {
+        ui.json(&Verified {
+            artifact: src.display().to_string(),
+            checksum: hash,
+            signer: name_with_rev,
+        })?;
+        return Ok(());
+    }
     ui.status(
         Status::Verified,
         format!(
@@ -33,3 +118,22 @@ pub fn start(ui: &mut UI, src: &Path, cache: &Path) -> Result<()> {
     ui.end(format!("Verified artifact {}.", &src.display()))?;
     Ok(())
 }
+
+fn fetch_origin_key(ui: &mut UI, url: &str, name_with_rev: &str, cache: &Path) -> Result<()> {
+    ui.status(
+        Status::Downloading,
+        format!("{} public origin key", &name_with_rev),
+    )?;
+    let (name, rev) = parse_name_with_rev(&name_with_rev)?;
+    let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
+    depot_client.fetch_origin_key(&name, &rev, cache, ui.progress())?;
+    ui.status(
+        Status::Cached,
+        format!("{} public origin key", &name_with_rev),
+    )?;
+    Ok(())
+}
+
+fn crypto_error(msg: String) -> Error {
+    Error::HabitatCore(hcore::Error::CryptoError(msg))
+}
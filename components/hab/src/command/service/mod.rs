@@ -13,3 +13,47 @@
 // limitations under the License.
 
 pub mod key;
+
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::Path;
+
+use hcore::fs as hfs;
+use hcore::package::PackageIdent;
+use toml;
+
+use error::Result;
+
+/// The part of a Supervisor's service spec telling which package the service runs
+#[derive(Deserialize)]
+struct ServiceSpec {
+    ident: String,
+}
+
+/// Returns the package identifiers of the services loaded in any Supervisor, as they were given
+/// when loading them.
+pub fn loaded_services(fs_root_path: &Path) -> Result<Vec<PackageIdent>> {
+    let sup_path = fs_root_path.join(hfs::ROOT_PATH).join("sup");
+    let mut idents = Vec::new();
+    let sups = match fs::read_dir(&sup_path) {
+        Ok(sups) => sups,
+        Err(_) => return Ok(idents),
+    };
+    for sup in sups {
+        let specs = match fs::read_dir(sup?.path().join("specs")) {
+            Ok(specs) => specs,
+            Err(_) => continue,
+        };
+        for spec in specs {
+            let path = spec?.path();
+            if path.extension().map_or(true, |ext| ext != "spec") {
+                continue;
+            }
+            let mut content = String::new();
+            File::open(&path)?.read_to_string(&mut content)?;
+            let spec: ServiceSpec = toml::from_str(&content)?;
+            idents.push(spec.ident.parse::<PackageIdent>()?);
+        }
+    }
+    Ok(idents)
+}
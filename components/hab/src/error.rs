@@ -26,6 +26,7 @@ use depot_client;
 use common;
 use hcore;
 use handlebars;
+use http_client;
 use toml;
 
 pub type Result<T> = result::Result<T, Error>;
@@ -38,6 +39,7 @@ pub enum Error {
     ButterflyError(String),
     CannotRemoveFromChannel((String, String)),
     CommandNotFoundInPkg((String, String)),
+    ConfigRenderFailed(String),
     CryptoCLI(String),
     DepotClient(depot_client::Error),
     DockerDaemonDown,
@@ -51,17 +53,21 @@ pub enum Error {
     HabitatCommon(common::Error),
     HabitatCore(hcore::Error),
     HandlebarsRenderError(handlebars::TemplateRenderError),
+    HttpClient(http_client::Error),
     IO(io::Error),
     JobGroupPromoteOrDemote(api_client::Error, bool /* promote */),
     JobGroupCancel(api_client::Error),
     JobGroupPromoteOrDemoteUnprocessable(bool /* promote */),
     PackageArchiveMalformed(String),
+    PackageInUse(String, Vec<String>),
     ParseIntError(num::ParseIntError),
     PathPrefixError(path::StripPrefixError),
     ProvidesError(String),
     RootRequired,
     ScheduleStatus(depot_client::Error),
+    ServiceNotLoaded(String),
     SubcommandNotSupported(String),
+    SupervisorGateway(String),
     UnsupportedExportFormat(String),
     TomlDeserializeError(toml::de::Error),
     TomlSerializeError(toml::ser::Error),
@@ -84,6 +90,9 @@ impl fmt::Display for Error {
                     p
                 )
             }
+            Error::ConfigRenderFailed(ref e) => {
+                format!("Configuration fails to render the service's templates: {}", e)
+            }
             Error::CryptoCLI(ref e) => format!("{}", e),
             Error::DepotClient(ref err) => format!("{}", err),
             Error::DockerDaemonDown => {
@@ -133,6 +142,7 @@ impl fmt::Display for Error {
             Error::HabitatCommon(ref e) => format!("{}", e),
             Error::HabitatCore(ref e) => format!("{}", e),
             Error::HandlebarsRenderError(ref e) => format!("{}", e),
+            Error::HttpClient(ref e) => format!("{}", e),
             Error::IO(ref err) => format!("{}", err),
             Error::JobGroupPromoteOrDemoteUnprocessable(true) => {
                 "Failed to promote job group, the build job is still in progress".to_string()
@@ -154,6 +164,13 @@ impl fmt::Display for Error {
                     e
                 )
             }
+            Error::PackageInUse(ref ident, ref users) => {
+                format!(
+                    "Can't uninstall {}, it's needed by {}. Pass --force to uninstall it anyway.",
+                    ident,
+                    users.join(", ")
+                )
+            }
             Error::ParseIntError(ref err) => format!("{}", err),
             Error::PathPrefixError(ref err) => format!("{}", err),
             Error::ProvidesError(ref err) => format!("Can't find {}", err),
@@ -161,9 +178,13 @@ impl fmt::Display for Error {
                 "Root or administrator permissions required to complete operation".to_string()
             }
             Error::ScheduleStatus(ref e) => format!("Failed to retrieve job group status: {:?}", e),
+            Error::ServiceNotLoaded(ref e) => format!("Service {} is not loaded", e),
             Error::SubcommandNotSupported(ref e) => {
                 format!("Subcommand `{}' not supported on this operating system", e)
             }
+            Error::SupervisorGateway(ref e) => {
+                format!("Supervisor HTTP gateway request failed: {}", e)
+            }
             Error::UnsupportedExportFormat(ref e) => format!("Unsupported export format: {}", e),
             Error::TomlDeserializeError(ref e) => format!("Can't deserialize TOML: {}", e),
             Error::TomlSerializeError(ref e) => format!("Can't serialize TOML: {}", e),
@@ -185,6 +206,7 @@ impl error::Error for Error {
             Error::CommandNotFoundInPkg(_) => {
                 "Command was not found under any 'PATH' directories in the package"
             }
+            Error::ConfigRenderFailed(_) => "Configuration fails to render the service's templates",
             Error::CryptoCLI(_) => "A cryptographic error has occurred",
             Error::DepotClient(ref err) => err.description(),
             Error::DockerDaemonDown => "The Docker daemon could not be found.",
@@ -198,6 +220,7 @@ impl error::Error for Error {
             Error::HabitatCommon(ref err) => err.description(),
             Error::HabitatCore(ref err) => err.description(),
             Error::HandlebarsRenderError(ref err) => err.description(),
+            Error::HttpClient(ref err) => err.description(),
             Error::IO(ref err) => err.description(),
             Error::JobGroupPromoteOrDemoteUnprocessable(true) => {
                 "Failed to promote job group, the build job is still in progress"
@@ -210,6 +233,9 @@ impl error::Error for Error {
             Error::PackageArchiveMalformed(_) => {
                 "Package archive was unreadable or had unexpected contents"
             }
+            Error::PackageInUse(_, _) => {
+                "Package is needed by another installed package or a loaded service"
+            }
             Error::ParseIntError(ref err) => err.description(),
             Error::PathPrefixError(ref err) => err.description(),
             Error::ProvidesError(_) => {
@@ -219,7 +245,9 @@ impl error::Error for Error {
                 "Root or administrator permissions required to complete operation"
             }
             Error::ScheduleStatus(ref err) => err.description(),
+            Error::ServiceNotLoaded(_) => "Service is not loaded",
             Error::SubcommandNotSupported(_) => "Subcommand not supported on this operating system",
+            Error::SupervisorGateway(_) => "Supervisor HTTP gateway request failed",
             Error::UnsupportedExportFormat(_) => "Unsupported export format",
             Error::TomlDeserializeError(_) => "Can't deserialize TOML",
             Error::TomlSerializeError(_) => "Can't serialize TOML",
@@ -259,6 +287,12 @@ impl From<handlebars::TemplateRenderError> for Error {
     }
 }
 
+impl From<http_client::Error> for Error {
+    fn from(err: http_client::Error) -> Error {
+        Error::HttpClient(err)
+    }
+}
+
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
         Error::IO(err)
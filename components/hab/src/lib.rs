@@ -40,7 +40,6 @@ extern crate url;
 extern crate uuid;
 extern crate walkdir;
 extern crate base64;
-#[cfg(test)]
 extern crate tempdir;
 
 pub mod analytics;
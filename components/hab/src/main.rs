@@ -21,6 +21,7 @@ extern crate env_logger;
 extern crate hab;
 extern crate habitat_core as hcore;
 extern crate habitat_common as common;
+extern crate habitat_depot_client as depot_client;
 extern crate handlebars;
 #[macro_use]
 extern crate lazy_static;
@@ -38,14 +39,15 @@ use std::thread;
 
 use clap::{ArgMatches, Shell};
 
-use common::command::package::install::InstallSource;
-use common::ui::{Coloring, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
+use common::command::package::install::{InstallSource, OFFLINE_ENVVAR};
+use common::ui::{Coloring, OutputFormat, UI, NOCOLORING_ENVVAR, NONINTERACTIVE_ENVVAR};
+use depot_client::throttle::THROTTLE_ENVVAR;
 use hcore::channel;
 use hcore::crypto::{init, default_cache_key_path, SigKeyPair};
 use hcore::crypto::keys::PairType;
 use hcore::env as henv;
 use hcore::fs::{cache_artifact_path, cache_analytics_path, cache_key_path};
-use hcore::package::PackageIdent;
+use hcore::package::{PackageIdent, PackageTarget};
 use hcore::service::ServiceGroup;
 use hcore::url::default_bldr_url;
 use hcore::binlink::default_binlink_dir;
@@ -104,12 +106,16 @@ fn start(ui: &mut UI) -> Result<()> {
         })
         .unwrap();
     let app_matches = child.join().unwrap();
+    if json_requested(&app_matches) {
+        ui.set_format(OutputFormat::Json);
+    }
 
     match app_matches.subcommand() {
         ("cli", Some(matches)) => {
             match matches.subcommand() {
                 ("setup", Some(_)) => sub_cli_setup(ui)?,
                 ("completers", Some(m)) => sub_cli_completers(m)?,
+                ("candidates", Some(m)) => sub_cli_candidates(m)?,
                 _ => unreachable!(),
             }
         }
@@ -122,10 +128,26 @@ fn start(ui: &mut UI) -> Result<()> {
                         ("export", Some(sc)) => sub_origin_key_export(sc)?,
                         ("generate", Some(sc)) => sub_origin_key_generate(ui, sc)?,
                         ("import", Some(_)) => sub_origin_key_import(ui)?,
+                        ("list", Some(sc)) => sub_origin_key_list(ui, sc)?,
+                        ("rotate", Some(sc)) => sub_origin_key_rotate(ui, sc)?,
                         ("upload", Some(sc)) => sub_origin_key_upload(ui, sc)?,
                         _ => unreachable!(),
                     }
                 }
+                ("secret", Some(m)) => {
+                    match m.subcommand() {
+                        ("upload", Some(sc)) => sub_origin_secret_upload(ui, sc)?,
+                        ("list", Some(sc)) => sub_origin_secret_list(ui, sc)?,
+                        ("delete", Some(sc)) => sub_origin_secret_delete(ui, sc)?,
+                        _ => unreachable!(),
+                    }
+                }
+                _ => unreachable!(),
+            }
+        }
+        ("config", Some(matches)) => {
+            match matches.subcommand() {
+                ("show", Some(m)) => sub_config_show(ui, m)?,
                 _ => unreachable!(),
             }
         }
@@ -137,11 +159,14 @@ fn start(ui: &mut UI) -> Result<()> {
                         ("cancel", Some(m)) => sub_bldr_job_cancel(ui, m)?,
                         ("promote", Some(m)) => sub_bldr_job_promote_or_demote(ui, m, true)?,
                         ("demote", Some(m)) => sub_bldr_job_promote_or_demote(ui, m, false)?,
+                        ("log", Some(m)) => sub_bldr_job_log(ui, m)?,
                         ("status", Some(m)) => sub_bldr_job_status(ui, m)?,
                         _ => unreachable!(),
                     }
                 }
                 ("encrypt", Some(m)) => sub_bldr_encrypt(ui, m)?,
+                ("export", Some(m)) => sub_bldr_export(ui, m)?,
+                ("import", Some(m)) => sub_bldr_import(ui, m)?,
                 _ => unreachable!(),
             }
         }
@@ -157,15 +182,19 @@ fn start(ui: &mut UI) -> Result<()> {
                 ("export", Some(m)) => sub_pkg_export(ui, m)?,
                 ("hash", Some(m)) => sub_pkg_hash(m)?,
                 ("install", Some(m)) => sub_pkg_install(ui, m)?,
+                ("list", Some(m)) => sub_pkg_list(ui, m)?,
                 ("path", Some(m)) => sub_pkg_path(m)?,
                 ("provides", Some(m)) => sub_pkg_provides(m)?,
-                ("search", Some(m)) => sub_pkg_search(m)?,
+                ("search", Some(m)) => sub_pkg_search(ui, m)?,
                 ("sign", Some(m)) => sub_pkg_sign(ui, m)?,
+                ("uninstall", Some(m)) => sub_pkg_uninstall(ui, m)?,
                 ("upload", Some(m)) => sub_pkg_upload(ui, m)?,
                 ("verify", Some(m)) => sub_pkg_verify(ui, m)?,
                 ("header", Some(m)) => sub_pkg_header(ui, m)?,
                 ("promote", Some(m)) => sub_pkg_promote(ui, m)?,
                 ("demote", Some(m)) => sub_pkg_demote(ui, m)?,
+                ("dependencies", Some(m)) => sub_pkg_dependencies(ui, m)?,
+                ("download", Some(m)) => sub_pkg_download(ui, m)?,
                 _ => unreachable!(),
             }
         }
@@ -230,8 +259,24 @@ fn sub_cli_completers(m: &ArgMatches) -> Result<()> {
     let shell = m.value_of("SHELL").expect(
         "Missing Shell; A shell is required",
     );
-    cli::get().gen_completions_to("hab", shell.parse::<Shell>().unwrap(), &mut io::stdout());
-    Ok(())
+    command::cli::completers::start(shell.parse::<Shell>().unwrap())
+}
+
+fn sub_cli_candidates(m: &ArgMatches) -> Result<()> {
+    let kind = m.value_of("KIND").unwrap(); // Required via clap
+    command::cli::completers::candidates(&*FS_ROOT, kind == "services")
+}
+
+fn sub_config_show(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let service_group = m.value_of("SERVICE_GROUP").unwrap(); // Required via clap
+    let mut service_group = ServiceGroup::from_str(service_group)?;
+    if let Some(org) = m.value_of("ORG") {
+        service_group.set_org(org);
+    }
+    let listen_http = m.value_of("LISTEN_HTTP").unwrap_or(
+        command::config::show::DEFAULT_LISTEN_HTTP,
+    );
+    command::config::show::start(ui, &service_group, listen_http, m.is_present("DIFF_DEFAULT"))
 }
 
 fn sub_origin_key_download(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -267,6 +312,13 @@ fn sub_origin_key_generate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::origin::key::generate::start(ui, &origin, &default_cache_key_path(Some(&*FS_ROOT)))
 }
 
+fn sub_origin_key_list(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let origin = origin_param_or_env(&m)?;
+    init();
+
+    command::origin::key::list::start(ui, &origin, &default_cache_key_path(Some(&*FS_ROOT)))
+}
+
 fn sub_origin_key_import(ui: &mut UI) -> Result<()> {
     let mut content = String::new();
     io::stdin().read_to_string(&mut content)?;
@@ -275,6 +327,22 @@ fn sub_origin_key_import(ui: &mut UI) -> Result<()> {
     command::origin::key::import::start(ui, &content, &default_cache_key_path(Some(&*FS_ROOT)))
 }
 
+fn sub_origin_key_rotate(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let token = auth_token_param_or_env(&m)?;
+    let origin = origin_param_or_env(&m)?;
+    init();
+
+    command::origin::key::rotate::start(
+        ui,
+        &url,
+        &token,
+        &origin,
+        m.is_present("DEPRECATE"),
+        &default_cache_key_path(Some(&*FS_ROOT)),
+    )
+}
+
 fn sub_origin_key_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let url = bldr_url_from_matches(m);
     let token = auth_token_param_or_env(&m)?;
@@ -300,6 +368,34 @@ fn sub_origin_key_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     }
 }
 
+fn sub_origin_secret_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let token = auth_token_param_or_env(&m)?;
+    let origin = origin_param_or_env(&m)?;
+    let key = m.value_of("KEY_NAME").unwrap(); // Required via clap
+    let secret = m.value_of("SECRET").unwrap(); // Required via clap
+    init();
+
+    command::origin::secret::upload::start(ui, &url, &token, &origin, &key, &secret)
+}
+
+fn sub_origin_secret_list(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let token = auth_token_param_or_env(&m)?;
+    let origin = origin_param_or_env(&m)?;
+
+    command::origin::secret::list::start(ui, &url, &token, &origin)
+}
+
+fn sub_origin_secret_delete(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let token = auth_token_param_or_env(&m)?;
+    let origin = origin_param_or_env(&m)?;
+    let key = m.value_of("KEY_NAME").unwrap(); // Required via clap
+
+    command::origin::secret::delete::start(ui, &url, &token, &origin, &key)
+}
+
 fn sub_pkg_binlink(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
     let dest_dir = binlink_dest_dir_from_matches(m);
@@ -410,6 +506,34 @@ fn sub_bldr_encrypt(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::bldr::encrypt::start(ui, &url, &content, &default_cache_key_path(Some(&*FS_ROOT)))
 }
 
+fn sub_bldr_export(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let origin = m.value_of("ORIGIN").unwrap(); // Required via clap
+    let packages: Vec<String> = m.values_of("PKG_IDENT")
+        .unwrap() // Required via clap
+        .map(String::from)
+        .collect();
+    let dst_path = Path::new(m.value_of("DEST_DIR").unwrap_or("."));
+    let token = auth_token_param_or_env(&m)?;
+    command::bldr::export::start(
+        ui,
+        &url,
+        origin,
+        &packages,
+        m.value_of("CHANNEL"),
+        m.value_of("PKG_TARGET"),
+        &token,
+        &dst_path,
+    )
+}
+
+fn sub_bldr_import(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let bundle = Path::new(m.value_of("BUNDLE").unwrap()); // Required via clap
+    let token = auth_token_param_or_env(&m)?;
+    command::bldr::import::start(ui, &url, &bundle, &token)
+}
+
 fn sub_bldr_job_start(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
     let url = bldr_url_from_matches(m);
@@ -450,7 +574,21 @@ fn sub_bldr_job_status(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let url = bldr_url_from_matches(m);
     let group_id = m.value_of("GROUP_ID");
     let origin = m.value_of("ORIGIN");
-    command::bldr::job::status::start(ui, &url, group_id, origin)
+    let token = maybe_auth_token(&m);
+    command::bldr::job::status::start(
+        ui,
+        &url,
+        group_id,
+        origin,
+        token.as_ref().map(String::as_str),
+    )
+}
+
+fn sub_bldr_job_log(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let job_id = m.value_of("JOB_ID").unwrap(); // Required via clap
+    let token = auth_token_param_or_env(&m)?;
+    command::bldr::job::log::start(ui, &url, &job_id, &token, m.is_present("FOLLOW"))
 }
 
 fn sub_plan_init(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -477,6 +615,10 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let channel = channel_from_matches(m);
     let install_sources = install_sources_from_matches(m)?;
     let token = maybe_auth_token(&m);
+    set_throttle_from_matches(m);
+    if m.is_present("OFFLINE") {
+        env::set_var(OFFLINE_ENVVAR, "true");
+    }
 
     init();
 
@@ -508,6 +650,41 @@ fn sub_pkg_install(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     Ok(())
 }
 
+fn sub_pkg_download(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let url = bldr_url_from_matches(m);
+    let channel = channel_from_matches(m);
+    let token = maybe_auth_token(&m);
+    let pkg_idents = m.values_of("PKG_IDENT").unwrap(); // Required via clap
+    let mut idents = Vec::new();
+    for ident in pkg_idents {
+        idents.push(PackageIdent::from_str(ident)?);
+    }
+    let target = match m.value_of("PKG_TARGET") {
+        Some(target) => PackageTarget::from_str(target)?,
+        None => PackageTarget::default(),
+    };
+    let download_dir = match m.value_of("DOWNLOAD_DIRECTORY") {
+        Some(dir) => PathBuf::from(dir),
+        None => env::current_dir()?,
+    };
+    set_throttle_from_matches(m);
+    init();
+
+    command::pkg::download::start(
+        ui,
+        &url,
+        Some(&channel),
+        &idents,
+        &target,
+        &download_dir,
+        token.as_ref().map(String::as_str),
+    )
+}
+
+fn sub_pkg_list(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    command::pkg::list::start(ui, m.value_of("PKG_FILTER"), &*FS_ROOT)
+}
+
 fn sub_pkg_path(m: &ArgMatches) -> Result<()> {
     let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?;
 
@@ -523,11 +700,11 @@ fn sub_pkg_provides(m: &ArgMatches) -> Result<()> {
     command::pkg::provides::start(&filename, &*FS_ROOT, full_releases, full_paths)
 }
 
-fn sub_pkg_search(m: &ArgMatches) -> Result<()> {
+fn sub_pkg_search(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let url = bldr_url_from_matches(m);
     let search_term = m.value_of("SEARCH_TERM").unwrap(); // Required via clap
     let token = maybe_auth_token(&m);
-    command::pkg::search::start(&search_term, &url, token.as_ref().map(String::as_str))
+    command::pkg::search::start(ui, &search_term, &url, token.as_ref().map(String::as_str))
 }
 
 fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -543,6 +720,18 @@ fn sub_pkg_sign(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::sign::start(ui, &pair, &src, &dst)
 }
 
+fn sub_pkg_uninstall(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
+
+    command::pkg::uninstall::start(
+        ui,
+        &ident,
+        &*FS_ROOT,
+        m.is_present("DEPS"),
+        m.is_present("FORCE"),
+    )
+}
+
 fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let key_path = cache_key_path(Some(&*FS_ROOT));
     let url = bldr_url_from_matches(m);
@@ -552,6 +741,7 @@ fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let additional_release_channel: Option<&str> = m.value_of("CHANNEL");
 
     let token = auth_token_param_or_env(&m)?;
+    set_throttle_from_matches(m);
     let artifact_paths = m.values_of("HART_FILE").unwrap(); // Required via clap
     for artifact_path in artifact_paths {
         command::pkg::upload::start(
@@ -568,9 +758,17 @@ fn sub_pkg_upload(ui: &mut UI, m: &ArgMatches) -> Result<()> {
 
 fn sub_pkg_verify(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let src = Path::new(m.value_of("SOURCE").unwrap()); // Required via clap
+    let origin_key = m.value_of("ORIGIN_KEY").map(Path::new);
+    let url = bldr_url_from_matches(m);
     init();
 
-    command::pkg::verify::start(ui, &src, &default_cache_key_path(Some(&*FS_ROOT)))
+    command::pkg::verify::start(
+        ui,
+        &src,
+        &default_cache_key_path(Some(&*FS_ROOT)),
+        origin_key,
+        &url,
+    )
 }
 
 fn sub_pkg_header(ui: &mut UI, m: &ArgMatches) -> Result<()> {
@@ -596,12 +794,39 @@ fn sub_pkg_demote(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     command::pkg::demote::start(ui, &url, &ident, &channel, &token)
 }
 
+fn sub_pkg_dependencies(ui: &mut UI, m: &ArgMatches) -> Result<()> {
+    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
+    let url = bldr_url_from_matches(m);
+    let channel = channel_from_matches(m);
+    let token = maybe_auth_token(&m);
+
+    command::pkg::dependencies::start(
+        ui,
+        &ident,
+        &*FS_ROOT,
+        &url,
+        &channel,
+        token.as_ref().map(String::as_str),
+        m.is_present("REVERSE"),
+    )
+}
+
 fn sub_pkg_channels(ui: &mut UI, m: &ArgMatches) -> Result<()> {
     let url = bldr_url_from_matches(m);
-    let ident = PackageIdent::from_str(m.value_of("PKG_IDENT").unwrap())?; // Required via clap
+    let ident_or_origin = m.value_of("PKG_IDENT_OR_ORIGIN").unwrap(); // Required via clap
     let token = maybe_auth_token(&m);
 
-    command::pkg::channels::start(ui, &url, &ident, token.as_ref().map(String::as_str))
+    if ident_or_origin.contains('/') {
+        let ident = PackageIdent::from_str(ident_or_origin)?;
+        command::pkg::channels::start(ui, &url, &ident, token.as_ref().map(String::as_str))
+    } else {
+        command::pkg::channels::start_origin(
+            ui,
+            &url,
+            ident_or_origin,
+            token.as_ref().map(String::as_str),
+        )
+    }
 }
 
 fn sub_ring_key_export(m: &ArgMatches) -> Result<()> {
@@ -679,6 +904,11 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
             args.insert(0, OsString::from("config"));
             command::butterfly::start(ui, args)
         }
+        // Shown from the local Supervisor's HTTP gateway rather than the ring
+        ("config", "show", _) |
+        ("config", "s", _) |
+        ("config", "sh", _) |
+        ("config", "sho", _) => Ok(()),
         ("config", _, _) | ("file", _, _) => {
             command::butterfly::start(ui, env::args_os().skip(1).collect())
         }
@@ -711,6 +941,11 @@ fn exec_subcommand_if_called(ui: &mut UI) -> Result<()> {
     }
 }
 
+/// Whether `--json` was given, which being global may follow any of the subcommands
+fn json_requested(matches: &ArgMatches) -> bool {
+    matches.is_present("JSON") || matches.subcommand().1.map_or(false, json_requested)
+}
+
 /// Parse the raw program arguments and split off any arguments that will skip clap's parsing.
 ///
 /// **Note** with the current version of clap there is no clean way to ignore arguments after a
@@ -766,6 +1001,14 @@ fn maybe_auth_token(m: &ArgMatches) -> Option<String> {
     }
 }
 
+/// Hands the transfer rate given with the THROTTLE param to the depot clients the command creates,
+/// which read it from the environment.
+fn set_throttle_from_matches(m: &ArgMatches) {
+    if let Some(rate) = m.value_of("THROTTLE") {
+        env::set_var(THROTTLE_ENVVAR, rate);
+    }
+}
+
 /// Check to see if the user has passed in an ORIGIN param.  If not, check the HABITAT_ORIGIN env
 /// var. If not, check the CLI config to see if there is a default origin set. If that's empty too,
 /// then error.
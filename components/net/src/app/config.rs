@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use std::fmt;
-use std::net::{IpAddr, Ipv4Addr};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::str::FromStr;
 
 pub use core::config::ConfigFile;
@@ -70,6 +70,10 @@ pub struct AppCfg {
     /// Count of Dispatch workers to start and supervise.
     #[serde(default = "AppCfg::default_worker_count")]
     pub worker_count: usize,
+    /// Listening address of the health endpoints
+    pub health_listen: IpAddr,
+    /// Listening port of the health endpoints
+    pub health_port: u16,
 }
 
 impl AppCfg {
@@ -85,6 +89,10 @@ impl AppCfg {
     pub fn default_worker_count() -> usize {
         num_cpus::get() * 8
     }
+
+    pub fn health_addr(&self) -> SocketAddr {
+        SocketAddr::new(self.health_listen, self.health_port)
+    }
 }
 
 impl Default for AppCfg {
@@ -93,6 +101,8 @@ impl Default for AppCfg {
             routers: Self::default_routers(),
             shards: Self::default_shards(),
             worker_count: Self::default_worker_count(),
+            health_listen: IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)),
+            health_port: 9638,
         }
     }
 }
@@ -115,6 +125,8 @@ mod tests {
         let content = r#"
         shards = [0]
         worker_count = 1
+        health_listen = "2.2.2.2"
+        health_port = 9000
 
         [[routers]]
         host = "1:1:1:1:1:1:1:1"
@@ -124,6 +136,7 @@ mod tests {
         let config = AppCfg::from_str(&content).unwrap();
         assert_eq!(config.shards, Some(vec![0]));
         assert_eq!(config.worker_count, 1);
+        assert_eq!(&format!("{}", config.health_addr()), "2.2.2.2:9000");
         assert_eq!(&format!("{}", config.routers[0]), "1:1:1:1:1:1:1:1:9000");
     }
 
@@ -135,5 +148,6 @@ mod tests {
 
         let config = AppCfg::from_str(&content).unwrap();
         assert_eq!(config.worker_count, 0);
+        assert_eq!(config.health_port, 9638);
     }
 }
@@ -24,6 +24,7 @@ use protocol::{Message, Protocol};
 
 use super::AppState;
 use super::config::AppCfg;
+use super::health::HealthChecks;
 use conn::{ConnErr, ConnEvent, RouteConn};
 
 /// Dispatchers connect to Message Queue Servers
@@ -45,6 +46,12 @@ pub trait Dispatcher: Sized + Send + 'static {
     /// Returns a function dispatch table mapping which maps which protocol message is handled
     /// by which `Handler`.
     fn dispatch_table() -> &'static DispatchTable<Self>;
+
+    /// Returns the dependencies reported by the health endpoints besides the RouteSrvs, such as
+    /// the datastore. The default implementation reports none.
+    fn health_checks(_: &<Self::State as AppState>::InitState) -> HealthChecks {
+        vec![]
+    }
 }
 
 /// Message handler for incoming protocol messages.
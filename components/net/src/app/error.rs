@@ -15,6 +15,7 @@
 use std::error;
 use std::fmt;
 
+use hyper;
 use protocol;
 use zmq;
 
@@ -29,6 +30,8 @@ where
 {
     /// Wrapper for network connection send and receive errors.
     Connection(conn::ConnErr),
+    /// Occurs when the health endpoints can't be served.
+    HealthServer(hyper::Error),
     /// Occurs when the Application fails to initialize.
     Init(E),
     /// Occurs when no active RouteSrv can be selected to route a request originating from a
@@ -45,6 +48,7 @@ where
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             AppError::Connection(ref e) => write!(f, "{}", e),
+            AppError::HealthServer(ref e) => write!(f, "Unable to serve health endpoints, {}", e),
             AppError::Init(ref e) => write!(f, "Application failed to initialize, {}", e),
             AppError::NoRouter => write!(f, "Failed to route request, no reachable RouteSrv"),
             AppError::Protocol(ref e) => write!(f, "{}", e),
@@ -59,6 +63,7 @@ where
     fn description(&self) -> &str {
         match *self {
             AppError::Connection(ref err) => err.description(),
+            AppError::HealthServer(ref err) => err.description(),
             AppError::Init(_) => "Application failed to initialize.",
             AppError::NoRouter => "Failed to route request, no reachable RouteSrv.",
             AppError::Protocol(ref err) => err.description(),
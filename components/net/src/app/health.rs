@@ -0,0 +1,191 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Serves the health of a service at `/health/ready` and `/health/deps`, so that load balancers
+//! and orchestrators can stop sending work to a service which lost its routers or its datastore.
+//!
+//! Every dependency is checked live on each request. `/health/deps` reports the status of each of
+//! them, while `/health/ready` only tells whether all of them are healthy, answering with
+//! `503 Service Unavailable` if they aren't.
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use hyper;
+use hyper::method::Method;
+use hyper::server::{Handler, Request, Response, Server};
+use hyper::status::StatusCode;
+use hyper::uri::RequestUri;
+use serde_json;
+
+/// A dependency a service needs to do its work.
+pub trait HealthCheck: Send + Sync {
+    /// Returns why the dependency is unavailable, if it is.
+    fn check(&self) -> Result<(), String>;
+}
+
+/// Named dependencies of a service, in the order they're reported.
+pub type HealthChecks = Vec<(&'static str, Box<HealthCheck>)>;
+
+#[derive(Debug, Serialize)]
+pub struct HealthReport {
+    /// Whether all dependencies are healthy
+    pub ready: bool,
+    pub deps: Vec<DepHealth>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DepHealth {
+    pub name: String,
+    pub healthy: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Readiness {
+    ready: bool,
+}
+
+impl HealthReport {
+    /// Checks every dependency.
+    pub fn check(checks: &HealthChecks) -> Self {
+        let deps: Vec<DepHealth> = checks
+            .iter()
+            .map(|&(name, ref check)| {
+                let error = check.check().err();
+                DepHealth {
+                    name: name.to_string(),
+                    healthy: error.is_none(),
+                    error: error,
+                }
+            })
+            .collect();
+        HealthReport {
+            ready: deps.iter().all(|dep| dep.healthy),
+            deps: deps,
+        }
+    }
+}
+
+/// Counts the routers a service is connected to, as it gets no work without any.
+#[derive(Clone, Default)]
+pub struct RouterHealth(Arc<AtomicUsize>);
+
+impl RouterHealth {
+    pub fn set_connected(&self, count: usize) {
+        self.0.store(count, Ordering::Relaxed);
+    }
+}
+
+impl HealthCheck for RouterHealth {
+    fn check(&self) -> Result<(), String> {
+        if self.0.load(Ordering::Relaxed) == 0 {
+            return Err("Not connected to any RouteSrv".to_string());
+        }
+        Ok(())
+    }
+}
+
+struct HealthSrv {
+    checks: HealthChecks,
+}
+
+impl Handler for HealthSrv {
+    fn handle(&self, req: Request, mut res: Response) {
+        let path = match req.uri {
+            RequestUri::AbsolutePath(ref path) => path.split('?').next().unwrap_or("").to_string(),
+            _ => String::new(),
+        };
+        if req.method != Method::Get {
+            *res.status_mut() = StatusCode::NotFound;
+            return;
+        }
+
+        let report = HealthReport::check(&self.checks);
+        let body = match path.as_str() {
+            "/health/ready" => {
+                if !report.ready {
+                    *res.status_mut() = StatusCode::ServiceUnavailable;
+                }
+                serde_json::to_vec(&Readiness { ready: report.ready })
+            }
+            "/health/deps" => serde_json::to_vec(&report),
+            _ => {
+                *res.status_mut() = StatusCode::NotFound;
+                return;
+            }
+        };
+
+        res.headers_mut().set_raw(
+            "Content-Type",
+            vec![b"application/json".to_vec()],
+        );
+        if let Err(err) = res.send(&body.unwrap()) {
+            debug!("Unable to send health report, err={}", err);
+        }
+    }
+}
+
+/// Starts serving the health of the given dependencies at the given address.
+pub fn start(addr: SocketAddr, checks: HealthChecks) -> hyper::Result<()> {
+    let server = Server::http(addr)?;
+    thread::Builder::new()
+        .name("health".to_string())
+        .spawn(move || {
+            // The listening guard waits on the server's threads, which run until exit
+            match server.handle_threads(HealthSrv { checks: checks }, 1) {
+                Ok(_listening) => (),
+                Err(err) => error!("Health server stopped, {}", err),
+            }
+        })
+        .unwrap();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Down;
+
+    impl HealthCheck for Down {
+        fn check(&self) -> Result<(), String> {
+            Err("connection refused".to_string())
+        }
+    }
+
+    #[test]
+    fn report() {
+        let router = RouterHealth::default();
+        let checks: HealthChecks = vec![("router", Box::new(router.clone()) as Box<HealthCheck>)];
+        assert!(!HealthReport::check(&checks).ready);
+        router.set_connected(1);
+        assert!(HealthReport::check(&checks).ready);
+
+        let checks: HealthChecks = vec![
+            ("router", Box::new(router) as Box<HealthCheck>),
+            ("datastore", Box::new(Down) as Box<HealthCheck>),
+        ];
+        let report = HealthReport::check(&checks);
+        assert!(!report.ready);
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            "{\"ready\":false,\"deps\":[{\"name\":\"router\",\"healthy\":true},\
+             {\"name\":\"datastore\",\"healthy\":false,\"error\":\"connection refused\"}]}"
+        );
+    }
+}
@@ -168,6 +168,7 @@
 
 pub mod config;
 pub mod error;
+pub mod health;
 pub mod prelude;
 mod dispatcher;
 
@@ -184,6 +185,7 @@ use zmq;
 
 use self::error::{AppError, AppResult};
 use self::dispatcher::{Dispatcher, DispatcherPool};
+use self::health::{HealthCheck, RouterHealth};
 use conn::{self, ConnErr, ConnEvent};
 use error::{ErrCode, NetError};
 use socket::{self, DEFAULT_CONTEXT, ToAddrString};
@@ -240,6 +242,8 @@ where
     router_sock: zmq::Socket,
     /// Set of RouteSrv's connections.
     routers: HashSet<Vec<u8>>,
+    /// Count of RouteSrv's connections, reported by the health endpoints.
+    router_health: RouterHealth,
     marker: PhantomData<T>,
 }
 
@@ -258,6 +262,7 @@ where
         let pipe_in = (**DEFAULT_CONTEXT).as_mut().socket(zmq::DEALER).unwrap();
         let mut registration = routesrv::Registration::new();
         registration.set_protocol(T::PROTOCOL);
+        registration.set_protocol_version(protocol::PROTOCOL_VERSION);
         if let Some(ref shards) = config.as_ref().shards {
             registration.set_shards(shards.to_vec());
         }
@@ -271,6 +276,7 @@ where
             registration: protocol::Message::build(&registration)?,
             router_sock: router_sock,
             routers: HashSet::default(),
+            router_health: RouterHealth::default(),
             marker: PhantomData,
         })
     }
@@ -300,6 +306,7 @@ where
         ) {
             Ok(()) => {
                 self.routers.insert(self.msg_buf.sender().unwrap().to_vec());
+                self.router_health.set_connected(self.routers.len());
                 Ok(())
             }
             Err(ConnErr::HostUnreachable) => Ok(()),
@@ -321,6 +328,9 @@ where
                         ErrCode::REG_CONFLICT => {
                             error!("{}, retrying registration to RouteSrv", err);
                         }
+                        ErrCode::REG_INCOMPATIBLE_VERSION => {
+                            error!("{}, upgrade this service or RouteSrv", err);
+                        }
                         ErrCode::REG_NOT_FOUND => {
                             match conn::send_to(
                                 &self.router_sock,
@@ -367,7 +377,18 @@ where
         self.pipe_in.bind(&*pipe_in)?;
         self.pipe_out.bind(&*pipe_out)?;
         let dispatch = DispatcherPool::<T>::new(pipe_in, pipe_out.clone(), &config);
+        let health_addr = config.as_ref().health_addr();
         let state = T::app_init(config, pipe_out).map_err(AppError::Init)?;
+        let mut checks = vec![
+            (
+                "router",
+                Box::new(self.router_health.clone()) as Box<HealthCheck>,
+            ),
+        ];
+        checks.extend(T::health_checks(&state));
+        health::start(health_addr, checks).map_err(
+            AppError::HealthServer,
+        )?;
         dispatch.run(state);
         info!("{} is ready to go.", T::APP_NAME);
         loop {
@@ -425,6 +446,7 @@ where
                     for addr in drop_buf.iter() {
                         self.routers.remove(addr);
                     }
+                    self.router_health.set_connected(self.routers.len());
                     drop_buf.clear();
                 }
                 RecvEvent::Shutdown => {
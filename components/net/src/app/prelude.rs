@@ -30,6 +30,7 @@ pub use super::start as app_start;
 pub use super::AppState;
 pub use super::config::AppCfg;
 pub use super::dispatcher::{Dispatcher, DispatchTable};
+pub use super::health::{HealthCheck, HealthChecks};
 pub use super::error::{AppError, AppResult};
 pub use conn::RouteConn;
 pub use error::{ErrCode, NetError, NetOk};
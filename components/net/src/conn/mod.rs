@@ -85,6 +85,29 @@ impl RouteClient {
     where
         M: Routable,
         T: protobuf::MessageStatic,
+    {
+        self.send_recv(msg)?;
+        self.parse_reply::<T>()
+    }
+
+    /// Routes a message like `route`, for services which may answer it with either a reply of
+    /// type `T` or a message of type `E` explaining why the request couldn't be served.
+    pub fn route_either<M, T, E>(&mut self, msg: &M) -> NetResult<Result<T, E>>
+    where
+        M: Routable,
+        T: protobuf::MessageStatic,
+        E: protobuf::MessageStatic,
+    {
+        self.send_recv(msg)?;
+        if self.msg_buf.message_id() == E::descriptor_static(None).name() {
+            return self.parse_reply::<E>().map(Err);
+        }
+        self.parse_reply::<T>().map(Ok)
+    }
+
+    fn send_recv<M>(&mut self, msg: &M) -> NetResult<()>
+    where
+        M: Routable,
     {
         self.msg_buf.reset();
         if let Err(e) = self.msg_buf.populate(msg) {
@@ -145,6 +168,13 @@ impl RouteClient {
                 Err(err) => panic!("{}", err),
             }
         }
+        Ok(())
+    }
+
+    fn parse_reply<T>(&self) -> NetResult<T>
+    where
+        T: protobuf::MessageStatic,
+    {
         match self.msg_buf.parse::<T>() {
             Ok(reply) => Ok(reply),
             Err(e) => {
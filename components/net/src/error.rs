@@ -167,6 +167,7 @@ impl error::Error for NetError {
             ErrCode::SOCK => "Network error.",
             ErrCode::DATA_STORE => "Database error.",
             ErrCode::BAD_TOKEN => "Received a badly formed authorization token.",
+            ErrCode::RATE_LIMITED => "Too many requests, slow down and try again later.",
             ErrCode::WORKSPACE_SETUP => "Worker runner unable to setup build workspace.",
             ErrCode::SECRET_KEY_FETCH => "Worker runner unable to fetch secret key for origin.",
             ErrCode::SECRET_KEY_IMPORT => "Worker runner unable to import secret key for origin.",
@@ -176,10 +177,20 @@ impl error::Error for NetError {
             ErrCode::INVALID_INTEGRATIONS => {
                 "Worker runner found invalid project or origin integrations."
             }
+            ErrCode::BUILD_TIMEOUT => "Worker runner stopped a build which ran out of time.",
+            ErrCode::BUILD_OFFLINE => {
+                "Build failed while it was denied network access after its downloads."
+            }
+            ErrCode::LOW_DISK_SPACE => "Worker runner has too little disk space to build.",
+            ErrCode::DOCKER_EXPORT => "Worker runner failed to export the build as a Docker image.",
+            ErrCode::BUILD_OOM => "Build ran out of the memory its job was given.",
             ErrCode::REG_CONFLICT => {
                 "Service registration rejected by RouteSrv. Conflicting registration."
             }
             ErrCode::REG_NOT_FOUND => "RouteSrv was unable to find a registration for Service.",
+            ErrCode::REG_INCOMPATIBLE_VERSION => {
+                "Service registration rejected by RouteSrv. Incompatible protocol version."
+            }
             ErrCode::REMOTE_UNAVAILABLE => "Remote server not respnoding.",
             ErrCode::GROUP_NOT_COMPLETE => "Scheduler Job Group incomplete.",
             ErrCode::PARTIAL_JOB_GROUP_PROMOTE => {
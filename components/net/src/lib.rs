@@ -19,6 +19,7 @@
 extern crate bitflags;
 extern crate habitat_builder_protocol as protocol;
 extern crate habitat_core as core;
+extern crate hyper;
 #[macro_use]
 extern crate lazy_static;
 #[macro_use]
@@ -28,6 +29,7 @@ extern crate protobuf;
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
+extern crate serde_json;
 extern crate time as simple_time;
 extern crate toml;
 extern crate uuid;
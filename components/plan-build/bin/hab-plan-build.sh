@@ -135,6 +135,16 @@
 # pkg_build_deps=(linux-headers)
 # ```
 #
+# ### pkg_secrets
+# An array of the names of secrets the build needs, such as tokens for private
+# registries. Builder sets them from the origin's secrets and a build without
+# one fails. Each is exported as an environment variable of the same name from
+# `do_download()` through `do_build()`, and unset again before the package is
+# checked and installed. Elsewhere, set them as `HAB_STUDIO_SECRET_<name>`.
+# ```
+# pkg_secrets=(NPM_TOKEN)
+# ```
+#
 # ### pkg_lib_dirs
 # An array of paths, relative to the final install of the software, where
 # libraries can be found. Used to populate `LD_FLAGS` and `LD_RUN_PATH` for
@@ -412,6 +422,10 @@ declare -A pkg_binds_optional
 pkg_svc_user=hab
 # The group to run the service as
 pkg_svc_group=$pkg_svc_user
+# The names of the secrets the build needs
+pkg_secrets=()
+# The values of the secrets passed to the program, keyed by name
+declare -A _secrets
 
 # Initially set $pkg_svc_* variables. This happens before the Plan is sourced,
 # meaning that `$pkg_name` is not yet set. However, `$pkg_svc_run` wants
@@ -1594,6 +1608,45 @@ _fix_libtool() {
   done
 }
 
+# **Internal** Moves the secrets passed in `HAB_STUDIO_SECRET_*` environment
+# variables into `$_secrets`, so that nothing the Plan runs sees them until the
+# build phases which need them.
+_stash_secrets() {
+  local var
+  for var in $(compgen -e HAB_STUDIO_SECRET_); do
+    _secrets[${var#HAB_STUDIO_SECRET_}]="${!var}"
+    unset "$var"
+  done
+}
+
+# **Internal** Verifies that every secret listed in `$pkg_secrets` was passed
+# to the program, otherwise it fails the build.
+_verify_secrets() {
+  local name
+  for name in "${pkg_secrets[@]}"; do
+    if [[ -z "${_secrets[$name]+x}" ]]; then
+      exit_with "Plan needs secret '$name', set it as HAB_STUDIO_SECRET_$name" 1
+    fi
+  done
+}
+
+# **Internal** Exports the secrets listed in `$pkg_secrets` under their names.
+_export_secrets() {
+  local name
+  for name in "${pkg_secrets[@]}"; do
+    export "$name=${_secrets[$name]}"
+  done
+}
+
+# **Internal** Removes the secrets listed in `$pkg_secrets` from the
+# environment.
+_unset_secrets() {
+  local name
+  for name in "${pkg_secrets[@]}"; do
+    unset "$name"
+  done
+}
+
 # **Internal** Verifies that any lazily-computed, required variables have been
 # set, otherwise it fails the build.
 _verify_vars() {
@@ -2122,6 +2175,9 @@ cd "$PLAN_CONTEXT"
 declare -g pkg_type="standalone"
 declare -A -g pkg_bind_map
 
+# Keep any secrets out of the environment until the build phases which need them
+_stash_secrets
+
 # Load the Plan
 build_line "Loading $PLAN_CONTEXT/plan.sh"
 if source "$PLAN_CONTEXT/plan.sh"; then
@@ -2237,6 +2293,9 @@ case "${pkg_type}" in
     "standalone")
         # Non-composite ("normal") Habitat package
 
+        # Fail before any work is done if the Plan needs a secret it wasn't given
+        _verify_secrets
+
         # Run `do_begin`
         build_line "$_program setup"
         do_begin
@@ -2271,12 +2330,19 @@ case "${pkg_type}" in
         # Run any code after the environment is set but before the build starts
         do_before
 
+        # Expose the Plan's secrets to the phases which fetch and build the source
+        _export_secrets
+
         # Download the source
         do_download
 
         # Verify the source
         do_verify
 
+        # Nothing is fetched past this point, which Builder workers may use to drop the build's
+        # network access
+        build_line "Downloads complete"
+
         # Clean the cache
         do_clean
 
@@ -2298,6 +2364,9 @@ case "${pkg_type}" in
         # Build the source
         do_build_wrapper
 
+        # Nothing past this point needs the Plan's secrets
+        _unset_secrets
+
         # Check the source
         do_check_wrapper
 
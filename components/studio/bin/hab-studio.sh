@@ -95,6 +95,7 @@ ENVIRONMENT VARIABLES:
     HAB_STUDIOS_HOME    Sets a home path for all Studios (default: /hab/studios)
     HAB_STUDIO_NOSTUDIORC Disables sourcing a \`.studiorc' in \`studio enter'
     HAB_STUDIO_ROOT     Sets a Studio root (\`-r' option overrides)
+    HAB_STUDIO_SECRET_* Propagates secrets, without the prefix, to plans listing them in \`pkg_secrets'
     HAB_STUDIO_SUP      Sets args for a Supervisor in \`studio enter'
     NO_ARTIFACT_PATH    If set, do not mount the source artifact cache path (\`-N' flag overrides)
     NO_SRC_PATH         If set, do not mount the source path (\`-n' flag overrides)
@@ -977,6 +978,12 @@ chroot_env() {
   if [ -n "${HAB_UPDATE_STRATEGY_FREQUENCY_MS:-}" ]; then
     env="$env HAB_UPDATE_STRATEGY_FREQUENCY_MS=$HAB_UPDATE_STRATEGY_FREQUENCY_MS"
   fi
+  # Propagate any secrets into the Studio's environment, where the build program
+  # decides which of them a plan gets to see. As the environment is a
+  # whitespace separated list, secret values can't contain whitespace.
+  for secret in $($bb env | $bb grep '^HAB_STUDIO_SECRET_'); do
+    env="$env $secret"
+  done
 
   # If HTTP proxy variables are detected in the current environment, propagate
   # them into the Studio's environment.
@@ -1032,6 +1039,9 @@ report_env_vars() {
   if [ -n "${HAB_UPDATE_STRATEGY_FREQUENCY_MS:-}" ]; then
     info "Exported: HAB_UPDATE_STRATEGY_FREQUENCY_MS=$HAB_UPDATE_STRATEGY_FREQUENCY_MS"
   fi
+  for secret in $($bb env | $bb grep '^HAB_STUDIO_SECRET_' | $bb cut -d= -f1); do
+    info "Exported: $secret=[redacted]"
+  done
   if [ -n "${http_proxy:-}" ]; then
     info "Exported: http_proxy=$http_proxy"
   fi
@@ -115,6 +115,7 @@ pub enum Error {
     BadStartStyle(String),
     BadEnvConfig(String),
     ButterflyError(butterfly::error::Error),
+    CensusDeserializationError(serde_json::Error),
     DepotClient(depot_client::Error),
     EnvJoinPathsError(env::JoinPathsError),
     ExecCommandNotFound(String),
@@ -218,6 +219,9 @@ impl fmt::Display for SupError {
                 format!("Unable to find valid TOML or JSON in {} ENVVAR", varname)
             }
             Error::ButterflyError(ref err) => format!("Butterfly error: {}", err),
+            Error::CensusDeserializationError(ref e) => {
+                format!("Can't deserialize census: {}", e)
+            }
             Error::ExecCommandNotFound(ref c) => {
                 format!("`{}' was not found on the filesystem or in PATH", c)
             }
@@ -370,6 +374,7 @@ impl error::Error for SupError {
             Error::BadStartStyle(_) => "Unknown start style in service spec",
             Error::BadEnvConfig(_) => "Unknown syntax in Env Configuration",
             Error::ButterflyError(ref err) => err.description(),
+            Error::CensusDeserializationError(_) => "Can't deserialize census",
             Error::ExecCommandNotFound(_) => "Exec command was not found on filesystem or in PATH",
             Error::TemplateFileError(ref err) => err.description(),
             Error::TemplateRenderError(ref err) => err.description(),
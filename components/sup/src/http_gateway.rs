@@ -13,13 +13,14 @@
 // limitations under the License.
 
 use std::collections::HashMap;
+use std::error::Error as StdError;
 use std::fmt;
 use std::fs::File;
 use std::io::{self, Read};
 use std::net::{IpAddr, Ipv4Addr, ToSocketAddrs, SocketAddr, SocketAddrV4};
 use std::ops::{Deref, DerefMut};
 use std::option;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::result;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -33,11 +34,12 @@ use persistent;
 use prometheus::{self, CounterVec, HistogramVec, TextEncoder, Encoder};
 use router::Router;
 use serde_json::{self, Value as Json};
+use toml;
 
 use error::{Result, Error, SupError};
 use manager;
-use manager::service::HealthCheck;
-use manager::service::hooks::{self, HealthCheckHook};
+use manager::service::{Cfg, HealthCheck};
+use manager::service::dry_run;
 
 static LOGKEY: &'static str = "HG";
 const APIDOCS: &'static str = include_str!(concat!(env!("OUT_DIR"), "/api.html"));
@@ -161,10 +163,22 @@ impl Server {
             service_config: get "/services/:svc/:group/config" => {
                 with_metrics!(config, "config")
             },
+            service_default_config: get "/services/:svc/:group/config/default" => {
+                with_metrics!(default_config, "default_config")
+            },
+            service_config_dry_run: post "/services/:svc/:group/config/dry-run" => {
+                with_metrics!(config_dry_run, "config_dry_run")
+            },
             service_health: get "/services/:svc/:group/health" => with_metrics!(health, "health"),
             service_config_org: get "/services/:svc/:group/:org/config" => {
                 with_metrics!(config, "config")
             },
+            service_default_config_org: get "/services/:svc/:group/:org/config/default" => {
+                with_metrics!(default_config, "default_config")
+            },
+            service_config_dry_run_org: post "/services/:svc/:group/:org/config/dry-run" => {
+                with_metrics!(config_dry_run, "config_dry_run")
+            },
             service_health_org: get "/services/:svc/:group/:org/health" => {
                 with_metrics!(health, "config")
             }
@@ -186,7 +200,7 @@ impl Server {
     }
 }
 
-#[derive(Default, Serialize)]
+#[derive(Serialize)]
 struct HealthCheckBody {
     stdout: String,
     stderr: String,
@@ -231,38 +245,115 @@ fn config(req: &mut Request) -> IronResult<Response> {
     }
 }
 
-fn health(req: &mut Request) -> IronResult<Response> {
+/// The default configuration of a service, which its running configuration was merged onto
+fn default_config(req: &mut Request) -> IronResult<Response> {
     let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
-    let (health_file, stdout_path, stderr_path) = match build_service_group(req) {
-        Ok(sg) => {
-            (
-                state.health_check_cache(&sg),
-                hooks::stdout_log_path::<HealthCheckHook>(&sg),
-                hooks::stderr_log_path::<HealthCheckHook>(&sg),
-            )
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    let service = match service_from_file(&service_group, &state.services_data_path) {
+        Ok(Some(service)) => service,
+        Ok(None) => return Ok(Response::with(status::NotFound)),
+        Err(_) => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    // The same directory the Supervisor loaded the service's `default.toml` from
+    let config_dir = match service["config_from"].as_str().or(
+        service["pkg"]["path"].as_str(),
+    ) {
+        Some(dir) => PathBuf::from(dir),
+        None => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    match Cfg::load_default(&config_dir) {
+        Ok(default) => {
+            let default = default.unwrap_or(toml::Value::Table(toml::value::Table::new()));
+            Ok(Response::with((
+                status::Ok,
+                Header(headers::ContentType::json()),
+                serde_json::to_string(&default).unwrap(),
+            )))
         }
+        Err(_) => Ok(Response::with(status::InternalServerError)),
+    }
+}
+
+/// Tries out the configuration in the request body without applying it to the service group.
+/// Only clients on the same host may, as the gateway doesn't authenticate its clients and the
+/// rendered templates can hold the service's secrets.
+fn config_dry_run(req: &mut Request) -> IronResult<Response> {
+    if !is_loopback(&req.remote_addr.ip()) {
+        return Ok(Response::with(status::Forbidden));
+    }
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
         Err(_) => return Ok(Response::with(status::BadRequest)),
     };
-    match File::open(&health_file) {
-        Ok(mut file) => {
-            let mut buf = String::new();
-            let mut body = HealthCheckBody::default();
-            file.read_to_string(&mut buf).unwrap();
-            let code = i8::from_str(buf.trim()).unwrap();
-            let status: status::Status = HealthCheck::from(code).into();
-            if let Ok(mut file) = File::open(&stdout_path) {
-                let _ = file.read_to_string(&mut body.stdout);
-            }
-            if let Ok(mut file) = File::open(&stderr_path) {
-                let _ = file.read_to_string(&mut body.stderr);
-            }
+    let mut body = String::new();
+    if req.body.read_to_string(&mut body).is_err() {
+        return Ok(Response::with(status::BadRequest));
+    }
+    let proposed = match toml::de::from_str::<toml::value::Table>(&body) {
+        Ok(table) => toml::Value::Table(table),
+        Err(err) => return Ok(Response::with((status::BadRequest, err.to_string()))),
+    };
+    let service = match service_from_file(&service_group, &state.services_data_path) {
+        Ok(Some(service)) => service,
+        Ok(None) => return Ok(Response::with(status::NotFound)),
+        Err(_) => return Ok(Response::with(status::ServiceUnavailable)),
+    };
+    match dry_run::config(&state, &service_group, &service, proposed) {
+        Ok(dry_run) => {
+            Ok(Response::with((
+                status::Ok,
+                Header(headers::ContentType::json()),
+                serde_json::to_string(&dry_run).unwrap(),
+            )))
+        }
+        // The service's templates weren't rendered yet
+        Err(SupError { err: Error::Io(ref err), .. }) if err.kind() == io::ErrorKind::NotFound => {
+            Ok(Response::with(status::ServiceUnavailable))
+        }
+        Err(err) => {
+            let message = match err.err {
+                Error::TemplateFileError(ref e) => e.to_string(),
+                Error::TemplateRenderError(ref e) => e.to_string(),
+                _ => err.description().to_string(),
+            };
+            Ok(Response::with((status::UnprocessableEntity, message)))
+        }
+    }
+}
+
+fn is_loopback(addr: &IpAddr) -> bool {
+    match *addr {
+        IpAddr::V4(ref addr) => addr.is_loopback(),
+        IpAddr::V6(ref addr) => {
+            addr.is_loopback() || addr.to_ipv4().map_or(false, |addr| addr.is_loopback())
+        }
+    }
+}
+
+fn health(req: &mut Request) -> IronResult<Response> {
+    let state = req.get::<persistent::Read<ManagerFs>>().unwrap();
+    let service_group = match build_service_group(req) {
+        Ok(sg) => sg,
+        Err(_) => return Ok(Response::with(status::BadRequest)),
+    };
+    match state.last_health_check(&service_group) {
+        Some(report) => {
+            let status: status::Status = report.result.into();
+            let body = HealthCheckBody {
+                stdout: report.stdout,
+                stderr: report.stderr,
+            };
             Ok(Response::with((
                 status,
                 Header(headers::ContentType::json()),
                 serde_json::to_string(&body).unwrap(),
             )))
         }
-        Err(_) => Ok(Response::with(status::NotFound)),
+        None => Ok(Response::with(status::NotFound)),
     }
 }
 
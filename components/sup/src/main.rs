@@ -15,6 +15,7 @@
 extern crate habitat_common as common;
 #[macro_use]
 extern crate habitat_core as hcore;
+extern crate habitat_depot_client as depot_client;
 extern crate habitat_launcher_client as launcher_client;
 #[macro_use]
 extern crate habitat_sup as sup;
@@ -27,9 +28,13 @@ extern crate clap;
 extern crate time;
 extern crate url;
 extern crate tabwriter;
+extern crate serde;
+#[macro_use]
+extern crate serde_derive;
 
 use std::collections::{HashMap, HashSet};
 use std::collections::hash_map::Entry;
+use std::env;
 use std::io::{self, Write};
 use std::net::{SocketAddr, ToSocketAddrs};
 use std::path::{Path, PathBuf};
@@ -40,7 +45,9 @@ use std::str::FromStr;
 use ansi_term::Colour::{Red, Yellow};
 use clap::{App, ArgMatches};
 use common::command::package::install::InstallSource;
-use common::ui::UI;
+use common::ui::{OutputFormat, UI};
+use depot_client::Rate;
+use depot_client::throttle::THROTTLE_ENVVAR;
 use hcore::channel;
 use hcore::crypto::{self, default_cache_key_path, SymKey};
 #[cfg(windows)]
@@ -51,7 +58,7 @@ use hcore::package::PackageIdent;
 use hcore::package::install::PackageInstall;
 use hcore::package::metadata::{BindMapping, PackageType};
 use hcore::service::{ApplicationEnvironment, ServiceGroup};
-use hcore::url::{bldr_url_from_env, default_bldr_url};
+use hcore::url::{bldr_url_from_env, bldr_urls, default_bldr_url};
 use launcher_client::{LauncherCli, ERR_NO_RETRY_EXCODE, OK_NO_RETRY_EXCODE};
 use tabwriter::TabWriter;
 use url::Url;
@@ -224,6 +231,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg BLDR_URL: --url -u +takes_value {valid_url}
                 "Receive Supervisor updates from Builder at the specified URL \
                 [default: https://bldr.habitat.sh]")
+            (@arg THROTTLE: --throttle +takes_value {valid_rate}
+                "Transfer packages from and to Builder no faster than the given rate \
+                (ex: 5MB/s) [default: unlimited]")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
                 itself")
             (@arg EVENTS: --events -n +takes_value {valid_service_group} "Name of the service \
@@ -275,6 +285,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The update strategy; [default: none] [values: none, at-once, rolling]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg THROTTLE: --throttle +takes_value {valid_rate}
+                "Transfer packages from and to Builder no faster than the given rate \
+                (ex: 5MB/s) [default: unlimited]")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                 "Use package config from this path, rather than the package itself")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
@@ -286,6 +299,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (about: "Query the status of Habitat services.")
             (aliases: &["stat", "statu", "status"])
             (@arg PKG_IDENT: +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg JSON: --json "Print the statuses as JSON for scripts")
+            (@arg DETAIL: -d --detail "Show the health check, updates and binds of each service")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
@@ -393,6 +408,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (@arg BLDR_URL: --url -u +takes_value {valid_url}
                 "Receive Supervisor updates from Builder at the specified URL \
                 [default: https://bldr.habitat.sh]")
+            (@arg THROTTLE: --throttle +takes_value {valid_rate}
+                "Transfer packages from and to Builder no faster than the given rate \
+                (ex: 5MB/s) [default: unlimited]")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
                 itself")
             (@arg EVENTS: --events -n +takes_value {valid_service_group} "Name of the service \
@@ -444,6 +462,9 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
                 "The update strategy; [default: none] [values: none, at-once, rolling]")
             (@arg BIND: --bind +takes_value +multiple
                 "One or more service groups to bind to a configuration")
+            (@arg THROTTLE: --throttle +takes_value {valid_rate}
+                "Transfer packages from and to Builder no faster than the given rate \
+                (ex: 5MB/s) [default: unlimited]")
             (@arg CONFIG_DIR: --("config-from") +takes_value {dir_exists}
                 "Use package config from this path, rather than the package itself")
             (@arg AUTO_UPDATE: --("auto-update") -A "Enable automatic updates for the Supervisor \
@@ -456,6 +477,8 @@ fn cli<'a, 'b>() -> App<'a, 'b> {
             (about: "Query the status of Habitat services.")
             (aliases: &["stat", "statu", "status"])
             (@arg PKG_IDENT: +takes_value "A Habitat package identifier (ex: core/redis)")
+            (@arg JSON: --json "Print the statuses as JSON for scripts")
+            (@arg DETAIL: -d --detail "Show the health check, updates and binds of each service")
             (@arg NAME: --("override-name") +takes_value
                 "The name for the state directory if there is more than one Supervisor running \
                 [default: default]")
@@ -853,18 +876,61 @@ fn sub_status(m: &ArgMatches) -> Result<()> {
                     process::exit(2);
                 }
             };
-            print_statuses(specs.iter()
-                                .filter_map(|spec| Manager::service_status(&cfg, &spec.ident).ok())
-                                .collect::<Vec<ServiceStatus>>())?;
+            let statuses = specs
+                .iter()
+                .filter_map(|spec| Manager::service_status(&cfg, &spec.ident).ok())
+                .collect::<Vec<ServiceStatus>>();
+            if m.is_present("DETAIL") {
+                print_status_details(&cfg, statuses, m.is_present("JSON"))?;
+            } else {
+                print_statuses(statuses, m.is_present("JSON"))?;
+            }
         }
         None => {
-            print_statuses(Manager::status(&cfg)?)?;
+            if m.is_present("DETAIL") {
+                print_status_details(&cfg, Manager::status(&cfg)?, m.is_present("JSON"))?;
+            } else {
+                print_statuses(Manager::status(&cfg)?, m.is_present("JSON"))?;
+            }
         }
     }
     Ok(())
 }
 
-fn print_statuses(statuses: Vec<ServiceStatus>) -> Result<()> {
+/// The status of a service as printed by `status --json`, with the columns of the table
+#[derive(Serialize)]
+struct StatusRow {
+    package: String,
+    #[serde(rename = "type")]
+    kind: String,
+    state: String,
+    uptime: i64,
+    pid: Option<u32>,
+    group: String,
+    style: String,
+}
+
+fn print_statuses(statuses: Vec<ServiceStatus>, json: bool) -> Result<()> {
+    if json {
+        let rows: Vec<StatusRow> = statuses
+            .into_iter()
+            .map(|status| {
+                StatusRow {
+                    package: status.pkg.ident.to_string(),
+                    kind: status.composite.unwrap_or("standalone".to_string()),
+                    state: status.process.state.to_string(),
+                    uptime: status.process.elapsed.num_seconds(),
+                    pid: status.process.pid,
+                    group: status.service_group.to_string(),
+                    style: status.start_style.to_string(),
+                }
+            })
+            .collect();
+        let mut ui = UI::default();
+        ui.set_format(OutputFormat::Json);
+        ui.json(&rows)?;
+        return Ok(());
+    }
     if statuses.is_empty() {
         println!("No services loaded.");
         return Ok(());
@@ -887,6 +953,158 @@ fn print_statuses(statuses: Vec<ServiceStatus>) -> Result<()> {
     return Ok(());
 }
 
+/// The details of a service as printed by `status --detail --json`
+#[derive(Serialize)]
+struct StatusDetail {
+    package: String,
+    #[serde(rename = "type")]
+    kind: String,
+    state: String,
+    uptime: i64,
+    pid: Option<u32>,
+    group: String,
+    style: String,
+    health: Option<HealthDetail>,
+    update_strategy: String,
+    channel: String,
+    latest: Option<String>,
+    update_available: Option<bool>,
+    binds: Vec<BindDetail>,
+}
+
+#[derive(Serialize)]
+struct HealthDetail {
+    status: String,
+    stdout: String,
+    stderr: String,
+}
+
+#[derive(Serialize)]
+struct BindDetail {
+    name: String,
+    group: String,
+    satisfied: Option<bool>,
+}
+
+fn status_detail(
+    cfg: &ManagerConfig,
+    status: ServiceStatus,
+    alive_groups: Option<&HashSet<String>>,
+) -> StatusDetail {
+    let health = Manager::health_check(cfg, &status.service_group).map(|report| {
+        HealthDetail {
+            status: report.result.to_string(),
+            stdout: report.stdout,
+            stderr: report.stderr,
+        }
+    });
+    // The latest release the Supervisor would update the service to
+    let latest = if status.bldr_url.is_empty() || status.channel.is_empty() {
+        None
+    } else {
+        let ident = status.spec_ident.clone().unwrap_or_else(|| {
+            PackageIdent::new(status.pkg.origin.clone(), status.pkg.name.clone(), None, None)
+        });
+        util::pkg::latest(&status.bldr_url, &ident, &status.channel).ok()
+    };
+    let binds = status
+        .binds
+        .iter()
+        .map(|bind| {
+            BindDetail {
+                name: bind.name.clone(),
+                group: bind.service_group.to_string(),
+                satisfied: alive_groups.map(|groups| {
+                    groups.contains(&bind.service_group.to_string())
+                }),
+            }
+        })
+        .collect();
+    StatusDetail {
+        package: status.pkg.ident.to_string(),
+        kind: status.composite.unwrap_or("standalone".to_string()),
+        state: status.process.state.to_string(),
+        uptime: status.process.elapsed.num_seconds(),
+        pid: status.process.pid,
+        group: status.service_group.to_string(),
+        style: status.start_style.to_string(),
+        health: health,
+        update_strategy: status.update_strategy.to_string(),
+        channel: status.channel,
+        update_available: latest.as_ref().map(|latest| *latest > status.pkg.ident),
+        latest: latest.map(|latest| latest.to_string()),
+        binds: binds,
+    }
+}
+
+fn print_status_details(
+    cfg: &ManagerConfig,
+    statuses: Vec<ServiceStatus>,
+    json: bool,
+) -> Result<()> {
+    let alive_groups = Manager::alive_service_groups(cfg).ok();
+    let details: Vec<StatusDetail> = statuses
+        .into_iter()
+        .map(|status| status_detail(cfg, status, alive_groups.as_ref()))
+        .collect();
+    if json {
+        let mut ui = UI::default();
+        ui.set_format(OutputFormat::Json);
+        ui.json(&details)?;
+        return Ok(());
+    }
+    if details.is_empty() {
+        println!("No services loaded.");
+        return Ok(());
+    }
+    let mut tw = TabWriter::new(io::stdout());
+    for (i, detail) in details.iter().enumerate() {
+        if i > 0 {
+            write!(tw, "\n")?;
+        }
+        write!(tw, "{} ({})\n", detail.package, detail.kind)?;
+        write!(tw, "  state:\t{} for {}s", detail.state, detail.uptime)?;
+        match detail.pid {
+            Some(pid) => write!(tw, ", pid {}\n", pid)?,
+            None => write!(tw, "\n")?,
+        }
+        write!(tw, "  group:\t{}\n", detail.group)?;
+        write!(tw, "  style:\t{}\n", detail.style)?;
+        match detail.health {
+            Some(ref health) => {
+                write!(tw, "  health:\t{}\n", health.status)?;
+                for line in health.stdout.lines().chain(health.stderr.lines()) {
+                    write!(tw, "\t{}\n", line)?;
+                }
+            }
+            None => write!(tw, "  health:\tnot checked yet\n")?,
+        }
+        write!(
+            tw,
+            "  update:\t{} from channel {}\n",
+            detail.update_strategy,
+            detail.channel
+        )?;
+        match (detail.latest.as_ref(), detail.update_available) {
+            (Some(latest), Some(true)) => {
+                write!(tw, "  latest:\t{} (update available)\n", latest)?
+            }
+            (Some(latest), _) => write!(tw, "  latest:\t{} (up to date)\n", latest)?,
+            (None, _) => write!(tw, "  latest:\tunknown\n")?,
+        }
+        for bind in detail.binds.iter() {
+            let satisfied = match bind.satisfied {
+                Some(true) => "satisfied",
+                Some(false) => "not satisfied",
+                None => "unknown",
+            };
+            write!(tw, "  bind:\t{} to {} ({})\n", bind.name, bind.group, satisfied)?;
+        }
+    }
+    tw.flush()?;
+    Ok(())
+}
+
 fn sub_stop(m: &ArgMatches) -> Result<()> {
     if m.is_present("VERBOSE") {
         hcore::output::set_verbose(true);
@@ -990,6 +1208,11 @@ fn mgrcfg_from_matches(m: &ArgMatches) -> Result<ManagerConfig> {
     cfg.auto_update = m.is_present("AUTO_UPDATE");
     cfg.update_url = bldr_url(m);
     cfg.update_channel = channel(m);
+    // Every depot client of the Supervisor reads the rate to throttle transfers at from the
+    // environment
+    if let Some(rate) = m.value_of("THROTTLE") {
+        env::set_var(THROTTLE_ENVVAR, rate);
+    }
     if let Some(addr_str) = m.value_of("LISTEN_GOSSIP") {
         cfg.gossip_listen = GossipListenAddr::from_str(addr_str)?;
     }
@@ -1498,13 +1721,22 @@ fn valid_update_strategy(val: String) -> result::Result<(), String> {
     }
 }
 
-fn valid_url(val: String) -> result::Result<(), String> {
-    match Url::parse(&val) {
+fn valid_rate(val: String) -> result::Result<(), String> {
+    match Rate::from_str(&val) {
         Ok(_) => Ok(()),
-        Err(_) => Err(format!("URL: '{}' is not valid", &val)),
+        Err(e) => Err(format!("Transfer rate: {}", e)),
     }
 }
 
+fn valid_url(val: String) -> result::Result<(), String> {
+    for url in bldr_urls(&val) {
+        if Url::parse(url).is_err() {
+            return Err(format!("URL: '{}' is not valid", url));
+        }
+    }
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////
 
 fn enable_features_from_env() {
@@ -24,7 +24,7 @@ mod file_watcher;
 mod peer_watcher;
 mod sys;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
@@ -55,7 +55,9 @@ use time::{self, Timespec, Duration as TimeDuration};
 pub use self::service::{CompositeSpec, Service, ServiceBind, ServiceSpec, UpdateStrategy, Topology};
 pub use self::sys::Sys;
 use self::self_updater::{SUP_PKG_IDENT, SelfUpdater};
-use self::service::{DesiredState, Pkg, ProcessState, StartStyle};
+use self::service::{DesiredState, HealthCheck, HealthCheckReport, Pkg, ProcessState,
+                    ServiceBind, StartStyle, UpdateStrategy};
+use self::service::hooks::{self, HealthCheckHook};
 use self::service_updater::ServiceUpdater;
 use self::spec_watcher::{SpecWatcher, SpecWatcherEvent};
 use self::peer_watcher::PeerWatcher;
@@ -118,6 +120,41 @@ impl FsCfg {
             format!("{}.health", service_group.service()),
         )
     }
+
+    /// The context the templates of a service were last rendered with
+    pub fn render_context_cache(&self, service_group: &ServiceGroup) -> PathBuf {
+        self.data_path.join(
+            format!("{}.render", service_group.service()),
+        )
+    }
+
+    /// The result of the last health check of a service, or None if it wasn't checked yet
+    pub fn last_health_check(&self, service_group: &ServiceGroup) -> Option<HealthCheckReport> {
+        let mut buf = String::new();
+        if File::open(self.health_check_cache(service_group))
+            .and_then(|mut file| file.read_to_string(&mut buf))
+            .is_err()
+        {
+            return None;
+        }
+        let mut report = match i8::from_str(buf.trim()) {
+            Ok(code) => HealthCheckReport {
+                result: HealthCheck::from(code),
+                ..Default::default()
+            },
+            Err(_) => return None,
+        };
+        // The hook's output is only there if the service has a health check hook
+        let stdout_path = hooks::stdout_log_path::<HealthCheckHook>(service_group);
+        if let Ok(mut file) = File::open(&stdout_path) {
+            let _ = file.read_to_string(&mut report.stdout);
+        }
+        let stderr_path = hooks::stderr_log_path::<HealthCheckHook>(service_group);
+        if let Ok(mut file) = File::open(&stderr_path) {
+            let _ = file.read_to_string(&mut report.stderr);
+        }
+        Some(report)
+    }
 }
 
 #[derive(Clone, Default)]
@@ -208,6 +245,27 @@ impl Manager {
         serde_json::from_reader(&dat).map_err(|e| sup_error!(Error::ServiceDeserializationError(e)))
     }
 
+    /// The result of the last health check of a service, or None if it wasn't checked yet
+    pub fn health_check(
+        cfg: &ManagerConfig,
+        service_group: &ServiceGroup,
+    ) -> Option<HealthCheckReport> {
+        FsCfg::new(Self::state_path_from(cfg)).last_health_check(service_group)
+    }
+
+    /// The service groups in the census with at least one member which is alive, which the binds
+    /// of services are satisfied by.
+    pub fn alive_service_groups(cfg: &ManagerConfig) -> Result<HashSet<String>> {
+        let state_path = Self::state_path_from(cfg);
+        let fs_cfg = FsCfg::new(state_path);
+
+        let dat = File::open(&fs_cfg.census_data_path)?;
+        let census: serde_json::Value = serde_json::from_reader(&dat).map_err(|e| {
+            sup_error!(Error::CensusDeserializationError(e))
+        })?;
+        Ok(alive_service_groups(&census))
+    }
+
     pub fn term(cfg: &ManagerConfig) -> Result<()> {
         let state_path = Self::state_path_from(&cfg);
         let fs_cfg = FsCfg::new(state_path);
@@ -855,6 +913,16 @@ impl Manager {
                 err
             );
         }
+        if let Err(err) = fs::remove_file(
+            self.fs_cfg.render_context_cache(&service.service_group),
+        )
+        {
+            debug!(
+                "Unable to cleanup service render context cache, {}, {}",
+                service,
+                err
+            );
+        }
     }
 
     fn write_service<W: ?Sized>(
@@ -1011,6 +1079,17 @@ pub struct ServiceStatus {
     pub service_group: ServiceGroup,
     pub start_style: StartStyle,
     pub composite: Option<String>,
+    // Older Supervisors didn't persist the following
+    #[serde(default)]
+    pub spec_ident: Option<PackageIdent>,
+    #[serde(default)]
+    pub bldr_url: String,
+    #[serde(default)]
+    pub channel: String,
+    #[serde(default)]
+    pub update_strategy: UpdateStrategy,
+    #[serde(default)]
+    pub binds: Vec<ServiceBind>,
 }
 
 impl fmt::Display for ServiceStatus {
@@ -1027,6 +1106,26 @@ impl fmt::Display for ServiceStatus {
     }
 }
 
+/// The names of the service groups of a census, as persisted by the Supervisor, with at least one
+/// member which is alive
+fn alive_service_groups(census: &serde_json::Value) -> HashSet<String> {
+    let groups = match census["census_groups"].as_object() {
+        Some(groups) => groups,
+        None => return HashSet::new(),
+    };
+    groups
+        .iter()
+        .filter(|&(_, group)| {
+            group["population"].as_object().map_or(false, |population| {
+                population.values().any(
+                    |member| member["alive"].as_bool().unwrap_or(false),
+                )
+            })
+        })
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
 #[derive(Debug)]
 struct SuitabilityLookup(Arc<RwLock<Vec<Service>>>);
 
@@ -1153,7 +1252,9 @@ where
 mod test {
     use std::path::PathBuf;
 
-    use super::{Manager, ManagerConfig, STATE_PATH_PREFIX};
+    use serde_json;
+
+    use super::{alive_service_groups, Manager, ManagerConfig, STATE_PATH_PREFIX};
 
     #[test]
     fn manager_state_path_default() {
@@ -1196,4 +1297,28 @@ mod test {
 
         assert_eq!(PathBuf::from("/tmp/partay"), path);
     }
+
+    #[test]
+    fn census_alive_service_groups() {
+        let census = json!({
+            "census_groups": {
+                "redis.default": {
+                    "population": {
+                        "a": { "alive": false },
+                        "b": { "alive": true }
+                    }
+                },
+                "postgres.default": {
+                    "population": {
+                        "c": { "alive": false }
+                    }
+                },
+                "nginx.default": { "population": {} }
+            }
+        });
+        let groups = alive_service_groups(&census);
+        assert_eq!(groups.len(), 1);
+        assert!(groups.contains("redis.default"));
+        assert!(alive_service_groups(&serde_json::Value::Null).is_empty());
+    }
 }
@@ -191,7 +191,8 @@ impl Cfg {
         }
     }
 
-    fn load_default<T: AsRef<Path>>(config_from: T) -> Result<Option<toml::Value>> {
+    /// Loads the `default.toml` of a package, or the directory configuration is taken from instead.
+    pub fn load_default<T: AsRef<Path>>(config_from: T) -> Result<Option<toml::Value>> {
         Self::load_toml_file(config_from, "default.toml")
     }
 
@@ -401,6 +402,22 @@ impl CfgRenderer {
         }
         Ok(changed)
     }
+
+    /// Renders all configuration files without writing them, returning the names of the ones
+    /// whose content differs from what's in the configuration directory.
+    pub fn changed<T: Serialize>(&self, pkg: &Pkg, ctx: &T) -> Result<Vec<String>> {
+        let mut changed = Vec::new();
+        for (template, _) in self.0.get_templates() {
+            let compiled = self.0.render(&template, ctx)?;
+            let file_hash = crypto::hash::hash_file(&pkg.svc_config_path.join(&template))
+                .unwrap_or_default();
+            if file_hash != crypto::hash::hash_string(&compiled) {
+                changed.push(template.to_string());
+            }
+        }
+        changed.sort();
+        Ok(changed)
+    }
 }
 
 // Recursively merges the `other` TOML table into `me`
@@ -0,0 +1,94 @@
+// Copyright (c) 2017 Chef Software Inc. and/or applicable contributors
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tries out a configuration for a service group without applying it.
+//!
+//! A configuration applied to a service group replaces the gossip layer of its services'
+//! configuration. The templates of a loaded service are rendered again with the proposed
+//! configuration merged in that way, against the context they were last rendered with, and the
+//! results are compared with the configuration files and hooks on disk. Nothing is written and no
+//! hook is run.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::PathBuf;
+
+use hcore::service::ServiceGroup;
+use serde_json::{self, Value as Json};
+use toml;
+
+use super::{Cfg, Pkg, Service};
+use super::config::CfgRenderer;
+use super::hooks::HookTable;
+use error::{Error, Result};
+use fs;
+use manager::FsCfg;
+
+static LOGKEY: &'static str = "DR";
+
+/// What applying a configuration to a service would do
+#[derive(Debug, Default, Serialize)]
+pub struct DryRun {
+    /// Configuration files whose content would change
+    pub config_files: Vec<String>,
+    /// Hooks whose content would change
+    pub hooks: Vec<String>,
+    /// Whether the service would be restarted, as it has no reload hook
+    pub restart: bool,
+    /// Whether the reload hook of the service would be run
+    pub reload: bool,
+    /// Whether the reconfigure hook of the service would be run
+    pub reconfigure: bool,
+}
+
+/// Renders the templates of a loaded service with the proposed configuration, given the service's
+/// state as persisted by the Manager.
+pub fn config(
+    fs_cfg: &FsCfg,
+    service_group: &ServiceGroup,
+    service: &Json,
+    proposed: toml::Value,
+) -> Result<DryRun> {
+    let pkg: Pkg = serde_json::from_value(service["pkg"].clone()).map_err(|e| {
+        sup_error!(Error::ServiceDeserializationError(e))
+    })?;
+    let config_from = service["config_from"].as_str().map(PathBuf::from);
+    let mut cfg = Cfg::new(&pkg, config_from.as_ref())?;
+    cfg.gossip = Some(proposed);
+
+    let file = File::open(fs_cfg.render_context_cache(service_group))?;
+    let mut ctx: Json = serde_json::from_reader(BufReader::new(file)).map_err(|e| {
+        sup_error!(Error::RenderContextSerialization(e))
+    })?;
+    ctx["cfg"] = serde_json::to_value(&cfg).map_err(|e| {
+        sup_error!(Error::RenderContextSerialization(e))
+    })?;
+
+    let renderer = CfgRenderer::new(&Service::config_root(&pkg, config_from.as_ref()))?;
+    let hooks = HookTable::load(
+        service_group,
+        &Service::hooks_root(&pkg, config_from.as_ref()),
+        fs::svc_hooks_path(service_group.service()),
+    );
+    let mut dry_run = DryRun::default();
+    dry_run.config_files = renderer.changed(&pkg, &ctx)?;
+    dry_run.hooks = hooks.changed(&ctx)?.iter().map(|h| h.to_string()).collect();
+    // The same as the Service does once its templates were compiled
+    if !dry_run.config_files.is_empty() || !dry_run.hooks.is_empty() {
+        dry_run.restart = hooks.reload.is_none();
+        dry_run.reload = hooks.reload.is_some();
+    }
+    dry_run.reconfigure = !dry_run.config_files.is_empty() && hooks.reconfigure.is_some();
+    Ok(dry_run)
+}
@@ -52,6 +52,14 @@ impl fmt::Display for HealthCheck {
     }
 }
 
+/// The result of the last health check of a service, with what its health check hook printed
+#[derive(Debug, Default)]
+pub struct HealthCheckReport {
+    pub result: HealthCheck,
+    pub stdout: String,
+    pub stderr: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum SmokeCheck {
     Ok,
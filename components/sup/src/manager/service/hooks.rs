@@ -116,6 +116,12 @@ pub trait Hook: fmt::Debug + Sized {
         }
     }
 
+    /// Whether compiling the hook with the given context would change it.
+    fn changed<T: Serialize>(&self, ctx: &T) -> Result<bool> {
+        let content = self.renderer().render(Self::file_name(), ctx)?;
+        Ok(hash_content(self.path())? != crypto::hash::hash_string(&content))
+    }
+
     /// Run a compiled hook.
     fn run<T>(
         &self,
@@ -888,6 +894,54 @@ impl HookTable {
         changed
     }
 
+    /// Renders all loaded hooks without writing them, returning the names of the ones which would
+    /// change.
+    pub fn changed<T: Serialize>(&self, ctx: &T) -> Result<Vec<&'static str>> {
+        let mut changed = Vec::new();
+        if let Some(ref hook) = self.file_updated {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        if let Some(ref hook) = self.health_check {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        if let Some(ref hook) = self.init {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        if let Some(ref hook) = self.reload {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        if let Some(ref hook) = self.reconfigure {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        if let Some(ref hook) = self.suitability {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        if let Some(ref hook) = self.run {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        if let Some(ref hook) = self.post_run {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        if let Some(ref hook) = self.smoke_test {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        if let Some(ref hook) = self.post_stop {
+            Self::changed_one(hook, ctx, &mut changed)?;
+        }
+        Ok(changed)
+    }
+
+    fn changed_one<H, T>(hook: &H, ctx: &T, changed: &mut Vec<&'static str>) -> Result<()>
+    where
+        H: Hook,
+        T: Serialize,
+    {
+        if hook.changed(ctx)? {
+            changed.push(H::file_name());
+        }
+        Ok(())
+    }
+
     fn compile_one<H>(&self, hook: &H, service_group: &ServiceGroup, ctx: &RenderContext) -> bool
     where
         H: Hook,
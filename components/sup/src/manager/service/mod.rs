@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod dry_run;
 pub mod hooks;
 mod composite_spec;
 mod config;
@@ -41,6 +42,7 @@ use hcore::util::deserialize_using_from_str;
 use hcore::util::perm::{set_owner, set_permissions};
 use launcher_client::LauncherCli;
 use serde;
+use serde_json;
 use time::Timespec;
 
 use super::Sys;
@@ -55,7 +57,7 @@ use templating::RenderContext;
 use util;
 
 pub use self::config::Cfg;
-pub use self::health::{HealthCheck, SmokeCheck};
+pub use self::health::{HealthCheck, HealthCheckReport, SmokeCheck};
 pub use self::package::Pkg;
 pub use self::composite_spec::CompositeSpec;
 pub use self::spec::{DesiredState, ServiceBind, ServiceSpec, StartStyle};
@@ -420,6 +422,7 @@ impl Service {
         if cfg_updated || census_ring.changed() {
             let (reload, reconfigure) = {
                 let ctx = self.render_context(census_ring);
+                self.cache_render_context(&ctx);
                 let reload = self.compile_hooks(&ctx);
                 let reconfigure = self.compile_configuration(&ctx);
                 (reload, reconfigure)
@@ -594,6 +597,48 @@ impl Service {
         }
     }
 
+    /// Keeps the context the templates were rendered with, so that a configuration can be tried out
+    /// against them without applying it.
+    fn cache_render_context(&self, ctx: &RenderContext) {
+        let cache_file = self.manager_fs_cfg.render_context_cache(&self.service_group);
+        let tmp_file = cache_file.with_extension("tmp");
+        let file = match File::create(&tmp_file) {
+            Ok(file) => file,
+            Err(err) => {
+                warn!(
+                    "Couldn't open temporary render context file, {}, {}",
+                    self.service_group,
+                    err
+                );
+                return;
+            }
+        };
+        let mut writer = BufWriter::new(file);
+        if let Some(err) = serde_json::to_writer(&mut writer, ctx).err() {
+            warn!(
+                "Couldn't write to temporary render context file, {}, {}",
+                self.service_group,
+                err
+            );
+            return;
+        }
+        if let Some(err) = writer.flush().err() {
+            warn!(
+                "Couldn't flush render context buffer to disk, {}, {}",
+                self.service_group,
+                err
+            );
+            return;
+        }
+        if let Some(err) = std::fs::rename(&tmp_file, &cache_file).err() {
+            warn!(
+                "Couldn't finalize render context file, {}, {}",
+                self.service_group,
+                err
+            );
+        }
+    }
+
     /// Helper for compiling configuration templates into configuration files.
     fn compile_configuration(&self, ctx: &RenderContext) -> bool {
         match self.config_renderer.compile(&self.pkg, ctx) {
@@ -17,6 +17,7 @@ use std::path::Path;
 use common;
 use common::command::package::install::InstallSource;
 use common::ui::UI;
+use depot_client::Client;
 use hcore::env as henv;
 use hcore::AUTH_TOKEN_ENVVAR;
 use hcore::fs::{self, FS_ROOT_PATH};
@@ -58,6 +59,18 @@ pub fn install(
 }
 
 /// Returns an installed package for the given ident, if one is present.
+/// The latest release of a package in a channel of Builder
+pub fn latest(url: &str, ident: &PackageIdent, channel: &str) -> Result<PackageIdent> {
+    let auth_token = henv::var(AUTH_TOKEN_ENVVAR).ok();
+    let depot_client = Client::new(url, PRODUCT, VERSION, None)?;
+    let package = depot_client.show_package(
+        ident,
+        Some(channel),
+        auth_token.as_ref().map(String::as_str),
+    )?;
+    Ok(package.get_ident().clone().into())
+}
+
 pub fn installed(ident: &PackageIdent) -> Option<PackageInstall> {
     let fs_root_path = Path::new(&*FS_ROOT_PATH);
     PackageInstall::load(ident, Some(fs_root_path)).ok()